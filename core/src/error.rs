@@ -21,6 +21,13 @@ pub enum ValidationError {
         _1
     )]
     MissingSchemaForBodyParameter(String, String),
+    /// If a query parameter is serialized as a `deepObject`, then schema must be specified.
+    #[error(
+        "Parameter {:?} in path {:?} is a deep object but the schema is missing",
+        _0,
+        _1
+    )]
+    MissingSchemaForDeepObjectParameter(String, String),
     /// Some headers have special meaning in OpenAPI. The user cannot have these headers
     /// in their API spec.
     #[error("Path {:?} has header parameter {:?} which is not allowed", _1, _0)]
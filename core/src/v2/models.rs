@@ -69,6 +69,7 @@ pub enum DataTypeFormat {
     Int64,
     Float,
     Double,
+    Decimal,
     Byte,
     Binary,
     Date,
@@ -92,6 +93,7 @@ impl ToString for DataTypeFormat {
             DataTypeFormat::Int64 => "int64",
             DataTypeFormat::Float => "float",
             DataTypeFormat::Double => "double",
+            DataTypeFormat::Decimal => "decimal",
             DataTypeFormat::Byte => "byte",
             DataTypeFormat::Binary => "binary",
             DataTypeFormat::Date => "date",
@@ -116,6 +118,7 @@ impl From<DataTypeFormat> for DataType {
             DataTypeFormat::Int64 => Self::Integer,
             DataTypeFormat::Float => Self::Number,
             DataTypeFormat::Double => Self::Number,
+            DataTypeFormat::Decimal => Self::Number,
             DataTypeFormat::Byte => Self::String,
             DataTypeFormat::Binary => Self::String,
             DataTypeFormat::Date => Self::String,
@@ -514,6 +517,10 @@ pub struct Parameter<S> {
     pub items: Option<Items>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub collection_format: Option<CollectionFormat>,
+    /// Serialization style of an object-valued query parameter (`x-style`
+    /// extension, borrowed from OpenAPI v3).
+    #[serde(rename = "x-style", skip_serializing_if = "Option::is_none")]
+    pub style: Option<ParameterStyle>,
     #[serde(default, skip_serializing_if = "is_false")]
     pub allow_empty_value: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -600,6 +607,16 @@ where
                 ));
             }
 
+            return Ok(());
+        } else if self.in_ == ParameterIn::Query && self.style == Some(ParameterStyle::DeepObject) {
+            // Deep objects are described by their schema.
+            if self.schema.is_none() {
+                return Err(ValidationError::MissingSchemaForDeepObjectParameter(
+                    self.name.clone(),
+                    path.into(),
+                ));
+            }
+
             return Ok(());
         } else if self.in_ == ParameterIn::Header {
             // Some headers aren't allowed.
@@ -672,6 +689,7 @@ pub enum ParameterIn {
     Path,
     FormData,
     Body,
+    Cookie,
 }
 
 /// Possible formats for array values in parameter.
@@ -685,6 +703,14 @@ pub enum CollectionFormat {
     Multi,
 }
 
+/// Serialization styles for object-valued parameters.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, Eq, PartialEq, Ord, PartialOrd)]
+#[serde(rename_all = "camelCase")]
+pub enum ParameterStyle {
+    /// Fields are sent as `name[field]=value` (nested objects add more brackets).
+    DeepObject,
+}
+
 /// Operation that can be traversed and resolved for codegen.
 pub type ResolvableOperation<S> = Operation<ResolvableParameter<S>, ResolvableResponse<S>>;
 
@@ -722,6 +748,29 @@ pub struct Operation<P, R> {
     pub deprecated: bool,
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub tags: Vec<String>,
+    #[serde(rename = "externalDocs", skip_serializing_if = "Option::is_none")]
+    pub external_docs: Option<ExternalDocs>,
+    /// Visibility of the client method generated for this operation
+    /// (`x-visibility` extension, e.g., `pub(crate)`).
+    #[serde(
+        default,
+        rename = "x-visibility",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub visibility: Option<String>,
+    /// Timeout (in milliseconds) for the requests made for this operation
+    /// (`x-timeout` extension). Generated clients set it on each request
+    /// (overriding the client's timeout), so requests taking any longer fail.
+    #[serde(default, rename = "x-timeout", skip_serializing_if = "Option::is_none")]
+    pub timeout: Option<u64>,
+    /// Requests made by the API back to the client (say, webhooks) after this
+    /// operation, mapped by their names (`x-callbacks` extension).
+    #[serde(
+        default,
+        rename = "x-callbacks",
+        skip_serializing_if = "BTreeMap::is_empty"
+    )]
+    pub callbacks: BTreeMap<String, Callback>,
 }
 
 impl<S> Operation<Parameter<S>, Response<S>> {
@@ -763,6 +812,26 @@ impl<S> Operation<Parameter<S>, Response<S>> {
     }
 }
 
+/// Request made by the API back to the client (`x-callbacks` extension, modeled
+/// after the `callbacks` of OpenAPI v3). The payload and the response (if any)
+/// refer to definitions.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct Callback {
+    /// URL of the request (usually a runtime expression like `{$request.body#/callbackUrl}`).
+    pub url: String,
+    /// HTTP method of the request (`POST` if unset).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub method: Option<HttpMethod>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Body of the request made by the API.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payload: Option<Reference>,
+    /// Body of the response expected from the client.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub response: Option<Reference>,
+}
+
 /// Reference object.
 ///
 /// <https://github.com/OAI/OpenAPI-Specification/blob/master/versions/2.0.md#referenceObject>
@@ -937,6 +1006,24 @@ where
             }
         }
     }
+
+    /// Fetch the example for this schema. Like the description, this is
+    /// taken from the referring schema (if it's a reference).
+    pub fn get_example(&self) -> Option<serde_json::Value> {
+        match *self {
+            Resolvable::Raw(ref s) => s.read().unwrap().example().cloned(),
+            Resolvable::Resolved { ref old, .. } => old.read().unwrap().example().cloned(),
+        }
+    }
+
+    /// Whether this schema is deprecated. Like the description, this is
+    /// taken from the referring schema (if it's a reference).
+    pub fn is_deprecated(&self) -> bool {
+        match *self {
+            Resolvable::Raw(ref s) => s.read().unwrap().deprecated(),
+            Resolvable::Resolved { ref old, .. } => old.read().unwrap().deprecated(),
+        }
+    }
 }
 
 /* Common trait impls */
@@ -8,7 +8,12 @@ use super::{
 use crate::error::ValidationError;
 use heck::ToPascalCase;
 
-use std::{cell::RefCell, collections::BTreeMap, mem};
+use std::{
+    cell::RefCell,
+    collections::{BTreeMap, BTreeSet, HashSet},
+    mem,
+    sync::Arc,
+};
 
 // FIXME: The resolver is not in its best. It "just" works atm.
 
@@ -98,9 +103,93 @@ where
             }
         }
 
+        // Now that all the references have been resolved, flatten `allOf`
+        // (including the anonymous schemas we've collected from operations).
+        let mut visited = HashSet::new();
+        for schema in self.defs.values() {
+            Self::flatten_all_of(schema, &mut visited);
+        }
+
         Ok(())
     }
 
+    /// Merges the properties of the `allOf` members (flattened first) of this
+    /// schema (and the schemas nested in it) into the schema, so that composed
+    /// objects have all the fields.
+    ///
+    /// A property is required if it's required by the schema or any of its
+    /// members. If more than one of them declares a property, then the schema's
+    /// own declaration is preferred over the members' (in their order), unless
+    /// it's nullable and a later one isn't (since values must satisfy all of them).
+    fn flatten_all_of(schema: &Resolvable<S>, visited: &mut HashSet<usize>) {
+        if !visited.insert(Arc::as_ptr(&**schema) as usize) {
+            return;
+        }
+
+        let (children, members) = {
+            let s = schema.read().unwrap();
+            let children = s
+                .properties()
+                .into_iter()
+                .flat_map(|p| p.values())
+                .chain(s.items())
+                .chain(s.additional_properties().and_then(Either::right))
+                .chain(s.one_of().into_iter().flatten())
+                .cloned()
+                .collect::<Vec<_>>();
+            let members = s.all_of().map(<[_]>::to_vec).unwrap_or_default();
+            (children, members)
+        };
+
+        for s in children.iter().chain(&members) {
+            Self::flatten_all_of(s, visited);
+        }
+
+        if members.is_empty() {
+            return;
+        }
+
+        let mut props = BTreeMap::new();
+        let mut required = BTreeSet::new();
+        {
+            let s = schema.read().unwrap();
+            if let Some(p) = s.properties() {
+                props.extend(p.iter().map(|(k, v)| (k.clone(), v.clone())));
+            }
+
+            required.extend(s.required_properties().into_iter().flatten().cloned());
+        }
+
+        for member in &members {
+            if Arc::ptr_eq(&**member, &**schema) {
+                continue;
+            }
+
+            let m = member.read().unwrap();
+            for (name, prop) in m.properties().into_iter().flatten() {
+                let replace = match props.get(name) {
+                    Some(p) => p.read().unwrap().nullable() && !prop.read().unwrap().nullable(),
+                    None => true,
+                };
+
+                if replace {
+                    props.insert(name.clone(), prop.clone());
+                }
+            }
+
+            required.extend(m.required_properties().into_iter().flatten().cloned());
+        }
+
+        let mut s = schema.write().unwrap();
+        for (name, prop) in props {
+            s.set_property(&name, prop);
+        }
+
+        for name in required {
+            s.set_required(&name);
+        }
+    }
+
     /// We've passed some definition. Resolve it assuming that it doesn't
     /// contain any reference.
     fn resolve_definitions_no_root_ref(
@@ -126,6 +215,13 @@ where
             })?;
         }
 
+        if let Some(props) = schema.pattern_properties_mut() {
+            props.iter_mut().try_for_each(|(k, s)| {
+                log::trace!("Resolving pattern property {:?}", k);
+                self.resolve_definitions(s)
+            })?;
+        }
+
         if let Some(props) = schema
             .additional_properties_mut()
             .take()
@@ -134,6 +230,18 @@ where
             self.resolve_definitions(props)?;
         }
 
+        if let Some(variants) = schema.one_of_mut() {
+            variants
+                .iter_mut()
+                .try_for_each(|s| self.resolve_definitions(s))?;
+        }
+
+        if let Some(members) = schema.all_of_mut() {
+            members
+                .iter_mut()
+                .try_for_each(|s| self.resolve_definitions(s))?;
+        }
+
         Ok(())
     }
 
@@ -178,6 +286,14 @@ where
     ) -> Result<(), ValidationError> {
         for (&method, op) in &mut map.methods {
             self.resolve_parameters(Some(method), path, &mut op.parameters)?;
+            // Callbacks are generated from the definitions they refer to,
+            // so we only need to make sure that those exist.
+            for callback in op.callbacks.values() {
+                for r in callback.payload.iter().chain(&callback.response) {
+                    self.resolve_definition_reference(&r.reference)?;
+                }
+            }
+
             for resp in op.responses.values_mut() {
                 let ref_resp = if let Some(r) = resp.left() {
                     log::trace!("Resolving response {}", r.reference);
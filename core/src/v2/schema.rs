@@ -1,8 +1,8 @@
 //! Traits used for code and spec generation.
 
 use super::models::{
-    DataType, DataTypeFormat, DefaultOperationRaw, DefaultSchemaRaw, Either, Resolvable,
-    SecurityScheme,
+    DataType, DataTypeFormat, DefaultOperationRaw, DefaultSchemaRaw, Either, ExternalDocs,
+    Resolvable, SecurityScheme,
 };
 
 use std::collections::{BTreeMap, BTreeSet};
@@ -17,6 +17,28 @@ pub trait Schema: Sized {
     /// Description for this schema, if any (`description` field).
     fn description(&self) -> Option<&str>;
 
+    /// Title for this schema, if any (`title` field).
+    fn title(&self) -> Option<&str> {
+        None
+    }
+
+    /// Whether this schema is only returned in responses and never sent
+    /// in requests (`readOnly` field).
+    fn read_only(&self) -> bool {
+        false
+    }
+
+    /// Whether this schema is only sent in requests and never returned
+    /// in responses (`writeOnly` field).
+    fn write_only(&self) -> bool {
+        false
+    }
+
+    /// Whether this schema is deprecated (`deprecated` field).
+    fn deprecated(&self) -> bool {
+        false
+    }
+
     /// Reference to some other schema, if any (`$ref` field).
     fn reference(&self) -> Option<&str>;
 
@@ -26,6 +48,56 @@ pub trait Schema: Sized {
     /// Data type format used by this schema, if any (`format` field).
     fn format(&self) -> Option<&DataTypeFormat>;
 
+    /// Lower bound for numeric values, if any (`minimum` field).
+    fn minimum(&self) -> Option<&serde_json::Number> {
+        None
+    }
+
+    /// Whether the lower bound itself is excluded (`exclusiveMinimum` field).
+    fn exclusive_minimum(&self) -> bool {
+        false
+    }
+
+    /// Upper bound for numeric values, if any (`maximum` field).
+    fn maximum(&self) -> Option<&serde_json::Number> {
+        None
+    }
+
+    /// Whether the upper bound itself is excluded (`exclusiveMaximum` field).
+    fn exclusive_maximum(&self) -> bool {
+        false
+    }
+
+    /// Whether `null` is allowed for this schema (`x-nullable` field).
+    fn nullable(&self) -> bool {
+        false
+    }
+
+    /// Maximum length of string values, if any (`maxLength` field).
+    fn max_length(&self) -> Option<u32> {
+        None
+    }
+
+    /// Regex which string values must match, if any (`pattern` field).
+    fn pattern(&self) -> Option<&str> {
+        None
+    }
+
+    /// Example value for this schema, if any (`example` field).
+    fn example(&self) -> Option<&serde_json::Value> {
+        None
+    }
+
+    /// Additional external documentation for this schema, if any (`externalDocs` field).
+    fn external_docs(&self) -> Option<&ExternalDocs> {
+        None
+    }
+
+    /// Default value for this schema, if any (`default` field).
+    fn default_value(&self) -> Option<&serde_json::Value> {
+        None
+    }
+
     /// Schema for array definitions, if any (`items` field).
     fn items(&self) -> Option<&Resolvable<Self>>;
 
@@ -44,9 +116,79 @@ pub trait Schema: Sized {
     /// Mutable access to `properties` field.
     fn properties_mut(&mut self) -> Option<&mut BTreeMap<String, Resolvable<Self>>>;
 
+    /// Value schemas for the properties whose names match the given patterns
+    /// (`patternProperties` field).
+    ///
+    /// **NOTE:** This isn't a part of OpenAPI v2, but it's borrowed from JSON
+    /// schema for maps with restricted keys.
+    fn pattern_properties(&self) -> Option<&BTreeMap<String, Resolvable<Self>>> {
+        None
+    }
+
+    /// Mutable access to `pattern_properties` field.
+    fn pattern_properties_mut(&mut self) -> Option<&mut BTreeMap<String, Resolvable<Self>>> {
+        None
+    }
+
     /// Returns the required properties (if any) for this object.
     fn required_properties(&self) -> Option<&BTreeSet<String>>;
 
+    /// Schemas for the alternatives (if any) of this schema (`oneOf` field).
+    ///
+    /// **NOTE:** This isn't a part of OpenAPI v2, but it's commonly used along
+    /// with `discriminator` for describing tagged unions.
+    fn one_of(&self) -> Option<&[Resolvable<Self>]> {
+        None
+    }
+
+    /// Mutable access to the `oneOf` field, if it has alternatives.
+    fn one_of_mut(&mut self) -> Option<&mut [Resolvable<Self>]> {
+        None
+    }
+
+    /// Schemas (if any) which this schema is composed of (`allOf` field). Once the
+    /// schema has been resolved, their properties have been flattened into this one.
+    fn all_of(&self) -> Option<&[Resolvable<Self>]> {
+        None
+    }
+
+    /// Mutable access to the `allOf` field, if it has members.
+    fn all_of_mut(&mut self) -> Option<&mut [Resolvable<Self>]> {
+        None
+    }
+
+    /// Sets (or replaces) the property with the given name, making this an object
+    /// (if it doesn't have a type already). This is done by the resolver when
+    /// flattening `allOf`, so it does nothing by default (like `all_of`).
+    fn set_property(&mut self, name: &str, schema: Resolvable<Self>) {
+        let _ = (name, schema);
+    }
+
+    /// Marks the property with the given name as required. This is done by the
+    /// resolver when flattening `allOf`, so it does nothing by default.
+    fn set_required(&mut self, name: &str) {
+        let _ = name;
+    }
+
+    /// Name of the property holding the type of this object, if any (`discriminator` field).
+    fn discriminator(&self) -> Option<&str> {
+        None
+    }
+
+    /// Name of the property holding the actual object (if it's wrapped) in
+    /// a tagged union (`x-discriminator-content` field).
+    fn discriminator_content(&self) -> Option<&str> {
+        None
+    }
+
+    /// Values of the discriminator for the alternatives (referenced by their
+    /// definitions) in a tagged union (`x-discriminator-mapping` field).
+    ///
+    /// Alternatives missing from this map use the names of their definitions.
+    fn discriminator_mapping(&self) -> Option<&BTreeMap<String, String>> {
+        None
+    }
+
     /// Enum variants in this schema (if any). It's `serde_json::Value`
     /// because:
     ///
@@ -84,7 +226,7 @@ pub trait Schema: Sized {
 }
 
 fn _schema_contains_any<'a, S: Schema>(schema: &'a S, mut nodes: Vec<&'a str>) -> bool {
-    if schema.data_type().is_none() {
+    if schema.data_type().is_none() && schema.one_of().is_none() {
         return true;
     }
 
@@ -107,6 +249,22 @@ fn _schema_contains_any<'a, S: Schema>(schema: &'a S, mut nodes: Vec<&'a str>) -
             .items()
             .map(|s| _schema_contains_any(&*s.read().unwrap(), nodes.clone()))
             .unwrap_or(false)
+        || schema
+            .one_of()
+            .map(|v| {
+                v.iter()
+                    .any(|s| _schema_contains_any(&*s.read().unwrap(), nodes.clone()))
+            })
+            .unwrap_or(false)
+        || schema
+            .pattern_properties()
+            .map(|t| {
+                // Values of multiple patterns are collected into the same map.
+                t.len() > 1
+                    || t.values()
+                        .any(|s| _schema_contains_any(&*s.read().unwrap(), nodes.clone()))
+            })
+            .unwrap_or(false)
         || schema
             .additional_properties()
             .map(|e| match e {
@@ -421,8 +579,8 @@ impl<T: TypedData> Apiv2Schema for T {
         DefaultSchemaRaw {
             data_type: Some(T::data_type()),
             format: T::format(),
-            maximum: T::max(),
-            minimum: T::min(),
+            maximum: T::max().and_then(|v| serde_json::Number::from_f64(v.into())),
+            minimum: T::min().and_then(|v| serde_json::Number::from_f64(v.into())),
             ..Default::default()
         }
     }
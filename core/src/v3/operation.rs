@@ -79,7 +79,7 @@ impl From<v2::Operation<v2::DefaultParameterRaw, v2::DefaultResponseRaw>> for op
             tags: v2.tags,
             summary: v2.summary,
             description: v2.description,
-            external_docs: None,
+            external_docs: v2.external_docs.map(From::from),
             operation_id: v2.operation_id,
             parameters,
             request_body,
@@ -25,6 +25,19 @@ impl From<v2::DefaultParameterRaw>
         };
 
         match v2.in_ {
+            v2::ParameterIn::Query if v2.style == Some(v2::ParameterStyle::DeepObject) => {
+                let mut data = parameter_data(None);
+                if let Some(schema) = v2.schema.clone() {
+                    data.format = openapiv3::ParameterSchemaOrContent::Schema(schema.into());
+                }
+
+                Either::Left(openapiv3::Parameter::Query {
+                    parameter_data: data,
+                    allow_reserved: false,
+                    style: openapiv3::QueryStyle::DeepObject,
+                    allow_empty_value: None,
+                })
+            }
             v2::ParameterIn::Query => Either::Left(openapiv3::Parameter::Query {
                 parameter_data: parameter_data(non_body_parameter_to_v3_parameter(false, &v2)),
                 allow_reserved: false,
@@ -39,6 +52,10 @@ impl From<v2::DefaultParameterRaw>
                 parameter_data: parameter_data(non_body_parameter_to_v3_parameter(false, &v2)),
                 style: Default::default(),
             }),
+            v2::ParameterIn::Cookie => Either::Left(openapiv3::Parameter::Cookie {
+                parameter_data: parameter_data(non_body_parameter_to_v3_parameter(false, &v2)),
+                style: Default::default(),
+            }),
             v2::ParameterIn::FormData => {
                 Either::Right(Either::Right(non_body_parameter_to_v3_parameter(true, &v2)))
             }
@@ -23,15 +23,22 @@ impl From<v2::DefaultSchemaRaw> for openapiv3::ReferenceOr<openapiv3::Schema> {
                 let item = openapiv3::Schema {
                     schema_data: openapiv3::SchemaData {
                         nullable: false,
-                        read_only: false,
-                        write_only: false,
-                        deprecated: false,
-                        external_docs: None,
+                        read_only: v2.read_only.unwrap_or(false),
+                        write_only: v2.write_only.unwrap_or(false),
+                        deprecated: v2.deprecated.unwrap_or(false),
+                        external_docs: v2.external_docs.map(From::from),
                         example: v2.example,
                         title: v2.title,
                         description: v2.description,
-                        discriminator: None,
-                        default: None,
+                        discriminator: match v2.discriminator {
+                            Some(property_name) => Some(openapiv3::Discriminator {
+                                property_name,
+                                mapping: v2.discriminator_mapping.into_iter().collect(),
+                                extensions: Default::default(),
+                            }),
+                            None => None,
+                        },
+                        default: v2.default,
                         extensions: Default::default(),
                     },
                     schema_kind: {
@@ -11,3 +11,103 @@ fn test_template_name_with_regex() {
     op.set_parameter_names_from_path_template("/test/{path:.*}");
     assert_eq!("path", op.parameters.first().unwrap().right().unwrap().name);
 }
+
+#[test]
+#[cfg(feature = "v2")]
+fn test_schema_defaults() {
+    use paperclip_core::v2::models::{DataType, DataTypeFormat, Either, Resolvable};
+    use paperclip_core::v2::schema::Schema;
+    use std::collections::{BTreeMap, BTreeSet};
+
+    // Schema implementing only the required methods (like before the optional
+    // accessors were added).
+    #[derive(Default)]
+    struct Plain {
+        name: Option<String>,
+        reference: Option<String>,
+        cyclic: bool,
+    }
+
+    impl Schema for Plain {
+        fn description(&self) -> Option<&str> {
+            None
+        }
+
+        fn reference(&self) -> Option<&str> {
+            self.reference.as_deref()
+        }
+
+        fn data_type(&self) -> Option<DataType> {
+            Some(DataType::Integer)
+        }
+
+        fn format(&self) -> Option<&DataTypeFormat> {
+            None
+        }
+
+        fn items(&self) -> Option<&Resolvable<Self>> {
+            None
+        }
+
+        fn items_mut(&mut self) -> Option<&mut Resolvable<Self>> {
+            None
+        }
+
+        fn additional_properties(&self) -> Option<&Either<bool, Resolvable<Self>>> {
+            None
+        }
+
+        fn additional_properties_mut(&mut self) -> Option<&mut Either<bool, Resolvable<Self>>> {
+            None
+        }
+
+        fn properties(&self) -> Option<&BTreeMap<String, Resolvable<Self>>> {
+            None
+        }
+
+        fn properties_mut(&mut self) -> Option<&mut BTreeMap<String, Resolvable<Self>>> {
+            None
+        }
+
+        fn required_properties(&self) -> Option<&BTreeSet<String>> {
+            None
+        }
+
+        fn enum_variants(&self) -> Option<&[serde_json::Value]> {
+            None
+        }
+
+        fn set_reference(&mut self, ref_: String) {
+            self.reference = Some(ref_);
+        }
+
+        fn set_cyclic(&mut self, cyclic: bool) {
+            self.cyclic = cyclic;
+        }
+
+        fn is_cyclic(&self) -> bool {
+            self.cyclic
+        }
+
+        fn name(&self) -> Option<&str> {
+            self.name.as_deref()
+        }
+
+        fn set_name(&mut self, name: &str) {
+            self.name = Some(name.into());
+        }
+    }
+
+    let mut schema = Plain::default();
+    assert!(schema.title().is_none());
+    assert!(!schema.read_only() && !schema.write_only() && !schema.deprecated());
+    assert!(schema.minimum().is_none() && schema.maximum().is_none());
+    assert!(!schema.nullable());
+    assert!(schema.one_of().is_none() && schema.all_of().is_none());
+    assert!(schema.pattern_properties().is_none());
+    assert!(schema.discriminator().is_none());
+    assert!(!schema.contains_any());
+
+    schema.set_required("id");
+    assert!(schema.required_properties().is_none());
+}
@@ -1223,6 +1223,7 @@ pub fn emit_v2_header(input: TokenStream) -> TokenStream {
             "int64" => quote! { Some(paperclip::v2::models::DataTypeFormat::Int64) },
             "float" => quote! { Some(paperclip::v2::models::DataTypeFormat::Float) },
             "double" => quote! { Some(paperclip::v2::models::DataTypeFormat::Double) },
+            "decimal" => quote! { Some(paperclip::v2::models::DataTypeFormat::Decimal) },
             "byte" => quote! { Some(paperclip::v2::models::DataTypeFormat::Byte) },
             "binary" => quote! { Some(paperclip::v2::models::DataTypeFormat::Binary) },
             "date" => quote! { Some(paperclip::v2::models::DataTypeFormat::Date) },
@@ -1650,14 +1651,14 @@ fn handle_field_struct(
 
         let max = if let Some(max) = extract_openapi_f32(&field.attrs, "maximum") {
             quote!({
-                s.maximum = Some(#max);
+                s.maximum = serde_json::Number::from_f64(f64::from(#max));
             })
         } else {
             quote!({})
         };
         let min = if let Some(min) = extract_openapi_f32(&field.attrs, "minimum") {
             quote!({
-                s.minimum = Some(#min);
+                s.minimum = serde_json::Number::from_f64(f64::from(#min));
             })
         } else {
             quote!({})
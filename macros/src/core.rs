@@ -61,8 +61,11 @@ pub fn emit_v2_schema_struct(input: TokenStream) -> TokenStream {
             /// Recursively removes all `$ref` values in this schema.
             pub fn remove_refs(&mut self) {
                 self.properties.values_mut().for_each(|s| s.remove_refs());
+                self.pattern_props.values_mut().for_each(|s| s.remove_refs());
                 self.items.as_mut().map(|s| s.remove_refs());
                 self.extra_props.as_mut().and_then(|s| s.right_mut()).map(|s| s.remove_refs());
+                self.one_of.iter_mut().for_each(|s| s.remove_refs());
+                self.all_of.iter_mut().for_each(|s| s.remove_refs());
                 self.reference = None;
             }
 
@@ -75,8 +78,11 @@ pub fn emit_v2_schema_struct(input: TokenStream) -> TokenStream {
                     self.reference = ref_;
                 } else {
                     self.properties.values_mut().for_each(|s| s.retain_ref());
+                    self.pattern_props.values_mut().for_each(|s| s.retain_ref());
                     self.items.as_mut().map(|s| s.retain_ref());
                     self.extra_props.as_mut().and_then(|s| s.right_mut()).map(|s| s.retain_ref());
+                    self.one_of.iter_mut().for_each(|s| s.retain_ref());
+                    self.all_of.iter_mut().for_each(|s| s.retain_ref());
                 }
             }
         }
@@ -107,6 +113,26 @@ pub fn emit_v2_schema_struct(input: TokenStream) -> TokenStream {
                 self.description.as_ref().map(String::as_str)
             }
 
+            #[inline]
+            fn title(&self) -> Option<&str> {
+                self.title.as_ref().map(String::as_str)
+            }
+
+            #[inline]
+            fn read_only(&self) -> bool {
+                self.read_only.unwrap_or(false)
+            }
+
+            #[inline]
+            fn write_only(&self) -> bool {
+                self.write_only.unwrap_or(false)
+            }
+
+            #[inline]
+            fn deprecated(&self) -> bool {
+                self.deprecated.unwrap_or(false)
+            }
+
             #[inline]
             fn reference(&self) -> Option<&str> {
                 self.reference.as_ref().map(String::as_str)
@@ -127,6 +153,56 @@ pub fn emit_v2_schema_struct(input: TokenStream) -> TokenStream {
                 self.format.as_ref()
             }
 
+            #[inline]
+            fn minimum(&self) -> Option<&serde_json::Number> {
+                self.minimum.as_ref()
+            }
+
+            #[inline]
+            fn exclusive_minimum(&self) -> bool {
+                self.exclusive_minimum.unwrap_or(false)
+            }
+
+            #[inline]
+            fn maximum(&self) -> Option<&serde_json::Number> {
+                self.maximum.as_ref()
+            }
+
+            #[inline]
+            fn exclusive_maximum(&self) -> bool {
+                self.exclusive_maximum.unwrap_or(false)
+            }
+
+            #[inline]
+            fn nullable(&self) -> bool {
+                self.nullable.unwrap_or(false)
+            }
+
+            #[inline]
+            fn max_length(&self) -> Option<u32> {
+                self.max_length
+            }
+
+            #[inline]
+            fn pattern(&self) -> Option<&str> {
+                self.pattern.as_deref()
+            }
+
+            #[inline]
+            fn example(&self) -> Option<&serde_json::Value> {
+                self.example.as_ref()
+            }
+
+            #[inline]
+            fn external_docs(&self) -> Option<&paperclip::v2::models::ExternalDocs> {
+                self.external_docs.as_ref()
+            }
+
+            #[inline]
+            fn default_value(&self) -> Option<&serde_json::Value> {
+                self.default.as_ref()
+            }
+
             #[inline]
             fn items(&self) -> Option<&paperclip::v2::models::Resolvable<Self>> {
                 self.items.as_ref()
@@ -165,6 +241,90 @@ pub fn emit_v2_schema_struct(input: TokenStream) -> TokenStream {
                 }
             }
 
+            #[inline]
+            fn pattern_properties(&self) -> Option<&std::collections::BTreeMap<String, paperclip::v2::models::Resolvable<Self>>> {
+                if self.pattern_props.is_empty() {
+                    None
+                } else {
+                    Some(&self.pattern_props)
+                }
+            }
+
+            #[inline]
+            fn pattern_properties_mut(&mut self) -> Option<&mut std::collections::BTreeMap<String, paperclip::v2::models::Resolvable<Self>>> {
+                if self.pattern_props.is_empty() {
+                    None
+                } else {
+                    Some(&mut self.pattern_props)
+                }
+            }
+
+            #[inline]
+            fn one_of(&self) -> Option<&[paperclip::v2::models::Resolvable<Self>]> {
+                if self.one_of.is_empty() {
+                    None
+                } else {
+                    Some(&self.one_of)
+                }
+            }
+
+            #[inline]
+            fn one_of_mut(&mut self) -> Option<&mut [paperclip::v2::models::Resolvable<Self>]> {
+                if self.one_of.is_empty() {
+                    None
+                } else {
+                    Some(&mut self.one_of)
+                }
+            }
+
+            #[inline]
+            fn all_of(&self) -> Option<&[paperclip::v2::models::Resolvable<Self>]> {
+                if self.all_of.is_empty() {
+                    None
+                } else {
+                    Some(&self.all_of)
+                }
+            }
+
+            #[inline]
+            fn all_of_mut(&mut self) -> Option<&mut [paperclip::v2::models::Resolvable<Self>]> {
+                if self.all_of.is_empty() {
+                    None
+                } else {
+                    Some(&mut self.all_of)
+                }
+            }
+
+            #[inline]
+            fn set_property(&mut self, name: &str, schema: paperclip::v2::models::Resolvable<Self>) {
+                self.data_type.get_or_insert(paperclip::v2::models::DataType::Object);
+                self.properties.insert(name.into(), schema);
+            }
+
+            #[inline]
+            fn set_required(&mut self, name: &str) {
+                self.required.insert(name.into());
+            }
+
+            #[inline]
+            fn discriminator(&self) -> Option<&str> {
+                self.discriminator.as_ref().map(String::as_str)
+            }
+
+            #[inline]
+            fn discriminator_content(&self) -> Option<&str> {
+                self.discriminator_content.as_ref().map(String::as_str)
+            }
+
+            #[inline]
+            fn discriminator_mapping(&self) -> Option<&std::collections::BTreeMap<String, String>> {
+                if self.discriminator_mapping.is_empty() {
+                    None
+                } else {
+                    Some(&self.discriminator_mapping)
+                }
+            }
+
             #[inline]
             fn required_properties(&self) -> Option<&std::collections::BTreeSet<String>> {
                 if self.required.is_empty() {
@@ -286,16 +446,52 @@ fn schema_fields(name: &Ident, is_ref: bool) -> proc_macro2::TokenStream {
     ));
     gen.extend(quote!(
         #[serde(skip_serializing_if = "Option::is_none")]
-        pub maximum: Option<f32>,
+        pub maximum: Option<serde_json::Number>,
+    ));
+    gen.extend(quote!(
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub minimum: Option<serde_json::Number>,
+    ));
+    gen.extend(quote!(
+        #[serde(rename = "exclusiveMinimum", skip_serializing_if = "Option::is_none")]
+        pub exclusive_minimum: Option<bool>,
+    ));
+    gen.extend(quote!(
+        #[serde(rename = "exclusiveMaximum", skip_serializing_if = "Option::is_none")]
+        pub exclusive_maximum: Option<bool>,
+    ));
+    gen.extend(quote!(
+        #[serde(rename = "maxLength", skip_serializing_if = "Option::is_none")]
+        pub max_length: Option<u32>,
     ));
     gen.extend(quote!(
         #[serde(skip_serializing_if = "Option::is_none")]
-        pub minimum: Option<f32>,
+        pub pattern: Option<String>,
     ));
     gen.extend(quote!(
         #[serde(skip_serializing_if = "Option::is_none")]
         pub example: Option<serde_json::Value>,
     ));
+    gen.extend(quote!(
+        #[serde(rename = "externalDocs", skip_serializing_if = "Option::is_none")]
+        pub external_docs: Option<paperclip::v2::models::ExternalDocs>,
+    ));
+    gen.extend(quote!(
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub default: Option<serde_json::Value>,
+    ));
+    gen.extend(quote!(
+        #[serde(rename = "readOnly", skip_serializing_if = "Option::is_none")]
+        pub read_only: Option<bool>,
+    ));
+    gen.extend(quote!(
+        #[serde(rename = "writeOnly", skip_serializing_if = "Option::is_none")]
+        pub write_only: Option<bool>,
+    ));
+    gen.extend(quote!(
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub deprecated: Option<bool>,
+    ));
 
     gen.extend(quote!(
         #[serde(default, skip_serializing_if = "std::collections::BTreeMap::is_empty")]
@@ -323,11 +519,49 @@ fn schema_fields(name: &Ident, is_ref: bool) -> proc_macro2::TokenStream {
     add_self(&mut gen);
     gen.extend(quote!(>>,));
 
+    gen.extend(quote!(
+        #[serde(default, rename = "patternProperties", skip_serializing_if = "std::collections::BTreeMap::is_empty")]
+        pub pattern_props: std::collections::BTreeMap<String,
+    ));
+    add_self(&mut gen);
+    gen.extend(quote!(>,));
+
     gen.extend(quote!(
         #[serde(default, skip_serializing_if = "std::collections::BTreeSet::is_empty")]
         pub required: std::collections::BTreeSet<String>,
     ));
 
+    gen.extend(quote!(
+        #[serde(default, rename = "oneOf", skip_serializing_if = "Vec::is_empty")]
+        pub one_of: Vec<
+    ));
+    add_self(&mut gen);
+    gen.extend(quote!(>,));
+
+    gen.extend(quote!(
+        #[serde(default, rename = "allOf", skip_serializing_if = "Vec::is_empty")]
+        pub all_of: Vec<
+    ));
+    add_self(&mut gen);
+    gen.extend(quote!(>,));
+
+    gen.extend(quote!(
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub discriminator: Option<String>,
+    ));
+    gen.extend(quote!(
+        #[serde(rename = "x-discriminator-content", skip_serializing_if = "Option::is_none")]
+        pub discriminator_content: Option<String>,
+    ));
+    gen.extend(quote!(
+        #[serde(default, rename = "x-discriminator-mapping", skip_serializing_if = "std::collections::BTreeMap::is_empty")]
+        pub discriminator_mapping: std::collections::BTreeMap<String, String>,
+    ));
+    gen.extend(quote!(
+        #[serde(rename = "x-nullable", skip_serializing_if = "Option::is_none")]
+        pub nullable: Option<bool>,
+    ));
+
     if is_ref {
         gen.extend(quote!(
             #[serde(skip)]
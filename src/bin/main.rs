@@ -2,7 +2,10 @@ use anyhow::Error;
 use paperclip::{
     v2::{
         self,
-        codegen::{CrateMeta, DefaultEmitter, EmitMode, Emitter, EmitterState},
+        codegen::{
+            AbsentNullable, CaseStrategy, CrateMeta, DefaultEmitter, EmitMode, Emitter,
+            EmitterState, MapType, NonExhaustive, UnusedDefinitions,
+        },
         models::{DefaultSchema, ResolvableApi},
     },
     PaperClipError,
@@ -22,11 +25,69 @@ fn parse_version(s: &str) -> Result<OApiVersion, Error> {
     }
 }
 
+fn parse_unused_definitions(s: &str) -> Result<UnusedDefinitions, Error> {
+    match s {
+        "keep" => Ok(UnusedDefinitions::Keep),
+        "warn" => Ok(UnusedDefinitions::Warn),
+        "omit" => Ok(UnusedDefinitions::Omit),
+        _ => Err(Error::msg(format!(
+            "expected one of \"keep\", \"warn\" or \"omit\" (found {:?})",
+            s
+        ))),
+    }
+}
+
 fn parse_spec(s: &str) -> Result<ResolvableApi<DefaultSchema>, Error> {
     let fd = File::open(s)?;
     Ok(v2::from_reader(fd)?)
 }
 
+fn parse_absent_nullable(s: &str) -> Result<AbsentNullable, Error> {
+    match s {
+        "strict" => Ok(AbsentNullable::Strict),
+        "tolerant" => Ok(AbsentNullable::Tolerant),
+        _ => Err(Error::msg(format!(
+            "expected either \"strict\" or \"tolerant\" (found {:?})",
+            s
+        ))),
+    }
+}
+
+fn parse_case_strategy(s: &str) -> Result<CaseStrategy, Error> {
+    match s {
+        "standard" => Ok(CaseStrategy::Standard),
+        "preserve-acronyms" => Ok(CaseStrategy::PreserveAcronyms),
+        _ => Err(Error::msg(format!(
+            "expected either \"standard\" or \"preserve-acronyms\" (found {:?})",
+            s
+        ))),
+    }
+}
+
+fn parse_map_type(s: &str) -> Result<MapType, Error> {
+    match s {
+        "btree" => Ok(MapType::BTreeMap),
+        "hash" => Ok(MapType::HashMap),
+        "index" => Ok(MapType::IndexMap),
+        _ => Err(Error::msg(format!(
+            "expected one of \"btree\", \"hash\" or \"index\" (found {:?})",
+            s
+        ))),
+    }
+}
+
+fn parse_non_exhaustive(s: &str) -> Result<NonExhaustive, Error> {
+    match s {
+        "none" => Ok(NonExhaustive::None),
+        "enums" => Ok(NonExhaustive::Enums),
+        "all" => Ok(NonExhaustive::All),
+        _ => Err(Error::msg(format!(
+            "expected one of \"none\", \"enums\" or \"all\" (found {:?})",
+            s
+        ))),
+    }
+}
+
 #[derive(Debug)]
 enum OApiVersion {
     V2,
@@ -57,6 +118,93 @@ struct Opt {
     /// Version (defaults to 0.1.0)
     #[structopt(long = "version")]
     pub version: Option<String>,
+    /// Name the generated types after schema titles (whenever they're available).
+    #[structopt(long = "prefer-titles")]
+    prefer_titles: bool,
+    /// Group operations (by their tags) into object-safe traits.
+    #[structopt(long = "op-traits")]
+    op_traits: bool,
+    /// Inline all the generated modules into a single self-contained file.
+    #[structopt(long = "standalone")]
+    standalone: bool,
+    /// Group the definitions into a module (file) for each tag.
+    #[structopt(long = "tag-modules")]
+    tag_modules: bool,
+    /// What to do with definitions not used by any operation (keep, warn or omit).
+    #[structopt(long = "unused-definitions", default_value = "keep", parse(try_from_str = parse_unused_definitions))]
+    unused_definitions: UnusedDefinitions,
+    /// Use `NonZero*` types for optional integer fields which must be at least 1.
+    #[structopt(long = "non-zero-integers")]
+    non_zero_integers: bool,
+    /// Generate `Client::from_env`, which reads credentials from environment
+    /// variables with this prefix.
+    #[structopt(long = "env-prefix")]
+    env_prefix: Option<String>,
+    /// Whether absent fields which are required but nullable fail deserialization
+    /// (strict) or become `None` (tolerant).
+    #[structopt(long = "absent-nullable", default_value = "strict", parse(try_from_str = parse_absent_nullable))]
+    absent_nullable: AbsentNullable,
+    /// Use `arrayvec::ArrayString` for string fields whose `maxLength` is at most this value.
+    #[structopt(long = "array-strings-up-to")]
+    array_strings_up_to: Option<u32>,
+    /// Generate `example()` constructors for objects with an `example` in their schema.
+    #[structopt(long = "object-examples")]
+    object_examples: bool,
+    /// Use `rust_decimal::Decimal` (encoded as strings) for numbers with `format: decimal`.
+    #[structopt(long = "decimal-strings")]
+    decimal_strings: bool,
+    /// Generate `merge` methods for combining partial objects.
+    #[structopt(long = "object-merge")]
+    object_merge: bool,
+    /// Send query parameters through generated structs (instead of pairs).
+    #[structopt(long = "query-structs")]
+    query_structs: bool,
+    /// Keep objects with unknown tags in an `Unknown` variant of tagged unions.
+    #[structopt(long = "union-fallback")]
+    union_fallback: bool,
+    /// Keep undeclared values of string enums in an `Unknown` variant.
+    #[structopt(long = "enum-fallback")]
+    enum_fallback: bool,
+    /// Use `util::Base64` (decoded on demand) for strings with `format: byte`.
+    #[structopt(long = "base64-bytes")]
+    base64_bytes: bool,
+    /// Use `Vec<u8>` (decoded on deserialization) for strings with `format: byte`.
+    #[structopt(long = "decoded-bytes")]
+    decoded_bytes: bool,
+    /// Whether type names keep the acronyms in the spec (preserve-acronyms)
+    /// or capitalize every word (standard).
+    #[structopt(long = "case-strategy", default_value = "standard", parse(try_from_str = parse_case_strategy))]
+    case_strategy: CaseStrategy,
+    /// Implement the client traits for `reqwest::blocking` as well (for `send_blocking`).
+    #[structopt(long = "blocking-client")]
+    blocking_client: bool,
+    /// Generate `client::MockClient` for testing without a server.
+    #[structopt(long = "mock-client")]
+    mock_client: bool,
+    /// Set required fields in builders without `unsafe` (by rebuilding the builders).
+    #[structopt(long = "safe-builders")]
+    safe_builders: bool,
+    /// Collection used for map fields: btree (`BTreeMap`), hash (`HashMap`)
+    /// or index (`indexmap::IndexMap`, which keeps the order of keys).
+    #[structopt(long = "map-type", default_value = "btree", parse(try_from_str = parse_map_type))]
+    map_type: MapType,
+    /// Keep the undeclared properties of free-form objects (as JSON values).
+    #[structopt(long = "keep-unknown-fields")]
+    keep_unknown_fields: bool,
+    /// Use `chrono` types for strings with `format: date-time` and `format: date`.
+    #[structopt(long = "chrono-dates")]
+    chrono_dates: bool,
+    /// Use `uuid::Uuid` for strings with `format: uuid`.
+    #[structopt(long = "uuids")]
+    uuids: bool,
+    /// Mark the generated types as `#[non_exhaustive]`: none, enums
+    /// (including unions) or all (enums and structs).
+    #[structopt(long = "non-exhaustive", default_value = "none", parse(try_from_str = parse_non_exhaustive))]
+    non_exhaustive: NonExhaustive,
+    /// Cache the generated output in this directory and reuse it when neither
+    /// the spec nor the options have changed.
+    #[structopt(long = "cache-dir", parse(from_os_str))]
+    cache_dir: Option<PathBuf>,
 }
 
 fn parse_args_and_run() -> Result<(), Error> {
@@ -65,7 +213,6 @@ fn parse_args_and_run() -> Result<(), Error> {
         return Err(PaperClipError::UnsupportedOpenAPIVersion.into());
     }
 
-    let spec = opt.spec.resolve()?;
     let mut state = EmitterState::default();
 
     if let Some(o) = opt.output {
@@ -73,6 +220,34 @@ fn parse_args_and_run() -> Result<(), Error> {
         state.working_dir = o;
     }
 
+    state.prefer_titles = opt.prefer_titles;
+    state.op_traits = opt.op_traits;
+    state.standalone = opt.standalone;
+    state.tag_modules = opt.tag_modules;
+    state.unused_definitions = opt.unused_definitions;
+    state.non_zero_integers = opt.non_zero_integers;
+    state.env_prefix = opt.env_prefix;
+    state.absent_nullable = opt.absent_nullable;
+    state.array_strings_up_to = opt.array_strings_up_to;
+    state.object_examples = opt.object_examples;
+    state.decimal_strings = opt.decimal_strings;
+    state.object_merge = opt.object_merge;
+    state.query_structs = opt.query_structs;
+    state.union_fallback = opt.union_fallback;
+    state.enum_fallback = opt.enum_fallback;
+    state.base64_bytes = opt.base64_bytes;
+    state.decoded_bytes = opt.decoded_bytes;
+    state.case_strategy = opt.case_strategy;
+    state.blocking_client = opt.blocking_client;
+    state.mock_client = opt.mock_client;
+    state.safe_builders = opt.safe_builders;
+    state.map_type = opt.map_type;
+    state.keep_unknown_fields = opt.keep_unknown_fields;
+    state.chrono_dates = opt.chrono_dates;
+    state.uuids = opt.uuids;
+    state.non_exhaustive = opt.non_exhaustive;
+    state.cache_dir = opt.cache_dir;
+
     let mut meta = CrateMeta::default();
     if opt.cli {
         meta.mode = EmitMode::App;
@@ -90,7 +265,7 @@ fn parse_args_and_run() -> Result<(), Error> {
 
     state.set_meta(meta);
     let emitter = DefaultEmitter::from(state);
-    emitter.generate(&spec)
+    emitter.generate_cached(opt.spec)
 }
 
 fn main() {
@@ -47,6 +47,18 @@ pub enum PaperClipError {
     /// The name for the given definition is missing.
     #[error("Missing name for definition")]
     MissingDefinitionName,
+    /// The example for the given definition doesn't match its schema.
+    #[error("Invalid example for {:?}: {}", _0, _1)]
+    InvalidExample(String, String),
+    /// The visibility (`x-visibility`) for an operation isn't supported.
+    #[error("Invalid visibility {:?} for operation in path {:?}", _1, _0)]
+    InvalidVisibility(String, String),
+    /// A pattern in `patternProperties` of the given definition isn't a valid regex.
+    #[error("Invalid key pattern in definition {:?}: {}", _0, _1)]
+    InvalidKeyPattern(String, String),
+    /// The `pattern` for a field in the given definition isn't a valid regex.
+    #[error("Invalid pattern for field {:?} in definition {:?}: {}", _1, _0, _2)]
+    InvalidPattern(String, String, String),
     /// A valid path cannot be obtained for the given definition.
     #[error("Invalid path for definition: {:?}", _0)]
     InvalidDefinitionPath(PathBuf),
@@ -1,16 +1,17 @@
 use super::{
     object::{
-        ApiObject, ObjectContainer, ObjectField, ObjectVariant, OpRequirement, Parameter, Response,
+        self, ApiObject, NumericBounds, ObjectContainer, ObjectField, ObjectVariant, OpRequirement,
+        Parameter, Response, UnionVariant,
     },
-    state::{ChildModule, EmitterState},
-    CrateMeta,
+    state::{CallbackItem, ChildModule, EmitterState},
+    CrateMeta, RUST_KEYWORDS,
 };
 use crate::{
     error::PaperClipError,
     v2::{
         models::{
             Coder, CollectionFormat, DataType, DataTypeFormat, Either, HttpMethod, Items,
-            MediaRange, ParameterIn, Reference, ResolvableApi, ResolvableOperation,
+            MediaRange, ParameterIn, ParameterStyle, Reference, ResolvableApi, ResolvableOperation,
             ResolvableParameter, ResolvablePathItem, ResolvableResponse, JSON_CODER, JSON_MIME,
             YAML_CODER, YAML_MIME,
         },
@@ -18,25 +19,46 @@ use crate::{
     },
 };
 use anyhow::Error;
-use heck::{ToPascalCase, ToSnakeCase};
+use heck::ToSnakeCase;
 use http::{header::HeaderName, HeaderMap};
 use itertools::Itertools;
 use std::{
     collections::{BTreeMap, BTreeSet, HashSet},
-    fmt::Debug,
+    fmt::{self, Debug, Write},
     fs,
     ops::Deref,
     path::{Path, PathBuf},
     sync::{Arc, RwLock},
 };
-use url_dep::Host;
+use url_dep::{Host, Url};
 
 /// Identifier used for `Any` generic parameters in struct definitions.
 pub(super) const ANY_GENERIC_PARAMETER: &str = "Any";
 /// Identifier used for file types in schema. This will be replaced with `ResponseStream`.
 pub(super) const FILE_MARKER: &str = "--FILE--";
+/// Type (in the helper module) of the `Prefer` header parameter, which is used
+/// for choosing the representation of the response.
+pub(super) const PREFER_RETURN_TY: &str = "util::PreferReturn";
+/// Type (in the helper module) of the `Range` header parameter, which is added
+/// to operations downloading files.
+pub(super) const BYTE_RANGE_TY: &str = "util::ByteRange";
+/// Type (in the helper module) of `formData` file parameters, which can either
+/// be a path (streamed from disk) or bytes.
+pub(super) const FILE_PART_TY: &str = "util::FilePart";
 /// Field that collects all properties when "additionalProperties" is set to "true"
 pub(super) const EXTRA_PROPS_FIELD: &str = "other_fields";
+/// Field that collects the properties matching the patterns in "patternProperties"
+const PATTERN_PROPS_FIELD: &str = "pattern_fields";
+/// Type for numbers with `format: decimal` (if they're encoded as strings).
+const DECIMAL_TYPE: &str = "rust_decimal::Decimal";
+/// Type (in the helper module) for strings with `format: byte` (if they're decoded on demand).
+const BASE64_TY: &str = "util::Base64";
+/// Type for strings with `format: byte` (if they're decoded when they're deserialized).
+pub(super) const BYTES_TYPE: &str = "Vec<u8>";
+/// Type for strings with `format: date` (if they're mapped to `chrono` types).
+pub(super) const DATE_TYPE: &str = "chrono::NaiveDate";
+/// Type for strings with `format: uuid` (if they're mapped to `uuid::Uuid`).
+pub(super) const UUID_TYPE: &str = "uuid::Uuid";
 
 /// Some "thing" emitted by the emitter.
 #[derive(Debug)]
@@ -136,11 +158,18 @@ pub trait Emitter: Sized {
     /// Returns the [CamelCase](https://docs.rs/heck/*/heck/trait.CamelCase.html)
     /// name for the given definition.
     fn def_name(&self, def: &Self::Definition) -> Result<String, Error> {
-        Ok(self
-            .def_ns_name(def)?
-            .last()
-            .map(|s| s.to_pascal_case())
-            .expect("last item always exists for split?"))
+        // Use the actual name (instead of the snake_cased one), so that
+        // the case strategy can find the acronyms in it.
+        let state = self.state();
+        def.name()
+            .map(|n| {
+                let name = n.split(state.ns_sep).last();
+                object::to_pascal_case(name.expect("last item always exists for split?"))
+            })
+            .ok_or_else(|| {
+                trace!("Missing name for definition: '{:?}'", def);
+                PaperClipError::MissingDefinitionName.into()
+            })
     }
 
     /// Returns the [CamelCase](https://docs.rs/heck/*/heck/trait.CamelCase.html)
@@ -157,7 +186,7 @@ pub trait Emitter: Sized {
             trace!("Unable to get name for anonymous schema: {:?}", def);
             None
         } else {
-            Some(name.to_pascal_case())
+            Some(object::to_pascal_case(&name))
         }
     }
 
@@ -167,20 +196,8 @@ pub trait Emitter: Sized {
         def: &Self::Definition,
         value: &serde_json::Value,
     ) -> Option<ObjectVariant> {
-        use serde_json::Value;
         let _ = def;
-
-        let name = match value {
-            Value::Number(ref n) => format!("Number_{}", n.to_string().replace(['-', '.'], "_")),
-            Value::Bool(b) => b.to_string().to_pascal_case(),
-            Value::String(ref s) => s.to_string().to_pascal_case().replace('.', "_"),
-            _ => return None,
-        };
-
-        Some(ObjectVariant {
-            name,
-            value: value.clone(),
-        })
+        default_enum_variant(value)
     }
 
     /// Returns the module path (from working directory) for the given definition.
@@ -189,6 +206,13 @@ pub trait Emitter: Sized {
     fn def_mod_path(&self, def: &Self::Definition) -> Result<PathBuf, Error> {
         let state = self.state();
         let mut path = state.working_dir.clone();
+        if let Some(tag) = def
+            .name()
+            .and_then(|n| state.def_tags.borrow().get(n).cloned())
+        {
+            path.push(tag);
+        }
+
         path.extend(self.def_ns_name(def)?);
         path.set_extension("rs");
         Ok(path)
@@ -243,6 +267,7 @@ pub trait Emitter: Sized {
     #[allow(clippy::field_reassign_with_default)]
     fn generate(&self, api: &ResolvableApi<Self::Definition>) -> Result<(), Error> {
         let state = self.state();
+        let _case = object::set_case_strategy(state.case_strategy);
         state.reset_internal_fields();
 
         let m = state.get_meta();
@@ -269,26 +294,13 @@ pub trait Emitter: Sized {
         }
 
         state.set_media_info(api.spec_format, &coders);
+        state.set_security_info(&api.security_definitions, &api.security);
 
         // Set host and base path.
-        if let Some(h) = api.host.as_ref() {
-            let mut parts = h.split(':');
-            let mut u = state.base_url.borrow_mut();
-            if let Some(host) = parts.next() {
-                Host::parse(host).map_err(|e| PaperClipError::InvalidHost(h.into(), e))?;
-                u.set_host(Some(host)).expect("expected valid host in URL?");
-            }
+        set_host_and_base_path(&mut state.base_url.borrow_mut(), api)?;
 
-            if let Some(port) = parts.next() {
-                let p = port.parse::<u16>().map_err(|_| {
-                    PaperClipError::InvalidHost(h.into(), url_dep::ParseError::InvalidPort)
-                })?;
-                u.set_port(Some(p)).expect("expected valid port in URL?");
-            }
-        }
-
-        if let Some(p) = api.base_path.as_ref() {
-            state.base_url.borrow_mut().set_path(p);
+        if state.tag_modules {
+            *state.def_tags.borrow_mut() = tag_modules(api);
         }
 
         let gen = CodegenEmitter(self);
@@ -310,15 +322,60 @@ pub trait Emitter: Sized {
             .collect()?;
         }
 
+        state.check_unused_definitions()?;
         state.declare_modules()?;
         state.write_definitions()?;
         state.add_builders()?;
         state.add_client_deps()?;
+        state.add_op_traits()?;
+        state.add_callbacks()?;
+        state.add_prelude()?;
         state.add_deps()?;
+        state.bundle_modules()?;
 
         Ok(())
     }
 
+    /// Same as `generate`, but this takes the raw (unresolved) spec and skips
+    /// codegen when the output for the same spec and options has already been
+    /// cached in `EmitterState::cache_dir`. Without a cache directory, this just
+    /// resolves the spec and calls `generate`.
+    ///
+    /// Restoring only (over)writes the cached files. Like `generate`, it doesn't
+    /// remove the files left in the working directory by an earlier run (say,
+    /// for definitions which have since been removed from the spec).
+    ///
+    /// **NOTE:** Not meant to be overridden.
+    fn generate_cached(&self, api: ResolvableApi<Self::Definition>) -> Result<(), Error>
+    where
+        Self::Definition: Default,
+    {
+        let state = self.state();
+        if state.cache_dir.is_none() {
+            return self.generate(&api.resolve()?);
+        }
+
+        // The crate name (if it's not set) is inferred from the working directory.
+        fs::create_dir_all(&state.working_dir)?;
+        // The base URL is hashed as it'd be once it's overridden by the spec.
+        let mut base_url = state.base_url.borrow().clone();
+        set_host_and_base_path(&mut base_url, &api)?;
+
+        let mut hasher = Fnv1a::default();
+        writeln!(hasher, "{}", env!("CARGO_PKG_VERSION"))?;
+        state.write_cache_options(&mut hasher)?;
+        write!(hasher, "{}\n{:?}", base_url, api)?;
+        let key = format!("{:016x}", hasher.0);
+
+        state.reset_internal_fields();
+        if state.restore_cached_output(&key)? {
+            return Ok(());
+        }
+
+        self.generate(&api.resolve()?)?;
+        state.cache_output(&key)
+    }
+
     /// Builds a schema. This resolves type aliases to known types
     /// and defines/reuses types based on the given context.
     ///
@@ -328,6 +385,10 @@ pub trait Emitter: Sized {
         def: &Self::Definition,
         ctx: DefinitionContext<'_>,
     ) -> Result<EmittedUnit, Error> {
+        if let Some(u) = CodegenEmitter(self).try_emit_union(def, ctx.clone())? {
+            return Ok(u);
+        }
+
         if let Some(u) = CodegenEmitter(self).try_emit_enum(def, ctx.clone())? {
             return Ok(u);
         }
@@ -338,6 +399,7 @@ pub trait Emitter: Sized {
                 return Ok(EmittedUnit::None);
             }
 
+            let ty = format_type(self.state(), def.format()).unwrap_or(ty);
             return Ok(EmittedUnit::Known(ty.to_owned()));
         }
 
@@ -505,29 +567,133 @@ where
                 .map(Some);
         }
 
-        let name = self.def_name(def).or_else(|e| {
-            // anonymous object
-            self.def_anon_name(def, &ctx.parents).ok_or(e)
-        })?;
+        let name = self.type_name(def, &ctx.parents)?;
 
         let mut obj = ApiObject::with_name(name);
         obj.description = def.description().map(String::from);
+        obj.deprecated = def.deprecated();
+        obj.external_docs = def.external_docs().cloned();
+        obj.non_exhaustive = self.state().non_exhaustive.enums();
+        let is_string = def.data_type() == Some(DataType::String);
         obj.inner = ObjectContainer::Enum {
             variants: vec![],
-            is_string: def.data_type() == Some(DataType::String),
+            is_string,
+            fallback: is_string && self.state().enum_fallback,
         };
 
-        for val in values {
-            if let Some(var) = self.0.enum_variant(def, val) {
-                obj.variants_mut().push(var);
+        *obj.variants_mut() = self.enum_variants(def, values);
+        if obj.variants().is_empty() {
+            return Ok(None);
+        }
+
+        if obj.inner.has_enum_fallback() && obj.variants().iter().any(|v| v.name == "Unknown") {
+            warn!(
+                "Skipping the fallback variant of {:?}, because it has an \"unknown\" value.",
+                obj.name
+            );
+            if let ObjectContainer::Enum { fallback, .. } = &mut obj.inner {
+                *fallback = false;
             }
         }
 
-        if obj.variants().is_empty() {
+        Ok(Some(EmittedUnit::Objects(vec![obj])))
+    }
+
+    /// Checks if the given definition is a union (i.e., it has `oneOf`) and returns
+    /// the corresponding enum (if it is). Unions without a `discriminator` are untagged.
+    fn try_emit_union(
+        &self,
+        def: &E::Definition,
+        ctx: DefinitionContext<'_>,
+    ) -> Result<Option<EmittedUnit>, Error> {
+        let schemas = match def.one_of() {
+            Some(s) if !s.is_empty() => s,
+            _ => return Ok(None),
+        };
+        let tag = def.discriminator();
+
+        if !ctx.define {
+            return self.emit_known_object_path(def, ctx).map(Some);
+        }
+
+        let name = self.type_name(def, &ctx.parents)?;
+        let mut obj = ApiObject::with_name(&name);
+        obj.description = def.description().map(String::from);
+        obj.deprecated = def.deprecated();
+        obj.external_docs = def.external_docs().cloned();
+        obj.non_exhaustive = self.state().non_exhaustive.enums();
+
+        let mut ctx = ctx.clone();
+        if ctx.parents.is_empty() && def.name().is_some() {
+            ctx = ctx.add_parent(&name);
+        }
+
+        // Anonymous objects that we've collected along the way.
+        let mut objects = vec![];
+        let mut variants = vec![];
+        for (idx, schema) in schemas.iter().enumerate() {
+            let schema = schema.read().unwrap();
+            // The tag for some variant is the name of its definition (as in
+            // polymorphism with `allOf`), so we need a name or a title. Variants
+            // of untagged unions are named after their position otherwise.
+            let tag = match schema.name().or_else(|| schema.title()) {
+                Some(t) => t.to_owned(),
+                None if tag.is_none() => format!("Variant{}", idx + 1),
+                None => {
+                    warn!(
+                        "Skipping variant without name or title in {:?} (tagged union).",
+                        name
+                    );
+                    continue;
+                }
+            };
+
+            // Referenced definitions can have other values for their tag.
+            let mut mapped = def
+                .discriminator_mapping()
+                .into_iter()
+                .flatten()
+                .filter(|(_, r)| {
+                    schema
+                        .name()
+                        .is_some_and(|n| r.as_str() == n || r.rsplit('/').next() == Some(n))
+                })
+                .map(|(v, _)| v.clone());
+            let ty = self.build_def(&schema, ctx.clone().define(false).add_parent(&tag))?;
+            variants.push(UnionVariant {
+                name: object::to_pascal_case(&tag),
+                ty_path: ty.known_type(),
+                needs_any: schema.contains_any(),
+                tag: mapped.next().unwrap_or(tag),
+                aliases: mapped.collect(),
+            });
+
+            // Fields of (anonymous) variant structs follow a common naming
+            // convention more often than not.
+            if let EmittedUnit::KnownButAnonymous(_, mut o) = ty {
+                for obj in &mut o {
+                    if let ObjectContainer::Struct { fields } = &obj.inner {
+                        obj.rename_all = object::detect_rename_rule(fields);
+                    }
+                }
+
+                objects.append(&mut o);
+            }
+        }
+
+        if variants.is_empty() {
             return Ok(None);
         }
 
-        Ok(Some(EmittedUnit::Objects(vec![obj])))
+        obj.inner = ObjectContainer::Union {
+            tag: tag.map(String::from),
+            content: def.discriminator_content().map(String::from),
+            variants,
+            fallback: self.state().union_fallback,
+        };
+
+        objects.insert(0, obj);
+        Ok(Some(EmittedUnit::Objects(objects)))
     }
 
     /// Assumes that the given definition is an object and returns the corresponding
@@ -537,9 +703,8 @@ where
         def: &E::Definition,
         ctx: DefinitionContext<'_>,
     ) -> Result<EmittedUnit, Error> {
-        match self.try_emit_map(def, &ctx)? {
-            EmittedUnit::None => (),
-            x => return Ok(x),
+        if let Some(u) = self.try_emit_map(def, ctx.clone())? {
+            return Ok(u);
         }
 
         if !ctx.define {
@@ -549,27 +714,52 @@ where
         self.emit_struct(def, ctx)
     }
 
-    /// Checks if the given definition is a simple map and returns the corresponding `BTreeMap`.
+    /// Checks if the given definition is a simple map (i.e., an object with typed
+    /// additional properties and nothing else) and returns the corresponding map type.
     fn try_emit_map(
         &self,
         def: &E::Definition,
-        ctx: &DefinitionContext<'_>,
-    ) -> Result<EmittedUnit, Error> {
-        if ctx.define {
-            return Ok(EmittedUnit::None);
+        ctx: DefinitionContext<'_>,
+    ) -> Result<Option<EmittedUnit>, Error> {
+        // Objects with fixed properties get a struct, which flattens the map.
+        if has_properties(def) {
+            return Ok(None);
         }
 
-        match def.additional_properties() {
-            Some(Either::Right(s)) => {
-                let schema = s.read().unwrap();
-                let ty = self
-                    .build_def(&schema, ctx.clone().define(false))?
-                    .known_type();
-                let map = format!("std::collections::BTreeMap<String, {}>", ty);
-                Ok(EmittedUnit::Known(map))
+        let value = match def.additional_properties() {
+            Some(Either::Right(s)) => s,
+            _ => return Ok(None),
+        };
+
+        let mut ctx = ctx.clone();
+        if let Some(n) = def.name() {
+            ctx = ctx.add_parent(n);
+        }
+
+        let schema = value.read().unwrap();
+        if schema.name().is_none() {
+            ctx = ctx.add_parent("value");
+        }
+
+        // Maps are resolved wherever they're used, so only the nested types
+        // (if any) need definitions.
+        if ctx.define {
+            if schema.name().is_none() {
+                if let e @ EmittedUnit::Objects(_) = self.build_def(&schema, ctx)? {
+                    return Ok(Some(e));
+                }
             }
-            _ => Ok(EmittedUnit::None),
+
+            return Ok(Some(EmittedUnit::None));
         }
+
+        let ty = self.build_def(&schema, ctx.define(false))?;
+        let map = format!(
+            "{}<String, {}>",
+            self.state().map_type.path(),
+            ty.known_type()
+        );
+        Ok(Some(ty.map_known(map)))
     }
 
     fn emit_known_object_path(
@@ -578,7 +768,9 @@ where
         ctx: DefinitionContext<'_>,
     ) -> Result<EmittedUnit, Error> {
         // Use absolute paths to save some pain.
-        let mut ty_path = String::from(self.state().mod_prefix.trim_matches(':'));
+        let state = self.state();
+        let mut ty_path = String::from(state.mod_prefix.trim_matches(':'));
+        let tags = state.def_tags.borrow();
 
         // If this is an anonymous object, then address it directly.
         if def.name().is_none() {
@@ -589,23 +781,39 @@ where
 
             // If the object has an anonymous name, then it would definitely
             // be in its own module, which is identified by the initial parent name.
-            if let Some(name) = self.def_anon_name(def, &ctx.parents) {
-                ty_path.push_str("::");
+            if self.def_anon_name(def, &ctx.parents).is_some() {
                 let parent = ctx.parents.first().expect("expected first parent name");
-                ty_path.push_str(&parent.to_snake_case());
+                let parent = parent.to_snake_case();
+                // It's in the same tag module as its parent (if any).
+                let tag = tags.iter().find(|(n, _)| {
+                    n.split(state.ns_sep).last().map(ToSnakeCase::to_snake_case)
+                        == Some(parent.clone())
+                });
+                if let Some((_, tag)) = tag {
+                    ty_path.push_str("::");
+                    ty_path.push_str(tag);
+                }
+
                 ty_path.push_str("::");
-                ty_path.push_str(&name);
+                ty_path.push_str(&parent);
+                ty_path.push_str("::");
+                ty_path.push_str(&self.type_name(def, &ctx.parents)?);
                 return Ok(EmittedUnit::KnownButAnonymous(ty_path, objects));
             }
         }
 
+        if let Some(tag) = def.name().and_then(|n| tags.get(n)) {
+            ty_path.push_str("::");
+            ty_path.push_str(tag);
+        }
+
         let mut iter = self.def_ns_name(def)?.peekable();
         while let Some(mut c) = iter.next() {
             ty_path.push_str("::");
             if iter.peek().is_none() {
                 ty_path.push_str(&c);
                 ty_path.push_str("::");
-                c = c.to_pascal_case();
+                c = self.type_name(def, &ctx.parents)?;
             }
 
             ty_path.push_str(&c);
@@ -614,6 +822,52 @@ where
         Ok(EmittedUnit::Known(ty_path))
     }
 
+    /// Returns the name of the Rust type for the given definition. This is
+    /// usually the name derived from its key (or parents, if it's anonymous),
+    /// but if the state prefers titles, then the title is used unless some
+    /// other type in the same module has already claimed that name.
+    fn type_name(&self, def: &E::Definition, parents: &[&str]) -> Result<String, Error> {
+        let key_name = self
+            .def_name(def)
+            .or_else(|e| self.def_anon_name(def, parents).ok_or(e))?;
+        let state = self.state();
+        if !state.prefer_titles {
+            return Ok(key_name);
+        }
+
+        let module = match def.name() {
+            Some(_) => self.def_ns_name(def)?.join("::"),
+            None => parents
+                .first()
+                .map(|p| p.to_snake_case())
+                .unwrap_or_default(),
+        };
+
+        // Schemas are behind locks, so their addresses are stable throughout the session.
+        let id = def as *const E::Definition as usize;
+        let mut names = state.type_names.borrow_mut();
+        let claimed = names.entry(module).or_default();
+        let is_free = |n: &str| claimed.get(n).map(|&i| i == id).unwrap_or(true);
+
+        if let Some(name) = def.title().map(object::to_pascal_case) {
+            if !name.is_empty() && is_free(&name) {
+                claimed.insert(name.clone(), id);
+                return Ok(name);
+            }
+        }
+
+        // Title-derived names could've claimed the key-derived name already.
+        let mut name = key_name.clone();
+        let mut idx = 1;
+        while !is_free(&name) {
+            idx += 1;
+            name = format!("{}{}", key_name, idx);
+        }
+
+        claimed.insert(name.clone(), id);
+        Ok(name)
+    }
+
     /// Helper for `emit_object` - This returns the Rust struct definition for the
     /// given schema definition.
     fn emit_struct(
@@ -621,12 +875,32 @@ where
         def: &E::Definition,
         ctx: DefinitionContext<'_>,
     ) -> Result<EmittedUnit, Error> {
-        let name = self.def_name(def).or_else(|e| {
-            // anonymous object
-            self.def_anon_name(def, &ctx.parents).ok_or(e)
-        })?;
+        let name = self.type_name(def, &ctx.parents)?;
         let mut obj = ApiObject::with_name(&name);
         obj.description = def.description().map(String::from);
+        obj.deprecated = def.deprecated();
+        obj.external_docs = def.external_docs().cloned();
+        obj.merge = self.state().object_merge;
+        obj.non_exhaustive = self.state().non_exhaustive.structs();
+        // Free-form objects keep the unknown keys, so that they survive round trips.
+        // Objects without any declared properties can have anything. Objects
+        // with `additionalProperties: true` already have a map (of `Any`) for that.
+        let free_form = def.additional_properties().is_none()
+            && def.pattern_properties().is_none()
+            && !has_properties(def);
+        if self.state().keep_unknown_fields && free_form {
+            let map = self.state().map_type.path();
+            obj.catch_all = Some(format!("{}<String, serde_json::Value>", map));
+        }
+
+        obj.example = def.example().cloned();
+        if self.state().object_examples {
+            if let Some(example) = def.example() {
+                check_example(def, example)
+                    .map_err(|e| PaperClipError::InvalidExample(name.clone(), e))?;
+                obj.example_method = true;
+            }
+        }
 
         // If we don't have any parents and there's a name for this object,
         // then it's the root object - add the name to parents before checking
@@ -640,25 +914,119 @@ where
         let mut objects = vec![];
 
         if let Some(props) = def.properties() {
+            let obj_name = name.clone();
             props
                 .iter()
                 .try_for_each(|(name, prop)| -> Result<(), Error> {
                     let schema = prop.read().unwrap();
                     let ctx = ctx.clone().define(false).add_parent(name);
                     let ty = self.build_def(&schema, ctx)?;
-                    let ty_path = ty.known_type();
+                    let mut is_required = def
+                        .required_properties()
+                        .map(|s| s.contains(name))
+                        .unwrap_or(false);
+                    // Required fields which can be `null` are optional in Rust.
+                    let absent_nullable = if is_required && schema.nullable() {
+                        is_required = false;
+                        Some(self.state().absent_nullable)
+                    } else {
+                        None
+                    };
+                    // Decimals (and decoded bytes) are strings on the wire, so they
+                    // need their own (de)serializers.
+                    let optional = if is_required { "" } else { "_option" };
+                    let (serde_with, with_ty) = match schema.format() {
+                        Some(DataTypeFormat::Decimal) if self.state().decimal_strings => (
+                            Some(format!("rust_decimal::serde::str{}", optional)),
+                            DECIMAL_TYPE,
+                        ),
+                        Some(DataTypeFormat::Byte) if self.state().decoded_bytes => (
+                            Some(format!(
+                                "{}::util::base64_vec{}",
+                                self.state().mod_prefix.trim_matches(':'),
+                                optional
+                            )),
+                            BYTES_TYPE,
+                        ),
+                        _ => (None, ""),
+                    };
+                    // Required fields keep their integers, because objects derive `Default`.
+                    let is_base64 = self.state().base64_bytes
+                        && matches!(schema.format(), Some(DataTypeFormat::Byte));
+                    let ty_path = match non_zero_type(&*schema) {
+                        _ if serde_with.is_some() => with_ty.into(),
+                        _ if is_base64 => format!(
+                            "{}::{}",
+                            self.state().mod_prefix.trim_matches(':'),
+                            BASE64_TY
+                        ),
+                        Some(t) if self.state().non_zero_integers && !is_required => t.into(),
+                        _ => self
+                            .state()
+                            .array_strings_up_to
+                            .and_then(|n| array_string_type(&*schema, n))
+                            .filter(|_| ty.known_type() == "String")
+                            .unwrap_or_else(|| ty.known_type()),
+                    };
+                    let (default, default_variant) = self.field_default(&schema, &ty_path);
+                    // A field can't be both, so it's sent and received as usual.
+                    let (read_only, write_only) = match (schema.read_only(), schema.write_only()) {
+                        (true, true) => {
+                            warn!(
+                                "Field {:?} in {:?} is both read-only and write-only, ignoring both.",
+                                name, obj_name
+                            );
+                            (false, false)
+                        }
+                        flags => flags,
+                    };
+                    let bounds = match schema.data_type() {
+                        Some(DataType::Integer) | Some(DataType::Number)
+                            if serde_with.is_none() =>
+                        {
+                            NumericBounds {
+                                minimum: schema.minimum().cloned(),
+                                exclusive_minimum: schema.exclusive_minimum(),
+                                maximum: schema.maximum().cloned(),
+                                exclusive_maximum: schema.exclusive_maximum(),
+                            }
+                        }
+                        _ => NumericBounds::default(),
+                    };
+                    let pattern = match schema.pattern() {
+                        Some(p) if ty_path == "String" => {
+                            regex::Regex::new(p).map_err(|e| {
+                                PaperClipError::InvalidPattern(
+                                    obj_name.clone(),
+                                    name.clone(),
+                                    e.to_string(),
+                                )
+                            })?;
+                            Some(p.to_owned())
+                        }
+                        _ => None,
+                    };
 
                     obj.fields_mut().push(ObjectField {
                         name: name.clone(),
                         description: prop.get_description(),
                         ty_path,
-                        is_required: def
-                            .required_properties()
-                            .map(|s| s.contains(name))
-                            .unwrap_or(false),
+                        is_required,
                         needs_any: schema.contains_any(),
                         boxed: schema.is_cyclic(),
                         child_req_fields: self.children_requirements(&schema),
+                        read_only,
+                        write_only,
+                        deprecated: prop.is_deprecated(),
+                        example: prop.get_example(),
+                        nullable: schema.nullable(),
+                        absent_nullable,
+                        serde_with,
+                        default,
+                        default_variant,
+                        bounds,
+                        pattern,
+                        ..Default::default()
                     });
 
                     if let EmittedUnit::KnownButAnonymous(_, mut o) = ty {
@@ -670,15 +1038,70 @@ where
 
             // If additional properties are enabled, then collect them into
             // a separate field for flattening.
-            if let Some(Either::Left(true)) = def.additional_properties() {
+            let extra = match def.additional_properties() {
+                Some(Either::Left(true)) => Some((ANY_GENERIC_PARAMETER.into(), true, vec![])),
+                Some(Either::Right(s)) => {
+                    let schema = s.read().unwrap();
+                    let ctx = ctx.clone().define(false).add_parent(EXTRA_PROPS_FIELD);
+                    let ty = self.build_def(&schema, ctx)?;
+                    let ty_path = ty.known_type();
+                    if let EmittedUnit::KnownButAnonymous(_, mut o) = ty {
+                        objects.append(&mut o);
+                    }
+
+                    Some((
+                        ty_path,
+                        schema.contains_any(),
+                        self.children_requirements(&schema),
+                    ))
+                }
+                _ => None,
+            };
+
+            if let Some((value_ty, needs_any, child_req_fields)) = extra {
                 obj.fields_mut().push(ObjectField {
                     name: EXTRA_PROPS_FIELD.into(),
-                    ty_path: "std::collections::BTreeMap<String, Any>".into(),
-                    description: None,
-                    is_required: false,
-                    needs_any: true,
-                    boxed: false,
-                    child_req_fields: vec![],
+                    ty_path: format!("{}<String, {}>", self.state().map_type.path(), value_ty),
+                    needs_any,
+                    child_req_fields,
+                    ..Default::default()
+                });
+            }
+        }
+
+        // Pattern properties are collected into a map, unless we're already
+        // collecting everything else in one.
+        match (def.pattern_properties(), def.additional_properties()) {
+            (_, Some(Either::Left(true))) | (_, Some(Either::Right(_))) | (None, _) => (),
+            (Some(props), _) => {
+                for pattern in props.keys() {
+                    regex::Regex::new(pattern).map_err(|e| {
+                        PaperClipError::InvalidKeyPattern(name.clone(), e.to_string())
+                    })?;
+                }
+
+                // Values of multiple patterns can only share a map as `Any`.
+                let (value_ty, needs_any) = match props.values().next() {
+                    Some(prop) if props.len() == 1 => {
+                        let schema = prop.read().unwrap();
+                        let ctx = ctx.clone().define(false).add_parent(PATTERN_PROPS_FIELD);
+                        let ty = self.build_def(&schema, ctx)?;
+                        let ty_path = ty.known_type();
+                        if let EmittedUnit::KnownButAnonymous(_, mut o) = ty {
+                            objects.append(&mut o);
+                        }
+
+                        (ty_path, schema.contains_any())
+                    }
+                    _ => (ANY_GENERIC_PARAMETER.into(), true),
+                };
+
+                obj.fields_mut().push(ObjectField {
+                    name: PATTERN_PROPS_FIELD.into(),
+                    ty_path: format!("{}<String, {}>", self.state().map_type.path(), value_ty),
+                    needs_any,
+                    key_patterns: props.keys().cloned().collect(),
+                    ..Default::default()
                 });
             }
         }
@@ -687,6 +1110,57 @@ where
         Ok(EmittedUnit::Objects(objects))
     }
 
+    /// Returns the default value of a field (if any) along with the enum variant
+    /// it refers to, as long as the value can be written as a Rust expression.
+    fn field_default(
+        &self,
+        schema: &E::Definition,
+        ty_path: &str,
+    ) -> (Option<serde_json::Value>, Option<String>) {
+        let value = match schema.default_value() {
+            Some(v) => v,
+            None => return (None, None),
+        };
+
+        if let Err(e) = check_example(schema, value) {
+            warn!("Ignoring default value for {}: {}", ty_path, e);
+            return (None, None);
+        }
+
+        match ty_path {
+            "String" | "bool" | "i32" | "i64" | "f32" | "f64" => (Some(value.clone()), None),
+            _ => match schema.enum_variants() {
+                Some(values) => match self
+                    .enum_variants(schema, values)
+                    .into_iter()
+                    .find(|v| v.value == *value)
+                {
+                    Some(var) => (Some(value.clone()), Some(var.name)),
+                    None => (None, None),
+                },
+                None => {
+                    info!("Skipping default value for unsupported type {}", ty_path);
+                    (None, None)
+                }
+            },
+        }
+    }
+
+    /// Returns the enum variants for the possible values of the given definition,
+    /// with unique names.
+    fn enum_variants(
+        &self,
+        def: &E::Definition,
+        values: &[serde_json::Value],
+    ) -> Vec<ObjectVariant> {
+        let mut variants = values
+            .iter()
+            .filter_map(|val| self.0.enum_variant(def, val))
+            .collect::<Vec<_>>();
+        dedup_variant_names(&mut variants);
+        variants
+    }
+
     /// Returns the requirements of the "deepest" child type in the given definition.
     ///
     /// See `ObjectField.children_req` field for what it means.
@@ -697,7 +1171,16 @@ where
                 if let Some(Either::Right(s)) = schema.additional_properties() {
                     return self.children_requirements(&s.read().unwrap());
                 } else if let Some(s) = schema.required_properties() {
-                    return s.iter().cloned().collect();
+                    // Read-only fields can't be set in builders.
+                    let props = schema.properties();
+                    return s
+                        .iter()
+                        .filter(|&name| {
+                            !matches!(props.and_then(|p| p.get(name)),
+                                Some(p) if p.read().unwrap().read_only())
+                        })
+                        .cloned()
+                        .collect();
                 }
             }
             Some(DataType::Array) => {
@@ -818,6 +1301,28 @@ where
             !skip
         });
 
+        // File downloads can be resumed (or split into chunks) using byte ranges,
+        // unless the operation has its own idea of a `Range` header.
+        if self.is_download(op)
+            && !params
+                .iter()
+                .any(|p| p.presence == ParameterIn::Header && p.name.eq_ignore_ascii_case("range"))
+        {
+            let prefix = self.emitter.state().mod_prefix.trim_matches(':');
+            params.push(Parameter {
+                name: "Range".into(),
+                description: Some("Range of bytes (inclusive) to be downloaded.".into()),
+                ty_path: format!("{}::{}", prefix, BYTE_RANGE_TY),
+                presence: ParameterIn::Header,
+                required: false,
+                delimiting: vec![],
+                variants: vec![],
+                deep_object: false,
+            });
+        }
+
+        self.collect_callbacks(meth, op)?;
+
         // If there's a matching object, add the params to its operation.
         if let Some(pat) = schema_path.as_ref() {
             self.bind_schema_to_operation(pat, meth, op, params)?;
@@ -828,6 +1333,66 @@ where
         Ok(())
     }
 
+    /// Collects the callbacks (`x-callbacks`) of the given operation, for which
+    /// a trait is generated later (see `EmitterState::add_callbacks`).
+    fn collect_callbacks(
+        &self,
+        meth: HttpMethod,
+        op: &ResolvableOperation<E::Definition>,
+    ) -> Result<(), Error> {
+        if op.callbacks.is_empty() {
+            return Ok(());
+        }
+
+        // Traits are named after the operations.
+        let op_id = match op.operation_id.as_ref() {
+            Some(id) => id,
+            None => {
+                warn!(
+                    "Skipping callbacks of {} operation in path {:?} without operationId.",
+                    meth, self.path
+                );
+                return Ok(());
+            }
+        };
+
+        let def_type = |r: &Reference| -> Result<String, Error> {
+            let name = r.reference.trim_start_matches("#/definitions/");
+            let schema = self.api.definitions.get(name).ok_or_else(|| {
+                PaperClipError::Validation(paperclip_core::ValidationError::MissingReference(
+                    name.into(),
+                ))
+            })?;
+            let schema = &*schema.read().unwrap();
+            let ty = self
+                .emitter
+                .build_def(schema, DefinitionContext::default())?
+                .known_type();
+            // Callbacks are assumed to be JSON.
+            if schema.contains_any() {
+                Ok(format!("{}<{}>", ty, JSON_CODER.any_value))
+            } else {
+                Ok(ty)
+            }
+        };
+
+        let mut items = vec![];
+        for (name, callback) in &op.callbacks {
+            items.push(CallbackItem {
+                name: format!("on_{}", object::to_snake_case(name)),
+                description: callback.description.clone(),
+                method: callback.method.unwrap_or(HttpMethod::Post),
+                url: callback.url.clone(),
+                payload_ty: callback.payload.as_ref().map(def_type).transpose()?,
+                response_ty: callback.response.as_ref().map(def_type).transpose()?,
+            });
+        }
+
+        let state = self.emitter.state();
+        state.callbacks.borrow_mut().insert(op_id.clone(), items);
+        Ok(())
+    }
+
     /// Collects headers as parameters for all responses in some operation.
     fn collect_response_headers(
         &self,
@@ -847,6 +1412,7 @@ where
 
                 // Enforce that the parameter is an allowed type and collect it.
                 let (ty, mut it_fmts) = match resolve_parameter_type(
+                    self.emitter.state(),
                     info.data_type,
                     info.format.as_ref(),
                     info.items.as_ref(),
@@ -875,6 +1441,8 @@ where
                     presence: ParameterIn::Header,
                     required: false,
                     delimiting: it_fmts,
+                    variants: vec![],
+                    deep_object: false,
                 };
 
                 map.insert(name, param);
@@ -897,6 +1465,49 @@ where
             p.check(self.path)?; // validate the parameter
 
             if let Some(def) = p.schema.as_ref() {
+                // Deep objects are sent in query (rather than in body).
+                if p.in_ == ParameterIn::Query && p.style == Some(ParameterStyle::DeepObject) {
+                    let ty_path = self
+                        .emitter
+                        .build_def(&def.read().unwrap(), DefinitionContext::default())?
+                        .known_type();
+                    params.push(Parameter {
+                        name: p.name.clone(),
+                        description: p.description.clone(),
+                        ty_path,
+                        presence: p.in_,
+                        required: p.required,
+                        delimiting: vec![],
+                        variants: vec![],
+                        deep_object: true,
+                    });
+
+                    continue;
+                }
+
+                // Strings in body (binary or otherwise) are sent as they are.
+                let (data_type, format) = {
+                    let schema = def.read().unwrap();
+                    (schema.data_type(), schema.format().cloned())
+                };
+                if p.in_ == ParameterIn::Body && data_type == Some(DataType::String) {
+                    params.push(Parameter {
+                        name: p.name.clone(),
+                        description: p.description.clone(),
+                        ty_path: match format {
+                            Some(DataTypeFormat::Binary) => BYTES_TYPE.into(),
+                            _ => "String".into(),
+                        },
+                        presence: p.in_,
+                        required: p.required,
+                        delimiting: vec![],
+                        variants: vec![],
+                        deep_object: false,
+                    });
+
+                    continue;
+                }
+
                 // If a schema exists, then get its path for later use.
                 let pat = self.emitter.def_mod_path(&def.read().unwrap())?;
                 if def_mods.get(&pat).is_some() {
@@ -918,17 +1529,21 @@ where
             }
 
             // Enforce that the parameter is an allowed type and collect it.
-            let (ty, mut it_fmts) =
-                match resolve_parameter_type(p.data_type, p.format.as_ref(), p.items.as_ref()) {
-                    Some(t) => t,
-                    None => {
-                        warn!(
-                            "Skipping parameter {:?} with unknown type {:?} in path {:?}",
-                            p.name, p.data_type, self.path
-                        );
-                        continue;
-                    }
-                };
+            let (ty, mut it_fmts) = match resolve_parameter_type(
+                self.emitter.state(),
+                p.data_type,
+                p.format.as_ref(),
+                p.items.as_ref(),
+            ) {
+                Some(t) => t,
+                None => {
+                    warn!(
+                        "Skipping parameter {:?} with unknown type {:?} in path {:?}",
+                        p.name, p.data_type, self.path
+                    );
+                    continue;
+                }
+            };
 
             validate_collection_format(
                 &p.name,
@@ -938,6 +1553,26 @@ where
                 &mut it_fmts,
             );
 
+            // Headers and path parameters constrained to some values get their own enum.
+            let has_enum = matches!(p.in_, ParameterIn::Header | ParameterIn::Path);
+            let variants: Vec<_> = if has_enum && it_fmts.is_empty() {
+                let mut variants: Vec<_> =
+                    p.enum_.iter().filter_map(default_enum_variant).collect();
+                dedup_variant_names(&mut variants);
+                variants
+            } else {
+                vec![]
+            };
+
+            let ty = if p.in_ == ParameterIn::Header && p.name.eq_ignore_ascii_case("prefer") {
+                let prefix = self.emitter.state().mod_prefix.trim_matches(':');
+                format!("{}::{}", prefix, PREFER_RETURN_TY)
+            } else if !variants.is_empty() {
+                object::to_pascal_case(&p.name)
+            } else {
+                ty
+            };
+
             params.push(Parameter {
                 name: p.name.clone(),
                 description: p.description.clone(),
@@ -946,6 +1581,8 @@ where
                 // NOTE: parameter is required if it's in path
                 required: p.required || p.in_ == ParameterIn::Path,
                 delimiting: it_fmts,
+                variants,
+                deep_object: false,
             });
         }
 
@@ -976,7 +1613,9 @@ where
             .or_default();
 
         let mut response_contains_any = false;
-        let response_ty_path = if let Some(s) = Self::get_2xx_response_schema(op) {
+        let response_ty_path = if self.is_download(op) {
+            Some(FILE_MARKER.into())
+        } else if let Some(s) = Self::get_2xx_response_schema(op) {
             let schema = &*s.read().unwrap();
             response_contains_any = schema.contains_any();
             Some(
@@ -988,6 +1627,10 @@ where
             None
         };
 
+        let prefer_return = params.iter().any(|p| p.ty_path.ends_with(PREFER_RETURN_TY));
+        let decoding = self.get_coder(op.produces.as_ref(), &self.api.produces);
+        let is_file = response_ty_path.as_deref() == Some(FILE_MARKER);
+        let raw_body = self.raw_body_media_type(op, &params);
         ops.req.insert(
             meth,
             OpRequirement {
@@ -995,15 +1638,28 @@ where
                 id: op.operation_id.clone(),
                 description: op.description.clone(),
                 deprecated: op.deprecated,
+                external_docs: op.external_docs.clone(),
+                visibility: operation_visibility(self.path, op)?,
+                timeout: op.timeout,
+                query_struct: self.emitter.state().query_structs,
+                tags: op.tags.clone(),
                 params,
                 response: Response {
                     contains_any: response_contains_any,
                     ty_path: response_ty_path,
                     headers: self.collect_response_headers(&op.responses),
+                    multipart_mixed: self.is_multipart_mixed(op.produces.as_ref()),
+                    prefer_return,
                 },
+                errors: self.collect_error_responses(op)?,
+                success: self.collect_success_responses(op)?,
+                security: self.collect_security(op),
                 body_required: true,
                 encoding: self.get_coder(op.consumes.as_ref(), &self.api.consumes),
-                decoding: self.get_coder(op.produces.as_ref(), &self.api.produces),
+                form_body: self.is_form_body(op.consumes.as_ref(), &self.api.consumes),
+                accept: self.accept_header(op, decoding.as_ref(), is_file),
+                raw_body,
+                decoding,
             },
         );
 
@@ -1055,12 +1711,14 @@ where
                     )?;
                 }
 
-                unknown_schema_context = Some((
-                    path,
+                let ty = if self.is_download(op) {
+                    FILE_MARKER.into()
+                } else {
                     self.emitter
                         .build_def(schema, DefinitionContext::default())?
-                        .known_type(),
-                ));
+                        .known_type()
+                };
+                unknown_schema_context = Some((path, ty));
                 s.clone()
             }
         };
@@ -1079,7 +1737,10 @@ where
                 .ok()
                 .and_then(|p| def_mods.get_mut(&p))
             {
-                Some(o) => (o, self.emitter.def_name(schema).ok()),
+                Some(o) => {
+                    let name = o[0].name.clone();
+                    (o, Some(name))
+                }
                 None => {
                     warn!(
                         "Skipping unknown response schema for path {:?}: {:?}",
@@ -1095,12 +1756,21 @@ where
             .entry(self.path.into())
             .or_default();
 
+        let prefer_return = params.iter().any(|p| p.ty_path.ends_with(PREFER_RETURN_TY));
+        let decoding = self.get_coder(op.produces.as_ref(), &self.api.produces);
+        let is_file = response_ty_path.as_deref() == Some(FILE_MARKER);
+        let raw_body = self.raw_body_media_type(op, &params);
         ops.req.insert(
             meth,
             OpRequirement {
                 id: op.operation_id.clone(),
                 description: op.description.clone(),
                 deprecated: op.deprecated,
+                external_docs: op.external_docs.clone(),
+                visibility: operation_visibility(self.path, op)?,
+                timeout: op.timeout,
+                query_struct: self.emitter.state().query_structs,
+                tags: op.tags.clone(),
                 params,
                 body_required: false,
                 listable,
@@ -1108,15 +1778,123 @@ where
                     ty_path: response_ty_path,
                     contains_any: schema.contains_any(),
                     headers: self.collect_response_headers(&op.responses),
+                    multipart_mixed: self.is_multipart_mixed(op.produces.as_ref()),
+                    prefer_return,
                 },
+                errors: self.collect_error_responses(op)?,
+                success: self.collect_success_responses(op)?,
+                security: self.collect_security(op),
                 encoding: self.get_coder(op.consumes.as_ref(), &self.api.consumes),
-                decoding: self.get_coder(op.produces.as_ref(), &self.api.produces),
+                form_body: self.is_form_body(op.consumes.as_ref(), &self.api.consumes),
+                accept: self.accept_header(op, decoding.as_ref(), is_file),
+                raw_body,
+                decoding,
             },
         );
 
         Ok(())
     }
 
+    /// Collects the security requirements of this operation (falling back to
+    /// the global requirements) as alternatives of scheme names. Schemes which
+    /// haven't been defined in `securityDefinitions` are ignored.
+    fn collect_security(&self, op: &ResolvableOperation<E::Definition>) -> Vec<Vec<String>> {
+        let defined = |name: &&String| {
+            let known = self.api.security_definitions.contains_key(*name);
+            if !known {
+                warn!(
+                    "Ignoring undefined security scheme {:?} in {:?}",
+                    name, self.path
+                );
+            }
+
+            known
+        };
+
+        if op.security.is_empty() {
+            self.api
+                .security
+                .iter()
+                .map(|req| req.keys().filter(defined).cloned().collect())
+                .collect()
+        } else {
+            op.security
+                .iter()
+                .map(|req| req.keys().filter(defined).cloned().collect())
+                .collect()
+        }
+    }
+
+    /// Collects the types of error responses (4xx, 5xx and `default`) in this
+    /// operation, provided that at least one of them has a schema.
+    fn collect_error_responses(
+        &self,
+        op: &ResolvableOperation<E::Definition>,
+    ) -> Result<Vec<(String, Option<String>)>, Error> {
+        let mut errors = self.collect_responses(op, |c| {
+            c.starts_with('4') || c.starts_with('5') || c == "default"
+        })?;
+        if errors.iter().all(|(_, ty)| ty.is_none()) {
+            errors.clear();
+        }
+
+        Ok(errors)
+    }
+
+    /// Collects the types of 2xx responses in this operation, provided that
+    /// they have different schemas (otherwise, the first 2xx schema is used).
+    fn collect_success_responses(
+        &self,
+        op: &ResolvableOperation<E::Definition>,
+    ) -> Result<Vec<(String, Option<String>)>, Error> {
+        let mut success = self.collect_responses(op, |c| c.starts_with('2'))?;
+        let distinct = success
+            .iter()
+            .filter_map(|(_, ty)| ty.as_ref())
+            .collect::<HashSet<_>>();
+        if distinct.len() < 2 {
+            success.clear();
+        }
+
+        Ok(success)
+    }
+
+    /// Collects the status codes (matching the given filter) and the types
+    /// of their responses (if they have schemas) in this operation.
+    fn collect_responses(
+        &self,
+        op: &ResolvableOperation<E::Definition>,
+        filter: impl Fn(&str) -> bool,
+    ) -> Result<Vec<(String, Option<String>)>, Error> {
+        let mut responses = vec![];
+        for (code, resp) in op.responses.iter().filter(|(c, _)| filter(c)) {
+            let schema = resp.read().unwrap().schema.as_ref().map(|s| (**s).clone());
+            let ty = match schema {
+                Some(s) => {
+                    let schema = &*s.read().unwrap();
+                    let mut ty = self
+                        .emitter
+                        .build_def(schema, DefinitionContext::default())?
+                        .known_type();
+                    if schema.contains_any() {
+                        let coder = self
+                            .get_coder(op.produces.as_ref(), &self.api.produces)
+                            .map(|(_, c)| c)
+                            .unwrap_or_else(|| JSON_CODER.clone());
+                        ty = format!("{}<{}>", ty, coder.any_value);
+                    }
+
+                    Some(ty)
+                }
+                None => None,
+            };
+
+            responses.push((code.clone(), ty));
+        }
+
+        Ok(responses)
+    }
+
     /// Returns the first 2xx response schema in this operation.
     ///
     /// **NOTE:** This assumes that 2xx response schemas are the same for an operation.
@@ -1133,6 +1911,63 @@ where
             .next()
     }
 
+    /// Returns whether the operation responds with a file, which is streamed
+    /// (instead of being decoded). Apart from `file` schemas, this includes strings
+    /// with `format: binary` and strings produced only as `application/octet-stream`.
+    fn is_download(&self, op: &ResolvableOperation<E::Definition>) -> bool {
+        let schema = match Self::get_2xx_response_schema(op) {
+            Some(s) => s,
+            None => return false,
+        };
+
+        let schema = schema.read().unwrap();
+        match schema.data_type() {
+            Some(DataType::File) => true,
+            Some(DataType::String) if schema.format() == Some(&DataTypeFormat::Binary) => true,
+            Some(DataType::String) => {
+                let ranges = op.produces.as_ref().unwrap_or(&self.api.produces);
+                !ranges.is_empty()
+                    && ranges
+                        .iter()
+                        .all(|r| r.0.essence_str() == "application/octet-stream")
+            }
+            _ => false,
+        }
+    }
+
+    /// Returns the value of the `Accept` header for the operation. Files are
+    /// accepted in any of the produced types, but other responses only in the
+    /// type we decode (JSON, unless we have a coder for some other type).
+    fn accept_header(
+        &self,
+        op: &ResolvableOperation<E::Definition>,
+        decoding: Option<&(String, Arc<Coder>)>,
+        is_file: bool,
+    ) -> Option<String> {
+        let ranges = op.produces.as_ref().unwrap_or(&self.api.produces);
+        if is_file {
+            return Some(ranges.iter().map(|r| r.0.as_ref()).join(", ")).filter(|s| !s.is_empty());
+        }
+
+        if self.is_multipart_mixed(op.produces.as_ref()) {
+            return Some("multipart/mixed".into());
+        }
+
+        Some(match decoding {
+            Some((range, _)) => range.clone(),
+            None => JSON_MIME.0.as_ref().into(),
+        })
+    }
+
+    /// Returns whether the operation (with the given local media ranges) produces
+    /// a `multipart/mixed` response.
+    fn is_multipart_mixed(&self, local_ref: Option<&BTreeSet<MediaRange>>) -> bool {
+        local_ref
+            .unwrap_or(&self.api.produces)
+            .iter()
+            .any(|r| r.0.essence_str() == "multipart/mixed")
+    }
+
     /// Returns the coder based on the given local and global media range, and `None`
     /// if it's JSON (as we already support it).
     fn get_coder(
@@ -1152,32 +1987,82 @@ where
             .filter_map(|r| self.api.coders.matching_coder(r).map(|c| (r, c)))
             .sorted_by(|(_, a), (_, b)| b.prefer.cmp(&a.prefer)); // sort based on preference.
 
-        let (range, coder) = coders
-            .next()
-            .unwrap_or_else(|| (self.api.spec_format.mime(), self.api.spec_format.coder()));
+        let (range, coder) = match coders.next() {
+            Some(c) => c,
+            // Operations accepting JSON don't need the spec format's encoding.
+            None if ranges.contains(&*JSON_MIME) => return None,
+            None => (self.api.spec_format.mime(), self.api.spec_format.coder()),
+        };
         if range == &*JSON_MIME {
             return None;
         }
 
         Some((range.0.as_ref().into(), coder))
     }
+
+    /// Returns the media type for the body of this operation, if it's a string
+    /// (sent as it is), i.e., the first media range it consumes (defaulting
+    /// to `application/octet-stream`).
+    fn raw_body_media_type(
+        &self,
+        op: &ResolvableOperation<E::Definition>,
+        params: &[Parameter],
+    ) -> Option<String> {
+        if !params.iter().any(|p| p.presence == ParameterIn::Body) {
+            return None;
+        }
+
+        let range = op
+            .consumes
+            .as_ref()
+            .unwrap_or(&self.api.consumes)
+            .iter()
+            .next()
+            .map(|r| r.0.as_ref().to_owned());
+        Some(range.unwrap_or_else(|| "application/octet-stream".into()))
+    }
+
+    /// Checks whether the body should be URL-encoded, i.e., the operation consumes
+    /// `application/x-www-form-urlencoded` and none of its other media ranges
+    /// are JSON (or have a coder), which are preferred otherwise.
+    fn is_form_body(
+        &self,
+        local_ref: Option<&BTreeSet<MediaRange>>,
+        global_ref: &BTreeSet<MediaRange>,
+    ) -> bool {
+        let ranges = local_ref.unwrap_or(global_ref);
+        ranges
+            .iter()
+            .any(|r| r.0.essence_str() == "application/x-www-form-urlencoded")
+            && ranges
+                .iter()
+                .all(|r| r != &*JSON_MIME && self.api.coders.matching_coder(r).is_none())
+    }
 }
 
 /// Ensures that a parameter type is either a simple type or an array
 /// and returns the resolved Rust type.
 fn resolve_parameter_type(
+    state: &EmitterState,
     dt: Option<DataType>,
     dt_fmt: Option<&DataTypeFormat>,
     items: Option<&Items>,
 ) -> Option<(String, Vec<CollectionFormat>)> {
     match matching_unit_type(dt_fmt, dt) {
-        Some(t) => return Some((t.into(), vec![])),
+        // Parameters are sent through `Display`, which isn't RFC 3339 for `DateTime`.
+        Some(t) if matches!(dt_fmt, Some(DataTypeFormat::DateTime)) => {
+            return Some((t.into(), vec![]))
+        }
+        Some(t) => return Some((format_type(state, dt_fmt).unwrap_or(t).into(), vec![])),
         None if dt == Some(DataType::File) => return Some((FILE_MARKER.into(), vec![])),
         None if dt == Some(DataType::Array) => {
             if let Some(i) = items {
-                if let Some((ty, mut fmts)) =
-                    resolve_parameter_type(i.data_type, i.format.as_ref(), i.items.as_deref())
-                {
+                if let Some((ty, mut fmts)) = resolve_parameter_type(
+                    state,
+                    i.data_type,
+                    i.format.as_ref(),
+                    i.items.as_deref(),
+                ) {
                     fmts.insert(0, i.collection_format.unwrap_or_default());
                     // We collect it as `Vec` for now - we'll replace it with our
                     // `Delimited` wrapper when we actually write the code.
@@ -1191,16 +2076,331 @@ fn resolve_parameter_type(
     None
 }
 
-/// Checks if the given type/format matches a known Rust type and returns it.
+/// Renames the variants whose names collide with the ones before them
+/// (after case conversion), by adding a numeric suffix. Suffixed names skip
+/// the names of other variants, so that those keep the names of their values.
+fn dedup_variant_names(variants: &mut [ObjectVariant]) {
+    let natural = variants
+        .iter()
+        .map(|v| v.name.clone())
+        .collect::<HashSet<_>>();
+    let mut seen = HashSet::new();
+    for var in variants {
+        let mut name = var.name.clone();
+        let mut idx = 1;
+        while seen.contains(&name) || (idx > 1 && natural.contains(&name)) {
+            name = format!("{}{}", var.name, idx);
+            idx += 1;
+        }
+
+        seen.insert(name.clone());
+        var.name = name;
+    }
+}
+
+/// Returns the enum variant for a possible value, named after the value.
+fn default_enum_variant(value: &serde_json::Value) -> Option<ObjectVariant> {
+    use serde_json::Value;
+
+    let mut name = match value {
+        Value::Number(ref n) => format!("Number_{}", n.to_string().replace(['-', '.'], "_")),
+        Value::Bool(b) => object::to_pascal_case(&b.to_string()),
+        Value::String(ref s) => object::to_pascal_case(s).replace('.', "_"),
+        _ => return None,
+    };
+
+    // Values can be empty or start with digits, which are invalid identifiers.
+    if name.is_empty() {
+        name = "Empty".into();
+    } else if name.starts_with(|c: char| c.is_ascii_digit()) {
+        name.insert_str(0, "Value_");
+    } else if RUST_KEYWORDS.contains(&name.as_str()) {
+        name.push('_');
+    }
+
+    Some(ObjectVariant {
+        name,
+        value: value.clone(),
+    })
+}
+
+/// Maps the definitions (by their names) to the modules they go into when
+/// `EmitterState::tag_modules` is set. Definitions used (directly or through
+/// other definitions) only by operations with the same (single) tag go into
+/// the module named after that tag, and the rest go into `types`.
+fn tag_modules<S: Schema>(api: &ResolvableApi<S>) -> BTreeMap<String, String> {
+    // `None` marks the definitions which are shared.
+    let mut tags = BTreeMap::<String, Option<&str>>::new();
+    for map in api.paths.values() {
+        for op in map.methods.values() {
+            let tag = match &*op.tags {
+                [tag] => Some(tag.as_str()),
+                _ => None,
+            };
+
+            let mut names = BTreeSet::new();
+            for p in map.parameters.iter().chain(&op.parameters) {
+                if let Some(s) = p.read().unwrap().schema.as_ref() {
+                    collect_def_names(&*s.read().unwrap(), &mut names);
+                }
+            }
+
+            for r in op.responses.values() {
+                if let Some(s) = r.read().unwrap().schema.as_ref() {
+                    collect_def_names(&*s.read().unwrap(), &mut names);
+                }
+            }
+
+            let callback_refs = op
+                .callbacks
+                .values()
+                .flat_map(|c| c.payload.iter().chain(&c.response));
+            for r in callback_refs {
+                let name = r.reference.trim_start_matches("#/definitions/");
+                if let Some(s) = api.definitions.get(name) {
+                    collect_def_names(&*s.read().unwrap(), &mut names);
+                }
+            }
+
+            for name in names {
+                tags.entry(name)
+                    .and_modify(|t| {
+                        if *t != tag {
+                            *t = None;
+                        }
+                    })
+                    .or_insert(tag);
+            }
+        }
+    }
+
+    api.definitions
+        .keys()
+        .map(|name| {
+            let module = match tags.get(name) {
+                Some(Some(tag)) => tag.to_snake_case(),
+                _ => "types".into(),
+            };
+
+            (name.clone(), module)
+        })
+        .collect()
+}
+
+/// Adds the names of the definitions used by the given schema (including its
+/// own name, if it's a definition) to the given set.
+fn collect_def_names<S: Schema>(def: &S, names: &mut BTreeSet<String>) {
+    if let Some(name) = def.name() {
+        if !names.insert(name.into()) {
+            return; // Either we've been here or we're in a cycle.
+        }
+    }
+
+    let children = def
+        .items()
+        .into_iter()
+        .chain(def.additional_properties().and_then(Either::right))
+        .chain(def.properties().into_iter().flat_map(|p| p.values()))
+        .chain(
+            def.pattern_properties()
+                .into_iter()
+                .flat_map(|p| p.values()),
+        )
+        .chain(def.one_of().unwrap_or_default())
+        .chain(def.all_of().unwrap_or_default());
+    for s in children {
+        collect_def_names(&*s.read().unwrap(), names);
+    }
+}
+
+/// Checks whether the given object schema declares any (fixed) properties.
+fn has_properties<S: Schema>(def: &S) -> bool {
+    matches!(def.properties(), Some(p) if !p.is_empty())
+}
+
+/// Returns the `NonZero*` type for an integer schema whose values must be at least 1.
+fn non_zero_type<S: Schema>(def: &S) -> Option<&'static str> {
+    let min = def.minimum()?.as_f64()?;
+    if min < 1.0 && !(min >= 0.0 && def.exclusive_minimum()) {
+        return None;
+    }
+
+    match matching_unit_type(def.format(), def.data_type()) {
+        Some("i32") => Some("std::num::NonZeroU32"),
+        Some("i64") => Some("std::num::NonZeroU64"),
+        _ => None,
+    }
+}
+
+/// Returns the (restricted) visibility for the given operation, if any.
+fn operation_visibility<S>(
+    path: &str,
+    op: &ResolvableOperation<S>,
+) -> Result<Option<String>, PaperClipError> {
+    match op.visibility.as_deref() {
+        None | Some("pub") => Ok(None),
+        Some(v @ "pub(crate)") => Ok(Some(v.into())),
+        Some(v) => Err(PaperClipError::InvalidVisibility(path.into(), v.into())),
+    }
+}
+
+/// Overrides the host, port and path of the given URL with the `host` and
+/// `basePath` fields in spec (if they exist).
+fn set_host_and_base_path<S>(url: &mut Url, api: &ResolvableApi<S>) -> Result<(), Error> {
+    if let Some(h) = api.host.as_ref() {
+        let mut parts = h.split(':');
+        if let Some(host) = parts.next() {
+            Host::parse(host).map_err(|e| PaperClipError::InvalidHost(h.into(), e))?;
+            url.set_host(Some(host))
+                .expect("expected valid host in URL?");
+        }
+
+        if let Some(port) = parts.next() {
+            let p = port.parse::<u16>().map_err(|_| {
+                PaperClipError::InvalidHost(h.into(), url_dep::ParseError::InvalidPort)
+            })?;
+            url.set_port(Some(p)).expect("expected valid port in URL?");
+        }
+    }
+
+    if let Some(p) = api.base_path.as_ref() {
+        url.set_path(p);
+    }
+
+    Ok(())
+}
+
+/// 64-bit FNV-1a hasher. Unlike `DefaultHasher`, its output is stable across
+/// Rust releases, which is what we need for keying cached output.
+struct Fnv1a(u64);
+
+impl Default for Fnv1a {
+    fn default() -> Self {
+        Fnv1a(0xcbf2_9ce4_8422_2325)
+    }
+}
+
+impl fmt::Write for Fnv1a {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for b in s.bytes() {
+            self.0 ^= u64::from(b);
+            self.0 = self.0.wrapping_mul(0x0100_0000_01b3);
+        }
+
+        Ok(())
+    }
+}
+
+/// Checks whether the given example can be deserialized into the type generated
+/// for the given schema, and returns the reason (if it can't).
+fn check_example<S: Schema>(def: &S, value: &serde_json::Value) -> Result<(), String> {
+    use serde_json::Value;
+
+    if let Some(values) = def.enum_variants() {
+        if !values.contains(value) {
+            return Err(format!("{} is not one of {:?}", value, values));
+        }
+
+        return Ok(());
+    }
+
+    let matches = match (def.data_type(), value) {
+        (Some(DataType::Integer), v) => v.is_i64() || v.is_u64(),
+        (Some(DataType::Number), v) => v.is_number(),
+        (Some(DataType::Boolean), v) => v.is_boolean(),
+        (Some(DataType::String), v) => v.is_string(),
+        (Some(DataType::Array), Value::Array(items)) => {
+            if let Some(schema) = def.items() {
+                let schema = schema.read().unwrap();
+                for (i, item) in items.iter().enumerate() {
+                    check_example(&*schema, item).map_err(|e| format!("[{}]: {}", i, e))?;
+                }
+            }
+
+            true
+        }
+        (Some(DataType::Array), _) => false,
+        (Some(DataType::Object), Value::Object(_)) | (None, Value::Object(_)) => {
+            let map = value.as_object().expect("object");
+            if let Some(name) = def
+                .required_properties()
+                .into_iter()
+                .flatten()
+                .find(|&p| !map.contains_key(p))
+            {
+                return Err(format!("required property {:?} is missing", name));
+            }
+
+            let required = def.required_properties();
+            for (name, schema) in def.properties().into_iter().flatten() {
+                let schema = schema.read().unwrap();
+                match map.get(name) {
+                    // Only optional fields can be `null`.
+                    Some(Value::Null)
+                        if required.is_some_and(|r| r.contains(name)) && !schema.nullable() =>
+                    {
+                        return Err(format!("required property {:?} is null", name));
+                    }
+                    Some(Value::Null) | None => (),
+                    Some(v) => {
+                        check_example(&*schema, v).map_err(|e| format!("{}: {}", name, e))?
+                    }
+                }
+            }
+
+            true
+        }
+        (Some(DataType::Object), _) => false,
+        // We can't say much about the rest (`Any`, files, etc.)
+        _ => true,
+    };
+
+    if !matches {
+        return Err(format!("{} doesn't match the schema type", value));
+    }
+
+    Ok(())
+}
+
+/// Returns the `ArrayString` type for a string schema whose `maxLength` is
+/// at most the given value.
+fn array_string_type<S: Schema>(def: &S, up_to: u32) -> Option<String> {
+    let len = def.max_length().filter(|&l| l > 0 && l <= up_to)?;
+    match matching_unit_type(def.format(), def.data_type()) {
+        Some("String") => Some(format!("arrayvec::ArrayString<{}>", len)),
+        _ => None,
+    }
+}
+
+/// Returns the type which replaces `String` for the given format (if the state
+/// enables that mapping).
+fn format_type(state: &EmitterState, format: Option<&DataTypeFormat>) -> Option<&'static str> {
+    match format {
+        Some(DataTypeFormat::DateTime) if state.chrono_dates => {
+            Some("chrono::DateTime<chrono::Utc>")
+        }
+        Some(DataTypeFormat::Date) if state.chrono_dates => Some(DATE_TYPE),
+        Some(DataTypeFormat::Uuid) if state.uuids => Some(UUID_TYPE),
+        _ => None,
+    }
+}
+
 fn matching_unit_type(
     format: Option<&DataTypeFormat>,
     type_: Option<DataType>,
 ) -> Option<&'static str> {
+    // Formats only refine numeric types (strings with `format: int64` are still
+    // strings on the wire) and integers can't have fractional formats.
+    let int_format = matches!(
+        type_,
+        None | Some(DataType::Integer) | Some(DataType::Number)
+    );
+    let float_format = matches!(type_, None | Some(DataType::Number));
     match format {
-        Some(DataTypeFormat::Int32) => Some("i32"),
-        Some(DataTypeFormat::Int64) => Some("i64"),
-        Some(DataTypeFormat::Float) => Some("f32"),
-        Some(DataTypeFormat::Double) => Some("f64"),
+        Some(DataTypeFormat::Int32) if int_format => Some("i32"),
+        Some(DataTypeFormat::Int64) if int_format => Some("i64"),
+        Some(DataTypeFormat::Float) if float_format => Some("f32"),
+        Some(DataTypeFormat::Double) if float_format => Some("f64"),
         _ => match type_ {
             Some(DataType::Integer) => Some("i64"),
             Some(DataType::Number) => Some("f64"),
@@ -1225,7 +2425,14 @@ fn validate_collection_format(
     }
 
     let default_fmt = CollectionFormat::default();
-    it_fmts.insert(0, collection_format.unwrap_or(default_fmt));
+    // Array query parameters are repeated (`?k=a&k=b`) unless specified otherwise.
+    let root_fmt = match collection_format {
+        Some(f) => f,
+        None if in_ == ParameterIn::Query => CollectionFormat::Multi,
+        None => default_fmt,
+    };
+
+    it_fmts.insert(0, root_fmt);
     it_fmts.pop(); // pop the final format, as it's unnecessary.
     let is_url_encoded = in_ == ParameterIn::Query || in_ == ParameterIn::FormData;
     if it_fmts.contains(&CollectionFormat::Multi) {
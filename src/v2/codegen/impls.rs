@@ -1,10 +1,16 @@
 use super::{
-    emitter::ANY_GENERIC_PARAMETER,
+    emitter::{
+        ANY_GENERIC_PARAMETER, BYTES_TYPE, BYTE_RANGE_TY, DATE_TYPE, FILE_PART_TY,
+        PREFER_RETURN_TY, UUID_TYPE,
+    },
     object,
-    object::{ApiObject, ApiObjectBuilder, Response, StructField, TypeParameters},
+    object::{
+        ApiObject, ApiObjectBuilder, ObjectField, Response, SharedBuilder, StructField,
+        TypeParameters,
+    },
     RUST_KEYWORDS,
 };
-use crate::v2::models::{CollectionFormat, ParameterIn, JSON_CODER, JSON_MIME};
+use crate::v2::models::{CollectionFormat, HttpMethod, ParameterIn, JSON_CODER, JSON_MIME};
 use heck::{ToKebabCase, ToSnakeCase};
 
 use std::{
@@ -29,6 +35,12 @@ fn mk_description_text(str: &str) -> String {
     }
 }
 
+/// `Copy` scalar types whose builder methods have a variant taking references.
+const COPY_SCALAR_TYPES: &[&str] = &["i32", "i64", "f32", "f64", "bool"];
+
+/// Media type of URL-encoded form bodies.
+const FORM_URLENCODED: &str = "application/x-www-form-urlencoded";
+
 /// Represents the API object impl.
 pub struct ApiObjectImpl<'a> {
     inner: &'a ApiObject,
@@ -43,10 +55,12 @@ impl ApiObject {
     /// Each builder is bound to an operation in a path. If the object is not
     /// bound to any operation, then the builder only keeps track of the fields
     /// for building the actual object.
-    // FIXME: Make operations generic across builders. This will reduce the
-    // number of structs generated.
-    pub fn impl_repr<'a>(&'a self, helper_module_prefix: &'a str) -> ApiObjectImpl<'a> {
-        if self.inner.is_enum() {
+    pub fn impl_repr<'a>(
+        &'a self,
+        helper_module_prefix: &'a str,
+        safe_builders: bool,
+    ) -> ApiObjectImpl<'a> {
+        if !self.inner.is_struct() {
             return ApiObjectImpl {
                 inner: self,
                 builders: vec![].into(),
@@ -62,6 +76,7 @@ impl ApiObject {
             fields: self.fields(),
             encoding: None,
             needs_any,
+            safe_builders,
             ..Default::default()
         };
 
@@ -87,10 +102,21 @@ impl ApiObject {
                         object: &self.name,
                         op_id: req.id.as_deref(),
                         deprecated: req.deprecated,
+                        external_docs: req.external_docs.as_ref(),
+                        visibility: req.visibility.as_deref(),
+                        timeout: req.timeout,
+                        query_struct: req.query_struct,
+                        tags: &req.tags,
+                        errors: &req.errors,
+                        success: &req.success,
+                        security: &req.security,
                         method: Some(method),
                         body_required: req.body_required,
                         encoding: req.encoding.as_ref(),
+                        form_body: req.form_body,
                         decoding: req.decoding.as_ref(),
+                        accept: req.accept.as_deref(),
+                        raw_body: req.raw_body.as_deref(),
                         fields: self.fields(),
                         global_params: &path_ops.params,
                         local_params: &req.params,
@@ -99,21 +125,71 @@ impl ApiObject {
                             ty_path: req.response.ty_path.as_deref(),
                             contains_any: req.response.contains_any,
                             headers: &req.response.headers,
+                            multipart_mixed: req.response.multipart_mixed,
+                            prefer_return: req.response.prefer_return,
                         },
+                        safe_builders,
+                        shared: None,
                     })
             });
 
+        let mut builders = iter::once(if main_builder.fields.is_empty() {
+            None
+        } else {
+            Some(main_builder)
+        })
+        .flatten()
+        .chain(path_iter)
+        .collect::<Vec<_>>();
+        self.share_builders(&mut builders);
+
         ApiObjectImpl {
             inner: self,
-            builders: iter::once(if main_builder.fields.is_empty() {
-                None
-            } else {
-                Some(main_builder)
-            })
-            .flatten()
-            .chain(path_iter)
-            .collect::<Vec<_>>()
-            .into(),
+            builders: builders.into(),
+        }
+    }
+
+    /// Makes operation builders with the same fields and parameters share a
+    /// single struct (generic over the operation), so that large specs don't
+    /// end up with a pair of structs for each operation.
+    fn share_builders(&self, builders: &mut [ApiObjectBuilder<'_>]) {
+        let mut groups: Vec<(String, Vec<usize>)> = vec![];
+        for (idx, builder) in builders.iter().enumerate() {
+            // Object builders aren't bound to operations, builders generic
+            // over `Any` already have a type parameter with a default, and
+            // unit structs aren't worth sharing.
+            if builder.method.is_none()
+                || builder.needs_any
+                || !(builder.body_required || builder.has_atleast_one_field())
+            {
+                continue;
+            }
+
+            let key = format!(
+                "{} {} {:?}",
+                builder.body_required,
+                builder.needs_container(),
+                builder.struct_fields_iter().collect::<Vec<_>>()
+            );
+            match groups.iter_mut().find(|(k, _)| *k == key) {
+                Some((_, indices)) => indices.push(idx),
+                None => groups.push((key, vec![idx])),
+            }
+        }
+
+        let groups = groups.into_iter().filter(|(_, indices)| indices.len() > 1);
+        for (i, (_, indices)) in groups.enumerate() {
+            let mut name = format!("{}OpBuilder", self.name);
+            if i > 0 {
+                name.push_str(&i.to_string());
+            }
+
+            for (j, idx) in indices.into_iter().enumerate() {
+                builders[idx].shared = Some(SharedBuilder {
+                    name: name.clone(),
+                    owner: j == 0,
+                });
+            }
         }
     }
 }
@@ -232,19 +308,28 @@ impl<'a> ApiObjectImpl<'a> {
         for builder in &*self.builders {
             let mut temp = String::new();
             let has_fields = builder.has_atleast_one_field();
-            if builder.description.is_none() {
+            // Only operations (which always have constructors) have external docs.
+            if builder.description.is_none() && builder.external_docs.is_none() {
                 temp.write_str("\n")?;
             }
 
             if builder.deprecated {
-                temp.write_str("    #[deprecated]\n")?;
+                ApiObject::write_deprecated(builder.description.as_ref(), &mut temp, 1)?;
             }
 
             // All builder constructor functions are inlined.
-            temp.write_str("    #[inline]\n    pub fn ")?;
+            temp.write_str("    #[inline]\n    ")?;
+            temp.write_str(builder.visibility.unwrap_or("pub"))?;
+            temp.write_str(" fn ")?;
             if let Some(name) = builder.constructor_fn_name() {
                 temp.write_str(&name)?;
                 ApiObject::write_docs(builder.description.as_ref(), f, 1)?;
+                ApiObject::write_external_docs(
+                    builder.external_docs,
+                    builder.description.is_some(),
+                    f,
+                    1,
+                )?;
             } else {
                 // If we can't generate a name of a builder, then we go for a
                 // simple object builder.
@@ -260,7 +345,8 @@ impl<'a> ApiObjectImpl<'a> {
             f.write_str(" {\n        ")?;
             builder.write_name(f)?;
 
-            if has_fields || builder.body_required {
+            let has_fields = has_fields || builder.body_required || builder.shared.is_some();
+            if has_fields {
                 f.write_str(" {")?;
             }
 
@@ -273,6 +359,10 @@ impl<'a> ApiObjectImpl<'a> {
                 f.write_str("body: Default::default(),")?;
             }
 
+            if builder.shared.is_some() {
+                f.write_str("\n            _op: core::marker::PhantomData,")?;
+            }
+
             builder
                 .struct_fields_iter()
                 .try_for_each::<_, fmt::Result>(|field| {
@@ -295,7 +385,7 @@ impl<'a> ApiObjectImpl<'a> {
                     Ok(())
                 })?;
 
-            if has_fields || builder.body_required {
+            if has_fields {
                 f.write_str("\n        }")?;
             }
 
@@ -305,6 +395,289 @@ impl<'a> ApiObjectImpl<'a> {
         Ok(())
     }
 
+    /// Writes the method for creating the object builder from an existing object
+    /// (for sending it back to the API). Required fields are marked as existing,
+    /// except write-only fields, which won't be in the objects from responses.
+    fn write_to_builder_method<F>(&self, f: &mut F) -> fmt::Result
+    where
+        F: Write,
+    {
+        let builder = match self.builders.first() {
+            Some(b) if b.method.is_none() => b,
+            _ => return Ok(()),
+        };
+
+        let write_only_fields = builder
+            .struct_fields_iter()
+            .filter(|f| f.prop.is_required() && f.write_only)
+            .map(|f| format!("`{}`", f.name))
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        f.write_str("\n    /// Create a builder from this object.")?;
+        if !write_only_fields.is_empty() {
+            write!(
+                f,
+                "\n    ///\n    /// **NOTE:** Write-only fields ({}) should be set again.",
+                write_only_fields
+            )?;
+        }
+
+        f.write_str("\n    #[inline]\n    pub fn to_builder(&self) -> ")?;
+        builder.write_name(f)?;
+        builder.write_generics_if_necessary(f, None, TypeParameters::ChangeAllButWriteOnly)?;
+        f.write_str("\n    where\n        Self: Clone,\n    {\n        ")?;
+        builder.write_name(f)?;
+        f.write_str(" {\n            body: self.clone(),")?;
+        builder
+            .struct_fields_iter()
+            .filter(|f| f.prop.is_required())
+            .try_for_each(|field| {
+                f.write_str("\n            _")?;
+                f.write_str(&object::to_snake_case(field.name))?;
+                f.write_str(": core::marker::PhantomData,")
+            })?;
+
+        f.write_str("\n        }\n    }\n")
+    }
+
+    /// Writes the method for constructing the example object (if any) documented
+    /// in the schema. The example has already been checked against the schema.
+    fn write_example_method<F>(&self, f: &mut F) -> fmt::Result
+    where
+        F: Write,
+    {
+        let example = match self.inner.example.as_ref() {
+            Some(e) if self.inner.example_method => e.to_string(),
+            _ => return Ok(()),
+        };
+
+        // Use enough hashes to keep the raw string intact.
+        let mut hashes = String::from("#");
+        while example.contains(&format!("\"{}", hashes)) {
+            hashes.push('#');
+        }
+
+        write!(
+            f,
+            "
+    /// Create the example object documented in the schema.
+    pub fn example() -> Self
+    where
+        Self: serde::de::DeserializeOwned,
+    {{
+        serde_json::from_str(r{hashes}\"{example}\"{hashes}).expect(\"invalid example?\")
+    }}
+",
+            hashes = hashes,
+            example = example
+        )
+    }
+
+    /// Writes the method for merging another (partial) object into this one.
+    fn write_merge_method<F>(&self, f: &mut F) -> fmt::Result
+    where
+        F: Write,
+    {
+        if !self.inner.merge {
+            return Ok(());
+        }
+
+        f.write_str(
+            "
+    /// Merge the fields of `other` into this object. Optional fields are
+    /// overwritten only if they're set in `other`, whereas required fields
+    /// are always overwritten.
+    pub fn merge(&mut self, other: Self) {",
+        )?;
+
+        for field in self.inner.fields() {
+            let name = object::field_ident(&field.name);
+            if field.is_required {
+                write!(f, "\n        self.{name} = other.{name};", name = name)?;
+            } else {
+                write!(
+                    f,
+                    "\n        if let Some(v) = other.{name} {{\n            self.{name} = Some(v);\n        }}",
+                    name = name
+                )?;
+            }
+        }
+
+        f.write_str("\n    }\n")
+    }
+
+    /// Writes the method for inserting pattern properties after checking their keys.
+    fn write_pattern_insert_method<F>(&self, f: &mut F) -> fmt::Result
+    where
+        F: Write,
+    {
+        let field = match self
+            .inner
+            .fields()
+            .iter()
+            .find(|f| !f.key_patterns.is_empty())
+        {
+            Some(f) => f,
+            None => return Ok(()),
+        };
+
+        let name = object::field_ident(&field.name);
+        let value_ty = object::split_map_ty(&field.ty_path)
+            .map_or(field.ty_path.as_str(), |(_, _, value_ty)| value_ty);
+        let mut ty = String::new();
+        if field.needs_any {
+            ApiObject::write_field_with_any(value_ty, &mut ty)?;
+        } else {
+            ty.push_str(value_ty);
+        }
+
+        write!(
+            f,
+            "
+    /// Insert a pattern property, provided that its key matches one of
+    /// the patterns for this object.
+    pub fn insert_pattern_field(&mut self, key: String, value: {ty}) -> Result<(), {prefix}util::InvalidKey> {{
+        lazy_static::lazy_static! {{
+            static ref PATTERNS: Vec<regex::Regex> = vec![",
+            ty = ty,
+            prefix = self.builders[0].helper_module_prefix,
+        )?;
+
+        for pattern in &field.key_patterns {
+            write!(
+                f,
+                "\n                regex::Regex::new({:?}).expect(\"invalid pattern?\"),",
+                pattern
+            )?;
+        }
+
+        write!(
+            f,
+            "
+            ];
+        }}
+
+        if !PATTERNS.iter().any(|p| p.is_match(&key)) {{
+            return Err({prefix}util::InvalidKey(key));
+        }}
+
+        self.{name}.get_or_insert_with(Default::default).insert(key, value);
+        Ok(())
+    }}
+",
+            prefix = self.builders[0].helper_module_prefix,
+            name = name,
+        )
+    }
+
+    /// Writes the method for checking the fields against the constraints in the schema.
+    fn write_validate_method<F>(&self, f: &mut F) -> fmt::Result
+    where
+        F: Write,
+    {
+        let fields = self
+            .inner
+            .fields()
+            .iter()
+            .filter(|f| !f.bounds.is_empty() || f.pattern.is_some())
+            .collect::<Vec<_>>();
+        if fields.is_empty() {
+            return Ok(());
+        }
+
+        let prefix = self.builders[0].helper_module_prefix;
+        write!(
+            f,
+            "
+    /// Check the fields of this object against the constraints (like `minimum`
+    /// or `pattern`) in the spec.
+    pub fn validate(&self) -> Result<(), {prefix}util::ValidationError> {{",
+            prefix = prefix
+        )?;
+
+        for field in fields {
+            let name = object::field_ident(&field.name);
+            let non_zero = field.ty_path.starts_with("std::num::NonZero");
+            let value = if non_zero { "v.get()" } else { "v" };
+            // Strings are borrowed, whereas numbers are copied.
+            let borrow = if field.pattern.is_some() { "&" } else { "" };
+            if field.is_required {
+                write!(
+                    f,
+                    "\n        {{\n            let v = {}self.{};",
+                    borrow, name
+                )?;
+            } else {
+                write!(f, "\n        if let Some(v) = {}self.{} {{", borrow, name)?;
+            }
+
+            let mut checks = vec![];
+            // Non-zero types already enforce their lower bound.
+            if let (Some(min), false) = (&field.bounds.minimum, non_zero) {
+                checks.extend(bound_check(
+                    field,
+                    min,
+                    field.bounds.exclusive_minimum,
+                    true,
+                ));
+            }
+
+            if let Some(max) = &field.bounds.maximum {
+                checks.extend(bound_check(
+                    field,
+                    max,
+                    field.bounds.exclusive_maximum,
+                    false,
+                ));
+            }
+
+            for (op, lit, reason) in checks {
+                write!(
+                    f,
+                    "
+            if {value} {op} {lit} {{
+                return Err({prefix}util::ValidationError {{
+                    field: {field:?},
+                    reason: {reason:?},
+                }});
+            }}",
+                    value = value,
+                    op = op,
+                    lit = lit,
+                    prefix = prefix,
+                    field = field.name,
+                    reason = reason,
+                )?;
+            }
+
+            if let Some(pattern) = &field.pattern {
+                write!(
+                    f,
+                    "
+            lazy_static::lazy_static! {{
+                static ref PATTERN: regex::Regex = regex::Regex::new({pattern:?}).expect(\"invalid pattern?\");
+            }}
+
+            if !PATTERN.is_match(v) {{
+                return Err({prefix}util::ValidationError {{
+                    field: {field:?},
+                    reason: {reason:?},
+                }});
+            }}",
+                    pattern = pattern,
+                    prefix = prefix,
+                    field = field.name,
+                    reason = format!("must match the pattern {:?}", pattern),
+                )?;
+            }
+
+            f.write_str("\n        }")?;
+        }
+
+        f.write_str("\n\n        Ok(())\n    }\n")
+    }
+
     /// Writes the `Into` impl for fulfilled builders (if they have a body).
     fn write_into_impl<F>(&self, builder: &ApiObjectBuilder<'_>, f: &mut F) -> fmt::Result
     where
@@ -359,6 +732,41 @@ impl<'a, 'b> ApiObjectBuilderImpl<'a, 'b>
 where
     'b: 'a,
 {
+    /// Returns the method name and the type of this builder (with all its
+    /// requirements satisfied) if this builder is for some operation. This
+    /// is used for grouping operations into traits.
+    pub(super) fn op_trait_item(&self, module_path: &str) -> Option<(String, String)> {
+        self.0.rel_path?;
+        // Public traits shouldn't expose restricted operations.
+        if self.0.visibility.is_some() {
+            return None;
+        }
+        // Builder constructors are numbered when there are several of them for
+        // an object, which isn't very helpful in a trait spanning multiple objects.
+        // So, we go for the operation ID or the method along with the path.
+        let name = match (self.0.op_id, self.0.method, self.0.rel_path) {
+            (Some(id), _, _) => id.to_snake_case(),
+            (None, Some(meth), Some(path)) => format!("{} {}", meth, path).to_snake_case(),
+            _ => return None,
+        };
+        let mut ty = format!("{}{}::", self.0.helper_module_prefix, module_path);
+        self.0.write_name(&mut ty).ok()?;
+        self.0
+            .write_generics_if_necessary(
+                &mut ty,
+                Some(
+                    self.0
+                        .encoding
+                        .map(|(_, c)| c.any_value.as_str())
+                        .unwrap_or_else(|| JSON_CODER.any_value.as_str()),
+                ),
+                TypeParameters::ChangeAll,
+            )
+            .ok()?;
+
+        Some((name, ty))
+    }
+
     /// Writes impl for getting args from `clap::ArgMatches`
     pub(super) fn write_arg_parsing<F>(&self, f: &mut F) -> fmt::Result
     where
@@ -406,6 +814,10 @@ where
         }
 
         let mut phantom = String::new();
+        if self.0.shared.is_some() {
+            phantom.push_str("\n            _op: core::marker::PhantomData,");
+        }
+
         self.0.struct_fields_iter().try_for_each(|field| {
             let (sk, kk) = (
                 object::to_snake_case(field.name),
@@ -435,7 +847,38 @@ where
             )?;
 
             if field.needs_file {
-                ty = "std::path::PathBuf".into();
+                ty = format!("{}{}", self.0.helper_module_prefix, FILE_PART_TY);
+            }
+
+            // Deep objects are passed as JSON.
+            if field.deep_object {
+                return writeln!(
+                    f,
+                    ": matches.and_then(|m| {{
+                    m.value_of(\"{arg}\").map(|v| {{
+                        serde_json::from_str::<{ty}>(v).unwrap_or_else(|e| {{
+                            clap::Error::with_description(&e.to_string(), clap::ErrorKind::InvalidValue).exit()
+                        }})
+                    }})
+                }}),",
+                    arg = kk,
+                    ty = ty
+                );
+            }
+
+            // Binary bodies are read from the given file.
+            if field.ty == BYTES_TYPE {
+                return writeln!(
+                    f,
+                    ": matches.and_then(|m| {{
+                    m.value_of(\"{arg}\").map(|v| {{
+                        std::fs::read(v).unwrap_or_else(|e| {{
+                            clap::Error::with_description(&e.to_string(), clap::ErrorKind::Io).exit()
+                        }})
+                    }})
+                }}),",
+                    arg = kk
+                );
             }
 
             // We're enforcing requirements in the CLI. We can relax here.
@@ -470,7 +913,7 @@ where
     /// Builds the method parameter type using the actual field type.
     ///
     /// For example, if a field is `Vec<T>`, then we replace it (in builder method)
-    /// with `impl Iterator<Item=Into<T>>`, and if we had `BTreeMap<String, T>`,
+    /// with `impl Iterator<Item=Into<T>>`, and if we had a map like `BTreeMap<String, T>`,
     /// then we replace it with `impl Iterator<Item = (String, T)>` and
     /// we do this... recursively.
     // FIXME: Investigate if there's a better way.
@@ -484,17 +927,22 @@ where
     where
         F: Write,
     {
-        if let Some(i) = ty.find('<') {
+        if ty == BYTES_TYPE {
+            f.write_str("impl Into<Vec<u8>>")?;
+        } else if let Some(i) = ty.find('<') {
             if ty[..i].ends_with("Vec") {
                 f.write_str("impl Iterator<Item = ")?;
                 self.write_builder_ty(&ty[i + 1..ty.len() - 1], req, needs_any, f)?;
                 f.write_str(">")?;
-            } else if ty[..i].ends_with("std::collections::BTreeMap") {
-                f.write_str("impl Iterator<Item = (String, ")?;
-                self.write_builder_ty(&ty[i + 9..ty.len() - 1], req, needs_any, f)?;
+            } else if let Some((_, key_ty, value_ty)) = object::split_map_ty(ty) {
+                write!(f, "impl Iterator<Item = ({}, ", key_ty)?;
+                self.write_builder_ty(value_ty, req, needs_any, f)?;
                 f.write_str(")>")?;
+            } else {
+                // Other generic types (like `ArrayString<N>`) are taken as they are.
+                write!(f, "impl Into<{}>", ty)?;
             }
-        } else if ApiObject::is_simple_type(ty) {
+        } else if ApiObject::is_simple_type(ty) || ty == DATE_TYPE || ty == UUID_TYPE {
             write!(f, "impl Into<{}", ty)?;
             if needs_any && ty != ANY_GENERIC_PARAMETER {
                 ApiObject::write_any_generic(f)?;
@@ -537,19 +985,23 @@ where
     /// appropriately. So, whenever we encounter collections, we recursively
     /// collect the iterator items and if it's not a collection, we go for
     /// `value.into()`.
-    fn write_value_map<F>(ty: &str, f: &mut F) -> fmt::Result
+    fn write_value_map<F>(&self, ty: &str, f: &mut F) -> fmt::Result
     where
         F: Write,
     {
-        if let Some(i) = ty.find('<') {
+        if ty == BYTES_TYPE {
+            f.write_str("value")?;
+        } else if let Some(i) = ty.find('<') {
             if ty[..i].ends_with("Vec") {
                 f.write_str("value.map(|value| ")?;
-                Self::write_value_map(&ty[i + 1..ty.len() - 1], f)?;
+                self.write_value_map(&ty[i + 1..ty.len() - 1], f)?;
                 f.write_str(").collect::<Vec<_>>()")?;
-            } else if ty[..i].ends_with("std::collections::BTreeMap") {
+            } else if let Some((map, _, value_ty)) = object::split_map_ty(ty) {
                 f.write_str("value.map(|(key, value)| (key, ")?;
-                Self::write_value_map(&ty[i + 9..ty.len() - 1], f)?;
-                f.write_str(")).collect::<std::collections::BTreeMap<_, _>>()")?;
+                self.write_value_map(value_ty, f)?;
+                write!(f, ")).collect::<{}<_, _>>()", map)?;
+            } else {
+                f.write_str("value")?;
             }
         } else {
             f.write_str("value")?;
@@ -572,52 +1024,59 @@ where
         );
         let collides_with_keyword = RUST_KEYWORDS.iter().any(|&k| k == field_name);
         let needs_trailing_dash = collides_with_keyword && field.prop.is_field();
+        let overrides_body = field.overridden && self.0.body_required;
 
         ApiObject::write_docs(field.desc, f, 1)?;
         if field.desc.is_none() {
             f.write_str("\n")?;
         }
 
-        // Inline property methods.
-        f.write_str("    #[inline]\n    pub fn ")?;
-        f.write_str(&field_name)?;
-        if collides_with_keyword {
-            f.write_str("_")?;
+        if field.deprecated {
+            ApiObject::write_deprecated(field.desc, f, 1)?;
         }
 
-        f.write_str("(mut self, value: ")?;
-        if field.needs_file {
-            f.write_str("impl AsRef<std::path::Path>")?;
-        } else {
-            self.write_builder_ty(field.ty, field.strict_child_fields, field.needs_any, f)?;
+        // `Range` header takes the first and last positions of the byte range
+        // (or only the first one, for the rest of the body).
+        if field.ty.ends_with(BYTE_RANGE_TY) {
+            return write!(
+                f,
+                "    #[inline]\n    pub fn {name}(mut self, start: u64, end: u64) -> Self {{
+        self.{container}param_{name} = Some({ty}(start, Some(end)));
+        self
+    }}
+
+    /// Same as [`{name}`](#method.{name}), but the range extends to the end of the body.
+    #[inline]
+    pub fn {name}_from(mut self, start: u64) -> Self {{
+        self.{container}param_{name} = Some({ty}(start, None));
+        self
+    }}
+",
+                name = field_name,
+                container = if needs_container { "inner." } else { "" },
+                ty = field.ty,
+            );
         }
 
-        f.write_str(") -> ")?;
-        if prop_is_required {
-            self.0.write_name(f)?;
-            self.0
-                .write_generics_if_necessary(f, None, TypeParameters::ChangeOne(field.name))?;
+        // Inline property methods.
+        f.write_str("    #[inline]\n    pub fn ")?;
+        // `Prefer` header is only used for choosing the response representation.
+        if field.ty.ends_with(PREFER_RETURN_TY) {
+            f.write_str("prefer_return")?;
         } else {
-            f.write_str("Self")?;
-        }
-
-        f.write_str(" {\n        self.")?;
-        if needs_container {
-            f.write_str("inner.")?;
-        }
-
-        if prop_is_parameter {
-            f.write_str("param_")?;
-        // If it's not a parameter, then it's definitely a body field.
-        } else if self.0.body_required {
-            f.write_str("body.")?;
+            f.write_str(&field_name)?;
         }
 
-        f.write_str(&field_name)?;
-        if needs_trailing_dash {
+        if collides_with_keyword {
             f.write_str("_")?;
         }
 
+        f.write_str("(mut self, value: ")?;
+        self.write_setter_value_ty(&field, f)?;
+        f.write_str(") -> ")?;
+        self.write_setter_return_ty(&field, f)?;
+        f.write_str(" {\n        ")?;
+        self.write_setter_target(&field, f)?;
         f.write_str(" = ")?;
         if prop_is_parameter || !prop_is_required {
             f.write_str("Some(")?;
@@ -626,12 +1085,12 @@ where
         }
 
         if field.needs_file {
-            f.write_str("value.as_ref().into()")?;
-        } else if field.overridden && self.0.body_required {
+            f.write_str("value.into()")?;
+        } else if overrides_body {
             // If there's a field in the body with similar name and type,
             // then override it with this value.
             f.write_str("{\n            let val = ")?;
-            Self::write_value_map(field.ty, f)?;
+            self.write_value_map(field.ty, f)?;
             f.write_str(";\n            self.")?;
             if needs_container {
                 f.write_str("inner.")?;
@@ -646,7 +1105,7 @@ where
             f.write_str(" = val.clone().into();")?;
             f.write_str("\n            val\n        }")?;
         } else {
-            Self::write_value_map(field.ty, f)?;
+            self.write_value_map(field.ty, f)?;
         }
 
         if prop_is_parameter || !prop_is_required || field.boxed {
@@ -654,57 +1113,335 @@ where
         }
 
         f.write_str(";\n        ")?;
-        // We need to transmute only if there's a required field/parameter.
-        if prop_is_required {
-            f.write_str("unsafe { std::mem::transmute(self) }")?;
-        } else {
-            f.write_str("self")?;
-        }
+        self.write_setter_end(&field, f)?;
+        f.write_str("\n    }\n")?;
 
-        f.write_str("\n    }\n")
-    }
-}
+        // Optional fields can also be set (or unset) from `Option` values.
+        if !prop_is_required && !overrides_body && !field.ty.ends_with(PREFER_RETURN_TY) {
+            write!(
+                f,
+                "\n    /// Sets [`{name}`](#method.{name}) if the given value is `Some` (or unsets it otherwise).\n",
+                name = if collides_with_keyword {
+                    field_name.clone() + "_"
+                } else {
+                    field_name.clone()
+                }
+            )?;
 
-/// Codegen for `Sendable` trait for operation builders.
-struct SendableCodegen<'a, 'b> {
-    builder: &'a ApiObjectBuilder<'b>,
-    needs_container: bool,
-    is_multipart: bool,
-    path_items: String,
-    headers: String,
-    form: String,
-    query: String,
-    multi_value_query: Vec<String>,
-}
+            if field.deprecated {
+                ApiObject::write_deprecated(field.desc, f, 1)?;
+            }
 
-impl<'a, 'b> From<&'a ApiObjectBuilder<'b>> for SendableCodegen<'a, 'b> {
-    fn from(builder: &'a ApiObjectBuilder<'b>) -> Self {
-        SendableCodegen {
-            builder,
-            needs_container: builder.needs_container(),
-            path_items: String::new(),
-            headers: String::new(),
-            is_multipart: builder.struct_fields_iter().any(|f| f.needs_file),
-            form: String::new(),
-            query: String::new(),
-            multi_value_query: vec![],
+            write!(
+                f,
+                "    #[inline]\n    pub fn maybe_{}(mut self, value: Option<",
+                field_name
+            )?;
+            self.write_setter_value_ty(&field, f)?;
+            f.write_str(">) -> Self {\n        ")?;
+            self.write_setter_target(&field, f)?;
+            f.write_str(" = value.map(|value| ")?;
+            if field.needs_file {
+                f.write_str("value.into()")?;
+            } else {
+                self.write_value_map(field.ty, f)?;
+            }
+
+            f.write_str(");\n        self\n    }\n")?;
         }
+
+        self.write_ref_property_method(&field, f)?;
+        self.write_vec_property_method(field, f)
     }
-}
 
-impl<'a, 'b> SendableCodegen<'a, 'b> {
-    /// Determine and write `Sendable` impl (if it's needed for this builder).
-    fn write_impl_if_needed<F>(mut self, f: &mut F) -> fmt::Result
+    /// Writes an additional method for setting a `Copy` scalar from a reference
+    /// (since `&T` doesn't implement `Into<T>`).
+    fn write_ref_property_method<F>(&self, field: &StructField<'b>, f: &mut F) -> fmt::Result
     where
         F: Write,
     {
-        let (path, method) = match (self.builder.rel_path, self.builder.method) {
-            (Some(p), Some(m)) => (p, m),
-            _ => return Ok(()),
-        };
+        if !COPY_SCALAR_TYPES.contains(&field.ty) || field.needs_file {
+            return Ok(());
+        }
 
-        f.write_str("\n")?;
-        if self.builder.response.is_file() {
+        let mut name = object::to_snake_case(field.name);
+        if RUST_KEYWORDS.iter().any(|&k| k == name) {
+            name.push('_');
+        }
+
+        write!(
+            f,
+            "\n    /// Same as [`{name}`](#method.{name}), but takes a reference.\n",
+            name = name
+        )?;
+
+        if field.deprecated {
+            ApiObject::write_deprecated(field.desc, f, 1)?;
+        }
+
+        write!(
+            f,
+            "    #[inline]\n    pub fn {}_ref(self, value: &{}) -> ",
+            name.trim_end_matches('_'),
+            field.ty
+        )?;
+        self.write_setter_return_ty(field, f)?;
+        write!(f, " {{\n        self.{}(*value)\n    }}\n", name)
+    }
+
+    /// Writes the type of the value taken by a property method.
+    fn write_setter_value_ty<F>(&self, field: &StructField<'b>, f: &mut F) -> fmt::Result
+    where
+        F: Write,
+    {
+        if field.needs_file {
+            write!(
+                f,
+                "impl Into<{}{}>",
+                self.0.helper_module_prefix, FILE_PART_TY
+            )
+        } else if field.prop.is_parameter() && field.ty == object::to_pascal_case(field.name) {
+            // Parameter enums are named after their parameters (just like the
+            // type parameters for required fields), so we address them with 'self::'.
+            write!(f, "impl Into<self::{}>", field.ty)
+        } else {
+            self.write_builder_ty(field.ty, field.strict_child_fields, field.needs_any, f)
+        }
+    }
+
+    /// Writes an additional method for setting an array from an owned `Vec`
+    /// (since the property method takes an iterator).
+    fn write_vec_property_method<F>(&self, field: StructField<'b>, f: &mut F) -> fmt::Result
+    where
+        F: Write,
+    {
+        let elem_ty = match field.ty.find('<') {
+            Some(i) if field.ty[..i].ends_with("Vec") => &field.ty[i + 1..field.ty.len() - 1],
+            _ => return Ok(()),
+        };
+
+        // Nested arrays in parameters are delimited at each level, so they're
+        // only set through iterators.
+        let is_parameter = field.prop.is_parameter();
+        if field.ty == BYTES_TYPE
+            || field.needs_file
+            || (field.overridden && self.0.body_required)
+            || (is_parameter && elem_ty.contains('<'))
+        {
+            return Ok(());
+        }
+
+        let field_name = object::to_snake_case(field.name);
+        write!(
+            f,
+            "\n    /// Same as [`{name}`](#method.{name}), but takes the values as a `Vec`.\n",
+            name = if RUST_KEYWORDS.iter().any(|&k| k == field_name) {
+                field_name.clone() + "_"
+            } else {
+                field_name.clone()
+            }
+        )?;
+
+        if field.deprecated {
+            ApiObject::write_deprecated(field.desc, f, 1)?;
+        }
+
+        write!(
+            f,
+            "    #[inline]\n    pub fn {}_vec(mut self, value: impl Into<",
+            field_name
+        )?;
+        if field.needs_any {
+            ApiObject::write_field_with_any(field.ty, f)?;
+        } else {
+            f.write_str(field.ty)?;
+        }
+
+        f.write_str(">) -> ")?;
+        self.write_setter_return_ty(&field, f)?;
+        f.write_str(" {\n        ")?;
+        if is_parameter {
+            // Parameters are kept in their delimited form.
+            f.write_str("let value: Vec<_> = value.into();\n        ")?;
+        }
+
+        self.write_setter_target(&field, f)?;
+        f.write_str(" = ")?;
+        let wrapped = is_parameter || !field.prop.is_required() || field.boxed;
+        if is_parameter || !field.prop.is_required() {
+            f.write_str("Some(")?;
+        } else if field.boxed {
+            f.write_str("Box::new(")?;
+        }
+
+        f.write_str("value.into()")?;
+        if wrapped {
+            f.write_str(")")?;
+        }
+
+        f.write_str(";\n        ")?;
+        self.write_setter_end(&field, f)?;
+        f.write_str("\n    }\n")
+    }
+
+    /// Writes the return type of a property method (which changes the type
+    /// parameter of a required field).
+    fn write_setter_return_ty<F>(&self, field: &StructField<'b>, f: &mut F) -> fmt::Result
+    where
+        F: Write,
+    {
+        if field.prop.is_required() {
+            self.0.write_struct_name(f)?;
+            self.0
+                .write_struct_generics(f, TypeParameters::ChangeOne(field.name))?;
+            Ok(())
+        } else {
+            f.write_str("Self")
+        }
+    }
+
+    /// Writes the place (in the builder) where the value of a property is stored.
+    fn write_setter_target<F>(&self, field: &StructField<'b>, f: &mut F) -> fmt::Result
+    where
+        F: Write,
+    {
+        let field_name = object::to_snake_case(field.name);
+        f.write_str("self.")?;
+        if self.0.needs_container() {
+            f.write_str("inner.")?;
+        }
+
+        if field.prop.is_parameter() {
+            f.write_str("param_")?;
+        // If it's not a parameter, then it's definitely a body field.
+        } else if self.0.body_required {
+            f.write_str("body.")?;
+        }
+
+        f.write_str(&field_name)?;
+        if field.prop.is_field() && RUST_KEYWORDS.iter().any(|&k| k == field_name) {
+            f.write_str("_")?;
+        }
+
+        Ok(())
+    }
+
+    /// Writes the final expression of a property method, which returns the builder.
+    fn write_setter_end<F>(&self, field: &StructField<'b>, f: &mut F) -> fmt::Result
+    where
+        F: Write,
+    {
+        // We need to change the type only if there's a required field/parameter.
+        if field.prop.is_required() && self.0.safe_builders {
+            self.write_rebuild(f)
+        } else if field.prop.is_required() {
+            f.write_str("unsafe { std::mem::transmute(self) }")
+        } else {
+            f.write_str("self")
+        }
+    }
+
+    /// Writes the expression which moves the contents of this builder into
+    /// a new builder (whose type parameters are inferred).
+    fn write_rebuild<F>(&self, f: &mut F) -> fmt::Result
+    where
+        F: Write,
+    {
+        self.0.write_struct_name(f)?;
+        f.write_str(" {")?;
+        if self.0.needs_container() {
+            f.write_str("\n            inner: self.inner,")?;
+        } else if self.0.body_required {
+            f.write_str("\n            body: self.body,")?;
+        }
+
+        if self.0.shared.is_some() {
+            f.write_str("\n            _op: core::marker::PhantomData,")?;
+        }
+
+        self.0.struct_fields_iter().try_for_each(|field| {
+            let name = object::to_snake_case(field.name);
+            let param = if field.prop.is_parameter() {
+                "_param"
+            } else {
+                ""
+            };
+            if field.prop.is_parameter() && !self.0.needs_container() {
+                write!(
+                    f,
+                    "\n            param_{name}: self.param_{name},",
+                    name = name
+                )?;
+            }
+
+            if field.prop.is_required() {
+                write!(
+                    f,
+                    "\n            {}_{}: core::marker::PhantomData,",
+                    param, name
+                )?;
+            }
+
+            Ok(())
+        })?;
+
+        f.write_str("\n        }")
+    }
+}
+
+/// Codegen for `Sendable` trait for operation builders.
+struct SendableCodegen<'a, 'b> {
+    builder: &'a ApiObjectBuilder<'b>,
+    needs_container: bool,
+    is_multipart: bool,
+    path_items: String,
+    headers: String,
+    header_checks: Vec<String>,
+    raw_body: Option<String>,
+    cookies: String,
+    form: String,
+    query: String,
+    multi_value_query: Vec<String>,
+    query_fields: String,
+}
+
+impl<'a, 'b> From<&'a ApiObjectBuilder<'b>> for SendableCodegen<'a, 'b> {
+    fn from(builder: &'a ApiObjectBuilder<'b>) -> Self {
+        SendableCodegen {
+            builder,
+            needs_container: builder.needs_container(),
+            path_items: String::new(),
+            headers: String::new(),
+            header_checks: vec![],
+            raw_body: None,
+            cookies: String::new(),
+            is_multipart: builder.struct_fields_iter().any(|f| f.needs_file),
+            form: String::new(),
+            query: String::new(),
+            multi_value_query: vec![],
+            query_fields: String::new(),
+        }
+    }
+}
+
+impl<'a, 'b> SendableCodegen<'a, 'b> {
+    /// Determine and write `Sendable` impl (if it's needed for this builder).
+    fn write_impl_if_needed<F>(mut self, f: &mut F) -> fmt::Result
+    where
+        F: Write,
+    {
+        let (path, method) = match (self.builder.rel_path, self.builder.method) {
+            (Some(p), Some(m)) => (p, m),
+            _ => return Ok(()),
+        };
+
+        f.write_str("\n")?;
+        // Overridden `send` methods need `async_trait` (and thread-safe builders).
+        let overrides_send = !self.builder.success.is_empty()
+            || self.builder.response.is_file()
+            || self.builder.response.multipart_mixed
+            || self.builder.response.is_optional();
+        if overrides_send {
             f.write_str("#[async_trait::async_trait]\n")?;
         }
 
@@ -714,6 +1451,9 @@ impl<'a, 'b> SendableCodegen<'a, 'b> {
 
         if self.builder.needs_any {
             f.write_str(", Any: serde::Serialize")?;
+            if overrides_send {
+                f.write_str(" + Send + Sync")?;
+            }
         }
 
         f.write_str("> ")?;
@@ -723,27 +1463,42 @@ impl<'a, 'b> SendableCodegen<'a, 'b> {
         self.builder
             .write_generics_if_necessary(f, None, TypeParameters::ChangeAll)?;
         f.write_str(" {\n    type Output = ")?;
-        let accepted_range = self.write_output_ty(f)?;
+        self.write_output_ty(f)?;
 
         f.write_str(";\n\n    const METHOD: http::Method = http::Method::")?;
         f.write_str(&method.to_string().to_uppercase())?;
-        f.write_str(";\n\n    fn rel_path(&self) -> std::borrow::Cow<'static, str> {\n        ")?;
+        if let Some(id) = self.builder.op_id {
+            write!(
+                f,
+                ";\n\n    const OPERATION_ID: Option<&'static str> = Some({:?})",
+                id
+            )?;
+        }
+
+        self.write_security(f)?;
+        write!(
+            f,
+            ";\n\n    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, {}client::ApiError<Client::Response>> {{\n        ",
+            self.builder.helper_module_prefix
+        )?;
 
         self.builder
             .struct_fields_iter()
             .for_each(|field| match field.param_loc {
                 Some(ParameterIn::Path) => self.handle_path_param(field),
                 Some(ParameterIn::Header) => self.handle_header_param(field),
+                Some(ParameterIn::Cookie) => self.handle_cookie_param(field),
                 Some(ParameterIn::FormData) => self.handle_form_param(field),
                 Some(ParameterIn::Query) => self.handle_query_param(field),
+                Some(ParameterIn::Body) => self.handle_body_param(field),
                 _ => (),
             });
 
         // Determine if we need a `&'static str` or `String`
         if self.path_items.is_empty() {
-            write!(f, "\"{}\".into()", path)?;
+            write!(f, "Ok(\"{}\".into())", path)?;
         } else {
-            write!(f, "format!(\"{}\"{}).into()", path, self.path_items)?;
+            write!(f, "Ok(format!(\"{}\"{}).into())", path, self.path_items)?;
         }
 
         f.write_str("\n    }")?;
@@ -754,16 +1509,278 @@ impl<'a, 'b> SendableCodegen<'a, 'b> {
             || !self.query.is_empty()
             || !self.multi_value_query.is_empty()
             || !self.headers.is_empty()
+            || !self.cookies.is_empty()
+            || self.raw_body.is_some()
+            || self.builder.timeout.is_some()
+            || self.builder.accept.is_some()
         {
-            self.write_modify_method(f, accepted_range)?;
+            self.write_modify_method(f)?;
         }
 
-        if self.builder.response.is_file() {
+        self.write_sets_header_method(f)?;
+
+        if !self.builder.success.is_empty() {
+            self.write_status_acceptor(f)?;
+        } else if self.builder.response.is_file() {
             self.write_file_acceptor(f)?;
+        } else if self.builder.response.multipart_mixed {
+            self.write_multipart_acceptor(f)?;
+        } else if self.builder.response.is_optional() {
+            self.write_optional_acceptor(f)?;
         }
 
         f.write_str("\n}\n")?;
-        self.write_response_headers_impl(f)
+        self.write_query_struct(path, method, f)?;
+        self.write_request_conversion(overrides_send, f)?;
+        self.write_response_headers_impl(f)?;
+        self.write_response_enum(f)?;
+        self.write_error_enum(f)
+    }
+
+    /// Writes the security requirements of this operation (if any).
+    fn write_security<F: Write>(&self, f: &mut F) -> fmt::Result {
+        if self
+            .builder
+            .security
+            .iter()
+            .all(|schemes| schemes.is_empty())
+        {
+            return Ok(());
+        }
+
+        f.write_str(";\n\n    const SECURITY: &'static [&'static [&'static str]] = &[")?;
+        for (i, schemes) in self.builder.security.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+
+            f.write_str("&[")?;
+            for (j, name) in schemes.iter().enumerate() {
+                if j > 0 {
+                    f.write_str(", ")?;
+                }
+
+                write!(f, "{:?}", name)?;
+            }
+
+            f.write_str("]")?;
+        }
+
+        f.write_str("]")
+    }
+
+    /// Writes the enum for 2xx responses (if the operation has different
+    /// schemas for them), which is the output of this builder.
+    fn write_response_enum<F: Write>(&self, f: &mut F) -> fmt::Result {
+        if self.builder.success.is_empty() {
+            return Ok(());
+        }
+
+        let mut name = String::new();
+        self.builder.write_response_name(&mut name)?;
+        f.write_str("\n/// Responses of [`")?;
+        self.builder.write_name(f)?;
+        write!(
+            f,
+            "`] (based on the status code).\n#[derive(Debug, Clone)]\npub enum {} {{",
+            name
+        )?;
+
+        for (code, ty) in self.builder.success {
+            write!(
+                f,
+                "\n    /// `{}` response.\n    {}",
+                code,
+                status_variant_name(code)
+            )?;
+            if let Some(ty) = ty {
+                write!(f, "({})", ty)?;
+            }
+
+            f.write_str(",")?;
+        }
+
+        write!(
+            f,
+            "
+}}
+
+impl<'de> serde::de::Deserialize<'de> for {name} {{
+    fn deserialize<D: serde::de::Deserializer<'de>>(_: D) -> Result<Self, D::Error> {{
+        Err(serde::de::Error::custom(\"{name} is decoded based on the response status\"))
+    }}
+}}
+",
+            name = name
+        )
+    }
+
+    /// Writes the enum for error responses (if any), along with its
+    /// `OperationError` impl for decoding failed responses based on status.
+    fn write_error_enum<F: Write>(&self, f: &mut F) -> fmt::Result {
+        if self.builder.errors.is_empty() {
+            return Ok(());
+        }
+
+        let prefix = self.builder.helper_module_prefix;
+        let mut name = String::new();
+        self.builder.write_error_name(&mut name)?;
+        f.write_str(
+            "
+/// Error responses of [`",
+        )?;
+        self.builder.write_name(f)?;
+        f.write_str(
+            "`] (based on the status code).
+#[derive(Debug)]
+pub enum ",
+        )?;
+        write!(f, "{}<R: {}client::Response + 'static> {{", name, prefix)?;
+
+        let mut has_default = false;
+        let mut arms = String::new();
+        for (code, ty) in self.builder.errors {
+            if code == "default" {
+                // Default responses without schemas are left to `Other`.
+                if let Some(ty) = ty {
+                    has_default = true;
+                    write!(
+                        f,
+                        "\n    /// Response for any other status code.\n    Default(http::StatusCode, {}),",
+                        ty
+                    )?;
+                }
+
+                continue;
+            }
+
+            let variant = status_variant_name(code);
+            write!(f, "\n    /// `{}` response.\n    {}", code, variant)?;
+            let _ = write!(arms, "\n            {} => ", code);
+            match ty {
+                Some(ty) => {
+                    write!(f, "({}),", ty)?;
+                    let _ = write!(
+                        arms,
+                        "match {}client::decode_body(resp).await {{
+                Ok(v) => {}::{}(v),
+                Err(e) => {}::Other(e),
+            }},",
+                        prefix, name, variant, name
+                    );
+                }
+                None => {
+                    f.write_str(",")?;
+                    let _ = write!(arms, "{}::{},", name, variant);
+                }
+            }
+        }
+
+        write!(
+            f,
+            "
+    /// Any other failure (including undeclared status codes).
+    Other({prefix}client::ApiError<R>),
+}}
+
+#[async_trait::async_trait]
+impl<R: {prefix}client::Response + 'static> {prefix}client::OperationError<R> for {name}<R> {{
+    async fn from_api_error(err: {prefix}client::ApiError<R>) -> Self {{
+        let ({path}, status, resp) = match err {{
+            {prefix}client::ApiError::Failure(p, s, r) => (p, s, r),
+            e => return {name}::Other(e),
+        }};
+
+        match status.as_u16() {{{arms}",
+            prefix = prefix,
+            name = name,
+            path = if has_default { "_" } else { "path" },
+            arms = arms,
+        )?;
+
+        if has_default {
+            write!(
+                f,
+                "
+            _ => match {prefix}client::decode_body(resp).await {{
+                Ok(v) => {name}::Default(status, v),
+                Err(e) => {name}::Other(e),
+            }},",
+                prefix = prefix,
+                name = name
+            )?;
+        } else {
+            write!(
+                f,
+                "
+            _ => {name}::Other({prefix}client::ApiError::Failure(path, status, resp)),",
+                prefix = prefix,
+                name = name
+            )?;
+        }
+
+        f.write_str("\n        }\n    }\n}\n")
+    }
+
+    /// Writes the struct for the query parameters collected in `handle_query_param`.
+    fn write_query_struct<F: Write>(
+        &self,
+        path: &str,
+        method: HttpMethod,
+        f: &mut F,
+    ) -> fmt::Result {
+        if self.query_fields.is_empty() {
+            return Ok(());
+        }
+
+        write!(
+            f,
+            "\n/// Query parameters of the `{}` operation on `{}`.\n#[derive(Debug, Serialize)]\npub struct ",
+            method.to_string().to_uppercase(),
+            path
+        )?;
+        self.builder.write_query_name(f)?;
+        f.write_str("<'a> {")?;
+        f.write_str(&self.query_fields)?;
+        f.write_str("\n}\n")
+    }
+
+    /// Writes the conversion from this (fully built) builder into a request for
+    /// the default client, so that it can be used with APIs accepting requests.
+    /// It's fallible, because it encodes the parameters and body.
+    fn write_request_conversion<F: Write>(&self, overrides_send: bool, f: &mut F) -> fmt::Result {
+        let mut builder_ty = String::new();
+        self.builder.write_name(&mut builder_ty)?;
+        self.builder.write_generics_if_necessary(
+            &mut builder_ty,
+            None,
+            TypeParameters::ChangeAll,
+        )?;
+
+        f.write_str("\nimpl")?;
+        if self.builder.needs_any {
+            f.write_str("<Any: serde::Serialize")?;
+            if overrides_send {
+                f.write_str(" + Send + Sync")?;
+            }
+
+            f.write_str(">")?;
+        }
+
+        write!(
+            f,
+            " std::convert::TryFrom<{builder}> for reqwest::Request {{
+    type Error = {prefix}client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: {builder}) -> Result<Self, Self::Error> {{
+        let req = {prefix}client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err({prefix}client::ApiError::Reqwest)
+    }}
+}}
+",
+            builder = builder_ty,
+            prefix = self.builder.helper_module_prefix
+        )
     }
 
     fn write_response_headers_impl<F: Write>(&mut self, f: &mut F) -> fmt::Result {
@@ -826,16 +1843,34 @@ impl<'a, 'b> SendableCodegen<'a, 'b> {
         f.write_str("\n}\n")
     }
 
-    /// Writes the output type for a `Sendable` implementor and returns
-    /// acceptable media range if it's "any" type.
-    fn write_output_ty<F>(&mut self, f: &mut F) -> Result<Option<String>, fmt::Error>
+    /// Writes the output type for a `Sendable` implementor.
+    fn write_output_ty<F>(&mut self, f: &mut F) -> fmt::Result
     where
         F: Write,
     {
+        let optional = self.builder.response.is_optional();
+        if optional {
+            f.write_str("Option<")?;
+        }
+
+        let multipart = self.builder.response.multipart_mixed && !self.builder.response.is_file();
+        if multipart {
+            write!(
+                f,
+                "{}util::MultipartMixed<",
+                self.builder.helper_module_prefix
+            )?;
+        }
+
         if self.builder.is_list_op {
             f.write_str("Vec<")?;
         }
 
+        if !self.builder.success.is_empty() {
+            self.builder.write_response_name(f)?;
+            return Ok(());
+        }
+
         if self.builder.response.is_file() {
             write!(f, "{prefix}util::ResponseStream<<<Client as {prefix}client::ApiClient>::Response as {prefix}client::Response>::Bytes, <<Client as {prefix}client::ApiClient>::Response as {prefix}client::Response>::Error>",
                    prefix=self.builder.helper_module_prefix)?;
@@ -846,17 +1881,15 @@ impl<'a, 'b> SendableCodegen<'a, 'b> {
 
         // If the type has `Any` or if we don't know what we're going to get, then
         // assume we have to write `Any` type.
-        let mut accepted_range = None;
         if self.builder.needs_any
             || self.builder.response.ty_path.is_none()
             || self.builder.response.contains_any
         {
-            let (range, coder) = match self.builder.decoding {
-                Some((r, c)) => (r.as_str(), c),
-                None => ((JSON_MIME).0.as_ref(), &*JSON_CODER),
+            let coder = match self.builder.decoding {
+                Some((_, c)) => c,
+                None => &*JSON_CODER,
             };
 
-            accepted_range = Some(range);
             if self.builder.response.ty_path.is_some() {
                 write!(f, "<{}>", coder.any_value)?;
             } else {
@@ -868,12 +1901,24 @@ impl<'a, 'b> SendableCodegen<'a, 'b> {
             f.write_str(">")?;
         }
 
-        Ok(accepted_range.map(|s| s.to_owned()))
+        if multipart {
+            f.write_str(">")?;
+        }
+
+        if optional {
+            f.write_str(">")?;
+        }
+
+        Ok(())
     }
 
     /// Handle field for a path parameter.
     fn handle_path_param(&mut self, field: StructField) {
-        let _ = write!(self.path_items, ", {}=self.", &field.name);
+        let _ = write!(
+            self.path_items,
+            ", {}={}client::path_param({:?}, self.",
+            &field.name, self.builder.helper_module_prefix, &field.name
+        );
         let name = object::to_snake_case(field.name);
         if self.needs_container {
             self.path_items.push_str("inner.");
@@ -881,7 +1926,7 @@ impl<'a, 'b> SendableCodegen<'a, 'b> {
 
         let _ = write!(
             self.path_items,
-            "param_{name}.as_ref().expect(\"missing parameter {name}?\")",
+            "param_{name}.as_ref().expect(\"missing parameter {name}?\"))?",
             name = name
         );
     }
@@ -902,10 +1947,19 @@ impl<'a, 'b> SendableCodegen<'a, 'b> {
             let _ = write!(param_ref, ".expect(\"missing parameter {}?\")", name);
         }
 
+        let mut check = format!("name.eq_ignore_ascii_case({:?})", field.name);
         if !is_required {
+            let _ = write!(
+                check,
+                " && self.{}param_{}.is_some()",
+                if self.needs_container { "inner." } else { "" },
+                name
+            );
             let _ = write!(self.headers, "\n        if let Some(v) = {} {{", param_ref);
         }
 
+        self.header_checks.push(check);
+
         self.headers.push_str("\n        ");
         if !is_required {
             self.headers.push_str("    ");
@@ -923,6 +1977,59 @@ impl<'a, 'b> SendableCodegen<'a, 'b> {
         }
     }
 
+    /// Handle field for a string (or binary) parameter, which is sent as the body.
+    /// The body is empty (with `Content-Length: 0`) if it's optional and unset.
+    fn handle_body_param(&mut self, field: StructField) {
+        let name = object::to_snake_case(field.name);
+        let mut body = format!(
+            "self.{}param_{}.clone().map(Vec::from)",
+            if self.needs_container { "inner." } else { "" },
+            name
+        );
+
+        if field.prop.is_required() {
+            let _ = write!(body, ".expect(\"missing parameter {}?\")", name);
+        } else {
+            body.push_str(".unwrap_or_default()");
+        }
+
+        self.raw_body = Some(body);
+    }
+
+    /// Handle field for a cookie parameter. All cookies are sent (as `name=value`
+    /// pairs) in a single `Cookie` header.
+    fn handle_cookie_param(&mut self, field: StructField) {
+        let name = object::to_snake_case(field.name);
+        let param_ref = format!(
+            "self.{}param_{}.as_ref()",
+            if self.needs_container { "inner." } else { "" },
+            name
+        );
+
+        if field.prop.is_required() {
+            let _ = write!(
+                self.cookies,
+                "
+        cookies.push({prefix}client::cookie_pair({name:?}, {param}.expect(\"missing parameter {snake}?\")));",
+                prefix = self.builder.helper_module_prefix,
+                name = field.name,
+                param = param_ref,
+                snake = name,
+            );
+        } else {
+            let _ = write!(
+                self.cookies,
+                "
+        if let Some(v) = {param} {{
+            cookies.push({prefix}client::cookie_pair({name:?}, v));
+        }}",
+                prefix = self.builder.helper_module_prefix,
+                name = field.name,
+                param = param_ref,
+            );
+        }
+    }
+
     /// Handle field for a form data parameter.
     fn handle_form_param(&mut self, field: StructField) {
         let name = object::to_snake_case(field.name);
@@ -954,7 +2061,7 @@ impl<'a, 'b> SendableCodegen<'a, 'b> {
                 self.form,
                 "
             if let Some(v) = self.{}param_{}.as_ref() {{
-                form = form.file({:?}, v)?;
+                form = form.file_part({:?}, v)?;
             }}",
                 if self.needs_container { "inner." } else { "" },
                 name,
@@ -988,6 +2095,19 @@ impl<'a, 'b> SendableCodegen<'a, 'b> {
     /// Handle field for an URL query parameter.
     fn handle_query_param(&mut self, field: StructField) {
         let name = object::to_snake_case(field.name);
+        if field.deep_object {
+            self.multi_value_query.push(format!(
+                "
+            &self.{}param_{}.as_ref().map(|v| {}client::deep_object_query({:?}, v)).unwrap_or_default()",
+                if self.needs_container { "inner." } else { "" },
+                name,
+                self.builder.helper_module_prefix,
+                &field.name,
+            ));
+
+            return;
+        }
+
         if let Some(CollectionFormat::Multi) = field.delimiting.first() {
             self.multi_value_query.push(format!(
                 "
@@ -1002,6 +2122,36 @@ impl<'a, 'b> SendableCodegen<'a, 'b> {
             return;
         }
 
+        if self.builder.query_struct {
+            let ident = object::field_ident(field.name);
+            self.query_fields.push_str("\n    #[serde(");
+            if ident != field.name {
+                let _ = write!(self.query_fields, "rename = {:?}, ", field.name);
+            }
+
+            let _ = write!(
+                self.query_fields,
+                "skip_serializing_if = \"Option::is_none\")]\n    pub {}: Option<&'a ",
+                ident
+            );
+            let _ = ApiObjectBuilder::write_wrapped_ty(
+                self.builder.helper_module_prefix,
+                field.ty,
+                field.delimiting,
+                &mut self.query_fields,
+            );
+            self.query_fields.push_str(">,");
+            let _ = write!(
+                self.query,
+                "\n            {}: self.{}param_{}.as_ref(),",
+                ident,
+                if self.needs_container { "inner." } else { "" },
+                name
+            );
+
+            return;
+        }
+
         if !self.query.is_empty() {
             self.query.push(',');
         }
@@ -1018,8 +2168,49 @@ impl<'a, 'b> SendableCodegen<'a, 'b> {
         );
     }
 
+    /// Writes the `sets_header` method (if this operation sets any headers
+    /// of its own), so that the client's default headers don't override them.
+    fn write_sets_header_method<F>(&mut self, f: &mut F) -> fmt::Result
+    where
+        F: Write,
+    {
+        if self.content_type().is_some() || self.is_multipart {
+            self.header_checks
+                .push("name.eq_ignore_ascii_case(\"Content-Type\")".into());
+        }
+
+        if self.builder.accept.is_some() {
+            self.header_checks
+                .push("name.eq_ignore_ascii_case(\"Accept\")".into());
+        }
+
+        if !self.cookies.is_empty() {
+            self.header_checks
+                .push("name.eq_ignore_ascii_case(\"Cookie\")".into());
+        }
+
+        if self.header_checks.is_empty() {
+            return Ok(());
+        }
+
+        f.write_str("\n\n    fn sets_header(&self, name: &str) -> bool {\n        ")?;
+        for (i, check) in self.header_checks.iter().enumerate() {
+            if i > 0 {
+                f.write_str("\n            || ")?;
+            }
+
+            if self.header_checks.len() > 1 && check.contains(" && ") {
+                write!(f, "({})", check)?;
+            } else {
+                f.write_str(check)?;
+            }
+        }
+
+        f.write_str("\n    }")
+    }
+
     /// We have determined that we have to override the default `modify` method.
-    fn write_modify_method<F>(&mut self, f: &mut F, accepted_range: Option<String>) -> fmt::Result
+    fn write_modify_method<F>(&mut self, f: &mut F) -> fmt::Result
     where
         F: Write,
     {
@@ -1030,24 +2221,44 @@ impl<'a, 'b> SendableCodegen<'a, 'b> {
         f.write_str(self.builder.helper_module_prefix)?;
         f.write_str("client::Request;")?;
 
-        if !self.headers.is_empty() {
+        if !self.headers.is_empty() || !self.cookies.is_empty() {
             f.write_str("\n        let mut req = req;")?;
             f.write_str(&self.headers)?;
+            if !self.cookies.is_empty() {
+                f.write_str("\n        let mut cookies = vec![];")?;
+                f.write_str(&self.cookies)?;
+                f.write_str(
+                    "
+        if !cookies.is_empty() {
+            req = req.header(\"Cookie\", &cookies.join(\"; \"));
+        }",
+                )?;
+            }
+
             f.write_str("\n")?;
         }
 
         f.write_str("\n        Ok(req")?;
-        if self.builder.body_required {
-            f.write_str("\n        ")?;
-            if let Some((range, coder)) = self.builder.encoding {
-                write!(
-                    f,
-                    ".header(http::header::CONTENT_TYPE.as_str(), {:?})",
-                    range
-                )?;
+        if let Some(ty) = self.content_type() {
+            write!(
+                f,
+                "\n        .header(http::header::CONTENT_TYPE.as_str(), {:?})",
+                ty
+            )?;
+        }
+
+        if self.builder.body_required && self.builder.form_body {
+            f.write_str("\n        .form(&self.")?;
+            if self.needs_container {
+                f.write_str("inner.")?;
+            }
 
+            f.write_str("body)")?;
+        } else if self.builder.body_required {
+            f.write_str("\n        ")?;
+            if let Some((_, coder)) = self.builder.encoding {
                 f.write_str(
-                    "\n        .body_bytes({
+                    ".body_bytes({
             let mut vec = vec![];
             ",
                 )?;
@@ -1067,9 +2278,11 @@ impl<'a, 'b> SendableCodegen<'a, 'b> {
             if self.builder.encoding.is_some() {
                 f.write_str("?;\n            vec\n        })")?;
             }
+        } else if let Some(body) = &self.raw_body {
+            write!(f, "\n        .body_bytes({})", body)?;
         }
 
-        if let Some(r) = accepted_range {
+        if let Some(r) = self.builder.accept {
             write!(
                 f,
                 "\n        .header(http::header::ACCEPT.as_str(), {:?})",
@@ -1103,13 +2316,15 @@ impl<'a, 'b> SendableCodegen<'a, 'b> {
                 "
             ser.finish().into_bytes()\n        })",
             )?;
-            f.write_str(
-                "
-        .header(http::header::CONTENT_TYPE.as_str(), \"application/x-www-form-urlencoded\")",
-            )?;
         }
 
-        if !self.query.is_empty() {
+        if !self.query_fields.is_empty() {
+            f.write_str("\n        .query(&")?;
+            self.builder.write_query_name(f)?;
+            f.write_str(" {")?;
+            f.write_str(&self.query)?;
+            f.write_str("\n        })")?;
+        } else if !self.query.is_empty() {
             f.write_str("\n        .query(&[")?;
             f.write_str(&self.query)?;
             f.write_str("\n        ])")?;
@@ -1121,9 +2336,90 @@ impl<'a, 'b> SendableCodegen<'a, 'b> {
             f.write_str("\n        })")?;
         }
 
+        if let Some(ms) = self.builder.timeout {
+            write!(
+                f,
+                "\n        .timeout(std::time::Duration::from_millis({}))",
+                ms
+            )?;
+        }
+
         f.write_str(")\n    }")
     }
 
+    /// Returns the `Content-Type` for the request body (if any). This is left
+    /// to the client for multipart forms, as it carries the boundary.
+    fn content_type(&self) -> Option<&'b str> {
+        if self.raw_body.is_some() {
+            return self.builder.raw_body;
+        }
+
+        if self.builder.body_required {
+            return Some(if self.builder.form_body {
+                FORM_URLENCODED
+            } else {
+                match self.builder.encoding {
+                    Some((range, _)) => range,
+                    None => JSON_MIME.0.as_ref(),
+                }
+            });
+        }
+
+        if self.form.is_empty() || self.is_multipart {
+            None
+        } else {
+            Some(FORM_URLENCODED)
+        }
+    }
+
+    /// Writes async `send` method for this operation assuming that the response is
+    /// one of the variants (based on status code) in the 2xx response enum.
+    fn write_status_acceptor<F>(&self, f: &mut F) -> fmt::Result
+    where
+        F: Write,
+    {
+        let prefix = self.builder.helper_module_prefix;
+        let mut name = String::new();
+        self.builder.write_response_name(&mut name)?;
+        write!(
+            f,
+            "
+
+    async fn send(&self, client: &Client) -> Result<{prefix}client::ResponseWrapper<Self::Output, Self>, {prefix}client::ApiError<Client::Response>> {{
+        use {prefix}client::Response;
+        let resp = self.send_raw(client).await?;
+        Ok(match resp.status().as_u16() {{",
+            prefix = prefix
+        )?;
+
+        // Undeclared 2xx codes are assumed to be the first (lowest) one.
+        let (first, rest) = self.builder.success.split_first().expect("2xx responses");
+        for (code, ty) in rest.iter().chain(iter::once(first)) {
+            if code == &first.0 {
+                f.write_str("\n            _ => ")?;
+            } else {
+                write!(f, "\n            {} => ", code)?;
+            }
+
+            let variant = status_variant_name(code);
+            if let Some(ty) = ty {
+                write!(
+                    f,
+                    "{}client::ResponseWrapper::<{}, Self>::decode(resp).await?.map({}::{}),",
+                    prefix, ty, name, variant
+                )?;
+            } else {
+                write!(
+                    f,
+                    "{}client::ResponseWrapper::wrap(resp, |_| async {{ Ok({}::{}) }}).await?,",
+                    prefix, name, variant
+                )?;
+            }
+        }
+
+        f.write_str("\n        })\n    }")
+    }
+
     /// Writes async `send` method for this operation assuming that the response is a file.
     fn write_file_acceptor<F>(&self, f: &mut F) -> fmt::Result
     where
@@ -1143,6 +2439,49 @@ impl<'a, 'b> SendableCodegen<'a, 'b> {
             prefix = self.builder.helper_module_prefix
         )
     }
+
+    /// Writes async `send` method for this operation assuming that the response
+    /// is `multipart/mixed` (with the first part holding the JSON object).
+    fn write_multipart_acceptor<F>(&self, f: &mut F) -> fmt::Result
+    where
+        F: Write,
+    {
+        write!(
+            f,
+            "
+
+    async fn send(&self, client: &Client) -> Result<{prefix}client::ResponseWrapper<Self::Output, Self>, {prefix}client::ApiError<Client::Response>> {{
+        use {prefix}client::Response;
+        let resp = self.send_raw(client).await?;
+        let boundary = resp.header(\"content-type\").and_then({prefix}util::multipart_boundary).map(String::from);
+        {prefix}client::ResponseWrapper::wrap(resp, |r| {prefix}client::multipart_mixed(boundary, r)).await
+    }}",
+            prefix = self.builder.helper_module_prefix
+        )
+    }
+
+    /// Writes async `send` method for this operation assuming that the response
+    /// body is absent when the server responds with `204 No Content`.
+    fn write_optional_acceptor<F>(&self, f: &mut F) -> fmt::Result
+    where
+        F: Write,
+    {
+        write!(
+            f,
+            "
+
+    async fn send(&self, client: &Client) -> Result<{prefix}client::ResponseWrapper<Self::Output, Self>, {prefix}client::ApiError<Client::Response>> {{
+        use {prefix}client::Response;
+        let resp = self.send_raw(client).await?;
+        if resp.status() == http::StatusCode::NO_CONTENT {{
+            return {prefix}client::ResponseWrapper::wrap(resp, |_| async {{ Ok(None) }}).await;
+        }}
+
+        {prefix}client::ResponseWrapper::decode(resp).await
+    }}",
+            prefix = self.builder.helper_module_prefix
+        )
+    }
 }
 
 impl<'a> Display for ApiObjectImpl<'a> {
@@ -1168,6 +2507,11 @@ impl<'a> Display for ApiObjectImpl<'a> {
 
         f.write_str(" {")?;
         self.write_builder_methods(f)?;
+        self.write_to_builder_method(f)?;
+        self.write_example_method(f)?;
+        self.write_merge_method(f)?;
+        self.write_pattern_insert_method(f)?;
+        self.write_validate_method(f)?;
         f.write_str("}\n")?;
 
         for builder in &*self.builders {
@@ -1182,12 +2526,15 @@ impl<'a, 'b> Display for ApiObjectBuilderImpl<'a, 'b> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let mut generics = String::new();
         self.0
-            .write_generics_if_necessary(&mut generics, None, TypeParameters::Generic)?;
+            .write_struct_generics(&mut generics, TypeParameters::Generic)?;
 
+        // Property methods of shared structs are written only once.
+        let writes_struct = !matches!(&self.0.shared, Some(s) if !s.owner);
         let mut has_fields = false;
         self.0
             .struct_fields_iter()
             .filter(|f| (self.0.body_required && f.prop.is_field()) || f.prop.is_parameter())
+            .filter(|_| writes_struct)
             .enumerate()
             .try_for_each(|(i, field)| {
                 if i == 0 {
@@ -1195,7 +2542,7 @@ impl<'a, 'b> Display for ApiObjectBuilderImpl<'a, 'b> {
                     f.write_str("impl")?;
                     f.write_str(&generics)?;
                     f.write_str(" ")?;
-                    self.0.write_name(f)?;
+                    self.0.write_struct_name(f)?;
                     f.write_str(&generics)?;
                     f.write_str(" {")?;
                 }
@@ -1210,3 +2557,112 @@ impl<'a, 'b> Display for ApiObjectBuilderImpl<'a, 'b> {
         SendableCodegen::from(self.0).write_impl_if_needed(f)
     }
 }
+
+/// Returns the name of the enum variant for the given status code
+/// (based on its canonical reason, if any).
+fn status_variant_name(code: &str) -> String {
+    code.parse()
+        .ok()
+        .and_then(|c| http::StatusCode::from_u16(c).ok())
+        .and_then(|s| s.canonical_reason())
+        .map(object::to_pascal_case)
+        .unwrap_or_else(|| format!("Status{}", code))
+}
+
+/// Returns the comparison (operator, literal and reason) failing the values of
+/// the given field past the given bound (`minimum` if `lower`, `maximum` otherwise).
+/// Integer bounds are written as integer literals (rounded towards the allowed
+/// values if they're fractional), so that they're exact.
+fn bound_check(
+    field: &ObjectField,
+    bound: &serde_json::Number,
+    exclusive: bool,
+    lower: bool,
+) -> Option<(&'static str, String, String)> {
+    let (op, reason) = match (lower, exclusive) {
+        (true, true) => ("<=", "must be greater than"),
+        (true, false) => ("<", "must be at least"),
+        (false, true) => (">=", "must be less than"),
+        (false, false) => (">", "must be at most"),
+    };
+
+    if field.ty_path == "f32" || field.ty_path == "f64" {
+        let max = if field.ty_path == "f32" {
+            f64::from(f32::MAX)
+        } else {
+            f64::MAX
+        };
+        if !edge_in_range(field, bound, bound.as_f64()?, -max, max, lower) {
+            return None;
+        }
+
+        let lit = format!("{}_{}", bound, field.ty_path);
+        return Some((op, lit, format!("{} {}", reason, bound)));
+    }
+
+    let (min, max): (i128, i128) = match field.ty_path.as_str() {
+        "i32" => (i32::MIN.into(), i32::MAX.into()),
+        "i64" => (i64::MIN.into(), i64::MAX.into()),
+        "std::num::NonZeroU32" => (1, u32::MAX.into()),
+        "std::num::NonZeroU64" => (1, u64::MAX.into()),
+        _ => return None,
+    };
+
+    let exact = bound
+        .as_i64()
+        .map(i128::from)
+        .or_else(|| bound.as_u64().map(i128::from))
+        .or_else(|| {
+            bound
+                .as_f64()
+                .filter(|f| f.fract() == 0.0)
+                .map(|f| f as i128)
+        });
+    // Check for the bound along with the first (or the last) allowed value.
+    let (op, n, reason, edge) = match (exact, lower) {
+        (Some(n), true) if exclusive => (op, n, reason, n + 1),
+        (Some(n), false) if exclusive => (op, n, reason, n - 1),
+        (Some(n), _) => (op, n, reason, n),
+        (None, true) => {
+            let n = bound.as_f64()?.ceil() as i128;
+            ("<", n, "must be at least", n)
+        }
+        (None, false) => {
+            let n = bound.as_f64()?.floor() as i128;
+            (">", n, "must be at most", n)
+        }
+    };
+
+    if !edge_in_range(field, bound, edge, min, max, lower) {
+        return None;
+    }
+
+    Some((op, n.to_string(), format!("{} {}", reason, n)))
+}
+
+/// Checks whether the first (or the last, unless `lower`) value allowed by a bound
+/// is within the range of the field's type. Bounds allowing all the values don't
+/// need checking, whereas bounds allowing none of them are ignored (with a warning).
+fn edge_in_range<T: PartialOrd>(
+    field: &ObjectField,
+    bound: &serde_json::Number,
+    edge: T,
+    min: T,
+    max: T,
+    lower: bool,
+) -> bool {
+    let (all, none) = if lower {
+        (edge <= min, edge > max)
+    } else {
+        (edge >= max, edge < min)
+    };
+
+    if none {
+        warn!(
+            "Bound {} of field {:?} can't be satisfied by {}, ignoring it.",
+            bound, field.name, field.ty_path
+        );
+    }
+
+    !(all || none)
+}
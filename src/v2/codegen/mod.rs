@@ -83,3 +83,93 @@ impl Default for EmitMode {
         EmitMode::Module
     }
 }
+
+/// What to do with definitions which aren't used by any operation (i.e., they're
+/// not reachable from operations through their parameters, responses and fields).
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
+pub enum UnusedDefinitions {
+    /// Emit them anyway.
+    #[default]
+    Keep,
+    /// Emit them, but log a warning listing them.
+    Warn,
+    /// Log a warning listing them and don't emit them.
+    Omit,
+}
+
+/// How to deserialize required fields which are nullable (`x-nullable`) when
+/// they're absent altogether (as opposed to being present with `null`).
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
+pub enum AbsentNullable {
+    /// Fail, because required fields must be present.
+    #[default]
+    Strict,
+    /// Treat them as `null` (for servers which drop nulls).
+    Tolerant,
+}
+
+/// How multi-word names (from the spec) are converted into Rust identifiers.
+/// This only matters for type names (including generic parameters), because
+/// functions and fields are always snake_cased.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
+pub enum CaseStrategy {
+    /// Every word is capitalized (e.g., `APIKey` becomes `ApiKey`).
+    #[default]
+    Standard,
+    /// Words which are uppercase in the spec are kept as they are
+    /// (e.g., `APIKey` stays `APIKey` and `userID` becomes `UserID`).
+    PreserveAcronyms,
+}
+
+/// Collection used for maps (`additionalProperties` and `patternProperties`).
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
+pub enum MapType {
+    /// `std::collections::BTreeMap` (ordered by keys).
+    #[default]
+    BTreeMap,
+    /// `std::collections::HashMap` (unordered).
+    HashMap,
+    /// `indexmap::IndexMap` (in the order of insertion, so keys are serialized
+    /// in the order they were deserialized). The generated manifest (if any)
+    /// then includes `indexmap`.
+    IndexMap,
+}
+
+impl MapType {
+    /// Path of this map type in the generated code.
+    pub fn path(self) -> &'static str {
+        match self {
+            MapType::BTreeMap => "std::collections::BTreeMap",
+            MapType::HashMap => "std::collections::HashMap",
+            MapType::IndexMap => "indexmap::IndexMap",
+        }
+    }
+}
+
+/// Which of the generated types are marked `#[non_exhaustive]`, so that
+/// variants (or fields) added to the spec later aren't breaking changes for
+/// the crates using them. In turn, those crates must match with a wildcard
+/// arm (`_`), destructure with `..` and can't build structs with literals
+/// (only through builders and `Default`).
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
+pub enum NonExhaustive {
+    /// Nothing is marked.
+    #[default]
+    None,
+    /// Enums (including unions) are marked.
+    Enums,
+    /// Enums and structs are marked.
+    All,
+}
+
+impl NonExhaustive {
+    /// Whether enums are marked.
+    pub fn enums(self) -> bool {
+        self != NonExhaustive::None
+    }
+
+    /// Whether structs are marked.
+    pub fn structs(self) -> bool {
+        self == NonExhaustive::All
+    }
+}
@@ -6,26 +6,42 @@
 pub use super::impls::{ApiObjectBuilderImpl, ApiObjectImpl};
 
 use super::{
-    emitter::{ANY_GENERIC_PARAMETER, EXTRA_PROPS_FIELD, FILE_MARKER},
-    RUST_KEYWORDS,
+    emitter::{ANY_GENERIC_PARAMETER, BYTES_TYPE, EXTRA_PROPS_FIELD, FILE_MARKER, FILE_PART_TY},
+    AbsentNullable, CaseStrategy, RUST_KEYWORDS,
 };
-use crate::v2::models::{Coder, CollectionFormat, HttpMethod, ParameterIn};
+use crate::v2::models::{Coder, CollectionFormat, ExternalDocs, HttpMethod, ParameterIn};
 use heck::{ToPascalCase, ToSnakeCase};
 use once_cell::sync::Lazy;
-use regex::{Captures, Regex};
+use regex::Regex;
 
 use std::{
+    borrow::Cow,
+    cell::Cell,
     collections::{BTreeMap, HashSet},
     fmt::{self, Display, Write},
     sync::Arc,
 };
 
-/// Regex for appropriate escaping in docs.
-static DOC_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"\[|\]").expect("invalid doc regex?"));
+/// Regex for checking whether a line of docs may need escaping.
+static DOC_REGEX: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"[\[\]*_<`]").expect("invalid doc regex?"));
 
 /// Regex for renaming properties with leading @
 static AT_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"^@").expect("invalid at regex?"));
 
+thread_local! {
+    /// Case strategy of the emitter which is generating code in this thread.
+    static CASE_STRATEGY: Cell<CaseStrategy> = const { Cell::new(CaseStrategy::Standard) };
+}
+
+/// Serde `rename_all` rules which we can use in place of per-field renames.
+const RENAME_RULES: &[&str] = &[
+    "camelCase",
+    "PascalCase",
+    "kebab-case",
+    "SCREAMING_SNAKE_CASE",
+];
+
 /// Represents a (simplified) Rust struct or enum.
 #[derive(Default, Debug, Clone)]
 pub struct ApiObject {
@@ -33,12 +49,31 @@ pub struct ApiObject {
     pub name: String,
     /// Description for this object (if any), to be used for docs.
     pub description: Option<String>,
+    /// Whether this object is deprecated.
+    pub deprecated: bool,
+    /// External docs for this object (if any), linked in its docs.
+    pub external_docs: Option<ExternalDocs>,
     /// Path to this object from (generated) root module.
     pub path: String,
     /// Fields/variants based on whether this is a struct/enum.
     pub inner: ObjectContainer,
     /// Paths with operations which address this object.
     pub paths: BTreeMap<String, PathOps>,
+    /// Serde `rename_all` rule for the fields of this struct (if any), in
+    /// which case the fields following the rule aren't renamed individually.
+    pub rename_all: Option<&'static str>,
+    /// Example (from the schema) for this object, if any. This is shown in
+    /// the docs of the object.
+    pub example: Option<serde_json::Value>,
+    /// Whether to generate `fn example()` for constructing the example object.
+    pub example_method: bool,
+    /// Whether to generate `merge` for combining partial objects.
+    pub merge: bool,
+    /// Whether this enum (or struct) is marked `#[non_exhaustive]`.
+    pub non_exhaustive: bool,
+    /// Type of the (flattened) map which collects all the keys, if this is
+    /// a free-form object (i.e., it doesn't declare any properties).
+    pub catch_all: Option<String>,
 }
 
 impl ApiObject {
@@ -91,11 +126,28 @@ pub enum ObjectContainer {
         /// When that's the case, we leave de/serialization to
         /// serde completely.
         is_string: bool,
+        /// Whether strings which don't match any variant are kept in an
+        /// `Unknown` variant instead of failing deserialization (only
+        /// applicable for string enums).
+        fallback: bool,
     },
     Struct {
         /// Fields if it's a struct.
         fields: Vec<ObjectField>,
     },
+    Union {
+        /// Name of the property holding the tag, or `None` if the union
+        /// is untagged (i.e., variants are tried in order).
+        tag: Option<String>,
+        /// Name of the property holding the actual object (if it's
+        /// wrapped instead of being alongside the tag).
+        content: Option<String>,
+        /// Variants of this union.
+        variants: Vec<UnionVariant>,
+        /// Whether objects with unknown tags are kept (as JSON values) in an
+        /// `Unknown` variant instead of failing deserialization.
+        fallback: bool,
+    },
 }
 
 impl ObjectContainer {
@@ -104,6 +156,11 @@ impl ObjectContainer {
         matches!(self, ObjectContainer::Enum { .. })
     }
 
+    /// Returns whether this object is a struct.
+    pub fn is_struct(&self) -> bool {
+        matches!(self, ObjectContainer::Struct { .. })
+    }
+
     /// Returns whether this enum is to represent a string.
     pub fn is_string_enum(&self) -> bool {
         match self {
@@ -111,6 +168,14 @@ impl ObjectContainer {
             _ => false,
         }
     }
+
+    /// Returns whether this enum has an `Unknown(String)` variant.
+    pub fn has_enum_fallback(&self) -> bool {
+        match self {
+            ObjectContainer::Enum { fallback, .. } => *fallback,
+            _ => false,
+        }
+    }
 }
 
 /// Operations in a path.
@@ -134,6 +199,17 @@ pub struct OpRequirement {
     pub description: Option<String>,
     /// Whether the operation is deprecated or not.
     pub deprecated: bool,
+    /// External docs for this operation (if any), linked in the docs of its
+    /// builder constructor.
+    pub external_docs: Option<ExternalDocs>,
+    /// Visibility of the builder constructor, if it's not `pub`.
+    pub visibility: Option<String>,
+    /// Default timeout (in milliseconds) for this operation's requests, if any.
+    pub timeout: Option<u64>,
+    /// Whether the query parameters are sent through a generated struct.
+    pub query_struct: bool,
+    /// Tags for this operation (used for grouping operations into traits).
+    pub tags: Vec<String>,
     /// Parameters required for this operation.
     pub params: Vec<Parameter>,
     /// Whether the object itself is required (in body) for this operation.
@@ -142,14 +218,33 @@ pub struct OpRequirement {
     pub listable: bool,
     /// Response information for this operation.
     pub response: Response<String, Vec<Parameter>>,
+    /// Error (4xx, 5xx and `default`) responses for this operation, mapping status
+    /// codes to their types (if they have schemas). This is empty if none of the
+    /// error responses have schemas.
+    pub errors: Vec<(String, Option<String>)>,
+    /// 2xx responses for this operation, mapping status codes to their types
+    /// (if they have schemas). This is empty unless the operation has multiple
+    /// 2xx responses with different schemas (which are then mapped to an enum).
+    pub success: Vec<(String, Option<String>)>,
+    /// Security requirements for this operation - alternatives, each listing the
+    /// names of the schemes which must be satisfied together.
+    pub security: Vec<Vec<String>>,
     /// Preferred media range and encoder for the client. This is ignored for
     /// methods that don't accept a body. If there's no coder, then JSON
     /// encoding is assumed.
     pub encoding: Option<(String, Arc<Coder>)>,
-    /// Preferred media range and decoder for the client. This is used only
-    /// when objects make use of `Any` type. If there's no coder, then JSON
-    /// encoding is assumed.
+    /// Whether the body is sent as `application/x-www-form-urlencoded` (i.e., the
+    /// operation doesn't consume anything we have a coder for).
+    pub form_body: bool,
+    /// Preferred media range and decoder for the client. If there's no coder,
+    /// then JSON encoding is assumed.
     pub decoding: Option<(String, Arc<Coder>)>,
+    /// Value of the `Accept` header sent with this operation (if any), which is
+    /// based on the media ranges it produces.
+    pub accept: Option<String>,
+    /// Media type of the body, if it's a string (or binary) parameter which
+    /// is sent as it is (rather than an object).
+    pub raw_body: Option<String>,
 }
 
 #[derive(Default, Debug, Clone)]
@@ -162,6 +257,11 @@ pub struct Response<S, H> {
     pub contains_any: bool,
     /// Custom response headers for this operation (if any).
     pub headers: H,
+    /// Whether the response is `multipart/mixed`.
+    pub multipart_mixed: bool,
+    /// Whether the operation accepts `Prefer: return=...` header, in which case
+    /// the server may respond without a body (`204 No Content`).
+    pub prefer_return: bool,
 }
 
 impl<S, H> Response<S, H>
@@ -175,6 +275,12 @@ where
             .map(|s| s.as_ref() == FILE_MARKER)
             .unwrap_or_default()
     }
+
+    /// Returns whether the response object is optional (i.e., the server may
+    /// have honored `Prefer: return=minimal` and responded without a body).
+    pub fn is_optional(&self) -> bool {
+        self.prefer_return && !self.multipart_mixed && !self.is_file()
+    }
 }
 
 /// Represents some parameter somewhere (header, path, query, etc.).
@@ -192,6 +298,42 @@ pub struct Parameter {
     pub presence: ParameterIn,
     /// If the parameter is an array of values, then the format for collecting them.
     pub delimiting: Vec<CollectionFormat>,
+    /// Allowed values of this parameter, if it's a header (or path parameter)
+    /// constrained by an `enum` (in which case `ty_path` is the name of the enum
+    /// emitted for it).
+    pub variants: Vec<ObjectVariant>,
+    /// Whether this is an object sent as a `deepObject` query parameter.
+    pub deep_object: bool,
+}
+
+impl Parameter {
+    /// Writes the enum for this parameter (if its values are constrained).
+    pub(super) fn write_enum<F>(&self, f: &mut F) -> fmt::Result
+    where
+        F: Write,
+    {
+        if self.variants.is_empty() {
+            return Ok(());
+        }
+
+        let mut obj = ApiObject::with_name(&self.ty_path);
+        obj.description = Some(format!(
+            "Allowed values for the `{}` {}.",
+            self.name,
+            if self.presence == ParameterIn::Path {
+                "path parameter"
+            } else {
+                "header"
+            }
+        ));
+        obj.inner = ObjectContainer::Enum {
+            variants: self.variants.clone(),
+            is_string: true,
+            fallback: false,
+        };
+
+        obj.write_param_enum(f)
+    }
 }
 
 /// Represents an enum variant.
@@ -204,8 +346,23 @@ pub struct ObjectVariant {
     pub value: serde_json::Value,
 }
 
-/// Represents a struct field.
+/// Represents a variant of a tagged union.
 #[derive(Debug, Clone)]
+pub struct UnionVariant {
+    /// Name of the variant (case unspecified).
+    pub name: String,
+    /// Value of the tag for this variant (unused in untagged unions).
+    pub tag: String,
+    /// Other values of the tag which are also accepted for this variant.
+    pub aliases: Vec<String>,
+    /// Type of the object in this variant as a path.
+    pub ty_path: String,
+    /// Whether this variant's type "is" or "has" an `Any` type.
+    pub needs_any: bool,
+}
+
+/// Represents a struct field.
+#[derive(Debug, Default, Clone)]
 pub struct ObjectField {
     /// Name of the field.
     pub name: String,
@@ -230,6 +387,75 @@ pub struct ObjectField {
     ///
     /// Yours sincerely.
     pub child_req_fields: Vec<String>,
+    /// Whether this field is read-only (i.e., it's never sent in requests).
+    pub read_only: bool,
+    /// Whether this field is write-only (i.e., it's absent in responses).
+    pub write_only: bool,
+    /// Whether this field is deprecated.
+    pub deprecated: bool,
+    /// Example value of this field from the schema (if any), shown in its docs.
+    pub example: Option<serde_json::Value>,
+    /// Whether this field can be `null` (regardless of whether it's required).
+    pub nullable: bool,
+    /// How to deserialize this field when it's absent, if it's required but
+    /// nullable (in which case it's optional in Rust).
+    pub absent_nullable: Option<AbsentNullable>,
+    /// Module for (de)serializing this field with `#[serde(with = "...")]`, if any.
+    pub serde_with: Option<String>,
+    /// Patterns allowed for the keys of this (map) field, if it collects
+    /// the pattern properties of the object.
+    pub key_patterns: Vec<String>,
+    /// Default value of this field from the schema (if any), used in the
+    /// `Default` impl of the object.
+    pub default: Option<serde_json::Value>,
+    /// Name of the variant for the default value, if this field is an enum.
+    pub default_variant: Option<String>,
+    /// Bounds for the values of this field, if it's numeric.
+    pub bounds: NumericBounds,
+    /// Regex which the values of this field must match, if it's a string.
+    pub pattern: Option<String>,
+}
+
+/// Bounds for numeric values (`minimum`, `maximum`, etc.) in a schema. These
+/// are kept as they are in the spec, so that integer bounds stay exact.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct NumericBounds {
+    pub minimum: Option<serde_json::Number>,
+    pub exclusive_minimum: bool,
+    pub maximum: Option<serde_json::Number>,
+    pub exclusive_maximum: bool,
+}
+
+impl NumericBounds {
+    /// Whether there are any bounds at all.
+    pub fn is_empty(&self) -> bool {
+        self.minimum.is_none() && self.maximum.is_none()
+    }
+}
+
+impl ObjectField {
+    /// Returns the Rust expression for the default value of this field (if any).
+    fn default_expr(&self) -> Option<String> {
+        use serde_json::Value;
+
+        let expr = match (&self.default_variant, self.default.as_ref()?) {
+            (Some(var), _) => format!("{}::{}", self.ty_path, var),
+            (None, Value::String(s)) => format!("{:?}.into()", s),
+            (None, Value::Number(n)) if self.ty_path.starts_with('f') => {
+                format!("{}_{}", n.as_f64()?, self.ty_path)
+            }
+            (None, Value::Number(n)) => format!("{}_{}", n, self.ty_path),
+            (None, Value::Bool(b)) => b.to_string(),
+            _ => return None,
+        };
+
+        Some(match (self.boxed, self.is_required) {
+            (true, true) => format!("Box::new({})", expr),
+            (true, false) => format!("Some(Box::new({}))", expr),
+            (false, true) => expr,
+            (false, false) => format!("Some({})", expr),
+        })
+    }
 }
 
 pub fn to_snake_case(name: &str) -> String {
@@ -237,9 +463,229 @@ pub fn to_snake_case(name: &str) -> String {
     new_name.to_snake_case()
 }
 
+/// Splits a generic type (like `Foo<A, Bar<B, C>>`) into its path and its
+/// (top-level) type arguments, by matching the angle brackets.
+fn split_generic_ty(ty: &str) -> Option<(&str, Vec<&str>)> {
+    let start = ty.find('<')?;
+    let (mut depth, mut last, mut args) = (0, start + 1, vec![]);
+    for (i, c) in ty.char_indices().skip_while(|&(i, _)| i < start) {
+        match c {
+            '<' => depth += 1,
+            ',' if depth == 1 => {
+                args.push(ty[last..i].trim());
+                last = i + 1;
+            }
+            '>' => {
+                depth -= 1;
+                if depth == 0 {
+                    // The closing bracket must be the end of the type.
+                    if i + 1 != ty.len() {
+                        return None;
+                    }
+
+                    args.push(ty[last..i].trim());
+                    return Some((&ty[..start], args));
+                }
+            }
+            _ => (),
+        }
+    }
+
+    None
+}
+
+/// Splits a map type (like `std::collections::BTreeMap<String, T>`) into
+/// the path of the map and the types of its keys and values.
+pub(super) fn split_map_ty(ty: &str) -> Option<(&str, &str, &str)> {
+    let (map, args) = split_generic_ty(ty)?;
+    // Maps are named like `BTreeMap` or `HashMap` (regardless of their paths).
+    if !map.rsplit("::").next()?.ends_with("Map") {
+        return None;
+    }
+
+    match args[..] {
+        [key, value] => Some((map, key, value)),
+        _ => None,
+    }
+}
+
 pub fn to_pascal_case(name: &str) -> String {
     let new_name = AT_REGEX.replace(name, "at_");
-    new_name.to_pascal_case()
+    let pascal = new_name.to_pascal_case();
+    if CASE_STRATEGY.with(Cell::get) == CaseStrategy::Standard {
+        return pascal;
+    }
+
+    // Words are split by `heck`, so we only have to find them in the original
+    // name and restore the ones which are uppercase.
+    let chars = new_name
+        .chars()
+        .filter(|c| c.is_alphanumeric())
+        .collect::<Vec<_>>();
+    let snake = new_name.to_snake_case();
+    let words = snake.split('_').filter(|w| !w.is_empty());
+    if words.clone().map(|w| w.chars().count()).sum::<usize>() != chars.len() {
+        return pascal;
+    }
+
+    let mut name = String::with_capacity(pascal.len());
+    let mut idx = 0;
+    for word in words {
+        let len = word.chars().count();
+        let orig = &chars[idx..idx + len];
+        idx += len;
+        if orig.iter().filter(|c| c.is_alphabetic()).count() > 1
+            && orig.iter().all(|c| !c.is_lowercase())
+        {
+            name.extend(orig);
+        } else {
+            name.push_str(&word.to_pascal_case());
+        }
+    }
+
+    name
+}
+
+/// Sets the case strategy for all the type names (see `to_pascal_case`) in this
+/// thread, until the returned guard is dropped.
+pub(super) fn set_case_strategy(strategy: CaseStrategy) -> CaseStrategyGuard {
+    CaseStrategyGuard(CASE_STRATEGY.with(|s| s.replace(strategy)))
+}
+
+/// Guard which restores the previous case strategy when it's dropped.
+pub(super) struct CaseStrategyGuard(CaseStrategy);
+
+impl Drop for CaseStrategyGuard {
+    fn drop(&mut self) {
+        CASE_STRATEGY.with(|s| s.set(self.0));
+    }
+}
+
+/// Escapes the characters in the given line of docs which rustdoc would
+/// otherwise interpret as Markdown (links, emphasis or stray backticks) or
+/// HTML tags. Code spans, autolinks, list bullets and characters which are
+/// already escaped are left alone.
+fn escape_doc(line: &str) -> Cow<'_, str> {
+    if !DOC_REGEX.is_match(line) {
+        return Cow::Borrowed(line);
+    }
+
+    let chars = line.chars().collect::<Vec<_>>();
+    // Backticks form code spans only if they're balanced.
+    let code_spans = chars.iter().filter(|&&c| c == '`').count() % 2 == 0;
+    let indent = line.chars().take_while(|c| c.is_whitespace()).count();
+    let is_space =
+        |i: Option<usize>| !matches!(i.and_then(|i| chars.get(i)), Some(c) if !c.is_whitespace());
+    let is_alnum =
+        |i: Option<usize>| matches!(i.and_then(|i| chars.get(i)), Some(c) if c.is_alphanumeric());
+
+    let mut escaped = String::with_capacity(line.len() + 8);
+    let (mut i, mut in_code) = (0, false);
+    while i < chars.len() {
+        let (c, prev, next) = (chars[i], i.checked_sub(1), Some(i + 1));
+        match c {
+            '`' if code_spans => in_code = !in_code,
+            _ if in_code => (),
+            '\\' if i + 1 < chars.len() => {
+                escaped.push(c);
+                i += 1;
+                escaped.push(chars[i]);
+                i += 1;
+                continue;
+            }
+            '<' => {
+                let rest = chars[i + 1..].iter().collect::<String>();
+                let link = rest.split('>').next().filter(|l| {
+                    rest.contains('>')
+                        && !l.contains(char::is_whitespace)
+                        && (l.starts_with("http://")
+                            || l.starts_with("https://")
+                            || l.starts_with("mailto:"))
+                });
+                if let Some(link) = link {
+                    escaped.push('<');
+                    escaped.push_str(link);
+                    escaped.push('>');
+                    i += link.chars().count() + 2;
+                    continue;
+                }
+
+                if matches!(chars.get(i + 1), Some(n) if n.is_ascii_alphabetic() || *n == '/' || *n == '!')
+                {
+                    escaped.push('\\');
+                }
+            }
+            '[' | ']' | '`' => escaped.push('\\'),
+            // List bullets
+            '*' if i == indent && is_space(next) => (),
+            // Separate asterisks (like in `a * b`) and underscores within
+            // words (like in `snake_case`) aren't emphasis.
+            '*' if !(is_space(prev) && is_space(next)) => escaped.push('\\'),
+            '_' if !(is_alnum(prev) && is_alnum(next)) => escaped.push('\\'),
+            _ => (),
+        }
+
+        escaped.push(c);
+        i += 1;
+    }
+
+    Cow::Owned(escaped)
+}
+
+/// Returns the Rust identifier for the struct field with the given name.
+pub(super) fn field_ident(name: &str) -> String {
+    let mut new_name = to_snake_case(name);
+    // Check if the field matches a Rust keyword and add '_' suffix.
+    if RUST_KEYWORDS.iter().any(|&k| k == new_name) {
+        new_name.push('_');
+    }
+
+    new_name
+}
+
+/// Renames the given (snake cased) field identifier the same way serde
+/// does for the given `rename_all` rule.
+fn apply_rename_rule(rule: &str, ident: &str) -> String {
+    match rule {
+        "camelCase" | "PascalCase" => {
+            let mut name = String::with_capacity(ident.len());
+            let mut capitalize = rule == "PascalCase";
+            for c in ident.chars() {
+                if c == '_' {
+                    capitalize = true;
+                } else if capitalize {
+                    name.push(c.to_ascii_uppercase());
+                    capitalize = false;
+                } else {
+                    name.push(c);
+                }
+            }
+
+            name
+        }
+        "kebab-case" => ident.replace('_', "-"),
+        "SCREAMING_SNAKE_CASE" => ident.to_ascii_uppercase(),
+        _ => ident.into(),
+    }
+}
+
+/// Returns the `rename_all` rule (if any) followed by the names of all the
+/// given fields, provided that some of those actually need renaming.
+pub fn detect_rename_rule(fields: &[ObjectField]) -> Option<&'static str> {
+    let names = fields
+        .iter()
+        .filter(|f| f.name != EXTRA_PROPS_FIELD && f.key_patterns.is_empty())
+        .map(|f| (field_ident(&f.name), f.name.as_str()))
+        .collect::<Vec<_>>();
+    if names.iter().all(|(ident, name)| ident == name) {
+        return None;
+    }
+
+    RENAME_RULES.iter().copied().find(|rule| {
+        names
+            .iter()
+            .all(|(ident, name)| apply_rename_rule(rule, ident) == *name)
+    })
 }
 
 impl ApiObject {
@@ -274,6 +720,8 @@ impl ApiObject {
     {
         let indent = " ".repeat(levels * 4);
         if let Some(desc) = stuff.as_ref() {
+            // Code blocks are rendered as they are, so they're not escaped.
+            let mut in_code = false;
             desc.as_ref().split('\n').try_for_each(|line| {
                 f.write_str("\n")?;
                 f.write_str(&indent)?;
@@ -283,15 +731,14 @@ impl ApiObject {
                 }
 
                 f.write_str(" ")?;
-                f.write_str(
-                    DOC_REGEX
-                        .replace_all(line, |c: &Captures| match &c[0] {
-                            "[" => "\\[",
-                            "]" => "\\]",
-                            _ => unreachable!(),
-                        })
-                        .trim_end(),
-                )
+                if line.trim_start().starts_with("```") {
+                    in_code = !in_code;
+                    return f.write_str(line.trim_end());
+                } else if in_code {
+                    return f.write_str(line.trim_end());
+                }
+
+                f.write_str(escape_doc(line).trim_end())
             })?;
             f.write_str("\n")?;
         }
@@ -299,6 +746,80 @@ impl ApiObject {
         Ok(())
     }
 
+    /// Writes a link to the given external docs (if any) into the given formatter.
+    /// This is meant to be called after writing the other docs (if any).
+    pub(super) fn write_external_docs<F>(
+        docs: Option<&ExternalDocs>,
+        has_docs: bool,
+        f: &mut F,
+        levels: usize,
+    ) -> fmt::Result
+    where
+        F: Write,
+    {
+        let docs = match docs {
+            Some(d) => d,
+            None => return Ok(()),
+        };
+
+        let indent = " ".repeat(levels * 4);
+        if has_docs {
+            writeln!(f, "{}///", indent)?;
+        } else {
+            f.write_str("\n")?;
+        }
+
+        // Angle brackets allow spaces and parentheses in the URL.
+        let text = docs.description.as_deref().map(str::trim);
+        writeln!(
+            f,
+            "{}/// [{}](<{}>)",
+            indent,
+            escape_doc(text.filter(|t| !t.is_empty()).unwrap_or("See more")),
+            docs.url.trim()
+        )
+    }
+
+    /// Writes the given description (if any) followed by the given example
+    /// (if any, as a JSON code block) as Rust documentation into the given formatter.
+    pub(super) fn write_docs_with_example<F, S>(
+        stuff: Option<S>,
+        example: Option<&serde_json::Value>,
+        f: &mut F,
+        levels: usize,
+    ) -> fmt::Result
+    where
+        F: Write,
+        S: AsRef<str>,
+    {
+        let example = match example.and_then(|e| serde_json::to_string_pretty(e).ok()) {
+            Some(e) => e,
+            None => return ApiObject::write_docs(stuff, f, levels),
+        };
+
+        let mut docs = stuff
+            .map(|s| s.as_ref().trim_end().to_owned() + "\n\n")
+            .unwrap_or_default();
+        docs.push_str("# Example\n\n```json\n");
+        docs.push_str(&example);
+        docs.push_str("\n```");
+        ApiObject::write_docs(Some(docs), f, levels)
+    }
+
+    /// Writes `#[deprecated]` (with the given description, if any, as the note)
+    /// into the given formatter.
+    pub(super) fn write_deprecated<F, S>(note: Option<S>, f: &mut F, levels: usize) -> fmt::Result
+    where
+        F: Write,
+        S: AsRef<str>,
+    {
+        let indent = " ".repeat(levels * 4);
+        match note.as_ref().map(|n| n.as_ref().trim()) {
+            Some(n) if !n.is_empty() => writeln!(f, "{}#[deprecated(note = {:?})]", indent, n),
+            _ => writeln!(f, "{}#[deprecated]", indent),
+        }
+    }
+
     /// Returns whether this type is simple (i.e., not an object defined by us).
     #[inline]
     pub(super) fn is_simple_type(ty: &str) -> bool {
@@ -307,7 +828,7 @@ impl ApiObject {
 
     /// Assuming that the given type "is" or "has" `Any`, this adds
     /// the appropriate generic parameter.
-    fn write_field_with_any<F>(ty: &str, f: &mut F) -> fmt::Result
+    pub(super) fn write_field_with_any<F>(ty: &str, f: &mut F) -> fmt::Result
     where
         F: Write,
     {
@@ -315,9 +836,9 @@ impl ApiObject {
             if ty[..i].ends_with("Vec") {
                 f.write_str(&ty[..=i])?;
                 Self::write_field_with_any(&ty[i + 1..ty.len() - 1], f)?;
-            } else if ty[..i].ends_with("std::collections::BTreeMap") {
-                f.write_str(&ty[..i + 9])?;
-                Self::write_field_with_any(&ty[i + 9..ty.len() - 1], f)?;
+            } else if let Some((map, key_ty, value_ty)) = split_map_ty(ty) {
+                write!(f, "{}<{}, ", map, key_ty)?;
+                Self::write_field_with_any(value_ty, f)?;
             } else {
                 unreachable!("no other generics expected.");
             }
@@ -342,6 +863,8 @@ pub(super) struct ApiObjectBuilder<'a> {
     pub idx: usize,
     /// Description if any, for docs.
     pub description: Option<&'a str>,
+    /// External docs (if any) of the operation, for docs.
+    pub external_docs: Option<&'a ExternalDocs>,
     /// Whether body is required for this builder.
     pub body_required: bool,
     /// Prefix for addressing stuff from crate root.
@@ -350,6 +873,12 @@ pub(super) struct ApiObjectBuilder<'a> {
     pub op_id: Option<&'a str>,
     /// Whether the operation is deprecated or not.
     pub deprecated: bool,
+    /// Visibility of the constructor for this builder, if it's not `pub`.
+    pub visibility: Option<&'a str>,
+    /// Default timeout (in milliseconds) for requests made by this builder.
+    pub timeout: Option<u64>,
+    /// Whether the query parameters are sent through a generated struct.
+    pub query_struct: bool,
     /// HTTP method for the operation - all builders (other than object builders)
     /// have this.
     pub method: Option<HttpMethod>,
@@ -363,11 +892,14 @@ pub(super) struct ApiObjectBuilder<'a> {
     pub object: &'a str,
     /// Encoding for the operation, if it's not JSON.
     pub encoding: Option<&'a (String, Arc<Coder>)>,
+    /// Whether the body is URL-encoded (overrides `encoding`).
+    pub form_body: bool,
     /// Decoding for the operation, if it's not JSON.
-    ///
-    /// **NOTE:** We use this to set the `Accept` header for operations
-    /// which return objects that are (or have) `Any` type.
     pub decoding: Option<&'a (String, Arc<Coder>)>,
+    /// Value of the `Accept` header for the operation (if any).
+    pub accept: Option<&'a str>,
+    /// Media type of the raw (string or binary) body, if any.
+    pub raw_body: Option<&'a str>,
     /// Whether there are multiple builders for this object.
     pub multiple_builders_exist: bool,
     /// Fields in this builder.
@@ -378,6 +910,31 @@ pub(super) struct ApiObjectBuilder<'a> {
     pub local_params: &'a [Parameter],
     /// Whether this builder is generic over `Any` type.
     pub needs_any: bool,
+    /// Tags for the operation (if any).
+    pub tags: &'a [String],
+    /// Error responses for the operation (see `OpRequirement.errors`).
+    pub errors: &'a [(String, Option<String>)],
+    /// 2xx responses for the operation (see `OpRequirement.success`).
+    pub success: &'a [(String, Option<String>)],
+    /// Security requirements for the operation (see `OpRequirement.security`).
+    pub security: &'a [Vec<String>],
+    /// Whether setting a required field rebuilds the builder with its new type
+    /// (instead of transmuting it).
+    pub safe_builders: bool,
+    /// Struct shared with other operations having the same fields and
+    /// parameters (if any), in which case this builder is an alias for it.
+    pub shared: Option<SharedBuilder>,
+}
+
+/// Builder struct which is generic over the operations using it. The builder
+/// for each of those operations is then an alias with its own marker type.
+#[derive(Debug, Clone)]
+pub(super) struct SharedBuilder {
+    /// Name of the shared struct.
+    pub name: String,
+    /// Whether this is the first of the operations, which writes the struct
+    /// and its property methods.
+    pub owner: bool,
 }
 
 /// The property we're dealing with.
@@ -412,6 +969,9 @@ pub(super) enum TypeParameters<'a> {
     ChangeOne(&'a str),
     ReplaceAll,
     ChangeAll,
+    /// Same as `ChangeAll`, except that write-only fields are reset to
+    /// `Missing{Name}` (because they're absent in objects from responses).
+    ChangeAllButWriteOnly,
 }
 
 /// Represents a Rust struct field (could be actual object field or a parameter).
@@ -436,11 +996,19 @@ pub(super) struct StructField<'a> {
     pub delimiting: &'a [CollectionFormat],
     /// Location of the parameter (if it is a parameter).
     pub param_loc: Option<ParameterIn>,
+    /// Whether this is a `deepObject` query parameter.
+    pub deep_object: bool,
     /// Whether this field "is" or "has" `Any` type. This is only
     /// applicable for object fields.
     pub needs_any: bool,
     /// Whether this field indicates a file upload.
     pub needs_file: bool,
+    /// Whether this field is write-only. This is only applicable for
+    /// object fields.
+    pub write_only: bool,
+    /// Whether this field is deprecated. This is only applicable for
+    /// object fields.
+    pub deprecated: bool,
 }
 
 impl<'a> ApiObjectBuilder<'a> {
@@ -479,24 +1047,32 @@ impl<'a> ApiObjectBuilder<'a> {
     /// and a parameter, then the latter overrides the former.
     pub(super) fn struct_fields_iter(&self) -> impl Iterator<Item = StructField<'a>> + 'a {
         let body_required = self.body_required;
-        let field_iter = self.fields.iter().map(move |field| StructField {
-            name: field.name.as_str(),
-            ty: field.ty_path.as_str(),
-            // We "require" the object fields only if the object itself is required.
-            prop: if body_required && field.is_required {
-                Property::RequiredField
-            } else {
-                Property::OptionalField
-            },
-            boxed: field.boxed,
-            desc: field.description.as_deref(),
-            strict_child_fields: &field.child_req_fields,
-            param_loc: None,
-            overridden: false,
-            needs_any: field.needs_any,
-            needs_file: field.ty_path == FILE_MARKER,
-            delimiting: &[],
-        });
+        // Read-only fields are never sent, so they can't be set in builders.
+        let field_iter = self
+            .fields
+            .iter()
+            .filter(|f| !f.read_only)
+            .map(move |field| StructField {
+                name: field.name.as_str(),
+                ty: field.ty_path.as_str(),
+                // We "require" the object fields only if the object itself is required.
+                prop: if body_required && field.is_required {
+                    Property::RequiredField
+                } else {
+                    Property::OptionalField
+                },
+                boxed: field.boxed,
+                desc: field.description.as_deref(),
+                strict_child_fields: &field.child_req_fields,
+                param_loc: None,
+                deep_object: false,
+                overridden: false,
+                needs_any: field.needs_any,
+                needs_file: field.ty_path == FILE_MARKER,
+                delimiting: &[],
+                write_only: field.write_only,
+                deprecated: field.deprecated,
+            });
 
         let param_iter = self
             .global_params
@@ -522,10 +1098,13 @@ impl<'a> ApiObjectBuilder<'a> {
                         desc: param.description.as_deref(),
                         strict_child_fields: &[] as &[_],
                         param_loc: Some(param.presence),
+                        deep_object: param.deep_object,
                         overridden: false,
                         needs_any: false,
                         needs_file: param.ty_path == FILE_MARKER,
                         delimiting: &param.delimiting,
+                        write_only: false,
+                        deprecated: false,
                     }))
                 }
             })
@@ -571,6 +1150,91 @@ impl<'a> ApiObjectBuilder<'a> {
         Ok(())
     }
 
+    /// Writes the name of the struct holding this builder's fields, which is
+    /// the shared struct (if any) or the builder itself.
+    pub(super) fn write_struct_name<F>(&self, f: &mut F) -> fmt::Result
+    where
+        F: Write,
+    {
+        match &self.shared {
+            Some(s) => f.write_str(&s.name),
+            None => self.write_name(f),
+        }
+    }
+
+    /// Writes the name of the type marking this builder's operation in the
+    /// shared struct.
+    pub(super) fn write_marker_name<F>(&self, f: &mut F) -> fmt::Result
+    where
+        F: Write,
+    {
+        f.write_str(self.object)?;
+        if let Some(method) = self.method {
+            write!(f, "{}", method)?;
+        }
+
+        f.write_str("Op")?;
+        if self.idx > 0 {
+            f.write_str(&self.idx.to_string())?;
+        }
+
+        Ok(())
+    }
+
+    /// Writes the name of the enum for this builder's error responses.
+    pub(super) fn write_error_name<F>(&self, f: &mut F) -> fmt::Result
+    where
+        F: Write,
+    {
+        f.write_str(self.object)?;
+        if let Some(method) = self.method {
+            write!(f, "{}", method)?;
+        }
+
+        f.write_str("Error")?;
+        if self.idx > 0 {
+            f.write_str(&self.idx.to_string())?;
+        }
+
+        Ok(())
+    }
+
+    /// Writes the name of the enum for this builder's 2xx responses.
+    pub(super) fn write_response_name<F>(&self, f: &mut F) -> fmt::Result
+    where
+        F: Write,
+    {
+        f.write_str(self.object)?;
+        if let Some(method) = self.method {
+            write!(f, "{}", method)?;
+        }
+
+        f.write_str("Response")?;
+        if self.idx > 0 {
+            f.write_str(&self.idx.to_string())?;
+        }
+
+        Ok(())
+    }
+
+    /// Writes the name of the struct for this builder's query parameters.
+    pub(super) fn write_query_name<F>(&self, f: &mut F) -> fmt::Result
+    where
+        F: Write,
+    {
+        f.write_str(self.object)?;
+        if let Some(method) = self.method {
+            write!(f, "{}", method)?;
+        }
+
+        f.write_str("Query")?;
+        if self.idx > 0 {
+            f.write_str(&self.idx.to_string())?;
+        }
+
+        Ok(())
+    }
+
     /// Writes generic parameters, if needed.
     ///
     /// Also takes an enum to specify whether the one/all/none of the parameters
@@ -605,8 +1269,14 @@ impl<'a> ApiObjectBuilder<'a> {
                         f.write_str(&to_pascal_case(field.name))?;
                         return f.write_str("Exists");
                     }
+                    // Write-only fields should be reset to `Missing{Name}`
+                    TypeParameters::ChangeAllButWriteOnly if field.write_only => {
+                        f.write_str(self.helper_module_prefix)?;
+                        f.write_str("generics::")?;
+                        f.write_str("Missing")?;
+                    }
                     // All names should be changed to `{Name}Exists`
-                    TypeParameters::ChangeAll => {
+                    TypeParameters::ChangeAll | TypeParameters::ChangeAllButWriteOnly => {
                         f.write_str(self.helper_module_prefix)?;
                         f.write_str("generics::")?;
                         f.write_str(&to_pascal_case(field.name))?;
@@ -642,6 +1312,47 @@ impl<'a> ApiObjectBuilder<'a> {
         Ok(num_generics)
     }
 
+    /// Same as `write_generics_if_necessary`, but for the struct holding the
+    /// fields, which has the operation as its first parameter if it's shared.
+    pub(super) fn write_struct_generics<F>(
+        &self,
+        f: &mut F,
+        params: TypeParameters<'_>,
+    ) -> Result<usize, fmt::Error>
+    where
+        F: Write,
+    {
+        if self.shared.is_none() {
+            return self.write_generics_if_necessary(f, None, params);
+        }
+
+        self.write_generics_with_op(f, "Op", params)
+    }
+
+    /// Writes the generic parameters prefixed by the given operation type.
+    fn write_generics_with_op<F>(
+        &self,
+        f: &mut F,
+        op: &str,
+        params: TypeParameters<'_>,
+    ) -> Result<usize, fmt::Error>
+    where
+        F: Write,
+    {
+        let mut generics = String::new();
+        let num_generics = self.write_generics_if_necessary(&mut generics, None, params)?;
+        f.write_str("<")?;
+        f.write_str(op)?;
+        if num_generics == 0 {
+            f.write_str(">")?;
+        } else {
+            f.write_str(", ")?;
+            f.write_str(&generics[1..])?;
+        }
+
+        Ok(num_generics + 1)
+    }
+
     /// Returns whether this builder will have at least one field.
     pub(super) fn has_atleast_one_field(&self) -> bool {
         self.struct_fields_iter()
@@ -679,7 +1390,7 @@ impl<'a> ApiObjectBuilder<'a> {
     where
         F: Write,
     {
-        self.write_name(f)?;
+        self.write_struct_name(f)?;
         f.write_str("Container")
     }
 
@@ -694,7 +1405,8 @@ impl<'a> ApiObjectBuilder<'a> {
     where
         F: fmt::Write,
     {
-        if !ty.contains("Vec") {
+        // Bytes are only allowed in body (as they are).
+        if ty == BYTES_TYPE || !ty.contains("Vec") {
             return f.write_str(ty);
         }
 
@@ -762,40 +1474,21 @@ impl<'a> ApiObjectBuilder<'a> {
         f.write_str(name)?;
         f.write_str(": Option<")?;
         if ty == FILE_MARKER {
-            f.write_str("std::path::PathBuf")?;
+            f.write_str(self.helper_module_prefix)?;
+            f.write_str(FILE_PART_TY)?;
         } else {
             Self::write_wrapped_ty(self.helper_module_prefix, ty, delims, f)?;
         }
 
         f.write_str(">,")
     }
-}
-
-impl<'a> Display for ApiObjectBuilder<'a> {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        f.write_str("/// Builder ")?;
-        if let (Some(name), Some(m)) = (self.constructor_fn_name(), self.method) {
-            f.write_str("created by [`")?;
-            f.write_str(self.object)?;
-            f.write_str("::")?;
-            f.write_str(&name)?;
-            f.write_str("`](./struct.")?;
-            f.write_str(self.object)?;
-            f.write_str(".html#method.")?;
-            f.write_str(&name)?;
-            f.write_str(") method for a `")?;
-            f.write_str(&m.to_string().to_uppercase())?;
-            f.write_str("` operation associated with `")?;
-            f.write_str(self.object)?;
-            f.write_str("`.\n")?;
-        } else {
-            f.write_str("for [`")?;
-            f.write_str(self.object)?;
-            f.write_str("`](./struct.")?;
-            f.write_str(self.object)?;
-            f.write_str(".html) object.\n")?;
-        }
 
+    /// Writes the struct holding the fields of this builder (along with its
+    /// container, if needed).
+    fn write_struct<F>(&self, f: &mut F) -> fmt::Result
+    where
+        F: Write,
+    {
         // If the builder "needs" parameters/fields, then we go for a separate
         // container which holds both the body (if any) and the parameters,
         // so that we can make the actual builder `#[repr(transparent)]`
@@ -805,14 +1498,23 @@ impl<'a> Display for ApiObjectBuilder<'a> {
             f.write_str("#[repr(transparent)]\n")?;
         }
 
-        f.write_str("#[derive(Debug, Clone)]\npub struct ")?;
-        self.write_name(f)?;
-        self.write_generics_if_necessary(f, None, TypeParameters::Generic)?;
+        let mut generics = String::new();
+        let num_generics = self.write_struct_generics(&mut generics, TypeParameters::Generic)?;
+        // Builders without any requirements can start out as defaults.
+        if num_generics == 0 {
+            f.write_str("#[derive(Debug, Default, Clone)]\npub struct ")?;
+        } else {
+            f.write_str("#[derive(Debug, Clone)]\npub struct ")?;
+        }
+
+        self.write_struct_name(f)?;
+        f.write_str(&generics)?;
 
         // If structs don't have any fields, then we go for unit structs.
-        let has_fields = self.has_atleast_one_field();
+        let has_fields =
+            self.has_atleast_one_field() || self.body_required || self.shared.is_some();
 
-        if has_fields || self.body_required || needs_container {
+        if has_fields || needs_container {
             f.write_str(" {")?;
         }
 
@@ -838,6 +1540,10 @@ impl<'a> Display for ApiObjectBuilder<'a> {
             self.write_body_field_if_required(f)?;
         }
 
+        if self.shared.is_some() {
+            f.write_str("\n    _op: core::marker::PhantomData<Op>,")?;
+        }
+
         // Write struct fields and the associated markers if needed.
         self.struct_fields_iter()
             .try_for_each::<_, fmt::Result>(|field| {
@@ -877,7 +1583,7 @@ impl<'a> Display for ApiObjectBuilder<'a> {
                 Ok(())
             })?;
 
-        if has_fields || self.body_required {
+        if has_fields {
             f.write_str("\n}\n")?;
         } else {
             f.write_str(";\n")?;
@@ -889,18 +1595,147 @@ impl<'a> Display for ApiObjectBuilder<'a> {
             f.write_str("\n}\n")?;
         }
 
+        // Markers can't be defaults, so shared builders without any other
+        // requirements need their own impl.
+        if self.shared.is_some() && num_generics == 1 {
+            f.write_str("\nimpl<Op> Default for ")?;
+            self.write_struct_name(f)?;
+            f.write_str("<Op> {\n    fn default() -> Self {\n        ")?;
+            self.write_struct_name(f)?;
+            f.write_str(" {")?;
+            if self.body_required {
+                f.write_str("\n            body: Default::default(),")?;
+            }
+
+            f.write_str("\n            _op: core::marker::PhantomData,")?;
+            self.struct_fields_iter()
+                .filter(|field| field.prop.is_parameter())
+                .try_for_each(|field| {
+                    write!(
+                        f,
+                        "\n            param_{}: None,",
+                        to_snake_case(field.name)
+                    )
+                })?;
+            f.write_str("\n        }\n    }\n}\n")?;
+        }
+
         Ok(())
     }
+
+    /// Writes the doc comment for this builder.
+    fn write_docs<F>(&self, f: &mut F) -> fmt::Result
+    where
+        F: Write,
+    {
+        f.write_str("/// Builder ")?;
+        if let (Some(name), Some(m)) = (self.constructor_fn_name(), self.method) {
+            f.write_str("created by [`")?;
+            f.write_str(self.object)?;
+            f.write_str("::")?;
+            f.write_str(&name)?;
+            f.write_str("`](./struct.")?;
+            f.write_str(self.object)?;
+            f.write_str(".html#method.")?;
+            f.write_str(&name)?;
+            f.write_str(") method for a `")?;
+            f.write_str(&m.to_string().to_uppercase())?;
+            f.write_str("` operation associated with `")?;
+            f.write_str(self.object)?;
+            f.write_str("`.\n")?;
+        } else {
+            f.write_str("for [`")?;
+            f.write_str(self.object)?;
+            f.write_str("`](./struct.")?;
+            f.write_str(self.object)?;
+            f.write_str(".html) object.\n")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a> Display for ApiObjectBuilder<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let shared = match &self.shared {
+            Some(s) => s,
+            None => {
+                self.write_docs(f)?;
+                return self.write_struct(f);
+            }
+        };
+
+        if shared.owner {
+            writeln!(
+                f,
+                "/// Builder shared by operations (with the same fields and parameters) associated with `{}`.",
+                self.object
+            )?;
+            self.write_struct(f)?;
+            f.write_str("\n")?;
+        }
+
+        let mut marker = String::new();
+        self.write_marker_name(&mut marker)?;
+        self.write_docs(f)?;
+        f.write_str("pub type ")?;
+        self.write_name(f)?;
+        self.write_generics_if_necessary(f, None, TypeParameters::Generic)?;
+        f.write_str(" = ")?;
+        self.write_struct_name(f)?;
+        self.write_generics_with_op(f, &marker, TypeParameters::Generic)?;
+        f.write_str(";\n\n/// Marks the operation of [`")?;
+        self.write_name(f)?;
+        writeln!(
+            f,
+            "`] in the shared builder.\n#[derive(Debug, Clone)]\npub enum {} {{}}",
+            marker
+        )
+    }
 }
 
 impl Display for ApiObject {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        ApiObject::write_docs(self.description.as_ref(), f, 0)?;
+        ApiObject::write_docs_with_example(self.description.as_ref(), self.example.as_ref(), f, 0)?;
+        ApiObject::write_external_docs(
+            self.external_docs.as_ref(),
+            self.description.is_some() || self.example.is_some(),
+            f,
+            0,
+        )?;
+        if self.deprecated {
+            ApiObject::write_deprecated(self.description.as_ref(), f, 0)?;
+        }
+
+        if self.non_exhaustive {
+            f.write_str("#[non_exhaustive]\n")?;
+        }
+
         if self.inner.is_enum() {
             return self.write_enum(f);
+        } else if let ObjectContainer::Union {
+            tag,
+            content,
+            variants,
+            fallback,
+        } = &self.inner
+        {
+            return self.write_union(tag.as_deref(), content.as_deref(), variants, *fallback, f);
+        }
+
+        // Objects with default values in their fields implement `Default` by hand.
+        let has_defaults = self.fields().iter().any(|f| f.default.is_some());
+        if has_defaults {
+            f.write_str("#[derive(Debug, Clone, Serialize, Deserialize)]\n")?;
+        } else {
+            f.write_str("#[derive(Debug, Default, Clone, Serialize, Deserialize)]\n")?;
         }
 
-        f.write_str("#[derive(Debug, Default, Clone, Serialize, Deserialize)]\npub struct ")?;
+        if let Some(rule) = self.rename_all {
+            writeln!(f, "#[serde(rename_all = {:?})]", rule)?;
+        }
+
+        f.write_str("pub struct ")?;
         f.write_str(&self.name)?;
         if !self.inner.is_enum() && self.fields().iter().any(|f| f.needs_any) {
             ApiObject::write_any_generic(f)?;
@@ -911,24 +1746,76 @@ impl Display for ApiObject {
         self.fields()
             .iter()
             .try_for_each::<_, fmt::Result>(|field| {
-                let mut new_name = to_snake_case(&field.name);
-                // Check if the field matches a Rust keyword and add '_' suffix.
-                if RUST_KEYWORDS.iter().any(|&k| k == new_name) {
-                    new_name.push('_');
+                let new_name = field_ident(&field.name);
+                let renamed = match self.rename_all {
+                    Some(rule) => apply_rename_rule(rule, &new_name),
+                    None => new_name.clone(),
+                };
+
+                ApiObject::write_docs_with_example(
+                    field.description.as_ref(),
+                    field.example.as_ref(),
+                    f,
+                    1,
+                )?;
+                if field.description.is_none() && field.example.is_none() {
+                    f.write_str("\n")?;
                 }
 
-                ApiObject::write_docs(field.description.as_ref(), f, 1)?;
-                if field.description.is_none() {
-                    f.write_str("\n")?;
+                if field.deprecated {
+                    ApiObject::write_deprecated(field.description.as_ref(), f, 1)?;
                 }
 
+                // Unset optional fields are left out of the payload, but required
+                // fields which are nullable should still be sent as `null`.
+                let required_nullable = field.nullable && field.absent_nullable.is_some();
+                let mut skip_none = !field.is_required && !required_nullable && !field.read_only;
                 f.write_str("    ")?;
-                if field.name == EXTRA_PROPS_FIELD {
+                if field.read_only {
+                    f.write_str("#[serde(skip_serializing)]\n    ")?;
+                } else if field.write_only {
+                    f.write_str("#[serde(skip_deserializing)]\n    ")?;
+                }
+
+                match (field.serde_with.as_deref(), field.absent_nullable) {
+                    // Absent fields fail with custom deserializers, unless we ask for defaults.
+                    (Some(m), absent) => {
+                        f.write_str("#[serde(")?;
+                        if !field.is_required && absent != Some(AbsentNullable::Strict) {
+                            f.write_str("default, ")?;
+                        }
+                        write!(f, "with = {:?}", m)?;
+                        if skip_none {
+                            f.write_str(", skip_serializing_if = \"Option::is_none\"")?;
+                            skip_none = false;
+                        }
+
+                        f.write_str(")]\n    ")?;
+                    }
+                    // `Option` fields are `None` by default, unless they have a
+                    // custom deserializer.
+                    (None, Some(AbsentNullable::Strict)) => {
+                        f.write_str("#[serde(deserialize_with = \"Option::deserialize\")]\n    ")?
+                    }
+                    (None, Some(AbsentNullable::Tolerant)) => {
+                        f.write_str("#[serde(default)]\n    ")?
+                    }
+                    (None, None) => (),
+                }
+
+                if field.name == EXTRA_PROPS_FIELD || !field.key_patterns.is_empty() {
                     f.write_str("#[serde(flatten)]\n    ")?;
-                } else if new_name != field.name.as_str() {
+                } else if renamed != field.name.as_str() {
                     f.write_str("#[serde(rename = \"")?;
                     f.write_str(&field.name)?;
-                    f.write_str("\")]\n    ")?;
+                    f.write_str("\"")?;
+                    if skip_none {
+                        f.write_str(", skip_serializing_if = \"Option::is_none\"")?;
+                    }
+
+                    f.write_str(")]\n    ")?;
+                } else if skip_none {
+                    f.write_str("#[serde(skip_serializing_if = \"Option::is_none\")]\n    ")?;
                 }
 
                 f.write_str("pub ")?;
@@ -960,15 +1847,180 @@ impl Display for ApiObject {
                 Ok(())
             })?;
 
-        if !self.fields().is_empty() {
+        if let Some(ty) = &self.catch_all {
+            write!(
+                f,
+                "\n    /// Properties which aren't declared in the schema.\n    #[serde(flatten)]\n    pub additional: {},",
+                ty
+            )?;
+        }
+
+        if !self.fields().is_empty() || self.catch_all.is_some() {
             f.write_str("\n")?;
         }
 
-        f.write_str("}\n")
+        f.write_str("}\n")?;
+        if has_defaults {
+            self.write_default_impl(f)?;
+        }
+
+        Ok(())
     }
 }
 
 impl ApiObject {
+    /// Writes the `Default` impl for a struct, using the default values
+    /// of its fields (wherever they exist).
+    fn write_default_impl<F>(&self, f: &mut F) -> fmt::Result
+    where
+        F: fmt::Write,
+    {
+        let needs_any = self.fields().iter().any(|f| f.needs_any);
+        f.write_str("\nimpl")?;
+        if needs_any {
+            write!(f, "<{}: Default>", ANY_GENERIC_PARAMETER)?;
+        }
+
+        f.write_str(" Default for ")?;
+        f.write_str(&self.name)?;
+        if needs_any {
+            ApiObject::write_any_generic(f)?;
+        }
+
+        write!(
+            f,
+            " {{\n    fn default() -> Self {{\n        {} {{",
+            self.name
+        )?;
+        for field in self.fields() {
+            write!(
+                f,
+                "\n            {}: {},",
+                field_ident(&field.name),
+                field
+                    .default_expr()
+                    .unwrap_or_else(|| "Default::default()".into())
+            )?;
+        }
+
+        f.write_str("\n        }\n    }\n}\n")
+    }
+
+    /// Writes a union as an enum (internally tagged by default, adjacently
+    /// tagged if the object is wrapped in a separate property, and untagged
+    /// if there's no tag at all).
+    fn write_union<F>(
+        &self,
+        tag: Option<&str>,
+        content: Option<&str>,
+        variants: &[UnionVariant],
+        fallback: bool,
+        f: &mut F,
+    ) -> fmt::Result
+    where
+        F: fmt::Write,
+    {
+        let needs_any = variants.iter().any(|v| v.needs_any);
+        f.write_str("#[derive(Debug, Clone, Serialize, Deserialize)]\n")?;
+        match tag {
+            Some(t) => {
+                write!(f, "#[serde(tag = {:?}", t)?;
+                if let Some(c) = content {
+                    write!(f, ", content = {:?}", c)?;
+                }
+
+                f.write_str(")]")?;
+            }
+            None => f.write_str("#[serde(untagged)]")?,
+        }
+
+        f.write_str("\npub enum ")?;
+        f.write_str(&self.name)?;
+        if needs_any {
+            ApiObject::write_any_generic(f)?;
+        }
+
+        f.write_str(" {")?;
+        for var in variants {
+            if tag.is_some() {
+                write!(f, "\n    #[serde(rename = {:?}", var.tag)?;
+                for alias in &var.aliases {
+                    write!(f, ", alias = {:?}", alias)?;
+                }
+
+                f.write_str(")]")?;
+            }
+
+            write!(f, "\n    {}(", var.name)?;
+            if var.needs_any {
+                Self::write_field_with_any(&var.ty_path, f)?;
+            } else {
+                f.write_str(&var.ty_path)?;
+            }
+
+            f.write_str("),")?;
+        }
+
+        if fallback && tag.is_some() {
+            f.write_str(
+                "\n    /// Object with an unknown tag (as it appeared on the wire).\n    #[serde(untagged)]\n    Unknown(serde_json::Value),",
+            )?;
+        } else if fallback {
+            f.write_str(
+                "\n    /// Value which doesn't match any variant (as it appeared on the wire).\n    Unknown(serde_json::Value),",
+            )?;
+        }
+
+        f.write_str("\n}\n")?;
+
+        if let Some(var) = variants.first() {
+            f.write_str("\nimpl")?;
+            if needs_any {
+                write!(f, "<{}: Default>", ANY_GENERIC_PARAMETER)?;
+            }
+
+            f.write_str(" Default for ")?;
+            f.write_str(&self.name)?;
+            if needs_any {
+                ApiObject::write_any_generic(f)?;
+            }
+
+            write!(
+                f,
+                " {{
+    fn default() -> Self {{
+        {}::{}(Default::default())
+    }}
+}}
+",
+                &self.name, &var.name
+            )?;
+        }
+
+        // Variants can be built from their objects, as long as it's unambiguous.
+        for var in variants.iter().filter(|_| !needs_any) {
+            if variants.iter().filter(|v| v.ty_path == var.ty_path).count() > 1 {
+                continue;
+            }
+
+            write!(
+                f,
+                "
+impl From<{ty}> for {name} {{
+    fn from(value: {ty}) -> Self {{
+        {name}::{var}(value)
+    }}
+}}
+",
+                ty = &var.ty_path,
+                name = &self.name,
+                var = &var.name
+            )?;
+        }
+
+        Ok(())
+    }
+
     /// Writes an enum declaration along with serde impl if needed.
     fn write_enum<F>(&self, f: &mut F) -> fmt::Result
     where
@@ -1001,6 +2053,12 @@ impl ApiObject {
             f.write_str(",")
         })?;
 
+        if self.inner.has_enum_fallback() {
+            f.write_str(
+                "\n    /// Value which doesn't match any variant (as it appeared on the wire).\n    #[serde(untagged)]\n    Unknown(String),",
+            )?;
+        }
+
         f.write_str("\n}\n")?;
 
         // FIXME: Currently, we're implementing the first value as enum default.
@@ -1018,12 +2076,158 @@ impl ApiObject {
             )?;
         }
 
+        self.write_enum_wire_values(f)?;
+        self.write_enum_str_conversions(f)?;
+
         if !is_string {
             EnumSerdeImpl::from(self).write_to(f)?;
         }
 
         Ok(())
     }
+
+    /// Writes a plain enum for some parameter, which is converted from/to
+    /// its wire value (instead of being (de)serialized).
+    fn write_param_enum<F>(&self, f: &mut F) -> fmt::Result
+    where
+        F: Write,
+    {
+        Self::write_docs(self.description.as_ref(), f, 0)?;
+        f.write_str("#[derive(Debug, Clone, Copy, PartialEq, Eq)]\n#[allow(non_camel_case_types)]\npub enum ")?;
+        f.write_str(&self.name)?;
+        f.write_str(" {")?;
+        self.variants()
+            .iter()
+            .try_for_each(|var| write!(f, "\n    {},", &var.name))?;
+        f.write_str("\n}\n")?;
+
+        self.write_enum_wire_values(f)?;
+        self.write_enum_str_conversions(f)
+    }
+
+    /// Writes the conversions between this enum and its wire values (`Display`,
+    /// `FromStr` and `TryFrom` for strings), for use outside of serde.
+    fn write_enum_str_conversions<F>(&self, f: &mut F) -> fmt::Result
+    where
+        F: fmt::Write,
+    {
+        // Unknown values end up in the fallback variant (if any) like they
+        // do in deserialization, in which case parsing never fails.
+        let parse = if self.inner.has_enum_fallback() {
+            format!(
+                "Ok(Self::ALL.iter().find(|v| v.as_wire_str() == s).cloned()
+            .unwrap_or_else(|| {}::Unknown(s.into())))",
+                &self.name
+            )
+        } else {
+            format!(
+                "Self::ALL.iter().find(|v| v.as_wire_str() == s).cloned().ok_or_else(|| {{
+            let expected: Vec<_> = Self::ALL.iter().map(Self::as_wire_str).collect();
+            format!(\"unknown value {{:?}} for {} (expected one of {{:?}})\", s, expected)
+        }})",
+                &self.name
+            )
+        };
+
+        write!(
+            f,
+            "
+impl std::fmt::Display for {name} {{
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {{
+        f.write_str(self.as_wire_str())
+    }}
+}}
+
+impl std::str::FromStr for {name} {{
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {{
+        {parse}
+    }}
+}}
+
+impl std::convert::TryFrom<&str> for {name} {{
+    type Error = String;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {{
+        s.parse()
+    }}
+}}
+
+impl std::convert::TryFrom<String> for {name} {{
+    type Error = String;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {{
+        s.parse()
+    }}
+}}
+",
+            name = &self.name,
+            parse = parse
+        )
+    }
+
+    /// Writes the `ALL` constant and `as_wire_str` method for this enum.
+    fn write_enum_wire_values<F>(&self, f: &mut F) -> fmt::Result
+    where
+        F: fmt::Write,
+    {
+        write!(
+            f,
+            "
+impl {} {{
+    /// All the variants of this enum.
+    pub const ALL: &'static [Self] = &[",
+            &self.name
+        )?;
+
+        self.variants()
+            .iter()
+            .try_for_each(|var| write!(f, "\n        {}::{},", &self.name, &var.name))?;
+
+        // Unknown values are borrowed from the enum.
+        let fallback = self.inner.has_enum_fallback();
+        write!(
+            f,
+            "
+    ];
+
+    /// Returns the value of this variant as it appears on the wire
+    /// (i.e., its serialized form).{}
+    pub fn as_wire_str(&self) -> {} {{
+        match self {{",
+            if fallback {
+                "
+    ///
+    /// **NOTE:** This borrows from `self` (rather than returning `&'static str`),
+    /// because it also returns the values held by `Unknown`."
+            } else {
+                ""
+            },
+            if fallback { "&str" } else { "&'static str" }
+        )?;
+
+        self.variants().iter().try_for_each(|var| {
+            write!(f, "\n            {}::{} => ", &self.name, &var.name)?;
+            match &var.value {
+                serde_json::Value::String(s) => write!(f, "{:?},", s),
+                v => write!(f, "{:?},", v.to_string()),
+            }
+        })?;
+
+        if fallback {
+            write!(f, "\n            {}::Unknown(s) => s,", &self.name)?;
+        }
+
+        write!(
+            f,
+            "
+        }}
+    }}
+}}
+"
+        )
+    }
 }
 
 /// Abstraction for implementing Serialize/Deserialize mechanism
@@ -1,33 +1,52 @@
 use super::{
     object,
-    object::ApiObject,
+    object::{ApiObject, ObjectContainer},
     template::{self, Template},
-    CrateMeta, EmitMode,
+    AbsentNullable, CaseStrategy, CrateMeta, EmitMode, MapType, NonExhaustive, UnusedDefinitions,
 };
 use crate::{
     error::PaperClipError,
-    v2::models::{Coders, SpecFormat},
+    v2::models::{Coders, HttpMethod, SecurityScheme, SpecFormat},
 };
 use anyhow::Error;
-use heck::ToPascalCase;
 #[cfg(feature = "cli")]
 use heck::ToSnakeCase;
+use heck::{ToPascalCase, ToShoutySnakeCase};
 use itertools::Itertools;
+use rayon::prelude::*;
 use url_dep::Url;
 
-#[cfg(feature = "cli")]
-use std::fs;
 use std::{
     cell::RefCell,
     collections::{BTreeMap, BTreeSet, HashMap, HashSet},
-    fmt::Write as _,
-    fs::OpenOptions,
+    fmt::{self, Write as _},
+    fs::{self, OpenOptions},
     hash::{Hash, Hasher},
     io::Write,
     path::{Path, PathBuf},
     rc::Rc,
 };
 
+/// Items from the client module re-exported by the generated prelude.
+const PRELUDE_CLIENT_ITEMS: &[&str] = &[
+    "ApiClient",
+    "ApiError",
+    "Client",
+    "ClientConfig",
+    "Credential",
+    "Form",
+    "Request",
+    "Response",
+    "ResponseWrapper",
+    "Sendable",
+];
+
+/// Items from the client module re-exported by the generated prelude (if `mock_client` is set).
+const PRELUDE_MOCK_ITEMS: &[&str] = &["MockClient", "MockResponse"];
+
+/// Items from the util module re-exported by the generated prelude.
+const PRELUDE_UTIL_ITEMS: &[&str] = &["Delimited", "Download", "ResponseStream"];
+
 /// Holds the state for your schema emitter.
 #[derive(Debug)]
 pub struct EmitterState {
@@ -37,6 +56,109 @@ pub struct EmitterState {
     pub ns_sep: &'static str,
     /// Module prefix for using in generated code.
     pub mod_prefix: &'static str,
+    /// Whether to name the generated types after the schema `title` (when it's
+    /// present and doesn't collide with some other type in the same module)
+    /// instead of the definition key or the parent names.
+    pub prefer_titles: bool,
+    /// Whether to group operations (by their tags) into object-safe async traits
+    /// implemented by all API clients. Untagged operations go into `DefaultApi`.
+    pub op_traits: bool,
+    /// Whether to inline all the generated modules (including the helper modules
+    /// like `generics`, `util` and `client`) into the root module, so that the
+    /// output is a single self-contained file.
+    pub standalone: bool,
+    /// Whether to group the definitions into a module (and a file) for each tag,
+    /// based on the tags of the operations using them (directly or through other
+    /// definitions). Definitions used by several tags (or only by untagged
+    /// operations, or by none at all) go into the shared `types` module.
+    pub tag_modules: bool,
+    /// What to do with definitions which aren't used by any operation.
+    pub unused_definitions: UnusedDefinitions,
+    /// Whether to map optional integer fields which must be at least 1 (based on
+    /// `minimum` and `exclusiveMinimum`) to `std::num::NonZeroU32`/`NonZeroU64`.
+    pub non_zero_integers: bool,
+    /// Prefix for the environment variables (named after the security schemes)
+    /// read by `Client::from_env`, which is generated only when this is set.
+    pub env_prefix: Option<String>,
+    /// How to deserialize required (but nullable) fields which are absent.
+    pub absent_nullable: AbsentNullable,
+    /// If set, string fields whose `maxLength` is at most this value become
+    /// `arrayvec::ArrayString<N>` (with `N` being `maxLength`) instead of `String`.
+    /// Note that the capacity is in bytes, so this is meant for ASCII values.
+    /// The generated manifest (if any) then includes `arrayvec`.
+    pub array_strings_up_to: Option<u32>,
+    /// Whether to generate `fn example() -> Self` for objects which have an
+    /// `example` in their schema (which must match the schema).
+    pub object_examples: bool,
+    /// Whether to map numbers with `format: decimal` to `rust_decimal::Decimal`,
+    /// which is (de)serialized as a string (e.g., `"12.34"`) on the wire.
+    /// The generated manifest (if any) then includes `rust_decimal`.
+    pub decimal_strings: bool,
+    /// Whether to generate `merge(&mut self, other: Self)` for objects, which
+    /// copies the fields set in `other` (and all required fields) into `self`.
+    pub object_merge: bool,
+    /// Whether to generate a struct (deriving `Serialize`) for the query parameters
+    /// of each operation, which is then passed to `Request::query` instead of pairs.
+    /// Parameters with `collectionFormat: multi` are still sent as pairs.
+    pub query_structs: bool,
+    /// Whether to add an `Unknown(serde_json::Value)` variant to tagged unions,
+    /// which holds (instead of rejecting) objects with unknown tags.
+    pub union_fallback: bool,
+    /// Whether to add an `Unknown(String)` variant to string enums, which holds
+    /// (instead of rejecting) the values the spec doesn't declare.
+    ///
+    /// **NOTE:** Since unknown values are borrowed from the enum, `as_wire_str`
+    /// of these enums returns `&str` (instead of `&'static str`).
+    pub enum_fallback: bool,
+    /// Whether to map strings with `format: byte` to `util::Base64`, which keeps
+    /// the data encoded until it's decoded (all at once or through a writer).
+    /// The generated manifest (if any) then includes `base64`.
+    pub base64_bytes: bool,
+    /// Whether to map strings with `format: byte` to `Vec<u8>` (in object fields),
+    /// which are decoded when they're deserialized and encoded again when they're
+    /// serialized (through `util::base64_vec`). This takes precedence over
+    /// `base64_bytes` and the generated manifest (if any) then includes `base64`.
+    pub decoded_bytes: bool,
+    /// How multi-word names are converted into type names (acronym handling).
+    pub case_strategy: CaseStrategy,
+    /// Whether to implement the client traits for `reqwest::blocking` as well, so
+    /// that operations can be sent with `Sendable::send_blocking` (without an
+    /// async runtime). The generated manifest (if any) then enables the
+    /// `blocking` feature of `reqwest`.
+    pub blocking_client: bool,
+    /// Whether to generate `client::MockClient` (along with `MockRequest`,
+    /// `MockResponse` and `MockForm`), which records the requests instead of
+    /// sending them and responds with canned responses, for testing the code
+    /// using the client without a server.
+    pub mock_client: bool,
+    /// Whether builders move their contents into a new builder when a required
+    /// field or parameter is set, instead of transmuting themselves (which needs
+    /// `unsafe`).
+    pub safe_builders: bool,
+    /// Collection used for map fields (`BTreeMap` by default).
+    pub map_type: MapType,
+    /// Whether free-form objects (i.e., objects without any declared properties
+    /// or `additionalProperties`) collect their keys into a flattened `additional`
+    /// map of `serde_json::Value`s, so that they survive round trips. Objects with
+    /// `additionalProperties: true` always collect them (as `Any`) in `other_fields`.
+    pub keep_unknown_fields: bool,
+    /// Whether to map strings with `format: date-time` to `chrono::DateTime<chrono::Utc>`
+    /// and `format: date` to `chrono::NaiveDate`. Date-time parameters are still
+    /// `String`s, because parameters are sent through their `Display` impls (which
+    /// aren't RFC 3339 for `DateTime`). The generated manifest (if any) then
+    /// includes `chrono`.
+    pub chrono_dates: bool,
+    /// Whether to map strings with `format: uuid` to `uuid::Uuid`, so that they're
+    /// checked when they're deserialized. The generated manifest (if any) then
+    /// includes `uuid`.
+    pub uuids: bool,
+    /// Which of the generated enums and structs are marked `#[non_exhaustive]`.
+    pub non_exhaustive: NonExhaustive,
+    /// Directory for caching the generated output (keyed by a hash of the spec
+    /// and these options). If this is set, then `Emitter::generate_cached` skips
+    /// codegen entirely for an unchanged spec and restores the cached files
+    /// (without removing any stale files in the working directory).
+    pub cache_dir: Option<PathBuf>,
 
     /* MARK: Private fields. */
     /// Base URL for the API.
@@ -55,14 +177,158 @@ pub struct EmitterState {
     pub(super) def_mods: RefCell<BTreeMap<PathBuf, Vec<ApiObject>>>,
     /// Relative paths
     pub(super) rel_paths: RefCell<HashSet<String>>,
+    /// Type names claimed in each module (along with the address of the claiming
+    /// schema). Used for disambiguating names when `prefer_titles` is set.
+    pub(super) type_names: RefCell<HashMap<String, HashMap<String, usize>>>,
     /// Media ranges and the corresponding decoders we've registered.
     media_coders: RefCell<Vec<MediaCoder>>,
+    /// Security schemes whose credentials can be read from the environment.
+    env_schemes: RefCell<Vec<EnvScheme>>,
+    /// Names and locations of the keys for `apiKey` security schemes.
+    api_keys: RefCell<Vec<ApiKeyScheme>>,
     /// Unit types used by builders.
     unit_types: RefCell<BTreeSet<String>>,
+    /// Operations (mapped by their method names) grouped by their tags.
+    op_trait_items: RefCell<BTreeMap<String, BTreeMap<String, OpTraitItem>>>,
+    /// Modules (named after the tags) for the definitions (mapped by their names).
+    /// This is filled only when `tag_modules` is set.
+    pub(super) def_tags: RefCell<BTreeMap<String, String>>,
+    /// Callbacks (`x-callbacks`) mapped by the IDs of their operations.
+    pub(super) callbacks: RefCell<BTreeMap<String, Vec<CallbackItem>>>,
     /// Generated CLI YAML for clap.
     cli_yaml: RefCell<String>,
     /// Generated match arms for clap subcommands and matches.
     cli_match_arms: RefCell<String>,
+    /// Files written (and not removed) during codegen.
+    written_files: RefCell<BTreeSet<PathBuf>>,
+}
+
+/// Operation in a trait generated for some tag.
+#[derive(Debug, Clone)]
+struct OpTraitItem {
+    /// Description of this operation (if any).
+    description: Option<String>,
+    /// Type of the builder (with all its requirements satisfied).
+    builder_ty: String,
+}
+
+/// Handler in the callbacks trait generated for some operation.
+#[derive(Debug, Clone)]
+pub(super) struct CallbackItem {
+    /// Name of the handler method.
+    pub name: String,
+    /// Description of this callback (if any).
+    pub description: Option<String>,
+    /// HTTP method of the request made by the API.
+    pub method: HttpMethod,
+    /// URL (expression) of the request made by the API.
+    pub url: String,
+    /// Type of the request body (if any).
+    pub payload_ty: Option<String>,
+    /// Type of the response body (if any).
+    pub response_ty: Option<String>,
+}
+
+/// Impl and builders rendered for an object (see `EmitterState::add_builders`).
+struct RenderedImpl {
+    /// Impl of the object, with the constructors of its builders.
+    impl_content: String,
+    /// Builder structs along with their impls.
+    builders: Vec<RenderedBuilder>,
+    /// Clap subcommand for the object (if we're generating a CLI).
+    cli_yaml: String,
+    /// Match arms for the subcommand (if we're generating a CLI).
+    match_arms: String,
+}
+
+/// Builder rendered for an object, along with the stuff it needs elsewhere.
+struct RenderedBuilder {
+    /// Builder struct along with its impls.
+    content: String,
+    /// Type paths and definitions of the parameter enums used by this builder.
+    param_enums: Vec<(String, String)>,
+    /// Unit types for the required fields of this builder.
+    unit_types: Vec<String>,
+    /// Method name and tags of the operation (if we're generating traits).
+    op_trait_item: Option<(String, Vec<String>, OpTraitItem)>,
+}
+
+impl RenderedImpl {
+    fn new(
+        object: &ApiObject,
+        module_prefix: &str,
+        safe_builders: bool,
+        is_cli: bool,
+        op_traits: bool,
+    ) -> Result<Self, fmt::Error> {
+        let repr = object.impl_repr(module_prefix, safe_builders);
+        let mut builders = Vec::with_capacity(repr.builders.len());
+        for builder in &*repr.builders {
+            let mut content = String::from("\n");
+            write!(content, "{}", builder)?;
+            content.push('\n');
+            let inner_repr = builder.impl_repr();
+            write!(content, "{}", inner_repr)?;
+            if is_cli {
+                inner_repr.write_arg_parsing(&mut content)?;
+            }
+
+            let mut param_enums = vec![];
+            for param in builder.global_params.iter().chain(builder.local_params) {
+                if !param.variants.is_empty() {
+                    let mut def = String::new();
+                    param.write_enum(&mut def)?;
+                    param_enums.push((param.ty_path.clone(), def));
+                }
+            }
+
+            let unit_types = builder
+                .struct_fields_iter()
+                .filter(|f| f.prop.is_required())
+                .map(|f| object::to_pascal_case(f.name))
+                .collect();
+
+            let op_trait_item = if op_traits {
+                inner_repr
+                    .op_trait_item(&object.path)
+                    .map(|(name, builder_ty)| {
+                        let tags = if builder.tags.is_empty() {
+                            vec![String::from("default")]
+                        } else {
+                            builder.tags.to_vec()
+                        };
+
+                        let item = OpTraitItem {
+                            description: builder.description.map(String::from),
+                            builder_ty,
+                        };
+                        (name, tags, item)
+                    })
+            } else {
+                None
+            };
+
+            builders.push(RenderedBuilder {
+                content,
+                param_enums,
+                unit_types,
+                op_trait_item,
+            });
+        }
+
+        let (mut cli_yaml, mut match_arms) = (String::new(), String::new());
+        if is_cli {
+            repr.write_clap_yaml(&mut cli_yaml)?;
+            repr.write_arg_match_arms(&mut match_arms)?;
+        }
+
+        Ok(RenderedImpl {
+            impl_content: format!("\n{}", repr),
+            builders,
+            cli_yaml,
+            match_arms,
+        })
+    }
 }
 
 /// Indicates a child module in codegen working directory.
@@ -98,10 +364,111 @@ impl EmitterState {
         *self.mod_children.borrow_mut() = Default::default();
         *self.def_mods.borrow_mut() = Default::default();
         *self.rel_paths.borrow_mut() = Default::default();
+        *self.type_names.borrow_mut() = Default::default();
         *self.unit_types.borrow_mut() = Default::default();
+        *self.op_trait_items.borrow_mut() = Default::default();
+        *self.def_tags.borrow_mut() = Default::default();
+        *self.callbacks.borrow_mut() = Default::default();
         *self.cli_yaml.borrow_mut() = Default::default();
         *self.cli_match_arms.borrow_mut() = Default::default();
         *self.media_coders.borrow_mut() = Default::default();
+        *self.env_schemes.borrow_mut() = Default::default();
+        *self.api_keys.borrow_mut() = Default::default();
+        *self.written_files.borrow_mut() = Default::default();
+    }
+
+    /// Writes the options which affect the generated output (for keying the cache).
+    /// The crate metadata (if any) is written after its unset fields are inferred,
+    /// so that it's the same before and after a session.
+    ///
+    /// **NOTE:** The state is destructured without `..`, so that new fields
+    /// can't be added without deciding whether they belong in the key.
+    pub(crate) fn write_cache_options(&self, w: &mut impl fmt::Write) -> Result<(), Error> {
+        let EmitterState {
+            // Where the output goes (or is cached) doesn't change it.
+            working_dir: _,
+            cache_dir: _,
+            ns_sep,
+            mod_prefix,
+            prefer_titles,
+            op_traits,
+            standalone,
+            tag_modules,
+            unused_definitions,
+            non_zero_integers,
+            env_prefix,
+            absent_nullable,
+            array_strings_up_to,
+            object_examples,
+            decimal_strings,
+            object_merge,
+            query_structs,
+            union_fallback,
+            enum_fallback,
+            base64_bytes,
+            decoded_bytes,
+            case_strategy,
+            blocking_client,
+            mock_client,
+            safe_builders,
+            map_type,
+            keep_unknown_fields,
+            chrono_dates,
+            uuids,
+            non_exhaustive,
+            // These are set from the spec, which is hashed separately.
+            base_url: _,
+            default_encoding: _,
+            // Inferred below.
+            #[cfg(feature = "cli")]
+                crate_meta: _,
+            // Session state.
+            mod_children: _,
+            def_mods: _,
+            rel_paths: _,
+            type_names: _,
+            media_coders: _,
+            env_schemes: _,
+            api_keys: _,
+            unit_types: _,
+            op_trait_items: _,
+            def_tags: _,
+            callbacks: _,
+            cli_yaml: _,
+            cli_match_arms: _,
+            written_files: _,
+        } = self;
+
+        writeln!(w, "ns_sep: {:?}", ns_sep)?;
+        writeln!(w, "mod_prefix: {:?}", mod_prefix)?;
+        writeln!(w, "prefer_titles: {}", prefer_titles)?;
+        writeln!(w, "op_traits: {}", op_traits)?;
+        writeln!(w, "standalone: {}", standalone)?;
+        writeln!(w, "tag_modules: {}", tag_modules)?;
+        writeln!(w, "unused_definitions: {:?}", unused_definitions)?;
+        writeln!(w, "non_zero_integers: {}", non_zero_integers)?;
+        writeln!(w, "env_prefix: {:?}", env_prefix)?;
+        writeln!(w, "absent_nullable: {:?}", absent_nullable)?;
+        writeln!(w, "array_strings_up_to: {:?}", array_strings_up_to)?;
+        writeln!(w, "object_examples: {}", object_examples)?;
+        writeln!(w, "decimal_strings: {}", decimal_strings)?;
+        writeln!(w, "object_merge: {}", object_merge)?;
+        writeln!(w, "query_structs: {}", query_structs)?;
+        writeln!(w, "union_fallback: {}", union_fallback)?;
+        writeln!(w, "enum_fallback: {}", enum_fallback)?;
+        writeln!(w, "base64_bytes: {}", base64_bytes)?;
+        writeln!(w, "decoded_bytes: {}", decoded_bytes)?;
+        writeln!(w, "case_strategy: {:?}", case_strategy)?;
+        writeln!(w, "blocking_client: {}", blocking_client)?;
+        writeln!(w, "mock_client: {}", mock_client)?;
+        writeln!(w, "safe_builders: {}", safe_builders)?;
+        writeln!(w, "map_type: {:?}", map_type)?;
+        writeln!(w, "keep_unknown_fields: {}", keep_unknown_fields)?;
+        writeln!(w, "chrono_dates: {}", chrono_dates)?;
+        writeln!(w, "uuids: {}", uuids)?;
+        writeln!(w, "non_exhaustive: {:?}", non_exhaustive)?;
+        writeln!(w, "crate_meta: {:?}", self.infer_crate_meta()?.borrow())?;
+        Ok(())
     }
 
     /// Sets the media type information for encoder/decoders.
@@ -120,18 +487,239 @@ impl EmitterState {
             .collect();
     }
 
+    /// Sets the security schemes whose credentials are read from the environment
+    /// by the generated client (if `env_prefix` has been set). Schemes present in
+    /// all the alternatives of the global security requirement are required.
+    ///
+    /// This also records where the keys for `apiKey` schemes should be sent.
+    pub(crate) fn set_security_info(
+        &self,
+        schemes: &BTreeMap<String, SecurityScheme>,
+        security: &[BTreeMap<String, BTreeSet<String>>],
+    ) {
+        *self.api_keys.borrow_mut() = schemes
+            .iter()
+            .filter(|(_, scheme)| scheme.type_ == "apiKey")
+            .filter_map(|(name, scheme)| {
+                let query = match scheme.in_.as_deref() {
+                    Some("header") => false,
+                    Some("query") => true,
+                    l => {
+                        warn!(
+                            "Skipping API key {:?} with unsupported location {:?}",
+                            name, l
+                        );
+                        return None;
+                    }
+                };
+
+                Some(ApiKeyScheme {
+                    scheme: name.clone(),
+                    name: scheme.name.clone().filter(|n| !n.is_empty())?,
+                    query,
+                })
+            })
+            .collect();
+
+        let prefix = match self.env_prefix.as_ref() {
+            Some(p) => p,
+            None => return,
+        };
+
+        *self.env_schemes.borrow_mut() = schemes
+            .iter()
+            .filter_map(|(name, scheme)| {
+                let var = format!("{}_{}", prefix, name).to_shouty_snake_case();
+                let (var, password, credential) = match scheme.type_.as_str() {
+                    "apiKey" => (var, None, String::from("Credential::ApiKey(v)")),
+                    "oauth2" => (var + "_TOKEN", None, String::from("Credential::Bearer(v)")),
+                    "basic" => {
+                        let password = var.clone() + "_PASSWORD";
+                        let credential = format!(
+                            "Credential::Basic {{ username: v, password: env_var({:?}) }}",
+                            password
+                        );
+                        (var + "_USERNAME", Some(password), credential)
+                    }
+                    t => {
+                        warn!("Skipping unsupported security scheme {:?} ({:?})", name, t);
+                        return None;
+                    }
+                };
+
+                let required =
+                    !security.is_empty() && security.iter().all(|r| r.contains_key(name));
+                let mut doc = format!("`{}`", var);
+                if let Some(p) = password {
+                    let _ = write!(doc, " and `{}`", p);
+                }
+
+                let _ = write!(doc, " for `{}`", name);
+                if required {
+                    doc.push_str(" (required)");
+                }
+
+                Some(EnvScheme {
+                    name: name.clone(),
+                    var,
+                    credential,
+                    required,
+                    doc,
+                })
+            })
+            .collect();
+    }
+
+    /// Once the emitter has collected requirements for paths, we can use this
+    /// method to find the definitions which aren't reachable from any operation
+    /// (through parameters, responses and fields) and warn about them or drop them
+    /// (along with the modules which end up empty) based on `unused_definitions`.
+    pub(crate) fn check_unused_definitions(&self) -> Result<(), Error> {
+        if self.unused_definitions == UnusedDefinitions::Keep {
+            return Ok(());
+        }
+
+        let prefix = self.normalized_mod_prefix();
+        let mut def_mods = self.def_mods.borrow_mut();
+        let unused = {
+            let objects = def_mods
+                .values()
+                .flatten()
+                .map(|o| (format!("{}::{}", o.path, o.name), o))
+                .collect::<HashMap<_, _>>();
+
+            // Objects with operations (and those used by callbacks) are where we start.
+            let mut pending = objects
+                .iter()
+                .filter(|(_, o)| !o.paths.is_empty())
+                .map(|(k, _)| k.as_str())
+                .collect::<Vec<_>>();
+            let callbacks = self.callbacks.borrow();
+            let callback_types = callbacks
+                .values()
+                .flatten()
+                .flat_map(|c| c.payload_ty.iter().chain(&c.response_ty));
+            for ty in callback_types {
+                pending.extend(
+                    ty.split(|c: char| !(c.is_alphanumeric() || c == '_' || c == ':'))
+                        .filter_map(|p| p.strip_prefix(&prefix))
+                        .filter_map(|p| objects.get_key_value(p))
+                        .map(|(k, _)| k.as_str()),
+                );
+            }
+
+            let mut reachable = HashSet::new();
+            while let Some(key) = pending.pop() {
+                if !reachable.insert(key) {
+                    continue;
+                }
+
+                let obj = objects[key];
+                let field_types = match &obj.inner {
+                    ObjectContainer::Struct { fields } => {
+                        fields.iter().map(|f| f.ty_path.as_str()).collect()
+                    }
+                    ObjectContainer::Union { variants, .. } => {
+                        variants.iter().map(|v| v.ty_path.as_str()).collect()
+                    }
+                    ObjectContainer::Enum { .. } => vec![],
+                };
+                let op_types = obj.paths.values().flat_map(|ops| {
+                    ops.params
+                        .iter()
+                        .chain(ops.req.values().flat_map(|r| &r.params))
+                        .map(|p| p.ty_path.as_str())
+                        .chain(
+                            ops.req
+                                .values()
+                                .filter_map(|r| r.response.ty_path.as_deref()),
+                        )
+                });
+
+                // Type paths could be nested (`Vec<BTreeMap<String, crate::foo::Foo>>`).
+                for ty in field_types.into_iter().chain(op_types) {
+                    pending.extend(
+                        ty.split(|c: char| !(c.is_alphanumeric() || c == '_' || c == ':'))
+                            .filter_map(|p| p.strip_prefix(&prefix))
+                            .filter_map(|p| objects.get_key_value(p))
+                            .map(|(k, _)| k.as_str()),
+                    );
+                }
+            }
+
+            objects
+                .keys()
+                .filter(|k| !reachable.contains(k.as_str()))
+                .cloned()
+                .sorted()
+                .collect::<Vec<_>>()
+        };
+
+        if unused.is_empty() {
+            return Ok(());
+        }
+
+        warn!(
+            "Found {} definition(s) not used by any operation: {}",
+            unused.len(),
+            unused.join(", ")
+        );
+
+        if self.unused_definitions != UnusedDefinitions::Omit {
+            return Ok(());
+        }
+
+        let mut mods = self.mod_children.borrow_mut();
+        def_mods.retain(|mod_path, objects| {
+            objects.retain(|o| !unused.contains(&format!("{}::{}", o.path, o.name)));
+            if !objects.is_empty() {
+                return true;
+            }
+
+            // Remove the module declaration and its parents (if they end up empty).
+            let rel_path = mod_path
+                .with_extension("")
+                .strip_prefix(&self.working_dir)
+                .map(Path::to_path_buf)
+                .unwrap_or_default();
+            let mut path = rel_path.as_path();
+            while let (Some(parent), Some(name)) = (path.parent(), path.file_name()) {
+                let is_empty = match mods.get_mut(parent) {
+                    Some(children) => {
+                        children.retain(|c| c.name.as_str() != name);
+                        children.is_empty()
+                    }
+                    None => false,
+                };
+
+                if !is_empty || parent.parent().is_none() {
+                    break;
+                }
+
+                mods.remove(parent);
+                path = parent;
+            }
+
+            false
+        });
+
+        Ok(())
+    }
+
     /// Once the emitter has generated the struct definitions,
     /// we can call this method to generate the module declarations
     /// from root.
     pub(crate) fn declare_modules(&self) -> Result<(), Error> {
         info!("Writing module declarations.");
         let is_app = self.is_cli()?;
+        let allow_deprecated = self.has_deprecated_items();
         let mods = self.mod_children.borrow();
         for (rel_parent, children) in &*mods {
             let mut mod_path = self.working_dir.join(rel_parent);
             let mut contents = String::new();
+            let is_root = rel_parent.parent().is_none();
 
-            if rel_parent.parent().is_none() && self.needs_root_module() {
+            if is_root && self.needs_root_module() {
                 mod_path = self.root_module_path();
                 contents.push_str(
                     "
@@ -157,6 +745,12 @@ pub mod cli {
             }
 
             for child in children.iter().sorted_by(|a, b| a.name.cmp(&b.name)) {
+                // Generated code uses deprecated items all the time. Lint levels
+                // are inherited, so it's enough to allow them in top-level modules.
+                if is_root && allow_deprecated {
+                    contents.push_str("\n#[allow(deprecated)]");
+                }
+
                 writeln!(
                     contents,
                     "
@@ -164,7 +758,8 @@ pub mod {name} {{
     include!(\"./{path}\");
 }}",
                     name = child.name,
-                    path = if child.is_final {
+                    // Tag modules are bundled into a single file later.
+                    path = if child.is_final || (is_root && self.tag_modules) {
                         child.name.clone() + ".rs"
                     } else {
                         child.name.clone() + "/mod.rs"
@@ -178,16 +773,32 @@ pub mod {name} {{
         Ok(())
     }
 
+    /// Checks whether any of the definitions (or their fields) are deprecated.
+    fn has_deprecated_items(&self) -> bool {
+        self.def_mods.borrow().values().flatten().any(|o| {
+            o.deprecated || matches!(&o.inner, ObjectContainer::Struct { fields } if fields.iter().any(|f| f.deprecated))
+        })
+    }
+
     /// Once the emitter has generated the struct definitions,
     /// we can call this method to write the definitions to leaf modules.
     pub(crate) fn write_definitions(&self) -> Result<(), Error> {
         let def_mods = self.def_mods.borrow();
         info!("Writing definitions.");
-        for (i, (mod_path, object)) in def_mods
+        let strategy = self.case_strategy;
+        // Objects are rendered in parallel, but written in their original order.
+        let rendered = def_mods
             .iter()
             .flat_map(move |(p, l)| l.iter().map(move |o| (p, o)).enumerate())
-        {
-            let contents = object.to_string();
+            .collect::<Vec<_>>()
+            .into_par_iter()
+            .map(|(i, (mod_path, object))| {
+                let _case = object::set_case_strategy(strategy);
+                (i, mod_path, object.to_string())
+            })
+            .collect::<Vec<_>>();
+
+        for (i, mod_path, contents) in rendered {
             if i == 0 {
                 self.write_contents(&contents, mod_path)?;
             } else {
@@ -207,41 +818,57 @@ pub mod {name} {{
         let def_mods = self.def_mods.borrow();
         let mut cli_yaml = self.cli_yaml.borrow_mut();
         let mut match_arms = self.cli_match_arms.borrow_mut();
+        let mut op_trait_items = self.op_trait_items.borrow_mut();
         let is_cli = self.is_cli()?;
 
-        for (mod_path, object) in def_mods
+        // Parameter enums which have been written to some module.
+        let mut param_enums = HashSet::new();
+
+        let objects = def_mods
             .iter()
             .flat_map(move |(p, l)| l.iter().map(move |o| (p, o)))
-        {
+            .collect::<Vec<_>>();
+        // Impls and builders are rendered in parallel. Everything that depends
+        // on the objects before them (shared enums, trait items) is done below.
+        let (strategy, safe_builders, op_traits) =
+            (self.case_strategy, self.safe_builders, self.op_traits);
+        let rendered = objects
+            .par_iter()
+            .map(|(_, object)| {
+                let _case = object::set_case_strategy(strategy);
+                RenderedImpl::new(object, &module_prefix, safe_builders, is_cli, op_traits)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        for ((mod_path, object), rendered) in objects.into_iter().zip(rendered) {
             let mut builder_content = String::new();
-            let repr = object.impl_repr(&module_prefix);
-            for builder in &*repr.builders {
-                builder
-                    .struct_fields_iter()
-                    .filter(|f| f.prop.is_required())
-                    .for_each(|f| {
-                        unit_types.insert(object::to_pascal_case(f.name));
-                    });
-
-                builder_content.push('\n');
-                let _ = write!(builder_content, "{}", builder);
-                builder_content.push('\n');
-                let inner_repr = builder.impl_repr();
-                let _ = write!(builder_content, "{}", inner_repr);
-                if is_cli {
-                    inner_repr.write_arg_parsing(&mut builder_content)?;
+            for builder in rendered.builders {
+                for (ty_path, def) in builder.param_enums {
+                    if param_enums.insert((mod_path, ty_path)) {
+                        builder_content.push_str(&def);
+                    }
                 }
-            }
 
-            if is_cli {
-                repr.write_clap_yaml(&mut *cli_yaml)?;
-                repr.write_arg_match_arms(&mut *match_arms)?;
+                unit_types.extend(builder.unit_types);
+                builder_content.push_str(&builder.content);
+                if let Some((name, tags, item)) = builder.op_trait_item {
+                    for tag in tags {
+                        let items = op_trait_items.entry(tag).or_default();
+                        // Operations from different objects could have the same name.
+                        let name = if items.contains_key(&name) {
+                            format!("{}_{}", object::to_snake_case(&object.name), name)
+                        } else {
+                            name.clone()
+                        };
+
+                        items.insert(name, item.clone());
+                    }
+                }
             }
 
-            let mut impl_content = String::from("\n");
-            write!(impl_content, "{}", repr)?;
-
-            self.append_contents(&impl_content, mod_path)?;
+            cli_yaml.push_str(&rendered.cli_yaml);
+            match_arms.push_str(&rendered.match_arms);
+            self.append_contents(&rendered.impl_content, mod_path)?;
             self.append_contents(&builder_content, mod_path)?;
         }
 
@@ -283,7 +910,13 @@ pub mod util {
         self.write_contents(&content, &module)?;
 
         module.set_file_name("util.rs");
-        let contents = template::render(Template::UTIL_MOD, &EmptyContext {})?;
+        let contents = template::render(
+            Template::UTIL_MOD,
+            &UtilModContext {
+                base64_bytes: self.base64_bytes,
+                decoded_bytes: self.decoded_bytes,
+            },
+        )?;
         self.write_contents(&contents, &module)?;
 
         self.add_cli_deps_if_needed()?;
@@ -299,12 +932,336 @@ pub mod util {
                 mod_prefix: &self.normalized_mod_prefix(),
                 media_coders: &self.media_coders.borrow(),
                 base_url: self.base_url.borrow().as_str(),
+                from_env: self.env_prefix.is_some(),
+                security_schemes: &self.env_schemes.borrow(),
+                api_keys: &self.api_keys.borrow(),
+                blocking: self.blocking_client,
+                mock_client: self.mock_client,
             },
         )?;
 
         self.append_contents(&contents, &module)
     }
 
+    /// Adds the `apis` module (if operation traits have been requested) containing
+    /// a trait for each tag, along with a blanket impl for all API clients.
+    pub(crate) fn add_op_traits(&self) -> Result<(), Error> {
+        let items = self.op_trait_items.borrow();
+        if items.is_empty() {
+            return Ok(());
+        }
+
+        let prefix = self.normalized_mod_prefix();
+        let mut module = self.root_module_path();
+        self.append_contents(
+            "
+pub mod apis {
+    include!(\"./apis.rs\");
+}
+",
+            &module,
+        )?;
+
+        let mut content = format!("\nuse {}client::Sendable;\n", prefix);
+        for (tag, ops) in &*items {
+            let trait_name = format!("{}Api", object::to_pascal_case(tag));
+            let _ = write!(
+                content,
+                "
+/// Operations tagged with `{tag}`.
+///
+/// This trait is object-safe (so it can be used as `Box<dyn {name}<reqwest::Client>>`).
+/// To keep it that way, methods are not generic - each method takes the builder for
+/// its operation (with all the required fields and parameters set) and returns
+/// a boxed future (through `async_trait`).
+#[async_trait::async_trait]
+pub trait {name}<Client: {prefix}client::ApiClient + Send + Sync + 'static>: Send + Sync {{",
+                tag = tag,
+                name = trait_name,
+                prefix = prefix
+            );
+
+            let mut impl_content = format!(
+                "
+#[async_trait::async_trait]
+impl<Client: {prefix}client::ApiClient + Send + Sync + 'static> {name}<Client> for Client {{",
+                name = trait_name,
+                prefix = prefix
+            );
+
+            for (i, (method, item)) in ops.iter().enumerate() {
+                if i > 0 {
+                    content.push('\n');
+                    impl_content.push('\n');
+                }
+
+                ApiObject::write_docs(item.description.as_ref(), &mut content, 1)?;
+                if item.description.is_none() {
+                    content.push('\n');
+                }
+
+                let signature = format!(
+                    "async fn {method}(&self, op: {ty}) -> Result<<{ty} as {prefix}client::Sendable<Client>>::Output, {prefix}client::ApiError<Client::Response>>",
+                    method = method,
+                    ty = &item.builder_ty,
+                    prefix = prefix
+                );
+
+                let _ = write!(content, "    {};", signature);
+                let _ = write!(
+                    impl_content,
+                    "
+    {} {{
+        Ok(op.send(self).await?.object)
+    }}",
+                    signature
+                );
+            }
+
+            content.push_str("\n}\n");
+            content.push_str(&impl_content);
+            content.push_str("\n}\n");
+        }
+
+        module.set_file_name("apis.rs");
+        self.write_contents(&content, &module)
+    }
+
+    /// Adds the `callbacks` module (if some operations have `x-callbacks`) containing
+    /// a trait for each of those operations, with a handler for each callback.
+    pub(crate) fn add_callbacks(&self) -> Result<(), Error> {
+        let callbacks = self.callbacks.borrow();
+        if callbacks.is_empty() {
+            return Ok(());
+        }
+
+        let mut module = self.root_module_path();
+        self.append_contents(
+            "
+pub mod callbacks {
+    include!(\"./callbacks.rs\");
+}
+",
+            &module,
+        )?;
+
+        let mut content = String::new();
+        for (op_id, items) in &*callbacks {
+            let _ = write!(
+                content,
+                "
+/// Handlers for the requests made by the API back to the client after `{id}`.
+///
+/// The generated client doesn't receive these requests, so this is meant to be
+/// implemented by the server (in whichever web framework) receiving them.
+pub trait {name}Callbacks {{",
+                id = op_id,
+                name = object::to_pascal_case(op_id)
+            );
+
+            for (i, item) in items.iter().enumerate() {
+                if i > 0 {
+                    content.push('\n');
+                }
+
+                let mut docs = item.description.clone().unwrap_or_default();
+                if !docs.is_empty() {
+                    docs.push_str("\n\n");
+                }
+
+                let _ = write!(
+                    docs,
+                    "`{} {}`",
+                    item.method.to_string().to_uppercase(),
+                    item.url
+                );
+                ApiObject::write_docs(Some(&docs), &mut content, 1)?;
+                let _ = write!(content, "    fn {}(&self", item.name);
+                if let Some(ty) = &item.payload_ty {
+                    let _ = write!(content, ", payload: {}", ty);
+                }
+
+                content.push(')');
+                if let Some(ty) = &item.response_ty {
+                    let _ = write!(content, " -> {}", ty);
+                }
+
+                content.push(';');
+            }
+
+            content.push_str("\n}\n");
+        }
+
+        module.set_file_name("callbacks.rs");
+        self.write_contents(&content, &module)
+    }
+
+    /// Adds a `prelude` module to root, re-exporting the client traits, error
+    /// and response types along with the definitions (whose names are unique).
+    pub(crate) fn add_prelude(&self) -> Result<(), Error> {
+        let prefix = self.normalized_mod_prefix();
+        let def_mods = self.def_mods.borrow();
+        let mut names = HashMap::new();
+        for obj in def_mods.values().flatten() {
+            *names.entry(obj.name.as_str()).or_insert(0) += 1;
+        }
+
+        let mut content = String::from(
+            "
+/// Commonly used traits and types, so that a single glob import gets you going.
+///
+/// This re-exports the client traits (`ApiClient`, `Sendable`, etc.), `ApiError`,
+/// `ResponseWrapper`, the client configuration, the mock client (if it's generated),
+/// the helper types from `util` and all the definitions whose names are unique
+/// across modules.
+#[allow(unused_imports",
+        );
+        if self.has_deprecated_items() {
+            content.push_str(", deprecated");
+        }
+
+        content.push_str(")]\npub mod prelude {");
+
+        let mut client_items = PRELUDE_CLIENT_ITEMS.to_vec();
+        if self.mock_client {
+            client_items.extend(PRELUDE_MOCK_ITEMS);
+            client_items.sort_unstable();
+        }
+
+        let _ = write!(
+            content,
+            "
+    pub use {prefix}client::{{{}}};
+    pub use {prefix}util::{{{}}};",
+            client_items.join(", "),
+            PRELUDE_UTIL_ITEMS.join(", "),
+            prefix = prefix
+        );
+
+        if !self.op_trait_items.borrow().is_empty() {
+            let _ = write!(content, "\n    pub use {}apis::*;", prefix);
+        }
+
+        if !self.callbacks.borrow().is_empty() {
+            let _ = write!(content, "\n    pub use {}callbacks::*;", prefix);
+        }
+
+        for obj in def_mods
+            .values()
+            .flatten()
+            .filter(|o| names[o.name.as_str()] == 1)
+            .filter(|o| {
+                !client_items
+                    .iter()
+                    .chain(PRELUDE_UTIL_ITEMS)
+                    .any(|&n| n == o.name)
+            })
+            .sorted_by(|a, b| a.name.cmp(&b.name))
+        {
+            let _ = write!(
+                content,
+                "\n    pub use {}{}::{};",
+                prefix, obj.path, obj.name
+            );
+        }
+
+        content.push_str("\n}\n");
+        self.append_contents(&content, &self.root_module_path())
+    }
+
+    /// Once everything has been generated, this inlines the modules included
+    /// (recursively) from the `mod.rs` of each tag module into a file for that
+    /// module (if `tag_modules` is set) and from the root module (if `standalone`
+    /// is set), and removes the included files along with the directories that
+    /// end up empty.
+    pub(crate) fn bundle_modules(&self) -> Result<(), Error> {
+        if self.tag_modules {
+            let tags = self
+                .mod_children
+                .borrow()
+                .get(Path::new(""))
+                .into_iter()
+                .flatten()
+                .filter(|c| !c.is_final)
+                .map(|c| c.name.clone())
+                .collect::<Vec<_>>();
+            for tag in tags {
+                info!("Bundling modules into {} module.", tag);
+                let path = self.working_dir.join(&tag).join("mod.rs");
+                self.bundle_module(&path, &self.working_dir.join(tag + ".rs"))?;
+            }
+        }
+
+        if !self.standalone {
+            return Ok(());
+        }
+
+        info!("Bundling modules into root module.");
+        let root = self.root_module_path();
+        self.bundle_module(&root, &root)
+    }
+
+    /// Writes the contents of the given module (with its includes inlined) to
+    /// the given destination and removes the included files (along with the
+    /// module itself, if it's been moved) and the directories that end up empty.
+    fn bundle_module(&self, path: &Path, dest: &Path) -> Result<(), Error> {
+        let mut included = vec![];
+        let contents = Self::inline_includes(path, &mut included)?;
+        self.write_contents(&contents, dest)?;
+        if path != dest {
+            included.push(path.into());
+        }
+
+        let mut written = self.written_files.borrow_mut();
+        for path in &included {
+            fs::remove_file(path)?;
+            written.remove(path);
+        }
+
+        // Deepest directories go first, so that their parents can become empty.
+        for dir in included
+            .iter()
+            .filter_map(|p| p.parent())
+            .filter(|&d| d != self.working_dir)
+            .unique()
+            .sorted_by_key(|d| std::cmp::Reverse(d.components().count()))
+        {
+            if fs::read_dir(dir)?.next().is_none() {
+                fs::remove_dir(dir)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns the contents of the module at the given path with all its
+    /// `include!` lines replaced by the contents of the included modules.
+    /// The paths of the included files are collected into the given vector.
+    fn inline_includes(path: &Path, included: &mut Vec<PathBuf>) -> Result<String, Error> {
+        let contents = fs::read_to_string(path)?;
+        let parent = path.parent().unwrap_or_else(|| Path::new("."));
+        let mut bundled = String::with_capacity(contents.len());
+        for line in contents.lines() {
+            let child = line
+                .trim()
+                .strip_prefix("include!(\"./")
+                .and_then(|l| l.strip_suffix("\");"));
+            match child {
+                Some(child) => {
+                    let child = parent.join(child);
+                    bundled.push_str(&Self::inline_includes(&child, included)?);
+                    included.push(child);
+                }
+                None => {
+                    bundled.push_str(line);
+                    bundled.push('\n');
+                }
+            }
+        }
+
+        Ok(bundled)
+    }
+
     /// Writes the given contents to a file at the given path (truncating the file if it exists).
     fn write_contents(&self, contents: &str, path: &Path) -> Result<(), Error> {
         let mut fd = OpenOptions::new()
@@ -313,6 +1270,7 @@ pub mod util {
             .truncate(true)
             .open(path)?;
         fd.write_all(contents.as_bytes())?;
+        self.written_files.borrow_mut().insert(path.into());
         Ok(())
     }
 
@@ -325,6 +1283,86 @@ pub mod util {
         Ok(())
     }
 
+    /// Restores the output cached for the given key (if any) into the working
+    /// directory and returns whether it was found. Files whose contents haven't
+    /// changed are left alone, so that their timestamps are preserved.
+    pub(crate) fn restore_cached_output(&self, key: &str) -> Result<bool, Error> {
+        let cached = match self.cache_dir.as_ref() {
+            Some(dir) => dir.join(key),
+            None => return Ok(false),
+        };
+
+        if !cached.is_dir() {
+            return Ok(false);
+        }
+
+        info!("Restoring cached output from {}", cached.display());
+        let mut dirs = vec![cached.clone()];
+        while let Some(dir) = dirs.pop() {
+            for entry in fs::read_dir(&dir)? {
+                let path = entry?.path();
+                if path.is_dir() {
+                    dirs.push(path);
+                    continue;
+                }
+
+                let target = self.working_dir.join(path.strip_prefix(&cached)?);
+                let contents = fs::read(&path)?;
+                if fs::read(&target).ok().as_ref() == Some(&contents) {
+                    continue;
+                }
+
+                if let Some(parent) = target.parent() {
+                    fs::create_dir_all(parent)?;
+                }
+
+                fs::write(&target, &contents)?;
+            }
+        }
+
+        Ok(true)
+    }
+
+    /// Copies the files written during codegen into the cache directory (if any)
+    /// under the given key.
+    pub(crate) fn cache_output(&self, key: &str) -> Result<(), Error> {
+        let dir = match self.cache_dir.as_ref() {
+            Some(d) => d,
+            None => return Ok(()),
+        };
+
+        // Copy into a temporary directory first, so that an interrupted run
+        // doesn't leave a partial entry behind.
+        let tmp = dir.join(format!("{}.tmp{}", key, std::process::id()));
+        if tmp.exists() {
+            fs::remove_dir_all(&tmp)?;
+        }
+
+        for path in self.written_files.borrow().iter() {
+            let rel = match path.strip_prefix(&self.working_dir) {
+                Ok(p) => p,
+                Err(_) => continue,
+            };
+
+            let target = tmp.join(rel);
+            if let Some(parent) = target.parent() {
+                fs::create_dir_all(parent)?;
+            }
+
+            fs::copy(path, target)?;
+        }
+
+        let cached = dir.join(key);
+        if cached.exists() {
+            fs::remove_dir_all(&tmp)?;
+        } else {
+            fs::create_dir_all(&tmp)?;
+            fs::rename(&tmp, cached)?;
+        }
+
+        Ok(())
+    }
+
     /// Adds CLI-related deps for the given object (if needed).
     fn add_cli_deps_if_needed(&self) -> Result<(), Error> {
         let root = self.root_module_path();
@@ -449,6 +1487,19 @@ impl EmitterState {
                     authors: &format!("{:?}", meta.authors.as_ref().unwrap()),
                     is_cli,
                     no_root,
+                    array_strings: self.array_strings_up_to.is_some(),
+                    decimal_strings: self.decimal_strings,
+                    base64_bytes: self.base64_bytes || self.decoded_bytes,
+                    blocking: self.blocking_client,
+                    index_map: self.map_type == MapType::IndexMap,
+                    chrono: self.chrono_dates,
+                    uuid: self.uuids,
+                    regex: self.def_mods.borrow().values().flatten().any(|o| {
+                        o.inner.is_struct()
+                            && o.fields()
+                                .iter()
+                                .any(|f| !f.key_patterns.is_empty() || f.pattern.is_some())
+                    }),
                 },
             )?;
 
@@ -532,6 +1583,33 @@ impl Clone for EmitterState {
             working_dir: self.working_dir.clone(),
             mod_prefix: self.mod_prefix,
             ns_sep: self.ns_sep,
+            prefer_titles: self.prefer_titles,
+            op_traits: self.op_traits,
+            standalone: self.standalone,
+            tag_modules: self.tag_modules,
+            unused_definitions: self.unused_definitions,
+            non_zero_integers: self.non_zero_integers,
+            env_prefix: self.env_prefix.clone(),
+            absent_nullable: self.absent_nullable,
+            array_strings_up_to: self.array_strings_up_to,
+            object_examples: self.object_examples,
+            decimal_strings: self.decimal_strings,
+            object_merge: self.object_merge,
+            query_structs: self.query_structs,
+            union_fallback: self.union_fallback,
+            enum_fallback: self.enum_fallback,
+            base64_bytes: self.base64_bytes,
+            decoded_bytes: self.decoded_bytes,
+            case_strategy: self.case_strategy,
+            blocking_client: self.blocking_client,
+            mock_client: self.mock_client,
+            safe_builders: self.safe_builders,
+            map_type: self.map_type,
+            keep_unknown_fields: self.keep_unknown_fields,
+            chrono_dates: self.chrono_dates,
+            uuids: self.uuids,
+            non_exhaustive: self.non_exhaustive,
+            cache_dir: self.cache_dir.clone(),
             #[cfg(feature = "cli")]
             crate_meta: self.crate_meta.clone(),
             base_url: self.base_url.clone(),
@@ -548,16 +1626,50 @@ impl Default for EmitterState {
             working_dir: PathBuf::from("."),
             mod_prefix: "crate::",
             ns_sep: ".",
+            prefer_titles: false,
+            op_traits: false,
+            standalone: false,
+            tag_modules: false,
+            unused_definitions: UnusedDefinitions::default(),
+            non_zero_integers: false,
+            env_prefix: None,
+            absent_nullable: AbsentNullable::default(),
+            array_strings_up_to: None,
+            object_examples: false,
+            decimal_strings: false,
+            object_merge: false,
+            query_structs: false,
+            union_fallback: false,
+            enum_fallback: false,
+            base64_bytes: false,
+            decoded_bytes: false,
+            case_strategy: CaseStrategy::default(),
+            blocking_client: false,
+            mock_client: false,
+            safe_builders: false,
+            map_type: MapType::default(),
+            keep_unknown_fields: false,
+            chrono_dates: false,
+            uuids: false,
+            non_exhaustive: NonExhaustive::default(),
+            cache_dir: None,
             #[cfg(feature = "cli")]
             crate_meta: Rc::new(RefCell::new(None)),
             base_url: RefCell::new("https://example.com".parse().expect("invalid URL?")),
             def_mods: RefCell::new(BTreeMap::new()),
             rel_paths: RefCell::new(HashSet::new()),
+            type_names: RefCell::new(HashMap::new()),
             mod_children: RefCell::new(HashMap::new()),
             unit_types: RefCell::new(BTreeSet::new()),
+            op_trait_items: RefCell::new(BTreeMap::new()),
+            def_tags: RefCell::new(BTreeMap::new()),
+            callbacks: RefCell::new(BTreeMap::new()),
             cli_yaml: RefCell::new(String::new()),
             cli_match_arms: RefCell::new(String::new()),
             media_coders: RefCell::new(vec![]),
+            env_schemes: RefCell::new(vec![]),
+            api_keys: RefCell::new(vec![]),
+            written_files: RefCell::new(BTreeSet::new()),
             default_encoding: RefCell::new(SpecFormat::Json),
         }
     }
@@ -585,6 +1697,14 @@ struct ManifestContext<'a> {
     authors: &'a str,
     is_cli: bool,
     no_root: bool,
+    array_strings: bool,
+    decimal_strings: bool,
+    regex: bool,
+    base64_bytes: bool,
+    blocking: bool,
+    index_map: bool,
+    chrono: bool,
+    uuid: bool,
 }
 
 #[derive(serde::Serialize)]
@@ -598,6 +1718,11 @@ struct ClientModContext<'a> {
     base_url: &'a str,
     mod_prefix: &'a str,
     media_coders: &'a [MediaCoder],
+    from_env: bool,
+    security_schemes: &'a [EnvScheme],
+    api_keys: &'a [ApiKeyScheme],
+    blocking: bool,
+    mock_client: bool,
 }
 
 #[derive(Debug, serde::Serialize)]
@@ -608,6 +1733,28 @@ struct MediaCoder {
     error_ty_path: String,
 }
 
+#[derive(Debug, serde::Serialize)]
+struct EnvScheme {
+    name: String,
+    var: String,
+    credential: String,
+    required: bool,
+    doc: String,
+}
+
+#[derive(Debug, serde::Serialize)]
+struct ApiKeyScheme {
+    scheme: String,
+    name: String,
+    query: bool,
+}
+
+#[derive(serde::Serialize)]
+struct UtilModContext {
+    base64_bytes: bool,
+    decoded_bytes: bool,
+}
+
 #[derive(serde::Serialize)]
 struct ClapYamlContext<'a> {
     name: &'a str,
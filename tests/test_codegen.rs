@@ -1,6 +1,9 @@
 use paperclip::v2::{
     self,
-    codegen::{CrateMeta, DefaultEmitter, EmitMode, Emitter, EmitterState},
+    codegen::{
+        AbsentNullable, CaseStrategy, CrateMeta, DefaultEmitter, EmitMode, Emitter, EmitterState,
+        MapType, NonExhaustive, UnusedDefinitions,
+    },
     models::{DefaultSchema, ResolvableApi},
 };
 
@@ -19,42 +22,160 @@ static K8S_SCHEMA: Lazy<ResolvableApi<K8sSchema>> = Lazy::new(|| {
     raw.resolve().expect("resolution")
 });
 static CODEGEN_PET_LIB: Lazy<()> = Lazy::new(|| {
-    let mut state = EmitterState::default();
-    state.working_dir = ROOT.clone();
-    state.working_dir.push("tests/test_pet");
-    let mut meta = CrateMeta::default();
-    meta.authors = Some(vec!["Me <me@example.com>".into()]);
-    meta.mode = EmitMode::Crate;
-    state.set_meta(meta);
-
-    let emitter = DefaultEmitter::from(state);
-    emitter.generate(&PET_SCHEMA).expect("codegen");
+    codegen_pet("", |s| {
+        s.op_traits = true;
+        s.env_prefix = Some("petstore".into());
+        s.object_examples = true;
+        s.mock_client = true;
+        s.set_meta(pet_crate_meta());
+    })
 });
 static CODEGEN_PET_LIB_NO_ROOT: Lazy<()> = Lazy::new(|| {
-    let mut state = EmitterState::default();
-    state.working_dir = ROOT.clone();
-    state.working_dir.push("tests/test_pet/no_root");
-    let mut meta = CrateMeta::default();
-    meta.authors = Some(vec!["Me <me@example.com>".into()]);
-    meta.mode = EmitMode::Crate;
-    meta.no_root = true;
-    state.set_meta(meta);
-
-    let emitter = DefaultEmitter::from(state);
-    emitter.generate(&PET_SCHEMA).expect("codegen");
+    codegen_pet("no_root", |s| {
+        let mut meta = pet_crate_meta();
+        meta.no_root = true;
+        s.set_meta(meta);
+    })
+});
+static CODEGEN_PET_LIB_TITLES: Lazy<()> =
+    Lazy::new(|| codegen_pet("titles", |s| s.prefer_titles = true));
+static CODEGEN_PET_LIB_STANDALONE: Lazy<()> = Lazy::new(|| {
+    codegen_pet("standalone", |s| {
+        s.standalone = true;
+        s.mock_client = true;
+    })
+});
+static CODEGEN_PET_LIB_TAG_MODULES: Lazy<()> = Lazy::new(|| {
+    codegen_pet("tag_modules", |s| {
+        s.tag_modules = true;
+        s.set_meta(pet_crate_meta());
+    })
+});
+static CODEGEN_PET_LIB_USED_ONLY: Lazy<()> = Lazy::new(|| {
+    codegen_pet("used_only", |s| {
+        s.unused_definitions = UnusedDefinitions::Omit
+    })
+});
+static CODEGEN_PET_LIB_NON_ZERO: Lazy<()> =
+    Lazy::new(|| codegen_pet("non_zero", |s| s.non_zero_integers = true));
+static CODEGEN_PET_LIB_TOLERANT: Lazy<()> = Lazy::new(|| {
+    codegen_pet("tolerant", |s| {
+        s.absent_nullable = AbsentNullable::Tolerant;
+        s.union_fallback = true;
+        s.enum_fallback = true;
+    })
+});
+static CODEGEN_PET_LIB_MERGE: Lazy<()> =
+    Lazy::new(|| codegen_pet("merge", |s| s.object_merge = true));
+static CODEGEN_PET_LIB_QUERY_STRUCTS: Lazy<()> = Lazy::new(|| {
+    codegen_pet("query_structs", |s| {
+        s.query_structs = true;
+        s.set_meta(pet_crate_meta());
+    })
+});
+static CODEGEN_PET_LIB_ARRAY_STRINGS: Lazy<()> = Lazy::new(|| {
+    codegen_pet("array_strings", |s| {
+        s.array_strings_up_to = Some(32);
+        s.set_meta(pet_crate_meta());
+    })
+});
+static CODEGEN_PET_LIB_DECIMAL_STRINGS: Lazy<()> = Lazy::new(|| {
+    codegen_pet("decimal_strings", |s| {
+        s.decimal_strings = true;
+        s.set_meta(pet_crate_meta());
+    })
+});
+static CODEGEN_PET_LIB_BASE64_BYTES: Lazy<()> = Lazy::new(|| {
+    codegen_pet("base64_bytes", |s| {
+        s.base64_bytes = true;
+        s.set_meta(pet_crate_meta());
+    })
+});
+static CODEGEN_PET_LIB_CHRONO_DATES: Lazy<()> = Lazy::new(|| {
+    codegen_pet("chrono_dates", |s| {
+        s.chrono_dates = true;
+        s.set_meta(pet_crate_meta());
+    })
+});
+static CODEGEN_PET_LIB_UUIDS: Lazy<()> = Lazy::new(|| {
+    codegen_pet("uuids", |s| {
+        s.uuids = true;
+        s.set_meta(pet_crate_meta());
+    })
+});
+static CODEGEN_PET_LIB_NON_EXHAUSTIVE: Lazy<()> = Lazy::new(|| {
+    codegen_pet("non_exhaustive", |s| {
+        s.non_exhaustive = NonExhaustive::All;
+        s.set_meta(pet_crate_meta());
+    })
+});
+static CODEGEN_PET_LIB_DECODED_BYTES: Lazy<()> = Lazy::new(|| {
+    codegen_pet("decoded_bytes", |s| {
+        s.decoded_bytes = true;
+        s.set_meta(pet_crate_meta());
+    })
+});
+static CODEGEN_PET_LIB_BLOCKING: Lazy<()> = Lazy::new(|| {
+    codegen_pet("blocking", |s| {
+        s.blocking_client = true;
+        s.set_meta(pet_crate_meta());
+    })
+});
+static CODEGEN_PET_LIB_SAFE_BUILDERS: Lazy<()> = Lazy::new(|| {
+    codegen_pet("safe_builders", |s| {
+        s.safe_builders = true;
+        s.set_meta(pet_crate_meta());
+    })
+});
+static CODEGEN_PET_LIB_HASH_MAPS: Lazy<()> = Lazy::new(|| {
+    codegen_pet("hash_maps", |s| {
+        s.map_type = MapType::HashMap;
+        s.set_meta(pet_crate_meta());
+    })
+});
+static CODEGEN_PET_LIB_INDEX_MAPS: Lazy<()> = Lazy::new(|| {
+    codegen_pet("index_maps", |s| {
+        s.map_type = MapType::IndexMap;
+        s.set_meta(pet_crate_meta());
+    })
+});
+static CODEGEN_PET_LIB_UNKNOWN_FIELDS: Lazy<()> = Lazy::new(|| {
+    codegen_pet("unknown_fields", |s| {
+        s.keep_unknown_fields = true;
+        s.set_meta(pet_crate_meta());
+    })
+});
+static CODEGEN_PET_LIB_ACRONYMS: Lazy<()> = Lazy::new(|| {
+    codegen_pet("acronyms", |s| {
+        s.case_strategy = CaseStrategy::PreserveAcronyms
+    })
 });
 static CODEGEN_PET_CLI: Lazy<()> = Lazy::new(|| {
+    codegen_pet("cli", |s| {
+        let mut meta = pet_crate_meta();
+        meta.mode = EmitMode::App;
+        s.set_meta(meta);
+    })
+});
+
+/// Generates the pet client into the given directory (under `tests/test_pet`)
+/// after applying the given options.
+fn codegen_pet(dir: &str, configure: impl FnOnce(&mut EmitterState)) {
     let mut state = EmitterState::default();
-    state.working_dir = (&*ROOT).into();
-    state.working_dir.push("tests/test_pet/cli");
-    let mut meta = CrateMeta::default();
-    meta.authors = Some(vec!["Me <me@example.com>".into()]);
-    meta.mode = EmitMode::App;
-    state.set_meta(meta);
+    state.working_dir = ROOT.join("tests/test_pet").join(dir);
+    configure(&mut state);
 
     let emitter = DefaultEmitter::from(state);
     emitter.generate(&PET_SCHEMA).expect("codegen");
-});
+}
+
+/// Metadata for generating the pet client as a crate.
+fn pet_crate_meta() -> CrateMeta {
+    let mut meta = CrateMeta::default();
+    meta.authors = Some(vec!["Me <me@example.com>".into()]);
+    meta.mode = EmitMode::Crate;
+    meta
+}
 
 static CODEGEN_K8S_LIB: Lazy<()> = Lazy::new(|| {
     let mut state = EmitterState::default();
@@ -105,6 +226,27 @@ static CODEGEN: Lazy<()> = Lazy::new(|| {
         .init();
     Lazy::force(&CODEGEN_PET_LIB);
     Lazy::force(&CODEGEN_PET_LIB_NO_ROOT);
+    Lazy::force(&CODEGEN_PET_LIB_TITLES);
+    Lazy::force(&CODEGEN_PET_LIB_STANDALONE);
+    Lazy::force(&CODEGEN_PET_LIB_TAG_MODULES);
+    Lazy::force(&CODEGEN_PET_LIB_USED_ONLY);
+    Lazy::force(&CODEGEN_PET_LIB_NON_ZERO);
+    Lazy::force(&CODEGEN_PET_LIB_TOLERANT);
+    Lazy::force(&CODEGEN_PET_LIB_MERGE);
+    Lazy::force(&CODEGEN_PET_LIB_QUERY_STRUCTS);
+    Lazy::force(&CODEGEN_PET_LIB_ARRAY_STRINGS);
+    Lazy::force(&CODEGEN_PET_LIB_DECIMAL_STRINGS);
+    Lazy::force(&CODEGEN_PET_LIB_BASE64_BYTES);
+    Lazy::force(&CODEGEN_PET_LIB_CHRONO_DATES);
+    Lazy::force(&CODEGEN_PET_LIB_UUIDS);
+    Lazy::force(&CODEGEN_PET_LIB_NON_EXHAUSTIVE);
+    Lazy::force(&CODEGEN_PET_LIB_DECODED_BYTES);
+    Lazy::force(&CODEGEN_PET_LIB_BLOCKING);
+    Lazy::force(&CODEGEN_PET_LIB_SAFE_BUILDERS);
+    Lazy::force(&CODEGEN_PET_LIB_HASH_MAPS);
+    Lazy::force(&CODEGEN_PET_LIB_INDEX_MAPS);
+    Lazy::force(&CODEGEN_PET_LIB_UNKNOWN_FIELDS);
+    Lazy::force(&CODEGEN_PET_LIB_ACRONYMS);
     Lazy::force(&CODEGEN_PET_CLI);
     Lazy::force(&CODEGEN_K8S_LIB);
     Lazy::force(&CODEGEN_K8S_CLI);
@@ -139,6 +281,8 @@ mod tests_pet {
     #[test]
     fn test_header_parameters() {
         assert_file("tests/test_pet/pet.rs");
+        // `X-Request-Id` is declared for all the operations in `/pets/{petId}`.
+        assert_file("tests/test_pet/status.rs");
     }
 
     #[test]
@@ -158,6 +302,12 @@ mod tests_pet {
         // them from known definitions. In that case, we autogenerate stuff.
         assert_file("tests/test_pet/order.rs");
     }
+
+    #[test]
+    fn test_schema_titles_as_type_names() {
+        assert_file("tests/test_pet/titles/order.rs");
+    }
+
     #[test]
     fn test_anonymous_object_definition_in_body() {
         assert_file("tests/test_pet/post_shipments_body.rs");
@@ -168,11 +318,839 @@ mod tests_pet {
         assert_file("tests/test_pet/get_shipments_id_response.rs");
     }
 
+    #[test]
+    fn test_restricted_operation_visibility() {
+        // `listPets` has `x-visibility: pub(crate)`, so its constructor
+        // isn't public (and it's left out of `PetApi`).
+        assert_file("tests/test_pet/pet.rs");
+        assert_file("tests/test_pet/apis.rs");
+    }
+
+    #[test]
+    fn test_cookie_parameters() {
+        // `getShipment` has `session` (required) and `theme` cookies,
+        // which are joined into a single `Cookie` header.
+        assert_file("tests/test_pet/get_shipments_id_response.rs");
+    }
+
+    #[test]
+    fn test_form_urlencoded_body() {
+        // `/oauth/token` consumes only `application/x-www-form-urlencoded`, so its
+        // body is URL-encoded, whereas `/oauth/revoke` (which also consumes JSON)
+        // sticks to JSON.
+        assert_file("tests/test_pet/token_request.rs");
+    }
+
+    #[test]
+    fn test_success_response_enum() {
+        // `/oauth/token` responds with different schemas for `200` and `202`
+        // (and without a body for `204`), so its output is an enum.
+        assert_file("tests/test_pet/token_request.rs");
+    }
+
+    #[test]
+    fn test_enum_variant_collisions() {
+        // `BOOYA` and `Booya` collide with `booya` after case conversion (and their
+        // suffixes skip `Booya1`, which belongs to `booya1`), `self` is a keyword,
+        // and `2nd` (along with the empty string and `-`) isn't a valid identifier.
+        assert_file("tests/test_pet/order.rs");
+    }
+
+    #[test]
+    fn test_untagged_union() {
+        // `Owner` has `oneOf` without a `discriminator`, so its variants (a reference,
+        // a primitive and an inline object) are tried in order.
+        assert_file("tests/test_pet/owner.rs");
+    }
+
+    #[test]
+    fn test_schema_default_values() {
+        // `status` and `test-string-enum` default to enum variants, whereas
+        // the nested `quantity` defaults to an integer, so `Order` (and its
+        // list item) implement `Default` using those values.
+        assert_file("tests/test_pet/order.rs");
+    }
+
+    #[test]
+    fn test_error_response_enum() {
+        // `getPetById` declares `404` (without schema), `422` and `default`
+        // responses, so failures can be decoded into `PetGetError1`.
+        assert_file("tests/test_pet/pet.rs");
+    }
+
+    #[test]
+    fn test_operation_timeout_extension() {
+        // `getPetById` has `x-timeout: 2500`, so only its builder sets
+        // a default timeout for the request.
+        assert_file("tests/test_pet/pet.rs");
+    }
+
     #[test]
     fn test_simple_array_parameter_in_path() {
         assert_file("tests/test_pet/status.rs");
     }
 
+    #[test]
+    fn test_array_query_parameters() {
+        // `listPets` has `tags` (pipes), `fields` (CSV) and `ids` (no `collectionFormat`),
+        // which is sent as repeated keys (`?ids=1&ids=2`).
+        assert_file("tests/test_pet/pet.rs");
+    }
+
+    #[test]
+    fn test_deep_object_query_parameter() {
+        // `filter` (with `x-style: deepObject`) is sent as `filter[name]=..`,
+        // `filter[category][id]=..` etc. through `client::deep_object_query`.
+        assert_file("tests/test_pet/pet.rs");
+        assert_file("tests/test_pet/pet_filter.rs");
+        assert_file("tests/test_pet/lib.rs");
+    }
+
+    #[test]
+    fn test_empty_path_parameter_is_rejected() {
+        // `rel_path` goes through `client::path_param` (defined in the root module).
+        assert_file("tests/test_pet/pet.rs");
+        assert_file("tests/test_pet/lib.rs");
+    }
+
+    #[test]
+    fn test_path_parameter_encoding() {
+        // `client::path_param` percent-encodes the values as path segments
+        // (`a/b c` becomes `a%2Fb%20c`), but leaves the existing escapes alone.
+        assert_file("tests/test_pet/lib.rs");
+    }
+
+    #[test]
+    fn test_byte_range_for_downloads() {
+        // `GET /test/file` responds with a file, so its builder accepts a byte range.
+        assert_file("tests/test_pet/miscellaneous.rs");
+        assert_file("tests/test_pet/lib.rs");
+    }
+
+    #[test]
+    fn test_binary_responses() {
+        // `GET /test/archive` responds with `format: binary` and `getPetCertificate`
+        // only produces `application/octet-stream`, so both are streamed like files.
+        assert_file("tests/test_pet/miscellaneous.rs");
+    }
+
+    #[test]
+    fn test_accept_header() {
+        // Every operation asks for the type we decode (YAML, since that's the format
+        // of the spec and it doesn't declare `produces`), but downloads accept
+        // anything they produce.
+        assert_file("tests/test_pet/miscellaneous.rs");
+        assert_file("tests/test_pet/pet.rs");
+    }
+
+    #[test]
+    fn test_shared_builders() {
+        // Operations with the same fields and parameters share a struct generic
+        // over the operation, and their builders are aliases with marker types.
+        assert_file("tests/test_pet/token_request.rs");
+        assert_file("tests/test_pet/miscellaneous.rs");
+    }
+
+    #[test]
+    fn test_content_type_header() {
+        // Bodies (JSON, URL-encoded or encoded with a coder) set `Content-Type`
+        // explicitly, but multipart forms leave it to the client for the boundary.
+        assert_file("tests/test_pet/token_request.rs");
+        assert_file("tests/test_pet/miscellaneous.rs");
+        assert_file("tests/test_pet/cli/status.rs");
+    }
+
+    #[test]
+    fn test_deprecated_items() {
+        // `Token` is deprecated, so is the `scope` field (and its setters) in
+        // `TokenRequest`. Generated modules allow using them internally.
+        assert_file("tests/test_pet/token.rs");
+        assert_file("tests/test_pet/token_request.rs");
+        assert_file("tests/test_pet/post_shipments_body.rs");
+        assert_file("tests/test_pet/lib.rs");
+    }
+
+    #[test]
+    fn test_examples_in_docs() {
+        // Examples of objects (`Tag`) and fields (`createdAt` and `photoUrls`
+        // in `Pet`) are shown as JSON code blocks, which aren't escaped.
+        assert_file("tests/test_pet/tag.rs");
+        assert_file("tests/test_pet/pet.rs");
+    }
+
+    #[test]
+    fn test_external_docs() {
+        // Objects (`Category`) and operations (`getShipment`) link their
+        // external docs. Brackets in the link text are escaped.
+        assert_file("tests/test_pet/category.rs");
+        assert_file("tests/test_pet/get_shipments_id_response.rs");
+    }
+
+    #[test]
+    fn test_doc_escaping() {
+        // HTML-like tags, emphasis and links in the description of `name` are
+        // escaped, but code spans, autolinks and escaped brackets aren't.
+        assert_file("tests/test_pet/tag.rs");
+    }
+
+    #[test]
+    fn test_dry_run_request() {
+        // `Sendable::dry_run` returns the assembled request without sending it.
+        assert_file("tests/test_pet/lib.rs");
+    }
+
+    #[test]
+    fn test_bearer_auth() {
+        // `getPetById` and `updatePet` declare their own security requirements
+        // (overriding the global `api_key`), which `Client` uses for picking
+        // the credentials, and sets `Authorization: Bearer <token>`.
+        assert_file("tests/test_pet/pet.rs");
+        assert_file("tests/test_pet/lib.rs");
+    }
+
+    #[test]
+    fn test_basic_auth() {
+        // `Credential::Basic` is sent as `Authorization: Basic <base64>` for operations
+        // requiring the `basic` scheme (`updatePet`, along with `api_key`).
+        assert_file("tests/test_pet/lib.rs");
+    }
+
+    #[test]
+    fn test_api_key_auth() {
+        // `listPets` needs both the `api_key` (sent as the `X-API-Key` header)
+        // and the `account_key` (sent as the `account` query parameter).
+        assert_file("tests/test_pet/pet.rs");
+        assert_file("tests/test_pet/lib.rs");
+    }
+
+    #[test]
+    fn test_raw_body() {
+        // `uploadPetCertificate` takes a binary string in body, which is sent
+        // as it is (empty if unset) with the media type it consumes.
+        assert_file("tests/test_pet/pet.rs");
+    }
+
+    #[test]
+    fn test_default_headers() {
+        // `ClientConfig::default_header` applies to all requests, except for
+        // operations setting that header (`X-Api-Version` is only skipped when set).
+        assert_file("tests/test_pet/pet.rs");
+        assert_file("tests/test_pet/lib.rs");
+    }
+
+    #[test]
+    fn test_runtime_base_url() {
+        // `ClientConfig::base_url` overrides the base URL (`BASE_URL`) from the spec.
+        assert_file("tests/test_pet/lib.rs");
+    }
+
+    #[test]
+    fn test_builder_into_future() {
+        // Builders bound to a client with `Sendable::with_client` can be awaited directly.
+        assert_file("tests/test_pet/lib.rs");
+    }
+
+    #[test]
+    fn test_cancellable_send() {
+        // `Sendable::send_cancellable` races the request against the given future.
+        assert_file("tests/test_pet/lib.rs");
+    }
+
+    #[test]
+    fn test_reqwest_error_conversion() {
+        // `ApiError` implements `From<reqwest::Error>`, so the send path uses `?`.
+        assert_file("tests/test_pet/lib.rs");
+    }
+
+    #[test]
+    fn test_prelude() {
+        assert_file("tests/test_pet/lib.rs");
+    }
+
+    #[test]
+    fn test_enum_header_parameter() {
+        // `X-Api-Version` can only be `v1` or `v2`, so its setter takes an enum.
+        assert_file("tests/test_pet/pet.rs");
+    }
+
+    #[test]
+    fn test_enum_path_parameter() {
+        // `kind` can only be `dog`, `cat` or `self` (`Self_`), so its setter takes an
+        // enum, which is substituted in the path through `Display` (i.e., `as_wire_str`).
+        assert_file("tests/test_pet/pet.rs");
+    }
+
+    #[test]
+    fn test_prefer_return_header() {
+        // `updatePet` accepts `Prefer`, so its output is `None` for `204 No Content`.
+        assert_file("tests/test_pet/pet.rs");
+        assert_file("tests/test_pet/lib.rs");
+        assert_file("tests/test_pet/util.rs");
+    }
+
+    #[test]
+    fn test_client_throttling() {
+        // `ClientConfig::max_in_flight` is backed by a semaphore whose permit
+        // travels with `ClientResponse` until the body has been read.
+        assert_file("tests/test_pet/lib.rs");
+        assert_file("tests/test_pet/Cargo.toml");
+    }
+
+    #[test]
+    fn test_client_from_env() {
+        // `api_key` is in the global security requirement, so it's the only
+        // credential whose variable must be set.
+        assert_file("tests/test_pet/lib.rs");
+    }
+
+    #[test]
+    fn test_shared_client_config() {
+        // Credentials live behind an `Arc<RwLock<_>>`, so `set_credential` on
+        // one client is visible to its clones.
+        assert_file("tests/test_pet/lib.rs");
+    }
+
+    #[test]
+    fn test_max_response_size() {
+        // Buffered bodies are read chunk by chunk (checking the limit) if
+        // `ClientConfig::max_response_size` is set.
+        assert_file("tests/test_pet/lib.rs");
+    }
+
+    #[test]
+    fn test_response_bom() {
+        // Buffered responses are decoded after stripping the UTF-8 BOM (if any).
+        assert_file("tests/test_pet/lib.rs");
+    }
+
+    #[test]
+    fn test_download_progress() {
+        // `Sendable::download` surfaces `Content-Length` (if any) and counts the
+        // bytes as the chunks are polled.
+        assert_file("tests/test_pet/lib.rs");
+        assert_file("tests/test_pet/util.rs");
+    }
+
+    #[test]
+    fn test_public_request_building() {
+        // `Sendable::request` builds (but doesn't send) the request, and `modify`
+        // works on any request whose URL has been built from `rel_path`.
+        assert_file("tests/test_pet/lib.rs");
+    }
+
+    #[test]
+    fn test_builder_into_request() {
+        // Fully built builders convert (through `TryFrom`) into `reqwest::Request`.
+        assert_file("tests/test_pet/pet.rs");
+    }
+
+    #[test]
+    fn test_object_example() {
+        // `Tag::example()` is built from the example in its schema.
+        assert_file("tests/test_pet/tag.rs");
+    }
+
+    #[test]
+    fn test_operation_traits() {
+        assert_file("tests/test_pet/apis.rs");
+    }
+
+    #[test]
+    fn test_multipart_file_parts() {
+        // File parameters accept either a path or bytes, and they're sent
+        // along with other `formData` fields.
+        assert_file("tests/test_pet/status.rs");
+        assert_file("tests/test_pet/util.rs");
+    }
+
+    #[test]
+    fn test_multipart_mixed_response() {
+        assert_file("tests/test_pet/pet.rs");
+        assert_file("tests/test_pet/util.rs");
+    }
+
+    #[test]
+    fn test_callbacks() {
+        assert_file("tests/test_pet/callbacks.rs");
+        assert_file("tests/test_pet/adoption.rs");
+    }
+
+    #[test]
+    fn test_standalone_module() {
+        assert_file("tests/test_pet/standalone/mod.rs");
+        let entries = std::fs::read_dir(ROOT.join("tests/test_pet/standalone"))
+            .expect("reading standalone dir")
+            .count();
+        assert_eq!(entries, 1);
+    }
+
+    #[test]
+    fn test_tag_modules() {
+        // Definitions are grouped by the tags of the operations using them (and
+        // the shared ones go into `types`), with each group in a single file.
+        Lazy::force(&CODEGEN);
+        let dir = ROOT.join("tests/test_pet/tag_modules");
+        let mut entries = std::fs::read_dir(&dir)
+            .expect("reading tag modules dir")
+            .map(|e| e.unwrap().file_name().to_string_lossy().into_owned())
+            .collect::<Vec<_>>();
+        entries.sort();
+        assert_eq!(
+            entries,
+            &[
+                "Cargo.toml",
+                "callbacks.rs",
+                "generics.rs",
+                "lib.rs",
+                "miscellaneous.rs",
+                "pet.rs",
+                "store.rs",
+                "types.rs",
+                "util.rs",
+            ]
+        );
+
+        let lib = std::fs::read_to_string(dir.join("lib.rs")).unwrap();
+        for name in &["miscellaneous", "pet", "store", "types"] {
+            let decl = format!("pub mod {0} {{\n    include!(\"./{0}.rs\");\n}}", name);
+            assert!(lib.contains(&decl), "missing declaration for {}", name);
+        }
+
+        // Each definition is still in its own module (inside the tag module).
+        let types = std::fs::read_to_string(dir.join("types.rs")).unwrap();
+        assert!(!types.contains("include!"));
+        assert!(types.contains("\npub mod pet {\n"));
+        assert!(lib.contains("pub use crate::types::pet::Pet;"));
+        assert!(lib.contains("pub use crate::pet::adoption::Adoption;"));
+        assert_file("tests/test_pet/tag_modules/pet.rs");
+        assert_file("tests/test_pet/tag_modules/store.rs");
+    }
+
+    #[test]
+    fn test_module_per_definition() {
+        // Each definition gets its own module (along with the builders of the
+        // operations bound to it), and the root module declares all of them.
+        Lazy::force(&CODEGEN);
+        let mut files = std::fs::read_dir(ROOT.join("tests/test_pet"))
+            .expect("reading pet dir")
+            .map(|e| e.unwrap().path())
+            .filter(|p| p.is_file())
+            .map(|p| p.file_name().unwrap().to_string_lossy().into_owned())
+            .collect::<Vec<_>>();
+        files.sort();
+        assert_eq!(
+            files,
+            &[
+                "Cargo.toml",
+                "account.rs",
+                "adoption.rs",
+                "animal.rs",
+                "api_key.rs",
+                "apis.rs",
+                "breeder.rs",
+                "callbacks.rs",
+                "category.rs",
+                "event.rs",
+                "generics.rs",
+                "get_shipments_id_response.rs",
+                "inventory.rs",
+                "lib.rs",
+                "metadata.rs",
+                "miscellaneous.rs",
+                "order.rs",
+                "owner.rs",
+                "payment.rs",
+                "pet.rs",
+                "pet_filter.rs",
+                "post_shipments_body.rs",
+                "recursive_container.rs",
+                "recursive_object.rs",
+                "status.rs",
+                "tag.rs",
+                "test_enum.rs",
+                "test_nested_array_with_object.rs",
+                "token.rs",
+                "token_request.rs",
+                "util.rs",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_cached_output() {
+        let out = ROOT.join("tests/test_pet/cached");
+        let cache = ROOT.join("tests/test_pet/cache");
+        let _ = std::fs::remove_dir_all(&out);
+        let _ = std::fs::remove_dir_all(&cache);
+
+        let generate = || {
+            let fd = File::open(ROOT.join("tests/pet-v2.yaml")).expect("file?");
+            let raw: ResolvableApi<DefaultSchema> = v2::from_reader(fd).expect("spec");
+            let mut state = EmitterState::default();
+            state.working_dir = out.clone();
+            state.cache_dir = Some(cache.clone());
+            let emitter = DefaultEmitter::from(state);
+            emitter.generate_cached(raw).expect("codegen");
+        };
+
+        generate();
+        let root = std::fs::read_to_string(out.join("mod.rs")).expect("mod.rs");
+        let entries = std::fs::read_dir(&cache).expect("cache").count();
+        assert_eq!(entries, 1);
+
+        // Unchanged spec and options - the output is restored from the cache.
+        std::fs::remove_file(out.join("mod.rs")).expect("removing mod.rs");
+        std::fs::remove_file(out.join("pet.rs")).expect("removing pet.rs");
+        generate();
+        assert_eq!(std::fs::read_to_string(out.join("mod.rs")).unwrap(), root);
+        assert!(out.join("pet.rs").exists());
+        let entries = std::fs::read_dir(&cache).expect("cache").count();
+        assert_eq!(entries, 1);
+    }
+
+    #[test]
+    fn test_cached_output_with_same_emitter() {
+        let out = ROOT.join("tests/test_pet/cached_crate");
+        let cache = ROOT.join("tests/test_pet/cache_crate");
+        let _ = std::fs::remove_dir_all(&out);
+        let _ = std::fs::remove_dir_all(&cache);
+
+        // The spec overrides the base URL and the crate metadata is inferred
+        // during codegen, which shouldn't affect the key for the next session.
+        let mut state = EmitterState::default();
+        state.working_dir = out.clone();
+        state.cache_dir = Some(cache.clone());
+        state.set_meta(pet_crate_meta());
+        let emitter = DefaultEmitter::from(state);
+        let generate = || {
+            let fd = File::open(ROOT.join("tests/pet-v2.yaml")).expect("file?");
+            let raw: ResolvableApi<DefaultSchema> = v2::from_reader(fd).expect("spec");
+            emitter.generate_cached(raw).expect("codegen");
+        };
+
+        generate();
+        let entries = std::fs::read_dir(&cache).expect("cache").count();
+        assert_eq!(entries, 1);
+
+        std::fs::remove_file(out.join("pet.rs")).expect("removing pet.rs");
+        generate();
+        assert!(out.join("pet.rs").exists());
+        let entries = std::fs::read_dir(&cache).expect("cache").count();
+        assert_eq!(entries, 1);
+    }
+
+    #[test]
+    fn test_non_zero_integers() {
+        // `position` has `minimum: 1` and `rank` has `minimum: 0` (exclusive).
+        assert_file("tests/test_pet/non_zero/category.rs");
+    }
+
+    #[test]
+    fn test_absent_required_nullable_field() {
+        // `nickname` is required and nullable, so it must be present by default
+        // (even if it's `null`), whereas it's `None` when absent in tolerant mode.
+        assert_file("tests/test_pet/account.rs");
+        assert_file("tests/test_pet/tolerant/account.rs");
+    }
+
+    #[test]
+    fn test_union_fallback() {
+        // Objects with unknown tags end up in the (untagged) `Unknown` variant.
+        assert_file("tests/test_pet/tolerant/animal.rs");
+        assert_file("tests/test_pet/tolerant/event.rs");
+    }
+
+    #[test]
+    fn test_enum_fallback() {
+        // Undeclared values of string enums end up in the (untagged) `Unknown` variant,
+        // which `as_wire_str` borrows from and `FromStr` falls back to.
+        assert_file("tests/test_pet/tolerant/order.rs");
+    }
+
+    #[test]
+    fn test_all_of_flattening() {
+        // `Breeder` gets the fields of `Account` along with its own, and the
+        // `nickname` it redeclares (as non-nullable) overrides the nullable one.
+        assert_file("tests/test_pet/breeder.rs");
+    }
+
+    #[test]
+    fn test_default_builder_without_requirements() {
+        // `CategoryBuilder` doesn't have any required fields, so it derives `Default`.
+        assert_file("tests/test_pet/category.rs");
+    }
+
+    #[test]
+    fn test_numeric_range_validation() {
+        // `Category` has bounds on its integer and float fields, which are
+        // checked by `validate` (non-zero types skip their lower bound).
+        assert_file("tests/test_pet/category.rs");
+        assert_file("tests/test_pet/non_zero/category.rs");
+        assert_file("tests/test_pet/util.rs");
+    }
+
+    #[test]
+    fn test_exact_integer_bounds() {
+        // Bounds are written as they're in the spec (without rounding through floats),
+        // and those covering the whole range of the type (`level`'s maximum) are skipped.
+        Lazy::force(&CODEGEN);
+        let contents = std::fs::read_to_string(ROOT.join("tests/test_pet/category.rs"))
+            .expect("reading category");
+        assert!(contents.contains("if v < -2147483647 {"));
+        assert!(!contents.contains("2147483600"));
+        assert!(!contents.contains("if v > 2147483647 {"));
+        assert!(contents.contains("if v < -9007199254740993 {"));
+        assert!(contents.contains("if v >= 9007199254740993 {"));
+    }
+
+    #[test]
+    fn test_numeric_formats() {
+        // Integers and numbers get the types of their formats (if any), and
+        // builder methods take anything that converts into those.
+        Lazy::force(&CODEGEN);
+        let contents = std::fs::read_to_string(ROOT.join("tests/test_pet/category.rs"))
+            .expect("reading category");
+        for (field, ty) in &[
+            ("id", "i64"),
+            ("position", "i64"),
+            ("rank", "i32"),
+            ("weight", "f32"),
+            ("ratio", "f64"),
+            ("score", "f64"),
+            ("count", "i64"),
+        ] {
+            assert!(contents.contains(&format!("pub {}: Option<{}>,", field, ty)));
+            assert!(contents.contains(&format!(
+                "pub fn {}(mut self, value: impl Into<{}>) -> Self",
+                field, ty
+            )));
+        }
+
+        // Integers encoded as strings are still strings.
+        assert!(contents.contains("pub serial: Option<String>,"));
+        let contents =
+            std::fs::read_to_string(ROOT.join("tests/test_pet/pet.rs")).expect("reading pet");
+        assert!(contents.contains("param_limit: Option<i32>,"));
+    }
+
+    #[test]
+    fn test_pattern_validation() {
+        // `name` in `Category` has a `pattern`, which is also checked by `validate`.
+        assert_file("tests/test_pet/category.rs");
+    }
+
+    #[test]
+    fn test_object_merge() {
+        // Required fields (`username`, `password`) are always overwritten,
+        // whereas `nickname` is overwritten only if it's set.
+        assert_file("tests/test_pet/merge/account.rs");
+    }
+
+    #[test]
+    fn test_query_structs() {
+        // `listPets` has query parameters, which are sent through `PetGetQuery`.
+        assert_file("tests/test_pet/query_structs/pet.rs");
+    }
+
+    #[test]
+    fn test_array_strings() {
+        // `name` has `maxLength: 16`, which is within the limit.
+        assert_file("tests/test_pet/array_strings/tag.rs");
+        assert_file("tests/test_pet/array_strings/Cargo.toml");
+    }
+
+    #[test]
+    fn test_decimal_strings() {
+        // `total` has `format: decimal`, so it's (de)serialized as a string.
+        assert_file("tests/test_pet/decimal_strings/order.rs");
+        assert_file("tests/test_pet/decimal_strings/Cargo.toml");
+    }
+
+    #[test]
+    fn test_blocking_client() {
+        // Client traits are implemented for `reqwest::blocking` types, and
+        // builders can be sent with `Sendable::send_blocking`.
+        assert_file("tests/test_pet/blocking/lib.rs");
+        assert_file("tests/test_pet/blocking/Cargo.toml");
+    }
+
+    #[test]
+    fn test_nested_map_fields() {
+        // `scores` in `Metadata` is a map of maps (of arrays), whose builder method
+        // takes nested iterators and collects them back into maps.
+        assert_file("tests/test_pet/metadata.rs");
+        assert_file("tests/test_pet/hash_maps/metadata.rs");
+    }
+
+    #[test]
+    fn test_typed_additional_properties() {
+        // `Inventory` has fixed fields and flattens the counts of other items into
+        // a map, while `Warehouses` is just a map, so it's used directly.
+        assert_file("tests/test_pet/inventory.rs");
+        assert!(!ROOT.join("tests/test_pet/warehouses.rs").exists());
+    }
+
+    #[test]
+    fn test_hash_map_fields() {
+        // Extra (and pattern) properties are collected into `HashMap`s,
+        // including in the builder methods and `insert_pattern_field`.
+        assert_file("tests/test_pet/hash_maps/pet.rs");
+        assert_file("tests/test_pet/hash_maps/metadata.rs");
+    }
+
+    #[test]
+    fn test_index_map_fields() {
+        // Maps keep the order of their keys, and only this manifest has `indexmap`.
+        assert_file("tests/test_pet/index_maps/metadata.rs");
+        assert_file("tests/test_pet/index_maps/Cargo.toml");
+        assert_file("tests/test_pet/hash_maps/Cargo.toml");
+    }
+
+    #[test]
+    fn test_keep_unknown_fields() {
+        // `MetadataLabels` doesn't have any declared properties, so it collects
+        // all the keys as JSON values.
+        assert_file("tests/test_pet/unknown_fields/metadata.rs");
+    }
+
+    #[test]
+    fn test_safe_builders() {
+        // Setting required fields and parameters moves everything into a new
+        // builder (with the updated type parameters) instead of transmuting.
+        assert_file("tests/test_pet/safe_builders/pet.rs");
+        assert_file("tests/test_pet/safe_builders/account.rs");
+        let paths = std::fs::read_dir(ROOT.join("tests/test_pet/safe_builders"))
+            .expect("reading safe builders dir")
+            .map(|e| e.unwrap().path())
+            .filter(|p| p.extension().and_then(|e| e.to_str()) == Some("rs"));
+        for path in paths {
+            let contents = std::fs::read_to_string(&path).expect("reading module");
+            assert!(!contents.contains("unsafe"), "{}", path.display());
+        }
+    }
+
+    #[test]
+    fn test_chrono_dates() {
+        // `placedAt` and `deliverBy` (along with the `bornAfter` query parameter)
+        // get `chrono` types, but `updatedSince` is still sent as a string.
+        assert_file("tests/test_pet/chrono_dates/order.rs");
+        assert_file("tests/test_pet/chrono_dates/pet.rs");
+        assert_file("tests/test_pet/chrono_dates/Cargo.toml");
+    }
+
+    #[test]
+    fn test_decoded_bytes() {
+        // `secret` is required, `signature` is required but nullable and
+        // `receipt` (in `Order`) is optional.
+        assert_file("tests/test_pet/decoded_bytes/api_key.rs");
+        assert_file("tests/test_pet/decoded_bytes/order.rs");
+        assert_file("tests/test_pet/decoded_bytes/util.rs");
+    }
+
+    #[test]
+    fn test_uuids() {
+        // The `id` path parameter is still interpolated through `Display`.
+        assert_file("tests/test_pet/uuids/get_shipments_id_response.rs");
+        assert_file("tests/test_pet/uuids/Cargo.toml");
+    }
+
+    #[test]
+    fn test_non_exhaustive() {
+        // Enums (`OrderStatus`), unions (`Animal`) and structs (`Order`) are all marked.
+        assert_file("tests/test_pet/non_exhaustive/order.rs");
+        assert_file("tests/test_pet/non_exhaustive/animal.rs");
+    }
+
+    #[test]
+    fn test_base64_bytes() {
+        // `receipt` has `format: byte`, so it's kept encoded until it's decoded.
+        assert_file("tests/test_pet/base64_bytes/order.rs");
+        assert_file("tests/test_pet/base64_bytes/util.rs");
+        assert_file("tests/test_pet/base64_bytes/Cargo.toml");
+    }
+
+    #[test]
+    fn test_standard_case_strategy() {
+        // Every word is capitalized, so we get `ApiKey` and `ApiKeyHttpHeaders`.
+        assert_file("tests/test_pet/api_key.rs");
+    }
+
+    #[test]
+    fn test_preserve_acronyms_case_strategy() {
+        // Uppercase words are kept, so we get `APIKey` and `APIKeyHTTPHeaders`.
+        assert_file("tests/test_pet/acronyms/api_key.rs");
+    }
+
+    #[test]
+    fn test_pattern_properties() {
+        // Keys of `pattern_fields` are checked against `^x-[a-z-]+$` on insertion.
+        assert_file("tests/test_pet/metadata.rs");
+    }
+
+    #[test]
+    fn test_nullable_fields() {
+        // `nickname` (required) is sent as `null` when it's `None`, whereas `bio`
+        // (optional) is left out, even though both are nullable.
+        assert_file("tests/test_pet/account.rs");
+    }
+
+    #[test]
+    fn test_skip_serializing_unset_optional_fields() {
+        // Optional fields are skipped when they're `None`, and the attribute is
+        // combined with the rename of `test-string-enum`.
+        assert_file("tests/test_pet/order.rs");
+        // `nickname` is required and nullable, so it's serialized even if `None`.
+        assert_file("tests/test_pet/account.rs");
+    }
+
+    #[test]
+    fn test_read_only_fields_are_left_out_of_builders() {
+        // `createdAt` in `Pet` is required, but it's read-only, so it's never
+        // serialized and it doesn't show up in the builders (or their generics).
+        assert_file("tests/test_pet/pet.rs");
+    }
+
+    #[test]
+    fn test_round_trip_builder_requires_write_only_fields() {
+        // `Account::to_builder` should leave `password` as `MissingPassword`.
+        assert_file("tests/test_pet/account.rs");
+    }
+
+    #[test]
+    fn test_write_only_fields_are_not_deserialized() {
+        // `password` is never deserialized, whereas `token` is both read-only
+        // and write-only, so it's treated like any other field.
+        assert_file("tests/test_pet/account.rs");
+    }
+
+    #[test]
+    fn test_unused_definitions_are_omitted() {
+        // `Account`, `Order` and `TestEnum` aren't used by any operation.
+        assert_file("tests/test_pet/used_only/mod.rs");
+        assert!(!ROOT.join("tests/test_pet/used_only/account.rs").exists());
+        assert!(!ROOT.join("tests/test_pet/used_only/test_enum.rs").exists());
+        assert!(ROOT.join("tests/test_pet/used_only/pet.rs").exists());
+    }
+
+    #[test]
+    fn test_internally_tagged_union() {
+        // Tags come from `x-discriminator-mapping` (with `tags` being an alias for
+        // `tag`) instead of the names of the definitions.
+        assert_file("tests/test_pet/animal.rs");
+    }
+
+    #[test]
+    fn test_adjacently_tagged_union() {
+        // `{"kind": "Pet", "data": {...}}` goes into `Event::Pet`.
+        assert_file("tests/test_pet/event.rs");
+    }
+
+    #[test]
+    fn test_union_variant_rename_rule() {
+        // Inline variants use `rename_all` when their fields follow some convention.
+        assert_file("tests/test_pet/payment.rs");
+    }
+
     #[test]
     fn test_misc() {
         assert_file("tests/test_pet/miscellaneous.rs");
@@ -187,6 +1165,14 @@ mod tests_pet {
     fn test_simple_any_enum() {
         assert_file("tests/test_pet/test_enum.rs");
     }
+
+    #[test]
+    fn test_enum_string_conversions() {
+        // Enums can be parsed from (and printed as) their wire values through
+        // `FromStr`, `TryFrom` and `Display`, with an error listing the expected values.
+        assert_file("tests/test_pet/order.rs");
+        assert_file("tests/test_pet/test_enum.rs");
+    }
 }
 
 #[cfg(test)]
@@ -278,6 +1264,20 @@ mod tests_k8s {
         assert_file("tests/test_k8s/io/k8s/apimachinery/pkg/apis/meta/v1/api_group_list.rs");
     }
 
+    #[test]
+    fn test_builder_field_with_owned_vec() {
+        // Arrays can also be set from a `Vec` (through `*_vec` methods), which
+        // change the type parameters of required fields just like the iterator ones.
+        assert_file("tests/test_k8s/io/k8s/apimachinery/pkg/apis/meta/v1/api_group_list.rs");
+    }
+
+    #[test]
+    fn test_builder_optional_fields_from_options() {
+        // Optional fields and parameters can also be set from `Option` values
+        // (through `maybe_*` methods), where `None` leaves them unset.
+        assert_file("tests/test_k8s/io/k8s/api/core/v1/config_map.rs");
+    }
+
     #[test]
     fn test_builder_field_with_iterators() {
         assert_file(
@@ -82,3 +82,108 @@ paths:
         "Path similar to \"/store/{storeId}/pets/{petId}\" already exists.",
     );
 }
+
+#[test]
+fn test_invalid_object_example() {
+    let spec = Cursor::new(
+        b"
+swagger: \"2.0\"
+definitions:
+  Pet:
+    type: object
+    required:
+    - name
+    properties:
+      name:
+        type: string
+      age:
+        type: integer
+    example:
+      name: Rex
+      age: old
+info:
+  title:  \"Petstore\"
+  version: \"1.0.0\"
+paths: {}
+" as &[_],
+    );
+
+    let raw: ResolvableApi<DefaultSchema> = v2::from_reader(spec).expect("deserializing spec");
+    let resolved = raw.resolve().expect("resolution");
+
+    let mut state = EmitterState::default();
+    state.object_examples = true;
+    let emitter = DefaultEmitter::from(state);
+    let err = emitter.generate(&resolved).unwrap_err().to_string();
+    assert_eq!(
+        err,
+        "Invalid example for \"Pet\": age: \"old\" doesn't match the schema type",
+    );
+}
+
+#[test]
+fn test_invalid_operation_visibility() {
+    let spec = Cursor::new(
+        b"
+swagger: \"2.0\"
+definitions:
+  Pet:
+    type: object
+    properties:
+      name:
+        type: string
+info:
+  title:  \"Petstore\"
+  version: \"1.0.0\"
+paths:
+  /pets:
+    get:
+      x-visibility: pub(super)
+      responses:
+        \"200\":
+          schema:
+            $ref: \"#/definitions/Pet\"
+" as &[_],
+    );
+
+    let raw: ResolvableApi<DefaultSchema> = v2::from_reader(spec).expect("deserializing spec");
+    let resolved = raw.resolve().expect("resolution");
+
+    let emitter = DefaultEmitter::from(EmitterState::default());
+    let err = emitter.generate(&resolved).unwrap_err().to_string();
+    assert_eq!(
+        err,
+        "Invalid visibility \"pub(super)\" for operation in path \"/pets\"",
+    );
+}
+
+#[test]
+fn test_invalid_field_pattern() {
+    let spec = Cursor::new(
+        b"
+swagger: \"2.0\"
+definitions:
+  Pet:
+    type: object
+    properties:
+      name:
+        type: string
+        pattern: \"^[a-z\"
+info:
+  title:  \"Petstore\"
+  version: \"1.0.0\"
+paths: {}
+" as &[_],
+    );
+
+    let raw: ResolvableApi<DefaultSchema> = v2::from_reader(spec).expect("deserializing spec");
+    let resolved = raw.resolve().expect("resolution");
+
+    let emitter = DefaultEmitter::from(EmitterState::default());
+    let err = emitter.generate(&resolved).unwrap_err().to_string();
+    assert!(
+        err.starts_with("Invalid pattern for field \"name\" in definition \"Pet\": "),
+        "{}",
+        err
+    );
+}
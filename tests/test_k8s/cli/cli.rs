@@ -0,0 +1,4365 @@
+use clap::ArgMatches;
+use crate::client::{ApiClient, ApiError, Sendable};
+use serde::{Serialize, Deserialize};
+
+use std::io::Read;
+
+pub(crate) fn read_from_input<T>(matches: Option<&ArgMatches<'_>>) -> Result<T, crate::ClientError>
+where
+    T: Serialize,
+    for<'de> T: Deserialize<'de>
+{
+    let path = matches
+        .expect("no args for builder with body?")
+        .value_of("payload").expect("payload?");
+
+    let mut bytes = vec![];
+    if path == "-" {
+        std::io::stdin().read_to_end(&mut bytes).map_err(crate::ClientError::Io)?;
+    } else {
+        std::fs::File::open(&path)
+            .and_then(|mut fd| fd.read_to_end(&mut bytes))
+            .map_err(crate::ClientError::Io)?;
+    };
+
+    let err = match serde_json::from_reader(bytes.as_slice()) {
+        Ok(t) => return Ok(t),
+        Err(e) => crate::ClientError::Api(ApiError::ApplicationJson(e)),
+    };
+
+    log::debug!("Error decoding payload as application/json: {:?}", err);
+
+    let err = match serde_yaml::from_reader(bytes.as_slice()) {
+        Ok(t) => return Ok(t),
+        Err(e) => crate::ClientError::Api(ApiError::ApplicationYaml(e)),
+    };
+
+    log::debug!("Error decoding payload as application/yaml: {:?}", err);
+
+    Err(err)
+}
+
+pub(super) async fn fetch_response<'a, C>(client: &'a C,
+                                          _matches: &ArgMatches<'_>,
+                                          sub_cmd: &str,
+                                          sub_matches: Option<&ArgMatches<'_>>)
+                                          -> Result<C::Response, crate::ClientError>
+where
+    C: ApiClient + Send + Sync + 'static,
+    crate::ClientError: From<ApiError<C::Response>>
+{
+    let resp = match sub_cmd {
+
+        "create-admissionregistration-v1beta1-mutating-webhook-configuration" => {
+            let builder = crate::io::k8s::api::admissionregistration::v1beta1::mutating_webhook_configuration::MutatingWebhookConfigurationPostBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "read-admissionregistration-v1beta1-mutating-webhook-configuration" => {
+            let builder = crate::io::k8s::api::admissionregistration::v1beta1::mutating_webhook_configuration::MutatingWebhookConfigurationGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "replace-admissionregistration-v1beta1-mutating-webhook-configuration" => {
+            let builder = crate::io::k8s::api::admissionregistration::v1beta1::mutating_webhook_configuration::MutatingWebhookConfigurationPutBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "list-admissionregistration-v1beta1-mutating-webhook-configuration" => {
+            let builder = crate::io::k8s::api::admissionregistration::v1beta1::mutating_webhook_configuration_list::MutatingWebhookConfigurationListGetBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "create-admissionregistration-v1beta1-validating-webhook-configuration" => {
+            let builder = crate::io::k8s::api::admissionregistration::v1beta1::validating_webhook_configuration::ValidatingWebhookConfigurationPostBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "read-admissionregistration-v1beta1-validating-webhook-configuration" => {
+            let builder = crate::io::k8s::api::admissionregistration::v1beta1::validating_webhook_configuration::ValidatingWebhookConfigurationGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "replace-admissionregistration-v1beta1-validating-webhook-configuration" => {
+            let builder = crate::io::k8s::api::admissionregistration::v1beta1::validating_webhook_configuration::ValidatingWebhookConfigurationPutBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "list-admissionregistration-v1beta1-validating-webhook-configuration" => {
+            let builder = crate::io::k8s::api::admissionregistration::v1beta1::validating_webhook_configuration_list::ValidatingWebhookConfigurationListGetBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "create-apps-v1-namespaced-controller-revision" => {
+            let builder = crate::io::k8s::api::apps::v1::controller_revision::ControllerRevisionPostBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "read-apps-v1-namespaced-controller-revision" => {
+            let builder = crate::io::k8s::api::apps::v1::controller_revision::ControllerRevisionGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "replace-apps-v1-namespaced-controller-revision" => {
+            let builder = crate::io::k8s::api::apps::v1::controller_revision::ControllerRevisionPutBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "list-apps-v1-controller-revision-for-all-namespaces" => {
+            let builder = crate::io::k8s::api::apps::v1::controller_revision_list::ControllerRevisionListGetBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "list-apps-v1-namespaced-controller-revision" => {
+            let builder = crate::io::k8s::api::apps::v1::controller_revision_list::ControllerRevisionListGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "create-apps-v1-namespaced-daemon-set" => {
+            let builder = crate::io::k8s::api::apps::v1::daemon_set::DaemonSetPostBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "read-apps-v1-namespaced-daemon-set" => {
+            let builder = crate::io::k8s::api::apps::v1::daemon_set::DaemonSetGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "replace-apps-v1-namespaced-daemon-set" => {
+            let builder = crate::io::k8s::api::apps::v1::daemon_set::DaemonSetPutBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "read-apps-v1-namespaced-daemon-set-status" => {
+            let builder = crate::io::k8s::api::apps::v1::daemon_set::DaemonSetGetBuilder2::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "replace-apps-v1-namespaced-daemon-set-status" => {
+            let builder = crate::io::k8s::api::apps::v1::daemon_set::DaemonSetPutBuilder2::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "list-apps-v1-daemon-set-for-all-namespaces" => {
+            let builder = crate::io::k8s::api::apps::v1::daemon_set_list::DaemonSetListGetBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "list-apps-v1-namespaced-daemon-set" => {
+            let builder = crate::io::k8s::api::apps::v1::daemon_set_list::DaemonSetListGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "create-apps-v1-namespaced-deployment" => {
+            let builder = crate::io::k8s::api::apps::v1::deployment::DeploymentPostBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "read-apps-v1-namespaced-deployment" => {
+            let builder = crate::io::k8s::api::apps::v1::deployment::DeploymentGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "replace-apps-v1-namespaced-deployment" => {
+            let builder = crate::io::k8s::api::apps::v1::deployment::DeploymentPutBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "read-apps-v1-namespaced-deployment-status" => {
+            let builder = crate::io::k8s::api::apps::v1::deployment::DeploymentGetBuilder2::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "replace-apps-v1-namespaced-deployment-status" => {
+            let builder = crate::io::k8s::api::apps::v1::deployment::DeploymentPutBuilder2::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "list-apps-v1-deployment-for-all-namespaces" => {
+            let builder = crate::io::k8s::api::apps::v1::deployment_list::DeploymentListGetBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "list-apps-v1-namespaced-deployment" => {
+            let builder = crate::io::k8s::api::apps::v1::deployment_list::DeploymentListGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "create-apps-v1-namespaced-replica-set" => {
+            let builder = crate::io::k8s::api::apps::v1::replica_set::ReplicaSetPostBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "read-apps-v1-namespaced-replica-set" => {
+            let builder = crate::io::k8s::api::apps::v1::replica_set::ReplicaSetGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "replace-apps-v1-namespaced-replica-set" => {
+            let builder = crate::io::k8s::api::apps::v1::replica_set::ReplicaSetPutBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "read-apps-v1-namespaced-replica-set-status" => {
+            let builder = crate::io::k8s::api::apps::v1::replica_set::ReplicaSetGetBuilder2::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "replace-apps-v1-namespaced-replica-set-status" => {
+            let builder = crate::io::k8s::api::apps::v1::replica_set::ReplicaSetPutBuilder2::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "list-apps-v1-namespaced-replica-set" => {
+            let builder = crate::io::k8s::api::apps::v1::replica_set_list::ReplicaSetListGetBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "list-apps-v1-replica-set-for-all-namespaces" => {
+            let builder = crate::io::k8s::api::apps::v1::replica_set_list::ReplicaSetListGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "create-apps-v1-namespaced-stateful-set" => {
+            let builder = crate::io::k8s::api::apps::v1::stateful_set::StatefulSetPostBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "read-apps-v1-namespaced-stateful-set" => {
+            let builder = crate::io::k8s::api::apps::v1::stateful_set::StatefulSetGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "replace-apps-v1-namespaced-stateful-set" => {
+            let builder = crate::io::k8s::api::apps::v1::stateful_set::StatefulSetPutBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "read-apps-v1-namespaced-stateful-set-status" => {
+            let builder = crate::io::k8s::api::apps::v1::stateful_set::StatefulSetGetBuilder2::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "replace-apps-v1-namespaced-stateful-set-status" => {
+            let builder = crate::io::k8s::api::apps::v1::stateful_set::StatefulSetPutBuilder2::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "list-apps-v1-namespaced-stateful-set" => {
+            let builder = crate::io::k8s::api::apps::v1::stateful_set_list::StatefulSetListGetBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "list-apps-v1-stateful-set-for-all-namespaces" => {
+            let builder = crate::io::k8s::api::apps::v1::stateful_set_list::StatefulSetListGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "create-apps-v1beta1-namespaced-controller-revision" => {
+            let builder = crate::io::k8s::api::apps::v1beta1::controller_revision::ControllerRevisionPostBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "read-apps-v1beta1-namespaced-controller-revision" => {
+            let builder = crate::io::k8s::api::apps::v1beta1::controller_revision::ControllerRevisionGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "replace-apps-v1beta1-namespaced-controller-revision" => {
+            let builder = crate::io::k8s::api::apps::v1beta1::controller_revision::ControllerRevisionPutBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "list-apps-v1beta1-controller-revision-for-all-namespaces" => {
+            let builder = crate::io::k8s::api::apps::v1beta1::controller_revision_list::ControllerRevisionListGetBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "list-apps-v1beta1-namespaced-controller-revision" => {
+            let builder = crate::io::k8s::api::apps::v1beta1::controller_revision_list::ControllerRevisionListGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "create-apps-v1beta1-namespaced-deployment" => {
+            let builder = crate::io::k8s::api::apps::v1beta1::deployment::DeploymentPostBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "read-apps-v1beta1-namespaced-deployment" => {
+            let builder = crate::io::k8s::api::apps::v1beta1::deployment::DeploymentGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "replace-apps-v1beta1-namespaced-deployment" => {
+            let builder = crate::io::k8s::api::apps::v1beta1::deployment::DeploymentPutBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "read-apps-v1beta1-namespaced-deployment-status" => {
+            let builder = crate::io::k8s::api::apps::v1beta1::deployment::DeploymentGetBuilder2::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "replace-apps-v1beta1-namespaced-deployment-status" => {
+            let builder = crate::io::k8s::api::apps::v1beta1::deployment::DeploymentPutBuilder2::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "list-apps-v1beta1-deployment-for-all-namespaces" => {
+            let builder = crate::io::k8s::api::apps::v1beta1::deployment_list::DeploymentListGetBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "list-apps-v1beta1-namespaced-deployment" => {
+            let builder = crate::io::k8s::api::apps::v1beta1::deployment_list::DeploymentListGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "create-apps-v1beta1-namespaced-deployment-rollback" => {
+            let builder = crate::io::k8s::api::apps::v1beta1::deployment_rollback::DeploymentRollbackPostBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "read-apps-v1beta1-namespaced-deployment-scale" => {
+            let builder = crate::io::k8s::api::apps::v1beta1::scale::ScaleGetBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "replace-apps-v1beta1-namespaced-deployment-scale" => {
+            let builder = crate::io::k8s::api::apps::v1beta1::scale::ScalePutBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "read-apps-v1beta1-namespaced-stateful-set-scale" => {
+            let builder = crate::io::k8s::api::apps::v1beta1::scale::ScaleGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "replace-apps-v1beta1-namespaced-stateful-set-scale" => {
+            let builder = crate::io::k8s::api::apps::v1beta1::scale::ScalePutBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "create-apps-v1beta1-namespaced-stateful-set" => {
+            let builder = crate::io::k8s::api::apps::v1beta1::stateful_set::StatefulSetPostBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "read-apps-v1beta1-namespaced-stateful-set" => {
+            let builder = crate::io::k8s::api::apps::v1beta1::stateful_set::StatefulSetGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "replace-apps-v1beta1-namespaced-stateful-set" => {
+            let builder = crate::io::k8s::api::apps::v1beta1::stateful_set::StatefulSetPutBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "read-apps-v1beta1-namespaced-stateful-set-status" => {
+            let builder = crate::io::k8s::api::apps::v1beta1::stateful_set::StatefulSetGetBuilder2::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "replace-apps-v1beta1-namespaced-stateful-set-status" => {
+            let builder = crate::io::k8s::api::apps::v1beta1::stateful_set::StatefulSetPutBuilder2::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "list-apps-v1beta1-namespaced-stateful-set" => {
+            let builder = crate::io::k8s::api::apps::v1beta1::stateful_set_list::StatefulSetListGetBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "list-apps-v1beta1-stateful-set-for-all-namespaces" => {
+            let builder = crate::io::k8s::api::apps::v1beta1::stateful_set_list::StatefulSetListGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "create-apps-v1beta2-namespaced-controller-revision" => {
+            let builder = crate::io::k8s::api::apps::v1beta2::controller_revision::ControllerRevisionPostBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "read-apps-v1beta2-namespaced-controller-revision" => {
+            let builder = crate::io::k8s::api::apps::v1beta2::controller_revision::ControllerRevisionGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "replace-apps-v1beta2-namespaced-controller-revision" => {
+            let builder = crate::io::k8s::api::apps::v1beta2::controller_revision::ControllerRevisionPutBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "list-apps-v1beta2-controller-revision-for-all-namespaces" => {
+            let builder = crate::io::k8s::api::apps::v1beta2::controller_revision_list::ControllerRevisionListGetBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "list-apps-v1beta2-namespaced-controller-revision" => {
+            let builder = crate::io::k8s::api::apps::v1beta2::controller_revision_list::ControllerRevisionListGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "create-apps-v1beta2-namespaced-daemon-set" => {
+            let builder = crate::io::k8s::api::apps::v1beta2::daemon_set::DaemonSetPostBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "read-apps-v1beta2-namespaced-daemon-set" => {
+            let builder = crate::io::k8s::api::apps::v1beta2::daemon_set::DaemonSetGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "replace-apps-v1beta2-namespaced-daemon-set" => {
+            let builder = crate::io::k8s::api::apps::v1beta2::daemon_set::DaemonSetPutBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "read-apps-v1beta2-namespaced-daemon-set-status" => {
+            let builder = crate::io::k8s::api::apps::v1beta2::daemon_set::DaemonSetGetBuilder2::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "replace-apps-v1beta2-namespaced-daemon-set-status" => {
+            let builder = crate::io::k8s::api::apps::v1beta2::daemon_set::DaemonSetPutBuilder2::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "list-apps-v1beta2-daemon-set-for-all-namespaces" => {
+            let builder = crate::io::k8s::api::apps::v1beta2::daemon_set_list::DaemonSetListGetBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "list-apps-v1beta2-namespaced-daemon-set" => {
+            let builder = crate::io::k8s::api::apps::v1beta2::daemon_set_list::DaemonSetListGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "create-apps-v1beta2-namespaced-deployment" => {
+            let builder = crate::io::k8s::api::apps::v1beta2::deployment::DeploymentPostBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "read-apps-v1beta2-namespaced-deployment" => {
+            let builder = crate::io::k8s::api::apps::v1beta2::deployment::DeploymentGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "replace-apps-v1beta2-namespaced-deployment" => {
+            let builder = crate::io::k8s::api::apps::v1beta2::deployment::DeploymentPutBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "read-apps-v1beta2-namespaced-deployment-status" => {
+            let builder = crate::io::k8s::api::apps::v1beta2::deployment::DeploymentGetBuilder2::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "replace-apps-v1beta2-namespaced-deployment-status" => {
+            let builder = crate::io::k8s::api::apps::v1beta2::deployment::DeploymentPutBuilder2::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "list-apps-v1beta2-deployment-for-all-namespaces" => {
+            let builder = crate::io::k8s::api::apps::v1beta2::deployment_list::DeploymentListGetBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "list-apps-v1beta2-namespaced-deployment" => {
+            let builder = crate::io::k8s::api::apps::v1beta2::deployment_list::DeploymentListGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "create-apps-v1beta2-namespaced-replica-set" => {
+            let builder = crate::io::k8s::api::apps::v1beta2::replica_set::ReplicaSetPostBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "read-apps-v1beta2-namespaced-replica-set" => {
+            let builder = crate::io::k8s::api::apps::v1beta2::replica_set::ReplicaSetGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "replace-apps-v1beta2-namespaced-replica-set" => {
+            let builder = crate::io::k8s::api::apps::v1beta2::replica_set::ReplicaSetPutBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "read-apps-v1beta2-namespaced-replica-set-status" => {
+            let builder = crate::io::k8s::api::apps::v1beta2::replica_set::ReplicaSetGetBuilder2::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "replace-apps-v1beta2-namespaced-replica-set-status" => {
+            let builder = crate::io::k8s::api::apps::v1beta2::replica_set::ReplicaSetPutBuilder2::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "list-apps-v1beta2-namespaced-replica-set" => {
+            let builder = crate::io::k8s::api::apps::v1beta2::replica_set_list::ReplicaSetListGetBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "list-apps-v1beta2-replica-set-for-all-namespaces" => {
+            let builder = crate::io::k8s::api::apps::v1beta2::replica_set_list::ReplicaSetListGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "read-apps-v1beta2-namespaced-deployment-scale" => {
+            let builder = crate::io::k8s::api::apps::v1beta2::scale::ScaleGetBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "replace-apps-v1beta2-namespaced-deployment-scale" => {
+            let builder = crate::io::k8s::api::apps::v1beta2::scale::ScalePutBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "read-apps-v1beta2-namespaced-replica-set-scale" => {
+            let builder = crate::io::k8s::api::apps::v1beta2::scale::ScaleGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "replace-apps-v1beta2-namespaced-replica-set-scale" => {
+            let builder = crate::io::k8s::api::apps::v1beta2::scale::ScalePutBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "read-apps-v1beta2-namespaced-stateful-set-scale" => {
+            let builder = crate::io::k8s::api::apps::v1beta2::scale::ScaleGetBuilder2::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "replace-apps-v1beta2-namespaced-stateful-set-scale" => {
+            let builder = crate::io::k8s::api::apps::v1beta2::scale::ScalePutBuilder2::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "create-apps-v1beta2-namespaced-stateful-set" => {
+            let builder = crate::io::k8s::api::apps::v1beta2::stateful_set::StatefulSetPostBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "read-apps-v1beta2-namespaced-stateful-set" => {
+            let builder = crate::io::k8s::api::apps::v1beta2::stateful_set::StatefulSetGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "replace-apps-v1beta2-namespaced-stateful-set" => {
+            let builder = crate::io::k8s::api::apps::v1beta2::stateful_set::StatefulSetPutBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "read-apps-v1beta2-namespaced-stateful-set-status" => {
+            let builder = crate::io::k8s::api::apps::v1beta2::stateful_set::StatefulSetGetBuilder2::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "replace-apps-v1beta2-namespaced-stateful-set-status" => {
+            let builder = crate::io::k8s::api::apps::v1beta2::stateful_set::StatefulSetPutBuilder2::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "list-apps-v1beta2-namespaced-stateful-set" => {
+            let builder = crate::io::k8s::api::apps::v1beta2::stateful_set_list::StatefulSetListGetBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "list-apps-v1beta2-stateful-set-for-all-namespaces" => {
+            let builder = crate::io::k8s::api::apps::v1beta2::stateful_set_list::StatefulSetListGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "create-auditregistration-v1alpha1-audit-sink" => {
+            let builder = crate::io::k8s::api::auditregistration::v1alpha1::audit_sink::AuditSinkPostBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "read-auditregistration-v1alpha1-audit-sink" => {
+            let builder = crate::io::k8s::api::auditregistration::v1alpha1::audit_sink::AuditSinkGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "replace-auditregistration-v1alpha1-audit-sink" => {
+            let builder = crate::io::k8s::api::auditregistration::v1alpha1::audit_sink::AuditSinkPutBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "list-auditregistration-v1alpha1-audit-sink" => {
+            let builder = crate::io::k8s::api::auditregistration::v1alpha1::audit_sink_list::AuditSinkListGetBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "create-authentication-v1-token-review" => {
+            let builder = crate::io::k8s::api::authentication::v1::token_review::TokenReviewPostBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "create-authentication-v1beta1-token-review" => {
+            let builder = crate::io::k8s::api::authentication::v1beta1::token_review::TokenReviewPostBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "create-authorization-v1-namespaced-local-subject-access-review" => {
+            let builder = crate::io::k8s::api::authorization::v1::local_subject_access_review::LocalSubjectAccessReviewPostBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "create-authorization-v1-self-subject-access-review" => {
+            let builder = crate::io::k8s::api::authorization::v1::self_subject_access_review::SelfSubjectAccessReviewPostBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "create-authorization-v1-self-subject-rules-review" => {
+            let builder = crate::io::k8s::api::authorization::v1::self_subject_rules_review::SelfSubjectRulesReviewPostBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "create-authorization-v1-subject-access-review" => {
+            let builder = crate::io::k8s::api::authorization::v1::subject_access_review::SubjectAccessReviewPostBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "create-authorization-v1beta1-namespaced-local-subject-access-review" => {
+            let builder = crate::io::k8s::api::authorization::v1beta1::local_subject_access_review::LocalSubjectAccessReviewPostBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "create-authorization-v1beta1-self-subject-access-review" => {
+            let builder = crate::io::k8s::api::authorization::v1beta1::self_subject_access_review::SelfSubjectAccessReviewPostBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "create-authorization-v1beta1-self-subject-rules-review" => {
+            let builder = crate::io::k8s::api::authorization::v1beta1::self_subject_rules_review::SelfSubjectRulesReviewPostBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "create-authorization-v1beta1-subject-access-review" => {
+            let builder = crate::io::k8s::api::authorization::v1beta1::subject_access_review::SubjectAccessReviewPostBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "create-autoscaling-v1-namespaced-horizontal-pod-autoscaler" => {
+            let builder = crate::io::k8s::api::autoscaling::v1::horizontal_pod_autoscaler::HorizontalPodAutoscalerPostBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "read-autoscaling-v1-namespaced-horizontal-pod-autoscaler" => {
+            let builder = crate::io::k8s::api::autoscaling::v1::horizontal_pod_autoscaler::HorizontalPodAutoscalerGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "replace-autoscaling-v1-namespaced-horizontal-pod-autoscaler" => {
+            let builder = crate::io::k8s::api::autoscaling::v1::horizontal_pod_autoscaler::HorizontalPodAutoscalerPutBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "read-autoscaling-v1-namespaced-horizontal-pod-autoscaler-status" => {
+            let builder = crate::io::k8s::api::autoscaling::v1::horizontal_pod_autoscaler::HorizontalPodAutoscalerGetBuilder2::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "replace-autoscaling-v1-namespaced-horizontal-pod-autoscaler-status" => {
+            let builder = crate::io::k8s::api::autoscaling::v1::horizontal_pod_autoscaler::HorizontalPodAutoscalerPutBuilder2::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "list-autoscaling-v1-horizontal-pod-autoscaler-for-all-namespaces" => {
+            let builder = crate::io::k8s::api::autoscaling::v1::horizontal_pod_autoscaler_list::HorizontalPodAutoscalerListGetBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "list-autoscaling-v1-namespaced-horizontal-pod-autoscaler" => {
+            let builder = crate::io::k8s::api::autoscaling::v1::horizontal_pod_autoscaler_list::HorizontalPodAutoscalerListGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "read-core-v1-namespaced-replication-controller-scale" => {
+            let builder = crate::io::k8s::api::autoscaling::v1::scale::ScaleGetBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "replace-core-v1-namespaced-replication-controller-scale" => {
+            let builder = crate::io::k8s::api::autoscaling::v1::scale::ScalePutBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "read-apps-v1-namespaced-deployment-scale" => {
+            let builder = crate::io::k8s::api::autoscaling::v1::scale::ScaleGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "replace-apps-v1-namespaced-deployment-scale" => {
+            let builder = crate::io::k8s::api::autoscaling::v1::scale::ScalePutBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "read-apps-v1-namespaced-replica-set-scale" => {
+            let builder = crate::io::k8s::api::autoscaling::v1::scale::ScaleGetBuilder2::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "replace-apps-v1-namespaced-replica-set-scale" => {
+            let builder = crate::io::k8s::api::autoscaling::v1::scale::ScalePutBuilder2::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "read-apps-v1-namespaced-stateful-set-scale" => {
+            let builder = crate::io::k8s::api::autoscaling::v1::scale::ScaleGetBuilder3::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "replace-apps-v1-namespaced-stateful-set-scale" => {
+            let builder = crate::io::k8s::api::autoscaling::v1::scale::ScalePutBuilder3::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "create-autoscaling-v2beta1-namespaced-horizontal-pod-autoscaler" => {
+            let builder = crate::io::k8s::api::autoscaling::v2beta1::horizontal_pod_autoscaler::HorizontalPodAutoscalerPostBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "read-autoscaling-v2beta1-namespaced-horizontal-pod-autoscaler" => {
+            let builder = crate::io::k8s::api::autoscaling::v2beta1::horizontal_pod_autoscaler::HorizontalPodAutoscalerGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "replace-autoscaling-v2beta1-namespaced-horizontal-pod-autoscaler" => {
+            let builder = crate::io::k8s::api::autoscaling::v2beta1::horizontal_pod_autoscaler::HorizontalPodAutoscalerPutBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "read-autoscaling-v2beta1-namespaced-horizontal-pod-autoscaler-status" => {
+            let builder = crate::io::k8s::api::autoscaling::v2beta1::horizontal_pod_autoscaler::HorizontalPodAutoscalerGetBuilder2::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "replace-autoscaling-v2beta1-namespaced-horizontal-pod-autoscaler-status" => {
+            let builder = crate::io::k8s::api::autoscaling::v2beta1::horizontal_pod_autoscaler::HorizontalPodAutoscalerPutBuilder2::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "list-autoscaling-v2beta1-horizontal-pod-autoscaler-for-all-namespaces" => {
+            let builder = crate::io::k8s::api::autoscaling::v2beta1::horizontal_pod_autoscaler_list::HorizontalPodAutoscalerListGetBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "list-autoscaling-v2beta1-namespaced-horizontal-pod-autoscaler" => {
+            let builder = crate::io::k8s::api::autoscaling::v2beta1::horizontal_pod_autoscaler_list::HorizontalPodAutoscalerListGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "create-autoscaling-v2beta2-namespaced-horizontal-pod-autoscaler" => {
+            let builder = crate::io::k8s::api::autoscaling::v2beta2::horizontal_pod_autoscaler::HorizontalPodAutoscalerPostBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "read-autoscaling-v2beta2-namespaced-horizontal-pod-autoscaler" => {
+            let builder = crate::io::k8s::api::autoscaling::v2beta2::horizontal_pod_autoscaler::HorizontalPodAutoscalerGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "replace-autoscaling-v2beta2-namespaced-horizontal-pod-autoscaler" => {
+            let builder = crate::io::k8s::api::autoscaling::v2beta2::horizontal_pod_autoscaler::HorizontalPodAutoscalerPutBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "read-autoscaling-v2beta2-namespaced-horizontal-pod-autoscaler-status" => {
+            let builder = crate::io::k8s::api::autoscaling::v2beta2::horizontal_pod_autoscaler::HorizontalPodAutoscalerGetBuilder2::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "replace-autoscaling-v2beta2-namespaced-horizontal-pod-autoscaler-status" => {
+            let builder = crate::io::k8s::api::autoscaling::v2beta2::horizontal_pod_autoscaler::HorizontalPodAutoscalerPutBuilder2::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "list-autoscaling-v2beta2-horizontal-pod-autoscaler-for-all-namespaces" => {
+            let builder = crate::io::k8s::api::autoscaling::v2beta2::horizontal_pod_autoscaler_list::HorizontalPodAutoscalerListGetBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "list-autoscaling-v2beta2-namespaced-horizontal-pod-autoscaler" => {
+            let builder = crate::io::k8s::api::autoscaling::v2beta2::horizontal_pod_autoscaler_list::HorizontalPodAutoscalerListGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "create-batch-v1-namespaced-job" => {
+            let builder = crate::io::k8s::api::batch::v1::job::JobPostBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "read-batch-v1-namespaced-job" => {
+            let builder = crate::io::k8s::api::batch::v1::job::JobGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "replace-batch-v1-namespaced-job" => {
+            let builder = crate::io::k8s::api::batch::v1::job::JobPutBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "read-batch-v1-namespaced-job-status" => {
+            let builder = crate::io::k8s::api::batch::v1::job::JobGetBuilder2::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "replace-batch-v1-namespaced-job-status" => {
+            let builder = crate::io::k8s::api::batch::v1::job::JobPutBuilder2::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "list-batch-v1-job-for-all-namespaces" => {
+            let builder = crate::io::k8s::api::batch::v1::job_list::JobListGetBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "list-batch-v1-namespaced-job" => {
+            let builder = crate::io::k8s::api::batch::v1::job_list::JobListGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "create-batch-v1beta1-namespaced-cron-job" => {
+            let builder = crate::io::k8s::api::batch::v1beta1::cron_job::CronJobPostBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "read-batch-v1beta1-namespaced-cron-job" => {
+            let builder = crate::io::k8s::api::batch::v1beta1::cron_job::CronJobGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "replace-batch-v1beta1-namespaced-cron-job" => {
+            let builder = crate::io::k8s::api::batch::v1beta1::cron_job::CronJobPutBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "read-batch-v1beta1-namespaced-cron-job-status" => {
+            let builder = crate::io::k8s::api::batch::v1beta1::cron_job::CronJobGetBuilder2::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "replace-batch-v1beta1-namespaced-cron-job-status" => {
+            let builder = crate::io::k8s::api::batch::v1beta1::cron_job::CronJobPutBuilder2::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "list-batch-v1beta1-cron-job-for-all-namespaces" => {
+            let builder = crate::io::k8s::api::batch::v1beta1::cron_job_list::CronJobListGetBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "list-batch-v1beta1-namespaced-cron-job" => {
+            let builder = crate::io::k8s::api::batch::v1beta1::cron_job_list::CronJobListGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "create-batch-v2alpha1-namespaced-cron-job" => {
+            let builder = crate::io::k8s::api::batch::v2alpha1::cron_job::CronJobPostBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "read-batch-v2alpha1-namespaced-cron-job" => {
+            let builder = crate::io::k8s::api::batch::v2alpha1::cron_job::CronJobGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "replace-batch-v2alpha1-namespaced-cron-job" => {
+            let builder = crate::io::k8s::api::batch::v2alpha1::cron_job::CronJobPutBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "read-batch-v2alpha1-namespaced-cron-job-status" => {
+            let builder = crate::io::k8s::api::batch::v2alpha1::cron_job::CronJobGetBuilder2::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "replace-batch-v2alpha1-namespaced-cron-job-status" => {
+            let builder = crate::io::k8s::api::batch::v2alpha1::cron_job::CronJobPutBuilder2::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "list-batch-v2alpha1-cron-job-for-all-namespaces" => {
+            let builder = crate::io::k8s::api::batch::v2alpha1::cron_job_list::CronJobListGetBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "list-batch-v2alpha1-namespaced-cron-job" => {
+            let builder = crate::io::k8s::api::batch::v2alpha1::cron_job_list::CronJobListGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "create-certificates-v1beta1-certificate-signing-request" => {
+            let builder = crate::io::k8s::api::certificates::v1beta1::certificate_signing_request::CertificateSigningRequestPostBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "read-certificates-v1beta1-certificate-signing-request" => {
+            let builder = crate::io::k8s::api::certificates::v1beta1::certificate_signing_request::CertificateSigningRequestGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "replace-certificates-v1beta1-certificate-signing-request" => {
+            let builder = crate::io::k8s::api::certificates::v1beta1::certificate_signing_request::CertificateSigningRequestPutBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "replace-certificates-v1beta1-certificate-signing-request-approval" => {
+            let builder = crate::io::k8s::api::certificates::v1beta1::certificate_signing_request::CertificateSigningRequestPutBuilder2::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "read-certificates-v1beta1-certificate-signing-request-status" => {
+            let builder = crate::io::k8s::api::certificates::v1beta1::certificate_signing_request::CertificateSigningRequestGetBuilder3::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "replace-certificates-v1beta1-certificate-signing-request-status" => {
+            let builder = crate::io::k8s::api::certificates::v1beta1::certificate_signing_request::CertificateSigningRequestPutBuilder3::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "list-certificates-v1beta1-certificate-signing-request" => {
+            let builder = crate::io::k8s::api::certificates::v1beta1::certificate_signing_request_list::CertificateSigningRequestListGetBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "create-coordination-v1-namespaced-lease" => {
+            let builder = crate::io::k8s::api::coordination::v1::lease::LeasePostBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "read-coordination-v1-namespaced-lease" => {
+            let builder = crate::io::k8s::api::coordination::v1::lease::LeaseGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "replace-coordination-v1-namespaced-lease" => {
+            let builder = crate::io::k8s::api::coordination::v1::lease::LeasePutBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "list-coordination-v1-lease-for-all-namespaces" => {
+            let builder = crate::io::k8s::api::coordination::v1::lease_list::LeaseListGetBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "list-coordination-v1-namespaced-lease" => {
+            let builder = crate::io::k8s::api::coordination::v1::lease_list::LeaseListGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "create-coordination-v1beta1-namespaced-lease" => {
+            let builder = crate::io::k8s::api::coordination::v1beta1::lease::LeasePostBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "read-coordination-v1beta1-namespaced-lease" => {
+            let builder = crate::io::k8s::api::coordination::v1beta1::lease::LeaseGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "replace-coordination-v1beta1-namespaced-lease" => {
+            let builder = crate::io::k8s::api::coordination::v1beta1::lease::LeasePutBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "list-coordination-v1beta1-lease-for-all-namespaces" => {
+            let builder = crate::io::k8s::api::coordination::v1beta1::lease_list::LeaseListGetBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "list-coordination-v1beta1-namespaced-lease" => {
+            let builder = crate::io::k8s::api::coordination::v1beta1::lease_list::LeaseListGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "create-core-v1-namespaced-binding" => {
+            let builder = crate::io::k8s::api::core::v1::binding::BindingPostBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "create-core-v1-namespaced-pod-binding" => {
+            let builder = crate::io::k8s::api::core::v1::binding::BindingPostBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "read-core-v1-component-status" => {
+            let builder = crate::io::k8s::api::core::v1::component_status::ComponentStatusGetBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "list-core-v1-component-status" => {
+            let builder = crate::io::k8s::api::core::v1::component_status_list::ComponentStatusListGetBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "create-core-v1-namespaced-config-map" => {
+            let builder = crate::io::k8s::api::core::v1::config_map::ConfigMapPostBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "read-core-v1-namespaced-config-map" => {
+            let builder = crate::io::k8s::api::core::v1::config_map::ConfigMapGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "replace-core-v1-namespaced-config-map" => {
+            let builder = crate::io::k8s::api::core::v1::config_map::ConfigMapPutBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "list-core-v1-config-map-for-all-namespaces" => {
+            let builder = crate::io::k8s::api::core::v1::config_map_list::ConfigMapListGetBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "list-core-v1-namespaced-config-map" => {
+            let builder = crate::io::k8s::api::core::v1::config_map_list::ConfigMapListGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "create-core-v1-namespaced-endpoints" => {
+            let builder = crate::io::k8s::api::core::v1::endpoints::EndpointsPostBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "read-core-v1-namespaced-endpoints" => {
+            let builder = crate::io::k8s::api::core::v1::endpoints::EndpointsGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "replace-core-v1-namespaced-endpoints" => {
+            let builder = crate::io::k8s::api::core::v1::endpoints::EndpointsPutBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "list-core-v1-endpoints-for-all-namespaces" => {
+            let builder = crate::io::k8s::api::core::v1::endpoints_list::EndpointsListGetBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "list-core-v1-namespaced-endpoints" => {
+            let builder = crate::io::k8s::api::core::v1::endpoints_list::EndpointsListGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "create-core-v1-namespaced-event" => {
+            let builder = crate::io::k8s::api::core::v1::event::EventPostBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "read-core-v1-namespaced-event" => {
+            let builder = crate::io::k8s::api::core::v1::event::EventGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "replace-core-v1-namespaced-event" => {
+            let builder = crate::io::k8s::api::core::v1::event::EventPutBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "list-core-v1-event-for-all-namespaces" => {
+            let builder = crate::io::k8s::api::core::v1::event_list::EventListGetBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "list-core-v1-namespaced-event" => {
+            let builder = crate::io::k8s::api::core::v1::event_list::EventListGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "create-core-v1-namespaced-limit-range" => {
+            let builder = crate::io::k8s::api::core::v1::limit_range::LimitRangePostBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "read-core-v1-namespaced-limit-range" => {
+            let builder = crate::io::k8s::api::core::v1::limit_range::LimitRangeGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "replace-core-v1-namespaced-limit-range" => {
+            let builder = crate::io::k8s::api::core::v1::limit_range::LimitRangePutBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "list-core-v1-limit-range-for-all-namespaces" => {
+            let builder = crate::io::k8s::api::core::v1::limit_range_list::LimitRangeListGetBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "list-core-v1-namespaced-limit-range" => {
+            let builder = crate::io::k8s::api::core::v1::limit_range_list::LimitRangeListGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "create-core-v1-namespace" => {
+            let builder = crate::io::k8s::api::core::v1::namespace::NamespacePostBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "read-core-v1-namespace" => {
+            let builder = crate::io::k8s::api::core::v1::namespace::NamespaceGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "replace-core-v1-namespace" => {
+            let builder = crate::io::k8s::api::core::v1::namespace::NamespacePutBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "replace-core-v1-namespace-finalize" => {
+            let builder = crate::io::k8s::api::core::v1::namespace::NamespacePutBuilder2::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "read-core-v1-namespace-status" => {
+            let builder = crate::io::k8s::api::core::v1::namespace::NamespaceGetBuilder3::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "replace-core-v1-namespace-status" => {
+            let builder = crate::io::k8s::api::core::v1::namespace::NamespacePutBuilder3::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "list-core-v1-namespace" => {
+            let builder = crate::io::k8s::api::core::v1::namespace_list::NamespaceListGetBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "create-core-v1-node" => {
+            let builder = crate::io::k8s::api::core::v1::node::NodePostBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "read-core-v1-node" => {
+            let builder = crate::io::k8s::api::core::v1::node::NodeGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "replace-core-v1-node" => {
+            let builder = crate::io::k8s::api::core::v1::node::NodePutBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "read-core-v1-node-status" => {
+            let builder = crate::io::k8s::api::core::v1::node::NodeGetBuilder2::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "replace-core-v1-node-status" => {
+            let builder = crate::io::k8s::api::core::v1::node::NodePutBuilder2::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "list-core-v1-node" => {
+            let builder = crate::io::k8s::api::core::v1::node_list::NodeListGetBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "create-core-v1-persistent-volume" => {
+            let builder = crate::io::k8s::api::core::v1::persistent_volume::PersistentVolumePostBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "read-core-v1-persistent-volume" => {
+            let builder = crate::io::k8s::api::core::v1::persistent_volume::PersistentVolumeGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "replace-core-v1-persistent-volume" => {
+            let builder = crate::io::k8s::api::core::v1::persistent_volume::PersistentVolumePutBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "read-core-v1-persistent-volume-status" => {
+            let builder = crate::io::k8s::api::core::v1::persistent_volume::PersistentVolumeGetBuilder2::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "replace-core-v1-persistent-volume-status" => {
+            let builder = crate::io::k8s::api::core::v1::persistent_volume::PersistentVolumePutBuilder2::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "create-core-v1-namespaced-persistent-volume-claim" => {
+            let builder = crate::io::k8s::api::core::v1::persistent_volume_claim::PersistentVolumeClaimPostBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "read-core-v1-namespaced-persistent-volume-claim" => {
+            let builder = crate::io::k8s::api::core::v1::persistent_volume_claim::PersistentVolumeClaimGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "replace-core-v1-namespaced-persistent-volume-claim" => {
+            let builder = crate::io::k8s::api::core::v1::persistent_volume_claim::PersistentVolumeClaimPutBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "read-core-v1-namespaced-persistent-volume-claim-status" => {
+            let builder = crate::io::k8s::api::core::v1::persistent_volume_claim::PersistentVolumeClaimGetBuilder2::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "replace-core-v1-namespaced-persistent-volume-claim-status" => {
+            let builder = crate::io::k8s::api::core::v1::persistent_volume_claim::PersistentVolumeClaimPutBuilder2::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "list-core-v1-namespaced-persistent-volume-claim" => {
+            let builder = crate::io::k8s::api::core::v1::persistent_volume_claim_list::PersistentVolumeClaimListGetBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "list-core-v1-persistent-volume-claim-for-all-namespaces" => {
+            let builder = crate::io::k8s::api::core::v1::persistent_volume_claim_list::PersistentVolumeClaimListGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "list-core-v1-persistent-volume" => {
+            let builder = crate::io::k8s::api::core::v1::persistent_volume_list::PersistentVolumeListGetBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "create-core-v1-namespaced-pod" => {
+            let builder = crate::io::k8s::api::core::v1::pod::PodPostBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "read-core-v1-namespaced-pod" => {
+            let builder = crate::io::k8s::api::core::v1::pod::PodGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "replace-core-v1-namespaced-pod" => {
+            let builder = crate::io::k8s::api::core::v1::pod::PodPutBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "read-core-v1-namespaced-pod-status" => {
+            let builder = crate::io::k8s::api::core::v1::pod::PodGetBuilder2::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "replace-core-v1-namespaced-pod-status" => {
+            let builder = crate::io::k8s::api::core::v1::pod::PodPutBuilder2::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "list-core-v1-namespaced-pod" => {
+            let builder = crate::io::k8s::api::core::v1::pod_list::PodListGetBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "list-core-v1-pod-for-all-namespaces" => {
+            let builder = crate::io::k8s::api::core::v1::pod_list::PodListGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "create-core-v1-namespaced-pod-template" => {
+            let builder = crate::io::k8s::api::core::v1::pod_template::PodTemplatePostBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "read-core-v1-namespaced-pod-template" => {
+            let builder = crate::io::k8s::api::core::v1::pod_template::PodTemplateGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "replace-core-v1-namespaced-pod-template" => {
+            let builder = crate::io::k8s::api::core::v1::pod_template::PodTemplatePutBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "list-core-v1-namespaced-pod-template" => {
+            let builder = crate::io::k8s::api::core::v1::pod_template_list::PodTemplateListGetBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "list-core-v1-pod-template-for-all-namespaces" => {
+            let builder = crate::io::k8s::api::core::v1::pod_template_list::PodTemplateListGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "create-core-v1-namespaced-replication-controller" => {
+            let builder = crate::io::k8s::api::core::v1::replication_controller::ReplicationControllerPostBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "read-core-v1-namespaced-replication-controller" => {
+            let builder = crate::io::k8s::api::core::v1::replication_controller::ReplicationControllerGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "replace-core-v1-namespaced-replication-controller" => {
+            let builder = crate::io::k8s::api::core::v1::replication_controller::ReplicationControllerPutBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "read-core-v1-namespaced-replication-controller-status" => {
+            let builder = crate::io::k8s::api::core::v1::replication_controller::ReplicationControllerGetBuilder2::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "replace-core-v1-namespaced-replication-controller-status" => {
+            let builder = crate::io::k8s::api::core::v1::replication_controller::ReplicationControllerPutBuilder2::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "list-core-v1-namespaced-replication-controller" => {
+            let builder = crate::io::k8s::api::core::v1::replication_controller_list::ReplicationControllerListGetBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "list-core-v1-replication-controller-for-all-namespaces" => {
+            let builder = crate::io::k8s::api::core::v1::replication_controller_list::ReplicationControllerListGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "create-core-v1-namespaced-resource-quota" => {
+            let builder = crate::io::k8s::api::core::v1::resource_quota::ResourceQuotaPostBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "read-core-v1-namespaced-resource-quota" => {
+            let builder = crate::io::k8s::api::core::v1::resource_quota::ResourceQuotaGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "replace-core-v1-namespaced-resource-quota" => {
+            let builder = crate::io::k8s::api::core::v1::resource_quota::ResourceQuotaPutBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "read-core-v1-namespaced-resource-quota-status" => {
+            let builder = crate::io::k8s::api::core::v1::resource_quota::ResourceQuotaGetBuilder2::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "replace-core-v1-namespaced-resource-quota-status" => {
+            let builder = crate::io::k8s::api::core::v1::resource_quota::ResourceQuotaPutBuilder2::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "list-core-v1-namespaced-resource-quota" => {
+            let builder = crate::io::k8s::api::core::v1::resource_quota_list::ResourceQuotaListGetBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "list-core-v1-resource-quota-for-all-namespaces" => {
+            let builder = crate::io::k8s::api::core::v1::resource_quota_list::ResourceQuotaListGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "create-core-v1-namespaced-secret" => {
+            let builder = crate::io::k8s::api::core::v1::secret::SecretPostBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "read-core-v1-namespaced-secret" => {
+            let builder = crate::io::k8s::api::core::v1::secret::SecretGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "replace-core-v1-namespaced-secret" => {
+            let builder = crate::io::k8s::api::core::v1::secret::SecretPutBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "list-core-v1-namespaced-secret" => {
+            let builder = crate::io::k8s::api::core::v1::secret_list::SecretListGetBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "list-core-v1-secret-for-all-namespaces" => {
+            let builder = crate::io::k8s::api::core::v1::secret_list::SecretListGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "create-core-v1-namespaced-service" => {
+            let builder = crate::io::k8s::api::core::v1::service::ServicePostBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "read-core-v1-namespaced-service" => {
+            let builder = crate::io::k8s::api::core::v1::service::ServiceGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "replace-core-v1-namespaced-service" => {
+            let builder = crate::io::k8s::api::core::v1::service::ServicePutBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "read-core-v1-namespaced-service-status" => {
+            let builder = crate::io::k8s::api::core::v1::service::ServiceGetBuilder2::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "replace-core-v1-namespaced-service-status" => {
+            let builder = crate::io::k8s::api::core::v1::service::ServicePutBuilder2::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "create-core-v1-namespaced-service-account" => {
+            let builder = crate::io::k8s::api::core::v1::service_account::ServiceAccountPostBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "read-core-v1-namespaced-service-account" => {
+            let builder = crate::io::k8s::api::core::v1::service_account::ServiceAccountGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "replace-core-v1-namespaced-service-account" => {
+            let builder = crate::io::k8s::api::core::v1::service_account::ServiceAccountPutBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "list-core-v1-namespaced-service-account" => {
+            let builder = crate::io::k8s::api::core::v1::service_account_list::ServiceAccountListGetBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "list-core-v1-service-account-for-all-namespaces" => {
+            let builder = crate::io::k8s::api::core::v1::service_account_list::ServiceAccountListGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "list-core-v1-namespaced-service" => {
+            let builder = crate::io::k8s::api::core::v1::service_list::ServiceListGetBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "list-core-v1-service-for-all-namespaces" => {
+            let builder = crate::io::k8s::api::core::v1::service_list::ServiceListGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "create-events-v1beta1-namespaced-event" => {
+            let builder = crate::io::k8s::api::events::v1beta1::event::EventPostBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "read-events-v1beta1-namespaced-event" => {
+            let builder = crate::io::k8s::api::events::v1beta1::event::EventGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "replace-events-v1beta1-namespaced-event" => {
+            let builder = crate::io::k8s::api::events::v1beta1::event::EventPutBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "list-events-v1beta1-event-for-all-namespaces" => {
+            let builder = crate::io::k8s::api::events::v1beta1::event_list::EventListGetBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "list-events-v1beta1-namespaced-event" => {
+            let builder = crate::io::k8s::api::events::v1beta1::event_list::EventListGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "create-extensions-v1beta1-namespaced-daemon-set" => {
+            let builder = crate::io::k8s::api::extensions::v1beta1::daemon_set::DaemonSetPostBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "read-extensions-v1beta1-namespaced-daemon-set" => {
+            let builder = crate::io::k8s::api::extensions::v1beta1::daemon_set::DaemonSetGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "replace-extensions-v1beta1-namespaced-daemon-set" => {
+            let builder = crate::io::k8s::api::extensions::v1beta1::daemon_set::DaemonSetPutBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "read-extensions-v1beta1-namespaced-daemon-set-status" => {
+            let builder = crate::io::k8s::api::extensions::v1beta1::daemon_set::DaemonSetGetBuilder2::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "replace-extensions-v1beta1-namespaced-daemon-set-status" => {
+            let builder = crate::io::k8s::api::extensions::v1beta1::daemon_set::DaemonSetPutBuilder2::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "list-extensions-v1beta1-daemon-set-for-all-namespaces" => {
+            let builder = crate::io::k8s::api::extensions::v1beta1::daemon_set_list::DaemonSetListGetBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "list-extensions-v1beta1-namespaced-daemon-set" => {
+            let builder = crate::io::k8s::api::extensions::v1beta1::daemon_set_list::DaemonSetListGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "create-extensions-v1beta1-namespaced-deployment" => {
+            let builder = crate::io::k8s::api::extensions::v1beta1::deployment::DeploymentPostBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "read-extensions-v1beta1-namespaced-deployment" => {
+            let builder = crate::io::k8s::api::extensions::v1beta1::deployment::DeploymentGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "replace-extensions-v1beta1-namespaced-deployment" => {
+            let builder = crate::io::k8s::api::extensions::v1beta1::deployment::DeploymentPutBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "read-extensions-v1beta1-namespaced-deployment-status" => {
+            let builder = crate::io::k8s::api::extensions::v1beta1::deployment::DeploymentGetBuilder2::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "replace-extensions-v1beta1-namespaced-deployment-status" => {
+            let builder = crate::io::k8s::api::extensions::v1beta1::deployment::DeploymentPutBuilder2::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "list-extensions-v1beta1-deployment-for-all-namespaces" => {
+            let builder = crate::io::k8s::api::extensions::v1beta1::deployment_list::DeploymentListGetBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "list-extensions-v1beta1-namespaced-deployment" => {
+            let builder = crate::io::k8s::api::extensions::v1beta1::deployment_list::DeploymentListGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "create-extensions-v1beta1-namespaced-deployment-rollback" => {
+            let builder = crate::io::k8s::api::extensions::v1beta1::deployment_rollback::DeploymentRollbackPostBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "create-extensions-v1beta1-namespaced-ingress" => {
+            let builder = crate::io::k8s::api::extensions::v1beta1::ingress::IngressPostBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "read-extensions-v1beta1-namespaced-ingress" => {
+            let builder = crate::io::k8s::api::extensions::v1beta1::ingress::IngressGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "replace-extensions-v1beta1-namespaced-ingress" => {
+            let builder = crate::io::k8s::api::extensions::v1beta1::ingress::IngressPutBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "read-extensions-v1beta1-namespaced-ingress-status" => {
+            let builder = crate::io::k8s::api::extensions::v1beta1::ingress::IngressGetBuilder2::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "replace-extensions-v1beta1-namespaced-ingress-status" => {
+            let builder = crate::io::k8s::api::extensions::v1beta1::ingress::IngressPutBuilder2::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "list-extensions-v1beta1-ingress-for-all-namespaces" => {
+            let builder = crate::io::k8s::api::extensions::v1beta1::ingress_list::IngressListGetBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "list-extensions-v1beta1-namespaced-ingress" => {
+            let builder = crate::io::k8s::api::extensions::v1beta1::ingress_list::IngressListGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "create-extensions-v1beta1-namespaced-network-policy" => {
+            let builder = crate::io::k8s::api::extensions::v1beta1::network_policy::NetworkPolicyPostBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "read-extensions-v1beta1-namespaced-network-policy" => {
+            let builder = crate::io::k8s::api::extensions::v1beta1::network_policy::NetworkPolicyGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "replace-extensions-v1beta1-namespaced-network-policy" => {
+            let builder = crate::io::k8s::api::extensions::v1beta1::network_policy::NetworkPolicyPutBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "list-extensions-v1beta1-namespaced-network-policy" => {
+            let builder = crate::io::k8s::api::extensions::v1beta1::network_policy_list::NetworkPolicyListGetBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "list-extensions-v1beta1-network-policy-for-all-namespaces" => {
+            let builder = crate::io::k8s::api::extensions::v1beta1::network_policy_list::NetworkPolicyListGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "create-extensions-v1beta1-pod-security-policy" => {
+            let builder = crate::io::k8s::api::extensions::v1beta1::pod_security_policy::PodSecurityPolicyPostBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "read-extensions-v1beta1-pod-security-policy" => {
+            let builder = crate::io::k8s::api::extensions::v1beta1::pod_security_policy::PodSecurityPolicyGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "replace-extensions-v1beta1-pod-security-policy" => {
+            let builder = crate::io::k8s::api::extensions::v1beta1::pod_security_policy::PodSecurityPolicyPutBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "list-extensions-v1beta1-pod-security-policy" => {
+            let builder = crate::io::k8s::api::extensions::v1beta1::pod_security_policy_list::PodSecurityPolicyListGetBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "create-extensions-v1beta1-namespaced-replica-set" => {
+            let builder = crate::io::k8s::api::extensions::v1beta1::replica_set::ReplicaSetPostBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "read-extensions-v1beta1-namespaced-replica-set" => {
+            let builder = crate::io::k8s::api::extensions::v1beta1::replica_set::ReplicaSetGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "replace-extensions-v1beta1-namespaced-replica-set" => {
+            let builder = crate::io::k8s::api::extensions::v1beta1::replica_set::ReplicaSetPutBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "read-extensions-v1beta1-namespaced-replica-set-status" => {
+            let builder = crate::io::k8s::api::extensions::v1beta1::replica_set::ReplicaSetGetBuilder2::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "replace-extensions-v1beta1-namespaced-replica-set-status" => {
+            let builder = crate::io::k8s::api::extensions::v1beta1::replica_set::ReplicaSetPutBuilder2::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "list-extensions-v1beta1-namespaced-replica-set" => {
+            let builder = crate::io::k8s::api::extensions::v1beta1::replica_set_list::ReplicaSetListGetBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "list-extensions-v1beta1-replica-set-for-all-namespaces" => {
+            let builder = crate::io::k8s::api::extensions::v1beta1::replica_set_list::ReplicaSetListGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "read-extensions-v1beta1-namespaced-deployment-scale" => {
+            let builder = crate::io::k8s::api::extensions::v1beta1::scale::ScaleGetBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "replace-extensions-v1beta1-namespaced-deployment-scale" => {
+            let builder = crate::io::k8s::api::extensions::v1beta1::scale::ScalePutBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "read-extensions-v1beta1-namespaced-replica-set-scale" => {
+            let builder = crate::io::k8s::api::extensions::v1beta1::scale::ScaleGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "replace-extensions-v1beta1-namespaced-replica-set-scale" => {
+            let builder = crate::io::k8s::api::extensions::v1beta1::scale::ScalePutBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "read-extensions-v1beta1-namespaced-replication-controller-dummy-scale" => {
+            let builder = crate::io::k8s::api::extensions::v1beta1::scale::ScaleGetBuilder2::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "replace-extensions-v1beta1-namespaced-replication-controller-dummy-scale" => {
+            let builder = crate::io::k8s::api::extensions::v1beta1::scale::ScalePutBuilder2::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "create-networking-v1-namespaced-network-policy" => {
+            let builder = crate::io::k8s::api::networking::v1::network_policy::NetworkPolicyPostBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "read-networking-v1-namespaced-network-policy" => {
+            let builder = crate::io::k8s::api::networking::v1::network_policy::NetworkPolicyGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "replace-networking-v1-namespaced-network-policy" => {
+            let builder = crate::io::k8s::api::networking::v1::network_policy::NetworkPolicyPutBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "list-networking-v1-namespaced-network-policy" => {
+            let builder = crate::io::k8s::api::networking::v1::network_policy_list::NetworkPolicyListGetBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "list-networking-v1-network-policy-for-all-namespaces" => {
+            let builder = crate::io::k8s::api::networking::v1::network_policy_list::NetworkPolicyListGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "create-networking-v1beta1-namespaced-ingress" => {
+            let builder = crate::io::k8s::api::networking::v1beta1::ingress::IngressPostBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "read-networking-v1beta1-namespaced-ingress" => {
+            let builder = crate::io::k8s::api::networking::v1beta1::ingress::IngressGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "replace-networking-v1beta1-namespaced-ingress" => {
+            let builder = crate::io::k8s::api::networking::v1beta1::ingress::IngressPutBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "read-networking-v1beta1-namespaced-ingress-status" => {
+            let builder = crate::io::k8s::api::networking::v1beta1::ingress::IngressGetBuilder2::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "replace-networking-v1beta1-namespaced-ingress-status" => {
+            let builder = crate::io::k8s::api::networking::v1beta1::ingress::IngressPutBuilder2::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "list-networking-v1beta1-ingress-for-all-namespaces" => {
+            let builder = crate::io::k8s::api::networking::v1beta1::ingress_list::IngressListGetBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "list-networking-v1beta1-namespaced-ingress" => {
+            let builder = crate::io::k8s::api::networking::v1beta1::ingress_list::IngressListGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "create-node-v1alpha1-runtime-class" => {
+            let builder = crate::io::k8s::api::node::v1alpha1::runtime_class::RuntimeClassPostBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "read-node-v1alpha1-runtime-class" => {
+            let builder = crate::io::k8s::api::node::v1alpha1::runtime_class::RuntimeClassGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "replace-node-v1alpha1-runtime-class" => {
+            let builder = crate::io::k8s::api::node::v1alpha1::runtime_class::RuntimeClassPutBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "list-node-v1alpha1-runtime-class" => {
+            let builder = crate::io::k8s::api::node::v1alpha1::runtime_class_list::RuntimeClassListGetBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "create-node-v1beta1-runtime-class" => {
+            let builder = crate::io::k8s::api::node::v1beta1::runtime_class::RuntimeClassPostBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "read-node-v1beta1-runtime-class" => {
+            let builder = crate::io::k8s::api::node::v1beta1::runtime_class::RuntimeClassGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "replace-node-v1beta1-runtime-class" => {
+            let builder = crate::io::k8s::api::node::v1beta1::runtime_class::RuntimeClassPutBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "list-node-v1beta1-runtime-class" => {
+            let builder = crate::io::k8s::api::node::v1beta1::runtime_class_list::RuntimeClassListGetBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "create-core-v1-namespaced-pod-eviction" => {
+            let builder = crate::io::k8s::api::policy::v1beta1::eviction::EvictionPostBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "create-policy-v1beta1-namespaced-pod-disruption-budget" => {
+            let builder = crate::io::k8s::api::policy::v1beta1::pod_disruption_budget::PodDisruptionBudgetPostBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "read-policy-v1beta1-namespaced-pod-disruption-budget" => {
+            let builder = crate::io::k8s::api::policy::v1beta1::pod_disruption_budget::PodDisruptionBudgetGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "replace-policy-v1beta1-namespaced-pod-disruption-budget" => {
+            let builder = crate::io::k8s::api::policy::v1beta1::pod_disruption_budget::PodDisruptionBudgetPutBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "read-policy-v1beta1-namespaced-pod-disruption-budget-status" => {
+            let builder = crate::io::k8s::api::policy::v1beta1::pod_disruption_budget::PodDisruptionBudgetGetBuilder2::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "replace-policy-v1beta1-namespaced-pod-disruption-budget-status" => {
+            let builder = crate::io::k8s::api::policy::v1beta1::pod_disruption_budget::PodDisruptionBudgetPutBuilder2::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "list-policy-v1beta1-namespaced-pod-disruption-budget" => {
+            let builder = crate::io::k8s::api::policy::v1beta1::pod_disruption_budget_list::PodDisruptionBudgetListGetBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "list-policy-v1beta1-pod-disruption-budget-for-all-namespaces" => {
+            let builder = crate::io::k8s::api::policy::v1beta1::pod_disruption_budget_list::PodDisruptionBudgetListGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "create-policy-v1beta1-pod-security-policy" => {
+            let builder = crate::io::k8s::api::policy::v1beta1::pod_security_policy::PodSecurityPolicyPostBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "read-policy-v1beta1-pod-security-policy" => {
+            let builder = crate::io::k8s::api::policy::v1beta1::pod_security_policy::PodSecurityPolicyGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "replace-policy-v1beta1-pod-security-policy" => {
+            let builder = crate::io::k8s::api::policy::v1beta1::pod_security_policy::PodSecurityPolicyPutBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "list-policy-v1beta1-pod-security-policy" => {
+            let builder = crate::io::k8s::api::policy::v1beta1::pod_security_policy_list::PodSecurityPolicyListGetBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "create-rbac-authorization-v1-cluster-role" => {
+            let builder = crate::io::k8s::api::rbac::v1::cluster_role::ClusterRolePostBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "read-rbac-authorization-v1-cluster-role" => {
+            let builder = crate::io::k8s::api::rbac::v1::cluster_role::ClusterRoleGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "replace-rbac-authorization-v1-cluster-role" => {
+            let builder = crate::io::k8s::api::rbac::v1::cluster_role::ClusterRolePutBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "create-rbac-authorization-v1-cluster-role-binding" => {
+            let builder = crate::io::k8s::api::rbac::v1::cluster_role_binding::ClusterRoleBindingPostBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "read-rbac-authorization-v1-cluster-role-binding" => {
+            let builder = crate::io::k8s::api::rbac::v1::cluster_role_binding::ClusterRoleBindingGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "replace-rbac-authorization-v1-cluster-role-binding" => {
+            let builder = crate::io::k8s::api::rbac::v1::cluster_role_binding::ClusterRoleBindingPutBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "list-rbac-authorization-v1-cluster-role-binding" => {
+            let builder = crate::io::k8s::api::rbac::v1::cluster_role_binding_list::ClusterRoleBindingListGetBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "list-rbac-authorization-v1-cluster-role" => {
+            let builder = crate::io::k8s::api::rbac::v1::cluster_role_list::ClusterRoleListGetBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "create-rbac-authorization-v1-namespaced-role" => {
+            let builder = crate::io::k8s::api::rbac::v1::role::RolePostBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "read-rbac-authorization-v1-namespaced-role" => {
+            let builder = crate::io::k8s::api::rbac::v1::role::RoleGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "replace-rbac-authorization-v1-namespaced-role" => {
+            let builder = crate::io::k8s::api::rbac::v1::role::RolePutBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "create-rbac-authorization-v1-namespaced-role-binding" => {
+            let builder = crate::io::k8s::api::rbac::v1::role_binding::RoleBindingPostBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "read-rbac-authorization-v1-namespaced-role-binding" => {
+            let builder = crate::io::k8s::api::rbac::v1::role_binding::RoleBindingGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "replace-rbac-authorization-v1-namespaced-role-binding" => {
+            let builder = crate::io::k8s::api::rbac::v1::role_binding::RoleBindingPutBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "list-rbac-authorization-v1-namespaced-role-binding" => {
+            let builder = crate::io::k8s::api::rbac::v1::role_binding_list::RoleBindingListGetBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "list-rbac-authorization-v1-role-binding-for-all-namespaces" => {
+            let builder = crate::io::k8s::api::rbac::v1::role_binding_list::RoleBindingListGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "list-rbac-authorization-v1-namespaced-role" => {
+            let builder = crate::io::k8s::api::rbac::v1::role_list::RoleListGetBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "list-rbac-authorization-v1-role-for-all-namespaces" => {
+            let builder = crate::io::k8s::api::rbac::v1::role_list::RoleListGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "create-rbac-authorization-v1alpha1-cluster-role" => {
+            let builder = crate::io::k8s::api::rbac::v1alpha1::cluster_role::ClusterRolePostBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "read-rbac-authorization-v1alpha1-cluster-role" => {
+            let builder = crate::io::k8s::api::rbac::v1alpha1::cluster_role::ClusterRoleGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "replace-rbac-authorization-v1alpha1-cluster-role" => {
+            let builder = crate::io::k8s::api::rbac::v1alpha1::cluster_role::ClusterRolePutBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "create-rbac-authorization-v1alpha1-cluster-role-binding" => {
+            let builder = crate::io::k8s::api::rbac::v1alpha1::cluster_role_binding::ClusterRoleBindingPostBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "read-rbac-authorization-v1alpha1-cluster-role-binding" => {
+            let builder = crate::io::k8s::api::rbac::v1alpha1::cluster_role_binding::ClusterRoleBindingGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "replace-rbac-authorization-v1alpha1-cluster-role-binding" => {
+            let builder = crate::io::k8s::api::rbac::v1alpha1::cluster_role_binding::ClusterRoleBindingPutBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "list-rbac-authorization-v1alpha1-cluster-role-binding" => {
+            let builder = crate::io::k8s::api::rbac::v1alpha1::cluster_role_binding_list::ClusterRoleBindingListGetBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "list-rbac-authorization-v1alpha1-cluster-role" => {
+            let builder = crate::io::k8s::api::rbac::v1alpha1::cluster_role_list::ClusterRoleListGetBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "create-rbac-authorization-v1alpha1-namespaced-role" => {
+            let builder = crate::io::k8s::api::rbac::v1alpha1::role::RolePostBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "read-rbac-authorization-v1alpha1-namespaced-role" => {
+            let builder = crate::io::k8s::api::rbac::v1alpha1::role::RoleGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "replace-rbac-authorization-v1alpha1-namespaced-role" => {
+            let builder = crate::io::k8s::api::rbac::v1alpha1::role::RolePutBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "create-rbac-authorization-v1alpha1-namespaced-role-binding" => {
+            let builder = crate::io::k8s::api::rbac::v1alpha1::role_binding::RoleBindingPostBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "read-rbac-authorization-v1alpha1-namespaced-role-binding" => {
+            let builder = crate::io::k8s::api::rbac::v1alpha1::role_binding::RoleBindingGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "replace-rbac-authorization-v1alpha1-namespaced-role-binding" => {
+            let builder = crate::io::k8s::api::rbac::v1alpha1::role_binding::RoleBindingPutBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "list-rbac-authorization-v1alpha1-namespaced-role-binding" => {
+            let builder = crate::io::k8s::api::rbac::v1alpha1::role_binding_list::RoleBindingListGetBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "list-rbac-authorization-v1alpha1-role-binding-for-all-namespaces" => {
+            let builder = crate::io::k8s::api::rbac::v1alpha1::role_binding_list::RoleBindingListGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "list-rbac-authorization-v1alpha1-namespaced-role" => {
+            let builder = crate::io::k8s::api::rbac::v1alpha1::role_list::RoleListGetBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "list-rbac-authorization-v1alpha1-role-for-all-namespaces" => {
+            let builder = crate::io::k8s::api::rbac::v1alpha1::role_list::RoleListGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "create-rbac-authorization-v1beta1-cluster-role" => {
+            let builder = crate::io::k8s::api::rbac::v1beta1::cluster_role::ClusterRolePostBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "read-rbac-authorization-v1beta1-cluster-role" => {
+            let builder = crate::io::k8s::api::rbac::v1beta1::cluster_role::ClusterRoleGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "replace-rbac-authorization-v1beta1-cluster-role" => {
+            let builder = crate::io::k8s::api::rbac::v1beta1::cluster_role::ClusterRolePutBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "create-rbac-authorization-v1beta1-cluster-role-binding" => {
+            let builder = crate::io::k8s::api::rbac::v1beta1::cluster_role_binding::ClusterRoleBindingPostBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "read-rbac-authorization-v1beta1-cluster-role-binding" => {
+            let builder = crate::io::k8s::api::rbac::v1beta1::cluster_role_binding::ClusterRoleBindingGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "replace-rbac-authorization-v1beta1-cluster-role-binding" => {
+            let builder = crate::io::k8s::api::rbac::v1beta1::cluster_role_binding::ClusterRoleBindingPutBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "list-rbac-authorization-v1beta1-cluster-role-binding" => {
+            let builder = crate::io::k8s::api::rbac::v1beta1::cluster_role_binding_list::ClusterRoleBindingListGetBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "list-rbac-authorization-v1beta1-cluster-role" => {
+            let builder = crate::io::k8s::api::rbac::v1beta1::cluster_role_list::ClusterRoleListGetBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "create-rbac-authorization-v1beta1-namespaced-role" => {
+            let builder = crate::io::k8s::api::rbac::v1beta1::role::RolePostBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "read-rbac-authorization-v1beta1-namespaced-role" => {
+            let builder = crate::io::k8s::api::rbac::v1beta1::role::RoleGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "replace-rbac-authorization-v1beta1-namespaced-role" => {
+            let builder = crate::io::k8s::api::rbac::v1beta1::role::RolePutBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "create-rbac-authorization-v1beta1-namespaced-role-binding" => {
+            let builder = crate::io::k8s::api::rbac::v1beta1::role_binding::RoleBindingPostBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "read-rbac-authorization-v1beta1-namespaced-role-binding" => {
+            let builder = crate::io::k8s::api::rbac::v1beta1::role_binding::RoleBindingGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "replace-rbac-authorization-v1beta1-namespaced-role-binding" => {
+            let builder = crate::io::k8s::api::rbac::v1beta1::role_binding::RoleBindingPutBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "list-rbac-authorization-v1beta1-namespaced-role-binding" => {
+            let builder = crate::io::k8s::api::rbac::v1beta1::role_binding_list::RoleBindingListGetBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "list-rbac-authorization-v1beta1-role-binding-for-all-namespaces" => {
+            let builder = crate::io::k8s::api::rbac::v1beta1::role_binding_list::RoleBindingListGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "list-rbac-authorization-v1beta1-namespaced-role" => {
+            let builder = crate::io::k8s::api::rbac::v1beta1::role_list::RoleListGetBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "list-rbac-authorization-v1beta1-role-for-all-namespaces" => {
+            let builder = crate::io::k8s::api::rbac::v1beta1::role_list::RoleListGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "create-scheduling-v1-priority-class" => {
+            let builder = crate::io::k8s::api::scheduling::v1::priority_class::PriorityClassPostBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "read-scheduling-v1-priority-class" => {
+            let builder = crate::io::k8s::api::scheduling::v1::priority_class::PriorityClassGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "replace-scheduling-v1-priority-class" => {
+            let builder = crate::io::k8s::api::scheduling::v1::priority_class::PriorityClassPutBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "list-scheduling-v1-priority-class" => {
+            let builder = crate::io::k8s::api::scheduling::v1::priority_class_list::PriorityClassListGetBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "create-scheduling-v1alpha1-priority-class" => {
+            let builder = crate::io::k8s::api::scheduling::v1alpha1::priority_class::PriorityClassPostBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "read-scheduling-v1alpha1-priority-class" => {
+            let builder = crate::io::k8s::api::scheduling::v1alpha1::priority_class::PriorityClassGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "replace-scheduling-v1alpha1-priority-class" => {
+            let builder = crate::io::k8s::api::scheduling::v1alpha1::priority_class::PriorityClassPutBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "list-scheduling-v1alpha1-priority-class" => {
+            let builder = crate::io::k8s::api::scheduling::v1alpha1::priority_class_list::PriorityClassListGetBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "create-scheduling-v1beta1-priority-class" => {
+            let builder = crate::io::k8s::api::scheduling::v1beta1::priority_class::PriorityClassPostBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "read-scheduling-v1beta1-priority-class" => {
+            let builder = crate::io::k8s::api::scheduling::v1beta1::priority_class::PriorityClassGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "replace-scheduling-v1beta1-priority-class" => {
+            let builder = crate::io::k8s::api::scheduling::v1beta1::priority_class::PriorityClassPutBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "list-scheduling-v1beta1-priority-class" => {
+            let builder = crate::io::k8s::api::scheduling::v1beta1::priority_class_list::PriorityClassListGetBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "create-settings-v1alpha1-namespaced-pod-preset" => {
+            let builder = crate::io::k8s::api::settings::v1alpha1::pod_preset::PodPresetPostBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "read-settings-v1alpha1-namespaced-pod-preset" => {
+            let builder = crate::io::k8s::api::settings::v1alpha1::pod_preset::PodPresetGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "replace-settings-v1alpha1-namespaced-pod-preset" => {
+            let builder = crate::io::k8s::api::settings::v1alpha1::pod_preset::PodPresetPutBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "list-settings-v1alpha1-namespaced-pod-preset" => {
+            let builder = crate::io::k8s::api::settings::v1alpha1::pod_preset_list::PodPresetListGetBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "list-settings-v1alpha1-pod-preset-for-all-namespaces" => {
+            let builder = crate::io::k8s::api::settings::v1alpha1::pod_preset_list::PodPresetListGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "create-storage-v1-storage-class" => {
+            let builder = crate::io::k8s::api::storage::v1::storage_class::StorageClassPostBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "read-storage-v1-storage-class" => {
+            let builder = crate::io::k8s::api::storage::v1::storage_class::StorageClassGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "replace-storage-v1-storage-class" => {
+            let builder = crate::io::k8s::api::storage::v1::storage_class::StorageClassPutBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "list-storage-v1-storage-class" => {
+            let builder = crate::io::k8s::api::storage::v1::storage_class_list::StorageClassListGetBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "create-storage-v1-volume-attachment" => {
+            let builder = crate::io::k8s::api::storage::v1::volume_attachment::VolumeAttachmentPostBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "read-storage-v1-volume-attachment" => {
+            let builder = crate::io::k8s::api::storage::v1::volume_attachment::VolumeAttachmentGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "replace-storage-v1-volume-attachment" => {
+            let builder = crate::io::k8s::api::storage::v1::volume_attachment::VolumeAttachmentPutBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "read-storage-v1-volume-attachment-status" => {
+            let builder = crate::io::k8s::api::storage::v1::volume_attachment::VolumeAttachmentGetBuilder2::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "replace-storage-v1-volume-attachment-status" => {
+            let builder = crate::io::k8s::api::storage::v1::volume_attachment::VolumeAttachmentPutBuilder2::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "list-storage-v1-volume-attachment" => {
+            let builder = crate::io::k8s::api::storage::v1::volume_attachment_list::VolumeAttachmentListGetBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "create-storage-v1alpha1-volume-attachment" => {
+            let builder = crate::io::k8s::api::storage::v1alpha1::volume_attachment::VolumeAttachmentPostBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "read-storage-v1alpha1-volume-attachment" => {
+            let builder = crate::io::k8s::api::storage::v1alpha1::volume_attachment::VolumeAttachmentGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "replace-storage-v1alpha1-volume-attachment" => {
+            let builder = crate::io::k8s::api::storage::v1alpha1::volume_attachment::VolumeAttachmentPutBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "list-storage-v1alpha1-volume-attachment" => {
+            let builder = crate::io::k8s::api::storage::v1alpha1::volume_attachment_list::VolumeAttachmentListGetBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "create-storage-v1beta1-csi-driver" => {
+            let builder = crate::io::k8s::api::storage::v1beta1::csi_driver::CsiDriverPostBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "read-storage-v1beta1-csi-driver" => {
+            let builder = crate::io::k8s::api::storage::v1beta1::csi_driver::CsiDriverGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "replace-storage-v1beta1-csi-driver" => {
+            let builder = crate::io::k8s::api::storage::v1beta1::csi_driver::CsiDriverPutBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "list-storage-v1beta1-csi-driver" => {
+            let builder = crate::io::k8s::api::storage::v1beta1::csi_driver_list::CsiDriverListGetBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "create-storage-v1beta1-csi-node" => {
+            let builder = crate::io::k8s::api::storage::v1beta1::csi_node::CsiNodePostBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "read-storage-v1beta1-csi-node" => {
+            let builder = crate::io::k8s::api::storage::v1beta1::csi_node::CsiNodeGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "replace-storage-v1beta1-csi-node" => {
+            let builder = crate::io::k8s::api::storage::v1beta1::csi_node::CsiNodePutBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "list-storage-v1beta1-csi-node" => {
+            let builder = crate::io::k8s::api::storage::v1beta1::csi_node_list::CsiNodeListGetBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "create-storage-v1beta1-storage-class" => {
+            let builder = crate::io::k8s::api::storage::v1beta1::storage_class::StorageClassPostBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "read-storage-v1beta1-storage-class" => {
+            let builder = crate::io::k8s::api::storage::v1beta1::storage_class::StorageClassGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "replace-storage-v1beta1-storage-class" => {
+            let builder = crate::io::k8s::api::storage::v1beta1::storage_class::StorageClassPutBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "list-storage-v1beta1-storage-class" => {
+            let builder = crate::io::k8s::api::storage::v1beta1::storage_class_list::StorageClassListGetBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "create-storage-v1beta1-volume-attachment" => {
+            let builder = crate::io::k8s::api::storage::v1beta1::volume_attachment::VolumeAttachmentPostBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "read-storage-v1beta1-volume-attachment" => {
+            let builder = crate::io::k8s::api::storage::v1beta1::volume_attachment::VolumeAttachmentGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "replace-storage-v1beta1-volume-attachment" => {
+            let builder = crate::io::k8s::api::storage::v1beta1::volume_attachment::VolumeAttachmentPutBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "list-storage-v1beta1-volume-attachment" => {
+            let builder = crate::io::k8s::api::storage::v1beta1::volume_attachment_list::VolumeAttachmentListGetBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "create-apiextensions-v1beta1-custom-resource-definition" => {
+            let builder = crate::io::k8s::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::custom_resource_definition::CustomResourceDefinitionPostBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "read-apiextensions-v1beta1-custom-resource-definition" => {
+            let builder = crate::io::k8s::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::custom_resource_definition::CustomResourceDefinitionGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "replace-apiextensions-v1beta1-custom-resource-definition" => {
+            let builder = crate::io::k8s::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::custom_resource_definition::CustomResourceDefinitionPutBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "read-apiextensions-v1beta1-custom-resource-definition-status" => {
+            let builder = crate::io::k8s::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::custom_resource_definition::CustomResourceDefinitionGetBuilder2::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "replace-apiextensions-v1beta1-custom-resource-definition-status" => {
+            let builder = crate::io::k8s::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::custom_resource_definition::CustomResourceDefinitionPutBuilder2::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "list-apiextensions-v1beta1-custom-resource-definition" => {
+            let builder = crate::io::k8s::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::custom_resource_definition_list::CustomResourceDefinitionListGetBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "get-admissionregistration-api-group" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::api_group::ApiGroupGetBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "get-apiextensions-api-group" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::api_group::ApiGroupGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "get-apiregistration-api-group" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::api_group::ApiGroupGetBuilder2::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "get-apps-api-group" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::api_group::ApiGroupGetBuilder3::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "get-auditregistration-api-group" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::api_group::ApiGroupGetBuilder4::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "get-authentication-api-group" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::api_group::ApiGroupGetBuilder5::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "get-authorization-api-group" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::api_group::ApiGroupGetBuilder6::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "get-autoscaling-api-group" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::api_group::ApiGroupGetBuilder7::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "get-batch-api-group" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::api_group::ApiGroupGetBuilder8::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "get-certificates-api-group" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::api_group::ApiGroupGetBuilder9::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "get-coordination-api-group" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::api_group::ApiGroupGetBuilder10::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "get-events-api-group" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::api_group::ApiGroupGetBuilder11::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "get-extensions-api-group" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::api_group::ApiGroupGetBuilder12::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "get-networking-api-group" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::api_group::ApiGroupGetBuilder13::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "get-node-api-group" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::api_group::ApiGroupGetBuilder14::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "get-policy-api-group" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::api_group::ApiGroupGetBuilder15::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "get-rbac-authorization-api-group" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::api_group::ApiGroupGetBuilder16::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "get-scheduling-api-group" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::api_group::ApiGroupGetBuilder17::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "get-settings-api-group" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::api_group::ApiGroupGetBuilder18::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "get-storage-api-group" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::api_group::ApiGroupGetBuilder19::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "get-api-versions" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::api_group_list::ApiGroupListGetBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "get-core-v1api-resources" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::api_resource_list::ApiResourceListGetBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "get-admissionregistration-v1beta1-api-resources" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::api_resource_list::ApiResourceListGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "get-apiextensions-v1beta1-api-resources" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::api_resource_list::ApiResourceListGetBuilder2::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "get-apiregistration-v1api-resources" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::api_resource_list::ApiResourceListGetBuilder3::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "get-apiregistration-v1beta1-api-resources" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::api_resource_list::ApiResourceListGetBuilder4::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "get-apps-v1api-resources" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::api_resource_list::ApiResourceListGetBuilder5::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "get-apps-v1beta1-api-resources" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::api_resource_list::ApiResourceListGetBuilder6::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "get-apps-v1beta2-api-resources" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::api_resource_list::ApiResourceListGetBuilder7::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "get-auditregistration-v1alpha1-api-resources" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::api_resource_list::ApiResourceListGetBuilder8::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "get-authentication-v1api-resources" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::api_resource_list::ApiResourceListGetBuilder9::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "get-authentication-v1beta1-api-resources" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::api_resource_list::ApiResourceListGetBuilder10::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "get-authorization-v1api-resources" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::api_resource_list::ApiResourceListGetBuilder11::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "get-authorization-v1beta1-api-resources" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::api_resource_list::ApiResourceListGetBuilder12::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "get-autoscaling-v1api-resources" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::api_resource_list::ApiResourceListGetBuilder13::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "get-autoscaling-v2beta1-api-resources" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::api_resource_list::ApiResourceListGetBuilder14::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "get-autoscaling-v2beta2-api-resources" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::api_resource_list::ApiResourceListGetBuilder15::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "get-batch-v1api-resources" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::api_resource_list::ApiResourceListGetBuilder16::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "get-batch-v1beta1-api-resources" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::api_resource_list::ApiResourceListGetBuilder17::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "get-batch-v2alpha1-api-resources" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::api_resource_list::ApiResourceListGetBuilder18::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "get-certificates-v1beta1-api-resources" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::api_resource_list::ApiResourceListGetBuilder19::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "get-coordination-v1api-resources" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::api_resource_list::ApiResourceListGetBuilder20::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "get-coordination-v1beta1-api-resources" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::api_resource_list::ApiResourceListGetBuilder21::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "get-events-v1beta1-api-resources" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::api_resource_list::ApiResourceListGetBuilder22::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "get-extensions-v1beta1-api-resources" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::api_resource_list::ApiResourceListGetBuilder23::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "get-networking-v1api-resources" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::api_resource_list::ApiResourceListGetBuilder24::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "get-networking-v1beta1-api-resources" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::api_resource_list::ApiResourceListGetBuilder25::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "get-node-v1alpha1-api-resources" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::api_resource_list::ApiResourceListGetBuilder26::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "get-node-v1beta1-api-resources" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::api_resource_list::ApiResourceListGetBuilder27::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "get-policy-v1beta1-api-resources" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::api_resource_list::ApiResourceListGetBuilder28::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "get-rbac-authorization-v1api-resources" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::api_resource_list::ApiResourceListGetBuilder29::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "get-rbac-authorization-v1alpha1-api-resources" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::api_resource_list::ApiResourceListGetBuilder30::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "get-rbac-authorization-v1beta1-api-resources" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::api_resource_list::ApiResourceListGetBuilder31::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "get-scheduling-v1api-resources" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::api_resource_list::ApiResourceListGetBuilder32::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "get-scheduling-v1alpha1-api-resources" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::api_resource_list::ApiResourceListGetBuilder33::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "get-scheduling-v1beta1-api-resources" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::api_resource_list::ApiResourceListGetBuilder34::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "get-settings-v1alpha1-api-resources" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::api_resource_list::ApiResourceListGetBuilder35::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "get-storage-v1api-resources" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::api_resource_list::ApiResourceListGetBuilder36::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "get-storage-v1alpha1-api-resources" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::api_resource_list::ApiResourceListGetBuilder37::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "get-storage-v1beta1-api-resources" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::api_resource_list::ApiResourceListGetBuilder38::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "get-core-api-versions" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::api_versions::ApiVersionsGetBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-core-v1-namespaced-config-map" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::delete_options::DeleteOptionsDeleteBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-core-v1-namespaced-endpoints" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::delete_options::DeleteOptionsDeleteBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-core-v1-namespaced-event" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::delete_options::DeleteOptionsDeleteBuilder2::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-core-v1-namespaced-limit-range" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::delete_options::DeleteOptionsDeleteBuilder3::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-core-v1-namespaced-persistent-volume-claim" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::delete_options::DeleteOptionsDeleteBuilder4::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-core-v1-namespaced-pod" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::delete_options::DeleteOptionsDeleteBuilder5::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-core-v1-namespaced-pod-template" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::delete_options::DeleteOptionsDeleteBuilder6::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-core-v1-namespaced-replication-controller" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::delete_options::DeleteOptionsDeleteBuilder7::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-core-v1-namespaced-resource-quota" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::delete_options::DeleteOptionsDeleteBuilder8::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-core-v1-namespaced-secret" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::delete_options::DeleteOptionsDeleteBuilder9::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-core-v1-namespaced-service-account" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::delete_options::DeleteOptionsDeleteBuilder10::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-core-v1-namespaced-service" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::delete_options::DeleteOptionsDeleteBuilder11::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-core-v1-namespace" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::delete_options::DeleteOptionsDeleteBuilder12::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-core-v1-node" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::delete_options::DeleteOptionsDeleteBuilder13::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-core-v1-persistent-volume" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::delete_options::DeleteOptionsDeleteBuilder14::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-admissionregistration-v1beta1-mutating-webhook-configuration" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::delete_options::DeleteOptionsDeleteBuilder15::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-admissionregistration-v1beta1-validating-webhook-configuration" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::delete_options::DeleteOptionsDeleteBuilder16::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-apiextensions-v1beta1-custom-resource-definition" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::delete_options::DeleteOptionsDeleteBuilder17::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-apiregistration-v1api-service" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::delete_options::DeleteOptionsDeleteBuilder18::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-apiregistration-v1beta1-api-service" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::delete_options::DeleteOptionsDeleteBuilder19::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-apps-v1-namespaced-controller-revision" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::delete_options::DeleteOptionsDeleteBuilder20::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-apps-v1-namespaced-daemon-set" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::delete_options::DeleteOptionsDeleteBuilder21::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-apps-v1-namespaced-deployment" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::delete_options::DeleteOptionsDeleteBuilder22::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-apps-v1-namespaced-replica-set" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::delete_options::DeleteOptionsDeleteBuilder23::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-apps-v1-namespaced-stateful-set" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::delete_options::DeleteOptionsDeleteBuilder24::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-apps-v1beta1-namespaced-controller-revision" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::delete_options::DeleteOptionsDeleteBuilder25::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-apps-v1beta1-namespaced-deployment" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::delete_options::DeleteOptionsDeleteBuilder26::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-apps-v1beta1-namespaced-stateful-set" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::delete_options::DeleteOptionsDeleteBuilder27::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-apps-v1beta2-namespaced-controller-revision" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::delete_options::DeleteOptionsDeleteBuilder28::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-apps-v1beta2-namespaced-daemon-set" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::delete_options::DeleteOptionsDeleteBuilder29::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-apps-v1beta2-namespaced-deployment" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::delete_options::DeleteOptionsDeleteBuilder30::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-apps-v1beta2-namespaced-replica-set" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::delete_options::DeleteOptionsDeleteBuilder31::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-apps-v1beta2-namespaced-stateful-set" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::delete_options::DeleteOptionsDeleteBuilder32::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-auditregistration-v1alpha1-audit-sink" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::delete_options::DeleteOptionsDeleteBuilder33::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-autoscaling-v1-namespaced-horizontal-pod-autoscaler" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::delete_options::DeleteOptionsDeleteBuilder34::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-autoscaling-v2beta1-namespaced-horizontal-pod-autoscaler" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::delete_options::DeleteOptionsDeleteBuilder35::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-autoscaling-v2beta2-namespaced-horizontal-pod-autoscaler" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::delete_options::DeleteOptionsDeleteBuilder36::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-batch-v1-namespaced-job" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::delete_options::DeleteOptionsDeleteBuilder37::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-batch-v1beta1-namespaced-cron-job" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::delete_options::DeleteOptionsDeleteBuilder38::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-batch-v2alpha1-namespaced-cron-job" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::delete_options::DeleteOptionsDeleteBuilder39::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-certificates-v1beta1-certificate-signing-request" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::delete_options::DeleteOptionsDeleteBuilder40::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-coordination-v1-namespaced-lease" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::delete_options::DeleteOptionsDeleteBuilder41::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-coordination-v1beta1-namespaced-lease" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::delete_options::DeleteOptionsDeleteBuilder42::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-events-v1beta1-namespaced-event" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::delete_options::DeleteOptionsDeleteBuilder43::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-extensions-v1beta1-namespaced-daemon-set" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::delete_options::DeleteOptionsDeleteBuilder44::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-extensions-v1beta1-namespaced-deployment" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::delete_options::DeleteOptionsDeleteBuilder45::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-extensions-v1beta1-namespaced-ingress" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::delete_options::DeleteOptionsDeleteBuilder46::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-extensions-v1beta1-namespaced-network-policy" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::delete_options::DeleteOptionsDeleteBuilder47::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-extensions-v1beta1-namespaced-replica-set" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::delete_options::DeleteOptionsDeleteBuilder48::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-extensions-v1beta1-pod-security-policy" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::delete_options::DeleteOptionsDeleteBuilder49::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-networking-v1-namespaced-network-policy" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::delete_options::DeleteOptionsDeleteBuilder50::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-networking-v1beta1-namespaced-ingress" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::delete_options::DeleteOptionsDeleteBuilder51::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-node-v1alpha1-runtime-class" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::delete_options::DeleteOptionsDeleteBuilder52::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-node-v1beta1-runtime-class" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::delete_options::DeleteOptionsDeleteBuilder53::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-policy-v1beta1-namespaced-pod-disruption-budget" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::delete_options::DeleteOptionsDeleteBuilder54::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-policy-v1beta1-pod-security-policy" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::delete_options::DeleteOptionsDeleteBuilder55::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-rbac-authorization-v1-cluster-role-binding" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::delete_options::DeleteOptionsDeleteBuilder56::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-rbac-authorization-v1-cluster-role" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::delete_options::DeleteOptionsDeleteBuilder57::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-rbac-authorization-v1-namespaced-role-binding" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::delete_options::DeleteOptionsDeleteBuilder58::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-rbac-authorization-v1-namespaced-role" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::delete_options::DeleteOptionsDeleteBuilder59::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-rbac-authorization-v1alpha1-cluster-role-binding" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::delete_options::DeleteOptionsDeleteBuilder60::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-rbac-authorization-v1alpha1-cluster-role" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::delete_options::DeleteOptionsDeleteBuilder61::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-rbac-authorization-v1alpha1-namespaced-role-binding" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::delete_options::DeleteOptionsDeleteBuilder62::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-rbac-authorization-v1alpha1-namespaced-role" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::delete_options::DeleteOptionsDeleteBuilder63::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-rbac-authorization-v1beta1-cluster-role-binding" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::delete_options::DeleteOptionsDeleteBuilder64::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-rbac-authorization-v1beta1-cluster-role" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::delete_options::DeleteOptionsDeleteBuilder65::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-rbac-authorization-v1beta1-namespaced-role-binding" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::delete_options::DeleteOptionsDeleteBuilder66::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-rbac-authorization-v1beta1-namespaced-role" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::delete_options::DeleteOptionsDeleteBuilder67::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-scheduling-v1-priority-class" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::delete_options::DeleteOptionsDeleteBuilder68::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-scheduling-v1alpha1-priority-class" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::delete_options::DeleteOptionsDeleteBuilder69::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-scheduling-v1beta1-priority-class" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::delete_options::DeleteOptionsDeleteBuilder70::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-settings-v1alpha1-namespaced-pod-preset" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::delete_options::DeleteOptionsDeleteBuilder71::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-storage-v1-storage-class" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::delete_options::DeleteOptionsDeleteBuilder72::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-storage-v1-volume-attachment" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::delete_options::DeleteOptionsDeleteBuilder73::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-storage-v1alpha1-volume-attachment" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::delete_options::DeleteOptionsDeleteBuilder74::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-storage-v1beta1-csi-driver" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::delete_options::DeleteOptionsDeleteBuilder75::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-storage-v1beta1-csi-node" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::delete_options::DeleteOptionsDeleteBuilder76::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-storage-v1beta1-storage-class" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::delete_options::DeleteOptionsDeleteBuilder77::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-storage-v1beta1-volume-attachment" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::delete_options::DeleteOptionsDeleteBuilder78::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "patch-core-v1-namespaced-endpoints" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::patch::PatchPatchBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "patch-core-v1-namespaced-event" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::patch::PatchPatchBuilder2::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "patch-core-v1-namespaced-limit-range" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::patch::PatchPatchBuilder3::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "patch-core-v1-namespaced-persistent-volume-claim" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::patch::PatchPatchBuilder4::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "patch-core-v1-namespaced-persistent-volume-claim-status" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::patch::PatchPatchBuilder5::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "patch-core-v1-namespaced-pod" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::patch::PatchPatchBuilder6::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "patch-core-v1-namespaced-pod-status" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::patch::PatchPatchBuilder7::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "patch-core-v1-namespaced-pod-template" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::patch::PatchPatchBuilder8::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "patch-core-v1-namespaced-replication-controller" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::patch::PatchPatchBuilder9::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "patch-core-v1-namespaced-replication-controller-scale" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::patch::PatchPatchBuilder10::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "patch-core-v1-namespaced-replication-controller-status" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::patch::PatchPatchBuilder11::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "patch-core-v1-namespaced-resource-quota" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::patch::PatchPatchBuilder12::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "patch-core-v1-namespaced-resource-quota-status" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::patch::PatchPatchBuilder13::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "patch-core-v1-namespaced-secret" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::patch::PatchPatchBuilder14::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "patch-core-v1-namespaced-service-account" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::patch::PatchPatchBuilder15::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "patch-core-v1-namespaced-service" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::patch::PatchPatchBuilder16::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "patch-core-v1-namespaced-service-status" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::patch::PatchPatchBuilder17::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "patch-core-v1-namespace" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::patch::PatchPatchBuilder18::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "patch-core-v1-namespace-status" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::patch::PatchPatchBuilder19::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "patch-core-v1-node" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::patch::PatchPatchBuilder20::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "patch-core-v1-node-status" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::patch::PatchPatchBuilder21::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "patch-core-v1-persistent-volume" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::patch::PatchPatchBuilder22::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "patch-core-v1-persistent-volume-status" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::patch::PatchPatchBuilder23::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "patch-admissionregistration-v1beta1-mutating-webhook-configuration" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::patch::PatchPatchBuilder24::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "patch-admissionregistration-v1beta1-validating-webhook-configuration" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::patch::PatchPatchBuilder25::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "patch-apiextensions-v1beta1-custom-resource-definition" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::patch::PatchPatchBuilder26::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "patch-apiextensions-v1beta1-custom-resource-definition-status" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::patch::PatchPatchBuilder27::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "patch-apiregistration-v1api-service" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::patch::PatchPatchBuilder28::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "patch-apiregistration-v1api-service-status" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::patch::PatchPatchBuilder29::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "patch-apiregistration-v1beta1-api-service" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::patch::PatchPatchBuilder30::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "patch-apiregistration-v1beta1-api-service-status" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::patch::PatchPatchBuilder31::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "patch-apps-v1-namespaced-controller-revision" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::patch::PatchPatchBuilder32::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "patch-apps-v1-namespaced-daemon-set" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::patch::PatchPatchBuilder33::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "patch-apps-v1-namespaced-daemon-set-status" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::patch::PatchPatchBuilder34::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "patch-apps-v1-namespaced-deployment" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::patch::PatchPatchBuilder35::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "patch-apps-v1-namespaced-deployment-scale" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::patch::PatchPatchBuilder36::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "patch-apps-v1-namespaced-deployment-status" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::patch::PatchPatchBuilder37::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "patch-apps-v1-namespaced-replica-set" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::patch::PatchPatchBuilder38::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "patch-apps-v1-namespaced-replica-set-scale" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::patch::PatchPatchBuilder39::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "patch-apps-v1-namespaced-replica-set-status" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::patch::PatchPatchBuilder40::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "patch-apps-v1-namespaced-stateful-set" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::patch::PatchPatchBuilder41::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "patch-apps-v1-namespaced-stateful-set-scale" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::patch::PatchPatchBuilder42::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "patch-apps-v1-namespaced-stateful-set-status" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::patch::PatchPatchBuilder43::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "patch-apps-v1beta1-namespaced-controller-revision" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::patch::PatchPatchBuilder44::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "patch-apps-v1beta1-namespaced-deployment" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::patch::PatchPatchBuilder45::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "patch-apps-v1beta1-namespaced-deployment-scale" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::patch::PatchPatchBuilder46::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "patch-apps-v1beta1-namespaced-deployment-status" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::patch::PatchPatchBuilder47::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "patch-apps-v1beta1-namespaced-stateful-set" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::patch::PatchPatchBuilder48::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "patch-apps-v1beta1-namespaced-stateful-set-scale" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::patch::PatchPatchBuilder49::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "patch-apps-v1beta1-namespaced-stateful-set-status" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::patch::PatchPatchBuilder50::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "patch-apps-v1beta2-namespaced-controller-revision" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::patch::PatchPatchBuilder51::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "patch-apps-v1beta2-namespaced-daemon-set" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::patch::PatchPatchBuilder52::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "patch-apps-v1beta2-namespaced-daemon-set-status" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::patch::PatchPatchBuilder53::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "patch-apps-v1beta2-namespaced-deployment" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::patch::PatchPatchBuilder54::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "patch-apps-v1beta2-namespaced-deployment-scale" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::patch::PatchPatchBuilder55::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "patch-apps-v1beta2-namespaced-deployment-status" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::patch::PatchPatchBuilder56::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "patch-apps-v1beta2-namespaced-replica-set" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::patch::PatchPatchBuilder57::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "patch-apps-v1beta2-namespaced-replica-set-scale" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::patch::PatchPatchBuilder58::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "patch-apps-v1beta2-namespaced-replica-set-status" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::patch::PatchPatchBuilder59::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "patch-apps-v1beta2-namespaced-stateful-set" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::patch::PatchPatchBuilder60::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "patch-apps-v1beta2-namespaced-stateful-set-scale" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::patch::PatchPatchBuilder61::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "patch-apps-v1beta2-namespaced-stateful-set-status" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::patch::PatchPatchBuilder62::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "patch-auditregistration-v1alpha1-audit-sink" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::patch::PatchPatchBuilder63::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "patch-autoscaling-v1-namespaced-horizontal-pod-autoscaler" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::patch::PatchPatchBuilder64::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "patch-autoscaling-v1-namespaced-horizontal-pod-autoscaler-status" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::patch::PatchPatchBuilder65::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "patch-autoscaling-v2beta1-namespaced-horizontal-pod-autoscaler" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::patch::PatchPatchBuilder66::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "patch-autoscaling-v2beta1-namespaced-horizontal-pod-autoscaler-status" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::patch::PatchPatchBuilder67::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "patch-autoscaling-v2beta2-namespaced-horizontal-pod-autoscaler" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::patch::PatchPatchBuilder68::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "patch-autoscaling-v2beta2-namespaced-horizontal-pod-autoscaler-status" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::patch::PatchPatchBuilder69::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "patch-batch-v1-namespaced-job" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::patch::PatchPatchBuilder70::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "patch-batch-v1-namespaced-job-status" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::patch::PatchPatchBuilder71::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "patch-batch-v1beta1-namespaced-cron-job" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::patch::PatchPatchBuilder72::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "patch-batch-v1beta1-namespaced-cron-job-status" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::patch::PatchPatchBuilder73::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "patch-batch-v2alpha1-namespaced-cron-job" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::patch::PatchPatchBuilder74::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "patch-batch-v2alpha1-namespaced-cron-job-status" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::patch::PatchPatchBuilder75::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "patch-certificates-v1beta1-certificate-signing-request" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::patch::PatchPatchBuilder76::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "patch-certificates-v1beta1-certificate-signing-request-status" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::patch::PatchPatchBuilder77::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "patch-coordination-v1-namespaced-lease" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::patch::PatchPatchBuilder78::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "patch-coordination-v1beta1-namespaced-lease" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::patch::PatchPatchBuilder79::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "patch-events-v1beta1-namespaced-event" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::patch::PatchPatchBuilder80::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "patch-extensions-v1beta1-namespaced-daemon-set" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::patch::PatchPatchBuilder81::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "patch-extensions-v1beta1-namespaced-daemon-set-status" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::patch::PatchPatchBuilder82::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "patch-extensions-v1beta1-namespaced-deployment" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::patch::PatchPatchBuilder83::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "patch-extensions-v1beta1-namespaced-deployment-scale" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::patch::PatchPatchBuilder84::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "patch-extensions-v1beta1-namespaced-deployment-status" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::patch::PatchPatchBuilder85::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "patch-extensions-v1beta1-namespaced-ingress" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::patch::PatchPatchBuilder86::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "patch-extensions-v1beta1-namespaced-ingress-status" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::patch::PatchPatchBuilder87::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "patch-extensions-v1beta1-namespaced-network-policy" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::patch::PatchPatchBuilder88::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "patch-extensions-v1beta1-namespaced-replica-set" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::patch::PatchPatchBuilder89::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "patch-extensions-v1beta1-namespaced-replica-set-scale" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::patch::PatchPatchBuilder90::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "patch-extensions-v1beta1-namespaced-replica-set-status" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::patch::PatchPatchBuilder91::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "patch-extensions-v1beta1-namespaced-replication-controller-dummy-scale" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::patch::PatchPatchBuilder92::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "patch-extensions-v1beta1-pod-security-policy" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::patch::PatchPatchBuilder93::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "patch-networking-v1-namespaced-network-policy" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::patch::PatchPatchBuilder94::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "patch-networking-v1beta1-namespaced-ingress" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::patch::PatchPatchBuilder95::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "patch-networking-v1beta1-namespaced-ingress-status" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::patch::PatchPatchBuilder96::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "patch-node-v1alpha1-runtime-class" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::patch::PatchPatchBuilder97::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "patch-node-v1beta1-runtime-class" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::patch::PatchPatchBuilder98::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "patch-policy-v1beta1-namespaced-pod-disruption-budget" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::patch::PatchPatchBuilder99::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "patch-policy-v1beta1-namespaced-pod-disruption-budget-status" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::patch::PatchPatchBuilder100::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "patch-policy-v1beta1-pod-security-policy" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::patch::PatchPatchBuilder101::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "patch-rbac-authorization-v1-cluster-role-binding" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::patch::PatchPatchBuilder102::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "patch-rbac-authorization-v1-cluster-role" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::patch::PatchPatchBuilder103::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "patch-rbac-authorization-v1-namespaced-role-binding" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::patch::PatchPatchBuilder104::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "patch-rbac-authorization-v1-namespaced-role" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::patch::PatchPatchBuilder105::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "patch-rbac-authorization-v1alpha1-cluster-role-binding" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::patch::PatchPatchBuilder106::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "patch-rbac-authorization-v1alpha1-cluster-role" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::patch::PatchPatchBuilder107::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "patch-rbac-authorization-v1alpha1-namespaced-role-binding" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::patch::PatchPatchBuilder108::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "patch-rbac-authorization-v1alpha1-namespaced-role" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::patch::PatchPatchBuilder109::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "patch-rbac-authorization-v1beta1-cluster-role-binding" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::patch::PatchPatchBuilder110::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "patch-rbac-authorization-v1beta1-cluster-role" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::patch::PatchPatchBuilder111::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "patch-rbac-authorization-v1beta1-namespaced-role-binding" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::patch::PatchPatchBuilder112::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "patch-rbac-authorization-v1beta1-namespaced-role" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::patch::PatchPatchBuilder113::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "patch-scheduling-v1-priority-class" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::patch::PatchPatchBuilder114::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "patch-scheduling-v1alpha1-priority-class" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::patch::PatchPatchBuilder115::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "patch-scheduling-v1beta1-priority-class" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::patch::PatchPatchBuilder116::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "patch-settings-v1alpha1-namespaced-pod-preset" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::patch::PatchPatchBuilder117::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "patch-storage-v1-storage-class" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::patch::PatchPatchBuilder118::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "patch-storage-v1-volume-attachment" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::patch::PatchPatchBuilder119::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "patch-storage-v1-volume-attachment-status" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::patch::PatchPatchBuilder120::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "patch-storage-v1alpha1-volume-attachment" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::patch::PatchPatchBuilder121::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "patch-storage-v1beta1-csi-driver" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::patch::PatchPatchBuilder122::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "patch-storage-v1beta1-csi-node" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::patch::PatchPatchBuilder123::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "patch-storage-v1beta1-storage-class" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::patch::PatchPatchBuilder124::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "patch-storage-v1beta1-volume-attachment" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::patch::PatchPatchBuilder125::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-core-v1-collection-namespaced-config-map" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::StatusDeleteBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-core-v1-collection-namespaced-endpoints" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::StatusDeleteBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-core-v1-collection-namespaced-event" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::StatusDeleteBuilder2::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-core-v1-collection-namespaced-limit-range" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::StatusDeleteBuilder3::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-core-v1-collection-namespaced-persistent-volume-claim" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::StatusDeleteBuilder4::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-core-v1-collection-namespaced-pod" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::StatusDeleteBuilder5::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-core-v1-collection-namespaced-pod-template" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::StatusDeleteBuilder6::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-core-v1-collection-namespaced-replication-controller" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::StatusDeleteBuilder7::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-core-v1-collection-namespaced-resource-quota" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::StatusDeleteBuilder8::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-core-v1-collection-namespaced-secret" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::StatusDeleteBuilder9::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-core-v1-collection-namespaced-service-account" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::StatusDeleteBuilder10::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-core-v1-collection-node" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::StatusDeleteBuilder11::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-core-v1-collection-persistent-volume" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::StatusDeleteBuilder12::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-admissionregistration-v1beta1-collection-mutating-webhook-configuration" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::StatusDeleteBuilder13::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-admissionregistration-v1beta1-collection-validating-webhook-configuration" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::StatusDeleteBuilder14::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-apiextensions-v1beta1-collection-custom-resource-definition" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::StatusDeleteBuilder15::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-apiregistration-v1-collection-api-service" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::StatusDeleteBuilder16::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-apiregistration-v1beta1-collection-api-service" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::StatusDeleteBuilder17::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-apps-v1-collection-namespaced-controller-revision" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::StatusDeleteBuilder18::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-apps-v1-collection-namespaced-daemon-set" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::StatusDeleteBuilder19::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-apps-v1-collection-namespaced-deployment" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::StatusDeleteBuilder20::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-apps-v1-collection-namespaced-replica-set" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::StatusDeleteBuilder21::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-apps-v1-collection-namespaced-stateful-set" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::StatusDeleteBuilder22::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-apps-v1beta1-collection-namespaced-controller-revision" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::StatusDeleteBuilder23::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-apps-v1beta1-collection-namespaced-deployment" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::StatusDeleteBuilder24::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-apps-v1beta1-collection-namespaced-stateful-set" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::StatusDeleteBuilder25::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-apps-v1beta2-collection-namespaced-controller-revision" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::StatusDeleteBuilder26::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-apps-v1beta2-collection-namespaced-daemon-set" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::StatusDeleteBuilder27::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-apps-v1beta2-collection-namespaced-deployment" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::StatusDeleteBuilder28::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-apps-v1beta2-collection-namespaced-replica-set" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::StatusDeleteBuilder29::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-apps-v1beta2-collection-namespaced-stateful-set" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::StatusDeleteBuilder30::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-auditregistration-v1alpha1-collection-audit-sink" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::StatusDeleteBuilder31::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-autoscaling-v1-collection-namespaced-horizontal-pod-autoscaler" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::StatusDeleteBuilder32::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-autoscaling-v2beta1-collection-namespaced-horizontal-pod-autoscaler" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::StatusDeleteBuilder33::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-autoscaling-v2beta2-collection-namespaced-horizontal-pod-autoscaler" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::StatusDeleteBuilder34::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-batch-v1-collection-namespaced-job" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::StatusDeleteBuilder35::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-batch-v1beta1-collection-namespaced-cron-job" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::StatusDeleteBuilder36::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-batch-v2alpha1-collection-namespaced-cron-job" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::StatusDeleteBuilder37::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-certificates-v1beta1-collection-certificate-signing-request" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::StatusDeleteBuilder38::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-coordination-v1-collection-namespaced-lease" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::StatusDeleteBuilder39::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-coordination-v1beta1-collection-namespaced-lease" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::StatusDeleteBuilder40::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-events-v1beta1-collection-namespaced-event" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::StatusDeleteBuilder41::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-extensions-v1beta1-collection-namespaced-daemon-set" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::StatusDeleteBuilder42::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-extensions-v1beta1-collection-namespaced-deployment" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::StatusDeleteBuilder43::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-extensions-v1beta1-collection-namespaced-ingress" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::StatusDeleteBuilder44::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-extensions-v1beta1-collection-namespaced-network-policy" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::StatusDeleteBuilder45::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-extensions-v1beta1-collection-namespaced-replica-set" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::StatusDeleteBuilder46::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-extensions-v1beta1-collection-pod-security-policy" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::StatusDeleteBuilder47::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-networking-v1-collection-namespaced-network-policy" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::StatusDeleteBuilder48::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-networking-v1beta1-collection-namespaced-ingress" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::StatusDeleteBuilder49::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-node-v1alpha1-collection-runtime-class" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::StatusDeleteBuilder50::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-node-v1beta1-collection-runtime-class" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::StatusDeleteBuilder51::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-policy-v1beta1-collection-namespaced-pod-disruption-budget" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::StatusDeleteBuilder52::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-policy-v1beta1-collection-pod-security-policy" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::StatusDeleteBuilder53::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-rbac-authorization-v1-collection-cluster-role-binding" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::StatusDeleteBuilder54::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-rbac-authorization-v1-collection-cluster-role" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::StatusDeleteBuilder55::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-rbac-authorization-v1-collection-namespaced-role-binding" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::StatusDeleteBuilder56::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-rbac-authorization-v1-collection-namespaced-role" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::StatusDeleteBuilder57::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-rbac-authorization-v1alpha1-collection-cluster-role-binding" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::StatusDeleteBuilder58::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-rbac-authorization-v1alpha1-collection-cluster-role" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::StatusDeleteBuilder59::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-rbac-authorization-v1alpha1-collection-namespaced-role-binding" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::StatusDeleteBuilder60::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-rbac-authorization-v1alpha1-collection-namespaced-role" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::StatusDeleteBuilder61::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-rbac-authorization-v1beta1-collection-cluster-role-binding" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::StatusDeleteBuilder62::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-rbac-authorization-v1beta1-collection-cluster-role" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::StatusDeleteBuilder63::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-rbac-authorization-v1beta1-collection-namespaced-role-binding" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::StatusDeleteBuilder64::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-rbac-authorization-v1beta1-collection-namespaced-role" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::StatusDeleteBuilder65::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-scheduling-v1-collection-priority-class" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::StatusDeleteBuilder66::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-scheduling-v1alpha1-collection-priority-class" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::StatusDeleteBuilder67::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-scheduling-v1beta1-collection-priority-class" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::StatusDeleteBuilder68::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-settings-v1alpha1-collection-namespaced-pod-preset" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::StatusDeleteBuilder69::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-storage-v1-collection-storage-class" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::StatusDeleteBuilder70::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-storage-v1-collection-volume-attachment" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::StatusDeleteBuilder71::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-storage-v1alpha1-collection-volume-attachment" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::StatusDeleteBuilder72::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-storage-v1beta1-collection-csi-driver" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::StatusDeleteBuilder73::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-storage-v1beta1-collection-csi-node" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::StatusDeleteBuilder74::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-storage-v1beta1-collection-storage-class" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::StatusDeleteBuilder75::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "delete-storage-v1beta1-collection-volume-attachment" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::StatusDeleteBuilder76::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-core-v1-config-map-list-for-all-namespaces" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-core-v1-endpoints-list-for-all-namespaces" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-core-v1-event-list-for-all-namespaces" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder2::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-core-v1-limit-range-list-for-all-namespaces" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder3::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-core-v1-namespace-list" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder4::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-core-v1-namespaced-config-map-list" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder5::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-core-v1-namespaced-config-map" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder6::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-core-v1-namespaced-endpoints-list" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder7::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-core-v1-namespaced-endpoints" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder8::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-core-v1-namespaced-event-list" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder9::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-core-v1-namespaced-event" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder10::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-core-v1-namespaced-limit-range-list" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder11::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-core-v1-namespaced-limit-range" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder12::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-core-v1-namespaced-persistent-volume-claim-list" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder13::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-core-v1-namespaced-persistent-volume-claim" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder14::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-core-v1-namespaced-pod-list" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder15::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-core-v1-namespaced-pod" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder16::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-core-v1-namespaced-pod-template-list" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder17::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-core-v1-namespaced-pod-template" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder18::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-core-v1-namespaced-replication-controller-list" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder19::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-core-v1-namespaced-replication-controller" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder20::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-core-v1-namespaced-resource-quota-list" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder21::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-core-v1-namespaced-resource-quota" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder22::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-core-v1-namespaced-secret-list" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder23::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-core-v1-namespaced-secret" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder24::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-core-v1-namespaced-service-account-list" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder25::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-core-v1-namespaced-service-account" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder26::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-core-v1-namespaced-service-list" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder27::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-core-v1-namespaced-service" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder28::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-core-v1-namespace" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder29::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-core-v1-node-list" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder30::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-core-v1-node" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder31::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-core-v1-persistent-volume-claim-list-for-all-namespaces" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder32::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-core-v1-persistent-volume-list" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder33::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-core-v1-persistent-volume" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder34::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-core-v1-pod-list-for-all-namespaces" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder35::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-core-v1-pod-template-list-for-all-namespaces" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder36::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-core-v1-replication-controller-list-for-all-namespaces" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder37::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-core-v1-resource-quota-list-for-all-namespaces" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder38::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-core-v1-secret-list-for-all-namespaces" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder39::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-core-v1-service-account-list-for-all-namespaces" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder40::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-core-v1-service-list-for-all-namespaces" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder41::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-admissionregistration-v1beta1-mutating-webhook-configuration-list" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder42::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-admissionregistration-v1beta1-mutating-webhook-configuration" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder43::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-admissionregistration-v1beta1-validating-webhook-configuration-list" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder44::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-admissionregistration-v1beta1-validating-webhook-configuration" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder45::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-apiextensions-v1beta1-custom-resource-definition-list" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder46::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-apiextensions-v1beta1-custom-resource-definition" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder47::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-apiregistration-v1api-service-list" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder48::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-apiregistration-v1api-service" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder49::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-apiregistration-v1beta1-api-service-list" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder50::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-apiregistration-v1beta1-api-service" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder51::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-apps-v1-controller-revision-list-for-all-namespaces" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder52::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-apps-v1-daemon-set-list-for-all-namespaces" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder53::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-apps-v1-deployment-list-for-all-namespaces" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder54::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-apps-v1-namespaced-controller-revision-list" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder55::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-apps-v1-namespaced-controller-revision" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder56::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-apps-v1-namespaced-daemon-set-list" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder57::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-apps-v1-namespaced-daemon-set" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder58::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-apps-v1-namespaced-deployment-list" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder59::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-apps-v1-namespaced-deployment" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder60::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-apps-v1-namespaced-replica-set-list" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder61::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-apps-v1-namespaced-replica-set" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder62::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-apps-v1-namespaced-stateful-set-list" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder63::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-apps-v1-namespaced-stateful-set" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder64::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-apps-v1-replica-set-list-for-all-namespaces" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder65::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-apps-v1-stateful-set-list-for-all-namespaces" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder66::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-apps-v1beta1-controller-revision-list-for-all-namespaces" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder67::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-apps-v1beta1-deployment-list-for-all-namespaces" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder68::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-apps-v1beta1-namespaced-controller-revision-list" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder69::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-apps-v1beta1-namespaced-controller-revision" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder70::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-apps-v1beta1-namespaced-deployment-list" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder71::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-apps-v1beta1-namespaced-deployment" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder72::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-apps-v1beta1-namespaced-stateful-set-list" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder73::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-apps-v1beta1-namespaced-stateful-set" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder74::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-apps-v1beta1-stateful-set-list-for-all-namespaces" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder75::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-apps-v1beta2-controller-revision-list-for-all-namespaces" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder76::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-apps-v1beta2-daemon-set-list-for-all-namespaces" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder77::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-apps-v1beta2-deployment-list-for-all-namespaces" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder78::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-apps-v1beta2-namespaced-controller-revision-list" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder79::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-apps-v1beta2-namespaced-controller-revision" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder80::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-apps-v1beta2-namespaced-daemon-set-list" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder81::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-apps-v1beta2-namespaced-daemon-set" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder82::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-apps-v1beta2-namespaced-deployment-list" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder83::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-apps-v1beta2-namespaced-deployment" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder84::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-apps-v1beta2-namespaced-replica-set-list" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder85::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-apps-v1beta2-namespaced-replica-set" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder86::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-apps-v1beta2-namespaced-stateful-set-list" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder87::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-apps-v1beta2-namespaced-stateful-set" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder88::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-apps-v1beta2-replica-set-list-for-all-namespaces" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder89::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-apps-v1beta2-stateful-set-list-for-all-namespaces" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder90::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-auditregistration-v1alpha1-audit-sink-list" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder91::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-auditregistration-v1alpha1-audit-sink" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder92::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-autoscaling-v1-horizontal-pod-autoscaler-list-for-all-namespaces" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder93::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-autoscaling-v1-namespaced-horizontal-pod-autoscaler-list" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder94::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-autoscaling-v1-namespaced-horizontal-pod-autoscaler" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder95::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-autoscaling-v2beta1-horizontal-pod-autoscaler-list-for-all-namespaces" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder96::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-autoscaling-v2beta1-namespaced-horizontal-pod-autoscaler-list" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder97::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-autoscaling-v2beta1-namespaced-horizontal-pod-autoscaler" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder98::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-autoscaling-v2beta2-horizontal-pod-autoscaler-list-for-all-namespaces" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder99::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-autoscaling-v2beta2-namespaced-horizontal-pod-autoscaler-list" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder100::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-autoscaling-v2beta2-namespaced-horizontal-pod-autoscaler" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder101::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-batch-v1-job-list-for-all-namespaces" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder102::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-batch-v1-namespaced-job-list" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder103::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-batch-v1-namespaced-job" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder104::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-batch-v1beta1-cron-job-list-for-all-namespaces" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder105::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-batch-v1beta1-namespaced-cron-job-list" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder106::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-batch-v1beta1-namespaced-cron-job" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder107::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-batch-v2alpha1-cron-job-list-for-all-namespaces" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder108::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-batch-v2alpha1-namespaced-cron-job-list" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder109::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-batch-v2alpha1-namespaced-cron-job" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder110::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-certificates-v1beta1-certificate-signing-request-list" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder111::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-certificates-v1beta1-certificate-signing-request" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder112::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-coordination-v1-lease-list-for-all-namespaces" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder113::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-coordination-v1-namespaced-lease-list" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder114::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-coordination-v1-namespaced-lease" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder115::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-coordination-v1beta1-lease-list-for-all-namespaces" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder116::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-coordination-v1beta1-namespaced-lease-list" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder117::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-coordination-v1beta1-namespaced-lease" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder118::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-events-v1beta1-event-list-for-all-namespaces" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder119::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-events-v1beta1-namespaced-event-list" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder120::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-events-v1beta1-namespaced-event" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder121::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-extensions-v1beta1-daemon-set-list-for-all-namespaces" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder122::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-extensions-v1beta1-deployment-list-for-all-namespaces" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder123::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-extensions-v1beta1-ingress-list-for-all-namespaces" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder124::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-extensions-v1beta1-namespaced-daemon-set-list" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder125::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-extensions-v1beta1-namespaced-daemon-set" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder126::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-extensions-v1beta1-namespaced-deployment-list" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder127::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-extensions-v1beta1-namespaced-deployment" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder128::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-extensions-v1beta1-namespaced-ingress-list" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder129::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-extensions-v1beta1-namespaced-ingress" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder130::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-extensions-v1beta1-namespaced-network-policy-list" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder131::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-extensions-v1beta1-namespaced-network-policy" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder132::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-extensions-v1beta1-namespaced-replica-set-list" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder133::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-extensions-v1beta1-namespaced-replica-set" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder134::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-extensions-v1beta1-network-policy-list-for-all-namespaces" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder135::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-extensions-v1beta1-pod-security-policy-list" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder136::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-extensions-v1beta1-pod-security-policy" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder137::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-extensions-v1beta1-replica-set-list-for-all-namespaces" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder138::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-networking-v1-namespaced-network-policy-list" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder139::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-networking-v1-namespaced-network-policy" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder140::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-networking-v1-network-policy-list-for-all-namespaces" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder141::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-networking-v1beta1-ingress-list-for-all-namespaces" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder142::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-networking-v1beta1-namespaced-ingress-list" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder143::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-networking-v1beta1-namespaced-ingress" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder144::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-node-v1alpha1-runtime-class-list" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder145::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-node-v1alpha1-runtime-class" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder146::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-node-v1beta1-runtime-class-list" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder147::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-node-v1beta1-runtime-class" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder148::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-policy-v1beta1-namespaced-pod-disruption-budget-list" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder149::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-policy-v1beta1-namespaced-pod-disruption-budget" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder150::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-policy-v1beta1-pod-disruption-budget-list-for-all-namespaces" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder151::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-policy-v1beta1-pod-security-policy-list" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder152::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-policy-v1beta1-pod-security-policy" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder153::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-rbac-authorization-v1-cluster-role-binding-list" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder154::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-rbac-authorization-v1-cluster-role-binding" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder155::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-rbac-authorization-v1-cluster-role-list" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder156::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-rbac-authorization-v1-cluster-role" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder157::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-rbac-authorization-v1-namespaced-role-binding-list" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder158::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-rbac-authorization-v1-namespaced-role-binding" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder159::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-rbac-authorization-v1-namespaced-role-list" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder160::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-rbac-authorization-v1-namespaced-role" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder161::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-rbac-authorization-v1-role-binding-list-for-all-namespaces" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder162::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-rbac-authorization-v1-role-list-for-all-namespaces" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder163::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-rbac-authorization-v1alpha1-cluster-role-binding-list" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder164::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-rbac-authorization-v1alpha1-cluster-role-binding" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder165::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-rbac-authorization-v1alpha1-cluster-role-list" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder166::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-rbac-authorization-v1alpha1-cluster-role" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder167::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-rbac-authorization-v1alpha1-namespaced-role-binding-list" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder168::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-rbac-authorization-v1alpha1-namespaced-role-binding" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder169::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-rbac-authorization-v1alpha1-namespaced-role-list" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder170::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-rbac-authorization-v1alpha1-namespaced-role" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder171::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-rbac-authorization-v1alpha1-role-binding-list-for-all-namespaces" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder172::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-rbac-authorization-v1alpha1-role-list-for-all-namespaces" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder173::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-rbac-authorization-v1beta1-cluster-role-binding-list" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder174::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-rbac-authorization-v1beta1-cluster-role-binding" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder175::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-rbac-authorization-v1beta1-cluster-role-list" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder176::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-rbac-authorization-v1beta1-cluster-role" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder177::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-rbac-authorization-v1beta1-namespaced-role-binding-list" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder178::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-rbac-authorization-v1beta1-namespaced-role-binding" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder179::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-rbac-authorization-v1beta1-namespaced-role-list" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder180::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-rbac-authorization-v1beta1-namespaced-role" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder181::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-rbac-authorization-v1beta1-role-binding-list-for-all-namespaces" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder182::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-rbac-authorization-v1beta1-role-list-for-all-namespaces" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder183::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-scheduling-v1-priority-class-list" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder184::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-scheduling-v1-priority-class" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder185::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-scheduling-v1alpha1-priority-class-list" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder186::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-scheduling-v1alpha1-priority-class" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder187::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-scheduling-v1beta1-priority-class-list" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder188::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-scheduling-v1beta1-priority-class" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder189::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-settings-v1alpha1-namespaced-pod-preset-list" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder190::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-settings-v1alpha1-namespaced-pod-preset" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder191::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-settings-v1alpha1-pod-preset-list-for-all-namespaces" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder192::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-storage-v1-storage-class-list" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder193::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-storage-v1-storage-class" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder194::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-storage-v1-volume-attachment-list" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder195::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-storage-v1-volume-attachment" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder196::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-storage-v1alpha1-volume-attachment-list" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder197::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-storage-v1alpha1-volume-attachment" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder198::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-storage-v1beta1-csi-driver-list" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder199::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-storage-v1beta1-csi-driver" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder200::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-storage-v1beta1-csi-node-list" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder201::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-storage-v1beta1-csi-node" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder202::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-storage-v1beta1-storage-class-list" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder203::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-storage-v1beta1-storage-class" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder204::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-storage-v1beta1-volume-attachment-list" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder205::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "watch-storage-v1beta1-volume-attachment" => {
+            let builder = crate::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEventGetBuilder206::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "get-code-version" => {
+            let builder = crate::io::k8s::apimachinery::pkg::version::info::InfoGetBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "create-apiregistration-v1api-service" => {
+            let builder = crate::io::k8s::kube_aggregator::pkg::apis::apiregistration::v1::api_service::ApiServicePostBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "read-apiregistration-v1api-service" => {
+            let builder = crate::io::k8s::kube_aggregator::pkg::apis::apiregistration::v1::api_service::ApiServiceGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "replace-apiregistration-v1api-service" => {
+            let builder = crate::io::k8s::kube_aggregator::pkg::apis::apiregistration::v1::api_service::ApiServicePutBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "read-apiregistration-v1api-service-status" => {
+            let builder = crate::io::k8s::kube_aggregator::pkg::apis::apiregistration::v1::api_service::ApiServiceGetBuilder2::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "replace-apiregistration-v1api-service-status" => {
+            let builder = crate::io::k8s::kube_aggregator::pkg::apis::apiregistration::v1::api_service::ApiServicePutBuilder2::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "list-apiregistration-v1api-service" => {
+            let builder = crate::io::k8s::kube_aggregator::pkg::apis::apiregistration::v1::api_service_list::ApiServiceListGetBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "create-apiregistration-v1beta1-api-service" => {
+            let builder = crate::io::k8s::kube_aggregator::pkg::apis::apiregistration::v1beta1::api_service::ApiServicePostBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "read-apiregistration-v1beta1-api-service" => {
+            let builder = crate::io::k8s::kube_aggregator::pkg::apis::apiregistration::v1beta1::api_service::ApiServiceGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "replace-apiregistration-v1beta1-api-service" => {
+            let builder = crate::io::k8s::kube_aggregator::pkg::apis::apiregistration::v1beta1::api_service::ApiServicePutBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "read-apiregistration-v1beta1-api-service-status" => {
+            let builder = crate::io::k8s::kube_aggregator::pkg::apis::apiregistration::v1beta1::api_service::ApiServiceGetBuilder2::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "replace-apiregistration-v1beta1-api-service-status" => {
+            let builder = crate::io::k8s::kube_aggregator::pkg::apis::apiregistration::v1beta1::api_service::ApiServicePutBuilder2::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "list-apiregistration-v1beta1-api-service" => {
+            let builder = crate::io::k8s::kube_aggregator::pkg::apis::apiregistration::v1beta1::api_service_list::ApiServiceListGetBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "connect-core-v1-post-namespaced-pod-attach" => {
+            let builder = crate::miscellaneous::MiscellaneousPostBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "connect-core-v1-get-namespaced-pod-exec" => {
+            let builder = crate::miscellaneous::MiscellaneousGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "connect-core-v1-post-namespaced-pod-exec" => {
+            let builder = crate::miscellaneous::MiscellaneousPostBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "read-core-v1-namespaced-pod-log" => {
+            let builder = crate::miscellaneous::MiscellaneousGetBuilder2::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "connect-core-v1-get-namespaced-pod-portforward" => {
+            let builder = crate::miscellaneous::MiscellaneousGetBuilder3::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "connect-core-v1-post-namespaced-pod-portforward" => {
+            let builder = crate::miscellaneous::MiscellaneousPostBuilder3::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "connect-core-v1-get-namespaced-pod-proxy" => {
+            let builder = crate::miscellaneous::MiscellaneousGetBuilder4::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "connect-core-v1-put-namespaced-pod-proxy" => {
+            let builder = crate::miscellaneous::MiscellaneousPutBuilder4::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "connect-core-v1-post-namespaced-pod-proxy" => {
+            let builder = crate::miscellaneous::MiscellaneousPostBuilder4::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "connect-core-v1-delete-namespaced-pod-proxy" => {
+            let builder = crate::miscellaneous::MiscellaneousDeleteBuilder4::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "connect-core-v1-options-namespaced-pod-proxy" => {
+            let builder = crate::miscellaneous::MiscellaneousOptionsBuilder4::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "connect-core-v1-head-namespaced-pod-proxy" => {
+            let builder = crate::miscellaneous::MiscellaneousHeadBuilder4::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "connect-core-v1-patch-namespaced-pod-proxy" => {
+            let builder = crate::miscellaneous::MiscellaneousPatchBuilder4::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "connect-core-v1-get-namespaced-pod-proxy-with-path" => {
+            let builder = crate::miscellaneous::MiscellaneousGetBuilder5::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "connect-core-v1-put-namespaced-pod-proxy-with-path" => {
+            let builder = crate::miscellaneous::MiscellaneousPutBuilder5::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "connect-core-v1-post-namespaced-pod-proxy-with-path" => {
+            let builder = crate::miscellaneous::MiscellaneousPostBuilder5::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "connect-core-v1-delete-namespaced-pod-proxy-with-path" => {
+            let builder = crate::miscellaneous::MiscellaneousDeleteBuilder5::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "connect-core-v1-options-namespaced-pod-proxy-with-path" => {
+            let builder = crate::miscellaneous::MiscellaneousOptionsBuilder5::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "connect-core-v1-head-namespaced-pod-proxy-with-path" => {
+            let builder = crate::miscellaneous::MiscellaneousHeadBuilder5::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "connect-core-v1-patch-namespaced-pod-proxy-with-path" => {
+            let builder = crate::miscellaneous::MiscellaneousPatchBuilder5::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "connect-core-v1-get-namespaced-service-proxy" => {
+            let builder = crate::miscellaneous::MiscellaneousGetBuilder6::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "connect-core-v1-put-namespaced-service-proxy" => {
+            let builder = crate::miscellaneous::MiscellaneousPutBuilder6::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "connect-core-v1-post-namespaced-service-proxy" => {
+            let builder = crate::miscellaneous::MiscellaneousPostBuilder6::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "connect-core-v1-delete-namespaced-service-proxy" => {
+            let builder = crate::miscellaneous::MiscellaneousDeleteBuilder6::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "connect-core-v1-options-namespaced-service-proxy" => {
+            let builder = crate::miscellaneous::MiscellaneousOptionsBuilder6::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "connect-core-v1-head-namespaced-service-proxy" => {
+            let builder = crate::miscellaneous::MiscellaneousHeadBuilder6::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "connect-core-v1-patch-namespaced-service-proxy" => {
+            let builder = crate::miscellaneous::MiscellaneousPatchBuilder6::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "connect-core-v1-get-namespaced-service-proxy-with-path" => {
+            let builder = crate::miscellaneous::MiscellaneousGetBuilder7::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "connect-core-v1-put-namespaced-service-proxy-with-path" => {
+            let builder = crate::miscellaneous::MiscellaneousPutBuilder7::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "connect-core-v1-post-namespaced-service-proxy-with-path" => {
+            let builder = crate::miscellaneous::MiscellaneousPostBuilder7::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "connect-core-v1-delete-namespaced-service-proxy-with-path" => {
+            let builder = crate::miscellaneous::MiscellaneousDeleteBuilder7::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "connect-core-v1-options-namespaced-service-proxy-with-path" => {
+            let builder = crate::miscellaneous::MiscellaneousOptionsBuilder7::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "connect-core-v1-head-namespaced-service-proxy-with-path" => {
+            let builder = crate::miscellaneous::MiscellaneousHeadBuilder7::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "connect-core-v1-patch-namespaced-service-proxy-with-path" => {
+            let builder = crate::miscellaneous::MiscellaneousPatchBuilder7::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "connect-core-v1-get-node-proxy" => {
+            let builder = crate::miscellaneous::MiscellaneousGetBuilder8::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "connect-core-v1-put-node-proxy" => {
+            let builder = crate::miscellaneous::MiscellaneousPutBuilder8::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "connect-core-v1-post-node-proxy" => {
+            let builder = crate::miscellaneous::MiscellaneousPostBuilder8::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "connect-core-v1-delete-node-proxy" => {
+            let builder = crate::miscellaneous::MiscellaneousDeleteBuilder8::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "connect-core-v1-options-node-proxy" => {
+            let builder = crate::miscellaneous::MiscellaneousOptionsBuilder8::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "connect-core-v1-head-node-proxy" => {
+            let builder = crate::miscellaneous::MiscellaneousHeadBuilder8::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "connect-core-v1-patch-node-proxy" => {
+            let builder = crate::miscellaneous::MiscellaneousPatchBuilder8::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "connect-core-v1-get-node-proxy-with-path" => {
+            let builder = crate::miscellaneous::MiscellaneousGetBuilder9::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "connect-core-v1-put-node-proxy-with-path" => {
+            let builder = crate::miscellaneous::MiscellaneousPutBuilder9::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "connect-core-v1-post-node-proxy-with-path" => {
+            let builder = crate::miscellaneous::MiscellaneousPostBuilder9::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "connect-core-v1-delete-node-proxy-with-path" => {
+            let builder = crate::miscellaneous::MiscellaneousDeleteBuilder9::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "connect-core-v1-options-node-proxy-with-path" => {
+            let builder = crate::miscellaneous::MiscellaneousOptionsBuilder9::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "connect-core-v1-head-node-proxy-with-path" => {
+            let builder = crate::miscellaneous::MiscellaneousHeadBuilder9::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "connect-core-v1-patch-node-proxy-with-path" => {
+            let builder = crate::miscellaneous::MiscellaneousPatchBuilder9::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        _ => unimplemented!(),
+    };
+
+    match resp {
+        Ok(r) => Ok(r),
+        Err(ApiError::Failure(_, _, r)) => Ok(r.into_inner()),
+        Err(e) => return Err(e.into()),
+    }
+}
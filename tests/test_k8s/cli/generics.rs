@@ -0,0 +1,359 @@
+
+pub struct MissingAcceptedNames;
+pub struct AcceptedNamesExists;
+pub struct MissingAddress;
+pub struct AddressExists;
+pub struct MissingAllowed;
+pub struct AllowedExists;
+pub struct MissingAllowedRuntimeClassNames;
+pub struct AllowedRuntimeClassNamesExists;
+pub struct MissingApiGroup;
+pub struct ApiGroupExists;
+pub struct MissingApiVersion;
+pub struct ApiVersionExists;
+pub struct MissingArchitecture;
+pub struct ArchitectureExists;
+pub struct MissingAttached;
+pub struct AttachedExists;
+pub struct MissingAttacher;
+pub struct AttacherExists;
+pub struct MissingBackend;
+pub struct BackendExists;
+pub struct MissingBootId;
+pub struct BootIdExists;
+pub struct MissingBuildDate;
+pub struct BuildDateExists;
+pub struct MissingCidr;
+pub struct CidrExists;
+pub struct MissingClaimName;
+pub struct ClaimNameExists;
+pub struct MissingClientCidr;
+pub struct ClientCidrExists;
+pub struct MissingClientConfig;
+pub struct ClientConfigExists;
+pub struct MissingCompiler;
+pub struct CompilerExists;
+pub struct MissingConditionType;
+pub struct ConditionTypeExists;
+pub struct MissingConditions;
+pub struct ConditionsExists;
+pub struct MissingContainerPort;
+pub struct ContainerPortExists;
+pub struct MissingContainerRuntimeVersion;
+pub struct ContainerRuntimeVersionExists;
+pub struct MissingContainers;
+pub struct ContainersExists;
+pub struct MissingCount;
+pub struct CountExists;
+pub struct MissingCurrent;
+pub struct CurrentExists;
+pub struct MissingCurrentAverageValue;
+pub struct CurrentAverageValueExists;
+pub struct MissingCurrentHealthy;
+pub struct CurrentHealthyExists;
+pub struct MissingCurrentNumberScheduled;
+pub struct CurrentNumberScheduledExists;
+pub struct MissingCurrentReplicas;
+pub struct CurrentReplicasExists;
+pub struct MissingCurrentValue;
+pub struct CurrentValueExists;
+pub struct MissingDescribedObject;
+pub struct DescribedObjectExists;
+pub struct MissingDesiredHealthy;
+pub struct DesiredHealthyExists;
+pub struct MissingDesiredNumberScheduled;
+pub struct DesiredNumberScheduledExists;
+pub struct MissingDesiredReplicas;
+pub struct DesiredReplicasExists;
+pub struct MissingDevicePath;
+pub struct DevicePathExists;
+pub struct MissingDiskName;
+pub struct DiskNameExists;
+pub struct MissingDiskUri;
+pub struct DiskUriExists;
+pub struct MissingDisruptionsAllowed;
+pub struct DisruptionsAllowedExists;
+pub struct MissingDriver;
+pub struct DriverExists;
+pub struct MissingDrivers;
+pub struct DriversExists;
+pub struct MissingEffect;
+pub struct EffectExists;
+pub struct MissingEndpoints;
+pub struct EndpointsExists;
+pub struct MissingEventTime;
+pub struct EventTimeExists;
+pub struct MissingExitCode;
+pub struct ExitCodeExists;
+pub struct MissingExpectedPods;
+pub struct ExpectedPodsExists;
+pub struct MissingFieldPath;
+pub struct FieldPathExists;
+pub struct MissingFsGroup;
+pub struct FsGroupExists;
+pub struct MissingGateway;
+pub struct GatewayExists;
+pub struct MissingGitCommit;
+pub struct GitCommitExists;
+pub struct MissingGitTreeState;
+pub struct GitTreeStateExists;
+pub struct MissingGitVersion;
+pub struct GitVersionExists;
+pub struct MissingGoVersion;
+pub struct GoVersionExists;
+pub struct MissingGroup;
+pub struct GroupExists;
+pub struct MissingGroupPriorityMinimum;
+pub struct GroupPriorityMinimumExists;
+pub struct MissingGroupVersion;
+pub struct GroupVersionExists;
+pub struct MissingGroups;
+pub struct GroupsExists;
+pub struct MissingHandler;
+pub struct HandlerExists;
+pub struct MissingImage;
+pub struct ImageExists;
+pub struct MissingImageId;
+pub struct ImageIdExists;
+pub struct MissingIncomplete;
+pub struct IncompleteExists;
+pub struct MissingInvolvedObject;
+pub struct InvolvedObjectExists;
+pub struct MissingIp;
+pub struct IpExists;
+pub struct MissingIqn;
+pub struct IqnExists;
+pub struct MissingItems;
+pub struct ItemsExists;
+pub struct MissingJobTemplate;
+pub struct JobTemplateExists;
+pub struct MissingJsonPath;
+pub struct JsonPathExists;
+pub struct MissingKernelVersion;
+pub struct KernelVersionExists;
+pub struct MissingKey;
+pub struct KeyExists;
+pub struct MissingKind;
+pub struct KindExists;
+pub struct MissingKubeProxyVersion;
+pub struct KubeProxyVersionExists;
+pub struct MissingKubeletConfigKey;
+pub struct KubeletConfigKeyExists;
+pub struct MissingKubeletVersion;
+pub struct KubeletVersionExists;
+pub struct MissingLastObservedTime;
+pub struct LastObservedTimeExists;
+pub struct MissingLevel;
+pub struct LevelExists;
+pub struct MissingLimits;
+pub struct LimitsExists;
+pub struct MissingLun;
+pub struct LunExists;
+pub struct MissingMachineId;
+pub struct MachineIdExists;
+pub struct MissingMajor;
+pub struct MajorExists;
+pub struct MissingMax;
+pub struct MaxExists;
+pub struct MissingMaxReplicas;
+pub struct MaxReplicasExists;
+pub struct MissingMetadata;
+pub struct MetadataExists;
+pub struct MissingMetric;
+pub struct MetricExists;
+pub struct MissingMetricName;
+pub struct MetricNameExists;
+pub struct MissingMin;
+pub struct MinExists;
+pub struct MissingMinor;
+pub struct MinorExists;
+pub struct MissingMonitors;
+pub struct MonitorsExists;
+pub struct MissingMountPath;
+pub struct MountPathExists;
+pub struct MissingName;
+pub struct NameExists;
+pub struct MissingNames;
+pub struct NamesExists;
+pub struct MissingNamespace;
+pub struct NamespaceExists;
+pub struct MissingNamespaced;
+pub struct NamespacedExists;
+pub struct MissingNodeId;
+pub struct NodeIdExists;
+pub struct MissingNodeName;
+pub struct NodeNameExists;
+pub struct MissingNodeSelectorTerms;
+pub struct NodeSelectorTermsExists;
+pub struct MissingNonResourceRules;
+pub struct NonResourceRulesExists;
+pub struct MissingNumberMisscheduled;
+pub struct NumberMisscheduledExists;
+pub struct MissingNumberReady;
+pub struct NumberReadyExists;
+pub struct MissingObject;
+pub struct ObjectExists;
+pub struct MissingOperatingSystem;
+pub struct OperatingSystemExists;
+pub struct MissingOperator;
+pub struct OperatorExists;
+pub struct MissingOsImage;
+pub struct OsImageExists;
+pub struct MissingPath;
+pub struct PathExists;
+pub struct MissingPaths;
+pub struct PathsExists;
+pub struct MissingPdId;
+pub struct PdIdExists;
+pub struct MissingPdName;
+pub struct PdNameExists;
+pub struct MissingPending;
+pub struct PendingExists;
+pub struct MissingPlatform;
+pub struct PlatformExists;
+pub struct MissingPlural;
+pub struct PluralExists;
+pub struct MissingPodAffinityTerm;
+pub struct PodAffinityTermExists;
+pub struct MissingPodSelector;
+pub struct PodSelectorExists;
+pub struct MissingPolicy;
+pub struct PolicyExists;
+pub struct MissingPort;
+pub struct PortExists;
+pub struct MissingPreference;
+pub struct PreferenceExists;
+pub struct MissingProvisioner;
+pub struct ProvisionerExists;
+pub struct MissingRaw;
+pub struct RawExists;
+pub struct MissingReady;
+pub struct ReadyExists;
+pub struct MissingRegistry;
+pub struct RegistryExists;
+pub struct MissingReplicas;
+pub struct ReplicasExists;
+pub struct MissingRepository;
+pub struct RepositoryExists;
+pub struct MissingRequest;
+pub struct RequestExists;
+pub struct MissingResource;
+pub struct ResourceExists;
+pub struct MissingResourceRules;
+pub struct ResourceRulesExists;
+pub struct MissingResources;
+pub struct ResourcesExists;
+pub struct MissingRestartCount;
+pub struct RestartCountExists;
+pub struct MissingRevision;
+pub struct RevisionExists;
+pub struct MissingRoleRef;
+pub struct RoleRefExists;
+pub struct MissingRollbackTo;
+pub struct RollbackToExists;
+pub struct MissingRule;
+pub struct RuleExists;
+pub struct MissingRunAsUser;
+pub struct RunAsUserExists;
+pub struct MissingRuntimeHandler;
+pub struct RuntimeHandlerExists;
+pub struct MissingScaleTargetRef;
+pub struct ScaleTargetRefExists;
+pub struct MissingSchedule;
+pub struct ScheduleExists;
+pub struct MissingScope;
+pub struct ScopeExists;
+pub struct MissingScopeName;
+pub struct ScopeNameExists;
+pub struct MissingSeLinux;
+pub struct SeLinuxExists;
+pub struct MissingSecretName;
+pub struct SecretNameExists;
+pub struct MissingSecretRef;
+pub struct SecretRefExists;
+pub struct MissingSelector;
+pub struct SelectorExists;
+pub struct MissingServed;
+pub struct ServedExists;
+pub struct MissingServer;
+pub struct ServerExists;
+pub struct MissingServerAddress;
+pub struct ServerAddressExists;
+pub struct MissingServerAddressByClientCidRs;
+pub struct ServerAddressByClientCidRsExists;
+pub struct MissingService;
+pub struct ServiceExists;
+pub struct MissingServiceName;
+pub struct ServiceNameExists;
+pub struct MissingServicePort;
+pub struct ServicePortExists;
+pub struct MissingShareName;
+pub struct ShareNameExists;
+pub struct MissingSingularName;
+pub struct SingularNameExists;
+pub struct MissingSource;
+pub struct SourceExists;
+pub struct MissingSources;
+pub struct SourcesExists;
+pub struct MissingSpec;
+pub struct SpecExists;
+pub struct MissingSpecReplicasPath;
+pub struct SpecReplicasPathExists;
+pub struct MissingState;
+pub struct StateExists;
+pub struct MissingStatus;
+pub struct StatusExists;
+pub struct MissingStatusReplicasPath;
+pub struct StatusReplicasPathExists;
+pub struct MissingStorage;
+pub struct StorageExists;
+pub struct MissingStoredVersions;
+pub struct StoredVersionsExists;
+pub struct MissingStrategy;
+pub struct StrategyExists;
+pub struct MissingSupplementalGroups;
+pub struct SupplementalGroupsExists;
+pub struct MissingSystem;
+pub struct SystemExists;
+pub struct MissingSystemUuid;
+pub struct SystemUuidExists;
+pub struct MissingTarget;
+pub struct TargetExists;
+pub struct MissingTargetAverageValue;
+pub struct TargetAverageValueExists;
+pub struct MissingTargetPortal;
+pub struct TargetPortalExists;
+pub struct MissingTargetValue;
+pub struct TargetValueExists;
+pub struct MissingTemplate;
+pub struct TemplateExists;
+pub struct MissingTopologyKey;
+pub struct TopologyKeyExists;
+pub struct MissingType;
+pub struct TypeExists;
+pub struct MissingUid;
+pub struct UidExists;
+pub struct MissingValue;
+pub struct ValueExists;
+pub struct MissingValues;
+pub struct ValuesExists;
+pub struct MissingVerbs;
+pub struct VerbsExists;
+pub struct MissingVersion;
+pub struct VersionExists;
+pub struct MissingVersionPriority;
+pub struct VersionPriorityExists;
+pub struct MissingVersions;
+pub struct VersionsExists;
+pub struct MissingVolume;
+pub struct VolumeExists;
+pub struct MissingVolumeHandle;
+pub struct VolumeHandleExists;
+pub struct MissingVolumeId;
+pub struct VolumeIdExists;
+pub struct MissingVolumePath;
+pub struct VolumePathExists;
+pub struct MissingWebhook;
+pub struct WebhookExists;
+pub struct MissingWeight;
+pub struct WeightExists;
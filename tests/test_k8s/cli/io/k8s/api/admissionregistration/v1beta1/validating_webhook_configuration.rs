@@ -0,0 +1,706 @@
+
+/// ValidatingWebhookConfiguration describes the configuration of and admission webhook that accept or reject and object without changing it.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct ValidatingWebhookConfiguration {
+    /// APIVersion defines the versioned schema of this representation of an object. Servers should convert recognized schemas to the latest internal value, and may reject unrecognized values. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#resources
+    #[serde(rename = "apiVersion", skip_serializing_if = "Option::is_none")]
+    pub api_version: Option<String>,
+    /// Kind is a string value representing the REST resource this object represents. Servers may infer this from the endpoint the client submits requests to. Cannot be updated. In CamelCase. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#types-kinds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub kind: Option<String>,
+    /// Standard object metadata; More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#metadata.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<crate::io::k8s::apimachinery::pkg::apis::meta::v1::object_meta::ObjectMeta>,
+    /// Webhooks is a list of webhooks and the affected resources and operations.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub webhooks: Option<Vec<crate::io::k8s::api::admissionregistration::v1beta1::webhook::Webhook>>,
+}
+
+impl ValidatingWebhookConfiguration {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> ValidatingWebhookConfigurationBuilder {
+        ValidatingWebhookConfigurationBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// create a ValidatingWebhookConfiguration
+    #[inline]
+    pub fn create_admissionregistration_v1beta1_validating_webhook_configuration() -> ValidatingWebhookConfigurationPostBuilder {
+        ValidatingWebhookConfigurationPostBuilder {
+            body: Default::default(),
+            param_dry_run: None,
+            param_field_manager: None,
+            param_pretty: None,
+        }
+    }
+
+    /// read the specified ValidatingWebhookConfiguration
+    #[inline]
+    pub fn read_admissionregistration_v1beta1_validating_webhook_configuration() -> ValidatingWebhookConfigurationGetBuilder1<crate::generics::MissingName> {
+        ValidatingWebhookConfigurationGetBuilder1 {
+            inner: Default::default(),
+            _param_name: core::marker::PhantomData,
+        }
+    }
+
+    /// replace the specified ValidatingWebhookConfiguration
+    #[inline]
+    pub fn replace_admissionregistration_v1beta1_validating_webhook_configuration() -> ValidatingWebhookConfigurationPutBuilder1<crate::generics::MissingName> {
+        ValidatingWebhookConfigurationPutBuilder1 {
+            inner: Default::default(),
+            _param_name: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> ValidatingWebhookConfigurationBuilder
+    where
+        Self: Clone,
+    {
+        ValidatingWebhookConfigurationBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<ValidatingWebhookConfiguration> for ValidatingWebhookConfigurationBuilder {
+    fn into(self) -> ValidatingWebhookConfiguration {
+        self.body
+    }
+}
+
+impl Into<ValidatingWebhookConfiguration> for ValidatingWebhookConfigurationPostBuilder {
+    fn into(self) -> ValidatingWebhookConfiguration {
+        self.body
+    }
+}
+
+impl Into<ValidatingWebhookConfiguration> for ValidatingWebhookConfigurationPutBuilder1<crate::generics::NameExists> {
+    fn into(self) -> ValidatingWebhookConfiguration {
+        self.inner.body
+    }
+}
+
+/// Builder for [`ValidatingWebhookConfiguration`](./struct.ValidatingWebhookConfiguration.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct ValidatingWebhookConfigurationBuilder {
+    body: self::ValidatingWebhookConfiguration,
+}
+
+impl ValidatingWebhookConfigurationBuilder {
+    /// APIVersion defines the versioned schema of this representation of an object. Servers should convert recognized schemas to the latest internal value, and may reject unrecognized values. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#resources
+    #[inline]
+    pub fn api_version(mut self, value: impl Into<String>) -> Self {
+        self.body.api_version = Some(value.into());
+        self
+    }
+
+    /// Sets [`api_version`](#method.api_version) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_api_version(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.api_version = value.map(|value| value.into());
+        self
+    }
+
+    /// Kind is a string value representing the REST resource this object represents. Servers may infer this from the endpoint the client submits requests to. Cannot be updated. In CamelCase. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#types-kinds
+    #[inline]
+    pub fn kind(mut self, value: impl Into<String>) -> Self {
+        self.body.kind = Some(value.into());
+        self
+    }
+
+    /// Sets [`kind`](#method.kind) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_kind(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.kind = value.map(|value| value.into());
+        self
+    }
+
+    /// Standard object metadata; More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#metadata.
+    #[inline]
+    pub fn metadata(mut self, value: crate::io::k8s::apimachinery::pkg::apis::meta::v1::object_meta::ObjectMeta) -> Self {
+        self.body.metadata = Some(value.into());
+        self
+    }
+
+    /// Sets [`metadata`](#method.metadata) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_metadata(mut self, value: Option<crate::io::k8s::apimachinery::pkg::apis::meta::v1::object_meta::ObjectMeta>) -> Self {
+        self.body.metadata = value.map(|value| value.into());
+        self
+    }
+
+    /// Webhooks is a list of webhooks and the affected resources and operations.
+    #[inline]
+    pub fn webhooks(mut self, value: impl Iterator<Item = crate::io::k8s::api::admissionregistration::v1beta1::webhook::WebhookBuilder<crate::generics::ClientConfigExists, crate::generics::NameExists>>) -> Self {
+        self.body.webhooks = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`webhooks`](#method.webhooks) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_webhooks(mut self, value: Option<impl Iterator<Item = crate::io::k8s::api::admissionregistration::v1beta1::webhook::WebhookBuilder<crate::generics::ClientConfigExists, crate::generics::NameExists>>>) -> Self {
+        self.body.webhooks = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`webhooks`](#method.webhooks), but takes the values as a `Vec`.
+    #[inline]
+    pub fn webhooks_vec(mut self, value: impl Into<Vec<crate::io::k8s::api::admissionregistration::v1beta1::webhook::Webhook>>) -> Self {
+        self.body.webhooks = Some(value.into());
+        self
+    }
+}
+
+/// Builder created by [`ValidatingWebhookConfiguration::create_admissionregistration_v1beta1_validating_webhook_configuration`](./struct.ValidatingWebhookConfiguration.html#method.create_admissionregistration_v1beta1_validating_webhook_configuration) method for a `POST` operation associated with `ValidatingWebhookConfiguration`.
+#[derive(Debug, Default, Clone)]
+pub struct ValidatingWebhookConfigurationPostBuilder {
+    body: self::ValidatingWebhookConfiguration,
+    param_dry_run: Option<String>,
+    param_field_manager: Option<String>,
+    param_pretty: Option<String>,
+}
+
+impl ValidatingWebhookConfigurationPostBuilder {
+    /// When present, indicates that modifications should not be persisted. An invalid or unrecognized dryRun directive will result in an error response and no further processing of the request. Valid values are: - All: all dry run stages will be processed
+    #[inline]
+    pub fn dry_run(mut self, value: impl Into<String>) -> Self {
+        self.param_dry_run = Some(value.into());
+        self
+    }
+
+    /// Sets [`dry_run`](#method.dry_run) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_dry_run(mut self, value: Option<impl Into<String>>) -> Self {
+        self.param_dry_run = value.map(|value| value.into());
+        self
+    }
+
+    /// fieldManager is a name associated with the actor or entity that is making these changes. The value must be less than or 128 characters long, and only contain printable characters, as defined by https://golang.org/pkg/unicode/#IsPrint.
+    #[inline]
+    pub fn field_manager(mut self, value: impl Into<String>) -> Self {
+        self.param_field_manager = Some(value.into());
+        self
+    }
+
+    /// Sets [`field_manager`](#method.field_manager) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_field_manager(mut self, value: Option<impl Into<String>>) -> Self {
+        self.param_field_manager = value.map(|value| value.into());
+        self
+    }
+
+    /// If 'true', then the output is pretty printed.
+    #[inline]
+    pub fn pretty(mut self, value: impl Into<String>) -> Self {
+        self.param_pretty = Some(value.into());
+        self
+    }
+
+    /// Sets [`pretty`](#method.pretty) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_pretty(mut self, value: Option<impl Into<String>>) -> Self {
+        self.param_pretty = value.map(|value| value.into());
+        self
+    }
+
+    /// APIVersion defines the versioned schema of this representation of an object. Servers should convert recognized schemas to the latest internal value, and may reject unrecognized values. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#resources
+    #[inline]
+    pub fn api_version(mut self, value: impl Into<String>) -> Self {
+        self.body.api_version = Some(value.into());
+        self
+    }
+
+    /// Sets [`api_version`](#method.api_version) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_api_version(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.api_version = value.map(|value| value.into());
+        self
+    }
+
+    /// Kind is a string value representing the REST resource this object represents. Servers may infer this from the endpoint the client submits requests to. Cannot be updated. In CamelCase. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#types-kinds
+    #[inline]
+    pub fn kind(mut self, value: impl Into<String>) -> Self {
+        self.body.kind = Some(value.into());
+        self
+    }
+
+    /// Sets [`kind`](#method.kind) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_kind(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.kind = value.map(|value| value.into());
+        self
+    }
+
+    /// Standard object metadata; More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#metadata.
+    #[inline]
+    pub fn metadata(mut self, value: crate::io::k8s::apimachinery::pkg::apis::meta::v1::object_meta::ObjectMeta) -> Self {
+        self.body.metadata = Some(value.into());
+        self
+    }
+
+    /// Sets [`metadata`](#method.metadata) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_metadata(mut self, value: Option<crate::io::k8s::apimachinery::pkg::apis::meta::v1::object_meta::ObjectMeta>) -> Self {
+        self.body.metadata = value.map(|value| value.into());
+        self
+    }
+
+    /// Webhooks is a list of webhooks and the affected resources and operations.
+    #[inline]
+    pub fn webhooks(mut self, value: impl Iterator<Item = crate::io::k8s::api::admissionregistration::v1beta1::webhook::WebhookBuilder<crate::generics::ClientConfigExists, crate::generics::NameExists>>) -> Self {
+        self.body.webhooks = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`webhooks`](#method.webhooks) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_webhooks(mut self, value: Option<impl Iterator<Item = crate::io::k8s::api::admissionregistration::v1beta1::webhook::WebhookBuilder<crate::generics::ClientConfigExists, crate::generics::NameExists>>>) -> Self {
+        self.body.webhooks = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`webhooks`](#method.webhooks), but takes the values as a `Vec`.
+    #[inline]
+    pub fn webhooks_vec(mut self, value: impl Into<Vec<crate::io::k8s::api::admissionregistration::v1beta1::webhook::Webhook>>) -> Self {
+        self.body.webhooks = Some(value.into());
+        self
+    }
+}
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for ValidatingWebhookConfigurationPostBuilder {
+    type Output = crate::io::k8s::api::admissionregistration::v1beta1::validating_webhook_configuration::ValidatingWebhookConfiguration;
+
+    const METHOD: http::Method = http::Method::POST;
+
+    const OPERATION_ID: Option<&'static str> = Some("createAdmissionregistrationV1beta1ValidatingWebhookConfiguration");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok("/apis/admissionregistration.k8s.io/v1beta1/validatingwebhookconfigurations".into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::CONTENT_TYPE.as_str(), "application/json")
+        .json(&self.body)
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("dryRun", self.param_dry_run.as_ref().map(std::string::ToString::to_string)),
+            ("fieldManager", self.param_field_manager.as_ref().map(std::string::ToString::to_string)),
+            ("pretty", self.param_pretty.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Content-Type")
+            || name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<ValidatingWebhookConfigurationPostBuilder> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: ValidatingWebhookConfigurationPostBuilder) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl ValidatingWebhookConfigurationPostBuilder {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = ValidatingWebhookConfigurationPostBuilder {
+            body: crate::cli::read_from_input(matches)?,
+            param_dry_run: matches.and_then(|m| {
+                    m.value_of("dry-run").map(|_| {
+                        value_t!(m, "dry-run", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_field_manager: matches.and_then(|m| {
+                    m.value_of("field-manager").map(|_| {
+                        value_t!(m, "field-manager", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_pretty: matches.and_then(|m| {
+                    m.value_of("pretty").map(|_| {
+                        value_t!(m, "pretty", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder created by [`ValidatingWebhookConfiguration::read_admissionregistration_v1beta1_validating_webhook_configuration`](./struct.ValidatingWebhookConfiguration.html#method.read_admissionregistration_v1beta1_validating_webhook_configuration) method for a `GET` operation associated with `ValidatingWebhookConfiguration`.
+#[repr(transparent)]
+#[derive(Debug, Clone)]
+pub struct ValidatingWebhookConfigurationGetBuilder1<Name> {
+    inner: ValidatingWebhookConfigurationGetBuilder1Container,
+    _param_name: core::marker::PhantomData<Name>,
+}
+
+#[derive(Debug, Default, Clone)]
+struct ValidatingWebhookConfigurationGetBuilder1Container {
+    param_exact: Option<bool>,
+    param_export: Option<bool>,
+    param_name: Option<String>,
+    param_pretty: Option<String>,
+}
+
+impl<Name> ValidatingWebhookConfigurationGetBuilder1<Name> {
+    /// Should the export be exact.  Exact export maintains cluster-specific fields like 'Namespace'. Deprecated. Planned for removal in 1.18.
+    #[inline]
+    pub fn exact(mut self, value: impl Into<bool>) -> Self {
+        self.inner.param_exact = Some(value.into());
+        self
+    }
+
+    /// Sets [`exact`](#method.exact) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_exact(mut self, value: Option<impl Into<bool>>) -> Self {
+        self.inner.param_exact = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`exact`](#method.exact), but takes a reference.
+    #[inline]
+    pub fn exact_ref(self, value: &bool) -> Self {
+        self.exact(*value)
+    }
+
+    /// Should this value be exported.  Export strips fields that a user can not specify. Deprecated. Planned for removal in 1.18.
+    #[inline]
+    pub fn export(mut self, value: impl Into<bool>) -> Self {
+        self.inner.param_export = Some(value.into());
+        self
+    }
+
+    /// Sets [`export`](#method.export) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_export(mut self, value: Option<impl Into<bool>>) -> Self {
+        self.inner.param_export = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`export`](#method.export), but takes a reference.
+    #[inline]
+    pub fn export_ref(self, value: &bool) -> Self {
+        self.export(*value)
+    }
+
+    /// name of the ValidatingWebhookConfiguration
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> ValidatingWebhookConfigurationGetBuilder1<crate::generics::NameExists> {
+        self.inner.param_name = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// If 'true', then the output is pretty printed.
+    #[inline]
+    pub fn pretty(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_pretty = Some(value.into());
+        self
+    }
+
+    /// Sets [`pretty`](#method.pretty) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_pretty(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_pretty = value.map(|value| value.into());
+        self
+    }
+}
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for ValidatingWebhookConfigurationGetBuilder1<crate::generics::NameExists> {
+    type Output = ValidatingWebhookConfiguration;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    const OPERATION_ID: Option<&'static str> = Some("readAdmissionregistrationV1beta1ValidatingWebhookConfiguration");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok(format!("/apis/admissionregistration.k8s.io/v1beta1/validatingwebhookconfigurations/{name}", name=crate::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("exact", self.inner.param_exact.as_ref().map(std::string::ToString::to_string)),
+            ("export", self.inner.param_export.as_ref().map(std::string::ToString::to_string)),
+            ("pretty", self.inner.param_pretty.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<ValidatingWebhookConfigurationGetBuilder1<crate::generics::NameExists>> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: ValidatingWebhookConfigurationGetBuilder1<crate::generics::NameExists>) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl ValidatingWebhookConfigurationGetBuilder1<crate::generics::NameExists> {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = ValidatingWebhookConfigurationGetBuilder1 {
+            inner: ValidatingWebhookConfigurationGetBuilder1Container {
+            param_exact: matches.and_then(|m| {
+                    m.value_of("exact").map(|_| {
+                        value_t!(m, "exact", bool).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_export: matches.and_then(|m| {
+                    m.value_of("export").map(|_| {
+                        value_t!(m, "export", bool).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_name: matches.and_then(|m| {
+                    m.value_of("name").map(|_| {
+                        value_t!(m, "name", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_pretty: matches.and_then(|m| {
+                    m.value_of("pretty").map(|_| {
+                        value_t!(m, "pretty", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            },
+            _param_name: core::marker::PhantomData,
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder created by [`ValidatingWebhookConfiguration::replace_admissionregistration_v1beta1_validating_webhook_configuration`](./struct.ValidatingWebhookConfiguration.html#method.replace_admissionregistration_v1beta1_validating_webhook_configuration) method for a `PUT` operation associated with `ValidatingWebhookConfiguration`.
+#[repr(transparent)]
+#[derive(Debug, Clone)]
+pub struct ValidatingWebhookConfigurationPutBuilder1<Name> {
+    inner: ValidatingWebhookConfigurationPutBuilder1Container,
+    _param_name: core::marker::PhantomData<Name>,
+}
+
+#[derive(Debug, Default, Clone)]
+struct ValidatingWebhookConfigurationPutBuilder1Container {
+    body: self::ValidatingWebhookConfiguration,
+    param_dry_run: Option<String>,
+    param_field_manager: Option<String>,
+    param_name: Option<String>,
+    param_pretty: Option<String>,
+}
+
+impl<Name> ValidatingWebhookConfigurationPutBuilder1<Name> {
+    /// When present, indicates that modifications should not be persisted. An invalid or unrecognized dryRun directive will result in an error response and no further processing of the request. Valid values are: - All: all dry run stages will be processed
+    #[inline]
+    pub fn dry_run(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_dry_run = Some(value.into());
+        self
+    }
+
+    /// Sets [`dry_run`](#method.dry_run) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_dry_run(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_dry_run = value.map(|value| value.into());
+        self
+    }
+
+    /// fieldManager is a name associated with the actor or entity that is making these changes. The value must be less than or 128 characters long, and only contain printable characters, as defined by https://golang.org/pkg/unicode/#IsPrint.
+    #[inline]
+    pub fn field_manager(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_field_manager = Some(value.into());
+        self
+    }
+
+    /// Sets [`field_manager`](#method.field_manager) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_field_manager(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_field_manager = value.map(|value| value.into());
+        self
+    }
+
+    /// name of the ValidatingWebhookConfiguration
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> ValidatingWebhookConfigurationPutBuilder1<crate::generics::NameExists> {
+        self.inner.param_name = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// If 'true', then the output is pretty printed.
+    #[inline]
+    pub fn pretty(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_pretty = Some(value.into());
+        self
+    }
+
+    /// Sets [`pretty`](#method.pretty) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_pretty(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_pretty = value.map(|value| value.into());
+        self
+    }
+
+    /// APIVersion defines the versioned schema of this representation of an object. Servers should convert recognized schemas to the latest internal value, and may reject unrecognized values. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#resources
+    #[inline]
+    pub fn api_version(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.api_version = Some(value.into());
+        self
+    }
+
+    /// Sets [`api_version`](#method.api_version) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_api_version(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.api_version = value.map(|value| value.into());
+        self
+    }
+
+    /// Kind is a string value representing the REST resource this object represents. Servers may infer this from the endpoint the client submits requests to. Cannot be updated. In CamelCase. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#types-kinds
+    #[inline]
+    pub fn kind(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.kind = Some(value.into());
+        self
+    }
+
+    /// Sets [`kind`](#method.kind) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_kind(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.kind = value.map(|value| value.into());
+        self
+    }
+
+    /// Standard object metadata; More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#metadata.
+    #[inline]
+    pub fn metadata(mut self, value: crate::io::k8s::apimachinery::pkg::apis::meta::v1::object_meta::ObjectMeta) -> Self {
+        self.inner.body.metadata = Some(value.into());
+        self
+    }
+
+    /// Sets [`metadata`](#method.metadata) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_metadata(mut self, value: Option<crate::io::k8s::apimachinery::pkg::apis::meta::v1::object_meta::ObjectMeta>) -> Self {
+        self.inner.body.metadata = value.map(|value| value.into());
+        self
+    }
+
+    /// Webhooks is a list of webhooks and the affected resources and operations.
+    #[inline]
+    pub fn webhooks(mut self, value: impl Iterator<Item = crate::io::k8s::api::admissionregistration::v1beta1::webhook::WebhookBuilder<crate::generics::ClientConfigExists, crate::generics::NameExists>>) -> Self {
+        self.inner.body.webhooks = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`webhooks`](#method.webhooks) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_webhooks(mut self, value: Option<impl Iterator<Item = crate::io::k8s::api::admissionregistration::v1beta1::webhook::WebhookBuilder<crate::generics::ClientConfigExists, crate::generics::NameExists>>>) -> Self {
+        self.inner.body.webhooks = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`webhooks`](#method.webhooks), but takes the values as a `Vec`.
+    #[inline]
+    pub fn webhooks_vec(mut self, value: impl Into<Vec<crate::io::k8s::api::admissionregistration::v1beta1::webhook::Webhook>>) -> Self {
+        self.inner.body.webhooks = Some(value.into());
+        self
+    }
+}
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for ValidatingWebhookConfigurationPutBuilder1<crate::generics::NameExists> {
+    type Output = crate::io::k8s::api::admissionregistration::v1beta1::validating_webhook_configuration::ValidatingWebhookConfiguration;
+
+    const METHOD: http::Method = http::Method::PUT;
+
+    const OPERATION_ID: Option<&'static str> = Some("replaceAdmissionregistrationV1beta1ValidatingWebhookConfiguration");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok(format!("/apis/admissionregistration.k8s.io/v1beta1/validatingwebhookconfigurations/{name}", name=crate::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::CONTENT_TYPE.as_str(), "application/json")
+        .json(&self.inner.body)
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("dryRun", self.inner.param_dry_run.as_ref().map(std::string::ToString::to_string)),
+            ("fieldManager", self.inner.param_field_manager.as_ref().map(std::string::ToString::to_string)),
+            ("pretty", self.inner.param_pretty.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Content-Type")
+            || name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<ValidatingWebhookConfigurationPutBuilder1<crate::generics::NameExists>> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: ValidatingWebhookConfigurationPutBuilder1<crate::generics::NameExists>) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl ValidatingWebhookConfigurationPutBuilder1<crate::generics::NameExists> {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = ValidatingWebhookConfigurationPutBuilder1 {
+            inner: ValidatingWebhookConfigurationPutBuilder1Container {
+            body: crate::cli::read_from_input(matches)?,
+            param_dry_run: matches.and_then(|m| {
+                    m.value_of("dry-run").map(|_| {
+                        value_t!(m, "dry-run", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_field_manager: matches.and_then(|m| {
+                    m.value_of("field-manager").map(|_| {
+                        value_t!(m, "field-manager", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_name: matches.and_then(|m| {
+                    m.value_of("name").map(|_| {
+                        value_t!(m, "name", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_pretty: matches.and_then(|m| {
+                    m.value_of("pretty").map(|_| {
+                        value_t!(m, "pretty", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            },
+            _param_name: core::marker::PhantomData,
+        };
+
+        Ok(thing)
+    }
+}
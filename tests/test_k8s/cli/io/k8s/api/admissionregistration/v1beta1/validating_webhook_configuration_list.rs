@@ -0,0 +1,406 @@
+
+/// ValidatingWebhookConfigurationList is a list of ValidatingWebhookConfiguration.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct ValidatingWebhookConfigurationList {
+    /// APIVersion defines the versioned schema of this representation of an object. Servers should convert recognized schemas to the latest internal value, and may reject unrecognized values. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#resources
+    #[serde(rename = "apiVersion", skip_serializing_if = "Option::is_none")]
+    pub api_version: Option<String>,
+    /// List of ValidatingWebhookConfiguration.
+    pub items: Vec<crate::io::k8s::api::admissionregistration::v1beta1::validating_webhook_configuration::ValidatingWebhookConfiguration>,
+    /// Kind is a string value representing the REST resource this object represents. Servers may infer this from the endpoint the client submits requests to. Cannot be updated. In CamelCase. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#types-kinds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub kind: Option<String>,
+    /// Standard list metadata. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#types-kinds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<crate::io::k8s::apimachinery::pkg::apis::meta::v1::list_meta::ListMeta>,
+}
+
+impl ValidatingWebhookConfigurationList {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> ValidatingWebhookConfigurationListBuilder<crate::generics::MissingItems> {
+        ValidatingWebhookConfigurationListBuilder {
+            body: Default::default(),
+            _items: core::marker::PhantomData,
+        }
+    }
+
+    /// list or watch objects of kind ValidatingWebhookConfiguration
+    #[inline]
+    pub fn list_admissionregistration_v1beta1_validating_webhook_configuration() -> ValidatingWebhookConfigurationListGetBuilder {
+        ValidatingWebhookConfigurationListGetBuilder {
+            param_allow_watch_bookmarks: None,
+            param_continue: None,
+            param_field_selector: None,
+            param_label_selector: None,
+            param_limit: None,
+            param_resource_version: None,
+            param_timeout_seconds: None,
+            param_watch: None,
+            param_pretty: None,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> ValidatingWebhookConfigurationListBuilder<crate::generics::ItemsExists>
+    where
+        Self: Clone,
+    {
+        ValidatingWebhookConfigurationListBuilder {
+            body: self.clone(),
+            _items: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<ValidatingWebhookConfigurationList> for ValidatingWebhookConfigurationListBuilder<crate::generics::ItemsExists> {
+    fn into(self) -> ValidatingWebhookConfigurationList {
+        self.body
+    }
+}
+
+/// Builder for [`ValidatingWebhookConfigurationList`](./struct.ValidatingWebhookConfigurationList.html) object.
+#[derive(Debug, Clone)]
+pub struct ValidatingWebhookConfigurationListBuilder<Items> {
+    body: self::ValidatingWebhookConfigurationList,
+    _items: core::marker::PhantomData<Items>,
+}
+
+impl<Items> ValidatingWebhookConfigurationListBuilder<Items> {
+    /// APIVersion defines the versioned schema of this representation of an object. Servers should convert recognized schemas to the latest internal value, and may reject unrecognized values. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#resources
+    #[inline]
+    pub fn api_version(mut self, value: impl Into<String>) -> Self {
+        self.body.api_version = Some(value.into());
+        self
+    }
+
+    /// Sets [`api_version`](#method.api_version) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_api_version(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.api_version = value.map(|value| value.into());
+        self
+    }
+
+    /// List of ValidatingWebhookConfiguration.
+    #[inline]
+    pub fn items(mut self, value: impl Iterator<Item = crate::io::k8s::api::admissionregistration::v1beta1::validating_webhook_configuration::ValidatingWebhookConfiguration>) -> ValidatingWebhookConfigurationListBuilder<crate::generics::ItemsExists> {
+        self.body.items = value.map(|value| value.into()).collect::<Vec<_>>().into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Same as [`items`](#method.items), but takes the values as a `Vec`.
+    #[inline]
+    pub fn items_vec(mut self, value: impl Into<Vec<crate::io::k8s::api::admissionregistration::v1beta1::validating_webhook_configuration::ValidatingWebhookConfiguration>>) -> ValidatingWebhookConfigurationListBuilder<crate::generics::ItemsExists> {
+        self.body.items = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Kind is a string value representing the REST resource this object represents. Servers may infer this from the endpoint the client submits requests to. Cannot be updated. In CamelCase. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#types-kinds
+    #[inline]
+    pub fn kind(mut self, value: impl Into<String>) -> Self {
+        self.body.kind = Some(value.into());
+        self
+    }
+
+    /// Sets [`kind`](#method.kind) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_kind(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.kind = value.map(|value| value.into());
+        self
+    }
+
+    /// Standard list metadata. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#types-kinds
+    #[inline]
+    pub fn metadata(mut self, value: crate::io::k8s::apimachinery::pkg::apis::meta::v1::list_meta::ListMeta) -> Self {
+        self.body.metadata = Some(value.into());
+        self
+    }
+
+    /// Sets [`metadata`](#method.metadata) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_metadata(mut self, value: Option<crate::io::k8s::apimachinery::pkg::apis::meta::v1::list_meta::ListMeta>) -> Self {
+        self.body.metadata = value.map(|value| value.into());
+        self
+    }
+}
+
+/// Builder created by [`ValidatingWebhookConfigurationList::list_admissionregistration_v1beta1_validating_webhook_configuration`](./struct.ValidatingWebhookConfigurationList.html#method.list_admissionregistration_v1beta1_validating_webhook_configuration) method for a `GET` operation associated with `ValidatingWebhookConfigurationList`.
+#[derive(Debug, Default, Clone)]
+pub struct ValidatingWebhookConfigurationListGetBuilder {
+    param_allow_watch_bookmarks: Option<bool>,
+    param_continue: Option<String>,
+    param_field_selector: Option<String>,
+    param_label_selector: Option<String>,
+    param_limit: Option<i64>,
+    param_resource_version: Option<String>,
+    param_timeout_seconds: Option<i64>,
+    param_watch: Option<bool>,
+    param_pretty: Option<String>,
+}
+
+impl ValidatingWebhookConfigurationListGetBuilder {
+    /// allowWatchBookmarks requests watch events with type "BOOKMARK". Servers that do not implement bookmarks may ignore this flag and bookmarks are sent at the server's discretion. Clients should not assume bookmarks are returned at any specific interval, nor may they assume the server will send any BOOKMARK event during a session. If this is not a watch, this field is ignored. If the feature gate WatchBookmarks is not enabled in apiserver, this field is ignored.
+    ///
+    /// This field is alpha and can be changed or removed without notice.
+    #[inline]
+    pub fn allow_watch_bookmarks(mut self, value: impl Into<bool>) -> Self {
+        self.param_allow_watch_bookmarks = Some(value.into());
+        self
+    }
+
+    /// Sets [`allow_watch_bookmarks`](#method.allow_watch_bookmarks) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_allow_watch_bookmarks(mut self, value: Option<impl Into<bool>>) -> Self {
+        self.param_allow_watch_bookmarks = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`allow_watch_bookmarks`](#method.allow_watch_bookmarks), but takes a reference.
+    #[inline]
+    pub fn allow_watch_bookmarks_ref(self, value: &bool) -> Self {
+        self.allow_watch_bookmarks(*value)
+    }
+
+    /// The continue option should be set when retrieving more results from the server. Since this value is server defined, clients may only use the continue value from a previous query result with identical query parameters (except for the value of continue) and the server may reject a continue value it does not recognize. If the specified continue value is no longer valid whether due to expiration (generally five to fifteen minutes) or a configuration change on the server, the server will respond with a 410 ResourceExpired error together with a continue token. If the client needs a consistent list, it must restart their list without the continue field. Otherwise, the client may send another list request with the token received with the 410 error, the server will respond with a list starting from the next key, but from the latest snapshot, which is inconsistent from the previous list results - objects that are created, modified, or deleted after the first list request will be included in the response, as long as their keys are after the "next key".
+    ///
+    /// This field is not supported when watch is true. Clients may start a watch from the last resourceVersion value returned by the server and not miss any modifications.
+    #[inline]
+    pub fn continue_(mut self, value: impl Into<String>) -> Self {
+        self.param_continue = Some(value.into());
+        self
+    }
+
+    /// Sets [`continue_`](#method.continue_) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_continue(mut self, value: Option<impl Into<String>>) -> Self {
+        self.param_continue = value.map(|value| value.into());
+        self
+    }
+
+    /// A selector to restrict the list of returned objects by their fields. Defaults to everything.
+    #[inline]
+    pub fn field_selector(mut self, value: impl Into<String>) -> Self {
+        self.param_field_selector = Some(value.into());
+        self
+    }
+
+    /// Sets [`field_selector`](#method.field_selector) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_field_selector(mut self, value: Option<impl Into<String>>) -> Self {
+        self.param_field_selector = value.map(|value| value.into());
+        self
+    }
+
+    /// A selector to restrict the list of returned objects by their labels. Defaults to everything.
+    #[inline]
+    pub fn label_selector(mut self, value: impl Into<String>) -> Self {
+        self.param_label_selector = Some(value.into());
+        self
+    }
+
+    /// Sets [`label_selector`](#method.label_selector) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_label_selector(mut self, value: Option<impl Into<String>>) -> Self {
+        self.param_label_selector = value.map(|value| value.into());
+        self
+    }
+
+    /// limit is a maximum number of responses to return for a list call. If more items exist, the server will set the `continue` field on the list metadata to a value that can be used with the same initial query to retrieve the next set of results. Setting a limit may return fewer than the requested amount of items (up to zero items) in the event all requested objects are filtered out and clients should only use the presence of the continue field to determine whether more results are available. Servers may choose not to support the limit argument and will return all of the available results. If limit is specified and the continue field is empty, clients may assume that no more results are available. This field is not supported if watch is true.
+    ///
+    /// The server guarantees that the objects returned when using continue will be identical to issuing a single list call without a limit - that is, no objects created, modified, or deleted after the first request is issued will be included in any subsequent continued requests. This is sometimes referred to as a consistent snapshot, and ensures that a client that is using limit to receive smaller chunks of a very large result can ensure they see all possible objects. If objects are updated during a chunked list the version of the object that was present at the time the first list result was calculated is returned.
+    #[inline]
+    pub fn limit(mut self, value: impl Into<i64>) -> Self {
+        self.param_limit = Some(value.into());
+        self
+    }
+
+    /// Sets [`limit`](#method.limit) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_limit(mut self, value: Option<impl Into<i64>>) -> Self {
+        self.param_limit = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`limit`](#method.limit), but takes a reference.
+    #[inline]
+    pub fn limit_ref(self, value: &i64) -> Self {
+        self.limit(*value)
+    }
+
+    /// When specified with a watch call, shows changes that occur after that particular version of a resource. Defaults to changes from the beginning of history. When specified for list: - if unset, then the result is returned from remote storage based on quorum-read flag; - if it's 0, then we simply return what we currently have in cache, no guarantee; - if set to non zero, then the result is at least as fresh as given rv.
+    #[inline]
+    pub fn resource_version(mut self, value: impl Into<String>) -> Self {
+        self.param_resource_version = Some(value.into());
+        self
+    }
+
+    /// Sets [`resource_version`](#method.resource_version) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_resource_version(mut self, value: Option<impl Into<String>>) -> Self {
+        self.param_resource_version = value.map(|value| value.into());
+        self
+    }
+
+    /// Timeout for the list/watch call. This limits the duration of the call, regardless of any activity or inactivity.
+    #[inline]
+    pub fn timeout_seconds(mut self, value: impl Into<i64>) -> Self {
+        self.param_timeout_seconds = Some(value.into());
+        self
+    }
+
+    /// Sets [`timeout_seconds`](#method.timeout_seconds) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_timeout_seconds(mut self, value: Option<impl Into<i64>>) -> Self {
+        self.param_timeout_seconds = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`timeout_seconds`](#method.timeout_seconds), but takes a reference.
+    #[inline]
+    pub fn timeout_seconds_ref(self, value: &i64) -> Self {
+        self.timeout_seconds(*value)
+    }
+
+    /// Watch for changes to the described resources and return them as a stream of add, update, and remove notifications. Specify resourceVersion.
+    #[inline]
+    pub fn watch(mut self, value: impl Into<bool>) -> Self {
+        self.param_watch = Some(value.into());
+        self
+    }
+
+    /// Sets [`watch`](#method.watch) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_watch(mut self, value: Option<impl Into<bool>>) -> Self {
+        self.param_watch = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`watch`](#method.watch), but takes a reference.
+    #[inline]
+    pub fn watch_ref(self, value: &bool) -> Self {
+        self.watch(*value)
+    }
+
+    /// If 'true', then the output is pretty printed.
+    #[inline]
+    pub fn pretty(mut self, value: impl Into<String>) -> Self {
+        self.param_pretty = Some(value.into());
+        self
+    }
+
+    /// Sets [`pretty`](#method.pretty) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_pretty(mut self, value: Option<impl Into<String>>) -> Self {
+        self.param_pretty = value.map(|value| value.into());
+        self
+    }
+}
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for ValidatingWebhookConfigurationListGetBuilder {
+    type Output = ValidatingWebhookConfigurationList;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    const OPERATION_ID: Option<&'static str> = Some("listAdmissionregistrationV1beta1ValidatingWebhookConfiguration");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok("/apis/admissionregistration.k8s.io/v1beta1/validatingwebhookconfigurations".into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("allowWatchBookmarks", self.param_allow_watch_bookmarks.as_ref().map(std::string::ToString::to_string)),
+            ("continue", self.param_continue.as_ref().map(std::string::ToString::to_string)),
+            ("fieldSelector", self.param_field_selector.as_ref().map(std::string::ToString::to_string)),
+            ("labelSelector", self.param_label_selector.as_ref().map(std::string::ToString::to_string)),
+            ("limit", self.param_limit.as_ref().map(std::string::ToString::to_string)),
+            ("resourceVersion", self.param_resource_version.as_ref().map(std::string::ToString::to_string)),
+            ("timeoutSeconds", self.param_timeout_seconds.as_ref().map(std::string::ToString::to_string)),
+            ("watch", self.param_watch.as_ref().map(std::string::ToString::to_string)),
+            ("pretty", self.param_pretty.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<ValidatingWebhookConfigurationListGetBuilder> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: ValidatingWebhookConfigurationListGetBuilder) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl ValidatingWebhookConfigurationListGetBuilder {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = ValidatingWebhookConfigurationListGetBuilder {
+            param_allow_watch_bookmarks: matches.and_then(|m| {
+                    m.value_of("allow-watch-bookmarks").map(|_| {
+                        value_t!(m, "allow-watch-bookmarks", bool).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_continue: matches.and_then(|m| {
+                    m.value_of("continue").map(|_| {
+                        value_t!(m, "continue", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_field_selector: matches.and_then(|m| {
+                    m.value_of("field-selector").map(|_| {
+                        value_t!(m, "field-selector", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_label_selector: matches.and_then(|m| {
+                    m.value_of("label-selector").map(|_| {
+                        value_t!(m, "label-selector", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_limit: matches.and_then(|m| {
+                    m.value_of("limit").map(|_| {
+                        value_t!(m, "limit", i64).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_resource_version: matches.and_then(|m| {
+                    m.value_of("resource-version").map(|_| {
+                        value_t!(m, "resource-version", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_timeout_seconds: matches.and_then(|m| {
+                    m.value_of("timeout-seconds").map(|_| {
+                        value_t!(m, "timeout-seconds", i64).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_watch: matches.and_then(|m| {
+                    m.value_of("watch").map(|_| {
+                        value_t!(m, "watch", bool).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_pretty: matches.and_then(|m| {
+                    m.value_of("pretty").map(|_| {
+                        value_t!(m, "pretty", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+        };
+
+        Ok(thing)
+    }
+}
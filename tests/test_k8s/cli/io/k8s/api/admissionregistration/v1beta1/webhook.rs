@@ -0,0 +1,247 @@
+
+/// Webhook describes an admission webhook and the resources and operations it applies to.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct Webhook {
+    /// AdmissionReviewVersions is an ordered list of preferred `AdmissionReview` versions the Webhook expects. API server will try to use first version in the list which it supports. If none of the versions specified in this list supported by API server, validation will fail for this object. If a persisted webhook configuration specifies allowed versions and does not include any versions known to the API Server, calls to the webhook will fail and be subject to the failure policy. Default to `['v1beta1']`.
+    #[serde(rename = "admissionReviewVersions", skip_serializing_if = "Option::is_none")]
+    pub admission_review_versions: Option<Vec<String>>,
+    /// ClientConfig defines how to communicate with the hook. Required
+    #[serde(rename = "clientConfig")]
+    pub client_config: crate::io::k8s::api::admissionregistration::v1beta1::webhook_client_config::WebhookClientConfig,
+    /// FailurePolicy defines how unrecognized errors from the admission endpoint are handled - allowed values are Ignore or Fail. Defaults to Ignore.
+    #[serde(rename = "failurePolicy", skip_serializing_if = "Option::is_none")]
+    pub failure_policy: Option<String>,
+    /// The name of the admission webhook. Name should be fully qualified, e.g., imagepolicy.kubernetes.io, where "imagepolicy" is the name of the webhook, and kubernetes.io is the name of the organization. Required.
+    pub name: String,
+    /// NamespaceSelector decides whether to run the webhook on an object based on whether the namespace for that object matches the selector. If the object itself is a namespace, the matching is performed on object.metadata.labels. If the object is another cluster scoped resource, it never skips the webhook.
+    ///
+    /// For example, to run the webhook on any objects whose namespace is not associated with "runlevel" of "0" or "1";  you will set the selector as follows: "namespaceSelector": {
+    ///   "matchExpressions": \[
+    ///     {
+    ///       "key": "runlevel",
+    ///       "operator": "NotIn",
+    ///       "values": \[
+    ///         "0",
+    ///         "1"
+    ///       \]
+    ///     }
+    ///   \]
+    /// }
+    ///
+    /// If instead you want to only run the webhook on any objects whose namespace is associated with the "environment" of "prod" or "staging"; you will set the selector as follows: "namespaceSelector": {
+    ///   "matchExpressions": \[
+    ///     {
+    ///       "key": "environment",
+    ///       "operator": "In",
+    ///       "values": \[
+    ///         "prod",
+    ///         "staging"
+    ///       \]
+    ///     }
+    ///   \]
+    /// }
+    ///
+    /// See https://kubernetes.io/docs/concepts/overview/working-with-objects/labels/ for more examples of label selectors.
+    ///
+    /// Default to the empty LabelSelector, which matches everything.
+    #[serde(rename = "namespaceSelector", skip_serializing_if = "Option::is_none")]
+    pub namespace_selector: Option<crate::io::k8s::apimachinery::pkg::apis::meta::v1::label_selector::LabelSelector>,
+    /// Rules describes what operations on what resources/subresources the webhook cares about. The webhook cares about an operation if it matches \_any\_ Rule. However, in order to prevent ValidatingAdmissionWebhooks and MutatingAdmissionWebhooks from putting the cluster in a state which cannot be recovered from without completely disabling the plugin, ValidatingAdmissionWebhooks and MutatingAdmissionWebhooks are never called on admission requests for ValidatingWebhookConfiguration and MutatingWebhookConfiguration objects.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rules: Option<Vec<crate::io::k8s::api::admissionregistration::v1beta1::rule_with_operations::RuleWithOperations>>,
+    /// SideEffects states whether this webhookk has side effects. Acceptable values are: Unknown, None, Some, NoneOnDryRun Webhooks with side effects MUST implement a reconciliation system, since a request may be rejected by a future step in the admission change and the side effects therefore need to be undone. Requests with the dryRun attribute will be auto-rejected if they match a webhook with sideEffects == Unknown or Some. Defaults to Unknown.
+    #[serde(rename = "sideEffects", skip_serializing_if = "Option::is_none")]
+    pub side_effects: Option<String>,
+    /// TimeoutSeconds specifies the timeout for this webhook. After the timeout passes, the webhook call will be ignored or the API call will fail based on the failure policy. The timeout value must be between 1 and 30 seconds. Default to 30 seconds.
+    #[serde(rename = "timeoutSeconds", skip_serializing_if = "Option::is_none")]
+    pub timeout_seconds: Option<i32>,
+}
+
+impl Webhook {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> WebhookBuilder<crate::generics::MissingClientConfig, crate::generics::MissingName> {
+        WebhookBuilder {
+            body: Default::default(),
+            _client_config: core::marker::PhantomData,
+            _name: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> WebhookBuilder<crate::generics::ClientConfigExists, crate::generics::NameExists>
+    where
+        Self: Clone,
+    {
+        WebhookBuilder {
+            body: self.clone(),
+            _client_config: core::marker::PhantomData,
+            _name: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<Webhook> for WebhookBuilder<crate::generics::ClientConfigExists, crate::generics::NameExists> {
+    fn into(self) -> Webhook {
+        self.body
+    }
+}
+
+/// Builder for [`Webhook`](./struct.Webhook.html) object.
+#[derive(Debug, Clone)]
+pub struct WebhookBuilder<ClientConfig, Name> {
+    body: self::Webhook,
+    _client_config: core::marker::PhantomData<ClientConfig>,
+    _name: core::marker::PhantomData<Name>,
+}
+
+impl<ClientConfig, Name> WebhookBuilder<ClientConfig, Name> {
+    /// AdmissionReviewVersions is an ordered list of preferred `AdmissionReview` versions the Webhook expects. API server will try to use first version in the list which it supports. If none of the versions specified in this list supported by API server, validation will fail for this object. If a persisted webhook configuration specifies allowed versions and does not include any versions known to the API Server, calls to the webhook will fail and be subject to the failure policy. Default to `['v1beta1']`.
+    #[inline]
+    pub fn admission_review_versions(mut self, value: impl Iterator<Item = impl Into<String>>) -> Self {
+        self.body.admission_review_versions = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`admission_review_versions`](#method.admission_review_versions) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_admission_review_versions(mut self, value: Option<impl Iterator<Item = impl Into<String>>>) -> Self {
+        self.body.admission_review_versions = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`admission_review_versions`](#method.admission_review_versions), but takes the values as a `Vec`.
+    #[inline]
+    pub fn admission_review_versions_vec(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.body.admission_review_versions = Some(value.into());
+        self
+    }
+
+    /// ClientConfig defines how to communicate with the hook. Required
+    #[inline]
+    pub fn client_config(mut self, value: crate::io::k8s::api::admissionregistration::v1beta1::webhook_client_config::WebhookClientConfig) -> WebhookBuilder<crate::generics::ClientConfigExists, Name> {
+        self.body.client_config = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// FailurePolicy defines how unrecognized errors from the admission endpoint are handled - allowed values are Ignore or Fail. Defaults to Ignore.
+    #[inline]
+    pub fn failure_policy(mut self, value: impl Into<String>) -> Self {
+        self.body.failure_policy = Some(value.into());
+        self
+    }
+
+    /// Sets [`failure_policy`](#method.failure_policy) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_failure_policy(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.failure_policy = value.map(|value| value.into());
+        self
+    }
+
+    /// The name of the admission webhook. Name should be fully qualified, e.g., imagepolicy.kubernetes.io, where "imagepolicy" is the name of the webhook, and kubernetes.io is the name of the organization. Required.
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> WebhookBuilder<ClientConfig, crate::generics::NameExists> {
+        self.body.name = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// NamespaceSelector decides whether to run the webhook on an object based on whether the namespace for that object matches the selector. If the object itself is a namespace, the matching is performed on object.metadata.labels. If the object is another cluster scoped resource, it never skips the webhook.
+    ///
+    /// For example, to run the webhook on any objects whose namespace is not associated with "runlevel" of "0" or "1";  you will set the selector as follows: "namespaceSelector": {
+    ///   "matchExpressions": \[
+    ///     {
+    ///       "key": "runlevel",
+    ///       "operator": "NotIn",
+    ///       "values": \[
+    ///         "0",
+    ///         "1"
+    ///       \]
+    ///     }
+    ///   \]
+    /// }
+    ///
+    /// If instead you want to only run the webhook on any objects whose namespace is associated with the "environment" of "prod" or "staging"; you will set the selector as follows: "namespaceSelector": {
+    ///   "matchExpressions": \[
+    ///     {
+    ///       "key": "environment",
+    ///       "operator": "In",
+    ///       "values": \[
+    ///         "prod",
+    ///         "staging"
+    ///       \]
+    ///     }
+    ///   \]
+    /// }
+    ///
+    /// See https://kubernetes.io/docs/concepts/overview/working-with-objects/labels/ for more examples of label selectors.
+    ///
+    /// Default to the empty LabelSelector, which matches everything.
+    #[inline]
+    pub fn namespace_selector(mut self, value: crate::io::k8s::apimachinery::pkg::apis::meta::v1::label_selector::LabelSelector) -> Self {
+        self.body.namespace_selector = Some(value.into());
+        self
+    }
+
+    /// Sets [`namespace_selector`](#method.namespace_selector) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_namespace_selector(mut self, value: Option<crate::io::k8s::apimachinery::pkg::apis::meta::v1::label_selector::LabelSelector>) -> Self {
+        self.body.namespace_selector = value.map(|value| value.into());
+        self
+    }
+
+    /// Rules describes what operations on what resources/subresources the webhook cares about. The webhook cares about an operation if it matches \_any\_ Rule. However, in order to prevent ValidatingAdmissionWebhooks and MutatingAdmissionWebhooks from putting the cluster in a state which cannot be recovered from without completely disabling the plugin, ValidatingAdmissionWebhooks and MutatingAdmissionWebhooks are never called on admission requests for ValidatingWebhookConfiguration and MutatingWebhookConfiguration objects.
+    #[inline]
+    pub fn rules(mut self, value: impl Iterator<Item = crate::io::k8s::api::admissionregistration::v1beta1::rule_with_operations::RuleWithOperations>) -> Self {
+        self.body.rules = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`rules`](#method.rules) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_rules(mut self, value: Option<impl Iterator<Item = crate::io::k8s::api::admissionregistration::v1beta1::rule_with_operations::RuleWithOperations>>) -> Self {
+        self.body.rules = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`rules`](#method.rules), but takes the values as a `Vec`.
+    #[inline]
+    pub fn rules_vec(mut self, value: impl Into<Vec<crate::io::k8s::api::admissionregistration::v1beta1::rule_with_operations::RuleWithOperations>>) -> Self {
+        self.body.rules = Some(value.into());
+        self
+    }
+
+    /// SideEffects states whether this webhookk has side effects. Acceptable values are: Unknown, None, Some, NoneOnDryRun Webhooks with side effects MUST implement a reconciliation system, since a request may be rejected by a future step in the admission change and the side effects therefore need to be undone. Requests with the dryRun attribute will be auto-rejected if they match a webhook with sideEffects == Unknown or Some. Defaults to Unknown.
+    #[inline]
+    pub fn side_effects(mut self, value: impl Into<String>) -> Self {
+        self.body.side_effects = Some(value.into());
+        self
+    }
+
+    /// Sets [`side_effects`](#method.side_effects) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_side_effects(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.side_effects = value.map(|value| value.into());
+        self
+    }
+
+    /// TimeoutSeconds specifies the timeout for this webhook. After the timeout passes, the webhook call will be ignored or the API call will fail based on the failure policy. The timeout value must be between 1 and 30 seconds. Default to 30 seconds.
+    #[inline]
+    pub fn timeout_seconds(mut self, value: impl Into<i32>) -> Self {
+        self.body.timeout_seconds = Some(value.into());
+        self
+    }
+
+    /// Sets [`timeout_seconds`](#method.timeout_seconds) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_timeout_seconds(mut self, value: Option<impl Into<i32>>) -> Self {
+        self.body.timeout_seconds = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`timeout_seconds`](#method.timeout_seconds), but takes a reference.
+    #[inline]
+    pub fn timeout_seconds_ref(self, value: &i32) -> Self {
+        self.timeout_seconds(*value)
+    }
+}
@@ -0,0 +1,12 @@
+
+pub mod v1 {
+    include!("./v1/mod.rs");
+}
+
+pub mod v1beta1 {
+    include!("./v1beta1/mod.rs");
+}
+
+pub mod v1beta2 {
+    include!("./v1beta2/mod.rs");
+}
@@ -0,0 +1,791 @@
+
+/// ControllerRevision implements an immutable snapshot of state data. Clients are responsible for serializing and deserializing the objects that contain their internal state. Once a ControllerRevision has been successfully created, it can not be updated. The API Server will fail validation of all requests that attempt to mutate the Data field. ControllerRevisions may, however, be deleted. Note that, due to its use by both the DaemonSet and StatefulSet controllers for update and rollback, this object is beta. However, it may be subject to name and representation changes in future releases, and clients should not depend on its stability. It is primarily for internal use by controllers.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct ControllerRevision {
+    /// APIVersion defines the versioned schema of this representation of an object. Servers should convert recognized schemas to the latest internal value, and may reject unrecognized values. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#resources
+    #[serde(rename = "apiVersion", skip_serializing_if = "Option::is_none")]
+    pub api_version: Option<String>,
+    /// Data is the serialized representation of the state.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<crate::io::k8s::apimachinery::pkg::runtime::raw_extension::RawExtension>,
+    /// Kind is a string value representing the REST resource this object represents. Servers may infer this from the endpoint the client submits requests to. Cannot be updated. In CamelCase. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#types-kinds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub kind: Option<String>,
+    /// Standard object's metadata. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#metadata
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<crate::io::k8s::apimachinery::pkg::apis::meta::v1::object_meta::ObjectMeta>,
+    /// Revision indicates the revision of the state represented by Data.
+    pub revision: i64,
+}
+
+impl ControllerRevision {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> ControllerRevisionBuilder<crate::generics::MissingRevision> {
+        ControllerRevisionBuilder {
+            body: Default::default(),
+            _revision: core::marker::PhantomData,
+        }
+    }
+
+    /// create a ControllerRevision
+    #[inline]
+    pub fn create_apps_v1_namespaced_controller_revision() -> ControllerRevisionPostBuilder<crate::generics::MissingNamespace, crate::generics::MissingRevision> {
+        ControllerRevisionPostBuilder {
+            inner: Default::default(),
+            _param_namespace: core::marker::PhantomData,
+            _revision: core::marker::PhantomData,
+        }
+    }
+
+    /// read the specified ControllerRevision
+    #[inline]
+    pub fn read_apps_v1_namespaced_controller_revision() -> ControllerRevisionGetBuilder1<crate::generics::MissingName, crate::generics::MissingNamespace> {
+        ControllerRevisionGetBuilder1 {
+            inner: Default::default(),
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        }
+    }
+
+    /// replace the specified ControllerRevision
+    #[inline]
+    pub fn replace_apps_v1_namespaced_controller_revision() -> ControllerRevisionPutBuilder1<crate::generics::MissingName, crate::generics::MissingNamespace, crate::generics::MissingRevision> {
+        ControllerRevisionPutBuilder1 {
+            inner: Default::default(),
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+            _revision: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> ControllerRevisionBuilder<crate::generics::RevisionExists>
+    where
+        Self: Clone,
+    {
+        ControllerRevisionBuilder {
+            body: self.clone(),
+            _revision: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<ControllerRevision> for ControllerRevisionBuilder<crate::generics::RevisionExists> {
+    fn into(self) -> ControllerRevision {
+        self.body
+    }
+}
+
+impl Into<ControllerRevision> for ControllerRevisionPostBuilder<crate::generics::NamespaceExists, crate::generics::RevisionExists> {
+    fn into(self) -> ControllerRevision {
+        self.inner.body
+    }
+}
+
+impl Into<ControllerRevision> for ControllerRevisionPutBuilder1<crate::generics::NameExists, crate::generics::NamespaceExists, crate::generics::RevisionExists> {
+    fn into(self) -> ControllerRevision {
+        self.inner.body
+    }
+}
+
+/// Builder for [`ControllerRevision`](./struct.ControllerRevision.html) object.
+#[derive(Debug, Clone)]
+pub struct ControllerRevisionBuilder<Revision> {
+    body: self::ControllerRevision,
+    _revision: core::marker::PhantomData<Revision>,
+}
+
+impl<Revision> ControllerRevisionBuilder<Revision> {
+    /// APIVersion defines the versioned schema of this representation of an object. Servers should convert recognized schemas to the latest internal value, and may reject unrecognized values. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#resources
+    #[inline]
+    pub fn api_version(mut self, value: impl Into<String>) -> Self {
+        self.body.api_version = Some(value.into());
+        self
+    }
+
+    /// Sets [`api_version`](#method.api_version) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_api_version(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.api_version = value.map(|value| value.into());
+        self
+    }
+
+    /// Data is the serialized representation of the state.
+    #[inline]
+    pub fn data(mut self, value: crate::io::k8s::apimachinery::pkg::runtime::raw_extension::RawExtensionBuilder<crate::generics::RawExists>) -> Self {
+        self.body.data = Some(value.into());
+        self
+    }
+
+    /// Sets [`data`](#method.data) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_data(mut self, value: Option<crate::io::k8s::apimachinery::pkg::runtime::raw_extension::RawExtensionBuilder<crate::generics::RawExists>>) -> Self {
+        self.body.data = value.map(|value| value.into());
+        self
+    }
+
+    /// Kind is a string value representing the REST resource this object represents. Servers may infer this from the endpoint the client submits requests to. Cannot be updated. In CamelCase. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#types-kinds
+    #[inline]
+    pub fn kind(mut self, value: impl Into<String>) -> Self {
+        self.body.kind = Some(value.into());
+        self
+    }
+
+    /// Sets [`kind`](#method.kind) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_kind(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.kind = value.map(|value| value.into());
+        self
+    }
+
+    /// Standard object's metadata. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#metadata
+    #[inline]
+    pub fn metadata(mut self, value: crate::io::k8s::apimachinery::pkg::apis::meta::v1::object_meta::ObjectMeta) -> Self {
+        self.body.metadata = Some(value.into());
+        self
+    }
+
+    /// Sets [`metadata`](#method.metadata) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_metadata(mut self, value: Option<crate::io::k8s::apimachinery::pkg::apis::meta::v1::object_meta::ObjectMeta>) -> Self {
+        self.body.metadata = value.map(|value| value.into());
+        self
+    }
+
+    /// Revision indicates the revision of the state represented by Data.
+    #[inline]
+    pub fn revision(mut self, value: impl Into<i64>) -> ControllerRevisionBuilder<crate::generics::RevisionExists> {
+        self.body.revision = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Same as [`revision`](#method.revision), but takes a reference.
+    #[inline]
+    pub fn revision_ref(self, value: &i64) -> ControllerRevisionBuilder<crate::generics::RevisionExists> {
+        self.revision(*value)
+    }
+}
+
+/// Builder created by [`ControllerRevision::create_apps_v1_namespaced_controller_revision`](./struct.ControllerRevision.html#method.create_apps_v1_namespaced_controller_revision) method for a `POST` operation associated with `ControllerRevision`.
+#[repr(transparent)]
+#[derive(Debug, Clone)]
+pub struct ControllerRevisionPostBuilder<Namespace, Revision> {
+    inner: ControllerRevisionPostBuilderContainer,
+    _param_namespace: core::marker::PhantomData<Namespace>,
+    _revision: core::marker::PhantomData<Revision>,
+}
+
+#[derive(Debug, Default, Clone)]
+struct ControllerRevisionPostBuilderContainer {
+    body: self::ControllerRevision,
+    param_dry_run: Option<String>,
+    param_field_manager: Option<String>,
+    param_namespace: Option<String>,
+    param_pretty: Option<String>,
+}
+
+impl<Namespace, Revision> ControllerRevisionPostBuilder<Namespace, Revision> {
+    /// When present, indicates that modifications should not be persisted. An invalid or unrecognized dryRun directive will result in an error response and no further processing of the request. Valid values are: - All: all dry run stages will be processed
+    #[inline]
+    pub fn dry_run(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_dry_run = Some(value.into());
+        self
+    }
+
+    /// Sets [`dry_run`](#method.dry_run) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_dry_run(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_dry_run = value.map(|value| value.into());
+        self
+    }
+
+    /// fieldManager is a name associated with the actor or entity that is making these changes. The value must be less than or 128 characters long, and only contain printable characters, as defined by https://golang.org/pkg/unicode/#IsPrint.
+    #[inline]
+    pub fn field_manager(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_field_manager = Some(value.into());
+        self
+    }
+
+    /// Sets [`field_manager`](#method.field_manager) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_field_manager(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_field_manager = value.map(|value| value.into());
+        self
+    }
+
+    /// object name and auth scope, such as for teams and projects
+    #[inline]
+    pub fn namespace(mut self, value: impl Into<String>) -> ControllerRevisionPostBuilder<crate::generics::NamespaceExists, Revision> {
+        self.inner.param_namespace = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// If 'true', then the output is pretty printed.
+    #[inline]
+    pub fn pretty(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_pretty = Some(value.into());
+        self
+    }
+
+    /// Sets [`pretty`](#method.pretty) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_pretty(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_pretty = value.map(|value| value.into());
+        self
+    }
+
+    /// APIVersion defines the versioned schema of this representation of an object. Servers should convert recognized schemas to the latest internal value, and may reject unrecognized values. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#resources
+    #[inline]
+    pub fn api_version(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.api_version = Some(value.into());
+        self
+    }
+
+    /// Sets [`api_version`](#method.api_version) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_api_version(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.api_version = value.map(|value| value.into());
+        self
+    }
+
+    /// Data is the serialized representation of the state.
+    #[inline]
+    pub fn data(mut self, value: crate::io::k8s::apimachinery::pkg::runtime::raw_extension::RawExtensionBuilder<crate::generics::RawExists>) -> Self {
+        self.inner.body.data = Some(value.into());
+        self
+    }
+
+    /// Sets [`data`](#method.data) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_data(mut self, value: Option<crate::io::k8s::apimachinery::pkg::runtime::raw_extension::RawExtensionBuilder<crate::generics::RawExists>>) -> Self {
+        self.inner.body.data = value.map(|value| value.into());
+        self
+    }
+
+    /// Kind is a string value representing the REST resource this object represents. Servers may infer this from the endpoint the client submits requests to. Cannot be updated. In CamelCase. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#types-kinds
+    #[inline]
+    pub fn kind(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.kind = Some(value.into());
+        self
+    }
+
+    /// Sets [`kind`](#method.kind) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_kind(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.kind = value.map(|value| value.into());
+        self
+    }
+
+    /// Standard object's metadata. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#metadata
+    #[inline]
+    pub fn metadata(mut self, value: crate::io::k8s::apimachinery::pkg::apis::meta::v1::object_meta::ObjectMeta) -> Self {
+        self.inner.body.metadata = Some(value.into());
+        self
+    }
+
+    /// Sets [`metadata`](#method.metadata) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_metadata(mut self, value: Option<crate::io::k8s::apimachinery::pkg::apis::meta::v1::object_meta::ObjectMeta>) -> Self {
+        self.inner.body.metadata = value.map(|value| value.into());
+        self
+    }
+
+    /// Revision indicates the revision of the state represented by Data.
+    #[inline]
+    pub fn revision(mut self, value: impl Into<i64>) -> ControllerRevisionPostBuilder<Namespace, crate::generics::RevisionExists> {
+        self.inner.body.revision = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Same as [`revision`](#method.revision), but takes a reference.
+    #[inline]
+    pub fn revision_ref(self, value: &i64) -> ControllerRevisionPostBuilder<Namespace, crate::generics::RevisionExists> {
+        self.revision(*value)
+    }
+}
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for ControllerRevisionPostBuilder<crate::generics::NamespaceExists, crate::generics::RevisionExists> {
+    type Output = crate::io::k8s::api::apps::v1::controller_revision::ControllerRevision;
+
+    const METHOD: http::Method = http::Method::POST;
+
+    const OPERATION_ID: Option<&'static str> = Some("createAppsV1NamespacedControllerRevision");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok(format!("/apis/apps/v1/namespaces/{namespace}/controllerrevisions", namespace=crate::client::path_param("namespace", self.inner.param_namespace.as_ref().expect("missing parameter namespace?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::CONTENT_TYPE.as_str(), "application/json")
+        .json(&self.inner.body)
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("dryRun", self.inner.param_dry_run.as_ref().map(std::string::ToString::to_string)),
+            ("fieldManager", self.inner.param_field_manager.as_ref().map(std::string::ToString::to_string)),
+            ("pretty", self.inner.param_pretty.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Content-Type")
+            || name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<ControllerRevisionPostBuilder<crate::generics::NamespaceExists, crate::generics::RevisionExists>> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: ControllerRevisionPostBuilder<crate::generics::NamespaceExists, crate::generics::RevisionExists>) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl ControllerRevisionPostBuilder<crate::generics::NamespaceExists, crate::generics::RevisionExists> {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = ControllerRevisionPostBuilder {
+            inner: ControllerRevisionPostBuilderContainer {
+            body: crate::cli::read_from_input(matches)?,
+            param_dry_run: matches.and_then(|m| {
+                    m.value_of("dry-run").map(|_| {
+                        value_t!(m, "dry-run", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_field_manager: matches.and_then(|m| {
+                    m.value_of("field-manager").map(|_| {
+                        value_t!(m, "field-manager", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_namespace: matches.and_then(|m| {
+                    m.value_of("namespace").map(|_| {
+                        value_t!(m, "namespace", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_pretty: matches.and_then(|m| {
+                    m.value_of("pretty").map(|_| {
+                        value_t!(m, "pretty", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            },
+            _param_namespace: core::marker::PhantomData,
+            _revision: core::marker::PhantomData,
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder created by [`ControllerRevision::read_apps_v1_namespaced_controller_revision`](./struct.ControllerRevision.html#method.read_apps_v1_namespaced_controller_revision) method for a `GET` operation associated with `ControllerRevision`.
+#[repr(transparent)]
+#[derive(Debug, Clone)]
+pub struct ControllerRevisionGetBuilder1<Name, Namespace> {
+    inner: ControllerRevisionGetBuilder1Container,
+    _param_name: core::marker::PhantomData<Name>,
+    _param_namespace: core::marker::PhantomData<Namespace>,
+}
+
+#[derive(Debug, Default, Clone)]
+struct ControllerRevisionGetBuilder1Container {
+    param_exact: Option<bool>,
+    param_export: Option<bool>,
+    param_name: Option<String>,
+    param_namespace: Option<String>,
+    param_pretty: Option<String>,
+}
+
+impl<Name, Namespace> ControllerRevisionGetBuilder1<Name, Namespace> {
+    /// Should the export be exact.  Exact export maintains cluster-specific fields like 'Namespace'. Deprecated. Planned for removal in 1.18.
+    #[inline]
+    pub fn exact(mut self, value: impl Into<bool>) -> Self {
+        self.inner.param_exact = Some(value.into());
+        self
+    }
+
+    /// Sets [`exact`](#method.exact) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_exact(mut self, value: Option<impl Into<bool>>) -> Self {
+        self.inner.param_exact = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`exact`](#method.exact), but takes a reference.
+    #[inline]
+    pub fn exact_ref(self, value: &bool) -> Self {
+        self.exact(*value)
+    }
+
+    /// Should this value be exported.  Export strips fields that a user can not specify. Deprecated. Planned for removal in 1.18.
+    #[inline]
+    pub fn export(mut self, value: impl Into<bool>) -> Self {
+        self.inner.param_export = Some(value.into());
+        self
+    }
+
+    /// Sets [`export`](#method.export) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_export(mut self, value: Option<impl Into<bool>>) -> Self {
+        self.inner.param_export = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`export`](#method.export), but takes a reference.
+    #[inline]
+    pub fn export_ref(self, value: &bool) -> Self {
+        self.export(*value)
+    }
+
+    /// name of the ControllerRevision
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> ControllerRevisionGetBuilder1<crate::generics::NameExists, Namespace> {
+        self.inner.param_name = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// object name and auth scope, such as for teams and projects
+    #[inline]
+    pub fn namespace(mut self, value: impl Into<String>) -> ControllerRevisionGetBuilder1<Name, crate::generics::NamespaceExists> {
+        self.inner.param_namespace = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// If 'true', then the output is pretty printed.
+    #[inline]
+    pub fn pretty(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_pretty = Some(value.into());
+        self
+    }
+
+    /// Sets [`pretty`](#method.pretty) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_pretty(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_pretty = value.map(|value| value.into());
+        self
+    }
+}
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for ControllerRevisionGetBuilder1<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    type Output = ControllerRevision;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    const OPERATION_ID: Option<&'static str> = Some("readAppsV1NamespacedControllerRevision");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok(format!("/apis/apps/v1/namespaces/{namespace}/controllerrevisions/{name}", name=crate::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?, namespace=crate::client::path_param("namespace", self.inner.param_namespace.as_ref().expect("missing parameter namespace?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("exact", self.inner.param_exact.as_ref().map(std::string::ToString::to_string)),
+            ("export", self.inner.param_export.as_ref().map(std::string::ToString::to_string)),
+            ("pretty", self.inner.param_pretty.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<ControllerRevisionGetBuilder1<crate::generics::NameExists, crate::generics::NamespaceExists>> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: ControllerRevisionGetBuilder1<crate::generics::NameExists, crate::generics::NamespaceExists>) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl ControllerRevisionGetBuilder1<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = ControllerRevisionGetBuilder1 {
+            inner: ControllerRevisionGetBuilder1Container {
+            param_exact: matches.and_then(|m| {
+                    m.value_of("exact").map(|_| {
+                        value_t!(m, "exact", bool).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_export: matches.and_then(|m| {
+                    m.value_of("export").map(|_| {
+                        value_t!(m, "export", bool).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_name: matches.and_then(|m| {
+                    m.value_of("name").map(|_| {
+                        value_t!(m, "name", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_namespace: matches.and_then(|m| {
+                    m.value_of("namespace").map(|_| {
+                        value_t!(m, "namespace", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_pretty: matches.and_then(|m| {
+                    m.value_of("pretty").map(|_| {
+                        value_t!(m, "pretty", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            },
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder created by [`ControllerRevision::replace_apps_v1_namespaced_controller_revision`](./struct.ControllerRevision.html#method.replace_apps_v1_namespaced_controller_revision) method for a `PUT` operation associated with `ControllerRevision`.
+#[repr(transparent)]
+#[derive(Debug, Clone)]
+pub struct ControllerRevisionPutBuilder1<Name, Namespace, Revision> {
+    inner: ControllerRevisionPutBuilder1Container,
+    _param_name: core::marker::PhantomData<Name>,
+    _param_namespace: core::marker::PhantomData<Namespace>,
+    _revision: core::marker::PhantomData<Revision>,
+}
+
+#[derive(Debug, Default, Clone)]
+struct ControllerRevisionPutBuilder1Container {
+    body: self::ControllerRevision,
+    param_dry_run: Option<String>,
+    param_field_manager: Option<String>,
+    param_name: Option<String>,
+    param_namespace: Option<String>,
+    param_pretty: Option<String>,
+}
+
+impl<Name, Namespace, Revision> ControllerRevisionPutBuilder1<Name, Namespace, Revision> {
+    /// When present, indicates that modifications should not be persisted. An invalid or unrecognized dryRun directive will result in an error response and no further processing of the request. Valid values are: - All: all dry run stages will be processed
+    #[inline]
+    pub fn dry_run(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_dry_run = Some(value.into());
+        self
+    }
+
+    /// Sets [`dry_run`](#method.dry_run) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_dry_run(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_dry_run = value.map(|value| value.into());
+        self
+    }
+
+    /// fieldManager is a name associated with the actor or entity that is making these changes. The value must be less than or 128 characters long, and only contain printable characters, as defined by https://golang.org/pkg/unicode/#IsPrint.
+    #[inline]
+    pub fn field_manager(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_field_manager = Some(value.into());
+        self
+    }
+
+    /// Sets [`field_manager`](#method.field_manager) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_field_manager(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_field_manager = value.map(|value| value.into());
+        self
+    }
+
+    /// name of the ControllerRevision
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> ControllerRevisionPutBuilder1<crate::generics::NameExists, Namespace, Revision> {
+        self.inner.param_name = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// object name and auth scope, such as for teams and projects
+    #[inline]
+    pub fn namespace(mut self, value: impl Into<String>) -> ControllerRevisionPutBuilder1<Name, crate::generics::NamespaceExists, Revision> {
+        self.inner.param_namespace = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// If 'true', then the output is pretty printed.
+    #[inline]
+    pub fn pretty(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_pretty = Some(value.into());
+        self
+    }
+
+    /// Sets [`pretty`](#method.pretty) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_pretty(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_pretty = value.map(|value| value.into());
+        self
+    }
+
+    /// APIVersion defines the versioned schema of this representation of an object. Servers should convert recognized schemas to the latest internal value, and may reject unrecognized values. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#resources
+    #[inline]
+    pub fn api_version(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.api_version = Some(value.into());
+        self
+    }
+
+    /// Sets [`api_version`](#method.api_version) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_api_version(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.api_version = value.map(|value| value.into());
+        self
+    }
+
+    /// Data is the serialized representation of the state.
+    #[inline]
+    pub fn data(mut self, value: crate::io::k8s::apimachinery::pkg::runtime::raw_extension::RawExtensionBuilder<crate::generics::RawExists>) -> Self {
+        self.inner.body.data = Some(value.into());
+        self
+    }
+
+    /// Sets [`data`](#method.data) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_data(mut self, value: Option<crate::io::k8s::apimachinery::pkg::runtime::raw_extension::RawExtensionBuilder<crate::generics::RawExists>>) -> Self {
+        self.inner.body.data = value.map(|value| value.into());
+        self
+    }
+
+    /// Kind is a string value representing the REST resource this object represents. Servers may infer this from the endpoint the client submits requests to. Cannot be updated. In CamelCase. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#types-kinds
+    #[inline]
+    pub fn kind(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.kind = Some(value.into());
+        self
+    }
+
+    /// Sets [`kind`](#method.kind) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_kind(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.kind = value.map(|value| value.into());
+        self
+    }
+
+    /// Standard object's metadata. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#metadata
+    #[inline]
+    pub fn metadata(mut self, value: crate::io::k8s::apimachinery::pkg::apis::meta::v1::object_meta::ObjectMeta) -> Self {
+        self.inner.body.metadata = Some(value.into());
+        self
+    }
+
+    /// Sets [`metadata`](#method.metadata) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_metadata(mut self, value: Option<crate::io::k8s::apimachinery::pkg::apis::meta::v1::object_meta::ObjectMeta>) -> Self {
+        self.inner.body.metadata = value.map(|value| value.into());
+        self
+    }
+
+    /// Revision indicates the revision of the state represented by Data.
+    #[inline]
+    pub fn revision(mut self, value: impl Into<i64>) -> ControllerRevisionPutBuilder1<Name, Namespace, crate::generics::RevisionExists> {
+        self.inner.body.revision = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Same as [`revision`](#method.revision), but takes a reference.
+    #[inline]
+    pub fn revision_ref(self, value: &i64) -> ControllerRevisionPutBuilder1<Name, Namespace, crate::generics::RevisionExists> {
+        self.revision(*value)
+    }
+}
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for ControllerRevisionPutBuilder1<crate::generics::NameExists, crate::generics::NamespaceExists, crate::generics::RevisionExists> {
+    type Output = crate::io::k8s::api::apps::v1::controller_revision::ControllerRevision;
+
+    const METHOD: http::Method = http::Method::PUT;
+
+    const OPERATION_ID: Option<&'static str> = Some("replaceAppsV1NamespacedControllerRevision");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok(format!("/apis/apps/v1/namespaces/{namespace}/controllerrevisions/{name}", name=crate::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?, namespace=crate::client::path_param("namespace", self.inner.param_namespace.as_ref().expect("missing parameter namespace?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::CONTENT_TYPE.as_str(), "application/json")
+        .json(&self.inner.body)
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("dryRun", self.inner.param_dry_run.as_ref().map(std::string::ToString::to_string)),
+            ("fieldManager", self.inner.param_field_manager.as_ref().map(std::string::ToString::to_string)),
+            ("pretty", self.inner.param_pretty.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Content-Type")
+            || name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<ControllerRevisionPutBuilder1<crate::generics::NameExists, crate::generics::NamespaceExists, crate::generics::RevisionExists>> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: ControllerRevisionPutBuilder1<crate::generics::NameExists, crate::generics::NamespaceExists, crate::generics::RevisionExists>) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl ControllerRevisionPutBuilder1<crate::generics::NameExists, crate::generics::NamespaceExists, crate::generics::RevisionExists> {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = ControllerRevisionPutBuilder1 {
+            inner: ControllerRevisionPutBuilder1Container {
+            body: crate::cli::read_from_input(matches)?,
+            param_dry_run: matches.and_then(|m| {
+                    m.value_of("dry-run").map(|_| {
+                        value_t!(m, "dry-run", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_field_manager: matches.and_then(|m| {
+                    m.value_of("field-manager").map(|_| {
+                        value_t!(m, "field-manager", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_name: matches.and_then(|m| {
+                    m.value_of("name").map(|_| {
+                        value_t!(m, "name", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_namespace: matches.and_then(|m| {
+                    m.value_of("namespace").map(|_| {
+                        value_t!(m, "namespace", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_pretty: matches.and_then(|m| {
+                    m.value_of("pretty").map(|_| {
+                        value_t!(m, "pretty", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            },
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+            _revision: core::marker::PhantomData,
+        };
+
+        Ok(thing)
+    }
+}
@@ -0,0 +1,1032 @@
+
+/// DaemonSet represents the configuration of a daemon set.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct DaemonSet {
+    /// APIVersion defines the versioned schema of this representation of an object. Servers should convert recognized schemas to the latest internal value, and may reject unrecognized values. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#resources
+    #[serde(rename = "apiVersion", skip_serializing_if = "Option::is_none")]
+    pub api_version: Option<String>,
+    /// Kind is a string value representing the REST resource this object represents. Servers may infer this from the endpoint the client submits requests to. Cannot be updated. In CamelCase. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#types-kinds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub kind: Option<String>,
+    /// Standard object's metadata. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#metadata
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<crate::io::k8s::apimachinery::pkg::apis::meta::v1::object_meta::ObjectMeta>,
+    /// The desired behavior of this daemon set. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#spec-and-status
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub spec: Option<crate::io::k8s::api::apps::v1::daemon_set_spec::DaemonSetSpec>,
+    /// The current status of this daemon set. This data may be out of date by some window of time. Populated by the system. Read-only. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#spec-and-status
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<crate::io::k8s::api::apps::v1::daemon_set_status::DaemonSetStatus>,
+}
+
+impl DaemonSet {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> DaemonSetBuilder {
+        DaemonSetBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// create a DaemonSet
+    #[inline]
+    pub fn create_apps_v1_namespaced_daemon_set() -> DaemonSetPostBuilder<crate::generics::MissingNamespace> {
+        DaemonSetPostBuilder {
+            inner: Default::default(),
+            _param_namespace: core::marker::PhantomData,
+        }
+    }
+
+    /// read the specified DaemonSet
+    #[inline]
+    pub fn read_apps_v1_namespaced_daemon_set() -> DaemonSetGetBuilder1<crate::generics::MissingName, crate::generics::MissingNamespace> {
+        DaemonSetGetBuilder1 {
+            inner: Default::default(),
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        }
+    }
+
+    /// replace the specified DaemonSet
+    #[inline]
+    pub fn replace_apps_v1_namespaced_daemon_set() -> DaemonSetPutBuilder1<crate::generics::MissingName, crate::generics::MissingNamespace> {
+        DaemonSetPutBuilder1 {
+            inner: Default::default(),
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        }
+    }
+
+    /// read status of the specified DaemonSet
+    #[inline]
+    pub fn read_apps_v1_namespaced_daemon_set_status() -> DaemonSetGetBuilder2<crate::generics::MissingName, crate::generics::MissingNamespace> {
+        DaemonSetGetBuilder2 {
+            inner: Default::default(),
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        }
+    }
+
+    /// replace status of the specified DaemonSet
+    #[inline]
+    pub fn replace_apps_v1_namespaced_daemon_set_status() -> DaemonSetPutBuilder2<crate::generics::MissingName, crate::generics::MissingNamespace> {
+        DaemonSetPutBuilder2 {
+            inner: Default::default(),
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> DaemonSetBuilder
+    where
+        Self: Clone,
+    {
+        DaemonSetBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<DaemonSet> for DaemonSetBuilder {
+    fn into(self) -> DaemonSet {
+        self.body
+    }
+}
+
+impl Into<DaemonSet> for DaemonSetPostBuilder<crate::generics::NamespaceExists> {
+    fn into(self) -> DaemonSet {
+        self.inner.body
+    }
+}
+
+impl Into<DaemonSet> for DaemonSetPutBuilder1<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    fn into(self) -> DaemonSet {
+        self.inner.body
+    }
+}
+
+impl Into<DaemonSet> for DaemonSetPutBuilder2<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    fn into(self) -> DaemonSet {
+        self.inner.body
+    }
+}
+
+/// Builder for [`DaemonSet`](./struct.DaemonSet.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct DaemonSetBuilder {
+    body: self::DaemonSet,
+}
+
+impl DaemonSetBuilder {
+    /// APIVersion defines the versioned schema of this representation of an object. Servers should convert recognized schemas to the latest internal value, and may reject unrecognized values. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#resources
+    #[inline]
+    pub fn api_version(mut self, value: impl Into<String>) -> Self {
+        self.body.api_version = Some(value.into());
+        self
+    }
+
+    /// Sets [`api_version`](#method.api_version) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_api_version(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.api_version = value.map(|value| value.into());
+        self
+    }
+
+    /// Kind is a string value representing the REST resource this object represents. Servers may infer this from the endpoint the client submits requests to. Cannot be updated. In CamelCase. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#types-kinds
+    #[inline]
+    pub fn kind(mut self, value: impl Into<String>) -> Self {
+        self.body.kind = Some(value.into());
+        self
+    }
+
+    /// Sets [`kind`](#method.kind) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_kind(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.kind = value.map(|value| value.into());
+        self
+    }
+
+    /// Standard object's metadata. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#metadata
+    #[inline]
+    pub fn metadata(mut self, value: crate::io::k8s::apimachinery::pkg::apis::meta::v1::object_meta::ObjectMeta) -> Self {
+        self.body.metadata = Some(value.into());
+        self
+    }
+
+    /// Sets [`metadata`](#method.metadata) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_metadata(mut self, value: Option<crate::io::k8s::apimachinery::pkg::apis::meta::v1::object_meta::ObjectMeta>) -> Self {
+        self.body.metadata = value.map(|value| value.into());
+        self
+    }
+
+    /// The desired behavior of this daemon set. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#spec-and-status
+    #[inline]
+    pub fn spec(mut self, value: crate::io::k8s::api::apps::v1::daemon_set_spec::DaemonSetSpecBuilder<crate::generics::SelectorExists, crate::generics::TemplateExists>) -> Self {
+        self.body.spec = Some(value.into());
+        self
+    }
+
+    /// Sets [`spec`](#method.spec) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_spec(mut self, value: Option<crate::io::k8s::api::apps::v1::daemon_set_spec::DaemonSetSpecBuilder<crate::generics::SelectorExists, crate::generics::TemplateExists>>) -> Self {
+        self.body.spec = value.map(|value| value.into());
+        self
+    }
+
+    /// The current status of this daemon set. This data may be out of date by some window of time. Populated by the system. Read-only. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#spec-and-status
+    #[inline]
+    pub fn status(mut self, value: crate::io::k8s::api::apps::v1::daemon_set_status::DaemonSetStatusBuilder<crate::generics::CurrentNumberScheduledExists, crate::generics::DesiredNumberScheduledExists, crate::generics::NumberMisscheduledExists, crate::generics::NumberReadyExists>) -> Self {
+        self.body.status = Some(value.into());
+        self
+    }
+
+    /// Sets [`status`](#method.status) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_status(mut self, value: Option<crate::io::k8s::api::apps::v1::daemon_set_status::DaemonSetStatusBuilder<crate::generics::CurrentNumberScheduledExists, crate::generics::DesiredNumberScheduledExists, crate::generics::NumberMisscheduledExists, crate::generics::NumberReadyExists>>) -> Self {
+        self.body.status = value.map(|value| value.into());
+        self
+    }
+}
+
+/// Builder created by [`DaemonSet::create_apps_v1_namespaced_daemon_set`](./struct.DaemonSet.html#method.create_apps_v1_namespaced_daemon_set) method for a `POST` operation associated with `DaemonSet`.
+#[repr(transparent)]
+#[derive(Debug, Clone)]
+pub struct DaemonSetPostBuilder<Namespace> {
+    inner: DaemonSetPostBuilderContainer,
+    _param_namespace: core::marker::PhantomData<Namespace>,
+}
+
+#[derive(Debug, Default, Clone)]
+struct DaemonSetPostBuilderContainer {
+    body: self::DaemonSet,
+    param_dry_run: Option<String>,
+    param_field_manager: Option<String>,
+    param_namespace: Option<String>,
+    param_pretty: Option<String>,
+}
+
+impl<Namespace> DaemonSetPostBuilder<Namespace> {
+    /// When present, indicates that modifications should not be persisted. An invalid or unrecognized dryRun directive will result in an error response and no further processing of the request. Valid values are: - All: all dry run stages will be processed
+    #[inline]
+    pub fn dry_run(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_dry_run = Some(value.into());
+        self
+    }
+
+    /// Sets [`dry_run`](#method.dry_run) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_dry_run(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_dry_run = value.map(|value| value.into());
+        self
+    }
+
+    /// fieldManager is a name associated with the actor or entity that is making these changes. The value must be less than or 128 characters long, and only contain printable characters, as defined by https://golang.org/pkg/unicode/#IsPrint.
+    #[inline]
+    pub fn field_manager(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_field_manager = Some(value.into());
+        self
+    }
+
+    /// Sets [`field_manager`](#method.field_manager) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_field_manager(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_field_manager = value.map(|value| value.into());
+        self
+    }
+
+    /// object name and auth scope, such as for teams and projects
+    #[inline]
+    pub fn namespace(mut self, value: impl Into<String>) -> DaemonSetPostBuilder<crate::generics::NamespaceExists> {
+        self.inner.param_namespace = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// If 'true', then the output is pretty printed.
+    #[inline]
+    pub fn pretty(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_pretty = Some(value.into());
+        self
+    }
+
+    /// Sets [`pretty`](#method.pretty) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_pretty(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_pretty = value.map(|value| value.into());
+        self
+    }
+
+    /// APIVersion defines the versioned schema of this representation of an object. Servers should convert recognized schemas to the latest internal value, and may reject unrecognized values. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#resources
+    #[inline]
+    pub fn api_version(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.api_version = Some(value.into());
+        self
+    }
+
+    /// Sets [`api_version`](#method.api_version) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_api_version(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.api_version = value.map(|value| value.into());
+        self
+    }
+
+    /// Kind is a string value representing the REST resource this object represents. Servers may infer this from the endpoint the client submits requests to. Cannot be updated. In CamelCase. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#types-kinds
+    #[inline]
+    pub fn kind(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.kind = Some(value.into());
+        self
+    }
+
+    /// Sets [`kind`](#method.kind) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_kind(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.kind = value.map(|value| value.into());
+        self
+    }
+
+    /// Standard object's metadata. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#metadata
+    #[inline]
+    pub fn metadata(mut self, value: crate::io::k8s::apimachinery::pkg::apis::meta::v1::object_meta::ObjectMeta) -> Self {
+        self.inner.body.metadata = Some(value.into());
+        self
+    }
+
+    /// Sets [`metadata`](#method.metadata) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_metadata(mut self, value: Option<crate::io::k8s::apimachinery::pkg::apis::meta::v1::object_meta::ObjectMeta>) -> Self {
+        self.inner.body.metadata = value.map(|value| value.into());
+        self
+    }
+
+    /// The desired behavior of this daemon set. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#spec-and-status
+    #[inline]
+    pub fn spec(mut self, value: crate::io::k8s::api::apps::v1::daemon_set_spec::DaemonSetSpecBuilder<crate::generics::SelectorExists, crate::generics::TemplateExists>) -> Self {
+        self.inner.body.spec = Some(value.into());
+        self
+    }
+
+    /// Sets [`spec`](#method.spec) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_spec(mut self, value: Option<crate::io::k8s::api::apps::v1::daemon_set_spec::DaemonSetSpecBuilder<crate::generics::SelectorExists, crate::generics::TemplateExists>>) -> Self {
+        self.inner.body.spec = value.map(|value| value.into());
+        self
+    }
+
+    /// The current status of this daemon set. This data may be out of date by some window of time. Populated by the system. Read-only. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#spec-and-status
+    #[inline]
+    pub fn status(mut self, value: crate::io::k8s::api::apps::v1::daemon_set_status::DaemonSetStatusBuilder<crate::generics::CurrentNumberScheduledExists, crate::generics::DesiredNumberScheduledExists, crate::generics::NumberMisscheduledExists, crate::generics::NumberReadyExists>) -> Self {
+        self.inner.body.status = Some(value.into());
+        self
+    }
+
+    /// Sets [`status`](#method.status) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_status(mut self, value: Option<crate::io::k8s::api::apps::v1::daemon_set_status::DaemonSetStatusBuilder<crate::generics::CurrentNumberScheduledExists, crate::generics::DesiredNumberScheduledExists, crate::generics::NumberMisscheduledExists, crate::generics::NumberReadyExists>>) -> Self {
+        self.inner.body.status = value.map(|value| value.into());
+        self
+    }
+}
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for DaemonSetPostBuilder<crate::generics::NamespaceExists> {
+    type Output = crate::io::k8s::api::apps::v1::daemon_set::DaemonSet;
+
+    const METHOD: http::Method = http::Method::POST;
+
+    const OPERATION_ID: Option<&'static str> = Some("createAppsV1NamespacedDaemonSet");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok(format!("/apis/apps/v1/namespaces/{namespace}/daemonsets", namespace=crate::client::path_param("namespace", self.inner.param_namespace.as_ref().expect("missing parameter namespace?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::CONTENT_TYPE.as_str(), "application/json")
+        .json(&self.inner.body)
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("dryRun", self.inner.param_dry_run.as_ref().map(std::string::ToString::to_string)),
+            ("fieldManager", self.inner.param_field_manager.as_ref().map(std::string::ToString::to_string)),
+            ("pretty", self.inner.param_pretty.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Content-Type")
+            || name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<DaemonSetPostBuilder<crate::generics::NamespaceExists>> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: DaemonSetPostBuilder<crate::generics::NamespaceExists>) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl DaemonSetPostBuilder<crate::generics::NamespaceExists> {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = DaemonSetPostBuilder {
+            inner: DaemonSetPostBuilderContainer {
+            body: crate::cli::read_from_input(matches)?,
+            param_dry_run: matches.and_then(|m| {
+                    m.value_of("dry-run").map(|_| {
+                        value_t!(m, "dry-run", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_field_manager: matches.and_then(|m| {
+                    m.value_of("field-manager").map(|_| {
+                        value_t!(m, "field-manager", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_namespace: matches.and_then(|m| {
+                    m.value_of("namespace").map(|_| {
+                        value_t!(m, "namespace", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_pretty: matches.and_then(|m| {
+                    m.value_of("pretty").map(|_| {
+                        value_t!(m, "pretty", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            },
+            _param_namespace: core::marker::PhantomData,
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder created by [`DaemonSet::read_apps_v1_namespaced_daemon_set`](./struct.DaemonSet.html#method.read_apps_v1_namespaced_daemon_set) method for a `GET` operation associated with `DaemonSet`.
+#[repr(transparent)]
+#[derive(Debug, Clone)]
+pub struct DaemonSetGetBuilder1<Name, Namespace> {
+    inner: DaemonSetGetBuilder1Container,
+    _param_name: core::marker::PhantomData<Name>,
+    _param_namespace: core::marker::PhantomData<Namespace>,
+}
+
+#[derive(Debug, Default, Clone)]
+struct DaemonSetGetBuilder1Container {
+    param_exact: Option<bool>,
+    param_export: Option<bool>,
+    param_name: Option<String>,
+    param_namespace: Option<String>,
+    param_pretty: Option<String>,
+}
+
+impl<Name, Namespace> DaemonSetGetBuilder1<Name, Namespace> {
+    /// Should the export be exact.  Exact export maintains cluster-specific fields like 'Namespace'. Deprecated. Planned for removal in 1.18.
+    #[inline]
+    pub fn exact(mut self, value: impl Into<bool>) -> Self {
+        self.inner.param_exact = Some(value.into());
+        self
+    }
+
+    /// Sets [`exact`](#method.exact) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_exact(mut self, value: Option<impl Into<bool>>) -> Self {
+        self.inner.param_exact = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`exact`](#method.exact), but takes a reference.
+    #[inline]
+    pub fn exact_ref(self, value: &bool) -> Self {
+        self.exact(*value)
+    }
+
+    /// Should this value be exported.  Export strips fields that a user can not specify. Deprecated. Planned for removal in 1.18.
+    #[inline]
+    pub fn export(mut self, value: impl Into<bool>) -> Self {
+        self.inner.param_export = Some(value.into());
+        self
+    }
+
+    /// Sets [`export`](#method.export) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_export(mut self, value: Option<impl Into<bool>>) -> Self {
+        self.inner.param_export = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`export`](#method.export), but takes a reference.
+    #[inline]
+    pub fn export_ref(self, value: &bool) -> Self {
+        self.export(*value)
+    }
+
+    /// name of the DaemonSet
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> DaemonSetGetBuilder1<crate::generics::NameExists, Namespace> {
+        self.inner.param_name = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// object name and auth scope, such as for teams and projects
+    #[inline]
+    pub fn namespace(mut self, value: impl Into<String>) -> DaemonSetGetBuilder1<Name, crate::generics::NamespaceExists> {
+        self.inner.param_namespace = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// If 'true', then the output is pretty printed.
+    #[inline]
+    pub fn pretty(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_pretty = Some(value.into());
+        self
+    }
+
+    /// Sets [`pretty`](#method.pretty) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_pretty(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_pretty = value.map(|value| value.into());
+        self
+    }
+}
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for DaemonSetGetBuilder1<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    type Output = DaemonSet;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    const OPERATION_ID: Option<&'static str> = Some("readAppsV1NamespacedDaemonSet");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok(format!("/apis/apps/v1/namespaces/{namespace}/daemonsets/{name}", name=crate::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?, namespace=crate::client::path_param("namespace", self.inner.param_namespace.as_ref().expect("missing parameter namespace?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("exact", self.inner.param_exact.as_ref().map(std::string::ToString::to_string)),
+            ("export", self.inner.param_export.as_ref().map(std::string::ToString::to_string)),
+            ("pretty", self.inner.param_pretty.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<DaemonSetGetBuilder1<crate::generics::NameExists, crate::generics::NamespaceExists>> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: DaemonSetGetBuilder1<crate::generics::NameExists, crate::generics::NamespaceExists>) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl DaemonSetGetBuilder1<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = DaemonSetGetBuilder1 {
+            inner: DaemonSetGetBuilder1Container {
+            param_exact: matches.and_then(|m| {
+                    m.value_of("exact").map(|_| {
+                        value_t!(m, "exact", bool).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_export: matches.and_then(|m| {
+                    m.value_of("export").map(|_| {
+                        value_t!(m, "export", bool).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_name: matches.and_then(|m| {
+                    m.value_of("name").map(|_| {
+                        value_t!(m, "name", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_namespace: matches.and_then(|m| {
+                    m.value_of("namespace").map(|_| {
+                        value_t!(m, "namespace", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_pretty: matches.and_then(|m| {
+                    m.value_of("pretty").map(|_| {
+                        value_t!(m, "pretty", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            },
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder shared by operations (with the same fields and parameters) associated with `DaemonSet`.
+#[repr(transparent)]
+#[derive(Debug, Clone)]
+pub struct DaemonSetOpBuilder<Op, Name, Namespace> {
+    inner: DaemonSetOpBuilderContainer,
+    _op: core::marker::PhantomData<Op>,
+    _param_name: core::marker::PhantomData<Name>,
+    _param_namespace: core::marker::PhantomData<Namespace>,
+}
+
+#[derive(Debug, Default, Clone)]
+struct DaemonSetOpBuilderContainer {
+    body: self::DaemonSet,
+    param_dry_run: Option<String>,
+    param_field_manager: Option<String>,
+    param_name: Option<String>,
+    param_namespace: Option<String>,
+    param_pretty: Option<String>,
+}
+
+/// Builder created by [`DaemonSet::replace_apps_v1_namespaced_daemon_set`](./struct.DaemonSet.html#method.replace_apps_v1_namespaced_daemon_set) method for a `PUT` operation associated with `DaemonSet`.
+pub type DaemonSetPutBuilder1<Name, Namespace> = DaemonSetOpBuilder<DaemonSetPutOp1, Name, Namespace>;
+
+/// Marks the operation of [`DaemonSetPutBuilder1`] in the shared builder.
+#[derive(Debug, Clone)]
+pub enum DaemonSetPutOp1 {}
+
+impl<Op, Name, Namespace> DaemonSetOpBuilder<Op, Name, Namespace> {
+    /// When present, indicates that modifications should not be persisted. An invalid or unrecognized dryRun directive will result in an error response and no further processing of the request. Valid values are: - All: all dry run stages will be processed
+    #[inline]
+    pub fn dry_run(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_dry_run = Some(value.into());
+        self
+    }
+
+    /// Sets [`dry_run`](#method.dry_run) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_dry_run(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_dry_run = value.map(|value| value.into());
+        self
+    }
+
+    /// fieldManager is a name associated with the actor or entity that is making these changes. The value must be less than or 128 characters long, and only contain printable characters, as defined by https://golang.org/pkg/unicode/#IsPrint.
+    #[inline]
+    pub fn field_manager(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_field_manager = Some(value.into());
+        self
+    }
+
+    /// Sets [`field_manager`](#method.field_manager) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_field_manager(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_field_manager = value.map(|value| value.into());
+        self
+    }
+
+    /// name of the DaemonSet
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> DaemonSetOpBuilder<Op, crate::generics::NameExists, Namespace> {
+        self.inner.param_name = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// object name and auth scope, such as for teams and projects
+    #[inline]
+    pub fn namespace(mut self, value: impl Into<String>) -> DaemonSetOpBuilder<Op, Name, crate::generics::NamespaceExists> {
+        self.inner.param_namespace = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// If 'true', then the output is pretty printed.
+    #[inline]
+    pub fn pretty(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_pretty = Some(value.into());
+        self
+    }
+
+    /// Sets [`pretty`](#method.pretty) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_pretty(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_pretty = value.map(|value| value.into());
+        self
+    }
+
+    /// APIVersion defines the versioned schema of this representation of an object. Servers should convert recognized schemas to the latest internal value, and may reject unrecognized values. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#resources
+    #[inline]
+    pub fn api_version(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.api_version = Some(value.into());
+        self
+    }
+
+    /// Sets [`api_version`](#method.api_version) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_api_version(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.api_version = value.map(|value| value.into());
+        self
+    }
+
+    /// Kind is a string value representing the REST resource this object represents. Servers may infer this from the endpoint the client submits requests to. Cannot be updated. In CamelCase. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#types-kinds
+    #[inline]
+    pub fn kind(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.kind = Some(value.into());
+        self
+    }
+
+    /// Sets [`kind`](#method.kind) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_kind(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.kind = value.map(|value| value.into());
+        self
+    }
+
+    /// Standard object's metadata. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#metadata
+    #[inline]
+    pub fn metadata(mut self, value: crate::io::k8s::apimachinery::pkg::apis::meta::v1::object_meta::ObjectMeta) -> Self {
+        self.inner.body.metadata = Some(value.into());
+        self
+    }
+
+    /// Sets [`metadata`](#method.metadata) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_metadata(mut self, value: Option<crate::io::k8s::apimachinery::pkg::apis::meta::v1::object_meta::ObjectMeta>) -> Self {
+        self.inner.body.metadata = value.map(|value| value.into());
+        self
+    }
+
+    /// The desired behavior of this daemon set. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#spec-and-status
+    #[inline]
+    pub fn spec(mut self, value: crate::io::k8s::api::apps::v1::daemon_set_spec::DaemonSetSpecBuilder<crate::generics::SelectorExists, crate::generics::TemplateExists>) -> Self {
+        self.inner.body.spec = Some(value.into());
+        self
+    }
+
+    /// Sets [`spec`](#method.spec) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_spec(mut self, value: Option<crate::io::k8s::api::apps::v1::daemon_set_spec::DaemonSetSpecBuilder<crate::generics::SelectorExists, crate::generics::TemplateExists>>) -> Self {
+        self.inner.body.spec = value.map(|value| value.into());
+        self
+    }
+
+    /// The current status of this daemon set. This data may be out of date by some window of time. Populated by the system. Read-only. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#spec-and-status
+    #[inline]
+    pub fn status(mut self, value: crate::io::k8s::api::apps::v1::daemon_set_status::DaemonSetStatusBuilder<crate::generics::CurrentNumberScheduledExists, crate::generics::DesiredNumberScheduledExists, crate::generics::NumberMisscheduledExists, crate::generics::NumberReadyExists>) -> Self {
+        self.inner.body.status = Some(value.into());
+        self
+    }
+
+    /// Sets [`status`](#method.status) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_status(mut self, value: Option<crate::io::k8s::api::apps::v1::daemon_set_status::DaemonSetStatusBuilder<crate::generics::CurrentNumberScheduledExists, crate::generics::DesiredNumberScheduledExists, crate::generics::NumberMisscheduledExists, crate::generics::NumberReadyExists>>) -> Self {
+        self.inner.body.status = value.map(|value| value.into());
+        self
+    }
+}
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for DaemonSetPutBuilder1<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    type Output = crate::io::k8s::api::apps::v1::daemon_set::DaemonSet;
+
+    const METHOD: http::Method = http::Method::PUT;
+
+    const OPERATION_ID: Option<&'static str> = Some("replaceAppsV1NamespacedDaemonSet");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok(format!("/apis/apps/v1/namespaces/{namespace}/daemonsets/{name}", name=crate::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?, namespace=crate::client::path_param("namespace", self.inner.param_namespace.as_ref().expect("missing parameter namespace?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::CONTENT_TYPE.as_str(), "application/json")
+        .json(&self.inner.body)
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("dryRun", self.inner.param_dry_run.as_ref().map(std::string::ToString::to_string)),
+            ("fieldManager", self.inner.param_field_manager.as_ref().map(std::string::ToString::to_string)),
+            ("pretty", self.inner.param_pretty.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Content-Type")
+            || name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<DaemonSetPutBuilder1<crate::generics::NameExists, crate::generics::NamespaceExists>> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: DaemonSetPutBuilder1<crate::generics::NameExists, crate::generics::NamespaceExists>) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl DaemonSetPutBuilder1<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = DaemonSetPutBuilder1 {
+            inner: DaemonSetOpBuilderContainer {
+            body: crate::cli::read_from_input(matches)?,
+            param_dry_run: matches.and_then(|m| {
+                    m.value_of("dry-run").map(|_| {
+                        value_t!(m, "dry-run", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_field_manager: matches.and_then(|m| {
+                    m.value_of("field-manager").map(|_| {
+                        value_t!(m, "field-manager", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_name: matches.and_then(|m| {
+                    m.value_of("name").map(|_| {
+                        value_t!(m, "name", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_namespace: matches.and_then(|m| {
+                    m.value_of("namespace").map(|_| {
+                        value_t!(m, "namespace", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_pretty: matches.and_then(|m| {
+                    m.value_of("pretty").map(|_| {
+                        value_t!(m, "pretty", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            },
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder created by [`DaemonSet::read_apps_v1_namespaced_daemon_set_status`](./struct.DaemonSet.html#method.read_apps_v1_namespaced_daemon_set_status) method for a `GET` operation associated with `DaemonSet`.
+#[repr(transparent)]
+#[derive(Debug, Clone)]
+pub struct DaemonSetGetBuilder2<Name, Namespace> {
+    inner: DaemonSetGetBuilder2Container,
+    _param_name: core::marker::PhantomData<Name>,
+    _param_namespace: core::marker::PhantomData<Namespace>,
+}
+
+#[derive(Debug, Default, Clone)]
+struct DaemonSetGetBuilder2Container {
+    param_name: Option<String>,
+    param_namespace: Option<String>,
+    param_pretty: Option<String>,
+}
+
+impl<Name, Namespace> DaemonSetGetBuilder2<Name, Namespace> {
+    /// name of the DaemonSet
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> DaemonSetGetBuilder2<crate::generics::NameExists, Namespace> {
+        self.inner.param_name = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// object name and auth scope, such as for teams and projects
+    #[inline]
+    pub fn namespace(mut self, value: impl Into<String>) -> DaemonSetGetBuilder2<Name, crate::generics::NamespaceExists> {
+        self.inner.param_namespace = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// If 'true', then the output is pretty printed.
+    #[inline]
+    pub fn pretty(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_pretty = Some(value.into());
+        self
+    }
+
+    /// Sets [`pretty`](#method.pretty) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_pretty(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_pretty = value.map(|value| value.into());
+        self
+    }
+}
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for DaemonSetGetBuilder2<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    type Output = DaemonSet;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    const OPERATION_ID: Option<&'static str> = Some("readAppsV1NamespacedDaemonSetStatus");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok(format!("/apis/apps/v1/namespaces/{namespace}/daemonsets/{name}/status", name=crate::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?, namespace=crate::client::path_param("namespace", self.inner.param_namespace.as_ref().expect("missing parameter namespace?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("pretty", self.inner.param_pretty.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<DaemonSetGetBuilder2<crate::generics::NameExists, crate::generics::NamespaceExists>> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: DaemonSetGetBuilder2<crate::generics::NameExists, crate::generics::NamespaceExists>) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl DaemonSetGetBuilder2<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = DaemonSetGetBuilder2 {
+            inner: DaemonSetGetBuilder2Container {
+            param_name: matches.and_then(|m| {
+                    m.value_of("name").map(|_| {
+                        value_t!(m, "name", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_namespace: matches.and_then(|m| {
+                    m.value_of("namespace").map(|_| {
+                        value_t!(m, "namespace", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_pretty: matches.and_then(|m| {
+                    m.value_of("pretty").map(|_| {
+                        value_t!(m, "pretty", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            },
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder created by [`DaemonSet::replace_apps_v1_namespaced_daemon_set_status`](./struct.DaemonSet.html#method.replace_apps_v1_namespaced_daemon_set_status) method for a `PUT` operation associated with `DaemonSet`.
+pub type DaemonSetPutBuilder2<Name, Namespace> = DaemonSetOpBuilder<DaemonSetPutOp2, Name, Namespace>;
+
+/// Marks the operation of [`DaemonSetPutBuilder2`] in the shared builder.
+#[derive(Debug, Clone)]
+pub enum DaemonSetPutOp2 {}
+
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for DaemonSetPutBuilder2<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    type Output = crate::io::k8s::api::apps::v1::daemon_set::DaemonSet;
+
+    const METHOD: http::Method = http::Method::PUT;
+
+    const OPERATION_ID: Option<&'static str> = Some("replaceAppsV1NamespacedDaemonSetStatus");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok(format!("/apis/apps/v1/namespaces/{namespace}/daemonsets/{name}/status", name=crate::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?, namespace=crate::client::path_param("namespace", self.inner.param_namespace.as_ref().expect("missing parameter namespace?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::CONTENT_TYPE.as_str(), "application/json")
+        .json(&self.inner.body)
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("dryRun", self.inner.param_dry_run.as_ref().map(std::string::ToString::to_string)),
+            ("fieldManager", self.inner.param_field_manager.as_ref().map(std::string::ToString::to_string)),
+            ("pretty", self.inner.param_pretty.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Content-Type")
+            || name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<DaemonSetPutBuilder2<crate::generics::NameExists, crate::generics::NamespaceExists>> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: DaemonSetPutBuilder2<crate::generics::NameExists, crate::generics::NamespaceExists>) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl DaemonSetPutBuilder2<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = DaemonSetPutBuilder2 {
+            inner: DaemonSetOpBuilderContainer {
+            body: crate::cli::read_from_input(matches)?,
+            param_dry_run: matches.and_then(|m| {
+                    m.value_of("dry-run").map(|_| {
+                        value_t!(m, "dry-run", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_field_manager: matches.and_then(|m| {
+                    m.value_of("field-manager").map(|_| {
+                        value_t!(m, "field-manager", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_name: matches.and_then(|m| {
+                    m.value_of("name").map(|_| {
+                        value_t!(m, "name", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_namespace: matches.and_then(|m| {
+                    m.value_of("namespace").map(|_| {
+                        value_t!(m, "namespace", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_pretty: matches.and_then(|m| {
+                    m.value_of("pretty").map(|_| {
+                        value_t!(m, "pretty", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            },
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        };
+
+        Ok(thing)
+    }
+}
@@ -0,0 +1,225 @@
+
+/// DeploymentStatus is the most recently observed status of the Deployment.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct DeploymentStatus {
+    /// Total number of available pods (ready for at least minReadySeconds) targeted by this deployment.
+    #[serde(rename = "availableReplicas", skip_serializing_if = "Option::is_none")]
+    pub available_replicas: Option<i32>,
+    /// Count of hash collisions for the Deployment. The Deployment controller uses this field as a collision avoidance mechanism when it needs to create the name for the newest ReplicaSet.
+    #[serde(rename = "collisionCount", skip_serializing_if = "Option::is_none")]
+    pub collision_count: Option<i32>,
+    /// Represents the latest available observations of a deployment's current state.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub conditions: Option<Vec<crate::io::k8s::api::apps::v1::deployment_condition::DeploymentCondition>>,
+    /// The generation observed by the deployment controller.
+    #[serde(rename = "observedGeneration", skip_serializing_if = "Option::is_none")]
+    pub observed_generation: Option<i64>,
+    /// Total number of ready pods targeted by this deployment.
+    #[serde(rename = "readyReplicas", skip_serializing_if = "Option::is_none")]
+    pub ready_replicas: Option<i32>,
+    /// Total number of non-terminated pods targeted by this deployment (their labels match the selector).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub replicas: Option<i32>,
+    /// Total number of unavailable pods targeted by this deployment. This is the total number of pods that are still required for the deployment to have 100% available capacity. They may either be pods that are running but not yet available or pods that still have not been created.
+    #[serde(rename = "unavailableReplicas", skip_serializing_if = "Option::is_none")]
+    pub unavailable_replicas: Option<i32>,
+    /// Total number of non-terminated pods targeted by this deployment that have the desired template spec.
+    #[serde(rename = "updatedReplicas", skip_serializing_if = "Option::is_none")]
+    pub updated_replicas: Option<i32>,
+}
+
+impl DeploymentStatus {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> DeploymentStatusBuilder {
+        DeploymentStatusBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> DeploymentStatusBuilder
+    where
+        Self: Clone,
+    {
+        DeploymentStatusBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<DeploymentStatus> for DeploymentStatusBuilder {
+    fn into(self) -> DeploymentStatus {
+        self.body
+    }
+}
+
+/// Builder for [`DeploymentStatus`](./struct.DeploymentStatus.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct DeploymentStatusBuilder {
+    body: self::DeploymentStatus,
+}
+
+impl DeploymentStatusBuilder {
+    /// Total number of available pods (ready for at least minReadySeconds) targeted by this deployment.
+    #[inline]
+    pub fn available_replicas(mut self, value: impl Into<i32>) -> Self {
+        self.body.available_replicas = Some(value.into());
+        self
+    }
+
+    /// Sets [`available_replicas`](#method.available_replicas) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_available_replicas(mut self, value: Option<impl Into<i32>>) -> Self {
+        self.body.available_replicas = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`available_replicas`](#method.available_replicas), but takes a reference.
+    #[inline]
+    pub fn available_replicas_ref(self, value: &i32) -> Self {
+        self.available_replicas(*value)
+    }
+
+    /// Count of hash collisions for the Deployment. The Deployment controller uses this field as a collision avoidance mechanism when it needs to create the name for the newest ReplicaSet.
+    #[inline]
+    pub fn collision_count(mut self, value: impl Into<i32>) -> Self {
+        self.body.collision_count = Some(value.into());
+        self
+    }
+
+    /// Sets [`collision_count`](#method.collision_count) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_collision_count(mut self, value: Option<impl Into<i32>>) -> Self {
+        self.body.collision_count = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`collision_count`](#method.collision_count), but takes a reference.
+    #[inline]
+    pub fn collision_count_ref(self, value: &i32) -> Self {
+        self.collision_count(*value)
+    }
+
+    /// Represents the latest available observations of a deployment's current state.
+    #[inline]
+    pub fn conditions(mut self, value: impl Iterator<Item = crate::io::k8s::api::apps::v1::deployment_condition::DeploymentConditionBuilder<crate::generics::StatusExists, crate::generics::TypeExists>>) -> Self {
+        self.body.conditions = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`conditions`](#method.conditions) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_conditions(mut self, value: Option<impl Iterator<Item = crate::io::k8s::api::apps::v1::deployment_condition::DeploymentConditionBuilder<crate::generics::StatusExists, crate::generics::TypeExists>>>) -> Self {
+        self.body.conditions = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`conditions`](#method.conditions), but takes the values as a `Vec`.
+    #[inline]
+    pub fn conditions_vec(mut self, value: impl Into<Vec<crate::io::k8s::api::apps::v1::deployment_condition::DeploymentCondition>>) -> Self {
+        self.body.conditions = Some(value.into());
+        self
+    }
+
+    /// The generation observed by the deployment controller.
+    #[inline]
+    pub fn observed_generation(mut self, value: impl Into<i64>) -> Self {
+        self.body.observed_generation = Some(value.into());
+        self
+    }
+
+    /// Sets [`observed_generation`](#method.observed_generation) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_observed_generation(mut self, value: Option<impl Into<i64>>) -> Self {
+        self.body.observed_generation = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`observed_generation`](#method.observed_generation), but takes a reference.
+    #[inline]
+    pub fn observed_generation_ref(self, value: &i64) -> Self {
+        self.observed_generation(*value)
+    }
+
+    /// Total number of ready pods targeted by this deployment.
+    #[inline]
+    pub fn ready_replicas(mut self, value: impl Into<i32>) -> Self {
+        self.body.ready_replicas = Some(value.into());
+        self
+    }
+
+    /// Sets [`ready_replicas`](#method.ready_replicas) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_ready_replicas(mut self, value: Option<impl Into<i32>>) -> Self {
+        self.body.ready_replicas = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`ready_replicas`](#method.ready_replicas), but takes a reference.
+    #[inline]
+    pub fn ready_replicas_ref(self, value: &i32) -> Self {
+        self.ready_replicas(*value)
+    }
+
+    /// Total number of non-terminated pods targeted by this deployment (their labels match the selector).
+    #[inline]
+    pub fn replicas(mut self, value: impl Into<i32>) -> Self {
+        self.body.replicas = Some(value.into());
+        self
+    }
+
+    /// Sets [`replicas`](#method.replicas) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_replicas(mut self, value: Option<impl Into<i32>>) -> Self {
+        self.body.replicas = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`replicas`](#method.replicas), but takes a reference.
+    #[inline]
+    pub fn replicas_ref(self, value: &i32) -> Self {
+        self.replicas(*value)
+    }
+
+    /// Total number of unavailable pods targeted by this deployment. This is the total number of pods that are still required for the deployment to have 100% available capacity. They may either be pods that are running but not yet available or pods that still have not been created.
+    #[inline]
+    pub fn unavailable_replicas(mut self, value: impl Into<i32>) -> Self {
+        self.body.unavailable_replicas = Some(value.into());
+        self
+    }
+
+    /// Sets [`unavailable_replicas`](#method.unavailable_replicas) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_unavailable_replicas(mut self, value: Option<impl Into<i32>>) -> Self {
+        self.body.unavailable_replicas = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`unavailable_replicas`](#method.unavailable_replicas), but takes a reference.
+    #[inline]
+    pub fn unavailable_replicas_ref(self, value: &i32) -> Self {
+        self.unavailable_replicas(*value)
+    }
+
+    /// Total number of non-terminated pods targeted by this deployment that have the desired template spec.
+    #[inline]
+    pub fn updated_replicas(mut self, value: impl Into<i32>) -> Self {
+        self.body.updated_replicas = Some(value.into());
+        self
+    }
+
+    /// Sets [`updated_replicas`](#method.updated_replicas) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_updated_replicas(mut self, value: Option<impl Into<i32>>) -> Self {
+        self.body.updated_replicas = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`updated_replicas`](#method.updated_replicas), but takes a reference.
+    #[inline]
+    pub fn updated_replicas_ref(self, value: &i32) -> Self {
+        self.updated_replicas(*value)
+    }
+}
@@ -0,0 +1,112 @@
+
+pub mod controller_revision {
+    include!("./controller_revision.rs");
+}
+
+pub mod controller_revision_list {
+    include!("./controller_revision_list.rs");
+}
+
+pub mod daemon_set {
+    include!("./daemon_set.rs");
+}
+
+pub mod daemon_set_condition {
+    include!("./daemon_set_condition.rs");
+}
+
+pub mod daemon_set_list {
+    include!("./daemon_set_list.rs");
+}
+
+pub mod daemon_set_spec {
+    include!("./daemon_set_spec.rs");
+}
+
+pub mod daemon_set_status {
+    include!("./daemon_set_status.rs");
+}
+
+pub mod daemon_set_update_strategy {
+    include!("./daemon_set_update_strategy.rs");
+}
+
+pub mod deployment {
+    include!("./deployment.rs");
+}
+
+pub mod deployment_condition {
+    include!("./deployment_condition.rs");
+}
+
+pub mod deployment_list {
+    include!("./deployment_list.rs");
+}
+
+pub mod deployment_spec {
+    include!("./deployment_spec.rs");
+}
+
+pub mod deployment_status {
+    include!("./deployment_status.rs");
+}
+
+pub mod deployment_strategy {
+    include!("./deployment_strategy.rs");
+}
+
+pub mod replica_set {
+    include!("./replica_set.rs");
+}
+
+pub mod replica_set_condition {
+    include!("./replica_set_condition.rs");
+}
+
+pub mod replica_set_list {
+    include!("./replica_set_list.rs");
+}
+
+pub mod replica_set_spec {
+    include!("./replica_set_spec.rs");
+}
+
+pub mod replica_set_status {
+    include!("./replica_set_status.rs");
+}
+
+pub mod rolling_update_daemon_set {
+    include!("./rolling_update_daemon_set.rs");
+}
+
+pub mod rolling_update_deployment {
+    include!("./rolling_update_deployment.rs");
+}
+
+pub mod rolling_update_stateful_set_strategy {
+    include!("./rolling_update_stateful_set_strategy.rs");
+}
+
+pub mod stateful_set {
+    include!("./stateful_set.rs");
+}
+
+pub mod stateful_set_condition {
+    include!("./stateful_set_condition.rs");
+}
+
+pub mod stateful_set_list {
+    include!("./stateful_set_list.rs");
+}
+
+pub mod stateful_set_spec {
+    include!("./stateful_set_spec.rs");
+}
+
+pub mod stateful_set_status {
+    include!("./stateful_set_status.rs");
+}
+
+pub mod stateful_set_update_strategy {
+    include!("./stateful_set_update_strategy.rs");
+}
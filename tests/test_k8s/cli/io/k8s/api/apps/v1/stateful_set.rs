@@ -0,0 +1,1031 @@
+
+/// StatefulSet represents a set of pods with consistent identities. Identities are defined as:
+///  - Network: A single stable DNS and hostname.
+///  - Storage: As many VolumeClaims as requested.
+/// The StatefulSet guarantees that a given network identity will always map to the same storage identity.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct StatefulSet {
+    /// APIVersion defines the versioned schema of this representation of an object. Servers should convert recognized schemas to the latest internal value, and may reject unrecognized values. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#resources
+    #[serde(rename = "apiVersion", skip_serializing_if = "Option::is_none")]
+    pub api_version: Option<String>,
+    /// Kind is a string value representing the REST resource this object represents. Servers may infer this from the endpoint the client submits requests to. Cannot be updated. In CamelCase. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#types-kinds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub kind: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<crate::io::k8s::apimachinery::pkg::apis::meta::v1::object_meta::ObjectMeta>,
+    /// Spec defines the desired identities of pods in this set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub spec: Option<crate::io::k8s::api::apps::v1::stateful_set_spec::StatefulSetSpec>,
+    /// Status is the current status of Pods in this StatefulSet. This data may be out of date by some window of time.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<crate::io::k8s::api::apps::v1::stateful_set_status::StatefulSetStatus>,
+}
+
+impl StatefulSet {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> StatefulSetBuilder {
+        StatefulSetBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// create a StatefulSet
+    #[inline]
+    pub fn create_apps_v1_namespaced_stateful_set() -> StatefulSetPostBuilder<crate::generics::MissingNamespace> {
+        StatefulSetPostBuilder {
+            inner: Default::default(),
+            _param_namespace: core::marker::PhantomData,
+        }
+    }
+
+    /// read the specified StatefulSet
+    #[inline]
+    pub fn read_apps_v1_namespaced_stateful_set() -> StatefulSetGetBuilder1<crate::generics::MissingName, crate::generics::MissingNamespace> {
+        StatefulSetGetBuilder1 {
+            inner: Default::default(),
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        }
+    }
+
+    /// replace the specified StatefulSet
+    #[inline]
+    pub fn replace_apps_v1_namespaced_stateful_set() -> StatefulSetPutBuilder1<crate::generics::MissingName, crate::generics::MissingNamespace> {
+        StatefulSetPutBuilder1 {
+            inner: Default::default(),
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        }
+    }
+
+    /// read status of the specified StatefulSet
+    #[inline]
+    pub fn read_apps_v1_namespaced_stateful_set_status() -> StatefulSetGetBuilder2<crate::generics::MissingName, crate::generics::MissingNamespace> {
+        StatefulSetGetBuilder2 {
+            inner: Default::default(),
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        }
+    }
+
+    /// replace status of the specified StatefulSet
+    #[inline]
+    pub fn replace_apps_v1_namespaced_stateful_set_status() -> StatefulSetPutBuilder2<crate::generics::MissingName, crate::generics::MissingNamespace> {
+        StatefulSetPutBuilder2 {
+            inner: Default::default(),
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> StatefulSetBuilder
+    where
+        Self: Clone,
+    {
+        StatefulSetBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<StatefulSet> for StatefulSetBuilder {
+    fn into(self) -> StatefulSet {
+        self.body
+    }
+}
+
+impl Into<StatefulSet> for StatefulSetPostBuilder<crate::generics::NamespaceExists> {
+    fn into(self) -> StatefulSet {
+        self.inner.body
+    }
+}
+
+impl Into<StatefulSet> for StatefulSetPutBuilder1<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    fn into(self) -> StatefulSet {
+        self.inner.body
+    }
+}
+
+impl Into<StatefulSet> for StatefulSetPutBuilder2<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    fn into(self) -> StatefulSet {
+        self.inner.body
+    }
+}
+
+/// Builder for [`StatefulSet`](./struct.StatefulSet.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct StatefulSetBuilder {
+    body: self::StatefulSet,
+}
+
+impl StatefulSetBuilder {
+    /// APIVersion defines the versioned schema of this representation of an object. Servers should convert recognized schemas to the latest internal value, and may reject unrecognized values. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#resources
+    #[inline]
+    pub fn api_version(mut self, value: impl Into<String>) -> Self {
+        self.body.api_version = Some(value.into());
+        self
+    }
+
+    /// Sets [`api_version`](#method.api_version) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_api_version(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.api_version = value.map(|value| value.into());
+        self
+    }
+
+    /// Kind is a string value representing the REST resource this object represents. Servers may infer this from the endpoint the client submits requests to. Cannot be updated. In CamelCase. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#types-kinds
+    #[inline]
+    pub fn kind(mut self, value: impl Into<String>) -> Self {
+        self.body.kind = Some(value.into());
+        self
+    }
+
+    /// Sets [`kind`](#method.kind) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_kind(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.kind = value.map(|value| value.into());
+        self
+    }
+
+    #[inline]
+    pub fn metadata(mut self, value: crate::io::k8s::apimachinery::pkg::apis::meta::v1::object_meta::ObjectMeta) -> Self {
+        self.body.metadata = Some(value.into());
+        self
+    }
+
+    /// Sets [`metadata`](#method.metadata) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_metadata(mut self, value: Option<crate::io::k8s::apimachinery::pkg::apis::meta::v1::object_meta::ObjectMeta>) -> Self {
+        self.body.metadata = value.map(|value| value.into());
+        self
+    }
+
+    /// Spec defines the desired identities of pods in this set.
+    #[inline]
+    pub fn spec(mut self, value: crate::io::k8s::api::apps::v1::stateful_set_spec::StatefulSetSpecBuilder<crate::generics::SelectorExists, crate::generics::ServiceNameExists, crate::generics::TemplateExists>) -> Self {
+        self.body.spec = Some(value.into());
+        self
+    }
+
+    /// Sets [`spec`](#method.spec) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_spec(mut self, value: Option<crate::io::k8s::api::apps::v1::stateful_set_spec::StatefulSetSpecBuilder<crate::generics::SelectorExists, crate::generics::ServiceNameExists, crate::generics::TemplateExists>>) -> Self {
+        self.body.spec = value.map(|value| value.into());
+        self
+    }
+
+    /// Status is the current status of Pods in this StatefulSet. This data may be out of date by some window of time.
+    #[inline]
+    pub fn status(mut self, value: crate::io::k8s::api::apps::v1::stateful_set_status::StatefulSetStatusBuilder<crate::generics::ReplicasExists>) -> Self {
+        self.body.status = Some(value.into());
+        self
+    }
+
+    /// Sets [`status`](#method.status) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_status(mut self, value: Option<crate::io::k8s::api::apps::v1::stateful_set_status::StatefulSetStatusBuilder<crate::generics::ReplicasExists>>) -> Self {
+        self.body.status = value.map(|value| value.into());
+        self
+    }
+}
+
+/// Builder created by [`StatefulSet::create_apps_v1_namespaced_stateful_set`](./struct.StatefulSet.html#method.create_apps_v1_namespaced_stateful_set) method for a `POST` operation associated with `StatefulSet`.
+#[repr(transparent)]
+#[derive(Debug, Clone)]
+pub struct StatefulSetPostBuilder<Namespace> {
+    inner: StatefulSetPostBuilderContainer,
+    _param_namespace: core::marker::PhantomData<Namespace>,
+}
+
+#[derive(Debug, Default, Clone)]
+struct StatefulSetPostBuilderContainer {
+    body: self::StatefulSet,
+    param_dry_run: Option<String>,
+    param_field_manager: Option<String>,
+    param_namespace: Option<String>,
+    param_pretty: Option<String>,
+}
+
+impl<Namespace> StatefulSetPostBuilder<Namespace> {
+    /// When present, indicates that modifications should not be persisted. An invalid or unrecognized dryRun directive will result in an error response and no further processing of the request. Valid values are: - All: all dry run stages will be processed
+    #[inline]
+    pub fn dry_run(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_dry_run = Some(value.into());
+        self
+    }
+
+    /// Sets [`dry_run`](#method.dry_run) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_dry_run(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_dry_run = value.map(|value| value.into());
+        self
+    }
+
+    /// fieldManager is a name associated with the actor or entity that is making these changes. The value must be less than or 128 characters long, and only contain printable characters, as defined by https://golang.org/pkg/unicode/#IsPrint.
+    #[inline]
+    pub fn field_manager(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_field_manager = Some(value.into());
+        self
+    }
+
+    /// Sets [`field_manager`](#method.field_manager) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_field_manager(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_field_manager = value.map(|value| value.into());
+        self
+    }
+
+    /// object name and auth scope, such as for teams and projects
+    #[inline]
+    pub fn namespace(mut self, value: impl Into<String>) -> StatefulSetPostBuilder<crate::generics::NamespaceExists> {
+        self.inner.param_namespace = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// If 'true', then the output is pretty printed.
+    #[inline]
+    pub fn pretty(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_pretty = Some(value.into());
+        self
+    }
+
+    /// Sets [`pretty`](#method.pretty) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_pretty(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_pretty = value.map(|value| value.into());
+        self
+    }
+
+    /// APIVersion defines the versioned schema of this representation of an object. Servers should convert recognized schemas to the latest internal value, and may reject unrecognized values. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#resources
+    #[inline]
+    pub fn api_version(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.api_version = Some(value.into());
+        self
+    }
+
+    /// Sets [`api_version`](#method.api_version) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_api_version(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.api_version = value.map(|value| value.into());
+        self
+    }
+
+    /// Kind is a string value representing the REST resource this object represents. Servers may infer this from the endpoint the client submits requests to. Cannot be updated. In CamelCase. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#types-kinds
+    #[inline]
+    pub fn kind(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.kind = Some(value.into());
+        self
+    }
+
+    /// Sets [`kind`](#method.kind) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_kind(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.kind = value.map(|value| value.into());
+        self
+    }
+
+    #[inline]
+    pub fn metadata(mut self, value: crate::io::k8s::apimachinery::pkg::apis::meta::v1::object_meta::ObjectMeta) -> Self {
+        self.inner.body.metadata = Some(value.into());
+        self
+    }
+
+    /// Sets [`metadata`](#method.metadata) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_metadata(mut self, value: Option<crate::io::k8s::apimachinery::pkg::apis::meta::v1::object_meta::ObjectMeta>) -> Self {
+        self.inner.body.metadata = value.map(|value| value.into());
+        self
+    }
+
+    /// Spec defines the desired identities of pods in this set.
+    #[inline]
+    pub fn spec(mut self, value: crate::io::k8s::api::apps::v1::stateful_set_spec::StatefulSetSpecBuilder<crate::generics::SelectorExists, crate::generics::ServiceNameExists, crate::generics::TemplateExists>) -> Self {
+        self.inner.body.spec = Some(value.into());
+        self
+    }
+
+    /// Sets [`spec`](#method.spec) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_spec(mut self, value: Option<crate::io::k8s::api::apps::v1::stateful_set_spec::StatefulSetSpecBuilder<crate::generics::SelectorExists, crate::generics::ServiceNameExists, crate::generics::TemplateExists>>) -> Self {
+        self.inner.body.spec = value.map(|value| value.into());
+        self
+    }
+
+    /// Status is the current status of Pods in this StatefulSet. This data may be out of date by some window of time.
+    #[inline]
+    pub fn status(mut self, value: crate::io::k8s::api::apps::v1::stateful_set_status::StatefulSetStatusBuilder<crate::generics::ReplicasExists>) -> Self {
+        self.inner.body.status = Some(value.into());
+        self
+    }
+
+    /// Sets [`status`](#method.status) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_status(mut self, value: Option<crate::io::k8s::api::apps::v1::stateful_set_status::StatefulSetStatusBuilder<crate::generics::ReplicasExists>>) -> Self {
+        self.inner.body.status = value.map(|value| value.into());
+        self
+    }
+}
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for StatefulSetPostBuilder<crate::generics::NamespaceExists> {
+    type Output = crate::io::k8s::api::apps::v1::stateful_set::StatefulSet;
+
+    const METHOD: http::Method = http::Method::POST;
+
+    const OPERATION_ID: Option<&'static str> = Some("createAppsV1NamespacedStatefulSet");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok(format!("/apis/apps/v1/namespaces/{namespace}/statefulsets", namespace=crate::client::path_param("namespace", self.inner.param_namespace.as_ref().expect("missing parameter namespace?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::CONTENT_TYPE.as_str(), "application/json")
+        .json(&self.inner.body)
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("dryRun", self.inner.param_dry_run.as_ref().map(std::string::ToString::to_string)),
+            ("fieldManager", self.inner.param_field_manager.as_ref().map(std::string::ToString::to_string)),
+            ("pretty", self.inner.param_pretty.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Content-Type")
+            || name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<StatefulSetPostBuilder<crate::generics::NamespaceExists>> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: StatefulSetPostBuilder<crate::generics::NamespaceExists>) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl StatefulSetPostBuilder<crate::generics::NamespaceExists> {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = StatefulSetPostBuilder {
+            inner: StatefulSetPostBuilderContainer {
+            body: crate::cli::read_from_input(matches)?,
+            param_dry_run: matches.and_then(|m| {
+                    m.value_of("dry-run").map(|_| {
+                        value_t!(m, "dry-run", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_field_manager: matches.and_then(|m| {
+                    m.value_of("field-manager").map(|_| {
+                        value_t!(m, "field-manager", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_namespace: matches.and_then(|m| {
+                    m.value_of("namespace").map(|_| {
+                        value_t!(m, "namespace", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_pretty: matches.and_then(|m| {
+                    m.value_of("pretty").map(|_| {
+                        value_t!(m, "pretty", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            },
+            _param_namespace: core::marker::PhantomData,
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder created by [`StatefulSet::read_apps_v1_namespaced_stateful_set`](./struct.StatefulSet.html#method.read_apps_v1_namespaced_stateful_set) method for a `GET` operation associated with `StatefulSet`.
+#[repr(transparent)]
+#[derive(Debug, Clone)]
+pub struct StatefulSetGetBuilder1<Name, Namespace> {
+    inner: StatefulSetGetBuilder1Container,
+    _param_name: core::marker::PhantomData<Name>,
+    _param_namespace: core::marker::PhantomData<Namespace>,
+}
+
+#[derive(Debug, Default, Clone)]
+struct StatefulSetGetBuilder1Container {
+    param_exact: Option<bool>,
+    param_export: Option<bool>,
+    param_name: Option<String>,
+    param_namespace: Option<String>,
+    param_pretty: Option<String>,
+}
+
+impl<Name, Namespace> StatefulSetGetBuilder1<Name, Namespace> {
+    /// Should the export be exact.  Exact export maintains cluster-specific fields like 'Namespace'. Deprecated. Planned for removal in 1.18.
+    #[inline]
+    pub fn exact(mut self, value: impl Into<bool>) -> Self {
+        self.inner.param_exact = Some(value.into());
+        self
+    }
+
+    /// Sets [`exact`](#method.exact) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_exact(mut self, value: Option<impl Into<bool>>) -> Self {
+        self.inner.param_exact = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`exact`](#method.exact), but takes a reference.
+    #[inline]
+    pub fn exact_ref(self, value: &bool) -> Self {
+        self.exact(*value)
+    }
+
+    /// Should this value be exported.  Export strips fields that a user can not specify. Deprecated. Planned for removal in 1.18.
+    #[inline]
+    pub fn export(mut self, value: impl Into<bool>) -> Self {
+        self.inner.param_export = Some(value.into());
+        self
+    }
+
+    /// Sets [`export`](#method.export) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_export(mut self, value: Option<impl Into<bool>>) -> Self {
+        self.inner.param_export = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`export`](#method.export), but takes a reference.
+    #[inline]
+    pub fn export_ref(self, value: &bool) -> Self {
+        self.export(*value)
+    }
+
+    /// name of the StatefulSet
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> StatefulSetGetBuilder1<crate::generics::NameExists, Namespace> {
+        self.inner.param_name = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// object name and auth scope, such as for teams and projects
+    #[inline]
+    pub fn namespace(mut self, value: impl Into<String>) -> StatefulSetGetBuilder1<Name, crate::generics::NamespaceExists> {
+        self.inner.param_namespace = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// If 'true', then the output is pretty printed.
+    #[inline]
+    pub fn pretty(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_pretty = Some(value.into());
+        self
+    }
+
+    /// Sets [`pretty`](#method.pretty) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_pretty(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_pretty = value.map(|value| value.into());
+        self
+    }
+}
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for StatefulSetGetBuilder1<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    type Output = StatefulSet;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    const OPERATION_ID: Option<&'static str> = Some("readAppsV1NamespacedStatefulSet");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok(format!("/apis/apps/v1/namespaces/{namespace}/statefulsets/{name}", name=crate::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?, namespace=crate::client::path_param("namespace", self.inner.param_namespace.as_ref().expect("missing parameter namespace?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("exact", self.inner.param_exact.as_ref().map(std::string::ToString::to_string)),
+            ("export", self.inner.param_export.as_ref().map(std::string::ToString::to_string)),
+            ("pretty", self.inner.param_pretty.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<StatefulSetGetBuilder1<crate::generics::NameExists, crate::generics::NamespaceExists>> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: StatefulSetGetBuilder1<crate::generics::NameExists, crate::generics::NamespaceExists>) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl StatefulSetGetBuilder1<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = StatefulSetGetBuilder1 {
+            inner: StatefulSetGetBuilder1Container {
+            param_exact: matches.and_then(|m| {
+                    m.value_of("exact").map(|_| {
+                        value_t!(m, "exact", bool).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_export: matches.and_then(|m| {
+                    m.value_of("export").map(|_| {
+                        value_t!(m, "export", bool).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_name: matches.and_then(|m| {
+                    m.value_of("name").map(|_| {
+                        value_t!(m, "name", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_namespace: matches.and_then(|m| {
+                    m.value_of("namespace").map(|_| {
+                        value_t!(m, "namespace", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_pretty: matches.and_then(|m| {
+                    m.value_of("pretty").map(|_| {
+                        value_t!(m, "pretty", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            },
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder shared by operations (with the same fields and parameters) associated with `StatefulSet`.
+#[repr(transparent)]
+#[derive(Debug, Clone)]
+pub struct StatefulSetOpBuilder<Op, Name, Namespace> {
+    inner: StatefulSetOpBuilderContainer,
+    _op: core::marker::PhantomData<Op>,
+    _param_name: core::marker::PhantomData<Name>,
+    _param_namespace: core::marker::PhantomData<Namespace>,
+}
+
+#[derive(Debug, Default, Clone)]
+struct StatefulSetOpBuilderContainer {
+    body: self::StatefulSet,
+    param_dry_run: Option<String>,
+    param_field_manager: Option<String>,
+    param_name: Option<String>,
+    param_namespace: Option<String>,
+    param_pretty: Option<String>,
+}
+
+/// Builder created by [`StatefulSet::replace_apps_v1_namespaced_stateful_set`](./struct.StatefulSet.html#method.replace_apps_v1_namespaced_stateful_set) method for a `PUT` operation associated with `StatefulSet`.
+pub type StatefulSetPutBuilder1<Name, Namespace> = StatefulSetOpBuilder<StatefulSetPutOp1, Name, Namespace>;
+
+/// Marks the operation of [`StatefulSetPutBuilder1`] in the shared builder.
+#[derive(Debug, Clone)]
+pub enum StatefulSetPutOp1 {}
+
+impl<Op, Name, Namespace> StatefulSetOpBuilder<Op, Name, Namespace> {
+    /// When present, indicates that modifications should not be persisted. An invalid or unrecognized dryRun directive will result in an error response and no further processing of the request. Valid values are: - All: all dry run stages will be processed
+    #[inline]
+    pub fn dry_run(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_dry_run = Some(value.into());
+        self
+    }
+
+    /// Sets [`dry_run`](#method.dry_run) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_dry_run(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_dry_run = value.map(|value| value.into());
+        self
+    }
+
+    /// fieldManager is a name associated with the actor or entity that is making these changes. The value must be less than or 128 characters long, and only contain printable characters, as defined by https://golang.org/pkg/unicode/#IsPrint.
+    #[inline]
+    pub fn field_manager(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_field_manager = Some(value.into());
+        self
+    }
+
+    /// Sets [`field_manager`](#method.field_manager) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_field_manager(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_field_manager = value.map(|value| value.into());
+        self
+    }
+
+    /// name of the StatefulSet
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> StatefulSetOpBuilder<Op, crate::generics::NameExists, Namespace> {
+        self.inner.param_name = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// object name and auth scope, such as for teams and projects
+    #[inline]
+    pub fn namespace(mut self, value: impl Into<String>) -> StatefulSetOpBuilder<Op, Name, crate::generics::NamespaceExists> {
+        self.inner.param_namespace = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// If 'true', then the output is pretty printed.
+    #[inline]
+    pub fn pretty(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_pretty = Some(value.into());
+        self
+    }
+
+    /// Sets [`pretty`](#method.pretty) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_pretty(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_pretty = value.map(|value| value.into());
+        self
+    }
+
+    /// APIVersion defines the versioned schema of this representation of an object. Servers should convert recognized schemas to the latest internal value, and may reject unrecognized values. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#resources
+    #[inline]
+    pub fn api_version(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.api_version = Some(value.into());
+        self
+    }
+
+    /// Sets [`api_version`](#method.api_version) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_api_version(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.api_version = value.map(|value| value.into());
+        self
+    }
+
+    /// Kind is a string value representing the REST resource this object represents. Servers may infer this from the endpoint the client submits requests to. Cannot be updated. In CamelCase. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#types-kinds
+    #[inline]
+    pub fn kind(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.kind = Some(value.into());
+        self
+    }
+
+    /// Sets [`kind`](#method.kind) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_kind(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.kind = value.map(|value| value.into());
+        self
+    }
+
+    #[inline]
+    pub fn metadata(mut self, value: crate::io::k8s::apimachinery::pkg::apis::meta::v1::object_meta::ObjectMeta) -> Self {
+        self.inner.body.metadata = Some(value.into());
+        self
+    }
+
+    /// Sets [`metadata`](#method.metadata) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_metadata(mut self, value: Option<crate::io::k8s::apimachinery::pkg::apis::meta::v1::object_meta::ObjectMeta>) -> Self {
+        self.inner.body.metadata = value.map(|value| value.into());
+        self
+    }
+
+    /// Spec defines the desired identities of pods in this set.
+    #[inline]
+    pub fn spec(mut self, value: crate::io::k8s::api::apps::v1::stateful_set_spec::StatefulSetSpecBuilder<crate::generics::SelectorExists, crate::generics::ServiceNameExists, crate::generics::TemplateExists>) -> Self {
+        self.inner.body.spec = Some(value.into());
+        self
+    }
+
+    /// Sets [`spec`](#method.spec) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_spec(mut self, value: Option<crate::io::k8s::api::apps::v1::stateful_set_spec::StatefulSetSpecBuilder<crate::generics::SelectorExists, crate::generics::ServiceNameExists, crate::generics::TemplateExists>>) -> Self {
+        self.inner.body.spec = value.map(|value| value.into());
+        self
+    }
+
+    /// Status is the current status of Pods in this StatefulSet. This data may be out of date by some window of time.
+    #[inline]
+    pub fn status(mut self, value: crate::io::k8s::api::apps::v1::stateful_set_status::StatefulSetStatusBuilder<crate::generics::ReplicasExists>) -> Self {
+        self.inner.body.status = Some(value.into());
+        self
+    }
+
+    /// Sets [`status`](#method.status) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_status(mut self, value: Option<crate::io::k8s::api::apps::v1::stateful_set_status::StatefulSetStatusBuilder<crate::generics::ReplicasExists>>) -> Self {
+        self.inner.body.status = value.map(|value| value.into());
+        self
+    }
+}
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for StatefulSetPutBuilder1<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    type Output = crate::io::k8s::api::apps::v1::stateful_set::StatefulSet;
+
+    const METHOD: http::Method = http::Method::PUT;
+
+    const OPERATION_ID: Option<&'static str> = Some("replaceAppsV1NamespacedStatefulSet");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok(format!("/apis/apps/v1/namespaces/{namespace}/statefulsets/{name}", name=crate::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?, namespace=crate::client::path_param("namespace", self.inner.param_namespace.as_ref().expect("missing parameter namespace?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::CONTENT_TYPE.as_str(), "application/json")
+        .json(&self.inner.body)
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("dryRun", self.inner.param_dry_run.as_ref().map(std::string::ToString::to_string)),
+            ("fieldManager", self.inner.param_field_manager.as_ref().map(std::string::ToString::to_string)),
+            ("pretty", self.inner.param_pretty.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Content-Type")
+            || name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<StatefulSetPutBuilder1<crate::generics::NameExists, crate::generics::NamespaceExists>> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: StatefulSetPutBuilder1<crate::generics::NameExists, crate::generics::NamespaceExists>) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl StatefulSetPutBuilder1<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = StatefulSetPutBuilder1 {
+            inner: StatefulSetOpBuilderContainer {
+            body: crate::cli::read_from_input(matches)?,
+            param_dry_run: matches.and_then(|m| {
+                    m.value_of("dry-run").map(|_| {
+                        value_t!(m, "dry-run", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_field_manager: matches.and_then(|m| {
+                    m.value_of("field-manager").map(|_| {
+                        value_t!(m, "field-manager", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_name: matches.and_then(|m| {
+                    m.value_of("name").map(|_| {
+                        value_t!(m, "name", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_namespace: matches.and_then(|m| {
+                    m.value_of("namespace").map(|_| {
+                        value_t!(m, "namespace", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_pretty: matches.and_then(|m| {
+                    m.value_of("pretty").map(|_| {
+                        value_t!(m, "pretty", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            },
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder created by [`StatefulSet::read_apps_v1_namespaced_stateful_set_status`](./struct.StatefulSet.html#method.read_apps_v1_namespaced_stateful_set_status) method for a `GET` operation associated with `StatefulSet`.
+#[repr(transparent)]
+#[derive(Debug, Clone)]
+pub struct StatefulSetGetBuilder2<Name, Namespace> {
+    inner: StatefulSetGetBuilder2Container,
+    _param_name: core::marker::PhantomData<Name>,
+    _param_namespace: core::marker::PhantomData<Namespace>,
+}
+
+#[derive(Debug, Default, Clone)]
+struct StatefulSetGetBuilder2Container {
+    param_name: Option<String>,
+    param_namespace: Option<String>,
+    param_pretty: Option<String>,
+}
+
+impl<Name, Namespace> StatefulSetGetBuilder2<Name, Namespace> {
+    /// name of the StatefulSet
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> StatefulSetGetBuilder2<crate::generics::NameExists, Namespace> {
+        self.inner.param_name = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// object name and auth scope, such as for teams and projects
+    #[inline]
+    pub fn namespace(mut self, value: impl Into<String>) -> StatefulSetGetBuilder2<Name, crate::generics::NamespaceExists> {
+        self.inner.param_namespace = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// If 'true', then the output is pretty printed.
+    #[inline]
+    pub fn pretty(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_pretty = Some(value.into());
+        self
+    }
+
+    /// Sets [`pretty`](#method.pretty) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_pretty(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_pretty = value.map(|value| value.into());
+        self
+    }
+}
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for StatefulSetGetBuilder2<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    type Output = StatefulSet;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    const OPERATION_ID: Option<&'static str> = Some("readAppsV1NamespacedStatefulSetStatus");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok(format!("/apis/apps/v1/namespaces/{namespace}/statefulsets/{name}/status", name=crate::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?, namespace=crate::client::path_param("namespace", self.inner.param_namespace.as_ref().expect("missing parameter namespace?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("pretty", self.inner.param_pretty.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<StatefulSetGetBuilder2<crate::generics::NameExists, crate::generics::NamespaceExists>> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: StatefulSetGetBuilder2<crate::generics::NameExists, crate::generics::NamespaceExists>) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl StatefulSetGetBuilder2<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = StatefulSetGetBuilder2 {
+            inner: StatefulSetGetBuilder2Container {
+            param_name: matches.and_then(|m| {
+                    m.value_of("name").map(|_| {
+                        value_t!(m, "name", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_namespace: matches.and_then(|m| {
+                    m.value_of("namespace").map(|_| {
+                        value_t!(m, "namespace", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_pretty: matches.and_then(|m| {
+                    m.value_of("pretty").map(|_| {
+                        value_t!(m, "pretty", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            },
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder created by [`StatefulSet::replace_apps_v1_namespaced_stateful_set_status`](./struct.StatefulSet.html#method.replace_apps_v1_namespaced_stateful_set_status) method for a `PUT` operation associated with `StatefulSet`.
+pub type StatefulSetPutBuilder2<Name, Namespace> = StatefulSetOpBuilder<StatefulSetPutOp2, Name, Namespace>;
+
+/// Marks the operation of [`StatefulSetPutBuilder2`] in the shared builder.
+#[derive(Debug, Clone)]
+pub enum StatefulSetPutOp2 {}
+
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for StatefulSetPutBuilder2<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    type Output = crate::io::k8s::api::apps::v1::stateful_set::StatefulSet;
+
+    const METHOD: http::Method = http::Method::PUT;
+
+    const OPERATION_ID: Option<&'static str> = Some("replaceAppsV1NamespacedStatefulSetStatus");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok(format!("/apis/apps/v1/namespaces/{namespace}/statefulsets/{name}/status", name=crate::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?, namespace=crate::client::path_param("namespace", self.inner.param_namespace.as_ref().expect("missing parameter namespace?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::CONTENT_TYPE.as_str(), "application/json")
+        .json(&self.inner.body)
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("dryRun", self.inner.param_dry_run.as_ref().map(std::string::ToString::to_string)),
+            ("fieldManager", self.inner.param_field_manager.as_ref().map(std::string::ToString::to_string)),
+            ("pretty", self.inner.param_pretty.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Content-Type")
+            || name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<StatefulSetPutBuilder2<crate::generics::NameExists, crate::generics::NamespaceExists>> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: StatefulSetPutBuilder2<crate::generics::NameExists, crate::generics::NamespaceExists>) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl StatefulSetPutBuilder2<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = StatefulSetPutBuilder2 {
+            inner: StatefulSetOpBuilderContainer {
+            body: crate::cli::read_from_input(matches)?,
+            param_dry_run: matches.and_then(|m| {
+                    m.value_of("dry-run").map(|_| {
+                        value_t!(m, "dry-run", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_field_manager: matches.and_then(|m| {
+                    m.value_of("field-manager").map(|_| {
+                        value_t!(m, "field-manager", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_name: matches.and_then(|m| {
+                    m.value_of("name").map(|_| {
+                        value_t!(m, "name", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_namespace: matches.and_then(|m| {
+                    m.value_of("namespace").map(|_| {
+                        value_t!(m, "namespace", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_pretty: matches.and_then(|m| {
+                    m.value_of("pretty").map(|_| {
+                        value_t!(m, "pretty", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            },
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        };
+
+        Ok(thing)
+    }
+}
@@ -0,0 +1,181 @@
+
+/// A StatefulSetSpec is the specification of a StatefulSet.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct StatefulSetSpec {
+    /// podManagementPolicy controls how pods are created during initial scale up, when replacing pods on nodes, or when scaling down. The default policy is `OrderedReady`, where pods are created in increasing order (pod-0, then pod-1, etc) and the controller will wait until each pod is ready before continuing. When scaling down, the pods are removed in the opposite order. The alternative policy is `Parallel` which will create pods in parallel to match the desired scale without waiting, and on scale down will delete all pods at once.
+    #[serde(rename = "podManagementPolicy", skip_serializing_if = "Option::is_none")]
+    pub pod_management_policy: Option<String>,
+    /// replicas is the desired number of replicas of the given Template. These are replicas in the sense that they are instantiations of the same Template, but individual replicas also have a consistent identity. If unspecified, defaults to 1.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub replicas: Option<i32>,
+    /// revisionHistoryLimit is the maximum number of revisions that will be maintained in the StatefulSet's revision history. The revision history consists of all revisions not represented by a currently applied StatefulSetSpec version. The default value is 10.
+    #[serde(rename = "revisionHistoryLimit", skip_serializing_if = "Option::is_none")]
+    pub revision_history_limit: Option<i32>,
+    /// selector is a label query over pods that should match the replica count. It must match the pod template's labels. More info: https://kubernetes.io/docs/concepts/overview/working-with-objects/labels/#label-selectors
+    pub selector: crate::io::k8s::apimachinery::pkg::apis::meta::v1::label_selector::LabelSelector,
+    /// serviceName is the name of the service that governs this StatefulSet. This service must exist before the StatefulSet, and is responsible for the network identity of the set. Pods get DNS/hostnames that follow the pattern: pod-specific-string.serviceName.default.svc.cluster.local where "pod-specific-string" is managed by the StatefulSet controller.
+    #[serde(rename = "serviceName")]
+    pub service_name: String,
+    /// template is the object that describes the pod that will be created if insufficient replicas are detected. Each pod stamped out by the StatefulSet will fulfill this Template, but have a unique identity from the rest of the StatefulSet.
+    pub template: crate::io::k8s::api::core::v1::pod_template_spec::PodTemplateSpec,
+    /// updateStrategy indicates the StatefulSetUpdateStrategy that will be employed to update Pods in the StatefulSet when a revision is made to Template.
+    #[serde(rename = "updateStrategy", skip_serializing_if = "Option::is_none")]
+    pub update_strategy: Option<crate::io::k8s::api::apps::v1::stateful_set_update_strategy::StatefulSetUpdateStrategy>,
+    /// volumeClaimTemplates is a list of claims that pods are allowed to reference. The StatefulSet controller is responsible for mapping network identities to claims in a way that maintains the identity of a pod. Every claim in this list must have at least one matching (by name) volumeMount in one container in the template. A claim in this list takes precedence over any volumes in the template, with the same name.
+    #[serde(rename = "volumeClaimTemplates", skip_serializing_if = "Option::is_none")]
+    pub volume_claim_templates: Option<Vec<crate::io::k8s::api::core::v1::persistent_volume_claim::PersistentVolumeClaim>>,
+}
+
+impl StatefulSetSpec {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> StatefulSetSpecBuilder<crate::generics::MissingSelector, crate::generics::MissingServiceName, crate::generics::MissingTemplate> {
+        StatefulSetSpecBuilder {
+            body: Default::default(),
+            _selector: core::marker::PhantomData,
+            _service_name: core::marker::PhantomData,
+            _template: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> StatefulSetSpecBuilder<crate::generics::SelectorExists, crate::generics::ServiceNameExists, crate::generics::TemplateExists>
+    where
+        Self: Clone,
+    {
+        StatefulSetSpecBuilder {
+            body: self.clone(),
+            _selector: core::marker::PhantomData,
+            _service_name: core::marker::PhantomData,
+            _template: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<StatefulSetSpec> for StatefulSetSpecBuilder<crate::generics::SelectorExists, crate::generics::ServiceNameExists, crate::generics::TemplateExists> {
+    fn into(self) -> StatefulSetSpec {
+        self.body
+    }
+}
+
+/// Builder for [`StatefulSetSpec`](./struct.StatefulSetSpec.html) object.
+#[derive(Debug, Clone)]
+pub struct StatefulSetSpecBuilder<Selector, ServiceName, Template> {
+    body: self::StatefulSetSpec,
+    _selector: core::marker::PhantomData<Selector>,
+    _service_name: core::marker::PhantomData<ServiceName>,
+    _template: core::marker::PhantomData<Template>,
+}
+
+impl<Selector, ServiceName, Template> StatefulSetSpecBuilder<Selector, ServiceName, Template> {
+    /// podManagementPolicy controls how pods are created during initial scale up, when replacing pods on nodes, or when scaling down. The default policy is `OrderedReady`, where pods are created in increasing order (pod-0, then pod-1, etc) and the controller will wait until each pod is ready before continuing. When scaling down, the pods are removed in the opposite order. The alternative policy is `Parallel` which will create pods in parallel to match the desired scale without waiting, and on scale down will delete all pods at once.
+    #[inline]
+    pub fn pod_management_policy(mut self, value: impl Into<String>) -> Self {
+        self.body.pod_management_policy = Some(value.into());
+        self
+    }
+
+    /// Sets [`pod_management_policy`](#method.pod_management_policy) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_pod_management_policy(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.pod_management_policy = value.map(|value| value.into());
+        self
+    }
+
+    /// replicas is the desired number of replicas of the given Template. These are replicas in the sense that they are instantiations of the same Template, but individual replicas also have a consistent identity. If unspecified, defaults to 1.
+    #[inline]
+    pub fn replicas(mut self, value: impl Into<i32>) -> Self {
+        self.body.replicas = Some(value.into());
+        self
+    }
+
+    /// Sets [`replicas`](#method.replicas) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_replicas(mut self, value: Option<impl Into<i32>>) -> Self {
+        self.body.replicas = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`replicas`](#method.replicas), but takes a reference.
+    #[inline]
+    pub fn replicas_ref(self, value: &i32) -> Self {
+        self.replicas(*value)
+    }
+
+    /// revisionHistoryLimit is the maximum number of revisions that will be maintained in the StatefulSet's revision history. The revision history consists of all revisions not represented by a currently applied StatefulSetSpec version. The default value is 10.
+    #[inline]
+    pub fn revision_history_limit(mut self, value: impl Into<i32>) -> Self {
+        self.body.revision_history_limit = Some(value.into());
+        self
+    }
+
+    /// Sets [`revision_history_limit`](#method.revision_history_limit) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_revision_history_limit(mut self, value: Option<impl Into<i32>>) -> Self {
+        self.body.revision_history_limit = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`revision_history_limit`](#method.revision_history_limit), but takes a reference.
+    #[inline]
+    pub fn revision_history_limit_ref(self, value: &i32) -> Self {
+        self.revision_history_limit(*value)
+    }
+
+    /// selector is a label query over pods that should match the replica count. It must match the pod template's labels. More info: https://kubernetes.io/docs/concepts/overview/working-with-objects/labels/#label-selectors
+    #[inline]
+    pub fn selector(mut self, value: crate::io::k8s::apimachinery::pkg::apis::meta::v1::label_selector::LabelSelector) -> StatefulSetSpecBuilder<crate::generics::SelectorExists, ServiceName, Template> {
+        self.body.selector = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// serviceName is the name of the service that governs this StatefulSet. This service must exist before the StatefulSet, and is responsible for the network identity of the set. Pods get DNS/hostnames that follow the pattern: pod-specific-string.serviceName.default.svc.cluster.local where "pod-specific-string" is managed by the StatefulSet controller.
+    #[inline]
+    pub fn service_name(mut self, value: impl Into<String>) -> StatefulSetSpecBuilder<Selector, crate::generics::ServiceNameExists, Template> {
+        self.body.service_name = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// template is the object that describes the pod that will be created if insufficient replicas are detected. Each pod stamped out by the StatefulSet will fulfill this Template, but have a unique identity from the rest of the StatefulSet.
+    #[inline]
+    pub fn template(mut self, value: crate::io::k8s::api::core::v1::pod_template_spec::PodTemplateSpec) -> StatefulSetSpecBuilder<Selector, ServiceName, crate::generics::TemplateExists> {
+        self.body.template = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// updateStrategy indicates the StatefulSetUpdateStrategy that will be employed to update Pods in the StatefulSet when a revision is made to Template.
+    #[inline]
+    pub fn update_strategy(mut self, value: crate::io::k8s::api::apps::v1::stateful_set_update_strategy::StatefulSetUpdateStrategy) -> Self {
+        self.body.update_strategy = Some(value.into());
+        self
+    }
+
+    /// Sets [`update_strategy`](#method.update_strategy) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_update_strategy(mut self, value: Option<crate::io::k8s::api::apps::v1::stateful_set_update_strategy::StatefulSetUpdateStrategy>) -> Self {
+        self.body.update_strategy = value.map(|value| value.into());
+        self
+    }
+
+    /// volumeClaimTemplates is a list of claims that pods are allowed to reference. The StatefulSet controller is responsible for mapping network identities to claims in a way that maintains the identity of a pod. Every claim in this list must have at least one matching (by name) volumeMount in one container in the template. A claim in this list takes precedence over any volumes in the template, with the same name.
+    #[inline]
+    pub fn volume_claim_templates(mut self, value: impl Iterator<Item = crate::io::k8s::api::core::v1::persistent_volume_claim::PersistentVolumeClaim>) -> Self {
+        self.body.volume_claim_templates = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`volume_claim_templates`](#method.volume_claim_templates) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_volume_claim_templates(mut self, value: Option<impl Iterator<Item = crate::io::k8s::api::core::v1::persistent_volume_claim::PersistentVolumeClaim>>) -> Self {
+        self.body.volume_claim_templates = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`volume_claim_templates`](#method.volume_claim_templates), but takes the values as a `Vec`.
+    #[inline]
+    pub fn volume_claim_templates_vec(mut self, value: impl Into<Vec<crate::io::k8s::api::core::v1::persistent_volume_claim::PersistentVolumeClaim>>) -> Self {
+        self.body.volume_claim_templates = Some(value.into());
+        self
+    }
+}
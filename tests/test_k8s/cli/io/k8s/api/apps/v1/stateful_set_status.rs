@@ -0,0 +1,231 @@
+
+/// StatefulSetStatus represents the current state of a StatefulSet.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct StatefulSetStatus {
+    /// collisionCount is the count of hash collisions for the StatefulSet. The StatefulSet controller uses this field as a collision avoidance mechanism when it needs to create the name for the newest ControllerRevision.
+    #[serde(rename = "collisionCount", skip_serializing_if = "Option::is_none")]
+    pub collision_count: Option<i32>,
+    /// Represents the latest available observations of a statefulset's current state.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub conditions: Option<Vec<crate::io::k8s::api::apps::v1::stateful_set_condition::StatefulSetCondition>>,
+    /// currentReplicas is the number of Pods created by the StatefulSet controller from the StatefulSet version indicated by currentRevision.
+    #[serde(rename = "currentReplicas", skip_serializing_if = "Option::is_none")]
+    pub current_replicas: Option<i32>,
+    /// currentRevision, if not empty, indicates the version of the StatefulSet used to generate Pods in the sequence \[0,currentReplicas).
+    #[serde(rename = "currentRevision", skip_serializing_if = "Option::is_none")]
+    pub current_revision: Option<String>,
+    /// observedGeneration is the most recent generation observed for this StatefulSet. It corresponds to the StatefulSet's generation, which is updated on mutation by the API Server.
+    #[serde(rename = "observedGeneration", skip_serializing_if = "Option::is_none")]
+    pub observed_generation: Option<i64>,
+    /// readyReplicas is the number of Pods created by the StatefulSet controller that have a Ready Condition.
+    #[serde(rename = "readyReplicas", skip_serializing_if = "Option::is_none")]
+    pub ready_replicas: Option<i32>,
+    /// replicas is the number of Pods created by the StatefulSet controller.
+    pub replicas: i32,
+    /// updateRevision, if not empty, indicates the version of the StatefulSet used to generate Pods in the sequence \[replicas-updatedReplicas,replicas)
+    #[serde(rename = "updateRevision", skip_serializing_if = "Option::is_none")]
+    pub update_revision: Option<String>,
+    /// updatedReplicas is the number of Pods created by the StatefulSet controller from the StatefulSet version indicated by updateRevision.
+    #[serde(rename = "updatedReplicas", skip_serializing_if = "Option::is_none")]
+    pub updated_replicas: Option<i32>,
+}
+
+impl StatefulSetStatus {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> StatefulSetStatusBuilder<crate::generics::MissingReplicas> {
+        StatefulSetStatusBuilder {
+            body: Default::default(),
+            _replicas: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> StatefulSetStatusBuilder<crate::generics::ReplicasExists>
+    where
+        Self: Clone,
+    {
+        StatefulSetStatusBuilder {
+            body: self.clone(),
+            _replicas: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<StatefulSetStatus> for StatefulSetStatusBuilder<crate::generics::ReplicasExists> {
+    fn into(self) -> StatefulSetStatus {
+        self.body
+    }
+}
+
+/// Builder for [`StatefulSetStatus`](./struct.StatefulSetStatus.html) object.
+#[derive(Debug, Clone)]
+pub struct StatefulSetStatusBuilder<Replicas> {
+    body: self::StatefulSetStatus,
+    _replicas: core::marker::PhantomData<Replicas>,
+}
+
+impl<Replicas> StatefulSetStatusBuilder<Replicas> {
+    /// collisionCount is the count of hash collisions for the StatefulSet. The StatefulSet controller uses this field as a collision avoidance mechanism when it needs to create the name for the newest ControllerRevision.
+    #[inline]
+    pub fn collision_count(mut self, value: impl Into<i32>) -> Self {
+        self.body.collision_count = Some(value.into());
+        self
+    }
+
+    /// Sets [`collision_count`](#method.collision_count) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_collision_count(mut self, value: Option<impl Into<i32>>) -> Self {
+        self.body.collision_count = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`collision_count`](#method.collision_count), but takes a reference.
+    #[inline]
+    pub fn collision_count_ref(self, value: &i32) -> Self {
+        self.collision_count(*value)
+    }
+
+    /// Represents the latest available observations of a statefulset's current state.
+    #[inline]
+    pub fn conditions(mut self, value: impl Iterator<Item = crate::io::k8s::api::apps::v1::stateful_set_condition::StatefulSetConditionBuilder<crate::generics::StatusExists, crate::generics::TypeExists>>) -> Self {
+        self.body.conditions = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`conditions`](#method.conditions) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_conditions(mut self, value: Option<impl Iterator<Item = crate::io::k8s::api::apps::v1::stateful_set_condition::StatefulSetConditionBuilder<crate::generics::StatusExists, crate::generics::TypeExists>>>) -> Self {
+        self.body.conditions = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`conditions`](#method.conditions), but takes the values as a `Vec`.
+    #[inline]
+    pub fn conditions_vec(mut self, value: impl Into<Vec<crate::io::k8s::api::apps::v1::stateful_set_condition::StatefulSetCondition>>) -> Self {
+        self.body.conditions = Some(value.into());
+        self
+    }
+
+    /// currentReplicas is the number of Pods created by the StatefulSet controller from the StatefulSet version indicated by currentRevision.
+    #[inline]
+    pub fn current_replicas(mut self, value: impl Into<i32>) -> Self {
+        self.body.current_replicas = Some(value.into());
+        self
+    }
+
+    /// Sets [`current_replicas`](#method.current_replicas) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_current_replicas(mut self, value: Option<impl Into<i32>>) -> Self {
+        self.body.current_replicas = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`current_replicas`](#method.current_replicas), but takes a reference.
+    #[inline]
+    pub fn current_replicas_ref(self, value: &i32) -> Self {
+        self.current_replicas(*value)
+    }
+
+    /// currentRevision, if not empty, indicates the version of the StatefulSet used to generate Pods in the sequence \[0,currentReplicas).
+    #[inline]
+    pub fn current_revision(mut self, value: impl Into<String>) -> Self {
+        self.body.current_revision = Some(value.into());
+        self
+    }
+
+    /// Sets [`current_revision`](#method.current_revision) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_current_revision(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.current_revision = value.map(|value| value.into());
+        self
+    }
+
+    /// observedGeneration is the most recent generation observed for this StatefulSet. It corresponds to the StatefulSet's generation, which is updated on mutation by the API Server.
+    #[inline]
+    pub fn observed_generation(mut self, value: impl Into<i64>) -> Self {
+        self.body.observed_generation = Some(value.into());
+        self
+    }
+
+    /// Sets [`observed_generation`](#method.observed_generation) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_observed_generation(mut self, value: Option<impl Into<i64>>) -> Self {
+        self.body.observed_generation = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`observed_generation`](#method.observed_generation), but takes a reference.
+    #[inline]
+    pub fn observed_generation_ref(self, value: &i64) -> Self {
+        self.observed_generation(*value)
+    }
+
+    /// readyReplicas is the number of Pods created by the StatefulSet controller that have a Ready Condition.
+    #[inline]
+    pub fn ready_replicas(mut self, value: impl Into<i32>) -> Self {
+        self.body.ready_replicas = Some(value.into());
+        self
+    }
+
+    /// Sets [`ready_replicas`](#method.ready_replicas) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_ready_replicas(mut self, value: Option<impl Into<i32>>) -> Self {
+        self.body.ready_replicas = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`ready_replicas`](#method.ready_replicas), but takes a reference.
+    #[inline]
+    pub fn ready_replicas_ref(self, value: &i32) -> Self {
+        self.ready_replicas(*value)
+    }
+
+    /// replicas is the number of Pods created by the StatefulSet controller.
+    #[inline]
+    pub fn replicas(mut self, value: impl Into<i32>) -> StatefulSetStatusBuilder<crate::generics::ReplicasExists> {
+        self.body.replicas = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Same as [`replicas`](#method.replicas), but takes a reference.
+    #[inline]
+    pub fn replicas_ref(self, value: &i32) -> StatefulSetStatusBuilder<crate::generics::ReplicasExists> {
+        self.replicas(*value)
+    }
+
+    /// updateRevision, if not empty, indicates the version of the StatefulSet used to generate Pods in the sequence \[replicas-updatedReplicas,replicas)
+    #[inline]
+    pub fn update_revision(mut self, value: impl Into<String>) -> Self {
+        self.body.update_revision = Some(value.into());
+        self
+    }
+
+    /// Sets [`update_revision`](#method.update_revision) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_update_revision(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.update_revision = value.map(|value| value.into());
+        self
+    }
+
+    /// updatedReplicas is the number of Pods created by the StatefulSet controller from the StatefulSet version indicated by updateRevision.
+    #[inline]
+    pub fn updated_replicas(mut self, value: impl Into<i32>) -> Self {
+        self.body.updated_replicas = Some(value.into());
+        self
+    }
+
+    /// Sets [`updated_replicas`](#method.updated_replicas) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_updated_replicas(mut self, value: Option<impl Into<i32>>) -> Self {
+        self.body.updated_replicas = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`updated_replicas`](#method.updated_replicas), but takes a reference.
+    #[inline]
+    pub fn updated_replicas_ref(self, value: &i32) -> Self {
+        self.updated_replicas(*value)
+    }
+}
@@ -0,0 +1,74 @@
+
+/// StatefulSetUpdateStrategy indicates the strategy that the StatefulSet controller will use to perform updates. It includes any additional parameters necessary to perform the update for the indicated strategy.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct StatefulSetUpdateStrategy {
+    /// RollingUpdate is used to communicate parameters when Type is RollingUpdateStatefulSetStrategyType.
+    #[serde(rename = "rollingUpdate", skip_serializing_if = "Option::is_none")]
+    pub rolling_update: Option<crate::io::k8s::api::apps::v1::rolling_update_stateful_set_strategy::RollingUpdateStatefulSetStrategy>,
+    /// Type indicates the type of the StatefulSetUpdateStrategy. Default is RollingUpdate.
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub type_: Option<String>,
+}
+
+impl StatefulSetUpdateStrategy {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> StatefulSetUpdateStrategyBuilder {
+        StatefulSetUpdateStrategyBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> StatefulSetUpdateStrategyBuilder
+    where
+        Self: Clone,
+    {
+        StatefulSetUpdateStrategyBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<StatefulSetUpdateStrategy> for StatefulSetUpdateStrategyBuilder {
+    fn into(self) -> StatefulSetUpdateStrategy {
+        self.body
+    }
+}
+
+/// Builder for [`StatefulSetUpdateStrategy`](./struct.StatefulSetUpdateStrategy.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct StatefulSetUpdateStrategyBuilder {
+    body: self::StatefulSetUpdateStrategy,
+}
+
+impl StatefulSetUpdateStrategyBuilder {
+    /// RollingUpdate is used to communicate parameters when Type is RollingUpdateStatefulSetStrategyType.
+    #[inline]
+    pub fn rolling_update(mut self, value: crate::io::k8s::api::apps::v1::rolling_update_stateful_set_strategy::RollingUpdateStatefulSetStrategy) -> Self {
+        self.body.rolling_update = Some(value.into());
+        self
+    }
+
+    /// Sets [`rolling_update`](#method.rolling_update) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_rolling_update(mut self, value: Option<crate::io::k8s::api::apps::v1::rolling_update_stateful_set_strategy::RollingUpdateStatefulSetStrategy>) -> Self {
+        self.body.rolling_update = value.map(|value| value.into());
+        self
+    }
+
+    /// Type indicates the type of the StatefulSetUpdateStrategy. Default is RollingUpdate.
+    #[inline]
+    pub fn type_(mut self, value: impl Into<String>) -> Self {
+        self.body.type_ = Some(value.into());
+        self
+    }
+
+    /// Sets [`type_`](#method.type_) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_type(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.type_ = value.map(|value| value.into());
+        self
+    }
+}
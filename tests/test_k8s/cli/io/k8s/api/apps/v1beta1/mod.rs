@@ -0,0 +1,84 @@
+
+pub mod controller_revision {
+    include!("./controller_revision.rs");
+}
+
+pub mod controller_revision_list {
+    include!("./controller_revision_list.rs");
+}
+
+pub mod deployment {
+    include!("./deployment.rs");
+}
+
+pub mod deployment_condition {
+    include!("./deployment_condition.rs");
+}
+
+pub mod deployment_list {
+    include!("./deployment_list.rs");
+}
+
+pub mod deployment_rollback {
+    include!("./deployment_rollback.rs");
+}
+
+pub mod deployment_spec {
+    include!("./deployment_spec.rs");
+}
+
+pub mod deployment_status {
+    include!("./deployment_status.rs");
+}
+
+pub mod deployment_strategy {
+    include!("./deployment_strategy.rs");
+}
+
+pub mod rollback_config {
+    include!("./rollback_config.rs");
+}
+
+pub mod rolling_update_deployment {
+    include!("./rolling_update_deployment.rs");
+}
+
+pub mod rolling_update_stateful_set_strategy {
+    include!("./rolling_update_stateful_set_strategy.rs");
+}
+
+pub mod scale {
+    include!("./scale.rs");
+}
+
+pub mod scale_spec {
+    include!("./scale_spec.rs");
+}
+
+pub mod scale_status {
+    include!("./scale_status.rs");
+}
+
+pub mod stateful_set {
+    include!("./stateful_set.rs");
+}
+
+pub mod stateful_set_condition {
+    include!("./stateful_set_condition.rs");
+}
+
+pub mod stateful_set_list {
+    include!("./stateful_set_list.rs");
+}
+
+pub mod stateful_set_spec {
+    include!("./stateful_set_spec.rs");
+}
+
+pub mod stateful_set_status {
+    include!("./stateful_set_status.rs");
+}
+
+pub mod stateful_set_update_strategy {
+    include!("./stateful_set_update_strategy.rs");
+}
@@ -0,0 +1,63 @@
+
+/// DEPRECATED.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct RollbackConfig {
+    /// The revision to rollback to. If set to 0, rollback to the last revision.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub revision: Option<i64>,
+}
+
+impl RollbackConfig {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> RollbackConfigBuilder {
+        RollbackConfigBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> RollbackConfigBuilder
+    where
+        Self: Clone,
+    {
+        RollbackConfigBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<RollbackConfig> for RollbackConfigBuilder {
+    fn into(self) -> RollbackConfig {
+        self.body
+    }
+}
+
+/// Builder for [`RollbackConfig`](./struct.RollbackConfig.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct RollbackConfigBuilder {
+    body: self::RollbackConfig,
+}
+
+impl RollbackConfigBuilder {
+    /// The revision to rollback to. If set to 0, rollback to the last revision.
+    #[inline]
+    pub fn revision(mut self, value: impl Into<i64>) -> Self {
+        self.body.revision = Some(value.into());
+        self
+    }
+
+    /// Sets [`revision`](#method.revision) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_revision(mut self, value: Option<impl Into<i64>>) -> Self {
+        self.body.revision = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`revision`](#method.revision), but takes a reference.
+    #[inline]
+    pub fn revision_ref(self, value: &i64) -> Self {
+        self.revision(*value)
+    }
+}
@@ -0,0 +1,63 @@
+
+/// ScaleSpec describes the attributes of a scale subresource
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct ScaleSpec {
+    /// desired number of instances for the scaled object.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub replicas: Option<i32>,
+}
+
+impl ScaleSpec {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> ScaleSpecBuilder {
+        ScaleSpecBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> ScaleSpecBuilder
+    where
+        Self: Clone,
+    {
+        ScaleSpecBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<ScaleSpec> for ScaleSpecBuilder {
+    fn into(self) -> ScaleSpec {
+        self.body
+    }
+}
+
+/// Builder for [`ScaleSpec`](./struct.ScaleSpec.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct ScaleSpecBuilder {
+    body: self::ScaleSpec,
+}
+
+impl ScaleSpecBuilder {
+    /// desired number of instances for the scaled object.
+    #[inline]
+    pub fn replicas(mut self, value: impl Into<i32>) -> Self {
+        self.body.replicas = Some(value.into());
+        self
+    }
+
+    /// Sets [`replicas`](#method.replicas) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_replicas(mut self, value: Option<impl Into<i32>>) -> Self {
+        self.body.replicas = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`replicas`](#method.replicas), but takes a reference.
+    #[inline]
+    pub fn replicas_ref(self, value: &i32) -> Self {
+        self.replicas(*value)
+    }
+}
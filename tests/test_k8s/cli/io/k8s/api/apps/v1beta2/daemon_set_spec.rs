@@ -0,0 +1,127 @@
+
+/// DaemonSetSpec is the specification of a daemon set.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct DaemonSetSpec {
+    /// The minimum number of seconds for which a newly created DaemonSet pod should be ready without any of its container crashing, for it to be considered available. Defaults to 0 (pod will be considered available as soon as it is ready).
+    #[serde(rename = "minReadySeconds", skip_serializing_if = "Option::is_none")]
+    pub min_ready_seconds: Option<i32>,
+    /// The number of old history to retain to allow rollback. This is a pointer to distinguish between explicit zero and not specified. Defaults to 10.
+    #[serde(rename = "revisionHistoryLimit", skip_serializing_if = "Option::is_none")]
+    pub revision_history_limit: Option<i32>,
+    /// A label query over pods that are managed by the daemon set. Must match in order to be controlled. It must match the pod template's labels. More info: https://kubernetes.io/docs/concepts/overview/working-with-objects/labels/#label-selectors
+    pub selector: crate::io::k8s::apimachinery::pkg::apis::meta::v1::label_selector::LabelSelector,
+    /// An object that describes the pod that will be created. The DaemonSet will create exactly one copy of this pod on every node that matches the template's node selector (or on every node if no node selector is specified). More info: https://kubernetes.io/docs/concepts/workloads/controllers/replicationcontroller#pod-template
+    pub template: crate::io::k8s::api::core::v1::pod_template_spec::PodTemplateSpec,
+    /// An update strategy to replace existing DaemonSet pods with new pods.
+    #[serde(rename = "updateStrategy", skip_serializing_if = "Option::is_none")]
+    pub update_strategy: Option<crate::io::k8s::api::apps::v1beta2::daemon_set_update_strategy::DaemonSetUpdateStrategy>,
+}
+
+impl DaemonSetSpec {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> DaemonSetSpecBuilder<crate::generics::MissingSelector, crate::generics::MissingTemplate> {
+        DaemonSetSpecBuilder {
+            body: Default::default(),
+            _selector: core::marker::PhantomData,
+            _template: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> DaemonSetSpecBuilder<crate::generics::SelectorExists, crate::generics::TemplateExists>
+    where
+        Self: Clone,
+    {
+        DaemonSetSpecBuilder {
+            body: self.clone(),
+            _selector: core::marker::PhantomData,
+            _template: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<DaemonSetSpec> for DaemonSetSpecBuilder<crate::generics::SelectorExists, crate::generics::TemplateExists> {
+    fn into(self) -> DaemonSetSpec {
+        self.body
+    }
+}
+
+/// Builder for [`DaemonSetSpec`](./struct.DaemonSetSpec.html) object.
+#[derive(Debug, Clone)]
+pub struct DaemonSetSpecBuilder<Selector, Template> {
+    body: self::DaemonSetSpec,
+    _selector: core::marker::PhantomData<Selector>,
+    _template: core::marker::PhantomData<Template>,
+}
+
+impl<Selector, Template> DaemonSetSpecBuilder<Selector, Template> {
+    /// The minimum number of seconds for which a newly created DaemonSet pod should be ready without any of its container crashing, for it to be considered available. Defaults to 0 (pod will be considered available as soon as it is ready).
+    #[inline]
+    pub fn min_ready_seconds(mut self, value: impl Into<i32>) -> Self {
+        self.body.min_ready_seconds = Some(value.into());
+        self
+    }
+
+    /// Sets [`min_ready_seconds`](#method.min_ready_seconds) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_min_ready_seconds(mut self, value: Option<impl Into<i32>>) -> Self {
+        self.body.min_ready_seconds = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`min_ready_seconds`](#method.min_ready_seconds), but takes a reference.
+    #[inline]
+    pub fn min_ready_seconds_ref(self, value: &i32) -> Self {
+        self.min_ready_seconds(*value)
+    }
+
+    /// The number of old history to retain to allow rollback. This is a pointer to distinguish between explicit zero and not specified. Defaults to 10.
+    #[inline]
+    pub fn revision_history_limit(mut self, value: impl Into<i32>) -> Self {
+        self.body.revision_history_limit = Some(value.into());
+        self
+    }
+
+    /// Sets [`revision_history_limit`](#method.revision_history_limit) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_revision_history_limit(mut self, value: Option<impl Into<i32>>) -> Self {
+        self.body.revision_history_limit = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`revision_history_limit`](#method.revision_history_limit), but takes a reference.
+    #[inline]
+    pub fn revision_history_limit_ref(self, value: &i32) -> Self {
+        self.revision_history_limit(*value)
+    }
+
+    /// A label query over pods that are managed by the daemon set. Must match in order to be controlled. It must match the pod template's labels. More info: https://kubernetes.io/docs/concepts/overview/working-with-objects/labels/#label-selectors
+    #[inline]
+    pub fn selector(mut self, value: crate::io::k8s::apimachinery::pkg::apis::meta::v1::label_selector::LabelSelector) -> DaemonSetSpecBuilder<crate::generics::SelectorExists, Template> {
+        self.body.selector = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// An object that describes the pod that will be created. The DaemonSet will create exactly one copy of this pod on every node that matches the template's node selector (or on every node if no node selector is specified). More info: https://kubernetes.io/docs/concepts/workloads/controllers/replicationcontroller#pod-template
+    #[inline]
+    pub fn template(mut self, value: crate::io::k8s::api::core::v1::pod_template_spec::PodTemplateSpec) -> DaemonSetSpecBuilder<Selector, crate::generics::TemplateExists> {
+        self.body.template = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// An update strategy to replace existing DaemonSet pods with new pods.
+    #[inline]
+    pub fn update_strategy(mut self, value: crate::io::k8s::api::apps::v1beta2::daemon_set_update_strategy::DaemonSetUpdateStrategy) -> Self {
+        self.body.update_strategy = Some(value.into());
+        self
+    }
+
+    /// Sets [`update_strategy`](#method.update_strategy) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_update_strategy(mut self, value: Option<crate::io::k8s::api::apps::v1beta2::daemon_set_update_strategy::DaemonSetUpdateStrategy>) -> Self {
+        self.body.update_strategy = value.map(|value| value.into());
+        self
+    }
+}
@@ -0,0 +1,196 @@
+
+/// DeploymentSpec is the specification of the desired behavior of the Deployment.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct DeploymentSpec {
+    /// Minimum number of seconds for which a newly created pod should be ready without any of its container crashing, for it to be considered available. Defaults to 0 (pod will be considered available as soon as it is ready)
+    #[serde(rename = "minReadySeconds", skip_serializing_if = "Option::is_none")]
+    pub min_ready_seconds: Option<i32>,
+    /// Indicates that the deployment is paused.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub paused: Option<bool>,
+    /// The maximum time in seconds for a deployment to make progress before it is considered to be failed. The deployment controller will continue to process failed deployments and a condition with a ProgressDeadlineExceeded reason will be surfaced in the deployment status. Note that progress will not be estimated during the time a deployment is paused. Defaults to 600s.
+    #[serde(rename = "progressDeadlineSeconds", skip_serializing_if = "Option::is_none")]
+    pub progress_deadline_seconds: Option<i32>,
+    /// Number of desired pods. This is a pointer to distinguish between explicit zero and not specified. Defaults to 1.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub replicas: Option<i32>,
+    /// The number of old ReplicaSets to retain to allow rollback. This is a pointer to distinguish between explicit zero and not specified. Defaults to 10.
+    #[serde(rename = "revisionHistoryLimit", skip_serializing_if = "Option::is_none")]
+    pub revision_history_limit: Option<i32>,
+    /// Label selector for pods. Existing ReplicaSets whose pods are selected by this will be the ones affected by this deployment. It must match the pod template's labels.
+    pub selector: crate::io::k8s::apimachinery::pkg::apis::meta::v1::label_selector::LabelSelector,
+    /// The deployment strategy to use to replace existing pods with new ones.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub strategy: Option<crate::io::k8s::api::apps::v1beta2::deployment_strategy::DeploymentStrategy>,
+    /// Template describes the pods that will be created.
+    pub template: crate::io::k8s::api::core::v1::pod_template_spec::PodTemplateSpec,
+}
+
+impl DeploymentSpec {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> DeploymentSpecBuilder<crate::generics::MissingSelector, crate::generics::MissingTemplate> {
+        DeploymentSpecBuilder {
+            body: Default::default(),
+            _selector: core::marker::PhantomData,
+            _template: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> DeploymentSpecBuilder<crate::generics::SelectorExists, crate::generics::TemplateExists>
+    where
+        Self: Clone,
+    {
+        DeploymentSpecBuilder {
+            body: self.clone(),
+            _selector: core::marker::PhantomData,
+            _template: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<DeploymentSpec> for DeploymentSpecBuilder<crate::generics::SelectorExists, crate::generics::TemplateExists> {
+    fn into(self) -> DeploymentSpec {
+        self.body
+    }
+}
+
+/// Builder for [`DeploymentSpec`](./struct.DeploymentSpec.html) object.
+#[derive(Debug, Clone)]
+pub struct DeploymentSpecBuilder<Selector, Template> {
+    body: self::DeploymentSpec,
+    _selector: core::marker::PhantomData<Selector>,
+    _template: core::marker::PhantomData<Template>,
+}
+
+impl<Selector, Template> DeploymentSpecBuilder<Selector, Template> {
+    /// Minimum number of seconds for which a newly created pod should be ready without any of its container crashing, for it to be considered available. Defaults to 0 (pod will be considered available as soon as it is ready)
+    #[inline]
+    pub fn min_ready_seconds(mut self, value: impl Into<i32>) -> Self {
+        self.body.min_ready_seconds = Some(value.into());
+        self
+    }
+
+    /// Sets [`min_ready_seconds`](#method.min_ready_seconds) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_min_ready_seconds(mut self, value: Option<impl Into<i32>>) -> Self {
+        self.body.min_ready_seconds = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`min_ready_seconds`](#method.min_ready_seconds), but takes a reference.
+    #[inline]
+    pub fn min_ready_seconds_ref(self, value: &i32) -> Self {
+        self.min_ready_seconds(*value)
+    }
+
+    /// Indicates that the deployment is paused.
+    #[inline]
+    pub fn paused(mut self, value: impl Into<bool>) -> Self {
+        self.body.paused = Some(value.into());
+        self
+    }
+
+    /// Sets [`paused`](#method.paused) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_paused(mut self, value: Option<impl Into<bool>>) -> Self {
+        self.body.paused = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`paused`](#method.paused), but takes a reference.
+    #[inline]
+    pub fn paused_ref(self, value: &bool) -> Self {
+        self.paused(*value)
+    }
+
+    /// The maximum time in seconds for a deployment to make progress before it is considered to be failed. The deployment controller will continue to process failed deployments and a condition with a ProgressDeadlineExceeded reason will be surfaced in the deployment status. Note that progress will not be estimated during the time a deployment is paused. Defaults to 600s.
+    #[inline]
+    pub fn progress_deadline_seconds(mut self, value: impl Into<i32>) -> Self {
+        self.body.progress_deadline_seconds = Some(value.into());
+        self
+    }
+
+    /// Sets [`progress_deadline_seconds`](#method.progress_deadline_seconds) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_progress_deadline_seconds(mut self, value: Option<impl Into<i32>>) -> Self {
+        self.body.progress_deadline_seconds = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`progress_deadline_seconds`](#method.progress_deadline_seconds), but takes a reference.
+    #[inline]
+    pub fn progress_deadline_seconds_ref(self, value: &i32) -> Self {
+        self.progress_deadline_seconds(*value)
+    }
+
+    /// Number of desired pods. This is a pointer to distinguish between explicit zero and not specified. Defaults to 1.
+    #[inline]
+    pub fn replicas(mut self, value: impl Into<i32>) -> Self {
+        self.body.replicas = Some(value.into());
+        self
+    }
+
+    /// Sets [`replicas`](#method.replicas) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_replicas(mut self, value: Option<impl Into<i32>>) -> Self {
+        self.body.replicas = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`replicas`](#method.replicas), but takes a reference.
+    #[inline]
+    pub fn replicas_ref(self, value: &i32) -> Self {
+        self.replicas(*value)
+    }
+
+    /// The number of old ReplicaSets to retain to allow rollback. This is a pointer to distinguish between explicit zero and not specified. Defaults to 10.
+    #[inline]
+    pub fn revision_history_limit(mut self, value: impl Into<i32>) -> Self {
+        self.body.revision_history_limit = Some(value.into());
+        self
+    }
+
+    /// Sets [`revision_history_limit`](#method.revision_history_limit) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_revision_history_limit(mut self, value: Option<impl Into<i32>>) -> Self {
+        self.body.revision_history_limit = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`revision_history_limit`](#method.revision_history_limit), but takes a reference.
+    #[inline]
+    pub fn revision_history_limit_ref(self, value: &i32) -> Self {
+        self.revision_history_limit(*value)
+    }
+
+    /// Label selector for pods. Existing ReplicaSets whose pods are selected by this will be the ones affected by this deployment. It must match the pod template's labels.
+    #[inline]
+    pub fn selector(mut self, value: crate::io::k8s::apimachinery::pkg::apis::meta::v1::label_selector::LabelSelector) -> DeploymentSpecBuilder<crate::generics::SelectorExists, Template> {
+        self.body.selector = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// The deployment strategy to use to replace existing pods with new ones.
+    #[inline]
+    pub fn strategy(mut self, value: crate::io::k8s::api::apps::v1beta2::deployment_strategy::DeploymentStrategy) -> Self {
+        self.body.strategy = Some(value.into());
+        self
+    }
+
+    /// Sets [`strategy`](#method.strategy) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_strategy(mut self, value: Option<crate::io::k8s::api::apps::v1beta2::deployment_strategy::DeploymentStrategy>) -> Self {
+        self.body.strategy = value.map(|value| value.into());
+        self
+    }
+
+    /// Template describes the pods that will be created.
+    #[inline]
+    pub fn template(mut self, value: crate::io::k8s::api::core::v1::pod_template_spec::PodTemplateSpec) -> DeploymentSpecBuilder<Selector, crate::generics::TemplateExists> {
+        self.body.template = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+}
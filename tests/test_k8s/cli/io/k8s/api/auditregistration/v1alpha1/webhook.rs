@@ -0,0 +1,70 @@
+
+/// Webhook holds the configuration of the webhook
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct Webhook {
+    /// ClientConfig holds the connection parameters for the webhook required
+    #[serde(rename = "clientConfig")]
+    pub client_config: crate::io::k8s::api::auditregistration::v1alpha1::webhook_client_config::WebhookClientConfig,
+    /// Throttle holds the options for throttling the webhook
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub throttle: Option<crate::io::k8s::api::auditregistration::v1alpha1::webhook_throttle_config::WebhookThrottleConfig>,
+}
+
+impl Webhook {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> WebhookBuilder<crate::generics::MissingClientConfig> {
+        WebhookBuilder {
+            body: Default::default(),
+            _client_config: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> WebhookBuilder<crate::generics::ClientConfigExists>
+    where
+        Self: Clone,
+    {
+        WebhookBuilder {
+            body: self.clone(),
+            _client_config: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<Webhook> for WebhookBuilder<crate::generics::ClientConfigExists> {
+    fn into(self) -> Webhook {
+        self.body
+    }
+}
+
+/// Builder for [`Webhook`](./struct.Webhook.html) object.
+#[derive(Debug, Clone)]
+pub struct WebhookBuilder<ClientConfig> {
+    body: self::Webhook,
+    _client_config: core::marker::PhantomData<ClientConfig>,
+}
+
+impl<ClientConfig> WebhookBuilder<ClientConfig> {
+    /// ClientConfig holds the connection parameters for the webhook required
+    #[inline]
+    pub fn client_config(mut self, value: crate::io::k8s::api::auditregistration::v1alpha1::webhook_client_config::WebhookClientConfig) -> WebhookBuilder<crate::generics::ClientConfigExists> {
+        self.body.client_config = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Throttle holds the options for throttling the webhook
+    #[inline]
+    pub fn throttle(mut self, value: crate::io::k8s::api::auditregistration::v1alpha1::webhook_throttle_config::WebhookThrottleConfig) -> Self {
+        self.body.throttle = Some(value.into());
+        self
+    }
+
+    /// Sets [`throttle`](#method.throttle) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_throttle(mut self, value: Option<crate::io::k8s::api::auditregistration::v1alpha1::webhook_throttle_config::WebhookThrottleConfig>) -> Self {
+        self.body.throttle = value.map(|value| value.into());
+        self
+    }
+}
@@ -0,0 +1,81 @@
+
+/// TokenReviewSpec is a description of the token authentication request.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct TokenReviewSpec {
+    /// Audiences is a list of the identifiers that the resource server presented with the token identifies as. Audience-aware token authenticators will verify that the token was intended for at least one of the audiences in this list. If no audiences are provided, the audience will default to the audience of the Kubernetes apiserver.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub audiences: Option<Vec<String>>,
+    /// Token is the opaque bearer token.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token: Option<String>,
+}
+
+impl TokenReviewSpec {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> TokenReviewSpecBuilder {
+        TokenReviewSpecBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> TokenReviewSpecBuilder
+    where
+        Self: Clone,
+    {
+        TokenReviewSpecBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<TokenReviewSpec> for TokenReviewSpecBuilder {
+    fn into(self) -> TokenReviewSpec {
+        self.body
+    }
+}
+
+/// Builder for [`TokenReviewSpec`](./struct.TokenReviewSpec.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct TokenReviewSpecBuilder {
+    body: self::TokenReviewSpec,
+}
+
+impl TokenReviewSpecBuilder {
+    /// Audiences is a list of the identifiers that the resource server presented with the token identifies as. Audience-aware token authenticators will verify that the token was intended for at least one of the audiences in this list. If no audiences are provided, the audience will default to the audience of the Kubernetes apiserver.
+    #[inline]
+    pub fn audiences(mut self, value: impl Iterator<Item = impl Into<String>>) -> Self {
+        self.body.audiences = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`audiences`](#method.audiences) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_audiences(mut self, value: Option<impl Iterator<Item = impl Into<String>>>) -> Self {
+        self.body.audiences = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`audiences`](#method.audiences), but takes the values as a `Vec`.
+    #[inline]
+    pub fn audiences_vec(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.body.audiences = Some(value.into());
+        self
+    }
+
+    /// Token is the opaque bearer token.
+    #[inline]
+    pub fn token(mut self, value: impl Into<String>) -> Self {
+        self.body.token = Some(value.into());
+        self
+    }
+
+    /// Sets [`token`](#method.token) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_token(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.token = value.map(|value| value.into());
+        self
+    }
+}
@@ -0,0 +1,345 @@
+
+/// LocalSubjectAccessReview checks whether or not a user or group can perform an action in a given namespace. Having a namespace scoped resource makes it much easier to grant namespace scoped policy that includes permissions checking.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct LocalSubjectAccessReview {
+    /// APIVersion defines the versioned schema of this representation of an object. Servers should convert recognized schemas to the latest internal value, and may reject unrecognized values. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#resources
+    #[serde(rename = "apiVersion", skip_serializing_if = "Option::is_none")]
+    pub api_version: Option<String>,
+    /// Kind is a string value representing the REST resource this object represents. Servers may infer this from the endpoint the client submits requests to. Cannot be updated. In CamelCase. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#types-kinds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub kind: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<crate::io::k8s::apimachinery::pkg::apis::meta::v1::object_meta::ObjectMeta>,
+    /// Spec holds information about the request being evaluated.  spec.namespace must be equal to the namespace you made the request against.  If empty, it is defaulted.
+    pub spec: crate::io::k8s::api::authorization::v1::subject_access_review_spec::SubjectAccessReviewSpec,
+    /// Status is filled in by the server and indicates whether the request is allowed or not
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<crate::io::k8s::api::authorization::v1::subject_access_review_status::SubjectAccessReviewStatus>,
+}
+
+impl LocalSubjectAccessReview {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> LocalSubjectAccessReviewBuilder<crate::generics::MissingSpec> {
+        LocalSubjectAccessReviewBuilder {
+            body: Default::default(),
+            _spec: core::marker::PhantomData,
+        }
+    }
+
+    /// create a LocalSubjectAccessReview
+    #[inline]
+    pub fn create_authorization_v1_namespaced_local_subject_access_review() -> LocalSubjectAccessReviewPostBuilder<crate::generics::MissingNamespace, crate::generics::MissingSpec> {
+        LocalSubjectAccessReviewPostBuilder {
+            inner: Default::default(),
+            _param_namespace: core::marker::PhantomData,
+            _spec: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> LocalSubjectAccessReviewBuilder<crate::generics::SpecExists>
+    where
+        Self: Clone,
+    {
+        LocalSubjectAccessReviewBuilder {
+            body: self.clone(),
+            _spec: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<LocalSubjectAccessReview> for LocalSubjectAccessReviewBuilder<crate::generics::SpecExists> {
+    fn into(self) -> LocalSubjectAccessReview {
+        self.body
+    }
+}
+
+impl Into<LocalSubjectAccessReview> for LocalSubjectAccessReviewPostBuilder<crate::generics::NamespaceExists, crate::generics::SpecExists> {
+    fn into(self) -> LocalSubjectAccessReview {
+        self.inner.body
+    }
+}
+
+/// Builder for [`LocalSubjectAccessReview`](./struct.LocalSubjectAccessReview.html) object.
+#[derive(Debug, Clone)]
+pub struct LocalSubjectAccessReviewBuilder<Spec> {
+    body: self::LocalSubjectAccessReview,
+    _spec: core::marker::PhantomData<Spec>,
+}
+
+impl<Spec> LocalSubjectAccessReviewBuilder<Spec> {
+    /// APIVersion defines the versioned schema of this representation of an object. Servers should convert recognized schemas to the latest internal value, and may reject unrecognized values. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#resources
+    #[inline]
+    pub fn api_version(mut self, value: impl Into<String>) -> Self {
+        self.body.api_version = Some(value.into());
+        self
+    }
+
+    /// Sets [`api_version`](#method.api_version) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_api_version(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.api_version = value.map(|value| value.into());
+        self
+    }
+
+    /// Kind is a string value representing the REST resource this object represents. Servers may infer this from the endpoint the client submits requests to. Cannot be updated. In CamelCase. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#types-kinds
+    #[inline]
+    pub fn kind(mut self, value: impl Into<String>) -> Self {
+        self.body.kind = Some(value.into());
+        self
+    }
+
+    /// Sets [`kind`](#method.kind) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_kind(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.kind = value.map(|value| value.into());
+        self
+    }
+
+    #[inline]
+    pub fn metadata(mut self, value: crate::io::k8s::apimachinery::pkg::apis::meta::v1::object_meta::ObjectMeta) -> Self {
+        self.body.metadata = Some(value.into());
+        self
+    }
+
+    /// Sets [`metadata`](#method.metadata) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_metadata(mut self, value: Option<crate::io::k8s::apimachinery::pkg::apis::meta::v1::object_meta::ObjectMeta>) -> Self {
+        self.body.metadata = value.map(|value| value.into());
+        self
+    }
+
+    /// Spec holds information about the request being evaluated.  spec.namespace must be equal to the namespace you made the request against.  If empty, it is defaulted.
+    #[inline]
+    pub fn spec(mut self, value: crate::io::k8s::api::authorization::v1::subject_access_review_spec::SubjectAccessReviewSpec) -> LocalSubjectAccessReviewBuilder<crate::generics::SpecExists> {
+        self.body.spec = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Status is filled in by the server and indicates whether the request is allowed or not
+    #[inline]
+    pub fn status(mut self, value: crate::io::k8s::api::authorization::v1::subject_access_review_status::SubjectAccessReviewStatusBuilder<crate::generics::AllowedExists>) -> Self {
+        self.body.status = Some(value.into());
+        self
+    }
+
+    /// Sets [`status`](#method.status) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_status(mut self, value: Option<crate::io::k8s::api::authorization::v1::subject_access_review_status::SubjectAccessReviewStatusBuilder<crate::generics::AllowedExists>>) -> Self {
+        self.body.status = value.map(|value| value.into());
+        self
+    }
+}
+
+/// Builder created by [`LocalSubjectAccessReview::create_authorization_v1_namespaced_local_subject_access_review`](./struct.LocalSubjectAccessReview.html#method.create_authorization_v1_namespaced_local_subject_access_review) method for a `POST` operation associated with `LocalSubjectAccessReview`.
+#[repr(transparent)]
+#[derive(Debug, Clone)]
+pub struct LocalSubjectAccessReviewPostBuilder<Namespace, Spec> {
+    inner: LocalSubjectAccessReviewPostBuilderContainer,
+    _param_namespace: core::marker::PhantomData<Namespace>,
+    _spec: core::marker::PhantomData<Spec>,
+}
+
+#[derive(Debug, Default, Clone)]
+struct LocalSubjectAccessReviewPostBuilderContainer {
+    body: self::LocalSubjectAccessReview,
+    param_dry_run: Option<String>,
+    param_field_manager: Option<String>,
+    param_namespace: Option<String>,
+    param_pretty: Option<String>,
+}
+
+impl<Namespace, Spec> LocalSubjectAccessReviewPostBuilder<Namespace, Spec> {
+    /// When present, indicates that modifications should not be persisted. An invalid or unrecognized dryRun directive will result in an error response and no further processing of the request. Valid values are: - All: all dry run stages will be processed
+    #[inline]
+    pub fn dry_run(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_dry_run = Some(value.into());
+        self
+    }
+
+    /// Sets [`dry_run`](#method.dry_run) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_dry_run(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_dry_run = value.map(|value| value.into());
+        self
+    }
+
+    /// fieldManager is a name associated with the actor or entity that is making these changes. The value must be less than or 128 characters long, and only contain printable characters, as defined by https://golang.org/pkg/unicode/#IsPrint.
+    #[inline]
+    pub fn field_manager(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_field_manager = Some(value.into());
+        self
+    }
+
+    /// Sets [`field_manager`](#method.field_manager) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_field_manager(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_field_manager = value.map(|value| value.into());
+        self
+    }
+
+    /// object name and auth scope, such as for teams and projects
+    #[inline]
+    pub fn namespace(mut self, value: impl Into<String>) -> LocalSubjectAccessReviewPostBuilder<crate::generics::NamespaceExists, Spec> {
+        self.inner.param_namespace = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// If 'true', then the output is pretty printed.
+    #[inline]
+    pub fn pretty(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_pretty = Some(value.into());
+        self
+    }
+
+    /// Sets [`pretty`](#method.pretty) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_pretty(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_pretty = value.map(|value| value.into());
+        self
+    }
+
+    /// APIVersion defines the versioned schema of this representation of an object. Servers should convert recognized schemas to the latest internal value, and may reject unrecognized values. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#resources
+    #[inline]
+    pub fn api_version(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.api_version = Some(value.into());
+        self
+    }
+
+    /// Sets [`api_version`](#method.api_version) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_api_version(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.api_version = value.map(|value| value.into());
+        self
+    }
+
+    /// Kind is a string value representing the REST resource this object represents. Servers may infer this from the endpoint the client submits requests to. Cannot be updated. In CamelCase. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#types-kinds
+    #[inline]
+    pub fn kind(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.kind = Some(value.into());
+        self
+    }
+
+    /// Sets [`kind`](#method.kind) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_kind(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.kind = value.map(|value| value.into());
+        self
+    }
+
+    #[inline]
+    pub fn metadata(mut self, value: crate::io::k8s::apimachinery::pkg::apis::meta::v1::object_meta::ObjectMeta) -> Self {
+        self.inner.body.metadata = Some(value.into());
+        self
+    }
+
+    /// Sets [`metadata`](#method.metadata) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_metadata(mut self, value: Option<crate::io::k8s::apimachinery::pkg::apis::meta::v1::object_meta::ObjectMeta>) -> Self {
+        self.inner.body.metadata = value.map(|value| value.into());
+        self
+    }
+
+    /// Spec holds information about the request being evaluated.  spec.namespace must be equal to the namespace you made the request against.  If empty, it is defaulted.
+    #[inline]
+    pub fn spec(mut self, value: crate::io::k8s::api::authorization::v1::subject_access_review_spec::SubjectAccessReviewSpec) -> LocalSubjectAccessReviewPostBuilder<Namespace, crate::generics::SpecExists> {
+        self.inner.body.spec = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Status is filled in by the server and indicates whether the request is allowed or not
+    #[inline]
+    pub fn status(mut self, value: crate::io::k8s::api::authorization::v1::subject_access_review_status::SubjectAccessReviewStatusBuilder<crate::generics::AllowedExists>) -> Self {
+        self.inner.body.status = Some(value.into());
+        self
+    }
+
+    /// Sets [`status`](#method.status) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_status(mut self, value: Option<crate::io::k8s::api::authorization::v1::subject_access_review_status::SubjectAccessReviewStatusBuilder<crate::generics::AllowedExists>>) -> Self {
+        self.inner.body.status = value.map(|value| value.into());
+        self
+    }
+}
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for LocalSubjectAccessReviewPostBuilder<crate::generics::NamespaceExists, crate::generics::SpecExists> {
+    type Output = crate::io::k8s::api::authorization::v1::local_subject_access_review::LocalSubjectAccessReview;
+
+    const METHOD: http::Method = http::Method::POST;
+
+    const OPERATION_ID: Option<&'static str> = Some("createAuthorizationV1NamespacedLocalSubjectAccessReview");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok(format!("/apis/authorization.k8s.io/v1/namespaces/{namespace}/localsubjectaccessreviews", namespace=crate::client::path_param("namespace", self.inner.param_namespace.as_ref().expect("missing parameter namespace?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::CONTENT_TYPE.as_str(), "application/json")
+        .json(&self.inner.body)
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("dryRun", self.inner.param_dry_run.as_ref().map(std::string::ToString::to_string)),
+            ("fieldManager", self.inner.param_field_manager.as_ref().map(std::string::ToString::to_string)),
+            ("pretty", self.inner.param_pretty.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Content-Type")
+            || name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<LocalSubjectAccessReviewPostBuilder<crate::generics::NamespaceExists, crate::generics::SpecExists>> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: LocalSubjectAccessReviewPostBuilder<crate::generics::NamespaceExists, crate::generics::SpecExists>) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl LocalSubjectAccessReviewPostBuilder<crate::generics::NamespaceExists, crate::generics::SpecExists> {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = LocalSubjectAccessReviewPostBuilder {
+            inner: LocalSubjectAccessReviewPostBuilderContainer {
+            body: crate::cli::read_from_input(matches)?,
+            param_dry_run: matches.and_then(|m| {
+                    m.value_of("dry-run").map(|_| {
+                        value_t!(m, "dry-run", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_field_manager: matches.and_then(|m| {
+                    m.value_of("field-manager").map(|_| {
+                        value_t!(m, "field-manager", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_namespace: matches.and_then(|m| {
+                    m.value_of("namespace").map(|_| {
+                        value_t!(m, "namespace", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_pretty: matches.and_then(|m| {
+                    m.value_of("pretty").map(|_| {
+                        value_t!(m, "pretty", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            },
+            _param_namespace: core::marker::PhantomData,
+            _spec: core::marker::PhantomData,
+        };
+
+        Ok(thing)
+    }
+}
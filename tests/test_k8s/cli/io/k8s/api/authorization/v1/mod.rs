@@ -0,0 +1,52 @@
+
+pub mod local_subject_access_review {
+    include!("./local_subject_access_review.rs");
+}
+
+pub mod non_resource_attributes {
+    include!("./non_resource_attributes.rs");
+}
+
+pub mod non_resource_rule {
+    include!("./non_resource_rule.rs");
+}
+
+pub mod resource_attributes {
+    include!("./resource_attributes.rs");
+}
+
+pub mod resource_rule {
+    include!("./resource_rule.rs");
+}
+
+pub mod self_subject_access_review {
+    include!("./self_subject_access_review.rs");
+}
+
+pub mod self_subject_access_review_spec {
+    include!("./self_subject_access_review_spec.rs");
+}
+
+pub mod self_subject_rules_review {
+    include!("./self_subject_rules_review.rs");
+}
+
+pub mod self_subject_rules_review_spec {
+    include!("./self_subject_rules_review_spec.rs");
+}
+
+pub mod subject_access_review {
+    include!("./subject_access_review.rs");
+}
+
+pub mod subject_access_review_spec {
+    include!("./subject_access_review_spec.rs");
+}
+
+pub mod subject_access_review_status {
+    include!("./subject_access_review_status.rs");
+}
+
+pub mod subject_rules_review_status {
+    include!("./subject_rules_review_status.rs");
+}
@@ -0,0 +1,133 @@
+
+/// ResourceRule is the list of actions the subject is allowed to perform on resources. The list ordering isn't significant, may contain duplicates, and possibly be incomplete.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct ResourceRule {
+    /// APIGroups is the name of the APIGroup that contains the resources.  If multiple API groups are specified, any action requested against one of the enumerated resources in any API group will be allowed.  "\*" means all.
+    #[serde(rename = "apiGroups", skip_serializing_if = "Option::is_none")]
+    pub api_groups: Option<Vec<String>>,
+    /// ResourceNames is an optional white list of names that the rule applies to.  An empty set means that everything is allowed.  "\*" means all.
+    #[serde(rename = "resourceNames", skip_serializing_if = "Option::is_none")]
+    pub resource_names: Option<Vec<String>>,
+    /// Resources is a list of resources this rule applies to.  "\*" means all in the specified apiGroups.
+    ///  "\*/foo" represents the subresource 'foo' for all resources in the specified apiGroups.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resources: Option<Vec<String>>,
+    /// Verb is a list of kubernetes resource API verbs, like: get, list, watch, create, update, delete, proxy.  "\*" means all.
+    pub verbs: Vec<String>,
+}
+
+impl ResourceRule {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> ResourceRuleBuilder<crate::generics::MissingVerbs> {
+        ResourceRuleBuilder {
+            body: Default::default(),
+            _verbs: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> ResourceRuleBuilder<crate::generics::VerbsExists>
+    where
+        Self: Clone,
+    {
+        ResourceRuleBuilder {
+            body: self.clone(),
+            _verbs: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<ResourceRule> for ResourceRuleBuilder<crate::generics::VerbsExists> {
+    fn into(self) -> ResourceRule {
+        self.body
+    }
+}
+
+/// Builder for [`ResourceRule`](./struct.ResourceRule.html) object.
+#[derive(Debug, Clone)]
+pub struct ResourceRuleBuilder<Verbs> {
+    body: self::ResourceRule,
+    _verbs: core::marker::PhantomData<Verbs>,
+}
+
+impl<Verbs> ResourceRuleBuilder<Verbs> {
+    /// APIGroups is the name of the APIGroup that contains the resources.  If multiple API groups are specified, any action requested against one of the enumerated resources in any API group will be allowed.  "\*" means all.
+    #[inline]
+    pub fn api_groups(mut self, value: impl Iterator<Item = impl Into<String>>) -> Self {
+        self.body.api_groups = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`api_groups`](#method.api_groups) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_api_groups(mut self, value: Option<impl Iterator<Item = impl Into<String>>>) -> Self {
+        self.body.api_groups = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`api_groups`](#method.api_groups), but takes the values as a `Vec`.
+    #[inline]
+    pub fn api_groups_vec(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.body.api_groups = Some(value.into());
+        self
+    }
+
+    /// ResourceNames is an optional white list of names that the rule applies to.  An empty set means that everything is allowed.  "\*" means all.
+    #[inline]
+    pub fn resource_names(mut self, value: impl Iterator<Item = impl Into<String>>) -> Self {
+        self.body.resource_names = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`resource_names`](#method.resource_names) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_resource_names(mut self, value: Option<impl Iterator<Item = impl Into<String>>>) -> Self {
+        self.body.resource_names = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`resource_names`](#method.resource_names), but takes the values as a `Vec`.
+    #[inline]
+    pub fn resource_names_vec(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.body.resource_names = Some(value.into());
+        self
+    }
+
+    /// Resources is a list of resources this rule applies to.  "\*" means all in the specified apiGroups.
+    ///  "\*/foo" represents the subresource 'foo' for all resources in the specified apiGroups.
+    #[inline]
+    pub fn resources(mut self, value: impl Iterator<Item = impl Into<String>>) -> Self {
+        self.body.resources = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`resources`](#method.resources) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_resources(mut self, value: Option<impl Iterator<Item = impl Into<String>>>) -> Self {
+        self.body.resources = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`resources`](#method.resources), but takes the values as a `Vec`.
+    #[inline]
+    pub fn resources_vec(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.body.resources = Some(value.into());
+        self
+    }
+
+    /// Verb is a list of kubernetes resource API verbs, like: get, list, watch, create, update, delete, proxy.  "\*" means all.
+    #[inline]
+    pub fn verbs(mut self, value: impl Iterator<Item = impl Into<String>>) -> ResourceRuleBuilder<crate::generics::VerbsExists> {
+        self.body.verbs = value.map(|value| value.into()).collect::<Vec<_>>().into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Same as [`verbs`](#method.verbs), but takes the values as a `Vec`.
+    #[inline]
+    pub fn verbs_vec(mut self, value: impl Into<Vec<String>>) -> ResourceRuleBuilder<crate::generics::VerbsExists> {
+        self.body.verbs = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+}
@@ -0,0 +1,328 @@
+
+/// SelfSubjectAccessReview checks whether or the current user can perform an action.  Not filling in a spec.namespace means "in all namespaces".  Self is a special case, because users should always be able to check whether they can perform an action
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct SelfSubjectAccessReview {
+    /// APIVersion defines the versioned schema of this representation of an object. Servers should convert recognized schemas to the latest internal value, and may reject unrecognized values. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#resources
+    #[serde(rename = "apiVersion", skip_serializing_if = "Option::is_none")]
+    pub api_version: Option<String>,
+    /// Kind is a string value representing the REST resource this object represents. Servers may infer this from the endpoint the client submits requests to. Cannot be updated. In CamelCase. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#types-kinds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub kind: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<crate::io::k8s::apimachinery::pkg::apis::meta::v1::object_meta::ObjectMeta>,
+    /// Spec holds information about the request being evaluated.  user and groups must be empty
+    pub spec: crate::io::k8s::api::authorization::v1::self_subject_access_review_spec::SelfSubjectAccessReviewSpec,
+    /// Status is filled in by the server and indicates whether the request is allowed or not
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<crate::io::k8s::api::authorization::v1::subject_access_review_status::SubjectAccessReviewStatus>,
+}
+
+impl SelfSubjectAccessReview {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> SelfSubjectAccessReviewBuilder<crate::generics::MissingSpec> {
+        SelfSubjectAccessReviewBuilder {
+            body: Default::default(),
+            _spec: core::marker::PhantomData,
+        }
+    }
+
+    /// create a SelfSubjectAccessReview
+    #[inline]
+    pub fn create_authorization_v1_self_subject_access_review() -> SelfSubjectAccessReviewPostBuilder<crate::generics::MissingSpec> {
+        SelfSubjectAccessReviewPostBuilder {
+            inner: Default::default(),
+            _spec: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> SelfSubjectAccessReviewBuilder<crate::generics::SpecExists>
+    where
+        Self: Clone,
+    {
+        SelfSubjectAccessReviewBuilder {
+            body: self.clone(),
+            _spec: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<SelfSubjectAccessReview> for SelfSubjectAccessReviewBuilder<crate::generics::SpecExists> {
+    fn into(self) -> SelfSubjectAccessReview {
+        self.body
+    }
+}
+
+impl Into<SelfSubjectAccessReview> for SelfSubjectAccessReviewPostBuilder<crate::generics::SpecExists> {
+    fn into(self) -> SelfSubjectAccessReview {
+        self.inner.body
+    }
+}
+
+/// Builder for [`SelfSubjectAccessReview`](./struct.SelfSubjectAccessReview.html) object.
+#[derive(Debug, Clone)]
+pub struct SelfSubjectAccessReviewBuilder<Spec> {
+    body: self::SelfSubjectAccessReview,
+    _spec: core::marker::PhantomData<Spec>,
+}
+
+impl<Spec> SelfSubjectAccessReviewBuilder<Spec> {
+    /// APIVersion defines the versioned schema of this representation of an object. Servers should convert recognized schemas to the latest internal value, and may reject unrecognized values. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#resources
+    #[inline]
+    pub fn api_version(mut self, value: impl Into<String>) -> Self {
+        self.body.api_version = Some(value.into());
+        self
+    }
+
+    /// Sets [`api_version`](#method.api_version) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_api_version(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.api_version = value.map(|value| value.into());
+        self
+    }
+
+    /// Kind is a string value representing the REST resource this object represents. Servers may infer this from the endpoint the client submits requests to. Cannot be updated. In CamelCase. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#types-kinds
+    #[inline]
+    pub fn kind(mut self, value: impl Into<String>) -> Self {
+        self.body.kind = Some(value.into());
+        self
+    }
+
+    /// Sets [`kind`](#method.kind) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_kind(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.kind = value.map(|value| value.into());
+        self
+    }
+
+    #[inline]
+    pub fn metadata(mut self, value: crate::io::k8s::apimachinery::pkg::apis::meta::v1::object_meta::ObjectMeta) -> Self {
+        self.body.metadata = Some(value.into());
+        self
+    }
+
+    /// Sets [`metadata`](#method.metadata) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_metadata(mut self, value: Option<crate::io::k8s::apimachinery::pkg::apis::meta::v1::object_meta::ObjectMeta>) -> Self {
+        self.body.metadata = value.map(|value| value.into());
+        self
+    }
+
+    /// Spec holds information about the request being evaluated.  user and groups must be empty
+    #[inline]
+    pub fn spec(mut self, value: crate::io::k8s::api::authorization::v1::self_subject_access_review_spec::SelfSubjectAccessReviewSpec) -> SelfSubjectAccessReviewBuilder<crate::generics::SpecExists> {
+        self.body.spec = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Status is filled in by the server and indicates whether the request is allowed or not
+    #[inline]
+    pub fn status(mut self, value: crate::io::k8s::api::authorization::v1::subject_access_review_status::SubjectAccessReviewStatusBuilder<crate::generics::AllowedExists>) -> Self {
+        self.body.status = Some(value.into());
+        self
+    }
+
+    /// Sets [`status`](#method.status) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_status(mut self, value: Option<crate::io::k8s::api::authorization::v1::subject_access_review_status::SubjectAccessReviewStatusBuilder<crate::generics::AllowedExists>>) -> Self {
+        self.body.status = value.map(|value| value.into());
+        self
+    }
+}
+
+/// Builder created by [`SelfSubjectAccessReview::create_authorization_v1_self_subject_access_review`](./struct.SelfSubjectAccessReview.html#method.create_authorization_v1_self_subject_access_review) method for a `POST` operation associated with `SelfSubjectAccessReview`.
+#[repr(transparent)]
+#[derive(Debug, Clone)]
+pub struct SelfSubjectAccessReviewPostBuilder<Spec> {
+    inner: SelfSubjectAccessReviewPostBuilderContainer,
+    _spec: core::marker::PhantomData<Spec>,
+}
+
+#[derive(Debug, Default, Clone)]
+struct SelfSubjectAccessReviewPostBuilderContainer {
+    body: self::SelfSubjectAccessReview,
+    param_dry_run: Option<String>,
+    param_field_manager: Option<String>,
+    param_pretty: Option<String>,
+}
+
+impl<Spec> SelfSubjectAccessReviewPostBuilder<Spec> {
+    /// When present, indicates that modifications should not be persisted. An invalid or unrecognized dryRun directive will result in an error response and no further processing of the request. Valid values are: - All: all dry run stages will be processed
+    #[inline]
+    pub fn dry_run(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_dry_run = Some(value.into());
+        self
+    }
+
+    /// Sets [`dry_run`](#method.dry_run) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_dry_run(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_dry_run = value.map(|value| value.into());
+        self
+    }
+
+    /// fieldManager is a name associated with the actor or entity that is making these changes. The value must be less than or 128 characters long, and only contain printable characters, as defined by https://golang.org/pkg/unicode/#IsPrint.
+    #[inline]
+    pub fn field_manager(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_field_manager = Some(value.into());
+        self
+    }
+
+    /// Sets [`field_manager`](#method.field_manager) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_field_manager(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_field_manager = value.map(|value| value.into());
+        self
+    }
+
+    /// If 'true', then the output is pretty printed.
+    #[inline]
+    pub fn pretty(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_pretty = Some(value.into());
+        self
+    }
+
+    /// Sets [`pretty`](#method.pretty) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_pretty(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_pretty = value.map(|value| value.into());
+        self
+    }
+
+    /// APIVersion defines the versioned schema of this representation of an object. Servers should convert recognized schemas to the latest internal value, and may reject unrecognized values. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#resources
+    #[inline]
+    pub fn api_version(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.api_version = Some(value.into());
+        self
+    }
+
+    /// Sets [`api_version`](#method.api_version) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_api_version(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.api_version = value.map(|value| value.into());
+        self
+    }
+
+    /// Kind is a string value representing the REST resource this object represents. Servers may infer this from the endpoint the client submits requests to. Cannot be updated. In CamelCase. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#types-kinds
+    #[inline]
+    pub fn kind(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.kind = Some(value.into());
+        self
+    }
+
+    /// Sets [`kind`](#method.kind) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_kind(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.kind = value.map(|value| value.into());
+        self
+    }
+
+    #[inline]
+    pub fn metadata(mut self, value: crate::io::k8s::apimachinery::pkg::apis::meta::v1::object_meta::ObjectMeta) -> Self {
+        self.inner.body.metadata = Some(value.into());
+        self
+    }
+
+    /// Sets [`metadata`](#method.metadata) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_metadata(mut self, value: Option<crate::io::k8s::apimachinery::pkg::apis::meta::v1::object_meta::ObjectMeta>) -> Self {
+        self.inner.body.metadata = value.map(|value| value.into());
+        self
+    }
+
+    /// Spec holds information about the request being evaluated.  user and groups must be empty
+    #[inline]
+    pub fn spec(mut self, value: crate::io::k8s::api::authorization::v1::self_subject_access_review_spec::SelfSubjectAccessReviewSpec) -> SelfSubjectAccessReviewPostBuilder<crate::generics::SpecExists> {
+        self.inner.body.spec = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Status is filled in by the server and indicates whether the request is allowed or not
+    #[inline]
+    pub fn status(mut self, value: crate::io::k8s::api::authorization::v1::subject_access_review_status::SubjectAccessReviewStatusBuilder<crate::generics::AllowedExists>) -> Self {
+        self.inner.body.status = Some(value.into());
+        self
+    }
+
+    /// Sets [`status`](#method.status) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_status(mut self, value: Option<crate::io::k8s::api::authorization::v1::subject_access_review_status::SubjectAccessReviewStatusBuilder<crate::generics::AllowedExists>>) -> Self {
+        self.inner.body.status = value.map(|value| value.into());
+        self
+    }
+}
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for SelfSubjectAccessReviewPostBuilder<crate::generics::SpecExists> {
+    type Output = crate::io::k8s::api::authorization::v1::self_subject_access_review::SelfSubjectAccessReview;
+
+    const METHOD: http::Method = http::Method::POST;
+
+    const OPERATION_ID: Option<&'static str> = Some("createAuthorizationV1SelfSubjectAccessReview");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok("/apis/authorization.k8s.io/v1/selfsubjectaccessreviews".into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::CONTENT_TYPE.as_str(), "application/json")
+        .json(&self.inner.body)
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("dryRun", self.inner.param_dry_run.as_ref().map(std::string::ToString::to_string)),
+            ("fieldManager", self.inner.param_field_manager.as_ref().map(std::string::ToString::to_string)),
+            ("pretty", self.inner.param_pretty.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Content-Type")
+            || name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<SelfSubjectAccessReviewPostBuilder<crate::generics::SpecExists>> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: SelfSubjectAccessReviewPostBuilder<crate::generics::SpecExists>) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl SelfSubjectAccessReviewPostBuilder<crate::generics::SpecExists> {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = SelfSubjectAccessReviewPostBuilder {
+            inner: SelfSubjectAccessReviewPostBuilderContainer {
+            body: crate::cli::read_from_input(matches)?,
+            param_dry_run: matches.and_then(|m| {
+                    m.value_of("dry-run").map(|_| {
+                        value_t!(m, "dry-run", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_field_manager: matches.and_then(|m| {
+                    m.value_of("field-manager").map(|_| {
+                        value_t!(m, "field-manager", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_pretty: matches.and_then(|m| {
+                    m.value_of("pretty").map(|_| {
+                        value_t!(m, "pretty", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            },
+            _spec: core::marker::PhantomData,
+        };
+
+        Ok(thing)
+    }
+}
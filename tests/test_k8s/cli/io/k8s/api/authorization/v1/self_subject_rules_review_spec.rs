@@ -0,0 +1,55 @@
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct SelfSubjectRulesReviewSpec {
+    /// Namespace to evaluate rules for. Required.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub namespace: Option<String>,
+}
+
+impl SelfSubjectRulesReviewSpec {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> SelfSubjectRulesReviewSpecBuilder {
+        SelfSubjectRulesReviewSpecBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> SelfSubjectRulesReviewSpecBuilder
+    where
+        Self: Clone,
+    {
+        SelfSubjectRulesReviewSpecBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<SelfSubjectRulesReviewSpec> for SelfSubjectRulesReviewSpecBuilder {
+    fn into(self) -> SelfSubjectRulesReviewSpec {
+        self.body
+    }
+}
+
+/// Builder for [`SelfSubjectRulesReviewSpec`](./struct.SelfSubjectRulesReviewSpec.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct SelfSubjectRulesReviewSpecBuilder {
+    body: self::SelfSubjectRulesReviewSpec,
+}
+
+impl SelfSubjectRulesReviewSpecBuilder {
+    /// Namespace to evaluate rules for. Required.
+    #[inline]
+    pub fn namespace(mut self, value: impl Into<String>) -> Self {
+        self.body.namespace = Some(value.into());
+        self
+    }
+
+    /// Sets [`namespace`](#method.namespace) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_namespace(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.namespace = value.map(|value| value.into());
+        self
+    }
+}
@@ -0,0 +1,149 @@
+
+/// SubjectAccessReviewSpec is a description of the access request.  Exactly one of ResourceAuthorizationAttributes and NonResourceAuthorizationAttributes must be set
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct SubjectAccessReviewSpec {
+    /// Extra corresponds to the user.Info.GetExtra() method from the authenticator.  Since that is input to the authorizer it needs a reflection here.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub extra: Option<std::collections::BTreeMap<String, Vec<String>>>,
+    /// Groups is the groups you're testing for.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub groups: Option<Vec<String>>,
+    /// NonResourceAttributes describes information for a non-resource access request
+    #[serde(rename = "nonResourceAttributes", skip_serializing_if = "Option::is_none")]
+    pub non_resource_attributes: Option<crate::io::k8s::api::authorization::v1::non_resource_attributes::NonResourceAttributes>,
+    /// ResourceAuthorizationAttributes describes information for a resource access request
+    #[serde(rename = "resourceAttributes", skip_serializing_if = "Option::is_none")]
+    pub resource_attributes: Option<crate::io::k8s::api::authorization::v1::resource_attributes::ResourceAttributes>,
+    /// UID information about the requesting user.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub uid: Option<String>,
+    /// User is the user you're testing for. If you specify "User" but not "Groups", then is it interpreted as "What if User were not a member of any groups
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<String>,
+}
+
+impl SubjectAccessReviewSpec {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> SubjectAccessReviewSpecBuilder {
+        SubjectAccessReviewSpecBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> SubjectAccessReviewSpecBuilder
+    where
+        Self: Clone,
+    {
+        SubjectAccessReviewSpecBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<SubjectAccessReviewSpec> for SubjectAccessReviewSpecBuilder {
+    fn into(self) -> SubjectAccessReviewSpec {
+        self.body
+    }
+}
+
+/// Builder for [`SubjectAccessReviewSpec`](./struct.SubjectAccessReviewSpec.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct SubjectAccessReviewSpecBuilder {
+    body: self::SubjectAccessReviewSpec,
+}
+
+impl SubjectAccessReviewSpecBuilder {
+    /// Extra corresponds to the user.Info.GetExtra() method from the authenticator.  Since that is input to the authorizer it needs a reflection here.
+    #[inline]
+    pub fn extra(mut self, value: impl Iterator<Item = (String, impl Iterator<Item = impl Into<String>>)>) -> Self {
+        self.body.extra = Some(value.map(|(key, value)| (key, value.map(|value| value.into()).collect::<Vec<_>>().into())).collect::<std::collections::BTreeMap<_, _>>().into());
+        self
+    }
+
+    /// Sets [`extra`](#method.extra) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_extra(mut self, value: Option<impl Iterator<Item = (String, impl Iterator<Item = impl Into<String>>)>>) -> Self {
+        self.body.extra = value.map(|value| value.map(|(key, value)| (key, value.map(|value| value.into()).collect::<Vec<_>>().into())).collect::<std::collections::BTreeMap<_, _>>().into());
+        self
+    }
+
+    /// Groups is the groups you're testing for.
+    #[inline]
+    pub fn groups(mut self, value: impl Iterator<Item = impl Into<String>>) -> Self {
+        self.body.groups = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`groups`](#method.groups) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_groups(mut self, value: Option<impl Iterator<Item = impl Into<String>>>) -> Self {
+        self.body.groups = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`groups`](#method.groups), but takes the values as a `Vec`.
+    #[inline]
+    pub fn groups_vec(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.body.groups = Some(value.into());
+        self
+    }
+
+    /// NonResourceAttributes describes information for a non-resource access request
+    #[inline]
+    pub fn non_resource_attributes(mut self, value: crate::io::k8s::api::authorization::v1::non_resource_attributes::NonResourceAttributes) -> Self {
+        self.body.non_resource_attributes = Some(value.into());
+        self
+    }
+
+    /// Sets [`non_resource_attributes`](#method.non_resource_attributes) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_non_resource_attributes(mut self, value: Option<crate::io::k8s::api::authorization::v1::non_resource_attributes::NonResourceAttributes>) -> Self {
+        self.body.non_resource_attributes = value.map(|value| value.into());
+        self
+    }
+
+    /// ResourceAuthorizationAttributes describes information for a resource access request
+    #[inline]
+    pub fn resource_attributes(mut self, value: crate::io::k8s::api::authorization::v1::resource_attributes::ResourceAttributes) -> Self {
+        self.body.resource_attributes = Some(value.into());
+        self
+    }
+
+    /// Sets [`resource_attributes`](#method.resource_attributes) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_resource_attributes(mut self, value: Option<crate::io::k8s::api::authorization::v1::resource_attributes::ResourceAttributes>) -> Self {
+        self.body.resource_attributes = value.map(|value| value.into());
+        self
+    }
+
+    /// UID information about the requesting user.
+    #[inline]
+    pub fn uid(mut self, value: impl Into<String>) -> Self {
+        self.body.uid = Some(value.into());
+        self
+    }
+
+    /// Sets [`uid`](#method.uid) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_uid(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.uid = value.map(|value| value.into());
+        self
+    }
+
+    /// User is the user you're testing for. If you specify "User" but not "Groups", then is it interpreted as "What if User were not a member of any groups
+    #[inline]
+    pub fn user(mut self, value: impl Into<String>) -> Self {
+        self.body.user = Some(value.into());
+        self
+    }
+
+    /// Sets [`user`](#method.user) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_user(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.user = value.map(|value| value.into());
+        self
+    }
+}
@@ -0,0 +1,159 @@
+
+/// ResourceAttributes includes the authorization attributes available for resource requests to the Authorizer interface
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct ResourceAttributes {
+    /// Group is the API Group of the Resource.  "\*" means all.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub group: Option<String>,
+    /// Name is the name of the resource being requested for a "get" or deleted for a "delete". "" (empty) means all.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Namespace is the namespace of the action being requested.  Currently, there is no distinction between no namespace and all namespaces "" (empty) is defaulted for LocalSubjectAccessReviews "" (empty) is empty for cluster-scoped resources "" (empty) means "all" for namespace scoped resources from a SubjectAccessReview or SelfSubjectAccessReview
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub namespace: Option<String>,
+    /// Resource is one of the existing resource types.  "\*" means all.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resource: Option<String>,
+    /// Subresource is one of the existing resource types.  "" means none.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subresource: Option<String>,
+    /// Verb is a kubernetes resource API verb, like: get, list, watch, create, update, delete, proxy.  "\*" means all.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub verb: Option<String>,
+    /// Version is the API Version of the Resource.  "\*" means all.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+}
+
+impl ResourceAttributes {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> ResourceAttributesBuilder {
+        ResourceAttributesBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> ResourceAttributesBuilder
+    where
+        Self: Clone,
+    {
+        ResourceAttributesBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<ResourceAttributes> for ResourceAttributesBuilder {
+    fn into(self) -> ResourceAttributes {
+        self.body
+    }
+}
+
+/// Builder for [`ResourceAttributes`](./struct.ResourceAttributes.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct ResourceAttributesBuilder {
+    body: self::ResourceAttributes,
+}
+
+impl ResourceAttributesBuilder {
+    /// Group is the API Group of the Resource.  "\*" means all.
+    #[inline]
+    pub fn group(mut self, value: impl Into<String>) -> Self {
+        self.body.group = Some(value.into());
+        self
+    }
+
+    /// Sets [`group`](#method.group) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_group(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.group = value.map(|value| value.into());
+        self
+    }
+
+    /// Name is the name of the resource being requested for a "get" or deleted for a "delete". "" (empty) means all.
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> Self {
+        self.body.name = Some(value.into());
+        self
+    }
+
+    /// Sets [`name`](#method.name) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_name(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.name = value.map(|value| value.into());
+        self
+    }
+
+    /// Namespace is the namespace of the action being requested.  Currently, there is no distinction between no namespace and all namespaces "" (empty) is defaulted for LocalSubjectAccessReviews "" (empty) is empty for cluster-scoped resources "" (empty) means "all" for namespace scoped resources from a SubjectAccessReview or SelfSubjectAccessReview
+    #[inline]
+    pub fn namespace(mut self, value: impl Into<String>) -> Self {
+        self.body.namespace = Some(value.into());
+        self
+    }
+
+    /// Sets [`namespace`](#method.namespace) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_namespace(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.namespace = value.map(|value| value.into());
+        self
+    }
+
+    /// Resource is one of the existing resource types.  "\*" means all.
+    #[inline]
+    pub fn resource(mut self, value: impl Into<String>) -> Self {
+        self.body.resource = Some(value.into());
+        self
+    }
+
+    /// Sets [`resource`](#method.resource) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_resource(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.resource = value.map(|value| value.into());
+        self
+    }
+
+    /// Subresource is one of the existing resource types.  "" means none.
+    #[inline]
+    pub fn subresource(mut self, value: impl Into<String>) -> Self {
+        self.body.subresource = Some(value.into());
+        self
+    }
+
+    /// Sets [`subresource`](#method.subresource) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_subresource(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.subresource = value.map(|value| value.into());
+        self
+    }
+
+    /// Verb is a kubernetes resource API verb, like: get, list, watch, create, update, delete, proxy.  "\*" means all.
+    #[inline]
+    pub fn verb(mut self, value: impl Into<String>) -> Self {
+        self.body.verb = Some(value.into());
+        self
+    }
+
+    /// Sets [`verb`](#method.verb) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_verb(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.verb = value.map(|value| value.into());
+        self
+    }
+
+    /// Version is the API Version of the Resource.  "\*" means all.
+    #[inline]
+    pub fn version(mut self, value: impl Into<String>) -> Self {
+        self.body.version = Some(value.into());
+        self
+    }
+
+    /// Sets [`version`](#method.version) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_version(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.version = value.map(|value| value.into());
+        self
+    }
+}
@@ -0,0 +1,118 @@
+
+/// specification of a horizontal pod autoscaler.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct HorizontalPodAutoscalerSpec {
+    /// upper limit for the number of pods that can be set by the autoscaler; cannot be smaller than MinReplicas.
+    #[serde(rename = "maxReplicas")]
+    pub max_replicas: i32,
+    /// lower limit for the number of pods that can be set by the autoscaler, default 1.
+    #[serde(rename = "minReplicas", skip_serializing_if = "Option::is_none")]
+    pub min_replicas: Option<i32>,
+    /// reference to scaled resource; horizontal pod autoscaler will learn the current resource consumption and will set the desired number of pods by using its Scale subresource.
+    #[serde(rename = "scaleTargetRef")]
+    pub scale_target_ref: crate::io::k8s::api::autoscaling::v1::cross_version_object_reference::CrossVersionObjectReference,
+    /// target average CPU utilization (represented as a percentage of requested CPU) over all the pods; if not specified the default autoscaling policy will be used.
+    #[serde(rename = "targetCPUUtilizationPercentage", skip_serializing_if = "Option::is_none")]
+    pub target_cpu_utilization_percentage: Option<i32>,
+}
+
+impl HorizontalPodAutoscalerSpec {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> HorizontalPodAutoscalerSpecBuilder<crate::generics::MissingMaxReplicas, crate::generics::MissingScaleTargetRef> {
+        HorizontalPodAutoscalerSpecBuilder {
+            body: Default::default(),
+            _max_replicas: core::marker::PhantomData,
+            _scale_target_ref: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> HorizontalPodAutoscalerSpecBuilder<crate::generics::MaxReplicasExists, crate::generics::ScaleTargetRefExists>
+    where
+        Self: Clone,
+    {
+        HorizontalPodAutoscalerSpecBuilder {
+            body: self.clone(),
+            _max_replicas: core::marker::PhantomData,
+            _scale_target_ref: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<HorizontalPodAutoscalerSpec> for HorizontalPodAutoscalerSpecBuilder<crate::generics::MaxReplicasExists, crate::generics::ScaleTargetRefExists> {
+    fn into(self) -> HorizontalPodAutoscalerSpec {
+        self.body
+    }
+}
+
+/// Builder for [`HorizontalPodAutoscalerSpec`](./struct.HorizontalPodAutoscalerSpec.html) object.
+#[derive(Debug, Clone)]
+pub struct HorizontalPodAutoscalerSpecBuilder<MaxReplicas, ScaleTargetRef> {
+    body: self::HorizontalPodAutoscalerSpec,
+    _max_replicas: core::marker::PhantomData<MaxReplicas>,
+    _scale_target_ref: core::marker::PhantomData<ScaleTargetRef>,
+}
+
+impl<MaxReplicas, ScaleTargetRef> HorizontalPodAutoscalerSpecBuilder<MaxReplicas, ScaleTargetRef> {
+    /// upper limit for the number of pods that can be set by the autoscaler; cannot be smaller than MinReplicas.
+    #[inline]
+    pub fn max_replicas(mut self, value: impl Into<i32>) -> HorizontalPodAutoscalerSpecBuilder<crate::generics::MaxReplicasExists, ScaleTargetRef> {
+        self.body.max_replicas = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Same as [`max_replicas`](#method.max_replicas), but takes a reference.
+    #[inline]
+    pub fn max_replicas_ref(self, value: &i32) -> HorizontalPodAutoscalerSpecBuilder<crate::generics::MaxReplicasExists, ScaleTargetRef> {
+        self.max_replicas(*value)
+    }
+
+    /// lower limit for the number of pods that can be set by the autoscaler, default 1.
+    #[inline]
+    pub fn min_replicas(mut self, value: impl Into<i32>) -> Self {
+        self.body.min_replicas = Some(value.into());
+        self
+    }
+
+    /// Sets [`min_replicas`](#method.min_replicas) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_min_replicas(mut self, value: Option<impl Into<i32>>) -> Self {
+        self.body.min_replicas = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`min_replicas`](#method.min_replicas), but takes a reference.
+    #[inline]
+    pub fn min_replicas_ref(self, value: &i32) -> Self {
+        self.min_replicas(*value)
+    }
+
+    /// reference to scaled resource; horizontal pod autoscaler will learn the current resource consumption and will set the desired number of pods by using its Scale subresource.
+    #[inline]
+    pub fn scale_target_ref(mut self, value: crate::io::k8s::api::autoscaling::v1::cross_version_object_reference::CrossVersionObjectReferenceBuilder<crate::generics::KindExists, crate::generics::NameExists>) -> HorizontalPodAutoscalerSpecBuilder<MaxReplicas, crate::generics::ScaleTargetRefExists> {
+        self.body.scale_target_ref = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// target average CPU utilization (represented as a percentage of requested CPU) over all the pods; if not specified the default autoscaling policy will be used.
+    #[inline]
+    pub fn target_cpu_utilization_percentage(mut self, value: impl Into<i32>) -> Self {
+        self.body.target_cpu_utilization_percentage = Some(value.into());
+        self
+    }
+
+    /// Sets [`target_cpu_utilization_percentage`](#method.target_cpu_utilization_percentage) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_target_cpu_utilization_percentage(mut self, value: Option<impl Into<i32>>) -> Self {
+        self.body.target_cpu_utilization_percentage = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`target_cpu_utilization_percentage`](#method.target_cpu_utilization_percentage), but takes a reference.
+    #[inline]
+    pub fn target_cpu_utilization_percentage_ref(self, value: &i32) -> Self {
+        self.target_cpu_utilization_percentage(*value)
+    }
+}
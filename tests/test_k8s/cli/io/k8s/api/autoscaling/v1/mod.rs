@@ -0,0 +1,32 @@
+
+pub mod cross_version_object_reference {
+    include!("./cross_version_object_reference.rs");
+}
+
+pub mod horizontal_pod_autoscaler {
+    include!("./horizontal_pod_autoscaler.rs");
+}
+
+pub mod horizontal_pod_autoscaler_list {
+    include!("./horizontal_pod_autoscaler_list.rs");
+}
+
+pub mod horizontal_pod_autoscaler_spec {
+    include!("./horizontal_pod_autoscaler_spec.rs");
+}
+
+pub mod horizontal_pod_autoscaler_status {
+    include!("./horizontal_pod_autoscaler_status.rs");
+}
+
+pub mod scale {
+    include!("./scale.rs");
+}
+
+pub mod scale_spec {
+    include!("./scale_spec.rs");
+}
+
+pub mod scale_status {
+    include!("./scale_status.rs");
+}
@@ -0,0 +1,104 @@
+
+/// ExternalMetricSource indicates how to scale on a metric not associated with any Kubernetes object (for example length of queue in cloud messaging service, or QPS from loadbalancer running outside of cluster). Exactly one "target" type should be set.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct ExternalMetricSource {
+    /// metricName is the name of the metric in question.
+    #[serde(rename = "metricName")]
+    pub metric_name: String,
+    /// metricSelector is used to identify a specific time series within a given metric.
+    #[serde(rename = "metricSelector", skip_serializing_if = "Option::is_none")]
+    pub metric_selector: Option<crate::io::k8s::apimachinery::pkg::apis::meta::v1::label_selector::LabelSelector>,
+    /// targetAverageValue is the target per-pod value of global metric (as a quantity). Mutually exclusive with TargetValue.
+    #[serde(rename = "targetAverageValue", skip_serializing_if = "Option::is_none")]
+    pub target_average_value: Option<String>,
+    /// targetValue is the target value of the metric (as a quantity). Mutually exclusive with TargetAverageValue.
+    #[serde(rename = "targetValue", skip_serializing_if = "Option::is_none")]
+    pub target_value: Option<String>,
+}
+
+impl ExternalMetricSource {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> ExternalMetricSourceBuilder<crate::generics::MissingMetricName> {
+        ExternalMetricSourceBuilder {
+            body: Default::default(),
+            _metric_name: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> ExternalMetricSourceBuilder<crate::generics::MetricNameExists>
+    where
+        Self: Clone,
+    {
+        ExternalMetricSourceBuilder {
+            body: self.clone(),
+            _metric_name: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<ExternalMetricSource> for ExternalMetricSourceBuilder<crate::generics::MetricNameExists> {
+    fn into(self) -> ExternalMetricSource {
+        self.body
+    }
+}
+
+/// Builder for [`ExternalMetricSource`](./struct.ExternalMetricSource.html) object.
+#[derive(Debug, Clone)]
+pub struct ExternalMetricSourceBuilder<MetricName> {
+    body: self::ExternalMetricSource,
+    _metric_name: core::marker::PhantomData<MetricName>,
+}
+
+impl<MetricName> ExternalMetricSourceBuilder<MetricName> {
+    /// metricName is the name of the metric in question.
+    #[inline]
+    pub fn metric_name(mut self, value: impl Into<String>) -> ExternalMetricSourceBuilder<crate::generics::MetricNameExists> {
+        self.body.metric_name = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// metricSelector is used to identify a specific time series within a given metric.
+    #[inline]
+    pub fn metric_selector(mut self, value: crate::io::k8s::apimachinery::pkg::apis::meta::v1::label_selector::LabelSelector) -> Self {
+        self.body.metric_selector = Some(value.into());
+        self
+    }
+
+    /// Sets [`metric_selector`](#method.metric_selector) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_metric_selector(mut self, value: Option<crate::io::k8s::apimachinery::pkg::apis::meta::v1::label_selector::LabelSelector>) -> Self {
+        self.body.metric_selector = value.map(|value| value.into());
+        self
+    }
+
+    /// targetAverageValue is the target per-pod value of global metric (as a quantity). Mutually exclusive with TargetValue.
+    #[inline]
+    pub fn target_average_value(mut self, value: impl Into<String>) -> Self {
+        self.body.target_average_value = Some(value.into());
+        self
+    }
+
+    /// Sets [`target_average_value`](#method.target_average_value) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_target_average_value(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.target_average_value = value.map(|value| value.into());
+        self
+    }
+
+    /// targetValue is the target value of the metric (as a quantity). Mutually exclusive with TargetAverageValue.
+    #[inline]
+    pub fn target_value(mut self, value: impl Into<String>) -> Self {
+        self.body.target_value = Some(value.into());
+        self
+    }
+
+    /// Sets [`target_value`](#method.target_value) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_target_value(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.target_value = value.map(|value| value.into());
+        self
+    }
+}
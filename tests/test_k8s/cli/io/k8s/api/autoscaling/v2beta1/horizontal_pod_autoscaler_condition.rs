@@ -0,0 +1,116 @@
+
+/// HorizontalPodAutoscalerCondition describes the state of a HorizontalPodAutoscaler at a certain point.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct HorizontalPodAutoscalerCondition {
+    /// lastTransitionTime is the last time the condition transitioned from one status to another
+    #[serde(rename = "lastTransitionTime", skip_serializing_if = "Option::is_none")]
+    pub last_transition_time: Option<String>,
+    /// message is a human-readable explanation containing details about the transition
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    /// reason is the reason for the condition's last transition.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+    /// status is the status of the condition (True, False, Unknown)
+    pub status: String,
+    /// type describes the current condition
+    #[serde(rename = "type")]
+    pub type_: String,
+}
+
+impl HorizontalPodAutoscalerCondition {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> HorizontalPodAutoscalerConditionBuilder<crate::generics::MissingStatus, crate::generics::MissingType> {
+        HorizontalPodAutoscalerConditionBuilder {
+            body: Default::default(),
+            _status: core::marker::PhantomData,
+            _type: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> HorizontalPodAutoscalerConditionBuilder<crate::generics::StatusExists, crate::generics::TypeExists>
+    where
+        Self: Clone,
+    {
+        HorizontalPodAutoscalerConditionBuilder {
+            body: self.clone(),
+            _status: core::marker::PhantomData,
+            _type: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<HorizontalPodAutoscalerCondition> for HorizontalPodAutoscalerConditionBuilder<crate::generics::StatusExists, crate::generics::TypeExists> {
+    fn into(self) -> HorizontalPodAutoscalerCondition {
+        self.body
+    }
+}
+
+/// Builder for [`HorizontalPodAutoscalerCondition`](./struct.HorizontalPodAutoscalerCondition.html) object.
+#[derive(Debug, Clone)]
+pub struct HorizontalPodAutoscalerConditionBuilder<Status, Type> {
+    body: self::HorizontalPodAutoscalerCondition,
+    _status: core::marker::PhantomData<Status>,
+    _type: core::marker::PhantomData<Type>,
+}
+
+impl<Status, Type> HorizontalPodAutoscalerConditionBuilder<Status, Type> {
+    /// lastTransitionTime is the last time the condition transitioned from one status to another
+    #[inline]
+    pub fn last_transition_time(mut self, value: impl Into<String>) -> Self {
+        self.body.last_transition_time = Some(value.into());
+        self
+    }
+
+    /// Sets [`last_transition_time`](#method.last_transition_time) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_last_transition_time(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.last_transition_time = value.map(|value| value.into());
+        self
+    }
+
+    /// message is a human-readable explanation containing details about the transition
+    #[inline]
+    pub fn message(mut self, value: impl Into<String>) -> Self {
+        self.body.message = Some(value.into());
+        self
+    }
+
+    /// Sets [`message`](#method.message) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_message(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.message = value.map(|value| value.into());
+        self
+    }
+
+    /// reason is the reason for the condition's last transition.
+    #[inline]
+    pub fn reason(mut self, value: impl Into<String>) -> Self {
+        self.body.reason = Some(value.into());
+        self
+    }
+
+    /// Sets [`reason`](#method.reason) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_reason(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.reason = value.map(|value| value.into());
+        self
+    }
+
+    /// status is the status of the condition (True, False, Unknown)
+    #[inline]
+    pub fn status(mut self, value: impl Into<String>) -> HorizontalPodAutoscalerConditionBuilder<crate::generics::StatusExists, Type> {
+        self.body.status = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// type describes the current condition
+    #[inline]
+    pub fn type_(mut self, value: impl Into<String>) -> HorizontalPodAutoscalerConditionBuilder<Status, crate::generics::TypeExists> {
+        self.body.type_ = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+}
@@ -0,0 +1,112 @@
+
+/// ObjectMetricStatus indicates the current value of a metric describing a kubernetes object (for example, hits-per-second on an Ingress object).
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct ObjectMetricStatus {
+    /// averageValue is the current value of the average of the metric across all relevant pods (as a quantity)
+    #[serde(rename = "averageValue", skip_serializing_if = "Option::is_none")]
+    pub average_value: Option<String>,
+    /// currentValue is the current value of the metric (as a quantity).
+    #[serde(rename = "currentValue")]
+    pub current_value: String,
+    /// metricName is the name of the metric in question.
+    #[serde(rename = "metricName")]
+    pub metric_name: String,
+    /// selector is the string-encoded form of a standard kubernetes label selector for the given metric When set in the ObjectMetricSource, it is passed as an additional parameter to the metrics server for more specific metrics scoping. When unset, just the metricName will be used to gather metrics.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub selector: Option<crate::io::k8s::apimachinery::pkg::apis::meta::v1::label_selector::LabelSelector>,
+    /// target is the described Kubernetes object.
+    pub target: crate::io::k8s::api::autoscaling::v2beta1::cross_version_object_reference::CrossVersionObjectReference,
+}
+
+impl ObjectMetricStatus {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> ObjectMetricStatusBuilder<crate::generics::MissingCurrentValue, crate::generics::MissingMetricName, crate::generics::MissingTarget> {
+        ObjectMetricStatusBuilder {
+            body: Default::default(),
+            _current_value: core::marker::PhantomData,
+            _metric_name: core::marker::PhantomData,
+            _target: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> ObjectMetricStatusBuilder<crate::generics::CurrentValueExists, crate::generics::MetricNameExists, crate::generics::TargetExists>
+    where
+        Self: Clone,
+    {
+        ObjectMetricStatusBuilder {
+            body: self.clone(),
+            _current_value: core::marker::PhantomData,
+            _metric_name: core::marker::PhantomData,
+            _target: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<ObjectMetricStatus> for ObjectMetricStatusBuilder<crate::generics::CurrentValueExists, crate::generics::MetricNameExists, crate::generics::TargetExists> {
+    fn into(self) -> ObjectMetricStatus {
+        self.body
+    }
+}
+
+/// Builder for [`ObjectMetricStatus`](./struct.ObjectMetricStatus.html) object.
+#[derive(Debug, Clone)]
+pub struct ObjectMetricStatusBuilder<CurrentValue, MetricName, Target> {
+    body: self::ObjectMetricStatus,
+    _current_value: core::marker::PhantomData<CurrentValue>,
+    _metric_name: core::marker::PhantomData<MetricName>,
+    _target: core::marker::PhantomData<Target>,
+}
+
+impl<CurrentValue, MetricName, Target> ObjectMetricStatusBuilder<CurrentValue, MetricName, Target> {
+    /// averageValue is the current value of the average of the metric across all relevant pods (as a quantity)
+    #[inline]
+    pub fn average_value(mut self, value: impl Into<String>) -> Self {
+        self.body.average_value = Some(value.into());
+        self
+    }
+
+    /// Sets [`average_value`](#method.average_value) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_average_value(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.average_value = value.map(|value| value.into());
+        self
+    }
+
+    /// currentValue is the current value of the metric (as a quantity).
+    #[inline]
+    pub fn current_value(mut self, value: impl Into<String>) -> ObjectMetricStatusBuilder<crate::generics::CurrentValueExists, MetricName, Target> {
+        self.body.current_value = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// metricName is the name of the metric in question.
+    #[inline]
+    pub fn metric_name(mut self, value: impl Into<String>) -> ObjectMetricStatusBuilder<CurrentValue, crate::generics::MetricNameExists, Target> {
+        self.body.metric_name = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// selector is the string-encoded form of a standard kubernetes label selector for the given metric When set in the ObjectMetricSource, it is passed as an additional parameter to the metrics server for more specific metrics scoping. When unset, just the metricName will be used to gather metrics.
+    #[inline]
+    pub fn selector(mut self, value: crate::io::k8s::apimachinery::pkg::apis::meta::v1::label_selector::LabelSelector) -> Self {
+        self.body.selector = Some(value.into());
+        self
+    }
+
+    /// Sets [`selector`](#method.selector) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_selector(mut self, value: Option<crate::io::k8s::apimachinery::pkg::apis::meta::v1::label_selector::LabelSelector>) -> Self {
+        self.body.selector = value.map(|value| value.into());
+        self
+    }
+
+    /// target is the described Kubernetes object.
+    #[inline]
+    pub fn target(mut self, value: crate::io::k8s::api::autoscaling::v2beta1::cross_version_object_reference::CrossVersionObjectReferenceBuilder<crate::generics::KindExists, crate::generics::NameExists>) -> ObjectMetricStatusBuilder<CurrentValue, MetricName, crate::generics::TargetExists> {
+        self.body.target = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+}
@@ -0,0 +1,83 @@
+
+/// PodsMetricSource indicates how to scale on a metric describing each pod in the current scale target (for example, transactions-processed-per-second). The values will be averaged together before being compared to the target value.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct PodsMetricSource {
+    /// metricName is the name of the metric in question
+    #[serde(rename = "metricName")]
+    pub metric_name: String,
+    /// selector is the string-encoded form of a standard kubernetes label selector for the given metric When set, it is passed as an additional parameter to the metrics server for more specific metrics scoping When unset, just the metricName will be used to gather metrics.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub selector: Option<crate::io::k8s::apimachinery::pkg::apis::meta::v1::label_selector::LabelSelector>,
+    /// targetAverageValue is the target value of the average of the metric across all relevant pods (as a quantity)
+    #[serde(rename = "targetAverageValue")]
+    pub target_average_value: String,
+}
+
+impl PodsMetricSource {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> PodsMetricSourceBuilder<crate::generics::MissingMetricName, crate::generics::MissingTargetAverageValue> {
+        PodsMetricSourceBuilder {
+            body: Default::default(),
+            _metric_name: core::marker::PhantomData,
+            _target_average_value: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> PodsMetricSourceBuilder<crate::generics::MetricNameExists, crate::generics::TargetAverageValueExists>
+    where
+        Self: Clone,
+    {
+        PodsMetricSourceBuilder {
+            body: self.clone(),
+            _metric_name: core::marker::PhantomData,
+            _target_average_value: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<PodsMetricSource> for PodsMetricSourceBuilder<crate::generics::MetricNameExists, crate::generics::TargetAverageValueExists> {
+    fn into(self) -> PodsMetricSource {
+        self.body
+    }
+}
+
+/// Builder for [`PodsMetricSource`](./struct.PodsMetricSource.html) object.
+#[derive(Debug, Clone)]
+pub struct PodsMetricSourceBuilder<MetricName, TargetAverageValue> {
+    body: self::PodsMetricSource,
+    _metric_name: core::marker::PhantomData<MetricName>,
+    _target_average_value: core::marker::PhantomData<TargetAverageValue>,
+}
+
+impl<MetricName, TargetAverageValue> PodsMetricSourceBuilder<MetricName, TargetAverageValue> {
+    /// metricName is the name of the metric in question
+    #[inline]
+    pub fn metric_name(mut self, value: impl Into<String>) -> PodsMetricSourceBuilder<crate::generics::MetricNameExists, TargetAverageValue> {
+        self.body.metric_name = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// selector is the string-encoded form of a standard kubernetes label selector for the given metric When set, it is passed as an additional parameter to the metrics server for more specific metrics scoping When unset, just the metricName will be used to gather metrics.
+    #[inline]
+    pub fn selector(mut self, value: crate::io::k8s::apimachinery::pkg::apis::meta::v1::label_selector::LabelSelector) -> Self {
+        self.body.selector = Some(value.into());
+        self
+    }
+
+    /// Sets [`selector`](#method.selector) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_selector(mut self, value: Option<crate::io::k8s::apimachinery::pkg::apis::meta::v1::label_selector::LabelSelector>) -> Self {
+        self.body.selector = value.map(|value| value.into());
+        self
+    }
+
+    /// targetAverageValue is the target value of the average of the metric across all relevant pods (as a quantity)
+    #[inline]
+    pub fn target_average_value(mut self, value: impl Into<String>) -> PodsMetricSourceBuilder<MetricName, crate::generics::TargetAverageValueExists> {
+        self.body.target_average_value = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+}
@@ -0,0 +1,92 @@
+
+/// ResourceMetricSource indicates how to scale on a resource metric known to Kubernetes, as specified in requests and limits, describing each pod in the current scale target (e.g. CPU or memory).  The values will be averaged together before being compared to the target.  Such metrics are built in to Kubernetes, and have special scaling options on top of those available to normal per-pod metrics using the "pods" source.  Only one "target" type should be set.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct ResourceMetricSource {
+    /// name is the name of the resource in question.
+    pub name: String,
+    /// targetAverageUtilization is the target value of the average of the resource metric across all relevant pods, represented as a percentage of the requested value of the resource for the pods.
+    #[serde(rename = "targetAverageUtilization", skip_serializing_if = "Option::is_none")]
+    pub target_average_utilization: Option<i32>,
+    /// targetAverageValue is the target value of the average of the resource metric across all relevant pods, as a raw value (instead of as a percentage of the request), similar to the "pods" metric source type.
+    #[serde(rename = "targetAverageValue", skip_serializing_if = "Option::is_none")]
+    pub target_average_value: Option<String>,
+}
+
+impl ResourceMetricSource {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> ResourceMetricSourceBuilder<crate::generics::MissingName> {
+        ResourceMetricSourceBuilder {
+            body: Default::default(),
+            _name: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> ResourceMetricSourceBuilder<crate::generics::NameExists>
+    where
+        Self: Clone,
+    {
+        ResourceMetricSourceBuilder {
+            body: self.clone(),
+            _name: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<ResourceMetricSource> for ResourceMetricSourceBuilder<crate::generics::NameExists> {
+    fn into(self) -> ResourceMetricSource {
+        self.body
+    }
+}
+
+/// Builder for [`ResourceMetricSource`](./struct.ResourceMetricSource.html) object.
+#[derive(Debug, Clone)]
+pub struct ResourceMetricSourceBuilder<Name> {
+    body: self::ResourceMetricSource,
+    _name: core::marker::PhantomData<Name>,
+}
+
+impl<Name> ResourceMetricSourceBuilder<Name> {
+    /// name is the name of the resource in question.
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> ResourceMetricSourceBuilder<crate::generics::NameExists> {
+        self.body.name = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// targetAverageUtilization is the target value of the average of the resource metric across all relevant pods, represented as a percentage of the requested value of the resource for the pods.
+    #[inline]
+    pub fn target_average_utilization(mut self, value: impl Into<i32>) -> Self {
+        self.body.target_average_utilization = Some(value.into());
+        self
+    }
+
+    /// Sets [`target_average_utilization`](#method.target_average_utilization) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_target_average_utilization(mut self, value: Option<impl Into<i32>>) -> Self {
+        self.body.target_average_utilization = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`target_average_utilization`](#method.target_average_utilization), but takes a reference.
+    #[inline]
+    pub fn target_average_utilization_ref(self, value: &i32) -> Self {
+        self.target_average_utilization(*value)
+    }
+
+    /// targetAverageValue is the target value of the average of the resource metric across all relevant pods, as a raw value (instead of as a percentage of the request), similar to the "pods" metric source type.
+    #[inline]
+    pub fn target_average_value(mut self, value: impl Into<String>) -> Self {
+        self.body.target_average_value = Some(value.into());
+        self
+    }
+
+    /// Sets [`target_average_value`](#method.target_average_value) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_target_average_value(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.target_average_value = value.map(|value| value.into());
+        self
+    }
+}
@@ -0,0 +1,64 @@
+
+/// ExternalMetricSource indicates how to scale on a metric not associated with any Kubernetes object (for example length of queue in cloud messaging service, or QPS from loadbalancer running outside of cluster).
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct ExternalMetricSource {
+    /// metric identifies the target metric by name and selector
+    pub metric: crate::io::k8s::api::autoscaling::v2beta2::metric_identifier::MetricIdentifier,
+    /// target specifies the target value for the given metric
+    pub target: crate::io::k8s::api::autoscaling::v2beta2::metric_target::MetricTarget,
+}
+
+impl ExternalMetricSource {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> ExternalMetricSourceBuilder<crate::generics::MissingMetric, crate::generics::MissingTarget> {
+        ExternalMetricSourceBuilder {
+            body: Default::default(),
+            _metric: core::marker::PhantomData,
+            _target: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> ExternalMetricSourceBuilder<crate::generics::MetricExists, crate::generics::TargetExists>
+    where
+        Self: Clone,
+    {
+        ExternalMetricSourceBuilder {
+            body: self.clone(),
+            _metric: core::marker::PhantomData,
+            _target: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<ExternalMetricSource> for ExternalMetricSourceBuilder<crate::generics::MetricExists, crate::generics::TargetExists> {
+    fn into(self) -> ExternalMetricSource {
+        self.body
+    }
+}
+
+/// Builder for [`ExternalMetricSource`](./struct.ExternalMetricSource.html) object.
+#[derive(Debug, Clone)]
+pub struct ExternalMetricSourceBuilder<Metric, Target> {
+    body: self::ExternalMetricSource,
+    _metric: core::marker::PhantomData<Metric>,
+    _target: core::marker::PhantomData<Target>,
+}
+
+impl<Metric, Target> ExternalMetricSourceBuilder<Metric, Target> {
+    /// metric identifies the target metric by name and selector
+    #[inline]
+    pub fn metric(mut self, value: crate::io::k8s::api::autoscaling::v2beta2::metric_identifier::MetricIdentifierBuilder<crate::generics::NameExists>) -> ExternalMetricSourceBuilder<crate::generics::MetricExists, Target> {
+        self.body.metric = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// target specifies the target value for the given metric
+    #[inline]
+    pub fn target(mut self, value: crate::io::k8s::api::autoscaling::v2beta2::metric_target::MetricTargetBuilder<crate::generics::TypeExists>) -> ExternalMetricSourceBuilder<Metric, crate::generics::TargetExists> {
+        self.body.target = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+}
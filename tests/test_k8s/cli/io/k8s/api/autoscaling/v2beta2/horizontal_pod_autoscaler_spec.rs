@@ -0,0 +1,119 @@
+
+/// HorizontalPodAutoscalerSpec describes the desired functionality of the HorizontalPodAutoscaler.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct HorizontalPodAutoscalerSpec {
+    /// maxReplicas is the upper limit for the number of replicas to which the autoscaler can scale up. It cannot be less that minReplicas.
+    #[serde(rename = "maxReplicas")]
+    pub max_replicas: i32,
+    /// metrics contains the specifications for which to use to calculate the desired replica count (the maximum replica count across all metrics will be used).  The desired replica count is calculated multiplying the ratio between the target value and the current value by the current number of pods.  Ergo, metrics used must decrease as the pod count is increased, and vice-versa.  See the individual metric source types for more information about how each type of metric must respond. If not set, the default metric will be set to 80% average CPU utilization.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metrics: Option<Vec<crate::io::k8s::api::autoscaling::v2beta2::metric_spec::MetricSpec>>,
+    /// minReplicas is the lower limit for the number of replicas to which the autoscaler can scale down. It defaults to 1 pod.
+    #[serde(rename = "minReplicas", skip_serializing_if = "Option::is_none")]
+    pub min_replicas: Option<i32>,
+    /// scaleTargetRef points to the target resource to scale, and is used to the pods for which metrics should be collected, as well as to actually change the replica count.
+    #[serde(rename = "scaleTargetRef")]
+    pub scale_target_ref: crate::io::k8s::api::autoscaling::v2beta2::cross_version_object_reference::CrossVersionObjectReference,
+}
+
+impl HorizontalPodAutoscalerSpec {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> HorizontalPodAutoscalerSpecBuilder<crate::generics::MissingMaxReplicas, crate::generics::MissingScaleTargetRef> {
+        HorizontalPodAutoscalerSpecBuilder {
+            body: Default::default(),
+            _max_replicas: core::marker::PhantomData,
+            _scale_target_ref: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> HorizontalPodAutoscalerSpecBuilder<crate::generics::MaxReplicasExists, crate::generics::ScaleTargetRefExists>
+    where
+        Self: Clone,
+    {
+        HorizontalPodAutoscalerSpecBuilder {
+            body: self.clone(),
+            _max_replicas: core::marker::PhantomData,
+            _scale_target_ref: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<HorizontalPodAutoscalerSpec> for HorizontalPodAutoscalerSpecBuilder<crate::generics::MaxReplicasExists, crate::generics::ScaleTargetRefExists> {
+    fn into(self) -> HorizontalPodAutoscalerSpec {
+        self.body
+    }
+}
+
+/// Builder for [`HorizontalPodAutoscalerSpec`](./struct.HorizontalPodAutoscalerSpec.html) object.
+#[derive(Debug, Clone)]
+pub struct HorizontalPodAutoscalerSpecBuilder<MaxReplicas, ScaleTargetRef> {
+    body: self::HorizontalPodAutoscalerSpec,
+    _max_replicas: core::marker::PhantomData<MaxReplicas>,
+    _scale_target_ref: core::marker::PhantomData<ScaleTargetRef>,
+}
+
+impl<MaxReplicas, ScaleTargetRef> HorizontalPodAutoscalerSpecBuilder<MaxReplicas, ScaleTargetRef> {
+    /// maxReplicas is the upper limit for the number of replicas to which the autoscaler can scale up. It cannot be less that minReplicas.
+    #[inline]
+    pub fn max_replicas(mut self, value: impl Into<i32>) -> HorizontalPodAutoscalerSpecBuilder<crate::generics::MaxReplicasExists, ScaleTargetRef> {
+        self.body.max_replicas = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Same as [`max_replicas`](#method.max_replicas), but takes a reference.
+    #[inline]
+    pub fn max_replicas_ref(self, value: &i32) -> HorizontalPodAutoscalerSpecBuilder<crate::generics::MaxReplicasExists, ScaleTargetRef> {
+        self.max_replicas(*value)
+    }
+
+    /// metrics contains the specifications for which to use to calculate the desired replica count (the maximum replica count across all metrics will be used).  The desired replica count is calculated multiplying the ratio between the target value and the current value by the current number of pods.  Ergo, metrics used must decrease as the pod count is increased, and vice-versa.  See the individual metric source types for more information about how each type of metric must respond. If not set, the default metric will be set to 80% average CPU utilization.
+    #[inline]
+    pub fn metrics(mut self, value: impl Iterator<Item = crate::io::k8s::api::autoscaling::v2beta2::metric_spec::MetricSpecBuilder<crate::generics::TypeExists>>) -> Self {
+        self.body.metrics = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`metrics`](#method.metrics) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_metrics(mut self, value: Option<impl Iterator<Item = crate::io::k8s::api::autoscaling::v2beta2::metric_spec::MetricSpecBuilder<crate::generics::TypeExists>>>) -> Self {
+        self.body.metrics = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`metrics`](#method.metrics), but takes the values as a `Vec`.
+    #[inline]
+    pub fn metrics_vec(mut self, value: impl Into<Vec<crate::io::k8s::api::autoscaling::v2beta2::metric_spec::MetricSpec>>) -> Self {
+        self.body.metrics = Some(value.into());
+        self
+    }
+
+    /// minReplicas is the lower limit for the number of replicas to which the autoscaler can scale down. It defaults to 1 pod.
+    #[inline]
+    pub fn min_replicas(mut self, value: impl Into<i32>) -> Self {
+        self.body.min_replicas = Some(value.into());
+        self
+    }
+
+    /// Sets [`min_replicas`](#method.min_replicas) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_min_replicas(mut self, value: Option<impl Into<i32>>) -> Self {
+        self.body.min_replicas = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`min_replicas`](#method.min_replicas), but takes a reference.
+    #[inline]
+    pub fn min_replicas_ref(self, value: &i32) -> Self {
+        self.min_replicas(*value)
+    }
+
+    /// scaleTargetRef points to the target resource to scale, and is used to the pods for which metrics should be collected, as well as to actually change the replica count.
+    #[inline]
+    pub fn scale_target_ref(mut self, value: crate::io::k8s::api::autoscaling::v2beta2::cross_version_object_reference::CrossVersionObjectReferenceBuilder<crate::generics::KindExists, crate::generics::NameExists>) -> HorizontalPodAutoscalerSpecBuilder<MaxReplicas, crate::generics::ScaleTargetRefExists> {
+        self.body.scale_target_ref = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+}
@@ -0,0 +1,110 @@
+
+/// MetricTarget defines the target value, average value, or average utilization of a specific metric
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct MetricTarget {
+    /// averageUtilization is the target value of the average of the resource metric across all relevant pods, represented as a percentage of the requested value of the resource for the pods. Currently only valid for Resource metric source type
+    #[serde(rename = "averageUtilization", skip_serializing_if = "Option::is_none")]
+    pub average_utilization: Option<i32>,
+    /// averageValue is the target value of the average of the metric across all relevant pods (as a quantity)
+    #[serde(rename = "averageValue", skip_serializing_if = "Option::is_none")]
+    pub average_value: Option<String>,
+    /// type represents whether the metric type is Utilization, Value, or AverageValue
+    #[serde(rename = "type")]
+    pub type_: String,
+    /// value is the target value of the metric (as a quantity).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+}
+
+impl MetricTarget {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> MetricTargetBuilder<crate::generics::MissingType> {
+        MetricTargetBuilder {
+            body: Default::default(),
+            _type: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> MetricTargetBuilder<crate::generics::TypeExists>
+    where
+        Self: Clone,
+    {
+        MetricTargetBuilder {
+            body: self.clone(),
+            _type: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<MetricTarget> for MetricTargetBuilder<crate::generics::TypeExists> {
+    fn into(self) -> MetricTarget {
+        self.body
+    }
+}
+
+/// Builder for [`MetricTarget`](./struct.MetricTarget.html) object.
+#[derive(Debug, Clone)]
+pub struct MetricTargetBuilder<Type> {
+    body: self::MetricTarget,
+    _type: core::marker::PhantomData<Type>,
+}
+
+impl<Type> MetricTargetBuilder<Type> {
+    /// averageUtilization is the target value of the average of the resource metric across all relevant pods, represented as a percentage of the requested value of the resource for the pods. Currently only valid for Resource metric source type
+    #[inline]
+    pub fn average_utilization(mut self, value: impl Into<i32>) -> Self {
+        self.body.average_utilization = Some(value.into());
+        self
+    }
+
+    /// Sets [`average_utilization`](#method.average_utilization) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_average_utilization(mut self, value: Option<impl Into<i32>>) -> Self {
+        self.body.average_utilization = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`average_utilization`](#method.average_utilization), but takes a reference.
+    #[inline]
+    pub fn average_utilization_ref(self, value: &i32) -> Self {
+        self.average_utilization(*value)
+    }
+
+    /// averageValue is the target value of the average of the metric across all relevant pods (as a quantity)
+    #[inline]
+    pub fn average_value(mut self, value: impl Into<String>) -> Self {
+        self.body.average_value = Some(value.into());
+        self
+    }
+
+    /// Sets [`average_value`](#method.average_value) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_average_value(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.average_value = value.map(|value| value.into());
+        self
+    }
+
+    /// type represents whether the metric type is Utilization, Value, or AverageValue
+    #[inline]
+    pub fn type_(mut self, value: impl Into<String>) -> MetricTargetBuilder<crate::generics::TypeExists> {
+        self.body.type_ = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// value is the target value of the metric (as a quantity).
+    #[inline]
+    pub fn value(mut self, value: impl Into<String>) -> Self {
+        self.body.value = Some(value.into());
+        self
+    }
+
+    /// Sets [`value`](#method.value) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_value(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.value = value.map(|value| value.into());
+        self
+    }
+}
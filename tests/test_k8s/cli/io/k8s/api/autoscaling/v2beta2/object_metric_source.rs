@@ -0,0 +1,75 @@
+
+/// ObjectMetricSource indicates how to scale on a metric describing a kubernetes object (for example, hits-per-second on an Ingress object).
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct ObjectMetricSource {
+    #[serde(rename = "describedObject")]
+    pub described_object: crate::io::k8s::api::autoscaling::v2beta2::cross_version_object_reference::CrossVersionObjectReference,
+    /// metric identifies the target metric by name and selector
+    pub metric: crate::io::k8s::api::autoscaling::v2beta2::metric_identifier::MetricIdentifier,
+    /// target specifies the target value for the given metric
+    pub target: crate::io::k8s::api::autoscaling::v2beta2::metric_target::MetricTarget,
+}
+
+impl ObjectMetricSource {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> ObjectMetricSourceBuilder<crate::generics::MissingDescribedObject, crate::generics::MissingMetric, crate::generics::MissingTarget> {
+        ObjectMetricSourceBuilder {
+            body: Default::default(),
+            _described_object: core::marker::PhantomData,
+            _metric: core::marker::PhantomData,
+            _target: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> ObjectMetricSourceBuilder<crate::generics::DescribedObjectExists, crate::generics::MetricExists, crate::generics::TargetExists>
+    where
+        Self: Clone,
+    {
+        ObjectMetricSourceBuilder {
+            body: self.clone(),
+            _described_object: core::marker::PhantomData,
+            _metric: core::marker::PhantomData,
+            _target: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<ObjectMetricSource> for ObjectMetricSourceBuilder<crate::generics::DescribedObjectExists, crate::generics::MetricExists, crate::generics::TargetExists> {
+    fn into(self) -> ObjectMetricSource {
+        self.body
+    }
+}
+
+/// Builder for [`ObjectMetricSource`](./struct.ObjectMetricSource.html) object.
+#[derive(Debug, Clone)]
+pub struct ObjectMetricSourceBuilder<DescribedObject, Metric, Target> {
+    body: self::ObjectMetricSource,
+    _described_object: core::marker::PhantomData<DescribedObject>,
+    _metric: core::marker::PhantomData<Metric>,
+    _target: core::marker::PhantomData<Target>,
+}
+
+impl<DescribedObject, Metric, Target> ObjectMetricSourceBuilder<DescribedObject, Metric, Target> {
+    #[inline]
+    pub fn described_object(mut self, value: crate::io::k8s::api::autoscaling::v2beta2::cross_version_object_reference::CrossVersionObjectReferenceBuilder<crate::generics::KindExists, crate::generics::NameExists>) -> ObjectMetricSourceBuilder<crate::generics::DescribedObjectExists, Metric, Target> {
+        self.body.described_object = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// metric identifies the target metric by name and selector
+    #[inline]
+    pub fn metric(mut self, value: crate::io::k8s::api::autoscaling::v2beta2::metric_identifier::MetricIdentifierBuilder<crate::generics::NameExists>) -> ObjectMetricSourceBuilder<DescribedObject, crate::generics::MetricExists, Target> {
+        self.body.metric = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// target specifies the target value for the given metric
+    #[inline]
+    pub fn target(mut self, value: crate::io::k8s::api::autoscaling::v2beta2::metric_target::MetricTargetBuilder<crate::generics::TypeExists>) -> ObjectMetricSourceBuilder<DescribedObject, Metric, crate::generics::TargetExists> {
+        self.body.target = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+}
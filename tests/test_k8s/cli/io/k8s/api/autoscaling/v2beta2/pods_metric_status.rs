@@ -0,0 +1,64 @@
+
+/// PodsMetricStatus indicates the current value of a metric describing each pod in the current scale target (for example, transactions-processed-per-second).
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct PodsMetricStatus {
+    /// current contains the current value for the given metric
+    pub current: crate::io::k8s::api::autoscaling::v2beta2::metric_value_status::MetricValueStatus,
+    /// metric identifies the target metric by name and selector
+    pub metric: crate::io::k8s::api::autoscaling::v2beta2::metric_identifier::MetricIdentifier,
+}
+
+impl PodsMetricStatus {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> PodsMetricStatusBuilder<crate::generics::MissingCurrent, crate::generics::MissingMetric> {
+        PodsMetricStatusBuilder {
+            body: Default::default(),
+            _current: core::marker::PhantomData,
+            _metric: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> PodsMetricStatusBuilder<crate::generics::CurrentExists, crate::generics::MetricExists>
+    where
+        Self: Clone,
+    {
+        PodsMetricStatusBuilder {
+            body: self.clone(),
+            _current: core::marker::PhantomData,
+            _metric: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<PodsMetricStatus> for PodsMetricStatusBuilder<crate::generics::CurrentExists, crate::generics::MetricExists> {
+    fn into(self) -> PodsMetricStatus {
+        self.body
+    }
+}
+
+/// Builder for [`PodsMetricStatus`](./struct.PodsMetricStatus.html) object.
+#[derive(Debug, Clone)]
+pub struct PodsMetricStatusBuilder<Current, Metric> {
+    body: self::PodsMetricStatus,
+    _current: core::marker::PhantomData<Current>,
+    _metric: core::marker::PhantomData<Metric>,
+}
+
+impl<Current, Metric> PodsMetricStatusBuilder<Current, Metric> {
+    /// current contains the current value for the given metric
+    #[inline]
+    pub fn current(mut self, value: crate::io::k8s::api::autoscaling::v2beta2::metric_value_status::MetricValueStatus) -> PodsMetricStatusBuilder<crate::generics::CurrentExists, Metric> {
+        self.body.current = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// metric identifies the target metric by name and selector
+    #[inline]
+    pub fn metric(mut self, value: crate::io::k8s::api::autoscaling::v2beta2::metric_identifier::MetricIdentifierBuilder<crate::generics::NameExists>) -> PodsMetricStatusBuilder<Current, crate::generics::MetricExists> {
+        self.body.metric = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+}
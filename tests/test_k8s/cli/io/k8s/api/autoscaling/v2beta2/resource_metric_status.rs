@@ -0,0 +1,64 @@
+
+/// ResourceMetricStatus indicates the current value of a resource metric known to Kubernetes, as specified in requests and limits, describing each pod in the current scale target (e.g. CPU or memory).  Such metrics are built in to Kubernetes, and have special scaling options on top of those available to normal per-pod metrics using the "pods" source.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct ResourceMetricStatus {
+    /// current contains the current value for the given metric
+    pub current: crate::io::k8s::api::autoscaling::v2beta2::metric_value_status::MetricValueStatus,
+    /// Name is the name of the resource in question.
+    pub name: String,
+}
+
+impl ResourceMetricStatus {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> ResourceMetricStatusBuilder<crate::generics::MissingCurrent, crate::generics::MissingName> {
+        ResourceMetricStatusBuilder {
+            body: Default::default(),
+            _current: core::marker::PhantomData,
+            _name: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> ResourceMetricStatusBuilder<crate::generics::CurrentExists, crate::generics::NameExists>
+    where
+        Self: Clone,
+    {
+        ResourceMetricStatusBuilder {
+            body: self.clone(),
+            _current: core::marker::PhantomData,
+            _name: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<ResourceMetricStatus> for ResourceMetricStatusBuilder<crate::generics::CurrentExists, crate::generics::NameExists> {
+    fn into(self) -> ResourceMetricStatus {
+        self.body
+    }
+}
+
+/// Builder for [`ResourceMetricStatus`](./struct.ResourceMetricStatus.html) object.
+#[derive(Debug, Clone)]
+pub struct ResourceMetricStatusBuilder<Current, Name> {
+    body: self::ResourceMetricStatus,
+    _current: core::marker::PhantomData<Current>,
+    _name: core::marker::PhantomData<Name>,
+}
+
+impl<Current, Name> ResourceMetricStatusBuilder<Current, Name> {
+    /// current contains the current value for the given metric
+    #[inline]
+    pub fn current(mut self, value: crate::io::k8s::api::autoscaling::v2beta2::metric_value_status::MetricValueStatus) -> ResourceMetricStatusBuilder<crate::generics::CurrentExists, Name> {
+        self.body.current = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Name is the name of the resource in question.
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> ResourceMetricStatusBuilder<Current, crate::generics::NameExists> {
+        self.body.name = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+}
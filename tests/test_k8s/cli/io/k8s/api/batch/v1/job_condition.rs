@@ -0,0 +1,133 @@
+
+/// JobCondition describes current state of a job.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct JobCondition {
+    /// Last time the condition was checked.
+    #[serde(rename = "lastProbeTime", skip_serializing_if = "Option::is_none")]
+    pub last_probe_time: Option<String>,
+    /// Last time the condition transit from one status to another.
+    #[serde(rename = "lastTransitionTime", skip_serializing_if = "Option::is_none")]
+    pub last_transition_time: Option<String>,
+    /// Human readable message indicating details about last transition.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    /// (brief) reason for the condition's last transition.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+    /// Status of the condition, one of True, False, Unknown.
+    pub status: String,
+    /// Type of job condition, Complete or Failed.
+    #[serde(rename = "type")]
+    pub type_: String,
+}
+
+impl JobCondition {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> JobConditionBuilder<crate::generics::MissingStatus, crate::generics::MissingType> {
+        JobConditionBuilder {
+            body: Default::default(),
+            _status: core::marker::PhantomData,
+            _type: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> JobConditionBuilder<crate::generics::StatusExists, crate::generics::TypeExists>
+    where
+        Self: Clone,
+    {
+        JobConditionBuilder {
+            body: self.clone(),
+            _status: core::marker::PhantomData,
+            _type: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<JobCondition> for JobConditionBuilder<crate::generics::StatusExists, crate::generics::TypeExists> {
+    fn into(self) -> JobCondition {
+        self.body
+    }
+}
+
+/// Builder for [`JobCondition`](./struct.JobCondition.html) object.
+#[derive(Debug, Clone)]
+pub struct JobConditionBuilder<Status, Type> {
+    body: self::JobCondition,
+    _status: core::marker::PhantomData<Status>,
+    _type: core::marker::PhantomData<Type>,
+}
+
+impl<Status, Type> JobConditionBuilder<Status, Type> {
+    /// Last time the condition was checked.
+    #[inline]
+    pub fn last_probe_time(mut self, value: impl Into<String>) -> Self {
+        self.body.last_probe_time = Some(value.into());
+        self
+    }
+
+    /// Sets [`last_probe_time`](#method.last_probe_time) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_last_probe_time(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.last_probe_time = value.map(|value| value.into());
+        self
+    }
+
+    /// Last time the condition transit from one status to another.
+    #[inline]
+    pub fn last_transition_time(mut self, value: impl Into<String>) -> Self {
+        self.body.last_transition_time = Some(value.into());
+        self
+    }
+
+    /// Sets [`last_transition_time`](#method.last_transition_time) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_last_transition_time(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.last_transition_time = value.map(|value| value.into());
+        self
+    }
+
+    /// Human readable message indicating details about last transition.
+    #[inline]
+    pub fn message(mut self, value: impl Into<String>) -> Self {
+        self.body.message = Some(value.into());
+        self
+    }
+
+    /// Sets [`message`](#method.message) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_message(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.message = value.map(|value| value.into());
+        self
+    }
+
+    /// (brief) reason for the condition's last transition.
+    #[inline]
+    pub fn reason(mut self, value: impl Into<String>) -> Self {
+        self.body.reason = Some(value.into());
+        self
+    }
+
+    /// Sets [`reason`](#method.reason) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_reason(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.reason = value.map(|value| value.into());
+        self
+    }
+
+    /// Status of the condition, one of True, False, Unknown.
+    #[inline]
+    pub fn status(mut self, value: impl Into<String>) -> JobConditionBuilder<crate::generics::StatusExists, Type> {
+        self.body.status = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Type of job condition, Complete or Failed.
+    #[inline]
+    pub fn type_(mut self, value: impl Into<String>) -> JobConditionBuilder<Status, crate::generics::TypeExists> {
+        self.body.type_ = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+}
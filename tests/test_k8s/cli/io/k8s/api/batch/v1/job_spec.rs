@@ -0,0 +1,207 @@
+
+/// JobSpec describes how the job execution will look like.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct JobSpec {
+    /// Specifies the duration in seconds relative to the startTime that the job may be active before the system tries to terminate it; value must be positive integer
+    #[serde(rename = "activeDeadlineSeconds", skip_serializing_if = "Option::is_none")]
+    pub active_deadline_seconds: Option<i64>,
+    /// Specifies the number of retries before marking this job failed. Defaults to 6
+    #[serde(rename = "backoffLimit", skip_serializing_if = "Option::is_none")]
+    pub backoff_limit: Option<i32>,
+    /// Specifies the desired number of successfully finished pods the job should be run with.  Setting to nil means that the success of any pod signals the success of all pods, and allows parallelism to have any positive value.  Setting to 1 means that parallelism is limited to 1 and the success of that pod signals the success of the job. More info: https://kubernetes.io/docs/concepts/workloads/controllers/jobs-run-to-completion/
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub completions: Option<i32>,
+    /// manualSelector controls generation of pod labels and pod selectors. Leave `manualSelector` unset unless you are certain what you are doing. When false or unset, the system pick labels unique to this job and appends those labels to the pod template.  When true, the user is responsible for picking unique labels and specifying the selector.  Failure to pick a unique label may cause this and other jobs to not function correctly.  However, You may see `manualSelector=true` in jobs that were created with the old `extensions/v1beta1` API. More info: https://kubernetes.io/docs/concepts/workloads/controllers/jobs-run-to-completion/#specifying-your-own-pod-selector
+    #[serde(rename = "manualSelector", skip_serializing_if = "Option::is_none")]
+    pub manual_selector: Option<bool>,
+    /// Specifies the maximum desired number of pods the job should run at any given time. The actual number of pods running in steady state will be less than this number when ((.spec.completions - .status.successful) < .spec.parallelism), i.e. when the work left to do is less than max parallelism. More info: https://kubernetes.io/docs/concepts/workloads/controllers/jobs-run-to-completion/
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parallelism: Option<i32>,
+    /// A label query over pods that should match the pod count. Normally, the system sets this field for you. More info: https://kubernetes.io/docs/concepts/overview/working-with-objects/labels/#label-selectors
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub selector: Option<crate::io::k8s::apimachinery::pkg::apis::meta::v1::label_selector::LabelSelector>,
+    /// Describes the pod that will be created when executing a job. More info: https://kubernetes.io/docs/concepts/workloads/controllers/jobs-run-to-completion/
+    pub template: crate::io::k8s::api::core::v1::pod_template_spec::PodTemplateSpec,
+    /// ttlSecondsAfterFinished limits the lifetime of a Job that has finished execution (either Complete or Failed). If this field is set, ttlSecondsAfterFinished after the Job finishes, it is eligible to be automatically deleted. When the Job is being deleted, its lifecycle guarantees (e.g. finalizers) will be honored. If this field is unset, the Job won't be automatically deleted. If this field is set to zero, the Job becomes eligible to be deleted immediately after it finishes. This field is alpha-level and is only honored by servers that enable the TTLAfterFinished feature.
+    #[serde(rename = "ttlSecondsAfterFinished", skip_serializing_if = "Option::is_none")]
+    pub ttl_seconds_after_finished: Option<i32>,
+}
+
+impl JobSpec {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> JobSpecBuilder<crate::generics::MissingTemplate> {
+        JobSpecBuilder {
+            body: Default::default(),
+            _template: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> JobSpecBuilder<crate::generics::TemplateExists>
+    where
+        Self: Clone,
+    {
+        JobSpecBuilder {
+            body: self.clone(),
+            _template: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<JobSpec> for JobSpecBuilder<crate::generics::TemplateExists> {
+    fn into(self) -> JobSpec {
+        self.body
+    }
+}
+
+/// Builder for [`JobSpec`](./struct.JobSpec.html) object.
+#[derive(Debug, Clone)]
+pub struct JobSpecBuilder<Template> {
+    body: self::JobSpec,
+    _template: core::marker::PhantomData<Template>,
+}
+
+impl<Template> JobSpecBuilder<Template> {
+    /// Specifies the duration in seconds relative to the startTime that the job may be active before the system tries to terminate it; value must be positive integer
+    #[inline]
+    pub fn active_deadline_seconds(mut self, value: impl Into<i64>) -> Self {
+        self.body.active_deadline_seconds = Some(value.into());
+        self
+    }
+
+    /// Sets [`active_deadline_seconds`](#method.active_deadline_seconds) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_active_deadline_seconds(mut self, value: Option<impl Into<i64>>) -> Self {
+        self.body.active_deadline_seconds = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`active_deadline_seconds`](#method.active_deadline_seconds), but takes a reference.
+    #[inline]
+    pub fn active_deadline_seconds_ref(self, value: &i64) -> Self {
+        self.active_deadline_seconds(*value)
+    }
+
+    /// Specifies the number of retries before marking this job failed. Defaults to 6
+    #[inline]
+    pub fn backoff_limit(mut self, value: impl Into<i32>) -> Self {
+        self.body.backoff_limit = Some(value.into());
+        self
+    }
+
+    /// Sets [`backoff_limit`](#method.backoff_limit) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_backoff_limit(mut self, value: Option<impl Into<i32>>) -> Self {
+        self.body.backoff_limit = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`backoff_limit`](#method.backoff_limit), but takes a reference.
+    #[inline]
+    pub fn backoff_limit_ref(self, value: &i32) -> Self {
+        self.backoff_limit(*value)
+    }
+
+    /// Specifies the desired number of successfully finished pods the job should be run with.  Setting to nil means that the success of any pod signals the success of all pods, and allows parallelism to have any positive value.  Setting to 1 means that parallelism is limited to 1 and the success of that pod signals the success of the job. More info: https://kubernetes.io/docs/concepts/workloads/controllers/jobs-run-to-completion/
+    #[inline]
+    pub fn completions(mut self, value: impl Into<i32>) -> Self {
+        self.body.completions = Some(value.into());
+        self
+    }
+
+    /// Sets [`completions`](#method.completions) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_completions(mut self, value: Option<impl Into<i32>>) -> Self {
+        self.body.completions = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`completions`](#method.completions), but takes a reference.
+    #[inline]
+    pub fn completions_ref(self, value: &i32) -> Self {
+        self.completions(*value)
+    }
+
+    /// manualSelector controls generation of pod labels and pod selectors. Leave `manualSelector` unset unless you are certain what you are doing. When false or unset, the system pick labels unique to this job and appends those labels to the pod template.  When true, the user is responsible for picking unique labels and specifying the selector.  Failure to pick a unique label may cause this and other jobs to not function correctly.  However, You may see `manualSelector=true` in jobs that were created with the old `extensions/v1beta1` API. More info: https://kubernetes.io/docs/concepts/workloads/controllers/jobs-run-to-completion/#specifying-your-own-pod-selector
+    #[inline]
+    pub fn manual_selector(mut self, value: impl Into<bool>) -> Self {
+        self.body.manual_selector = Some(value.into());
+        self
+    }
+
+    /// Sets [`manual_selector`](#method.manual_selector) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_manual_selector(mut self, value: Option<impl Into<bool>>) -> Self {
+        self.body.manual_selector = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`manual_selector`](#method.manual_selector), but takes a reference.
+    #[inline]
+    pub fn manual_selector_ref(self, value: &bool) -> Self {
+        self.manual_selector(*value)
+    }
+
+    /// Specifies the maximum desired number of pods the job should run at any given time. The actual number of pods running in steady state will be less than this number when ((.spec.completions - .status.successful) < .spec.parallelism), i.e. when the work left to do is less than max parallelism. More info: https://kubernetes.io/docs/concepts/workloads/controllers/jobs-run-to-completion/
+    #[inline]
+    pub fn parallelism(mut self, value: impl Into<i32>) -> Self {
+        self.body.parallelism = Some(value.into());
+        self
+    }
+
+    /// Sets [`parallelism`](#method.parallelism) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_parallelism(mut self, value: Option<impl Into<i32>>) -> Self {
+        self.body.parallelism = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`parallelism`](#method.parallelism), but takes a reference.
+    #[inline]
+    pub fn parallelism_ref(self, value: &i32) -> Self {
+        self.parallelism(*value)
+    }
+
+    /// A label query over pods that should match the pod count. Normally, the system sets this field for you. More info: https://kubernetes.io/docs/concepts/overview/working-with-objects/labels/#label-selectors
+    #[inline]
+    pub fn selector(mut self, value: crate::io::k8s::apimachinery::pkg::apis::meta::v1::label_selector::LabelSelector) -> Self {
+        self.body.selector = Some(value.into());
+        self
+    }
+
+    /// Sets [`selector`](#method.selector) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_selector(mut self, value: Option<crate::io::k8s::apimachinery::pkg::apis::meta::v1::label_selector::LabelSelector>) -> Self {
+        self.body.selector = value.map(|value| value.into());
+        self
+    }
+
+    /// Describes the pod that will be created when executing a job. More info: https://kubernetes.io/docs/concepts/workloads/controllers/jobs-run-to-completion/
+    #[inline]
+    pub fn template(mut self, value: crate::io::k8s::api::core::v1::pod_template_spec::PodTemplateSpec) -> JobSpecBuilder<crate::generics::TemplateExists> {
+        self.body.template = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// ttlSecondsAfterFinished limits the lifetime of a Job that has finished execution (either Complete or Failed). If this field is set, ttlSecondsAfterFinished after the Job finishes, it is eligible to be automatically deleted. When the Job is being deleted, its lifecycle guarantees (e.g. finalizers) will be honored. If this field is unset, the Job won't be automatically deleted. If this field is set to zero, the Job becomes eligible to be deleted immediately after it finishes. This field is alpha-level and is only honored by servers that enable the TTLAfterFinished feature.
+    #[inline]
+    pub fn ttl_seconds_after_finished(mut self, value: impl Into<i32>) -> Self {
+        self.body.ttl_seconds_after_finished = Some(value.into());
+        self
+    }
+
+    /// Sets [`ttl_seconds_after_finished`](#method.ttl_seconds_after_finished) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_ttl_seconds_after_finished(mut self, value: Option<impl Into<i32>>) -> Self {
+        self.body.ttl_seconds_after_finished = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`ttl_seconds_after_finished`](#method.ttl_seconds_after_finished), but takes a reference.
+    #[inline]
+    pub fn ttl_seconds_after_finished_ref(self, value: &i32) -> Self {
+        self.ttl_seconds_after_finished(*value)
+    }
+}
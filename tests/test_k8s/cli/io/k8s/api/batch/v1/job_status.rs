@@ -0,0 +1,167 @@
+
+/// JobStatus represents the current state of a Job.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct JobStatus {
+    /// The number of actively running pods.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub active: Option<i32>,
+    /// Represents time when the job was completed. It is not guaranteed to be set in happens-before order across separate operations. It is represented in RFC3339 form and is in UTC.
+    #[serde(rename = "completionTime", skip_serializing_if = "Option::is_none")]
+    pub completion_time: Option<String>,
+    /// The latest available observations of an object's current state. More info: https://kubernetes.io/docs/concepts/workloads/controllers/jobs-run-to-completion/
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub conditions: Option<Vec<crate::io::k8s::api::batch::v1::job_condition::JobCondition>>,
+    /// The number of pods which reached phase Failed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub failed: Option<i32>,
+    /// Represents time when the job was acknowledged by the job controller. It is not guaranteed to be set in happens-before order across separate operations. It is represented in RFC3339 form and is in UTC.
+    #[serde(rename = "startTime", skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<String>,
+    /// The number of pods which reached phase Succeeded.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub succeeded: Option<i32>,
+}
+
+impl JobStatus {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> JobStatusBuilder {
+        JobStatusBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> JobStatusBuilder
+    where
+        Self: Clone,
+    {
+        JobStatusBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<JobStatus> for JobStatusBuilder {
+    fn into(self) -> JobStatus {
+        self.body
+    }
+}
+
+/// Builder for [`JobStatus`](./struct.JobStatus.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct JobStatusBuilder {
+    body: self::JobStatus,
+}
+
+impl JobStatusBuilder {
+    /// The number of actively running pods.
+    #[inline]
+    pub fn active(mut self, value: impl Into<i32>) -> Self {
+        self.body.active = Some(value.into());
+        self
+    }
+
+    /// Sets [`active`](#method.active) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_active(mut self, value: Option<impl Into<i32>>) -> Self {
+        self.body.active = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`active`](#method.active), but takes a reference.
+    #[inline]
+    pub fn active_ref(self, value: &i32) -> Self {
+        self.active(*value)
+    }
+
+    /// Represents time when the job was completed. It is not guaranteed to be set in happens-before order across separate operations. It is represented in RFC3339 form and is in UTC.
+    #[inline]
+    pub fn completion_time(mut self, value: impl Into<String>) -> Self {
+        self.body.completion_time = Some(value.into());
+        self
+    }
+
+    /// Sets [`completion_time`](#method.completion_time) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_completion_time(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.completion_time = value.map(|value| value.into());
+        self
+    }
+
+    /// The latest available observations of an object's current state. More info: https://kubernetes.io/docs/concepts/workloads/controllers/jobs-run-to-completion/
+    #[inline]
+    pub fn conditions(mut self, value: impl Iterator<Item = crate::io::k8s::api::batch::v1::job_condition::JobConditionBuilder<crate::generics::StatusExists, crate::generics::TypeExists>>) -> Self {
+        self.body.conditions = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`conditions`](#method.conditions) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_conditions(mut self, value: Option<impl Iterator<Item = crate::io::k8s::api::batch::v1::job_condition::JobConditionBuilder<crate::generics::StatusExists, crate::generics::TypeExists>>>) -> Self {
+        self.body.conditions = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`conditions`](#method.conditions), but takes the values as a `Vec`.
+    #[inline]
+    pub fn conditions_vec(mut self, value: impl Into<Vec<crate::io::k8s::api::batch::v1::job_condition::JobCondition>>) -> Self {
+        self.body.conditions = Some(value.into());
+        self
+    }
+
+    /// The number of pods which reached phase Failed.
+    #[inline]
+    pub fn failed(mut self, value: impl Into<i32>) -> Self {
+        self.body.failed = Some(value.into());
+        self
+    }
+
+    /// Sets [`failed`](#method.failed) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_failed(mut self, value: Option<impl Into<i32>>) -> Self {
+        self.body.failed = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`failed`](#method.failed), but takes a reference.
+    #[inline]
+    pub fn failed_ref(self, value: &i32) -> Self {
+        self.failed(*value)
+    }
+
+    /// Represents time when the job was acknowledged by the job controller. It is not guaranteed to be set in happens-before order across separate operations. It is represented in RFC3339 form and is in UTC.
+    #[inline]
+    pub fn start_time(mut self, value: impl Into<String>) -> Self {
+        self.body.start_time = Some(value.into());
+        self
+    }
+
+    /// Sets [`start_time`](#method.start_time) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_start_time(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.start_time = value.map(|value| value.into());
+        self
+    }
+
+    /// The number of pods which reached phase Succeeded.
+    #[inline]
+    pub fn succeeded(mut self, value: impl Into<i32>) -> Self {
+        self.body.succeeded = Some(value.into());
+        self
+    }
+
+    /// Sets [`succeeded`](#method.succeeded) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_succeeded(mut self, value: Option<impl Into<i32>>) -> Self {
+        self.body.succeeded = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`succeeded`](#method.succeeded), but takes a reference.
+    #[inline]
+    pub fn succeeded_ref(self, value: &i32) -> Self {
+        self.succeeded(*value)
+    }
+}
@@ -0,0 +1,20 @@
+
+pub mod job {
+    include!("./job.rs");
+}
+
+pub mod job_condition {
+    include!("./job_condition.rs");
+}
+
+pub mod job_list {
+    include!("./job_list.rs");
+}
+
+pub mod job_spec {
+    include!("./job_spec.rs");
+}
+
+pub mod job_status {
+    include!("./job_status.rs");
+}
@@ -0,0 +1,20 @@
+
+pub mod cron_job {
+    include!("./cron_job.rs");
+}
+
+pub mod cron_job_list {
+    include!("./cron_job_list.rs");
+}
+
+pub mod cron_job_spec {
+    include!("./cron_job_spec.rs");
+}
+
+pub mod cron_job_status {
+    include!("./cron_job_status.rs");
+}
+
+pub mod job_template_spec {
+    include!("./job_template_spec.rs");
+}
@@ -0,0 +1,153 @@
+
+/// This information is immutable after the request is created. Only the Request and Usages fields can be set on creation, other fields are derived by Kubernetes and cannot be modified by users.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct CertificateSigningRequestSpec {
+    /// Extra information about the requesting user. See user.Info interface for details.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub extra: Option<std::collections::BTreeMap<String, Vec<String>>>,
+    /// Group information about the requesting user. See user.Info interface for details.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub groups: Option<Vec<String>>,
+    /// Base64-encoded PKCS#10 CSR data
+    pub request: String,
+    /// UID information about the requesting user. See user.Info interface for details.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub uid: Option<String>,
+    /// allowedUsages specifies a set of usage contexts the key will be valid for. See: https://tools.ietf.org/html/rfc5280#section-4.2.1.3
+    ///      https://tools.ietf.org/html/rfc5280#section-4.2.1.12
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub usages: Option<Vec<String>>,
+    /// Information about the requesting user. See user.Info interface for details.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub username: Option<String>,
+}
+
+impl CertificateSigningRequestSpec {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> CertificateSigningRequestSpecBuilder<crate::generics::MissingRequest> {
+        CertificateSigningRequestSpecBuilder {
+            body: Default::default(),
+            _request: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> CertificateSigningRequestSpecBuilder<crate::generics::RequestExists>
+    where
+        Self: Clone,
+    {
+        CertificateSigningRequestSpecBuilder {
+            body: self.clone(),
+            _request: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<CertificateSigningRequestSpec> for CertificateSigningRequestSpecBuilder<crate::generics::RequestExists> {
+    fn into(self) -> CertificateSigningRequestSpec {
+        self.body
+    }
+}
+
+/// Builder for [`CertificateSigningRequestSpec`](./struct.CertificateSigningRequestSpec.html) object.
+#[derive(Debug, Clone)]
+pub struct CertificateSigningRequestSpecBuilder<Request> {
+    body: self::CertificateSigningRequestSpec,
+    _request: core::marker::PhantomData<Request>,
+}
+
+impl<Request> CertificateSigningRequestSpecBuilder<Request> {
+    /// Extra information about the requesting user. See user.Info interface for details.
+    #[inline]
+    pub fn extra(mut self, value: impl Iterator<Item = (String, impl Iterator<Item = impl Into<String>>)>) -> Self {
+        self.body.extra = Some(value.map(|(key, value)| (key, value.map(|value| value.into()).collect::<Vec<_>>().into())).collect::<std::collections::BTreeMap<_, _>>().into());
+        self
+    }
+
+    /// Sets [`extra`](#method.extra) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_extra(mut self, value: Option<impl Iterator<Item = (String, impl Iterator<Item = impl Into<String>>)>>) -> Self {
+        self.body.extra = value.map(|value| value.map(|(key, value)| (key, value.map(|value| value.into()).collect::<Vec<_>>().into())).collect::<std::collections::BTreeMap<_, _>>().into());
+        self
+    }
+
+    /// Group information about the requesting user. See user.Info interface for details.
+    #[inline]
+    pub fn groups(mut self, value: impl Iterator<Item = impl Into<String>>) -> Self {
+        self.body.groups = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`groups`](#method.groups) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_groups(mut self, value: Option<impl Iterator<Item = impl Into<String>>>) -> Self {
+        self.body.groups = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`groups`](#method.groups), but takes the values as a `Vec`.
+    #[inline]
+    pub fn groups_vec(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.body.groups = Some(value.into());
+        self
+    }
+
+    /// Base64-encoded PKCS#10 CSR data
+    #[inline]
+    pub fn request(mut self, value: impl Into<String>) -> CertificateSigningRequestSpecBuilder<crate::generics::RequestExists> {
+        self.body.request = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// UID information about the requesting user. See user.Info interface for details.
+    #[inline]
+    pub fn uid(mut self, value: impl Into<String>) -> Self {
+        self.body.uid = Some(value.into());
+        self
+    }
+
+    /// Sets [`uid`](#method.uid) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_uid(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.uid = value.map(|value| value.into());
+        self
+    }
+
+    /// allowedUsages specifies a set of usage contexts the key will be valid for. See: https://tools.ietf.org/html/rfc5280#section-4.2.1.3
+    ///      https://tools.ietf.org/html/rfc5280#section-4.2.1.12
+    #[inline]
+    pub fn usages(mut self, value: impl Iterator<Item = impl Into<String>>) -> Self {
+        self.body.usages = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`usages`](#method.usages) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_usages(mut self, value: Option<impl Iterator<Item = impl Into<String>>>) -> Self {
+        self.body.usages = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`usages`](#method.usages), but takes the values as a `Vec`.
+    #[inline]
+    pub fn usages_vec(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.body.usages = Some(value.into());
+        self
+    }
+
+    /// Information about the requesting user. See user.Info interface for details.
+    #[inline]
+    pub fn username(mut self, value: impl Into<String>) -> Self {
+        self.body.username = Some(value.into());
+        self
+    }
+
+    /// Sets [`username`](#method.username) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_username(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.username = value.map(|value| value.into());
+        self
+    }
+}
@@ -0,0 +1,79 @@
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct CertificateSigningRequestStatus {
+    /// If request was approved, the controller will place the issued certificate here.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub certificate: Option<String>,
+    /// Conditions applied to the request, such as approval or denial.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub conditions: Option<Vec<crate::io::k8s::api::certificates::v1beta1::certificate_signing_request_condition::CertificateSigningRequestCondition>>,
+}
+
+impl CertificateSigningRequestStatus {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> CertificateSigningRequestStatusBuilder {
+        CertificateSigningRequestStatusBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> CertificateSigningRequestStatusBuilder
+    where
+        Self: Clone,
+    {
+        CertificateSigningRequestStatusBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<CertificateSigningRequestStatus> for CertificateSigningRequestStatusBuilder {
+    fn into(self) -> CertificateSigningRequestStatus {
+        self.body
+    }
+}
+
+/// Builder for [`CertificateSigningRequestStatus`](./struct.CertificateSigningRequestStatus.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct CertificateSigningRequestStatusBuilder {
+    body: self::CertificateSigningRequestStatus,
+}
+
+impl CertificateSigningRequestStatusBuilder {
+    /// If request was approved, the controller will place the issued certificate here.
+    #[inline]
+    pub fn certificate(mut self, value: impl Into<String>) -> Self {
+        self.body.certificate = Some(value.into());
+        self
+    }
+
+    /// Sets [`certificate`](#method.certificate) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_certificate(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.certificate = value.map(|value| value.into());
+        self
+    }
+
+    /// Conditions applied to the request, such as approval or denial.
+    #[inline]
+    pub fn conditions(mut self, value: impl Iterator<Item = crate::io::k8s::api::certificates::v1beta1::certificate_signing_request_condition::CertificateSigningRequestConditionBuilder<crate::generics::TypeExists>>) -> Self {
+        self.body.conditions = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`conditions`](#method.conditions) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_conditions(mut self, value: Option<impl Iterator<Item = crate::io::k8s::api::certificates::v1beta1::certificate_signing_request_condition::CertificateSigningRequestConditionBuilder<crate::generics::TypeExists>>>) -> Self {
+        self.body.conditions = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`conditions`](#method.conditions), but takes the values as a `Vec`.
+    #[inline]
+    pub fn conditions_vec(mut self, value: impl Into<Vec<crate::io::k8s::api::certificates::v1beta1::certificate_signing_request_condition::CertificateSigningRequestCondition>>) -> Self {
+        self.body.conditions = Some(value.into());
+        self
+    }
+}
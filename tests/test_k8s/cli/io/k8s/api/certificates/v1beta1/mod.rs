@@ -0,0 +1,20 @@
+
+pub mod certificate_signing_request {
+    include!("./certificate_signing_request.rs");
+}
+
+pub mod certificate_signing_request_condition {
+    include!("./certificate_signing_request_condition.rs");
+}
+
+pub mod certificate_signing_request_list {
+    include!("./certificate_signing_request_list.rs");
+}
+
+pub mod certificate_signing_request_spec {
+    include!("./certificate_signing_request_spec.rs");
+}
+
+pub mod certificate_signing_request_status {
+    include!("./certificate_signing_request_status.rs");
+}
@@ -0,0 +1,137 @@
+
+/// LeaseSpec is a specification of a Lease.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct LeaseSpec {
+    /// acquireTime is a time when the current lease was acquired.
+    #[serde(rename = "acquireTime", skip_serializing_if = "Option::is_none")]
+    pub acquire_time: Option<String>,
+    /// holderIdentity contains the identity of the holder of a current lease.
+    #[serde(rename = "holderIdentity", skip_serializing_if = "Option::is_none")]
+    pub holder_identity: Option<String>,
+    /// leaseDurationSeconds is a duration that candidates for a lease need to wait to force acquire it. This is measure against time of last observed RenewTime.
+    #[serde(rename = "leaseDurationSeconds", skip_serializing_if = "Option::is_none")]
+    pub lease_duration_seconds: Option<i32>,
+    /// leaseTransitions is the number of transitions of a lease between holders.
+    #[serde(rename = "leaseTransitions", skip_serializing_if = "Option::is_none")]
+    pub lease_transitions: Option<i32>,
+    /// renewTime is a time when the current holder of a lease has last updated the lease.
+    #[serde(rename = "renewTime", skip_serializing_if = "Option::is_none")]
+    pub renew_time: Option<String>,
+}
+
+impl LeaseSpec {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> LeaseSpecBuilder {
+        LeaseSpecBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> LeaseSpecBuilder
+    where
+        Self: Clone,
+    {
+        LeaseSpecBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<LeaseSpec> for LeaseSpecBuilder {
+    fn into(self) -> LeaseSpec {
+        self.body
+    }
+}
+
+/// Builder for [`LeaseSpec`](./struct.LeaseSpec.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct LeaseSpecBuilder {
+    body: self::LeaseSpec,
+}
+
+impl LeaseSpecBuilder {
+    /// acquireTime is a time when the current lease was acquired.
+    #[inline]
+    pub fn acquire_time(mut self, value: impl Into<String>) -> Self {
+        self.body.acquire_time = Some(value.into());
+        self
+    }
+
+    /// Sets [`acquire_time`](#method.acquire_time) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_acquire_time(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.acquire_time = value.map(|value| value.into());
+        self
+    }
+
+    /// holderIdentity contains the identity of the holder of a current lease.
+    #[inline]
+    pub fn holder_identity(mut self, value: impl Into<String>) -> Self {
+        self.body.holder_identity = Some(value.into());
+        self
+    }
+
+    /// Sets [`holder_identity`](#method.holder_identity) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_holder_identity(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.holder_identity = value.map(|value| value.into());
+        self
+    }
+
+    /// leaseDurationSeconds is a duration that candidates for a lease need to wait to force acquire it. This is measure against time of last observed RenewTime.
+    #[inline]
+    pub fn lease_duration_seconds(mut self, value: impl Into<i32>) -> Self {
+        self.body.lease_duration_seconds = Some(value.into());
+        self
+    }
+
+    /// Sets [`lease_duration_seconds`](#method.lease_duration_seconds) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_lease_duration_seconds(mut self, value: Option<impl Into<i32>>) -> Self {
+        self.body.lease_duration_seconds = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`lease_duration_seconds`](#method.lease_duration_seconds), but takes a reference.
+    #[inline]
+    pub fn lease_duration_seconds_ref(self, value: &i32) -> Self {
+        self.lease_duration_seconds(*value)
+    }
+
+    /// leaseTransitions is the number of transitions of a lease between holders.
+    #[inline]
+    pub fn lease_transitions(mut self, value: impl Into<i32>) -> Self {
+        self.body.lease_transitions = Some(value.into());
+        self
+    }
+
+    /// Sets [`lease_transitions`](#method.lease_transitions) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_lease_transitions(mut self, value: Option<impl Into<i32>>) -> Self {
+        self.body.lease_transitions = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`lease_transitions`](#method.lease_transitions), but takes a reference.
+    #[inline]
+    pub fn lease_transitions_ref(self, value: &i32) -> Self {
+        self.lease_transitions(*value)
+    }
+
+    /// renewTime is a time when the current holder of a lease has last updated the lease.
+    #[inline]
+    pub fn renew_time(mut self, value: impl Into<String>) -> Self {
+        self.body.renew_time = Some(value.into());
+        self
+    }
+
+    /// Sets [`renew_time`](#method.renew_time) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_renew_time(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.renew_time = value.map(|value| value.into());
+        self
+    }
+}
@@ -0,0 +1,91 @@
+
+/// Affinity is a group of affinity scheduling rules.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct Affinity {
+    /// Describes node affinity scheduling rules for the pod.
+    #[serde(rename = "nodeAffinity", skip_serializing_if = "Option::is_none")]
+    pub node_affinity: Option<crate::io::k8s::api::core::v1::node_affinity::NodeAffinity>,
+    /// Describes pod affinity scheduling rules (e.g. co-locate this pod in the same node, zone, etc. as some other pod(s)).
+    #[serde(rename = "podAffinity", skip_serializing_if = "Option::is_none")]
+    pub pod_affinity: Option<crate::io::k8s::api::core::v1::pod_affinity::PodAffinity>,
+    /// Describes pod anti-affinity scheduling rules (e.g. avoid putting this pod in the same node, zone, etc. as some other pod(s)).
+    #[serde(rename = "podAntiAffinity", skip_serializing_if = "Option::is_none")]
+    pub pod_anti_affinity: Option<crate::io::k8s::api::core::v1::pod_anti_affinity::PodAntiAffinity>,
+}
+
+impl Affinity {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> AffinityBuilder {
+        AffinityBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> AffinityBuilder
+    where
+        Self: Clone,
+    {
+        AffinityBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<Affinity> for AffinityBuilder {
+    fn into(self) -> Affinity {
+        self.body
+    }
+}
+
+/// Builder for [`Affinity`](./struct.Affinity.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct AffinityBuilder {
+    body: self::Affinity,
+}
+
+impl AffinityBuilder {
+    /// Describes node affinity scheduling rules for the pod.
+    #[inline]
+    pub fn node_affinity(mut self, value: crate::io::k8s::api::core::v1::node_affinity::NodeAffinity) -> Self {
+        self.body.node_affinity = Some(value.into());
+        self
+    }
+
+    /// Sets [`node_affinity`](#method.node_affinity) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_node_affinity(mut self, value: Option<crate::io::k8s::api::core::v1::node_affinity::NodeAffinity>) -> Self {
+        self.body.node_affinity = value.map(|value| value.into());
+        self
+    }
+
+    /// Describes pod affinity scheduling rules (e.g. co-locate this pod in the same node, zone, etc. as some other pod(s)).
+    #[inline]
+    pub fn pod_affinity(mut self, value: crate::io::k8s::api::core::v1::pod_affinity::PodAffinity) -> Self {
+        self.body.pod_affinity = Some(value.into());
+        self
+    }
+
+    /// Sets [`pod_affinity`](#method.pod_affinity) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_pod_affinity(mut self, value: Option<crate::io::k8s::api::core::v1::pod_affinity::PodAffinity>) -> Self {
+        self.body.pod_affinity = value.map(|value| value.into());
+        self
+    }
+
+    /// Describes pod anti-affinity scheduling rules (e.g. avoid putting this pod in the same node, zone, etc. as some other pod(s)).
+    #[inline]
+    pub fn pod_anti_affinity(mut self, value: crate::io::k8s::api::core::v1::pod_anti_affinity::PodAntiAffinity) -> Self {
+        self.body.pod_anti_affinity = Some(value.into());
+        self
+    }
+
+    /// Sets [`pod_anti_affinity`](#method.pod_anti_affinity) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_pod_anti_affinity(mut self, value: Option<crate::io::k8s::api::core::v1::pod_anti_affinity::PodAntiAffinity>) -> Self {
+        self.body.pod_anti_affinity = value.map(|value| value.into());
+        self
+    }
+}
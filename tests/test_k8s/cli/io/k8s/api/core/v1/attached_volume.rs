@@ -0,0 +1,65 @@
+
+/// AttachedVolume describes a volume attached to a node
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct AttachedVolume {
+    /// DevicePath represents the device path where the volume should be available
+    #[serde(rename = "devicePath")]
+    pub device_path: String,
+    /// Name of the attached volume
+    pub name: String,
+}
+
+impl AttachedVolume {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> AttachedVolumeBuilder<crate::generics::MissingDevicePath, crate::generics::MissingName> {
+        AttachedVolumeBuilder {
+            body: Default::default(),
+            _device_path: core::marker::PhantomData,
+            _name: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> AttachedVolumeBuilder<crate::generics::DevicePathExists, crate::generics::NameExists>
+    where
+        Self: Clone,
+    {
+        AttachedVolumeBuilder {
+            body: self.clone(),
+            _device_path: core::marker::PhantomData,
+            _name: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<AttachedVolume> for AttachedVolumeBuilder<crate::generics::DevicePathExists, crate::generics::NameExists> {
+    fn into(self) -> AttachedVolume {
+        self.body
+    }
+}
+
+/// Builder for [`AttachedVolume`](./struct.AttachedVolume.html) object.
+#[derive(Debug, Clone)]
+pub struct AttachedVolumeBuilder<DevicePath, Name> {
+    body: self::AttachedVolume,
+    _device_path: core::marker::PhantomData<DevicePath>,
+    _name: core::marker::PhantomData<Name>,
+}
+
+impl<DevicePath, Name> AttachedVolumeBuilder<DevicePath, Name> {
+    /// DevicePath represents the device path where the volume should be available
+    #[inline]
+    pub fn device_path(mut self, value: impl Into<String>) -> AttachedVolumeBuilder<crate::generics::DevicePathExists, Name> {
+        self.body.device_path = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Name of the attached volume
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> AttachedVolumeBuilder<DevicePath, crate::generics::NameExists> {
+        self.body.name = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+}
@@ -0,0 +1,118 @@
+
+/// Represents a Persistent Disk resource in AWS.
+///
+/// An AWS EBS disk must exist before mounting to a container. The disk must also be in the same AWS zone as the kubelet. An AWS EBS disk can only be mounted as read/write once. AWS EBS volumes support ownership management and SELinux relabeling.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct AwsElasticBlockStoreVolumeSource {
+    /// Filesystem type of the volume that you want to mount. Tip: Ensure that the filesystem type is supported by the host operating system. Examples: "ext4", "xfs", "ntfs". Implicitly inferred to be "ext4" if unspecified. More info: https://kubernetes.io/docs/concepts/storage/volumes#awselasticblockstore
+    #[serde(rename = "fsType", skip_serializing_if = "Option::is_none")]
+    pub fs_type: Option<String>,
+    /// The partition in the volume that you want to mount. If omitted, the default is to mount by volume name. Examples: For volume /dev/sda1, you specify the partition as "1". Similarly, the volume partition for /dev/sda is "0" (or you can leave the property empty).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub partition: Option<i32>,
+    /// Specify "true" to force and set the ReadOnly property in VolumeMounts to "true". If omitted, the default is "false". More info: https://kubernetes.io/docs/concepts/storage/volumes#awselasticblockstore
+    #[serde(rename = "readOnly", skip_serializing_if = "Option::is_none")]
+    pub read_only: Option<bool>,
+    /// Unique ID of the persistent disk resource in AWS (Amazon EBS volume). More info: https://kubernetes.io/docs/concepts/storage/volumes#awselasticblockstore
+    #[serde(rename = "volumeID")]
+    pub volume_id: String,
+}
+
+impl AwsElasticBlockStoreVolumeSource {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> AwsElasticBlockStoreVolumeSourceBuilder<crate::generics::MissingVolumeId> {
+        AwsElasticBlockStoreVolumeSourceBuilder {
+            body: Default::default(),
+            _volume_id: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> AwsElasticBlockStoreVolumeSourceBuilder<crate::generics::VolumeIdExists>
+    where
+        Self: Clone,
+    {
+        AwsElasticBlockStoreVolumeSourceBuilder {
+            body: self.clone(),
+            _volume_id: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<AwsElasticBlockStoreVolumeSource> for AwsElasticBlockStoreVolumeSourceBuilder<crate::generics::VolumeIdExists> {
+    fn into(self) -> AwsElasticBlockStoreVolumeSource {
+        self.body
+    }
+}
+
+/// Builder for [`AwsElasticBlockStoreVolumeSource`](./struct.AwsElasticBlockStoreVolumeSource.html) object.
+#[derive(Debug, Clone)]
+pub struct AwsElasticBlockStoreVolumeSourceBuilder<VolumeId> {
+    body: self::AwsElasticBlockStoreVolumeSource,
+    _volume_id: core::marker::PhantomData<VolumeId>,
+}
+
+impl<VolumeId> AwsElasticBlockStoreVolumeSourceBuilder<VolumeId> {
+    /// Filesystem type of the volume that you want to mount. Tip: Ensure that the filesystem type is supported by the host operating system. Examples: "ext4", "xfs", "ntfs". Implicitly inferred to be "ext4" if unspecified. More info: https://kubernetes.io/docs/concepts/storage/volumes#awselasticblockstore
+    #[inline]
+    pub fn fs_type(mut self, value: impl Into<String>) -> Self {
+        self.body.fs_type = Some(value.into());
+        self
+    }
+
+    /// Sets [`fs_type`](#method.fs_type) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_fs_type(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.fs_type = value.map(|value| value.into());
+        self
+    }
+
+    /// The partition in the volume that you want to mount. If omitted, the default is to mount by volume name. Examples: For volume /dev/sda1, you specify the partition as "1". Similarly, the volume partition for /dev/sda is "0" (or you can leave the property empty).
+    #[inline]
+    pub fn partition(mut self, value: impl Into<i32>) -> Self {
+        self.body.partition = Some(value.into());
+        self
+    }
+
+    /// Sets [`partition`](#method.partition) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_partition(mut self, value: Option<impl Into<i32>>) -> Self {
+        self.body.partition = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`partition`](#method.partition), but takes a reference.
+    #[inline]
+    pub fn partition_ref(self, value: &i32) -> Self {
+        self.partition(*value)
+    }
+
+    /// Specify "true" to force and set the ReadOnly property in VolumeMounts to "true". If omitted, the default is "false". More info: https://kubernetes.io/docs/concepts/storage/volumes#awselasticblockstore
+    #[inline]
+    pub fn read_only(mut self, value: impl Into<bool>) -> Self {
+        self.body.read_only = Some(value.into());
+        self
+    }
+
+    /// Sets [`read_only`](#method.read_only) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_read_only(mut self, value: Option<impl Into<bool>>) -> Self {
+        self.body.read_only = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`read_only`](#method.read_only), but takes a reference.
+    #[inline]
+    pub fn read_only_ref(self, value: &bool) -> Self {
+        self.read_only(*value)
+    }
+
+    /// Unique ID of the persistent disk resource in AWS (Amazon EBS volume). More info: https://kubernetes.io/docs/concepts/storage/volumes#awselasticblockstore
+    #[inline]
+    pub fn volume_id(mut self, value: impl Into<String>) -> AwsElasticBlockStoreVolumeSourceBuilder<crate::generics::VolumeIdExists> {
+        self.body.volume_id = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+}
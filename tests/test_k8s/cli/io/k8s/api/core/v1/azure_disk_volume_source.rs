@@ -0,0 +1,140 @@
+
+/// AzureDisk represents an Azure Data Disk mount on the host and bind mount to the pod.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct AzureDiskVolumeSource {
+    /// Host Caching mode: None, Read Only, Read Write.
+    #[serde(rename = "cachingMode", skip_serializing_if = "Option::is_none")]
+    pub caching_mode: Option<String>,
+    /// The Name of the data disk in the blob storage
+    #[serde(rename = "diskName")]
+    pub disk_name: String,
+    /// The URI the data disk in the blob storage
+    #[serde(rename = "diskURI")]
+    pub disk_uri: String,
+    /// Filesystem type to mount. Must be a filesystem type supported by the host operating system. Ex. "ext4", "xfs", "ntfs". Implicitly inferred to be "ext4" if unspecified.
+    #[serde(rename = "fsType", skip_serializing_if = "Option::is_none")]
+    pub fs_type: Option<String>,
+    /// Expected values Shared: multiple blob disks per storage account  Dedicated: single blob disk per storage account  Managed: azure managed data disk (only in managed availability set). defaults to shared
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub kind: Option<String>,
+    /// Defaults to false (read/write). ReadOnly here will force the ReadOnly setting in VolumeMounts.
+    #[serde(rename = "readOnly", skip_serializing_if = "Option::is_none")]
+    pub read_only: Option<bool>,
+}
+
+impl AzureDiskVolumeSource {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> AzureDiskVolumeSourceBuilder<crate::generics::MissingDiskName, crate::generics::MissingDiskUri> {
+        AzureDiskVolumeSourceBuilder {
+            body: Default::default(),
+            _disk_name: core::marker::PhantomData,
+            _disk_uri: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> AzureDiskVolumeSourceBuilder<crate::generics::DiskNameExists, crate::generics::DiskUriExists>
+    where
+        Self: Clone,
+    {
+        AzureDiskVolumeSourceBuilder {
+            body: self.clone(),
+            _disk_name: core::marker::PhantomData,
+            _disk_uri: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<AzureDiskVolumeSource> for AzureDiskVolumeSourceBuilder<crate::generics::DiskNameExists, crate::generics::DiskUriExists> {
+    fn into(self) -> AzureDiskVolumeSource {
+        self.body
+    }
+}
+
+/// Builder for [`AzureDiskVolumeSource`](./struct.AzureDiskVolumeSource.html) object.
+#[derive(Debug, Clone)]
+pub struct AzureDiskVolumeSourceBuilder<DiskName, DiskUri> {
+    body: self::AzureDiskVolumeSource,
+    _disk_name: core::marker::PhantomData<DiskName>,
+    _disk_uri: core::marker::PhantomData<DiskUri>,
+}
+
+impl<DiskName, DiskUri> AzureDiskVolumeSourceBuilder<DiskName, DiskUri> {
+    /// Host Caching mode: None, Read Only, Read Write.
+    #[inline]
+    pub fn caching_mode(mut self, value: impl Into<String>) -> Self {
+        self.body.caching_mode = Some(value.into());
+        self
+    }
+
+    /// Sets [`caching_mode`](#method.caching_mode) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_caching_mode(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.caching_mode = value.map(|value| value.into());
+        self
+    }
+
+    /// The Name of the data disk in the blob storage
+    #[inline]
+    pub fn disk_name(mut self, value: impl Into<String>) -> AzureDiskVolumeSourceBuilder<crate::generics::DiskNameExists, DiskUri> {
+        self.body.disk_name = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// The URI the data disk in the blob storage
+    #[inline]
+    pub fn disk_uri(mut self, value: impl Into<String>) -> AzureDiskVolumeSourceBuilder<DiskName, crate::generics::DiskUriExists> {
+        self.body.disk_uri = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Filesystem type to mount. Must be a filesystem type supported by the host operating system. Ex. "ext4", "xfs", "ntfs". Implicitly inferred to be "ext4" if unspecified.
+    #[inline]
+    pub fn fs_type(mut self, value: impl Into<String>) -> Self {
+        self.body.fs_type = Some(value.into());
+        self
+    }
+
+    /// Sets [`fs_type`](#method.fs_type) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_fs_type(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.fs_type = value.map(|value| value.into());
+        self
+    }
+
+    /// Expected values Shared: multiple blob disks per storage account  Dedicated: single blob disk per storage account  Managed: azure managed data disk (only in managed availability set). defaults to shared
+    #[inline]
+    pub fn kind(mut self, value: impl Into<String>) -> Self {
+        self.body.kind = Some(value.into());
+        self
+    }
+
+    /// Sets [`kind`](#method.kind) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_kind(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.kind = value.map(|value| value.into());
+        self
+    }
+
+    /// Defaults to false (read/write). ReadOnly here will force the ReadOnly setting in VolumeMounts.
+    #[inline]
+    pub fn read_only(mut self, value: impl Into<bool>) -> Self {
+        self.body.read_only = Some(value.into());
+        self
+    }
+
+    /// Sets [`read_only`](#method.read_only) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_read_only(mut self, value: Option<impl Into<bool>>) -> Self {
+        self.body.read_only = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`read_only`](#method.read_only), but takes a reference.
+    #[inline]
+    pub fn read_only_ref(self, value: &bool) -> Self {
+        self.read_only(*value)
+    }
+}
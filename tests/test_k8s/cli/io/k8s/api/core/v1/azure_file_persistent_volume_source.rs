@@ -0,0 +1,106 @@
+
+/// AzureFile represents an Azure File Service mount on the host and bind mount to the pod.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct AzureFilePersistentVolumeSource {
+    /// Defaults to false (read/write). ReadOnly here will force the ReadOnly setting in VolumeMounts.
+    #[serde(rename = "readOnly", skip_serializing_if = "Option::is_none")]
+    pub read_only: Option<bool>,
+    /// the name of secret that contains Azure Storage Account Name and Key
+    #[serde(rename = "secretName")]
+    pub secret_name: String,
+    /// the namespace of the secret that contains Azure Storage Account Name and Key default is the same as the Pod
+    #[serde(rename = "secretNamespace", skip_serializing_if = "Option::is_none")]
+    pub secret_namespace: Option<String>,
+    /// Share Name
+    #[serde(rename = "shareName")]
+    pub share_name: String,
+}
+
+impl AzureFilePersistentVolumeSource {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> AzureFilePersistentVolumeSourceBuilder<crate::generics::MissingSecretName, crate::generics::MissingShareName> {
+        AzureFilePersistentVolumeSourceBuilder {
+            body: Default::default(),
+            _secret_name: core::marker::PhantomData,
+            _share_name: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> AzureFilePersistentVolumeSourceBuilder<crate::generics::SecretNameExists, crate::generics::ShareNameExists>
+    where
+        Self: Clone,
+    {
+        AzureFilePersistentVolumeSourceBuilder {
+            body: self.clone(),
+            _secret_name: core::marker::PhantomData,
+            _share_name: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<AzureFilePersistentVolumeSource> for AzureFilePersistentVolumeSourceBuilder<crate::generics::SecretNameExists, crate::generics::ShareNameExists> {
+    fn into(self) -> AzureFilePersistentVolumeSource {
+        self.body
+    }
+}
+
+/// Builder for [`AzureFilePersistentVolumeSource`](./struct.AzureFilePersistentVolumeSource.html) object.
+#[derive(Debug, Clone)]
+pub struct AzureFilePersistentVolumeSourceBuilder<SecretName, ShareName> {
+    body: self::AzureFilePersistentVolumeSource,
+    _secret_name: core::marker::PhantomData<SecretName>,
+    _share_name: core::marker::PhantomData<ShareName>,
+}
+
+impl<SecretName, ShareName> AzureFilePersistentVolumeSourceBuilder<SecretName, ShareName> {
+    /// Defaults to false (read/write). ReadOnly here will force the ReadOnly setting in VolumeMounts.
+    #[inline]
+    pub fn read_only(mut self, value: impl Into<bool>) -> Self {
+        self.body.read_only = Some(value.into());
+        self
+    }
+
+    /// Sets [`read_only`](#method.read_only) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_read_only(mut self, value: Option<impl Into<bool>>) -> Self {
+        self.body.read_only = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`read_only`](#method.read_only), but takes a reference.
+    #[inline]
+    pub fn read_only_ref(self, value: &bool) -> Self {
+        self.read_only(*value)
+    }
+
+    /// the name of secret that contains Azure Storage Account Name and Key
+    #[inline]
+    pub fn secret_name(mut self, value: impl Into<String>) -> AzureFilePersistentVolumeSourceBuilder<crate::generics::SecretNameExists, ShareName> {
+        self.body.secret_name = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// the namespace of the secret that contains Azure Storage Account Name and Key default is the same as the Pod
+    #[inline]
+    pub fn secret_namespace(mut self, value: impl Into<String>) -> Self {
+        self.body.secret_namespace = Some(value.into());
+        self
+    }
+
+    /// Sets [`secret_namespace`](#method.secret_namespace) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_secret_namespace(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.secret_namespace = value.map(|value| value.into());
+        self
+    }
+
+    /// Share Name
+    #[inline]
+    pub fn share_name(mut self, value: impl Into<String>) -> AzureFilePersistentVolumeSourceBuilder<SecretName, crate::generics::ShareNameExists> {
+        self.body.share_name = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+}
@@ -0,0 +1,88 @@
+
+/// Adds and removes POSIX capabilities from running containers.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct Capabilities {
+    /// Added capabilities
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub add: Option<Vec<String>>,
+    /// Removed capabilities
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub drop: Option<Vec<String>>,
+}
+
+impl Capabilities {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> CapabilitiesBuilder {
+        CapabilitiesBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> CapabilitiesBuilder
+    where
+        Self: Clone,
+    {
+        CapabilitiesBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<Capabilities> for CapabilitiesBuilder {
+    fn into(self) -> Capabilities {
+        self.body
+    }
+}
+
+/// Builder for [`Capabilities`](./struct.Capabilities.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct CapabilitiesBuilder {
+    body: self::Capabilities,
+}
+
+impl CapabilitiesBuilder {
+    /// Added capabilities
+    #[inline]
+    pub fn add(mut self, value: impl Iterator<Item = impl Into<String>>) -> Self {
+        self.body.add = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`add`](#method.add) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_add(mut self, value: Option<impl Iterator<Item = impl Into<String>>>) -> Self {
+        self.body.add = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`add`](#method.add), but takes the values as a `Vec`.
+    #[inline]
+    pub fn add_vec(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.body.add = Some(value.into());
+        self
+    }
+
+    /// Removed capabilities
+    #[inline]
+    pub fn drop(mut self, value: impl Iterator<Item = impl Into<String>>) -> Self {
+        self.body.drop = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`drop`](#method.drop) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_drop(mut self, value: Option<impl Iterator<Item = impl Into<String>>>) -> Self {
+        self.body.drop = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`drop`](#method.drop), but takes the values as a `Vec`.
+    #[inline]
+    pub fn drop_vec(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.body.drop = Some(value.into());
+        self
+    }
+}
@@ -0,0 +1,150 @@
+
+/// Represents a Ceph Filesystem mount that lasts the lifetime of a pod Cephfs volumes do not support ownership management or SELinux relabeling.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct CephFsVolumeSource {
+    /// Required: Monitors is a collection of Ceph monitors More info: https://releases.k8s.io/HEAD/examples/volumes/cephfs/README.md#how-to-use-it
+    pub monitors: Vec<String>,
+    /// Optional: Used as the mounted root, rather than the full Ceph tree, default is /
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+    /// Optional: Defaults to false (read/write). ReadOnly here will force the ReadOnly setting in VolumeMounts. More info: https://releases.k8s.io/HEAD/examples/volumes/cephfs/README.md#how-to-use-it
+    #[serde(rename = "readOnly", skip_serializing_if = "Option::is_none")]
+    pub read_only: Option<bool>,
+    /// Optional: SecretFile is the path to key ring for User, default is /etc/ceph/user.secret More info: https://releases.k8s.io/HEAD/examples/volumes/cephfs/README.md#how-to-use-it
+    #[serde(rename = "secretFile", skip_serializing_if = "Option::is_none")]
+    pub secret_file: Option<String>,
+    /// Optional: SecretRef is reference to the authentication secret for User, default is empty. More info: https://releases.k8s.io/HEAD/examples/volumes/cephfs/README.md#how-to-use-it
+    #[serde(rename = "secretRef", skip_serializing_if = "Option::is_none")]
+    pub secret_ref: Option<crate::io::k8s::api::core::v1::local_object_reference::LocalObjectReference>,
+    /// Optional: User is the rados user name, default is admin More info: https://releases.k8s.io/HEAD/examples/volumes/cephfs/README.md#how-to-use-it
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<String>,
+}
+
+impl CephFsVolumeSource {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> CephFsVolumeSourceBuilder<crate::generics::MissingMonitors> {
+        CephFsVolumeSourceBuilder {
+            body: Default::default(),
+            _monitors: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> CephFsVolumeSourceBuilder<crate::generics::MonitorsExists>
+    where
+        Self: Clone,
+    {
+        CephFsVolumeSourceBuilder {
+            body: self.clone(),
+            _monitors: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<CephFsVolumeSource> for CephFsVolumeSourceBuilder<crate::generics::MonitorsExists> {
+    fn into(self) -> CephFsVolumeSource {
+        self.body
+    }
+}
+
+/// Builder for [`CephFsVolumeSource`](./struct.CephFsVolumeSource.html) object.
+#[derive(Debug, Clone)]
+pub struct CephFsVolumeSourceBuilder<Monitors> {
+    body: self::CephFsVolumeSource,
+    _monitors: core::marker::PhantomData<Monitors>,
+}
+
+impl<Monitors> CephFsVolumeSourceBuilder<Monitors> {
+    /// Required: Monitors is a collection of Ceph monitors More info: https://releases.k8s.io/HEAD/examples/volumes/cephfs/README.md#how-to-use-it
+    #[inline]
+    pub fn monitors(mut self, value: impl Iterator<Item = impl Into<String>>) -> CephFsVolumeSourceBuilder<crate::generics::MonitorsExists> {
+        self.body.monitors = value.map(|value| value.into()).collect::<Vec<_>>().into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Same as [`monitors`](#method.monitors), but takes the values as a `Vec`.
+    #[inline]
+    pub fn monitors_vec(mut self, value: impl Into<Vec<String>>) -> CephFsVolumeSourceBuilder<crate::generics::MonitorsExists> {
+        self.body.monitors = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Optional: Used as the mounted root, rather than the full Ceph tree, default is /
+    #[inline]
+    pub fn path(mut self, value: impl Into<String>) -> Self {
+        self.body.path = Some(value.into());
+        self
+    }
+
+    /// Sets [`path`](#method.path) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_path(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.path = value.map(|value| value.into());
+        self
+    }
+
+    /// Optional: Defaults to false (read/write). ReadOnly here will force the ReadOnly setting in VolumeMounts. More info: https://releases.k8s.io/HEAD/examples/volumes/cephfs/README.md#how-to-use-it
+    #[inline]
+    pub fn read_only(mut self, value: impl Into<bool>) -> Self {
+        self.body.read_only = Some(value.into());
+        self
+    }
+
+    /// Sets [`read_only`](#method.read_only) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_read_only(mut self, value: Option<impl Into<bool>>) -> Self {
+        self.body.read_only = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`read_only`](#method.read_only), but takes a reference.
+    #[inline]
+    pub fn read_only_ref(self, value: &bool) -> Self {
+        self.read_only(*value)
+    }
+
+    /// Optional: SecretFile is the path to key ring for User, default is /etc/ceph/user.secret More info: https://releases.k8s.io/HEAD/examples/volumes/cephfs/README.md#how-to-use-it
+    #[inline]
+    pub fn secret_file(mut self, value: impl Into<String>) -> Self {
+        self.body.secret_file = Some(value.into());
+        self
+    }
+
+    /// Sets [`secret_file`](#method.secret_file) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_secret_file(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.secret_file = value.map(|value| value.into());
+        self
+    }
+
+    /// Optional: SecretRef is reference to the authentication secret for User, default is empty. More info: https://releases.k8s.io/HEAD/examples/volumes/cephfs/README.md#how-to-use-it
+    #[inline]
+    pub fn secret_ref(mut self, value: crate::io::k8s::api::core::v1::local_object_reference::LocalObjectReference) -> Self {
+        self.body.secret_ref = Some(value.into());
+        self
+    }
+
+    /// Sets [`secret_ref`](#method.secret_ref) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_secret_ref(mut self, value: Option<crate::io::k8s::api::core::v1::local_object_reference::LocalObjectReference>) -> Self {
+        self.body.secret_ref = value.map(|value| value.into());
+        self
+    }
+
+    /// Optional: User is the rados user name, default is admin More info: https://releases.k8s.io/HEAD/examples/volumes/cephfs/README.md#how-to-use-it
+    #[inline]
+    pub fn user(mut self, value: impl Into<String>) -> Self {
+        self.body.user = Some(value.into());
+        self
+    }
+
+    /// Sets [`user`](#method.user) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_user(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.user = value.map(|value| value.into());
+        self
+    }
+}
@@ -0,0 +1,110 @@
+
+/// Represents a cinder volume resource in Openstack. A Cinder volume must exist before mounting to a container. The volume must also be in the same region as the kubelet. Cinder volumes support ownership management and SELinux relabeling.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct CinderVolumeSource {
+    /// Filesystem type to mount. Must be a filesystem type supported by the host operating system. Examples: "ext4", "xfs", "ntfs". Implicitly inferred to be "ext4" if unspecified. More info: https://releases.k8s.io/HEAD/examples/mysql-cinder-pd/README.md
+    #[serde(rename = "fsType", skip_serializing_if = "Option::is_none")]
+    pub fs_type: Option<String>,
+    /// Optional: Defaults to false (read/write). ReadOnly here will force the ReadOnly setting in VolumeMounts. More info: https://releases.k8s.io/HEAD/examples/mysql-cinder-pd/README.md
+    #[serde(rename = "readOnly", skip_serializing_if = "Option::is_none")]
+    pub read_only: Option<bool>,
+    /// Optional: points to a secret object containing parameters used to connect to OpenStack.
+    #[serde(rename = "secretRef", skip_serializing_if = "Option::is_none")]
+    pub secret_ref: Option<crate::io::k8s::api::core::v1::local_object_reference::LocalObjectReference>,
+    /// volume id used to identify the volume in cinder More info: https://releases.k8s.io/HEAD/examples/mysql-cinder-pd/README.md
+    #[serde(rename = "volumeID")]
+    pub volume_id: String,
+}
+
+impl CinderVolumeSource {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> CinderVolumeSourceBuilder<crate::generics::MissingVolumeId> {
+        CinderVolumeSourceBuilder {
+            body: Default::default(),
+            _volume_id: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> CinderVolumeSourceBuilder<crate::generics::VolumeIdExists>
+    where
+        Self: Clone,
+    {
+        CinderVolumeSourceBuilder {
+            body: self.clone(),
+            _volume_id: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<CinderVolumeSource> for CinderVolumeSourceBuilder<crate::generics::VolumeIdExists> {
+    fn into(self) -> CinderVolumeSource {
+        self.body
+    }
+}
+
+/// Builder for [`CinderVolumeSource`](./struct.CinderVolumeSource.html) object.
+#[derive(Debug, Clone)]
+pub struct CinderVolumeSourceBuilder<VolumeId> {
+    body: self::CinderVolumeSource,
+    _volume_id: core::marker::PhantomData<VolumeId>,
+}
+
+impl<VolumeId> CinderVolumeSourceBuilder<VolumeId> {
+    /// Filesystem type to mount. Must be a filesystem type supported by the host operating system. Examples: "ext4", "xfs", "ntfs". Implicitly inferred to be "ext4" if unspecified. More info: https://releases.k8s.io/HEAD/examples/mysql-cinder-pd/README.md
+    #[inline]
+    pub fn fs_type(mut self, value: impl Into<String>) -> Self {
+        self.body.fs_type = Some(value.into());
+        self
+    }
+
+    /// Sets [`fs_type`](#method.fs_type) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_fs_type(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.fs_type = value.map(|value| value.into());
+        self
+    }
+
+    /// Optional: Defaults to false (read/write). ReadOnly here will force the ReadOnly setting in VolumeMounts. More info: https://releases.k8s.io/HEAD/examples/mysql-cinder-pd/README.md
+    #[inline]
+    pub fn read_only(mut self, value: impl Into<bool>) -> Self {
+        self.body.read_only = Some(value.into());
+        self
+    }
+
+    /// Sets [`read_only`](#method.read_only) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_read_only(mut self, value: Option<impl Into<bool>>) -> Self {
+        self.body.read_only = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`read_only`](#method.read_only), but takes a reference.
+    #[inline]
+    pub fn read_only_ref(self, value: &bool) -> Self {
+        self.read_only(*value)
+    }
+
+    /// Optional: points to a secret object containing parameters used to connect to OpenStack.
+    #[inline]
+    pub fn secret_ref(mut self, value: crate::io::k8s::api::core::v1::local_object_reference::LocalObjectReference) -> Self {
+        self.body.secret_ref = Some(value.into());
+        self
+    }
+
+    /// Sets [`secret_ref`](#method.secret_ref) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_secret_ref(mut self, value: Option<crate::io::k8s::api::core::v1::local_object_reference::LocalObjectReference>) -> Self {
+        self.body.secret_ref = value.map(|value| value.into());
+        self
+    }
+
+    /// volume id used to identify the volume in cinder More info: https://releases.k8s.io/HEAD/examples/mysql-cinder-pd/README.md
+    #[inline]
+    pub fn volume_id(mut self, value: impl Into<String>) -> CinderVolumeSourceBuilder<crate::generics::VolumeIdExists> {
+        self.body.volume_id = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+}
@@ -0,0 +1,99 @@
+
+/// Information about the condition of a component.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct ComponentCondition {
+    /// Condition error code for a component. For example, a health check error code.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// Message about the condition for a component. For example, information about a health check.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    /// Status of the condition for a component. Valid values for "Healthy": "True", "False", or "Unknown".
+    pub status: String,
+    /// Type of condition for a component. Valid value: "Healthy"
+    #[serde(rename = "type")]
+    pub type_: String,
+}
+
+impl ComponentCondition {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> ComponentConditionBuilder<crate::generics::MissingStatus, crate::generics::MissingType> {
+        ComponentConditionBuilder {
+            body: Default::default(),
+            _status: core::marker::PhantomData,
+            _type: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> ComponentConditionBuilder<crate::generics::StatusExists, crate::generics::TypeExists>
+    where
+        Self: Clone,
+    {
+        ComponentConditionBuilder {
+            body: self.clone(),
+            _status: core::marker::PhantomData,
+            _type: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<ComponentCondition> for ComponentConditionBuilder<crate::generics::StatusExists, crate::generics::TypeExists> {
+    fn into(self) -> ComponentCondition {
+        self.body
+    }
+}
+
+/// Builder for [`ComponentCondition`](./struct.ComponentCondition.html) object.
+#[derive(Debug, Clone)]
+pub struct ComponentConditionBuilder<Status, Type> {
+    body: self::ComponentCondition,
+    _status: core::marker::PhantomData<Status>,
+    _type: core::marker::PhantomData<Type>,
+}
+
+impl<Status, Type> ComponentConditionBuilder<Status, Type> {
+    /// Condition error code for a component. For example, a health check error code.
+    #[inline]
+    pub fn error(mut self, value: impl Into<String>) -> Self {
+        self.body.error = Some(value.into());
+        self
+    }
+
+    /// Sets [`error`](#method.error) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_error(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.error = value.map(|value| value.into());
+        self
+    }
+
+    /// Message about the condition for a component. For example, information about a health check.
+    #[inline]
+    pub fn message(mut self, value: impl Into<String>) -> Self {
+        self.body.message = Some(value.into());
+        self
+    }
+
+    /// Sets [`message`](#method.message) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_message(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.message = value.map(|value| value.into());
+        self
+    }
+
+    /// Status of the condition for a component. Valid values for "Healthy": "True", "False", or "Unknown".
+    #[inline]
+    pub fn status(mut self, value: impl Into<String>) -> ComponentConditionBuilder<crate::generics::StatusExists, Type> {
+        self.body.status = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Type of condition for a component. Valid value: "Healthy"
+    #[inline]
+    pub fn type_(mut self, value: impl Into<String>) -> ComponentConditionBuilder<Status, crate::generics::TypeExists> {
+        self.body.type_ = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+}
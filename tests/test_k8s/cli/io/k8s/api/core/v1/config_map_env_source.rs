@@ -0,0 +1,82 @@
+
+/// ConfigMapEnvSource selects a ConfigMap to populate the environment variables with.
+///
+/// The contents of the target ConfigMap's Data field will represent the key-value pairs as environment variables.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct ConfigMapEnvSource {
+    /// Name of the referent. More info: https://kubernetes.io/docs/concepts/overview/working-with-objects/names/#names
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Specify whether the ConfigMap must be defined
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub optional: Option<bool>,
+}
+
+impl ConfigMapEnvSource {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> ConfigMapEnvSourceBuilder {
+        ConfigMapEnvSourceBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> ConfigMapEnvSourceBuilder
+    where
+        Self: Clone,
+    {
+        ConfigMapEnvSourceBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<ConfigMapEnvSource> for ConfigMapEnvSourceBuilder {
+    fn into(self) -> ConfigMapEnvSource {
+        self.body
+    }
+}
+
+/// Builder for [`ConfigMapEnvSource`](./struct.ConfigMapEnvSource.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct ConfigMapEnvSourceBuilder {
+    body: self::ConfigMapEnvSource,
+}
+
+impl ConfigMapEnvSourceBuilder {
+    /// Name of the referent. More info: https://kubernetes.io/docs/concepts/overview/working-with-objects/names/#names
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> Self {
+        self.body.name = Some(value.into());
+        self
+    }
+
+    /// Sets [`name`](#method.name) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_name(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.name = value.map(|value| value.into());
+        self
+    }
+
+    /// Specify whether the ConfigMap must be defined
+    #[inline]
+    pub fn optional(mut self, value: impl Into<bool>) -> Self {
+        self.body.optional = Some(value.into());
+        self
+    }
+
+    /// Sets [`optional`](#method.optional) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_optional(mut self, value: Option<impl Into<bool>>) -> Self {
+        self.body.optional = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`optional`](#method.optional), but takes a reference.
+    #[inline]
+    pub fn optional_ref(self, value: &bool) -> Self {
+        self.optional(*value)
+    }
+}
@@ -0,0 +1,92 @@
+
+/// Selects a key from a ConfigMap.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct ConfigMapKeySelector {
+    /// The key to select.
+    pub key: String,
+    /// Name of the referent. More info: https://kubernetes.io/docs/concepts/overview/working-with-objects/names/#names
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Specify whether the ConfigMap or its key must be defined
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub optional: Option<bool>,
+}
+
+impl ConfigMapKeySelector {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> ConfigMapKeySelectorBuilder<crate::generics::MissingKey> {
+        ConfigMapKeySelectorBuilder {
+            body: Default::default(),
+            _key: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> ConfigMapKeySelectorBuilder<crate::generics::KeyExists>
+    where
+        Self: Clone,
+    {
+        ConfigMapKeySelectorBuilder {
+            body: self.clone(),
+            _key: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<ConfigMapKeySelector> for ConfigMapKeySelectorBuilder<crate::generics::KeyExists> {
+    fn into(self) -> ConfigMapKeySelector {
+        self.body
+    }
+}
+
+/// Builder for [`ConfigMapKeySelector`](./struct.ConfigMapKeySelector.html) object.
+#[derive(Debug, Clone)]
+pub struct ConfigMapKeySelectorBuilder<Key> {
+    body: self::ConfigMapKeySelector,
+    _key: core::marker::PhantomData<Key>,
+}
+
+impl<Key> ConfigMapKeySelectorBuilder<Key> {
+    /// The key to select.
+    #[inline]
+    pub fn key(mut self, value: impl Into<String>) -> ConfigMapKeySelectorBuilder<crate::generics::KeyExists> {
+        self.body.key = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Name of the referent. More info: https://kubernetes.io/docs/concepts/overview/working-with-objects/names/#names
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> Self {
+        self.body.name = Some(value.into());
+        self
+    }
+
+    /// Sets [`name`](#method.name) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_name(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.name = value.map(|value| value.into());
+        self
+    }
+
+    /// Specify whether the ConfigMap or its key must be defined
+    #[inline]
+    pub fn optional(mut self, value: impl Into<bool>) -> Self {
+        self.body.optional = Some(value.into());
+        self
+    }
+
+    /// Sets [`optional`](#method.optional) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_optional(mut self, value: Option<impl Into<bool>>) -> Self {
+        self.body.optional = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`optional`](#method.optional), but takes a reference.
+    #[inline]
+    pub fn optional_ref(self, value: &bool) -> Self {
+        self.optional(*value)
+    }
+}
@@ -0,0 +1,111 @@
+
+/// ConfigMapNodeConfigSource contains the information to reference a ConfigMap as a config source for the Node.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct ConfigMapNodeConfigSource {
+    /// KubeletConfigKey declares which key of the referenced ConfigMap corresponds to the KubeletConfiguration structure This field is required in all cases.
+    #[serde(rename = "kubeletConfigKey")]
+    pub kubelet_config_key: String,
+    /// Name is the metadata.name of the referenced ConfigMap. This field is required in all cases.
+    pub name: String,
+    /// Namespace is the metadata.namespace of the referenced ConfigMap. This field is required in all cases.
+    pub namespace: String,
+    /// ResourceVersion is the metadata.ResourceVersion of the referenced ConfigMap. This field is forbidden in Node.Spec, and required in Node.Status.
+    #[serde(rename = "resourceVersion", skip_serializing_if = "Option::is_none")]
+    pub resource_version: Option<String>,
+    /// UID is the metadata.UID of the referenced ConfigMap. This field is forbidden in Node.Spec, and required in Node.Status.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub uid: Option<String>,
+}
+
+impl ConfigMapNodeConfigSource {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> ConfigMapNodeConfigSourceBuilder<crate::generics::MissingKubeletConfigKey, crate::generics::MissingName, crate::generics::MissingNamespace> {
+        ConfigMapNodeConfigSourceBuilder {
+            body: Default::default(),
+            _kubelet_config_key: core::marker::PhantomData,
+            _name: core::marker::PhantomData,
+            _namespace: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> ConfigMapNodeConfigSourceBuilder<crate::generics::KubeletConfigKeyExists, crate::generics::NameExists, crate::generics::NamespaceExists>
+    where
+        Self: Clone,
+    {
+        ConfigMapNodeConfigSourceBuilder {
+            body: self.clone(),
+            _kubelet_config_key: core::marker::PhantomData,
+            _name: core::marker::PhantomData,
+            _namespace: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<ConfigMapNodeConfigSource> for ConfigMapNodeConfigSourceBuilder<crate::generics::KubeletConfigKeyExists, crate::generics::NameExists, crate::generics::NamespaceExists> {
+    fn into(self) -> ConfigMapNodeConfigSource {
+        self.body
+    }
+}
+
+/// Builder for [`ConfigMapNodeConfigSource`](./struct.ConfigMapNodeConfigSource.html) object.
+#[derive(Debug, Clone)]
+pub struct ConfigMapNodeConfigSourceBuilder<KubeletConfigKey, Name, Namespace> {
+    body: self::ConfigMapNodeConfigSource,
+    _kubelet_config_key: core::marker::PhantomData<KubeletConfigKey>,
+    _name: core::marker::PhantomData<Name>,
+    _namespace: core::marker::PhantomData<Namespace>,
+}
+
+impl<KubeletConfigKey, Name, Namespace> ConfigMapNodeConfigSourceBuilder<KubeletConfigKey, Name, Namespace> {
+    /// KubeletConfigKey declares which key of the referenced ConfigMap corresponds to the KubeletConfiguration structure This field is required in all cases.
+    #[inline]
+    pub fn kubelet_config_key(mut self, value: impl Into<String>) -> ConfigMapNodeConfigSourceBuilder<crate::generics::KubeletConfigKeyExists, Name, Namespace> {
+        self.body.kubelet_config_key = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Name is the metadata.name of the referenced ConfigMap. This field is required in all cases.
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> ConfigMapNodeConfigSourceBuilder<KubeletConfigKey, crate::generics::NameExists, Namespace> {
+        self.body.name = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Namespace is the metadata.namespace of the referenced ConfigMap. This field is required in all cases.
+    #[inline]
+    pub fn namespace(mut self, value: impl Into<String>) -> ConfigMapNodeConfigSourceBuilder<KubeletConfigKey, Name, crate::generics::NamespaceExists> {
+        self.body.namespace = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// ResourceVersion is the metadata.ResourceVersion of the referenced ConfigMap. This field is forbidden in Node.Spec, and required in Node.Status.
+    #[inline]
+    pub fn resource_version(mut self, value: impl Into<String>) -> Self {
+        self.body.resource_version = Some(value.into());
+        self
+    }
+
+    /// Sets [`resource_version`](#method.resource_version) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_resource_version(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.resource_version = value.map(|value| value.into());
+        self
+    }
+
+    /// UID is the metadata.UID of the referenced ConfigMap. This field is forbidden in Node.Spec, and required in Node.Status.
+    #[inline]
+    pub fn uid(mut self, value: impl Into<String>) -> Self {
+        self.body.uid = Some(value.into());
+        self
+    }
+
+    /// Sets [`uid`](#method.uid) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_uid(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.uid = value.map(|value| value.into());
+        self
+    }
+}
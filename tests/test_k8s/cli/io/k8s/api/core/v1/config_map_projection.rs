@@ -0,0 +1,106 @@
+
+/// Adapts a ConfigMap into a projected volume.
+///
+/// The contents of the target ConfigMap's Data field will be presented in a projected volume as files using the keys in the Data field as the file names, unless the items element is populated with specific mappings of keys to paths. Note that this is identical to a configmap volume source without the default mode.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct ConfigMapProjection {
+    /// If unspecified, each key-value pair in the Data field of the referenced ConfigMap will be projected into the volume as a file whose name is the key and content is the value. If specified, the listed keys will be projected into the specified paths, and unlisted keys will not be present. If a key is specified which is not present in the ConfigMap, the volume setup will error unless it is marked optional. Paths must be relative and may not contain the '..' path or start with '..'.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub items: Option<Vec<crate::io::k8s::api::core::v1::key_to_path::KeyToPath>>,
+    /// Name of the referent. More info: https://kubernetes.io/docs/concepts/overview/working-with-objects/names/#names
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Specify whether the ConfigMap or its keys must be defined
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub optional: Option<bool>,
+}
+
+impl ConfigMapProjection {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> ConfigMapProjectionBuilder {
+        ConfigMapProjectionBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> ConfigMapProjectionBuilder
+    where
+        Self: Clone,
+    {
+        ConfigMapProjectionBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<ConfigMapProjection> for ConfigMapProjectionBuilder {
+    fn into(self) -> ConfigMapProjection {
+        self.body
+    }
+}
+
+/// Builder for [`ConfigMapProjection`](./struct.ConfigMapProjection.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct ConfigMapProjectionBuilder {
+    body: self::ConfigMapProjection,
+}
+
+impl ConfigMapProjectionBuilder {
+    /// If unspecified, each key-value pair in the Data field of the referenced ConfigMap will be projected into the volume as a file whose name is the key and content is the value. If specified, the listed keys will be projected into the specified paths, and unlisted keys will not be present. If a key is specified which is not present in the ConfigMap, the volume setup will error unless it is marked optional. Paths must be relative and may not contain the '..' path or start with '..'.
+    #[inline]
+    pub fn items(mut self, value: impl Iterator<Item = crate::io::k8s::api::core::v1::key_to_path::KeyToPathBuilder<crate::generics::KeyExists, crate::generics::PathExists>>) -> Self {
+        self.body.items = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`items`](#method.items) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_items(mut self, value: Option<impl Iterator<Item = crate::io::k8s::api::core::v1::key_to_path::KeyToPathBuilder<crate::generics::KeyExists, crate::generics::PathExists>>>) -> Self {
+        self.body.items = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`items`](#method.items), but takes the values as a `Vec`.
+    #[inline]
+    pub fn items_vec(mut self, value: impl Into<Vec<crate::io::k8s::api::core::v1::key_to_path::KeyToPath>>) -> Self {
+        self.body.items = Some(value.into());
+        self
+    }
+
+    /// Name of the referent. More info: https://kubernetes.io/docs/concepts/overview/working-with-objects/names/#names
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> Self {
+        self.body.name = Some(value.into());
+        self
+    }
+
+    /// Sets [`name`](#method.name) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_name(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.name = value.map(|value| value.into());
+        self
+    }
+
+    /// Specify whether the ConfigMap or its keys must be defined
+    #[inline]
+    pub fn optional(mut self, value: impl Into<bool>) -> Self {
+        self.body.optional = Some(value.into());
+        self
+    }
+
+    /// Sets [`optional`](#method.optional) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_optional(mut self, value: Option<impl Into<bool>>) -> Self {
+        self.body.optional = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`optional`](#method.optional), but takes a reference.
+    #[inline]
+    pub fn optional_ref(self, value: &bool) -> Self {
+        self.optional(*value)
+    }
+}
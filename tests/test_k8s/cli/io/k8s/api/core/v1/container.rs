@@ -0,0 +1,459 @@
+
+/// A single application container that you want to run within a pod.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct Container {
+    /// Arguments to the entrypoint. The docker image's CMD is used if this is not provided. Variable references $(VAR_NAME) are expanded using the container's environment. If a variable cannot be resolved, the reference in the input string will be unchanged. The $(VAR_NAME) syntax can be escaped with a double $$, ie: $$(VAR_NAME). Escaped references will never be expanded, regardless of whether the variable exists or not. Cannot be updated. More info: https://kubernetes.io/docs/tasks/inject-data-application/define-command-argument-container/#running-a-command-in-a-shell
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub args: Option<Vec<String>>,
+    /// Entrypoint array. Not executed within a shell. The docker image's ENTRYPOINT is used if this is not provided. Variable references $(VAR_NAME) are expanded using the container's environment. If a variable cannot be resolved, the reference in the input string will be unchanged. The $(VAR_NAME) syntax can be escaped with a double $$, ie: $$(VAR_NAME). Escaped references will never be expanded, regardless of whether the variable exists or not. Cannot be updated. More info: https://kubernetes.io/docs/tasks/inject-data-application/define-command-argument-container/#running-a-command-in-a-shell
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub command: Option<Vec<String>>,
+    /// List of environment variables to set in the container. Cannot be updated.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub env: Option<Vec<crate::io::k8s::api::core::v1::env_var::EnvVar>>,
+    /// List of sources to populate environment variables in the container. The keys defined within a source must be a C_IDENTIFIER. All invalid keys will be reported as an event when the container is starting. When a key exists in multiple sources, the value associated with the last source will take precedence. Values defined by an Env with a duplicate key will take precedence. Cannot be updated.
+    #[serde(rename = "envFrom", skip_serializing_if = "Option::is_none")]
+    pub env_from: Option<Vec<crate::io::k8s::api::core::v1::env_from_source::EnvFromSource>>,
+    /// Docker image name. More info: https://kubernetes.io/docs/concepts/containers/images This field is optional to allow higher level config management to default or override container images in workload controllers like Deployments and StatefulSets.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image: Option<String>,
+    /// Image pull policy. One of Always, Never, IfNotPresent. Defaults to Always if :latest tag is specified, or IfNotPresent otherwise. Cannot be updated. More info: https://kubernetes.io/docs/concepts/containers/images#updating-images
+    #[serde(rename = "imagePullPolicy", skip_serializing_if = "Option::is_none")]
+    pub image_pull_policy: Option<String>,
+    /// Actions that the management system should take in response to container lifecycle events. Cannot be updated.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lifecycle: Option<crate::io::k8s::api::core::v1::lifecycle::Lifecycle>,
+    /// Periodic probe of container liveness. Container will be restarted if the probe fails. Cannot be updated. More info: https://kubernetes.io/docs/concepts/workloads/pods/pod-lifecycle#container-probes
+    #[serde(rename = "livenessProbe", skip_serializing_if = "Option::is_none")]
+    pub liveness_probe: Option<crate::io::k8s::api::core::v1::probe::Probe>,
+    /// Name of the container specified as a DNS_LABEL. Each container in a pod must have a unique name (DNS_LABEL). Cannot be updated.
+    pub name: String,
+    /// List of ports to expose from the container. Exposing a port here gives the system additional information about the network connections a container uses, but is primarily informational. Not specifying a port here DOES NOT prevent that port from being exposed. Any port which is listening on the default "0.0.0.0" address inside a container will be accessible from the network. Cannot be updated.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ports: Option<Vec<crate::io::k8s::api::core::v1::container_port::ContainerPort>>,
+    /// Periodic probe of container service readiness. Container will be removed from service endpoints if the probe fails. Cannot be updated. More info: https://kubernetes.io/docs/concepts/workloads/pods/pod-lifecycle#container-probes
+    #[serde(rename = "readinessProbe", skip_serializing_if = "Option::is_none")]
+    pub readiness_probe: Option<crate::io::k8s::api::core::v1::probe::Probe>,
+    /// Compute Resources required by this container. Cannot be updated. More info: https://kubernetes.io/docs/concepts/configuration/manage-compute-resources-container/
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resources: Option<crate::io::k8s::api::core::v1::resource_requirements::ResourceRequirements>,
+    /// Security options the pod should run with. More info: https://kubernetes.io/docs/concepts/policy/security-context/ More info: https://kubernetes.io/docs/tasks/configure-pod-container/security-context/
+    #[serde(rename = "securityContext", skip_serializing_if = "Option::is_none")]
+    pub security_context: Option<crate::io::k8s::api::core::v1::security_context::SecurityContext>,
+    /// Whether this container should allocate a buffer for stdin in the container runtime. If this is not set, reads from stdin in the container will always result in EOF. Default is false.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stdin: Option<bool>,
+    /// Whether the container runtime should close the stdin channel after it has been opened by a single attach. When stdin is true the stdin stream will remain open across multiple attach sessions. If stdinOnce is set to true, stdin is opened on container start, is empty until the first client attaches to stdin, and then remains open and accepts data until the client disconnects, at which time stdin is closed and remains closed until the container is restarted. If this flag is false, a container processes that reads from stdin will never receive an EOF. Default is false
+    #[serde(rename = "stdinOnce", skip_serializing_if = "Option::is_none")]
+    pub stdin_once: Option<bool>,
+    /// Optional: Path at which the file to which the container's termination message will be written is mounted into the container's filesystem. Message written is intended to be brief final status, such as an assertion failure message. Will be truncated by the node if greater than 4096 bytes. The total message length across all containers will be limited to 12kb. Defaults to /dev/termination-log. Cannot be updated.
+    #[serde(rename = "terminationMessagePath", skip_serializing_if = "Option::is_none")]
+    pub termination_message_path: Option<String>,
+    /// Indicate how the termination message should be populated. File will use the contents of terminationMessagePath to populate the container status message on both success and failure. FallbackToLogsOnError will use the last chunk of container log output if the termination message file is empty and the container exited with an error. The log output is limited to 2048 bytes or 80 lines, whichever is smaller. Defaults to File. Cannot be updated.
+    #[serde(rename = "terminationMessagePolicy", skip_serializing_if = "Option::is_none")]
+    pub termination_message_policy: Option<String>,
+    /// Whether this container should allocate a TTY for itself, also requires 'stdin' to be true. Default is false.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tty: Option<bool>,
+    /// volumeDevices is the list of block devices to be used by the container. This is a beta feature.
+    #[serde(rename = "volumeDevices", skip_serializing_if = "Option::is_none")]
+    pub volume_devices: Option<Vec<crate::io::k8s::api::core::v1::volume_device::VolumeDevice>>,
+    /// Pod volumes to mount into the container's filesystem. Cannot be updated.
+    #[serde(rename = "volumeMounts", skip_serializing_if = "Option::is_none")]
+    pub volume_mounts: Option<Vec<crate::io::k8s::api::core::v1::volume_mount::VolumeMount>>,
+    /// Container's working directory. If not specified, the container runtime's default will be used, which might be configured in the container image. Cannot be updated.
+    #[serde(rename = "workingDir", skip_serializing_if = "Option::is_none")]
+    pub working_dir: Option<String>,
+}
+
+impl Container {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> ContainerBuilder<crate::generics::MissingName> {
+        ContainerBuilder {
+            body: Default::default(),
+            _name: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> ContainerBuilder<crate::generics::NameExists>
+    where
+        Self: Clone,
+    {
+        ContainerBuilder {
+            body: self.clone(),
+            _name: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<Container> for ContainerBuilder<crate::generics::NameExists> {
+    fn into(self) -> Container {
+        self.body
+    }
+}
+
+/// Builder for [`Container`](./struct.Container.html) object.
+#[derive(Debug, Clone)]
+pub struct ContainerBuilder<Name> {
+    body: self::Container,
+    _name: core::marker::PhantomData<Name>,
+}
+
+impl<Name> ContainerBuilder<Name> {
+    /// Arguments to the entrypoint. The docker image's CMD is used if this is not provided. Variable references $(VAR_NAME) are expanded using the container's environment. If a variable cannot be resolved, the reference in the input string will be unchanged. The $(VAR_NAME) syntax can be escaped with a double $$, ie: $$(VAR_NAME). Escaped references will never be expanded, regardless of whether the variable exists or not. Cannot be updated. More info: https://kubernetes.io/docs/tasks/inject-data-application/define-command-argument-container/#running-a-command-in-a-shell
+    #[inline]
+    pub fn args(mut self, value: impl Iterator<Item = impl Into<String>>) -> Self {
+        self.body.args = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`args`](#method.args) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_args(mut self, value: Option<impl Iterator<Item = impl Into<String>>>) -> Self {
+        self.body.args = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`args`](#method.args), but takes the values as a `Vec`.
+    #[inline]
+    pub fn args_vec(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.body.args = Some(value.into());
+        self
+    }
+
+    /// Entrypoint array. Not executed within a shell. The docker image's ENTRYPOINT is used if this is not provided. Variable references $(VAR_NAME) are expanded using the container's environment. If a variable cannot be resolved, the reference in the input string will be unchanged. The $(VAR_NAME) syntax can be escaped with a double $$, ie: $$(VAR_NAME). Escaped references will never be expanded, regardless of whether the variable exists or not. Cannot be updated. More info: https://kubernetes.io/docs/tasks/inject-data-application/define-command-argument-container/#running-a-command-in-a-shell
+    #[inline]
+    pub fn command(mut self, value: impl Iterator<Item = impl Into<String>>) -> Self {
+        self.body.command = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`command`](#method.command) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_command(mut self, value: Option<impl Iterator<Item = impl Into<String>>>) -> Self {
+        self.body.command = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`command`](#method.command), but takes the values as a `Vec`.
+    #[inline]
+    pub fn command_vec(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.body.command = Some(value.into());
+        self
+    }
+
+    /// List of environment variables to set in the container. Cannot be updated.
+    #[inline]
+    pub fn env(mut self, value: impl Iterator<Item = crate::io::k8s::api::core::v1::env_var::EnvVarBuilder<crate::generics::NameExists>>) -> Self {
+        self.body.env = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`env`](#method.env) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_env(mut self, value: Option<impl Iterator<Item = crate::io::k8s::api::core::v1::env_var::EnvVarBuilder<crate::generics::NameExists>>>) -> Self {
+        self.body.env = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`env`](#method.env), but takes the values as a `Vec`.
+    #[inline]
+    pub fn env_vec(mut self, value: impl Into<Vec<crate::io::k8s::api::core::v1::env_var::EnvVar>>) -> Self {
+        self.body.env = Some(value.into());
+        self
+    }
+
+    /// List of sources to populate environment variables in the container. The keys defined within a source must be a C_IDENTIFIER. All invalid keys will be reported as an event when the container is starting. When a key exists in multiple sources, the value associated with the last source will take precedence. Values defined by an Env with a duplicate key will take precedence. Cannot be updated.
+    #[inline]
+    pub fn env_from(mut self, value: impl Iterator<Item = crate::io::k8s::api::core::v1::env_from_source::EnvFromSource>) -> Self {
+        self.body.env_from = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`env_from`](#method.env_from) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_env_from(mut self, value: Option<impl Iterator<Item = crate::io::k8s::api::core::v1::env_from_source::EnvFromSource>>) -> Self {
+        self.body.env_from = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`env_from`](#method.env_from), but takes the values as a `Vec`.
+    #[inline]
+    pub fn env_from_vec(mut self, value: impl Into<Vec<crate::io::k8s::api::core::v1::env_from_source::EnvFromSource>>) -> Self {
+        self.body.env_from = Some(value.into());
+        self
+    }
+
+    /// Docker image name. More info: https://kubernetes.io/docs/concepts/containers/images This field is optional to allow higher level config management to default or override container images in workload controllers like Deployments and StatefulSets.
+    #[inline]
+    pub fn image(mut self, value: impl Into<String>) -> Self {
+        self.body.image = Some(value.into());
+        self
+    }
+
+    /// Sets [`image`](#method.image) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_image(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.image = value.map(|value| value.into());
+        self
+    }
+
+    /// Image pull policy. One of Always, Never, IfNotPresent. Defaults to Always if :latest tag is specified, or IfNotPresent otherwise. Cannot be updated. More info: https://kubernetes.io/docs/concepts/containers/images#updating-images
+    #[inline]
+    pub fn image_pull_policy(mut self, value: impl Into<String>) -> Self {
+        self.body.image_pull_policy = Some(value.into());
+        self
+    }
+
+    /// Sets [`image_pull_policy`](#method.image_pull_policy) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_image_pull_policy(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.image_pull_policy = value.map(|value| value.into());
+        self
+    }
+
+    /// Actions that the management system should take in response to container lifecycle events. Cannot be updated.
+    #[inline]
+    pub fn lifecycle(mut self, value: crate::io::k8s::api::core::v1::lifecycle::Lifecycle) -> Self {
+        self.body.lifecycle = Some(value.into());
+        self
+    }
+
+    /// Sets [`lifecycle`](#method.lifecycle) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_lifecycle(mut self, value: Option<crate::io::k8s::api::core::v1::lifecycle::Lifecycle>) -> Self {
+        self.body.lifecycle = value.map(|value| value.into());
+        self
+    }
+
+    /// Periodic probe of container liveness. Container will be restarted if the probe fails. Cannot be updated. More info: https://kubernetes.io/docs/concepts/workloads/pods/pod-lifecycle#container-probes
+    #[inline]
+    pub fn liveness_probe(mut self, value: crate::io::k8s::api::core::v1::probe::Probe) -> Self {
+        self.body.liveness_probe = Some(value.into());
+        self
+    }
+
+    /// Sets [`liveness_probe`](#method.liveness_probe) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_liveness_probe(mut self, value: Option<crate::io::k8s::api::core::v1::probe::Probe>) -> Self {
+        self.body.liveness_probe = value.map(|value| value.into());
+        self
+    }
+
+    /// Name of the container specified as a DNS_LABEL. Each container in a pod must have a unique name (DNS_LABEL). Cannot be updated.
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> ContainerBuilder<crate::generics::NameExists> {
+        self.body.name = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// List of ports to expose from the container. Exposing a port here gives the system additional information about the network connections a container uses, but is primarily informational. Not specifying a port here DOES NOT prevent that port from being exposed. Any port which is listening on the default "0.0.0.0" address inside a container will be accessible from the network. Cannot be updated.
+    #[inline]
+    pub fn ports(mut self, value: impl Iterator<Item = crate::io::k8s::api::core::v1::container_port::ContainerPortBuilder<crate::generics::ContainerPortExists>>) -> Self {
+        self.body.ports = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`ports`](#method.ports) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_ports(mut self, value: Option<impl Iterator<Item = crate::io::k8s::api::core::v1::container_port::ContainerPortBuilder<crate::generics::ContainerPortExists>>>) -> Self {
+        self.body.ports = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`ports`](#method.ports), but takes the values as a `Vec`.
+    #[inline]
+    pub fn ports_vec(mut self, value: impl Into<Vec<crate::io::k8s::api::core::v1::container_port::ContainerPort>>) -> Self {
+        self.body.ports = Some(value.into());
+        self
+    }
+
+    /// Periodic probe of container service readiness. Container will be removed from service endpoints if the probe fails. Cannot be updated. More info: https://kubernetes.io/docs/concepts/workloads/pods/pod-lifecycle#container-probes
+    #[inline]
+    pub fn readiness_probe(mut self, value: crate::io::k8s::api::core::v1::probe::Probe) -> Self {
+        self.body.readiness_probe = Some(value.into());
+        self
+    }
+
+    /// Sets [`readiness_probe`](#method.readiness_probe) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_readiness_probe(mut self, value: Option<crate::io::k8s::api::core::v1::probe::Probe>) -> Self {
+        self.body.readiness_probe = value.map(|value| value.into());
+        self
+    }
+
+    /// Compute Resources required by this container. Cannot be updated. More info: https://kubernetes.io/docs/concepts/configuration/manage-compute-resources-container/
+    #[inline]
+    pub fn resources(mut self, value: crate::io::k8s::api::core::v1::resource_requirements::ResourceRequirements) -> Self {
+        self.body.resources = Some(value.into());
+        self
+    }
+
+    /// Sets [`resources`](#method.resources) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_resources(mut self, value: Option<crate::io::k8s::api::core::v1::resource_requirements::ResourceRequirements>) -> Self {
+        self.body.resources = value.map(|value| value.into());
+        self
+    }
+
+    /// Security options the pod should run with. More info: https://kubernetes.io/docs/concepts/policy/security-context/ More info: https://kubernetes.io/docs/tasks/configure-pod-container/security-context/
+    #[inline]
+    pub fn security_context(mut self, value: crate::io::k8s::api::core::v1::security_context::SecurityContext) -> Self {
+        self.body.security_context = Some(value.into());
+        self
+    }
+
+    /// Sets [`security_context`](#method.security_context) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_security_context(mut self, value: Option<crate::io::k8s::api::core::v1::security_context::SecurityContext>) -> Self {
+        self.body.security_context = value.map(|value| value.into());
+        self
+    }
+
+    /// Whether this container should allocate a buffer for stdin in the container runtime. If this is not set, reads from stdin in the container will always result in EOF. Default is false.
+    #[inline]
+    pub fn stdin(mut self, value: impl Into<bool>) -> Self {
+        self.body.stdin = Some(value.into());
+        self
+    }
+
+    /// Sets [`stdin`](#method.stdin) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_stdin(mut self, value: Option<impl Into<bool>>) -> Self {
+        self.body.stdin = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`stdin`](#method.stdin), but takes a reference.
+    #[inline]
+    pub fn stdin_ref(self, value: &bool) -> Self {
+        self.stdin(*value)
+    }
+
+    /// Whether the container runtime should close the stdin channel after it has been opened by a single attach. When stdin is true the stdin stream will remain open across multiple attach sessions. If stdinOnce is set to true, stdin is opened on container start, is empty until the first client attaches to stdin, and then remains open and accepts data until the client disconnects, at which time stdin is closed and remains closed until the container is restarted. If this flag is false, a container processes that reads from stdin will never receive an EOF. Default is false
+    #[inline]
+    pub fn stdin_once(mut self, value: impl Into<bool>) -> Self {
+        self.body.stdin_once = Some(value.into());
+        self
+    }
+
+    /// Sets [`stdin_once`](#method.stdin_once) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_stdin_once(mut self, value: Option<impl Into<bool>>) -> Self {
+        self.body.stdin_once = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`stdin_once`](#method.stdin_once), but takes a reference.
+    #[inline]
+    pub fn stdin_once_ref(self, value: &bool) -> Self {
+        self.stdin_once(*value)
+    }
+
+    /// Optional: Path at which the file to which the container's termination message will be written is mounted into the container's filesystem. Message written is intended to be brief final status, such as an assertion failure message. Will be truncated by the node if greater than 4096 bytes. The total message length across all containers will be limited to 12kb. Defaults to /dev/termination-log. Cannot be updated.
+    #[inline]
+    pub fn termination_message_path(mut self, value: impl Into<String>) -> Self {
+        self.body.termination_message_path = Some(value.into());
+        self
+    }
+
+    /// Sets [`termination_message_path`](#method.termination_message_path) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_termination_message_path(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.termination_message_path = value.map(|value| value.into());
+        self
+    }
+
+    /// Indicate how the termination message should be populated. File will use the contents of terminationMessagePath to populate the container status message on both success and failure. FallbackToLogsOnError will use the last chunk of container log output if the termination message file is empty and the container exited with an error. The log output is limited to 2048 bytes or 80 lines, whichever is smaller. Defaults to File. Cannot be updated.
+    #[inline]
+    pub fn termination_message_policy(mut self, value: impl Into<String>) -> Self {
+        self.body.termination_message_policy = Some(value.into());
+        self
+    }
+
+    /// Sets [`termination_message_policy`](#method.termination_message_policy) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_termination_message_policy(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.termination_message_policy = value.map(|value| value.into());
+        self
+    }
+
+    /// Whether this container should allocate a TTY for itself, also requires 'stdin' to be true. Default is false.
+    #[inline]
+    pub fn tty(mut self, value: impl Into<bool>) -> Self {
+        self.body.tty = Some(value.into());
+        self
+    }
+
+    /// Sets [`tty`](#method.tty) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_tty(mut self, value: Option<impl Into<bool>>) -> Self {
+        self.body.tty = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`tty`](#method.tty), but takes a reference.
+    #[inline]
+    pub fn tty_ref(self, value: &bool) -> Self {
+        self.tty(*value)
+    }
+
+    /// volumeDevices is the list of block devices to be used by the container. This is a beta feature.
+    #[inline]
+    pub fn volume_devices(mut self, value: impl Iterator<Item = crate::io::k8s::api::core::v1::volume_device::VolumeDeviceBuilder<crate::generics::DevicePathExists, crate::generics::NameExists>>) -> Self {
+        self.body.volume_devices = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`volume_devices`](#method.volume_devices) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_volume_devices(mut self, value: Option<impl Iterator<Item = crate::io::k8s::api::core::v1::volume_device::VolumeDeviceBuilder<crate::generics::DevicePathExists, crate::generics::NameExists>>>) -> Self {
+        self.body.volume_devices = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`volume_devices`](#method.volume_devices), but takes the values as a `Vec`.
+    #[inline]
+    pub fn volume_devices_vec(mut self, value: impl Into<Vec<crate::io::k8s::api::core::v1::volume_device::VolumeDevice>>) -> Self {
+        self.body.volume_devices = Some(value.into());
+        self
+    }
+
+    /// Pod volumes to mount into the container's filesystem. Cannot be updated.
+    #[inline]
+    pub fn volume_mounts(mut self, value: impl Iterator<Item = crate::io::k8s::api::core::v1::volume_mount::VolumeMountBuilder<crate::generics::MountPathExists, crate::generics::NameExists>>) -> Self {
+        self.body.volume_mounts = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`volume_mounts`](#method.volume_mounts) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_volume_mounts(mut self, value: Option<impl Iterator<Item = crate::io::k8s::api::core::v1::volume_mount::VolumeMountBuilder<crate::generics::MountPathExists, crate::generics::NameExists>>>) -> Self {
+        self.body.volume_mounts = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`volume_mounts`](#method.volume_mounts), but takes the values as a `Vec`.
+    #[inline]
+    pub fn volume_mounts_vec(mut self, value: impl Into<Vec<crate::io::k8s::api::core::v1::volume_mount::VolumeMount>>) -> Self {
+        self.body.volume_mounts = Some(value.into());
+        self
+    }
+
+    /// Container's working directory. If not specified, the container runtime's default will be used, which might be configured in the container image. Cannot be updated.
+    #[inline]
+    pub fn working_dir(mut self, value: impl Into<String>) -> Self {
+        self.body.working_dir = Some(value.into());
+        self
+    }
+
+    /// Sets [`working_dir`](#method.working_dir) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_working_dir(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.working_dir = value.map(|value| value.into());
+        self
+    }
+}
@@ -0,0 +1,82 @@
+
+/// Describe a container image
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct ContainerImage {
+    /// Names by which this image is known. e.g. \["k8s.gcr.io/hyperkube:v1.0.7", "dockerhub.io/google_containers/hyperkube:v1.0.7"\]
+    pub names: Vec<String>,
+    /// The size of the image in bytes.
+    #[serde(rename = "sizeBytes", skip_serializing_if = "Option::is_none")]
+    pub size_bytes: Option<i64>,
+}
+
+impl ContainerImage {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> ContainerImageBuilder<crate::generics::MissingNames> {
+        ContainerImageBuilder {
+            body: Default::default(),
+            _names: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> ContainerImageBuilder<crate::generics::NamesExists>
+    where
+        Self: Clone,
+    {
+        ContainerImageBuilder {
+            body: self.clone(),
+            _names: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<ContainerImage> for ContainerImageBuilder<crate::generics::NamesExists> {
+    fn into(self) -> ContainerImage {
+        self.body
+    }
+}
+
+/// Builder for [`ContainerImage`](./struct.ContainerImage.html) object.
+#[derive(Debug, Clone)]
+pub struct ContainerImageBuilder<Names> {
+    body: self::ContainerImage,
+    _names: core::marker::PhantomData<Names>,
+}
+
+impl<Names> ContainerImageBuilder<Names> {
+    /// Names by which this image is known. e.g. \["k8s.gcr.io/hyperkube:v1.0.7", "dockerhub.io/google_containers/hyperkube:v1.0.7"\]
+    #[inline]
+    pub fn names(mut self, value: impl Iterator<Item = impl Into<String>>) -> ContainerImageBuilder<crate::generics::NamesExists> {
+        self.body.names = value.map(|value| value.into()).collect::<Vec<_>>().into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Same as [`names`](#method.names), but takes the values as a `Vec`.
+    #[inline]
+    pub fn names_vec(mut self, value: impl Into<Vec<String>>) -> ContainerImageBuilder<crate::generics::NamesExists> {
+        self.body.names = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// The size of the image in bytes.
+    #[inline]
+    pub fn size_bytes(mut self, value: impl Into<i64>) -> Self {
+        self.body.size_bytes = Some(value.into());
+        self
+    }
+
+    /// Sets [`size_bytes`](#method.size_bytes) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_size_bytes(mut self, value: Option<impl Into<i64>>) -> Self {
+        self.body.size_bytes = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`size_bytes`](#method.size_bytes), but takes a reference.
+    #[inline]
+    pub fn size_bytes_ref(self, value: &i64) -> Self {
+        self.size_bytes(*value)
+    }
+}
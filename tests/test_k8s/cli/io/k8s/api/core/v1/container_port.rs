@@ -0,0 +1,133 @@
+
+/// ContainerPort represents a network port in a single container.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct ContainerPort {
+    /// Number of port to expose on the pod's IP address. This must be a valid port number, 0 < x < 65536.
+    #[serde(rename = "containerPort")]
+    pub container_port: i32,
+    /// What host IP to bind the external port to.
+    #[serde(rename = "hostIP", skip_serializing_if = "Option::is_none")]
+    pub host_ip: Option<String>,
+    /// Number of port to expose on the host. If specified, this must be a valid port number, 0 < x < 65536. If HostNetwork is specified, this must match ContainerPort. Most containers do not need this.
+    #[serde(rename = "hostPort", skip_serializing_if = "Option::is_none")]
+    pub host_port: Option<i32>,
+    /// If specified, this must be an IANA_SVC_NAME and unique within the pod. Each named port in a pod must have a unique name. Name for the port that can be referred to by services.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Protocol for port. Must be UDP, TCP, or SCTP. Defaults to "TCP".
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub protocol: Option<String>,
+}
+
+impl ContainerPort {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> ContainerPortBuilder<crate::generics::MissingContainerPort> {
+        ContainerPortBuilder {
+            body: Default::default(),
+            _container_port: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> ContainerPortBuilder<crate::generics::ContainerPortExists>
+    where
+        Self: Clone,
+    {
+        ContainerPortBuilder {
+            body: self.clone(),
+            _container_port: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<ContainerPort> for ContainerPortBuilder<crate::generics::ContainerPortExists> {
+    fn into(self) -> ContainerPort {
+        self.body
+    }
+}
+
+/// Builder for [`ContainerPort`](./struct.ContainerPort.html) object.
+#[derive(Debug, Clone)]
+pub struct ContainerPortBuilder<ContainerPort> {
+    body: self::ContainerPort,
+    _container_port: core::marker::PhantomData<ContainerPort>,
+}
+
+impl<ContainerPort> ContainerPortBuilder<ContainerPort> {
+    /// Number of port to expose on the pod's IP address. This must be a valid port number, 0 < x < 65536.
+    #[inline]
+    pub fn container_port(mut self, value: impl Into<i32>) -> ContainerPortBuilder<crate::generics::ContainerPortExists> {
+        self.body.container_port = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Same as [`container_port`](#method.container_port), but takes a reference.
+    #[inline]
+    pub fn container_port_ref(self, value: &i32) -> ContainerPortBuilder<crate::generics::ContainerPortExists> {
+        self.container_port(*value)
+    }
+
+    /// What host IP to bind the external port to.
+    #[inline]
+    pub fn host_ip(mut self, value: impl Into<String>) -> Self {
+        self.body.host_ip = Some(value.into());
+        self
+    }
+
+    /// Sets [`host_ip`](#method.host_ip) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_host_ip(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.host_ip = value.map(|value| value.into());
+        self
+    }
+
+    /// Number of port to expose on the host. If specified, this must be a valid port number, 0 < x < 65536. If HostNetwork is specified, this must match ContainerPort. Most containers do not need this.
+    #[inline]
+    pub fn host_port(mut self, value: impl Into<i32>) -> Self {
+        self.body.host_port = Some(value.into());
+        self
+    }
+
+    /// Sets [`host_port`](#method.host_port) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_host_port(mut self, value: Option<impl Into<i32>>) -> Self {
+        self.body.host_port = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`host_port`](#method.host_port), but takes a reference.
+    #[inline]
+    pub fn host_port_ref(self, value: &i32) -> Self {
+        self.host_port(*value)
+    }
+
+    /// If specified, this must be an IANA_SVC_NAME and unique within the pod. Each named port in a pod must have a unique name. Name for the port that can be referred to by services.
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> Self {
+        self.body.name = Some(value.into());
+        self
+    }
+
+    /// Sets [`name`](#method.name) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_name(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.name = value.map(|value| value.into());
+        self
+    }
+
+    /// Protocol for port. Must be UDP, TCP, or SCTP. Defaults to "TCP".
+    #[inline]
+    pub fn protocol(mut self, value: impl Into<String>) -> Self {
+        self.body.protocol = Some(value.into());
+        self
+    }
+
+    /// Sets [`protocol`](#method.protocol) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_protocol(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.protocol = value.map(|value| value.into());
+        self
+    }
+}
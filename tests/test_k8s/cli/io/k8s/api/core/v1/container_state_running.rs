@@ -0,0 +1,57 @@
+
+/// ContainerStateRunning is a running state of a container.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct ContainerStateRunning {
+    /// Time at which the container was last (re-)started
+    #[serde(rename = "startedAt", skip_serializing_if = "Option::is_none")]
+    pub started_at: Option<String>,
+}
+
+impl ContainerStateRunning {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> ContainerStateRunningBuilder {
+        ContainerStateRunningBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> ContainerStateRunningBuilder
+    where
+        Self: Clone,
+    {
+        ContainerStateRunningBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<ContainerStateRunning> for ContainerStateRunningBuilder {
+    fn into(self) -> ContainerStateRunning {
+        self.body
+    }
+}
+
+/// Builder for [`ContainerStateRunning`](./struct.ContainerStateRunning.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct ContainerStateRunningBuilder {
+    body: self::ContainerStateRunning,
+}
+
+impl ContainerStateRunningBuilder {
+    /// Time at which the container was last (re-)started
+    #[inline]
+    pub fn started_at(mut self, value: impl Into<String>) -> Self {
+        self.body.started_at = Some(value.into());
+        self
+    }
+
+    /// Sets [`started_at`](#method.started_at) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_started_at(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.started_at = value.map(|value| value.into());
+        self
+    }
+}
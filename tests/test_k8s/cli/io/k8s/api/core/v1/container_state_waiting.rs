@@ -0,0 +1,74 @@
+
+/// ContainerStateWaiting is a waiting state of a container.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct ContainerStateWaiting {
+    /// Message regarding why the container is not yet running.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    /// (brief) reason the container is not yet running.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+}
+
+impl ContainerStateWaiting {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> ContainerStateWaitingBuilder {
+        ContainerStateWaitingBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> ContainerStateWaitingBuilder
+    where
+        Self: Clone,
+    {
+        ContainerStateWaitingBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<ContainerStateWaiting> for ContainerStateWaitingBuilder {
+    fn into(self) -> ContainerStateWaiting {
+        self.body
+    }
+}
+
+/// Builder for [`ContainerStateWaiting`](./struct.ContainerStateWaiting.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct ContainerStateWaitingBuilder {
+    body: self::ContainerStateWaiting,
+}
+
+impl ContainerStateWaitingBuilder {
+    /// Message regarding why the container is not yet running.
+    #[inline]
+    pub fn message(mut self, value: impl Into<String>) -> Self {
+        self.body.message = Some(value.into());
+        self
+    }
+
+    /// Sets [`message`](#method.message) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_message(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.message = value.map(|value| value.into());
+        self
+    }
+
+    /// (brief) reason the container is not yet running.
+    #[inline]
+    pub fn reason(mut self, value: impl Into<String>) -> Self {
+        self.body.reason = Some(value.into());
+        self
+    }
+
+    /// Sets [`reason`](#method.reason) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_reason(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.reason = value.map(|value| value.into());
+        self
+    }
+}
@@ -0,0 +1,165 @@
+
+/// ContainerStatus contains details for the current status of this container.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct ContainerStatus {
+    /// Container's ID in the format 'docker://\<container_id>'.
+    #[serde(rename = "containerID", skip_serializing_if = "Option::is_none")]
+    pub container_id: Option<String>,
+    /// The image the container is running. More info: https://kubernetes.io/docs/concepts/containers/images
+    pub image: String,
+    /// ImageID of the container's image.
+    #[serde(rename = "imageID")]
+    pub image_id: String,
+    /// Details about the container's last termination condition.
+    #[serde(rename = "lastState", skip_serializing_if = "Option::is_none")]
+    pub last_state: Option<crate::io::k8s::api::core::v1::container_state::ContainerState>,
+    /// This must be a DNS_LABEL. Each container in a pod must have a unique name. Cannot be updated.
+    pub name: String,
+    /// Specifies whether the container has passed its readiness probe.
+    pub ready: bool,
+    /// The number of times the container has been restarted, currently based on the number of dead containers that have not yet been removed. Note that this is calculated from dead containers. But those containers are subject to garbage collection. This value will get capped at 5 by GC.
+    #[serde(rename = "restartCount")]
+    pub restart_count: i32,
+    /// Details about the container's current condition.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<crate::io::k8s::api::core::v1::container_state::ContainerState>,
+}
+
+impl ContainerStatus {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> ContainerStatusBuilder<crate::generics::MissingImage, crate::generics::MissingImageId, crate::generics::MissingName, crate::generics::MissingReady, crate::generics::MissingRestartCount> {
+        ContainerStatusBuilder {
+            body: Default::default(),
+            _image: core::marker::PhantomData,
+            _image_id: core::marker::PhantomData,
+            _name: core::marker::PhantomData,
+            _ready: core::marker::PhantomData,
+            _restart_count: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> ContainerStatusBuilder<crate::generics::ImageExists, crate::generics::ImageIdExists, crate::generics::NameExists, crate::generics::ReadyExists, crate::generics::RestartCountExists>
+    where
+        Self: Clone,
+    {
+        ContainerStatusBuilder {
+            body: self.clone(),
+            _image: core::marker::PhantomData,
+            _image_id: core::marker::PhantomData,
+            _name: core::marker::PhantomData,
+            _ready: core::marker::PhantomData,
+            _restart_count: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<ContainerStatus> for ContainerStatusBuilder<crate::generics::ImageExists, crate::generics::ImageIdExists, crate::generics::NameExists, crate::generics::ReadyExists, crate::generics::RestartCountExists> {
+    fn into(self) -> ContainerStatus {
+        self.body
+    }
+}
+
+/// Builder for [`ContainerStatus`](./struct.ContainerStatus.html) object.
+#[derive(Debug, Clone)]
+pub struct ContainerStatusBuilder<Image, ImageId, Name, Ready, RestartCount> {
+    body: self::ContainerStatus,
+    _image: core::marker::PhantomData<Image>,
+    _image_id: core::marker::PhantomData<ImageId>,
+    _name: core::marker::PhantomData<Name>,
+    _ready: core::marker::PhantomData<Ready>,
+    _restart_count: core::marker::PhantomData<RestartCount>,
+}
+
+impl<Image, ImageId, Name, Ready, RestartCount> ContainerStatusBuilder<Image, ImageId, Name, Ready, RestartCount> {
+    /// Container's ID in the format 'docker://\<container_id>'.
+    #[inline]
+    pub fn container_id(mut self, value: impl Into<String>) -> Self {
+        self.body.container_id = Some(value.into());
+        self
+    }
+
+    /// Sets [`container_id`](#method.container_id) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_container_id(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.container_id = value.map(|value| value.into());
+        self
+    }
+
+    /// The image the container is running. More info: https://kubernetes.io/docs/concepts/containers/images
+    #[inline]
+    pub fn image(mut self, value: impl Into<String>) -> ContainerStatusBuilder<crate::generics::ImageExists, ImageId, Name, Ready, RestartCount> {
+        self.body.image = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// ImageID of the container's image.
+    #[inline]
+    pub fn image_id(mut self, value: impl Into<String>) -> ContainerStatusBuilder<Image, crate::generics::ImageIdExists, Name, Ready, RestartCount> {
+        self.body.image_id = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Details about the container's last termination condition.
+    #[inline]
+    pub fn last_state(mut self, value: crate::io::k8s::api::core::v1::container_state::ContainerState) -> Self {
+        self.body.last_state = Some(value.into());
+        self
+    }
+
+    /// Sets [`last_state`](#method.last_state) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_last_state(mut self, value: Option<crate::io::k8s::api::core::v1::container_state::ContainerState>) -> Self {
+        self.body.last_state = value.map(|value| value.into());
+        self
+    }
+
+    /// This must be a DNS_LABEL. Each container in a pod must have a unique name. Cannot be updated.
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> ContainerStatusBuilder<Image, ImageId, crate::generics::NameExists, Ready, RestartCount> {
+        self.body.name = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Specifies whether the container has passed its readiness probe.
+    #[inline]
+    pub fn ready(mut self, value: impl Into<bool>) -> ContainerStatusBuilder<Image, ImageId, Name, crate::generics::ReadyExists, RestartCount> {
+        self.body.ready = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Same as [`ready`](#method.ready), but takes a reference.
+    #[inline]
+    pub fn ready_ref(self, value: &bool) -> ContainerStatusBuilder<Image, ImageId, Name, crate::generics::ReadyExists, RestartCount> {
+        self.ready(*value)
+    }
+
+    /// The number of times the container has been restarted, currently based on the number of dead containers that have not yet been removed. Note that this is calculated from dead containers. But those containers are subject to garbage collection. This value will get capped at 5 by GC.
+    #[inline]
+    pub fn restart_count(mut self, value: impl Into<i32>) -> ContainerStatusBuilder<Image, ImageId, Name, Ready, crate::generics::RestartCountExists> {
+        self.body.restart_count = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Same as [`restart_count`](#method.restart_count), but takes a reference.
+    #[inline]
+    pub fn restart_count_ref(self, value: &i32) -> ContainerStatusBuilder<Image, ImageId, Name, Ready, crate::generics::RestartCountExists> {
+        self.restart_count(*value)
+    }
+
+    /// Details about the container's current condition.
+    #[inline]
+    pub fn state(mut self, value: crate::io::k8s::api::core::v1::container_state::ContainerState) -> Self {
+        self.body.state = Some(value.into());
+        self
+    }
+
+    /// Sets [`state`](#method.state) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_state(mut self, value: Option<crate::io::k8s::api::core::v1::container_state::ContainerState>) -> Self {
+        self.body.state = value.map(|value| value.into());
+        self
+    }
+}
@@ -0,0 +1,190 @@
+
+/// Represents storage that is managed by an external CSI volume driver (Beta feature)
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct CsiPersistentVolumeSource {
+    /// ControllerExpandSecretRef is a reference to the secret object containing sensitive information to pass to the CSI driver to complete the CSI ControllerExpandVolume call. This is an alpha field and requires enabling ExpandCSIVolumes feature gate. This field is optional, and may be empty if no secret is required. If the secret object contains more than one secret, all secrets are passed.
+    #[serde(rename = "controllerExpandSecretRef", skip_serializing_if = "Option::is_none")]
+    pub controller_expand_secret_ref: Option<crate::io::k8s::api::core::v1::secret_reference::SecretReference>,
+    /// ControllerPublishSecretRef is a reference to the secret object containing sensitive information to pass to the CSI driver to complete the CSI ControllerPublishVolume and ControllerUnpublishVolume calls. This field is optional, and may be empty if no secret is required. If the secret object contains more than one secret, all secrets are passed.
+    #[serde(rename = "controllerPublishSecretRef", skip_serializing_if = "Option::is_none")]
+    pub controller_publish_secret_ref: Option<crate::io::k8s::api::core::v1::secret_reference::SecretReference>,
+    /// Driver is the name of the driver to use for this volume. Required.
+    pub driver: String,
+    /// Filesystem type to mount. Must be a filesystem type supported by the host operating system. Ex. "ext4", "xfs", "ntfs".
+    #[serde(rename = "fsType", skip_serializing_if = "Option::is_none")]
+    pub fs_type: Option<String>,
+    /// NodePublishSecretRef is a reference to the secret object containing sensitive information to pass to the CSI driver to complete the CSI NodePublishVolume and NodeUnpublishVolume calls. This field is optional, and may be empty if no secret is required. If the secret object contains more than one secret, all secrets are passed.
+    #[serde(rename = "nodePublishSecretRef", skip_serializing_if = "Option::is_none")]
+    pub node_publish_secret_ref: Option<crate::io::k8s::api::core::v1::secret_reference::SecretReference>,
+    /// NodeStageSecretRef is a reference to the secret object containing sensitive information to pass to the CSI driver to complete the CSI NodeStageVolume and NodeStageVolume and NodeUnstageVolume calls. This field is optional, and may be empty if no secret is required. If the secret object contains more than one secret, all secrets are passed.
+    #[serde(rename = "nodeStageSecretRef", skip_serializing_if = "Option::is_none")]
+    pub node_stage_secret_ref: Option<crate::io::k8s::api::core::v1::secret_reference::SecretReference>,
+    /// Optional: The value to pass to ControllerPublishVolumeRequest. Defaults to false (read/write).
+    #[serde(rename = "readOnly", skip_serializing_if = "Option::is_none")]
+    pub read_only: Option<bool>,
+    /// Attributes of the volume to publish.
+    #[serde(rename = "volumeAttributes", skip_serializing_if = "Option::is_none")]
+    pub volume_attributes: Option<std::collections::BTreeMap<String, String>>,
+    /// VolumeHandle is the unique volume name returned by the CSI volume plugin’s CreateVolume to refer to the volume on all subsequent calls. Required.
+    #[serde(rename = "volumeHandle")]
+    pub volume_handle: String,
+}
+
+impl CsiPersistentVolumeSource {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> CsiPersistentVolumeSourceBuilder<crate::generics::MissingDriver, crate::generics::MissingVolumeHandle> {
+        CsiPersistentVolumeSourceBuilder {
+            body: Default::default(),
+            _driver: core::marker::PhantomData,
+            _volume_handle: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> CsiPersistentVolumeSourceBuilder<crate::generics::DriverExists, crate::generics::VolumeHandleExists>
+    where
+        Self: Clone,
+    {
+        CsiPersistentVolumeSourceBuilder {
+            body: self.clone(),
+            _driver: core::marker::PhantomData,
+            _volume_handle: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<CsiPersistentVolumeSource> for CsiPersistentVolumeSourceBuilder<crate::generics::DriverExists, crate::generics::VolumeHandleExists> {
+    fn into(self) -> CsiPersistentVolumeSource {
+        self.body
+    }
+}
+
+/// Builder for [`CsiPersistentVolumeSource`](./struct.CsiPersistentVolumeSource.html) object.
+#[derive(Debug, Clone)]
+pub struct CsiPersistentVolumeSourceBuilder<Driver, VolumeHandle> {
+    body: self::CsiPersistentVolumeSource,
+    _driver: core::marker::PhantomData<Driver>,
+    _volume_handle: core::marker::PhantomData<VolumeHandle>,
+}
+
+impl<Driver, VolumeHandle> CsiPersistentVolumeSourceBuilder<Driver, VolumeHandle> {
+    /// ControllerExpandSecretRef is a reference to the secret object containing sensitive information to pass to the CSI driver to complete the CSI ControllerExpandVolume call. This is an alpha field and requires enabling ExpandCSIVolumes feature gate. This field is optional, and may be empty if no secret is required. If the secret object contains more than one secret, all secrets are passed.
+    #[inline]
+    pub fn controller_expand_secret_ref(mut self, value: crate::io::k8s::api::core::v1::secret_reference::SecretReference) -> Self {
+        self.body.controller_expand_secret_ref = Some(value.into());
+        self
+    }
+
+    /// Sets [`controller_expand_secret_ref`](#method.controller_expand_secret_ref) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_controller_expand_secret_ref(mut self, value: Option<crate::io::k8s::api::core::v1::secret_reference::SecretReference>) -> Self {
+        self.body.controller_expand_secret_ref = value.map(|value| value.into());
+        self
+    }
+
+    /// ControllerPublishSecretRef is a reference to the secret object containing sensitive information to pass to the CSI driver to complete the CSI ControllerPublishVolume and ControllerUnpublishVolume calls. This field is optional, and may be empty if no secret is required. If the secret object contains more than one secret, all secrets are passed.
+    #[inline]
+    pub fn controller_publish_secret_ref(mut self, value: crate::io::k8s::api::core::v1::secret_reference::SecretReference) -> Self {
+        self.body.controller_publish_secret_ref = Some(value.into());
+        self
+    }
+
+    /// Sets [`controller_publish_secret_ref`](#method.controller_publish_secret_ref) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_controller_publish_secret_ref(mut self, value: Option<crate::io::k8s::api::core::v1::secret_reference::SecretReference>) -> Self {
+        self.body.controller_publish_secret_ref = value.map(|value| value.into());
+        self
+    }
+
+    /// Driver is the name of the driver to use for this volume. Required.
+    #[inline]
+    pub fn driver(mut self, value: impl Into<String>) -> CsiPersistentVolumeSourceBuilder<crate::generics::DriverExists, VolumeHandle> {
+        self.body.driver = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Filesystem type to mount. Must be a filesystem type supported by the host operating system. Ex. "ext4", "xfs", "ntfs".
+    #[inline]
+    pub fn fs_type(mut self, value: impl Into<String>) -> Self {
+        self.body.fs_type = Some(value.into());
+        self
+    }
+
+    /// Sets [`fs_type`](#method.fs_type) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_fs_type(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.fs_type = value.map(|value| value.into());
+        self
+    }
+
+    /// NodePublishSecretRef is a reference to the secret object containing sensitive information to pass to the CSI driver to complete the CSI NodePublishVolume and NodeUnpublishVolume calls. This field is optional, and may be empty if no secret is required. If the secret object contains more than one secret, all secrets are passed.
+    #[inline]
+    pub fn node_publish_secret_ref(mut self, value: crate::io::k8s::api::core::v1::secret_reference::SecretReference) -> Self {
+        self.body.node_publish_secret_ref = Some(value.into());
+        self
+    }
+
+    /// Sets [`node_publish_secret_ref`](#method.node_publish_secret_ref) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_node_publish_secret_ref(mut self, value: Option<crate::io::k8s::api::core::v1::secret_reference::SecretReference>) -> Self {
+        self.body.node_publish_secret_ref = value.map(|value| value.into());
+        self
+    }
+
+    /// NodeStageSecretRef is a reference to the secret object containing sensitive information to pass to the CSI driver to complete the CSI NodeStageVolume and NodeStageVolume and NodeUnstageVolume calls. This field is optional, and may be empty if no secret is required. If the secret object contains more than one secret, all secrets are passed.
+    #[inline]
+    pub fn node_stage_secret_ref(mut self, value: crate::io::k8s::api::core::v1::secret_reference::SecretReference) -> Self {
+        self.body.node_stage_secret_ref = Some(value.into());
+        self
+    }
+
+    /// Sets [`node_stage_secret_ref`](#method.node_stage_secret_ref) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_node_stage_secret_ref(mut self, value: Option<crate::io::k8s::api::core::v1::secret_reference::SecretReference>) -> Self {
+        self.body.node_stage_secret_ref = value.map(|value| value.into());
+        self
+    }
+
+    /// Optional: The value to pass to ControllerPublishVolumeRequest. Defaults to false (read/write).
+    #[inline]
+    pub fn read_only(mut self, value: impl Into<bool>) -> Self {
+        self.body.read_only = Some(value.into());
+        self
+    }
+
+    /// Sets [`read_only`](#method.read_only) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_read_only(mut self, value: Option<impl Into<bool>>) -> Self {
+        self.body.read_only = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`read_only`](#method.read_only), but takes a reference.
+    #[inline]
+    pub fn read_only_ref(self, value: &bool) -> Self {
+        self.read_only(*value)
+    }
+
+    /// Attributes of the volume to publish.
+    #[inline]
+    pub fn volume_attributes(mut self, value: impl Iterator<Item = (String, impl Into<String>)>) -> Self {
+        self.body.volume_attributes = Some(value.map(|(key, value)| (key, value.into())).collect::<std::collections::BTreeMap<_, _>>().into());
+        self
+    }
+
+    /// Sets [`volume_attributes`](#method.volume_attributes) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_volume_attributes(mut self, value: Option<impl Iterator<Item = (String, impl Into<String>)>>) -> Self {
+        self.body.volume_attributes = value.map(|value| value.map(|(key, value)| (key, value.into())).collect::<std::collections::BTreeMap<_, _>>().into());
+        self
+    }
+
+    /// VolumeHandle is the unique volume name returned by the CSI volume plugin’s CreateVolume to refer to the volume on all subsequent calls. Required.
+    #[inline]
+    pub fn volume_handle(mut self, value: impl Into<String>) -> CsiPersistentVolumeSourceBuilder<Driver, crate::generics::VolumeHandleExists> {
+        self.body.volume_handle = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+}
@@ -0,0 +1,126 @@
+
+/// Represents a source location of a volume to mount, managed by an external CSI driver
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct CsiVolumeSource {
+    /// Driver is the name of the CSI driver that handles this volume. Consult with your admin for the correct name as registered in the cluster.
+    pub driver: String,
+    /// Filesystem type to mount. Ex. "ext4", "xfs", "ntfs". If not provided, the empty value is passed to the associated CSI driver which will determine the default filesystem to apply.
+    #[serde(rename = "fsType", skip_serializing_if = "Option::is_none")]
+    pub fs_type: Option<String>,
+    /// NodePublishSecretRef is a reference to the secret object containing sensitive information to pass to the CSI driver to complete the CSI NodePublishVolume and NodeUnpublishVolume calls. This field is optional, and  may be empty if no secret is required. If the secret object contains more than one secret, all secret references are passed.
+    #[serde(rename = "nodePublishSecretRef", skip_serializing_if = "Option::is_none")]
+    pub node_publish_secret_ref: Option<crate::io::k8s::api::core::v1::local_object_reference::LocalObjectReference>,
+    /// Specifies a read-only configuration for the volume. Defaults to false (read/write).
+    #[serde(rename = "readOnly", skip_serializing_if = "Option::is_none")]
+    pub read_only: Option<bool>,
+    /// VolumeAttributes stores driver-specific properties that are passed to the CSI driver. Consult your driver's documentation for supported values.
+    #[serde(rename = "volumeAttributes", skip_serializing_if = "Option::is_none")]
+    pub volume_attributes: Option<std::collections::BTreeMap<String, String>>,
+}
+
+impl CsiVolumeSource {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> CsiVolumeSourceBuilder<crate::generics::MissingDriver> {
+        CsiVolumeSourceBuilder {
+            body: Default::default(),
+            _driver: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> CsiVolumeSourceBuilder<crate::generics::DriverExists>
+    where
+        Self: Clone,
+    {
+        CsiVolumeSourceBuilder {
+            body: self.clone(),
+            _driver: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<CsiVolumeSource> for CsiVolumeSourceBuilder<crate::generics::DriverExists> {
+    fn into(self) -> CsiVolumeSource {
+        self.body
+    }
+}
+
+/// Builder for [`CsiVolumeSource`](./struct.CsiVolumeSource.html) object.
+#[derive(Debug, Clone)]
+pub struct CsiVolumeSourceBuilder<Driver> {
+    body: self::CsiVolumeSource,
+    _driver: core::marker::PhantomData<Driver>,
+}
+
+impl<Driver> CsiVolumeSourceBuilder<Driver> {
+    /// Driver is the name of the CSI driver that handles this volume. Consult with your admin for the correct name as registered in the cluster.
+    #[inline]
+    pub fn driver(mut self, value: impl Into<String>) -> CsiVolumeSourceBuilder<crate::generics::DriverExists> {
+        self.body.driver = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Filesystem type to mount. Ex. "ext4", "xfs", "ntfs". If not provided, the empty value is passed to the associated CSI driver which will determine the default filesystem to apply.
+    #[inline]
+    pub fn fs_type(mut self, value: impl Into<String>) -> Self {
+        self.body.fs_type = Some(value.into());
+        self
+    }
+
+    /// Sets [`fs_type`](#method.fs_type) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_fs_type(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.fs_type = value.map(|value| value.into());
+        self
+    }
+
+    /// NodePublishSecretRef is a reference to the secret object containing sensitive information to pass to the CSI driver to complete the CSI NodePublishVolume and NodeUnpublishVolume calls. This field is optional, and  may be empty if no secret is required. If the secret object contains more than one secret, all secret references are passed.
+    #[inline]
+    pub fn node_publish_secret_ref(mut self, value: crate::io::k8s::api::core::v1::local_object_reference::LocalObjectReference) -> Self {
+        self.body.node_publish_secret_ref = Some(value.into());
+        self
+    }
+
+    /// Sets [`node_publish_secret_ref`](#method.node_publish_secret_ref) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_node_publish_secret_ref(mut self, value: Option<crate::io::k8s::api::core::v1::local_object_reference::LocalObjectReference>) -> Self {
+        self.body.node_publish_secret_ref = value.map(|value| value.into());
+        self
+    }
+
+    /// Specifies a read-only configuration for the volume. Defaults to false (read/write).
+    #[inline]
+    pub fn read_only(mut self, value: impl Into<bool>) -> Self {
+        self.body.read_only = Some(value.into());
+        self
+    }
+
+    /// Sets [`read_only`](#method.read_only) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_read_only(mut self, value: Option<impl Into<bool>>) -> Self {
+        self.body.read_only = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`read_only`](#method.read_only), but takes a reference.
+    #[inline]
+    pub fn read_only_ref(self, value: &bool) -> Self {
+        self.read_only(*value)
+    }
+
+    /// VolumeAttributes stores driver-specific properties that are passed to the CSI driver. Consult your driver's documentation for supported values.
+    #[inline]
+    pub fn volume_attributes(mut self, value: impl Iterator<Item = (String, impl Into<String>)>) -> Self {
+        self.body.volume_attributes = Some(value.map(|(key, value)| (key, value.into())).collect::<std::collections::BTreeMap<_, _>>().into());
+        self
+    }
+
+    /// Sets [`volume_attributes`](#method.volume_attributes) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_volume_attributes(mut self, value: Option<impl Iterator<Item = (String, impl Into<String>)>>) -> Self {
+        self.body.volume_attributes = value.map(|value| value.map(|(key, value)| (key, value.into())).collect::<std::collections::BTreeMap<_, _>>().into());
+        self
+    }
+}
@@ -0,0 +1,64 @@
+
+/// Represents downward API info for projecting into a projected volume. Note that this is identical to a downwardAPI volume source without the default mode.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct DownwardApiProjection {
+    /// Items is a list of DownwardAPIVolume file
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub items: Option<Vec<crate::io::k8s::api::core::v1::downward_api_volume_file::DownwardApiVolumeFile>>,
+}
+
+impl DownwardApiProjection {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> DownwardApiProjectionBuilder {
+        DownwardApiProjectionBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> DownwardApiProjectionBuilder
+    where
+        Self: Clone,
+    {
+        DownwardApiProjectionBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<DownwardApiProjection> for DownwardApiProjectionBuilder {
+    fn into(self) -> DownwardApiProjection {
+        self.body
+    }
+}
+
+/// Builder for [`DownwardApiProjection`](./struct.DownwardApiProjection.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct DownwardApiProjectionBuilder {
+    body: self::DownwardApiProjection,
+}
+
+impl DownwardApiProjectionBuilder {
+    /// Items is a list of DownwardAPIVolume file
+    #[inline]
+    pub fn items(mut self, value: impl Iterator<Item = crate::io::k8s::api::core::v1::downward_api_volume_file::DownwardApiVolumeFileBuilder<crate::generics::PathExists>>) -> Self {
+        self.body.items = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`items`](#method.items) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_items(mut self, value: Option<impl Iterator<Item = crate::io::k8s::api::core::v1::downward_api_volume_file::DownwardApiVolumeFileBuilder<crate::generics::PathExists>>>) -> Self {
+        self.body.items = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`items`](#method.items), but takes the values as a `Vec`.
+    #[inline]
+    pub fn items_vec(mut self, value: impl Into<Vec<crate::io::k8s::api::core::v1::downward_api_volume_file::DownwardApiVolumeFile>>) -> Self {
+        self.body.items = Some(value.into());
+        self
+    }
+}
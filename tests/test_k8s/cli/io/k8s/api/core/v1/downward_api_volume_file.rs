@@ -0,0 +1,109 @@
+
+/// DownwardAPIVolumeFile represents information to create the file containing the pod field
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct DownwardApiVolumeFile {
+    /// Required: Selects a field of the pod: only annotations, labels, name and namespace are supported.
+    #[serde(rename = "fieldRef", skip_serializing_if = "Option::is_none")]
+    pub field_ref: Option<crate::io::k8s::api::core::v1::object_field_selector::ObjectFieldSelector>,
+    /// Optional: mode bits to use on this file, must be a value between 0 and 0777. If not specified, the volume defaultMode will be used. This might be in conflict with other options that affect the file mode, like fsGroup, and the result can be other mode bits set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mode: Option<i32>,
+    /// Required: Path is  the relative path name of the file to be created. Must not be absolute or contain the '..' path. Must be utf-8 encoded. The first item of the relative path must not start with '..'
+    pub path: String,
+    /// Selects a resource of the container: only resources limits and requests (limits.cpu, limits.memory, requests.cpu and requests.memory) are currently supported.
+    #[serde(rename = "resourceFieldRef", skip_serializing_if = "Option::is_none")]
+    pub resource_field_ref: Option<crate::io::k8s::api::core::v1::resource_field_selector::ResourceFieldSelector>,
+}
+
+impl DownwardApiVolumeFile {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> DownwardApiVolumeFileBuilder<crate::generics::MissingPath> {
+        DownwardApiVolumeFileBuilder {
+            body: Default::default(),
+            _path: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> DownwardApiVolumeFileBuilder<crate::generics::PathExists>
+    where
+        Self: Clone,
+    {
+        DownwardApiVolumeFileBuilder {
+            body: self.clone(),
+            _path: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<DownwardApiVolumeFile> for DownwardApiVolumeFileBuilder<crate::generics::PathExists> {
+    fn into(self) -> DownwardApiVolumeFile {
+        self.body
+    }
+}
+
+/// Builder for [`DownwardApiVolumeFile`](./struct.DownwardApiVolumeFile.html) object.
+#[derive(Debug, Clone)]
+pub struct DownwardApiVolumeFileBuilder<Path> {
+    body: self::DownwardApiVolumeFile,
+    _path: core::marker::PhantomData<Path>,
+}
+
+impl<Path> DownwardApiVolumeFileBuilder<Path> {
+    /// Required: Selects a field of the pod: only annotations, labels, name and namespace are supported.
+    #[inline]
+    pub fn field_ref(mut self, value: crate::io::k8s::api::core::v1::object_field_selector::ObjectFieldSelectorBuilder<crate::generics::FieldPathExists>) -> Self {
+        self.body.field_ref = Some(value.into());
+        self
+    }
+
+    /// Sets [`field_ref`](#method.field_ref) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_field_ref(mut self, value: Option<crate::io::k8s::api::core::v1::object_field_selector::ObjectFieldSelectorBuilder<crate::generics::FieldPathExists>>) -> Self {
+        self.body.field_ref = value.map(|value| value.into());
+        self
+    }
+
+    /// Optional: mode bits to use on this file, must be a value between 0 and 0777. If not specified, the volume defaultMode will be used. This might be in conflict with other options that affect the file mode, like fsGroup, and the result can be other mode bits set.
+    #[inline]
+    pub fn mode(mut self, value: impl Into<i32>) -> Self {
+        self.body.mode = Some(value.into());
+        self
+    }
+
+    /// Sets [`mode`](#method.mode) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_mode(mut self, value: Option<impl Into<i32>>) -> Self {
+        self.body.mode = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`mode`](#method.mode), but takes a reference.
+    #[inline]
+    pub fn mode_ref(self, value: &i32) -> Self {
+        self.mode(*value)
+    }
+
+    /// Required: Path is  the relative path name of the file to be created. Must not be absolute or contain the '..' path. Must be utf-8 encoded. The first item of the relative path must not start with '..'
+    #[inline]
+    pub fn path(mut self, value: impl Into<String>) -> DownwardApiVolumeFileBuilder<crate::generics::PathExists> {
+        self.body.path = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Selects a resource of the container: only resources limits and requests (limits.cpu, limits.memory, requests.cpu and requests.memory) are currently supported.
+    #[inline]
+    pub fn resource_field_ref(mut self, value: crate::io::k8s::api::core::v1::resource_field_selector::ResourceFieldSelectorBuilder<crate::generics::ResourceExists>) -> Self {
+        self.body.resource_field_ref = Some(value.into());
+        self
+    }
+
+    /// Sets [`resource_field_ref`](#method.resource_field_ref) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_resource_field_ref(mut self, value: Option<crate::io::k8s::api::core::v1::resource_field_selector::ResourceFieldSelectorBuilder<crate::generics::ResourceExists>>) -> Self {
+        self.body.resource_field_ref = value.map(|value| value.into());
+        self
+    }
+}
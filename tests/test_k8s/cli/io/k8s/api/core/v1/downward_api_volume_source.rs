@@ -0,0 +1,87 @@
+
+/// DownwardAPIVolumeSource represents a volume containing downward API info. Downward API volumes support ownership management and SELinux relabeling.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct DownwardApiVolumeSource {
+    /// Optional: mode bits to use on created files by default. Must be a value between 0 and 0777. Defaults to 0644. Directories within the path are not affected by this setting. This might be in conflict with other options that affect the file mode, like fsGroup, and the result can be other mode bits set.
+    #[serde(rename = "defaultMode", skip_serializing_if = "Option::is_none")]
+    pub default_mode: Option<i32>,
+    /// Items is a list of downward API volume file
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub items: Option<Vec<crate::io::k8s::api::core::v1::downward_api_volume_file::DownwardApiVolumeFile>>,
+}
+
+impl DownwardApiVolumeSource {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> DownwardApiVolumeSourceBuilder {
+        DownwardApiVolumeSourceBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> DownwardApiVolumeSourceBuilder
+    where
+        Self: Clone,
+    {
+        DownwardApiVolumeSourceBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<DownwardApiVolumeSource> for DownwardApiVolumeSourceBuilder {
+    fn into(self) -> DownwardApiVolumeSource {
+        self.body
+    }
+}
+
+/// Builder for [`DownwardApiVolumeSource`](./struct.DownwardApiVolumeSource.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct DownwardApiVolumeSourceBuilder {
+    body: self::DownwardApiVolumeSource,
+}
+
+impl DownwardApiVolumeSourceBuilder {
+    /// Optional: mode bits to use on created files by default. Must be a value between 0 and 0777. Defaults to 0644. Directories within the path are not affected by this setting. This might be in conflict with other options that affect the file mode, like fsGroup, and the result can be other mode bits set.
+    #[inline]
+    pub fn default_mode(mut self, value: impl Into<i32>) -> Self {
+        self.body.default_mode = Some(value.into());
+        self
+    }
+
+    /// Sets [`default_mode`](#method.default_mode) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_default_mode(mut self, value: Option<impl Into<i32>>) -> Self {
+        self.body.default_mode = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`default_mode`](#method.default_mode), but takes a reference.
+    #[inline]
+    pub fn default_mode_ref(self, value: &i32) -> Self {
+        self.default_mode(*value)
+    }
+
+    /// Items is a list of downward API volume file
+    #[inline]
+    pub fn items(mut self, value: impl Iterator<Item = crate::io::k8s::api::core::v1::downward_api_volume_file::DownwardApiVolumeFileBuilder<crate::generics::PathExists>>) -> Self {
+        self.body.items = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`items`](#method.items) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_items(mut self, value: Option<impl Iterator<Item = crate::io::k8s::api::core::v1::downward_api_volume_file::DownwardApiVolumeFileBuilder<crate::generics::PathExists>>>) -> Self {
+        self.body.items = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`items`](#method.items), but takes the values as a `Vec`.
+    #[inline]
+    pub fn items_vec(mut self, value: impl Into<Vec<crate::io::k8s::api::core::v1::downward_api_volume_file::DownwardApiVolumeFile>>) -> Self {
+        self.body.items = Some(value.into());
+        self
+    }
+}
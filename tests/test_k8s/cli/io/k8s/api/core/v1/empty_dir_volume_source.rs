@@ -0,0 +1,74 @@
+
+/// Represents an empty directory for a pod. Empty directory volumes support ownership management and SELinux relabeling.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct EmptyDirVolumeSource {
+    /// What type of storage medium should back this directory. The default is "" which means to use the node's default medium. Must be an empty string (default) or Memory. More info: https://kubernetes.io/docs/concepts/storage/volumes#emptydir
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub medium: Option<String>,
+    /// Total amount of local storage required for this EmptyDir volume. The size limit is also applicable for memory medium. The maximum usage on memory medium EmptyDir would be the minimum value between the SizeLimit specified here and the sum of memory limits of all containers in a pod. The default is nil which means that the limit is undefined. More info: http://kubernetes.io/docs/user-guide/volumes#emptydir
+    #[serde(rename = "sizeLimit", skip_serializing_if = "Option::is_none")]
+    pub size_limit: Option<String>,
+}
+
+impl EmptyDirVolumeSource {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> EmptyDirVolumeSourceBuilder {
+        EmptyDirVolumeSourceBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> EmptyDirVolumeSourceBuilder
+    where
+        Self: Clone,
+    {
+        EmptyDirVolumeSourceBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<EmptyDirVolumeSource> for EmptyDirVolumeSourceBuilder {
+    fn into(self) -> EmptyDirVolumeSource {
+        self.body
+    }
+}
+
+/// Builder for [`EmptyDirVolumeSource`](./struct.EmptyDirVolumeSource.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct EmptyDirVolumeSourceBuilder {
+    body: self::EmptyDirVolumeSource,
+}
+
+impl EmptyDirVolumeSourceBuilder {
+    /// What type of storage medium should back this directory. The default is "" which means to use the node's default medium. Must be an empty string (default) or Memory. More info: https://kubernetes.io/docs/concepts/storage/volumes#emptydir
+    #[inline]
+    pub fn medium(mut self, value: impl Into<String>) -> Self {
+        self.body.medium = Some(value.into());
+        self
+    }
+
+    /// Sets [`medium`](#method.medium) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_medium(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.medium = value.map(|value| value.into());
+        self
+    }
+
+    /// Total amount of local storage required for this EmptyDir volume. The size limit is also applicable for memory medium. The maximum usage on memory medium EmptyDir would be the minimum value between the SizeLimit specified here and the sum of memory limits of all containers in a pod. The default is nil which means that the limit is undefined. More info: http://kubernetes.io/docs/user-guide/volumes#emptydir
+    #[inline]
+    pub fn size_limit(mut self, value: impl Into<String>) -> Self {
+        self.body.size_limit = Some(value.into());
+        self
+    }
+
+    /// Sets [`size_limit`](#method.size_limit) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_size_limit(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.size_limit = value.map(|value| value.into());
+        self
+    }
+}
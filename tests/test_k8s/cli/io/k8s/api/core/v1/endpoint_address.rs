@@ -0,0 +1,103 @@
+
+/// EndpointAddress is a tuple that describes single IP address.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct EndpointAddress {
+    /// The Hostname of this endpoint
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hostname: Option<String>,
+    /// The IP of this endpoint. May not be loopback (127.0.0.0/8), link-local (169.254.0.0/16), or link-local multicast ((224.0.0.0/24). IPv6 is also accepted but not fully supported on all platforms. Also, certain kubernetes components, like kube-proxy, are not IPv6 ready.
+    pub ip: String,
+    /// Optional: Node hosting this endpoint. This can be used to determine endpoints local to a node.
+    #[serde(rename = "nodeName", skip_serializing_if = "Option::is_none")]
+    pub node_name: Option<String>,
+    /// Reference to object providing the endpoint.
+    #[serde(rename = "targetRef", skip_serializing_if = "Option::is_none")]
+    pub target_ref: Option<crate::io::k8s::api::core::v1::object_reference::ObjectReference>,
+}
+
+impl EndpointAddress {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> EndpointAddressBuilder<crate::generics::MissingIp> {
+        EndpointAddressBuilder {
+            body: Default::default(),
+            _ip: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> EndpointAddressBuilder<crate::generics::IpExists>
+    where
+        Self: Clone,
+    {
+        EndpointAddressBuilder {
+            body: self.clone(),
+            _ip: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<EndpointAddress> for EndpointAddressBuilder<crate::generics::IpExists> {
+    fn into(self) -> EndpointAddress {
+        self.body
+    }
+}
+
+/// Builder for [`EndpointAddress`](./struct.EndpointAddress.html) object.
+#[derive(Debug, Clone)]
+pub struct EndpointAddressBuilder<Ip> {
+    body: self::EndpointAddress,
+    _ip: core::marker::PhantomData<Ip>,
+}
+
+impl<Ip> EndpointAddressBuilder<Ip> {
+    /// The Hostname of this endpoint
+    #[inline]
+    pub fn hostname(mut self, value: impl Into<String>) -> Self {
+        self.body.hostname = Some(value.into());
+        self
+    }
+
+    /// Sets [`hostname`](#method.hostname) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_hostname(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.hostname = value.map(|value| value.into());
+        self
+    }
+
+    /// The IP of this endpoint. May not be loopback (127.0.0.0/8), link-local (169.254.0.0/16), or link-local multicast ((224.0.0.0/24). IPv6 is also accepted but not fully supported on all platforms. Also, certain kubernetes components, like kube-proxy, are not IPv6 ready.
+    #[inline]
+    pub fn ip(mut self, value: impl Into<String>) -> EndpointAddressBuilder<crate::generics::IpExists> {
+        self.body.ip = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Optional: Node hosting this endpoint. This can be used to determine endpoints local to a node.
+    #[inline]
+    pub fn node_name(mut self, value: impl Into<String>) -> Self {
+        self.body.node_name = Some(value.into());
+        self
+    }
+
+    /// Sets [`node_name`](#method.node_name) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_node_name(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.node_name = value.map(|value| value.into());
+        self
+    }
+
+    /// Reference to object providing the endpoint.
+    #[inline]
+    pub fn target_ref(mut self, value: crate::io::k8s::api::core::v1::object_reference::ObjectReference) -> Self {
+        self.body.target_ref = Some(value.into());
+        self
+    }
+
+    /// Sets [`target_ref`](#method.target_ref) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_target_ref(mut self, value: Option<crate::io::k8s::api::core::v1::object_reference::ObjectReference>) -> Self {
+        self.body.target_ref = value.map(|value| value.into());
+        self
+    }
+}
@@ -0,0 +1,92 @@
+
+/// EndpointPort is a tuple that describes a single port.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct EndpointPort {
+    /// The name of this port (corresponds to ServicePort.Name). Must be a DNS_LABEL. Optional only if one port is defined.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The port number of the endpoint.
+    pub port: i32,
+    /// The IP protocol for this port. Must be UDP, TCP, or SCTP. Default is TCP.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub protocol: Option<String>,
+}
+
+impl EndpointPort {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> EndpointPortBuilder<crate::generics::MissingPort> {
+        EndpointPortBuilder {
+            body: Default::default(),
+            _port: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> EndpointPortBuilder<crate::generics::PortExists>
+    where
+        Self: Clone,
+    {
+        EndpointPortBuilder {
+            body: self.clone(),
+            _port: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<EndpointPort> for EndpointPortBuilder<crate::generics::PortExists> {
+    fn into(self) -> EndpointPort {
+        self.body
+    }
+}
+
+/// Builder for [`EndpointPort`](./struct.EndpointPort.html) object.
+#[derive(Debug, Clone)]
+pub struct EndpointPortBuilder<Port> {
+    body: self::EndpointPort,
+    _port: core::marker::PhantomData<Port>,
+}
+
+impl<Port> EndpointPortBuilder<Port> {
+    /// The name of this port (corresponds to ServicePort.Name). Must be a DNS_LABEL. Optional only if one port is defined.
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> Self {
+        self.body.name = Some(value.into());
+        self
+    }
+
+    /// Sets [`name`](#method.name) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_name(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.name = value.map(|value| value.into());
+        self
+    }
+
+    /// The port number of the endpoint.
+    #[inline]
+    pub fn port(mut self, value: impl Into<i32>) -> EndpointPortBuilder<crate::generics::PortExists> {
+        self.body.port = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Same as [`port`](#method.port), but takes a reference.
+    #[inline]
+    pub fn port_ref(self, value: &i32) -> EndpointPortBuilder<crate::generics::PortExists> {
+        self.port(*value)
+    }
+
+    /// The IP protocol for this port. Must be UDP, TCP, or SCTP. Default is TCP.
+    #[inline]
+    pub fn protocol(mut self, value: impl Into<String>) -> Self {
+        self.body.protocol = Some(value.into());
+        self
+    }
+
+    /// Sets [`protocol`](#method.protocol) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_protocol(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.protocol = value.map(|value| value.into());
+        self
+    }
+}
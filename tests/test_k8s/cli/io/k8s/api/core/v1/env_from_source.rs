@@ -0,0 +1,91 @@
+
+/// EnvFromSource represents the source of a set of ConfigMaps
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct EnvFromSource {
+    /// The ConfigMap to select from
+    #[serde(rename = "configMapRef", skip_serializing_if = "Option::is_none")]
+    pub config_map_ref: Option<crate::io::k8s::api::core::v1::config_map_env_source::ConfigMapEnvSource>,
+    /// An optional identifier to prepend to each key in the ConfigMap. Must be a C_IDENTIFIER.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prefix: Option<String>,
+    /// The Secret to select from
+    #[serde(rename = "secretRef", skip_serializing_if = "Option::is_none")]
+    pub secret_ref: Option<crate::io::k8s::api::core::v1::secret_env_source::SecretEnvSource>,
+}
+
+impl EnvFromSource {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> EnvFromSourceBuilder {
+        EnvFromSourceBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> EnvFromSourceBuilder
+    where
+        Self: Clone,
+    {
+        EnvFromSourceBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<EnvFromSource> for EnvFromSourceBuilder {
+    fn into(self) -> EnvFromSource {
+        self.body
+    }
+}
+
+/// Builder for [`EnvFromSource`](./struct.EnvFromSource.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct EnvFromSourceBuilder {
+    body: self::EnvFromSource,
+}
+
+impl EnvFromSourceBuilder {
+    /// The ConfigMap to select from
+    #[inline]
+    pub fn config_map_ref(mut self, value: crate::io::k8s::api::core::v1::config_map_env_source::ConfigMapEnvSource) -> Self {
+        self.body.config_map_ref = Some(value.into());
+        self
+    }
+
+    /// Sets [`config_map_ref`](#method.config_map_ref) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_config_map_ref(mut self, value: Option<crate::io::k8s::api::core::v1::config_map_env_source::ConfigMapEnvSource>) -> Self {
+        self.body.config_map_ref = value.map(|value| value.into());
+        self
+    }
+
+    /// An optional identifier to prepend to each key in the ConfigMap. Must be a C_IDENTIFIER.
+    #[inline]
+    pub fn prefix(mut self, value: impl Into<String>) -> Self {
+        self.body.prefix = Some(value.into());
+        self
+    }
+
+    /// Sets [`prefix`](#method.prefix) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_prefix(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.prefix = value.map(|value| value.into());
+        self
+    }
+
+    /// The Secret to select from
+    #[inline]
+    pub fn secret_ref(mut self, value: crate::io::k8s::api::core::v1::secret_env_source::SecretEnvSource) -> Self {
+        self.body.secret_ref = Some(value.into());
+        self
+    }
+
+    /// Sets [`secret_ref`](#method.secret_ref) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_secret_ref(mut self, value: Option<crate::io::k8s::api::core::v1::secret_env_source::SecretEnvSource>) -> Self {
+        self.body.secret_ref = value.map(|value| value.into());
+        self
+    }
+}
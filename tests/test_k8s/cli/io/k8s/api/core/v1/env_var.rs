@@ -0,0 +1,86 @@
+
+/// EnvVar represents an environment variable present in a Container.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct EnvVar {
+    /// Name of the environment variable. Must be a C_IDENTIFIER.
+    pub name: String,
+    /// Variable references $(VAR_NAME) are expanded using the previous defined environment variables in the container and any service environment variables. If a variable cannot be resolved, the reference in the input string will be unchanged. The $(VAR_NAME) syntax can be escaped with a double $$, ie: $$(VAR_NAME). Escaped references will never be expanded, regardless of whether the variable exists or not. Defaults to "".
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+    /// Source for the environment variable's value. Cannot be used if value is not empty.
+    #[serde(rename = "valueFrom", skip_serializing_if = "Option::is_none")]
+    pub value_from: Option<crate::io::k8s::api::core::v1::env_var_source::EnvVarSource>,
+}
+
+impl EnvVar {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> EnvVarBuilder<crate::generics::MissingName> {
+        EnvVarBuilder {
+            body: Default::default(),
+            _name: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> EnvVarBuilder<crate::generics::NameExists>
+    where
+        Self: Clone,
+    {
+        EnvVarBuilder {
+            body: self.clone(),
+            _name: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<EnvVar> for EnvVarBuilder<crate::generics::NameExists> {
+    fn into(self) -> EnvVar {
+        self.body
+    }
+}
+
+/// Builder for [`EnvVar`](./struct.EnvVar.html) object.
+#[derive(Debug, Clone)]
+pub struct EnvVarBuilder<Name> {
+    body: self::EnvVar,
+    _name: core::marker::PhantomData<Name>,
+}
+
+impl<Name> EnvVarBuilder<Name> {
+    /// Name of the environment variable. Must be a C_IDENTIFIER.
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> EnvVarBuilder<crate::generics::NameExists> {
+        self.body.name = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Variable references $(VAR_NAME) are expanded using the previous defined environment variables in the container and any service environment variables. If a variable cannot be resolved, the reference in the input string will be unchanged. The $(VAR_NAME) syntax can be escaped with a double $$, ie: $$(VAR_NAME). Escaped references will never be expanded, regardless of whether the variable exists or not. Defaults to "".
+    #[inline]
+    pub fn value(mut self, value: impl Into<String>) -> Self {
+        self.body.value = Some(value.into());
+        self
+    }
+
+    /// Sets [`value`](#method.value) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_value(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.value = value.map(|value| value.into());
+        self
+    }
+
+    /// Source for the environment variable's value. Cannot be used if value is not empty.
+    #[inline]
+    pub fn value_from(mut self, value: crate::io::k8s::api::core::v1::env_var_source::EnvVarSource) -> Self {
+        self.body.value_from = Some(value.into());
+        self
+    }
+
+    /// Sets [`value_from`](#method.value_from) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_value_from(mut self, value: Option<crate::io::k8s::api::core::v1::env_var_source::EnvVarSource>) -> Self {
+        self.body.value_from = value.map(|value| value.into());
+        self
+    }
+}
@@ -0,0 +1,108 @@
+
+/// EnvVarSource represents a source for the value of an EnvVar.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct EnvVarSource {
+    /// Selects a key of a ConfigMap.
+    #[serde(rename = "configMapKeyRef", skip_serializing_if = "Option::is_none")]
+    pub config_map_key_ref: Option<crate::io::k8s::api::core::v1::config_map_key_selector::ConfigMapKeySelector>,
+    /// Selects a field of the pod: supports metadata.name, metadata.namespace, metadata.labels, metadata.annotations, spec.nodeName, spec.serviceAccountName, status.hostIP, status.podIP.
+    #[serde(rename = "fieldRef", skip_serializing_if = "Option::is_none")]
+    pub field_ref: Option<crate::io::k8s::api::core::v1::object_field_selector::ObjectFieldSelector>,
+    /// Selects a resource of the container: only resources limits and requests (limits.cpu, limits.memory, limits.ephemeral-storage, requests.cpu, requests.memory and requests.ephemeral-storage) are currently supported.
+    #[serde(rename = "resourceFieldRef", skip_serializing_if = "Option::is_none")]
+    pub resource_field_ref: Option<crate::io::k8s::api::core::v1::resource_field_selector::ResourceFieldSelector>,
+    /// Selects a key of a secret in the pod's namespace
+    #[serde(rename = "secretKeyRef", skip_serializing_if = "Option::is_none")]
+    pub secret_key_ref: Option<crate::io::k8s::api::core::v1::secret_key_selector::SecretKeySelector>,
+}
+
+impl EnvVarSource {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> EnvVarSourceBuilder {
+        EnvVarSourceBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> EnvVarSourceBuilder
+    where
+        Self: Clone,
+    {
+        EnvVarSourceBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<EnvVarSource> for EnvVarSourceBuilder {
+    fn into(self) -> EnvVarSource {
+        self.body
+    }
+}
+
+/// Builder for [`EnvVarSource`](./struct.EnvVarSource.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct EnvVarSourceBuilder {
+    body: self::EnvVarSource,
+}
+
+impl EnvVarSourceBuilder {
+    /// Selects a key of a ConfigMap.
+    #[inline]
+    pub fn config_map_key_ref(mut self, value: crate::io::k8s::api::core::v1::config_map_key_selector::ConfigMapKeySelectorBuilder<crate::generics::KeyExists>) -> Self {
+        self.body.config_map_key_ref = Some(value.into());
+        self
+    }
+
+    /// Sets [`config_map_key_ref`](#method.config_map_key_ref) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_config_map_key_ref(mut self, value: Option<crate::io::k8s::api::core::v1::config_map_key_selector::ConfigMapKeySelectorBuilder<crate::generics::KeyExists>>) -> Self {
+        self.body.config_map_key_ref = value.map(|value| value.into());
+        self
+    }
+
+    /// Selects a field of the pod: supports metadata.name, metadata.namespace, metadata.labels, metadata.annotations, spec.nodeName, spec.serviceAccountName, status.hostIP, status.podIP.
+    #[inline]
+    pub fn field_ref(mut self, value: crate::io::k8s::api::core::v1::object_field_selector::ObjectFieldSelectorBuilder<crate::generics::FieldPathExists>) -> Self {
+        self.body.field_ref = Some(value.into());
+        self
+    }
+
+    /// Sets [`field_ref`](#method.field_ref) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_field_ref(mut self, value: Option<crate::io::k8s::api::core::v1::object_field_selector::ObjectFieldSelectorBuilder<crate::generics::FieldPathExists>>) -> Self {
+        self.body.field_ref = value.map(|value| value.into());
+        self
+    }
+
+    /// Selects a resource of the container: only resources limits and requests (limits.cpu, limits.memory, limits.ephemeral-storage, requests.cpu, requests.memory and requests.ephemeral-storage) are currently supported.
+    #[inline]
+    pub fn resource_field_ref(mut self, value: crate::io::k8s::api::core::v1::resource_field_selector::ResourceFieldSelectorBuilder<crate::generics::ResourceExists>) -> Self {
+        self.body.resource_field_ref = Some(value.into());
+        self
+    }
+
+    /// Sets [`resource_field_ref`](#method.resource_field_ref) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_resource_field_ref(mut self, value: Option<crate::io::k8s::api::core::v1::resource_field_selector::ResourceFieldSelectorBuilder<crate::generics::ResourceExists>>) -> Self {
+        self.body.resource_field_ref = value.map(|value| value.into());
+        self
+    }
+
+    /// Selects a key of a secret in the pod's namespace
+    #[inline]
+    pub fn secret_key_ref(mut self, value: crate::io::k8s::api::core::v1::secret_key_selector::SecretKeySelectorBuilder<crate::generics::KeyExists>) -> Self {
+        self.body.secret_key_ref = Some(value.into());
+        self
+    }
+
+    /// Sets [`secret_key_ref`](#method.secret_key_ref) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_secret_key_ref(mut self, value: Option<crate::io::k8s::api::core::v1::secret_key_selector::SecretKeySelectorBuilder<crate::generics::KeyExists>>) -> Self {
+        self.body.secret_key_ref = value.map(|value| value.into());
+        self
+    }
+}
@@ -0,0 +1,97 @@
+
+/// EventSeries contain information on series of events, i.e. thing that was/is happening continuously for some time.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct EventSeries {
+    /// Number of occurrences in this series up to the last heartbeat time
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub count: Option<i32>,
+    /// Time of the last occurrence observed
+    #[serde(rename = "lastObservedTime", skip_serializing_if = "Option::is_none")]
+    pub last_observed_time: Option<String>,
+    /// State of this Series: Ongoing or Finished Deprecated. Planned removal for 1.18
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<String>,
+}
+
+impl EventSeries {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> EventSeriesBuilder {
+        EventSeriesBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> EventSeriesBuilder
+    where
+        Self: Clone,
+    {
+        EventSeriesBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<EventSeries> for EventSeriesBuilder {
+    fn into(self) -> EventSeries {
+        self.body
+    }
+}
+
+/// Builder for [`EventSeries`](./struct.EventSeries.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct EventSeriesBuilder {
+    body: self::EventSeries,
+}
+
+impl EventSeriesBuilder {
+    /// Number of occurrences in this series up to the last heartbeat time
+    #[inline]
+    pub fn count(mut self, value: impl Into<i32>) -> Self {
+        self.body.count = Some(value.into());
+        self
+    }
+
+    /// Sets [`count`](#method.count) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_count(mut self, value: Option<impl Into<i32>>) -> Self {
+        self.body.count = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`count`](#method.count), but takes a reference.
+    #[inline]
+    pub fn count_ref(self, value: &i32) -> Self {
+        self.count(*value)
+    }
+
+    /// Time of the last occurrence observed
+    #[inline]
+    pub fn last_observed_time(mut self, value: impl Into<String>) -> Self {
+        self.body.last_observed_time = Some(value.into());
+        self
+    }
+
+    /// Sets [`last_observed_time`](#method.last_observed_time) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_last_observed_time(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.last_observed_time = value.map(|value| value.into());
+        self
+    }
+
+    /// State of this Series: Ongoing or Finished Deprecated. Planned removal for 1.18
+    #[inline]
+    pub fn state(mut self, value: impl Into<String>) -> Self {
+        self.body.state = Some(value.into());
+        self
+    }
+
+    /// Sets [`state`](#method.state) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_state(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.state = value.map(|value| value.into());
+        self
+    }
+}
@@ -0,0 +1,151 @@
+
+/// Represents a Fibre Channel volume. Fibre Channel volumes can only be mounted as read/write once. Fibre Channel volumes support ownership management and SELinux relabeling.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct FcVolumeSource {
+    /// Filesystem type to mount. Must be a filesystem type supported by the host operating system. Ex. "ext4", "xfs", "ntfs". Implicitly inferred to be "ext4" if unspecified.
+    #[serde(rename = "fsType", skip_serializing_if = "Option::is_none")]
+    pub fs_type: Option<String>,
+    /// Optional: FC target lun number
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lun: Option<i32>,
+    /// Optional: Defaults to false (read/write). ReadOnly here will force the ReadOnly setting in VolumeMounts.
+    #[serde(rename = "readOnly", skip_serializing_if = "Option::is_none")]
+    pub read_only: Option<bool>,
+    /// Optional: FC target worldwide names (WWNs)
+    #[serde(rename = "targetWWNs", skip_serializing_if = "Option::is_none")]
+    pub target_ww_ns: Option<Vec<String>>,
+    /// Optional: FC volume world wide identifiers (wwids) Either wwids or combination of targetWWNs and lun must be set, but not both simultaneously.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub wwids: Option<Vec<String>>,
+}
+
+impl FcVolumeSource {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> FcVolumeSourceBuilder {
+        FcVolumeSourceBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> FcVolumeSourceBuilder
+    where
+        Self: Clone,
+    {
+        FcVolumeSourceBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<FcVolumeSource> for FcVolumeSourceBuilder {
+    fn into(self) -> FcVolumeSource {
+        self.body
+    }
+}
+
+/// Builder for [`FcVolumeSource`](./struct.FcVolumeSource.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct FcVolumeSourceBuilder {
+    body: self::FcVolumeSource,
+}
+
+impl FcVolumeSourceBuilder {
+    /// Filesystem type to mount. Must be a filesystem type supported by the host operating system. Ex. "ext4", "xfs", "ntfs". Implicitly inferred to be "ext4" if unspecified.
+    #[inline]
+    pub fn fs_type(mut self, value: impl Into<String>) -> Self {
+        self.body.fs_type = Some(value.into());
+        self
+    }
+
+    /// Sets [`fs_type`](#method.fs_type) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_fs_type(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.fs_type = value.map(|value| value.into());
+        self
+    }
+
+    /// Optional: FC target lun number
+    #[inline]
+    pub fn lun(mut self, value: impl Into<i32>) -> Self {
+        self.body.lun = Some(value.into());
+        self
+    }
+
+    /// Sets [`lun`](#method.lun) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_lun(mut self, value: Option<impl Into<i32>>) -> Self {
+        self.body.lun = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`lun`](#method.lun), but takes a reference.
+    #[inline]
+    pub fn lun_ref(self, value: &i32) -> Self {
+        self.lun(*value)
+    }
+
+    /// Optional: Defaults to false (read/write). ReadOnly here will force the ReadOnly setting in VolumeMounts.
+    #[inline]
+    pub fn read_only(mut self, value: impl Into<bool>) -> Self {
+        self.body.read_only = Some(value.into());
+        self
+    }
+
+    /// Sets [`read_only`](#method.read_only) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_read_only(mut self, value: Option<impl Into<bool>>) -> Self {
+        self.body.read_only = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`read_only`](#method.read_only), but takes a reference.
+    #[inline]
+    pub fn read_only_ref(self, value: &bool) -> Self {
+        self.read_only(*value)
+    }
+
+    /// Optional: FC target worldwide names (WWNs)
+    #[inline]
+    pub fn target_ww_ns(mut self, value: impl Iterator<Item = impl Into<String>>) -> Self {
+        self.body.target_ww_ns = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`target_ww_ns`](#method.target_ww_ns) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_target_ww_ns(mut self, value: Option<impl Iterator<Item = impl Into<String>>>) -> Self {
+        self.body.target_ww_ns = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`target_ww_ns`](#method.target_ww_ns), but takes the values as a `Vec`.
+    #[inline]
+    pub fn target_ww_ns_vec(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.body.target_ww_ns = Some(value.into());
+        self
+    }
+
+    /// Optional: FC volume world wide identifiers (wwids) Either wwids or combination of targetWWNs and lun must be set, but not both simultaneously.
+    #[inline]
+    pub fn wwids(mut self, value: impl Iterator<Item = impl Into<String>>) -> Self {
+        self.body.wwids = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`wwids`](#method.wwids) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_wwids(mut self, value: Option<impl Iterator<Item = impl Into<String>>>) -> Self {
+        self.body.wwids = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`wwids`](#method.wwids), but takes the values as a `Vec`.
+    #[inline]
+    pub fn wwids_vec(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.body.wwids = Some(value.into());
+        self
+    }
+}
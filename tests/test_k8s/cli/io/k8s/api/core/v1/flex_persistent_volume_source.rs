@@ -0,0 +1,126 @@
+
+/// FlexPersistentVolumeSource represents a generic persistent volume resource that is provisioned/attached using an exec based plugin.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct FlexPersistentVolumeSource {
+    /// Driver is the name of the driver to use for this volume.
+    pub driver: String,
+    /// Filesystem type to mount. Must be a filesystem type supported by the host operating system. Ex. "ext4", "xfs", "ntfs". The default filesystem depends on FlexVolume script.
+    #[serde(rename = "fsType", skip_serializing_if = "Option::is_none")]
+    pub fs_type: Option<String>,
+    /// Optional: Extra command options if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub options: Option<std::collections::BTreeMap<String, String>>,
+    /// Optional: Defaults to false (read/write). ReadOnly here will force the ReadOnly setting in VolumeMounts.
+    #[serde(rename = "readOnly", skip_serializing_if = "Option::is_none")]
+    pub read_only: Option<bool>,
+    /// Optional: SecretRef is reference to the secret object containing sensitive information to pass to the plugin scripts. This may be empty if no secret object is specified. If the secret object contains more than one secret, all secrets are passed to the plugin scripts.
+    #[serde(rename = "secretRef", skip_serializing_if = "Option::is_none")]
+    pub secret_ref: Option<crate::io::k8s::api::core::v1::secret_reference::SecretReference>,
+}
+
+impl FlexPersistentVolumeSource {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> FlexPersistentVolumeSourceBuilder<crate::generics::MissingDriver> {
+        FlexPersistentVolumeSourceBuilder {
+            body: Default::default(),
+            _driver: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> FlexPersistentVolumeSourceBuilder<crate::generics::DriverExists>
+    where
+        Self: Clone,
+    {
+        FlexPersistentVolumeSourceBuilder {
+            body: self.clone(),
+            _driver: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<FlexPersistentVolumeSource> for FlexPersistentVolumeSourceBuilder<crate::generics::DriverExists> {
+    fn into(self) -> FlexPersistentVolumeSource {
+        self.body
+    }
+}
+
+/// Builder for [`FlexPersistentVolumeSource`](./struct.FlexPersistentVolumeSource.html) object.
+#[derive(Debug, Clone)]
+pub struct FlexPersistentVolumeSourceBuilder<Driver> {
+    body: self::FlexPersistentVolumeSource,
+    _driver: core::marker::PhantomData<Driver>,
+}
+
+impl<Driver> FlexPersistentVolumeSourceBuilder<Driver> {
+    /// Driver is the name of the driver to use for this volume.
+    #[inline]
+    pub fn driver(mut self, value: impl Into<String>) -> FlexPersistentVolumeSourceBuilder<crate::generics::DriverExists> {
+        self.body.driver = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Filesystem type to mount. Must be a filesystem type supported by the host operating system. Ex. "ext4", "xfs", "ntfs". The default filesystem depends on FlexVolume script.
+    #[inline]
+    pub fn fs_type(mut self, value: impl Into<String>) -> Self {
+        self.body.fs_type = Some(value.into());
+        self
+    }
+
+    /// Sets [`fs_type`](#method.fs_type) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_fs_type(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.fs_type = value.map(|value| value.into());
+        self
+    }
+
+    /// Optional: Extra command options if any.
+    #[inline]
+    pub fn options(mut self, value: impl Iterator<Item = (String, impl Into<String>)>) -> Self {
+        self.body.options = Some(value.map(|(key, value)| (key, value.into())).collect::<std::collections::BTreeMap<_, _>>().into());
+        self
+    }
+
+    /// Sets [`options`](#method.options) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_options(mut self, value: Option<impl Iterator<Item = (String, impl Into<String>)>>) -> Self {
+        self.body.options = value.map(|value| value.map(|(key, value)| (key, value.into())).collect::<std::collections::BTreeMap<_, _>>().into());
+        self
+    }
+
+    /// Optional: Defaults to false (read/write). ReadOnly here will force the ReadOnly setting in VolumeMounts.
+    #[inline]
+    pub fn read_only(mut self, value: impl Into<bool>) -> Self {
+        self.body.read_only = Some(value.into());
+        self
+    }
+
+    /// Sets [`read_only`](#method.read_only) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_read_only(mut self, value: Option<impl Into<bool>>) -> Self {
+        self.body.read_only = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`read_only`](#method.read_only), but takes a reference.
+    #[inline]
+    pub fn read_only_ref(self, value: &bool) -> Self {
+        self.read_only(*value)
+    }
+
+    /// Optional: SecretRef is reference to the secret object containing sensitive information to pass to the plugin scripts. This may be empty if no secret object is specified. If the secret object contains more than one secret, all secrets are passed to the plugin scripts.
+    #[inline]
+    pub fn secret_ref(mut self, value: crate::io::k8s::api::core::v1::secret_reference::SecretReference) -> Self {
+        self.body.secret_ref = Some(value.into());
+        self
+    }
+
+    /// Sets [`secret_ref`](#method.secret_ref) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_secret_ref(mut self, value: Option<crate::io::k8s::api::core::v1::secret_reference::SecretReference>) -> Self {
+        self.body.secret_ref = value.map(|value| value.into());
+        self
+    }
+}
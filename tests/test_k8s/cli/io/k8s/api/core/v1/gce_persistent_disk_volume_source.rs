@@ -0,0 +1,118 @@
+
+/// Represents a Persistent Disk resource in Google Compute Engine.
+///
+/// A GCE PD must exist before mounting to a container. The disk must also be in the same GCE project and zone as the kubelet. A GCE PD can only be mounted as read/write once or read-only many times. GCE PDs support ownership management and SELinux relabeling.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct GcePersistentDiskVolumeSource {
+    /// Filesystem type of the volume that you want to mount. Tip: Ensure that the filesystem type is supported by the host operating system. Examples: "ext4", "xfs", "ntfs". Implicitly inferred to be "ext4" if unspecified. More info: https://kubernetes.io/docs/concepts/storage/volumes#gcepersistentdisk
+    #[serde(rename = "fsType", skip_serializing_if = "Option::is_none")]
+    pub fs_type: Option<String>,
+    /// The partition in the volume that you want to mount. If omitted, the default is to mount by volume name. Examples: For volume /dev/sda1, you specify the partition as "1". Similarly, the volume partition for /dev/sda is "0" (or you can leave the property empty). More info: https://kubernetes.io/docs/concepts/storage/volumes#gcepersistentdisk
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub partition: Option<i32>,
+    /// Unique name of the PD resource in GCE. Used to identify the disk in GCE. More info: https://kubernetes.io/docs/concepts/storage/volumes#gcepersistentdisk
+    #[serde(rename = "pdName")]
+    pub pd_name: String,
+    /// ReadOnly here will force the ReadOnly setting in VolumeMounts. Defaults to false. More info: https://kubernetes.io/docs/concepts/storage/volumes#gcepersistentdisk
+    #[serde(rename = "readOnly", skip_serializing_if = "Option::is_none")]
+    pub read_only: Option<bool>,
+}
+
+impl GcePersistentDiskVolumeSource {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> GcePersistentDiskVolumeSourceBuilder<crate::generics::MissingPdName> {
+        GcePersistentDiskVolumeSourceBuilder {
+            body: Default::default(),
+            _pd_name: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> GcePersistentDiskVolumeSourceBuilder<crate::generics::PdNameExists>
+    where
+        Self: Clone,
+    {
+        GcePersistentDiskVolumeSourceBuilder {
+            body: self.clone(),
+            _pd_name: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<GcePersistentDiskVolumeSource> for GcePersistentDiskVolumeSourceBuilder<crate::generics::PdNameExists> {
+    fn into(self) -> GcePersistentDiskVolumeSource {
+        self.body
+    }
+}
+
+/// Builder for [`GcePersistentDiskVolumeSource`](./struct.GcePersistentDiskVolumeSource.html) object.
+#[derive(Debug, Clone)]
+pub struct GcePersistentDiskVolumeSourceBuilder<PdName> {
+    body: self::GcePersistentDiskVolumeSource,
+    _pd_name: core::marker::PhantomData<PdName>,
+}
+
+impl<PdName> GcePersistentDiskVolumeSourceBuilder<PdName> {
+    /// Filesystem type of the volume that you want to mount. Tip: Ensure that the filesystem type is supported by the host operating system. Examples: "ext4", "xfs", "ntfs". Implicitly inferred to be "ext4" if unspecified. More info: https://kubernetes.io/docs/concepts/storage/volumes#gcepersistentdisk
+    #[inline]
+    pub fn fs_type(mut self, value: impl Into<String>) -> Self {
+        self.body.fs_type = Some(value.into());
+        self
+    }
+
+    /// Sets [`fs_type`](#method.fs_type) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_fs_type(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.fs_type = value.map(|value| value.into());
+        self
+    }
+
+    /// The partition in the volume that you want to mount. If omitted, the default is to mount by volume name. Examples: For volume /dev/sda1, you specify the partition as "1". Similarly, the volume partition for /dev/sda is "0" (or you can leave the property empty). More info: https://kubernetes.io/docs/concepts/storage/volumes#gcepersistentdisk
+    #[inline]
+    pub fn partition(mut self, value: impl Into<i32>) -> Self {
+        self.body.partition = Some(value.into());
+        self
+    }
+
+    /// Sets [`partition`](#method.partition) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_partition(mut self, value: Option<impl Into<i32>>) -> Self {
+        self.body.partition = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`partition`](#method.partition), but takes a reference.
+    #[inline]
+    pub fn partition_ref(self, value: &i32) -> Self {
+        self.partition(*value)
+    }
+
+    /// Unique name of the PD resource in GCE. Used to identify the disk in GCE. More info: https://kubernetes.io/docs/concepts/storage/volumes#gcepersistentdisk
+    #[inline]
+    pub fn pd_name(mut self, value: impl Into<String>) -> GcePersistentDiskVolumeSourceBuilder<crate::generics::PdNameExists> {
+        self.body.pd_name = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// ReadOnly here will force the ReadOnly setting in VolumeMounts. Defaults to false. More info: https://kubernetes.io/docs/concepts/storage/volumes#gcepersistentdisk
+    #[inline]
+    pub fn read_only(mut self, value: impl Into<bool>) -> Self {
+        self.body.read_only = Some(value.into());
+        self
+    }
+
+    /// Sets [`read_only`](#method.read_only) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_read_only(mut self, value: Option<impl Into<bool>>) -> Self {
+        self.body.read_only = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`read_only`](#method.read_only), but takes a reference.
+    #[inline]
+    pub fn read_only_ref(self, value: &bool) -> Self {
+        self.read_only(*value)
+    }
+}
@@ -0,0 +1,88 @@
+
+/// Represents a volume that is populated with the contents of a git repository. Git repo volumes do not support ownership management. Git repo volumes support SELinux relabeling.
+///
+/// DEPRECATED: GitRepo is deprecated. To provision a container with a git repo, mount an EmptyDir into an InitContainer that clones the repo using git, then mount the EmptyDir into the Pod's container.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct GitRepoVolumeSource {
+    /// Target directory name. Must not contain or start with '..'.  If '.' is supplied, the volume directory will be the git repository.  Otherwise, if specified, the volume will contain the git repository in the subdirectory with the given name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub directory: Option<String>,
+    /// Repository URL
+    pub repository: String,
+    /// Commit hash for the specified revision.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub revision: Option<String>,
+}
+
+impl GitRepoVolumeSource {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> GitRepoVolumeSourceBuilder<crate::generics::MissingRepository> {
+        GitRepoVolumeSourceBuilder {
+            body: Default::default(),
+            _repository: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> GitRepoVolumeSourceBuilder<crate::generics::RepositoryExists>
+    where
+        Self: Clone,
+    {
+        GitRepoVolumeSourceBuilder {
+            body: self.clone(),
+            _repository: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<GitRepoVolumeSource> for GitRepoVolumeSourceBuilder<crate::generics::RepositoryExists> {
+    fn into(self) -> GitRepoVolumeSource {
+        self.body
+    }
+}
+
+/// Builder for [`GitRepoVolumeSource`](./struct.GitRepoVolumeSource.html) object.
+#[derive(Debug, Clone)]
+pub struct GitRepoVolumeSourceBuilder<Repository> {
+    body: self::GitRepoVolumeSource,
+    _repository: core::marker::PhantomData<Repository>,
+}
+
+impl<Repository> GitRepoVolumeSourceBuilder<Repository> {
+    /// Target directory name. Must not contain or start with '..'.  If '.' is supplied, the volume directory will be the git repository.  Otherwise, if specified, the volume will contain the git repository in the subdirectory with the given name.
+    #[inline]
+    pub fn directory(mut self, value: impl Into<String>) -> Self {
+        self.body.directory = Some(value.into());
+        self
+    }
+
+    /// Sets [`directory`](#method.directory) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_directory(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.directory = value.map(|value| value.into());
+        self
+    }
+
+    /// Repository URL
+    #[inline]
+    pub fn repository(mut self, value: impl Into<String>) -> GitRepoVolumeSourceBuilder<crate::generics::RepositoryExists> {
+        self.body.repository = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Commit hash for the specified revision.
+    #[inline]
+    pub fn revision(mut self, value: impl Into<String>) -> Self {
+        self.body.revision = Some(value.into());
+        self
+    }
+
+    /// Sets [`revision`](#method.revision) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_revision(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.revision = value.map(|value| value.into());
+        self
+    }
+}
@@ -0,0 +1,87 @@
+
+/// Represents a Glusterfs mount that lasts the lifetime of a pod. Glusterfs volumes do not support ownership management or SELinux relabeling.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct GlusterfsVolumeSource {
+    /// EndpointsName is the endpoint name that details Glusterfs topology. More info: https://releases.k8s.io/HEAD/examples/volumes/glusterfs/README.md#create-a-pod
+    pub endpoints: String,
+    /// Path is the Glusterfs volume path. More info: https://releases.k8s.io/HEAD/examples/volumes/glusterfs/README.md#create-a-pod
+    pub path: String,
+    /// ReadOnly here will force the Glusterfs volume to be mounted with read-only permissions. Defaults to false. More info: https://releases.k8s.io/HEAD/examples/volumes/glusterfs/README.md#create-a-pod
+    #[serde(rename = "readOnly", skip_serializing_if = "Option::is_none")]
+    pub read_only: Option<bool>,
+}
+
+impl GlusterfsVolumeSource {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> GlusterfsVolumeSourceBuilder<crate::generics::MissingEndpoints, crate::generics::MissingPath> {
+        GlusterfsVolumeSourceBuilder {
+            body: Default::default(),
+            _endpoints: core::marker::PhantomData,
+            _path: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> GlusterfsVolumeSourceBuilder<crate::generics::EndpointsExists, crate::generics::PathExists>
+    where
+        Self: Clone,
+    {
+        GlusterfsVolumeSourceBuilder {
+            body: self.clone(),
+            _endpoints: core::marker::PhantomData,
+            _path: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<GlusterfsVolumeSource> for GlusterfsVolumeSourceBuilder<crate::generics::EndpointsExists, crate::generics::PathExists> {
+    fn into(self) -> GlusterfsVolumeSource {
+        self.body
+    }
+}
+
+/// Builder for [`GlusterfsVolumeSource`](./struct.GlusterfsVolumeSource.html) object.
+#[derive(Debug, Clone)]
+pub struct GlusterfsVolumeSourceBuilder<Endpoints, Path> {
+    body: self::GlusterfsVolumeSource,
+    _endpoints: core::marker::PhantomData<Endpoints>,
+    _path: core::marker::PhantomData<Path>,
+}
+
+impl<Endpoints, Path> GlusterfsVolumeSourceBuilder<Endpoints, Path> {
+    /// EndpointsName is the endpoint name that details Glusterfs topology. More info: https://releases.k8s.io/HEAD/examples/volumes/glusterfs/README.md#create-a-pod
+    #[inline]
+    pub fn endpoints(mut self, value: impl Into<String>) -> GlusterfsVolumeSourceBuilder<crate::generics::EndpointsExists, Path> {
+        self.body.endpoints = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Path is the Glusterfs volume path. More info: https://releases.k8s.io/HEAD/examples/volumes/glusterfs/README.md#create-a-pod
+    #[inline]
+    pub fn path(mut self, value: impl Into<String>) -> GlusterfsVolumeSourceBuilder<Endpoints, crate::generics::PathExists> {
+        self.body.path = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// ReadOnly here will force the Glusterfs volume to be mounted with read-only permissions. Defaults to false. More info: https://releases.k8s.io/HEAD/examples/volumes/glusterfs/README.md#create-a-pod
+    #[inline]
+    pub fn read_only(mut self, value: impl Into<bool>) -> Self {
+        self.body.read_only = Some(value.into());
+        self
+    }
+
+    /// Sets [`read_only`](#method.read_only) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_read_only(mut self, value: Option<impl Into<bool>>) -> Self {
+        self.body.read_only = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`read_only`](#method.read_only), but takes a reference.
+    #[inline]
+    pub fn read_only_ref(self, value: &bool) -> Self {
+        self.read_only(*value)
+    }
+}
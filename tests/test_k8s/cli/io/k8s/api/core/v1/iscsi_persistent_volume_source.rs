@@ -0,0 +1,244 @@
+
+/// ISCSIPersistentVolumeSource represents an ISCSI disk. ISCSI volumes can only be mounted as read/write once. ISCSI volumes support ownership management and SELinux relabeling.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct IscsiPersistentVolumeSource {
+    /// whether support iSCSI Discovery CHAP authentication
+    #[serde(rename = "chapAuthDiscovery", skip_serializing_if = "Option::is_none")]
+    pub chap_auth_discovery: Option<bool>,
+    /// whether support iSCSI Session CHAP authentication
+    #[serde(rename = "chapAuthSession", skip_serializing_if = "Option::is_none")]
+    pub chap_auth_session: Option<bool>,
+    /// Filesystem type of the volume that you want to mount. Tip: Ensure that the filesystem type is supported by the host operating system. Examples: "ext4", "xfs", "ntfs". Implicitly inferred to be "ext4" if unspecified. More info: https://kubernetes.io/docs/concepts/storage/volumes#iscsi
+    #[serde(rename = "fsType", skip_serializing_if = "Option::is_none")]
+    pub fs_type: Option<String>,
+    /// Custom iSCSI Initiator Name. If initiatorName is specified with iscsiInterface simultaneously, new iSCSI interface \<target portal>:\<volume name> will be created for the connection.
+    #[serde(rename = "initiatorName", skip_serializing_if = "Option::is_none")]
+    pub initiator_name: Option<String>,
+    /// Target iSCSI Qualified Name.
+    pub iqn: String,
+    /// iSCSI Interface Name that uses an iSCSI transport. Defaults to 'default' (tcp).
+    #[serde(rename = "iscsiInterface", skip_serializing_if = "Option::is_none")]
+    pub iscsi_interface: Option<String>,
+    /// iSCSI Target Lun number.
+    pub lun: i32,
+    /// iSCSI Target Portal List. The Portal is either an IP or ip_addr:port if the port is other than default (typically TCP ports 860 and 3260).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub portals: Option<Vec<String>>,
+    /// ReadOnly here will force the ReadOnly setting in VolumeMounts. Defaults to false.
+    #[serde(rename = "readOnly", skip_serializing_if = "Option::is_none")]
+    pub read_only: Option<bool>,
+    /// CHAP Secret for iSCSI target and initiator authentication
+    #[serde(rename = "secretRef", skip_serializing_if = "Option::is_none")]
+    pub secret_ref: Option<crate::io::k8s::api::core::v1::secret_reference::SecretReference>,
+    /// iSCSI Target Portal. The Portal is either an IP or ip_addr:port if the port is other than default (typically TCP ports 860 and 3260).
+    #[serde(rename = "targetPortal")]
+    pub target_portal: String,
+}
+
+impl IscsiPersistentVolumeSource {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> IscsiPersistentVolumeSourceBuilder<crate::generics::MissingIqn, crate::generics::MissingLun, crate::generics::MissingTargetPortal> {
+        IscsiPersistentVolumeSourceBuilder {
+            body: Default::default(),
+            _iqn: core::marker::PhantomData,
+            _lun: core::marker::PhantomData,
+            _target_portal: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> IscsiPersistentVolumeSourceBuilder<crate::generics::IqnExists, crate::generics::LunExists, crate::generics::TargetPortalExists>
+    where
+        Self: Clone,
+    {
+        IscsiPersistentVolumeSourceBuilder {
+            body: self.clone(),
+            _iqn: core::marker::PhantomData,
+            _lun: core::marker::PhantomData,
+            _target_portal: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<IscsiPersistentVolumeSource> for IscsiPersistentVolumeSourceBuilder<crate::generics::IqnExists, crate::generics::LunExists, crate::generics::TargetPortalExists> {
+    fn into(self) -> IscsiPersistentVolumeSource {
+        self.body
+    }
+}
+
+/// Builder for [`IscsiPersistentVolumeSource`](./struct.IscsiPersistentVolumeSource.html) object.
+#[derive(Debug, Clone)]
+pub struct IscsiPersistentVolumeSourceBuilder<Iqn, Lun, TargetPortal> {
+    body: self::IscsiPersistentVolumeSource,
+    _iqn: core::marker::PhantomData<Iqn>,
+    _lun: core::marker::PhantomData<Lun>,
+    _target_portal: core::marker::PhantomData<TargetPortal>,
+}
+
+impl<Iqn, Lun, TargetPortal> IscsiPersistentVolumeSourceBuilder<Iqn, Lun, TargetPortal> {
+    /// whether support iSCSI Discovery CHAP authentication
+    #[inline]
+    pub fn chap_auth_discovery(mut self, value: impl Into<bool>) -> Self {
+        self.body.chap_auth_discovery = Some(value.into());
+        self
+    }
+
+    /// Sets [`chap_auth_discovery`](#method.chap_auth_discovery) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_chap_auth_discovery(mut self, value: Option<impl Into<bool>>) -> Self {
+        self.body.chap_auth_discovery = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`chap_auth_discovery`](#method.chap_auth_discovery), but takes a reference.
+    #[inline]
+    pub fn chap_auth_discovery_ref(self, value: &bool) -> Self {
+        self.chap_auth_discovery(*value)
+    }
+
+    /// whether support iSCSI Session CHAP authentication
+    #[inline]
+    pub fn chap_auth_session(mut self, value: impl Into<bool>) -> Self {
+        self.body.chap_auth_session = Some(value.into());
+        self
+    }
+
+    /// Sets [`chap_auth_session`](#method.chap_auth_session) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_chap_auth_session(mut self, value: Option<impl Into<bool>>) -> Self {
+        self.body.chap_auth_session = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`chap_auth_session`](#method.chap_auth_session), but takes a reference.
+    #[inline]
+    pub fn chap_auth_session_ref(self, value: &bool) -> Self {
+        self.chap_auth_session(*value)
+    }
+
+    /// Filesystem type of the volume that you want to mount. Tip: Ensure that the filesystem type is supported by the host operating system. Examples: "ext4", "xfs", "ntfs". Implicitly inferred to be "ext4" if unspecified. More info: https://kubernetes.io/docs/concepts/storage/volumes#iscsi
+    #[inline]
+    pub fn fs_type(mut self, value: impl Into<String>) -> Self {
+        self.body.fs_type = Some(value.into());
+        self
+    }
+
+    /// Sets [`fs_type`](#method.fs_type) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_fs_type(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.fs_type = value.map(|value| value.into());
+        self
+    }
+
+    /// Custom iSCSI Initiator Name. If initiatorName is specified with iscsiInterface simultaneously, new iSCSI interface \<target portal>:\<volume name> will be created for the connection.
+    #[inline]
+    pub fn initiator_name(mut self, value: impl Into<String>) -> Self {
+        self.body.initiator_name = Some(value.into());
+        self
+    }
+
+    /// Sets [`initiator_name`](#method.initiator_name) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_initiator_name(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.initiator_name = value.map(|value| value.into());
+        self
+    }
+
+    /// Target iSCSI Qualified Name.
+    #[inline]
+    pub fn iqn(mut self, value: impl Into<String>) -> IscsiPersistentVolumeSourceBuilder<crate::generics::IqnExists, Lun, TargetPortal> {
+        self.body.iqn = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// iSCSI Interface Name that uses an iSCSI transport. Defaults to 'default' (tcp).
+    #[inline]
+    pub fn iscsi_interface(mut self, value: impl Into<String>) -> Self {
+        self.body.iscsi_interface = Some(value.into());
+        self
+    }
+
+    /// Sets [`iscsi_interface`](#method.iscsi_interface) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_iscsi_interface(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.iscsi_interface = value.map(|value| value.into());
+        self
+    }
+
+    /// iSCSI Target Lun number.
+    #[inline]
+    pub fn lun(mut self, value: impl Into<i32>) -> IscsiPersistentVolumeSourceBuilder<Iqn, crate::generics::LunExists, TargetPortal> {
+        self.body.lun = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Same as [`lun`](#method.lun), but takes a reference.
+    #[inline]
+    pub fn lun_ref(self, value: &i32) -> IscsiPersistentVolumeSourceBuilder<Iqn, crate::generics::LunExists, TargetPortal> {
+        self.lun(*value)
+    }
+
+    /// iSCSI Target Portal List. The Portal is either an IP or ip_addr:port if the port is other than default (typically TCP ports 860 and 3260).
+    #[inline]
+    pub fn portals(mut self, value: impl Iterator<Item = impl Into<String>>) -> Self {
+        self.body.portals = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`portals`](#method.portals) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_portals(mut self, value: Option<impl Iterator<Item = impl Into<String>>>) -> Self {
+        self.body.portals = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`portals`](#method.portals), but takes the values as a `Vec`.
+    #[inline]
+    pub fn portals_vec(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.body.portals = Some(value.into());
+        self
+    }
+
+    /// ReadOnly here will force the ReadOnly setting in VolumeMounts. Defaults to false.
+    #[inline]
+    pub fn read_only(mut self, value: impl Into<bool>) -> Self {
+        self.body.read_only = Some(value.into());
+        self
+    }
+
+    /// Sets [`read_only`](#method.read_only) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_read_only(mut self, value: Option<impl Into<bool>>) -> Self {
+        self.body.read_only = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`read_only`](#method.read_only), but takes a reference.
+    #[inline]
+    pub fn read_only_ref(self, value: &bool) -> Self {
+        self.read_only(*value)
+    }
+
+    /// CHAP Secret for iSCSI target and initiator authentication
+    #[inline]
+    pub fn secret_ref(mut self, value: crate::io::k8s::api::core::v1::secret_reference::SecretReference) -> Self {
+        self.body.secret_ref = Some(value.into());
+        self
+    }
+
+    /// Sets [`secret_ref`](#method.secret_ref) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_secret_ref(mut self, value: Option<crate::io::k8s::api::core::v1::secret_reference::SecretReference>) -> Self {
+        self.body.secret_ref = value.map(|value| value.into());
+        self
+    }
+
+    /// iSCSI Target Portal. The Portal is either an IP or ip_addr:port if the port is other than default (typically TCP ports 860 and 3260).
+    #[inline]
+    pub fn target_portal(mut self, value: impl Into<String>) -> IscsiPersistentVolumeSourceBuilder<Iqn, Lun, crate::generics::TargetPortalExists> {
+        self.body.target_portal = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+}
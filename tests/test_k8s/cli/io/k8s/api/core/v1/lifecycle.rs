@@ -0,0 +1,74 @@
+
+/// Lifecycle describes actions that the management system should take in response to container lifecycle events. For the PostStart and PreStop lifecycle handlers, management of the container blocks until the action is complete, unless the container process fails, in which case the handler is aborted.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct Lifecycle {
+    /// PostStart is called immediately after a container is created. If the handler fails, the container is terminated and restarted according to its restart policy. Other management of the container blocks until the hook completes. More info: https://kubernetes.io/docs/concepts/containers/container-lifecycle-hooks/#container-hooks
+    #[serde(rename = "postStart", skip_serializing_if = "Option::is_none")]
+    pub post_start: Option<crate::io::k8s::api::core::v1::handler::Handler>,
+    /// PreStop is called immediately before a container is terminated due to an API request or management event such as liveness probe failure, preemption, resource contention, etc. The handler is not called if the container crashes or exits. The reason for termination is passed to the handler. The Pod's termination grace period countdown begins before the PreStop hooked is executed. Regardless of the outcome of the handler, the container will eventually terminate within the Pod's termination grace period. Other management of the container blocks until the hook completes or until the termination grace period is reached. More info: https://kubernetes.io/docs/concepts/containers/container-lifecycle-hooks/#container-hooks
+    #[serde(rename = "preStop", skip_serializing_if = "Option::is_none")]
+    pub pre_stop: Option<crate::io::k8s::api::core::v1::handler::Handler>,
+}
+
+impl Lifecycle {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> LifecycleBuilder {
+        LifecycleBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> LifecycleBuilder
+    where
+        Self: Clone,
+    {
+        LifecycleBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<Lifecycle> for LifecycleBuilder {
+    fn into(self) -> Lifecycle {
+        self.body
+    }
+}
+
+/// Builder for [`Lifecycle`](./struct.Lifecycle.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct LifecycleBuilder {
+    body: self::Lifecycle,
+}
+
+impl LifecycleBuilder {
+    /// PostStart is called immediately after a container is created. If the handler fails, the container is terminated and restarted according to its restart policy. Other management of the container blocks until the hook completes. More info: https://kubernetes.io/docs/concepts/containers/container-lifecycle-hooks/#container-hooks
+    #[inline]
+    pub fn post_start(mut self, value: crate::io::k8s::api::core::v1::handler::Handler) -> Self {
+        self.body.post_start = Some(value.into());
+        self
+    }
+
+    /// Sets [`post_start`](#method.post_start) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_post_start(mut self, value: Option<crate::io::k8s::api::core::v1::handler::Handler>) -> Self {
+        self.body.post_start = value.map(|value| value.into());
+        self
+    }
+
+    /// PreStop is called immediately before a container is terminated due to an API request or management event such as liveness probe failure, preemption, resource contention, etc. The handler is not called if the container crashes or exits. The reason for termination is passed to the handler. The Pod's termination grace period countdown begins before the PreStop hooked is executed. Regardless of the outcome of the handler, the container will eventually terminate within the Pod's termination grace period. Other management of the container blocks until the hook completes or until the termination grace period is reached. More info: https://kubernetes.io/docs/concepts/containers/container-lifecycle-hooks/#container-hooks
+    #[inline]
+    pub fn pre_stop(mut self, value: crate::io::k8s::api::core::v1::handler::Handler) -> Self {
+        self.body.pre_stop = Some(value.into());
+        self
+    }
+
+    /// Sets [`pre_stop`](#method.pre_stop) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_pre_stop(mut self, value: Option<crate::io::k8s::api::core::v1::handler::Handler>) -> Self {
+        self.body.pre_stop = value.map(|value| value.into());
+        self
+    }
+}
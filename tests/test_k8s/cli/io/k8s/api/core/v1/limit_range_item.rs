@@ -0,0 +1,142 @@
+
+/// LimitRangeItem defines a min/max usage limit for any resource that matches on kind.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct LimitRangeItem {
+    /// Default resource requirement limit value by resource name if resource limit is omitted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default: Option<std::collections::BTreeMap<String, String>>,
+    /// DefaultRequest is the default resource requirement request value by resource name if resource request is omitted.
+    #[serde(rename = "defaultRequest", skip_serializing_if = "Option::is_none")]
+    pub default_request: Option<std::collections::BTreeMap<String, String>>,
+    /// Max usage constraints on this kind by resource name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max: Option<std::collections::BTreeMap<String, String>>,
+    /// MaxLimitRequestRatio if specified, the named resource must have a request and limit that are both non-zero where limit divided by request is less than or equal to the enumerated value; this represents the max burst for the named resource.
+    #[serde(rename = "maxLimitRequestRatio", skip_serializing_if = "Option::is_none")]
+    pub max_limit_request_ratio: Option<std::collections::BTreeMap<String, String>>,
+    /// Min usage constraints on this kind by resource name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min: Option<std::collections::BTreeMap<String, String>>,
+    /// Type of resource that this limit applies to.
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub type_: Option<String>,
+}
+
+impl LimitRangeItem {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> LimitRangeItemBuilder {
+        LimitRangeItemBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> LimitRangeItemBuilder
+    where
+        Self: Clone,
+    {
+        LimitRangeItemBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<LimitRangeItem> for LimitRangeItemBuilder {
+    fn into(self) -> LimitRangeItem {
+        self.body
+    }
+}
+
+/// Builder for [`LimitRangeItem`](./struct.LimitRangeItem.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct LimitRangeItemBuilder {
+    body: self::LimitRangeItem,
+}
+
+impl LimitRangeItemBuilder {
+    /// Default resource requirement limit value by resource name if resource limit is omitted.
+    #[inline]
+    pub fn default(mut self, value: impl Iterator<Item = (String, impl Into<String>)>) -> Self {
+        self.body.default = Some(value.map(|(key, value)| (key, value.into())).collect::<std::collections::BTreeMap<_, _>>().into());
+        self
+    }
+
+    /// Sets [`default`](#method.default) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_default(mut self, value: Option<impl Iterator<Item = (String, impl Into<String>)>>) -> Self {
+        self.body.default = value.map(|value| value.map(|(key, value)| (key, value.into())).collect::<std::collections::BTreeMap<_, _>>().into());
+        self
+    }
+
+    /// DefaultRequest is the default resource requirement request value by resource name if resource request is omitted.
+    #[inline]
+    pub fn default_request(mut self, value: impl Iterator<Item = (String, impl Into<String>)>) -> Self {
+        self.body.default_request = Some(value.map(|(key, value)| (key, value.into())).collect::<std::collections::BTreeMap<_, _>>().into());
+        self
+    }
+
+    /// Sets [`default_request`](#method.default_request) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_default_request(mut self, value: Option<impl Iterator<Item = (String, impl Into<String>)>>) -> Self {
+        self.body.default_request = value.map(|value| value.map(|(key, value)| (key, value.into())).collect::<std::collections::BTreeMap<_, _>>().into());
+        self
+    }
+
+    /// Max usage constraints on this kind by resource name.
+    #[inline]
+    pub fn max(mut self, value: impl Iterator<Item = (String, impl Into<String>)>) -> Self {
+        self.body.max = Some(value.map(|(key, value)| (key, value.into())).collect::<std::collections::BTreeMap<_, _>>().into());
+        self
+    }
+
+    /// Sets [`max`](#method.max) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_max(mut self, value: Option<impl Iterator<Item = (String, impl Into<String>)>>) -> Self {
+        self.body.max = value.map(|value| value.map(|(key, value)| (key, value.into())).collect::<std::collections::BTreeMap<_, _>>().into());
+        self
+    }
+
+    /// MaxLimitRequestRatio if specified, the named resource must have a request and limit that are both non-zero where limit divided by request is less than or equal to the enumerated value; this represents the max burst for the named resource.
+    #[inline]
+    pub fn max_limit_request_ratio(mut self, value: impl Iterator<Item = (String, impl Into<String>)>) -> Self {
+        self.body.max_limit_request_ratio = Some(value.map(|(key, value)| (key, value.into())).collect::<std::collections::BTreeMap<_, _>>().into());
+        self
+    }
+
+    /// Sets [`max_limit_request_ratio`](#method.max_limit_request_ratio) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_max_limit_request_ratio(mut self, value: Option<impl Iterator<Item = (String, impl Into<String>)>>) -> Self {
+        self.body.max_limit_request_ratio = value.map(|value| value.map(|(key, value)| (key, value.into())).collect::<std::collections::BTreeMap<_, _>>().into());
+        self
+    }
+
+    /// Min usage constraints on this kind by resource name.
+    #[inline]
+    pub fn min(mut self, value: impl Iterator<Item = (String, impl Into<String>)>) -> Self {
+        self.body.min = Some(value.map(|(key, value)| (key, value.into())).collect::<std::collections::BTreeMap<_, _>>().into());
+        self
+    }
+
+    /// Sets [`min`](#method.min) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_min(mut self, value: Option<impl Iterator<Item = (String, impl Into<String>)>>) -> Self {
+        self.body.min = value.map(|value| value.map(|(key, value)| (key, value.into())).collect::<std::collections::BTreeMap<_, _>>().into());
+        self
+    }
+
+    /// Type of resource that this limit applies to.
+    #[inline]
+    pub fn type_(mut self, value: impl Into<String>) -> Self {
+        self.body.type_ = Some(value.into());
+        self
+    }
+
+    /// Sets [`type_`](#method.type_) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_type(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.type_ = value.map(|value| value.into());
+        self
+    }
+}
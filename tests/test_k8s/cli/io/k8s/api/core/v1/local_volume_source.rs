@@ -0,0 +1,69 @@
+
+/// Local represents directly-attached storage with node affinity (Beta feature)
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct LocalVolumeSource {
+    /// Filesystem type to mount. It applies only when the Path is a block device. Must be a filesystem type supported by the host operating system. Ex. "ext4", "xfs", "ntfs". The default value is to auto-select a fileystem if unspecified.
+    #[serde(rename = "fsType", skip_serializing_if = "Option::is_none")]
+    pub fs_type: Option<String>,
+    /// The full path to the volume on the node. It can be either a directory or block device (disk, partition, ...).
+    pub path: String,
+}
+
+impl LocalVolumeSource {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> LocalVolumeSourceBuilder<crate::generics::MissingPath> {
+        LocalVolumeSourceBuilder {
+            body: Default::default(),
+            _path: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> LocalVolumeSourceBuilder<crate::generics::PathExists>
+    where
+        Self: Clone,
+    {
+        LocalVolumeSourceBuilder {
+            body: self.clone(),
+            _path: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<LocalVolumeSource> for LocalVolumeSourceBuilder<crate::generics::PathExists> {
+    fn into(self) -> LocalVolumeSource {
+        self.body
+    }
+}
+
+/// Builder for [`LocalVolumeSource`](./struct.LocalVolumeSource.html) object.
+#[derive(Debug, Clone)]
+pub struct LocalVolumeSourceBuilder<Path> {
+    body: self::LocalVolumeSource,
+    _path: core::marker::PhantomData<Path>,
+}
+
+impl<Path> LocalVolumeSourceBuilder<Path> {
+    /// Filesystem type to mount. It applies only when the Path is a block device. Must be a filesystem type supported by the host operating system. Ex. "ext4", "xfs", "ntfs". The default value is to auto-select a fileystem if unspecified.
+    #[inline]
+    pub fn fs_type(mut self, value: impl Into<String>) -> Self {
+        self.body.fs_type = Some(value.into());
+        self
+    }
+
+    /// Sets [`fs_type`](#method.fs_type) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_fs_type(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.fs_type = value.map(|value| value.into());
+        self
+    }
+
+    /// The full path to the volume on the node. It can be either a directory or block device (disk, partition, ...).
+    #[inline]
+    pub fn path(mut self, value: impl Into<String>) -> LocalVolumeSourceBuilder<crate::generics::PathExists> {
+        self.body.path = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+}
@@ -0,0 +1,720 @@
+
+pub mod affinity {
+    include!("./affinity.rs");
+}
+
+pub mod attached_volume {
+    include!("./attached_volume.rs");
+}
+
+pub mod aws_elastic_block_store_volume_source {
+    include!("./aws_elastic_block_store_volume_source.rs");
+}
+
+pub mod azure_disk_volume_source {
+    include!("./azure_disk_volume_source.rs");
+}
+
+pub mod azure_file_persistent_volume_source {
+    include!("./azure_file_persistent_volume_source.rs");
+}
+
+pub mod azure_file_volume_source {
+    include!("./azure_file_volume_source.rs");
+}
+
+pub mod binding {
+    include!("./binding.rs");
+}
+
+pub mod capabilities {
+    include!("./capabilities.rs");
+}
+
+pub mod ceph_fs_persistent_volume_source {
+    include!("./ceph_fs_persistent_volume_source.rs");
+}
+
+pub mod ceph_fs_volume_source {
+    include!("./ceph_fs_volume_source.rs");
+}
+
+pub mod cinder_persistent_volume_source {
+    include!("./cinder_persistent_volume_source.rs");
+}
+
+pub mod cinder_volume_source {
+    include!("./cinder_volume_source.rs");
+}
+
+pub mod client_ip_config {
+    include!("./client_ip_config.rs");
+}
+
+pub mod component_condition {
+    include!("./component_condition.rs");
+}
+
+pub mod component_status {
+    include!("./component_status.rs");
+}
+
+pub mod component_status_list {
+    include!("./component_status_list.rs");
+}
+
+pub mod config_map {
+    include!("./config_map.rs");
+}
+
+pub mod config_map_env_source {
+    include!("./config_map_env_source.rs");
+}
+
+pub mod config_map_key_selector {
+    include!("./config_map_key_selector.rs");
+}
+
+pub mod config_map_list {
+    include!("./config_map_list.rs");
+}
+
+pub mod config_map_node_config_source {
+    include!("./config_map_node_config_source.rs");
+}
+
+pub mod config_map_projection {
+    include!("./config_map_projection.rs");
+}
+
+pub mod config_map_volume_source {
+    include!("./config_map_volume_source.rs");
+}
+
+pub mod container {
+    include!("./container.rs");
+}
+
+pub mod container_image {
+    include!("./container_image.rs");
+}
+
+pub mod container_port {
+    include!("./container_port.rs");
+}
+
+pub mod container_state {
+    include!("./container_state.rs");
+}
+
+pub mod container_state_running {
+    include!("./container_state_running.rs");
+}
+
+pub mod container_state_terminated {
+    include!("./container_state_terminated.rs");
+}
+
+pub mod container_state_waiting {
+    include!("./container_state_waiting.rs");
+}
+
+pub mod container_status {
+    include!("./container_status.rs");
+}
+
+pub mod csi_persistent_volume_source {
+    include!("./csi_persistent_volume_source.rs");
+}
+
+pub mod csi_volume_source {
+    include!("./csi_volume_source.rs");
+}
+
+pub mod daemon_endpoint {
+    include!("./daemon_endpoint.rs");
+}
+
+pub mod downward_api_projection {
+    include!("./downward_api_projection.rs");
+}
+
+pub mod downward_api_volume_file {
+    include!("./downward_api_volume_file.rs");
+}
+
+pub mod downward_api_volume_source {
+    include!("./downward_api_volume_source.rs");
+}
+
+pub mod empty_dir_volume_source {
+    include!("./empty_dir_volume_source.rs");
+}
+
+pub mod endpoint_address {
+    include!("./endpoint_address.rs");
+}
+
+pub mod endpoint_port {
+    include!("./endpoint_port.rs");
+}
+
+pub mod endpoint_subset {
+    include!("./endpoint_subset.rs");
+}
+
+pub mod endpoints {
+    include!("./endpoints.rs");
+}
+
+pub mod endpoints_list {
+    include!("./endpoints_list.rs");
+}
+
+pub mod env_from_source {
+    include!("./env_from_source.rs");
+}
+
+pub mod env_var {
+    include!("./env_var.rs");
+}
+
+pub mod env_var_source {
+    include!("./env_var_source.rs");
+}
+
+pub mod event {
+    include!("./event.rs");
+}
+
+pub mod event_list {
+    include!("./event_list.rs");
+}
+
+pub mod event_series {
+    include!("./event_series.rs");
+}
+
+pub mod event_source {
+    include!("./event_source.rs");
+}
+
+pub mod exec_action {
+    include!("./exec_action.rs");
+}
+
+pub mod fc_volume_source {
+    include!("./fc_volume_source.rs");
+}
+
+pub mod flex_persistent_volume_source {
+    include!("./flex_persistent_volume_source.rs");
+}
+
+pub mod flex_volume_source {
+    include!("./flex_volume_source.rs");
+}
+
+pub mod flocker_volume_source {
+    include!("./flocker_volume_source.rs");
+}
+
+pub mod gce_persistent_disk_volume_source {
+    include!("./gce_persistent_disk_volume_source.rs");
+}
+
+pub mod git_repo_volume_source {
+    include!("./git_repo_volume_source.rs");
+}
+
+pub mod glusterfs_persistent_volume_source {
+    include!("./glusterfs_persistent_volume_source.rs");
+}
+
+pub mod glusterfs_volume_source {
+    include!("./glusterfs_volume_source.rs");
+}
+
+pub mod handler {
+    include!("./handler.rs");
+}
+
+pub mod host_alias {
+    include!("./host_alias.rs");
+}
+
+pub mod host_path_volume_source {
+    include!("./host_path_volume_source.rs");
+}
+
+pub mod http_get_action {
+    include!("./http_get_action.rs");
+}
+
+pub mod http_header {
+    include!("./http_header.rs");
+}
+
+pub mod iscsi_persistent_volume_source {
+    include!("./iscsi_persistent_volume_source.rs");
+}
+
+pub mod iscsi_volume_source {
+    include!("./iscsi_volume_source.rs");
+}
+
+pub mod key_to_path {
+    include!("./key_to_path.rs");
+}
+
+pub mod lifecycle {
+    include!("./lifecycle.rs");
+}
+
+pub mod limit_range {
+    include!("./limit_range.rs");
+}
+
+pub mod limit_range_item {
+    include!("./limit_range_item.rs");
+}
+
+pub mod limit_range_list {
+    include!("./limit_range_list.rs");
+}
+
+pub mod limit_range_spec {
+    include!("./limit_range_spec.rs");
+}
+
+pub mod load_balancer_ingress {
+    include!("./load_balancer_ingress.rs");
+}
+
+pub mod load_balancer_status {
+    include!("./load_balancer_status.rs");
+}
+
+pub mod local_object_reference {
+    include!("./local_object_reference.rs");
+}
+
+pub mod local_volume_source {
+    include!("./local_volume_source.rs");
+}
+
+pub mod namespace {
+    include!("./namespace.rs");
+}
+
+pub mod namespace_list {
+    include!("./namespace_list.rs");
+}
+
+pub mod namespace_spec {
+    include!("./namespace_spec.rs");
+}
+
+pub mod namespace_status {
+    include!("./namespace_status.rs");
+}
+
+pub mod nfs_volume_source {
+    include!("./nfs_volume_source.rs");
+}
+
+pub mod node {
+    include!("./node.rs");
+}
+
+pub mod node_address {
+    include!("./node_address.rs");
+}
+
+pub mod node_affinity {
+    include!("./node_affinity.rs");
+}
+
+pub mod node_condition {
+    include!("./node_condition.rs");
+}
+
+pub mod node_config_source {
+    include!("./node_config_source.rs");
+}
+
+pub mod node_config_status {
+    include!("./node_config_status.rs");
+}
+
+pub mod node_daemon_endpoints {
+    include!("./node_daemon_endpoints.rs");
+}
+
+pub mod node_list {
+    include!("./node_list.rs");
+}
+
+pub mod node_selector {
+    include!("./node_selector.rs");
+}
+
+pub mod node_selector_requirement {
+    include!("./node_selector_requirement.rs");
+}
+
+pub mod node_selector_term {
+    include!("./node_selector_term.rs");
+}
+
+pub mod node_spec {
+    include!("./node_spec.rs");
+}
+
+pub mod node_status {
+    include!("./node_status.rs");
+}
+
+pub mod node_system_info {
+    include!("./node_system_info.rs");
+}
+
+pub mod object_field_selector {
+    include!("./object_field_selector.rs");
+}
+
+pub mod object_reference {
+    include!("./object_reference.rs");
+}
+
+pub mod persistent_volume {
+    include!("./persistent_volume.rs");
+}
+
+pub mod persistent_volume_claim {
+    include!("./persistent_volume_claim.rs");
+}
+
+pub mod persistent_volume_claim_condition {
+    include!("./persistent_volume_claim_condition.rs");
+}
+
+pub mod persistent_volume_claim_list {
+    include!("./persistent_volume_claim_list.rs");
+}
+
+pub mod persistent_volume_claim_spec {
+    include!("./persistent_volume_claim_spec.rs");
+}
+
+pub mod persistent_volume_claim_status {
+    include!("./persistent_volume_claim_status.rs");
+}
+
+pub mod persistent_volume_claim_volume_source {
+    include!("./persistent_volume_claim_volume_source.rs");
+}
+
+pub mod persistent_volume_list {
+    include!("./persistent_volume_list.rs");
+}
+
+pub mod persistent_volume_spec {
+    include!("./persistent_volume_spec.rs");
+}
+
+pub mod persistent_volume_status {
+    include!("./persistent_volume_status.rs");
+}
+
+pub mod photon_persistent_disk_volume_source {
+    include!("./photon_persistent_disk_volume_source.rs");
+}
+
+pub mod pod {
+    include!("./pod.rs");
+}
+
+pub mod pod_affinity {
+    include!("./pod_affinity.rs");
+}
+
+pub mod pod_affinity_term {
+    include!("./pod_affinity_term.rs");
+}
+
+pub mod pod_anti_affinity {
+    include!("./pod_anti_affinity.rs");
+}
+
+pub mod pod_condition {
+    include!("./pod_condition.rs");
+}
+
+pub mod pod_dns_config {
+    include!("./pod_dns_config.rs");
+}
+
+pub mod pod_dns_config_option {
+    include!("./pod_dns_config_option.rs");
+}
+
+pub mod pod_list {
+    include!("./pod_list.rs");
+}
+
+pub mod pod_readiness_gate {
+    include!("./pod_readiness_gate.rs");
+}
+
+pub mod pod_security_context {
+    include!("./pod_security_context.rs");
+}
+
+pub mod pod_spec {
+    include!("./pod_spec.rs");
+}
+
+pub mod pod_status {
+    include!("./pod_status.rs");
+}
+
+pub mod pod_template {
+    include!("./pod_template.rs");
+}
+
+pub mod pod_template_list {
+    include!("./pod_template_list.rs");
+}
+
+pub mod pod_template_spec {
+    include!("./pod_template_spec.rs");
+}
+
+pub mod portworx_volume_source {
+    include!("./portworx_volume_source.rs");
+}
+
+pub mod preferred_scheduling_term {
+    include!("./preferred_scheduling_term.rs");
+}
+
+pub mod probe {
+    include!("./probe.rs");
+}
+
+pub mod projected_volume_source {
+    include!("./projected_volume_source.rs");
+}
+
+pub mod quobyte_volume_source {
+    include!("./quobyte_volume_source.rs");
+}
+
+pub mod rbd_persistent_volume_source {
+    include!("./rbd_persistent_volume_source.rs");
+}
+
+pub mod rbd_volume_source {
+    include!("./rbd_volume_source.rs");
+}
+
+pub mod replication_controller {
+    include!("./replication_controller.rs");
+}
+
+pub mod replication_controller_condition {
+    include!("./replication_controller_condition.rs");
+}
+
+pub mod replication_controller_list {
+    include!("./replication_controller_list.rs");
+}
+
+pub mod replication_controller_spec {
+    include!("./replication_controller_spec.rs");
+}
+
+pub mod replication_controller_status {
+    include!("./replication_controller_status.rs");
+}
+
+pub mod resource_field_selector {
+    include!("./resource_field_selector.rs");
+}
+
+pub mod resource_quota {
+    include!("./resource_quota.rs");
+}
+
+pub mod resource_quota_list {
+    include!("./resource_quota_list.rs");
+}
+
+pub mod resource_quota_spec {
+    include!("./resource_quota_spec.rs");
+}
+
+pub mod resource_quota_status {
+    include!("./resource_quota_status.rs");
+}
+
+pub mod resource_requirements {
+    include!("./resource_requirements.rs");
+}
+
+pub mod scale_io_persistent_volume_source {
+    include!("./scale_io_persistent_volume_source.rs");
+}
+
+pub mod scale_io_volume_source {
+    include!("./scale_io_volume_source.rs");
+}
+
+pub mod scope_selector {
+    include!("./scope_selector.rs");
+}
+
+pub mod scoped_resource_selector_requirement {
+    include!("./scoped_resource_selector_requirement.rs");
+}
+
+pub mod se_linux_options {
+    include!("./se_linux_options.rs");
+}
+
+pub mod secret {
+    include!("./secret.rs");
+}
+
+pub mod secret_env_source {
+    include!("./secret_env_source.rs");
+}
+
+pub mod secret_key_selector {
+    include!("./secret_key_selector.rs");
+}
+
+pub mod secret_list {
+    include!("./secret_list.rs");
+}
+
+pub mod secret_projection {
+    include!("./secret_projection.rs");
+}
+
+pub mod secret_reference {
+    include!("./secret_reference.rs");
+}
+
+pub mod secret_volume_source {
+    include!("./secret_volume_source.rs");
+}
+
+pub mod security_context {
+    include!("./security_context.rs");
+}
+
+pub mod service {
+    include!("./service.rs");
+}
+
+pub mod service_account {
+    include!("./service_account.rs");
+}
+
+pub mod service_account_list {
+    include!("./service_account_list.rs");
+}
+
+pub mod service_account_token_projection {
+    include!("./service_account_token_projection.rs");
+}
+
+pub mod service_list {
+    include!("./service_list.rs");
+}
+
+pub mod service_port {
+    include!("./service_port.rs");
+}
+
+pub mod service_spec {
+    include!("./service_spec.rs");
+}
+
+pub mod service_status {
+    include!("./service_status.rs");
+}
+
+pub mod session_affinity_config {
+    include!("./session_affinity_config.rs");
+}
+
+pub mod storage_os_persistent_volume_source {
+    include!("./storage_os_persistent_volume_source.rs");
+}
+
+pub mod storage_os_volume_source {
+    include!("./storage_os_volume_source.rs");
+}
+
+pub mod sysctl {
+    include!("./sysctl.rs");
+}
+
+pub mod taint {
+    include!("./taint.rs");
+}
+
+pub mod tcp_socket_action {
+    include!("./tcp_socket_action.rs");
+}
+
+pub mod toleration {
+    include!("./toleration.rs");
+}
+
+pub mod topology_selector_label_requirement {
+    include!("./topology_selector_label_requirement.rs");
+}
+
+pub mod topology_selector_term {
+    include!("./topology_selector_term.rs");
+}
+
+pub mod typed_local_object_reference {
+    include!("./typed_local_object_reference.rs");
+}
+
+pub mod volume {
+    include!("./volume.rs");
+}
+
+pub mod volume_device {
+    include!("./volume_device.rs");
+}
+
+pub mod volume_mount {
+    include!("./volume_mount.rs");
+}
+
+pub mod volume_node_affinity {
+    include!("./volume_node_affinity.rs");
+}
+
+pub mod volume_projection {
+    include!("./volume_projection.rs");
+}
+
+pub mod vsphere_virtual_disk_volume_source {
+    include!("./vsphere_virtual_disk_volume_source.rs");
+}
+
+pub mod weighted_pod_affinity_term {
+    include!("./weighted_pod_affinity_term.rs");
+}
+
+pub mod windows_security_context_options {
+    include!("./windows_security_context_options.rs");
+}
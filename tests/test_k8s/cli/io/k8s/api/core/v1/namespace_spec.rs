@@ -0,0 +1,64 @@
+
+/// NamespaceSpec describes the attributes on a Namespace.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct NamespaceSpec {
+    /// Finalizers is an opaque list of values that must be empty to permanently remove object from storage. More info: https://kubernetes.io/docs/tasks/administer-cluster/namespaces/
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub finalizers: Option<Vec<String>>,
+}
+
+impl NamespaceSpec {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> NamespaceSpecBuilder {
+        NamespaceSpecBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> NamespaceSpecBuilder
+    where
+        Self: Clone,
+    {
+        NamespaceSpecBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<NamespaceSpec> for NamespaceSpecBuilder {
+    fn into(self) -> NamespaceSpec {
+        self.body
+    }
+}
+
+/// Builder for [`NamespaceSpec`](./struct.NamespaceSpec.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct NamespaceSpecBuilder {
+    body: self::NamespaceSpec,
+}
+
+impl NamespaceSpecBuilder {
+    /// Finalizers is an opaque list of values that must be empty to permanently remove object from storage. More info: https://kubernetes.io/docs/tasks/administer-cluster/namespaces/
+    #[inline]
+    pub fn finalizers(mut self, value: impl Iterator<Item = impl Into<String>>) -> Self {
+        self.body.finalizers = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`finalizers`](#method.finalizers) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_finalizers(mut self, value: Option<impl Iterator<Item = impl Into<String>>>) -> Self {
+        self.body.finalizers = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`finalizers`](#method.finalizers), but takes the values as a `Vec`.
+    #[inline]
+    pub fn finalizers_vec(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.body.finalizers = Some(value.into());
+        self
+    }
+}
@@ -0,0 +1,87 @@
+
+/// Represents an NFS mount that lasts the lifetime of a pod. NFS volumes do not support ownership management or SELinux relabeling.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct NfsVolumeSource {
+    /// Path that is exported by the NFS server. More info: https://kubernetes.io/docs/concepts/storage/volumes#nfs
+    pub path: String,
+    /// ReadOnly here will force the NFS export to be mounted with read-only permissions. Defaults to false. More info: https://kubernetes.io/docs/concepts/storage/volumes#nfs
+    #[serde(rename = "readOnly", skip_serializing_if = "Option::is_none")]
+    pub read_only: Option<bool>,
+    /// Server is the hostname or IP address of the NFS server. More info: https://kubernetes.io/docs/concepts/storage/volumes#nfs
+    pub server: String,
+}
+
+impl NfsVolumeSource {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> NfsVolumeSourceBuilder<crate::generics::MissingPath, crate::generics::MissingServer> {
+        NfsVolumeSourceBuilder {
+            body: Default::default(),
+            _path: core::marker::PhantomData,
+            _server: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> NfsVolumeSourceBuilder<crate::generics::PathExists, crate::generics::ServerExists>
+    where
+        Self: Clone,
+    {
+        NfsVolumeSourceBuilder {
+            body: self.clone(),
+            _path: core::marker::PhantomData,
+            _server: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<NfsVolumeSource> for NfsVolumeSourceBuilder<crate::generics::PathExists, crate::generics::ServerExists> {
+    fn into(self) -> NfsVolumeSource {
+        self.body
+    }
+}
+
+/// Builder for [`NfsVolumeSource`](./struct.NfsVolumeSource.html) object.
+#[derive(Debug, Clone)]
+pub struct NfsVolumeSourceBuilder<Path, Server> {
+    body: self::NfsVolumeSource,
+    _path: core::marker::PhantomData<Path>,
+    _server: core::marker::PhantomData<Server>,
+}
+
+impl<Path, Server> NfsVolumeSourceBuilder<Path, Server> {
+    /// Path that is exported by the NFS server. More info: https://kubernetes.io/docs/concepts/storage/volumes#nfs
+    #[inline]
+    pub fn path(mut self, value: impl Into<String>) -> NfsVolumeSourceBuilder<crate::generics::PathExists, Server> {
+        self.body.path = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// ReadOnly here will force the NFS export to be mounted with read-only permissions. Defaults to false. More info: https://kubernetes.io/docs/concepts/storage/volumes#nfs
+    #[inline]
+    pub fn read_only(mut self, value: impl Into<bool>) -> Self {
+        self.body.read_only = Some(value.into());
+        self
+    }
+
+    /// Sets [`read_only`](#method.read_only) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_read_only(mut self, value: Option<impl Into<bool>>) -> Self {
+        self.body.read_only = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`read_only`](#method.read_only), but takes a reference.
+    #[inline]
+    pub fn read_only_ref(self, value: &bool) -> Self {
+        self.read_only(*value)
+    }
+
+    /// Server is the hostname or IP address of the NFS server. More info: https://kubernetes.io/docs/concepts/storage/volumes#nfs
+    #[inline]
+    pub fn server(mut self, value: impl Into<String>) -> NfsVolumeSourceBuilder<Path, crate::generics::ServerExists> {
+        self.body.server = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+}
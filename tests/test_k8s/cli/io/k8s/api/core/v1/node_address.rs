@@ -0,0 +1,65 @@
+
+/// NodeAddress contains information for the node's address.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct NodeAddress {
+    /// The node address.
+    pub address: String,
+    /// Node address type, one of Hostname, ExternalIP or InternalIP.
+    #[serde(rename = "type")]
+    pub type_: String,
+}
+
+impl NodeAddress {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> NodeAddressBuilder<crate::generics::MissingAddress, crate::generics::MissingType> {
+        NodeAddressBuilder {
+            body: Default::default(),
+            _address: core::marker::PhantomData,
+            _type: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> NodeAddressBuilder<crate::generics::AddressExists, crate::generics::TypeExists>
+    where
+        Self: Clone,
+    {
+        NodeAddressBuilder {
+            body: self.clone(),
+            _address: core::marker::PhantomData,
+            _type: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<NodeAddress> for NodeAddressBuilder<crate::generics::AddressExists, crate::generics::TypeExists> {
+    fn into(self) -> NodeAddress {
+        self.body
+    }
+}
+
+/// Builder for [`NodeAddress`](./struct.NodeAddress.html) object.
+#[derive(Debug, Clone)]
+pub struct NodeAddressBuilder<Address, Type> {
+    body: self::NodeAddress,
+    _address: core::marker::PhantomData<Address>,
+    _type: core::marker::PhantomData<Type>,
+}
+
+impl<Address, Type> NodeAddressBuilder<Address, Type> {
+    /// The node address.
+    #[inline]
+    pub fn address(mut self, value: impl Into<String>) -> NodeAddressBuilder<crate::generics::AddressExists, Type> {
+        self.body.address = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Node address type, one of Hostname, ExternalIP or InternalIP.
+    #[inline]
+    pub fn type_(mut self, value: impl Into<String>) -> NodeAddressBuilder<Address, crate::generics::TypeExists> {
+        self.body.type_ = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+}
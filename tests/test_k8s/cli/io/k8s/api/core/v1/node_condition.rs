@@ -0,0 +1,133 @@
+
+/// NodeCondition contains condition information for a node.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct NodeCondition {
+    /// Last time we got an update on a given condition.
+    #[serde(rename = "lastHeartbeatTime", skip_serializing_if = "Option::is_none")]
+    pub last_heartbeat_time: Option<String>,
+    /// Last time the condition transit from one status to another.
+    #[serde(rename = "lastTransitionTime", skip_serializing_if = "Option::is_none")]
+    pub last_transition_time: Option<String>,
+    /// Human readable message indicating details about last transition.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    /// (brief) reason for the condition's last transition.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+    /// Status of the condition, one of True, False, Unknown.
+    pub status: String,
+    /// Type of node condition.
+    #[serde(rename = "type")]
+    pub type_: String,
+}
+
+impl NodeCondition {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> NodeConditionBuilder<crate::generics::MissingStatus, crate::generics::MissingType> {
+        NodeConditionBuilder {
+            body: Default::default(),
+            _status: core::marker::PhantomData,
+            _type: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> NodeConditionBuilder<crate::generics::StatusExists, crate::generics::TypeExists>
+    where
+        Self: Clone,
+    {
+        NodeConditionBuilder {
+            body: self.clone(),
+            _status: core::marker::PhantomData,
+            _type: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<NodeCondition> for NodeConditionBuilder<crate::generics::StatusExists, crate::generics::TypeExists> {
+    fn into(self) -> NodeCondition {
+        self.body
+    }
+}
+
+/// Builder for [`NodeCondition`](./struct.NodeCondition.html) object.
+#[derive(Debug, Clone)]
+pub struct NodeConditionBuilder<Status, Type> {
+    body: self::NodeCondition,
+    _status: core::marker::PhantomData<Status>,
+    _type: core::marker::PhantomData<Type>,
+}
+
+impl<Status, Type> NodeConditionBuilder<Status, Type> {
+    /// Last time we got an update on a given condition.
+    #[inline]
+    pub fn last_heartbeat_time(mut self, value: impl Into<String>) -> Self {
+        self.body.last_heartbeat_time = Some(value.into());
+        self
+    }
+
+    /// Sets [`last_heartbeat_time`](#method.last_heartbeat_time) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_last_heartbeat_time(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.last_heartbeat_time = value.map(|value| value.into());
+        self
+    }
+
+    /// Last time the condition transit from one status to another.
+    #[inline]
+    pub fn last_transition_time(mut self, value: impl Into<String>) -> Self {
+        self.body.last_transition_time = Some(value.into());
+        self
+    }
+
+    /// Sets [`last_transition_time`](#method.last_transition_time) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_last_transition_time(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.last_transition_time = value.map(|value| value.into());
+        self
+    }
+
+    /// Human readable message indicating details about last transition.
+    #[inline]
+    pub fn message(mut self, value: impl Into<String>) -> Self {
+        self.body.message = Some(value.into());
+        self
+    }
+
+    /// Sets [`message`](#method.message) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_message(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.message = value.map(|value| value.into());
+        self
+    }
+
+    /// (brief) reason for the condition's last transition.
+    #[inline]
+    pub fn reason(mut self, value: impl Into<String>) -> Self {
+        self.body.reason = Some(value.into());
+        self
+    }
+
+    /// Sets [`reason`](#method.reason) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_reason(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.reason = value.map(|value| value.into());
+        self
+    }
+
+    /// Status of the condition, one of True, False, Unknown.
+    #[inline]
+    pub fn status(mut self, value: impl Into<String>) -> NodeConditionBuilder<crate::generics::StatusExists, Type> {
+        self.body.status = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Type of node condition.
+    #[inline]
+    pub fn type_(mut self, value: impl Into<String>) -> NodeConditionBuilder<Status, crate::generics::TypeExists> {
+        self.body.type_ = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+}
@@ -0,0 +1,57 @@
+
+/// NodeConfigSource specifies a source of node configuration. Exactly one subfield (excluding metadata) must be non-nil.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct NodeConfigSource {
+    /// ConfigMap is a reference to a Node's ConfigMap
+    #[serde(rename = "configMap", skip_serializing_if = "Option::is_none")]
+    pub config_map: Option<crate::io::k8s::api::core::v1::config_map_node_config_source::ConfigMapNodeConfigSource>,
+}
+
+impl NodeConfigSource {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> NodeConfigSourceBuilder {
+        NodeConfigSourceBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> NodeConfigSourceBuilder
+    where
+        Self: Clone,
+    {
+        NodeConfigSourceBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<NodeConfigSource> for NodeConfigSourceBuilder {
+    fn into(self) -> NodeConfigSource {
+        self.body
+    }
+}
+
+/// Builder for [`NodeConfigSource`](./struct.NodeConfigSource.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct NodeConfigSourceBuilder {
+    body: self::NodeConfigSource,
+}
+
+impl NodeConfigSourceBuilder {
+    /// ConfigMap is a reference to a Node's ConfigMap
+    #[inline]
+    pub fn config_map(mut self, value: crate::io::k8s::api::core::v1::config_map_node_config_source::ConfigMapNodeConfigSourceBuilder<crate::generics::KubeletConfigKeyExists, crate::generics::NameExists, crate::generics::NamespaceExists>) -> Self {
+        self.body.config_map = Some(value.into());
+        self
+    }
+
+    /// Sets [`config_map`](#method.config_map) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_config_map(mut self, value: Option<crate::io::k8s::api::core::v1::config_map_node_config_source::ConfigMapNodeConfigSourceBuilder<crate::generics::KubeletConfigKeyExists, crate::generics::NameExists, crate::generics::NamespaceExists>>) -> Self {
+        self.body.config_map = value.map(|value| value.into());
+        self
+    }
+}
@@ -0,0 +1,60 @@
+
+/// A node selector represents the union of the results of one or more label queries over a set of nodes; that is, it represents the OR of the selectors represented by the node selector terms.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct NodeSelector {
+    /// Required. A list of node selector terms. The terms are ORed.
+    #[serde(rename = "nodeSelectorTerms")]
+    pub node_selector_terms: Vec<crate::io::k8s::api::core::v1::node_selector_term::NodeSelectorTerm>,
+}
+
+impl NodeSelector {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> NodeSelectorBuilder<crate::generics::MissingNodeSelectorTerms> {
+        NodeSelectorBuilder {
+            body: Default::default(),
+            _node_selector_terms: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> NodeSelectorBuilder<crate::generics::NodeSelectorTermsExists>
+    where
+        Self: Clone,
+    {
+        NodeSelectorBuilder {
+            body: self.clone(),
+            _node_selector_terms: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<NodeSelector> for NodeSelectorBuilder<crate::generics::NodeSelectorTermsExists> {
+    fn into(self) -> NodeSelector {
+        self.body
+    }
+}
+
+/// Builder for [`NodeSelector`](./struct.NodeSelector.html) object.
+#[derive(Debug, Clone)]
+pub struct NodeSelectorBuilder<NodeSelectorTerms> {
+    body: self::NodeSelector,
+    _node_selector_terms: core::marker::PhantomData<NodeSelectorTerms>,
+}
+
+impl<NodeSelectorTerms> NodeSelectorBuilder<NodeSelectorTerms> {
+    /// Required. A list of node selector terms. The terms are ORed.
+    #[inline]
+    pub fn node_selector_terms(mut self, value: impl Iterator<Item = crate::io::k8s::api::core::v1::node_selector_term::NodeSelectorTerm>) -> NodeSelectorBuilder<crate::generics::NodeSelectorTermsExists> {
+        self.body.node_selector_terms = value.map(|value| value.into()).collect::<Vec<_>>().into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Same as [`node_selector_terms`](#method.node_selector_terms), but takes the values as a `Vec`.
+    #[inline]
+    pub fn node_selector_terms_vec(mut self, value: impl Into<Vec<crate::io::k8s::api::core::v1::node_selector_term::NodeSelectorTerm>>) -> NodeSelectorBuilder<crate::generics::NodeSelectorTermsExists> {
+        self.body.node_selector_terms = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+}
@@ -0,0 +1,88 @@
+
+/// A node selector requirement is a selector that contains values, a key, and an operator that relates the key and values.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct NodeSelectorRequirement {
+    /// The label key that the selector applies to.
+    pub key: String,
+    /// Represents a key's relationship to a set of values. Valid operators are In, NotIn, Exists, DoesNotExist. Gt, and Lt.
+    pub operator: String,
+    /// An array of string values. If the operator is In or NotIn, the values array must be non-empty. If the operator is Exists or DoesNotExist, the values array must be empty. If the operator is Gt or Lt, the values array must have a single element, which will be interpreted as an integer. This array is replaced during a strategic merge patch.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub values: Option<Vec<String>>,
+}
+
+impl NodeSelectorRequirement {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> NodeSelectorRequirementBuilder<crate::generics::MissingKey, crate::generics::MissingOperator> {
+        NodeSelectorRequirementBuilder {
+            body: Default::default(),
+            _key: core::marker::PhantomData,
+            _operator: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> NodeSelectorRequirementBuilder<crate::generics::KeyExists, crate::generics::OperatorExists>
+    where
+        Self: Clone,
+    {
+        NodeSelectorRequirementBuilder {
+            body: self.clone(),
+            _key: core::marker::PhantomData,
+            _operator: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<NodeSelectorRequirement> for NodeSelectorRequirementBuilder<crate::generics::KeyExists, crate::generics::OperatorExists> {
+    fn into(self) -> NodeSelectorRequirement {
+        self.body
+    }
+}
+
+/// Builder for [`NodeSelectorRequirement`](./struct.NodeSelectorRequirement.html) object.
+#[derive(Debug, Clone)]
+pub struct NodeSelectorRequirementBuilder<Key, Operator> {
+    body: self::NodeSelectorRequirement,
+    _key: core::marker::PhantomData<Key>,
+    _operator: core::marker::PhantomData<Operator>,
+}
+
+impl<Key, Operator> NodeSelectorRequirementBuilder<Key, Operator> {
+    /// The label key that the selector applies to.
+    #[inline]
+    pub fn key(mut self, value: impl Into<String>) -> NodeSelectorRequirementBuilder<crate::generics::KeyExists, Operator> {
+        self.body.key = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Represents a key's relationship to a set of values. Valid operators are In, NotIn, Exists, DoesNotExist. Gt, and Lt.
+    #[inline]
+    pub fn operator(mut self, value: impl Into<String>) -> NodeSelectorRequirementBuilder<Key, crate::generics::OperatorExists> {
+        self.body.operator = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// An array of string values. If the operator is In or NotIn, the values array must be non-empty. If the operator is Exists or DoesNotExist, the values array must be empty. If the operator is Gt or Lt, the values array must have a single element, which will be interpreted as an integer. This array is replaced during a strategic merge patch.
+    #[inline]
+    pub fn values(mut self, value: impl Iterator<Item = impl Into<String>>) -> Self {
+        self.body.values = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`values`](#method.values) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_values(mut self, value: Option<impl Iterator<Item = impl Into<String>>>) -> Self {
+        self.body.values = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`values`](#method.values), but takes the values as a `Vec`.
+    #[inline]
+    pub fn values_vec(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.body.values = Some(value.into());
+        self
+    }
+}
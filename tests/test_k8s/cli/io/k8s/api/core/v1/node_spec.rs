@@ -0,0 +1,155 @@
+
+/// NodeSpec describes the attributes that a node is created with.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct NodeSpec {
+    /// If specified, the source to get node configuration from The DynamicKubeletConfig feature gate must be enabled for the Kubelet to use this field
+    #[serde(rename = "configSource", skip_serializing_if = "Option::is_none")]
+    pub config_source: Option<crate::io::k8s::api::core::v1::node_config_source::NodeConfigSource>,
+    /// Deprecated. Not all kubelets will set this field. Remove field after 1.13. see: https://issues.k8s.io/61966
+    #[serde(rename = "externalID", skip_serializing_if = "Option::is_none")]
+    pub external_id: Option<String>,
+    /// PodCIDR represents the pod IP range assigned to the node.
+    #[serde(rename = "podCIDR", skip_serializing_if = "Option::is_none")]
+    pub pod_cidr: Option<String>,
+    /// ID of the node assigned by the cloud provider in the format: \<ProviderName>://\<ProviderSpecificNodeID>
+    #[serde(rename = "providerID", skip_serializing_if = "Option::is_none")]
+    pub provider_id: Option<String>,
+    /// If specified, the node's taints.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub taints: Option<Vec<crate::io::k8s::api::core::v1::taint::Taint>>,
+    /// Unschedulable controls node schedulability of new pods. By default, node is schedulable. More info: https://kubernetes.io/docs/concepts/nodes/node/#manual-node-administration
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unschedulable: Option<bool>,
+}
+
+impl NodeSpec {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> NodeSpecBuilder {
+        NodeSpecBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> NodeSpecBuilder
+    where
+        Self: Clone,
+    {
+        NodeSpecBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<NodeSpec> for NodeSpecBuilder {
+    fn into(self) -> NodeSpec {
+        self.body
+    }
+}
+
+/// Builder for [`NodeSpec`](./struct.NodeSpec.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct NodeSpecBuilder {
+    body: self::NodeSpec,
+}
+
+impl NodeSpecBuilder {
+    /// If specified, the source to get node configuration from The DynamicKubeletConfig feature gate must be enabled for the Kubelet to use this field
+    #[inline]
+    pub fn config_source(mut self, value: crate::io::k8s::api::core::v1::node_config_source::NodeConfigSource) -> Self {
+        self.body.config_source = Some(value.into());
+        self
+    }
+
+    /// Sets [`config_source`](#method.config_source) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_config_source(mut self, value: Option<crate::io::k8s::api::core::v1::node_config_source::NodeConfigSource>) -> Self {
+        self.body.config_source = value.map(|value| value.into());
+        self
+    }
+
+    /// Deprecated. Not all kubelets will set this field. Remove field after 1.13. see: https://issues.k8s.io/61966
+    #[inline]
+    pub fn external_id(mut self, value: impl Into<String>) -> Self {
+        self.body.external_id = Some(value.into());
+        self
+    }
+
+    /// Sets [`external_id`](#method.external_id) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_external_id(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.external_id = value.map(|value| value.into());
+        self
+    }
+
+    /// PodCIDR represents the pod IP range assigned to the node.
+    #[inline]
+    pub fn pod_cidr(mut self, value: impl Into<String>) -> Self {
+        self.body.pod_cidr = Some(value.into());
+        self
+    }
+
+    /// Sets [`pod_cidr`](#method.pod_cidr) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_pod_cidr(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.pod_cidr = value.map(|value| value.into());
+        self
+    }
+
+    /// ID of the node assigned by the cloud provider in the format: \<ProviderName>://\<ProviderSpecificNodeID>
+    #[inline]
+    pub fn provider_id(mut self, value: impl Into<String>) -> Self {
+        self.body.provider_id = Some(value.into());
+        self
+    }
+
+    /// Sets [`provider_id`](#method.provider_id) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_provider_id(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.provider_id = value.map(|value| value.into());
+        self
+    }
+
+    /// If specified, the node's taints.
+    #[inline]
+    pub fn taints(mut self, value: impl Iterator<Item = crate::io::k8s::api::core::v1::taint::TaintBuilder<crate::generics::EffectExists, crate::generics::KeyExists>>) -> Self {
+        self.body.taints = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`taints`](#method.taints) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_taints(mut self, value: Option<impl Iterator<Item = crate::io::k8s::api::core::v1::taint::TaintBuilder<crate::generics::EffectExists, crate::generics::KeyExists>>>) -> Self {
+        self.body.taints = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`taints`](#method.taints), but takes the values as a `Vec`.
+    #[inline]
+    pub fn taints_vec(mut self, value: impl Into<Vec<crate::io::k8s::api::core::v1::taint::Taint>>) -> Self {
+        self.body.taints = Some(value.into());
+        self
+    }
+
+    /// Unschedulable controls node schedulability of new pods. By default, node is schedulable. More info: https://kubernetes.io/docs/concepts/nodes/node/#manual-node-administration
+    #[inline]
+    pub fn unschedulable(mut self, value: impl Into<bool>) -> Self {
+        self.body.unschedulable = Some(value.into());
+        self
+    }
+
+    /// Sets [`unschedulable`](#method.unschedulable) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_unschedulable(mut self, value: Option<impl Into<bool>>) -> Self {
+        self.body.unschedulable = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`unschedulable`](#method.unschedulable), but takes a reference.
+    #[inline]
+    pub fn unschedulable_ref(self, value: &bool) -> Self {
+        self.unschedulable(*value)
+    }
+}
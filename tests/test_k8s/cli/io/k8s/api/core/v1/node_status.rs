@@ -0,0 +1,262 @@
+
+/// NodeStatus is information about the current status of a node.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct NodeStatus {
+    /// List of addresses reachable to the node. Queried from cloud provider, if available. More info: https://kubernetes.io/docs/concepts/nodes/node/#addresses
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub addresses: Option<Vec<crate::io::k8s::api::core::v1::node_address::NodeAddress>>,
+    /// Allocatable represents the resources of a node that are available for scheduling. Defaults to Capacity.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allocatable: Option<std::collections::BTreeMap<String, String>>,
+    /// Capacity represents the total resources of a node. More info: https://kubernetes.io/docs/concepts/storage/persistent-volumes#capacity
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub capacity: Option<std::collections::BTreeMap<String, String>>,
+    /// Conditions is an array of current observed node conditions. More info: https://kubernetes.io/docs/concepts/nodes/node/#condition
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub conditions: Option<Vec<crate::io::k8s::api::core::v1::node_condition::NodeCondition>>,
+    /// Status of the config assigned to the node via the dynamic Kubelet config feature.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub config: Option<crate::io::k8s::api::core::v1::node_config_status::NodeConfigStatus>,
+    /// Endpoints of daemons running on the Node.
+    #[serde(rename = "daemonEndpoints", skip_serializing_if = "Option::is_none")]
+    pub daemon_endpoints: Option<crate::io::k8s::api::core::v1::node_daemon_endpoints::NodeDaemonEndpoints>,
+    /// List of container images on this node
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub images: Option<Vec<crate::io::k8s::api::core::v1::container_image::ContainerImage>>,
+    /// Set of ids/uuids to uniquely identify the node. More info: https://kubernetes.io/docs/concepts/nodes/node/#info
+    #[serde(rename = "nodeInfo", skip_serializing_if = "Option::is_none")]
+    pub node_info: Option<crate::io::k8s::api::core::v1::node_system_info::NodeSystemInfo>,
+    /// NodePhase is the recently observed lifecycle phase of the node. More info: https://kubernetes.io/docs/concepts/nodes/node/#phase The field is never populated, and now is deprecated.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phase: Option<String>,
+    /// List of volumes that are attached to the node.
+    #[serde(rename = "volumesAttached", skip_serializing_if = "Option::is_none")]
+    pub volumes_attached: Option<Vec<crate::io::k8s::api::core::v1::attached_volume::AttachedVolume>>,
+    /// List of attachable volumes in use (mounted) by the node.
+    #[serde(rename = "volumesInUse", skip_serializing_if = "Option::is_none")]
+    pub volumes_in_use: Option<Vec<String>>,
+}
+
+impl NodeStatus {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> NodeStatusBuilder {
+        NodeStatusBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> NodeStatusBuilder
+    where
+        Self: Clone,
+    {
+        NodeStatusBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<NodeStatus> for NodeStatusBuilder {
+    fn into(self) -> NodeStatus {
+        self.body
+    }
+}
+
+/// Builder for [`NodeStatus`](./struct.NodeStatus.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct NodeStatusBuilder {
+    body: self::NodeStatus,
+}
+
+impl NodeStatusBuilder {
+    /// List of addresses reachable to the node. Queried from cloud provider, if available. More info: https://kubernetes.io/docs/concepts/nodes/node/#addresses
+    #[inline]
+    pub fn addresses(mut self, value: impl Iterator<Item = crate::io::k8s::api::core::v1::node_address::NodeAddressBuilder<crate::generics::AddressExists, crate::generics::TypeExists>>) -> Self {
+        self.body.addresses = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`addresses`](#method.addresses) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_addresses(mut self, value: Option<impl Iterator<Item = crate::io::k8s::api::core::v1::node_address::NodeAddressBuilder<crate::generics::AddressExists, crate::generics::TypeExists>>>) -> Self {
+        self.body.addresses = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`addresses`](#method.addresses), but takes the values as a `Vec`.
+    #[inline]
+    pub fn addresses_vec(mut self, value: impl Into<Vec<crate::io::k8s::api::core::v1::node_address::NodeAddress>>) -> Self {
+        self.body.addresses = Some(value.into());
+        self
+    }
+
+    /// Allocatable represents the resources of a node that are available for scheduling. Defaults to Capacity.
+    #[inline]
+    pub fn allocatable(mut self, value: impl Iterator<Item = (String, impl Into<String>)>) -> Self {
+        self.body.allocatable = Some(value.map(|(key, value)| (key, value.into())).collect::<std::collections::BTreeMap<_, _>>().into());
+        self
+    }
+
+    /// Sets [`allocatable`](#method.allocatable) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_allocatable(mut self, value: Option<impl Iterator<Item = (String, impl Into<String>)>>) -> Self {
+        self.body.allocatable = value.map(|value| value.map(|(key, value)| (key, value.into())).collect::<std::collections::BTreeMap<_, _>>().into());
+        self
+    }
+
+    /// Capacity represents the total resources of a node. More info: https://kubernetes.io/docs/concepts/storage/persistent-volumes#capacity
+    #[inline]
+    pub fn capacity(mut self, value: impl Iterator<Item = (String, impl Into<String>)>) -> Self {
+        self.body.capacity = Some(value.map(|(key, value)| (key, value.into())).collect::<std::collections::BTreeMap<_, _>>().into());
+        self
+    }
+
+    /// Sets [`capacity`](#method.capacity) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_capacity(mut self, value: Option<impl Iterator<Item = (String, impl Into<String>)>>) -> Self {
+        self.body.capacity = value.map(|value| value.map(|(key, value)| (key, value.into())).collect::<std::collections::BTreeMap<_, _>>().into());
+        self
+    }
+
+    /// Conditions is an array of current observed node conditions. More info: https://kubernetes.io/docs/concepts/nodes/node/#condition
+    #[inline]
+    pub fn conditions(mut self, value: impl Iterator<Item = crate::io::k8s::api::core::v1::node_condition::NodeConditionBuilder<crate::generics::StatusExists, crate::generics::TypeExists>>) -> Self {
+        self.body.conditions = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`conditions`](#method.conditions) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_conditions(mut self, value: Option<impl Iterator<Item = crate::io::k8s::api::core::v1::node_condition::NodeConditionBuilder<crate::generics::StatusExists, crate::generics::TypeExists>>>) -> Self {
+        self.body.conditions = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`conditions`](#method.conditions), but takes the values as a `Vec`.
+    #[inline]
+    pub fn conditions_vec(mut self, value: impl Into<Vec<crate::io::k8s::api::core::v1::node_condition::NodeCondition>>) -> Self {
+        self.body.conditions = Some(value.into());
+        self
+    }
+
+    /// Status of the config assigned to the node via the dynamic Kubelet config feature.
+    #[inline]
+    pub fn config(mut self, value: crate::io::k8s::api::core::v1::node_config_status::NodeConfigStatus) -> Self {
+        self.body.config = Some(value.into());
+        self
+    }
+
+    /// Sets [`config`](#method.config) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_config(mut self, value: Option<crate::io::k8s::api::core::v1::node_config_status::NodeConfigStatus>) -> Self {
+        self.body.config = value.map(|value| value.into());
+        self
+    }
+
+    /// Endpoints of daemons running on the Node.
+    #[inline]
+    pub fn daemon_endpoints(mut self, value: crate::io::k8s::api::core::v1::node_daemon_endpoints::NodeDaemonEndpoints) -> Self {
+        self.body.daemon_endpoints = Some(value.into());
+        self
+    }
+
+    /// Sets [`daemon_endpoints`](#method.daemon_endpoints) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_daemon_endpoints(mut self, value: Option<crate::io::k8s::api::core::v1::node_daemon_endpoints::NodeDaemonEndpoints>) -> Self {
+        self.body.daemon_endpoints = value.map(|value| value.into());
+        self
+    }
+
+    /// List of container images on this node
+    #[inline]
+    pub fn images(mut self, value: impl Iterator<Item = crate::io::k8s::api::core::v1::container_image::ContainerImageBuilder<crate::generics::NamesExists>>) -> Self {
+        self.body.images = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`images`](#method.images) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_images(mut self, value: Option<impl Iterator<Item = crate::io::k8s::api::core::v1::container_image::ContainerImageBuilder<crate::generics::NamesExists>>>) -> Self {
+        self.body.images = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`images`](#method.images), but takes the values as a `Vec`.
+    #[inline]
+    pub fn images_vec(mut self, value: impl Into<Vec<crate::io::k8s::api::core::v1::container_image::ContainerImage>>) -> Self {
+        self.body.images = Some(value.into());
+        self
+    }
+
+    /// Set of ids/uuids to uniquely identify the node. More info: https://kubernetes.io/docs/concepts/nodes/node/#info
+    #[inline]
+    pub fn node_info(mut self, value: crate::io::k8s::api::core::v1::node_system_info::NodeSystemInfoBuilder<crate::generics::ArchitectureExists, crate::generics::BootIdExists, crate::generics::ContainerRuntimeVersionExists, crate::generics::KernelVersionExists, crate::generics::KubeProxyVersionExists, crate::generics::KubeletVersionExists, crate::generics::MachineIdExists, crate::generics::OperatingSystemExists, crate::generics::OsImageExists, crate::generics::SystemUuidExists>) -> Self {
+        self.body.node_info = Some(value.into());
+        self
+    }
+
+    /// Sets [`node_info`](#method.node_info) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_node_info(mut self, value: Option<crate::io::k8s::api::core::v1::node_system_info::NodeSystemInfoBuilder<crate::generics::ArchitectureExists, crate::generics::BootIdExists, crate::generics::ContainerRuntimeVersionExists, crate::generics::KernelVersionExists, crate::generics::KubeProxyVersionExists, crate::generics::KubeletVersionExists, crate::generics::MachineIdExists, crate::generics::OperatingSystemExists, crate::generics::OsImageExists, crate::generics::SystemUuidExists>>) -> Self {
+        self.body.node_info = value.map(|value| value.into());
+        self
+    }
+
+    /// NodePhase is the recently observed lifecycle phase of the node. More info: https://kubernetes.io/docs/concepts/nodes/node/#phase The field is never populated, and now is deprecated.
+    #[inline]
+    pub fn phase(mut self, value: impl Into<String>) -> Self {
+        self.body.phase = Some(value.into());
+        self
+    }
+
+    /// Sets [`phase`](#method.phase) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_phase(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.phase = value.map(|value| value.into());
+        self
+    }
+
+    /// List of volumes that are attached to the node.
+    #[inline]
+    pub fn volumes_attached(mut self, value: impl Iterator<Item = crate::io::k8s::api::core::v1::attached_volume::AttachedVolumeBuilder<crate::generics::DevicePathExists, crate::generics::NameExists>>) -> Self {
+        self.body.volumes_attached = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`volumes_attached`](#method.volumes_attached) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_volumes_attached(mut self, value: Option<impl Iterator<Item = crate::io::k8s::api::core::v1::attached_volume::AttachedVolumeBuilder<crate::generics::DevicePathExists, crate::generics::NameExists>>>) -> Self {
+        self.body.volumes_attached = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`volumes_attached`](#method.volumes_attached), but takes the values as a `Vec`.
+    #[inline]
+    pub fn volumes_attached_vec(mut self, value: impl Into<Vec<crate::io::k8s::api::core::v1::attached_volume::AttachedVolume>>) -> Self {
+        self.body.volumes_attached = Some(value.into());
+        self
+    }
+
+    /// List of attachable volumes in use (mounted) by the node.
+    #[inline]
+    pub fn volumes_in_use(mut self, value: impl Iterator<Item = impl Into<String>>) -> Self {
+        self.body.volumes_in_use = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`volumes_in_use`](#method.volumes_in_use) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_volumes_in_use(mut self, value: Option<impl Iterator<Item = impl Into<String>>>) -> Self {
+        self.body.volumes_in_use = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`volumes_in_use`](#method.volumes_in_use), but takes the values as a `Vec`.
+    #[inline]
+    pub fn volumes_in_use_vec(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.body.volumes_in_use = Some(value.into());
+        self
+    }
+}
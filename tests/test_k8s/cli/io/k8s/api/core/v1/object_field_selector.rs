@@ -0,0 +1,70 @@
+
+/// ObjectFieldSelector selects an APIVersioned field of an object.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct ObjectFieldSelector {
+    /// Version of the schema the FieldPath is written in terms of, defaults to "v1".
+    #[serde(rename = "apiVersion", skip_serializing_if = "Option::is_none")]
+    pub api_version: Option<String>,
+    /// Path of the field to select in the specified API version.
+    #[serde(rename = "fieldPath")]
+    pub field_path: String,
+}
+
+impl ObjectFieldSelector {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> ObjectFieldSelectorBuilder<crate::generics::MissingFieldPath> {
+        ObjectFieldSelectorBuilder {
+            body: Default::default(),
+            _field_path: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> ObjectFieldSelectorBuilder<crate::generics::FieldPathExists>
+    where
+        Self: Clone,
+    {
+        ObjectFieldSelectorBuilder {
+            body: self.clone(),
+            _field_path: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<ObjectFieldSelector> for ObjectFieldSelectorBuilder<crate::generics::FieldPathExists> {
+    fn into(self) -> ObjectFieldSelector {
+        self.body
+    }
+}
+
+/// Builder for [`ObjectFieldSelector`](./struct.ObjectFieldSelector.html) object.
+#[derive(Debug, Clone)]
+pub struct ObjectFieldSelectorBuilder<FieldPath> {
+    body: self::ObjectFieldSelector,
+    _field_path: core::marker::PhantomData<FieldPath>,
+}
+
+impl<FieldPath> ObjectFieldSelectorBuilder<FieldPath> {
+    /// Version of the schema the FieldPath is written in terms of, defaults to "v1".
+    #[inline]
+    pub fn api_version(mut self, value: impl Into<String>) -> Self {
+        self.body.api_version = Some(value.into());
+        self
+    }
+
+    /// Sets [`api_version`](#method.api_version) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_api_version(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.api_version = value.map(|value| value.into());
+        self
+    }
+
+    /// Path of the field to select in the specified API version.
+    #[inline]
+    pub fn field_path(mut self, value: impl Into<String>) -> ObjectFieldSelectorBuilder<crate::generics::FieldPathExists> {
+        self.body.field_path = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+}
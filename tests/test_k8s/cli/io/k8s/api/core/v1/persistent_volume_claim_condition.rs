@@ -0,0 +1,129 @@
+
+/// PersistentVolumeClaimCondition contails details about state of pvc
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct PersistentVolumeClaimCondition {
+    /// Last time we probed the condition.
+    #[serde(rename = "lastProbeTime", skip_serializing_if = "Option::is_none")]
+    pub last_probe_time: Option<String>,
+    /// Last time the condition transitioned from one status to another.
+    #[serde(rename = "lastTransitionTime", skip_serializing_if = "Option::is_none")]
+    pub last_transition_time: Option<String>,
+    /// Human-readable message indicating details about last transition.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    /// Unique, this should be a short, machine understandable string that gives the reason for condition's last transition. If it reports "ResizeStarted" that means the underlying persistent volume is being resized.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+    pub status: String,
+    #[serde(rename = "type")]
+    pub type_: String,
+}
+
+impl PersistentVolumeClaimCondition {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> PersistentVolumeClaimConditionBuilder<crate::generics::MissingStatus, crate::generics::MissingType> {
+        PersistentVolumeClaimConditionBuilder {
+            body: Default::default(),
+            _status: core::marker::PhantomData,
+            _type: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> PersistentVolumeClaimConditionBuilder<crate::generics::StatusExists, crate::generics::TypeExists>
+    where
+        Self: Clone,
+    {
+        PersistentVolumeClaimConditionBuilder {
+            body: self.clone(),
+            _status: core::marker::PhantomData,
+            _type: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<PersistentVolumeClaimCondition> for PersistentVolumeClaimConditionBuilder<crate::generics::StatusExists, crate::generics::TypeExists> {
+    fn into(self) -> PersistentVolumeClaimCondition {
+        self.body
+    }
+}
+
+/// Builder for [`PersistentVolumeClaimCondition`](./struct.PersistentVolumeClaimCondition.html) object.
+#[derive(Debug, Clone)]
+pub struct PersistentVolumeClaimConditionBuilder<Status, Type> {
+    body: self::PersistentVolumeClaimCondition,
+    _status: core::marker::PhantomData<Status>,
+    _type: core::marker::PhantomData<Type>,
+}
+
+impl<Status, Type> PersistentVolumeClaimConditionBuilder<Status, Type> {
+    /// Last time we probed the condition.
+    #[inline]
+    pub fn last_probe_time(mut self, value: impl Into<String>) -> Self {
+        self.body.last_probe_time = Some(value.into());
+        self
+    }
+
+    /// Sets [`last_probe_time`](#method.last_probe_time) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_last_probe_time(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.last_probe_time = value.map(|value| value.into());
+        self
+    }
+
+    /// Last time the condition transitioned from one status to another.
+    #[inline]
+    pub fn last_transition_time(mut self, value: impl Into<String>) -> Self {
+        self.body.last_transition_time = Some(value.into());
+        self
+    }
+
+    /// Sets [`last_transition_time`](#method.last_transition_time) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_last_transition_time(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.last_transition_time = value.map(|value| value.into());
+        self
+    }
+
+    /// Human-readable message indicating details about last transition.
+    #[inline]
+    pub fn message(mut self, value: impl Into<String>) -> Self {
+        self.body.message = Some(value.into());
+        self
+    }
+
+    /// Sets [`message`](#method.message) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_message(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.message = value.map(|value| value.into());
+        self
+    }
+
+    /// Unique, this should be a short, machine understandable string that gives the reason for condition's last transition. If it reports "ResizeStarted" that means the underlying persistent volume is being resized.
+    #[inline]
+    pub fn reason(mut self, value: impl Into<String>) -> Self {
+        self.body.reason = Some(value.into());
+        self
+    }
+
+    /// Sets [`reason`](#method.reason) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_reason(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.reason = value.map(|value| value.into());
+        self
+    }
+
+    #[inline]
+    pub fn status(mut self, value: impl Into<String>) -> PersistentVolumeClaimConditionBuilder<crate::generics::StatusExists, Type> {
+        self.body.status = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    #[inline]
+    pub fn type_(mut self, value: impl Into<String>) -> PersistentVolumeClaimConditionBuilder<Status, crate::generics::TypeExists> {
+        self.body.type_ = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+}
@@ -0,0 +1,166 @@
+
+/// PersistentVolumeClaimSpec describes the common attributes of storage devices and allows a Source for provider-specific attributes
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct PersistentVolumeClaimSpec {
+    /// AccessModes contains the desired access modes the volume should have. More info: https://kubernetes.io/docs/concepts/storage/persistent-volumes#access-modes-1
+    #[serde(rename = "accessModes", skip_serializing_if = "Option::is_none")]
+    pub access_modes: Option<Vec<String>>,
+    /// This field requires the VolumeSnapshotDataSource alpha feature gate to be enabled and currently VolumeSnapshot is the only supported data source. If the provisioner can support VolumeSnapshot data source, it will create a new volume and data will be restored to the volume at the same time. If the provisioner does not support VolumeSnapshot data source, volume will not be created and the failure will be reported as an event. In the future, we plan to support more data source types and the behavior of the provisioner may change.
+    #[serde(rename = "dataSource", skip_serializing_if = "Option::is_none")]
+    pub data_source: Option<crate::io::k8s::api::core::v1::typed_local_object_reference::TypedLocalObjectReference>,
+    /// Resources represents the minimum resources the volume should have. More info: https://kubernetes.io/docs/concepts/storage/persistent-volumes#resources
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resources: Option<crate::io::k8s::api::core::v1::resource_requirements::ResourceRequirements>,
+    /// A label query over volumes to consider for binding.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub selector: Option<crate::io::k8s::apimachinery::pkg::apis::meta::v1::label_selector::LabelSelector>,
+    /// Name of the StorageClass required by the claim. More info: https://kubernetes.io/docs/concepts/storage/persistent-volumes#class-1
+    #[serde(rename = "storageClassName", skip_serializing_if = "Option::is_none")]
+    pub storage_class_name: Option<String>,
+    /// volumeMode defines what type of volume is required by the claim. Value of Filesystem is implied when not included in claim spec. This is a beta feature.
+    #[serde(rename = "volumeMode", skip_serializing_if = "Option::is_none")]
+    pub volume_mode: Option<String>,
+    /// VolumeName is the binding reference to the PersistentVolume backing this claim.
+    #[serde(rename = "volumeName", skip_serializing_if = "Option::is_none")]
+    pub volume_name: Option<String>,
+}
+
+impl PersistentVolumeClaimSpec {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> PersistentVolumeClaimSpecBuilder {
+        PersistentVolumeClaimSpecBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> PersistentVolumeClaimSpecBuilder
+    where
+        Self: Clone,
+    {
+        PersistentVolumeClaimSpecBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<PersistentVolumeClaimSpec> for PersistentVolumeClaimSpecBuilder {
+    fn into(self) -> PersistentVolumeClaimSpec {
+        self.body
+    }
+}
+
+/// Builder for [`PersistentVolumeClaimSpec`](./struct.PersistentVolumeClaimSpec.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct PersistentVolumeClaimSpecBuilder {
+    body: self::PersistentVolumeClaimSpec,
+}
+
+impl PersistentVolumeClaimSpecBuilder {
+    /// AccessModes contains the desired access modes the volume should have. More info: https://kubernetes.io/docs/concepts/storage/persistent-volumes#access-modes-1
+    #[inline]
+    pub fn access_modes(mut self, value: impl Iterator<Item = impl Into<String>>) -> Self {
+        self.body.access_modes = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`access_modes`](#method.access_modes) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_access_modes(mut self, value: Option<impl Iterator<Item = impl Into<String>>>) -> Self {
+        self.body.access_modes = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`access_modes`](#method.access_modes), but takes the values as a `Vec`.
+    #[inline]
+    pub fn access_modes_vec(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.body.access_modes = Some(value.into());
+        self
+    }
+
+    /// This field requires the VolumeSnapshotDataSource alpha feature gate to be enabled and currently VolumeSnapshot is the only supported data source. If the provisioner can support VolumeSnapshot data source, it will create a new volume and data will be restored to the volume at the same time. If the provisioner does not support VolumeSnapshot data source, volume will not be created and the failure will be reported as an event. In the future, we plan to support more data source types and the behavior of the provisioner may change.
+    #[inline]
+    pub fn data_source(mut self, value: crate::io::k8s::api::core::v1::typed_local_object_reference::TypedLocalObjectReferenceBuilder<crate::generics::KindExists, crate::generics::NameExists>) -> Self {
+        self.body.data_source = Some(value.into());
+        self
+    }
+
+    /// Sets [`data_source`](#method.data_source) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_data_source(mut self, value: Option<crate::io::k8s::api::core::v1::typed_local_object_reference::TypedLocalObjectReferenceBuilder<crate::generics::KindExists, crate::generics::NameExists>>) -> Self {
+        self.body.data_source = value.map(|value| value.into());
+        self
+    }
+
+    /// Resources represents the minimum resources the volume should have. More info: https://kubernetes.io/docs/concepts/storage/persistent-volumes#resources
+    #[inline]
+    pub fn resources(mut self, value: crate::io::k8s::api::core::v1::resource_requirements::ResourceRequirements) -> Self {
+        self.body.resources = Some(value.into());
+        self
+    }
+
+    /// Sets [`resources`](#method.resources) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_resources(mut self, value: Option<crate::io::k8s::api::core::v1::resource_requirements::ResourceRequirements>) -> Self {
+        self.body.resources = value.map(|value| value.into());
+        self
+    }
+
+    /// A label query over volumes to consider for binding.
+    #[inline]
+    pub fn selector(mut self, value: crate::io::k8s::apimachinery::pkg::apis::meta::v1::label_selector::LabelSelector) -> Self {
+        self.body.selector = Some(value.into());
+        self
+    }
+
+    /// Sets [`selector`](#method.selector) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_selector(mut self, value: Option<crate::io::k8s::apimachinery::pkg::apis::meta::v1::label_selector::LabelSelector>) -> Self {
+        self.body.selector = value.map(|value| value.into());
+        self
+    }
+
+    /// Name of the StorageClass required by the claim. More info: https://kubernetes.io/docs/concepts/storage/persistent-volumes#class-1
+    #[inline]
+    pub fn storage_class_name(mut self, value: impl Into<String>) -> Self {
+        self.body.storage_class_name = Some(value.into());
+        self
+    }
+
+    /// Sets [`storage_class_name`](#method.storage_class_name) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_storage_class_name(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.storage_class_name = value.map(|value| value.into());
+        self
+    }
+
+    /// volumeMode defines what type of volume is required by the claim. Value of Filesystem is implied when not included in claim spec. This is a beta feature.
+    #[inline]
+    pub fn volume_mode(mut self, value: impl Into<String>) -> Self {
+        self.body.volume_mode = Some(value.into());
+        self
+    }
+
+    /// Sets [`volume_mode`](#method.volume_mode) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_volume_mode(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.volume_mode = value.map(|value| value.into());
+        self
+    }
+
+    /// VolumeName is the binding reference to the PersistentVolume backing this claim.
+    #[inline]
+    pub fn volume_name(mut self, value: impl Into<String>) -> Self {
+        self.body.volume_name = Some(value.into());
+        self
+    }
+
+    /// Sets [`volume_name`](#method.volume_name) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_volume_name(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.volume_name = value.map(|value| value.into());
+        self
+    }
+}
@@ -0,0 +1,122 @@
+
+/// PersistentVolumeClaimStatus is the current status of a persistent volume claim.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct PersistentVolumeClaimStatus {
+    /// AccessModes contains the actual access modes the volume backing the PVC has. More info: https://kubernetes.io/docs/concepts/storage/persistent-volumes#access-modes-1
+    #[serde(rename = "accessModes", skip_serializing_if = "Option::is_none")]
+    pub access_modes: Option<Vec<String>>,
+    /// Represents the actual resources of the underlying volume.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub capacity: Option<std::collections::BTreeMap<String, String>>,
+    /// Current Condition of persistent volume claim. If underlying persistent volume is being resized then the Condition will be set to 'ResizeStarted'.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub conditions: Option<Vec<crate::io::k8s::api::core::v1::persistent_volume_claim_condition::PersistentVolumeClaimCondition>>,
+    /// Phase represents the current phase of PersistentVolumeClaim.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phase: Option<String>,
+}
+
+impl PersistentVolumeClaimStatus {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> PersistentVolumeClaimStatusBuilder {
+        PersistentVolumeClaimStatusBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> PersistentVolumeClaimStatusBuilder
+    where
+        Self: Clone,
+    {
+        PersistentVolumeClaimStatusBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<PersistentVolumeClaimStatus> for PersistentVolumeClaimStatusBuilder {
+    fn into(self) -> PersistentVolumeClaimStatus {
+        self.body
+    }
+}
+
+/// Builder for [`PersistentVolumeClaimStatus`](./struct.PersistentVolumeClaimStatus.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct PersistentVolumeClaimStatusBuilder {
+    body: self::PersistentVolumeClaimStatus,
+}
+
+impl PersistentVolumeClaimStatusBuilder {
+    /// AccessModes contains the actual access modes the volume backing the PVC has. More info: https://kubernetes.io/docs/concepts/storage/persistent-volumes#access-modes-1
+    #[inline]
+    pub fn access_modes(mut self, value: impl Iterator<Item = impl Into<String>>) -> Self {
+        self.body.access_modes = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`access_modes`](#method.access_modes) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_access_modes(mut self, value: Option<impl Iterator<Item = impl Into<String>>>) -> Self {
+        self.body.access_modes = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`access_modes`](#method.access_modes), but takes the values as a `Vec`.
+    #[inline]
+    pub fn access_modes_vec(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.body.access_modes = Some(value.into());
+        self
+    }
+
+    /// Represents the actual resources of the underlying volume.
+    #[inline]
+    pub fn capacity(mut self, value: impl Iterator<Item = (String, impl Into<String>)>) -> Self {
+        self.body.capacity = Some(value.map(|(key, value)| (key, value.into())).collect::<std::collections::BTreeMap<_, _>>().into());
+        self
+    }
+
+    /// Sets [`capacity`](#method.capacity) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_capacity(mut self, value: Option<impl Iterator<Item = (String, impl Into<String>)>>) -> Self {
+        self.body.capacity = value.map(|value| value.map(|(key, value)| (key, value.into())).collect::<std::collections::BTreeMap<_, _>>().into());
+        self
+    }
+
+    /// Current Condition of persistent volume claim. If underlying persistent volume is being resized then the Condition will be set to 'ResizeStarted'.
+    #[inline]
+    pub fn conditions(mut self, value: impl Iterator<Item = crate::io::k8s::api::core::v1::persistent_volume_claim_condition::PersistentVolumeClaimConditionBuilder<crate::generics::StatusExists, crate::generics::TypeExists>>) -> Self {
+        self.body.conditions = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`conditions`](#method.conditions) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_conditions(mut self, value: Option<impl Iterator<Item = crate::io::k8s::api::core::v1::persistent_volume_claim_condition::PersistentVolumeClaimConditionBuilder<crate::generics::StatusExists, crate::generics::TypeExists>>>) -> Self {
+        self.body.conditions = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`conditions`](#method.conditions), but takes the values as a `Vec`.
+    #[inline]
+    pub fn conditions_vec(mut self, value: impl Into<Vec<crate::io::k8s::api::core::v1::persistent_volume_claim_condition::PersistentVolumeClaimCondition>>) -> Self {
+        self.body.conditions = Some(value.into());
+        self
+    }
+
+    /// Phase represents the current phase of PersistentVolumeClaim.
+    #[inline]
+    pub fn phase(mut self, value: impl Into<String>) -> Self {
+        self.body.phase = Some(value.into());
+        self
+    }
+
+    /// Sets [`phase`](#method.phase) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_phase(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.phase = value.map(|value| value.into());
+        self
+    }
+}
@@ -0,0 +1,70 @@
+
+/// Represents a Photon Controller persistent disk resource.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct PhotonPersistentDiskVolumeSource {
+    /// Filesystem type to mount. Must be a filesystem type supported by the host operating system. Ex. "ext4", "xfs", "ntfs". Implicitly inferred to be "ext4" if unspecified.
+    #[serde(rename = "fsType", skip_serializing_if = "Option::is_none")]
+    pub fs_type: Option<String>,
+    /// ID that identifies Photon Controller persistent disk
+    #[serde(rename = "pdID")]
+    pub pd_id: String,
+}
+
+impl PhotonPersistentDiskVolumeSource {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> PhotonPersistentDiskVolumeSourceBuilder<crate::generics::MissingPdId> {
+        PhotonPersistentDiskVolumeSourceBuilder {
+            body: Default::default(),
+            _pd_id: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> PhotonPersistentDiskVolumeSourceBuilder<crate::generics::PdIdExists>
+    where
+        Self: Clone,
+    {
+        PhotonPersistentDiskVolumeSourceBuilder {
+            body: self.clone(),
+            _pd_id: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<PhotonPersistentDiskVolumeSource> for PhotonPersistentDiskVolumeSourceBuilder<crate::generics::PdIdExists> {
+    fn into(self) -> PhotonPersistentDiskVolumeSource {
+        self.body
+    }
+}
+
+/// Builder for [`PhotonPersistentDiskVolumeSource`](./struct.PhotonPersistentDiskVolumeSource.html) object.
+#[derive(Debug, Clone)]
+pub struct PhotonPersistentDiskVolumeSourceBuilder<PdId> {
+    body: self::PhotonPersistentDiskVolumeSource,
+    _pd_id: core::marker::PhantomData<PdId>,
+}
+
+impl<PdId> PhotonPersistentDiskVolumeSourceBuilder<PdId> {
+    /// Filesystem type to mount. Must be a filesystem type supported by the host operating system. Ex. "ext4", "xfs", "ntfs". Implicitly inferred to be "ext4" if unspecified.
+    #[inline]
+    pub fn fs_type(mut self, value: impl Into<String>) -> Self {
+        self.body.fs_type = Some(value.into());
+        self
+    }
+
+    /// Sets [`fs_type`](#method.fs_type) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_fs_type(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.fs_type = value.map(|value| value.into());
+        self
+    }
+
+    /// ID that identifies Photon Controller persistent disk
+    #[inline]
+    pub fn pd_id(mut self, value: impl Into<String>) -> PhotonPersistentDiskVolumeSourceBuilder<crate::generics::PdIdExists> {
+        self.body.pd_id = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+}
@@ -0,0 +1,72 @@
+
+/// PodDNSConfigOption defines DNS resolver options of a pod.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct PodDnsConfigOption {
+    /// Required.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+}
+
+impl PodDnsConfigOption {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> PodDnsConfigOptionBuilder {
+        PodDnsConfigOptionBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> PodDnsConfigOptionBuilder
+    where
+        Self: Clone,
+    {
+        PodDnsConfigOptionBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<PodDnsConfigOption> for PodDnsConfigOptionBuilder {
+    fn into(self) -> PodDnsConfigOption {
+        self.body
+    }
+}
+
+/// Builder for [`PodDnsConfigOption`](./struct.PodDnsConfigOption.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct PodDnsConfigOptionBuilder {
+    body: self::PodDnsConfigOption,
+}
+
+impl PodDnsConfigOptionBuilder {
+    /// Required.
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> Self {
+        self.body.name = Some(value.into());
+        self
+    }
+
+    /// Sets [`name`](#method.name) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_name(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.name = value.map(|value| value.into());
+        self
+    }
+
+    #[inline]
+    pub fn value(mut self, value: impl Into<String>) -> Self {
+        self.body.value = Some(value.into());
+        self
+    }
+
+    /// Sets [`value`](#method.value) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_value(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.value = value.map(|value| value.into());
+        self
+    }
+}
@@ -0,0 +1,53 @@
+
+/// PodReadinessGate contains the reference to a pod condition
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct PodReadinessGate {
+    /// ConditionType refers to a condition in the pod's condition list with matching type.
+    #[serde(rename = "conditionType")]
+    pub condition_type: String,
+}
+
+impl PodReadinessGate {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> PodReadinessGateBuilder<crate::generics::MissingConditionType> {
+        PodReadinessGateBuilder {
+            body: Default::default(),
+            _condition_type: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> PodReadinessGateBuilder<crate::generics::ConditionTypeExists>
+    where
+        Self: Clone,
+    {
+        PodReadinessGateBuilder {
+            body: self.clone(),
+            _condition_type: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<PodReadinessGate> for PodReadinessGateBuilder<crate::generics::ConditionTypeExists> {
+    fn into(self) -> PodReadinessGate {
+        self.body
+    }
+}
+
+/// Builder for [`PodReadinessGate`](./struct.PodReadinessGate.html) object.
+#[derive(Debug, Clone)]
+pub struct PodReadinessGateBuilder<ConditionType> {
+    body: self::PodReadinessGate,
+    _condition_type: core::marker::PhantomData<ConditionType>,
+}
+
+impl<ConditionType> PodReadinessGateBuilder<ConditionType> {
+    /// ConditionType refers to a condition in the pod's condition list with matching type.
+    #[inline]
+    pub fn condition_type(mut self, value: impl Into<String>) -> PodReadinessGateBuilder<crate::generics::ConditionTypeExists> {
+        self.body.condition_type = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+}
@@ -0,0 +1,222 @@
+
+/// PodSecurityContext holds pod-level security attributes and common container settings. Some fields are also present in container.securityContext.  Field values of container.securityContext take precedence over field values of PodSecurityContext.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct PodSecurityContext {
+    /// A special supplemental group that applies to all containers in a pod. Some volume types allow the Kubelet to change the ownership of that volume to be owned by the pod:
+    ///
+    /// 1. The owning GID will be the FSGroup 2. The setgid bit is set (new files created in the volume will be owned by FSGroup) 3. The permission bits are OR'd with rw-rw----
+    ///
+    /// If unset, the Kubelet will not modify the ownership and permissions of any volume.
+    #[serde(rename = "fsGroup", skip_serializing_if = "Option::is_none")]
+    pub fs_group: Option<i64>,
+    /// The GID to run the entrypoint of the container process. Uses runtime default if unset. May also be set in SecurityContext.  If set in both SecurityContext and PodSecurityContext, the value specified in SecurityContext takes precedence for that container.
+    #[serde(rename = "runAsGroup", skip_serializing_if = "Option::is_none")]
+    pub run_as_group: Option<i64>,
+    /// Indicates that the container must run as a non-root user. If true, the Kubelet will validate the image at runtime to ensure that it does not run as UID 0 (root) and fail to start the container if it does. If unset or false, no such validation will be performed. May also be set in SecurityContext.  If set in both SecurityContext and PodSecurityContext, the value specified in SecurityContext takes precedence.
+    #[serde(rename = "runAsNonRoot", skip_serializing_if = "Option::is_none")]
+    pub run_as_non_root: Option<bool>,
+    /// The UID to run the entrypoint of the container process. Defaults to user specified in image metadata if unspecified. May also be set in SecurityContext.  If set in both SecurityContext and PodSecurityContext, the value specified in SecurityContext takes precedence for that container.
+    #[serde(rename = "runAsUser", skip_serializing_if = "Option::is_none")]
+    pub run_as_user: Option<i64>,
+    /// The SELinux context to be applied to all containers. If unspecified, the container runtime will allocate a random SELinux context for each container.  May also be set in SecurityContext.  If set in both SecurityContext and PodSecurityContext, the value specified in SecurityContext takes precedence for that container.
+    #[serde(rename = "seLinuxOptions", skip_serializing_if = "Option::is_none")]
+    pub se_linux_options: Option<crate::io::k8s::api::core::v1::se_linux_options::SeLinuxOptions>,
+    /// A list of groups applied to the first process run in each container, in addition to the container's primary GID.  If unspecified, no groups will be added to any container.
+    #[serde(rename = "supplementalGroups", skip_serializing_if = "Option::is_none")]
+    pub supplemental_groups: Option<Vec<i64>>,
+    /// Sysctls hold a list of namespaced sysctls used for the pod. Pods with unsupported sysctls (by the container runtime) might fail to launch.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sysctls: Option<Vec<crate::io::k8s::api::core::v1::sysctl::Sysctl>>,
+    /// Windows security options.
+    #[serde(rename = "windowsOptions", skip_serializing_if = "Option::is_none")]
+    pub windows_options: Option<crate::io::k8s::api::core::v1::windows_security_context_options::WindowsSecurityContextOptions>,
+}
+
+impl PodSecurityContext {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> PodSecurityContextBuilder {
+        PodSecurityContextBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> PodSecurityContextBuilder
+    where
+        Self: Clone,
+    {
+        PodSecurityContextBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<PodSecurityContext> for PodSecurityContextBuilder {
+    fn into(self) -> PodSecurityContext {
+        self.body
+    }
+}
+
+/// Builder for [`PodSecurityContext`](./struct.PodSecurityContext.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct PodSecurityContextBuilder {
+    body: self::PodSecurityContext,
+}
+
+impl PodSecurityContextBuilder {
+    /// A special supplemental group that applies to all containers in a pod. Some volume types allow the Kubelet to change the ownership of that volume to be owned by the pod:
+    ///
+    /// 1. The owning GID will be the FSGroup 2. The setgid bit is set (new files created in the volume will be owned by FSGroup) 3. The permission bits are OR'd with rw-rw----
+    ///
+    /// If unset, the Kubelet will not modify the ownership and permissions of any volume.
+    #[inline]
+    pub fn fs_group(mut self, value: impl Into<i64>) -> Self {
+        self.body.fs_group = Some(value.into());
+        self
+    }
+
+    /// Sets [`fs_group`](#method.fs_group) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_fs_group(mut self, value: Option<impl Into<i64>>) -> Self {
+        self.body.fs_group = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`fs_group`](#method.fs_group), but takes a reference.
+    #[inline]
+    pub fn fs_group_ref(self, value: &i64) -> Self {
+        self.fs_group(*value)
+    }
+
+    /// The GID to run the entrypoint of the container process. Uses runtime default if unset. May also be set in SecurityContext.  If set in both SecurityContext and PodSecurityContext, the value specified in SecurityContext takes precedence for that container.
+    #[inline]
+    pub fn run_as_group(mut self, value: impl Into<i64>) -> Self {
+        self.body.run_as_group = Some(value.into());
+        self
+    }
+
+    /// Sets [`run_as_group`](#method.run_as_group) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_run_as_group(mut self, value: Option<impl Into<i64>>) -> Self {
+        self.body.run_as_group = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`run_as_group`](#method.run_as_group), but takes a reference.
+    #[inline]
+    pub fn run_as_group_ref(self, value: &i64) -> Self {
+        self.run_as_group(*value)
+    }
+
+    /// Indicates that the container must run as a non-root user. If true, the Kubelet will validate the image at runtime to ensure that it does not run as UID 0 (root) and fail to start the container if it does. If unset or false, no such validation will be performed. May also be set in SecurityContext.  If set in both SecurityContext and PodSecurityContext, the value specified in SecurityContext takes precedence.
+    #[inline]
+    pub fn run_as_non_root(mut self, value: impl Into<bool>) -> Self {
+        self.body.run_as_non_root = Some(value.into());
+        self
+    }
+
+    /// Sets [`run_as_non_root`](#method.run_as_non_root) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_run_as_non_root(mut self, value: Option<impl Into<bool>>) -> Self {
+        self.body.run_as_non_root = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`run_as_non_root`](#method.run_as_non_root), but takes a reference.
+    #[inline]
+    pub fn run_as_non_root_ref(self, value: &bool) -> Self {
+        self.run_as_non_root(*value)
+    }
+
+    /// The UID to run the entrypoint of the container process. Defaults to user specified in image metadata if unspecified. May also be set in SecurityContext.  If set in both SecurityContext and PodSecurityContext, the value specified in SecurityContext takes precedence for that container.
+    #[inline]
+    pub fn run_as_user(mut self, value: impl Into<i64>) -> Self {
+        self.body.run_as_user = Some(value.into());
+        self
+    }
+
+    /// Sets [`run_as_user`](#method.run_as_user) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_run_as_user(mut self, value: Option<impl Into<i64>>) -> Self {
+        self.body.run_as_user = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`run_as_user`](#method.run_as_user), but takes a reference.
+    #[inline]
+    pub fn run_as_user_ref(self, value: &i64) -> Self {
+        self.run_as_user(*value)
+    }
+
+    /// The SELinux context to be applied to all containers. If unspecified, the container runtime will allocate a random SELinux context for each container.  May also be set in SecurityContext.  If set in both SecurityContext and PodSecurityContext, the value specified in SecurityContext takes precedence for that container.
+    #[inline]
+    pub fn se_linux_options(mut self, value: crate::io::k8s::api::core::v1::se_linux_options::SeLinuxOptions) -> Self {
+        self.body.se_linux_options = Some(value.into());
+        self
+    }
+
+    /// Sets [`se_linux_options`](#method.se_linux_options) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_se_linux_options(mut self, value: Option<crate::io::k8s::api::core::v1::se_linux_options::SeLinuxOptions>) -> Self {
+        self.body.se_linux_options = value.map(|value| value.into());
+        self
+    }
+
+    /// A list of groups applied to the first process run in each container, in addition to the container's primary GID.  If unspecified, no groups will be added to any container.
+    #[inline]
+    pub fn supplemental_groups(mut self, value: impl Iterator<Item = impl Into<i64>>) -> Self {
+        self.body.supplemental_groups = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`supplemental_groups`](#method.supplemental_groups) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_supplemental_groups(mut self, value: Option<impl Iterator<Item = impl Into<i64>>>) -> Self {
+        self.body.supplemental_groups = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`supplemental_groups`](#method.supplemental_groups), but takes the values as a `Vec`.
+    #[inline]
+    pub fn supplemental_groups_vec(mut self, value: impl Into<Vec<i64>>) -> Self {
+        self.body.supplemental_groups = Some(value.into());
+        self
+    }
+
+    /// Sysctls hold a list of namespaced sysctls used for the pod. Pods with unsupported sysctls (by the container runtime) might fail to launch.
+    #[inline]
+    pub fn sysctls(mut self, value: impl Iterator<Item = crate::io::k8s::api::core::v1::sysctl::SysctlBuilder<crate::generics::NameExists, crate::generics::ValueExists>>) -> Self {
+        self.body.sysctls = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`sysctls`](#method.sysctls) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_sysctls(mut self, value: Option<impl Iterator<Item = crate::io::k8s::api::core::v1::sysctl::SysctlBuilder<crate::generics::NameExists, crate::generics::ValueExists>>>) -> Self {
+        self.body.sysctls = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`sysctls`](#method.sysctls), but takes the values as a `Vec`.
+    #[inline]
+    pub fn sysctls_vec(mut self, value: impl Into<Vec<crate::io::k8s::api::core::v1::sysctl::Sysctl>>) -> Self {
+        self.body.sysctls = Some(value.into());
+        self
+    }
+
+    /// Windows security options.
+    #[inline]
+    pub fn windows_options(mut self, value: crate::io::k8s::api::core::v1::windows_security_context_options::WindowsSecurityContextOptions) -> Self {
+        self.body.windows_options = Some(value.into());
+        self
+    }
+
+    /// Sets [`windows_options`](#method.windows_options) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_windows_options(mut self, value: Option<crate::io::k8s::api::core::v1::windows_security_context_options::WindowsSecurityContextOptions>) -> Self {
+        self.body.windows_options = value.map(|value| value.into());
+        self
+    }
+}
@@ -0,0 +1,74 @@
+
+/// PodTemplateSpec describes the data a pod should have when created from a template
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct PodTemplateSpec {
+    /// Standard object's metadata. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#metadata
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<crate::io::k8s::apimachinery::pkg::apis::meta::v1::object_meta::ObjectMeta>,
+    /// Specification of the desired behavior of the pod. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#spec-and-status
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub spec: Option<crate::io::k8s::api::core::v1::pod_spec::PodSpec>,
+}
+
+impl PodTemplateSpec {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> PodTemplateSpecBuilder {
+        PodTemplateSpecBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> PodTemplateSpecBuilder
+    where
+        Self: Clone,
+    {
+        PodTemplateSpecBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<PodTemplateSpec> for PodTemplateSpecBuilder {
+    fn into(self) -> PodTemplateSpec {
+        self.body
+    }
+}
+
+/// Builder for [`PodTemplateSpec`](./struct.PodTemplateSpec.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct PodTemplateSpecBuilder {
+    body: self::PodTemplateSpec,
+}
+
+impl PodTemplateSpecBuilder {
+    /// Standard object's metadata. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#metadata
+    #[inline]
+    pub fn metadata(mut self, value: crate::io::k8s::apimachinery::pkg::apis::meta::v1::object_meta::ObjectMeta) -> Self {
+        self.body.metadata = Some(value.into());
+        self
+    }
+
+    /// Sets [`metadata`](#method.metadata) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_metadata(mut self, value: Option<crate::io::k8s::apimachinery::pkg::apis::meta::v1::object_meta::ObjectMeta>) -> Self {
+        self.body.metadata = value.map(|value| value.into());
+        self
+    }
+
+    /// Specification of the desired behavior of the pod. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#spec-and-status
+    #[inline]
+    pub fn spec(mut self, value: crate::io::k8s::api::core::v1::pod_spec::PodSpecBuilder<crate::generics::ContainersExists>) -> Self {
+        self.body.spec = Some(value.into());
+        self
+    }
+
+    /// Sets [`spec`](#method.spec) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_spec(mut self, value: Option<crate::io::k8s::api::core::v1::pod_spec::PodSpecBuilder<crate::generics::ContainersExists>>) -> Self {
+        self.body.spec = value.map(|value| value.into());
+        self
+    }
+}
@@ -0,0 +1,70 @@
+
+/// An empty preferred scheduling term matches all objects with implicit weight 0 (i.e. it's a no-op). A null preferred scheduling term matches no objects (i.e. is also a no-op).
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct PreferredSchedulingTerm {
+    /// A node selector term, associated with the corresponding weight.
+    pub preference: crate::io::k8s::api::core::v1::node_selector_term::NodeSelectorTerm,
+    /// Weight associated with matching the corresponding nodeSelectorTerm, in the range 1-100.
+    pub weight: i32,
+}
+
+impl PreferredSchedulingTerm {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> PreferredSchedulingTermBuilder<crate::generics::MissingPreference, crate::generics::MissingWeight> {
+        PreferredSchedulingTermBuilder {
+            body: Default::default(),
+            _preference: core::marker::PhantomData,
+            _weight: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> PreferredSchedulingTermBuilder<crate::generics::PreferenceExists, crate::generics::WeightExists>
+    where
+        Self: Clone,
+    {
+        PreferredSchedulingTermBuilder {
+            body: self.clone(),
+            _preference: core::marker::PhantomData,
+            _weight: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<PreferredSchedulingTerm> for PreferredSchedulingTermBuilder<crate::generics::PreferenceExists, crate::generics::WeightExists> {
+    fn into(self) -> PreferredSchedulingTerm {
+        self.body
+    }
+}
+
+/// Builder for [`PreferredSchedulingTerm`](./struct.PreferredSchedulingTerm.html) object.
+#[derive(Debug, Clone)]
+pub struct PreferredSchedulingTermBuilder<Preference, Weight> {
+    body: self::PreferredSchedulingTerm,
+    _preference: core::marker::PhantomData<Preference>,
+    _weight: core::marker::PhantomData<Weight>,
+}
+
+impl<Preference, Weight> PreferredSchedulingTermBuilder<Preference, Weight> {
+    /// A node selector term, associated with the corresponding weight.
+    #[inline]
+    pub fn preference(mut self, value: crate::io::k8s::api::core::v1::node_selector_term::NodeSelectorTerm) -> PreferredSchedulingTermBuilder<crate::generics::PreferenceExists, Weight> {
+        self.body.preference = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Weight associated with matching the corresponding nodeSelectorTerm, in the range 1-100.
+    #[inline]
+    pub fn weight(mut self, value: impl Into<i32>) -> PreferredSchedulingTermBuilder<Preference, crate::generics::WeightExists> {
+        self.body.weight = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Same as [`weight`](#method.weight), but takes a reference.
+    #[inline]
+    pub fn weight_ref(self, value: &i32) -> PreferredSchedulingTermBuilder<Preference, crate::generics::WeightExists> {
+        self.weight(*value)
+    }
+}
@@ -0,0 +1,206 @@
+
+/// Probe describes a health check to be performed against a container to determine whether it is alive or ready to receive traffic.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct Probe {
+    /// One and only one of the following should be specified. Exec specifies the action to take.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exec: Option<crate::io::k8s::api::core::v1::exec_action::ExecAction>,
+    /// Minimum consecutive failures for the probe to be considered failed after having succeeded. Defaults to 3. Minimum value is 1.
+    #[serde(rename = "failureThreshold", skip_serializing_if = "Option::is_none")]
+    pub failure_threshold: Option<i32>,
+    /// HTTPGet specifies the http request to perform.
+    #[serde(rename = "httpGet", skip_serializing_if = "Option::is_none")]
+    pub http_get: Option<crate::io::k8s::api::core::v1::http_get_action::HttpGetAction>,
+    /// Number of seconds after the container has started before liveness probes are initiated. More info: https://kubernetes.io/docs/concepts/workloads/pods/pod-lifecycle#container-probes
+    #[serde(rename = "initialDelaySeconds", skip_serializing_if = "Option::is_none")]
+    pub initial_delay_seconds: Option<i32>,
+    /// How often (in seconds) to perform the probe. Default to 10 seconds. Minimum value is 1.
+    #[serde(rename = "periodSeconds", skip_serializing_if = "Option::is_none")]
+    pub period_seconds: Option<i32>,
+    /// Minimum consecutive successes for the probe to be considered successful after having failed. Defaults to 1. Must be 1 for liveness. Minimum value is 1.
+    #[serde(rename = "successThreshold", skip_serializing_if = "Option::is_none")]
+    pub success_threshold: Option<i32>,
+    /// TCPSocket specifies an action involving a TCP port. TCP hooks not yet supported
+    #[serde(rename = "tcpSocket", skip_serializing_if = "Option::is_none")]
+    pub tcp_socket: Option<crate::io::k8s::api::core::v1::tcp_socket_action::TcpSocketAction>,
+    /// Number of seconds after which the probe times out. Defaults to 1 second. Minimum value is 1. More info: https://kubernetes.io/docs/concepts/workloads/pods/pod-lifecycle#container-probes
+    #[serde(rename = "timeoutSeconds", skip_serializing_if = "Option::is_none")]
+    pub timeout_seconds: Option<i32>,
+}
+
+impl Probe {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> ProbeBuilder {
+        ProbeBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> ProbeBuilder
+    where
+        Self: Clone,
+    {
+        ProbeBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<Probe> for ProbeBuilder {
+    fn into(self) -> Probe {
+        self.body
+    }
+}
+
+/// Builder for [`Probe`](./struct.Probe.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct ProbeBuilder {
+    body: self::Probe,
+}
+
+impl ProbeBuilder {
+    /// One and only one of the following should be specified. Exec specifies the action to take.
+    #[inline]
+    pub fn exec(mut self, value: crate::io::k8s::api::core::v1::exec_action::ExecAction) -> Self {
+        self.body.exec = Some(value.into());
+        self
+    }
+
+    /// Sets [`exec`](#method.exec) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_exec(mut self, value: Option<crate::io::k8s::api::core::v1::exec_action::ExecAction>) -> Self {
+        self.body.exec = value.map(|value| value.into());
+        self
+    }
+
+    /// Minimum consecutive failures for the probe to be considered failed after having succeeded. Defaults to 3. Minimum value is 1.
+    #[inline]
+    pub fn failure_threshold(mut self, value: impl Into<i32>) -> Self {
+        self.body.failure_threshold = Some(value.into());
+        self
+    }
+
+    /// Sets [`failure_threshold`](#method.failure_threshold) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_failure_threshold(mut self, value: Option<impl Into<i32>>) -> Self {
+        self.body.failure_threshold = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`failure_threshold`](#method.failure_threshold), but takes a reference.
+    #[inline]
+    pub fn failure_threshold_ref(self, value: &i32) -> Self {
+        self.failure_threshold(*value)
+    }
+
+    /// HTTPGet specifies the http request to perform.
+    #[inline]
+    pub fn http_get(mut self, value: crate::io::k8s::api::core::v1::http_get_action::HttpGetActionBuilder<crate::generics::PortExists>) -> Self {
+        self.body.http_get = Some(value.into());
+        self
+    }
+
+    /// Sets [`http_get`](#method.http_get) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_http_get(mut self, value: Option<crate::io::k8s::api::core::v1::http_get_action::HttpGetActionBuilder<crate::generics::PortExists>>) -> Self {
+        self.body.http_get = value.map(|value| value.into());
+        self
+    }
+
+    /// Number of seconds after the container has started before liveness probes are initiated. More info: https://kubernetes.io/docs/concepts/workloads/pods/pod-lifecycle#container-probes
+    #[inline]
+    pub fn initial_delay_seconds(mut self, value: impl Into<i32>) -> Self {
+        self.body.initial_delay_seconds = Some(value.into());
+        self
+    }
+
+    /// Sets [`initial_delay_seconds`](#method.initial_delay_seconds) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_initial_delay_seconds(mut self, value: Option<impl Into<i32>>) -> Self {
+        self.body.initial_delay_seconds = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`initial_delay_seconds`](#method.initial_delay_seconds), but takes a reference.
+    #[inline]
+    pub fn initial_delay_seconds_ref(self, value: &i32) -> Self {
+        self.initial_delay_seconds(*value)
+    }
+
+    /// How often (in seconds) to perform the probe. Default to 10 seconds. Minimum value is 1.
+    #[inline]
+    pub fn period_seconds(mut self, value: impl Into<i32>) -> Self {
+        self.body.period_seconds = Some(value.into());
+        self
+    }
+
+    /// Sets [`period_seconds`](#method.period_seconds) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_period_seconds(mut self, value: Option<impl Into<i32>>) -> Self {
+        self.body.period_seconds = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`period_seconds`](#method.period_seconds), but takes a reference.
+    #[inline]
+    pub fn period_seconds_ref(self, value: &i32) -> Self {
+        self.period_seconds(*value)
+    }
+
+    /// Minimum consecutive successes for the probe to be considered successful after having failed. Defaults to 1. Must be 1 for liveness. Minimum value is 1.
+    #[inline]
+    pub fn success_threshold(mut self, value: impl Into<i32>) -> Self {
+        self.body.success_threshold = Some(value.into());
+        self
+    }
+
+    /// Sets [`success_threshold`](#method.success_threshold) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_success_threshold(mut self, value: Option<impl Into<i32>>) -> Self {
+        self.body.success_threshold = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`success_threshold`](#method.success_threshold), but takes a reference.
+    #[inline]
+    pub fn success_threshold_ref(self, value: &i32) -> Self {
+        self.success_threshold(*value)
+    }
+
+    /// TCPSocket specifies an action involving a TCP port. TCP hooks not yet supported
+    #[inline]
+    pub fn tcp_socket(mut self, value: crate::io::k8s::api::core::v1::tcp_socket_action::TcpSocketActionBuilder<crate::generics::PortExists>) -> Self {
+        self.body.tcp_socket = Some(value.into());
+        self
+    }
+
+    /// Sets [`tcp_socket`](#method.tcp_socket) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_tcp_socket(mut self, value: Option<crate::io::k8s::api::core::v1::tcp_socket_action::TcpSocketActionBuilder<crate::generics::PortExists>>) -> Self {
+        self.body.tcp_socket = value.map(|value| value.into());
+        self
+    }
+
+    /// Number of seconds after which the probe times out. Defaults to 1 second. Minimum value is 1. More info: https://kubernetes.io/docs/concepts/workloads/pods/pod-lifecycle#container-probes
+    #[inline]
+    pub fn timeout_seconds(mut self, value: impl Into<i32>) -> Self {
+        self.body.timeout_seconds = Some(value.into());
+        self
+    }
+
+    /// Sets [`timeout_seconds`](#method.timeout_seconds) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_timeout_seconds(mut self, value: Option<impl Into<i32>>) -> Self {
+        self.body.timeout_seconds = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`timeout_seconds`](#method.timeout_seconds), but takes a reference.
+    #[inline]
+    pub fn timeout_seconds_ref(self, value: &i32) -> Self {
+        self.timeout_seconds(*value)
+    }
+}
@@ -0,0 +1,179 @@
+
+/// Represents a Rados Block Device mount that lasts the lifetime of a pod. RBD volumes support ownership management and SELinux relabeling.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct RbdVolumeSource {
+    /// Filesystem type of the volume that you want to mount. Tip: Ensure that the filesystem type is supported by the host operating system. Examples: "ext4", "xfs", "ntfs". Implicitly inferred to be "ext4" if unspecified. More info: https://kubernetes.io/docs/concepts/storage/volumes#rbd
+    #[serde(rename = "fsType", skip_serializing_if = "Option::is_none")]
+    pub fs_type: Option<String>,
+    /// The rados image name. More info: https://releases.k8s.io/HEAD/examples/volumes/rbd/README.md#how-to-use-it
+    pub image: String,
+    /// Keyring is the path to key ring for RBDUser. Default is /etc/ceph/keyring. More info: https://releases.k8s.io/HEAD/examples/volumes/rbd/README.md#how-to-use-it
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub keyring: Option<String>,
+    /// A collection of Ceph monitors. More info: https://releases.k8s.io/HEAD/examples/volumes/rbd/README.md#how-to-use-it
+    pub monitors: Vec<String>,
+    /// The rados pool name. Default is rbd. More info: https://releases.k8s.io/HEAD/examples/volumes/rbd/README.md#how-to-use-it
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pool: Option<String>,
+    /// ReadOnly here will force the ReadOnly setting in VolumeMounts. Defaults to false. More info: https://releases.k8s.io/HEAD/examples/volumes/rbd/README.md#how-to-use-it
+    #[serde(rename = "readOnly", skip_serializing_if = "Option::is_none")]
+    pub read_only: Option<bool>,
+    /// SecretRef is name of the authentication secret for RBDUser. If provided overrides keyring. Default is nil. More info: https://releases.k8s.io/HEAD/examples/volumes/rbd/README.md#how-to-use-it
+    #[serde(rename = "secretRef", skip_serializing_if = "Option::is_none")]
+    pub secret_ref: Option<crate::io::k8s::api::core::v1::local_object_reference::LocalObjectReference>,
+    /// The rados user name. Default is admin. More info: https://releases.k8s.io/HEAD/examples/volumes/rbd/README.md#how-to-use-it
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<String>,
+}
+
+impl RbdVolumeSource {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> RbdVolumeSourceBuilder<crate::generics::MissingImage, crate::generics::MissingMonitors> {
+        RbdVolumeSourceBuilder {
+            body: Default::default(),
+            _image: core::marker::PhantomData,
+            _monitors: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> RbdVolumeSourceBuilder<crate::generics::ImageExists, crate::generics::MonitorsExists>
+    where
+        Self: Clone,
+    {
+        RbdVolumeSourceBuilder {
+            body: self.clone(),
+            _image: core::marker::PhantomData,
+            _monitors: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<RbdVolumeSource> for RbdVolumeSourceBuilder<crate::generics::ImageExists, crate::generics::MonitorsExists> {
+    fn into(self) -> RbdVolumeSource {
+        self.body
+    }
+}
+
+/// Builder for [`RbdVolumeSource`](./struct.RbdVolumeSource.html) object.
+#[derive(Debug, Clone)]
+pub struct RbdVolumeSourceBuilder<Image, Monitors> {
+    body: self::RbdVolumeSource,
+    _image: core::marker::PhantomData<Image>,
+    _monitors: core::marker::PhantomData<Monitors>,
+}
+
+impl<Image, Monitors> RbdVolumeSourceBuilder<Image, Monitors> {
+    /// Filesystem type of the volume that you want to mount. Tip: Ensure that the filesystem type is supported by the host operating system. Examples: "ext4", "xfs", "ntfs". Implicitly inferred to be "ext4" if unspecified. More info: https://kubernetes.io/docs/concepts/storage/volumes#rbd
+    #[inline]
+    pub fn fs_type(mut self, value: impl Into<String>) -> Self {
+        self.body.fs_type = Some(value.into());
+        self
+    }
+
+    /// Sets [`fs_type`](#method.fs_type) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_fs_type(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.fs_type = value.map(|value| value.into());
+        self
+    }
+
+    /// The rados image name. More info: https://releases.k8s.io/HEAD/examples/volumes/rbd/README.md#how-to-use-it
+    #[inline]
+    pub fn image(mut self, value: impl Into<String>) -> RbdVolumeSourceBuilder<crate::generics::ImageExists, Monitors> {
+        self.body.image = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Keyring is the path to key ring for RBDUser. Default is /etc/ceph/keyring. More info: https://releases.k8s.io/HEAD/examples/volumes/rbd/README.md#how-to-use-it
+    #[inline]
+    pub fn keyring(mut self, value: impl Into<String>) -> Self {
+        self.body.keyring = Some(value.into());
+        self
+    }
+
+    /// Sets [`keyring`](#method.keyring) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_keyring(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.keyring = value.map(|value| value.into());
+        self
+    }
+
+    /// A collection of Ceph monitors. More info: https://releases.k8s.io/HEAD/examples/volumes/rbd/README.md#how-to-use-it
+    #[inline]
+    pub fn monitors(mut self, value: impl Iterator<Item = impl Into<String>>) -> RbdVolumeSourceBuilder<Image, crate::generics::MonitorsExists> {
+        self.body.monitors = value.map(|value| value.into()).collect::<Vec<_>>().into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Same as [`monitors`](#method.monitors), but takes the values as a `Vec`.
+    #[inline]
+    pub fn monitors_vec(mut self, value: impl Into<Vec<String>>) -> RbdVolumeSourceBuilder<Image, crate::generics::MonitorsExists> {
+        self.body.monitors = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// The rados pool name. Default is rbd. More info: https://releases.k8s.io/HEAD/examples/volumes/rbd/README.md#how-to-use-it
+    #[inline]
+    pub fn pool(mut self, value: impl Into<String>) -> Self {
+        self.body.pool = Some(value.into());
+        self
+    }
+
+    /// Sets [`pool`](#method.pool) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_pool(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.pool = value.map(|value| value.into());
+        self
+    }
+
+    /// ReadOnly here will force the ReadOnly setting in VolumeMounts. Defaults to false. More info: https://releases.k8s.io/HEAD/examples/volumes/rbd/README.md#how-to-use-it
+    #[inline]
+    pub fn read_only(mut self, value: impl Into<bool>) -> Self {
+        self.body.read_only = Some(value.into());
+        self
+    }
+
+    /// Sets [`read_only`](#method.read_only) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_read_only(mut self, value: Option<impl Into<bool>>) -> Self {
+        self.body.read_only = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`read_only`](#method.read_only), but takes a reference.
+    #[inline]
+    pub fn read_only_ref(self, value: &bool) -> Self {
+        self.read_only(*value)
+    }
+
+    /// SecretRef is name of the authentication secret for RBDUser. If provided overrides keyring. Default is nil. More info: https://releases.k8s.io/HEAD/examples/volumes/rbd/README.md#how-to-use-it
+    #[inline]
+    pub fn secret_ref(mut self, value: crate::io::k8s::api::core::v1::local_object_reference::LocalObjectReference) -> Self {
+        self.body.secret_ref = Some(value.into());
+        self
+    }
+
+    /// Sets [`secret_ref`](#method.secret_ref) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_secret_ref(mut self, value: Option<crate::io::k8s::api::core::v1::local_object_reference::LocalObjectReference>) -> Self {
+        self.body.secret_ref = value.map(|value| value.into());
+        self
+    }
+
+    /// The rados user name. Default is admin. More info: https://releases.k8s.io/HEAD/examples/volumes/rbd/README.md#how-to-use-it
+    #[inline]
+    pub fn user(mut self, value: impl Into<String>) -> Self {
+        self.body.user = Some(value.into());
+        self
+    }
+
+    /// Sets [`user`](#method.user) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_user(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.user = value.map(|value| value.into());
+        self
+    }
+}
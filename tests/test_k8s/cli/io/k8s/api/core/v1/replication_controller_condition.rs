@@ -0,0 +1,116 @@
+
+/// ReplicationControllerCondition describes the state of a replication controller at a certain point.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct ReplicationControllerCondition {
+    /// The last time the condition transitioned from one status to another.
+    #[serde(rename = "lastTransitionTime", skip_serializing_if = "Option::is_none")]
+    pub last_transition_time: Option<String>,
+    /// A human readable message indicating details about the transition.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    /// The reason for the condition's last transition.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+    /// Status of the condition, one of True, False, Unknown.
+    pub status: String,
+    /// Type of replication controller condition.
+    #[serde(rename = "type")]
+    pub type_: String,
+}
+
+impl ReplicationControllerCondition {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> ReplicationControllerConditionBuilder<crate::generics::MissingStatus, crate::generics::MissingType> {
+        ReplicationControllerConditionBuilder {
+            body: Default::default(),
+            _status: core::marker::PhantomData,
+            _type: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> ReplicationControllerConditionBuilder<crate::generics::StatusExists, crate::generics::TypeExists>
+    where
+        Self: Clone,
+    {
+        ReplicationControllerConditionBuilder {
+            body: self.clone(),
+            _status: core::marker::PhantomData,
+            _type: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<ReplicationControllerCondition> for ReplicationControllerConditionBuilder<crate::generics::StatusExists, crate::generics::TypeExists> {
+    fn into(self) -> ReplicationControllerCondition {
+        self.body
+    }
+}
+
+/// Builder for [`ReplicationControllerCondition`](./struct.ReplicationControllerCondition.html) object.
+#[derive(Debug, Clone)]
+pub struct ReplicationControllerConditionBuilder<Status, Type> {
+    body: self::ReplicationControllerCondition,
+    _status: core::marker::PhantomData<Status>,
+    _type: core::marker::PhantomData<Type>,
+}
+
+impl<Status, Type> ReplicationControllerConditionBuilder<Status, Type> {
+    /// The last time the condition transitioned from one status to another.
+    #[inline]
+    pub fn last_transition_time(mut self, value: impl Into<String>) -> Self {
+        self.body.last_transition_time = Some(value.into());
+        self
+    }
+
+    /// Sets [`last_transition_time`](#method.last_transition_time) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_last_transition_time(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.last_transition_time = value.map(|value| value.into());
+        self
+    }
+
+    /// A human readable message indicating details about the transition.
+    #[inline]
+    pub fn message(mut self, value: impl Into<String>) -> Self {
+        self.body.message = Some(value.into());
+        self
+    }
+
+    /// Sets [`message`](#method.message) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_message(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.message = value.map(|value| value.into());
+        self
+    }
+
+    /// The reason for the condition's last transition.
+    #[inline]
+    pub fn reason(mut self, value: impl Into<String>) -> Self {
+        self.body.reason = Some(value.into());
+        self
+    }
+
+    /// Sets [`reason`](#method.reason) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_reason(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.reason = value.map(|value| value.into());
+        self
+    }
+
+    /// Status of the condition, one of True, False, Unknown.
+    #[inline]
+    pub fn status(mut self, value: impl Into<String>) -> ReplicationControllerConditionBuilder<crate::generics::StatusExists, Type> {
+        self.body.status = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Type of replication controller condition.
+    #[inline]
+    pub fn type_(mut self, value: impl Into<String>) -> ReplicationControllerConditionBuilder<Status, crate::generics::TypeExists> {
+        self.body.type_ = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+}
@@ -0,0 +1,86 @@
+
+/// ResourceFieldSelector represents container resources (cpu, memory) and their output format
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct ResourceFieldSelector {
+    /// Container name: required for volumes, optional for env vars
+    #[serde(rename = "containerName", skip_serializing_if = "Option::is_none")]
+    pub container_name: Option<String>,
+    /// Specifies the output format of the exposed resources, defaults to "1"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub divisor: Option<String>,
+    /// Required: resource to select
+    pub resource: String,
+}
+
+impl ResourceFieldSelector {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> ResourceFieldSelectorBuilder<crate::generics::MissingResource> {
+        ResourceFieldSelectorBuilder {
+            body: Default::default(),
+            _resource: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> ResourceFieldSelectorBuilder<crate::generics::ResourceExists>
+    where
+        Self: Clone,
+    {
+        ResourceFieldSelectorBuilder {
+            body: self.clone(),
+            _resource: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<ResourceFieldSelector> for ResourceFieldSelectorBuilder<crate::generics::ResourceExists> {
+    fn into(self) -> ResourceFieldSelector {
+        self.body
+    }
+}
+
+/// Builder for [`ResourceFieldSelector`](./struct.ResourceFieldSelector.html) object.
+#[derive(Debug, Clone)]
+pub struct ResourceFieldSelectorBuilder<Resource> {
+    body: self::ResourceFieldSelector,
+    _resource: core::marker::PhantomData<Resource>,
+}
+
+impl<Resource> ResourceFieldSelectorBuilder<Resource> {
+    /// Container name: required for volumes, optional for env vars
+    #[inline]
+    pub fn container_name(mut self, value: impl Into<String>) -> Self {
+        self.body.container_name = Some(value.into());
+        self
+    }
+
+    /// Sets [`container_name`](#method.container_name) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_container_name(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.container_name = value.map(|value| value.into());
+        self
+    }
+
+    /// Specifies the output format of the exposed resources, defaults to "1"
+    #[inline]
+    pub fn divisor(mut self, value: impl Into<String>) -> Self {
+        self.body.divisor = Some(value.into());
+        self
+    }
+
+    /// Sets [`divisor`](#method.divisor) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_divisor(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.divisor = value.map(|value| value.into());
+        self
+    }
+
+    /// Required: resource to select
+    #[inline]
+    pub fn resource(mut self, value: impl Into<String>) -> ResourceFieldSelectorBuilder<crate::generics::ResourceExists> {
+        self.body.resource = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+}
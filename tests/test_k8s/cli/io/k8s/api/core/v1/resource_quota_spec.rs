@@ -0,0 +1,98 @@
+
+/// ResourceQuotaSpec defines the desired hard limits to enforce for Quota.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct ResourceQuotaSpec {
+    /// hard is the set of desired hard limits for each named resource. More info: https://kubernetes.io/docs/concepts/policy/resource-quotas/
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hard: Option<std::collections::BTreeMap<String, String>>,
+    /// scopeSelector is also a collection of filters like scopes that must match each object tracked by a quota but expressed using ScopeSelectorOperator in combination with possible values. For a resource to match, both scopes AND scopeSelector (if specified in spec), must be matched.
+    #[serde(rename = "scopeSelector", skip_serializing_if = "Option::is_none")]
+    pub scope_selector: Option<crate::io::k8s::api::core::v1::scope_selector::ScopeSelector>,
+    /// A collection of filters that must match each object tracked by a quota. If not specified, the quota matches all objects.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scopes: Option<Vec<String>>,
+}
+
+impl ResourceQuotaSpec {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> ResourceQuotaSpecBuilder {
+        ResourceQuotaSpecBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> ResourceQuotaSpecBuilder
+    where
+        Self: Clone,
+    {
+        ResourceQuotaSpecBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<ResourceQuotaSpec> for ResourceQuotaSpecBuilder {
+    fn into(self) -> ResourceQuotaSpec {
+        self.body
+    }
+}
+
+/// Builder for [`ResourceQuotaSpec`](./struct.ResourceQuotaSpec.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct ResourceQuotaSpecBuilder {
+    body: self::ResourceQuotaSpec,
+}
+
+impl ResourceQuotaSpecBuilder {
+    /// hard is the set of desired hard limits for each named resource. More info: https://kubernetes.io/docs/concepts/policy/resource-quotas/
+    #[inline]
+    pub fn hard(mut self, value: impl Iterator<Item = (String, impl Into<String>)>) -> Self {
+        self.body.hard = Some(value.map(|(key, value)| (key, value.into())).collect::<std::collections::BTreeMap<_, _>>().into());
+        self
+    }
+
+    /// Sets [`hard`](#method.hard) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_hard(mut self, value: Option<impl Iterator<Item = (String, impl Into<String>)>>) -> Self {
+        self.body.hard = value.map(|value| value.map(|(key, value)| (key, value.into())).collect::<std::collections::BTreeMap<_, _>>().into());
+        self
+    }
+
+    /// scopeSelector is also a collection of filters like scopes that must match each object tracked by a quota but expressed using ScopeSelectorOperator in combination with possible values. For a resource to match, both scopes AND scopeSelector (if specified in spec), must be matched.
+    #[inline]
+    pub fn scope_selector(mut self, value: crate::io::k8s::api::core::v1::scope_selector::ScopeSelector) -> Self {
+        self.body.scope_selector = Some(value.into());
+        self
+    }
+
+    /// Sets [`scope_selector`](#method.scope_selector) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_scope_selector(mut self, value: Option<crate::io::k8s::api::core::v1::scope_selector::ScopeSelector>) -> Self {
+        self.body.scope_selector = value.map(|value| value.into());
+        self
+    }
+
+    /// A collection of filters that must match each object tracked by a quota. If not specified, the quota matches all objects.
+    #[inline]
+    pub fn scopes(mut self, value: impl Iterator<Item = impl Into<String>>) -> Self {
+        self.body.scopes = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`scopes`](#method.scopes) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_scopes(mut self, value: Option<impl Iterator<Item = impl Into<String>>>) -> Self {
+        self.body.scopes = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`scopes`](#method.scopes), but takes the values as a `Vec`.
+    #[inline]
+    pub fn scopes_vec(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.body.scopes = Some(value.into());
+        self
+    }
+}
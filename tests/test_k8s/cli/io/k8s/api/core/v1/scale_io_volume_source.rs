@@ -0,0 +1,208 @@
+
+/// ScaleIOVolumeSource represents a persistent ScaleIO volume
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct ScaleIoVolumeSource {
+    /// Filesystem type to mount. Must be a filesystem type supported by the host operating system. Ex. "ext4", "xfs", "ntfs". Default is "xfs".
+    #[serde(rename = "fsType", skip_serializing_if = "Option::is_none")]
+    pub fs_type: Option<String>,
+    /// The host address of the ScaleIO API Gateway.
+    pub gateway: String,
+    /// The name of the ScaleIO Protection Domain for the configured storage.
+    #[serde(rename = "protectionDomain", skip_serializing_if = "Option::is_none")]
+    pub protection_domain: Option<String>,
+    /// Defaults to false (read/write). ReadOnly here will force the ReadOnly setting in VolumeMounts.
+    #[serde(rename = "readOnly", skip_serializing_if = "Option::is_none")]
+    pub read_only: Option<bool>,
+    /// SecretRef references to the secret for ScaleIO user and other sensitive information. If this is not provided, Login operation will fail.
+    #[serde(rename = "secretRef")]
+    pub secret_ref: crate::io::k8s::api::core::v1::local_object_reference::LocalObjectReference,
+    /// Flag to enable/disable SSL communication with Gateway, default false
+    #[serde(rename = "sslEnabled", skip_serializing_if = "Option::is_none")]
+    pub ssl_enabled: Option<bool>,
+    /// Indicates whether the storage for a volume should be ThickProvisioned or ThinProvisioned. Default is ThinProvisioned.
+    #[serde(rename = "storageMode", skip_serializing_if = "Option::is_none")]
+    pub storage_mode: Option<String>,
+    /// The ScaleIO Storage Pool associated with the protection domain.
+    #[serde(rename = "storagePool", skip_serializing_if = "Option::is_none")]
+    pub storage_pool: Option<String>,
+    /// The name of the storage system as configured in ScaleIO.
+    pub system: String,
+    /// The name of a volume already created in the ScaleIO system that is associated with this volume source.
+    #[serde(rename = "volumeName", skip_serializing_if = "Option::is_none")]
+    pub volume_name: Option<String>,
+}
+
+impl ScaleIoVolumeSource {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> ScaleIoVolumeSourceBuilder<crate::generics::MissingGateway, crate::generics::MissingSecretRef, crate::generics::MissingSystem> {
+        ScaleIoVolumeSourceBuilder {
+            body: Default::default(),
+            _gateway: core::marker::PhantomData,
+            _secret_ref: core::marker::PhantomData,
+            _system: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> ScaleIoVolumeSourceBuilder<crate::generics::GatewayExists, crate::generics::SecretRefExists, crate::generics::SystemExists>
+    where
+        Self: Clone,
+    {
+        ScaleIoVolumeSourceBuilder {
+            body: self.clone(),
+            _gateway: core::marker::PhantomData,
+            _secret_ref: core::marker::PhantomData,
+            _system: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<ScaleIoVolumeSource> for ScaleIoVolumeSourceBuilder<crate::generics::GatewayExists, crate::generics::SecretRefExists, crate::generics::SystemExists> {
+    fn into(self) -> ScaleIoVolumeSource {
+        self.body
+    }
+}
+
+/// Builder for [`ScaleIoVolumeSource`](./struct.ScaleIoVolumeSource.html) object.
+#[derive(Debug, Clone)]
+pub struct ScaleIoVolumeSourceBuilder<Gateway, SecretRef, System> {
+    body: self::ScaleIoVolumeSource,
+    _gateway: core::marker::PhantomData<Gateway>,
+    _secret_ref: core::marker::PhantomData<SecretRef>,
+    _system: core::marker::PhantomData<System>,
+}
+
+impl<Gateway, SecretRef, System> ScaleIoVolumeSourceBuilder<Gateway, SecretRef, System> {
+    /// Filesystem type to mount. Must be a filesystem type supported by the host operating system. Ex. "ext4", "xfs", "ntfs". Default is "xfs".
+    #[inline]
+    pub fn fs_type(mut self, value: impl Into<String>) -> Self {
+        self.body.fs_type = Some(value.into());
+        self
+    }
+
+    /// Sets [`fs_type`](#method.fs_type) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_fs_type(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.fs_type = value.map(|value| value.into());
+        self
+    }
+
+    /// The host address of the ScaleIO API Gateway.
+    #[inline]
+    pub fn gateway(mut self, value: impl Into<String>) -> ScaleIoVolumeSourceBuilder<crate::generics::GatewayExists, SecretRef, System> {
+        self.body.gateway = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// The name of the ScaleIO Protection Domain for the configured storage.
+    #[inline]
+    pub fn protection_domain(mut self, value: impl Into<String>) -> Self {
+        self.body.protection_domain = Some(value.into());
+        self
+    }
+
+    /// Sets [`protection_domain`](#method.protection_domain) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_protection_domain(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.protection_domain = value.map(|value| value.into());
+        self
+    }
+
+    /// Defaults to false (read/write). ReadOnly here will force the ReadOnly setting in VolumeMounts.
+    #[inline]
+    pub fn read_only(mut self, value: impl Into<bool>) -> Self {
+        self.body.read_only = Some(value.into());
+        self
+    }
+
+    /// Sets [`read_only`](#method.read_only) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_read_only(mut self, value: Option<impl Into<bool>>) -> Self {
+        self.body.read_only = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`read_only`](#method.read_only), but takes a reference.
+    #[inline]
+    pub fn read_only_ref(self, value: &bool) -> Self {
+        self.read_only(*value)
+    }
+
+    /// SecretRef references to the secret for ScaleIO user and other sensitive information. If this is not provided, Login operation will fail.
+    #[inline]
+    pub fn secret_ref(mut self, value: crate::io::k8s::api::core::v1::local_object_reference::LocalObjectReference) -> ScaleIoVolumeSourceBuilder<Gateway, crate::generics::SecretRefExists, System> {
+        self.body.secret_ref = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Flag to enable/disable SSL communication with Gateway, default false
+    #[inline]
+    pub fn ssl_enabled(mut self, value: impl Into<bool>) -> Self {
+        self.body.ssl_enabled = Some(value.into());
+        self
+    }
+
+    /// Sets [`ssl_enabled`](#method.ssl_enabled) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_ssl_enabled(mut self, value: Option<impl Into<bool>>) -> Self {
+        self.body.ssl_enabled = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`ssl_enabled`](#method.ssl_enabled), but takes a reference.
+    #[inline]
+    pub fn ssl_enabled_ref(self, value: &bool) -> Self {
+        self.ssl_enabled(*value)
+    }
+
+    /// Indicates whether the storage for a volume should be ThickProvisioned or ThinProvisioned. Default is ThinProvisioned.
+    #[inline]
+    pub fn storage_mode(mut self, value: impl Into<String>) -> Self {
+        self.body.storage_mode = Some(value.into());
+        self
+    }
+
+    /// Sets [`storage_mode`](#method.storage_mode) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_storage_mode(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.storage_mode = value.map(|value| value.into());
+        self
+    }
+
+    /// The ScaleIO Storage Pool associated with the protection domain.
+    #[inline]
+    pub fn storage_pool(mut self, value: impl Into<String>) -> Self {
+        self.body.storage_pool = Some(value.into());
+        self
+    }
+
+    /// Sets [`storage_pool`](#method.storage_pool) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_storage_pool(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.storage_pool = value.map(|value| value.into());
+        self
+    }
+
+    /// The name of the storage system as configured in ScaleIO.
+    #[inline]
+    pub fn system(mut self, value: impl Into<String>) -> ScaleIoVolumeSourceBuilder<Gateway, SecretRef, crate::generics::SystemExists> {
+        self.body.system = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// The name of a volume already created in the ScaleIO system that is associated with this volume source.
+    #[inline]
+    pub fn volume_name(mut self, value: impl Into<String>) -> Self {
+        self.body.volume_name = Some(value.into());
+        self
+    }
+
+    /// Sets [`volume_name`](#method.volume_name) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_volume_name(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.volume_name = value.map(|value| value.into());
+        self
+    }
+}
@@ -0,0 +1,64 @@
+
+/// A scope selector represents the AND of the selectors represented by the scoped-resource selector requirements.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct ScopeSelector {
+    /// A list of scope selector requirements by scope of the resources.
+    #[serde(rename = "matchExpressions", skip_serializing_if = "Option::is_none")]
+    pub match_expressions: Option<Vec<crate::io::k8s::api::core::v1::scoped_resource_selector_requirement::ScopedResourceSelectorRequirement>>,
+}
+
+impl ScopeSelector {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> ScopeSelectorBuilder {
+        ScopeSelectorBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> ScopeSelectorBuilder
+    where
+        Self: Clone,
+    {
+        ScopeSelectorBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<ScopeSelector> for ScopeSelectorBuilder {
+    fn into(self) -> ScopeSelector {
+        self.body
+    }
+}
+
+/// Builder for [`ScopeSelector`](./struct.ScopeSelector.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct ScopeSelectorBuilder {
+    body: self::ScopeSelector,
+}
+
+impl ScopeSelectorBuilder {
+    /// A list of scope selector requirements by scope of the resources.
+    #[inline]
+    pub fn match_expressions(mut self, value: impl Iterator<Item = crate::io::k8s::api::core::v1::scoped_resource_selector_requirement::ScopedResourceSelectorRequirementBuilder<crate::generics::OperatorExists, crate::generics::ScopeNameExists>>) -> Self {
+        self.body.match_expressions = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`match_expressions`](#method.match_expressions) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_match_expressions(mut self, value: Option<impl Iterator<Item = crate::io::k8s::api::core::v1::scoped_resource_selector_requirement::ScopedResourceSelectorRequirementBuilder<crate::generics::OperatorExists, crate::generics::ScopeNameExists>>>) -> Self {
+        self.body.match_expressions = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`match_expressions`](#method.match_expressions), but takes the values as a `Vec`.
+    #[inline]
+    pub fn match_expressions_vec(mut self, value: impl Into<Vec<crate::io::k8s::api::core::v1::scoped_resource_selector_requirement::ScopedResourceSelectorRequirement>>) -> Self {
+        self.body.match_expressions = Some(value.into());
+        self
+    }
+}
@@ -0,0 +1,74 @@
+
+/// SecretReference represents a Secret Reference. It has enough information to retrieve secret in any namespace
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct SecretReference {
+    /// Name is unique within a namespace to reference a secret resource.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Namespace defines the space within which the secret name must be unique.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub namespace: Option<String>,
+}
+
+impl SecretReference {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> SecretReferenceBuilder {
+        SecretReferenceBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> SecretReferenceBuilder
+    where
+        Self: Clone,
+    {
+        SecretReferenceBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<SecretReference> for SecretReferenceBuilder {
+    fn into(self) -> SecretReference {
+        self.body
+    }
+}
+
+/// Builder for [`SecretReference`](./struct.SecretReference.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct SecretReferenceBuilder {
+    body: self::SecretReference,
+}
+
+impl SecretReferenceBuilder {
+    /// Name is unique within a namespace to reference a secret resource.
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> Self {
+        self.body.name = Some(value.into());
+        self
+    }
+
+    /// Sets [`name`](#method.name) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_name(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.name = value.map(|value| value.into());
+        self
+    }
+
+    /// Namespace defines the space within which the secret name must be unique.
+    #[inline]
+    pub fn namespace(mut self, value: impl Into<String>) -> Self {
+        self.body.namespace = Some(value.into());
+        self
+    }
+
+    /// Sets [`namespace`](#method.namespace) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_namespace(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.namespace = value.map(|value| value.into());
+        self
+    }
+}
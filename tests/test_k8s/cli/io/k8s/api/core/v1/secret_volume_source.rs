@@ -0,0 +1,129 @@
+
+/// Adapts a Secret into a volume.
+///
+/// The contents of the target Secret's Data field will be presented in a volume as files using the keys in the Data field as the file names. Secret volumes support ownership management and SELinux relabeling.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct SecretVolumeSource {
+    /// Optional: mode bits to use on created files by default. Must be a value between 0 and 0777. Defaults to 0644. Directories within the path are not affected by this setting. This might be in conflict with other options that affect the file mode, like fsGroup, and the result can be other mode bits set.
+    #[serde(rename = "defaultMode", skip_serializing_if = "Option::is_none")]
+    pub default_mode: Option<i32>,
+    /// If unspecified, each key-value pair in the Data field of the referenced Secret will be projected into the volume as a file whose name is the key and content is the value. If specified, the listed keys will be projected into the specified paths, and unlisted keys will not be present. If a key is specified which is not present in the Secret, the volume setup will error unless it is marked optional. Paths must be relative and may not contain the '..' path or start with '..'.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub items: Option<Vec<crate::io::k8s::api::core::v1::key_to_path::KeyToPath>>,
+    /// Specify whether the Secret or its keys must be defined
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub optional: Option<bool>,
+    /// Name of the secret in the pod's namespace to use. More info: https://kubernetes.io/docs/concepts/storage/volumes#secret
+    #[serde(rename = "secretName", skip_serializing_if = "Option::is_none")]
+    pub secret_name: Option<String>,
+}
+
+impl SecretVolumeSource {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> SecretVolumeSourceBuilder {
+        SecretVolumeSourceBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> SecretVolumeSourceBuilder
+    where
+        Self: Clone,
+    {
+        SecretVolumeSourceBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<SecretVolumeSource> for SecretVolumeSourceBuilder {
+    fn into(self) -> SecretVolumeSource {
+        self.body
+    }
+}
+
+/// Builder for [`SecretVolumeSource`](./struct.SecretVolumeSource.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct SecretVolumeSourceBuilder {
+    body: self::SecretVolumeSource,
+}
+
+impl SecretVolumeSourceBuilder {
+    /// Optional: mode bits to use on created files by default. Must be a value between 0 and 0777. Defaults to 0644. Directories within the path are not affected by this setting. This might be in conflict with other options that affect the file mode, like fsGroup, and the result can be other mode bits set.
+    #[inline]
+    pub fn default_mode(mut self, value: impl Into<i32>) -> Self {
+        self.body.default_mode = Some(value.into());
+        self
+    }
+
+    /// Sets [`default_mode`](#method.default_mode) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_default_mode(mut self, value: Option<impl Into<i32>>) -> Self {
+        self.body.default_mode = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`default_mode`](#method.default_mode), but takes a reference.
+    #[inline]
+    pub fn default_mode_ref(self, value: &i32) -> Self {
+        self.default_mode(*value)
+    }
+
+    /// If unspecified, each key-value pair in the Data field of the referenced Secret will be projected into the volume as a file whose name is the key and content is the value. If specified, the listed keys will be projected into the specified paths, and unlisted keys will not be present. If a key is specified which is not present in the Secret, the volume setup will error unless it is marked optional. Paths must be relative and may not contain the '..' path or start with '..'.
+    #[inline]
+    pub fn items(mut self, value: impl Iterator<Item = crate::io::k8s::api::core::v1::key_to_path::KeyToPathBuilder<crate::generics::KeyExists, crate::generics::PathExists>>) -> Self {
+        self.body.items = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`items`](#method.items) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_items(mut self, value: Option<impl Iterator<Item = crate::io::k8s::api::core::v1::key_to_path::KeyToPathBuilder<crate::generics::KeyExists, crate::generics::PathExists>>>) -> Self {
+        self.body.items = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`items`](#method.items), but takes the values as a `Vec`.
+    #[inline]
+    pub fn items_vec(mut self, value: impl Into<Vec<crate::io::k8s::api::core::v1::key_to_path::KeyToPath>>) -> Self {
+        self.body.items = Some(value.into());
+        self
+    }
+
+    /// Specify whether the Secret or its keys must be defined
+    #[inline]
+    pub fn optional(mut self, value: impl Into<bool>) -> Self {
+        self.body.optional = Some(value.into());
+        self
+    }
+
+    /// Sets [`optional`](#method.optional) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_optional(mut self, value: Option<impl Into<bool>>) -> Self {
+        self.body.optional = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`optional`](#method.optional), but takes a reference.
+    #[inline]
+    pub fn optional_ref(self, value: &bool) -> Self {
+        self.optional(*value)
+    }
+
+    /// Name of the secret in the pod's namespace to use. More info: https://kubernetes.io/docs/concepts/storage/volumes#secret
+    #[inline]
+    pub fn secret_name(mut self, value: impl Into<String>) -> Self {
+        self.body.secret_name = Some(value.into());
+        self
+    }
+
+    /// Sets [`secret_name`](#method.secret_name) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_secret_name(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.secret_name = value.map(|value| value.into());
+        self
+    }
+}
@@ -0,0 +1,246 @@
+
+/// SecurityContext holds security configuration that will be applied to a container. Some fields are present in both SecurityContext and PodSecurityContext.  When both are set, the values in SecurityContext take precedence.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct SecurityContext {
+    /// AllowPrivilegeEscalation controls whether a process can gain more privileges than its parent process. This bool directly controls if the no_new_privs flag will be set on the container process. AllowPrivilegeEscalation is true always when the container is: 1) run as Privileged 2) has CAP_SYS_ADMIN
+    #[serde(rename = "allowPrivilegeEscalation", skip_serializing_if = "Option::is_none")]
+    pub allow_privilege_escalation: Option<bool>,
+    /// The capabilities to add/drop when running containers. Defaults to the default set of capabilities granted by the container runtime.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub capabilities: Option<crate::io::k8s::api::core::v1::capabilities::Capabilities>,
+    /// Run container in privileged mode. Processes in privileged containers are essentially equivalent to root on the host. Defaults to false.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub privileged: Option<bool>,
+    /// procMount denotes the type of proc mount to use for the containers. The default is DefaultProcMount which uses the container runtime defaults for readonly paths and masked paths. This requires the ProcMountType feature flag to be enabled.
+    #[serde(rename = "procMount", skip_serializing_if = "Option::is_none")]
+    pub proc_mount: Option<String>,
+    /// Whether this container has a read-only root filesystem. Default is false.
+    #[serde(rename = "readOnlyRootFilesystem", skip_serializing_if = "Option::is_none")]
+    pub read_only_root_filesystem: Option<bool>,
+    /// The GID to run the entrypoint of the container process. Uses runtime default if unset. May also be set in PodSecurityContext.  If set in both SecurityContext and PodSecurityContext, the value specified in SecurityContext takes precedence.
+    #[serde(rename = "runAsGroup", skip_serializing_if = "Option::is_none")]
+    pub run_as_group: Option<i64>,
+    /// Indicates that the container must run as a non-root user. If true, the Kubelet will validate the image at runtime to ensure that it does not run as UID 0 (root) and fail to start the container if it does. If unset or false, no such validation will be performed. May also be set in PodSecurityContext.  If set in both SecurityContext and PodSecurityContext, the value specified in SecurityContext takes precedence.
+    #[serde(rename = "runAsNonRoot", skip_serializing_if = "Option::is_none")]
+    pub run_as_non_root: Option<bool>,
+    /// The UID to run the entrypoint of the container process. Defaults to user specified in image metadata if unspecified. May also be set in PodSecurityContext.  If set in both SecurityContext and PodSecurityContext, the value specified in SecurityContext takes precedence.
+    #[serde(rename = "runAsUser", skip_serializing_if = "Option::is_none")]
+    pub run_as_user: Option<i64>,
+    /// The SELinux context to be applied to the container. If unspecified, the container runtime will allocate a random SELinux context for each container.  May also be set in PodSecurityContext.  If set in both SecurityContext and PodSecurityContext, the value specified in SecurityContext takes precedence.
+    #[serde(rename = "seLinuxOptions", skip_serializing_if = "Option::is_none")]
+    pub se_linux_options: Option<crate::io::k8s::api::core::v1::se_linux_options::SeLinuxOptions>,
+    /// Windows security options.
+    #[serde(rename = "windowsOptions", skip_serializing_if = "Option::is_none")]
+    pub windows_options: Option<crate::io::k8s::api::core::v1::windows_security_context_options::WindowsSecurityContextOptions>,
+}
+
+impl SecurityContext {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> SecurityContextBuilder {
+        SecurityContextBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> SecurityContextBuilder
+    where
+        Self: Clone,
+    {
+        SecurityContextBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<SecurityContext> for SecurityContextBuilder {
+    fn into(self) -> SecurityContext {
+        self.body
+    }
+}
+
+/// Builder for [`SecurityContext`](./struct.SecurityContext.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct SecurityContextBuilder {
+    body: self::SecurityContext,
+}
+
+impl SecurityContextBuilder {
+    /// AllowPrivilegeEscalation controls whether a process can gain more privileges than its parent process. This bool directly controls if the no_new_privs flag will be set on the container process. AllowPrivilegeEscalation is true always when the container is: 1) run as Privileged 2) has CAP_SYS_ADMIN
+    #[inline]
+    pub fn allow_privilege_escalation(mut self, value: impl Into<bool>) -> Self {
+        self.body.allow_privilege_escalation = Some(value.into());
+        self
+    }
+
+    /// Sets [`allow_privilege_escalation`](#method.allow_privilege_escalation) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_allow_privilege_escalation(mut self, value: Option<impl Into<bool>>) -> Self {
+        self.body.allow_privilege_escalation = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`allow_privilege_escalation`](#method.allow_privilege_escalation), but takes a reference.
+    #[inline]
+    pub fn allow_privilege_escalation_ref(self, value: &bool) -> Self {
+        self.allow_privilege_escalation(*value)
+    }
+
+    /// The capabilities to add/drop when running containers. Defaults to the default set of capabilities granted by the container runtime.
+    #[inline]
+    pub fn capabilities(mut self, value: crate::io::k8s::api::core::v1::capabilities::Capabilities) -> Self {
+        self.body.capabilities = Some(value.into());
+        self
+    }
+
+    /// Sets [`capabilities`](#method.capabilities) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_capabilities(mut self, value: Option<crate::io::k8s::api::core::v1::capabilities::Capabilities>) -> Self {
+        self.body.capabilities = value.map(|value| value.into());
+        self
+    }
+
+    /// Run container in privileged mode. Processes in privileged containers are essentially equivalent to root on the host. Defaults to false.
+    #[inline]
+    pub fn privileged(mut self, value: impl Into<bool>) -> Self {
+        self.body.privileged = Some(value.into());
+        self
+    }
+
+    /// Sets [`privileged`](#method.privileged) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_privileged(mut self, value: Option<impl Into<bool>>) -> Self {
+        self.body.privileged = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`privileged`](#method.privileged), but takes a reference.
+    #[inline]
+    pub fn privileged_ref(self, value: &bool) -> Self {
+        self.privileged(*value)
+    }
+
+    /// procMount denotes the type of proc mount to use for the containers. The default is DefaultProcMount which uses the container runtime defaults for readonly paths and masked paths. This requires the ProcMountType feature flag to be enabled.
+    #[inline]
+    pub fn proc_mount(mut self, value: impl Into<String>) -> Self {
+        self.body.proc_mount = Some(value.into());
+        self
+    }
+
+    /// Sets [`proc_mount`](#method.proc_mount) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_proc_mount(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.proc_mount = value.map(|value| value.into());
+        self
+    }
+
+    /// Whether this container has a read-only root filesystem. Default is false.
+    #[inline]
+    pub fn read_only_root_filesystem(mut self, value: impl Into<bool>) -> Self {
+        self.body.read_only_root_filesystem = Some(value.into());
+        self
+    }
+
+    /// Sets [`read_only_root_filesystem`](#method.read_only_root_filesystem) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_read_only_root_filesystem(mut self, value: Option<impl Into<bool>>) -> Self {
+        self.body.read_only_root_filesystem = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`read_only_root_filesystem`](#method.read_only_root_filesystem), but takes a reference.
+    #[inline]
+    pub fn read_only_root_filesystem_ref(self, value: &bool) -> Self {
+        self.read_only_root_filesystem(*value)
+    }
+
+    /// The GID to run the entrypoint of the container process. Uses runtime default if unset. May also be set in PodSecurityContext.  If set in both SecurityContext and PodSecurityContext, the value specified in SecurityContext takes precedence.
+    #[inline]
+    pub fn run_as_group(mut self, value: impl Into<i64>) -> Self {
+        self.body.run_as_group = Some(value.into());
+        self
+    }
+
+    /// Sets [`run_as_group`](#method.run_as_group) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_run_as_group(mut self, value: Option<impl Into<i64>>) -> Self {
+        self.body.run_as_group = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`run_as_group`](#method.run_as_group), but takes a reference.
+    #[inline]
+    pub fn run_as_group_ref(self, value: &i64) -> Self {
+        self.run_as_group(*value)
+    }
+
+    /// Indicates that the container must run as a non-root user. If true, the Kubelet will validate the image at runtime to ensure that it does not run as UID 0 (root) and fail to start the container if it does. If unset or false, no such validation will be performed. May also be set in PodSecurityContext.  If set in both SecurityContext and PodSecurityContext, the value specified in SecurityContext takes precedence.
+    #[inline]
+    pub fn run_as_non_root(mut self, value: impl Into<bool>) -> Self {
+        self.body.run_as_non_root = Some(value.into());
+        self
+    }
+
+    /// Sets [`run_as_non_root`](#method.run_as_non_root) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_run_as_non_root(mut self, value: Option<impl Into<bool>>) -> Self {
+        self.body.run_as_non_root = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`run_as_non_root`](#method.run_as_non_root), but takes a reference.
+    #[inline]
+    pub fn run_as_non_root_ref(self, value: &bool) -> Self {
+        self.run_as_non_root(*value)
+    }
+
+    /// The UID to run the entrypoint of the container process. Defaults to user specified in image metadata if unspecified. May also be set in PodSecurityContext.  If set in both SecurityContext and PodSecurityContext, the value specified in SecurityContext takes precedence.
+    #[inline]
+    pub fn run_as_user(mut self, value: impl Into<i64>) -> Self {
+        self.body.run_as_user = Some(value.into());
+        self
+    }
+
+    /// Sets [`run_as_user`](#method.run_as_user) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_run_as_user(mut self, value: Option<impl Into<i64>>) -> Self {
+        self.body.run_as_user = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`run_as_user`](#method.run_as_user), but takes a reference.
+    #[inline]
+    pub fn run_as_user_ref(self, value: &i64) -> Self {
+        self.run_as_user(*value)
+    }
+
+    /// The SELinux context to be applied to the container. If unspecified, the container runtime will allocate a random SELinux context for each container.  May also be set in PodSecurityContext.  If set in both SecurityContext and PodSecurityContext, the value specified in SecurityContext takes precedence.
+    #[inline]
+    pub fn se_linux_options(mut self, value: crate::io::k8s::api::core::v1::se_linux_options::SeLinuxOptions) -> Self {
+        self.body.se_linux_options = Some(value.into());
+        self
+    }
+
+    /// Sets [`se_linux_options`](#method.se_linux_options) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_se_linux_options(mut self, value: Option<crate::io::k8s::api::core::v1::se_linux_options::SeLinuxOptions>) -> Self {
+        self.body.se_linux_options = value.map(|value| value.into());
+        self
+    }
+
+    /// Windows security options.
+    #[inline]
+    pub fn windows_options(mut self, value: crate::io::k8s::api::core::v1::windows_security_context_options::WindowsSecurityContextOptions) -> Self {
+        self.body.windows_options = Some(value.into());
+        self
+    }
+
+    /// Sets [`windows_options`](#method.windows_options) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_windows_options(mut self, value: Option<crate::io::k8s::api::core::v1::windows_security_context_options::WindowsSecurityContextOptions>) -> Self {
+        self.body.windows_options = value.map(|value| value.into());
+        self
+    }
+}
@@ -0,0 +1,92 @@
+
+/// ServiceAccountTokenProjection represents a projected service account token volume. This projection can be used to insert a service account token into the pods runtime filesystem for use against APIs (Kubernetes API Server or otherwise).
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct ServiceAccountTokenProjection {
+    /// Audience is the intended audience of the token. A recipient of a token must identify itself with an identifier specified in the audience of the token, and otherwise should reject the token. The audience defaults to the identifier of the apiserver.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub audience: Option<String>,
+    /// ExpirationSeconds is the requested duration of validity of the service account token. As the token approaches expiration, the kubelet volume plugin will proactively rotate the service account token. The kubelet will start trying to rotate the token if the token is older than 80 percent of its time to live or if the token is older than 24 hours.Defaults to 1 hour and must be at least 10 minutes.
+    #[serde(rename = "expirationSeconds", skip_serializing_if = "Option::is_none")]
+    pub expiration_seconds: Option<i64>,
+    /// Path is the path relative to the mount point of the file to project the token into.
+    pub path: String,
+}
+
+impl ServiceAccountTokenProjection {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> ServiceAccountTokenProjectionBuilder<crate::generics::MissingPath> {
+        ServiceAccountTokenProjectionBuilder {
+            body: Default::default(),
+            _path: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> ServiceAccountTokenProjectionBuilder<crate::generics::PathExists>
+    where
+        Self: Clone,
+    {
+        ServiceAccountTokenProjectionBuilder {
+            body: self.clone(),
+            _path: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<ServiceAccountTokenProjection> for ServiceAccountTokenProjectionBuilder<crate::generics::PathExists> {
+    fn into(self) -> ServiceAccountTokenProjection {
+        self.body
+    }
+}
+
+/// Builder for [`ServiceAccountTokenProjection`](./struct.ServiceAccountTokenProjection.html) object.
+#[derive(Debug, Clone)]
+pub struct ServiceAccountTokenProjectionBuilder<Path> {
+    body: self::ServiceAccountTokenProjection,
+    _path: core::marker::PhantomData<Path>,
+}
+
+impl<Path> ServiceAccountTokenProjectionBuilder<Path> {
+    /// Audience is the intended audience of the token. A recipient of a token must identify itself with an identifier specified in the audience of the token, and otherwise should reject the token. The audience defaults to the identifier of the apiserver.
+    #[inline]
+    pub fn audience(mut self, value: impl Into<String>) -> Self {
+        self.body.audience = Some(value.into());
+        self
+    }
+
+    /// Sets [`audience`](#method.audience) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_audience(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.audience = value.map(|value| value.into());
+        self
+    }
+
+    /// ExpirationSeconds is the requested duration of validity of the service account token. As the token approaches expiration, the kubelet volume plugin will proactively rotate the service account token. The kubelet will start trying to rotate the token if the token is older than 80 percent of its time to live or if the token is older than 24 hours.Defaults to 1 hour and must be at least 10 minutes.
+    #[inline]
+    pub fn expiration_seconds(mut self, value: impl Into<i64>) -> Self {
+        self.body.expiration_seconds = Some(value.into());
+        self
+    }
+
+    /// Sets [`expiration_seconds`](#method.expiration_seconds) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_expiration_seconds(mut self, value: Option<impl Into<i64>>) -> Self {
+        self.body.expiration_seconds = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`expiration_seconds`](#method.expiration_seconds), but takes a reference.
+    #[inline]
+    pub fn expiration_seconds_ref(self, value: &i64) -> Self {
+        self.expiration_seconds(*value)
+    }
+
+    /// Path is the path relative to the mount point of the file to project the token into.
+    #[inline]
+    pub fn path(mut self, value: impl Into<String>) -> ServiceAccountTokenProjectionBuilder<crate::generics::PathExists> {
+        self.body.path = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+}
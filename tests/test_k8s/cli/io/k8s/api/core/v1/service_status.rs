@@ -0,0 +1,57 @@
+
+/// ServiceStatus represents the current status of a service.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct ServiceStatus {
+    /// LoadBalancer contains the current status of the load-balancer, if one is present.
+    #[serde(rename = "loadBalancer", skip_serializing_if = "Option::is_none")]
+    pub load_balancer: Option<crate::io::k8s::api::core::v1::load_balancer_status::LoadBalancerStatus>,
+}
+
+impl ServiceStatus {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> ServiceStatusBuilder {
+        ServiceStatusBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> ServiceStatusBuilder
+    where
+        Self: Clone,
+    {
+        ServiceStatusBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<ServiceStatus> for ServiceStatusBuilder {
+    fn into(self) -> ServiceStatus {
+        self.body
+    }
+}
+
+/// Builder for [`ServiceStatus`](./struct.ServiceStatus.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct ServiceStatusBuilder {
+    body: self::ServiceStatus,
+}
+
+impl ServiceStatusBuilder {
+    /// LoadBalancer contains the current status of the load-balancer, if one is present.
+    #[inline]
+    pub fn load_balancer(mut self, value: crate::io::k8s::api::core::v1::load_balancer_status::LoadBalancerStatus) -> Self {
+        self.body.load_balancer = Some(value.into());
+        self
+    }
+
+    /// Sets [`load_balancer`](#method.load_balancer) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_load_balancer(mut self, value: Option<crate::io::k8s::api::core::v1::load_balancer_status::LoadBalancerStatus>) -> Self {
+        self.body.load_balancer = value.map(|value| value.into());
+        self
+    }
+}
@@ -0,0 +1,131 @@
+
+/// Represents a StorageOS persistent volume resource.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct StorageOsVolumeSource {
+    /// Filesystem type to mount. Must be a filesystem type supported by the host operating system. Ex. "ext4", "xfs", "ntfs". Implicitly inferred to be "ext4" if unspecified.
+    #[serde(rename = "fsType", skip_serializing_if = "Option::is_none")]
+    pub fs_type: Option<String>,
+    /// Defaults to false (read/write). ReadOnly here will force the ReadOnly setting in VolumeMounts.
+    #[serde(rename = "readOnly", skip_serializing_if = "Option::is_none")]
+    pub read_only: Option<bool>,
+    /// SecretRef specifies the secret to use for obtaining the StorageOS API credentials.  If not specified, default values will be attempted.
+    #[serde(rename = "secretRef", skip_serializing_if = "Option::is_none")]
+    pub secret_ref: Option<crate::io::k8s::api::core::v1::local_object_reference::LocalObjectReference>,
+    /// VolumeName is the human-readable name of the StorageOS volume.  Volume names are only unique within a namespace.
+    #[serde(rename = "volumeName", skip_serializing_if = "Option::is_none")]
+    pub volume_name: Option<String>,
+    /// VolumeNamespace specifies the scope of the volume within StorageOS.  If no namespace is specified then the Pod's namespace will be used.  This allows the Kubernetes name scoping to be mirrored within StorageOS for tighter integration. Set VolumeName to any name to override the default behaviour. Set to "default" if you are not using namespaces within StorageOS. Namespaces that do not pre-exist within StorageOS will be created.
+    #[serde(rename = "volumeNamespace", skip_serializing_if = "Option::is_none")]
+    pub volume_namespace: Option<String>,
+}
+
+impl StorageOsVolumeSource {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> StorageOsVolumeSourceBuilder {
+        StorageOsVolumeSourceBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> StorageOsVolumeSourceBuilder
+    where
+        Self: Clone,
+    {
+        StorageOsVolumeSourceBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<StorageOsVolumeSource> for StorageOsVolumeSourceBuilder {
+    fn into(self) -> StorageOsVolumeSource {
+        self.body
+    }
+}
+
+/// Builder for [`StorageOsVolumeSource`](./struct.StorageOsVolumeSource.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct StorageOsVolumeSourceBuilder {
+    body: self::StorageOsVolumeSource,
+}
+
+impl StorageOsVolumeSourceBuilder {
+    /// Filesystem type to mount. Must be a filesystem type supported by the host operating system. Ex. "ext4", "xfs", "ntfs". Implicitly inferred to be "ext4" if unspecified.
+    #[inline]
+    pub fn fs_type(mut self, value: impl Into<String>) -> Self {
+        self.body.fs_type = Some(value.into());
+        self
+    }
+
+    /// Sets [`fs_type`](#method.fs_type) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_fs_type(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.fs_type = value.map(|value| value.into());
+        self
+    }
+
+    /// Defaults to false (read/write). ReadOnly here will force the ReadOnly setting in VolumeMounts.
+    #[inline]
+    pub fn read_only(mut self, value: impl Into<bool>) -> Self {
+        self.body.read_only = Some(value.into());
+        self
+    }
+
+    /// Sets [`read_only`](#method.read_only) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_read_only(mut self, value: Option<impl Into<bool>>) -> Self {
+        self.body.read_only = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`read_only`](#method.read_only), but takes a reference.
+    #[inline]
+    pub fn read_only_ref(self, value: &bool) -> Self {
+        self.read_only(*value)
+    }
+
+    /// SecretRef specifies the secret to use for obtaining the StorageOS API credentials.  If not specified, default values will be attempted.
+    #[inline]
+    pub fn secret_ref(mut self, value: crate::io::k8s::api::core::v1::local_object_reference::LocalObjectReference) -> Self {
+        self.body.secret_ref = Some(value.into());
+        self
+    }
+
+    /// Sets [`secret_ref`](#method.secret_ref) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_secret_ref(mut self, value: Option<crate::io::k8s::api::core::v1::local_object_reference::LocalObjectReference>) -> Self {
+        self.body.secret_ref = value.map(|value| value.into());
+        self
+    }
+
+    /// VolumeName is the human-readable name of the StorageOS volume.  Volume names are only unique within a namespace.
+    #[inline]
+    pub fn volume_name(mut self, value: impl Into<String>) -> Self {
+        self.body.volume_name = Some(value.into());
+        self
+    }
+
+    /// Sets [`volume_name`](#method.volume_name) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_volume_name(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.volume_name = value.map(|value| value.into());
+        self
+    }
+
+    /// VolumeNamespace specifies the scope of the volume within StorageOS.  If no namespace is specified then the Pod's namespace will be used.  This allows the Kubernetes name scoping to be mirrored within StorageOS for tighter integration. Set VolumeName to any name to override the default behaviour. Set to "default" if you are not using namespaces within StorageOS. Namespaces that do not pre-exist within StorageOS will be created.
+    #[inline]
+    pub fn volume_namespace(mut self, value: impl Into<String>) -> Self {
+        self.body.volume_namespace = Some(value.into());
+        self
+    }
+
+    /// Sets [`volume_namespace`](#method.volume_namespace) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_volume_namespace(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.volume_namespace = value.map(|value| value.into());
+        self
+    }
+}
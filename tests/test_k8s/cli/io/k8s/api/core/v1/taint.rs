@@ -0,0 +1,98 @@
+
+/// The node this Taint is attached to has the "effect" on any pod that does not tolerate the Taint.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct Taint {
+    /// Required. The effect of the taint on pods that do not tolerate the taint. Valid effects are NoSchedule, PreferNoSchedule and NoExecute.
+    pub effect: String,
+    /// Required. The taint key to be applied to a node.
+    pub key: String,
+    /// TimeAdded represents the time at which the taint was added. It is only written for NoExecute taints.
+    #[serde(rename = "timeAdded", skip_serializing_if = "Option::is_none")]
+    pub time_added: Option<String>,
+    /// Required. The taint value corresponding to the taint key.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+}
+
+impl Taint {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> TaintBuilder<crate::generics::MissingEffect, crate::generics::MissingKey> {
+        TaintBuilder {
+            body: Default::default(),
+            _effect: core::marker::PhantomData,
+            _key: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> TaintBuilder<crate::generics::EffectExists, crate::generics::KeyExists>
+    where
+        Self: Clone,
+    {
+        TaintBuilder {
+            body: self.clone(),
+            _effect: core::marker::PhantomData,
+            _key: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<Taint> for TaintBuilder<crate::generics::EffectExists, crate::generics::KeyExists> {
+    fn into(self) -> Taint {
+        self.body
+    }
+}
+
+/// Builder for [`Taint`](./struct.Taint.html) object.
+#[derive(Debug, Clone)]
+pub struct TaintBuilder<Effect, Key> {
+    body: self::Taint,
+    _effect: core::marker::PhantomData<Effect>,
+    _key: core::marker::PhantomData<Key>,
+}
+
+impl<Effect, Key> TaintBuilder<Effect, Key> {
+    /// Required. The effect of the taint on pods that do not tolerate the taint. Valid effects are NoSchedule, PreferNoSchedule and NoExecute.
+    #[inline]
+    pub fn effect(mut self, value: impl Into<String>) -> TaintBuilder<crate::generics::EffectExists, Key> {
+        self.body.effect = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Required. The taint key to be applied to a node.
+    #[inline]
+    pub fn key(mut self, value: impl Into<String>) -> TaintBuilder<Effect, crate::generics::KeyExists> {
+        self.body.key = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// TimeAdded represents the time at which the taint was added. It is only written for NoExecute taints.
+    #[inline]
+    pub fn time_added(mut self, value: impl Into<String>) -> Self {
+        self.body.time_added = Some(value.into());
+        self
+    }
+
+    /// Sets [`time_added`](#method.time_added) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_time_added(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.time_added = value.map(|value| value.into());
+        self
+    }
+
+    /// Required. The taint value corresponding to the taint key.
+    #[inline]
+    pub fn value(mut self, value: impl Into<String>) -> Self {
+        self.body.value = Some(value.into());
+        self
+    }
+
+    /// Sets [`value`](#method.value) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_value(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.value = value.map(|value| value.into());
+        self
+    }
+}
@@ -0,0 +1,131 @@
+
+/// The pod this Toleration is attached to tolerates any taint that matches the triple \<key,value,effect> using the matching operator \<operator>.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct Toleration {
+    /// Effect indicates the taint effect to match. Empty means match all taint effects. When specified, allowed values are NoSchedule, PreferNoSchedule and NoExecute.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub effect: Option<String>,
+    /// Key is the taint key that the toleration applies to. Empty means match all taint keys. If the key is empty, operator must be Exists; this combination means to match all values and all keys.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub key: Option<String>,
+    /// Operator represents a key's relationship to the value. Valid operators are Exists and Equal. Defaults to Equal. Exists is equivalent to wildcard for value, so that a pod can tolerate all taints of a particular category.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub operator: Option<String>,
+    /// TolerationSeconds represents the period of time the toleration (which must be of effect NoExecute, otherwise this field is ignored) tolerates the taint. By default, it is not set, which means tolerate the taint forever (do not evict). Zero and negative values will be treated as 0 (evict immediately) by the system.
+    #[serde(rename = "tolerationSeconds", skip_serializing_if = "Option::is_none")]
+    pub toleration_seconds: Option<i64>,
+    /// Value is the taint value the toleration matches to. If the operator is Exists, the value should be empty, otherwise just a regular string.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+}
+
+impl Toleration {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> TolerationBuilder {
+        TolerationBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> TolerationBuilder
+    where
+        Self: Clone,
+    {
+        TolerationBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<Toleration> for TolerationBuilder {
+    fn into(self) -> Toleration {
+        self.body
+    }
+}
+
+/// Builder for [`Toleration`](./struct.Toleration.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct TolerationBuilder {
+    body: self::Toleration,
+}
+
+impl TolerationBuilder {
+    /// Effect indicates the taint effect to match. Empty means match all taint effects. When specified, allowed values are NoSchedule, PreferNoSchedule and NoExecute.
+    #[inline]
+    pub fn effect(mut self, value: impl Into<String>) -> Self {
+        self.body.effect = Some(value.into());
+        self
+    }
+
+    /// Sets [`effect`](#method.effect) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_effect(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.effect = value.map(|value| value.into());
+        self
+    }
+
+    /// Key is the taint key that the toleration applies to. Empty means match all taint keys. If the key is empty, operator must be Exists; this combination means to match all values and all keys.
+    #[inline]
+    pub fn key(mut self, value: impl Into<String>) -> Self {
+        self.body.key = Some(value.into());
+        self
+    }
+
+    /// Sets [`key`](#method.key) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_key(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.key = value.map(|value| value.into());
+        self
+    }
+
+    /// Operator represents a key's relationship to the value. Valid operators are Exists and Equal. Defaults to Equal. Exists is equivalent to wildcard for value, so that a pod can tolerate all taints of a particular category.
+    #[inline]
+    pub fn operator(mut self, value: impl Into<String>) -> Self {
+        self.body.operator = Some(value.into());
+        self
+    }
+
+    /// Sets [`operator`](#method.operator) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_operator(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.operator = value.map(|value| value.into());
+        self
+    }
+
+    /// TolerationSeconds represents the period of time the toleration (which must be of effect NoExecute, otherwise this field is ignored) tolerates the taint. By default, it is not set, which means tolerate the taint forever (do not evict). Zero and negative values will be treated as 0 (evict immediately) by the system.
+    #[inline]
+    pub fn toleration_seconds(mut self, value: impl Into<i64>) -> Self {
+        self.body.toleration_seconds = Some(value.into());
+        self
+    }
+
+    /// Sets [`toleration_seconds`](#method.toleration_seconds) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_toleration_seconds(mut self, value: Option<impl Into<i64>>) -> Self {
+        self.body.toleration_seconds = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`toleration_seconds`](#method.toleration_seconds), but takes a reference.
+    #[inline]
+    pub fn toleration_seconds_ref(self, value: &i64) -> Self {
+        self.toleration_seconds(*value)
+    }
+
+    /// Value is the taint value the toleration matches to. If the operator is Exists, the value should be empty, otherwise just a regular string.
+    #[inline]
+    pub fn value(mut self, value: impl Into<String>) -> Self {
+        self.body.value = Some(value.into());
+        self
+    }
+
+    /// Sets [`value`](#method.value) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_value(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.value = value.map(|value| value.into());
+        self
+    }
+}
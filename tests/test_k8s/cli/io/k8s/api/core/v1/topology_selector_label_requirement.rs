@@ -0,0 +1,71 @@
+
+/// A topology selector requirement is a selector that matches given label. This is an alpha feature and may change in the future.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct TopologySelectorLabelRequirement {
+    /// The label key that the selector applies to.
+    pub key: String,
+    /// An array of string values. One value must match the label to be selected. Each entry in Values is ORed.
+    pub values: Vec<String>,
+}
+
+impl TopologySelectorLabelRequirement {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> TopologySelectorLabelRequirementBuilder<crate::generics::MissingKey, crate::generics::MissingValues> {
+        TopologySelectorLabelRequirementBuilder {
+            body: Default::default(),
+            _key: core::marker::PhantomData,
+            _values: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> TopologySelectorLabelRequirementBuilder<crate::generics::KeyExists, crate::generics::ValuesExists>
+    where
+        Self: Clone,
+    {
+        TopologySelectorLabelRequirementBuilder {
+            body: self.clone(),
+            _key: core::marker::PhantomData,
+            _values: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<TopologySelectorLabelRequirement> for TopologySelectorLabelRequirementBuilder<crate::generics::KeyExists, crate::generics::ValuesExists> {
+    fn into(self) -> TopologySelectorLabelRequirement {
+        self.body
+    }
+}
+
+/// Builder for [`TopologySelectorLabelRequirement`](./struct.TopologySelectorLabelRequirement.html) object.
+#[derive(Debug, Clone)]
+pub struct TopologySelectorLabelRequirementBuilder<Key, Values> {
+    body: self::TopologySelectorLabelRequirement,
+    _key: core::marker::PhantomData<Key>,
+    _values: core::marker::PhantomData<Values>,
+}
+
+impl<Key, Values> TopologySelectorLabelRequirementBuilder<Key, Values> {
+    /// The label key that the selector applies to.
+    #[inline]
+    pub fn key(mut self, value: impl Into<String>) -> TopologySelectorLabelRequirementBuilder<crate::generics::KeyExists, Values> {
+        self.body.key = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// An array of string values. One value must match the label to be selected. Each entry in Values is ORed.
+    #[inline]
+    pub fn values(mut self, value: impl Iterator<Item = impl Into<String>>) -> TopologySelectorLabelRequirementBuilder<Key, crate::generics::ValuesExists> {
+        self.body.values = value.map(|value| value.into()).collect::<Vec<_>>().into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Same as [`values`](#method.values), but takes the values as a `Vec`.
+    #[inline]
+    pub fn values_vec(mut self, value: impl Into<Vec<String>>) -> TopologySelectorLabelRequirementBuilder<Key, crate::generics::ValuesExists> {
+        self.body.values = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+}
@@ -0,0 +1,64 @@
+
+/// A topology selector term represents the result of label queries. A null or empty topology selector term matches no objects. The requirements of them are ANDed. It provides a subset of functionality as NodeSelectorTerm. This is an alpha feature and may change in the future.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct TopologySelectorTerm {
+    /// A list of topology selector requirements by labels.
+    #[serde(rename = "matchLabelExpressions", skip_serializing_if = "Option::is_none")]
+    pub match_label_expressions: Option<Vec<crate::io::k8s::api::core::v1::topology_selector_label_requirement::TopologySelectorLabelRequirement>>,
+}
+
+impl TopologySelectorTerm {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> TopologySelectorTermBuilder {
+        TopologySelectorTermBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> TopologySelectorTermBuilder
+    where
+        Self: Clone,
+    {
+        TopologySelectorTermBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<TopologySelectorTerm> for TopologySelectorTermBuilder {
+    fn into(self) -> TopologySelectorTerm {
+        self.body
+    }
+}
+
+/// Builder for [`TopologySelectorTerm`](./struct.TopologySelectorTerm.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct TopologySelectorTermBuilder {
+    body: self::TopologySelectorTerm,
+}
+
+impl TopologySelectorTermBuilder {
+    /// A list of topology selector requirements by labels.
+    #[inline]
+    pub fn match_label_expressions(mut self, value: impl Iterator<Item = crate::io::k8s::api::core::v1::topology_selector_label_requirement::TopologySelectorLabelRequirementBuilder<crate::generics::KeyExists, crate::generics::ValuesExists>>) -> Self {
+        self.body.match_label_expressions = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`match_label_expressions`](#method.match_label_expressions) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_match_label_expressions(mut self, value: Option<impl Iterator<Item = crate::io::k8s::api::core::v1::topology_selector_label_requirement::TopologySelectorLabelRequirementBuilder<crate::generics::KeyExists, crate::generics::ValuesExists>>>) -> Self {
+        self.body.match_label_expressions = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`match_label_expressions`](#method.match_label_expressions), but takes the values as a `Vec`.
+    #[inline]
+    pub fn match_label_expressions_vec(mut self, value: impl Into<Vec<crate::io::k8s::api::core::v1::topology_selector_label_requirement::TopologySelectorLabelRequirement>>) -> Self {
+        self.body.match_label_expressions = Some(value.into());
+        self
+    }
+}
@@ -0,0 +1,81 @@
+
+/// TypedLocalObjectReference contains enough information to let you locate the typed referenced object inside the same namespace.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct TypedLocalObjectReference {
+    /// APIGroup is the group for the resource being referenced. If APIGroup is not specified, the specified Kind must be in the core API group. For any other third-party types, APIGroup is required.
+    #[serde(rename = "apiGroup", skip_serializing_if = "Option::is_none")]
+    pub api_group: Option<String>,
+    /// Kind is the type of resource being referenced
+    pub kind: String,
+    /// Name is the name of resource being referenced
+    pub name: String,
+}
+
+impl TypedLocalObjectReference {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> TypedLocalObjectReferenceBuilder<crate::generics::MissingKind, crate::generics::MissingName> {
+        TypedLocalObjectReferenceBuilder {
+            body: Default::default(),
+            _kind: core::marker::PhantomData,
+            _name: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> TypedLocalObjectReferenceBuilder<crate::generics::KindExists, crate::generics::NameExists>
+    where
+        Self: Clone,
+    {
+        TypedLocalObjectReferenceBuilder {
+            body: self.clone(),
+            _kind: core::marker::PhantomData,
+            _name: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<TypedLocalObjectReference> for TypedLocalObjectReferenceBuilder<crate::generics::KindExists, crate::generics::NameExists> {
+    fn into(self) -> TypedLocalObjectReference {
+        self.body
+    }
+}
+
+/// Builder for [`TypedLocalObjectReference`](./struct.TypedLocalObjectReference.html) object.
+#[derive(Debug, Clone)]
+pub struct TypedLocalObjectReferenceBuilder<Kind, Name> {
+    body: self::TypedLocalObjectReference,
+    _kind: core::marker::PhantomData<Kind>,
+    _name: core::marker::PhantomData<Name>,
+}
+
+impl<Kind, Name> TypedLocalObjectReferenceBuilder<Kind, Name> {
+    /// APIGroup is the group for the resource being referenced. If APIGroup is not specified, the specified Kind must be in the core API group. For any other third-party types, APIGroup is required.
+    #[inline]
+    pub fn api_group(mut self, value: impl Into<String>) -> Self {
+        self.body.api_group = Some(value.into());
+        self
+    }
+
+    /// Sets [`api_group`](#method.api_group) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_api_group(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.api_group = value.map(|value| value.into());
+        self
+    }
+
+    /// Kind is the type of resource being referenced
+    #[inline]
+    pub fn kind(mut self, value: impl Into<String>) -> TypedLocalObjectReferenceBuilder<crate::generics::KindExists, Name> {
+        self.body.kind = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Name is the name of resource being referenced
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> TypedLocalObjectReferenceBuilder<Kind, crate::generics::NameExists> {
+        self.body.name = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+}
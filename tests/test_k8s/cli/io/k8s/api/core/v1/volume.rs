@@ -0,0 +1,528 @@
+
+/// Volume represents a named volume in a pod that may be accessed by any container in the pod.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct Volume {
+    /// AWSElasticBlockStore represents an AWS Disk resource that is attached to a kubelet's host machine and then exposed to the pod. More info: https://kubernetes.io/docs/concepts/storage/volumes#awselasticblockstore
+    #[serde(rename = "awsElasticBlockStore", skip_serializing_if = "Option::is_none")]
+    pub aws_elastic_block_store: Option<crate::io::k8s::api::core::v1::aws_elastic_block_store_volume_source::AwsElasticBlockStoreVolumeSource>,
+    /// AzureDisk represents an Azure Data Disk mount on the host and bind mount to the pod.
+    #[serde(rename = "azureDisk", skip_serializing_if = "Option::is_none")]
+    pub azure_disk: Option<crate::io::k8s::api::core::v1::azure_disk_volume_source::AzureDiskVolumeSource>,
+    /// AzureFile represents an Azure File Service mount on the host and bind mount to the pod.
+    #[serde(rename = "azureFile", skip_serializing_if = "Option::is_none")]
+    pub azure_file: Option<crate::io::k8s::api::core::v1::azure_file_volume_source::AzureFileVolumeSource>,
+    /// CephFS represents a Ceph FS mount on the host that shares a pod's lifetime
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cephfs: Option<crate::io::k8s::api::core::v1::ceph_fs_volume_source::CephFsVolumeSource>,
+    /// Cinder represents a cinder volume attached and mounted on kubelets host machine More info: https://releases.k8s.io/HEAD/examples/mysql-cinder-pd/README.md
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cinder: Option<crate::io::k8s::api::core::v1::cinder_volume_source::CinderVolumeSource>,
+    /// ConfigMap represents a configMap that should populate this volume
+    #[serde(rename = "configMap", skip_serializing_if = "Option::is_none")]
+    pub config_map: Option<crate::io::k8s::api::core::v1::config_map_volume_source::ConfigMapVolumeSource>,
+    /// CSI (Container Storage Interface) represents storage that is handled by an external CSI driver (Alpha feature).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub csi: Option<crate::io::k8s::api::core::v1::csi_volume_source::CsiVolumeSource>,
+    /// DownwardAPI represents downward API about the pod that should populate this volume
+    #[serde(rename = "downwardAPI", skip_serializing_if = "Option::is_none")]
+    pub downward_api: Option<crate::io::k8s::api::core::v1::downward_api_volume_source::DownwardApiVolumeSource>,
+    /// EmptyDir represents a temporary directory that shares a pod's lifetime. More info: https://kubernetes.io/docs/concepts/storage/volumes#emptydir
+    #[serde(rename = "emptyDir", skip_serializing_if = "Option::is_none")]
+    pub empty_dir: Option<crate::io::k8s::api::core::v1::empty_dir_volume_source::EmptyDirVolumeSource>,
+    /// FC represents a Fibre Channel resource that is attached to a kubelet's host machine and then exposed to the pod.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fc: Option<crate::io::k8s::api::core::v1::fc_volume_source::FcVolumeSource>,
+    /// FlexVolume represents a generic volume resource that is provisioned/attached using an exec based plugin.
+    #[serde(rename = "flexVolume", skip_serializing_if = "Option::is_none")]
+    pub flex_volume: Option<crate::io::k8s::api::core::v1::flex_volume_source::FlexVolumeSource>,
+    /// Flocker represents a Flocker volume attached to a kubelet's host machine. This depends on the Flocker control service being running
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub flocker: Option<crate::io::k8s::api::core::v1::flocker_volume_source::FlockerVolumeSource>,
+    /// GCEPersistentDisk represents a GCE Disk resource that is attached to a kubelet's host machine and then exposed to the pod. More info: https://kubernetes.io/docs/concepts/storage/volumes#gcepersistentdisk
+    #[serde(rename = "gcePersistentDisk", skip_serializing_if = "Option::is_none")]
+    pub gce_persistent_disk: Option<crate::io::k8s::api::core::v1::gce_persistent_disk_volume_source::GcePersistentDiskVolumeSource>,
+    /// GitRepo represents a git repository at a particular revision. DEPRECATED: GitRepo is deprecated. To provision a container with a git repo, mount an EmptyDir into an InitContainer that clones the repo using git, then mount the EmptyDir into the Pod's container.
+    #[serde(rename = "gitRepo", skip_serializing_if = "Option::is_none")]
+    pub git_repo: Option<crate::io::k8s::api::core::v1::git_repo_volume_source::GitRepoVolumeSource>,
+    /// Glusterfs represents a Glusterfs mount on the host that shares a pod's lifetime. More info: https://releases.k8s.io/HEAD/examples/volumes/glusterfs/README.md
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub glusterfs: Option<crate::io::k8s::api::core::v1::glusterfs_volume_source::GlusterfsVolumeSource>,
+    /// HostPath represents a pre-existing file or directory on the host machine that is directly exposed to the container. This is generally used for system agents or other privileged things that are allowed to see the host machine. Most containers will NOT need this. More info: https://kubernetes.io/docs/concepts/storage/volumes#hostpath
+    #[serde(rename = "hostPath", skip_serializing_if = "Option::is_none")]
+    pub host_path: Option<crate::io::k8s::api::core::v1::host_path_volume_source::HostPathVolumeSource>,
+    /// ISCSI represents an ISCSI Disk resource that is attached to a kubelet's host machine and then exposed to the pod. More info: https://releases.k8s.io/HEAD/examples/volumes/iscsi/README.md
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub iscsi: Option<crate::io::k8s::api::core::v1::iscsi_volume_source::IscsiVolumeSource>,
+    /// Volume's name. Must be a DNS_LABEL and unique within the pod. More info: https://kubernetes.io/docs/concepts/overview/working-with-objects/names/#names
+    pub name: String,
+    /// NFS represents an NFS mount on the host that shares a pod's lifetime More info: https://kubernetes.io/docs/concepts/storage/volumes#nfs
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nfs: Option<crate::io::k8s::api::core::v1::nfs_volume_source::NfsVolumeSource>,
+    /// PersistentVolumeClaimVolumeSource represents a reference to a PersistentVolumeClaim in the same namespace. More info: https://kubernetes.io/docs/concepts/storage/persistent-volumes#persistentvolumeclaims
+    #[serde(rename = "persistentVolumeClaim", skip_serializing_if = "Option::is_none")]
+    pub persistent_volume_claim: Option<crate::io::k8s::api::core::v1::persistent_volume_claim_volume_source::PersistentVolumeClaimVolumeSource>,
+    /// PhotonPersistentDisk represents a PhotonController persistent disk attached and mounted on kubelets host machine
+    #[serde(rename = "photonPersistentDisk", skip_serializing_if = "Option::is_none")]
+    pub photon_persistent_disk: Option<crate::io::k8s::api::core::v1::photon_persistent_disk_volume_source::PhotonPersistentDiskVolumeSource>,
+    /// PortworxVolume represents a portworx volume attached and mounted on kubelets host machine
+    #[serde(rename = "portworxVolume", skip_serializing_if = "Option::is_none")]
+    pub portworx_volume: Option<crate::io::k8s::api::core::v1::portworx_volume_source::PortworxVolumeSource>,
+    /// Items for all in one resources secrets, configmaps, and downward API
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub projected: Option<crate::io::k8s::api::core::v1::projected_volume_source::ProjectedVolumeSource>,
+    /// Quobyte represents a Quobyte mount on the host that shares a pod's lifetime
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quobyte: Option<crate::io::k8s::api::core::v1::quobyte_volume_source::QuobyteVolumeSource>,
+    /// RBD represents a Rados Block Device mount on the host that shares a pod's lifetime. More info: https://releases.k8s.io/HEAD/examples/volumes/rbd/README.md
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rbd: Option<crate::io::k8s::api::core::v1::rbd_volume_source::RbdVolumeSource>,
+    /// ScaleIO represents a ScaleIO persistent volume attached and mounted on Kubernetes nodes.
+    #[serde(rename = "scaleIO", skip_serializing_if = "Option::is_none")]
+    pub scale_io: Option<crate::io::k8s::api::core::v1::scale_io_volume_source::ScaleIoVolumeSource>,
+    /// Secret represents a secret that should populate this volume. More info: https://kubernetes.io/docs/concepts/storage/volumes#secret
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub secret: Option<crate::io::k8s::api::core::v1::secret_volume_source::SecretVolumeSource>,
+    /// StorageOS represents a StorageOS volume attached and mounted on Kubernetes nodes.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub storageos: Option<crate::io::k8s::api::core::v1::storage_os_volume_source::StorageOsVolumeSource>,
+    /// VsphereVolume represents a vSphere volume attached and mounted on kubelets host machine
+    #[serde(rename = "vsphereVolume", skip_serializing_if = "Option::is_none")]
+    pub vsphere_volume: Option<crate::io::k8s::api::core::v1::vsphere_virtual_disk_volume_source::VsphereVirtualDiskVolumeSource>,
+}
+
+impl Volume {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> VolumeBuilder<crate::generics::MissingName> {
+        VolumeBuilder {
+            body: Default::default(),
+            _name: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> VolumeBuilder<crate::generics::NameExists>
+    where
+        Self: Clone,
+    {
+        VolumeBuilder {
+            body: self.clone(),
+            _name: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<Volume> for VolumeBuilder<crate::generics::NameExists> {
+    fn into(self) -> Volume {
+        self.body
+    }
+}
+
+/// Builder for [`Volume`](./struct.Volume.html) object.
+#[derive(Debug, Clone)]
+pub struct VolumeBuilder<Name> {
+    body: self::Volume,
+    _name: core::marker::PhantomData<Name>,
+}
+
+impl<Name> VolumeBuilder<Name> {
+    /// AWSElasticBlockStore represents an AWS Disk resource that is attached to a kubelet's host machine and then exposed to the pod. More info: https://kubernetes.io/docs/concepts/storage/volumes#awselasticblockstore
+    #[inline]
+    pub fn aws_elastic_block_store(mut self, value: crate::io::k8s::api::core::v1::aws_elastic_block_store_volume_source::AwsElasticBlockStoreVolumeSourceBuilder<crate::generics::VolumeIdExists>) -> Self {
+        self.body.aws_elastic_block_store = Some(value.into());
+        self
+    }
+
+    /// Sets [`aws_elastic_block_store`](#method.aws_elastic_block_store) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_aws_elastic_block_store(mut self, value: Option<crate::io::k8s::api::core::v1::aws_elastic_block_store_volume_source::AwsElasticBlockStoreVolumeSourceBuilder<crate::generics::VolumeIdExists>>) -> Self {
+        self.body.aws_elastic_block_store = value.map(|value| value.into());
+        self
+    }
+
+    /// AzureDisk represents an Azure Data Disk mount on the host and bind mount to the pod.
+    #[inline]
+    pub fn azure_disk(mut self, value: crate::io::k8s::api::core::v1::azure_disk_volume_source::AzureDiskVolumeSourceBuilder<crate::generics::DiskNameExists, crate::generics::DiskUriExists>) -> Self {
+        self.body.azure_disk = Some(value.into());
+        self
+    }
+
+    /// Sets [`azure_disk`](#method.azure_disk) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_azure_disk(mut self, value: Option<crate::io::k8s::api::core::v1::azure_disk_volume_source::AzureDiskVolumeSourceBuilder<crate::generics::DiskNameExists, crate::generics::DiskUriExists>>) -> Self {
+        self.body.azure_disk = value.map(|value| value.into());
+        self
+    }
+
+    /// AzureFile represents an Azure File Service mount on the host and bind mount to the pod.
+    #[inline]
+    pub fn azure_file(mut self, value: crate::io::k8s::api::core::v1::azure_file_volume_source::AzureFileVolumeSourceBuilder<crate::generics::SecretNameExists, crate::generics::ShareNameExists>) -> Self {
+        self.body.azure_file = Some(value.into());
+        self
+    }
+
+    /// Sets [`azure_file`](#method.azure_file) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_azure_file(mut self, value: Option<crate::io::k8s::api::core::v1::azure_file_volume_source::AzureFileVolumeSourceBuilder<crate::generics::SecretNameExists, crate::generics::ShareNameExists>>) -> Self {
+        self.body.azure_file = value.map(|value| value.into());
+        self
+    }
+
+    /// CephFS represents a Ceph FS mount on the host that shares a pod's lifetime
+    #[inline]
+    pub fn cephfs(mut self, value: crate::io::k8s::api::core::v1::ceph_fs_volume_source::CephFsVolumeSourceBuilder<crate::generics::MonitorsExists>) -> Self {
+        self.body.cephfs = Some(value.into());
+        self
+    }
+
+    /// Sets [`cephfs`](#method.cephfs) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_cephfs(mut self, value: Option<crate::io::k8s::api::core::v1::ceph_fs_volume_source::CephFsVolumeSourceBuilder<crate::generics::MonitorsExists>>) -> Self {
+        self.body.cephfs = value.map(|value| value.into());
+        self
+    }
+
+    /// Cinder represents a cinder volume attached and mounted on kubelets host machine More info: https://releases.k8s.io/HEAD/examples/mysql-cinder-pd/README.md
+    #[inline]
+    pub fn cinder(mut self, value: crate::io::k8s::api::core::v1::cinder_volume_source::CinderVolumeSourceBuilder<crate::generics::VolumeIdExists>) -> Self {
+        self.body.cinder = Some(value.into());
+        self
+    }
+
+    /// Sets [`cinder`](#method.cinder) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_cinder(mut self, value: Option<crate::io::k8s::api::core::v1::cinder_volume_source::CinderVolumeSourceBuilder<crate::generics::VolumeIdExists>>) -> Self {
+        self.body.cinder = value.map(|value| value.into());
+        self
+    }
+
+    /// ConfigMap represents a configMap that should populate this volume
+    #[inline]
+    pub fn config_map(mut self, value: crate::io::k8s::api::core::v1::config_map_volume_source::ConfigMapVolumeSource) -> Self {
+        self.body.config_map = Some(value.into());
+        self
+    }
+
+    /// Sets [`config_map`](#method.config_map) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_config_map(mut self, value: Option<crate::io::k8s::api::core::v1::config_map_volume_source::ConfigMapVolumeSource>) -> Self {
+        self.body.config_map = value.map(|value| value.into());
+        self
+    }
+
+    /// CSI (Container Storage Interface) represents storage that is handled by an external CSI driver (Alpha feature).
+    #[inline]
+    pub fn csi(mut self, value: crate::io::k8s::api::core::v1::csi_volume_source::CsiVolumeSourceBuilder<crate::generics::DriverExists>) -> Self {
+        self.body.csi = Some(value.into());
+        self
+    }
+
+    /// Sets [`csi`](#method.csi) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_csi(mut self, value: Option<crate::io::k8s::api::core::v1::csi_volume_source::CsiVolumeSourceBuilder<crate::generics::DriverExists>>) -> Self {
+        self.body.csi = value.map(|value| value.into());
+        self
+    }
+
+    /// DownwardAPI represents downward API about the pod that should populate this volume
+    #[inline]
+    pub fn downward_api(mut self, value: crate::io::k8s::api::core::v1::downward_api_volume_source::DownwardApiVolumeSource) -> Self {
+        self.body.downward_api = Some(value.into());
+        self
+    }
+
+    /// Sets [`downward_api`](#method.downward_api) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_downward_api(mut self, value: Option<crate::io::k8s::api::core::v1::downward_api_volume_source::DownwardApiVolumeSource>) -> Self {
+        self.body.downward_api = value.map(|value| value.into());
+        self
+    }
+
+    /// EmptyDir represents a temporary directory that shares a pod's lifetime. More info: https://kubernetes.io/docs/concepts/storage/volumes#emptydir
+    #[inline]
+    pub fn empty_dir(mut self, value: crate::io::k8s::api::core::v1::empty_dir_volume_source::EmptyDirVolumeSource) -> Self {
+        self.body.empty_dir = Some(value.into());
+        self
+    }
+
+    /// Sets [`empty_dir`](#method.empty_dir) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_empty_dir(mut self, value: Option<crate::io::k8s::api::core::v1::empty_dir_volume_source::EmptyDirVolumeSource>) -> Self {
+        self.body.empty_dir = value.map(|value| value.into());
+        self
+    }
+
+    /// FC represents a Fibre Channel resource that is attached to a kubelet's host machine and then exposed to the pod.
+    #[inline]
+    pub fn fc(mut self, value: crate::io::k8s::api::core::v1::fc_volume_source::FcVolumeSource) -> Self {
+        self.body.fc = Some(value.into());
+        self
+    }
+
+    /// Sets [`fc`](#method.fc) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_fc(mut self, value: Option<crate::io::k8s::api::core::v1::fc_volume_source::FcVolumeSource>) -> Self {
+        self.body.fc = value.map(|value| value.into());
+        self
+    }
+
+    /// FlexVolume represents a generic volume resource that is provisioned/attached using an exec based plugin.
+    #[inline]
+    pub fn flex_volume(mut self, value: crate::io::k8s::api::core::v1::flex_volume_source::FlexVolumeSourceBuilder<crate::generics::DriverExists>) -> Self {
+        self.body.flex_volume = Some(value.into());
+        self
+    }
+
+    /// Sets [`flex_volume`](#method.flex_volume) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_flex_volume(mut self, value: Option<crate::io::k8s::api::core::v1::flex_volume_source::FlexVolumeSourceBuilder<crate::generics::DriverExists>>) -> Self {
+        self.body.flex_volume = value.map(|value| value.into());
+        self
+    }
+
+    /// Flocker represents a Flocker volume attached to a kubelet's host machine. This depends on the Flocker control service being running
+    #[inline]
+    pub fn flocker(mut self, value: crate::io::k8s::api::core::v1::flocker_volume_source::FlockerVolumeSource) -> Self {
+        self.body.flocker = Some(value.into());
+        self
+    }
+
+    /// Sets [`flocker`](#method.flocker) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_flocker(mut self, value: Option<crate::io::k8s::api::core::v1::flocker_volume_source::FlockerVolumeSource>) -> Self {
+        self.body.flocker = value.map(|value| value.into());
+        self
+    }
+
+    /// GCEPersistentDisk represents a GCE Disk resource that is attached to a kubelet's host machine and then exposed to the pod. More info: https://kubernetes.io/docs/concepts/storage/volumes#gcepersistentdisk
+    #[inline]
+    pub fn gce_persistent_disk(mut self, value: crate::io::k8s::api::core::v1::gce_persistent_disk_volume_source::GcePersistentDiskVolumeSourceBuilder<crate::generics::PdNameExists>) -> Self {
+        self.body.gce_persistent_disk = Some(value.into());
+        self
+    }
+
+    /// Sets [`gce_persistent_disk`](#method.gce_persistent_disk) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_gce_persistent_disk(mut self, value: Option<crate::io::k8s::api::core::v1::gce_persistent_disk_volume_source::GcePersistentDiskVolumeSourceBuilder<crate::generics::PdNameExists>>) -> Self {
+        self.body.gce_persistent_disk = value.map(|value| value.into());
+        self
+    }
+
+    /// GitRepo represents a git repository at a particular revision. DEPRECATED: GitRepo is deprecated. To provision a container with a git repo, mount an EmptyDir into an InitContainer that clones the repo using git, then mount the EmptyDir into the Pod's container.
+    #[inline]
+    pub fn git_repo(mut self, value: crate::io::k8s::api::core::v1::git_repo_volume_source::GitRepoVolumeSourceBuilder<crate::generics::RepositoryExists>) -> Self {
+        self.body.git_repo = Some(value.into());
+        self
+    }
+
+    /// Sets [`git_repo`](#method.git_repo) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_git_repo(mut self, value: Option<crate::io::k8s::api::core::v1::git_repo_volume_source::GitRepoVolumeSourceBuilder<crate::generics::RepositoryExists>>) -> Self {
+        self.body.git_repo = value.map(|value| value.into());
+        self
+    }
+
+    /// Glusterfs represents a Glusterfs mount on the host that shares a pod's lifetime. More info: https://releases.k8s.io/HEAD/examples/volumes/glusterfs/README.md
+    #[inline]
+    pub fn glusterfs(mut self, value: crate::io::k8s::api::core::v1::glusterfs_volume_source::GlusterfsVolumeSourceBuilder<crate::generics::EndpointsExists, crate::generics::PathExists>) -> Self {
+        self.body.glusterfs = Some(value.into());
+        self
+    }
+
+    /// Sets [`glusterfs`](#method.glusterfs) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_glusterfs(mut self, value: Option<crate::io::k8s::api::core::v1::glusterfs_volume_source::GlusterfsVolumeSourceBuilder<crate::generics::EndpointsExists, crate::generics::PathExists>>) -> Self {
+        self.body.glusterfs = value.map(|value| value.into());
+        self
+    }
+
+    /// HostPath represents a pre-existing file or directory on the host machine that is directly exposed to the container. This is generally used for system agents or other privileged things that are allowed to see the host machine. Most containers will NOT need this. More info: https://kubernetes.io/docs/concepts/storage/volumes#hostpath
+    #[inline]
+    pub fn host_path(mut self, value: crate::io::k8s::api::core::v1::host_path_volume_source::HostPathVolumeSourceBuilder<crate::generics::PathExists>) -> Self {
+        self.body.host_path = Some(value.into());
+        self
+    }
+
+    /// Sets [`host_path`](#method.host_path) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_host_path(mut self, value: Option<crate::io::k8s::api::core::v1::host_path_volume_source::HostPathVolumeSourceBuilder<crate::generics::PathExists>>) -> Self {
+        self.body.host_path = value.map(|value| value.into());
+        self
+    }
+
+    /// ISCSI represents an ISCSI Disk resource that is attached to a kubelet's host machine and then exposed to the pod. More info: https://releases.k8s.io/HEAD/examples/volumes/iscsi/README.md
+    #[inline]
+    pub fn iscsi(mut self, value: crate::io::k8s::api::core::v1::iscsi_volume_source::IscsiVolumeSourceBuilder<crate::generics::IqnExists, crate::generics::LunExists, crate::generics::TargetPortalExists>) -> Self {
+        self.body.iscsi = Some(value.into());
+        self
+    }
+
+    /// Sets [`iscsi`](#method.iscsi) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_iscsi(mut self, value: Option<crate::io::k8s::api::core::v1::iscsi_volume_source::IscsiVolumeSourceBuilder<crate::generics::IqnExists, crate::generics::LunExists, crate::generics::TargetPortalExists>>) -> Self {
+        self.body.iscsi = value.map(|value| value.into());
+        self
+    }
+
+    /// Volume's name. Must be a DNS_LABEL and unique within the pod. More info: https://kubernetes.io/docs/concepts/overview/working-with-objects/names/#names
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> VolumeBuilder<crate::generics::NameExists> {
+        self.body.name = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// NFS represents an NFS mount on the host that shares a pod's lifetime More info: https://kubernetes.io/docs/concepts/storage/volumes#nfs
+    #[inline]
+    pub fn nfs(mut self, value: crate::io::k8s::api::core::v1::nfs_volume_source::NfsVolumeSourceBuilder<crate::generics::PathExists, crate::generics::ServerExists>) -> Self {
+        self.body.nfs = Some(value.into());
+        self
+    }
+
+    /// Sets [`nfs`](#method.nfs) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_nfs(mut self, value: Option<crate::io::k8s::api::core::v1::nfs_volume_source::NfsVolumeSourceBuilder<crate::generics::PathExists, crate::generics::ServerExists>>) -> Self {
+        self.body.nfs = value.map(|value| value.into());
+        self
+    }
+
+    /// PersistentVolumeClaimVolumeSource represents a reference to a PersistentVolumeClaim in the same namespace. More info: https://kubernetes.io/docs/concepts/storage/persistent-volumes#persistentvolumeclaims
+    #[inline]
+    pub fn persistent_volume_claim(mut self, value: crate::io::k8s::api::core::v1::persistent_volume_claim_volume_source::PersistentVolumeClaimVolumeSourceBuilder<crate::generics::ClaimNameExists>) -> Self {
+        self.body.persistent_volume_claim = Some(value.into());
+        self
+    }
+
+    /// Sets [`persistent_volume_claim`](#method.persistent_volume_claim) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_persistent_volume_claim(mut self, value: Option<crate::io::k8s::api::core::v1::persistent_volume_claim_volume_source::PersistentVolumeClaimVolumeSourceBuilder<crate::generics::ClaimNameExists>>) -> Self {
+        self.body.persistent_volume_claim = value.map(|value| value.into());
+        self
+    }
+
+    /// PhotonPersistentDisk represents a PhotonController persistent disk attached and mounted on kubelets host machine
+    #[inline]
+    pub fn photon_persistent_disk(mut self, value: crate::io::k8s::api::core::v1::photon_persistent_disk_volume_source::PhotonPersistentDiskVolumeSourceBuilder<crate::generics::PdIdExists>) -> Self {
+        self.body.photon_persistent_disk = Some(value.into());
+        self
+    }
+
+    /// Sets [`photon_persistent_disk`](#method.photon_persistent_disk) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_photon_persistent_disk(mut self, value: Option<crate::io::k8s::api::core::v1::photon_persistent_disk_volume_source::PhotonPersistentDiskVolumeSourceBuilder<crate::generics::PdIdExists>>) -> Self {
+        self.body.photon_persistent_disk = value.map(|value| value.into());
+        self
+    }
+
+    /// PortworxVolume represents a portworx volume attached and mounted on kubelets host machine
+    #[inline]
+    pub fn portworx_volume(mut self, value: crate::io::k8s::api::core::v1::portworx_volume_source::PortworxVolumeSourceBuilder<crate::generics::VolumeIdExists>) -> Self {
+        self.body.portworx_volume = Some(value.into());
+        self
+    }
+
+    /// Sets [`portworx_volume`](#method.portworx_volume) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_portworx_volume(mut self, value: Option<crate::io::k8s::api::core::v1::portworx_volume_source::PortworxVolumeSourceBuilder<crate::generics::VolumeIdExists>>) -> Self {
+        self.body.portworx_volume = value.map(|value| value.into());
+        self
+    }
+
+    /// Items for all in one resources secrets, configmaps, and downward API
+    #[inline]
+    pub fn projected(mut self, value: crate::io::k8s::api::core::v1::projected_volume_source::ProjectedVolumeSourceBuilder<crate::generics::SourcesExists>) -> Self {
+        self.body.projected = Some(value.into());
+        self
+    }
+
+    /// Sets [`projected`](#method.projected) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_projected(mut self, value: Option<crate::io::k8s::api::core::v1::projected_volume_source::ProjectedVolumeSourceBuilder<crate::generics::SourcesExists>>) -> Self {
+        self.body.projected = value.map(|value| value.into());
+        self
+    }
+
+    /// Quobyte represents a Quobyte mount on the host that shares a pod's lifetime
+    #[inline]
+    pub fn quobyte(mut self, value: crate::io::k8s::api::core::v1::quobyte_volume_source::QuobyteVolumeSourceBuilder<crate::generics::RegistryExists, crate::generics::VolumeExists>) -> Self {
+        self.body.quobyte = Some(value.into());
+        self
+    }
+
+    /// Sets [`quobyte`](#method.quobyte) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_quobyte(mut self, value: Option<crate::io::k8s::api::core::v1::quobyte_volume_source::QuobyteVolumeSourceBuilder<crate::generics::RegistryExists, crate::generics::VolumeExists>>) -> Self {
+        self.body.quobyte = value.map(|value| value.into());
+        self
+    }
+
+    /// RBD represents a Rados Block Device mount on the host that shares a pod's lifetime. More info: https://releases.k8s.io/HEAD/examples/volumes/rbd/README.md
+    #[inline]
+    pub fn rbd(mut self, value: crate::io::k8s::api::core::v1::rbd_volume_source::RbdVolumeSourceBuilder<crate::generics::ImageExists, crate::generics::MonitorsExists>) -> Self {
+        self.body.rbd = Some(value.into());
+        self
+    }
+
+    /// Sets [`rbd`](#method.rbd) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_rbd(mut self, value: Option<crate::io::k8s::api::core::v1::rbd_volume_source::RbdVolumeSourceBuilder<crate::generics::ImageExists, crate::generics::MonitorsExists>>) -> Self {
+        self.body.rbd = value.map(|value| value.into());
+        self
+    }
+
+    /// ScaleIO represents a ScaleIO persistent volume attached and mounted on Kubernetes nodes.
+    #[inline]
+    pub fn scale_io(mut self, value: crate::io::k8s::api::core::v1::scale_io_volume_source::ScaleIoVolumeSourceBuilder<crate::generics::GatewayExists, crate::generics::SecretRefExists, crate::generics::SystemExists>) -> Self {
+        self.body.scale_io = Some(value.into());
+        self
+    }
+
+    /// Sets [`scale_io`](#method.scale_io) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_scale_io(mut self, value: Option<crate::io::k8s::api::core::v1::scale_io_volume_source::ScaleIoVolumeSourceBuilder<crate::generics::GatewayExists, crate::generics::SecretRefExists, crate::generics::SystemExists>>) -> Self {
+        self.body.scale_io = value.map(|value| value.into());
+        self
+    }
+
+    /// Secret represents a secret that should populate this volume. More info: https://kubernetes.io/docs/concepts/storage/volumes#secret
+    #[inline]
+    pub fn secret(mut self, value: crate::io::k8s::api::core::v1::secret_volume_source::SecretVolumeSource) -> Self {
+        self.body.secret = Some(value.into());
+        self
+    }
+
+    /// Sets [`secret`](#method.secret) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_secret(mut self, value: Option<crate::io::k8s::api::core::v1::secret_volume_source::SecretVolumeSource>) -> Self {
+        self.body.secret = value.map(|value| value.into());
+        self
+    }
+
+    /// StorageOS represents a StorageOS volume attached and mounted on Kubernetes nodes.
+    #[inline]
+    pub fn storageos(mut self, value: crate::io::k8s::api::core::v1::storage_os_volume_source::StorageOsVolumeSource) -> Self {
+        self.body.storageos = Some(value.into());
+        self
+    }
+
+    /// Sets [`storageos`](#method.storageos) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_storageos(mut self, value: Option<crate::io::k8s::api::core::v1::storage_os_volume_source::StorageOsVolumeSource>) -> Self {
+        self.body.storageos = value.map(|value| value.into());
+        self
+    }
+
+    /// VsphereVolume represents a vSphere volume attached and mounted on kubelets host machine
+    #[inline]
+    pub fn vsphere_volume(mut self, value: crate::io::k8s::api::core::v1::vsphere_virtual_disk_volume_source::VsphereVirtualDiskVolumeSourceBuilder<crate::generics::VolumePathExists>) -> Self {
+        self.body.vsphere_volume = Some(value.into());
+        self
+    }
+
+    /// Sets [`vsphere_volume`](#method.vsphere_volume) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_vsphere_volume(mut self, value: Option<crate::io::k8s::api::core::v1::vsphere_virtual_disk_volume_source::VsphereVirtualDiskVolumeSourceBuilder<crate::generics::VolumePathExists>>) -> Self {
+        self.body.vsphere_volume = value.map(|value| value.into());
+        self
+    }
+}
@@ -0,0 +1,65 @@
+
+/// volumeDevice describes a mapping of a raw block device within a container.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct VolumeDevice {
+    /// devicePath is the path inside of the container that the device will be mapped to.
+    #[serde(rename = "devicePath")]
+    pub device_path: String,
+    /// name must match the name of a persistentVolumeClaim in the pod
+    pub name: String,
+}
+
+impl VolumeDevice {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> VolumeDeviceBuilder<crate::generics::MissingDevicePath, crate::generics::MissingName> {
+        VolumeDeviceBuilder {
+            body: Default::default(),
+            _device_path: core::marker::PhantomData,
+            _name: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> VolumeDeviceBuilder<crate::generics::DevicePathExists, crate::generics::NameExists>
+    where
+        Self: Clone,
+    {
+        VolumeDeviceBuilder {
+            body: self.clone(),
+            _device_path: core::marker::PhantomData,
+            _name: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<VolumeDevice> for VolumeDeviceBuilder<crate::generics::DevicePathExists, crate::generics::NameExists> {
+    fn into(self) -> VolumeDevice {
+        self.body
+    }
+}
+
+/// Builder for [`VolumeDevice`](./struct.VolumeDevice.html) object.
+#[derive(Debug, Clone)]
+pub struct VolumeDeviceBuilder<DevicePath, Name> {
+    body: self::VolumeDevice,
+    _device_path: core::marker::PhantomData<DevicePath>,
+    _name: core::marker::PhantomData<Name>,
+}
+
+impl<DevicePath, Name> VolumeDeviceBuilder<DevicePath, Name> {
+    /// devicePath is the path inside of the container that the device will be mapped to.
+    #[inline]
+    pub fn device_path(mut self, value: impl Into<String>) -> VolumeDeviceBuilder<crate::generics::DevicePathExists, Name> {
+        self.body.device_path = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// name must match the name of a persistentVolumeClaim in the pod
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> VolumeDeviceBuilder<DevicePath, crate::generics::NameExists> {
+        self.body.name = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+}
@@ -0,0 +1,139 @@
+
+/// VolumeMount describes a mounting of a Volume within a container.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct VolumeMount {
+    /// Path within the container at which the volume should be mounted.  Must not contain ':'.
+    #[serde(rename = "mountPath")]
+    pub mount_path: String,
+    /// mountPropagation determines how mounts are propagated from the host to container and the other way around. When not set, MountPropagationNone is used. This field is beta in 1.10.
+    #[serde(rename = "mountPropagation", skip_serializing_if = "Option::is_none")]
+    pub mount_propagation: Option<String>,
+    /// This must match the Name of a Volume.
+    pub name: String,
+    /// Mounted read-only if true, read-write otherwise (false or unspecified). Defaults to false.
+    #[serde(rename = "readOnly", skip_serializing_if = "Option::is_none")]
+    pub read_only: Option<bool>,
+    /// Path within the volume from which the container's volume should be mounted. Defaults to "" (volume's root).
+    #[serde(rename = "subPath", skip_serializing_if = "Option::is_none")]
+    pub sub_path: Option<String>,
+    /// Expanded path within the volume from which the container's volume should be mounted. Behaves similarly to SubPath but environment variable references $(VAR_NAME) are expanded using the container's environment. Defaults to "" (volume's root). SubPathExpr and SubPath are mutually exclusive. This field is beta in 1.15.
+    #[serde(rename = "subPathExpr", skip_serializing_if = "Option::is_none")]
+    pub sub_path_expr: Option<String>,
+}
+
+impl VolumeMount {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> VolumeMountBuilder<crate::generics::MissingMountPath, crate::generics::MissingName> {
+        VolumeMountBuilder {
+            body: Default::default(),
+            _mount_path: core::marker::PhantomData,
+            _name: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> VolumeMountBuilder<crate::generics::MountPathExists, crate::generics::NameExists>
+    where
+        Self: Clone,
+    {
+        VolumeMountBuilder {
+            body: self.clone(),
+            _mount_path: core::marker::PhantomData,
+            _name: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<VolumeMount> for VolumeMountBuilder<crate::generics::MountPathExists, crate::generics::NameExists> {
+    fn into(self) -> VolumeMount {
+        self.body
+    }
+}
+
+/// Builder for [`VolumeMount`](./struct.VolumeMount.html) object.
+#[derive(Debug, Clone)]
+pub struct VolumeMountBuilder<MountPath, Name> {
+    body: self::VolumeMount,
+    _mount_path: core::marker::PhantomData<MountPath>,
+    _name: core::marker::PhantomData<Name>,
+}
+
+impl<MountPath, Name> VolumeMountBuilder<MountPath, Name> {
+    /// Path within the container at which the volume should be mounted.  Must not contain ':'.
+    #[inline]
+    pub fn mount_path(mut self, value: impl Into<String>) -> VolumeMountBuilder<crate::generics::MountPathExists, Name> {
+        self.body.mount_path = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// mountPropagation determines how mounts are propagated from the host to container and the other way around. When not set, MountPropagationNone is used. This field is beta in 1.10.
+    #[inline]
+    pub fn mount_propagation(mut self, value: impl Into<String>) -> Self {
+        self.body.mount_propagation = Some(value.into());
+        self
+    }
+
+    /// Sets [`mount_propagation`](#method.mount_propagation) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_mount_propagation(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.mount_propagation = value.map(|value| value.into());
+        self
+    }
+
+    /// This must match the Name of a Volume.
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> VolumeMountBuilder<MountPath, crate::generics::NameExists> {
+        self.body.name = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Mounted read-only if true, read-write otherwise (false or unspecified). Defaults to false.
+    #[inline]
+    pub fn read_only(mut self, value: impl Into<bool>) -> Self {
+        self.body.read_only = Some(value.into());
+        self
+    }
+
+    /// Sets [`read_only`](#method.read_only) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_read_only(mut self, value: Option<impl Into<bool>>) -> Self {
+        self.body.read_only = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`read_only`](#method.read_only), but takes a reference.
+    #[inline]
+    pub fn read_only_ref(self, value: &bool) -> Self {
+        self.read_only(*value)
+    }
+
+    /// Path within the volume from which the container's volume should be mounted. Defaults to "" (volume's root).
+    #[inline]
+    pub fn sub_path(mut self, value: impl Into<String>) -> Self {
+        self.body.sub_path = Some(value.into());
+        self
+    }
+
+    /// Sets [`sub_path`](#method.sub_path) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_sub_path(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.sub_path = value.map(|value| value.into());
+        self
+    }
+
+    /// Expanded path within the volume from which the container's volume should be mounted. Behaves similarly to SubPath but environment variable references $(VAR_NAME) are expanded using the container's environment. Defaults to "" (volume's root). SubPathExpr and SubPath are mutually exclusive. This field is beta in 1.15.
+    #[inline]
+    pub fn sub_path_expr(mut self, value: impl Into<String>) -> Self {
+        self.body.sub_path_expr = Some(value.into());
+        self
+    }
+
+    /// Sets [`sub_path_expr`](#method.sub_path_expr) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_sub_path_expr(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.sub_path_expr = value.map(|value| value.into());
+        self
+    }
+}
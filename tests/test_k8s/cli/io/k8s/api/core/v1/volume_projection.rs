@@ -0,0 +1,108 @@
+
+/// Projection that may be projected along with other supported volume types
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct VolumeProjection {
+    /// information about the configMap data to project
+    #[serde(rename = "configMap", skip_serializing_if = "Option::is_none")]
+    pub config_map: Option<crate::io::k8s::api::core::v1::config_map_projection::ConfigMapProjection>,
+    /// information about the downwardAPI data to project
+    #[serde(rename = "downwardAPI", skip_serializing_if = "Option::is_none")]
+    pub downward_api: Option<crate::io::k8s::api::core::v1::downward_api_projection::DownwardApiProjection>,
+    /// information about the secret data to project
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub secret: Option<crate::io::k8s::api::core::v1::secret_projection::SecretProjection>,
+    /// information about the serviceAccountToken data to project
+    #[serde(rename = "serviceAccountToken", skip_serializing_if = "Option::is_none")]
+    pub service_account_token: Option<crate::io::k8s::api::core::v1::service_account_token_projection::ServiceAccountTokenProjection>,
+}
+
+impl VolumeProjection {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> VolumeProjectionBuilder {
+        VolumeProjectionBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> VolumeProjectionBuilder
+    where
+        Self: Clone,
+    {
+        VolumeProjectionBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<VolumeProjection> for VolumeProjectionBuilder {
+    fn into(self) -> VolumeProjection {
+        self.body
+    }
+}
+
+/// Builder for [`VolumeProjection`](./struct.VolumeProjection.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct VolumeProjectionBuilder {
+    body: self::VolumeProjection,
+}
+
+impl VolumeProjectionBuilder {
+    /// information about the configMap data to project
+    #[inline]
+    pub fn config_map(mut self, value: crate::io::k8s::api::core::v1::config_map_projection::ConfigMapProjection) -> Self {
+        self.body.config_map = Some(value.into());
+        self
+    }
+
+    /// Sets [`config_map`](#method.config_map) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_config_map(mut self, value: Option<crate::io::k8s::api::core::v1::config_map_projection::ConfigMapProjection>) -> Self {
+        self.body.config_map = value.map(|value| value.into());
+        self
+    }
+
+    /// information about the downwardAPI data to project
+    #[inline]
+    pub fn downward_api(mut self, value: crate::io::k8s::api::core::v1::downward_api_projection::DownwardApiProjection) -> Self {
+        self.body.downward_api = Some(value.into());
+        self
+    }
+
+    /// Sets [`downward_api`](#method.downward_api) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_downward_api(mut self, value: Option<crate::io::k8s::api::core::v1::downward_api_projection::DownwardApiProjection>) -> Self {
+        self.body.downward_api = value.map(|value| value.into());
+        self
+    }
+
+    /// information about the secret data to project
+    #[inline]
+    pub fn secret(mut self, value: crate::io::k8s::api::core::v1::secret_projection::SecretProjection) -> Self {
+        self.body.secret = Some(value.into());
+        self
+    }
+
+    /// Sets [`secret`](#method.secret) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_secret(mut self, value: Option<crate::io::k8s::api::core::v1::secret_projection::SecretProjection>) -> Self {
+        self.body.secret = value.map(|value| value.into());
+        self
+    }
+
+    /// information about the serviceAccountToken data to project
+    #[inline]
+    pub fn service_account_token(mut self, value: crate::io::k8s::api::core::v1::service_account_token_projection::ServiceAccountTokenProjectionBuilder<crate::generics::PathExists>) -> Self {
+        self.body.service_account_token = Some(value.into());
+        self
+    }
+
+    /// Sets [`service_account_token`](#method.service_account_token) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_service_account_token(mut self, value: Option<crate::io::k8s::api::core::v1::service_account_token_projection::ServiceAccountTokenProjectionBuilder<crate::generics::PathExists>>) -> Self {
+        self.body.service_account_token = value.map(|value| value.into());
+        self
+    }
+}
@@ -0,0 +1,71 @@
+
+/// The weights of all of the matched WeightedPodAffinityTerm fields are added per-node to find the most preferred node(s)
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct WeightedPodAffinityTerm {
+    /// Required. A pod affinity term, associated with the corresponding weight.
+    #[serde(rename = "podAffinityTerm")]
+    pub pod_affinity_term: crate::io::k8s::api::core::v1::pod_affinity_term::PodAffinityTerm,
+    /// weight associated with matching the corresponding podAffinityTerm, in the range 1-100.
+    pub weight: i32,
+}
+
+impl WeightedPodAffinityTerm {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> WeightedPodAffinityTermBuilder<crate::generics::MissingPodAffinityTerm, crate::generics::MissingWeight> {
+        WeightedPodAffinityTermBuilder {
+            body: Default::default(),
+            _pod_affinity_term: core::marker::PhantomData,
+            _weight: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> WeightedPodAffinityTermBuilder<crate::generics::PodAffinityTermExists, crate::generics::WeightExists>
+    where
+        Self: Clone,
+    {
+        WeightedPodAffinityTermBuilder {
+            body: self.clone(),
+            _pod_affinity_term: core::marker::PhantomData,
+            _weight: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<WeightedPodAffinityTerm> for WeightedPodAffinityTermBuilder<crate::generics::PodAffinityTermExists, crate::generics::WeightExists> {
+    fn into(self) -> WeightedPodAffinityTerm {
+        self.body
+    }
+}
+
+/// Builder for [`WeightedPodAffinityTerm`](./struct.WeightedPodAffinityTerm.html) object.
+#[derive(Debug, Clone)]
+pub struct WeightedPodAffinityTermBuilder<PodAffinityTerm, Weight> {
+    body: self::WeightedPodAffinityTerm,
+    _pod_affinity_term: core::marker::PhantomData<PodAffinityTerm>,
+    _weight: core::marker::PhantomData<Weight>,
+}
+
+impl<PodAffinityTerm, Weight> WeightedPodAffinityTermBuilder<PodAffinityTerm, Weight> {
+    /// Required. A pod affinity term, associated with the corresponding weight.
+    #[inline]
+    pub fn pod_affinity_term(mut self, value: crate::io::k8s::api::core::v1::pod_affinity_term::PodAffinityTermBuilder<crate::generics::TopologyKeyExists>) -> WeightedPodAffinityTermBuilder<crate::generics::PodAffinityTermExists, Weight> {
+        self.body.pod_affinity_term = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// weight associated with matching the corresponding podAffinityTerm, in the range 1-100.
+    #[inline]
+    pub fn weight(mut self, value: impl Into<i32>) -> WeightedPodAffinityTermBuilder<PodAffinityTerm, crate::generics::WeightExists> {
+        self.body.weight = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Same as [`weight`](#method.weight), but takes a reference.
+    #[inline]
+    pub fn weight_ref(self, value: &i32) -> WeightedPodAffinityTermBuilder<PodAffinityTerm, crate::generics::WeightExists> {
+        self.weight(*value)
+    }
+}
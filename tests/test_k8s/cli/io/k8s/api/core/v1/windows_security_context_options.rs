@@ -0,0 +1,5 @@
+
+/// WindowsSecurityContextOptions contain Windows-specific options and credentials.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct WindowsSecurityContextOptions {}
+
@@ -0,0 +1,12 @@
+
+pub mod event {
+    include!("./event.rs");
+}
+
+pub mod event_list {
+    include!("./event_list.rs");
+}
+
+pub mod event_series {
+    include!("./event_series.rs");
+}
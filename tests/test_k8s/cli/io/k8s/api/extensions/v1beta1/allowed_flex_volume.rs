@@ -0,0 +1,52 @@
+
+/// AllowedFlexVolume represents a single Flexvolume that is allowed to be used. Deprecated: use AllowedFlexVolume from policy API Group instead.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct AllowedFlexVolume {
+    /// driver is the name of the Flexvolume driver.
+    pub driver: String,
+}
+
+impl AllowedFlexVolume {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> AllowedFlexVolumeBuilder<crate::generics::MissingDriver> {
+        AllowedFlexVolumeBuilder {
+            body: Default::default(),
+            _driver: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> AllowedFlexVolumeBuilder<crate::generics::DriverExists>
+    where
+        Self: Clone,
+    {
+        AllowedFlexVolumeBuilder {
+            body: self.clone(),
+            _driver: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<AllowedFlexVolume> for AllowedFlexVolumeBuilder<crate::generics::DriverExists> {
+    fn into(self) -> AllowedFlexVolume {
+        self.body
+    }
+}
+
+/// Builder for [`AllowedFlexVolume`](./struct.AllowedFlexVolume.html) object.
+#[derive(Debug, Clone)]
+pub struct AllowedFlexVolumeBuilder<Driver> {
+    body: self::AllowedFlexVolume,
+    _driver: core::marker::PhantomData<Driver>,
+}
+
+impl<Driver> AllowedFlexVolumeBuilder<Driver> {
+    /// driver is the name of the Flexvolume driver.
+    #[inline]
+    pub fn driver(mut self, value: impl Into<String>) -> AllowedFlexVolumeBuilder<crate::generics::DriverExists> {
+        self.body.driver = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+}
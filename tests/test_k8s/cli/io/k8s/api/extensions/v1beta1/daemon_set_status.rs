@@ -0,0 +1,255 @@
+
+/// DaemonSetStatus represents the current status of a daemon set.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct DaemonSetStatus {
+    /// Count of hash collisions for the DaemonSet. The DaemonSet controller uses this field as a collision avoidance mechanism when it needs to create the name for the newest ControllerRevision.
+    #[serde(rename = "collisionCount", skip_serializing_if = "Option::is_none")]
+    pub collision_count: Option<i32>,
+    /// Represents the latest available observations of a DaemonSet's current state.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub conditions: Option<Vec<crate::io::k8s::api::extensions::v1beta1::daemon_set_condition::DaemonSetCondition>>,
+    /// The number of nodes that are running at least 1 daemon pod and are supposed to run the daemon pod. More info: https://kubernetes.io/docs/concepts/workloads/controllers/daemonset/
+    #[serde(rename = "currentNumberScheduled")]
+    pub current_number_scheduled: i32,
+    /// The total number of nodes that should be running the daemon pod (including nodes correctly running the daemon pod). More info: https://kubernetes.io/docs/concepts/workloads/controllers/daemonset/
+    #[serde(rename = "desiredNumberScheduled")]
+    pub desired_number_scheduled: i32,
+    /// The number of nodes that should be running the daemon pod and have one or more of the daemon pod running and available (ready for at least spec.minReadySeconds)
+    #[serde(rename = "numberAvailable", skip_serializing_if = "Option::is_none")]
+    pub number_available: Option<i32>,
+    /// The number of nodes that are running the daemon pod, but are not supposed to run the daemon pod. More info: https://kubernetes.io/docs/concepts/workloads/controllers/daemonset/
+    #[serde(rename = "numberMisscheduled")]
+    pub number_misscheduled: i32,
+    /// The number of nodes that should be running the daemon pod and have one or more of the daemon pod running and ready.
+    #[serde(rename = "numberReady")]
+    pub number_ready: i32,
+    /// The number of nodes that should be running the daemon pod and have none of the daemon pod running and available (ready for at least spec.minReadySeconds)
+    #[serde(rename = "numberUnavailable", skip_serializing_if = "Option::is_none")]
+    pub number_unavailable: Option<i32>,
+    /// The most recent generation observed by the daemon set controller.
+    #[serde(rename = "observedGeneration", skip_serializing_if = "Option::is_none")]
+    pub observed_generation: Option<i64>,
+    /// The total number of nodes that are running updated daemon pod
+    #[serde(rename = "updatedNumberScheduled", skip_serializing_if = "Option::is_none")]
+    pub updated_number_scheduled: Option<i32>,
+}
+
+impl DaemonSetStatus {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> DaemonSetStatusBuilder<crate::generics::MissingCurrentNumberScheduled, crate::generics::MissingDesiredNumberScheduled, crate::generics::MissingNumberMisscheduled, crate::generics::MissingNumberReady> {
+        DaemonSetStatusBuilder {
+            body: Default::default(),
+            _current_number_scheduled: core::marker::PhantomData,
+            _desired_number_scheduled: core::marker::PhantomData,
+            _number_misscheduled: core::marker::PhantomData,
+            _number_ready: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> DaemonSetStatusBuilder<crate::generics::CurrentNumberScheduledExists, crate::generics::DesiredNumberScheduledExists, crate::generics::NumberMisscheduledExists, crate::generics::NumberReadyExists>
+    where
+        Self: Clone,
+    {
+        DaemonSetStatusBuilder {
+            body: self.clone(),
+            _current_number_scheduled: core::marker::PhantomData,
+            _desired_number_scheduled: core::marker::PhantomData,
+            _number_misscheduled: core::marker::PhantomData,
+            _number_ready: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<DaemonSetStatus> for DaemonSetStatusBuilder<crate::generics::CurrentNumberScheduledExists, crate::generics::DesiredNumberScheduledExists, crate::generics::NumberMisscheduledExists, crate::generics::NumberReadyExists> {
+    fn into(self) -> DaemonSetStatus {
+        self.body
+    }
+}
+
+/// Builder for [`DaemonSetStatus`](./struct.DaemonSetStatus.html) object.
+#[derive(Debug, Clone)]
+pub struct DaemonSetStatusBuilder<CurrentNumberScheduled, DesiredNumberScheduled, NumberMisscheduled, NumberReady> {
+    body: self::DaemonSetStatus,
+    _current_number_scheduled: core::marker::PhantomData<CurrentNumberScheduled>,
+    _desired_number_scheduled: core::marker::PhantomData<DesiredNumberScheduled>,
+    _number_misscheduled: core::marker::PhantomData<NumberMisscheduled>,
+    _number_ready: core::marker::PhantomData<NumberReady>,
+}
+
+impl<CurrentNumberScheduled, DesiredNumberScheduled, NumberMisscheduled, NumberReady> DaemonSetStatusBuilder<CurrentNumberScheduled, DesiredNumberScheduled, NumberMisscheduled, NumberReady> {
+    /// Count of hash collisions for the DaemonSet. The DaemonSet controller uses this field as a collision avoidance mechanism when it needs to create the name for the newest ControllerRevision.
+    #[inline]
+    pub fn collision_count(mut self, value: impl Into<i32>) -> Self {
+        self.body.collision_count = Some(value.into());
+        self
+    }
+
+    /// Sets [`collision_count`](#method.collision_count) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_collision_count(mut self, value: Option<impl Into<i32>>) -> Self {
+        self.body.collision_count = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`collision_count`](#method.collision_count), but takes a reference.
+    #[inline]
+    pub fn collision_count_ref(self, value: &i32) -> Self {
+        self.collision_count(*value)
+    }
+
+    /// Represents the latest available observations of a DaemonSet's current state.
+    #[inline]
+    pub fn conditions(mut self, value: impl Iterator<Item = crate::io::k8s::api::extensions::v1beta1::daemon_set_condition::DaemonSetConditionBuilder<crate::generics::StatusExists, crate::generics::TypeExists>>) -> Self {
+        self.body.conditions = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`conditions`](#method.conditions) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_conditions(mut self, value: Option<impl Iterator<Item = crate::io::k8s::api::extensions::v1beta1::daemon_set_condition::DaemonSetConditionBuilder<crate::generics::StatusExists, crate::generics::TypeExists>>>) -> Self {
+        self.body.conditions = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`conditions`](#method.conditions), but takes the values as a `Vec`.
+    #[inline]
+    pub fn conditions_vec(mut self, value: impl Into<Vec<crate::io::k8s::api::extensions::v1beta1::daemon_set_condition::DaemonSetCondition>>) -> Self {
+        self.body.conditions = Some(value.into());
+        self
+    }
+
+    /// The number of nodes that are running at least 1 daemon pod and are supposed to run the daemon pod. More info: https://kubernetes.io/docs/concepts/workloads/controllers/daemonset/
+    #[inline]
+    pub fn current_number_scheduled(mut self, value: impl Into<i32>) -> DaemonSetStatusBuilder<crate::generics::CurrentNumberScheduledExists, DesiredNumberScheduled, NumberMisscheduled, NumberReady> {
+        self.body.current_number_scheduled = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Same as [`current_number_scheduled`](#method.current_number_scheduled), but takes a reference.
+    #[inline]
+    pub fn current_number_scheduled_ref(self, value: &i32) -> DaemonSetStatusBuilder<crate::generics::CurrentNumberScheduledExists, DesiredNumberScheduled, NumberMisscheduled, NumberReady> {
+        self.current_number_scheduled(*value)
+    }
+
+    /// The total number of nodes that should be running the daemon pod (including nodes correctly running the daemon pod). More info: https://kubernetes.io/docs/concepts/workloads/controllers/daemonset/
+    #[inline]
+    pub fn desired_number_scheduled(mut self, value: impl Into<i32>) -> DaemonSetStatusBuilder<CurrentNumberScheduled, crate::generics::DesiredNumberScheduledExists, NumberMisscheduled, NumberReady> {
+        self.body.desired_number_scheduled = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Same as [`desired_number_scheduled`](#method.desired_number_scheduled), but takes a reference.
+    #[inline]
+    pub fn desired_number_scheduled_ref(self, value: &i32) -> DaemonSetStatusBuilder<CurrentNumberScheduled, crate::generics::DesiredNumberScheduledExists, NumberMisscheduled, NumberReady> {
+        self.desired_number_scheduled(*value)
+    }
+
+    /// The number of nodes that should be running the daemon pod and have one or more of the daemon pod running and available (ready for at least spec.minReadySeconds)
+    #[inline]
+    pub fn number_available(mut self, value: impl Into<i32>) -> Self {
+        self.body.number_available = Some(value.into());
+        self
+    }
+
+    /// Sets [`number_available`](#method.number_available) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_number_available(mut self, value: Option<impl Into<i32>>) -> Self {
+        self.body.number_available = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`number_available`](#method.number_available), but takes a reference.
+    #[inline]
+    pub fn number_available_ref(self, value: &i32) -> Self {
+        self.number_available(*value)
+    }
+
+    /// The number of nodes that are running the daemon pod, but are not supposed to run the daemon pod. More info: https://kubernetes.io/docs/concepts/workloads/controllers/daemonset/
+    #[inline]
+    pub fn number_misscheduled(mut self, value: impl Into<i32>) -> DaemonSetStatusBuilder<CurrentNumberScheduled, DesiredNumberScheduled, crate::generics::NumberMisscheduledExists, NumberReady> {
+        self.body.number_misscheduled = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Same as [`number_misscheduled`](#method.number_misscheduled), but takes a reference.
+    #[inline]
+    pub fn number_misscheduled_ref(self, value: &i32) -> DaemonSetStatusBuilder<CurrentNumberScheduled, DesiredNumberScheduled, crate::generics::NumberMisscheduledExists, NumberReady> {
+        self.number_misscheduled(*value)
+    }
+
+    /// The number of nodes that should be running the daemon pod and have one or more of the daemon pod running and ready.
+    #[inline]
+    pub fn number_ready(mut self, value: impl Into<i32>) -> DaemonSetStatusBuilder<CurrentNumberScheduled, DesiredNumberScheduled, NumberMisscheduled, crate::generics::NumberReadyExists> {
+        self.body.number_ready = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Same as [`number_ready`](#method.number_ready), but takes a reference.
+    #[inline]
+    pub fn number_ready_ref(self, value: &i32) -> DaemonSetStatusBuilder<CurrentNumberScheduled, DesiredNumberScheduled, NumberMisscheduled, crate::generics::NumberReadyExists> {
+        self.number_ready(*value)
+    }
+
+    /// The number of nodes that should be running the daemon pod and have none of the daemon pod running and available (ready for at least spec.minReadySeconds)
+    #[inline]
+    pub fn number_unavailable(mut self, value: impl Into<i32>) -> Self {
+        self.body.number_unavailable = Some(value.into());
+        self
+    }
+
+    /// Sets [`number_unavailable`](#method.number_unavailable) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_number_unavailable(mut self, value: Option<impl Into<i32>>) -> Self {
+        self.body.number_unavailable = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`number_unavailable`](#method.number_unavailable), but takes a reference.
+    #[inline]
+    pub fn number_unavailable_ref(self, value: &i32) -> Self {
+        self.number_unavailable(*value)
+    }
+
+    /// The most recent generation observed by the daemon set controller.
+    #[inline]
+    pub fn observed_generation(mut self, value: impl Into<i64>) -> Self {
+        self.body.observed_generation = Some(value.into());
+        self
+    }
+
+    /// Sets [`observed_generation`](#method.observed_generation) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_observed_generation(mut self, value: Option<impl Into<i64>>) -> Self {
+        self.body.observed_generation = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`observed_generation`](#method.observed_generation), but takes a reference.
+    #[inline]
+    pub fn observed_generation_ref(self, value: &i64) -> Self {
+        self.observed_generation(*value)
+    }
+
+    /// The total number of nodes that are running updated daemon pod
+    #[inline]
+    pub fn updated_number_scheduled(mut self, value: impl Into<i32>) -> Self {
+        self.body.updated_number_scheduled = Some(value.into());
+        self
+    }
+
+    /// Sets [`updated_number_scheduled`](#method.updated_number_scheduled) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_updated_number_scheduled(mut self, value: Option<impl Into<i32>>) -> Self {
+        self.body.updated_number_scheduled = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`updated_number_scheduled`](#method.updated_number_scheduled), but takes a reference.
+    #[inline]
+    pub fn updated_number_scheduled_ref(self, value: &i32) -> Self {
+        self.updated_number_scheduled(*value)
+    }
+}
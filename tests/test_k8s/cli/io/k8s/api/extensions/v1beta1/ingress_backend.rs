@@ -0,0 +1,66 @@
+
+/// IngressBackend describes all endpoints for a given service and port.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct IngressBackend {
+    /// Specifies the name of the referenced service.
+    #[serde(rename = "serviceName")]
+    pub service_name: String,
+    /// Specifies the port of the referenced service.
+    #[serde(rename = "servicePort")]
+    pub service_port: String,
+}
+
+impl IngressBackend {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> IngressBackendBuilder<crate::generics::MissingServiceName, crate::generics::MissingServicePort> {
+        IngressBackendBuilder {
+            body: Default::default(),
+            _service_name: core::marker::PhantomData,
+            _service_port: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> IngressBackendBuilder<crate::generics::ServiceNameExists, crate::generics::ServicePortExists>
+    where
+        Self: Clone,
+    {
+        IngressBackendBuilder {
+            body: self.clone(),
+            _service_name: core::marker::PhantomData,
+            _service_port: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<IngressBackend> for IngressBackendBuilder<crate::generics::ServiceNameExists, crate::generics::ServicePortExists> {
+    fn into(self) -> IngressBackend {
+        self.body
+    }
+}
+
+/// Builder for [`IngressBackend`](./struct.IngressBackend.html) object.
+#[derive(Debug, Clone)]
+pub struct IngressBackendBuilder<ServiceName, ServicePort> {
+    body: self::IngressBackend,
+    _service_name: core::marker::PhantomData<ServiceName>,
+    _service_port: core::marker::PhantomData<ServicePort>,
+}
+
+impl<ServiceName, ServicePort> IngressBackendBuilder<ServiceName, ServicePort> {
+    /// Specifies the name of the referenced service.
+    #[inline]
+    pub fn service_name(mut self, value: impl Into<String>) -> IngressBackendBuilder<crate::generics::ServiceNameExists, ServicePort> {
+        self.body.service_name = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Specifies the port of the referenced service.
+    #[inline]
+    pub fn service_port(mut self, value: impl Into<String>) -> IngressBackendBuilder<ServiceName, crate::generics::ServicePortExists> {
+        self.body.service_port = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+}
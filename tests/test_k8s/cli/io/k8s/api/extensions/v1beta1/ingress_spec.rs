@@ -0,0 +1,105 @@
+
+/// IngressSpec describes the Ingress the user wishes to exist.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct IngressSpec {
+    /// A default backend capable of servicing requests that don't match any rule. At least one of 'backend' or 'rules' must be specified. This field is optional to allow the loadbalancer controller or defaulting logic to specify a global default.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub backend: Option<crate::io::k8s::api::extensions::v1beta1::ingress_backend::IngressBackend>,
+    /// A list of host rules used to configure the Ingress. If unspecified, or no rule matches, all traffic is sent to the default backend.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rules: Option<Vec<crate::io::k8s::api::extensions::v1beta1::ingress_rule::IngressRule>>,
+    /// TLS configuration. Currently the Ingress only supports a single TLS port, 443. If multiple members of this list specify different hosts, they will be multiplexed on the same port according to the hostname specified through the SNI TLS extension, if the ingress controller fulfilling the ingress supports SNI.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tls: Option<Vec<crate::io::k8s::api::extensions::v1beta1::ingress_tls::IngressTls>>,
+}
+
+impl IngressSpec {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> IngressSpecBuilder {
+        IngressSpecBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> IngressSpecBuilder
+    where
+        Self: Clone,
+    {
+        IngressSpecBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<IngressSpec> for IngressSpecBuilder {
+    fn into(self) -> IngressSpec {
+        self.body
+    }
+}
+
+/// Builder for [`IngressSpec`](./struct.IngressSpec.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct IngressSpecBuilder {
+    body: self::IngressSpec,
+}
+
+impl IngressSpecBuilder {
+    /// A default backend capable of servicing requests that don't match any rule. At least one of 'backend' or 'rules' must be specified. This field is optional to allow the loadbalancer controller or defaulting logic to specify a global default.
+    #[inline]
+    pub fn backend(mut self, value: crate::io::k8s::api::extensions::v1beta1::ingress_backend::IngressBackendBuilder<crate::generics::ServiceNameExists, crate::generics::ServicePortExists>) -> Self {
+        self.body.backend = Some(value.into());
+        self
+    }
+
+    /// Sets [`backend`](#method.backend) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_backend(mut self, value: Option<crate::io::k8s::api::extensions::v1beta1::ingress_backend::IngressBackendBuilder<crate::generics::ServiceNameExists, crate::generics::ServicePortExists>>) -> Self {
+        self.body.backend = value.map(|value| value.into());
+        self
+    }
+
+    /// A list of host rules used to configure the Ingress. If unspecified, or no rule matches, all traffic is sent to the default backend.
+    #[inline]
+    pub fn rules(mut self, value: impl Iterator<Item = crate::io::k8s::api::extensions::v1beta1::ingress_rule::IngressRule>) -> Self {
+        self.body.rules = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`rules`](#method.rules) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_rules(mut self, value: Option<impl Iterator<Item = crate::io::k8s::api::extensions::v1beta1::ingress_rule::IngressRule>>) -> Self {
+        self.body.rules = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`rules`](#method.rules), but takes the values as a `Vec`.
+    #[inline]
+    pub fn rules_vec(mut self, value: impl Into<Vec<crate::io::k8s::api::extensions::v1beta1::ingress_rule::IngressRule>>) -> Self {
+        self.body.rules = Some(value.into());
+        self
+    }
+
+    /// TLS configuration. Currently the Ingress only supports a single TLS port, 443. If multiple members of this list specify different hosts, they will be multiplexed on the same port according to the hostname specified through the SNI TLS extension, if the ingress controller fulfilling the ingress supports SNI.
+    #[inline]
+    pub fn tls(mut self, value: impl Iterator<Item = crate::io::k8s::api::extensions::v1beta1::ingress_tls::IngressTls>) -> Self {
+        self.body.tls = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`tls`](#method.tls) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_tls(mut self, value: Option<impl Iterator<Item = crate::io::k8s::api::extensions::v1beta1::ingress_tls::IngressTls>>) -> Self {
+        self.body.tls = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`tls`](#method.tls), but takes the values as a `Vec`.
+    #[inline]
+    pub fn tls_vec(mut self, value: impl Into<Vec<crate::io::k8s::api::extensions::v1beta1::ingress_tls::IngressTls>>) -> Self {
+        self.body.tls = Some(value.into());
+        self
+    }
+}
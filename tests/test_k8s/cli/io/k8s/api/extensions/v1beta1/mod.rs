@@ -0,0 +1,220 @@
+
+pub mod allowed_csi_driver {
+    include!("./allowed_csi_driver.rs");
+}
+
+pub mod allowed_flex_volume {
+    include!("./allowed_flex_volume.rs");
+}
+
+pub mod allowed_host_path {
+    include!("./allowed_host_path.rs");
+}
+
+pub mod daemon_set {
+    include!("./daemon_set.rs");
+}
+
+pub mod daemon_set_condition {
+    include!("./daemon_set_condition.rs");
+}
+
+pub mod daemon_set_list {
+    include!("./daemon_set_list.rs");
+}
+
+pub mod daemon_set_spec {
+    include!("./daemon_set_spec.rs");
+}
+
+pub mod daemon_set_status {
+    include!("./daemon_set_status.rs");
+}
+
+pub mod daemon_set_update_strategy {
+    include!("./daemon_set_update_strategy.rs");
+}
+
+pub mod deployment {
+    include!("./deployment.rs");
+}
+
+pub mod deployment_condition {
+    include!("./deployment_condition.rs");
+}
+
+pub mod deployment_list {
+    include!("./deployment_list.rs");
+}
+
+pub mod deployment_rollback {
+    include!("./deployment_rollback.rs");
+}
+
+pub mod deployment_spec {
+    include!("./deployment_spec.rs");
+}
+
+pub mod deployment_status {
+    include!("./deployment_status.rs");
+}
+
+pub mod deployment_strategy {
+    include!("./deployment_strategy.rs");
+}
+
+pub mod fs_group_strategy_options {
+    include!("./fs_group_strategy_options.rs");
+}
+
+pub mod host_port_range {
+    include!("./host_port_range.rs");
+}
+
+pub mod http_ingress_path {
+    include!("./http_ingress_path.rs");
+}
+
+pub mod http_ingress_rule_value {
+    include!("./http_ingress_rule_value.rs");
+}
+
+pub mod id_range {
+    include!("./id_range.rs");
+}
+
+pub mod ingress {
+    include!("./ingress.rs");
+}
+
+pub mod ingress_backend {
+    include!("./ingress_backend.rs");
+}
+
+pub mod ingress_list {
+    include!("./ingress_list.rs");
+}
+
+pub mod ingress_rule {
+    include!("./ingress_rule.rs");
+}
+
+pub mod ingress_spec {
+    include!("./ingress_spec.rs");
+}
+
+pub mod ingress_status {
+    include!("./ingress_status.rs");
+}
+
+pub mod ingress_tls {
+    include!("./ingress_tls.rs");
+}
+
+pub mod ip_block {
+    include!("./ip_block.rs");
+}
+
+pub mod network_policy {
+    include!("./network_policy.rs");
+}
+
+pub mod network_policy_egress_rule {
+    include!("./network_policy_egress_rule.rs");
+}
+
+pub mod network_policy_ingress_rule {
+    include!("./network_policy_ingress_rule.rs");
+}
+
+pub mod network_policy_list {
+    include!("./network_policy_list.rs");
+}
+
+pub mod network_policy_peer {
+    include!("./network_policy_peer.rs");
+}
+
+pub mod network_policy_port {
+    include!("./network_policy_port.rs");
+}
+
+pub mod network_policy_spec {
+    include!("./network_policy_spec.rs");
+}
+
+pub mod pod_security_policy {
+    include!("./pod_security_policy.rs");
+}
+
+pub mod pod_security_policy_list {
+    include!("./pod_security_policy_list.rs");
+}
+
+pub mod pod_security_policy_spec {
+    include!("./pod_security_policy_spec.rs");
+}
+
+pub mod replica_set {
+    include!("./replica_set.rs");
+}
+
+pub mod replica_set_condition {
+    include!("./replica_set_condition.rs");
+}
+
+pub mod replica_set_list {
+    include!("./replica_set_list.rs");
+}
+
+pub mod replica_set_spec {
+    include!("./replica_set_spec.rs");
+}
+
+pub mod replica_set_status {
+    include!("./replica_set_status.rs");
+}
+
+pub mod rollback_config {
+    include!("./rollback_config.rs");
+}
+
+pub mod rolling_update_daemon_set {
+    include!("./rolling_update_daemon_set.rs");
+}
+
+pub mod rolling_update_deployment {
+    include!("./rolling_update_deployment.rs");
+}
+
+pub mod run_as_group_strategy_options {
+    include!("./run_as_group_strategy_options.rs");
+}
+
+pub mod run_as_user_strategy_options {
+    include!("./run_as_user_strategy_options.rs");
+}
+
+pub mod runtime_class_strategy_options {
+    include!("./runtime_class_strategy_options.rs");
+}
+
+pub mod scale {
+    include!("./scale.rs");
+}
+
+pub mod scale_spec {
+    include!("./scale_spec.rs");
+}
+
+pub mod scale_status {
+    include!("./scale_status.rs");
+}
+
+pub mod se_linux_strategy_options {
+    include!("./se_linux_strategy_options.rs");
+}
+
+pub mod supplemental_groups_strategy_options {
+    include!("./supplemental_groups_strategy_options.rs");
+}
@@ -0,0 +1,74 @@
+
+/// DEPRECATED 1.9 - This group version of NetworkPolicyPort is deprecated by networking/v1/NetworkPolicyPort.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct NetworkPolicyPort {
+    /// If specified, the port on the given protocol.  This can either be a numerical or named port on a pod.  If this field is not provided, this matches all port names and numbers. If present, only traffic on the specified protocol AND port will be matched.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub port: Option<String>,
+    /// Optional.  The protocol (TCP, UDP, or SCTP) which traffic must match. If not specified, this field defaults to TCP.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub protocol: Option<String>,
+}
+
+impl NetworkPolicyPort {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> NetworkPolicyPortBuilder {
+        NetworkPolicyPortBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> NetworkPolicyPortBuilder
+    where
+        Self: Clone,
+    {
+        NetworkPolicyPortBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<NetworkPolicyPort> for NetworkPolicyPortBuilder {
+    fn into(self) -> NetworkPolicyPort {
+        self.body
+    }
+}
+
+/// Builder for [`NetworkPolicyPort`](./struct.NetworkPolicyPort.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct NetworkPolicyPortBuilder {
+    body: self::NetworkPolicyPort,
+}
+
+impl NetworkPolicyPortBuilder {
+    /// If specified, the port on the given protocol.  This can either be a numerical or named port on a pod.  If this field is not provided, this matches all port names and numbers. If present, only traffic on the specified protocol AND port will be matched.
+    #[inline]
+    pub fn port(mut self, value: impl Into<String>) -> Self {
+        self.body.port = Some(value.into());
+        self
+    }
+
+    /// Sets [`port`](#method.port) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_port(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.port = value.map(|value| value.into());
+        self
+    }
+
+    /// Optional.  The protocol (TCP, UDP, or SCTP) which traffic must match. If not specified, this field defaults to TCP.
+    #[inline]
+    pub fn protocol(mut self, value: impl Into<String>) -> Self {
+        self.body.protocol = Some(value.into());
+        self
+    }
+
+    /// Sets [`protocol`](#method.protocol) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_protocol(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.protocol = value.map(|value| value.into());
+        self
+    }
+}
@@ -0,0 +1,32 @@
+
+pub mod ip_block {
+    include!("./ip_block.rs");
+}
+
+pub mod network_policy {
+    include!("./network_policy.rs");
+}
+
+pub mod network_policy_egress_rule {
+    include!("./network_policy_egress_rule.rs");
+}
+
+pub mod network_policy_ingress_rule {
+    include!("./network_policy_ingress_rule.rs");
+}
+
+pub mod network_policy_list {
+    include!("./network_policy_list.rs");
+}
+
+pub mod network_policy_peer {
+    include!("./network_policy_peer.rs");
+}
+
+pub mod network_policy_port {
+    include!("./network_policy_port.rs");
+}
+
+pub mod network_policy_spec {
+    include!("./network_policy_spec.rs");
+}
@@ -0,0 +1,88 @@
+
+/// NetworkPolicyIngressRule describes a particular set of traffic that is allowed to the pods matched by a NetworkPolicySpec's podSelector. The traffic must match both ports and from.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct NetworkPolicyIngressRule {
+    /// List of sources which should be able to access the pods selected for this rule. Items in this list are combined using a logical OR operation. If this field is empty or missing, this rule matches all sources (traffic not restricted by source). If this field is present and contains at least on item, this rule allows traffic only if the traffic matches at least one item in the from list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from: Option<Vec<crate::io::k8s::api::networking::v1::network_policy_peer::NetworkPolicyPeer>>,
+    /// List of ports which should be made accessible on the pods selected for this rule. Each item in this list is combined using a logical OR. If this field is empty or missing, this rule matches all ports (traffic not restricted by port). If this field is present and contains at least one item, then this rule allows traffic only if the traffic matches at least one port in the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ports: Option<Vec<crate::io::k8s::api::networking::v1::network_policy_port::NetworkPolicyPort>>,
+}
+
+impl NetworkPolicyIngressRule {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> NetworkPolicyIngressRuleBuilder {
+        NetworkPolicyIngressRuleBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> NetworkPolicyIngressRuleBuilder
+    where
+        Self: Clone,
+    {
+        NetworkPolicyIngressRuleBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<NetworkPolicyIngressRule> for NetworkPolicyIngressRuleBuilder {
+    fn into(self) -> NetworkPolicyIngressRule {
+        self.body
+    }
+}
+
+/// Builder for [`NetworkPolicyIngressRule`](./struct.NetworkPolicyIngressRule.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct NetworkPolicyIngressRuleBuilder {
+    body: self::NetworkPolicyIngressRule,
+}
+
+impl NetworkPolicyIngressRuleBuilder {
+    /// List of sources which should be able to access the pods selected for this rule. Items in this list are combined using a logical OR operation. If this field is empty or missing, this rule matches all sources (traffic not restricted by source). If this field is present and contains at least on item, this rule allows traffic only if the traffic matches at least one item in the from list.
+    #[inline]
+    pub fn from(mut self, value: impl Iterator<Item = crate::io::k8s::api::networking::v1::network_policy_peer::NetworkPolicyPeer>) -> Self {
+        self.body.from = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`from`](#method.from) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_from(mut self, value: Option<impl Iterator<Item = crate::io::k8s::api::networking::v1::network_policy_peer::NetworkPolicyPeer>>) -> Self {
+        self.body.from = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`from`](#method.from), but takes the values as a `Vec`.
+    #[inline]
+    pub fn from_vec(mut self, value: impl Into<Vec<crate::io::k8s::api::networking::v1::network_policy_peer::NetworkPolicyPeer>>) -> Self {
+        self.body.from = Some(value.into());
+        self
+    }
+
+    /// List of ports which should be made accessible on the pods selected for this rule. Each item in this list is combined using a logical OR. If this field is empty or missing, this rule matches all ports (traffic not restricted by port). If this field is present and contains at least one item, then this rule allows traffic only if the traffic matches at least one port in the list.
+    #[inline]
+    pub fn ports(mut self, value: impl Iterator<Item = crate::io::k8s::api::networking::v1::network_policy_port::NetworkPolicyPort>) -> Self {
+        self.body.ports = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`ports`](#method.ports) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_ports(mut self, value: Option<impl Iterator<Item = crate::io::k8s::api::networking::v1::network_policy_port::NetworkPolicyPort>>) -> Self {
+        self.body.ports = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`ports`](#method.ports), but takes the values as a `Vec`.
+    #[inline]
+    pub fn ports_vec(mut self, value: impl Into<Vec<crate::io::k8s::api::networking::v1::network_policy_port::NetworkPolicyPort>>) -> Self {
+        self.body.ports = Some(value.into());
+        self
+    }
+}
@@ -0,0 +1,125 @@
+
+/// NetworkPolicySpec provides the specification of a NetworkPolicy
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct NetworkPolicySpec {
+    /// List of egress rules to be applied to the selected pods. Outgoing traffic is allowed if there are no NetworkPolicies selecting the pod (and cluster policy otherwise allows the traffic), OR if the traffic matches at least one egress rule across all of the NetworkPolicy objects whose podSelector matches the pod. If this field is empty then this NetworkPolicy limits all outgoing traffic (and serves solely to ensure that the pods it selects are isolated by default). This field is beta-level in 1.8
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub egress: Option<Vec<crate::io::k8s::api::networking::v1::network_policy_egress_rule::NetworkPolicyEgressRule>>,
+    /// List of ingress rules to be applied to the selected pods. Traffic is allowed to a pod if there are no NetworkPolicies selecting the pod (and cluster policy otherwise allows the traffic), OR if the traffic source is the pod's local node, OR if the traffic matches at least one ingress rule across all of the NetworkPolicy objects whose podSelector matches the pod. If this field is empty then this NetworkPolicy does not allow any traffic (and serves solely to ensure that the pods it selects are isolated by default)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ingress: Option<Vec<crate::io::k8s::api::networking::v1::network_policy_ingress_rule::NetworkPolicyIngressRule>>,
+    /// Selects the pods to which this NetworkPolicy object applies. The array of ingress rules is applied to any pods selected by this field. Multiple network policies can select the same set of pods. In this case, the ingress rules for each are combined additively. This field is NOT optional and follows standard label selector semantics. An empty podSelector matches all pods in this namespace.
+    #[serde(rename = "podSelector")]
+    pub pod_selector: crate::io::k8s::apimachinery::pkg::apis::meta::v1::label_selector::LabelSelector,
+    /// List of rule types that the NetworkPolicy relates to. Valid options are "Ingress", "Egress", or "Ingress,Egress". If this field is not specified, it will default based on the existence of Ingress or Egress rules; policies that contain an Egress section are assumed to affect Egress, and all policies (whether or not they contain an Ingress section) are assumed to affect Ingress. If you want to write an egress-only policy, you must explicitly specify policyTypes \[ "Egress" \]. Likewise, if you want to write a policy that specifies that no egress is allowed, you must specify a policyTypes value that include "Egress" (since such a policy would not include an Egress section and would otherwise default to just \[ "Ingress" \]). This field is beta-level in 1.8
+    #[serde(rename = "policyTypes", skip_serializing_if = "Option::is_none")]
+    pub policy_types: Option<Vec<String>>,
+}
+
+impl NetworkPolicySpec {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> NetworkPolicySpecBuilder<crate::generics::MissingPodSelector> {
+        NetworkPolicySpecBuilder {
+            body: Default::default(),
+            _pod_selector: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> NetworkPolicySpecBuilder<crate::generics::PodSelectorExists>
+    where
+        Self: Clone,
+    {
+        NetworkPolicySpecBuilder {
+            body: self.clone(),
+            _pod_selector: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<NetworkPolicySpec> for NetworkPolicySpecBuilder<crate::generics::PodSelectorExists> {
+    fn into(self) -> NetworkPolicySpec {
+        self.body
+    }
+}
+
+/// Builder for [`NetworkPolicySpec`](./struct.NetworkPolicySpec.html) object.
+#[derive(Debug, Clone)]
+pub struct NetworkPolicySpecBuilder<PodSelector> {
+    body: self::NetworkPolicySpec,
+    _pod_selector: core::marker::PhantomData<PodSelector>,
+}
+
+impl<PodSelector> NetworkPolicySpecBuilder<PodSelector> {
+    /// List of egress rules to be applied to the selected pods. Outgoing traffic is allowed if there are no NetworkPolicies selecting the pod (and cluster policy otherwise allows the traffic), OR if the traffic matches at least one egress rule across all of the NetworkPolicy objects whose podSelector matches the pod. If this field is empty then this NetworkPolicy limits all outgoing traffic (and serves solely to ensure that the pods it selects are isolated by default). This field is beta-level in 1.8
+    #[inline]
+    pub fn egress(mut self, value: impl Iterator<Item = crate::io::k8s::api::networking::v1::network_policy_egress_rule::NetworkPolicyEgressRule>) -> Self {
+        self.body.egress = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`egress`](#method.egress) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_egress(mut self, value: Option<impl Iterator<Item = crate::io::k8s::api::networking::v1::network_policy_egress_rule::NetworkPolicyEgressRule>>) -> Self {
+        self.body.egress = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`egress`](#method.egress), but takes the values as a `Vec`.
+    #[inline]
+    pub fn egress_vec(mut self, value: impl Into<Vec<crate::io::k8s::api::networking::v1::network_policy_egress_rule::NetworkPolicyEgressRule>>) -> Self {
+        self.body.egress = Some(value.into());
+        self
+    }
+
+    /// List of ingress rules to be applied to the selected pods. Traffic is allowed to a pod if there are no NetworkPolicies selecting the pod (and cluster policy otherwise allows the traffic), OR if the traffic source is the pod's local node, OR if the traffic matches at least one ingress rule across all of the NetworkPolicy objects whose podSelector matches the pod. If this field is empty then this NetworkPolicy does not allow any traffic (and serves solely to ensure that the pods it selects are isolated by default)
+    #[inline]
+    pub fn ingress(mut self, value: impl Iterator<Item = crate::io::k8s::api::networking::v1::network_policy_ingress_rule::NetworkPolicyIngressRule>) -> Self {
+        self.body.ingress = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`ingress`](#method.ingress) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_ingress(mut self, value: Option<impl Iterator<Item = crate::io::k8s::api::networking::v1::network_policy_ingress_rule::NetworkPolicyIngressRule>>) -> Self {
+        self.body.ingress = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`ingress`](#method.ingress), but takes the values as a `Vec`.
+    #[inline]
+    pub fn ingress_vec(mut self, value: impl Into<Vec<crate::io::k8s::api::networking::v1::network_policy_ingress_rule::NetworkPolicyIngressRule>>) -> Self {
+        self.body.ingress = Some(value.into());
+        self
+    }
+
+    /// Selects the pods to which this NetworkPolicy object applies. The array of ingress rules is applied to any pods selected by this field. Multiple network policies can select the same set of pods. In this case, the ingress rules for each are combined additively. This field is NOT optional and follows standard label selector semantics. An empty podSelector matches all pods in this namespace.
+    #[inline]
+    pub fn pod_selector(mut self, value: crate::io::k8s::apimachinery::pkg::apis::meta::v1::label_selector::LabelSelector) -> NetworkPolicySpecBuilder<crate::generics::PodSelectorExists> {
+        self.body.pod_selector = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// List of rule types that the NetworkPolicy relates to. Valid options are "Ingress", "Egress", or "Ingress,Egress". If this field is not specified, it will default based on the existence of Ingress or Egress rules; policies that contain an Egress section are assumed to affect Egress, and all policies (whether or not they contain an Ingress section) are assumed to affect Ingress. If you want to write an egress-only policy, you must explicitly specify policyTypes \[ "Egress" \]. Likewise, if you want to write a policy that specifies that no egress is allowed, you must specify a policyTypes value that include "Egress" (since such a policy would not include an Egress section and would otherwise default to just \[ "Ingress" \]). This field is beta-level in 1.8
+    #[inline]
+    pub fn policy_types(mut self, value: impl Iterator<Item = impl Into<String>>) -> Self {
+        self.body.policy_types = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`policy_types`](#method.policy_types) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_policy_types(mut self, value: Option<impl Iterator<Item = impl Into<String>>>) -> Self {
+        self.body.policy_types = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`policy_types`](#method.policy_types), but takes the values as a `Vec`.
+    #[inline]
+    pub fn policy_types_vec(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.body.policy_types = Some(value.into());
+        self
+    }
+}
@@ -0,0 +1,69 @@
+
+/// HTTPIngressPath associates a path regex with a backend. Incoming urls matching the path are forwarded to the backend.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct HttpIngressPath {
+    /// Backend defines the referenced service endpoint to which the traffic will be forwarded to.
+    pub backend: crate::io::k8s::api::networking::v1beta1::ingress_backend::IngressBackend,
+    /// Path is an extended POSIX regex as defined by IEEE Std 1003.1, (i.e this follows the egrep/unix syntax, not the perl syntax) matched against the path of an incoming request. Currently it can contain characters disallowed from the conventional "path" part of a URL as defined by RFC 3986. Paths must begin with a '/'. If unspecified, the path defaults to a catch all sending traffic to the backend.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+}
+
+impl HttpIngressPath {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> HttpIngressPathBuilder<crate::generics::MissingBackend> {
+        HttpIngressPathBuilder {
+            body: Default::default(),
+            _backend: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> HttpIngressPathBuilder<crate::generics::BackendExists>
+    where
+        Self: Clone,
+    {
+        HttpIngressPathBuilder {
+            body: self.clone(),
+            _backend: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<HttpIngressPath> for HttpIngressPathBuilder<crate::generics::BackendExists> {
+    fn into(self) -> HttpIngressPath {
+        self.body
+    }
+}
+
+/// Builder for [`HttpIngressPath`](./struct.HttpIngressPath.html) object.
+#[derive(Debug, Clone)]
+pub struct HttpIngressPathBuilder<Backend> {
+    body: self::HttpIngressPath,
+    _backend: core::marker::PhantomData<Backend>,
+}
+
+impl<Backend> HttpIngressPathBuilder<Backend> {
+    /// Backend defines the referenced service endpoint to which the traffic will be forwarded to.
+    #[inline]
+    pub fn backend(mut self, value: crate::io::k8s::api::networking::v1beta1::ingress_backend::IngressBackendBuilder<crate::generics::ServiceNameExists, crate::generics::ServicePortExists>) -> HttpIngressPathBuilder<crate::generics::BackendExists> {
+        self.body.backend = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Path is an extended POSIX regex as defined by IEEE Std 1003.1, (i.e this follows the egrep/unix syntax, not the perl syntax) matched against the path of an incoming request. Currently it can contain characters disallowed from the conventional "path" part of a URL as defined by RFC 3986. Paths must begin with a '/'. If unspecified, the path defaults to a catch all sending traffic to the backend.
+    #[inline]
+    pub fn path(mut self, value: impl Into<String>) -> Self {
+        self.body.path = Some(value.into());
+        self
+    }
+
+    /// Sets [`path`](#method.path) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_path(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.path = value.map(|value| value.into());
+        self
+    }
+}
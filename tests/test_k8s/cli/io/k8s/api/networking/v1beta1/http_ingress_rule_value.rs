@@ -0,0 +1,59 @@
+
+/// HTTPIngressRuleValue is a list of http selectors pointing to backends. In the example: http://\<host>/\<path>?\<searchpart> -> backend where where parts of the url correspond to RFC 3986, this resource will be used to match against everything after the last '/' and before the first '?' or '#'.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct HttpIngressRuleValue {
+    /// A collection of paths that map requests to backends.
+    pub paths: Vec<crate::io::k8s::api::networking::v1beta1::http_ingress_path::HttpIngressPath>,
+}
+
+impl HttpIngressRuleValue {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> HttpIngressRuleValueBuilder<crate::generics::MissingPaths> {
+        HttpIngressRuleValueBuilder {
+            body: Default::default(),
+            _paths: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> HttpIngressRuleValueBuilder<crate::generics::PathsExists>
+    where
+        Self: Clone,
+    {
+        HttpIngressRuleValueBuilder {
+            body: self.clone(),
+            _paths: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<HttpIngressRuleValue> for HttpIngressRuleValueBuilder<crate::generics::PathsExists> {
+    fn into(self) -> HttpIngressRuleValue {
+        self.body
+    }
+}
+
+/// Builder for [`HttpIngressRuleValue`](./struct.HttpIngressRuleValue.html) object.
+#[derive(Debug, Clone)]
+pub struct HttpIngressRuleValueBuilder<Paths> {
+    body: self::HttpIngressRuleValue,
+    _paths: core::marker::PhantomData<Paths>,
+}
+
+impl<Paths> HttpIngressRuleValueBuilder<Paths> {
+    /// A collection of paths that map requests to backends.
+    #[inline]
+    pub fn paths(mut self, value: impl Iterator<Item = crate::io::k8s::api::networking::v1beta1::http_ingress_path::HttpIngressPathBuilder<crate::generics::BackendExists>>) -> HttpIngressRuleValueBuilder<crate::generics::PathsExists> {
+        self.body.paths = value.map(|value| value.into()).collect::<Vec<_>>().into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Same as [`paths`](#method.paths), but takes the values as a `Vec`.
+    #[inline]
+    pub fn paths_vec(mut self, value: impl Into<Vec<crate::io::k8s::api::networking::v1beta1::http_ingress_path::HttpIngressPath>>) -> HttpIngressRuleValueBuilder<crate::generics::PathsExists> {
+        self.body.paths = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+}
@@ -0,0 +1,81 @@
+
+/// IngressTLS describes the transport layer security associated with an Ingress.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct IngressTls {
+    /// Hosts are a list of hosts included in the TLS certificate. The values in this list must match the name/s used in the tlsSecret. Defaults to the wildcard host setting for the loadbalancer controller fulfilling this Ingress, if left unspecified.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hosts: Option<Vec<String>>,
+    /// SecretName is the name of the secret used to terminate SSL traffic on 443. Field is left optional to allow SSL routing based on SNI hostname alone. If the SNI host in a listener conflicts with the "Host" header field used by an IngressRule, the SNI host is used for termination and value of the Host header is used for routing.
+    #[serde(rename = "secretName", skip_serializing_if = "Option::is_none")]
+    pub secret_name: Option<String>,
+}
+
+impl IngressTls {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> IngressTlsBuilder {
+        IngressTlsBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> IngressTlsBuilder
+    where
+        Self: Clone,
+    {
+        IngressTlsBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<IngressTls> for IngressTlsBuilder {
+    fn into(self) -> IngressTls {
+        self.body
+    }
+}
+
+/// Builder for [`IngressTls`](./struct.IngressTls.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct IngressTlsBuilder {
+    body: self::IngressTls,
+}
+
+impl IngressTlsBuilder {
+    /// Hosts are a list of hosts included in the TLS certificate. The values in this list must match the name/s used in the tlsSecret. Defaults to the wildcard host setting for the loadbalancer controller fulfilling this Ingress, if left unspecified.
+    #[inline]
+    pub fn hosts(mut self, value: impl Iterator<Item = impl Into<String>>) -> Self {
+        self.body.hosts = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`hosts`](#method.hosts) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_hosts(mut self, value: Option<impl Iterator<Item = impl Into<String>>>) -> Self {
+        self.body.hosts = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`hosts`](#method.hosts), but takes the values as a `Vec`.
+    #[inline]
+    pub fn hosts_vec(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.body.hosts = Some(value.into());
+        self
+    }
+
+    /// SecretName is the name of the secret used to terminate SSL traffic on 443. Field is left optional to allow SSL routing based on SNI hostname alone. If the SNI host in a listener conflicts with the "Host" header field used by an IngressRule, the SNI host is used for termination and value of the Host header is used for routing.
+    #[inline]
+    pub fn secret_name(mut self, value: impl Into<String>) -> Self {
+        self.body.secret_name = Some(value.into());
+        self
+    }
+
+    /// Sets [`secret_name`](#method.secret_name) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_secret_name(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.secret_name = value.map(|value| value.into());
+        self
+    }
+}
@@ -0,0 +1,36 @@
+
+pub mod http_ingress_path {
+    include!("./http_ingress_path.rs");
+}
+
+pub mod http_ingress_rule_value {
+    include!("./http_ingress_rule_value.rs");
+}
+
+pub mod ingress {
+    include!("./ingress.rs");
+}
+
+pub mod ingress_backend {
+    include!("./ingress_backend.rs");
+}
+
+pub mod ingress_list {
+    include!("./ingress_list.rs");
+}
+
+pub mod ingress_rule {
+    include!("./ingress_rule.rs");
+}
+
+pub mod ingress_spec {
+    include!("./ingress_spec.rs");
+}
+
+pub mod ingress_status {
+    include!("./ingress_status.rs");
+}
+
+pub mod ingress_tls {
+    include!("./ingress_tls.rs");
+}
@@ -0,0 +1,8 @@
+
+pub mod v1alpha1 {
+    include!("./v1alpha1/mod.rs");
+}
+
+pub mod v1beta1 {
+    include!("./v1beta1/mod.rs");
+}
@@ -0,0 +1,53 @@
+
+/// RuntimeClassSpec is a specification of a RuntimeClass. It contains parameters that are required to describe the RuntimeClass to the Container Runtime Interface (CRI) implementation, as well as any other components that need to understand how the pod will be run. The RuntimeClassSpec is immutable.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct RuntimeClassSpec {
+    /// RuntimeHandler specifies the underlying runtime and configuration that the CRI implementation will use to handle pods of this class. The possible values are specific to the node & CRI configuration.  It is assumed that all handlers are available on every node, and handlers of the same name are equivalent on every node. For example, a handler called "runc" might specify that the runc OCI runtime (using native Linux containers) will be used to run the containers in a pod. The RuntimeHandler must conform to the DNS Label (RFC 1123) requirements and is immutable.
+    #[serde(rename = "runtimeHandler")]
+    pub runtime_handler: String,
+}
+
+impl RuntimeClassSpec {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> RuntimeClassSpecBuilder<crate::generics::MissingRuntimeHandler> {
+        RuntimeClassSpecBuilder {
+            body: Default::default(),
+            _runtime_handler: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> RuntimeClassSpecBuilder<crate::generics::RuntimeHandlerExists>
+    where
+        Self: Clone,
+    {
+        RuntimeClassSpecBuilder {
+            body: self.clone(),
+            _runtime_handler: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<RuntimeClassSpec> for RuntimeClassSpecBuilder<crate::generics::RuntimeHandlerExists> {
+    fn into(self) -> RuntimeClassSpec {
+        self.body
+    }
+}
+
+/// Builder for [`RuntimeClassSpec`](./struct.RuntimeClassSpec.html) object.
+#[derive(Debug, Clone)]
+pub struct RuntimeClassSpecBuilder<RuntimeHandler> {
+    body: self::RuntimeClassSpec,
+    _runtime_handler: core::marker::PhantomData<RuntimeHandler>,
+}
+
+impl<RuntimeHandler> RuntimeClassSpecBuilder<RuntimeHandler> {
+    /// RuntimeHandler specifies the underlying runtime and configuration that the CRI implementation will use to handle pods of this class. The possible values are specific to the node & CRI configuration.  It is assumed that all handlers are available on every node, and handlers of the same name are equivalent on every node. For example, a handler called "runc" might specify that the runc OCI runtime (using native Linux containers) will be used to run the containers in a pod. The RuntimeHandler must conform to the DNS Label (RFC 1123) requirements and is immutable.
+    #[inline]
+    pub fn runtime_handler(mut self, value: impl Into<String>) -> RuntimeClassSpecBuilder<crate::generics::RuntimeHandlerExists> {
+        self.body.runtime_handler = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+}
@@ -0,0 +1,76 @@
+
+/// HostPortRange defines a range of host ports that will be enabled by a policy for pods to use.  It requires both the start and end to be defined.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct HostPortRange {
+    /// max is the end of the range, inclusive.
+    pub max: i32,
+    /// min is the start of the range, inclusive.
+    pub min: i32,
+}
+
+impl HostPortRange {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> HostPortRangeBuilder<crate::generics::MissingMax, crate::generics::MissingMin> {
+        HostPortRangeBuilder {
+            body: Default::default(),
+            _max: core::marker::PhantomData,
+            _min: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> HostPortRangeBuilder<crate::generics::MaxExists, crate::generics::MinExists>
+    where
+        Self: Clone,
+    {
+        HostPortRangeBuilder {
+            body: self.clone(),
+            _max: core::marker::PhantomData,
+            _min: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<HostPortRange> for HostPortRangeBuilder<crate::generics::MaxExists, crate::generics::MinExists> {
+    fn into(self) -> HostPortRange {
+        self.body
+    }
+}
+
+/// Builder for [`HostPortRange`](./struct.HostPortRange.html) object.
+#[derive(Debug, Clone)]
+pub struct HostPortRangeBuilder<Max, Min> {
+    body: self::HostPortRange,
+    _max: core::marker::PhantomData<Max>,
+    _min: core::marker::PhantomData<Min>,
+}
+
+impl<Max, Min> HostPortRangeBuilder<Max, Min> {
+    /// max is the end of the range, inclusive.
+    #[inline]
+    pub fn max(mut self, value: impl Into<i32>) -> HostPortRangeBuilder<crate::generics::MaxExists, Min> {
+        self.body.max = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Same as [`max`](#method.max), but takes a reference.
+    #[inline]
+    pub fn max_ref(self, value: &i32) -> HostPortRangeBuilder<crate::generics::MaxExists, Min> {
+        self.max(*value)
+    }
+
+    /// min is the start of the range, inclusive.
+    #[inline]
+    pub fn min(mut self, value: impl Into<i32>) -> HostPortRangeBuilder<Max, crate::generics::MinExists> {
+        self.body.min = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Same as [`min`](#method.min), but takes a reference.
+    #[inline]
+    pub fn min_ref(self, value: &i32) -> HostPortRangeBuilder<Max, crate::generics::MinExists> {
+        self.min(*value)
+    }
+}
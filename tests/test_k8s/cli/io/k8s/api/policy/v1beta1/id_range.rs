@@ -0,0 +1,76 @@
+
+/// IDRange provides a min/max of an allowed range of IDs.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct IdRange {
+    /// max is the end of the range, inclusive.
+    pub max: i64,
+    /// min is the start of the range, inclusive.
+    pub min: i64,
+}
+
+impl IdRange {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> IdRangeBuilder<crate::generics::MissingMax, crate::generics::MissingMin> {
+        IdRangeBuilder {
+            body: Default::default(),
+            _max: core::marker::PhantomData,
+            _min: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> IdRangeBuilder<crate::generics::MaxExists, crate::generics::MinExists>
+    where
+        Self: Clone,
+    {
+        IdRangeBuilder {
+            body: self.clone(),
+            _max: core::marker::PhantomData,
+            _min: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<IdRange> for IdRangeBuilder<crate::generics::MaxExists, crate::generics::MinExists> {
+    fn into(self) -> IdRange {
+        self.body
+    }
+}
+
+/// Builder for [`IdRange`](./struct.IdRange.html) object.
+#[derive(Debug, Clone)]
+pub struct IdRangeBuilder<Max, Min> {
+    body: self::IdRange,
+    _max: core::marker::PhantomData<Max>,
+    _min: core::marker::PhantomData<Min>,
+}
+
+impl<Max, Min> IdRangeBuilder<Max, Min> {
+    /// max is the end of the range, inclusive.
+    #[inline]
+    pub fn max(mut self, value: impl Into<i64>) -> IdRangeBuilder<crate::generics::MaxExists, Min> {
+        self.body.max = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Same as [`max`](#method.max), but takes a reference.
+    #[inline]
+    pub fn max_ref(self, value: &i64) -> IdRangeBuilder<crate::generics::MaxExists, Min> {
+        self.max(*value)
+    }
+
+    /// min is the start of the range, inclusive.
+    #[inline]
+    pub fn min(mut self, value: impl Into<i64>) -> IdRangeBuilder<Max, crate::generics::MinExists> {
+        self.body.min = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Same as [`min`](#method.min), but takes a reference.
+    #[inline]
+    pub fn min_ref(self, value: &i64) -> IdRangeBuilder<Max, crate::generics::MinExists> {
+        self.min(*value)
+    }
+}
@@ -0,0 +1,76 @@
+
+pub mod allowed_csi_driver {
+    include!("./allowed_csi_driver.rs");
+}
+
+pub mod allowed_flex_volume {
+    include!("./allowed_flex_volume.rs");
+}
+
+pub mod allowed_host_path {
+    include!("./allowed_host_path.rs");
+}
+
+pub mod eviction {
+    include!("./eviction.rs");
+}
+
+pub mod fs_group_strategy_options {
+    include!("./fs_group_strategy_options.rs");
+}
+
+pub mod host_port_range {
+    include!("./host_port_range.rs");
+}
+
+pub mod id_range {
+    include!("./id_range.rs");
+}
+
+pub mod pod_disruption_budget {
+    include!("./pod_disruption_budget.rs");
+}
+
+pub mod pod_disruption_budget_list {
+    include!("./pod_disruption_budget_list.rs");
+}
+
+pub mod pod_disruption_budget_spec {
+    include!("./pod_disruption_budget_spec.rs");
+}
+
+pub mod pod_disruption_budget_status {
+    include!("./pod_disruption_budget_status.rs");
+}
+
+pub mod pod_security_policy {
+    include!("./pod_security_policy.rs");
+}
+
+pub mod pod_security_policy_list {
+    include!("./pod_security_policy_list.rs");
+}
+
+pub mod pod_security_policy_spec {
+    include!("./pod_security_policy_spec.rs");
+}
+
+pub mod run_as_group_strategy_options {
+    include!("./run_as_group_strategy_options.rs");
+}
+
+pub mod run_as_user_strategy_options {
+    include!("./run_as_user_strategy_options.rs");
+}
+
+pub mod runtime_class_strategy_options {
+    include!("./runtime_class_strategy_options.rs");
+}
+
+pub mod se_linux_strategy_options {
+    include!("./se_linux_strategy_options.rs");
+}
+
+pub mod supplemental_groups_strategy_options {
+    include!("./supplemental_groups_strategy_options.rs");
+}
@@ -0,0 +1,91 @@
+
+/// PodDisruptionBudgetSpec is a description of a PodDisruptionBudget.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct PodDisruptionBudgetSpec {
+    /// An eviction is allowed if at most "maxUnavailable" pods selected by "selector" are unavailable after the eviction, i.e. even in absence of the evicted pod. For example, one can prevent all voluntary evictions by specifying 0. This is a mutually exclusive setting with "minAvailable".
+    #[serde(rename = "maxUnavailable", skip_serializing_if = "Option::is_none")]
+    pub max_unavailable: Option<String>,
+    /// An eviction is allowed if at least "minAvailable" pods selected by "selector" will still be available after the eviction, i.e. even in the absence of the evicted pod.  So for example you can prevent all voluntary evictions by specifying "100%".
+    #[serde(rename = "minAvailable", skip_serializing_if = "Option::is_none")]
+    pub min_available: Option<String>,
+    /// Label query over pods whose evictions are managed by the disruption budget.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub selector: Option<crate::io::k8s::apimachinery::pkg::apis::meta::v1::label_selector::LabelSelector>,
+}
+
+impl PodDisruptionBudgetSpec {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> PodDisruptionBudgetSpecBuilder {
+        PodDisruptionBudgetSpecBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> PodDisruptionBudgetSpecBuilder
+    where
+        Self: Clone,
+    {
+        PodDisruptionBudgetSpecBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<PodDisruptionBudgetSpec> for PodDisruptionBudgetSpecBuilder {
+    fn into(self) -> PodDisruptionBudgetSpec {
+        self.body
+    }
+}
+
+/// Builder for [`PodDisruptionBudgetSpec`](./struct.PodDisruptionBudgetSpec.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct PodDisruptionBudgetSpecBuilder {
+    body: self::PodDisruptionBudgetSpec,
+}
+
+impl PodDisruptionBudgetSpecBuilder {
+    /// An eviction is allowed if at most "maxUnavailable" pods selected by "selector" are unavailable after the eviction, i.e. even in absence of the evicted pod. For example, one can prevent all voluntary evictions by specifying 0. This is a mutually exclusive setting with "minAvailable".
+    #[inline]
+    pub fn max_unavailable(mut self, value: impl Into<String>) -> Self {
+        self.body.max_unavailable = Some(value.into());
+        self
+    }
+
+    /// Sets [`max_unavailable`](#method.max_unavailable) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_max_unavailable(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.max_unavailable = value.map(|value| value.into());
+        self
+    }
+
+    /// An eviction is allowed if at least "minAvailable" pods selected by "selector" will still be available after the eviction, i.e. even in the absence of the evicted pod.  So for example you can prevent all voluntary evictions by specifying "100%".
+    #[inline]
+    pub fn min_available(mut self, value: impl Into<String>) -> Self {
+        self.body.min_available = Some(value.into());
+        self
+    }
+
+    /// Sets [`min_available`](#method.min_available) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_min_available(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.min_available = value.map(|value| value.into());
+        self
+    }
+
+    /// Label query over pods whose evictions are managed by the disruption budget.
+    #[inline]
+    pub fn selector(mut self, value: crate::io::k8s::apimachinery::pkg::apis::meta::v1::label_selector::LabelSelector) -> Self {
+        self.body.selector = Some(value.into());
+        self
+    }
+
+    /// Sets [`selector`](#method.selector) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_selector(mut self, value: Option<crate::io::k8s::apimachinery::pkg::apis::meta::v1::label_selector::LabelSelector>) -> Self {
+        self.body.selector = value.map(|value| value.into());
+        self
+    }
+}
@@ -0,0 +1,76 @@
+
+/// RunAsGroupStrategyOptions defines the strategy type and any options used to create the strategy.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct RunAsGroupStrategyOptions {
+    /// ranges are the allowed ranges of gids that may be used. If you would like to force a single gid then supply a single range with the same start and end. Required for MustRunAs.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ranges: Option<Vec<crate::io::k8s::api::policy::v1beta1::id_range::IdRange>>,
+    /// rule is the strategy that will dictate the allowable RunAsGroup values that may be set.
+    pub rule: String,
+}
+
+impl RunAsGroupStrategyOptions {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> RunAsGroupStrategyOptionsBuilder<crate::generics::MissingRule> {
+        RunAsGroupStrategyOptionsBuilder {
+            body: Default::default(),
+            _rule: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> RunAsGroupStrategyOptionsBuilder<crate::generics::RuleExists>
+    where
+        Self: Clone,
+    {
+        RunAsGroupStrategyOptionsBuilder {
+            body: self.clone(),
+            _rule: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<RunAsGroupStrategyOptions> for RunAsGroupStrategyOptionsBuilder<crate::generics::RuleExists> {
+    fn into(self) -> RunAsGroupStrategyOptions {
+        self.body
+    }
+}
+
+/// Builder for [`RunAsGroupStrategyOptions`](./struct.RunAsGroupStrategyOptions.html) object.
+#[derive(Debug, Clone)]
+pub struct RunAsGroupStrategyOptionsBuilder<Rule> {
+    body: self::RunAsGroupStrategyOptions,
+    _rule: core::marker::PhantomData<Rule>,
+}
+
+impl<Rule> RunAsGroupStrategyOptionsBuilder<Rule> {
+    /// ranges are the allowed ranges of gids that may be used. If you would like to force a single gid then supply a single range with the same start and end. Required for MustRunAs.
+    #[inline]
+    pub fn ranges(mut self, value: impl Iterator<Item = crate::io::k8s::api::policy::v1beta1::id_range::IdRangeBuilder<crate::generics::MaxExists, crate::generics::MinExists>>) -> Self {
+        self.body.ranges = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`ranges`](#method.ranges) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_ranges(mut self, value: Option<impl Iterator<Item = crate::io::k8s::api::policy::v1beta1::id_range::IdRangeBuilder<crate::generics::MaxExists, crate::generics::MinExists>>>) -> Self {
+        self.body.ranges = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`ranges`](#method.ranges), but takes the values as a `Vec`.
+    #[inline]
+    pub fn ranges_vec(mut self, value: impl Into<Vec<crate::io::k8s::api::policy::v1beta1::id_range::IdRange>>) -> Self {
+        self.body.ranges = Some(value.into());
+        self
+    }
+
+    /// rule is the strategy that will dictate the allowable RunAsGroup values that may be set.
+    #[inline]
+    pub fn rule(mut self, value: impl Into<String>) -> RunAsGroupStrategyOptionsBuilder<crate::generics::RuleExists> {
+        self.body.rule = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+}
@@ -0,0 +1,77 @@
+
+/// RuntimeClassStrategyOptions define the strategy that will dictate the allowable RuntimeClasses for a pod.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct RuntimeClassStrategyOptions {
+    /// allowedRuntimeClassNames is a whitelist of RuntimeClass names that may be specified on a pod. A value of "\*" means that any RuntimeClass name is allowed, and must be the only item in the list. An empty list requires the RuntimeClassName field to be unset.
+    #[serde(rename = "allowedRuntimeClassNames")]
+    pub allowed_runtime_class_names: Vec<String>,
+    /// defaultRuntimeClassName is the default RuntimeClassName to set on the pod. The default MUST be allowed by the allowedRuntimeClassNames list. A value of nil does not mutate the Pod.
+    #[serde(rename = "defaultRuntimeClassName", skip_serializing_if = "Option::is_none")]
+    pub default_runtime_class_name: Option<String>,
+}
+
+impl RuntimeClassStrategyOptions {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> RuntimeClassStrategyOptionsBuilder<crate::generics::MissingAllowedRuntimeClassNames> {
+        RuntimeClassStrategyOptionsBuilder {
+            body: Default::default(),
+            _allowed_runtime_class_names: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> RuntimeClassStrategyOptionsBuilder<crate::generics::AllowedRuntimeClassNamesExists>
+    where
+        Self: Clone,
+    {
+        RuntimeClassStrategyOptionsBuilder {
+            body: self.clone(),
+            _allowed_runtime_class_names: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<RuntimeClassStrategyOptions> for RuntimeClassStrategyOptionsBuilder<crate::generics::AllowedRuntimeClassNamesExists> {
+    fn into(self) -> RuntimeClassStrategyOptions {
+        self.body
+    }
+}
+
+/// Builder for [`RuntimeClassStrategyOptions`](./struct.RuntimeClassStrategyOptions.html) object.
+#[derive(Debug, Clone)]
+pub struct RuntimeClassStrategyOptionsBuilder<AllowedRuntimeClassNames> {
+    body: self::RuntimeClassStrategyOptions,
+    _allowed_runtime_class_names: core::marker::PhantomData<AllowedRuntimeClassNames>,
+}
+
+impl<AllowedRuntimeClassNames> RuntimeClassStrategyOptionsBuilder<AllowedRuntimeClassNames> {
+    /// allowedRuntimeClassNames is a whitelist of RuntimeClass names that may be specified on a pod. A value of "\*" means that any RuntimeClass name is allowed, and must be the only item in the list. An empty list requires the RuntimeClassName field to be unset.
+    #[inline]
+    pub fn allowed_runtime_class_names(mut self, value: impl Iterator<Item = impl Into<String>>) -> RuntimeClassStrategyOptionsBuilder<crate::generics::AllowedRuntimeClassNamesExists> {
+        self.body.allowed_runtime_class_names = value.map(|value| value.into()).collect::<Vec<_>>().into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Same as [`allowed_runtime_class_names`](#method.allowed_runtime_class_names), but takes the values as a `Vec`.
+    #[inline]
+    pub fn allowed_runtime_class_names_vec(mut self, value: impl Into<Vec<String>>) -> RuntimeClassStrategyOptionsBuilder<crate::generics::AllowedRuntimeClassNamesExists> {
+        self.body.allowed_runtime_class_names = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// defaultRuntimeClassName is the default RuntimeClassName to set on the pod. The default MUST be allowed by the allowedRuntimeClassNames list. A value of nil does not mutate the Pod.
+    #[inline]
+    pub fn default_runtime_class_name(mut self, value: impl Into<String>) -> Self {
+        self.body.default_runtime_class_name = Some(value.into());
+        self
+    }
+
+    /// Sets [`default_runtime_class_name`](#method.default_runtime_class_name) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_default_runtime_class_name(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.default_runtime_class_name = value.map(|value| value.into());
+        self
+    }
+}
@@ -0,0 +1,69 @@
+
+/// SELinuxStrategyOptions defines the strategy type and any options used to create the strategy.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct SeLinuxStrategyOptions {
+    /// rule is the strategy that will dictate the allowable labels that may be set.
+    pub rule: String,
+    /// seLinuxOptions required to run as; required for MustRunAs More info: https://kubernetes.io/docs/tasks/configure-pod-container/security-context/
+    #[serde(rename = "seLinuxOptions", skip_serializing_if = "Option::is_none")]
+    pub se_linux_options: Option<crate::io::k8s::api::core::v1::se_linux_options::SeLinuxOptions>,
+}
+
+impl SeLinuxStrategyOptions {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> SeLinuxStrategyOptionsBuilder<crate::generics::MissingRule> {
+        SeLinuxStrategyOptionsBuilder {
+            body: Default::default(),
+            _rule: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> SeLinuxStrategyOptionsBuilder<crate::generics::RuleExists>
+    where
+        Self: Clone,
+    {
+        SeLinuxStrategyOptionsBuilder {
+            body: self.clone(),
+            _rule: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<SeLinuxStrategyOptions> for SeLinuxStrategyOptionsBuilder<crate::generics::RuleExists> {
+    fn into(self) -> SeLinuxStrategyOptions {
+        self.body
+    }
+}
+
+/// Builder for [`SeLinuxStrategyOptions`](./struct.SeLinuxStrategyOptions.html) object.
+#[derive(Debug, Clone)]
+pub struct SeLinuxStrategyOptionsBuilder<Rule> {
+    body: self::SeLinuxStrategyOptions,
+    _rule: core::marker::PhantomData<Rule>,
+}
+
+impl<Rule> SeLinuxStrategyOptionsBuilder<Rule> {
+    /// rule is the strategy that will dictate the allowable labels that may be set.
+    #[inline]
+    pub fn rule(mut self, value: impl Into<String>) -> SeLinuxStrategyOptionsBuilder<crate::generics::RuleExists> {
+        self.body.rule = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// seLinuxOptions required to run as; required for MustRunAs More info: https://kubernetes.io/docs/tasks/configure-pod-container/security-context/
+    #[inline]
+    pub fn se_linux_options(mut self, value: crate::io::k8s::api::core::v1::se_linux_options::SeLinuxOptions) -> Self {
+        self.body.se_linux_options = Some(value.into());
+        self
+    }
+
+    /// Sets [`se_linux_options`](#method.se_linux_options) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_se_linux_options(mut self, value: Option<crate::io::k8s::api::core::v1::se_linux_options::SeLinuxOptions>) -> Self {
+        self.body.se_linux_options = value.map(|value| value.into());
+        self
+    }
+}
@@ -0,0 +1,81 @@
+
+/// SupplementalGroupsStrategyOptions defines the strategy type and options used to create the strategy.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct SupplementalGroupsStrategyOptions {
+    /// ranges are the allowed ranges of supplemental groups.  If you would like to force a single supplemental group then supply a single range with the same start and end. Required for MustRunAs.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ranges: Option<Vec<crate::io::k8s::api::policy::v1beta1::id_range::IdRange>>,
+    /// rule is the strategy that will dictate what supplemental groups is used in the SecurityContext.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rule: Option<String>,
+}
+
+impl SupplementalGroupsStrategyOptions {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> SupplementalGroupsStrategyOptionsBuilder {
+        SupplementalGroupsStrategyOptionsBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> SupplementalGroupsStrategyOptionsBuilder
+    where
+        Self: Clone,
+    {
+        SupplementalGroupsStrategyOptionsBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<SupplementalGroupsStrategyOptions> for SupplementalGroupsStrategyOptionsBuilder {
+    fn into(self) -> SupplementalGroupsStrategyOptions {
+        self.body
+    }
+}
+
+/// Builder for [`SupplementalGroupsStrategyOptions`](./struct.SupplementalGroupsStrategyOptions.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct SupplementalGroupsStrategyOptionsBuilder {
+    body: self::SupplementalGroupsStrategyOptions,
+}
+
+impl SupplementalGroupsStrategyOptionsBuilder {
+    /// ranges are the allowed ranges of supplemental groups.  If you would like to force a single supplemental group then supply a single range with the same start and end. Required for MustRunAs.
+    #[inline]
+    pub fn ranges(mut self, value: impl Iterator<Item = crate::io::k8s::api::policy::v1beta1::id_range::IdRangeBuilder<crate::generics::MaxExists, crate::generics::MinExists>>) -> Self {
+        self.body.ranges = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`ranges`](#method.ranges) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_ranges(mut self, value: Option<impl Iterator<Item = crate::io::k8s::api::policy::v1beta1::id_range::IdRangeBuilder<crate::generics::MaxExists, crate::generics::MinExists>>>) -> Self {
+        self.body.ranges = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`ranges`](#method.ranges), but takes the values as a `Vec`.
+    #[inline]
+    pub fn ranges_vec(mut self, value: impl Into<Vec<crate::io::k8s::api::policy::v1beta1::id_range::IdRange>>) -> Self {
+        self.body.ranges = Some(value.into());
+        self
+    }
+
+    /// rule is the strategy that will dictate what supplemental groups is used in the SecurityContext.
+    #[inline]
+    pub fn rule(mut self, value: impl Into<String>) -> Self {
+        self.body.rule = Some(value.into());
+        self
+    }
+
+    /// Sets [`rule`](#method.rule) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_rule(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.rule = value.map(|value| value.into());
+        self
+    }
+}
@@ -0,0 +1,155 @@
+
+/// PolicyRule holds information that describes a policy rule, but does not contain information about who the rule applies to or which namespace the rule applies to.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct PolicyRule {
+    /// APIGroups is the name of the APIGroup that contains the resources.  If multiple API groups are specified, any action requested against one of the enumerated resources in any API group will be allowed.
+    #[serde(rename = "apiGroups", skip_serializing_if = "Option::is_none")]
+    pub api_groups: Option<Vec<String>>,
+    /// NonResourceURLs is a set of partial urls that a user should have access to.  \*s are allowed, but only as the full, final step in the path Since non-resource URLs are not namespaced, this field is only applicable for ClusterRoles referenced from a ClusterRoleBinding. Rules can either apply to API resources (such as "pods" or "secrets") or non-resource URL paths (such as "/api"),  but not both.
+    #[serde(rename = "nonResourceURLs", skip_serializing_if = "Option::is_none")]
+    pub non_resource_ur_ls: Option<Vec<String>>,
+    /// ResourceNames is an optional white list of names that the rule applies to.  An empty set means that everything is allowed.
+    #[serde(rename = "resourceNames", skip_serializing_if = "Option::is_none")]
+    pub resource_names: Option<Vec<String>>,
+    /// Resources is a list of resources this rule applies to.  ResourceAll represents all resources.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resources: Option<Vec<String>>,
+    /// Verbs is a list of Verbs that apply to ALL the ResourceKinds and AttributeRestrictions contained in this rule.  VerbAll represents all kinds.
+    pub verbs: Vec<String>,
+}
+
+impl PolicyRule {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> PolicyRuleBuilder<crate::generics::MissingVerbs> {
+        PolicyRuleBuilder {
+            body: Default::default(),
+            _verbs: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> PolicyRuleBuilder<crate::generics::VerbsExists>
+    where
+        Self: Clone,
+    {
+        PolicyRuleBuilder {
+            body: self.clone(),
+            _verbs: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<PolicyRule> for PolicyRuleBuilder<crate::generics::VerbsExists> {
+    fn into(self) -> PolicyRule {
+        self.body
+    }
+}
+
+/// Builder for [`PolicyRule`](./struct.PolicyRule.html) object.
+#[derive(Debug, Clone)]
+pub struct PolicyRuleBuilder<Verbs> {
+    body: self::PolicyRule,
+    _verbs: core::marker::PhantomData<Verbs>,
+}
+
+impl<Verbs> PolicyRuleBuilder<Verbs> {
+    /// APIGroups is the name of the APIGroup that contains the resources.  If multiple API groups are specified, any action requested against one of the enumerated resources in any API group will be allowed.
+    #[inline]
+    pub fn api_groups(mut self, value: impl Iterator<Item = impl Into<String>>) -> Self {
+        self.body.api_groups = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`api_groups`](#method.api_groups) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_api_groups(mut self, value: Option<impl Iterator<Item = impl Into<String>>>) -> Self {
+        self.body.api_groups = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`api_groups`](#method.api_groups), but takes the values as a `Vec`.
+    #[inline]
+    pub fn api_groups_vec(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.body.api_groups = Some(value.into());
+        self
+    }
+
+    /// NonResourceURLs is a set of partial urls that a user should have access to.  \*s are allowed, but only as the full, final step in the path Since non-resource URLs are not namespaced, this field is only applicable for ClusterRoles referenced from a ClusterRoleBinding. Rules can either apply to API resources (such as "pods" or "secrets") or non-resource URL paths (such as "/api"),  but not both.
+    #[inline]
+    pub fn non_resource_ur_ls(mut self, value: impl Iterator<Item = impl Into<String>>) -> Self {
+        self.body.non_resource_ur_ls = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`non_resource_ur_ls`](#method.non_resource_ur_ls) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_non_resource_ur_ls(mut self, value: Option<impl Iterator<Item = impl Into<String>>>) -> Self {
+        self.body.non_resource_ur_ls = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`non_resource_ur_ls`](#method.non_resource_ur_ls), but takes the values as a `Vec`.
+    #[inline]
+    pub fn non_resource_ur_ls_vec(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.body.non_resource_ur_ls = Some(value.into());
+        self
+    }
+
+    /// ResourceNames is an optional white list of names that the rule applies to.  An empty set means that everything is allowed.
+    #[inline]
+    pub fn resource_names(mut self, value: impl Iterator<Item = impl Into<String>>) -> Self {
+        self.body.resource_names = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`resource_names`](#method.resource_names) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_resource_names(mut self, value: Option<impl Iterator<Item = impl Into<String>>>) -> Self {
+        self.body.resource_names = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`resource_names`](#method.resource_names), but takes the values as a `Vec`.
+    #[inline]
+    pub fn resource_names_vec(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.body.resource_names = Some(value.into());
+        self
+    }
+
+    /// Resources is a list of resources this rule applies to.  ResourceAll represents all resources.
+    #[inline]
+    pub fn resources(mut self, value: impl Iterator<Item = impl Into<String>>) -> Self {
+        self.body.resources = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`resources`](#method.resources) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_resources(mut self, value: Option<impl Iterator<Item = impl Into<String>>>) -> Self {
+        self.body.resources = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`resources`](#method.resources), but takes the values as a `Vec`.
+    #[inline]
+    pub fn resources_vec(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.body.resources = Some(value.into());
+        self
+    }
+
+    /// Verbs is a list of Verbs that apply to ALL the ResourceKinds and AttributeRestrictions contained in this rule.  VerbAll represents all kinds.
+    #[inline]
+    pub fn verbs(mut self, value: impl Iterator<Item = impl Into<String>>) -> PolicyRuleBuilder<crate::generics::VerbsExists> {
+        self.body.verbs = value.map(|value| value.into()).collect::<Vec<_>>().into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Same as [`verbs`](#method.verbs), but takes the values as a `Vec`.
+    #[inline]
+    pub fn verbs_vec(mut self, value: impl Into<Vec<String>>) -> PolicyRuleBuilder<crate::generics::VerbsExists> {
+        self.body.verbs = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+}
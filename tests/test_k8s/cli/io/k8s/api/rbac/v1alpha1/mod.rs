@@ -0,0 +1,48 @@
+
+pub mod aggregation_rule {
+    include!("./aggregation_rule.rs");
+}
+
+pub mod cluster_role {
+    include!("./cluster_role.rs");
+}
+
+pub mod cluster_role_binding {
+    include!("./cluster_role_binding.rs");
+}
+
+pub mod cluster_role_binding_list {
+    include!("./cluster_role_binding_list.rs");
+}
+
+pub mod cluster_role_list {
+    include!("./cluster_role_list.rs");
+}
+
+pub mod policy_rule {
+    include!("./policy_rule.rs");
+}
+
+pub mod role {
+    include!("./role.rs");
+}
+
+pub mod role_binding {
+    include!("./role_binding.rs");
+}
+
+pub mod role_binding_list {
+    include!("./role_binding_list.rs");
+}
+
+pub mod role_list {
+    include!("./role_list.rs");
+}
+
+pub mod role_ref {
+    include!("./role_ref.rs");
+}
+
+pub mod subject {
+    include!("./subject.rs");
+}
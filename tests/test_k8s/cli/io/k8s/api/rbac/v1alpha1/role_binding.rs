@@ -0,0 +1,739 @@
+
+/// RoleBinding references a role, but does not contain it.  It can reference a Role in the same namespace or a ClusterRole in the global namespace. It adds who information via Subjects and namespace information by which namespace it exists in.  RoleBindings in a given namespace only have effect in that namespace.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct RoleBinding {
+    /// APIVersion defines the versioned schema of this representation of an object. Servers should convert recognized schemas to the latest internal value, and may reject unrecognized values. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#resources
+    #[serde(rename = "apiVersion", skip_serializing_if = "Option::is_none")]
+    pub api_version: Option<String>,
+    /// Kind is a string value representing the REST resource this object represents. Servers may infer this from the endpoint the client submits requests to. Cannot be updated. In CamelCase. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#types-kinds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub kind: Option<String>,
+    /// Standard object's metadata.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<crate::io::k8s::apimachinery::pkg::apis::meta::v1::object_meta::ObjectMeta>,
+    /// RoleRef can reference a Role in the current namespace or a ClusterRole in the global namespace. If the RoleRef cannot be resolved, the Authorizer must return an error.
+    #[serde(rename = "roleRef")]
+    pub role_ref: crate::io::k8s::api::rbac::v1alpha1::role_ref::RoleRef,
+    /// Subjects holds references to the objects the role applies to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subjects: Option<Vec<crate::io::k8s::api::rbac::v1alpha1::subject::Subject>>,
+}
+
+impl RoleBinding {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> RoleBindingBuilder<crate::generics::MissingRoleRef> {
+        RoleBindingBuilder {
+            body: Default::default(),
+            _role_ref: core::marker::PhantomData,
+        }
+    }
+
+    /// create a RoleBinding
+    #[inline]
+    pub fn create_rbac_authorization_v1alpha1_namespaced_role_binding() -> RoleBindingPostBuilder<crate::generics::MissingNamespace, crate::generics::MissingRoleRef> {
+        RoleBindingPostBuilder {
+            inner: Default::default(),
+            _param_namespace: core::marker::PhantomData,
+            _role_ref: core::marker::PhantomData,
+        }
+    }
+
+    /// read the specified RoleBinding
+    #[inline]
+    pub fn read_rbac_authorization_v1alpha1_namespaced_role_binding() -> RoleBindingGetBuilder1<crate::generics::MissingName, crate::generics::MissingNamespace> {
+        RoleBindingGetBuilder1 {
+            inner: Default::default(),
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        }
+    }
+
+    /// replace the specified RoleBinding
+    #[inline]
+    pub fn replace_rbac_authorization_v1alpha1_namespaced_role_binding() -> RoleBindingPutBuilder1<crate::generics::MissingName, crate::generics::MissingNamespace, crate::generics::MissingRoleRef> {
+        RoleBindingPutBuilder1 {
+            inner: Default::default(),
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+            _role_ref: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> RoleBindingBuilder<crate::generics::RoleRefExists>
+    where
+        Self: Clone,
+    {
+        RoleBindingBuilder {
+            body: self.clone(),
+            _role_ref: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<RoleBinding> for RoleBindingBuilder<crate::generics::RoleRefExists> {
+    fn into(self) -> RoleBinding {
+        self.body
+    }
+}
+
+impl Into<RoleBinding> for RoleBindingPostBuilder<crate::generics::NamespaceExists, crate::generics::RoleRefExists> {
+    fn into(self) -> RoleBinding {
+        self.inner.body
+    }
+}
+
+impl Into<RoleBinding> for RoleBindingPutBuilder1<crate::generics::NameExists, crate::generics::NamespaceExists, crate::generics::RoleRefExists> {
+    fn into(self) -> RoleBinding {
+        self.inner.body
+    }
+}
+
+/// Builder for [`RoleBinding`](./struct.RoleBinding.html) object.
+#[derive(Debug, Clone)]
+pub struct RoleBindingBuilder<RoleRef> {
+    body: self::RoleBinding,
+    _role_ref: core::marker::PhantomData<RoleRef>,
+}
+
+impl<RoleRef> RoleBindingBuilder<RoleRef> {
+    /// APIVersion defines the versioned schema of this representation of an object. Servers should convert recognized schemas to the latest internal value, and may reject unrecognized values. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#resources
+    #[inline]
+    pub fn api_version(mut self, value: impl Into<String>) -> Self {
+        self.body.api_version = Some(value.into());
+        self
+    }
+
+    /// Sets [`api_version`](#method.api_version) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_api_version(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.api_version = value.map(|value| value.into());
+        self
+    }
+
+    /// Kind is a string value representing the REST resource this object represents. Servers may infer this from the endpoint the client submits requests to. Cannot be updated. In CamelCase. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#types-kinds
+    #[inline]
+    pub fn kind(mut self, value: impl Into<String>) -> Self {
+        self.body.kind = Some(value.into());
+        self
+    }
+
+    /// Sets [`kind`](#method.kind) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_kind(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.kind = value.map(|value| value.into());
+        self
+    }
+
+    /// Standard object's metadata.
+    #[inline]
+    pub fn metadata(mut self, value: crate::io::k8s::apimachinery::pkg::apis::meta::v1::object_meta::ObjectMeta) -> Self {
+        self.body.metadata = Some(value.into());
+        self
+    }
+
+    /// Sets [`metadata`](#method.metadata) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_metadata(mut self, value: Option<crate::io::k8s::apimachinery::pkg::apis::meta::v1::object_meta::ObjectMeta>) -> Self {
+        self.body.metadata = value.map(|value| value.into());
+        self
+    }
+
+    /// RoleRef can reference a Role in the current namespace or a ClusterRole in the global namespace. If the RoleRef cannot be resolved, the Authorizer must return an error.
+    #[inline]
+    pub fn role_ref(mut self, value: crate::io::k8s::api::rbac::v1alpha1::role_ref::RoleRefBuilder<crate::generics::ApiGroupExists, crate::generics::KindExists, crate::generics::NameExists>) -> RoleBindingBuilder<crate::generics::RoleRefExists> {
+        self.body.role_ref = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Subjects holds references to the objects the role applies to.
+    #[inline]
+    pub fn subjects(mut self, value: impl Iterator<Item = crate::io::k8s::api::rbac::v1alpha1::subject::SubjectBuilder<crate::generics::KindExists, crate::generics::NameExists>>) -> Self {
+        self.body.subjects = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`subjects`](#method.subjects) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_subjects(mut self, value: Option<impl Iterator<Item = crate::io::k8s::api::rbac::v1alpha1::subject::SubjectBuilder<crate::generics::KindExists, crate::generics::NameExists>>>) -> Self {
+        self.body.subjects = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`subjects`](#method.subjects), but takes the values as a `Vec`.
+    #[inline]
+    pub fn subjects_vec(mut self, value: impl Into<Vec<crate::io::k8s::api::rbac::v1alpha1::subject::Subject>>) -> Self {
+        self.body.subjects = Some(value.into());
+        self
+    }
+}
+
+/// Builder created by [`RoleBinding::create_rbac_authorization_v1alpha1_namespaced_role_binding`](./struct.RoleBinding.html#method.create_rbac_authorization_v1alpha1_namespaced_role_binding) method for a `POST` operation associated with `RoleBinding`.
+#[repr(transparent)]
+#[derive(Debug, Clone)]
+pub struct RoleBindingPostBuilder<Namespace, RoleRef> {
+    inner: RoleBindingPostBuilderContainer,
+    _param_namespace: core::marker::PhantomData<Namespace>,
+    _role_ref: core::marker::PhantomData<RoleRef>,
+}
+
+#[derive(Debug, Default, Clone)]
+struct RoleBindingPostBuilderContainer {
+    body: self::RoleBinding,
+    param_dry_run: Option<String>,
+    param_field_manager: Option<String>,
+    param_namespace: Option<String>,
+    param_pretty: Option<String>,
+}
+
+impl<Namespace, RoleRef> RoleBindingPostBuilder<Namespace, RoleRef> {
+    /// When present, indicates that modifications should not be persisted. An invalid or unrecognized dryRun directive will result in an error response and no further processing of the request. Valid values are: - All: all dry run stages will be processed
+    #[inline]
+    pub fn dry_run(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_dry_run = Some(value.into());
+        self
+    }
+
+    /// Sets [`dry_run`](#method.dry_run) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_dry_run(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_dry_run = value.map(|value| value.into());
+        self
+    }
+
+    /// fieldManager is a name associated with the actor or entity that is making these changes. The value must be less than or 128 characters long, and only contain printable characters, as defined by https://golang.org/pkg/unicode/#IsPrint.
+    #[inline]
+    pub fn field_manager(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_field_manager = Some(value.into());
+        self
+    }
+
+    /// Sets [`field_manager`](#method.field_manager) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_field_manager(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_field_manager = value.map(|value| value.into());
+        self
+    }
+
+    /// object name and auth scope, such as for teams and projects
+    #[inline]
+    pub fn namespace(mut self, value: impl Into<String>) -> RoleBindingPostBuilder<crate::generics::NamespaceExists, RoleRef> {
+        self.inner.param_namespace = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// If 'true', then the output is pretty printed.
+    #[inline]
+    pub fn pretty(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_pretty = Some(value.into());
+        self
+    }
+
+    /// Sets [`pretty`](#method.pretty) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_pretty(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_pretty = value.map(|value| value.into());
+        self
+    }
+
+    /// APIVersion defines the versioned schema of this representation of an object. Servers should convert recognized schemas to the latest internal value, and may reject unrecognized values. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#resources
+    #[inline]
+    pub fn api_version(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.api_version = Some(value.into());
+        self
+    }
+
+    /// Sets [`api_version`](#method.api_version) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_api_version(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.api_version = value.map(|value| value.into());
+        self
+    }
+
+    /// Kind is a string value representing the REST resource this object represents. Servers may infer this from the endpoint the client submits requests to. Cannot be updated. In CamelCase. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#types-kinds
+    #[inline]
+    pub fn kind(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.kind = Some(value.into());
+        self
+    }
+
+    /// Sets [`kind`](#method.kind) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_kind(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.kind = value.map(|value| value.into());
+        self
+    }
+
+    /// Standard object's metadata.
+    #[inline]
+    pub fn metadata(mut self, value: crate::io::k8s::apimachinery::pkg::apis::meta::v1::object_meta::ObjectMeta) -> Self {
+        self.inner.body.metadata = Some(value.into());
+        self
+    }
+
+    /// Sets [`metadata`](#method.metadata) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_metadata(mut self, value: Option<crate::io::k8s::apimachinery::pkg::apis::meta::v1::object_meta::ObjectMeta>) -> Self {
+        self.inner.body.metadata = value.map(|value| value.into());
+        self
+    }
+
+    /// RoleRef can reference a Role in the current namespace or a ClusterRole in the global namespace. If the RoleRef cannot be resolved, the Authorizer must return an error.
+    #[inline]
+    pub fn role_ref(mut self, value: crate::io::k8s::api::rbac::v1alpha1::role_ref::RoleRefBuilder<crate::generics::ApiGroupExists, crate::generics::KindExists, crate::generics::NameExists>) -> RoleBindingPostBuilder<Namespace, crate::generics::RoleRefExists> {
+        self.inner.body.role_ref = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Subjects holds references to the objects the role applies to.
+    #[inline]
+    pub fn subjects(mut self, value: impl Iterator<Item = crate::io::k8s::api::rbac::v1alpha1::subject::SubjectBuilder<crate::generics::KindExists, crate::generics::NameExists>>) -> Self {
+        self.inner.body.subjects = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`subjects`](#method.subjects) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_subjects(mut self, value: Option<impl Iterator<Item = crate::io::k8s::api::rbac::v1alpha1::subject::SubjectBuilder<crate::generics::KindExists, crate::generics::NameExists>>>) -> Self {
+        self.inner.body.subjects = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`subjects`](#method.subjects), but takes the values as a `Vec`.
+    #[inline]
+    pub fn subjects_vec(mut self, value: impl Into<Vec<crate::io::k8s::api::rbac::v1alpha1::subject::Subject>>) -> Self {
+        self.inner.body.subjects = Some(value.into());
+        self
+    }
+}
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for RoleBindingPostBuilder<crate::generics::NamespaceExists, crate::generics::RoleRefExists> {
+    type Output = crate::io::k8s::api::rbac::v1alpha1::role_binding::RoleBinding;
+
+    const METHOD: http::Method = http::Method::POST;
+
+    const OPERATION_ID: Option<&'static str> = Some("createRbacAuthorizationV1alpha1NamespacedRoleBinding");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok(format!("/apis/rbac.authorization.k8s.io/v1alpha1/namespaces/{namespace}/rolebindings", namespace=crate::client::path_param("namespace", self.inner.param_namespace.as_ref().expect("missing parameter namespace?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::CONTENT_TYPE.as_str(), "application/json")
+        .json(&self.inner.body)
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("dryRun", self.inner.param_dry_run.as_ref().map(std::string::ToString::to_string)),
+            ("fieldManager", self.inner.param_field_manager.as_ref().map(std::string::ToString::to_string)),
+            ("pretty", self.inner.param_pretty.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Content-Type")
+            || name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<RoleBindingPostBuilder<crate::generics::NamespaceExists, crate::generics::RoleRefExists>> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: RoleBindingPostBuilder<crate::generics::NamespaceExists, crate::generics::RoleRefExists>) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl RoleBindingPostBuilder<crate::generics::NamespaceExists, crate::generics::RoleRefExists> {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = RoleBindingPostBuilder {
+            inner: RoleBindingPostBuilderContainer {
+            body: crate::cli::read_from_input(matches)?,
+            param_dry_run: matches.and_then(|m| {
+                    m.value_of("dry-run").map(|_| {
+                        value_t!(m, "dry-run", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_field_manager: matches.and_then(|m| {
+                    m.value_of("field-manager").map(|_| {
+                        value_t!(m, "field-manager", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_namespace: matches.and_then(|m| {
+                    m.value_of("namespace").map(|_| {
+                        value_t!(m, "namespace", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_pretty: matches.and_then(|m| {
+                    m.value_of("pretty").map(|_| {
+                        value_t!(m, "pretty", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            },
+            _param_namespace: core::marker::PhantomData,
+            _role_ref: core::marker::PhantomData,
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder created by [`RoleBinding::read_rbac_authorization_v1alpha1_namespaced_role_binding`](./struct.RoleBinding.html#method.read_rbac_authorization_v1alpha1_namespaced_role_binding) method for a `GET` operation associated with `RoleBinding`.
+#[repr(transparent)]
+#[derive(Debug, Clone)]
+pub struct RoleBindingGetBuilder1<Name, Namespace> {
+    inner: RoleBindingGetBuilder1Container,
+    _param_name: core::marker::PhantomData<Name>,
+    _param_namespace: core::marker::PhantomData<Namespace>,
+}
+
+#[derive(Debug, Default, Clone)]
+struct RoleBindingGetBuilder1Container {
+    param_name: Option<String>,
+    param_namespace: Option<String>,
+    param_pretty: Option<String>,
+}
+
+impl<Name, Namespace> RoleBindingGetBuilder1<Name, Namespace> {
+    /// name of the RoleBinding
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> RoleBindingGetBuilder1<crate::generics::NameExists, Namespace> {
+        self.inner.param_name = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// object name and auth scope, such as for teams and projects
+    #[inline]
+    pub fn namespace(mut self, value: impl Into<String>) -> RoleBindingGetBuilder1<Name, crate::generics::NamespaceExists> {
+        self.inner.param_namespace = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// If 'true', then the output is pretty printed.
+    #[inline]
+    pub fn pretty(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_pretty = Some(value.into());
+        self
+    }
+
+    /// Sets [`pretty`](#method.pretty) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_pretty(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_pretty = value.map(|value| value.into());
+        self
+    }
+}
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for RoleBindingGetBuilder1<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    type Output = RoleBinding;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    const OPERATION_ID: Option<&'static str> = Some("readRbacAuthorizationV1alpha1NamespacedRoleBinding");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok(format!("/apis/rbac.authorization.k8s.io/v1alpha1/namespaces/{namespace}/rolebindings/{name}", name=crate::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?, namespace=crate::client::path_param("namespace", self.inner.param_namespace.as_ref().expect("missing parameter namespace?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("pretty", self.inner.param_pretty.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<RoleBindingGetBuilder1<crate::generics::NameExists, crate::generics::NamespaceExists>> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: RoleBindingGetBuilder1<crate::generics::NameExists, crate::generics::NamespaceExists>) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl RoleBindingGetBuilder1<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = RoleBindingGetBuilder1 {
+            inner: RoleBindingGetBuilder1Container {
+            param_name: matches.and_then(|m| {
+                    m.value_of("name").map(|_| {
+                        value_t!(m, "name", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_namespace: matches.and_then(|m| {
+                    m.value_of("namespace").map(|_| {
+                        value_t!(m, "namespace", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_pretty: matches.and_then(|m| {
+                    m.value_of("pretty").map(|_| {
+                        value_t!(m, "pretty", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            },
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder created by [`RoleBinding::replace_rbac_authorization_v1alpha1_namespaced_role_binding`](./struct.RoleBinding.html#method.replace_rbac_authorization_v1alpha1_namespaced_role_binding) method for a `PUT` operation associated with `RoleBinding`.
+#[repr(transparent)]
+#[derive(Debug, Clone)]
+pub struct RoleBindingPutBuilder1<Name, Namespace, RoleRef> {
+    inner: RoleBindingPutBuilder1Container,
+    _param_name: core::marker::PhantomData<Name>,
+    _param_namespace: core::marker::PhantomData<Namespace>,
+    _role_ref: core::marker::PhantomData<RoleRef>,
+}
+
+#[derive(Debug, Default, Clone)]
+struct RoleBindingPutBuilder1Container {
+    body: self::RoleBinding,
+    param_dry_run: Option<String>,
+    param_field_manager: Option<String>,
+    param_name: Option<String>,
+    param_namespace: Option<String>,
+    param_pretty: Option<String>,
+}
+
+impl<Name, Namespace, RoleRef> RoleBindingPutBuilder1<Name, Namespace, RoleRef> {
+    /// When present, indicates that modifications should not be persisted. An invalid or unrecognized dryRun directive will result in an error response and no further processing of the request. Valid values are: - All: all dry run stages will be processed
+    #[inline]
+    pub fn dry_run(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_dry_run = Some(value.into());
+        self
+    }
+
+    /// Sets [`dry_run`](#method.dry_run) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_dry_run(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_dry_run = value.map(|value| value.into());
+        self
+    }
+
+    /// fieldManager is a name associated with the actor or entity that is making these changes. The value must be less than or 128 characters long, and only contain printable characters, as defined by https://golang.org/pkg/unicode/#IsPrint.
+    #[inline]
+    pub fn field_manager(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_field_manager = Some(value.into());
+        self
+    }
+
+    /// Sets [`field_manager`](#method.field_manager) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_field_manager(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_field_manager = value.map(|value| value.into());
+        self
+    }
+
+    /// name of the RoleBinding
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> RoleBindingPutBuilder1<crate::generics::NameExists, Namespace, RoleRef> {
+        self.inner.param_name = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// object name and auth scope, such as for teams and projects
+    #[inline]
+    pub fn namespace(mut self, value: impl Into<String>) -> RoleBindingPutBuilder1<Name, crate::generics::NamespaceExists, RoleRef> {
+        self.inner.param_namespace = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// If 'true', then the output is pretty printed.
+    #[inline]
+    pub fn pretty(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_pretty = Some(value.into());
+        self
+    }
+
+    /// Sets [`pretty`](#method.pretty) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_pretty(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_pretty = value.map(|value| value.into());
+        self
+    }
+
+    /// APIVersion defines the versioned schema of this representation of an object. Servers should convert recognized schemas to the latest internal value, and may reject unrecognized values. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#resources
+    #[inline]
+    pub fn api_version(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.api_version = Some(value.into());
+        self
+    }
+
+    /// Sets [`api_version`](#method.api_version) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_api_version(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.api_version = value.map(|value| value.into());
+        self
+    }
+
+    /// Kind is a string value representing the REST resource this object represents. Servers may infer this from the endpoint the client submits requests to. Cannot be updated. In CamelCase. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#types-kinds
+    #[inline]
+    pub fn kind(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.kind = Some(value.into());
+        self
+    }
+
+    /// Sets [`kind`](#method.kind) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_kind(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.kind = value.map(|value| value.into());
+        self
+    }
+
+    /// Standard object's metadata.
+    #[inline]
+    pub fn metadata(mut self, value: crate::io::k8s::apimachinery::pkg::apis::meta::v1::object_meta::ObjectMeta) -> Self {
+        self.inner.body.metadata = Some(value.into());
+        self
+    }
+
+    /// Sets [`metadata`](#method.metadata) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_metadata(mut self, value: Option<crate::io::k8s::apimachinery::pkg::apis::meta::v1::object_meta::ObjectMeta>) -> Self {
+        self.inner.body.metadata = value.map(|value| value.into());
+        self
+    }
+
+    /// RoleRef can reference a Role in the current namespace or a ClusterRole in the global namespace. If the RoleRef cannot be resolved, the Authorizer must return an error.
+    #[inline]
+    pub fn role_ref(mut self, value: crate::io::k8s::api::rbac::v1alpha1::role_ref::RoleRefBuilder<crate::generics::ApiGroupExists, crate::generics::KindExists, crate::generics::NameExists>) -> RoleBindingPutBuilder1<Name, Namespace, crate::generics::RoleRefExists> {
+        self.inner.body.role_ref = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Subjects holds references to the objects the role applies to.
+    #[inline]
+    pub fn subjects(mut self, value: impl Iterator<Item = crate::io::k8s::api::rbac::v1alpha1::subject::SubjectBuilder<crate::generics::KindExists, crate::generics::NameExists>>) -> Self {
+        self.inner.body.subjects = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`subjects`](#method.subjects) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_subjects(mut self, value: Option<impl Iterator<Item = crate::io::k8s::api::rbac::v1alpha1::subject::SubjectBuilder<crate::generics::KindExists, crate::generics::NameExists>>>) -> Self {
+        self.inner.body.subjects = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`subjects`](#method.subjects), but takes the values as a `Vec`.
+    #[inline]
+    pub fn subjects_vec(mut self, value: impl Into<Vec<crate::io::k8s::api::rbac::v1alpha1::subject::Subject>>) -> Self {
+        self.inner.body.subjects = Some(value.into());
+        self
+    }
+}
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for RoleBindingPutBuilder1<crate::generics::NameExists, crate::generics::NamespaceExists, crate::generics::RoleRefExists> {
+    type Output = crate::io::k8s::api::rbac::v1alpha1::role_binding::RoleBinding;
+
+    const METHOD: http::Method = http::Method::PUT;
+
+    const OPERATION_ID: Option<&'static str> = Some("replaceRbacAuthorizationV1alpha1NamespacedRoleBinding");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok(format!("/apis/rbac.authorization.k8s.io/v1alpha1/namespaces/{namespace}/rolebindings/{name}", name=crate::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?, namespace=crate::client::path_param("namespace", self.inner.param_namespace.as_ref().expect("missing parameter namespace?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::CONTENT_TYPE.as_str(), "application/json")
+        .json(&self.inner.body)
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("dryRun", self.inner.param_dry_run.as_ref().map(std::string::ToString::to_string)),
+            ("fieldManager", self.inner.param_field_manager.as_ref().map(std::string::ToString::to_string)),
+            ("pretty", self.inner.param_pretty.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Content-Type")
+            || name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<RoleBindingPutBuilder1<crate::generics::NameExists, crate::generics::NamespaceExists, crate::generics::RoleRefExists>> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: RoleBindingPutBuilder1<crate::generics::NameExists, crate::generics::NamespaceExists, crate::generics::RoleRefExists>) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl RoleBindingPutBuilder1<crate::generics::NameExists, crate::generics::NamespaceExists, crate::generics::RoleRefExists> {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = RoleBindingPutBuilder1 {
+            inner: RoleBindingPutBuilder1Container {
+            body: crate::cli::read_from_input(matches)?,
+            param_dry_run: matches.and_then(|m| {
+                    m.value_of("dry-run").map(|_| {
+                        value_t!(m, "dry-run", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_field_manager: matches.and_then(|m| {
+                    m.value_of("field-manager").map(|_| {
+                        value_t!(m, "field-manager", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_name: matches.and_then(|m| {
+                    m.value_of("name").map(|_| {
+                        value_t!(m, "name", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_namespace: matches.and_then(|m| {
+                    m.value_of("namespace").map(|_| {
+                        value_t!(m, "namespace", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_pretty: matches.and_then(|m| {
+                    m.value_of("pretty").map(|_| {
+                        value_t!(m, "pretty", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            },
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+            _role_ref: core::marker::PhantomData,
+        };
+
+        Ok(thing)
+    }
+}
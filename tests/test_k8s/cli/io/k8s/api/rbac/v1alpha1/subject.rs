@@ -0,0 +1,98 @@
+
+/// Subject contains a reference to the object or user identities a role binding applies to.  This can either hold a direct API object reference, or a value for non-objects such as user and group names.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct Subject {
+    /// APIVersion holds the API group and version of the referenced subject. Defaults to "v1" for ServiceAccount subjects. Defaults to "rbac.authorization.k8s.io/v1alpha1" for User and Group subjects.
+    #[serde(rename = "apiVersion", skip_serializing_if = "Option::is_none")]
+    pub api_version: Option<String>,
+    /// Kind of object being referenced. Values defined by this API group are "User", "Group", and "ServiceAccount". If the Authorizer does not recognized the kind value, the Authorizer should report an error.
+    pub kind: String,
+    /// Name of the object being referenced.
+    pub name: String,
+    /// Namespace of the referenced object.  If the object kind is non-namespace, such as "User" or "Group", and this value is not empty the Authorizer should report an error.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub namespace: Option<String>,
+}
+
+impl Subject {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> SubjectBuilder<crate::generics::MissingKind, crate::generics::MissingName> {
+        SubjectBuilder {
+            body: Default::default(),
+            _kind: core::marker::PhantomData,
+            _name: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> SubjectBuilder<crate::generics::KindExists, crate::generics::NameExists>
+    where
+        Self: Clone,
+    {
+        SubjectBuilder {
+            body: self.clone(),
+            _kind: core::marker::PhantomData,
+            _name: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<Subject> for SubjectBuilder<crate::generics::KindExists, crate::generics::NameExists> {
+    fn into(self) -> Subject {
+        self.body
+    }
+}
+
+/// Builder for [`Subject`](./struct.Subject.html) object.
+#[derive(Debug, Clone)]
+pub struct SubjectBuilder<Kind, Name> {
+    body: self::Subject,
+    _kind: core::marker::PhantomData<Kind>,
+    _name: core::marker::PhantomData<Name>,
+}
+
+impl<Kind, Name> SubjectBuilder<Kind, Name> {
+    /// APIVersion holds the API group and version of the referenced subject. Defaults to "v1" for ServiceAccount subjects. Defaults to "rbac.authorization.k8s.io/v1alpha1" for User and Group subjects.
+    #[inline]
+    pub fn api_version(mut self, value: impl Into<String>) -> Self {
+        self.body.api_version = Some(value.into());
+        self
+    }
+
+    /// Sets [`api_version`](#method.api_version) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_api_version(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.api_version = value.map(|value| value.into());
+        self
+    }
+
+    /// Kind of object being referenced. Values defined by this API group are "User", "Group", and "ServiceAccount". If the Authorizer does not recognized the kind value, the Authorizer should report an error.
+    #[inline]
+    pub fn kind(mut self, value: impl Into<String>) -> SubjectBuilder<crate::generics::KindExists, Name> {
+        self.body.kind = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Name of the object being referenced.
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> SubjectBuilder<Kind, crate::generics::NameExists> {
+        self.body.name = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Namespace of the referenced object.  If the object kind is non-namespace, such as "User" or "Group", and this value is not empty the Authorizer should report an error.
+    #[inline]
+    pub fn namespace(mut self, value: impl Into<String>) -> Self {
+        self.body.namespace = Some(value.into());
+        self
+    }
+
+    /// Sets [`namespace`](#method.namespace) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_namespace(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.namespace = value.map(|value| value.into());
+        self
+    }
+}
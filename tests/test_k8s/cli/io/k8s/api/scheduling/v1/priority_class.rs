@@ -0,0 +1,803 @@
+
+/// PriorityClass defines mapping from a priority class name to the priority integer value. The value can be any valid integer.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct PriorityClass {
+    /// APIVersion defines the versioned schema of this representation of an object. Servers should convert recognized schemas to the latest internal value, and may reject unrecognized values. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#resources
+    #[serde(rename = "apiVersion", skip_serializing_if = "Option::is_none")]
+    pub api_version: Option<String>,
+    /// description is an arbitrary string that usually provides guidelines on when this priority class should be used.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// globalDefault specifies whether this PriorityClass should be considered as the default priority for pods that do not have any priority class. Only one PriorityClass can be marked as `globalDefault`. However, if more than one PriorityClasses exists with their `globalDefault` field set to true, the smallest value of such global default PriorityClasses will be used as the default priority.
+    #[serde(rename = "globalDefault", skip_serializing_if = "Option::is_none")]
+    pub global_default: Option<bool>,
+    /// Kind is a string value representing the REST resource this object represents. Servers may infer this from the endpoint the client submits requests to. Cannot be updated. In CamelCase. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#types-kinds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub kind: Option<String>,
+    /// Standard object's metadata. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#metadata
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<crate::io::k8s::apimachinery::pkg::apis::meta::v1::object_meta::ObjectMeta>,
+    /// The value of this priority class. This is the actual priority that pods receive when they have the name of this class in their pod spec.
+    pub value: i32,
+}
+
+impl PriorityClass {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> PriorityClassBuilder<crate::generics::MissingValue> {
+        PriorityClassBuilder {
+            body: Default::default(),
+            _value: core::marker::PhantomData,
+        }
+    }
+
+    /// create a PriorityClass
+    #[inline]
+    pub fn create_scheduling_v1_priority_class() -> PriorityClassPostBuilder<crate::generics::MissingValue> {
+        PriorityClassPostBuilder {
+            inner: Default::default(),
+            _value: core::marker::PhantomData,
+        }
+    }
+
+    /// read the specified PriorityClass
+    #[inline]
+    pub fn read_scheduling_v1_priority_class() -> PriorityClassGetBuilder1<crate::generics::MissingName> {
+        PriorityClassGetBuilder1 {
+            inner: Default::default(),
+            _param_name: core::marker::PhantomData,
+        }
+    }
+
+    /// replace the specified PriorityClass
+    #[inline]
+    pub fn replace_scheduling_v1_priority_class() -> PriorityClassPutBuilder1<crate::generics::MissingName, crate::generics::MissingValue> {
+        PriorityClassPutBuilder1 {
+            inner: Default::default(),
+            _param_name: core::marker::PhantomData,
+            _value: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> PriorityClassBuilder<crate::generics::ValueExists>
+    where
+        Self: Clone,
+    {
+        PriorityClassBuilder {
+            body: self.clone(),
+            _value: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<PriorityClass> for PriorityClassBuilder<crate::generics::ValueExists> {
+    fn into(self) -> PriorityClass {
+        self.body
+    }
+}
+
+impl Into<PriorityClass> for PriorityClassPostBuilder<crate::generics::ValueExists> {
+    fn into(self) -> PriorityClass {
+        self.inner.body
+    }
+}
+
+impl Into<PriorityClass> for PriorityClassPutBuilder1<crate::generics::NameExists, crate::generics::ValueExists> {
+    fn into(self) -> PriorityClass {
+        self.inner.body
+    }
+}
+
+/// Builder for [`PriorityClass`](./struct.PriorityClass.html) object.
+#[derive(Debug, Clone)]
+pub struct PriorityClassBuilder<Value> {
+    body: self::PriorityClass,
+    _value: core::marker::PhantomData<Value>,
+}
+
+impl<Value> PriorityClassBuilder<Value> {
+    /// APIVersion defines the versioned schema of this representation of an object. Servers should convert recognized schemas to the latest internal value, and may reject unrecognized values. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#resources
+    #[inline]
+    pub fn api_version(mut self, value: impl Into<String>) -> Self {
+        self.body.api_version = Some(value.into());
+        self
+    }
+
+    /// Sets [`api_version`](#method.api_version) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_api_version(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.api_version = value.map(|value| value.into());
+        self
+    }
+
+    /// description is an arbitrary string that usually provides guidelines on when this priority class should be used.
+    #[inline]
+    pub fn description(mut self, value: impl Into<String>) -> Self {
+        self.body.description = Some(value.into());
+        self
+    }
+
+    /// Sets [`description`](#method.description) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_description(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.description = value.map(|value| value.into());
+        self
+    }
+
+    /// globalDefault specifies whether this PriorityClass should be considered as the default priority for pods that do not have any priority class. Only one PriorityClass can be marked as `globalDefault`. However, if more than one PriorityClasses exists with their `globalDefault` field set to true, the smallest value of such global default PriorityClasses will be used as the default priority.
+    #[inline]
+    pub fn global_default(mut self, value: impl Into<bool>) -> Self {
+        self.body.global_default = Some(value.into());
+        self
+    }
+
+    /// Sets [`global_default`](#method.global_default) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_global_default(mut self, value: Option<impl Into<bool>>) -> Self {
+        self.body.global_default = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`global_default`](#method.global_default), but takes a reference.
+    #[inline]
+    pub fn global_default_ref(self, value: &bool) -> Self {
+        self.global_default(*value)
+    }
+
+    /// Kind is a string value representing the REST resource this object represents. Servers may infer this from the endpoint the client submits requests to. Cannot be updated. In CamelCase. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#types-kinds
+    #[inline]
+    pub fn kind(mut self, value: impl Into<String>) -> Self {
+        self.body.kind = Some(value.into());
+        self
+    }
+
+    /// Sets [`kind`](#method.kind) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_kind(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.kind = value.map(|value| value.into());
+        self
+    }
+
+    /// Standard object's metadata. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#metadata
+    #[inline]
+    pub fn metadata(mut self, value: crate::io::k8s::apimachinery::pkg::apis::meta::v1::object_meta::ObjectMeta) -> Self {
+        self.body.metadata = Some(value.into());
+        self
+    }
+
+    /// Sets [`metadata`](#method.metadata) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_metadata(mut self, value: Option<crate::io::k8s::apimachinery::pkg::apis::meta::v1::object_meta::ObjectMeta>) -> Self {
+        self.body.metadata = value.map(|value| value.into());
+        self
+    }
+
+    /// The value of this priority class. This is the actual priority that pods receive when they have the name of this class in their pod spec.
+    #[inline]
+    pub fn value(mut self, value: impl Into<i32>) -> PriorityClassBuilder<crate::generics::ValueExists> {
+        self.body.value = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Same as [`value`](#method.value), but takes a reference.
+    #[inline]
+    pub fn value_ref(self, value: &i32) -> PriorityClassBuilder<crate::generics::ValueExists> {
+        self.value(*value)
+    }
+}
+
+/// Builder created by [`PriorityClass::create_scheduling_v1_priority_class`](./struct.PriorityClass.html#method.create_scheduling_v1_priority_class) method for a `POST` operation associated with `PriorityClass`.
+#[repr(transparent)]
+#[derive(Debug, Clone)]
+pub struct PriorityClassPostBuilder<Value> {
+    inner: PriorityClassPostBuilderContainer,
+    _value: core::marker::PhantomData<Value>,
+}
+
+#[derive(Debug, Default, Clone)]
+struct PriorityClassPostBuilderContainer {
+    body: self::PriorityClass,
+    param_dry_run: Option<String>,
+    param_field_manager: Option<String>,
+    param_pretty: Option<String>,
+}
+
+impl<Value> PriorityClassPostBuilder<Value> {
+    /// When present, indicates that modifications should not be persisted. An invalid or unrecognized dryRun directive will result in an error response and no further processing of the request. Valid values are: - All: all dry run stages will be processed
+    #[inline]
+    pub fn dry_run(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_dry_run = Some(value.into());
+        self
+    }
+
+    /// Sets [`dry_run`](#method.dry_run) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_dry_run(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_dry_run = value.map(|value| value.into());
+        self
+    }
+
+    /// fieldManager is a name associated with the actor or entity that is making these changes. The value must be less than or 128 characters long, and only contain printable characters, as defined by https://golang.org/pkg/unicode/#IsPrint.
+    #[inline]
+    pub fn field_manager(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_field_manager = Some(value.into());
+        self
+    }
+
+    /// Sets [`field_manager`](#method.field_manager) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_field_manager(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_field_manager = value.map(|value| value.into());
+        self
+    }
+
+    /// If 'true', then the output is pretty printed.
+    #[inline]
+    pub fn pretty(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_pretty = Some(value.into());
+        self
+    }
+
+    /// Sets [`pretty`](#method.pretty) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_pretty(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_pretty = value.map(|value| value.into());
+        self
+    }
+
+    /// APIVersion defines the versioned schema of this representation of an object. Servers should convert recognized schemas to the latest internal value, and may reject unrecognized values. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#resources
+    #[inline]
+    pub fn api_version(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.api_version = Some(value.into());
+        self
+    }
+
+    /// Sets [`api_version`](#method.api_version) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_api_version(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.api_version = value.map(|value| value.into());
+        self
+    }
+
+    /// description is an arbitrary string that usually provides guidelines on when this priority class should be used.
+    #[inline]
+    pub fn description(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.description = Some(value.into());
+        self
+    }
+
+    /// Sets [`description`](#method.description) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_description(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.description = value.map(|value| value.into());
+        self
+    }
+
+    /// globalDefault specifies whether this PriorityClass should be considered as the default priority for pods that do not have any priority class. Only one PriorityClass can be marked as `globalDefault`. However, if more than one PriorityClasses exists with their `globalDefault` field set to true, the smallest value of such global default PriorityClasses will be used as the default priority.
+    #[inline]
+    pub fn global_default(mut self, value: impl Into<bool>) -> Self {
+        self.inner.body.global_default = Some(value.into());
+        self
+    }
+
+    /// Sets [`global_default`](#method.global_default) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_global_default(mut self, value: Option<impl Into<bool>>) -> Self {
+        self.inner.body.global_default = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`global_default`](#method.global_default), but takes a reference.
+    #[inline]
+    pub fn global_default_ref(self, value: &bool) -> Self {
+        self.global_default(*value)
+    }
+
+    /// Kind is a string value representing the REST resource this object represents. Servers may infer this from the endpoint the client submits requests to. Cannot be updated. In CamelCase. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#types-kinds
+    #[inline]
+    pub fn kind(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.kind = Some(value.into());
+        self
+    }
+
+    /// Sets [`kind`](#method.kind) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_kind(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.kind = value.map(|value| value.into());
+        self
+    }
+
+    /// Standard object's metadata. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#metadata
+    #[inline]
+    pub fn metadata(mut self, value: crate::io::k8s::apimachinery::pkg::apis::meta::v1::object_meta::ObjectMeta) -> Self {
+        self.inner.body.metadata = Some(value.into());
+        self
+    }
+
+    /// Sets [`metadata`](#method.metadata) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_metadata(mut self, value: Option<crate::io::k8s::apimachinery::pkg::apis::meta::v1::object_meta::ObjectMeta>) -> Self {
+        self.inner.body.metadata = value.map(|value| value.into());
+        self
+    }
+
+    /// The value of this priority class. This is the actual priority that pods receive when they have the name of this class in their pod spec.
+    #[inline]
+    pub fn value(mut self, value: impl Into<i32>) -> PriorityClassPostBuilder<crate::generics::ValueExists> {
+        self.inner.body.value = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Same as [`value`](#method.value), but takes a reference.
+    #[inline]
+    pub fn value_ref(self, value: &i32) -> PriorityClassPostBuilder<crate::generics::ValueExists> {
+        self.value(*value)
+    }
+}
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for PriorityClassPostBuilder<crate::generics::ValueExists> {
+    type Output = crate::io::k8s::api::scheduling::v1::priority_class::PriorityClass;
+
+    const METHOD: http::Method = http::Method::POST;
+
+    const OPERATION_ID: Option<&'static str> = Some("createSchedulingV1PriorityClass");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok("/apis/scheduling.k8s.io/v1/priorityclasses".into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::CONTENT_TYPE.as_str(), "application/json")
+        .json(&self.inner.body)
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("dryRun", self.inner.param_dry_run.as_ref().map(std::string::ToString::to_string)),
+            ("fieldManager", self.inner.param_field_manager.as_ref().map(std::string::ToString::to_string)),
+            ("pretty", self.inner.param_pretty.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Content-Type")
+            || name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<PriorityClassPostBuilder<crate::generics::ValueExists>> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: PriorityClassPostBuilder<crate::generics::ValueExists>) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl PriorityClassPostBuilder<crate::generics::ValueExists> {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = PriorityClassPostBuilder {
+            inner: PriorityClassPostBuilderContainer {
+            body: crate::cli::read_from_input(matches)?,
+            param_dry_run: matches.and_then(|m| {
+                    m.value_of("dry-run").map(|_| {
+                        value_t!(m, "dry-run", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_field_manager: matches.and_then(|m| {
+                    m.value_of("field-manager").map(|_| {
+                        value_t!(m, "field-manager", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_pretty: matches.and_then(|m| {
+                    m.value_of("pretty").map(|_| {
+                        value_t!(m, "pretty", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            },
+            _value: core::marker::PhantomData,
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder created by [`PriorityClass::read_scheduling_v1_priority_class`](./struct.PriorityClass.html#method.read_scheduling_v1_priority_class) method for a `GET` operation associated with `PriorityClass`.
+#[repr(transparent)]
+#[derive(Debug, Clone)]
+pub struct PriorityClassGetBuilder1<Name> {
+    inner: PriorityClassGetBuilder1Container,
+    _param_name: core::marker::PhantomData<Name>,
+}
+
+#[derive(Debug, Default, Clone)]
+struct PriorityClassGetBuilder1Container {
+    param_exact: Option<bool>,
+    param_export: Option<bool>,
+    param_name: Option<String>,
+    param_pretty: Option<String>,
+}
+
+impl<Name> PriorityClassGetBuilder1<Name> {
+    /// Should the export be exact.  Exact export maintains cluster-specific fields like 'Namespace'. Deprecated. Planned for removal in 1.18.
+    #[inline]
+    pub fn exact(mut self, value: impl Into<bool>) -> Self {
+        self.inner.param_exact = Some(value.into());
+        self
+    }
+
+    /// Sets [`exact`](#method.exact) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_exact(mut self, value: Option<impl Into<bool>>) -> Self {
+        self.inner.param_exact = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`exact`](#method.exact), but takes a reference.
+    #[inline]
+    pub fn exact_ref(self, value: &bool) -> Self {
+        self.exact(*value)
+    }
+
+    /// Should this value be exported.  Export strips fields that a user can not specify. Deprecated. Planned for removal in 1.18.
+    #[inline]
+    pub fn export(mut self, value: impl Into<bool>) -> Self {
+        self.inner.param_export = Some(value.into());
+        self
+    }
+
+    /// Sets [`export`](#method.export) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_export(mut self, value: Option<impl Into<bool>>) -> Self {
+        self.inner.param_export = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`export`](#method.export), but takes a reference.
+    #[inline]
+    pub fn export_ref(self, value: &bool) -> Self {
+        self.export(*value)
+    }
+
+    /// name of the PriorityClass
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> PriorityClassGetBuilder1<crate::generics::NameExists> {
+        self.inner.param_name = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// If 'true', then the output is pretty printed.
+    #[inline]
+    pub fn pretty(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_pretty = Some(value.into());
+        self
+    }
+
+    /// Sets [`pretty`](#method.pretty) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_pretty(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_pretty = value.map(|value| value.into());
+        self
+    }
+}
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for PriorityClassGetBuilder1<crate::generics::NameExists> {
+    type Output = PriorityClass;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    const OPERATION_ID: Option<&'static str> = Some("readSchedulingV1PriorityClass");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok(format!("/apis/scheduling.k8s.io/v1/priorityclasses/{name}", name=crate::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("exact", self.inner.param_exact.as_ref().map(std::string::ToString::to_string)),
+            ("export", self.inner.param_export.as_ref().map(std::string::ToString::to_string)),
+            ("pretty", self.inner.param_pretty.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<PriorityClassGetBuilder1<crate::generics::NameExists>> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: PriorityClassGetBuilder1<crate::generics::NameExists>) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl PriorityClassGetBuilder1<crate::generics::NameExists> {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = PriorityClassGetBuilder1 {
+            inner: PriorityClassGetBuilder1Container {
+            param_exact: matches.and_then(|m| {
+                    m.value_of("exact").map(|_| {
+                        value_t!(m, "exact", bool).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_export: matches.and_then(|m| {
+                    m.value_of("export").map(|_| {
+                        value_t!(m, "export", bool).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_name: matches.and_then(|m| {
+                    m.value_of("name").map(|_| {
+                        value_t!(m, "name", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_pretty: matches.and_then(|m| {
+                    m.value_of("pretty").map(|_| {
+                        value_t!(m, "pretty", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            },
+            _param_name: core::marker::PhantomData,
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder created by [`PriorityClass::replace_scheduling_v1_priority_class`](./struct.PriorityClass.html#method.replace_scheduling_v1_priority_class) method for a `PUT` operation associated with `PriorityClass`.
+#[repr(transparent)]
+#[derive(Debug, Clone)]
+pub struct PriorityClassPutBuilder1<Name, Value> {
+    inner: PriorityClassPutBuilder1Container,
+    _param_name: core::marker::PhantomData<Name>,
+    _value: core::marker::PhantomData<Value>,
+}
+
+#[derive(Debug, Default, Clone)]
+struct PriorityClassPutBuilder1Container {
+    body: self::PriorityClass,
+    param_dry_run: Option<String>,
+    param_field_manager: Option<String>,
+    param_name: Option<String>,
+    param_pretty: Option<String>,
+}
+
+impl<Name, Value> PriorityClassPutBuilder1<Name, Value> {
+    /// When present, indicates that modifications should not be persisted. An invalid or unrecognized dryRun directive will result in an error response and no further processing of the request. Valid values are: - All: all dry run stages will be processed
+    #[inline]
+    pub fn dry_run(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_dry_run = Some(value.into());
+        self
+    }
+
+    /// Sets [`dry_run`](#method.dry_run) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_dry_run(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_dry_run = value.map(|value| value.into());
+        self
+    }
+
+    /// fieldManager is a name associated with the actor or entity that is making these changes. The value must be less than or 128 characters long, and only contain printable characters, as defined by https://golang.org/pkg/unicode/#IsPrint.
+    #[inline]
+    pub fn field_manager(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_field_manager = Some(value.into());
+        self
+    }
+
+    /// Sets [`field_manager`](#method.field_manager) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_field_manager(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_field_manager = value.map(|value| value.into());
+        self
+    }
+
+    /// name of the PriorityClass
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> PriorityClassPutBuilder1<crate::generics::NameExists, Value> {
+        self.inner.param_name = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// If 'true', then the output is pretty printed.
+    #[inline]
+    pub fn pretty(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_pretty = Some(value.into());
+        self
+    }
+
+    /// Sets [`pretty`](#method.pretty) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_pretty(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_pretty = value.map(|value| value.into());
+        self
+    }
+
+    /// APIVersion defines the versioned schema of this representation of an object. Servers should convert recognized schemas to the latest internal value, and may reject unrecognized values. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#resources
+    #[inline]
+    pub fn api_version(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.api_version = Some(value.into());
+        self
+    }
+
+    /// Sets [`api_version`](#method.api_version) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_api_version(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.api_version = value.map(|value| value.into());
+        self
+    }
+
+    /// description is an arbitrary string that usually provides guidelines on when this priority class should be used.
+    #[inline]
+    pub fn description(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.description = Some(value.into());
+        self
+    }
+
+    /// Sets [`description`](#method.description) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_description(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.description = value.map(|value| value.into());
+        self
+    }
+
+    /// globalDefault specifies whether this PriorityClass should be considered as the default priority for pods that do not have any priority class. Only one PriorityClass can be marked as `globalDefault`. However, if more than one PriorityClasses exists with their `globalDefault` field set to true, the smallest value of such global default PriorityClasses will be used as the default priority.
+    #[inline]
+    pub fn global_default(mut self, value: impl Into<bool>) -> Self {
+        self.inner.body.global_default = Some(value.into());
+        self
+    }
+
+    /// Sets [`global_default`](#method.global_default) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_global_default(mut self, value: Option<impl Into<bool>>) -> Self {
+        self.inner.body.global_default = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`global_default`](#method.global_default), but takes a reference.
+    #[inline]
+    pub fn global_default_ref(self, value: &bool) -> Self {
+        self.global_default(*value)
+    }
+
+    /// Kind is a string value representing the REST resource this object represents. Servers may infer this from the endpoint the client submits requests to. Cannot be updated. In CamelCase. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#types-kinds
+    #[inline]
+    pub fn kind(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.kind = Some(value.into());
+        self
+    }
+
+    /// Sets [`kind`](#method.kind) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_kind(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.kind = value.map(|value| value.into());
+        self
+    }
+
+    /// Standard object's metadata. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#metadata
+    #[inline]
+    pub fn metadata(mut self, value: crate::io::k8s::apimachinery::pkg::apis::meta::v1::object_meta::ObjectMeta) -> Self {
+        self.inner.body.metadata = Some(value.into());
+        self
+    }
+
+    /// Sets [`metadata`](#method.metadata) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_metadata(mut self, value: Option<crate::io::k8s::apimachinery::pkg::apis::meta::v1::object_meta::ObjectMeta>) -> Self {
+        self.inner.body.metadata = value.map(|value| value.into());
+        self
+    }
+
+    /// The value of this priority class. This is the actual priority that pods receive when they have the name of this class in their pod spec.
+    #[inline]
+    pub fn value(mut self, value: impl Into<i32>) -> PriorityClassPutBuilder1<Name, crate::generics::ValueExists> {
+        self.inner.body.value = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Same as [`value`](#method.value), but takes a reference.
+    #[inline]
+    pub fn value_ref(self, value: &i32) -> PriorityClassPutBuilder1<Name, crate::generics::ValueExists> {
+        self.value(*value)
+    }
+}
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for PriorityClassPutBuilder1<crate::generics::NameExists, crate::generics::ValueExists> {
+    type Output = crate::io::k8s::api::scheduling::v1::priority_class::PriorityClass;
+
+    const METHOD: http::Method = http::Method::PUT;
+
+    const OPERATION_ID: Option<&'static str> = Some("replaceSchedulingV1PriorityClass");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok(format!("/apis/scheduling.k8s.io/v1/priorityclasses/{name}", name=crate::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::CONTENT_TYPE.as_str(), "application/json")
+        .json(&self.inner.body)
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("dryRun", self.inner.param_dry_run.as_ref().map(std::string::ToString::to_string)),
+            ("fieldManager", self.inner.param_field_manager.as_ref().map(std::string::ToString::to_string)),
+            ("pretty", self.inner.param_pretty.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Content-Type")
+            || name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<PriorityClassPutBuilder1<crate::generics::NameExists, crate::generics::ValueExists>> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: PriorityClassPutBuilder1<crate::generics::NameExists, crate::generics::ValueExists>) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl PriorityClassPutBuilder1<crate::generics::NameExists, crate::generics::ValueExists> {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = PriorityClassPutBuilder1 {
+            inner: PriorityClassPutBuilder1Container {
+            body: crate::cli::read_from_input(matches)?,
+            param_dry_run: matches.and_then(|m| {
+                    m.value_of("dry-run").map(|_| {
+                        value_t!(m, "dry-run", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_field_manager: matches.and_then(|m| {
+                    m.value_of("field-manager").map(|_| {
+                        value_t!(m, "field-manager", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_name: matches.and_then(|m| {
+                    m.value_of("name").map(|_| {
+                        value_t!(m, "name", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_pretty: matches.and_then(|m| {
+                    m.value_of("pretty").map(|_| {
+                        value_t!(m, "pretty", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            },
+            _param_name: core::marker::PhantomData,
+            _value: core::marker::PhantomData,
+        };
+
+        Ok(thing)
+    }
+}
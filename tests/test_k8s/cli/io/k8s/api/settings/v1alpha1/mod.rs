@@ -0,0 +1,12 @@
+
+pub mod pod_preset {
+    include!("./pod_preset.rs");
+}
+
+pub mod pod_preset_list {
+    include!("./pod_preset_list.rs");
+}
+
+pub mod pod_preset_spec {
+    include!("./pod_preset_spec.rs");
+}
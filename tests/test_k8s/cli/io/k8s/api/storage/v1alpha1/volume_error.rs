@@ -0,0 +1,74 @@
+
+/// VolumeError captures an error encountered during a volume operation.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct VolumeError {
+    /// String detailing the error encountered during Attach or Detach operation. This string maybe logged, so it should not contain sensitive information.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    /// Time the error was encountered.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time: Option<String>,
+}
+
+impl VolumeError {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> VolumeErrorBuilder {
+        VolumeErrorBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> VolumeErrorBuilder
+    where
+        Self: Clone,
+    {
+        VolumeErrorBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<VolumeError> for VolumeErrorBuilder {
+    fn into(self) -> VolumeError {
+        self.body
+    }
+}
+
+/// Builder for [`VolumeError`](./struct.VolumeError.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct VolumeErrorBuilder {
+    body: self::VolumeError,
+}
+
+impl VolumeErrorBuilder {
+    /// String detailing the error encountered during Attach or Detach operation. This string maybe logged, so it should not contain sensitive information.
+    #[inline]
+    pub fn message(mut self, value: impl Into<String>) -> Self {
+        self.body.message = Some(value.into());
+        self
+    }
+
+    /// Sets [`message`](#method.message) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_message(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.message = value.map(|value| value.into());
+        self
+    }
+
+    /// Time the error was encountered.
+    #[inline]
+    pub fn time(mut self, value: impl Into<String>) -> Self {
+        self.body.time = Some(value.into());
+        self
+    }
+
+    /// Sets [`time`](#method.time) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_time(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.time = value.map(|value| value.into());
+        self
+    }
+}
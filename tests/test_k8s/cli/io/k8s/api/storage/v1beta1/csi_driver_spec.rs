@@ -0,0 +1,86 @@
+
+/// CSIDriverSpec is the specification of a CSIDriver.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct CsiDriverSpec {
+    /// attachRequired indicates this CSI volume driver requires an attach operation (because it implements the CSI ControllerPublishVolume() method), and that the Kubernetes attach detach controller should call the attach volume interface which checks the volumeattachment status and waits until the volume is attached before proceeding to mounting. The CSI external-attacher coordinates with CSI volume driver and updates the volumeattachment status when the attach operation is complete. If the CSIDriverRegistry feature gate is enabled and the value is specified to false, the attach operation will be skipped. Otherwise the attach operation will be called.
+    #[serde(rename = "attachRequired", skip_serializing_if = "Option::is_none")]
+    pub attach_required: Option<bool>,
+    /// If set to true, podInfoOnMount indicates this CSI volume driver requires additional pod information (like podName, podUID, etc.) during mount operations. If set to false, pod information will not be passed on mount. Default is false. The CSI driver specifies podInfoOnMount as part of driver deployment. If true, Kubelet will pass pod information as VolumeContext in the CSI NodePublishVolume() calls. The CSI driver is responsible for parsing and validating the information passed in as VolumeContext. The following VolumeConext will be passed if podInfoOnMount is set to true. This list might grow, but the prefix will be used. "csi.storage.k8s.io/pod.name": pod.Name "csi.storage.k8s.io/pod.namespace": pod.Namespace "csi.storage.k8s.io/pod.uid": string(pod.UID)
+    #[serde(rename = "podInfoOnMount", skip_serializing_if = "Option::is_none")]
+    pub pod_info_on_mount: Option<bool>,
+}
+
+impl CsiDriverSpec {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> CsiDriverSpecBuilder {
+        CsiDriverSpecBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> CsiDriverSpecBuilder
+    where
+        Self: Clone,
+    {
+        CsiDriverSpecBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<CsiDriverSpec> for CsiDriverSpecBuilder {
+    fn into(self) -> CsiDriverSpec {
+        self.body
+    }
+}
+
+/// Builder for [`CsiDriverSpec`](./struct.CsiDriverSpec.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct CsiDriverSpecBuilder {
+    body: self::CsiDriverSpec,
+}
+
+impl CsiDriverSpecBuilder {
+    /// attachRequired indicates this CSI volume driver requires an attach operation (because it implements the CSI ControllerPublishVolume() method), and that the Kubernetes attach detach controller should call the attach volume interface which checks the volumeattachment status and waits until the volume is attached before proceeding to mounting. The CSI external-attacher coordinates with CSI volume driver and updates the volumeattachment status when the attach operation is complete. If the CSIDriverRegistry feature gate is enabled and the value is specified to false, the attach operation will be skipped. Otherwise the attach operation will be called.
+    #[inline]
+    pub fn attach_required(mut self, value: impl Into<bool>) -> Self {
+        self.body.attach_required = Some(value.into());
+        self
+    }
+
+    /// Sets [`attach_required`](#method.attach_required) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_attach_required(mut self, value: Option<impl Into<bool>>) -> Self {
+        self.body.attach_required = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`attach_required`](#method.attach_required), but takes a reference.
+    #[inline]
+    pub fn attach_required_ref(self, value: &bool) -> Self {
+        self.attach_required(*value)
+    }
+
+    /// If set to true, podInfoOnMount indicates this CSI volume driver requires additional pod information (like podName, podUID, etc.) during mount operations. If set to false, pod information will not be passed on mount. Default is false. The CSI driver specifies podInfoOnMount as part of driver deployment. If true, Kubelet will pass pod information as VolumeContext in the CSI NodePublishVolume() calls. The CSI driver is responsible for parsing and validating the information passed in as VolumeContext. The following VolumeConext will be passed if podInfoOnMount is set to true. This list might grow, but the prefix will be used. "csi.storage.k8s.io/pod.name": pod.Name "csi.storage.k8s.io/pod.namespace": pod.Namespace "csi.storage.k8s.io/pod.uid": string(pod.UID)
+    #[inline]
+    pub fn pod_info_on_mount(mut self, value: impl Into<bool>) -> Self {
+        self.body.pod_info_on_mount = Some(value.into());
+        self
+    }
+
+    /// Sets [`pod_info_on_mount`](#method.pod_info_on_mount) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_pod_info_on_mount(mut self, value: Option<impl Into<bool>>) -> Self {
+        self.body.pod_info_on_mount = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`pod_info_on_mount`](#method.pod_info_on_mount), but takes a reference.
+    #[inline]
+    pub fn pod_info_on_mount_ref(self, value: &bool) -> Self {
+        self.pod_info_on_mount(*value)
+    }
+}
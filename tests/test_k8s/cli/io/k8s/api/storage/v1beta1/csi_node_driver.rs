@@ -0,0 +1,89 @@
+
+/// CSINodeDriver holds information about the specification of one CSI driver installed on a node
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct CsiNodeDriver {
+    /// This is the name of the CSI driver that this object refers to. This MUST be the same name returned by the CSI GetPluginName() call for that driver.
+    pub name: String,
+    /// nodeID of the node from the driver point of view. This field enables Kubernetes to communicate with storage systems that do not share the same nomenclature for nodes. For example, Kubernetes may refer to a given node as "node1", but the storage system may refer to the same node as "nodeA". When Kubernetes issues a command to the storage system to attach a volume to a specific node, it can use this field to refer to the node name using the ID that the storage system will understand, e.g. "nodeA" instead of "node1". This field is required.
+    #[serde(rename = "nodeID")]
+    pub node_id: String,
+    /// topologyKeys is the list of keys supported by the driver. When a driver is initialized on a cluster, it provides a set of topology keys that it understands (e.g. "company.com/zone", "company.com/region"). When a driver is initialized on a node, it provides the same topology keys along with values. Kubelet will expose these topology keys as labels on its own node object. When Kubernetes does topology aware provisioning, it can use this list to determine which labels it should retrieve from the node object and pass back to the driver. It is possible for different nodes to use different topology keys. This can be empty if driver does not support topology.
+    #[serde(rename = "topologyKeys", skip_serializing_if = "Option::is_none")]
+    pub topology_keys: Option<Vec<String>>,
+}
+
+impl CsiNodeDriver {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> CsiNodeDriverBuilder<crate::generics::MissingName, crate::generics::MissingNodeId> {
+        CsiNodeDriverBuilder {
+            body: Default::default(),
+            _name: core::marker::PhantomData,
+            _node_id: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> CsiNodeDriverBuilder<crate::generics::NameExists, crate::generics::NodeIdExists>
+    where
+        Self: Clone,
+    {
+        CsiNodeDriverBuilder {
+            body: self.clone(),
+            _name: core::marker::PhantomData,
+            _node_id: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<CsiNodeDriver> for CsiNodeDriverBuilder<crate::generics::NameExists, crate::generics::NodeIdExists> {
+    fn into(self) -> CsiNodeDriver {
+        self.body
+    }
+}
+
+/// Builder for [`CsiNodeDriver`](./struct.CsiNodeDriver.html) object.
+#[derive(Debug, Clone)]
+pub struct CsiNodeDriverBuilder<Name, NodeId> {
+    body: self::CsiNodeDriver,
+    _name: core::marker::PhantomData<Name>,
+    _node_id: core::marker::PhantomData<NodeId>,
+}
+
+impl<Name, NodeId> CsiNodeDriverBuilder<Name, NodeId> {
+    /// This is the name of the CSI driver that this object refers to. This MUST be the same name returned by the CSI GetPluginName() call for that driver.
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> CsiNodeDriverBuilder<crate::generics::NameExists, NodeId> {
+        self.body.name = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// nodeID of the node from the driver point of view. This field enables Kubernetes to communicate with storage systems that do not share the same nomenclature for nodes. For example, Kubernetes may refer to a given node as "node1", but the storage system may refer to the same node as "nodeA". When Kubernetes issues a command to the storage system to attach a volume to a specific node, it can use this field to refer to the node name using the ID that the storage system will understand, e.g. "nodeA" instead of "node1". This field is required.
+    #[inline]
+    pub fn node_id(mut self, value: impl Into<String>) -> CsiNodeDriverBuilder<Name, crate::generics::NodeIdExists> {
+        self.body.node_id = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// topologyKeys is the list of keys supported by the driver. When a driver is initialized on a cluster, it provides a set of topology keys that it understands (e.g. "company.com/zone", "company.com/region"). When a driver is initialized on a node, it provides the same topology keys along with values. Kubelet will expose these topology keys as labels on its own node object. When Kubernetes does topology aware provisioning, it can use this list to determine which labels it should retrieve from the node object and pass back to the driver. It is possible for different nodes to use different topology keys. This can be empty if driver does not support topology.
+    #[inline]
+    pub fn topology_keys(mut self, value: impl Iterator<Item = impl Into<String>>) -> Self {
+        self.body.topology_keys = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`topology_keys`](#method.topology_keys) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_topology_keys(mut self, value: Option<impl Iterator<Item = impl Into<String>>>) -> Self {
+        self.body.topology_keys = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`topology_keys`](#method.topology_keys), but takes the values as a `Vec`.
+    #[inline]
+    pub fn topology_keys_vec(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.body.topology_keys = Some(value.into());
+        self
+    }
+}
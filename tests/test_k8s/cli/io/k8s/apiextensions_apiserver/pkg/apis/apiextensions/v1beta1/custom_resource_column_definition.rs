@@ -0,0 +1,135 @@
+
+/// CustomResourceColumnDefinition specifies a column for server side printing.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct CustomResourceColumnDefinition {
+    /// JSONPath is a simple JSON path, i.e. with array notation.
+    #[serde(rename = "JSONPath")]
+    pub json_path: String,
+    /// description is a human readable description of this column.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// format is an optional OpenAPI type definition for this column. The 'name' format is applied to the primary identifier column to assist in clients identifying column is the resource name. See https://github.com/OAI/OpenAPI-Specification/blob/master/versions/2.0.md#data-types for more.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub format: Option<String>,
+    /// name is a human readable name for the column.
+    pub name: String,
+    /// priority is an integer defining the relative importance of this column compared to others. Lower numbers are considered higher priority. Columns that may be omitted in limited space scenarios should be given a higher priority.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub priority: Option<i32>,
+    /// type is an OpenAPI type definition for this column. See https://github.com/OAI/OpenAPI-Specification/blob/master/versions/2.0.md#data-types for more.
+    #[serde(rename = "type")]
+    pub type_: String,
+}
+
+impl CustomResourceColumnDefinition {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> CustomResourceColumnDefinitionBuilder<crate::generics::MissingJsonPath, crate::generics::MissingName, crate::generics::MissingType> {
+        CustomResourceColumnDefinitionBuilder {
+            body: Default::default(),
+            _json_path: core::marker::PhantomData,
+            _name: core::marker::PhantomData,
+            _type: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> CustomResourceColumnDefinitionBuilder<crate::generics::JsonPathExists, crate::generics::NameExists, crate::generics::TypeExists>
+    where
+        Self: Clone,
+    {
+        CustomResourceColumnDefinitionBuilder {
+            body: self.clone(),
+            _json_path: core::marker::PhantomData,
+            _name: core::marker::PhantomData,
+            _type: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<CustomResourceColumnDefinition> for CustomResourceColumnDefinitionBuilder<crate::generics::JsonPathExists, crate::generics::NameExists, crate::generics::TypeExists> {
+    fn into(self) -> CustomResourceColumnDefinition {
+        self.body
+    }
+}
+
+/// Builder for [`CustomResourceColumnDefinition`](./struct.CustomResourceColumnDefinition.html) object.
+#[derive(Debug, Clone)]
+pub struct CustomResourceColumnDefinitionBuilder<JsonPath, Name, Type> {
+    body: self::CustomResourceColumnDefinition,
+    _json_path: core::marker::PhantomData<JsonPath>,
+    _name: core::marker::PhantomData<Name>,
+    _type: core::marker::PhantomData<Type>,
+}
+
+impl<JsonPath, Name, Type> CustomResourceColumnDefinitionBuilder<JsonPath, Name, Type> {
+    /// JSONPath is a simple JSON path, i.e. with array notation.
+    #[inline]
+    pub fn json_path(mut self, value: impl Into<String>) -> CustomResourceColumnDefinitionBuilder<crate::generics::JsonPathExists, Name, Type> {
+        self.body.json_path = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// description is a human readable description of this column.
+    #[inline]
+    pub fn description(mut self, value: impl Into<String>) -> Self {
+        self.body.description = Some(value.into());
+        self
+    }
+
+    /// Sets [`description`](#method.description) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_description(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.description = value.map(|value| value.into());
+        self
+    }
+
+    /// format is an optional OpenAPI type definition for this column. The 'name' format is applied to the primary identifier column to assist in clients identifying column is the resource name. See https://github.com/OAI/OpenAPI-Specification/blob/master/versions/2.0.md#data-types for more.
+    #[inline]
+    pub fn format(mut self, value: impl Into<String>) -> Self {
+        self.body.format = Some(value.into());
+        self
+    }
+
+    /// Sets [`format`](#method.format) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_format(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.format = value.map(|value| value.into());
+        self
+    }
+
+    /// name is a human readable name for the column.
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> CustomResourceColumnDefinitionBuilder<JsonPath, crate::generics::NameExists, Type> {
+        self.body.name = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// priority is an integer defining the relative importance of this column compared to others. Lower numbers are considered higher priority. Columns that may be omitted in limited space scenarios should be given a higher priority.
+    #[inline]
+    pub fn priority(mut self, value: impl Into<i32>) -> Self {
+        self.body.priority = Some(value.into());
+        self
+    }
+
+    /// Sets [`priority`](#method.priority) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_priority(mut self, value: Option<impl Into<i32>>) -> Self {
+        self.body.priority = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`priority`](#method.priority), but takes a reference.
+    #[inline]
+    pub fn priority_ref(self, value: &i32) -> Self {
+        self.priority(*value)
+    }
+
+    /// type is an OpenAPI type definition for this column. See https://github.com/OAI/OpenAPI-Specification/blob/master/versions/2.0.md#data-types for more.
+    #[inline]
+    pub fn type_(mut self, value: impl Into<String>) -> CustomResourceColumnDefinitionBuilder<JsonPath, Name, crate::generics::TypeExists> {
+        self.body.type_ = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+}
@@ -0,0 +1,116 @@
+
+/// CustomResourceDefinitionCondition contains details for the current condition of this pod.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct CustomResourceDefinitionCondition {
+    /// Last time the condition transitioned from one status to another.
+    #[serde(rename = "lastTransitionTime", skip_serializing_if = "Option::is_none")]
+    pub last_transition_time: Option<String>,
+    /// Human-readable message indicating details about last transition.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    /// Unique, one-word, CamelCase reason for the condition's last transition.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+    /// Status is the status of the condition. Can be True, False, Unknown.
+    pub status: String,
+    /// Type is the type of the condition. Types include Established, NamesAccepted and Terminating.
+    #[serde(rename = "type")]
+    pub type_: String,
+}
+
+impl CustomResourceDefinitionCondition {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> CustomResourceDefinitionConditionBuilder<crate::generics::MissingStatus, crate::generics::MissingType> {
+        CustomResourceDefinitionConditionBuilder {
+            body: Default::default(),
+            _status: core::marker::PhantomData,
+            _type: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> CustomResourceDefinitionConditionBuilder<crate::generics::StatusExists, crate::generics::TypeExists>
+    where
+        Self: Clone,
+    {
+        CustomResourceDefinitionConditionBuilder {
+            body: self.clone(),
+            _status: core::marker::PhantomData,
+            _type: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<CustomResourceDefinitionCondition> for CustomResourceDefinitionConditionBuilder<crate::generics::StatusExists, crate::generics::TypeExists> {
+    fn into(self) -> CustomResourceDefinitionCondition {
+        self.body
+    }
+}
+
+/// Builder for [`CustomResourceDefinitionCondition`](./struct.CustomResourceDefinitionCondition.html) object.
+#[derive(Debug, Clone)]
+pub struct CustomResourceDefinitionConditionBuilder<Status, Type> {
+    body: self::CustomResourceDefinitionCondition,
+    _status: core::marker::PhantomData<Status>,
+    _type: core::marker::PhantomData<Type>,
+}
+
+impl<Status, Type> CustomResourceDefinitionConditionBuilder<Status, Type> {
+    /// Last time the condition transitioned from one status to another.
+    #[inline]
+    pub fn last_transition_time(mut self, value: impl Into<String>) -> Self {
+        self.body.last_transition_time = Some(value.into());
+        self
+    }
+
+    /// Sets [`last_transition_time`](#method.last_transition_time) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_last_transition_time(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.last_transition_time = value.map(|value| value.into());
+        self
+    }
+
+    /// Human-readable message indicating details about last transition.
+    #[inline]
+    pub fn message(mut self, value: impl Into<String>) -> Self {
+        self.body.message = Some(value.into());
+        self
+    }
+
+    /// Sets [`message`](#method.message) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_message(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.message = value.map(|value| value.into());
+        self
+    }
+
+    /// Unique, one-word, CamelCase reason for the condition's last transition.
+    #[inline]
+    pub fn reason(mut self, value: impl Into<String>) -> Self {
+        self.body.reason = Some(value.into());
+        self
+    }
+
+    /// Sets [`reason`](#method.reason) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_reason(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.reason = value.map(|value| value.into());
+        self
+    }
+
+    /// Status is the status of the condition. Can be True, False, Unknown.
+    #[inline]
+    pub fn status(mut self, value: impl Into<String>) -> CustomResourceDefinitionConditionBuilder<crate::generics::StatusExists, Type> {
+        self.body.status = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Type is the type of the condition. Types include Established, NamesAccepted and Terminating.
+    #[inline]
+    pub fn type_(mut self, value: impl Into<String>) -> CustomResourceDefinitionConditionBuilder<Status, crate::generics::TypeExists> {
+        self.body.type_ = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+}
@@ -0,0 +1,192 @@
+
+/// CustomResourceDefinitionSpec describes how a user wants their resource to appear
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct CustomResourceDefinitionSpec<Any> {
+    /// AdditionalPrinterColumns are additional columns shown e.g. in kubectl next to the name. Defaults to a created-at column. Optional, the global columns for all versions. Top-level and per-version columns are mutually exclusive.
+    #[serde(rename = "additionalPrinterColumns", skip_serializing_if = "Option::is_none")]
+    pub additional_printer_columns: Option<Vec<crate::io::k8s::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::custom_resource_column_definition::CustomResourceColumnDefinition>>,
+    /// `conversion` defines conversion settings for the CRD.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub conversion: Option<crate::io::k8s::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::custom_resource_conversion::CustomResourceConversion>,
+    /// Group is the group this resource belongs in
+    pub group: String,
+    /// Names are the names used to describe this custom resource
+    pub names: crate::io::k8s::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::custom_resource_definition_names::CustomResourceDefinitionNames,
+    /// Scope indicates whether this resource is cluster or namespace scoped.  Default is namespaced
+    pub scope: String,
+    /// Subresources describes the subresources for CustomResource Optional, the global subresources for all versions. Top-level and per-version subresources are mutually exclusive.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subresources: Option<crate::io::k8s::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::custom_resource_subresources::CustomResourceSubresources>,
+    /// Validation describes the validation methods for CustomResources Optional, the global validation schema for all versions. Top-level and per-version schemas are mutually exclusive.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub validation: Option<crate::io::k8s::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::custom_resource_validation::CustomResourceValidation<Any>>,
+    /// Version is the version this resource belongs in Should be always first item in Versions field if provided. Optional, but at least one of Version or Versions must be set. Deprecated: Please use `Versions`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+    /// Versions is the list of all supported versions for this resource. If Version field is provided, this field is optional. Validation: All versions must use the same validation schema for now. i.e., top level Validation field is applied to all of these versions. Order: The version name will be used to compute the order. If the version string is "kube-like", it will sort above non "kube-like" version strings, which are ordered lexicographically. "Kube-like" versions start with a "v", then are followed by a number (the major version), then optionally the string "alpha" or "beta" and another number (the minor version). These are sorted first by GA > beta > alpha (where GA is a version with no suffix such as beta or alpha), and then by comparing major version, then minor version. An example sorted list of versions: v10, v2, v1, v11beta2, v10beta3, v3beta1, v12alpha1, v11alpha2, foo1, foo10.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub versions: Option<Vec<crate::io::k8s::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::custom_resource_definition_version::CustomResourceDefinitionVersion<Any>>>,
+}
+
+impl<Any: Default> CustomResourceDefinitionSpec<Any> {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> CustomResourceDefinitionSpecBuilder<crate::generics::MissingGroup, crate::generics::MissingNames, crate::generics::MissingScope, Any> {
+        CustomResourceDefinitionSpecBuilder {
+            body: Default::default(),
+            _group: core::marker::PhantomData,
+            _names: core::marker::PhantomData,
+            _scope: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> CustomResourceDefinitionSpecBuilder<crate::generics::GroupExists, crate::generics::NamesExists, crate::generics::ScopeExists, Any>
+    where
+        Self: Clone,
+    {
+        CustomResourceDefinitionSpecBuilder {
+            body: self.clone(),
+            _group: core::marker::PhantomData,
+            _names: core::marker::PhantomData,
+            _scope: core::marker::PhantomData,
+        }
+    }
+}
+
+impl<Any> Into<CustomResourceDefinitionSpec<Any>> for CustomResourceDefinitionSpecBuilder<crate::generics::GroupExists, crate::generics::NamesExists, crate::generics::ScopeExists, Any> {
+    fn into(self) -> CustomResourceDefinitionSpec<Any> {
+        self.body
+    }
+}
+
+/// Builder for [`CustomResourceDefinitionSpec`](./struct.CustomResourceDefinitionSpec.html) object.
+#[derive(Debug, Clone)]
+pub struct CustomResourceDefinitionSpecBuilder<Group, Names, Scope, Any> {
+    body: self::CustomResourceDefinitionSpec<Any>,
+    _group: core::marker::PhantomData<Group>,
+    _names: core::marker::PhantomData<Names>,
+    _scope: core::marker::PhantomData<Scope>,
+}
+
+impl<Group, Names, Scope, Any> CustomResourceDefinitionSpecBuilder<Group, Names, Scope, Any> {
+    /// AdditionalPrinterColumns are additional columns shown e.g. in kubectl next to the name. Defaults to a created-at column. Optional, the global columns for all versions. Top-level and per-version columns are mutually exclusive.
+    #[inline]
+    pub fn additional_printer_columns(mut self, value: impl Iterator<Item = crate::io::k8s::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::custom_resource_column_definition::CustomResourceColumnDefinitionBuilder<crate::generics::JsonPathExists, crate::generics::NameExists, crate::generics::TypeExists>>) -> Self {
+        self.body.additional_printer_columns = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`additional_printer_columns`](#method.additional_printer_columns) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_additional_printer_columns(mut self, value: Option<impl Iterator<Item = crate::io::k8s::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::custom_resource_column_definition::CustomResourceColumnDefinitionBuilder<crate::generics::JsonPathExists, crate::generics::NameExists, crate::generics::TypeExists>>>) -> Self {
+        self.body.additional_printer_columns = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`additional_printer_columns`](#method.additional_printer_columns), but takes the values as a `Vec`.
+    #[inline]
+    pub fn additional_printer_columns_vec(mut self, value: impl Into<Vec<crate::io::k8s::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::custom_resource_column_definition::CustomResourceColumnDefinition>>) -> Self {
+        self.body.additional_printer_columns = Some(value.into());
+        self
+    }
+
+    /// `conversion` defines conversion settings for the CRD.
+    #[inline]
+    pub fn conversion(mut self, value: crate::io::k8s::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::custom_resource_conversion::CustomResourceConversionBuilder<crate::generics::StrategyExists>) -> Self {
+        self.body.conversion = Some(value.into());
+        self
+    }
+
+    /// Sets [`conversion`](#method.conversion) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_conversion(mut self, value: Option<crate::io::k8s::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::custom_resource_conversion::CustomResourceConversionBuilder<crate::generics::StrategyExists>>) -> Self {
+        self.body.conversion = value.map(|value| value.into());
+        self
+    }
+
+    /// Group is the group this resource belongs in
+    #[inline]
+    pub fn group(mut self, value: impl Into<String>) -> CustomResourceDefinitionSpecBuilder<crate::generics::GroupExists, Names, Scope, Any> {
+        self.body.group = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Names are the names used to describe this custom resource
+    #[inline]
+    pub fn names(mut self, value: crate::io::k8s::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::custom_resource_definition_names::CustomResourceDefinitionNamesBuilder<crate::generics::KindExists, crate::generics::PluralExists>) -> CustomResourceDefinitionSpecBuilder<Group, crate::generics::NamesExists, Scope, Any> {
+        self.body.names = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Scope indicates whether this resource is cluster or namespace scoped.  Default is namespaced
+    #[inline]
+    pub fn scope(mut self, value: impl Into<String>) -> CustomResourceDefinitionSpecBuilder<Group, Names, crate::generics::ScopeExists, Any> {
+        self.body.scope = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Subresources describes the subresources for CustomResource Optional, the global subresources for all versions. Top-level and per-version subresources are mutually exclusive.
+    #[inline]
+    pub fn subresources(mut self, value: crate::io::k8s::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::custom_resource_subresources::CustomResourceSubresources) -> Self {
+        self.body.subresources = Some(value.into());
+        self
+    }
+
+    /// Sets [`subresources`](#method.subresources) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_subresources(mut self, value: Option<crate::io::k8s::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::custom_resource_subresources::CustomResourceSubresources>) -> Self {
+        self.body.subresources = value.map(|value| value.into());
+        self
+    }
+
+    /// Validation describes the validation methods for CustomResources Optional, the global validation schema for all versions. Top-level and per-version schemas are mutually exclusive.
+    #[inline]
+    pub fn validation(mut self, value: crate::io::k8s::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::custom_resource_validation::CustomResourceValidation<Any>) -> Self {
+        self.body.validation = Some(value.into());
+        self
+    }
+
+    /// Sets [`validation`](#method.validation) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_validation(mut self, value: Option<crate::io::k8s::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::custom_resource_validation::CustomResourceValidation<Any>>) -> Self {
+        self.body.validation = value.map(|value| value.into());
+        self
+    }
+
+    /// Version is the version this resource belongs in Should be always first item in Versions field if provided. Optional, but at least one of Version or Versions must be set. Deprecated: Please use `Versions`.
+    #[inline]
+    pub fn version(mut self, value: impl Into<String>) -> Self {
+        self.body.version = Some(value.into());
+        self
+    }
+
+    /// Sets [`version`](#method.version) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_version(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.version = value.map(|value| value.into());
+        self
+    }
+
+    /// Versions is the list of all supported versions for this resource. If Version field is provided, this field is optional. Validation: All versions must use the same validation schema for now. i.e., top level Validation field is applied to all of these versions. Order: The version name will be used to compute the order. If the version string is "kube-like", it will sort above non "kube-like" version strings, which are ordered lexicographically. "Kube-like" versions start with a "v", then are followed by a number (the major version), then optionally the string "alpha" or "beta" and another number (the minor version). These are sorted first by GA > beta > alpha (where GA is a version with no suffix such as beta or alpha), and then by comparing major version, then minor version. An example sorted list of versions: v10, v2, v1, v11beta2, v10beta3, v3beta1, v12alpha1, v11alpha2, foo1, foo10.
+    #[inline]
+    pub fn versions(mut self, value: impl Iterator<Item = crate::io::k8s::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::custom_resource_definition_version::CustomResourceDefinitionVersionBuilder<crate::generics::NameExists, crate::generics::ServedExists, crate::generics::StorageExists, Any>>) -> Self {
+        self.body.versions = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`versions`](#method.versions) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_versions(mut self, value: Option<impl Iterator<Item = crate::io::k8s::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::custom_resource_definition_version::CustomResourceDefinitionVersionBuilder<crate::generics::NameExists, crate::generics::ServedExists, crate::generics::StorageExists, Any>>>) -> Self {
+        self.body.versions = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`versions`](#method.versions), but takes the values as a `Vec`.
+    #[inline]
+    pub fn versions_vec(mut self, value: impl Into<Vec<crate::io::k8s::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::custom_resource_definition_version::CustomResourceDefinitionVersion<Any>>>) -> Self {
+        self.body.versions = Some(value.into());
+        self
+    }
+}
@@ -0,0 +1,92 @@
+
+/// CustomResourceDefinitionStatus indicates the state of the CustomResourceDefinition
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct CustomResourceDefinitionStatus {
+    /// AcceptedNames are the names that are actually being used to serve discovery They may be different than the names in spec.
+    #[serde(rename = "acceptedNames")]
+    pub accepted_names: crate::io::k8s::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::custom_resource_definition_names::CustomResourceDefinitionNames,
+    /// Conditions indicate state for particular aspects of a CustomResourceDefinition
+    pub conditions: Vec<crate::io::k8s::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::custom_resource_definition_condition::CustomResourceDefinitionCondition>,
+    /// StoredVersions are all versions of CustomResources that were ever persisted. Tracking these versions allows a migration path for stored versions in etcd. The field is mutable so the migration controller can first finish a migration to another version (i.e. that no old objects are left in the storage), and then remove the rest of the versions from this list. None of the versions in this list can be removed from the spec.Versions field.
+    #[serde(rename = "storedVersions")]
+    pub stored_versions: Vec<String>,
+}
+
+impl CustomResourceDefinitionStatus {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> CustomResourceDefinitionStatusBuilder<crate::generics::MissingAcceptedNames, crate::generics::MissingConditions, crate::generics::MissingStoredVersions> {
+        CustomResourceDefinitionStatusBuilder {
+            body: Default::default(),
+            _accepted_names: core::marker::PhantomData,
+            _conditions: core::marker::PhantomData,
+            _stored_versions: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> CustomResourceDefinitionStatusBuilder<crate::generics::AcceptedNamesExists, crate::generics::ConditionsExists, crate::generics::StoredVersionsExists>
+    where
+        Self: Clone,
+    {
+        CustomResourceDefinitionStatusBuilder {
+            body: self.clone(),
+            _accepted_names: core::marker::PhantomData,
+            _conditions: core::marker::PhantomData,
+            _stored_versions: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<CustomResourceDefinitionStatus> for CustomResourceDefinitionStatusBuilder<crate::generics::AcceptedNamesExists, crate::generics::ConditionsExists, crate::generics::StoredVersionsExists> {
+    fn into(self) -> CustomResourceDefinitionStatus {
+        self.body
+    }
+}
+
+/// Builder for [`CustomResourceDefinitionStatus`](./struct.CustomResourceDefinitionStatus.html) object.
+#[derive(Debug, Clone)]
+pub struct CustomResourceDefinitionStatusBuilder<AcceptedNames, Conditions, StoredVersions> {
+    body: self::CustomResourceDefinitionStatus,
+    _accepted_names: core::marker::PhantomData<AcceptedNames>,
+    _conditions: core::marker::PhantomData<Conditions>,
+    _stored_versions: core::marker::PhantomData<StoredVersions>,
+}
+
+impl<AcceptedNames, Conditions, StoredVersions> CustomResourceDefinitionStatusBuilder<AcceptedNames, Conditions, StoredVersions> {
+    /// AcceptedNames are the names that are actually being used to serve discovery They may be different than the names in spec.
+    #[inline]
+    pub fn accepted_names(mut self, value: crate::io::k8s::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::custom_resource_definition_names::CustomResourceDefinitionNamesBuilder<crate::generics::KindExists, crate::generics::PluralExists>) -> CustomResourceDefinitionStatusBuilder<crate::generics::AcceptedNamesExists, Conditions, StoredVersions> {
+        self.body.accepted_names = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Conditions indicate state for particular aspects of a CustomResourceDefinition
+    #[inline]
+    pub fn conditions(mut self, value: impl Iterator<Item = crate::io::k8s::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::custom_resource_definition_condition::CustomResourceDefinitionConditionBuilder<crate::generics::StatusExists, crate::generics::TypeExists>>) -> CustomResourceDefinitionStatusBuilder<AcceptedNames, crate::generics::ConditionsExists, StoredVersions> {
+        self.body.conditions = value.map(|value| value.into()).collect::<Vec<_>>().into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Same as [`conditions`](#method.conditions), but takes the values as a `Vec`.
+    #[inline]
+    pub fn conditions_vec(mut self, value: impl Into<Vec<crate::io::k8s::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::custom_resource_definition_condition::CustomResourceDefinitionCondition>>) -> CustomResourceDefinitionStatusBuilder<AcceptedNames, crate::generics::ConditionsExists, StoredVersions> {
+        self.body.conditions = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// StoredVersions are all versions of CustomResources that were ever persisted. Tracking these versions allows a migration path for stored versions in etcd. The field is mutable so the migration controller can first finish a migration to another version (i.e. that no old objects are left in the storage), and then remove the rest of the versions from this list. None of the versions in this list can be removed from the spec.Versions field.
+    #[inline]
+    pub fn stored_versions(mut self, value: impl Iterator<Item = impl Into<String>>) -> CustomResourceDefinitionStatusBuilder<AcceptedNames, Conditions, crate::generics::StoredVersionsExists> {
+        self.body.stored_versions = value.map(|value| value.into()).collect::<Vec<_>>().into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Same as [`stored_versions`](#method.stored_versions), but takes the values as a `Vec`.
+    #[inline]
+    pub fn stored_versions_vec(mut self, value: impl Into<Vec<String>>) -> CustomResourceDefinitionStatusBuilder<AcceptedNames, Conditions, crate::generics::StoredVersionsExists> {
+        self.body.stored_versions = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+}
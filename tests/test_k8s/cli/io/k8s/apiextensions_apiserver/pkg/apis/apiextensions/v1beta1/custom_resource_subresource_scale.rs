@@ -0,0 +1,83 @@
+
+/// CustomResourceSubresourceScale defines how to serve the scale subresource for CustomResources.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct CustomResourceSubresourceScale {
+    /// LabelSelectorPath defines the JSON path inside of a CustomResource that corresponds to Scale.Status.Selector. Only JSON paths without the array notation are allowed. Must be a JSON Path under .status. Must be set to work with HPA. If there is no value under the given path in the CustomResource, the status label selector value in the /scale subresource will default to the empty string.
+    #[serde(rename = "labelSelectorPath", skip_serializing_if = "Option::is_none")]
+    pub label_selector_path: Option<String>,
+    /// SpecReplicasPath defines the JSON path inside of a CustomResource that corresponds to Scale.Spec.Replicas. Only JSON paths without the array notation are allowed. Must be a JSON Path under .spec. If there is no value under the given path in the CustomResource, the /scale subresource will return an error on GET.
+    #[serde(rename = "specReplicasPath")]
+    pub spec_replicas_path: String,
+    /// StatusReplicasPath defines the JSON path inside of a CustomResource that corresponds to Scale.Status.Replicas. Only JSON paths without the array notation are allowed. Must be a JSON Path under .status. If there is no value under the given path in the CustomResource, the status replica value in the /scale subresource will default to 0.
+    #[serde(rename = "statusReplicasPath")]
+    pub status_replicas_path: String,
+}
+
+impl CustomResourceSubresourceScale {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> CustomResourceSubresourceScaleBuilder<crate::generics::MissingSpecReplicasPath, crate::generics::MissingStatusReplicasPath> {
+        CustomResourceSubresourceScaleBuilder {
+            body: Default::default(),
+            _spec_replicas_path: core::marker::PhantomData,
+            _status_replicas_path: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> CustomResourceSubresourceScaleBuilder<crate::generics::SpecReplicasPathExists, crate::generics::StatusReplicasPathExists>
+    where
+        Self: Clone,
+    {
+        CustomResourceSubresourceScaleBuilder {
+            body: self.clone(),
+            _spec_replicas_path: core::marker::PhantomData,
+            _status_replicas_path: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<CustomResourceSubresourceScale> for CustomResourceSubresourceScaleBuilder<crate::generics::SpecReplicasPathExists, crate::generics::StatusReplicasPathExists> {
+    fn into(self) -> CustomResourceSubresourceScale {
+        self.body
+    }
+}
+
+/// Builder for [`CustomResourceSubresourceScale`](./struct.CustomResourceSubresourceScale.html) object.
+#[derive(Debug, Clone)]
+pub struct CustomResourceSubresourceScaleBuilder<SpecReplicasPath, StatusReplicasPath> {
+    body: self::CustomResourceSubresourceScale,
+    _spec_replicas_path: core::marker::PhantomData<SpecReplicasPath>,
+    _status_replicas_path: core::marker::PhantomData<StatusReplicasPath>,
+}
+
+impl<SpecReplicasPath, StatusReplicasPath> CustomResourceSubresourceScaleBuilder<SpecReplicasPath, StatusReplicasPath> {
+    /// LabelSelectorPath defines the JSON path inside of a CustomResource that corresponds to Scale.Status.Selector. Only JSON paths without the array notation are allowed. Must be a JSON Path under .status. Must be set to work with HPA. If there is no value under the given path in the CustomResource, the status label selector value in the /scale subresource will default to the empty string.
+    #[inline]
+    pub fn label_selector_path(mut self, value: impl Into<String>) -> Self {
+        self.body.label_selector_path = Some(value.into());
+        self
+    }
+
+    /// Sets [`label_selector_path`](#method.label_selector_path) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_label_selector_path(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.label_selector_path = value.map(|value| value.into());
+        self
+    }
+
+    /// SpecReplicasPath defines the JSON path inside of a CustomResource that corresponds to Scale.Spec.Replicas. Only JSON paths without the array notation are allowed. Must be a JSON Path under .spec. If there is no value under the given path in the CustomResource, the /scale subresource will return an error on GET.
+    #[inline]
+    pub fn spec_replicas_path(mut self, value: impl Into<String>) -> CustomResourceSubresourceScaleBuilder<crate::generics::SpecReplicasPathExists, StatusReplicasPath> {
+        self.body.spec_replicas_path = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// StatusReplicasPath defines the JSON path inside of a CustomResource that corresponds to Scale.Status.Replicas. Only JSON paths without the array notation are allowed. Must be a JSON Path under .status. If there is no value under the given path in the CustomResource, the status replica value in the /scale subresource will default to 0.
+    #[inline]
+    pub fn status_replicas_path(mut self, value: impl Into<String>) -> CustomResourceSubresourceScaleBuilder<SpecReplicasPath, crate::generics::StatusReplicasPathExists> {
+        self.body.status_replicas_path = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+}
@@ -0,0 +1,70 @@
+
+/// ExternalDocumentation allows referencing an external resource for extended documentation.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct ExternalDocumentation {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+impl ExternalDocumentation {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> ExternalDocumentationBuilder {
+        ExternalDocumentationBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> ExternalDocumentationBuilder
+    where
+        Self: Clone,
+    {
+        ExternalDocumentationBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<ExternalDocumentation> for ExternalDocumentationBuilder {
+    fn into(self) -> ExternalDocumentation {
+        self.body
+    }
+}
+
+/// Builder for [`ExternalDocumentation`](./struct.ExternalDocumentation.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct ExternalDocumentationBuilder {
+    body: self::ExternalDocumentation,
+}
+
+impl ExternalDocumentationBuilder {
+    #[inline]
+    pub fn description(mut self, value: impl Into<String>) -> Self {
+        self.body.description = Some(value.into());
+        self
+    }
+
+    /// Sets [`description`](#method.description) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_description(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.description = value.map(|value| value.into());
+        self
+    }
+
+    #[inline]
+    pub fn url(mut self, value: impl Into<String>) -> Self {
+        self.body.url = Some(value.into());
+        self
+    }
+
+    /// Sets [`url`](#method.url) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_url(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.url = value.map(|value| value.into());
+        self
+    }
+}
@@ -0,0 +1,795 @@
+
+/// JSONSchemaProps is a JSON-Schema following Specification Draft 4 (http://json-schema.org/).
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct JsonSchemaProps<Any> {
+    #[serde(rename = "$ref", skip_serializing_if = "Option::is_none")]
+    pub ref_: Option<String>,
+    #[serde(rename = "$schema", skip_serializing_if = "Option::is_none")]
+    pub schema: Option<String>,
+    #[serde(rename = "additionalItems", skip_serializing_if = "Option::is_none")]
+    pub additional_items: Option<Any>,
+    #[serde(rename = "additionalProperties", skip_serializing_if = "Option::is_none")]
+    pub additional_properties: Option<Any>,
+    #[serde(rename = "allOf", skip_serializing_if = "Option::is_none")]
+    pub all_of: Option<Vec<crate::io::k8s::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::json_schema_props::JsonSchemaProps<Any>>>,
+    #[serde(rename = "anyOf", skip_serializing_if = "Option::is_none")]
+    pub any_of: Option<Vec<crate::io::k8s::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::json_schema_props::JsonSchemaProps<Any>>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default: Option<Any>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub definitions: Option<std::collections::BTreeMap<String, crate::io::k8s::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::json_schema_props::JsonSchemaProps<Any>>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dependencies: Option<std::collections::BTreeMap<String, Any>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(rename = "enum", skip_serializing_if = "Option::is_none")]
+    pub enum_: Option<Vec<Any>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub example: Option<Any>,
+    #[serde(rename = "exclusiveMaximum", skip_serializing_if = "Option::is_none")]
+    pub exclusive_maximum: Option<bool>,
+    #[serde(rename = "exclusiveMinimum", skip_serializing_if = "Option::is_none")]
+    pub exclusive_minimum: Option<bool>,
+    #[serde(rename = "externalDocs", skip_serializing_if = "Option::is_none")]
+    pub external_docs: Option<crate::io::k8s::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::external_documentation::ExternalDocumentation>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub format: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub items: Option<Any>,
+    #[serde(rename = "maxItems", skip_serializing_if = "Option::is_none")]
+    pub max_items: Option<i64>,
+    #[serde(rename = "maxLength", skip_serializing_if = "Option::is_none")]
+    pub max_length: Option<i64>,
+    #[serde(rename = "maxProperties", skip_serializing_if = "Option::is_none")]
+    pub max_properties: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub maximum: Option<f64>,
+    #[serde(rename = "minItems", skip_serializing_if = "Option::is_none")]
+    pub min_items: Option<i64>,
+    #[serde(rename = "minLength", skip_serializing_if = "Option::is_none")]
+    pub min_length: Option<i64>,
+    #[serde(rename = "minProperties", skip_serializing_if = "Option::is_none")]
+    pub min_properties: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub minimum: Option<f64>,
+    #[serde(rename = "multipleOf", skip_serializing_if = "Option::is_none")]
+    pub multiple_of: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub not: Option<Box<crate::io::k8s::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::json_schema_props::JsonSchemaProps<Any>>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nullable: Option<bool>,
+    #[serde(rename = "oneOf", skip_serializing_if = "Option::is_none")]
+    pub one_of: Option<Vec<crate::io::k8s::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::json_schema_props::JsonSchemaProps<Any>>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pattern: Option<String>,
+    #[serde(rename = "patternProperties", skip_serializing_if = "Option::is_none")]
+    pub pattern_properties: Option<std::collections::BTreeMap<String, crate::io::k8s::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::json_schema_props::JsonSchemaProps<Any>>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub properties: Option<std::collections::BTreeMap<String, crate::io::k8s::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::json_schema_props::JsonSchemaProps<Any>>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub required: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub type_: Option<String>,
+    #[serde(rename = "uniqueItems", skip_serializing_if = "Option::is_none")]
+    pub unique_items: Option<bool>,
+    /// x-kubernetes-embedded-resource defines that the value is an embedded Kubernetes runtime.Object, with TypeMeta and ObjectMeta. The type must be object. It is allowed to further restrict the embedded object. kind, apiVersion and metadata are validated automatically. x-kubernetes-preserve-unknown-fields is allowed to be true, but does not have to be if the object is fully specified (up to kind, apiVersion, metadata).
+    #[serde(rename = "x-kubernetes-embedded-resource", skip_serializing_if = "Option::is_none")]
+    pub x_kubernetes_embedded_resource: Option<bool>,
+    /// x-kubernetes-int-or-string specifies that this value is either an integer or a string. If this is true, an empty type is allowed and type as child of anyOf is permitted if following one of the following patterns:
+    ///
+    /// 1) anyOf:
+    ///    - type: integer
+    ///    - type: string
+    /// 2) allOf:
+    ///    - anyOf:
+    ///      - type: integer
+    ///      - type: string
+    ///    - ... zero or more
+    #[serde(rename = "x-kubernetes-int-or-string", skip_serializing_if = "Option::is_none")]
+    pub x_kubernetes_int_or_string: Option<bool>,
+    /// x-kubernetes-preserve-unknown-fields stops the API server decoding step from pruning fields which are not specified in the validation schema. This affects fields recursively, but switches back to normal pruning behaviour if nested properties or additionalProperties are specified in the schema. This can either be true or undefined. False is forbidden.
+    #[serde(rename = "x-kubernetes-preserve-unknown-fields", skip_serializing_if = "Option::is_none")]
+    pub x_kubernetes_preserve_unknown_fields: Option<bool>,
+}
+
+impl<Any: Default> JsonSchemaProps<Any> {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> JsonSchemaPropsBuilder<Any> {
+        JsonSchemaPropsBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> JsonSchemaPropsBuilder<Any>
+    where
+        Self: Clone,
+    {
+        JsonSchemaPropsBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl<Any> Into<JsonSchemaProps<Any>> for JsonSchemaPropsBuilder<Any> {
+    fn into(self) -> JsonSchemaProps<Any> {
+        self.body
+    }
+}
+
+/// Builder for [`JsonSchemaProps`](./struct.JsonSchemaProps.html) object.
+#[derive(Debug, Clone)]
+pub struct JsonSchemaPropsBuilder<Any> {
+    body: self::JsonSchemaProps<Any>,
+}
+
+impl<Any> JsonSchemaPropsBuilder<Any> {
+    #[inline]
+    pub fn ref_(mut self, value: impl Into<String>) -> Self {
+        self.body.ref_ = Some(value.into());
+        self
+    }
+
+    /// Sets [`ref_`](#method.ref_) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_ref(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.ref_ = value.map(|value| value.into());
+        self
+    }
+
+    #[inline]
+    pub fn schema(mut self, value: impl Into<String>) -> Self {
+        self.body.schema = Some(value.into());
+        self
+    }
+
+    /// Sets [`schema`](#method.schema) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_schema(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.schema = value.map(|value| value.into());
+        self
+    }
+
+    #[inline]
+    pub fn additional_items(mut self, value: impl Into<Any>) -> Self {
+        self.body.additional_items = Some(value.into());
+        self
+    }
+
+    /// Sets [`additional_items`](#method.additional_items) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_additional_items(mut self, value: Option<impl Into<Any>>) -> Self {
+        self.body.additional_items = value.map(|value| value.into());
+        self
+    }
+
+    #[inline]
+    pub fn additional_properties(mut self, value: impl Into<Any>) -> Self {
+        self.body.additional_properties = Some(value.into());
+        self
+    }
+
+    /// Sets [`additional_properties`](#method.additional_properties) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_additional_properties(mut self, value: Option<impl Into<Any>>) -> Self {
+        self.body.additional_properties = value.map(|value| value.into());
+        self
+    }
+
+    #[inline]
+    pub fn all_of(mut self, value: impl Iterator<Item = crate::io::k8s::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::json_schema_props::JsonSchemaProps<Any>>) -> Self {
+        self.body.all_of = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`all_of`](#method.all_of) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_all_of(mut self, value: Option<impl Iterator<Item = crate::io::k8s::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::json_schema_props::JsonSchemaProps<Any>>>) -> Self {
+        self.body.all_of = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`all_of`](#method.all_of), but takes the values as a `Vec`.
+    #[inline]
+    pub fn all_of_vec(mut self, value: impl Into<Vec<crate::io::k8s::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::json_schema_props::JsonSchemaProps<Any>>>) -> Self {
+        self.body.all_of = Some(value.into());
+        self
+    }
+
+    #[inline]
+    pub fn any_of(mut self, value: impl Iterator<Item = crate::io::k8s::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::json_schema_props::JsonSchemaProps<Any>>) -> Self {
+        self.body.any_of = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`any_of`](#method.any_of) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_any_of(mut self, value: Option<impl Iterator<Item = crate::io::k8s::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::json_schema_props::JsonSchemaProps<Any>>>) -> Self {
+        self.body.any_of = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`any_of`](#method.any_of), but takes the values as a `Vec`.
+    #[inline]
+    pub fn any_of_vec(mut self, value: impl Into<Vec<crate::io::k8s::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::json_schema_props::JsonSchemaProps<Any>>>) -> Self {
+        self.body.any_of = Some(value.into());
+        self
+    }
+
+    #[inline]
+    pub fn default(mut self, value: impl Into<Any>) -> Self {
+        self.body.default = Some(value.into());
+        self
+    }
+
+    /// Sets [`default`](#method.default) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_default(mut self, value: Option<impl Into<Any>>) -> Self {
+        self.body.default = value.map(|value| value.into());
+        self
+    }
+
+    #[inline]
+    pub fn definitions(mut self, value: impl Iterator<Item = (String, crate::io::k8s::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::json_schema_props::JsonSchemaProps<Any>)>) -> Self {
+        self.body.definitions = Some(value.map(|(key, value)| (key, value.into())).collect::<std::collections::BTreeMap<_, _>>().into());
+        self
+    }
+
+    /// Sets [`definitions`](#method.definitions) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_definitions(mut self, value: Option<impl Iterator<Item = (String, crate::io::k8s::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::json_schema_props::JsonSchemaProps<Any>)>>) -> Self {
+        self.body.definitions = value.map(|value| value.map(|(key, value)| (key, value.into())).collect::<std::collections::BTreeMap<_, _>>().into());
+        self
+    }
+
+    #[inline]
+    pub fn dependencies(mut self, value: impl Iterator<Item = (String, impl Into<Any>)>) -> Self {
+        self.body.dependencies = Some(value.map(|(key, value)| (key, value.into())).collect::<std::collections::BTreeMap<_, _>>().into());
+        self
+    }
+
+    /// Sets [`dependencies`](#method.dependencies) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_dependencies(mut self, value: Option<impl Iterator<Item = (String, impl Into<Any>)>>) -> Self {
+        self.body.dependencies = value.map(|value| value.map(|(key, value)| (key, value.into())).collect::<std::collections::BTreeMap<_, _>>().into());
+        self
+    }
+
+    #[inline]
+    pub fn description(mut self, value: impl Into<String>) -> Self {
+        self.body.description = Some(value.into());
+        self
+    }
+
+    /// Sets [`description`](#method.description) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_description(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.description = value.map(|value| value.into());
+        self
+    }
+
+    #[inline]
+    pub fn enum_(mut self, value: impl Iterator<Item = impl Into<Any>>) -> Self {
+        self.body.enum_ = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`enum_`](#method.enum_) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_enum(mut self, value: Option<impl Iterator<Item = impl Into<Any>>>) -> Self {
+        self.body.enum_ = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`enum_`](#method.enum_), but takes the values as a `Vec`.
+    #[inline]
+    pub fn enum_vec(mut self, value: impl Into<Vec<Any>>) -> Self {
+        self.body.enum_ = Some(value.into());
+        self
+    }
+
+    #[inline]
+    pub fn example(mut self, value: impl Into<Any>) -> Self {
+        self.body.example = Some(value.into());
+        self
+    }
+
+    /// Sets [`example`](#method.example) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_example(mut self, value: Option<impl Into<Any>>) -> Self {
+        self.body.example = value.map(|value| value.into());
+        self
+    }
+
+    #[inline]
+    pub fn exclusive_maximum(mut self, value: impl Into<bool>) -> Self {
+        self.body.exclusive_maximum = Some(value.into());
+        self
+    }
+
+    /// Sets [`exclusive_maximum`](#method.exclusive_maximum) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_exclusive_maximum(mut self, value: Option<impl Into<bool>>) -> Self {
+        self.body.exclusive_maximum = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`exclusive_maximum`](#method.exclusive_maximum), but takes a reference.
+    #[inline]
+    pub fn exclusive_maximum_ref(self, value: &bool) -> Self {
+        self.exclusive_maximum(*value)
+    }
+
+    #[inline]
+    pub fn exclusive_minimum(mut self, value: impl Into<bool>) -> Self {
+        self.body.exclusive_minimum = Some(value.into());
+        self
+    }
+
+    /// Sets [`exclusive_minimum`](#method.exclusive_minimum) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_exclusive_minimum(mut self, value: Option<impl Into<bool>>) -> Self {
+        self.body.exclusive_minimum = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`exclusive_minimum`](#method.exclusive_minimum), but takes a reference.
+    #[inline]
+    pub fn exclusive_minimum_ref(self, value: &bool) -> Self {
+        self.exclusive_minimum(*value)
+    }
+
+    #[inline]
+    pub fn external_docs(mut self, value: crate::io::k8s::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::external_documentation::ExternalDocumentation) -> Self {
+        self.body.external_docs = Some(value.into());
+        self
+    }
+
+    /// Sets [`external_docs`](#method.external_docs) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_external_docs(mut self, value: Option<crate::io::k8s::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::external_documentation::ExternalDocumentation>) -> Self {
+        self.body.external_docs = value.map(|value| value.into());
+        self
+    }
+
+    #[inline]
+    pub fn format(mut self, value: impl Into<String>) -> Self {
+        self.body.format = Some(value.into());
+        self
+    }
+
+    /// Sets [`format`](#method.format) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_format(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.format = value.map(|value| value.into());
+        self
+    }
+
+    #[inline]
+    pub fn id(mut self, value: impl Into<String>) -> Self {
+        self.body.id = Some(value.into());
+        self
+    }
+
+    /// Sets [`id`](#method.id) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_id(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.id = value.map(|value| value.into());
+        self
+    }
+
+    #[inline]
+    pub fn items(mut self, value: impl Into<Any>) -> Self {
+        self.body.items = Some(value.into());
+        self
+    }
+
+    /// Sets [`items`](#method.items) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_items(mut self, value: Option<impl Into<Any>>) -> Self {
+        self.body.items = value.map(|value| value.into());
+        self
+    }
+
+    #[inline]
+    pub fn max_items(mut self, value: impl Into<i64>) -> Self {
+        self.body.max_items = Some(value.into());
+        self
+    }
+
+    /// Sets [`max_items`](#method.max_items) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_max_items(mut self, value: Option<impl Into<i64>>) -> Self {
+        self.body.max_items = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`max_items`](#method.max_items), but takes a reference.
+    #[inline]
+    pub fn max_items_ref(self, value: &i64) -> Self {
+        self.max_items(*value)
+    }
+
+    #[inline]
+    pub fn max_length(mut self, value: impl Into<i64>) -> Self {
+        self.body.max_length = Some(value.into());
+        self
+    }
+
+    /// Sets [`max_length`](#method.max_length) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_max_length(mut self, value: Option<impl Into<i64>>) -> Self {
+        self.body.max_length = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`max_length`](#method.max_length), but takes a reference.
+    #[inline]
+    pub fn max_length_ref(self, value: &i64) -> Self {
+        self.max_length(*value)
+    }
+
+    #[inline]
+    pub fn max_properties(mut self, value: impl Into<i64>) -> Self {
+        self.body.max_properties = Some(value.into());
+        self
+    }
+
+    /// Sets [`max_properties`](#method.max_properties) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_max_properties(mut self, value: Option<impl Into<i64>>) -> Self {
+        self.body.max_properties = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`max_properties`](#method.max_properties), but takes a reference.
+    #[inline]
+    pub fn max_properties_ref(self, value: &i64) -> Self {
+        self.max_properties(*value)
+    }
+
+    #[inline]
+    pub fn maximum(mut self, value: impl Into<f64>) -> Self {
+        self.body.maximum = Some(value.into());
+        self
+    }
+
+    /// Sets [`maximum`](#method.maximum) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_maximum(mut self, value: Option<impl Into<f64>>) -> Self {
+        self.body.maximum = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`maximum`](#method.maximum), but takes a reference.
+    #[inline]
+    pub fn maximum_ref(self, value: &f64) -> Self {
+        self.maximum(*value)
+    }
+
+    #[inline]
+    pub fn min_items(mut self, value: impl Into<i64>) -> Self {
+        self.body.min_items = Some(value.into());
+        self
+    }
+
+    /// Sets [`min_items`](#method.min_items) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_min_items(mut self, value: Option<impl Into<i64>>) -> Self {
+        self.body.min_items = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`min_items`](#method.min_items), but takes a reference.
+    #[inline]
+    pub fn min_items_ref(self, value: &i64) -> Self {
+        self.min_items(*value)
+    }
+
+    #[inline]
+    pub fn min_length(mut self, value: impl Into<i64>) -> Self {
+        self.body.min_length = Some(value.into());
+        self
+    }
+
+    /// Sets [`min_length`](#method.min_length) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_min_length(mut self, value: Option<impl Into<i64>>) -> Self {
+        self.body.min_length = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`min_length`](#method.min_length), but takes a reference.
+    #[inline]
+    pub fn min_length_ref(self, value: &i64) -> Self {
+        self.min_length(*value)
+    }
+
+    #[inline]
+    pub fn min_properties(mut self, value: impl Into<i64>) -> Self {
+        self.body.min_properties = Some(value.into());
+        self
+    }
+
+    /// Sets [`min_properties`](#method.min_properties) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_min_properties(mut self, value: Option<impl Into<i64>>) -> Self {
+        self.body.min_properties = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`min_properties`](#method.min_properties), but takes a reference.
+    #[inline]
+    pub fn min_properties_ref(self, value: &i64) -> Self {
+        self.min_properties(*value)
+    }
+
+    #[inline]
+    pub fn minimum(mut self, value: impl Into<f64>) -> Self {
+        self.body.minimum = Some(value.into());
+        self
+    }
+
+    /// Sets [`minimum`](#method.minimum) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_minimum(mut self, value: Option<impl Into<f64>>) -> Self {
+        self.body.minimum = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`minimum`](#method.minimum), but takes a reference.
+    #[inline]
+    pub fn minimum_ref(self, value: &f64) -> Self {
+        self.minimum(*value)
+    }
+
+    #[inline]
+    pub fn multiple_of(mut self, value: impl Into<f64>) -> Self {
+        self.body.multiple_of = Some(value.into());
+        self
+    }
+
+    /// Sets [`multiple_of`](#method.multiple_of) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_multiple_of(mut self, value: Option<impl Into<f64>>) -> Self {
+        self.body.multiple_of = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`multiple_of`](#method.multiple_of), but takes a reference.
+    #[inline]
+    pub fn multiple_of_ref(self, value: &f64) -> Self {
+        self.multiple_of(*value)
+    }
+
+    #[inline]
+    pub fn not(mut self, value: crate::io::k8s::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::json_schema_props::JsonSchemaProps<Any>) -> Self {
+        self.body.not = Some(value.into());
+        self
+    }
+
+    /// Sets [`not`](#method.not) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_not(mut self, value: Option<crate::io::k8s::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::json_schema_props::JsonSchemaProps<Any>>) -> Self {
+        self.body.not = value.map(|value| value.into());
+        self
+    }
+
+    #[inline]
+    pub fn nullable(mut self, value: impl Into<bool>) -> Self {
+        self.body.nullable = Some(value.into());
+        self
+    }
+
+    /// Sets [`nullable`](#method.nullable) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_nullable(mut self, value: Option<impl Into<bool>>) -> Self {
+        self.body.nullable = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`nullable`](#method.nullable), but takes a reference.
+    #[inline]
+    pub fn nullable_ref(self, value: &bool) -> Self {
+        self.nullable(*value)
+    }
+
+    #[inline]
+    pub fn one_of(mut self, value: impl Iterator<Item = crate::io::k8s::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::json_schema_props::JsonSchemaProps<Any>>) -> Self {
+        self.body.one_of = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`one_of`](#method.one_of) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_one_of(mut self, value: Option<impl Iterator<Item = crate::io::k8s::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::json_schema_props::JsonSchemaProps<Any>>>) -> Self {
+        self.body.one_of = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`one_of`](#method.one_of), but takes the values as a `Vec`.
+    #[inline]
+    pub fn one_of_vec(mut self, value: impl Into<Vec<crate::io::k8s::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::json_schema_props::JsonSchemaProps<Any>>>) -> Self {
+        self.body.one_of = Some(value.into());
+        self
+    }
+
+    #[inline]
+    pub fn pattern(mut self, value: impl Into<String>) -> Self {
+        self.body.pattern = Some(value.into());
+        self
+    }
+
+    /// Sets [`pattern`](#method.pattern) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_pattern(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.pattern = value.map(|value| value.into());
+        self
+    }
+
+    #[inline]
+    pub fn pattern_properties(mut self, value: impl Iterator<Item = (String, crate::io::k8s::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::json_schema_props::JsonSchemaProps<Any>)>) -> Self {
+        self.body.pattern_properties = Some(value.map(|(key, value)| (key, value.into())).collect::<std::collections::BTreeMap<_, _>>().into());
+        self
+    }
+
+    /// Sets [`pattern_properties`](#method.pattern_properties) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_pattern_properties(mut self, value: Option<impl Iterator<Item = (String, crate::io::k8s::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::json_schema_props::JsonSchemaProps<Any>)>>) -> Self {
+        self.body.pattern_properties = value.map(|value| value.map(|(key, value)| (key, value.into())).collect::<std::collections::BTreeMap<_, _>>().into());
+        self
+    }
+
+    #[inline]
+    pub fn properties(mut self, value: impl Iterator<Item = (String, crate::io::k8s::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::json_schema_props::JsonSchemaProps<Any>)>) -> Self {
+        self.body.properties = Some(value.map(|(key, value)| (key, value.into())).collect::<std::collections::BTreeMap<_, _>>().into());
+        self
+    }
+
+    /// Sets [`properties`](#method.properties) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_properties(mut self, value: Option<impl Iterator<Item = (String, crate::io::k8s::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::json_schema_props::JsonSchemaProps<Any>)>>) -> Self {
+        self.body.properties = value.map(|value| value.map(|(key, value)| (key, value.into())).collect::<std::collections::BTreeMap<_, _>>().into());
+        self
+    }
+
+    #[inline]
+    pub fn required(mut self, value: impl Iterator<Item = impl Into<String>>) -> Self {
+        self.body.required = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`required`](#method.required) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_required(mut self, value: Option<impl Iterator<Item = impl Into<String>>>) -> Self {
+        self.body.required = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`required`](#method.required), but takes the values as a `Vec`.
+    #[inline]
+    pub fn required_vec(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.body.required = Some(value.into());
+        self
+    }
+
+    #[inline]
+    pub fn title(mut self, value: impl Into<String>) -> Self {
+        self.body.title = Some(value.into());
+        self
+    }
+
+    /// Sets [`title`](#method.title) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_title(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.title = value.map(|value| value.into());
+        self
+    }
+
+    #[inline]
+    pub fn type_(mut self, value: impl Into<String>) -> Self {
+        self.body.type_ = Some(value.into());
+        self
+    }
+
+    /// Sets [`type_`](#method.type_) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_type(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.type_ = value.map(|value| value.into());
+        self
+    }
+
+    #[inline]
+    pub fn unique_items(mut self, value: impl Into<bool>) -> Self {
+        self.body.unique_items = Some(value.into());
+        self
+    }
+
+    /// Sets [`unique_items`](#method.unique_items) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_unique_items(mut self, value: Option<impl Into<bool>>) -> Self {
+        self.body.unique_items = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`unique_items`](#method.unique_items), but takes a reference.
+    #[inline]
+    pub fn unique_items_ref(self, value: &bool) -> Self {
+        self.unique_items(*value)
+    }
+
+    /// x-kubernetes-embedded-resource defines that the value is an embedded Kubernetes runtime.Object, with TypeMeta and ObjectMeta. The type must be object. It is allowed to further restrict the embedded object. kind, apiVersion and metadata are validated automatically. x-kubernetes-preserve-unknown-fields is allowed to be true, but does not have to be if the object is fully specified (up to kind, apiVersion, metadata).
+    #[inline]
+    pub fn x_kubernetes_embedded_resource(mut self, value: impl Into<bool>) -> Self {
+        self.body.x_kubernetes_embedded_resource = Some(value.into());
+        self
+    }
+
+    /// Sets [`x_kubernetes_embedded_resource`](#method.x_kubernetes_embedded_resource) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_x_kubernetes_embedded_resource(mut self, value: Option<impl Into<bool>>) -> Self {
+        self.body.x_kubernetes_embedded_resource = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`x_kubernetes_embedded_resource`](#method.x_kubernetes_embedded_resource), but takes a reference.
+    #[inline]
+    pub fn x_kubernetes_embedded_resource_ref(self, value: &bool) -> Self {
+        self.x_kubernetes_embedded_resource(*value)
+    }
+
+    /// x-kubernetes-int-or-string specifies that this value is either an integer or a string. If this is true, an empty type is allowed and type as child of anyOf is permitted if following one of the following patterns:
+    ///
+    /// 1) anyOf:
+    ///    - type: integer
+    ///    - type: string
+    /// 2) allOf:
+    ///    - anyOf:
+    ///      - type: integer
+    ///      - type: string
+    ///    - ... zero or more
+    #[inline]
+    pub fn x_kubernetes_int_or_string(mut self, value: impl Into<bool>) -> Self {
+        self.body.x_kubernetes_int_or_string = Some(value.into());
+        self
+    }
+
+    /// Sets [`x_kubernetes_int_or_string`](#method.x_kubernetes_int_or_string) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_x_kubernetes_int_or_string(mut self, value: Option<impl Into<bool>>) -> Self {
+        self.body.x_kubernetes_int_or_string = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`x_kubernetes_int_or_string`](#method.x_kubernetes_int_or_string), but takes a reference.
+    #[inline]
+    pub fn x_kubernetes_int_or_string_ref(self, value: &bool) -> Self {
+        self.x_kubernetes_int_or_string(*value)
+    }
+
+    /// x-kubernetes-preserve-unknown-fields stops the API server decoding step from pruning fields which are not specified in the validation schema. This affects fields recursively, but switches back to normal pruning behaviour if nested properties or additionalProperties are specified in the schema. This can either be true or undefined. False is forbidden.
+    #[inline]
+    pub fn x_kubernetes_preserve_unknown_fields(mut self, value: impl Into<bool>) -> Self {
+        self.body.x_kubernetes_preserve_unknown_fields = Some(value.into());
+        self
+    }
+
+    /// Sets [`x_kubernetes_preserve_unknown_fields`](#method.x_kubernetes_preserve_unknown_fields) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_x_kubernetes_preserve_unknown_fields(mut self, value: Option<impl Into<bool>>) -> Self {
+        self.body.x_kubernetes_preserve_unknown_fields = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`x_kubernetes_preserve_unknown_fields`](#method.x_kubernetes_preserve_unknown_fields), but takes a reference.
+    #[inline]
+    pub fn x_kubernetes_preserve_unknown_fields_ref(self, value: &bool) -> Self {
+        self.x_kubernetes_preserve_unknown_fields(*value)
+    }
+}
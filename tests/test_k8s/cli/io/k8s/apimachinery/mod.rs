@@ -0,0 +1,4 @@
+
+pub mod pkg {
+    include!("./pkg/mod.rs");
+}
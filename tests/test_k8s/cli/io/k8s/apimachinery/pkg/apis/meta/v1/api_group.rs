@@ -0,0 +1,1226 @@
+
+/// APIGroup contains the name, the supported versions, and the preferred version of a group.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct ApiGroup {
+    /// APIVersion defines the versioned schema of this representation of an object. Servers should convert recognized schemas to the latest internal value, and may reject unrecognized values. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#resources
+    #[serde(rename = "apiVersion", skip_serializing_if = "Option::is_none")]
+    pub api_version: Option<String>,
+    /// Kind is a string value representing the REST resource this object represents. Servers may infer this from the endpoint the client submits requests to. Cannot be updated. In CamelCase. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#types-kinds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub kind: Option<String>,
+    /// name is the name of the group.
+    pub name: String,
+    /// preferredVersion is the version preferred by the API server, which probably is the storage version.
+    #[serde(rename = "preferredVersion", skip_serializing_if = "Option::is_none")]
+    pub preferred_version: Option<crate::io::k8s::apimachinery::pkg::apis::meta::v1::group_version_for_discovery::GroupVersionForDiscovery>,
+    /// a map of client CIDR to server address that is serving this group. This is to help clients reach servers in the most network-efficient way possible. Clients can use the appropriate server address as per the CIDR that they match. In case of multiple matches, clients should use the longest matching CIDR. The server returns only those CIDRs that it thinks that the client can match. For example: the master will return an internal IP CIDR only, if the client reaches the server using an internal IP. Server looks at X-Forwarded-For header or X-Real-Ip header or request.RemoteAddr (in that order) to get the client IP.
+    #[serde(rename = "serverAddressByClientCIDRs", skip_serializing_if = "Option::is_none")]
+    pub server_address_by_client_cid_rs: Option<Vec<crate::io::k8s::apimachinery::pkg::apis::meta::v1::server_address_by_client_cidr::ServerAddressByClientCidr>>,
+    /// versions are the versions supported in this group.
+    pub versions: Vec<crate::io::k8s::apimachinery::pkg::apis::meta::v1::group_version_for_discovery::GroupVersionForDiscovery>,
+}
+
+impl ApiGroup {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> ApiGroupBuilder<crate::generics::MissingName, crate::generics::MissingVersions> {
+        ApiGroupBuilder {
+            body: Default::default(),
+            _name: core::marker::PhantomData,
+            _versions: core::marker::PhantomData,
+        }
+    }
+
+    /// get information of a group
+    #[inline]
+    pub fn get_admissionregistration_api_group() -> ApiGroupGetBuilder {
+        ApiGroupGetBuilder
+    }
+
+    /// get information of a group
+    #[inline]
+    pub fn get_apiextensions_api_group() -> ApiGroupGetBuilder1 {
+        ApiGroupGetBuilder1
+    }
+
+    /// get information of a group
+    #[inline]
+    pub fn get_apiregistration_api_group() -> ApiGroupGetBuilder2 {
+        ApiGroupGetBuilder2
+    }
+
+    /// get information of a group
+    #[inline]
+    pub fn get_apps_api_group() -> ApiGroupGetBuilder3 {
+        ApiGroupGetBuilder3
+    }
+
+    /// get information of a group
+    #[inline]
+    pub fn get_auditregistration_api_group() -> ApiGroupGetBuilder4 {
+        ApiGroupGetBuilder4
+    }
+
+    /// get information of a group
+    #[inline]
+    pub fn get_authentication_api_group() -> ApiGroupGetBuilder5 {
+        ApiGroupGetBuilder5
+    }
+
+    /// get information of a group
+    #[inline]
+    pub fn get_authorization_api_group() -> ApiGroupGetBuilder6 {
+        ApiGroupGetBuilder6
+    }
+
+    /// get information of a group
+    #[inline]
+    pub fn get_autoscaling_api_group() -> ApiGroupGetBuilder7 {
+        ApiGroupGetBuilder7
+    }
+
+    /// get information of a group
+    #[inline]
+    pub fn get_batch_api_group() -> ApiGroupGetBuilder8 {
+        ApiGroupGetBuilder8
+    }
+
+    /// get information of a group
+    #[inline]
+    pub fn get_certificates_api_group() -> ApiGroupGetBuilder9 {
+        ApiGroupGetBuilder9
+    }
+
+    /// get information of a group
+    #[inline]
+    pub fn get_coordination_api_group() -> ApiGroupGetBuilder10 {
+        ApiGroupGetBuilder10
+    }
+
+    /// get information of a group
+    #[inline]
+    pub fn get_events_api_group() -> ApiGroupGetBuilder11 {
+        ApiGroupGetBuilder11
+    }
+
+    /// get information of a group
+    #[inline]
+    pub fn get_extensions_api_group() -> ApiGroupGetBuilder12 {
+        ApiGroupGetBuilder12
+    }
+
+    /// get information of a group
+    #[inline]
+    pub fn get_networking_api_group() -> ApiGroupGetBuilder13 {
+        ApiGroupGetBuilder13
+    }
+
+    /// get information of a group
+    #[inline]
+    pub fn get_node_api_group() -> ApiGroupGetBuilder14 {
+        ApiGroupGetBuilder14
+    }
+
+    /// get information of a group
+    #[inline]
+    pub fn get_policy_api_group() -> ApiGroupGetBuilder15 {
+        ApiGroupGetBuilder15
+    }
+
+    /// get information of a group
+    #[inline]
+    pub fn get_rbac_authorization_api_group() -> ApiGroupGetBuilder16 {
+        ApiGroupGetBuilder16
+    }
+
+    /// get information of a group
+    #[inline]
+    pub fn get_scheduling_api_group() -> ApiGroupGetBuilder17 {
+        ApiGroupGetBuilder17
+    }
+
+    /// get information of a group
+    #[inline]
+    pub fn get_settings_api_group() -> ApiGroupGetBuilder18 {
+        ApiGroupGetBuilder18
+    }
+
+    /// get information of a group
+    #[inline]
+    pub fn get_storage_api_group() -> ApiGroupGetBuilder19 {
+        ApiGroupGetBuilder19
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> ApiGroupBuilder<crate::generics::NameExists, crate::generics::VersionsExists>
+    where
+        Self: Clone,
+    {
+        ApiGroupBuilder {
+            body: self.clone(),
+            _name: core::marker::PhantomData,
+            _versions: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<ApiGroup> for ApiGroupBuilder<crate::generics::NameExists, crate::generics::VersionsExists> {
+    fn into(self) -> ApiGroup {
+        self.body
+    }
+}
+
+/// Builder for [`ApiGroup`](./struct.ApiGroup.html) object.
+#[derive(Debug, Clone)]
+pub struct ApiGroupBuilder<Name, Versions> {
+    body: self::ApiGroup,
+    _name: core::marker::PhantomData<Name>,
+    _versions: core::marker::PhantomData<Versions>,
+}
+
+impl<Name, Versions> ApiGroupBuilder<Name, Versions> {
+    /// APIVersion defines the versioned schema of this representation of an object. Servers should convert recognized schemas to the latest internal value, and may reject unrecognized values. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#resources
+    #[inline]
+    pub fn api_version(mut self, value: impl Into<String>) -> Self {
+        self.body.api_version = Some(value.into());
+        self
+    }
+
+    /// Sets [`api_version`](#method.api_version) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_api_version(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.api_version = value.map(|value| value.into());
+        self
+    }
+
+    /// Kind is a string value representing the REST resource this object represents. Servers may infer this from the endpoint the client submits requests to. Cannot be updated. In CamelCase. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#types-kinds
+    #[inline]
+    pub fn kind(mut self, value: impl Into<String>) -> Self {
+        self.body.kind = Some(value.into());
+        self
+    }
+
+    /// Sets [`kind`](#method.kind) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_kind(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.kind = value.map(|value| value.into());
+        self
+    }
+
+    /// name is the name of the group.
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> ApiGroupBuilder<crate::generics::NameExists, Versions> {
+        self.body.name = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// preferredVersion is the version preferred by the API server, which probably is the storage version.
+    #[inline]
+    pub fn preferred_version(mut self, value: crate::io::k8s::apimachinery::pkg::apis::meta::v1::group_version_for_discovery::GroupVersionForDiscoveryBuilder<crate::generics::GroupVersionExists, crate::generics::VersionExists>) -> Self {
+        self.body.preferred_version = Some(value.into());
+        self
+    }
+
+    /// Sets [`preferred_version`](#method.preferred_version) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_preferred_version(mut self, value: Option<crate::io::k8s::apimachinery::pkg::apis::meta::v1::group_version_for_discovery::GroupVersionForDiscoveryBuilder<crate::generics::GroupVersionExists, crate::generics::VersionExists>>) -> Self {
+        self.body.preferred_version = value.map(|value| value.into());
+        self
+    }
+
+    /// a map of client CIDR to server address that is serving this group. This is to help clients reach servers in the most network-efficient way possible. Clients can use the appropriate server address as per the CIDR that they match. In case of multiple matches, clients should use the longest matching CIDR. The server returns only those CIDRs that it thinks that the client can match. For example: the master will return an internal IP CIDR only, if the client reaches the server using an internal IP. Server looks at X-Forwarded-For header or X-Real-Ip header or request.RemoteAddr (in that order) to get the client IP.
+    #[inline]
+    pub fn server_address_by_client_cid_rs(mut self, value: impl Iterator<Item = crate::io::k8s::apimachinery::pkg::apis::meta::v1::server_address_by_client_cidr::ServerAddressByClientCidrBuilder<crate::generics::ClientCidrExists, crate::generics::ServerAddressExists>>) -> Self {
+        self.body.server_address_by_client_cid_rs = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`server_address_by_client_cid_rs`](#method.server_address_by_client_cid_rs) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_server_address_by_client_cid_rs(mut self, value: Option<impl Iterator<Item = crate::io::k8s::apimachinery::pkg::apis::meta::v1::server_address_by_client_cidr::ServerAddressByClientCidrBuilder<crate::generics::ClientCidrExists, crate::generics::ServerAddressExists>>>) -> Self {
+        self.body.server_address_by_client_cid_rs = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`server_address_by_client_cid_rs`](#method.server_address_by_client_cid_rs), but takes the values as a `Vec`.
+    #[inline]
+    pub fn server_address_by_client_cid_rs_vec(mut self, value: impl Into<Vec<crate::io::k8s::apimachinery::pkg::apis::meta::v1::server_address_by_client_cidr::ServerAddressByClientCidr>>) -> Self {
+        self.body.server_address_by_client_cid_rs = Some(value.into());
+        self
+    }
+
+    /// versions are the versions supported in this group.
+    #[inline]
+    pub fn versions(mut self, value: impl Iterator<Item = crate::io::k8s::apimachinery::pkg::apis::meta::v1::group_version_for_discovery::GroupVersionForDiscoveryBuilder<crate::generics::GroupVersionExists, crate::generics::VersionExists>>) -> ApiGroupBuilder<Name, crate::generics::VersionsExists> {
+        self.body.versions = value.map(|value| value.into()).collect::<Vec<_>>().into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Same as [`versions`](#method.versions), but takes the values as a `Vec`.
+    #[inline]
+    pub fn versions_vec(mut self, value: impl Into<Vec<crate::io::k8s::apimachinery::pkg::apis::meta::v1::group_version_for_discovery::GroupVersionForDiscovery>>) -> ApiGroupBuilder<Name, crate::generics::VersionsExists> {
+        self.body.versions = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+}
+
+/// Builder created by [`ApiGroup::get_admissionregistration_api_group`](./struct.ApiGroup.html#method.get_admissionregistration_api_group) method for a `GET` operation associated with `ApiGroup`.
+#[derive(Debug, Default, Clone)]
+pub struct ApiGroupGetBuilder;
+
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for ApiGroupGetBuilder {
+    type Output = ApiGroup;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    const OPERATION_ID: Option<&'static str> = Some("getAdmissionregistrationAPIGroup");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok("/apis/admissionregistration.k8s.io/".into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::ACCEPT.as_str(), "application/json"))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<ApiGroupGetBuilder> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: ApiGroupGetBuilder) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl ApiGroupGetBuilder {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = ApiGroupGetBuilder {
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder created by [`ApiGroup::get_apiextensions_api_group`](./struct.ApiGroup.html#method.get_apiextensions_api_group) method for a `GET` operation associated with `ApiGroup`.
+#[derive(Debug, Default, Clone)]
+pub struct ApiGroupGetBuilder1;
+
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for ApiGroupGetBuilder1 {
+    type Output = ApiGroup;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    const OPERATION_ID: Option<&'static str> = Some("getApiextensionsAPIGroup");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok("/apis/apiextensions.k8s.io/".into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::ACCEPT.as_str(), "application/json"))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<ApiGroupGetBuilder1> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: ApiGroupGetBuilder1) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl ApiGroupGetBuilder1 {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = ApiGroupGetBuilder1 {
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder created by [`ApiGroup::get_apiregistration_api_group`](./struct.ApiGroup.html#method.get_apiregistration_api_group) method for a `GET` operation associated with `ApiGroup`.
+#[derive(Debug, Default, Clone)]
+pub struct ApiGroupGetBuilder2;
+
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for ApiGroupGetBuilder2 {
+    type Output = ApiGroup;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    const OPERATION_ID: Option<&'static str> = Some("getApiregistrationAPIGroup");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok("/apis/apiregistration.k8s.io/".into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::ACCEPT.as_str(), "application/json"))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<ApiGroupGetBuilder2> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: ApiGroupGetBuilder2) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl ApiGroupGetBuilder2 {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = ApiGroupGetBuilder2 {
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder created by [`ApiGroup::get_apps_api_group`](./struct.ApiGroup.html#method.get_apps_api_group) method for a `GET` operation associated with `ApiGroup`.
+#[derive(Debug, Default, Clone)]
+pub struct ApiGroupGetBuilder3;
+
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for ApiGroupGetBuilder3 {
+    type Output = ApiGroup;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    const OPERATION_ID: Option<&'static str> = Some("getAppsAPIGroup");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok("/apis/apps/".into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::ACCEPT.as_str(), "application/json"))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<ApiGroupGetBuilder3> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: ApiGroupGetBuilder3) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl ApiGroupGetBuilder3 {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = ApiGroupGetBuilder3 {
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder created by [`ApiGroup::get_auditregistration_api_group`](./struct.ApiGroup.html#method.get_auditregistration_api_group) method for a `GET` operation associated with `ApiGroup`.
+#[derive(Debug, Default, Clone)]
+pub struct ApiGroupGetBuilder4;
+
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for ApiGroupGetBuilder4 {
+    type Output = ApiGroup;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    const OPERATION_ID: Option<&'static str> = Some("getAuditregistrationAPIGroup");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok("/apis/auditregistration.k8s.io/".into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::ACCEPT.as_str(), "application/json"))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<ApiGroupGetBuilder4> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: ApiGroupGetBuilder4) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl ApiGroupGetBuilder4 {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = ApiGroupGetBuilder4 {
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder created by [`ApiGroup::get_authentication_api_group`](./struct.ApiGroup.html#method.get_authentication_api_group) method for a `GET` operation associated with `ApiGroup`.
+#[derive(Debug, Default, Clone)]
+pub struct ApiGroupGetBuilder5;
+
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for ApiGroupGetBuilder5 {
+    type Output = ApiGroup;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    const OPERATION_ID: Option<&'static str> = Some("getAuthenticationAPIGroup");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok("/apis/authentication.k8s.io/".into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::ACCEPT.as_str(), "application/json"))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<ApiGroupGetBuilder5> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: ApiGroupGetBuilder5) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl ApiGroupGetBuilder5 {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = ApiGroupGetBuilder5 {
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder created by [`ApiGroup::get_authorization_api_group`](./struct.ApiGroup.html#method.get_authorization_api_group) method for a `GET` operation associated with `ApiGroup`.
+#[derive(Debug, Default, Clone)]
+pub struct ApiGroupGetBuilder6;
+
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for ApiGroupGetBuilder6 {
+    type Output = ApiGroup;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    const OPERATION_ID: Option<&'static str> = Some("getAuthorizationAPIGroup");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok("/apis/authorization.k8s.io/".into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::ACCEPT.as_str(), "application/json"))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<ApiGroupGetBuilder6> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: ApiGroupGetBuilder6) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl ApiGroupGetBuilder6 {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = ApiGroupGetBuilder6 {
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder created by [`ApiGroup::get_autoscaling_api_group`](./struct.ApiGroup.html#method.get_autoscaling_api_group) method for a `GET` operation associated with `ApiGroup`.
+#[derive(Debug, Default, Clone)]
+pub struct ApiGroupGetBuilder7;
+
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for ApiGroupGetBuilder7 {
+    type Output = ApiGroup;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    const OPERATION_ID: Option<&'static str> = Some("getAutoscalingAPIGroup");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok("/apis/autoscaling/".into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::ACCEPT.as_str(), "application/json"))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<ApiGroupGetBuilder7> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: ApiGroupGetBuilder7) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl ApiGroupGetBuilder7 {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = ApiGroupGetBuilder7 {
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder created by [`ApiGroup::get_batch_api_group`](./struct.ApiGroup.html#method.get_batch_api_group) method for a `GET` operation associated with `ApiGroup`.
+#[derive(Debug, Default, Clone)]
+pub struct ApiGroupGetBuilder8;
+
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for ApiGroupGetBuilder8 {
+    type Output = ApiGroup;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    const OPERATION_ID: Option<&'static str> = Some("getBatchAPIGroup");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok("/apis/batch/".into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::ACCEPT.as_str(), "application/json"))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<ApiGroupGetBuilder8> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: ApiGroupGetBuilder8) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl ApiGroupGetBuilder8 {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = ApiGroupGetBuilder8 {
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder created by [`ApiGroup::get_certificates_api_group`](./struct.ApiGroup.html#method.get_certificates_api_group) method for a `GET` operation associated with `ApiGroup`.
+#[derive(Debug, Default, Clone)]
+pub struct ApiGroupGetBuilder9;
+
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for ApiGroupGetBuilder9 {
+    type Output = ApiGroup;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    const OPERATION_ID: Option<&'static str> = Some("getCertificatesAPIGroup");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok("/apis/certificates.k8s.io/".into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::ACCEPT.as_str(), "application/json"))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<ApiGroupGetBuilder9> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: ApiGroupGetBuilder9) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl ApiGroupGetBuilder9 {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = ApiGroupGetBuilder9 {
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder created by [`ApiGroup::get_coordination_api_group`](./struct.ApiGroup.html#method.get_coordination_api_group) method for a `GET` operation associated with `ApiGroup`.
+#[derive(Debug, Default, Clone)]
+pub struct ApiGroupGetBuilder10;
+
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for ApiGroupGetBuilder10 {
+    type Output = ApiGroup;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    const OPERATION_ID: Option<&'static str> = Some("getCoordinationAPIGroup");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok("/apis/coordination.k8s.io/".into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::ACCEPT.as_str(), "application/json"))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<ApiGroupGetBuilder10> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: ApiGroupGetBuilder10) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl ApiGroupGetBuilder10 {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = ApiGroupGetBuilder10 {
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder created by [`ApiGroup::get_events_api_group`](./struct.ApiGroup.html#method.get_events_api_group) method for a `GET` operation associated with `ApiGroup`.
+#[derive(Debug, Default, Clone)]
+pub struct ApiGroupGetBuilder11;
+
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for ApiGroupGetBuilder11 {
+    type Output = ApiGroup;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    const OPERATION_ID: Option<&'static str> = Some("getEventsAPIGroup");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok("/apis/events.k8s.io/".into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::ACCEPT.as_str(), "application/json"))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<ApiGroupGetBuilder11> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: ApiGroupGetBuilder11) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl ApiGroupGetBuilder11 {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = ApiGroupGetBuilder11 {
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder created by [`ApiGroup::get_extensions_api_group`](./struct.ApiGroup.html#method.get_extensions_api_group) method for a `GET` operation associated with `ApiGroup`.
+#[derive(Debug, Default, Clone)]
+pub struct ApiGroupGetBuilder12;
+
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for ApiGroupGetBuilder12 {
+    type Output = ApiGroup;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    const OPERATION_ID: Option<&'static str> = Some("getExtensionsAPIGroup");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok("/apis/extensions/".into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::ACCEPT.as_str(), "application/json"))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<ApiGroupGetBuilder12> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: ApiGroupGetBuilder12) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl ApiGroupGetBuilder12 {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = ApiGroupGetBuilder12 {
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder created by [`ApiGroup::get_networking_api_group`](./struct.ApiGroup.html#method.get_networking_api_group) method for a `GET` operation associated with `ApiGroup`.
+#[derive(Debug, Default, Clone)]
+pub struct ApiGroupGetBuilder13;
+
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for ApiGroupGetBuilder13 {
+    type Output = ApiGroup;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    const OPERATION_ID: Option<&'static str> = Some("getNetworkingAPIGroup");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok("/apis/networking.k8s.io/".into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::ACCEPT.as_str(), "application/json"))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<ApiGroupGetBuilder13> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: ApiGroupGetBuilder13) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl ApiGroupGetBuilder13 {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = ApiGroupGetBuilder13 {
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder created by [`ApiGroup::get_node_api_group`](./struct.ApiGroup.html#method.get_node_api_group) method for a `GET` operation associated with `ApiGroup`.
+#[derive(Debug, Default, Clone)]
+pub struct ApiGroupGetBuilder14;
+
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for ApiGroupGetBuilder14 {
+    type Output = ApiGroup;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    const OPERATION_ID: Option<&'static str> = Some("getNodeAPIGroup");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok("/apis/node.k8s.io/".into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::ACCEPT.as_str(), "application/json"))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<ApiGroupGetBuilder14> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: ApiGroupGetBuilder14) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl ApiGroupGetBuilder14 {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = ApiGroupGetBuilder14 {
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder created by [`ApiGroup::get_policy_api_group`](./struct.ApiGroup.html#method.get_policy_api_group) method for a `GET` operation associated with `ApiGroup`.
+#[derive(Debug, Default, Clone)]
+pub struct ApiGroupGetBuilder15;
+
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for ApiGroupGetBuilder15 {
+    type Output = ApiGroup;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    const OPERATION_ID: Option<&'static str> = Some("getPolicyAPIGroup");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok("/apis/policy/".into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::ACCEPT.as_str(), "application/json"))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<ApiGroupGetBuilder15> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: ApiGroupGetBuilder15) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl ApiGroupGetBuilder15 {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = ApiGroupGetBuilder15 {
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder created by [`ApiGroup::get_rbac_authorization_api_group`](./struct.ApiGroup.html#method.get_rbac_authorization_api_group) method for a `GET` operation associated with `ApiGroup`.
+#[derive(Debug, Default, Clone)]
+pub struct ApiGroupGetBuilder16;
+
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for ApiGroupGetBuilder16 {
+    type Output = ApiGroup;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    const OPERATION_ID: Option<&'static str> = Some("getRbacAuthorizationAPIGroup");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok("/apis/rbac.authorization.k8s.io/".into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::ACCEPT.as_str(), "application/json"))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<ApiGroupGetBuilder16> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: ApiGroupGetBuilder16) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl ApiGroupGetBuilder16 {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = ApiGroupGetBuilder16 {
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder created by [`ApiGroup::get_scheduling_api_group`](./struct.ApiGroup.html#method.get_scheduling_api_group) method for a `GET` operation associated with `ApiGroup`.
+#[derive(Debug, Default, Clone)]
+pub struct ApiGroupGetBuilder17;
+
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for ApiGroupGetBuilder17 {
+    type Output = ApiGroup;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    const OPERATION_ID: Option<&'static str> = Some("getSchedulingAPIGroup");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok("/apis/scheduling.k8s.io/".into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::ACCEPT.as_str(), "application/json"))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<ApiGroupGetBuilder17> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: ApiGroupGetBuilder17) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl ApiGroupGetBuilder17 {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = ApiGroupGetBuilder17 {
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder created by [`ApiGroup::get_settings_api_group`](./struct.ApiGroup.html#method.get_settings_api_group) method for a `GET` operation associated with `ApiGroup`.
+#[derive(Debug, Default, Clone)]
+pub struct ApiGroupGetBuilder18;
+
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for ApiGroupGetBuilder18 {
+    type Output = ApiGroup;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    const OPERATION_ID: Option<&'static str> = Some("getSettingsAPIGroup");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok("/apis/settings.k8s.io/".into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::ACCEPT.as_str(), "application/json"))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<ApiGroupGetBuilder18> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: ApiGroupGetBuilder18) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl ApiGroupGetBuilder18 {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = ApiGroupGetBuilder18 {
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder created by [`ApiGroup::get_storage_api_group`](./struct.ApiGroup.html#method.get_storage_api_group) method for a `GET` operation associated with `ApiGroup`.
+#[derive(Debug, Default, Clone)]
+pub struct ApiGroupGetBuilder19;
+
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for ApiGroupGetBuilder19 {
+    type Output = ApiGroup;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    const OPERATION_ID: Option<&'static str> = Some("getStorageAPIGroup");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok("/apis/storage.k8s.io/".into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::ACCEPT.as_str(), "application/json"))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<ApiGroupGetBuilder19> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: ApiGroupGetBuilder19) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl ApiGroupGetBuilder19 {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = ApiGroupGetBuilder19 {
+        };
+
+        Ok(thing)
+    }
+}
@@ -0,0 +1,147 @@
+
+/// APIGroupList is a list of APIGroup, to allow clients to discover the API at /apis.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct ApiGroupList {
+    /// APIVersion defines the versioned schema of this representation of an object. Servers should convert recognized schemas to the latest internal value, and may reject unrecognized values. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#resources
+    #[serde(rename = "apiVersion", skip_serializing_if = "Option::is_none")]
+    pub api_version: Option<String>,
+    /// groups is a list of APIGroup.
+    pub groups: Vec<crate::io::k8s::apimachinery::pkg::apis::meta::v1::api_group::ApiGroup>,
+    /// Kind is a string value representing the REST resource this object represents. Servers may infer this from the endpoint the client submits requests to. Cannot be updated. In CamelCase. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#types-kinds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub kind: Option<String>,
+}
+
+impl ApiGroupList {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> ApiGroupListBuilder<crate::generics::MissingGroups> {
+        ApiGroupListBuilder {
+            body: Default::default(),
+            _groups: core::marker::PhantomData,
+        }
+    }
+
+    /// get available API versions
+    #[inline]
+    pub fn get_api_versions() -> ApiGroupListGetBuilder {
+        ApiGroupListGetBuilder
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> ApiGroupListBuilder<crate::generics::GroupsExists>
+    where
+        Self: Clone,
+    {
+        ApiGroupListBuilder {
+            body: self.clone(),
+            _groups: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<ApiGroupList> for ApiGroupListBuilder<crate::generics::GroupsExists> {
+    fn into(self) -> ApiGroupList {
+        self.body
+    }
+}
+
+/// Builder for [`ApiGroupList`](./struct.ApiGroupList.html) object.
+#[derive(Debug, Clone)]
+pub struct ApiGroupListBuilder<Groups> {
+    body: self::ApiGroupList,
+    _groups: core::marker::PhantomData<Groups>,
+}
+
+impl<Groups> ApiGroupListBuilder<Groups> {
+    /// APIVersion defines the versioned schema of this representation of an object. Servers should convert recognized schemas to the latest internal value, and may reject unrecognized values. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#resources
+    #[inline]
+    pub fn api_version(mut self, value: impl Into<String>) -> Self {
+        self.body.api_version = Some(value.into());
+        self
+    }
+
+    /// Sets [`api_version`](#method.api_version) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_api_version(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.api_version = value.map(|value| value.into());
+        self
+    }
+
+    /// groups is a list of APIGroup.
+    #[inline]
+    pub fn groups(mut self, value: impl Iterator<Item = crate::io::k8s::apimachinery::pkg::apis::meta::v1::api_group::ApiGroupBuilder<crate::generics::NameExists, crate::generics::VersionsExists>>) -> ApiGroupListBuilder<crate::generics::GroupsExists> {
+        self.body.groups = value.map(|value| value.into()).collect::<Vec<_>>().into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Same as [`groups`](#method.groups), but takes the values as a `Vec`.
+    #[inline]
+    pub fn groups_vec(mut self, value: impl Into<Vec<crate::io::k8s::apimachinery::pkg::apis::meta::v1::api_group::ApiGroup>>) -> ApiGroupListBuilder<crate::generics::GroupsExists> {
+        self.body.groups = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Kind is a string value representing the REST resource this object represents. Servers may infer this from the endpoint the client submits requests to. Cannot be updated. In CamelCase. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#types-kinds
+    #[inline]
+    pub fn kind(mut self, value: impl Into<String>) -> Self {
+        self.body.kind = Some(value.into());
+        self
+    }
+
+    /// Sets [`kind`](#method.kind) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_kind(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.kind = value.map(|value| value.into());
+        self
+    }
+}
+
+/// Builder created by [`ApiGroupList::get_api_versions`](./struct.ApiGroupList.html#method.get_api_versions) method for a `GET` operation associated with `ApiGroupList`.
+#[derive(Debug, Default, Clone)]
+pub struct ApiGroupListGetBuilder;
+
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for ApiGroupListGetBuilder {
+    type Output = ApiGroupList;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    const OPERATION_ID: Option<&'static str> = Some("getAPIVersions");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok("/apis/".into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::ACCEPT.as_str(), "application/json"))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<ApiGroupListGetBuilder> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: ApiGroupListGetBuilder) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl ApiGroupListGetBuilder {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = ApiGroupListGetBuilder {
+        };
+
+        Ok(thing)
+    }
+}
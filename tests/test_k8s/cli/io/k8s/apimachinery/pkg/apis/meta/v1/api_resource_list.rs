@@ -0,0 +1,2212 @@
+
+/// APIResourceList is a list of APIResource, it is used to expose the name of the resources supported in a specific group and version, and if the resource is namespaced.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct ApiResourceList {
+    /// APIVersion defines the versioned schema of this representation of an object. Servers should convert recognized schemas to the latest internal value, and may reject unrecognized values. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#resources
+    #[serde(rename = "apiVersion", skip_serializing_if = "Option::is_none")]
+    pub api_version: Option<String>,
+    /// groupVersion is the group and version this APIResourceList is for.
+    #[serde(rename = "groupVersion")]
+    pub group_version: String,
+    /// Kind is a string value representing the REST resource this object represents. Servers may infer this from the endpoint the client submits requests to. Cannot be updated. In CamelCase. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#types-kinds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub kind: Option<String>,
+    /// resources contains the name of the resources and if they are namespaced.
+    pub resources: Vec<crate::io::k8s::apimachinery::pkg::apis::meta::v1::api_resource::ApiResource>,
+}
+
+impl ApiResourceList {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> ApiResourceListBuilder<crate::generics::MissingGroupVersion, crate::generics::MissingResources> {
+        ApiResourceListBuilder {
+            body: Default::default(),
+            _group_version: core::marker::PhantomData,
+            _resources: core::marker::PhantomData,
+        }
+    }
+
+    /// get available resources
+    #[inline]
+    pub fn get_core_v1api_resources() -> ApiResourceListGetBuilder {
+        ApiResourceListGetBuilder
+    }
+
+    /// get available resources
+    #[inline]
+    pub fn get_admissionregistration_v1beta1_api_resources() -> ApiResourceListGetBuilder1 {
+        ApiResourceListGetBuilder1
+    }
+
+    /// get available resources
+    #[inline]
+    pub fn get_apiextensions_v1beta1_api_resources() -> ApiResourceListGetBuilder2 {
+        ApiResourceListGetBuilder2
+    }
+
+    /// get available resources
+    #[inline]
+    pub fn get_apiregistration_v1api_resources() -> ApiResourceListGetBuilder3 {
+        ApiResourceListGetBuilder3
+    }
+
+    /// get available resources
+    #[inline]
+    pub fn get_apiregistration_v1beta1_api_resources() -> ApiResourceListGetBuilder4 {
+        ApiResourceListGetBuilder4
+    }
+
+    /// get available resources
+    #[inline]
+    pub fn get_apps_v1api_resources() -> ApiResourceListGetBuilder5 {
+        ApiResourceListGetBuilder5
+    }
+
+    /// get available resources
+    #[inline]
+    pub fn get_apps_v1beta1_api_resources() -> ApiResourceListGetBuilder6 {
+        ApiResourceListGetBuilder6
+    }
+
+    /// get available resources
+    #[inline]
+    pub fn get_apps_v1beta2_api_resources() -> ApiResourceListGetBuilder7 {
+        ApiResourceListGetBuilder7
+    }
+
+    /// get available resources
+    #[inline]
+    pub fn get_auditregistration_v1alpha1_api_resources() -> ApiResourceListGetBuilder8 {
+        ApiResourceListGetBuilder8
+    }
+
+    /// get available resources
+    #[inline]
+    pub fn get_authentication_v1api_resources() -> ApiResourceListGetBuilder9 {
+        ApiResourceListGetBuilder9
+    }
+
+    /// get available resources
+    #[inline]
+    pub fn get_authentication_v1beta1_api_resources() -> ApiResourceListGetBuilder10 {
+        ApiResourceListGetBuilder10
+    }
+
+    /// get available resources
+    #[inline]
+    pub fn get_authorization_v1api_resources() -> ApiResourceListGetBuilder11 {
+        ApiResourceListGetBuilder11
+    }
+
+    /// get available resources
+    #[inline]
+    pub fn get_authorization_v1beta1_api_resources() -> ApiResourceListGetBuilder12 {
+        ApiResourceListGetBuilder12
+    }
+
+    /// get available resources
+    #[inline]
+    pub fn get_autoscaling_v1api_resources() -> ApiResourceListGetBuilder13 {
+        ApiResourceListGetBuilder13
+    }
+
+    /// get available resources
+    #[inline]
+    pub fn get_autoscaling_v2beta1_api_resources() -> ApiResourceListGetBuilder14 {
+        ApiResourceListGetBuilder14
+    }
+
+    /// get available resources
+    #[inline]
+    pub fn get_autoscaling_v2beta2_api_resources() -> ApiResourceListGetBuilder15 {
+        ApiResourceListGetBuilder15
+    }
+
+    /// get available resources
+    #[inline]
+    pub fn get_batch_v1api_resources() -> ApiResourceListGetBuilder16 {
+        ApiResourceListGetBuilder16
+    }
+
+    /// get available resources
+    #[inline]
+    pub fn get_batch_v1beta1_api_resources() -> ApiResourceListGetBuilder17 {
+        ApiResourceListGetBuilder17
+    }
+
+    /// get available resources
+    #[inline]
+    pub fn get_batch_v2alpha1_api_resources() -> ApiResourceListGetBuilder18 {
+        ApiResourceListGetBuilder18
+    }
+
+    /// get available resources
+    #[inline]
+    pub fn get_certificates_v1beta1_api_resources() -> ApiResourceListGetBuilder19 {
+        ApiResourceListGetBuilder19
+    }
+
+    /// get available resources
+    #[inline]
+    pub fn get_coordination_v1api_resources() -> ApiResourceListGetBuilder20 {
+        ApiResourceListGetBuilder20
+    }
+
+    /// get available resources
+    #[inline]
+    pub fn get_coordination_v1beta1_api_resources() -> ApiResourceListGetBuilder21 {
+        ApiResourceListGetBuilder21
+    }
+
+    /// get available resources
+    #[inline]
+    pub fn get_events_v1beta1_api_resources() -> ApiResourceListGetBuilder22 {
+        ApiResourceListGetBuilder22
+    }
+
+    /// get available resources
+    #[inline]
+    pub fn get_extensions_v1beta1_api_resources() -> ApiResourceListGetBuilder23 {
+        ApiResourceListGetBuilder23
+    }
+
+    /// get available resources
+    #[inline]
+    pub fn get_networking_v1api_resources() -> ApiResourceListGetBuilder24 {
+        ApiResourceListGetBuilder24
+    }
+
+    /// get available resources
+    #[inline]
+    pub fn get_networking_v1beta1_api_resources() -> ApiResourceListGetBuilder25 {
+        ApiResourceListGetBuilder25
+    }
+
+    /// get available resources
+    #[inline]
+    pub fn get_node_v1alpha1_api_resources() -> ApiResourceListGetBuilder26 {
+        ApiResourceListGetBuilder26
+    }
+
+    /// get available resources
+    #[inline]
+    pub fn get_node_v1beta1_api_resources() -> ApiResourceListGetBuilder27 {
+        ApiResourceListGetBuilder27
+    }
+
+    /// get available resources
+    #[inline]
+    pub fn get_policy_v1beta1_api_resources() -> ApiResourceListGetBuilder28 {
+        ApiResourceListGetBuilder28
+    }
+
+    /// get available resources
+    #[inline]
+    pub fn get_rbac_authorization_v1api_resources() -> ApiResourceListGetBuilder29 {
+        ApiResourceListGetBuilder29
+    }
+
+    /// get available resources
+    #[inline]
+    pub fn get_rbac_authorization_v1alpha1_api_resources() -> ApiResourceListGetBuilder30 {
+        ApiResourceListGetBuilder30
+    }
+
+    /// get available resources
+    #[inline]
+    pub fn get_rbac_authorization_v1beta1_api_resources() -> ApiResourceListGetBuilder31 {
+        ApiResourceListGetBuilder31
+    }
+
+    /// get available resources
+    #[inline]
+    pub fn get_scheduling_v1api_resources() -> ApiResourceListGetBuilder32 {
+        ApiResourceListGetBuilder32
+    }
+
+    /// get available resources
+    #[inline]
+    pub fn get_scheduling_v1alpha1_api_resources() -> ApiResourceListGetBuilder33 {
+        ApiResourceListGetBuilder33
+    }
+
+    /// get available resources
+    #[inline]
+    pub fn get_scheduling_v1beta1_api_resources() -> ApiResourceListGetBuilder34 {
+        ApiResourceListGetBuilder34
+    }
+
+    /// get available resources
+    #[inline]
+    pub fn get_settings_v1alpha1_api_resources() -> ApiResourceListGetBuilder35 {
+        ApiResourceListGetBuilder35
+    }
+
+    /// get available resources
+    #[inline]
+    pub fn get_storage_v1api_resources() -> ApiResourceListGetBuilder36 {
+        ApiResourceListGetBuilder36
+    }
+
+    /// get available resources
+    #[inline]
+    pub fn get_storage_v1alpha1_api_resources() -> ApiResourceListGetBuilder37 {
+        ApiResourceListGetBuilder37
+    }
+
+    /// get available resources
+    #[inline]
+    pub fn get_storage_v1beta1_api_resources() -> ApiResourceListGetBuilder38 {
+        ApiResourceListGetBuilder38
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> ApiResourceListBuilder<crate::generics::GroupVersionExists, crate::generics::ResourcesExists>
+    where
+        Self: Clone,
+    {
+        ApiResourceListBuilder {
+            body: self.clone(),
+            _group_version: core::marker::PhantomData,
+            _resources: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<ApiResourceList> for ApiResourceListBuilder<crate::generics::GroupVersionExists, crate::generics::ResourcesExists> {
+    fn into(self) -> ApiResourceList {
+        self.body
+    }
+}
+
+/// Builder for [`ApiResourceList`](./struct.ApiResourceList.html) object.
+#[derive(Debug, Clone)]
+pub struct ApiResourceListBuilder<GroupVersion, Resources> {
+    body: self::ApiResourceList,
+    _group_version: core::marker::PhantomData<GroupVersion>,
+    _resources: core::marker::PhantomData<Resources>,
+}
+
+impl<GroupVersion, Resources> ApiResourceListBuilder<GroupVersion, Resources> {
+    /// APIVersion defines the versioned schema of this representation of an object. Servers should convert recognized schemas to the latest internal value, and may reject unrecognized values. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#resources
+    #[inline]
+    pub fn api_version(mut self, value: impl Into<String>) -> Self {
+        self.body.api_version = Some(value.into());
+        self
+    }
+
+    /// Sets [`api_version`](#method.api_version) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_api_version(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.api_version = value.map(|value| value.into());
+        self
+    }
+
+    /// groupVersion is the group and version this APIResourceList is for.
+    #[inline]
+    pub fn group_version(mut self, value: impl Into<String>) -> ApiResourceListBuilder<crate::generics::GroupVersionExists, Resources> {
+        self.body.group_version = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Kind is a string value representing the REST resource this object represents. Servers may infer this from the endpoint the client submits requests to. Cannot be updated. In CamelCase. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#types-kinds
+    #[inline]
+    pub fn kind(mut self, value: impl Into<String>) -> Self {
+        self.body.kind = Some(value.into());
+        self
+    }
+
+    /// Sets [`kind`](#method.kind) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_kind(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.kind = value.map(|value| value.into());
+        self
+    }
+
+    /// resources contains the name of the resources and if they are namespaced.
+    #[inline]
+    pub fn resources(mut self, value: impl Iterator<Item = crate::io::k8s::apimachinery::pkg::apis::meta::v1::api_resource::ApiResourceBuilder<crate::generics::KindExists, crate::generics::NameExists, crate::generics::NamespacedExists, crate::generics::SingularNameExists, crate::generics::VerbsExists>>) -> ApiResourceListBuilder<GroupVersion, crate::generics::ResourcesExists> {
+        self.body.resources = value.map(|value| value.into()).collect::<Vec<_>>().into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Same as [`resources`](#method.resources), but takes the values as a `Vec`.
+    #[inline]
+    pub fn resources_vec(mut self, value: impl Into<Vec<crate::io::k8s::apimachinery::pkg::apis::meta::v1::api_resource::ApiResource>>) -> ApiResourceListBuilder<GroupVersion, crate::generics::ResourcesExists> {
+        self.body.resources = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+}
+
+/// Builder created by [`ApiResourceList::get_core_v1api_resources`](./struct.ApiResourceList.html#method.get_core_v1api_resources) method for a `GET` operation associated with `ApiResourceList`.
+#[derive(Debug, Default, Clone)]
+pub struct ApiResourceListGetBuilder;
+
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for ApiResourceListGetBuilder {
+    type Output = ApiResourceList;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    const OPERATION_ID: Option<&'static str> = Some("getCoreV1APIResources");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok("/api/v1/".into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::ACCEPT.as_str(), "application/json"))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<ApiResourceListGetBuilder> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: ApiResourceListGetBuilder) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl ApiResourceListGetBuilder {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = ApiResourceListGetBuilder {
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder created by [`ApiResourceList::get_admissionregistration_v1beta1_api_resources`](./struct.ApiResourceList.html#method.get_admissionregistration_v1beta1_api_resources) method for a `GET` operation associated with `ApiResourceList`.
+#[derive(Debug, Default, Clone)]
+pub struct ApiResourceListGetBuilder1;
+
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for ApiResourceListGetBuilder1 {
+    type Output = ApiResourceList;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    const OPERATION_ID: Option<&'static str> = Some("getAdmissionregistrationV1beta1APIResources");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok("/apis/admissionregistration.k8s.io/v1beta1/".into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::ACCEPT.as_str(), "application/json"))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<ApiResourceListGetBuilder1> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: ApiResourceListGetBuilder1) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl ApiResourceListGetBuilder1 {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = ApiResourceListGetBuilder1 {
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder created by [`ApiResourceList::get_apiextensions_v1beta1_api_resources`](./struct.ApiResourceList.html#method.get_apiextensions_v1beta1_api_resources) method for a `GET` operation associated with `ApiResourceList`.
+#[derive(Debug, Default, Clone)]
+pub struct ApiResourceListGetBuilder2;
+
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for ApiResourceListGetBuilder2 {
+    type Output = ApiResourceList;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    const OPERATION_ID: Option<&'static str> = Some("getApiextensionsV1beta1APIResources");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok("/apis/apiextensions.k8s.io/v1beta1/".into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::ACCEPT.as_str(), "application/json"))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<ApiResourceListGetBuilder2> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: ApiResourceListGetBuilder2) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl ApiResourceListGetBuilder2 {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = ApiResourceListGetBuilder2 {
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder created by [`ApiResourceList::get_apiregistration_v1api_resources`](./struct.ApiResourceList.html#method.get_apiregistration_v1api_resources) method for a `GET` operation associated with `ApiResourceList`.
+#[derive(Debug, Default, Clone)]
+pub struct ApiResourceListGetBuilder3;
+
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for ApiResourceListGetBuilder3 {
+    type Output = ApiResourceList;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    const OPERATION_ID: Option<&'static str> = Some("getApiregistrationV1APIResources");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok("/apis/apiregistration.k8s.io/v1/".into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::ACCEPT.as_str(), "application/json"))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<ApiResourceListGetBuilder3> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: ApiResourceListGetBuilder3) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl ApiResourceListGetBuilder3 {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = ApiResourceListGetBuilder3 {
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder created by [`ApiResourceList::get_apiregistration_v1beta1_api_resources`](./struct.ApiResourceList.html#method.get_apiregistration_v1beta1_api_resources) method for a `GET` operation associated with `ApiResourceList`.
+#[derive(Debug, Default, Clone)]
+pub struct ApiResourceListGetBuilder4;
+
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for ApiResourceListGetBuilder4 {
+    type Output = ApiResourceList;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    const OPERATION_ID: Option<&'static str> = Some("getApiregistrationV1beta1APIResources");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok("/apis/apiregistration.k8s.io/v1beta1/".into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::ACCEPT.as_str(), "application/json"))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<ApiResourceListGetBuilder4> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: ApiResourceListGetBuilder4) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl ApiResourceListGetBuilder4 {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = ApiResourceListGetBuilder4 {
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder created by [`ApiResourceList::get_apps_v1api_resources`](./struct.ApiResourceList.html#method.get_apps_v1api_resources) method for a `GET` operation associated with `ApiResourceList`.
+#[derive(Debug, Default, Clone)]
+pub struct ApiResourceListGetBuilder5;
+
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for ApiResourceListGetBuilder5 {
+    type Output = ApiResourceList;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    const OPERATION_ID: Option<&'static str> = Some("getAppsV1APIResources");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok("/apis/apps/v1/".into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::ACCEPT.as_str(), "application/json"))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<ApiResourceListGetBuilder5> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: ApiResourceListGetBuilder5) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl ApiResourceListGetBuilder5 {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = ApiResourceListGetBuilder5 {
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder created by [`ApiResourceList::get_apps_v1beta1_api_resources`](./struct.ApiResourceList.html#method.get_apps_v1beta1_api_resources) method for a `GET` operation associated with `ApiResourceList`.
+#[derive(Debug, Default, Clone)]
+pub struct ApiResourceListGetBuilder6;
+
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for ApiResourceListGetBuilder6 {
+    type Output = ApiResourceList;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    const OPERATION_ID: Option<&'static str> = Some("getAppsV1beta1APIResources");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok("/apis/apps/v1beta1/".into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::ACCEPT.as_str(), "application/json"))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<ApiResourceListGetBuilder6> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: ApiResourceListGetBuilder6) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl ApiResourceListGetBuilder6 {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = ApiResourceListGetBuilder6 {
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder created by [`ApiResourceList::get_apps_v1beta2_api_resources`](./struct.ApiResourceList.html#method.get_apps_v1beta2_api_resources) method for a `GET` operation associated with `ApiResourceList`.
+#[derive(Debug, Default, Clone)]
+pub struct ApiResourceListGetBuilder7;
+
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for ApiResourceListGetBuilder7 {
+    type Output = ApiResourceList;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    const OPERATION_ID: Option<&'static str> = Some("getAppsV1beta2APIResources");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok("/apis/apps/v1beta2/".into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::ACCEPT.as_str(), "application/json"))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<ApiResourceListGetBuilder7> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: ApiResourceListGetBuilder7) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl ApiResourceListGetBuilder7 {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = ApiResourceListGetBuilder7 {
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder created by [`ApiResourceList::get_auditregistration_v1alpha1_api_resources`](./struct.ApiResourceList.html#method.get_auditregistration_v1alpha1_api_resources) method for a `GET` operation associated with `ApiResourceList`.
+#[derive(Debug, Default, Clone)]
+pub struct ApiResourceListGetBuilder8;
+
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for ApiResourceListGetBuilder8 {
+    type Output = ApiResourceList;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    const OPERATION_ID: Option<&'static str> = Some("getAuditregistrationV1alpha1APIResources");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok("/apis/auditregistration.k8s.io/v1alpha1/".into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::ACCEPT.as_str(), "application/json"))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<ApiResourceListGetBuilder8> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: ApiResourceListGetBuilder8) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl ApiResourceListGetBuilder8 {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = ApiResourceListGetBuilder8 {
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder created by [`ApiResourceList::get_authentication_v1api_resources`](./struct.ApiResourceList.html#method.get_authentication_v1api_resources) method for a `GET` operation associated with `ApiResourceList`.
+#[derive(Debug, Default, Clone)]
+pub struct ApiResourceListGetBuilder9;
+
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for ApiResourceListGetBuilder9 {
+    type Output = ApiResourceList;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    const OPERATION_ID: Option<&'static str> = Some("getAuthenticationV1APIResources");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok("/apis/authentication.k8s.io/v1/".into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::ACCEPT.as_str(), "application/json"))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<ApiResourceListGetBuilder9> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: ApiResourceListGetBuilder9) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl ApiResourceListGetBuilder9 {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = ApiResourceListGetBuilder9 {
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder created by [`ApiResourceList::get_authentication_v1beta1_api_resources`](./struct.ApiResourceList.html#method.get_authentication_v1beta1_api_resources) method for a `GET` operation associated with `ApiResourceList`.
+#[derive(Debug, Default, Clone)]
+pub struct ApiResourceListGetBuilder10;
+
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for ApiResourceListGetBuilder10 {
+    type Output = ApiResourceList;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    const OPERATION_ID: Option<&'static str> = Some("getAuthenticationV1beta1APIResources");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok("/apis/authentication.k8s.io/v1beta1/".into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::ACCEPT.as_str(), "application/json"))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<ApiResourceListGetBuilder10> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: ApiResourceListGetBuilder10) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl ApiResourceListGetBuilder10 {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = ApiResourceListGetBuilder10 {
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder created by [`ApiResourceList::get_authorization_v1api_resources`](./struct.ApiResourceList.html#method.get_authorization_v1api_resources) method for a `GET` operation associated with `ApiResourceList`.
+#[derive(Debug, Default, Clone)]
+pub struct ApiResourceListGetBuilder11;
+
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for ApiResourceListGetBuilder11 {
+    type Output = ApiResourceList;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    const OPERATION_ID: Option<&'static str> = Some("getAuthorizationV1APIResources");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok("/apis/authorization.k8s.io/v1/".into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::ACCEPT.as_str(), "application/json"))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<ApiResourceListGetBuilder11> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: ApiResourceListGetBuilder11) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl ApiResourceListGetBuilder11 {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = ApiResourceListGetBuilder11 {
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder created by [`ApiResourceList::get_authorization_v1beta1_api_resources`](./struct.ApiResourceList.html#method.get_authorization_v1beta1_api_resources) method for a `GET` operation associated with `ApiResourceList`.
+#[derive(Debug, Default, Clone)]
+pub struct ApiResourceListGetBuilder12;
+
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for ApiResourceListGetBuilder12 {
+    type Output = ApiResourceList;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    const OPERATION_ID: Option<&'static str> = Some("getAuthorizationV1beta1APIResources");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok("/apis/authorization.k8s.io/v1beta1/".into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::ACCEPT.as_str(), "application/json"))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<ApiResourceListGetBuilder12> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: ApiResourceListGetBuilder12) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl ApiResourceListGetBuilder12 {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = ApiResourceListGetBuilder12 {
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder created by [`ApiResourceList::get_autoscaling_v1api_resources`](./struct.ApiResourceList.html#method.get_autoscaling_v1api_resources) method for a `GET` operation associated with `ApiResourceList`.
+#[derive(Debug, Default, Clone)]
+pub struct ApiResourceListGetBuilder13;
+
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for ApiResourceListGetBuilder13 {
+    type Output = ApiResourceList;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    const OPERATION_ID: Option<&'static str> = Some("getAutoscalingV1APIResources");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok("/apis/autoscaling/v1/".into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::ACCEPT.as_str(), "application/json"))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<ApiResourceListGetBuilder13> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: ApiResourceListGetBuilder13) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl ApiResourceListGetBuilder13 {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = ApiResourceListGetBuilder13 {
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder created by [`ApiResourceList::get_autoscaling_v2beta1_api_resources`](./struct.ApiResourceList.html#method.get_autoscaling_v2beta1_api_resources) method for a `GET` operation associated with `ApiResourceList`.
+#[derive(Debug, Default, Clone)]
+pub struct ApiResourceListGetBuilder14;
+
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for ApiResourceListGetBuilder14 {
+    type Output = ApiResourceList;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    const OPERATION_ID: Option<&'static str> = Some("getAutoscalingV2beta1APIResources");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok("/apis/autoscaling/v2beta1/".into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::ACCEPT.as_str(), "application/json"))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<ApiResourceListGetBuilder14> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: ApiResourceListGetBuilder14) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl ApiResourceListGetBuilder14 {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = ApiResourceListGetBuilder14 {
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder created by [`ApiResourceList::get_autoscaling_v2beta2_api_resources`](./struct.ApiResourceList.html#method.get_autoscaling_v2beta2_api_resources) method for a `GET` operation associated with `ApiResourceList`.
+#[derive(Debug, Default, Clone)]
+pub struct ApiResourceListGetBuilder15;
+
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for ApiResourceListGetBuilder15 {
+    type Output = ApiResourceList;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    const OPERATION_ID: Option<&'static str> = Some("getAutoscalingV2beta2APIResources");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok("/apis/autoscaling/v2beta2/".into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::ACCEPT.as_str(), "application/json"))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<ApiResourceListGetBuilder15> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: ApiResourceListGetBuilder15) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl ApiResourceListGetBuilder15 {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = ApiResourceListGetBuilder15 {
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder created by [`ApiResourceList::get_batch_v1api_resources`](./struct.ApiResourceList.html#method.get_batch_v1api_resources) method for a `GET` operation associated with `ApiResourceList`.
+#[derive(Debug, Default, Clone)]
+pub struct ApiResourceListGetBuilder16;
+
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for ApiResourceListGetBuilder16 {
+    type Output = ApiResourceList;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    const OPERATION_ID: Option<&'static str> = Some("getBatchV1APIResources");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok("/apis/batch/v1/".into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::ACCEPT.as_str(), "application/json"))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<ApiResourceListGetBuilder16> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: ApiResourceListGetBuilder16) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl ApiResourceListGetBuilder16 {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = ApiResourceListGetBuilder16 {
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder created by [`ApiResourceList::get_batch_v1beta1_api_resources`](./struct.ApiResourceList.html#method.get_batch_v1beta1_api_resources) method for a `GET` operation associated with `ApiResourceList`.
+#[derive(Debug, Default, Clone)]
+pub struct ApiResourceListGetBuilder17;
+
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for ApiResourceListGetBuilder17 {
+    type Output = ApiResourceList;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    const OPERATION_ID: Option<&'static str> = Some("getBatchV1beta1APIResources");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok("/apis/batch/v1beta1/".into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::ACCEPT.as_str(), "application/json"))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<ApiResourceListGetBuilder17> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: ApiResourceListGetBuilder17) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl ApiResourceListGetBuilder17 {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = ApiResourceListGetBuilder17 {
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder created by [`ApiResourceList::get_batch_v2alpha1_api_resources`](./struct.ApiResourceList.html#method.get_batch_v2alpha1_api_resources) method for a `GET` operation associated with `ApiResourceList`.
+#[derive(Debug, Default, Clone)]
+pub struct ApiResourceListGetBuilder18;
+
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for ApiResourceListGetBuilder18 {
+    type Output = ApiResourceList;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    const OPERATION_ID: Option<&'static str> = Some("getBatchV2alpha1APIResources");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok("/apis/batch/v2alpha1/".into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::ACCEPT.as_str(), "application/json"))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<ApiResourceListGetBuilder18> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: ApiResourceListGetBuilder18) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl ApiResourceListGetBuilder18 {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = ApiResourceListGetBuilder18 {
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder created by [`ApiResourceList::get_certificates_v1beta1_api_resources`](./struct.ApiResourceList.html#method.get_certificates_v1beta1_api_resources) method for a `GET` operation associated with `ApiResourceList`.
+#[derive(Debug, Default, Clone)]
+pub struct ApiResourceListGetBuilder19;
+
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for ApiResourceListGetBuilder19 {
+    type Output = ApiResourceList;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    const OPERATION_ID: Option<&'static str> = Some("getCertificatesV1beta1APIResources");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok("/apis/certificates.k8s.io/v1beta1/".into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::ACCEPT.as_str(), "application/json"))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<ApiResourceListGetBuilder19> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: ApiResourceListGetBuilder19) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl ApiResourceListGetBuilder19 {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = ApiResourceListGetBuilder19 {
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder created by [`ApiResourceList::get_coordination_v1api_resources`](./struct.ApiResourceList.html#method.get_coordination_v1api_resources) method for a `GET` operation associated with `ApiResourceList`.
+#[derive(Debug, Default, Clone)]
+pub struct ApiResourceListGetBuilder20;
+
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for ApiResourceListGetBuilder20 {
+    type Output = ApiResourceList;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    const OPERATION_ID: Option<&'static str> = Some("getCoordinationV1APIResources");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok("/apis/coordination.k8s.io/v1/".into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::ACCEPT.as_str(), "application/json"))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<ApiResourceListGetBuilder20> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: ApiResourceListGetBuilder20) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl ApiResourceListGetBuilder20 {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = ApiResourceListGetBuilder20 {
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder created by [`ApiResourceList::get_coordination_v1beta1_api_resources`](./struct.ApiResourceList.html#method.get_coordination_v1beta1_api_resources) method for a `GET` operation associated with `ApiResourceList`.
+#[derive(Debug, Default, Clone)]
+pub struct ApiResourceListGetBuilder21;
+
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for ApiResourceListGetBuilder21 {
+    type Output = ApiResourceList;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    const OPERATION_ID: Option<&'static str> = Some("getCoordinationV1beta1APIResources");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok("/apis/coordination.k8s.io/v1beta1/".into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::ACCEPT.as_str(), "application/json"))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<ApiResourceListGetBuilder21> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: ApiResourceListGetBuilder21) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl ApiResourceListGetBuilder21 {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = ApiResourceListGetBuilder21 {
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder created by [`ApiResourceList::get_events_v1beta1_api_resources`](./struct.ApiResourceList.html#method.get_events_v1beta1_api_resources) method for a `GET` operation associated with `ApiResourceList`.
+#[derive(Debug, Default, Clone)]
+pub struct ApiResourceListGetBuilder22;
+
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for ApiResourceListGetBuilder22 {
+    type Output = ApiResourceList;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    const OPERATION_ID: Option<&'static str> = Some("getEventsV1beta1APIResources");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok("/apis/events.k8s.io/v1beta1/".into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::ACCEPT.as_str(), "application/json"))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<ApiResourceListGetBuilder22> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: ApiResourceListGetBuilder22) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl ApiResourceListGetBuilder22 {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = ApiResourceListGetBuilder22 {
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder created by [`ApiResourceList::get_extensions_v1beta1_api_resources`](./struct.ApiResourceList.html#method.get_extensions_v1beta1_api_resources) method for a `GET` operation associated with `ApiResourceList`.
+#[derive(Debug, Default, Clone)]
+pub struct ApiResourceListGetBuilder23;
+
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for ApiResourceListGetBuilder23 {
+    type Output = ApiResourceList;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    const OPERATION_ID: Option<&'static str> = Some("getExtensionsV1beta1APIResources");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok("/apis/extensions/v1beta1/".into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::ACCEPT.as_str(), "application/json"))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<ApiResourceListGetBuilder23> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: ApiResourceListGetBuilder23) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl ApiResourceListGetBuilder23 {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = ApiResourceListGetBuilder23 {
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder created by [`ApiResourceList::get_networking_v1api_resources`](./struct.ApiResourceList.html#method.get_networking_v1api_resources) method for a `GET` operation associated with `ApiResourceList`.
+#[derive(Debug, Default, Clone)]
+pub struct ApiResourceListGetBuilder24;
+
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for ApiResourceListGetBuilder24 {
+    type Output = ApiResourceList;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    const OPERATION_ID: Option<&'static str> = Some("getNetworkingV1APIResources");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok("/apis/networking.k8s.io/v1/".into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::ACCEPT.as_str(), "application/json"))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<ApiResourceListGetBuilder24> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: ApiResourceListGetBuilder24) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl ApiResourceListGetBuilder24 {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = ApiResourceListGetBuilder24 {
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder created by [`ApiResourceList::get_networking_v1beta1_api_resources`](./struct.ApiResourceList.html#method.get_networking_v1beta1_api_resources) method for a `GET` operation associated with `ApiResourceList`.
+#[derive(Debug, Default, Clone)]
+pub struct ApiResourceListGetBuilder25;
+
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for ApiResourceListGetBuilder25 {
+    type Output = ApiResourceList;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    const OPERATION_ID: Option<&'static str> = Some("getNetworkingV1beta1APIResources");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok("/apis/networking.k8s.io/v1beta1/".into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::ACCEPT.as_str(), "application/json"))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<ApiResourceListGetBuilder25> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: ApiResourceListGetBuilder25) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl ApiResourceListGetBuilder25 {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = ApiResourceListGetBuilder25 {
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder created by [`ApiResourceList::get_node_v1alpha1_api_resources`](./struct.ApiResourceList.html#method.get_node_v1alpha1_api_resources) method for a `GET` operation associated with `ApiResourceList`.
+#[derive(Debug, Default, Clone)]
+pub struct ApiResourceListGetBuilder26;
+
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for ApiResourceListGetBuilder26 {
+    type Output = ApiResourceList;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    const OPERATION_ID: Option<&'static str> = Some("getNodeV1alpha1APIResources");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok("/apis/node.k8s.io/v1alpha1/".into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::ACCEPT.as_str(), "application/json"))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<ApiResourceListGetBuilder26> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: ApiResourceListGetBuilder26) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl ApiResourceListGetBuilder26 {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = ApiResourceListGetBuilder26 {
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder created by [`ApiResourceList::get_node_v1beta1_api_resources`](./struct.ApiResourceList.html#method.get_node_v1beta1_api_resources) method for a `GET` operation associated with `ApiResourceList`.
+#[derive(Debug, Default, Clone)]
+pub struct ApiResourceListGetBuilder27;
+
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for ApiResourceListGetBuilder27 {
+    type Output = ApiResourceList;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    const OPERATION_ID: Option<&'static str> = Some("getNodeV1beta1APIResources");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok("/apis/node.k8s.io/v1beta1/".into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::ACCEPT.as_str(), "application/json"))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<ApiResourceListGetBuilder27> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: ApiResourceListGetBuilder27) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl ApiResourceListGetBuilder27 {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = ApiResourceListGetBuilder27 {
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder created by [`ApiResourceList::get_policy_v1beta1_api_resources`](./struct.ApiResourceList.html#method.get_policy_v1beta1_api_resources) method for a `GET` operation associated with `ApiResourceList`.
+#[derive(Debug, Default, Clone)]
+pub struct ApiResourceListGetBuilder28;
+
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for ApiResourceListGetBuilder28 {
+    type Output = ApiResourceList;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    const OPERATION_ID: Option<&'static str> = Some("getPolicyV1beta1APIResources");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok("/apis/policy/v1beta1/".into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::ACCEPT.as_str(), "application/json"))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<ApiResourceListGetBuilder28> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: ApiResourceListGetBuilder28) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl ApiResourceListGetBuilder28 {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = ApiResourceListGetBuilder28 {
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder created by [`ApiResourceList::get_rbac_authorization_v1api_resources`](./struct.ApiResourceList.html#method.get_rbac_authorization_v1api_resources) method for a `GET` operation associated with `ApiResourceList`.
+#[derive(Debug, Default, Clone)]
+pub struct ApiResourceListGetBuilder29;
+
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for ApiResourceListGetBuilder29 {
+    type Output = ApiResourceList;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    const OPERATION_ID: Option<&'static str> = Some("getRbacAuthorizationV1APIResources");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok("/apis/rbac.authorization.k8s.io/v1/".into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::ACCEPT.as_str(), "application/json"))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<ApiResourceListGetBuilder29> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: ApiResourceListGetBuilder29) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl ApiResourceListGetBuilder29 {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = ApiResourceListGetBuilder29 {
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder created by [`ApiResourceList::get_rbac_authorization_v1alpha1_api_resources`](./struct.ApiResourceList.html#method.get_rbac_authorization_v1alpha1_api_resources) method for a `GET` operation associated with `ApiResourceList`.
+#[derive(Debug, Default, Clone)]
+pub struct ApiResourceListGetBuilder30;
+
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for ApiResourceListGetBuilder30 {
+    type Output = ApiResourceList;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    const OPERATION_ID: Option<&'static str> = Some("getRbacAuthorizationV1alpha1APIResources");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok("/apis/rbac.authorization.k8s.io/v1alpha1/".into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::ACCEPT.as_str(), "application/json"))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<ApiResourceListGetBuilder30> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: ApiResourceListGetBuilder30) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl ApiResourceListGetBuilder30 {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = ApiResourceListGetBuilder30 {
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder created by [`ApiResourceList::get_rbac_authorization_v1beta1_api_resources`](./struct.ApiResourceList.html#method.get_rbac_authorization_v1beta1_api_resources) method for a `GET` operation associated with `ApiResourceList`.
+#[derive(Debug, Default, Clone)]
+pub struct ApiResourceListGetBuilder31;
+
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for ApiResourceListGetBuilder31 {
+    type Output = ApiResourceList;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    const OPERATION_ID: Option<&'static str> = Some("getRbacAuthorizationV1beta1APIResources");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok("/apis/rbac.authorization.k8s.io/v1beta1/".into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::ACCEPT.as_str(), "application/json"))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<ApiResourceListGetBuilder31> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: ApiResourceListGetBuilder31) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl ApiResourceListGetBuilder31 {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = ApiResourceListGetBuilder31 {
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder created by [`ApiResourceList::get_scheduling_v1api_resources`](./struct.ApiResourceList.html#method.get_scheduling_v1api_resources) method for a `GET` operation associated with `ApiResourceList`.
+#[derive(Debug, Default, Clone)]
+pub struct ApiResourceListGetBuilder32;
+
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for ApiResourceListGetBuilder32 {
+    type Output = ApiResourceList;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    const OPERATION_ID: Option<&'static str> = Some("getSchedulingV1APIResources");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok("/apis/scheduling.k8s.io/v1/".into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::ACCEPT.as_str(), "application/json"))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<ApiResourceListGetBuilder32> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: ApiResourceListGetBuilder32) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl ApiResourceListGetBuilder32 {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = ApiResourceListGetBuilder32 {
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder created by [`ApiResourceList::get_scheduling_v1alpha1_api_resources`](./struct.ApiResourceList.html#method.get_scheduling_v1alpha1_api_resources) method for a `GET` operation associated with `ApiResourceList`.
+#[derive(Debug, Default, Clone)]
+pub struct ApiResourceListGetBuilder33;
+
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for ApiResourceListGetBuilder33 {
+    type Output = ApiResourceList;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    const OPERATION_ID: Option<&'static str> = Some("getSchedulingV1alpha1APIResources");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok("/apis/scheduling.k8s.io/v1alpha1/".into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::ACCEPT.as_str(), "application/json"))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<ApiResourceListGetBuilder33> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: ApiResourceListGetBuilder33) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl ApiResourceListGetBuilder33 {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = ApiResourceListGetBuilder33 {
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder created by [`ApiResourceList::get_scheduling_v1beta1_api_resources`](./struct.ApiResourceList.html#method.get_scheduling_v1beta1_api_resources) method for a `GET` operation associated with `ApiResourceList`.
+#[derive(Debug, Default, Clone)]
+pub struct ApiResourceListGetBuilder34;
+
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for ApiResourceListGetBuilder34 {
+    type Output = ApiResourceList;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    const OPERATION_ID: Option<&'static str> = Some("getSchedulingV1beta1APIResources");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok("/apis/scheduling.k8s.io/v1beta1/".into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::ACCEPT.as_str(), "application/json"))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<ApiResourceListGetBuilder34> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: ApiResourceListGetBuilder34) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl ApiResourceListGetBuilder34 {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = ApiResourceListGetBuilder34 {
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder created by [`ApiResourceList::get_settings_v1alpha1_api_resources`](./struct.ApiResourceList.html#method.get_settings_v1alpha1_api_resources) method for a `GET` operation associated with `ApiResourceList`.
+#[derive(Debug, Default, Clone)]
+pub struct ApiResourceListGetBuilder35;
+
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for ApiResourceListGetBuilder35 {
+    type Output = ApiResourceList;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    const OPERATION_ID: Option<&'static str> = Some("getSettingsV1alpha1APIResources");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok("/apis/settings.k8s.io/v1alpha1/".into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::ACCEPT.as_str(), "application/json"))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<ApiResourceListGetBuilder35> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: ApiResourceListGetBuilder35) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl ApiResourceListGetBuilder35 {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = ApiResourceListGetBuilder35 {
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder created by [`ApiResourceList::get_storage_v1api_resources`](./struct.ApiResourceList.html#method.get_storage_v1api_resources) method for a `GET` operation associated with `ApiResourceList`.
+#[derive(Debug, Default, Clone)]
+pub struct ApiResourceListGetBuilder36;
+
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for ApiResourceListGetBuilder36 {
+    type Output = ApiResourceList;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    const OPERATION_ID: Option<&'static str> = Some("getStorageV1APIResources");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok("/apis/storage.k8s.io/v1/".into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::ACCEPT.as_str(), "application/json"))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<ApiResourceListGetBuilder36> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: ApiResourceListGetBuilder36) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl ApiResourceListGetBuilder36 {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = ApiResourceListGetBuilder36 {
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder created by [`ApiResourceList::get_storage_v1alpha1_api_resources`](./struct.ApiResourceList.html#method.get_storage_v1alpha1_api_resources) method for a `GET` operation associated with `ApiResourceList`.
+#[derive(Debug, Default, Clone)]
+pub struct ApiResourceListGetBuilder37;
+
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for ApiResourceListGetBuilder37 {
+    type Output = ApiResourceList;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    const OPERATION_ID: Option<&'static str> = Some("getStorageV1alpha1APIResources");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok("/apis/storage.k8s.io/v1alpha1/".into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::ACCEPT.as_str(), "application/json"))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<ApiResourceListGetBuilder37> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: ApiResourceListGetBuilder37) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl ApiResourceListGetBuilder37 {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = ApiResourceListGetBuilder37 {
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder created by [`ApiResourceList::get_storage_v1beta1_api_resources`](./struct.ApiResourceList.html#method.get_storage_v1beta1_api_resources) method for a `GET` operation associated with `ApiResourceList`.
+#[derive(Debug, Default, Clone)]
+pub struct ApiResourceListGetBuilder38;
+
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for ApiResourceListGetBuilder38 {
+    type Output = ApiResourceList;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    const OPERATION_ID: Option<&'static str> = Some("getStorageV1beta1APIResources");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok("/apis/storage.k8s.io/v1beta1/".into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::ACCEPT.as_str(), "application/json"))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<ApiResourceListGetBuilder38> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: ApiResourceListGetBuilder38) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl ApiResourceListGetBuilder38 {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = ApiResourceListGetBuilder38 {
+        };
+
+        Ok(thing)
+    }
+}
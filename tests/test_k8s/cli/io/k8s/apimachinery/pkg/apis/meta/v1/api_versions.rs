@@ -0,0 +1,167 @@
+
+/// APIVersions lists the versions that are available, to allow clients to discover the API at /api, which is the root path of the legacy v1 API.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct ApiVersions {
+    /// APIVersion defines the versioned schema of this representation of an object. Servers should convert recognized schemas to the latest internal value, and may reject unrecognized values. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#resources
+    #[serde(rename = "apiVersion", skip_serializing_if = "Option::is_none")]
+    pub api_version: Option<String>,
+    /// Kind is a string value representing the REST resource this object represents. Servers may infer this from the endpoint the client submits requests to. Cannot be updated. In CamelCase. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#types-kinds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub kind: Option<String>,
+    /// a map of client CIDR to server address that is serving this group. This is to help clients reach servers in the most network-efficient way possible. Clients can use the appropriate server address as per the CIDR that they match. In case of multiple matches, clients should use the longest matching CIDR. The server returns only those CIDRs that it thinks that the client can match. For example: the master will return an internal IP CIDR only, if the client reaches the server using an internal IP. Server looks at X-Forwarded-For header or X-Real-Ip header or request.RemoteAddr (in that order) to get the client IP.
+    #[serde(rename = "serverAddressByClientCIDRs")]
+    pub server_address_by_client_cid_rs: Vec<crate::io::k8s::apimachinery::pkg::apis::meta::v1::server_address_by_client_cidr::ServerAddressByClientCidr>,
+    /// versions are the api versions that are available.
+    pub versions: Vec<String>,
+}
+
+impl ApiVersions {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> ApiVersionsBuilder<crate::generics::MissingServerAddressByClientCidRs, crate::generics::MissingVersions> {
+        ApiVersionsBuilder {
+            body: Default::default(),
+            _server_address_by_client_cid_rs: core::marker::PhantomData,
+            _versions: core::marker::PhantomData,
+        }
+    }
+
+    /// get available API versions
+    #[inline]
+    pub fn get_core_api_versions() -> ApiVersionsGetBuilder {
+        ApiVersionsGetBuilder
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> ApiVersionsBuilder<crate::generics::ServerAddressByClientCidRsExists, crate::generics::VersionsExists>
+    where
+        Self: Clone,
+    {
+        ApiVersionsBuilder {
+            body: self.clone(),
+            _server_address_by_client_cid_rs: core::marker::PhantomData,
+            _versions: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<ApiVersions> for ApiVersionsBuilder<crate::generics::ServerAddressByClientCidRsExists, crate::generics::VersionsExists> {
+    fn into(self) -> ApiVersions {
+        self.body
+    }
+}
+
+/// Builder for [`ApiVersions`](./struct.ApiVersions.html) object.
+#[derive(Debug, Clone)]
+pub struct ApiVersionsBuilder<ServerAddressByClientCidRs, Versions> {
+    body: self::ApiVersions,
+    _server_address_by_client_cid_rs: core::marker::PhantomData<ServerAddressByClientCidRs>,
+    _versions: core::marker::PhantomData<Versions>,
+}
+
+impl<ServerAddressByClientCidRs, Versions> ApiVersionsBuilder<ServerAddressByClientCidRs, Versions> {
+    /// APIVersion defines the versioned schema of this representation of an object. Servers should convert recognized schemas to the latest internal value, and may reject unrecognized values. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#resources
+    #[inline]
+    pub fn api_version(mut self, value: impl Into<String>) -> Self {
+        self.body.api_version = Some(value.into());
+        self
+    }
+
+    /// Sets [`api_version`](#method.api_version) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_api_version(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.api_version = value.map(|value| value.into());
+        self
+    }
+
+    /// Kind is a string value representing the REST resource this object represents. Servers may infer this from the endpoint the client submits requests to. Cannot be updated. In CamelCase. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#types-kinds
+    #[inline]
+    pub fn kind(mut self, value: impl Into<String>) -> Self {
+        self.body.kind = Some(value.into());
+        self
+    }
+
+    /// Sets [`kind`](#method.kind) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_kind(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.kind = value.map(|value| value.into());
+        self
+    }
+
+    /// a map of client CIDR to server address that is serving this group. This is to help clients reach servers in the most network-efficient way possible. Clients can use the appropriate server address as per the CIDR that they match. In case of multiple matches, clients should use the longest matching CIDR. The server returns only those CIDRs that it thinks that the client can match. For example: the master will return an internal IP CIDR only, if the client reaches the server using an internal IP. Server looks at X-Forwarded-For header or X-Real-Ip header or request.RemoteAddr (in that order) to get the client IP.
+    #[inline]
+    pub fn server_address_by_client_cid_rs(mut self, value: impl Iterator<Item = crate::io::k8s::apimachinery::pkg::apis::meta::v1::server_address_by_client_cidr::ServerAddressByClientCidrBuilder<crate::generics::ClientCidrExists, crate::generics::ServerAddressExists>>) -> ApiVersionsBuilder<crate::generics::ServerAddressByClientCidRsExists, Versions> {
+        self.body.server_address_by_client_cid_rs = value.map(|value| value.into()).collect::<Vec<_>>().into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Same as [`server_address_by_client_cid_rs`](#method.server_address_by_client_cid_rs), but takes the values as a `Vec`.
+    #[inline]
+    pub fn server_address_by_client_cid_rs_vec(mut self, value: impl Into<Vec<crate::io::k8s::apimachinery::pkg::apis::meta::v1::server_address_by_client_cidr::ServerAddressByClientCidr>>) -> ApiVersionsBuilder<crate::generics::ServerAddressByClientCidRsExists, Versions> {
+        self.body.server_address_by_client_cid_rs = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// versions are the api versions that are available.
+    #[inline]
+    pub fn versions(mut self, value: impl Iterator<Item = impl Into<String>>) -> ApiVersionsBuilder<ServerAddressByClientCidRs, crate::generics::VersionsExists> {
+        self.body.versions = value.map(|value| value.into()).collect::<Vec<_>>().into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Same as [`versions`](#method.versions), but takes the values as a `Vec`.
+    #[inline]
+    pub fn versions_vec(mut self, value: impl Into<Vec<String>>) -> ApiVersionsBuilder<ServerAddressByClientCidRs, crate::generics::VersionsExists> {
+        self.body.versions = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+}
+
+/// Builder created by [`ApiVersions::get_core_api_versions`](./struct.ApiVersions.html#method.get_core_api_versions) method for a `GET` operation associated with `ApiVersions`.
+#[derive(Debug, Default, Clone)]
+pub struct ApiVersionsGetBuilder;
+
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for ApiVersionsGetBuilder {
+    type Output = ApiVersions;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    const OPERATION_ID: Option<&'static str> = Some("getCoreAPIVersions");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok("/api/".into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::ACCEPT.as_str(), "application/json"))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<ApiVersionsGetBuilder> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: ApiVersionsGetBuilder) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl ApiVersionsGetBuilder {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = ApiVersionsGetBuilder {
+        };
+
+        Ok(thing)
+    }
+}
@@ -0,0 +1,16330 @@
+
+/// DeleteOptions may be provided when deleting an API object.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct DeleteOptions {
+    /// APIVersion defines the versioned schema of this representation of an object. Servers should convert recognized schemas to the latest internal value, and may reject unrecognized values. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#resources
+    #[serde(rename = "apiVersion", skip_serializing_if = "Option::is_none")]
+    pub api_version: Option<String>,
+    /// When present, indicates that modifications should not be persisted. An invalid or unrecognized dryRun directive will result in an error response and no further processing of the request. Valid values are: - All: all dry run stages will be processed
+    #[serde(rename = "dryRun", skip_serializing_if = "Option::is_none")]
+    pub dry_run: Option<Vec<String>>,
+    /// The duration in seconds before the object should be deleted. Value must be non-negative integer. The value zero indicates delete immediately. If this value is nil, the default grace period for the specified type will be used. Defaults to a per object value if not specified. zero means delete immediately.
+    #[serde(rename = "gracePeriodSeconds", skip_serializing_if = "Option::is_none")]
+    pub grace_period_seconds: Option<i64>,
+    /// Kind is a string value representing the REST resource this object represents. Servers may infer this from the endpoint the client submits requests to. Cannot be updated. In CamelCase. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#types-kinds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub kind: Option<String>,
+    /// Deprecated: please use the PropagationPolicy, this field will be deprecated in 1.7. Should the dependent objects be orphaned. If true/false, the "orphan" finalizer will be added to/removed from the object's finalizers list. Either this field or PropagationPolicy may be set, but not both.
+    #[serde(rename = "orphanDependents", skip_serializing_if = "Option::is_none")]
+    pub orphan_dependents: Option<bool>,
+    /// Must be fulfilled before a deletion is carried out. If not possible, a 409 Conflict status will be returned.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub preconditions: Option<crate::io::k8s::apimachinery::pkg::apis::meta::v1::preconditions::Preconditions>,
+    /// Whether and how garbage collection will be performed. Either this field or OrphanDependents may be set, but not both. The default policy is decided by the existing finalizer set in the metadata.finalizers and the resource-specific default policy. Acceptable values are: 'Orphan' - orphan the dependents; 'Background' - allow the garbage collector to delete the dependents in the background; 'Foreground' - a cascading policy that deletes all dependents in the foreground.
+    #[serde(rename = "propagationPolicy", skip_serializing_if = "Option::is_none")]
+    pub propagation_policy: Option<String>,
+}
+
+impl DeleteOptions {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> DeleteOptionsBuilder {
+        DeleteOptionsBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// delete a ConfigMap
+    #[inline]
+    pub fn delete_core_v1_namespaced_config_map() -> DeleteOptionsDeleteBuilder<crate::generics::MissingName, crate::generics::MissingNamespace> {
+        DeleteOptionsDeleteBuilder {
+            inner: Default::default(),
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        }
+    }
+
+    /// delete Endpoints
+    #[inline]
+    pub fn delete_core_v1_namespaced_endpoints() -> DeleteOptionsDeleteBuilder1<crate::generics::MissingName, crate::generics::MissingNamespace> {
+        DeleteOptionsDeleteBuilder1 {
+            inner: Default::default(),
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        }
+    }
+
+    /// delete an Event
+    #[inline]
+    pub fn delete_core_v1_namespaced_event() -> DeleteOptionsDeleteBuilder2<crate::generics::MissingName, crate::generics::MissingNamespace> {
+        DeleteOptionsDeleteBuilder2 {
+            inner: Default::default(),
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        }
+    }
+
+    /// delete a LimitRange
+    #[inline]
+    pub fn delete_core_v1_namespaced_limit_range() -> DeleteOptionsDeleteBuilder3<crate::generics::MissingName, crate::generics::MissingNamespace> {
+        DeleteOptionsDeleteBuilder3 {
+            inner: Default::default(),
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        }
+    }
+
+    /// delete a PersistentVolumeClaim
+    #[inline]
+    pub fn delete_core_v1_namespaced_persistent_volume_claim() -> DeleteOptionsDeleteBuilder4<crate::generics::MissingName, crate::generics::MissingNamespace> {
+        DeleteOptionsDeleteBuilder4 {
+            inner: Default::default(),
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        }
+    }
+
+    /// delete a Pod
+    #[inline]
+    pub fn delete_core_v1_namespaced_pod() -> DeleteOptionsDeleteBuilder5<crate::generics::MissingName, crate::generics::MissingNamespace> {
+        DeleteOptionsDeleteBuilder5 {
+            inner: Default::default(),
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        }
+    }
+
+    /// delete a PodTemplate
+    #[inline]
+    pub fn delete_core_v1_namespaced_pod_template() -> DeleteOptionsDeleteBuilder6<crate::generics::MissingName, crate::generics::MissingNamespace> {
+        DeleteOptionsDeleteBuilder6 {
+            inner: Default::default(),
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        }
+    }
+
+    /// delete a ReplicationController
+    #[inline]
+    pub fn delete_core_v1_namespaced_replication_controller() -> DeleteOptionsDeleteBuilder7<crate::generics::MissingName, crate::generics::MissingNamespace> {
+        DeleteOptionsDeleteBuilder7 {
+            inner: Default::default(),
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        }
+    }
+
+    /// delete a ResourceQuota
+    #[inline]
+    pub fn delete_core_v1_namespaced_resource_quota() -> DeleteOptionsDeleteBuilder8<crate::generics::MissingName, crate::generics::MissingNamespace> {
+        DeleteOptionsDeleteBuilder8 {
+            inner: Default::default(),
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        }
+    }
+
+    /// delete a Secret
+    #[inline]
+    pub fn delete_core_v1_namespaced_secret() -> DeleteOptionsDeleteBuilder9<crate::generics::MissingName, crate::generics::MissingNamespace> {
+        DeleteOptionsDeleteBuilder9 {
+            inner: Default::default(),
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        }
+    }
+
+    /// delete a ServiceAccount
+    #[inline]
+    pub fn delete_core_v1_namespaced_service_account() -> DeleteOptionsDeleteBuilder10<crate::generics::MissingName, crate::generics::MissingNamespace> {
+        DeleteOptionsDeleteBuilder10 {
+            inner: Default::default(),
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        }
+    }
+
+    /// delete a Service
+    #[inline]
+    pub fn delete_core_v1_namespaced_service() -> DeleteOptionsDeleteBuilder11<crate::generics::MissingName, crate::generics::MissingNamespace> {
+        DeleteOptionsDeleteBuilder11 {
+            inner: Default::default(),
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        }
+    }
+
+    /// delete a Namespace
+    #[inline]
+    pub fn delete_core_v1_namespace() -> DeleteOptionsDeleteBuilder12<crate::generics::MissingName> {
+        DeleteOptionsDeleteBuilder12 {
+            inner: Default::default(),
+            _param_name: core::marker::PhantomData,
+        }
+    }
+
+    /// delete a Node
+    #[inline]
+    pub fn delete_core_v1_node() -> DeleteOptionsDeleteBuilder13<crate::generics::MissingName> {
+        DeleteOptionsDeleteBuilder13 {
+            inner: Default::default(),
+            _param_name: core::marker::PhantomData,
+        }
+    }
+
+    /// delete a PersistentVolume
+    #[inline]
+    pub fn delete_core_v1_persistent_volume() -> DeleteOptionsDeleteBuilder14<crate::generics::MissingName> {
+        DeleteOptionsDeleteBuilder14 {
+            inner: Default::default(),
+            _param_name: core::marker::PhantomData,
+        }
+    }
+
+    /// delete a MutatingWebhookConfiguration
+    #[inline]
+    pub fn delete_admissionregistration_v1beta1_mutating_webhook_configuration() -> DeleteOptionsDeleteBuilder15<crate::generics::MissingName> {
+        DeleteOptionsDeleteBuilder15 {
+            inner: Default::default(),
+            _param_name: core::marker::PhantomData,
+        }
+    }
+
+    /// delete a ValidatingWebhookConfiguration
+    #[inline]
+    pub fn delete_admissionregistration_v1beta1_validating_webhook_configuration() -> DeleteOptionsDeleteBuilder16<crate::generics::MissingName> {
+        DeleteOptionsDeleteBuilder16 {
+            inner: Default::default(),
+            _param_name: core::marker::PhantomData,
+        }
+    }
+
+    /// delete a CustomResourceDefinition
+    #[inline]
+    pub fn delete_apiextensions_v1beta1_custom_resource_definition() -> DeleteOptionsDeleteBuilder17<crate::generics::MissingName> {
+        DeleteOptionsDeleteBuilder17 {
+            inner: Default::default(),
+            _param_name: core::marker::PhantomData,
+        }
+    }
+
+    /// delete an APIService
+    #[inline]
+    pub fn delete_apiregistration_v1api_service() -> DeleteOptionsDeleteBuilder18<crate::generics::MissingName> {
+        DeleteOptionsDeleteBuilder18 {
+            inner: Default::default(),
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+        }
+    }
+
+    /// delete an APIService
+    #[inline]
+    pub fn delete_apiregistration_v1beta1_api_service() -> DeleteOptionsDeleteBuilder19<crate::generics::MissingName> {
+        DeleteOptionsDeleteBuilder19 {
+            inner: Default::default(),
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+        }
+    }
+
+    /// delete a ControllerRevision
+    #[inline]
+    pub fn delete_apps_v1_namespaced_controller_revision() -> DeleteOptionsDeleteBuilder20<crate::generics::MissingName, crate::generics::MissingNamespace> {
+        DeleteOptionsDeleteBuilder20 {
+            inner: Default::default(),
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        }
+    }
+
+    /// delete a DaemonSet
+    #[inline]
+    pub fn delete_apps_v1_namespaced_daemon_set() -> DeleteOptionsDeleteBuilder21<crate::generics::MissingName, crate::generics::MissingNamespace> {
+        DeleteOptionsDeleteBuilder21 {
+            inner: Default::default(),
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        }
+    }
+
+    /// delete a Deployment
+    #[inline]
+    pub fn delete_apps_v1_namespaced_deployment() -> DeleteOptionsDeleteBuilder22<crate::generics::MissingName, crate::generics::MissingNamespace> {
+        DeleteOptionsDeleteBuilder22 {
+            inner: Default::default(),
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        }
+    }
+
+    /// delete a ReplicaSet
+    #[inline]
+    pub fn delete_apps_v1_namespaced_replica_set() -> DeleteOptionsDeleteBuilder23<crate::generics::MissingName, crate::generics::MissingNamespace> {
+        DeleteOptionsDeleteBuilder23 {
+            inner: Default::default(),
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        }
+    }
+
+    /// delete a StatefulSet
+    #[inline]
+    pub fn delete_apps_v1_namespaced_stateful_set() -> DeleteOptionsDeleteBuilder24<crate::generics::MissingName, crate::generics::MissingNamespace> {
+        DeleteOptionsDeleteBuilder24 {
+            inner: Default::default(),
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        }
+    }
+
+    /// delete a ControllerRevision
+    #[inline]
+    pub fn delete_apps_v1beta1_namespaced_controller_revision() -> DeleteOptionsDeleteBuilder25<crate::generics::MissingName, crate::generics::MissingNamespace> {
+        DeleteOptionsDeleteBuilder25 {
+            inner: Default::default(),
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        }
+    }
+
+    /// delete a Deployment
+    #[inline]
+    pub fn delete_apps_v1beta1_namespaced_deployment() -> DeleteOptionsDeleteBuilder26<crate::generics::MissingName, crate::generics::MissingNamespace> {
+        DeleteOptionsDeleteBuilder26 {
+            inner: Default::default(),
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        }
+    }
+
+    /// delete a StatefulSet
+    #[inline]
+    pub fn delete_apps_v1beta1_namespaced_stateful_set() -> DeleteOptionsDeleteBuilder27<crate::generics::MissingName, crate::generics::MissingNamespace> {
+        DeleteOptionsDeleteBuilder27 {
+            inner: Default::default(),
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        }
+    }
+
+    /// delete a ControllerRevision
+    #[inline]
+    pub fn delete_apps_v1beta2_namespaced_controller_revision() -> DeleteOptionsDeleteBuilder28<crate::generics::MissingName, crate::generics::MissingNamespace> {
+        DeleteOptionsDeleteBuilder28 {
+            inner: Default::default(),
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        }
+    }
+
+    /// delete a DaemonSet
+    #[inline]
+    pub fn delete_apps_v1beta2_namespaced_daemon_set() -> DeleteOptionsDeleteBuilder29<crate::generics::MissingName, crate::generics::MissingNamespace> {
+        DeleteOptionsDeleteBuilder29 {
+            inner: Default::default(),
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        }
+    }
+
+    /// delete a Deployment
+    #[inline]
+    pub fn delete_apps_v1beta2_namespaced_deployment() -> DeleteOptionsDeleteBuilder30<crate::generics::MissingName, crate::generics::MissingNamespace> {
+        DeleteOptionsDeleteBuilder30 {
+            inner: Default::default(),
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        }
+    }
+
+    /// delete a ReplicaSet
+    #[inline]
+    pub fn delete_apps_v1beta2_namespaced_replica_set() -> DeleteOptionsDeleteBuilder31<crate::generics::MissingName, crate::generics::MissingNamespace> {
+        DeleteOptionsDeleteBuilder31 {
+            inner: Default::default(),
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        }
+    }
+
+    /// delete a StatefulSet
+    #[inline]
+    pub fn delete_apps_v1beta2_namespaced_stateful_set() -> DeleteOptionsDeleteBuilder32<crate::generics::MissingName, crate::generics::MissingNamespace> {
+        DeleteOptionsDeleteBuilder32 {
+            inner: Default::default(),
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        }
+    }
+
+    /// delete an AuditSink
+    #[inline]
+    pub fn delete_auditregistration_v1alpha1_audit_sink() -> DeleteOptionsDeleteBuilder33<crate::generics::MissingName> {
+        DeleteOptionsDeleteBuilder33 {
+            inner: Default::default(),
+            _param_name: core::marker::PhantomData,
+        }
+    }
+
+    /// delete a HorizontalPodAutoscaler
+    #[inline]
+    pub fn delete_autoscaling_v1_namespaced_horizontal_pod_autoscaler() -> DeleteOptionsDeleteBuilder34<crate::generics::MissingName, crate::generics::MissingNamespace> {
+        DeleteOptionsDeleteBuilder34 {
+            inner: Default::default(),
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        }
+    }
+
+    /// delete a HorizontalPodAutoscaler
+    #[inline]
+    pub fn delete_autoscaling_v2beta1_namespaced_horizontal_pod_autoscaler() -> DeleteOptionsDeleteBuilder35<crate::generics::MissingName, crate::generics::MissingNamespace> {
+        DeleteOptionsDeleteBuilder35 {
+            inner: Default::default(),
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        }
+    }
+
+    /// delete a HorizontalPodAutoscaler
+    #[inline]
+    pub fn delete_autoscaling_v2beta2_namespaced_horizontal_pod_autoscaler() -> DeleteOptionsDeleteBuilder36<crate::generics::MissingName, crate::generics::MissingNamespace> {
+        DeleteOptionsDeleteBuilder36 {
+            inner: Default::default(),
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        }
+    }
+
+    /// delete a Job
+    #[inline]
+    pub fn delete_batch_v1_namespaced_job() -> DeleteOptionsDeleteBuilder37<crate::generics::MissingName, crate::generics::MissingNamespace> {
+        DeleteOptionsDeleteBuilder37 {
+            inner: Default::default(),
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        }
+    }
+
+    /// delete a CronJob
+    #[inline]
+    pub fn delete_batch_v1beta1_namespaced_cron_job() -> DeleteOptionsDeleteBuilder38<crate::generics::MissingName, crate::generics::MissingNamespace> {
+        DeleteOptionsDeleteBuilder38 {
+            inner: Default::default(),
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        }
+    }
+
+    /// delete a CronJob
+    #[inline]
+    pub fn delete_batch_v2alpha1_namespaced_cron_job() -> DeleteOptionsDeleteBuilder39<crate::generics::MissingName, crate::generics::MissingNamespace> {
+        DeleteOptionsDeleteBuilder39 {
+            inner: Default::default(),
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        }
+    }
+
+    /// delete a CertificateSigningRequest
+    #[inline]
+    pub fn delete_certificates_v1beta1_certificate_signing_request() -> DeleteOptionsDeleteBuilder40<crate::generics::MissingName> {
+        DeleteOptionsDeleteBuilder40 {
+            inner: Default::default(),
+            _param_name: core::marker::PhantomData,
+        }
+    }
+
+    /// delete a Lease
+    #[inline]
+    pub fn delete_coordination_v1_namespaced_lease() -> DeleteOptionsDeleteBuilder41<crate::generics::MissingName, crate::generics::MissingNamespace> {
+        DeleteOptionsDeleteBuilder41 {
+            inner: Default::default(),
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        }
+    }
+
+    /// delete a Lease
+    #[inline]
+    pub fn delete_coordination_v1beta1_namespaced_lease() -> DeleteOptionsDeleteBuilder42<crate::generics::MissingName, crate::generics::MissingNamespace> {
+        DeleteOptionsDeleteBuilder42 {
+            inner: Default::default(),
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        }
+    }
+
+    /// delete an Event
+    #[inline]
+    pub fn delete_events_v1beta1_namespaced_event() -> DeleteOptionsDeleteBuilder43<crate::generics::MissingName, crate::generics::MissingNamespace> {
+        DeleteOptionsDeleteBuilder43 {
+            inner: Default::default(),
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        }
+    }
+
+    /// delete a DaemonSet
+    #[inline]
+    pub fn delete_extensions_v1beta1_namespaced_daemon_set() -> DeleteOptionsDeleteBuilder44<crate::generics::MissingName, crate::generics::MissingNamespace> {
+        DeleteOptionsDeleteBuilder44 {
+            inner: Default::default(),
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        }
+    }
+
+    /// delete a Deployment
+    #[inline]
+    pub fn delete_extensions_v1beta1_namespaced_deployment() -> DeleteOptionsDeleteBuilder45<crate::generics::MissingName, crate::generics::MissingNamespace> {
+        DeleteOptionsDeleteBuilder45 {
+            inner: Default::default(),
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        }
+    }
+
+    /// delete an Ingress
+    #[inline]
+    pub fn delete_extensions_v1beta1_namespaced_ingress() -> DeleteOptionsDeleteBuilder46<crate::generics::MissingName, crate::generics::MissingNamespace> {
+        DeleteOptionsDeleteBuilder46 {
+            inner: Default::default(),
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        }
+    }
+
+    /// delete a NetworkPolicy
+    #[inline]
+    pub fn delete_extensions_v1beta1_namespaced_network_policy() -> DeleteOptionsDeleteBuilder47<crate::generics::MissingName, crate::generics::MissingNamespace> {
+        DeleteOptionsDeleteBuilder47 {
+            inner: Default::default(),
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        }
+    }
+
+    /// delete a ReplicaSet
+    #[inline]
+    pub fn delete_extensions_v1beta1_namespaced_replica_set() -> DeleteOptionsDeleteBuilder48<crate::generics::MissingName, crate::generics::MissingNamespace> {
+        DeleteOptionsDeleteBuilder48 {
+            inner: Default::default(),
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        }
+    }
+
+    /// delete a PodSecurityPolicy
+    #[inline]
+    pub fn delete_extensions_v1beta1_pod_security_policy() -> DeleteOptionsDeleteBuilder49<crate::generics::MissingName> {
+        DeleteOptionsDeleteBuilder49 {
+            inner: Default::default(),
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+        }
+    }
+
+    /// delete a NetworkPolicy
+    #[inline]
+    pub fn delete_networking_v1_namespaced_network_policy() -> DeleteOptionsDeleteBuilder50<crate::generics::MissingName, crate::generics::MissingNamespace> {
+        DeleteOptionsDeleteBuilder50 {
+            inner: Default::default(),
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        }
+    }
+
+    /// delete an Ingress
+    #[inline]
+    pub fn delete_networking_v1beta1_namespaced_ingress() -> DeleteOptionsDeleteBuilder51<crate::generics::MissingName, crate::generics::MissingNamespace> {
+        DeleteOptionsDeleteBuilder51 {
+            inner: Default::default(),
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        }
+    }
+
+    /// delete a RuntimeClass
+    #[inline]
+    pub fn delete_node_v1alpha1_runtime_class() -> DeleteOptionsDeleteBuilder52<crate::generics::MissingName> {
+        DeleteOptionsDeleteBuilder52 {
+            inner: Default::default(),
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+        }
+    }
+
+    /// delete a RuntimeClass
+    #[inline]
+    pub fn delete_node_v1beta1_runtime_class() -> DeleteOptionsDeleteBuilder53<crate::generics::MissingName> {
+        DeleteOptionsDeleteBuilder53 {
+            inner: Default::default(),
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+        }
+    }
+
+    /// delete a PodDisruptionBudget
+    #[inline]
+    pub fn delete_policy_v1beta1_namespaced_pod_disruption_budget() -> DeleteOptionsDeleteBuilder54<crate::generics::MissingName, crate::generics::MissingNamespace> {
+        DeleteOptionsDeleteBuilder54 {
+            inner: Default::default(),
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        }
+    }
+
+    /// delete a PodSecurityPolicy
+    #[inline]
+    pub fn delete_policy_v1beta1_pod_security_policy() -> DeleteOptionsDeleteBuilder55<crate::generics::MissingName> {
+        DeleteOptionsDeleteBuilder55 {
+            inner: Default::default(),
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+        }
+    }
+
+    /// delete a ClusterRoleBinding
+    #[inline]
+    pub fn delete_rbac_authorization_v1_cluster_role_binding() -> DeleteOptionsDeleteBuilder56<crate::generics::MissingName> {
+        DeleteOptionsDeleteBuilder56 {
+            inner: Default::default(),
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+        }
+    }
+
+    /// delete a ClusterRole
+    #[inline]
+    pub fn delete_rbac_authorization_v1_cluster_role() -> DeleteOptionsDeleteBuilder57<crate::generics::MissingName> {
+        DeleteOptionsDeleteBuilder57 {
+            inner: Default::default(),
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+        }
+    }
+
+    /// delete a RoleBinding
+    #[inline]
+    pub fn delete_rbac_authorization_v1_namespaced_role_binding() -> DeleteOptionsDeleteBuilder58<crate::generics::MissingName, crate::generics::MissingNamespace> {
+        DeleteOptionsDeleteBuilder58 {
+            inner: Default::default(),
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        }
+    }
+
+    /// delete a Role
+    #[inline]
+    pub fn delete_rbac_authorization_v1_namespaced_role() -> DeleteOptionsDeleteBuilder59<crate::generics::MissingName, crate::generics::MissingNamespace> {
+        DeleteOptionsDeleteBuilder59 {
+            inner: Default::default(),
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        }
+    }
+
+    /// delete a ClusterRoleBinding
+    #[inline]
+    pub fn delete_rbac_authorization_v1alpha1_cluster_role_binding() -> DeleteOptionsDeleteBuilder60<crate::generics::MissingName> {
+        DeleteOptionsDeleteBuilder60 {
+            inner: Default::default(),
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+        }
+    }
+
+    /// delete a ClusterRole
+    #[inline]
+    pub fn delete_rbac_authorization_v1alpha1_cluster_role() -> DeleteOptionsDeleteBuilder61<crate::generics::MissingName> {
+        DeleteOptionsDeleteBuilder61 {
+            inner: Default::default(),
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+        }
+    }
+
+    /// delete a RoleBinding
+    #[inline]
+    pub fn delete_rbac_authorization_v1alpha1_namespaced_role_binding() -> DeleteOptionsDeleteBuilder62<crate::generics::MissingName, crate::generics::MissingNamespace> {
+        DeleteOptionsDeleteBuilder62 {
+            inner: Default::default(),
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        }
+    }
+
+    /// delete a Role
+    #[inline]
+    pub fn delete_rbac_authorization_v1alpha1_namespaced_role() -> DeleteOptionsDeleteBuilder63<crate::generics::MissingName, crate::generics::MissingNamespace> {
+        DeleteOptionsDeleteBuilder63 {
+            inner: Default::default(),
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        }
+    }
+
+    /// delete a ClusterRoleBinding
+    #[inline]
+    pub fn delete_rbac_authorization_v1beta1_cluster_role_binding() -> DeleteOptionsDeleteBuilder64<crate::generics::MissingName> {
+        DeleteOptionsDeleteBuilder64 {
+            inner: Default::default(),
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+        }
+    }
+
+    /// delete a ClusterRole
+    #[inline]
+    pub fn delete_rbac_authorization_v1beta1_cluster_role() -> DeleteOptionsDeleteBuilder65<crate::generics::MissingName> {
+        DeleteOptionsDeleteBuilder65 {
+            inner: Default::default(),
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+        }
+    }
+
+    /// delete a RoleBinding
+    #[inline]
+    pub fn delete_rbac_authorization_v1beta1_namespaced_role_binding() -> DeleteOptionsDeleteBuilder66<crate::generics::MissingName, crate::generics::MissingNamespace> {
+        DeleteOptionsDeleteBuilder66 {
+            inner: Default::default(),
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        }
+    }
+
+    /// delete a Role
+    #[inline]
+    pub fn delete_rbac_authorization_v1beta1_namespaced_role() -> DeleteOptionsDeleteBuilder67<crate::generics::MissingName, crate::generics::MissingNamespace> {
+        DeleteOptionsDeleteBuilder67 {
+            inner: Default::default(),
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        }
+    }
+
+    /// delete a PriorityClass
+    #[inline]
+    pub fn delete_scheduling_v1_priority_class() -> DeleteOptionsDeleteBuilder68<crate::generics::MissingName> {
+        DeleteOptionsDeleteBuilder68 {
+            inner: Default::default(),
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+        }
+    }
+
+    /// delete a PriorityClass
+    #[inline]
+    pub fn delete_scheduling_v1alpha1_priority_class() -> DeleteOptionsDeleteBuilder69<crate::generics::MissingName> {
+        DeleteOptionsDeleteBuilder69 {
+            inner: Default::default(),
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+        }
+    }
+
+    /// delete a PriorityClass
+    #[inline]
+    pub fn delete_scheduling_v1beta1_priority_class() -> DeleteOptionsDeleteBuilder70<crate::generics::MissingName> {
+        DeleteOptionsDeleteBuilder70 {
+            inner: Default::default(),
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+        }
+    }
+
+    /// delete a PodPreset
+    #[inline]
+    pub fn delete_settings_v1alpha1_namespaced_pod_preset() -> DeleteOptionsDeleteBuilder71<crate::generics::MissingName, crate::generics::MissingNamespace> {
+        DeleteOptionsDeleteBuilder71 {
+            inner: Default::default(),
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        }
+    }
+
+    /// delete a StorageClass
+    #[inline]
+    pub fn delete_storage_v1_storage_class() -> DeleteOptionsDeleteBuilder72<crate::generics::MissingName> {
+        DeleteOptionsDeleteBuilder72 {
+            inner: Default::default(),
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+        }
+    }
+
+    /// delete a VolumeAttachment
+    #[inline]
+    pub fn delete_storage_v1_volume_attachment() -> DeleteOptionsDeleteBuilder73<crate::generics::MissingName> {
+        DeleteOptionsDeleteBuilder73 {
+            inner: Default::default(),
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+        }
+    }
+
+    /// delete a VolumeAttachment
+    #[inline]
+    pub fn delete_storage_v1alpha1_volume_attachment() -> DeleteOptionsDeleteBuilder74<crate::generics::MissingName> {
+        DeleteOptionsDeleteBuilder74 {
+            inner: Default::default(),
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+        }
+    }
+
+    /// delete a CSIDriver
+    #[inline]
+    pub fn delete_storage_v1beta1_csi_driver() -> DeleteOptionsDeleteBuilder75<crate::generics::MissingName> {
+        DeleteOptionsDeleteBuilder75 {
+            inner: Default::default(),
+            _param_name: core::marker::PhantomData,
+        }
+    }
+
+    /// delete a CSINode
+    #[inline]
+    pub fn delete_storage_v1beta1_csi_node() -> DeleteOptionsDeleteBuilder76<crate::generics::MissingName> {
+        DeleteOptionsDeleteBuilder76 {
+            inner: Default::default(),
+            _param_name: core::marker::PhantomData,
+        }
+    }
+
+    /// delete a StorageClass
+    #[inline]
+    pub fn delete_storage_v1beta1_storage_class() -> DeleteOptionsDeleteBuilder77<crate::generics::MissingName> {
+        DeleteOptionsDeleteBuilder77 {
+            inner: Default::default(),
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+        }
+    }
+
+    /// delete a VolumeAttachment
+    #[inline]
+    pub fn delete_storage_v1beta1_volume_attachment() -> DeleteOptionsDeleteBuilder78<crate::generics::MissingName> {
+        DeleteOptionsDeleteBuilder78 {
+            inner: Default::default(),
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> DeleteOptionsBuilder
+    where
+        Self: Clone,
+    {
+        DeleteOptionsBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<DeleteOptions> for DeleteOptionsBuilder {
+    fn into(self) -> DeleteOptions {
+        self.body
+    }
+}
+
+impl Into<DeleteOptions> for DeleteOptionsDeleteBuilder<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    fn into(self) -> DeleteOptions {
+        self.inner.body
+    }
+}
+
+impl Into<DeleteOptions> for DeleteOptionsDeleteBuilder1<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    fn into(self) -> DeleteOptions {
+        self.inner.body
+    }
+}
+
+impl Into<DeleteOptions> for DeleteOptionsDeleteBuilder2<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    fn into(self) -> DeleteOptions {
+        self.inner.body
+    }
+}
+
+impl Into<DeleteOptions> for DeleteOptionsDeleteBuilder3<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    fn into(self) -> DeleteOptions {
+        self.inner.body
+    }
+}
+
+impl Into<DeleteOptions> for DeleteOptionsDeleteBuilder4<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    fn into(self) -> DeleteOptions {
+        self.inner.body
+    }
+}
+
+impl Into<DeleteOptions> for DeleteOptionsDeleteBuilder5<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    fn into(self) -> DeleteOptions {
+        self.inner.body
+    }
+}
+
+impl Into<DeleteOptions> for DeleteOptionsDeleteBuilder6<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    fn into(self) -> DeleteOptions {
+        self.inner.body
+    }
+}
+
+impl Into<DeleteOptions> for DeleteOptionsDeleteBuilder7<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    fn into(self) -> DeleteOptions {
+        self.inner.body
+    }
+}
+
+impl Into<DeleteOptions> for DeleteOptionsDeleteBuilder8<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    fn into(self) -> DeleteOptions {
+        self.inner.body
+    }
+}
+
+impl Into<DeleteOptions> for DeleteOptionsDeleteBuilder9<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    fn into(self) -> DeleteOptions {
+        self.inner.body
+    }
+}
+
+impl Into<DeleteOptions> for DeleteOptionsDeleteBuilder10<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    fn into(self) -> DeleteOptions {
+        self.inner.body
+    }
+}
+
+impl Into<DeleteOptions> for DeleteOptionsDeleteBuilder11<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    fn into(self) -> DeleteOptions {
+        self.inner.body
+    }
+}
+
+impl Into<DeleteOptions> for DeleteOptionsDeleteBuilder12<crate::generics::NameExists> {
+    fn into(self) -> DeleteOptions {
+        self.inner.body
+    }
+}
+
+impl Into<DeleteOptions> for DeleteOptionsDeleteBuilder13<crate::generics::NameExists> {
+    fn into(self) -> DeleteOptions {
+        self.inner.body
+    }
+}
+
+impl Into<DeleteOptions> for DeleteOptionsDeleteBuilder14<crate::generics::NameExists> {
+    fn into(self) -> DeleteOptions {
+        self.inner.body
+    }
+}
+
+impl Into<DeleteOptions> for DeleteOptionsDeleteBuilder15<crate::generics::NameExists> {
+    fn into(self) -> DeleteOptions {
+        self.inner.body
+    }
+}
+
+impl Into<DeleteOptions> for DeleteOptionsDeleteBuilder16<crate::generics::NameExists> {
+    fn into(self) -> DeleteOptions {
+        self.inner.body
+    }
+}
+
+impl Into<DeleteOptions> for DeleteOptionsDeleteBuilder17<crate::generics::NameExists> {
+    fn into(self) -> DeleteOptions {
+        self.inner.body
+    }
+}
+
+impl Into<DeleteOptions> for DeleteOptionsDeleteBuilder18<crate::generics::NameExists> {
+    fn into(self) -> DeleteOptions {
+        self.inner.body
+    }
+}
+
+impl Into<DeleteOptions> for DeleteOptionsDeleteBuilder19<crate::generics::NameExists> {
+    fn into(self) -> DeleteOptions {
+        self.inner.body
+    }
+}
+
+impl Into<DeleteOptions> for DeleteOptionsDeleteBuilder20<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    fn into(self) -> DeleteOptions {
+        self.inner.body
+    }
+}
+
+impl Into<DeleteOptions> for DeleteOptionsDeleteBuilder21<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    fn into(self) -> DeleteOptions {
+        self.inner.body
+    }
+}
+
+impl Into<DeleteOptions> for DeleteOptionsDeleteBuilder22<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    fn into(self) -> DeleteOptions {
+        self.inner.body
+    }
+}
+
+impl Into<DeleteOptions> for DeleteOptionsDeleteBuilder23<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    fn into(self) -> DeleteOptions {
+        self.inner.body
+    }
+}
+
+impl Into<DeleteOptions> for DeleteOptionsDeleteBuilder24<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    fn into(self) -> DeleteOptions {
+        self.inner.body
+    }
+}
+
+impl Into<DeleteOptions> for DeleteOptionsDeleteBuilder25<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    fn into(self) -> DeleteOptions {
+        self.inner.body
+    }
+}
+
+impl Into<DeleteOptions> for DeleteOptionsDeleteBuilder26<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    fn into(self) -> DeleteOptions {
+        self.inner.body
+    }
+}
+
+impl Into<DeleteOptions> for DeleteOptionsDeleteBuilder27<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    fn into(self) -> DeleteOptions {
+        self.inner.body
+    }
+}
+
+impl Into<DeleteOptions> for DeleteOptionsDeleteBuilder28<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    fn into(self) -> DeleteOptions {
+        self.inner.body
+    }
+}
+
+impl Into<DeleteOptions> for DeleteOptionsDeleteBuilder29<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    fn into(self) -> DeleteOptions {
+        self.inner.body
+    }
+}
+
+impl Into<DeleteOptions> for DeleteOptionsDeleteBuilder30<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    fn into(self) -> DeleteOptions {
+        self.inner.body
+    }
+}
+
+impl Into<DeleteOptions> for DeleteOptionsDeleteBuilder31<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    fn into(self) -> DeleteOptions {
+        self.inner.body
+    }
+}
+
+impl Into<DeleteOptions> for DeleteOptionsDeleteBuilder32<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    fn into(self) -> DeleteOptions {
+        self.inner.body
+    }
+}
+
+impl Into<DeleteOptions> for DeleteOptionsDeleteBuilder33<crate::generics::NameExists> {
+    fn into(self) -> DeleteOptions {
+        self.inner.body
+    }
+}
+
+impl Into<DeleteOptions> for DeleteOptionsDeleteBuilder34<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    fn into(self) -> DeleteOptions {
+        self.inner.body
+    }
+}
+
+impl Into<DeleteOptions> for DeleteOptionsDeleteBuilder35<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    fn into(self) -> DeleteOptions {
+        self.inner.body
+    }
+}
+
+impl Into<DeleteOptions> for DeleteOptionsDeleteBuilder36<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    fn into(self) -> DeleteOptions {
+        self.inner.body
+    }
+}
+
+impl Into<DeleteOptions> for DeleteOptionsDeleteBuilder37<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    fn into(self) -> DeleteOptions {
+        self.inner.body
+    }
+}
+
+impl Into<DeleteOptions> for DeleteOptionsDeleteBuilder38<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    fn into(self) -> DeleteOptions {
+        self.inner.body
+    }
+}
+
+impl Into<DeleteOptions> for DeleteOptionsDeleteBuilder39<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    fn into(self) -> DeleteOptions {
+        self.inner.body
+    }
+}
+
+impl Into<DeleteOptions> for DeleteOptionsDeleteBuilder40<crate::generics::NameExists> {
+    fn into(self) -> DeleteOptions {
+        self.inner.body
+    }
+}
+
+impl Into<DeleteOptions> for DeleteOptionsDeleteBuilder41<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    fn into(self) -> DeleteOptions {
+        self.inner.body
+    }
+}
+
+impl Into<DeleteOptions> for DeleteOptionsDeleteBuilder42<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    fn into(self) -> DeleteOptions {
+        self.inner.body
+    }
+}
+
+impl Into<DeleteOptions> for DeleteOptionsDeleteBuilder43<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    fn into(self) -> DeleteOptions {
+        self.inner.body
+    }
+}
+
+impl Into<DeleteOptions> for DeleteOptionsDeleteBuilder44<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    fn into(self) -> DeleteOptions {
+        self.inner.body
+    }
+}
+
+impl Into<DeleteOptions> for DeleteOptionsDeleteBuilder45<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    fn into(self) -> DeleteOptions {
+        self.inner.body
+    }
+}
+
+impl Into<DeleteOptions> for DeleteOptionsDeleteBuilder46<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    fn into(self) -> DeleteOptions {
+        self.inner.body
+    }
+}
+
+impl Into<DeleteOptions> for DeleteOptionsDeleteBuilder47<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    fn into(self) -> DeleteOptions {
+        self.inner.body
+    }
+}
+
+impl Into<DeleteOptions> for DeleteOptionsDeleteBuilder48<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    fn into(self) -> DeleteOptions {
+        self.inner.body
+    }
+}
+
+impl Into<DeleteOptions> for DeleteOptionsDeleteBuilder49<crate::generics::NameExists> {
+    fn into(self) -> DeleteOptions {
+        self.inner.body
+    }
+}
+
+impl Into<DeleteOptions> for DeleteOptionsDeleteBuilder50<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    fn into(self) -> DeleteOptions {
+        self.inner.body
+    }
+}
+
+impl Into<DeleteOptions> for DeleteOptionsDeleteBuilder51<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    fn into(self) -> DeleteOptions {
+        self.inner.body
+    }
+}
+
+impl Into<DeleteOptions> for DeleteOptionsDeleteBuilder52<crate::generics::NameExists> {
+    fn into(self) -> DeleteOptions {
+        self.inner.body
+    }
+}
+
+impl Into<DeleteOptions> for DeleteOptionsDeleteBuilder53<crate::generics::NameExists> {
+    fn into(self) -> DeleteOptions {
+        self.inner.body
+    }
+}
+
+impl Into<DeleteOptions> for DeleteOptionsDeleteBuilder54<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    fn into(self) -> DeleteOptions {
+        self.inner.body
+    }
+}
+
+impl Into<DeleteOptions> for DeleteOptionsDeleteBuilder55<crate::generics::NameExists> {
+    fn into(self) -> DeleteOptions {
+        self.inner.body
+    }
+}
+
+impl Into<DeleteOptions> for DeleteOptionsDeleteBuilder56<crate::generics::NameExists> {
+    fn into(self) -> DeleteOptions {
+        self.inner.body
+    }
+}
+
+impl Into<DeleteOptions> for DeleteOptionsDeleteBuilder57<crate::generics::NameExists> {
+    fn into(self) -> DeleteOptions {
+        self.inner.body
+    }
+}
+
+impl Into<DeleteOptions> for DeleteOptionsDeleteBuilder58<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    fn into(self) -> DeleteOptions {
+        self.inner.body
+    }
+}
+
+impl Into<DeleteOptions> for DeleteOptionsDeleteBuilder59<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    fn into(self) -> DeleteOptions {
+        self.inner.body
+    }
+}
+
+impl Into<DeleteOptions> for DeleteOptionsDeleteBuilder60<crate::generics::NameExists> {
+    fn into(self) -> DeleteOptions {
+        self.inner.body
+    }
+}
+
+impl Into<DeleteOptions> for DeleteOptionsDeleteBuilder61<crate::generics::NameExists> {
+    fn into(self) -> DeleteOptions {
+        self.inner.body
+    }
+}
+
+impl Into<DeleteOptions> for DeleteOptionsDeleteBuilder62<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    fn into(self) -> DeleteOptions {
+        self.inner.body
+    }
+}
+
+impl Into<DeleteOptions> for DeleteOptionsDeleteBuilder63<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    fn into(self) -> DeleteOptions {
+        self.inner.body
+    }
+}
+
+impl Into<DeleteOptions> for DeleteOptionsDeleteBuilder64<crate::generics::NameExists> {
+    fn into(self) -> DeleteOptions {
+        self.inner.body
+    }
+}
+
+impl Into<DeleteOptions> for DeleteOptionsDeleteBuilder65<crate::generics::NameExists> {
+    fn into(self) -> DeleteOptions {
+        self.inner.body
+    }
+}
+
+impl Into<DeleteOptions> for DeleteOptionsDeleteBuilder66<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    fn into(self) -> DeleteOptions {
+        self.inner.body
+    }
+}
+
+impl Into<DeleteOptions> for DeleteOptionsDeleteBuilder67<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    fn into(self) -> DeleteOptions {
+        self.inner.body
+    }
+}
+
+impl Into<DeleteOptions> for DeleteOptionsDeleteBuilder68<crate::generics::NameExists> {
+    fn into(self) -> DeleteOptions {
+        self.inner.body
+    }
+}
+
+impl Into<DeleteOptions> for DeleteOptionsDeleteBuilder69<crate::generics::NameExists> {
+    fn into(self) -> DeleteOptions {
+        self.inner.body
+    }
+}
+
+impl Into<DeleteOptions> for DeleteOptionsDeleteBuilder70<crate::generics::NameExists> {
+    fn into(self) -> DeleteOptions {
+        self.inner.body
+    }
+}
+
+impl Into<DeleteOptions> for DeleteOptionsDeleteBuilder71<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    fn into(self) -> DeleteOptions {
+        self.inner.body
+    }
+}
+
+impl Into<DeleteOptions> for DeleteOptionsDeleteBuilder72<crate::generics::NameExists> {
+    fn into(self) -> DeleteOptions {
+        self.inner.body
+    }
+}
+
+impl Into<DeleteOptions> for DeleteOptionsDeleteBuilder73<crate::generics::NameExists> {
+    fn into(self) -> DeleteOptions {
+        self.inner.body
+    }
+}
+
+impl Into<DeleteOptions> for DeleteOptionsDeleteBuilder74<crate::generics::NameExists> {
+    fn into(self) -> DeleteOptions {
+        self.inner.body
+    }
+}
+
+impl Into<DeleteOptions> for DeleteOptionsDeleteBuilder75<crate::generics::NameExists> {
+    fn into(self) -> DeleteOptions {
+        self.inner.body
+    }
+}
+
+impl Into<DeleteOptions> for DeleteOptionsDeleteBuilder76<crate::generics::NameExists> {
+    fn into(self) -> DeleteOptions {
+        self.inner.body
+    }
+}
+
+impl Into<DeleteOptions> for DeleteOptionsDeleteBuilder77<crate::generics::NameExists> {
+    fn into(self) -> DeleteOptions {
+        self.inner.body
+    }
+}
+
+impl Into<DeleteOptions> for DeleteOptionsDeleteBuilder78<crate::generics::NameExists> {
+    fn into(self) -> DeleteOptions {
+        self.inner.body
+    }
+}
+
+/// Builder for [`DeleteOptions`](./struct.DeleteOptions.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct DeleteOptionsBuilder {
+    body: self::DeleteOptions,
+}
+
+impl DeleteOptionsBuilder {
+    /// APIVersion defines the versioned schema of this representation of an object. Servers should convert recognized schemas to the latest internal value, and may reject unrecognized values. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#resources
+    #[inline]
+    pub fn api_version(mut self, value: impl Into<String>) -> Self {
+        self.body.api_version = Some(value.into());
+        self
+    }
+
+    /// Sets [`api_version`](#method.api_version) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_api_version(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.api_version = value.map(|value| value.into());
+        self
+    }
+
+    /// When present, indicates that modifications should not be persisted. An invalid or unrecognized dryRun directive will result in an error response and no further processing of the request. Valid values are: - All: all dry run stages will be processed
+    #[inline]
+    pub fn dry_run(mut self, value: impl Iterator<Item = impl Into<String>>) -> Self {
+        self.body.dry_run = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`dry_run`](#method.dry_run) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_dry_run(mut self, value: Option<impl Iterator<Item = impl Into<String>>>) -> Self {
+        self.body.dry_run = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`dry_run`](#method.dry_run), but takes the values as a `Vec`.
+    #[inline]
+    pub fn dry_run_vec(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.body.dry_run = Some(value.into());
+        self
+    }
+
+    /// The duration in seconds before the object should be deleted. Value must be non-negative integer. The value zero indicates delete immediately. If this value is nil, the default grace period for the specified type will be used. Defaults to a per object value if not specified. zero means delete immediately.
+    #[inline]
+    pub fn grace_period_seconds(mut self, value: impl Into<i64>) -> Self {
+        self.body.grace_period_seconds = Some(value.into());
+        self
+    }
+
+    /// Sets [`grace_period_seconds`](#method.grace_period_seconds) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_grace_period_seconds(mut self, value: Option<impl Into<i64>>) -> Self {
+        self.body.grace_period_seconds = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`grace_period_seconds`](#method.grace_period_seconds), but takes a reference.
+    #[inline]
+    pub fn grace_period_seconds_ref(self, value: &i64) -> Self {
+        self.grace_period_seconds(*value)
+    }
+
+    /// Kind is a string value representing the REST resource this object represents. Servers may infer this from the endpoint the client submits requests to. Cannot be updated. In CamelCase. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#types-kinds
+    #[inline]
+    pub fn kind(mut self, value: impl Into<String>) -> Self {
+        self.body.kind = Some(value.into());
+        self
+    }
+
+    /// Sets [`kind`](#method.kind) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_kind(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.kind = value.map(|value| value.into());
+        self
+    }
+
+    /// Deprecated: please use the PropagationPolicy, this field will be deprecated in 1.7. Should the dependent objects be orphaned. If true/false, the "orphan" finalizer will be added to/removed from the object's finalizers list. Either this field or PropagationPolicy may be set, but not both.
+    #[inline]
+    pub fn orphan_dependents(mut self, value: impl Into<bool>) -> Self {
+        self.body.orphan_dependents = Some(value.into());
+        self
+    }
+
+    /// Sets [`orphan_dependents`](#method.orphan_dependents) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_orphan_dependents(mut self, value: Option<impl Into<bool>>) -> Self {
+        self.body.orphan_dependents = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`orphan_dependents`](#method.orphan_dependents), but takes a reference.
+    #[inline]
+    pub fn orphan_dependents_ref(self, value: &bool) -> Self {
+        self.orphan_dependents(*value)
+    }
+
+    /// Must be fulfilled before a deletion is carried out. If not possible, a 409 Conflict status will be returned.
+    #[inline]
+    pub fn preconditions(mut self, value: crate::io::k8s::apimachinery::pkg::apis::meta::v1::preconditions::Preconditions) -> Self {
+        self.body.preconditions = Some(value.into());
+        self
+    }
+
+    /// Sets [`preconditions`](#method.preconditions) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_preconditions(mut self, value: Option<crate::io::k8s::apimachinery::pkg::apis::meta::v1::preconditions::Preconditions>) -> Self {
+        self.body.preconditions = value.map(|value| value.into());
+        self
+    }
+
+    /// Whether and how garbage collection will be performed. Either this field or OrphanDependents may be set, but not both. The default policy is decided by the existing finalizer set in the metadata.finalizers and the resource-specific default policy. Acceptable values are: 'Orphan' - orphan the dependents; 'Background' - allow the garbage collector to delete the dependents in the background; 'Foreground' - a cascading policy that deletes all dependents in the foreground.
+    #[inline]
+    pub fn propagation_policy(mut self, value: impl Into<String>) -> Self {
+        self.body.propagation_policy = Some(value.into());
+        self
+    }
+
+    /// Sets [`propagation_policy`](#method.propagation_policy) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_propagation_policy(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.propagation_policy = value.map(|value| value.into());
+        self
+    }
+}
+
+/// Builder created by [`DeleteOptions::delete_core_v1_namespaced_config_map`](./struct.DeleteOptions.html#method.delete_core_v1_namespaced_config_map) method for a `DELETE` operation associated with `DeleteOptions`.
+#[repr(transparent)]
+#[derive(Debug, Clone)]
+pub struct DeleteOptionsDeleteBuilder<Name, Namespace> {
+    inner: DeleteOptionsDeleteBuilderContainer,
+    _param_name: core::marker::PhantomData<Name>,
+    _param_namespace: core::marker::PhantomData<Namespace>,
+}
+
+#[derive(Debug, Default, Clone)]
+struct DeleteOptionsDeleteBuilderContainer {
+    body: self::DeleteOptions,
+    param_dry_run: Option<String>,
+    param_grace_period_seconds: Option<i64>,
+    param_orphan_dependents: Option<bool>,
+    param_propagation_policy: Option<String>,
+    param_name: Option<String>,
+    param_namespace: Option<String>,
+    param_pretty: Option<String>,
+}
+
+impl<Name, Namespace> DeleteOptionsDeleteBuilder<Name, Namespace> {
+    /// When present, indicates that modifications should not be persisted. An invalid or unrecognized dryRun directive will result in an error response and no further processing of the request. Valid values are: - All: all dry run stages will be processed
+    #[inline]
+    pub fn dry_run(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_dry_run = Some(value.into());
+        self
+    }
+
+    /// Sets [`dry_run`](#method.dry_run) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_dry_run(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_dry_run = value.map(|value| value.into());
+        self
+    }
+
+    /// The duration in seconds before the object should be deleted. Value must be non-negative integer. The value zero indicates delete immediately. If this value is nil, the default grace period for the specified type will be used. Defaults to a per object value if not specified. zero means delete immediately.
+    #[inline]
+    pub fn grace_period_seconds(mut self, value: impl Into<i64>) -> Self {
+        self.inner.param_grace_period_seconds = Some({
+            let val = value.into();
+            self.inner.body.grace_period_seconds = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// Same as [`grace_period_seconds`](#method.grace_period_seconds), but takes a reference.
+    #[inline]
+    pub fn grace_period_seconds_ref(self, value: &i64) -> Self {
+        self.grace_period_seconds(*value)
+    }
+
+    /// Deprecated: please use the PropagationPolicy, this field will be deprecated in 1.7. Should the dependent objects be orphaned. If true/false, the "orphan" finalizer will be added to/removed from the object's finalizers list. Either this field or PropagationPolicy may be set, but not both.
+    #[inline]
+    pub fn orphan_dependents(mut self, value: impl Into<bool>) -> Self {
+        self.inner.param_orphan_dependents = Some({
+            let val = value.into();
+            self.inner.body.orphan_dependents = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// Same as [`orphan_dependents`](#method.orphan_dependents), but takes a reference.
+    #[inline]
+    pub fn orphan_dependents_ref(self, value: &bool) -> Self {
+        self.orphan_dependents(*value)
+    }
+
+    /// Whether and how garbage collection will be performed. Either this field or OrphanDependents may be set, but not both. The default policy is decided by the existing finalizer set in the metadata.finalizers and the resource-specific default policy. Acceptable values are: 'Orphan' - orphan the dependents; 'Background' - allow the garbage collector to delete the dependents in the background; 'Foreground' - a cascading policy that deletes all dependents in the foreground.
+    #[inline]
+    pub fn propagation_policy(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_propagation_policy = Some({
+            let val = value.into();
+            self.inner.body.propagation_policy = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// name of the ConfigMap
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> DeleteOptionsDeleteBuilder<crate::generics::NameExists, Namespace> {
+        self.inner.param_name = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// object name and auth scope, such as for teams and projects
+    #[inline]
+    pub fn namespace(mut self, value: impl Into<String>) -> DeleteOptionsDeleteBuilder<Name, crate::generics::NamespaceExists> {
+        self.inner.param_namespace = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// If 'true', then the output is pretty printed.
+    #[inline]
+    pub fn pretty(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_pretty = Some(value.into());
+        self
+    }
+
+    /// Sets [`pretty`](#method.pretty) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_pretty(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_pretty = value.map(|value| value.into());
+        self
+    }
+
+    /// APIVersion defines the versioned schema of this representation of an object. Servers should convert recognized schemas to the latest internal value, and may reject unrecognized values. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#resources
+    #[inline]
+    pub fn api_version(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.api_version = Some(value.into());
+        self
+    }
+
+    /// Sets [`api_version`](#method.api_version) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_api_version(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.api_version = value.map(|value| value.into());
+        self
+    }
+
+    /// Kind is a string value representing the REST resource this object represents. Servers may infer this from the endpoint the client submits requests to. Cannot be updated. In CamelCase. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#types-kinds
+    #[inline]
+    pub fn kind(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.kind = Some(value.into());
+        self
+    }
+
+    /// Sets [`kind`](#method.kind) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_kind(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.kind = value.map(|value| value.into());
+        self
+    }
+
+    /// Must be fulfilled before a deletion is carried out. If not possible, a 409 Conflict status will be returned.
+    #[inline]
+    pub fn preconditions(mut self, value: crate::io::k8s::apimachinery::pkg::apis::meta::v1::preconditions::Preconditions) -> Self {
+        self.inner.body.preconditions = Some(value.into());
+        self
+    }
+
+    /// Sets [`preconditions`](#method.preconditions) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_preconditions(mut self, value: Option<crate::io::k8s::apimachinery::pkg::apis::meta::v1::preconditions::Preconditions>) -> Self {
+        self.inner.body.preconditions = value.map(|value| value.into());
+        self
+    }
+}
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for DeleteOptionsDeleteBuilder<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    type Output = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::Status;
+
+    const METHOD: http::Method = http::Method::DELETE;
+
+    const OPERATION_ID: Option<&'static str> = Some("deleteCoreV1NamespacedConfigMap");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok(format!("/api/v1/namespaces/{namespace}/configmaps/{name}", name=crate::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?, namespace=crate::client::path_param("namespace", self.inner.param_namespace.as_ref().expect("missing parameter namespace?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::CONTENT_TYPE.as_str(), "application/json")
+        .json(&self.inner.body)
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("dryRun", self.inner.param_dry_run.as_ref().map(std::string::ToString::to_string)),
+            ("gracePeriodSeconds", self.inner.param_grace_period_seconds.as_ref().map(std::string::ToString::to_string)),
+            ("orphanDependents", self.inner.param_orphan_dependents.as_ref().map(std::string::ToString::to_string)),
+            ("propagationPolicy", self.inner.param_propagation_policy.as_ref().map(std::string::ToString::to_string)),
+            ("pretty", self.inner.param_pretty.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Content-Type")
+            || name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<DeleteOptionsDeleteBuilder<crate::generics::NameExists, crate::generics::NamespaceExists>> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: DeleteOptionsDeleteBuilder<crate::generics::NameExists, crate::generics::NamespaceExists>) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl DeleteOptionsDeleteBuilder<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = DeleteOptionsDeleteBuilder {
+            inner: DeleteOptionsDeleteBuilderContainer {
+            body: crate::cli::read_from_input(matches)?,
+            param_dry_run: matches.and_then(|m| {
+                    m.value_of("dry-run").map(|_| {
+                        value_t!(m, "dry-run", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_grace_period_seconds: matches.and_then(|m| {
+                    m.value_of("grace-period-seconds").map(|_| {
+                        value_t!(m, "grace-period-seconds", i64).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_orphan_dependents: matches.and_then(|m| {
+                    m.value_of("orphan-dependents").map(|_| {
+                        value_t!(m, "orphan-dependents", bool).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_propagation_policy: matches.and_then(|m| {
+                    m.value_of("propagation-policy").map(|_| {
+                        value_t!(m, "propagation-policy", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_name: matches.and_then(|m| {
+                    m.value_of("name").map(|_| {
+                        value_t!(m, "name", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_namespace: matches.and_then(|m| {
+                    m.value_of("namespace").map(|_| {
+                        value_t!(m, "namespace", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_pretty: matches.and_then(|m| {
+                    m.value_of("pretty").map(|_| {
+                        value_t!(m, "pretty", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            },
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder created by [`DeleteOptions::delete_core_v1_namespaced_endpoints`](./struct.DeleteOptions.html#method.delete_core_v1_namespaced_endpoints) method for a `DELETE` operation associated with `DeleteOptions`.
+#[repr(transparent)]
+#[derive(Debug, Clone)]
+pub struct DeleteOptionsDeleteBuilder1<Name, Namespace> {
+    inner: DeleteOptionsDeleteBuilder1Container,
+    _param_name: core::marker::PhantomData<Name>,
+    _param_namespace: core::marker::PhantomData<Namespace>,
+}
+
+#[derive(Debug, Default, Clone)]
+struct DeleteOptionsDeleteBuilder1Container {
+    body: self::DeleteOptions,
+    param_dry_run: Option<String>,
+    param_grace_period_seconds: Option<i64>,
+    param_orphan_dependents: Option<bool>,
+    param_propagation_policy: Option<String>,
+    param_name: Option<String>,
+    param_namespace: Option<String>,
+    param_pretty: Option<String>,
+}
+
+impl<Name, Namespace> DeleteOptionsDeleteBuilder1<Name, Namespace> {
+    /// When present, indicates that modifications should not be persisted. An invalid or unrecognized dryRun directive will result in an error response and no further processing of the request. Valid values are: - All: all dry run stages will be processed
+    #[inline]
+    pub fn dry_run(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_dry_run = Some(value.into());
+        self
+    }
+
+    /// Sets [`dry_run`](#method.dry_run) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_dry_run(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_dry_run = value.map(|value| value.into());
+        self
+    }
+
+    /// The duration in seconds before the object should be deleted. Value must be non-negative integer. The value zero indicates delete immediately. If this value is nil, the default grace period for the specified type will be used. Defaults to a per object value if not specified. zero means delete immediately.
+    #[inline]
+    pub fn grace_period_seconds(mut self, value: impl Into<i64>) -> Self {
+        self.inner.param_grace_period_seconds = Some({
+            let val = value.into();
+            self.inner.body.grace_period_seconds = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// Same as [`grace_period_seconds`](#method.grace_period_seconds), but takes a reference.
+    #[inline]
+    pub fn grace_period_seconds_ref(self, value: &i64) -> Self {
+        self.grace_period_seconds(*value)
+    }
+
+    /// Deprecated: please use the PropagationPolicy, this field will be deprecated in 1.7. Should the dependent objects be orphaned. If true/false, the "orphan" finalizer will be added to/removed from the object's finalizers list. Either this field or PropagationPolicy may be set, but not both.
+    #[inline]
+    pub fn orphan_dependents(mut self, value: impl Into<bool>) -> Self {
+        self.inner.param_orphan_dependents = Some({
+            let val = value.into();
+            self.inner.body.orphan_dependents = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// Same as [`orphan_dependents`](#method.orphan_dependents), but takes a reference.
+    #[inline]
+    pub fn orphan_dependents_ref(self, value: &bool) -> Self {
+        self.orphan_dependents(*value)
+    }
+
+    /// Whether and how garbage collection will be performed. Either this field or OrphanDependents may be set, but not both. The default policy is decided by the existing finalizer set in the metadata.finalizers and the resource-specific default policy. Acceptable values are: 'Orphan' - orphan the dependents; 'Background' - allow the garbage collector to delete the dependents in the background; 'Foreground' - a cascading policy that deletes all dependents in the foreground.
+    #[inline]
+    pub fn propagation_policy(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_propagation_policy = Some({
+            let val = value.into();
+            self.inner.body.propagation_policy = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// name of the Endpoints
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> DeleteOptionsDeleteBuilder1<crate::generics::NameExists, Namespace> {
+        self.inner.param_name = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// object name and auth scope, such as for teams and projects
+    #[inline]
+    pub fn namespace(mut self, value: impl Into<String>) -> DeleteOptionsDeleteBuilder1<Name, crate::generics::NamespaceExists> {
+        self.inner.param_namespace = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// If 'true', then the output is pretty printed.
+    #[inline]
+    pub fn pretty(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_pretty = Some(value.into());
+        self
+    }
+
+    /// Sets [`pretty`](#method.pretty) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_pretty(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_pretty = value.map(|value| value.into());
+        self
+    }
+
+    /// APIVersion defines the versioned schema of this representation of an object. Servers should convert recognized schemas to the latest internal value, and may reject unrecognized values. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#resources
+    #[inline]
+    pub fn api_version(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.api_version = Some(value.into());
+        self
+    }
+
+    /// Sets [`api_version`](#method.api_version) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_api_version(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.api_version = value.map(|value| value.into());
+        self
+    }
+
+    /// Kind is a string value representing the REST resource this object represents. Servers may infer this from the endpoint the client submits requests to. Cannot be updated. In CamelCase. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#types-kinds
+    #[inline]
+    pub fn kind(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.kind = Some(value.into());
+        self
+    }
+
+    /// Sets [`kind`](#method.kind) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_kind(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.kind = value.map(|value| value.into());
+        self
+    }
+
+    /// Must be fulfilled before a deletion is carried out. If not possible, a 409 Conflict status will be returned.
+    #[inline]
+    pub fn preconditions(mut self, value: crate::io::k8s::apimachinery::pkg::apis::meta::v1::preconditions::Preconditions) -> Self {
+        self.inner.body.preconditions = Some(value.into());
+        self
+    }
+
+    /// Sets [`preconditions`](#method.preconditions) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_preconditions(mut self, value: Option<crate::io::k8s::apimachinery::pkg::apis::meta::v1::preconditions::Preconditions>) -> Self {
+        self.inner.body.preconditions = value.map(|value| value.into());
+        self
+    }
+}
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for DeleteOptionsDeleteBuilder1<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    type Output = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::Status;
+
+    const METHOD: http::Method = http::Method::DELETE;
+
+    const OPERATION_ID: Option<&'static str> = Some("deleteCoreV1NamespacedEndpoints");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok(format!("/api/v1/namespaces/{namespace}/endpoints/{name}", name=crate::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?, namespace=crate::client::path_param("namespace", self.inner.param_namespace.as_ref().expect("missing parameter namespace?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::CONTENT_TYPE.as_str(), "application/json")
+        .json(&self.inner.body)
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("dryRun", self.inner.param_dry_run.as_ref().map(std::string::ToString::to_string)),
+            ("gracePeriodSeconds", self.inner.param_grace_period_seconds.as_ref().map(std::string::ToString::to_string)),
+            ("orphanDependents", self.inner.param_orphan_dependents.as_ref().map(std::string::ToString::to_string)),
+            ("propagationPolicy", self.inner.param_propagation_policy.as_ref().map(std::string::ToString::to_string)),
+            ("pretty", self.inner.param_pretty.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Content-Type")
+            || name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<DeleteOptionsDeleteBuilder1<crate::generics::NameExists, crate::generics::NamespaceExists>> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: DeleteOptionsDeleteBuilder1<crate::generics::NameExists, crate::generics::NamespaceExists>) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl DeleteOptionsDeleteBuilder1<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = DeleteOptionsDeleteBuilder1 {
+            inner: DeleteOptionsDeleteBuilder1Container {
+            body: crate::cli::read_from_input(matches)?,
+            param_dry_run: matches.and_then(|m| {
+                    m.value_of("dry-run").map(|_| {
+                        value_t!(m, "dry-run", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_grace_period_seconds: matches.and_then(|m| {
+                    m.value_of("grace-period-seconds").map(|_| {
+                        value_t!(m, "grace-period-seconds", i64).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_orphan_dependents: matches.and_then(|m| {
+                    m.value_of("orphan-dependents").map(|_| {
+                        value_t!(m, "orphan-dependents", bool).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_propagation_policy: matches.and_then(|m| {
+                    m.value_of("propagation-policy").map(|_| {
+                        value_t!(m, "propagation-policy", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_name: matches.and_then(|m| {
+                    m.value_of("name").map(|_| {
+                        value_t!(m, "name", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_namespace: matches.and_then(|m| {
+                    m.value_of("namespace").map(|_| {
+                        value_t!(m, "namespace", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_pretty: matches.and_then(|m| {
+                    m.value_of("pretty").map(|_| {
+                        value_t!(m, "pretty", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            },
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder shared by operations (with the same fields and parameters) associated with `DeleteOptions`.
+#[repr(transparent)]
+#[derive(Debug, Clone)]
+pub struct DeleteOptionsOpBuilder<Op, Name, Namespace> {
+    inner: DeleteOptionsOpBuilderContainer,
+    _op: core::marker::PhantomData<Op>,
+    _param_name: core::marker::PhantomData<Name>,
+    _param_namespace: core::marker::PhantomData<Namespace>,
+}
+
+#[derive(Debug, Default, Clone)]
+struct DeleteOptionsOpBuilderContainer {
+    body: self::DeleteOptions,
+    param_dry_run: Option<String>,
+    param_grace_period_seconds: Option<i64>,
+    param_orphan_dependents: Option<bool>,
+    param_propagation_policy: Option<String>,
+    param_name: Option<String>,
+    param_namespace: Option<String>,
+    param_pretty: Option<String>,
+}
+
+/// Builder created by [`DeleteOptions::delete_core_v1_namespaced_event`](./struct.DeleteOptions.html#method.delete_core_v1_namespaced_event) method for a `DELETE` operation associated with `DeleteOptions`.
+pub type DeleteOptionsDeleteBuilder2<Name, Namespace> = DeleteOptionsOpBuilder<DeleteOptionsDeleteOp2, Name, Namespace>;
+
+/// Marks the operation of [`DeleteOptionsDeleteBuilder2`] in the shared builder.
+#[derive(Debug, Clone)]
+pub enum DeleteOptionsDeleteOp2 {}
+
+impl<Op, Name, Namespace> DeleteOptionsOpBuilder<Op, Name, Namespace> {
+    /// When present, indicates that modifications should not be persisted. An invalid or unrecognized dryRun directive will result in an error response and no further processing of the request. Valid values are: - All: all dry run stages will be processed
+    #[inline]
+    pub fn dry_run(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_dry_run = Some(value.into());
+        self
+    }
+
+    /// Sets [`dry_run`](#method.dry_run) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_dry_run(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_dry_run = value.map(|value| value.into());
+        self
+    }
+
+    /// The duration in seconds before the object should be deleted. Value must be non-negative integer. The value zero indicates delete immediately. If this value is nil, the default grace period for the specified type will be used. Defaults to a per object value if not specified. zero means delete immediately.
+    #[inline]
+    pub fn grace_period_seconds(mut self, value: impl Into<i64>) -> Self {
+        self.inner.param_grace_period_seconds = Some({
+            let val = value.into();
+            self.inner.body.grace_period_seconds = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// Same as [`grace_period_seconds`](#method.grace_period_seconds), but takes a reference.
+    #[inline]
+    pub fn grace_period_seconds_ref(self, value: &i64) -> Self {
+        self.grace_period_seconds(*value)
+    }
+
+    /// Deprecated: please use the PropagationPolicy, this field will be deprecated in 1.7. Should the dependent objects be orphaned. If true/false, the "orphan" finalizer will be added to/removed from the object's finalizers list. Either this field or PropagationPolicy may be set, but not both.
+    #[inline]
+    pub fn orphan_dependents(mut self, value: impl Into<bool>) -> Self {
+        self.inner.param_orphan_dependents = Some({
+            let val = value.into();
+            self.inner.body.orphan_dependents = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// Same as [`orphan_dependents`](#method.orphan_dependents), but takes a reference.
+    #[inline]
+    pub fn orphan_dependents_ref(self, value: &bool) -> Self {
+        self.orphan_dependents(*value)
+    }
+
+    /// Whether and how garbage collection will be performed. Either this field or OrphanDependents may be set, but not both. The default policy is decided by the existing finalizer set in the metadata.finalizers and the resource-specific default policy. Acceptable values are: 'Orphan' - orphan the dependents; 'Background' - allow the garbage collector to delete the dependents in the background; 'Foreground' - a cascading policy that deletes all dependents in the foreground.
+    #[inline]
+    pub fn propagation_policy(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_propagation_policy = Some({
+            let val = value.into();
+            self.inner.body.propagation_policy = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// name of the Event
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> DeleteOptionsOpBuilder<Op, crate::generics::NameExists, Namespace> {
+        self.inner.param_name = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// object name and auth scope, such as for teams and projects
+    #[inline]
+    pub fn namespace(mut self, value: impl Into<String>) -> DeleteOptionsOpBuilder<Op, Name, crate::generics::NamespaceExists> {
+        self.inner.param_namespace = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// If 'true', then the output is pretty printed.
+    #[inline]
+    pub fn pretty(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_pretty = Some(value.into());
+        self
+    }
+
+    /// Sets [`pretty`](#method.pretty) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_pretty(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_pretty = value.map(|value| value.into());
+        self
+    }
+
+    /// APIVersion defines the versioned schema of this representation of an object. Servers should convert recognized schemas to the latest internal value, and may reject unrecognized values. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#resources
+    #[inline]
+    pub fn api_version(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.api_version = Some(value.into());
+        self
+    }
+
+    /// Sets [`api_version`](#method.api_version) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_api_version(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.api_version = value.map(|value| value.into());
+        self
+    }
+
+    /// Kind is a string value representing the REST resource this object represents. Servers may infer this from the endpoint the client submits requests to. Cannot be updated. In CamelCase. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#types-kinds
+    #[inline]
+    pub fn kind(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.kind = Some(value.into());
+        self
+    }
+
+    /// Sets [`kind`](#method.kind) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_kind(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.kind = value.map(|value| value.into());
+        self
+    }
+
+    /// Must be fulfilled before a deletion is carried out. If not possible, a 409 Conflict status will be returned.
+    #[inline]
+    pub fn preconditions(mut self, value: crate::io::k8s::apimachinery::pkg::apis::meta::v1::preconditions::Preconditions) -> Self {
+        self.inner.body.preconditions = Some(value.into());
+        self
+    }
+
+    /// Sets [`preconditions`](#method.preconditions) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_preconditions(mut self, value: Option<crate::io::k8s::apimachinery::pkg::apis::meta::v1::preconditions::Preconditions>) -> Self {
+        self.inner.body.preconditions = value.map(|value| value.into());
+        self
+    }
+}
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for DeleteOptionsDeleteBuilder2<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    type Output = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::Status;
+
+    const METHOD: http::Method = http::Method::DELETE;
+
+    const OPERATION_ID: Option<&'static str> = Some("deleteCoreV1NamespacedEvent");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok(format!("/api/v1/namespaces/{namespace}/events/{name}", name=crate::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?, namespace=crate::client::path_param("namespace", self.inner.param_namespace.as_ref().expect("missing parameter namespace?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::CONTENT_TYPE.as_str(), "application/json")
+        .json(&self.inner.body)
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("dryRun", self.inner.param_dry_run.as_ref().map(std::string::ToString::to_string)),
+            ("gracePeriodSeconds", self.inner.param_grace_period_seconds.as_ref().map(std::string::ToString::to_string)),
+            ("orphanDependents", self.inner.param_orphan_dependents.as_ref().map(std::string::ToString::to_string)),
+            ("propagationPolicy", self.inner.param_propagation_policy.as_ref().map(std::string::ToString::to_string)),
+            ("pretty", self.inner.param_pretty.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Content-Type")
+            || name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<DeleteOptionsDeleteBuilder2<crate::generics::NameExists, crate::generics::NamespaceExists>> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: DeleteOptionsDeleteBuilder2<crate::generics::NameExists, crate::generics::NamespaceExists>) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl DeleteOptionsDeleteBuilder2<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = DeleteOptionsDeleteBuilder2 {
+            inner: DeleteOptionsOpBuilderContainer {
+            body: crate::cli::read_from_input(matches)?,
+            param_dry_run: matches.and_then(|m| {
+                    m.value_of("dry-run").map(|_| {
+                        value_t!(m, "dry-run", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_grace_period_seconds: matches.and_then(|m| {
+                    m.value_of("grace-period-seconds").map(|_| {
+                        value_t!(m, "grace-period-seconds", i64).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_orphan_dependents: matches.and_then(|m| {
+                    m.value_of("orphan-dependents").map(|_| {
+                        value_t!(m, "orphan-dependents", bool).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_propagation_policy: matches.and_then(|m| {
+                    m.value_of("propagation-policy").map(|_| {
+                        value_t!(m, "propagation-policy", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_name: matches.and_then(|m| {
+                    m.value_of("name").map(|_| {
+                        value_t!(m, "name", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_namespace: matches.and_then(|m| {
+                    m.value_of("namespace").map(|_| {
+                        value_t!(m, "namespace", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_pretty: matches.and_then(|m| {
+                    m.value_of("pretty").map(|_| {
+                        value_t!(m, "pretty", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            },
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder created by [`DeleteOptions::delete_core_v1_namespaced_limit_range`](./struct.DeleteOptions.html#method.delete_core_v1_namespaced_limit_range) method for a `DELETE` operation associated with `DeleteOptions`.
+#[repr(transparent)]
+#[derive(Debug, Clone)]
+pub struct DeleteOptionsDeleteBuilder3<Name, Namespace> {
+    inner: DeleteOptionsDeleteBuilder3Container,
+    _param_name: core::marker::PhantomData<Name>,
+    _param_namespace: core::marker::PhantomData<Namespace>,
+}
+
+#[derive(Debug, Default, Clone)]
+struct DeleteOptionsDeleteBuilder3Container {
+    body: self::DeleteOptions,
+    param_dry_run: Option<String>,
+    param_grace_period_seconds: Option<i64>,
+    param_orphan_dependents: Option<bool>,
+    param_propagation_policy: Option<String>,
+    param_name: Option<String>,
+    param_namespace: Option<String>,
+    param_pretty: Option<String>,
+}
+
+impl<Name, Namespace> DeleteOptionsDeleteBuilder3<Name, Namespace> {
+    /// When present, indicates that modifications should not be persisted. An invalid or unrecognized dryRun directive will result in an error response and no further processing of the request. Valid values are: - All: all dry run stages will be processed
+    #[inline]
+    pub fn dry_run(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_dry_run = Some(value.into());
+        self
+    }
+
+    /// Sets [`dry_run`](#method.dry_run) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_dry_run(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_dry_run = value.map(|value| value.into());
+        self
+    }
+
+    /// The duration in seconds before the object should be deleted. Value must be non-negative integer. The value zero indicates delete immediately. If this value is nil, the default grace period for the specified type will be used. Defaults to a per object value if not specified. zero means delete immediately.
+    #[inline]
+    pub fn grace_period_seconds(mut self, value: impl Into<i64>) -> Self {
+        self.inner.param_grace_period_seconds = Some({
+            let val = value.into();
+            self.inner.body.grace_period_seconds = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// Same as [`grace_period_seconds`](#method.grace_period_seconds), but takes a reference.
+    #[inline]
+    pub fn grace_period_seconds_ref(self, value: &i64) -> Self {
+        self.grace_period_seconds(*value)
+    }
+
+    /// Deprecated: please use the PropagationPolicy, this field will be deprecated in 1.7. Should the dependent objects be orphaned. If true/false, the "orphan" finalizer will be added to/removed from the object's finalizers list. Either this field or PropagationPolicy may be set, but not both.
+    #[inline]
+    pub fn orphan_dependents(mut self, value: impl Into<bool>) -> Self {
+        self.inner.param_orphan_dependents = Some({
+            let val = value.into();
+            self.inner.body.orphan_dependents = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// Same as [`orphan_dependents`](#method.orphan_dependents), but takes a reference.
+    #[inline]
+    pub fn orphan_dependents_ref(self, value: &bool) -> Self {
+        self.orphan_dependents(*value)
+    }
+
+    /// Whether and how garbage collection will be performed. Either this field or OrphanDependents may be set, but not both. The default policy is decided by the existing finalizer set in the metadata.finalizers and the resource-specific default policy. Acceptable values are: 'Orphan' - orphan the dependents; 'Background' - allow the garbage collector to delete the dependents in the background; 'Foreground' - a cascading policy that deletes all dependents in the foreground.
+    #[inline]
+    pub fn propagation_policy(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_propagation_policy = Some({
+            let val = value.into();
+            self.inner.body.propagation_policy = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// name of the LimitRange
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> DeleteOptionsDeleteBuilder3<crate::generics::NameExists, Namespace> {
+        self.inner.param_name = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// object name and auth scope, such as for teams and projects
+    #[inline]
+    pub fn namespace(mut self, value: impl Into<String>) -> DeleteOptionsDeleteBuilder3<Name, crate::generics::NamespaceExists> {
+        self.inner.param_namespace = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// If 'true', then the output is pretty printed.
+    #[inline]
+    pub fn pretty(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_pretty = Some(value.into());
+        self
+    }
+
+    /// Sets [`pretty`](#method.pretty) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_pretty(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_pretty = value.map(|value| value.into());
+        self
+    }
+
+    /// APIVersion defines the versioned schema of this representation of an object. Servers should convert recognized schemas to the latest internal value, and may reject unrecognized values. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#resources
+    #[inline]
+    pub fn api_version(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.api_version = Some(value.into());
+        self
+    }
+
+    /// Sets [`api_version`](#method.api_version) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_api_version(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.api_version = value.map(|value| value.into());
+        self
+    }
+
+    /// Kind is a string value representing the REST resource this object represents. Servers may infer this from the endpoint the client submits requests to. Cannot be updated. In CamelCase. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#types-kinds
+    #[inline]
+    pub fn kind(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.kind = Some(value.into());
+        self
+    }
+
+    /// Sets [`kind`](#method.kind) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_kind(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.kind = value.map(|value| value.into());
+        self
+    }
+
+    /// Must be fulfilled before a deletion is carried out. If not possible, a 409 Conflict status will be returned.
+    #[inline]
+    pub fn preconditions(mut self, value: crate::io::k8s::apimachinery::pkg::apis::meta::v1::preconditions::Preconditions) -> Self {
+        self.inner.body.preconditions = Some(value.into());
+        self
+    }
+
+    /// Sets [`preconditions`](#method.preconditions) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_preconditions(mut self, value: Option<crate::io::k8s::apimachinery::pkg::apis::meta::v1::preconditions::Preconditions>) -> Self {
+        self.inner.body.preconditions = value.map(|value| value.into());
+        self
+    }
+}
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for DeleteOptionsDeleteBuilder3<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    type Output = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::Status;
+
+    const METHOD: http::Method = http::Method::DELETE;
+
+    const OPERATION_ID: Option<&'static str> = Some("deleteCoreV1NamespacedLimitRange");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok(format!("/api/v1/namespaces/{namespace}/limitranges/{name}", name=crate::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?, namespace=crate::client::path_param("namespace", self.inner.param_namespace.as_ref().expect("missing parameter namespace?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::CONTENT_TYPE.as_str(), "application/json")
+        .json(&self.inner.body)
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("dryRun", self.inner.param_dry_run.as_ref().map(std::string::ToString::to_string)),
+            ("gracePeriodSeconds", self.inner.param_grace_period_seconds.as_ref().map(std::string::ToString::to_string)),
+            ("orphanDependents", self.inner.param_orphan_dependents.as_ref().map(std::string::ToString::to_string)),
+            ("propagationPolicy", self.inner.param_propagation_policy.as_ref().map(std::string::ToString::to_string)),
+            ("pretty", self.inner.param_pretty.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Content-Type")
+            || name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<DeleteOptionsDeleteBuilder3<crate::generics::NameExists, crate::generics::NamespaceExists>> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: DeleteOptionsDeleteBuilder3<crate::generics::NameExists, crate::generics::NamespaceExists>) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl DeleteOptionsDeleteBuilder3<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = DeleteOptionsDeleteBuilder3 {
+            inner: DeleteOptionsDeleteBuilder3Container {
+            body: crate::cli::read_from_input(matches)?,
+            param_dry_run: matches.and_then(|m| {
+                    m.value_of("dry-run").map(|_| {
+                        value_t!(m, "dry-run", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_grace_period_seconds: matches.and_then(|m| {
+                    m.value_of("grace-period-seconds").map(|_| {
+                        value_t!(m, "grace-period-seconds", i64).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_orphan_dependents: matches.and_then(|m| {
+                    m.value_of("orphan-dependents").map(|_| {
+                        value_t!(m, "orphan-dependents", bool).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_propagation_policy: matches.and_then(|m| {
+                    m.value_of("propagation-policy").map(|_| {
+                        value_t!(m, "propagation-policy", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_name: matches.and_then(|m| {
+                    m.value_of("name").map(|_| {
+                        value_t!(m, "name", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_namespace: matches.and_then(|m| {
+                    m.value_of("namespace").map(|_| {
+                        value_t!(m, "namespace", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_pretty: matches.and_then(|m| {
+                    m.value_of("pretty").map(|_| {
+                        value_t!(m, "pretty", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            },
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder created by [`DeleteOptions::delete_core_v1_namespaced_persistent_volume_claim`](./struct.DeleteOptions.html#method.delete_core_v1_namespaced_persistent_volume_claim) method for a `DELETE` operation associated with `DeleteOptions`.
+#[repr(transparent)]
+#[derive(Debug, Clone)]
+pub struct DeleteOptionsDeleteBuilder4<Name, Namespace> {
+    inner: DeleteOptionsDeleteBuilder4Container,
+    _param_name: core::marker::PhantomData<Name>,
+    _param_namespace: core::marker::PhantomData<Namespace>,
+}
+
+#[derive(Debug, Default, Clone)]
+struct DeleteOptionsDeleteBuilder4Container {
+    body: self::DeleteOptions,
+    param_dry_run: Option<String>,
+    param_grace_period_seconds: Option<i64>,
+    param_orphan_dependents: Option<bool>,
+    param_propagation_policy: Option<String>,
+    param_name: Option<String>,
+    param_namespace: Option<String>,
+    param_pretty: Option<String>,
+}
+
+impl<Name, Namespace> DeleteOptionsDeleteBuilder4<Name, Namespace> {
+    /// When present, indicates that modifications should not be persisted. An invalid or unrecognized dryRun directive will result in an error response and no further processing of the request. Valid values are: - All: all dry run stages will be processed
+    #[inline]
+    pub fn dry_run(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_dry_run = Some(value.into());
+        self
+    }
+
+    /// Sets [`dry_run`](#method.dry_run) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_dry_run(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_dry_run = value.map(|value| value.into());
+        self
+    }
+
+    /// The duration in seconds before the object should be deleted. Value must be non-negative integer. The value zero indicates delete immediately. If this value is nil, the default grace period for the specified type will be used. Defaults to a per object value if not specified. zero means delete immediately.
+    #[inline]
+    pub fn grace_period_seconds(mut self, value: impl Into<i64>) -> Self {
+        self.inner.param_grace_period_seconds = Some({
+            let val = value.into();
+            self.inner.body.grace_period_seconds = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// Same as [`grace_period_seconds`](#method.grace_period_seconds), but takes a reference.
+    #[inline]
+    pub fn grace_period_seconds_ref(self, value: &i64) -> Self {
+        self.grace_period_seconds(*value)
+    }
+
+    /// Deprecated: please use the PropagationPolicy, this field will be deprecated in 1.7. Should the dependent objects be orphaned. If true/false, the "orphan" finalizer will be added to/removed from the object's finalizers list. Either this field or PropagationPolicy may be set, but not both.
+    #[inline]
+    pub fn orphan_dependents(mut self, value: impl Into<bool>) -> Self {
+        self.inner.param_orphan_dependents = Some({
+            let val = value.into();
+            self.inner.body.orphan_dependents = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// Same as [`orphan_dependents`](#method.orphan_dependents), but takes a reference.
+    #[inline]
+    pub fn orphan_dependents_ref(self, value: &bool) -> Self {
+        self.orphan_dependents(*value)
+    }
+
+    /// Whether and how garbage collection will be performed. Either this field or OrphanDependents may be set, but not both. The default policy is decided by the existing finalizer set in the metadata.finalizers and the resource-specific default policy. Acceptable values are: 'Orphan' - orphan the dependents; 'Background' - allow the garbage collector to delete the dependents in the background; 'Foreground' - a cascading policy that deletes all dependents in the foreground.
+    #[inline]
+    pub fn propagation_policy(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_propagation_policy = Some({
+            let val = value.into();
+            self.inner.body.propagation_policy = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// name of the PersistentVolumeClaim
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> DeleteOptionsDeleteBuilder4<crate::generics::NameExists, Namespace> {
+        self.inner.param_name = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// object name and auth scope, such as for teams and projects
+    #[inline]
+    pub fn namespace(mut self, value: impl Into<String>) -> DeleteOptionsDeleteBuilder4<Name, crate::generics::NamespaceExists> {
+        self.inner.param_namespace = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// If 'true', then the output is pretty printed.
+    #[inline]
+    pub fn pretty(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_pretty = Some(value.into());
+        self
+    }
+
+    /// Sets [`pretty`](#method.pretty) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_pretty(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_pretty = value.map(|value| value.into());
+        self
+    }
+
+    /// APIVersion defines the versioned schema of this representation of an object. Servers should convert recognized schemas to the latest internal value, and may reject unrecognized values. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#resources
+    #[inline]
+    pub fn api_version(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.api_version = Some(value.into());
+        self
+    }
+
+    /// Sets [`api_version`](#method.api_version) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_api_version(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.api_version = value.map(|value| value.into());
+        self
+    }
+
+    /// Kind is a string value representing the REST resource this object represents. Servers may infer this from the endpoint the client submits requests to. Cannot be updated. In CamelCase. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#types-kinds
+    #[inline]
+    pub fn kind(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.kind = Some(value.into());
+        self
+    }
+
+    /// Sets [`kind`](#method.kind) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_kind(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.kind = value.map(|value| value.into());
+        self
+    }
+
+    /// Must be fulfilled before a deletion is carried out. If not possible, a 409 Conflict status will be returned.
+    #[inline]
+    pub fn preconditions(mut self, value: crate::io::k8s::apimachinery::pkg::apis::meta::v1::preconditions::Preconditions) -> Self {
+        self.inner.body.preconditions = Some(value.into());
+        self
+    }
+
+    /// Sets [`preconditions`](#method.preconditions) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_preconditions(mut self, value: Option<crate::io::k8s::apimachinery::pkg::apis::meta::v1::preconditions::Preconditions>) -> Self {
+        self.inner.body.preconditions = value.map(|value| value.into());
+        self
+    }
+}
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for DeleteOptionsDeleteBuilder4<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    type Output = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::Status;
+
+    const METHOD: http::Method = http::Method::DELETE;
+
+    const OPERATION_ID: Option<&'static str> = Some("deleteCoreV1NamespacedPersistentVolumeClaim");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok(format!("/api/v1/namespaces/{namespace}/persistentvolumeclaims/{name}", name=crate::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?, namespace=crate::client::path_param("namespace", self.inner.param_namespace.as_ref().expect("missing parameter namespace?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::CONTENT_TYPE.as_str(), "application/json")
+        .json(&self.inner.body)
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("dryRun", self.inner.param_dry_run.as_ref().map(std::string::ToString::to_string)),
+            ("gracePeriodSeconds", self.inner.param_grace_period_seconds.as_ref().map(std::string::ToString::to_string)),
+            ("orphanDependents", self.inner.param_orphan_dependents.as_ref().map(std::string::ToString::to_string)),
+            ("propagationPolicy", self.inner.param_propagation_policy.as_ref().map(std::string::ToString::to_string)),
+            ("pretty", self.inner.param_pretty.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Content-Type")
+            || name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<DeleteOptionsDeleteBuilder4<crate::generics::NameExists, crate::generics::NamespaceExists>> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: DeleteOptionsDeleteBuilder4<crate::generics::NameExists, crate::generics::NamespaceExists>) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl DeleteOptionsDeleteBuilder4<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = DeleteOptionsDeleteBuilder4 {
+            inner: DeleteOptionsDeleteBuilder4Container {
+            body: crate::cli::read_from_input(matches)?,
+            param_dry_run: matches.and_then(|m| {
+                    m.value_of("dry-run").map(|_| {
+                        value_t!(m, "dry-run", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_grace_period_seconds: matches.and_then(|m| {
+                    m.value_of("grace-period-seconds").map(|_| {
+                        value_t!(m, "grace-period-seconds", i64).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_orphan_dependents: matches.and_then(|m| {
+                    m.value_of("orphan-dependents").map(|_| {
+                        value_t!(m, "orphan-dependents", bool).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_propagation_policy: matches.and_then(|m| {
+                    m.value_of("propagation-policy").map(|_| {
+                        value_t!(m, "propagation-policy", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_name: matches.and_then(|m| {
+                    m.value_of("name").map(|_| {
+                        value_t!(m, "name", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_namespace: matches.and_then(|m| {
+                    m.value_of("namespace").map(|_| {
+                        value_t!(m, "namespace", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_pretty: matches.and_then(|m| {
+                    m.value_of("pretty").map(|_| {
+                        value_t!(m, "pretty", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            },
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder created by [`DeleteOptions::delete_core_v1_namespaced_pod`](./struct.DeleteOptions.html#method.delete_core_v1_namespaced_pod) method for a `DELETE` operation associated with `DeleteOptions`.
+#[repr(transparent)]
+#[derive(Debug, Clone)]
+pub struct DeleteOptionsDeleteBuilder5<Name, Namespace> {
+    inner: DeleteOptionsDeleteBuilder5Container,
+    _param_name: core::marker::PhantomData<Name>,
+    _param_namespace: core::marker::PhantomData<Namespace>,
+}
+
+#[derive(Debug, Default, Clone)]
+struct DeleteOptionsDeleteBuilder5Container {
+    body: self::DeleteOptions,
+    param_dry_run: Option<String>,
+    param_grace_period_seconds: Option<i64>,
+    param_orphan_dependents: Option<bool>,
+    param_propagation_policy: Option<String>,
+    param_name: Option<String>,
+    param_namespace: Option<String>,
+    param_pretty: Option<String>,
+}
+
+impl<Name, Namespace> DeleteOptionsDeleteBuilder5<Name, Namespace> {
+    /// When present, indicates that modifications should not be persisted. An invalid or unrecognized dryRun directive will result in an error response and no further processing of the request. Valid values are: - All: all dry run stages will be processed
+    #[inline]
+    pub fn dry_run(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_dry_run = Some(value.into());
+        self
+    }
+
+    /// Sets [`dry_run`](#method.dry_run) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_dry_run(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_dry_run = value.map(|value| value.into());
+        self
+    }
+
+    /// The duration in seconds before the object should be deleted. Value must be non-negative integer. The value zero indicates delete immediately. If this value is nil, the default grace period for the specified type will be used. Defaults to a per object value if not specified. zero means delete immediately.
+    #[inline]
+    pub fn grace_period_seconds(mut self, value: impl Into<i64>) -> Self {
+        self.inner.param_grace_period_seconds = Some({
+            let val = value.into();
+            self.inner.body.grace_period_seconds = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// Same as [`grace_period_seconds`](#method.grace_period_seconds), but takes a reference.
+    #[inline]
+    pub fn grace_period_seconds_ref(self, value: &i64) -> Self {
+        self.grace_period_seconds(*value)
+    }
+
+    /// Deprecated: please use the PropagationPolicy, this field will be deprecated in 1.7. Should the dependent objects be orphaned. If true/false, the "orphan" finalizer will be added to/removed from the object's finalizers list. Either this field or PropagationPolicy may be set, but not both.
+    #[inline]
+    pub fn orphan_dependents(mut self, value: impl Into<bool>) -> Self {
+        self.inner.param_orphan_dependents = Some({
+            let val = value.into();
+            self.inner.body.orphan_dependents = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// Same as [`orphan_dependents`](#method.orphan_dependents), but takes a reference.
+    #[inline]
+    pub fn orphan_dependents_ref(self, value: &bool) -> Self {
+        self.orphan_dependents(*value)
+    }
+
+    /// Whether and how garbage collection will be performed. Either this field or OrphanDependents may be set, but not both. The default policy is decided by the existing finalizer set in the metadata.finalizers and the resource-specific default policy. Acceptable values are: 'Orphan' - orphan the dependents; 'Background' - allow the garbage collector to delete the dependents in the background; 'Foreground' - a cascading policy that deletes all dependents in the foreground.
+    #[inline]
+    pub fn propagation_policy(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_propagation_policy = Some({
+            let val = value.into();
+            self.inner.body.propagation_policy = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// name of the Pod
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> DeleteOptionsDeleteBuilder5<crate::generics::NameExists, Namespace> {
+        self.inner.param_name = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// object name and auth scope, such as for teams and projects
+    #[inline]
+    pub fn namespace(mut self, value: impl Into<String>) -> DeleteOptionsDeleteBuilder5<Name, crate::generics::NamespaceExists> {
+        self.inner.param_namespace = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// If 'true', then the output is pretty printed.
+    #[inline]
+    pub fn pretty(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_pretty = Some(value.into());
+        self
+    }
+
+    /// Sets [`pretty`](#method.pretty) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_pretty(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_pretty = value.map(|value| value.into());
+        self
+    }
+
+    /// APIVersion defines the versioned schema of this representation of an object. Servers should convert recognized schemas to the latest internal value, and may reject unrecognized values. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#resources
+    #[inline]
+    pub fn api_version(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.api_version = Some(value.into());
+        self
+    }
+
+    /// Sets [`api_version`](#method.api_version) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_api_version(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.api_version = value.map(|value| value.into());
+        self
+    }
+
+    /// Kind is a string value representing the REST resource this object represents. Servers may infer this from the endpoint the client submits requests to. Cannot be updated. In CamelCase. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#types-kinds
+    #[inline]
+    pub fn kind(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.kind = Some(value.into());
+        self
+    }
+
+    /// Sets [`kind`](#method.kind) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_kind(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.kind = value.map(|value| value.into());
+        self
+    }
+
+    /// Must be fulfilled before a deletion is carried out. If not possible, a 409 Conflict status will be returned.
+    #[inline]
+    pub fn preconditions(mut self, value: crate::io::k8s::apimachinery::pkg::apis::meta::v1::preconditions::Preconditions) -> Self {
+        self.inner.body.preconditions = Some(value.into());
+        self
+    }
+
+    /// Sets [`preconditions`](#method.preconditions) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_preconditions(mut self, value: Option<crate::io::k8s::apimachinery::pkg::apis::meta::v1::preconditions::Preconditions>) -> Self {
+        self.inner.body.preconditions = value.map(|value| value.into());
+        self
+    }
+}
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for DeleteOptionsDeleteBuilder5<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    type Output = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::Status;
+
+    const METHOD: http::Method = http::Method::DELETE;
+
+    const OPERATION_ID: Option<&'static str> = Some("deleteCoreV1NamespacedPod");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok(format!("/api/v1/namespaces/{namespace}/pods/{name}", name=crate::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?, namespace=crate::client::path_param("namespace", self.inner.param_namespace.as_ref().expect("missing parameter namespace?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::CONTENT_TYPE.as_str(), "application/json")
+        .json(&self.inner.body)
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("dryRun", self.inner.param_dry_run.as_ref().map(std::string::ToString::to_string)),
+            ("gracePeriodSeconds", self.inner.param_grace_period_seconds.as_ref().map(std::string::ToString::to_string)),
+            ("orphanDependents", self.inner.param_orphan_dependents.as_ref().map(std::string::ToString::to_string)),
+            ("propagationPolicy", self.inner.param_propagation_policy.as_ref().map(std::string::ToString::to_string)),
+            ("pretty", self.inner.param_pretty.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Content-Type")
+            || name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<DeleteOptionsDeleteBuilder5<crate::generics::NameExists, crate::generics::NamespaceExists>> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: DeleteOptionsDeleteBuilder5<crate::generics::NameExists, crate::generics::NamespaceExists>) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl DeleteOptionsDeleteBuilder5<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = DeleteOptionsDeleteBuilder5 {
+            inner: DeleteOptionsDeleteBuilder5Container {
+            body: crate::cli::read_from_input(matches)?,
+            param_dry_run: matches.and_then(|m| {
+                    m.value_of("dry-run").map(|_| {
+                        value_t!(m, "dry-run", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_grace_period_seconds: matches.and_then(|m| {
+                    m.value_of("grace-period-seconds").map(|_| {
+                        value_t!(m, "grace-period-seconds", i64).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_orphan_dependents: matches.and_then(|m| {
+                    m.value_of("orphan-dependents").map(|_| {
+                        value_t!(m, "orphan-dependents", bool).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_propagation_policy: matches.and_then(|m| {
+                    m.value_of("propagation-policy").map(|_| {
+                        value_t!(m, "propagation-policy", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_name: matches.and_then(|m| {
+                    m.value_of("name").map(|_| {
+                        value_t!(m, "name", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_namespace: matches.and_then(|m| {
+                    m.value_of("namespace").map(|_| {
+                        value_t!(m, "namespace", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_pretty: matches.and_then(|m| {
+                    m.value_of("pretty").map(|_| {
+                        value_t!(m, "pretty", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            },
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder created by [`DeleteOptions::delete_core_v1_namespaced_pod_template`](./struct.DeleteOptions.html#method.delete_core_v1_namespaced_pod_template) method for a `DELETE` operation associated with `DeleteOptions`.
+#[repr(transparent)]
+#[derive(Debug, Clone)]
+pub struct DeleteOptionsDeleteBuilder6<Name, Namespace> {
+    inner: DeleteOptionsDeleteBuilder6Container,
+    _param_name: core::marker::PhantomData<Name>,
+    _param_namespace: core::marker::PhantomData<Namespace>,
+}
+
+#[derive(Debug, Default, Clone)]
+struct DeleteOptionsDeleteBuilder6Container {
+    body: self::DeleteOptions,
+    param_dry_run: Option<String>,
+    param_grace_period_seconds: Option<i64>,
+    param_orphan_dependents: Option<bool>,
+    param_propagation_policy: Option<String>,
+    param_name: Option<String>,
+    param_namespace: Option<String>,
+    param_pretty: Option<String>,
+}
+
+impl<Name, Namespace> DeleteOptionsDeleteBuilder6<Name, Namespace> {
+    /// When present, indicates that modifications should not be persisted. An invalid or unrecognized dryRun directive will result in an error response and no further processing of the request. Valid values are: - All: all dry run stages will be processed
+    #[inline]
+    pub fn dry_run(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_dry_run = Some(value.into());
+        self
+    }
+
+    /// Sets [`dry_run`](#method.dry_run) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_dry_run(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_dry_run = value.map(|value| value.into());
+        self
+    }
+
+    /// The duration in seconds before the object should be deleted. Value must be non-negative integer. The value zero indicates delete immediately. If this value is nil, the default grace period for the specified type will be used. Defaults to a per object value if not specified. zero means delete immediately.
+    #[inline]
+    pub fn grace_period_seconds(mut self, value: impl Into<i64>) -> Self {
+        self.inner.param_grace_period_seconds = Some({
+            let val = value.into();
+            self.inner.body.grace_period_seconds = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// Same as [`grace_period_seconds`](#method.grace_period_seconds), but takes a reference.
+    #[inline]
+    pub fn grace_period_seconds_ref(self, value: &i64) -> Self {
+        self.grace_period_seconds(*value)
+    }
+
+    /// Deprecated: please use the PropagationPolicy, this field will be deprecated in 1.7. Should the dependent objects be orphaned. If true/false, the "orphan" finalizer will be added to/removed from the object's finalizers list. Either this field or PropagationPolicy may be set, but not both.
+    #[inline]
+    pub fn orphan_dependents(mut self, value: impl Into<bool>) -> Self {
+        self.inner.param_orphan_dependents = Some({
+            let val = value.into();
+            self.inner.body.orphan_dependents = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// Same as [`orphan_dependents`](#method.orphan_dependents), but takes a reference.
+    #[inline]
+    pub fn orphan_dependents_ref(self, value: &bool) -> Self {
+        self.orphan_dependents(*value)
+    }
+
+    /// Whether and how garbage collection will be performed. Either this field or OrphanDependents may be set, but not both. The default policy is decided by the existing finalizer set in the metadata.finalizers and the resource-specific default policy. Acceptable values are: 'Orphan' - orphan the dependents; 'Background' - allow the garbage collector to delete the dependents in the background; 'Foreground' - a cascading policy that deletes all dependents in the foreground.
+    #[inline]
+    pub fn propagation_policy(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_propagation_policy = Some({
+            let val = value.into();
+            self.inner.body.propagation_policy = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// name of the PodTemplate
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> DeleteOptionsDeleteBuilder6<crate::generics::NameExists, Namespace> {
+        self.inner.param_name = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// object name and auth scope, such as for teams and projects
+    #[inline]
+    pub fn namespace(mut self, value: impl Into<String>) -> DeleteOptionsDeleteBuilder6<Name, crate::generics::NamespaceExists> {
+        self.inner.param_namespace = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// If 'true', then the output is pretty printed.
+    #[inline]
+    pub fn pretty(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_pretty = Some(value.into());
+        self
+    }
+
+    /// Sets [`pretty`](#method.pretty) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_pretty(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_pretty = value.map(|value| value.into());
+        self
+    }
+
+    /// APIVersion defines the versioned schema of this representation of an object. Servers should convert recognized schemas to the latest internal value, and may reject unrecognized values. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#resources
+    #[inline]
+    pub fn api_version(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.api_version = Some(value.into());
+        self
+    }
+
+    /// Sets [`api_version`](#method.api_version) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_api_version(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.api_version = value.map(|value| value.into());
+        self
+    }
+
+    /// Kind is a string value representing the REST resource this object represents. Servers may infer this from the endpoint the client submits requests to. Cannot be updated. In CamelCase. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#types-kinds
+    #[inline]
+    pub fn kind(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.kind = Some(value.into());
+        self
+    }
+
+    /// Sets [`kind`](#method.kind) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_kind(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.kind = value.map(|value| value.into());
+        self
+    }
+
+    /// Must be fulfilled before a deletion is carried out. If not possible, a 409 Conflict status will be returned.
+    #[inline]
+    pub fn preconditions(mut self, value: crate::io::k8s::apimachinery::pkg::apis::meta::v1::preconditions::Preconditions) -> Self {
+        self.inner.body.preconditions = Some(value.into());
+        self
+    }
+
+    /// Sets [`preconditions`](#method.preconditions) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_preconditions(mut self, value: Option<crate::io::k8s::apimachinery::pkg::apis::meta::v1::preconditions::Preconditions>) -> Self {
+        self.inner.body.preconditions = value.map(|value| value.into());
+        self
+    }
+}
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for DeleteOptionsDeleteBuilder6<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    type Output = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::Status;
+
+    const METHOD: http::Method = http::Method::DELETE;
+
+    const OPERATION_ID: Option<&'static str> = Some("deleteCoreV1NamespacedPodTemplate");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok(format!("/api/v1/namespaces/{namespace}/podtemplates/{name}", name=crate::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?, namespace=crate::client::path_param("namespace", self.inner.param_namespace.as_ref().expect("missing parameter namespace?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::CONTENT_TYPE.as_str(), "application/json")
+        .json(&self.inner.body)
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("dryRun", self.inner.param_dry_run.as_ref().map(std::string::ToString::to_string)),
+            ("gracePeriodSeconds", self.inner.param_grace_period_seconds.as_ref().map(std::string::ToString::to_string)),
+            ("orphanDependents", self.inner.param_orphan_dependents.as_ref().map(std::string::ToString::to_string)),
+            ("propagationPolicy", self.inner.param_propagation_policy.as_ref().map(std::string::ToString::to_string)),
+            ("pretty", self.inner.param_pretty.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Content-Type")
+            || name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<DeleteOptionsDeleteBuilder6<crate::generics::NameExists, crate::generics::NamespaceExists>> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: DeleteOptionsDeleteBuilder6<crate::generics::NameExists, crate::generics::NamespaceExists>) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl DeleteOptionsDeleteBuilder6<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = DeleteOptionsDeleteBuilder6 {
+            inner: DeleteOptionsDeleteBuilder6Container {
+            body: crate::cli::read_from_input(matches)?,
+            param_dry_run: matches.and_then(|m| {
+                    m.value_of("dry-run").map(|_| {
+                        value_t!(m, "dry-run", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_grace_period_seconds: matches.and_then(|m| {
+                    m.value_of("grace-period-seconds").map(|_| {
+                        value_t!(m, "grace-period-seconds", i64).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_orphan_dependents: matches.and_then(|m| {
+                    m.value_of("orphan-dependents").map(|_| {
+                        value_t!(m, "orphan-dependents", bool).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_propagation_policy: matches.and_then(|m| {
+                    m.value_of("propagation-policy").map(|_| {
+                        value_t!(m, "propagation-policy", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_name: matches.and_then(|m| {
+                    m.value_of("name").map(|_| {
+                        value_t!(m, "name", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_namespace: matches.and_then(|m| {
+                    m.value_of("namespace").map(|_| {
+                        value_t!(m, "namespace", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_pretty: matches.and_then(|m| {
+                    m.value_of("pretty").map(|_| {
+                        value_t!(m, "pretty", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            },
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder created by [`DeleteOptions::delete_core_v1_namespaced_replication_controller`](./struct.DeleteOptions.html#method.delete_core_v1_namespaced_replication_controller) method for a `DELETE` operation associated with `DeleteOptions`.
+#[repr(transparent)]
+#[derive(Debug, Clone)]
+pub struct DeleteOptionsDeleteBuilder7<Name, Namespace> {
+    inner: DeleteOptionsDeleteBuilder7Container,
+    _param_name: core::marker::PhantomData<Name>,
+    _param_namespace: core::marker::PhantomData<Namespace>,
+}
+
+#[derive(Debug, Default, Clone)]
+struct DeleteOptionsDeleteBuilder7Container {
+    body: self::DeleteOptions,
+    param_dry_run: Option<String>,
+    param_grace_period_seconds: Option<i64>,
+    param_orphan_dependents: Option<bool>,
+    param_propagation_policy: Option<String>,
+    param_name: Option<String>,
+    param_namespace: Option<String>,
+    param_pretty: Option<String>,
+}
+
+impl<Name, Namespace> DeleteOptionsDeleteBuilder7<Name, Namespace> {
+    /// When present, indicates that modifications should not be persisted. An invalid or unrecognized dryRun directive will result in an error response and no further processing of the request. Valid values are: - All: all dry run stages will be processed
+    #[inline]
+    pub fn dry_run(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_dry_run = Some(value.into());
+        self
+    }
+
+    /// Sets [`dry_run`](#method.dry_run) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_dry_run(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_dry_run = value.map(|value| value.into());
+        self
+    }
+
+    /// The duration in seconds before the object should be deleted. Value must be non-negative integer. The value zero indicates delete immediately. If this value is nil, the default grace period for the specified type will be used. Defaults to a per object value if not specified. zero means delete immediately.
+    #[inline]
+    pub fn grace_period_seconds(mut self, value: impl Into<i64>) -> Self {
+        self.inner.param_grace_period_seconds = Some({
+            let val = value.into();
+            self.inner.body.grace_period_seconds = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// Same as [`grace_period_seconds`](#method.grace_period_seconds), but takes a reference.
+    #[inline]
+    pub fn grace_period_seconds_ref(self, value: &i64) -> Self {
+        self.grace_period_seconds(*value)
+    }
+
+    /// Deprecated: please use the PropagationPolicy, this field will be deprecated in 1.7. Should the dependent objects be orphaned. If true/false, the "orphan" finalizer will be added to/removed from the object's finalizers list. Either this field or PropagationPolicy may be set, but not both.
+    #[inline]
+    pub fn orphan_dependents(mut self, value: impl Into<bool>) -> Self {
+        self.inner.param_orphan_dependents = Some({
+            let val = value.into();
+            self.inner.body.orphan_dependents = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// Same as [`orphan_dependents`](#method.orphan_dependents), but takes a reference.
+    #[inline]
+    pub fn orphan_dependents_ref(self, value: &bool) -> Self {
+        self.orphan_dependents(*value)
+    }
+
+    /// Whether and how garbage collection will be performed. Either this field or OrphanDependents may be set, but not both. The default policy is decided by the existing finalizer set in the metadata.finalizers and the resource-specific default policy. Acceptable values are: 'Orphan' - orphan the dependents; 'Background' - allow the garbage collector to delete the dependents in the background; 'Foreground' - a cascading policy that deletes all dependents in the foreground.
+    #[inline]
+    pub fn propagation_policy(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_propagation_policy = Some({
+            let val = value.into();
+            self.inner.body.propagation_policy = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// name of the ReplicationController
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> DeleteOptionsDeleteBuilder7<crate::generics::NameExists, Namespace> {
+        self.inner.param_name = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// object name and auth scope, such as for teams and projects
+    #[inline]
+    pub fn namespace(mut self, value: impl Into<String>) -> DeleteOptionsDeleteBuilder7<Name, crate::generics::NamespaceExists> {
+        self.inner.param_namespace = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// If 'true', then the output is pretty printed.
+    #[inline]
+    pub fn pretty(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_pretty = Some(value.into());
+        self
+    }
+
+    /// Sets [`pretty`](#method.pretty) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_pretty(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_pretty = value.map(|value| value.into());
+        self
+    }
+
+    /// APIVersion defines the versioned schema of this representation of an object. Servers should convert recognized schemas to the latest internal value, and may reject unrecognized values. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#resources
+    #[inline]
+    pub fn api_version(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.api_version = Some(value.into());
+        self
+    }
+
+    /// Sets [`api_version`](#method.api_version) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_api_version(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.api_version = value.map(|value| value.into());
+        self
+    }
+
+    /// Kind is a string value representing the REST resource this object represents. Servers may infer this from the endpoint the client submits requests to. Cannot be updated. In CamelCase. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#types-kinds
+    #[inline]
+    pub fn kind(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.kind = Some(value.into());
+        self
+    }
+
+    /// Sets [`kind`](#method.kind) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_kind(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.kind = value.map(|value| value.into());
+        self
+    }
+
+    /// Must be fulfilled before a deletion is carried out. If not possible, a 409 Conflict status will be returned.
+    #[inline]
+    pub fn preconditions(mut self, value: crate::io::k8s::apimachinery::pkg::apis::meta::v1::preconditions::Preconditions) -> Self {
+        self.inner.body.preconditions = Some(value.into());
+        self
+    }
+
+    /// Sets [`preconditions`](#method.preconditions) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_preconditions(mut self, value: Option<crate::io::k8s::apimachinery::pkg::apis::meta::v1::preconditions::Preconditions>) -> Self {
+        self.inner.body.preconditions = value.map(|value| value.into());
+        self
+    }
+}
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for DeleteOptionsDeleteBuilder7<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    type Output = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::Status;
+
+    const METHOD: http::Method = http::Method::DELETE;
+
+    const OPERATION_ID: Option<&'static str> = Some("deleteCoreV1NamespacedReplicationController");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok(format!("/api/v1/namespaces/{namespace}/replicationcontrollers/{name}", name=crate::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?, namespace=crate::client::path_param("namespace", self.inner.param_namespace.as_ref().expect("missing parameter namespace?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::CONTENT_TYPE.as_str(), "application/json")
+        .json(&self.inner.body)
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("dryRun", self.inner.param_dry_run.as_ref().map(std::string::ToString::to_string)),
+            ("gracePeriodSeconds", self.inner.param_grace_period_seconds.as_ref().map(std::string::ToString::to_string)),
+            ("orphanDependents", self.inner.param_orphan_dependents.as_ref().map(std::string::ToString::to_string)),
+            ("propagationPolicy", self.inner.param_propagation_policy.as_ref().map(std::string::ToString::to_string)),
+            ("pretty", self.inner.param_pretty.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Content-Type")
+            || name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<DeleteOptionsDeleteBuilder7<crate::generics::NameExists, crate::generics::NamespaceExists>> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: DeleteOptionsDeleteBuilder7<crate::generics::NameExists, crate::generics::NamespaceExists>) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl DeleteOptionsDeleteBuilder7<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = DeleteOptionsDeleteBuilder7 {
+            inner: DeleteOptionsDeleteBuilder7Container {
+            body: crate::cli::read_from_input(matches)?,
+            param_dry_run: matches.and_then(|m| {
+                    m.value_of("dry-run").map(|_| {
+                        value_t!(m, "dry-run", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_grace_period_seconds: matches.and_then(|m| {
+                    m.value_of("grace-period-seconds").map(|_| {
+                        value_t!(m, "grace-period-seconds", i64).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_orphan_dependents: matches.and_then(|m| {
+                    m.value_of("orphan-dependents").map(|_| {
+                        value_t!(m, "orphan-dependents", bool).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_propagation_policy: matches.and_then(|m| {
+                    m.value_of("propagation-policy").map(|_| {
+                        value_t!(m, "propagation-policy", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_name: matches.and_then(|m| {
+                    m.value_of("name").map(|_| {
+                        value_t!(m, "name", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_namespace: matches.and_then(|m| {
+                    m.value_of("namespace").map(|_| {
+                        value_t!(m, "namespace", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_pretty: matches.and_then(|m| {
+                    m.value_of("pretty").map(|_| {
+                        value_t!(m, "pretty", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            },
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder created by [`DeleteOptions::delete_core_v1_namespaced_resource_quota`](./struct.DeleteOptions.html#method.delete_core_v1_namespaced_resource_quota) method for a `DELETE` operation associated with `DeleteOptions`.
+#[repr(transparent)]
+#[derive(Debug, Clone)]
+pub struct DeleteOptionsDeleteBuilder8<Name, Namespace> {
+    inner: DeleteOptionsDeleteBuilder8Container,
+    _param_name: core::marker::PhantomData<Name>,
+    _param_namespace: core::marker::PhantomData<Namespace>,
+}
+
+#[derive(Debug, Default, Clone)]
+struct DeleteOptionsDeleteBuilder8Container {
+    body: self::DeleteOptions,
+    param_dry_run: Option<String>,
+    param_grace_period_seconds: Option<i64>,
+    param_orphan_dependents: Option<bool>,
+    param_propagation_policy: Option<String>,
+    param_name: Option<String>,
+    param_namespace: Option<String>,
+    param_pretty: Option<String>,
+}
+
+impl<Name, Namespace> DeleteOptionsDeleteBuilder8<Name, Namespace> {
+    /// When present, indicates that modifications should not be persisted. An invalid or unrecognized dryRun directive will result in an error response and no further processing of the request. Valid values are: - All: all dry run stages will be processed
+    #[inline]
+    pub fn dry_run(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_dry_run = Some(value.into());
+        self
+    }
+
+    /// Sets [`dry_run`](#method.dry_run) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_dry_run(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_dry_run = value.map(|value| value.into());
+        self
+    }
+
+    /// The duration in seconds before the object should be deleted. Value must be non-negative integer. The value zero indicates delete immediately. If this value is nil, the default grace period for the specified type will be used. Defaults to a per object value if not specified. zero means delete immediately.
+    #[inline]
+    pub fn grace_period_seconds(mut self, value: impl Into<i64>) -> Self {
+        self.inner.param_grace_period_seconds = Some({
+            let val = value.into();
+            self.inner.body.grace_period_seconds = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// Same as [`grace_period_seconds`](#method.grace_period_seconds), but takes a reference.
+    #[inline]
+    pub fn grace_period_seconds_ref(self, value: &i64) -> Self {
+        self.grace_period_seconds(*value)
+    }
+
+    /// Deprecated: please use the PropagationPolicy, this field will be deprecated in 1.7. Should the dependent objects be orphaned. If true/false, the "orphan" finalizer will be added to/removed from the object's finalizers list. Either this field or PropagationPolicy may be set, but not both.
+    #[inline]
+    pub fn orphan_dependents(mut self, value: impl Into<bool>) -> Self {
+        self.inner.param_orphan_dependents = Some({
+            let val = value.into();
+            self.inner.body.orphan_dependents = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// Same as [`orphan_dependents`](#method.orphan_dependents), but takes a reference.
+    #[inline]
+    pub fn orphan_dependents_ref(self, value: &bool) -> Self {
+        self.orphan_dependents(*value)
+    }
+
+    /// Whether and how garbage collection will be performed. Either this field or OrphanDependents may be set, but not both. The default policy is decided by the existing finalizer set in the metadata.finalizers and the resource-specific default policy. Acceptable values are: 'Orphan' - orphan the dependents; 'Background' - allow the garbage collector to delete the dependents in the background; 'Foreground' - a cascading policy that deletes all dependents in the foreground.
+    #[inline]
+    pub fn propagation_policy(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_propagation_policy = Some({
+            let val = value.into();
+            self.inner.body.propagation_policy = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// name of the ResourceQuota
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> DeleteOptionsDeleteBuilder8<crate::generics::NameExists, Namespace> {
+        self.inner.param_name = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// object name and auth scope, such as for teams and projects
+    #[inline]
+    pub fn namespace(mut self, value: impl Into<String>) -> DeleteOptionsDeleteBuilder8<Name, crate::generics::NamespaceExists> {
+        self.inner.param_namespace = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// If 'true', then the output is pretty printed.
+    #[inline]
+    pub fn pretty(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_pretty = Some(value.into());
+        self
+    }
+
+    /// Sets [`pretty`](#method.pretty) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_pretty(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_pretty = value.map(|value| value.into());
+        self
+    }
+
+    /// APIVersion defines the versioned schema of this representation of an object. Servers should convert recognized schemas to the latest internal value, and may reject unrecognized values. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#resources
+    #[inline]
+    pub fn api_version(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.api_version = Some(value.into());
+        self
+    }
+
+    /// Sets [`api_version`](#method.api_version) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_api_version(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.api_version = value.map(|value| value.into());
+        self
+    }
+
+    /// Kind is a string value representing the REST resource this object represents. Servers may infer this from the endpoint the client submits requests to. Cannot be updated. In CamelCase. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#types-kinds
+    #[inline]
+    pub fn kind(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.kind = Some(value.into());
+        self
+    }
+
+    /// Sets [`kind`](#method.kind) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_kind(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.kind = value.map(|value| value.into());
+        self
+    }
+
+    /// Must be fulfilled before a deletion is carried out. If not possible, a 409 Conflict status will be returned.
+    #[inline]
+    pub fn preconditions(mut self, value: crate::io::k8s::apimachinery::pkg::apis::meta::v1::preconditions::Preconditions) -> Self {
+        self.inner.body.preconditions = Some(value.into());
+        self
+    }
+
+    /// Sets [`preconditions`](#method.preconditions) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_preconditions(mut self, value: Option<crate::io::k8s::apimachinery::pkg::apis::meta::v1::preconditions::Preconditions>) -> Self {
+        self.inner.body.preconditions = value.map(|value| value.into());
+        self
+    }
+}
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for DeleteOptionsDeleteBuilder8<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    type Output = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::Status;
+
+    const METHOD: http::Method = http::Method::DELETE;
+
+    const OPERATION_ID: Option<&'static str> = Some("deleteCoreV1NamespacedResourceQuota");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok(format!("/api/v1/namespaces/{namespace}/resourcequotas/{name}", name=crate::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?, namespace=crate::client::path_param("namespace", self.inner.param_namespace.as_ref().expect("missing parameter namespace?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::CONTENT_TYPE.as_str(), "application/json")
+        .json(&self.inner.body)
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("dryRun", self.inner.param_dry_run.as_ref().map(std::string::ToString::to_string)),
+            ("gracePeriodSeconds", self.inner.param_grace_period_seconds.as_ref().map(std::string::ToString::to_string)),
+            ("orphanDependents", self.inner.param_orphan_dependents.as_ref().map(std::string::ToString::to_string)),
+            ("propagationPolicy", self.inner.param_propagation_policy.as_ref().map(std::string::ToString::to_string)),
+            ("pretty", self.inner.param_pretty.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Content-Type")
+            || name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<DeleteOptionsDeleteBuilder8<crate::generics::NameExists, crate::generics::NamespaceExists>> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: DeleteOptionsDeleteBuilder8<crate::generics::NameExists, crate::generics::NamespaceExists>) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl DeleteOptionsDeleteBuilder8<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = DeleteOptionsDeleteBuilder8 {
+            inner: DeleteOptionsDeleteBuilder8Container {
+            body: crate::cli::read_from_input(matches)?,
+            param_dry_run: matches.and_then(|m| {
+                    m.value_of("dry-run").map(|_| {
+                        value_t!(m, "dry-run", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_grace_period_seconds: matches.and_then(|m| {
+                    m.value_of("grace-period-seconds").map(|_| {
+                        value_t!(m, "grace-period-seconds", i64).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_orphan_dependents: matches.and_then(|m| {
+                    m.value_of("orphan-dependents").map(|_| {
+                        value_t!(m, "orphan-dependents", bool).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_propagation_policy: matches.and_then(|m| {
+                    m.value_of("propagation-policy").map(|_| {
+                        value_t!(m, "propagation-policy", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_name: matches.and_then(|m| {
+                    m.value_of("name").map(|_| {
+                        value_t!(m, "name", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_namespace: matches.and_then(|m| {
+                    m.value_of("namespace").map(|_| {
+                        value_t!(m, "namespace", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_pretty: matches.and_then(|m| {
+                    m.value_of("pretty").map(|_| {
+                        value_t!(m, "pretty", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            },
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder created by [`DeleteOptions::delete_core_v1_namespaced_secret`](./struct.DeleteOptions.html#method.delete_core_v1_namespaced_secret) method for a `DELETE` operation associated with `DeleteOptions`.
+#[repr(transparent)]
+#[derive(Debug, Clone)]
+pub struct DeleteOptionsDeleteBuilder9<Name, Namespace> {
+    inner: DeleteOptionsDeleteBuilder9Container,
+    _param_name: core::marker::PhantomData<Name>,
+    _param_namespace: core::marker::PhantomData<Namespace>,
+}
+
+#[derive(Debug, Default, Clone)]
+struct DeleteOptionsDeleteBuilder9Container {
+    body: self::DeleteOptions,
+    param_dry_run: Option<String>,
+    param_grace_period_seconds: Option<i64>,
+    param_orphan_dependents: Option<bool>,
+    param_propagation_policy: Option<String>,
+    param_name: Option<String>,
+    param_namespace: Option<String>,
+    param_pretty: Option<String>,
+}
+
+impl<Name, Namespace> DeleteOptionsDeleteBuilder9<Name, Namespace> {
+    /// When present, indicates that modifications should not be persisted. An invalid or unrecognized dryRun directive will result in an error response and no further processing of the request. Valid values are: - All: all dry run stages will be processed
+    #[inline]
+    pub fn dry_run(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_dry_run = Some(value.into());
+        self
+    }
+
+    /// Sets [`dry_run`](#method.dry_run) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_dry_run(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_dry_run = value.map(|value| value.into());
+        self
+    }
+
+    /// The duration in seconds before the object should be deleted. Value must be non-negative integer. The value zero indicates delete immediately. If this value is nil, the default grace period for the specified type will be used. Defaults to a per object value if not specified. zero means delete immediately.
+    #[inline]
+    pub fn grace_period_seconds(mut self, value: impl Into<i64>) -> Self {
+        self.inner.param_grace_period_seconds = Some({
+            let val = value.into();
+            self.inner.body.grace_period_seconds = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// Same as [`grace_period_seconds`](#method.grace_period_seconds), but takes a reference.
+    #[inline]
+    pub fn grace_period_seconds_ref(self, value: &i64) -> Self {
+        self.grace_period_seconds(*value)
+    }
+
+    /// Deprecated: please use the PropagationPolicy, this field will be deprecated in 1.7. Should the dependent objects be orphaned. If true/false, the "orphan" finalizer will be added to/removed from the object's finalizers list. Either this field or PropagationPolicy may be set, but not both.
+    #[inline]
+    pub fn orphan_dependents(mut self, value: impl Into<bool>) -> Self {
+        self.inner.param_orphan_dependents = Some({
+            let val = value.into();
+            self.inner.body.orphan_dependents = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// Same as [`orphan_dependents`](#method.orphan_dependents), but takes a reference.
+    #[inline]
+    pub fn orphan_dependents_ref(self, value: &bool) -> Self {
+        self.orphan_dependents(*value)
+    }
+
+    /// Whether and how garbage collection will be performed. Either this field or OrphanDependents may be set, but not both. The default policy is decided by the existing finalizer set in the metadata.finalizers and the resource-specific default policy. Acceptable values are: 'Orphan' - orphan the dependents; 'Background' - allow the garbage collector to delete the dependents in the background; 'Foreground' - a cascading policy that deletes all dependents in the foreground.
+    #[inline]
+    pub fn propagation_policy(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_propagation_policy = Some({
+            let val = value.into();
+            self.inner.body.propagation_policy = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// name of the Secret
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> DeleteOptionsDeleteBuilder9<crate::generics::NameExists, Namespace> {
+        self.inner.param_name = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// object name and auth scope, such as for teams and projects
+    #[inline]
+    pub fn namespace(mut self, value: impl Into<String>) -> DeleteOptionsDeleteBuilder9<Name, crate::generics::NamespaceExists> {
+        self.inner.param_namespace = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// If 'true', then the output is pretty printed.
+    #[inline]
+    pub fn pretty(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_pretty = Some(value.into());
+        self
+    }
+
+    /// Sets [`pretty`](#method.pretty) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_pretty(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_pretty = value.map(|value| value.into());
+        self
+    }
+
+    /// APIVersion defines the versioned schema of this representation of an object. Servers should convert recognized schemas to the latest internal value, and may reject unrecognized values. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#resources
+    #[inline]
+    pub fn api_version(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.api_version = Some(value.into());
+        self
+    }
+
+    /// Sets [`api_version`](#method.api_version) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_api_version(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.api_version = value.map(|value| value.into());
+        self
+    }
+
+    /// Kind is a string value representing the REST resource this object represents. Servers may infer this from the endpoint the client submits requests to. Cannot be updated. In CamelCase. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#types-kinds
+    #[inline]
+    pub fn kind(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.kind = Some(value.into());
+        self
+    }
+
+    /// Sets [`kind`](#method.kind) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_kind(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.kind = value.map(|value| value.into());
+        self
+    }
+
+    /// Must be fulfilled before a deletion is carried out. If not possible, a 409 Conflict status will be returned.
+    #[inline]
+    pub fn preconditions(mut self, value: crate::io::k8s::apimachinery::pkg::apis::meta::v1::preconditions::Preconditions) -> Self {
+        self.inner.body.preconditions = Some(value.into());
+        self
+    }
+
+    /// Sets [`preconditions`](#method.preconditions) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_preconditions(mut self, value: Option<crate::io::k8s::apimachinery::pkg::apis::meta::v1::preconditions::Preconditions>) -> Self {
+        self.inner.body.preconditions = value.map(|value| value.into());
+        self
+    }
+}
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for DeleteOptionsDeleteBuilder9<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    type Output = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::Status;
+
+    const METHOD: http::Method = http::Method::DELETE;
+
+    const OPERATION_ID: Option<&'static str> = Some("deleteCoreV1NamespacedSecret");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok(format!("/api/v1/namespaces/{namespace}/secrets/{name}", name=crate::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?, namespace=crate::client::path_param("namespace", self.inner.param_namespace.as_ref().expect("missing parameter namespace?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::CONTENT_TYPE.as_str(), "application/json")
+        .json(&self.inner.body)
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("dryRun", self.inner.param_dry_run.as_ref().map(std::string::ToString::to_string)),
+            ("gracePeriodSeconds", self.inner.param_grace_period_seconds.as_ref().map(std::string::ToString::to_string)),
+            ("orphanDependents", self.inner.param_orphan_dependents.as_ref().map(std::string::ToString::to_string)),
+            ("propagationPolicy", self.inner.param_propagation_policy.as_ref().map(std::string::ToString::to_string)),
+            ("pretty", self.inner.param_pretty.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Content-Type")
+            || name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<DeleteOptionsDeleteBuilder9<crate::generics::NameExists, crate::generics::NamespaceExists>> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: DeleteOptionsDeleteBuilder9<crate::generics::NameExists, crate::generics::NamespaceExists>) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl DeleteOptionsDeleteBuilder9<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = DeleteOptionsDeleteBuilder9 {
+            inner: DeleteOptionsDeleteBuilder9Container {
+            body: crate::cli::read_from_input(matches)?,
+            param_dry_run: matches.and_then(|m| {
+                    m.value_of("dry-run").map(|_| {
+                        value_t!(m, "dry-run", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_grace_period_seconds: matches.and_then(|m| {
+                    m.value_of("grace-period-seconds").map(|_| {
+                        value_t!(m, "grace-period-seconds", i64).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_orphan_dependents: matches.and_then(|m| {
+                    m.value_of("orphan-dependents").map(|_| {
+                        value_t!(m, "orphan-dependents", bool).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_propagation_policy: matches.and_then(|m| {
+                    m.value_of("propagation-policy").map(|_| {
+                        value_t!(m, "propagation-policy", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_name: matches.and_then(|m| {
+                    m.value_of("name").map(|_| {
+                        value_t!(m, "name", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_namespace: matches.and_then(|m| {
+                    m.value_of("namespace").map(|_| {
+                        value_t!(m, "namespace", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_pretty: matches.and_then(|m| {
+                    m.value_of("pretty").map(|_| {
+                        value_t!(m, "pretty", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            },
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder created by [`DeleteOptions::delete_core_v1_namespaced_service_account`](./struct.DeleteOptions.html#method.delete_core_v1_namespaced_service_account) method for a `DELETE` operation associated with `DeleteOptions`.
+#[repr(transparent)]
+#[derive(Debug, Clone)]
+pub struct DeleteOptionsDeleteBuilder10<Name, Namespace> {
+    inner: DeleteOptionsDeleteBuilder10Container,
+    _param_name: core::marker::PhantomData<Name>,
+    _param_namespace: core::marker::PhantomData<Namespace>,
+}
+
+#[derive(Debug, Default, Clone)]
+struct DeleteOptionsDeleteBuilder10Container {
+    body: self::DeleteOptions,
+    param_dry_run: Option<String>,
+    param_grace_period_seconds: Option<i64>,
+    param_orphan_dependents: Option<bool>,
+    param_propagation_policy: Option<String>,
+    param_name: Option<String>,
+    param_namespace: Option<String>,
+    param_pretty: Option<String>,
+}
+
+impl<Name, Namespace> DeleteOptionsDeleteBuilder10<Name, Namespace> {
+    /// When present, indicates that modifications should not be persisted. An invalid or unrecognized dryRun directive will result in an error response and no further processing of the request. Valid values are: - All: all dry run stages will be processed
+    #[inline]
+    pub fn dry_run(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_dry_run = Some(value.into());
+        self
+    }
+
+    /// Sets [`dry_run`](#method.dry_run) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_dry_run(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_dry_run = value.map(|value| value.into());
+        self
+    }
+
+    /// The duration in seconds before the object should be deleted. Value must be non-negative integer. The value zero indicates delete immediately. If this value is nil, the default grace period for the specified type will be used. Defaults to a per object value if not specified. zero means delete immediately.
+    #[inline]
+    pub fn grace_period_seconds(mut self, value: impl Into<i64>) -> Self {
+        self.inner.param_grace_period_seconds = Some({
+            let val = value.into();
+            self.inner.body.grace_period_seconds = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// Same as [`grace_period_seconds`](#method.grace_period_seconds), but takes a reference.
+    #[inline]
+    pub fn grace_period_seconds_ref(self, value: &i64) -> Self {
+        self.grace_period_seconds(*value)
+    }
+
+    /// Deprecated: please use the PropagationPolicy, this field will be deprecated in 1.7. Should the dependent objects be orphaned. If true/false, the "orphan" finalizer will be added to/removed from the object's finalizers list. Either this field or PropagationPolicy may be set, but not both.
+    #[inline]
+    pub fn orphan_dependents(mut self, value: impl Into<bool>) -> Self {
+        self.inner.param_orphan_dependents = Some({
+            let val = value.into();
+            self.inner.body.orphan_dependents = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// Same as [`orphan_dependents`](#method.orphan_dependents), but takes a reference.
+    #[inline]
+    pub fn orphan_dependents_ref(self, value: &bool) -> Self {
+        self.orphan_dependents(*value)
+    }
+
+    /// Whether and how garbage collection will be performed. Either this field or OrphanDependents may be set, but not both. The default policy is decided by the existing finalizer set in the metadata.finalizers and the resource-specific default policy. Acceptable values are: 'Orphan' - orphan the dependents; 'Background' - allow the garbage collector to delete the dependents in the background; 'Foreground' - a cascading policy that deletes all dependents in the foreground.
+    #[inline]
+    pub fn propagation_policy(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_propagation_policy = Some({
+            let val = value.into();
+            self.inner.body.propagation_policy = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// name of the ServiceAccount
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> DeleteOptionsDeleteBuilder10<crate::generics::NameExists, Namespace> {
+        self.inner.param_name = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// object name and auth scope, such as for teams and projects
+    #[inline]
+    pub fn namespace(mut self, value: impl Into<String>) -> DeleteOptionsDeleteBuilder10<Name, crate::generics::NamespaceExists> {
+        self.inner.param_namespace = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// If 'true', then the output is pretty printed.
+    #[inline]
+    pub fn pretty(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_pretty = Some(value.into());
+        self
+    }
+
+    /// Sets [`pretty`](#method.pretty) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_pretty(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_pretty = value.map(|value| value.into());
+        self
+    }
+
+    /// APIVersion defines the versioned schema of this representation of an object. Servers should convert recognized schemas to the latest internal value, and may reject unrecognized values. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#resources
+    #[inline]
+    pub fn api_version(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.api_version = Some(value.into());
+        self
+    }
+
+    /// Sets [`api_version`](#method.api_version) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_api_version(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.api_version = value.map(|value| value.into());
+        self
+    }
+
+    /// Kind is a string value representing the REST resource this object represents. Servers may infer this from the endpoint the client submits requests to. Cannot be updated. In CamelCase. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#types-kinds
+    #[inline]
+    pub fn kind(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.kind = Some(value.into());
+        self
+    }
+
+    /// Sets [`kind`](#method.kind) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_kind(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.kind = value.map(|value| value.into());
+        self
+    }
+
+    /// Must be fulfilled before a deletion is carried out. If not possible, a 409 Conflict status will be returned.
+    #[inline]
+    pub fn preconditions(mut self, value: crate::io::k8s::apimachinery::pkg::apis::meta::v1::preconditions::Preconditions) -> Self {
+        self.inner.body.preconditions = Some(value.into());
+        self
+    }
+
+    /// Sets [`preconditions`](#method.preconditions) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_preconditions(mut self, value: Option<crate::io::k8s::apimachinery::pkg::apis::meta::v1::preconditions::Preconditions>) -> Self {
+        self.inner.body.preconditions = value.map(|value| value.into());
+        self
+    }
+}
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for DeleteOptionsDeleteBuilder10<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    type Output = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::Status;
+
+    const METHOD: http::Method = http::Method::DELETE;
+
+    const OPERATION_ID: Option<&'static str> = Some("deleteCoreV1NamespacedServiceAccount");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok(format!("/api/v1/namespaces/{namespace}/serviceaccounts/{name}", name=crate::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?, namespace=crate::client::path_param("namespace", self.inner.param_namespace.as_ref().expect("missing parameter namespace?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::CONTENT_TYPE.as_str(), "application/json")
+        .json(&self.inner.body)
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("dryRun", self.inner.param_dry_run.as_ref().map(std::string::ToString::to_string)),
+            ("gracePeriodSeconds", self.inner.param_grace_period_seconds.as_ref().map(std::string::ToString::to_string)),
+            ("orphanDependents", self.inner.param_orphan_dependents.as_ref().map(std::string::ToString::to_string)),
+            ("propagationPolicy", self.inner.param_propagation_policy.as_ref().map(std::string::ToString::to_string)),
+            ("pretty", self.inner.param_pretty.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Content-Type")
+            || name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<DeleteOptionsDeleteBuilder10<crate::generics::NameExists, crate::generics::NamespaceExists>> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: DeleteOptionsDeleteBuilder10<crate::generics::NameExists, crate::generics::NamespaceExists>) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl DeleteOptionsDeleteBuilder10<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = DeleteOptionsDeleteBuilder10 {
+            inner: DeleteOptionsDeleteBuilder10Container {
+            body: crate::cli::read_from_input(matches)?,
+            param_dry_run: matches.and_then(|m| {
+                    m.value_of("dry-run").map(|_| {
+                        value_t!(m, "dry-run", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_grace_period_seconds: matches.and_then(|m| {
+                    m.value_of("grace-period-seconds").map(|_| {
+                        value_t!(m, "grace-period-seconds", i64).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_orphan_dependents: matches.and_then(|m| {
+                    m.value_of("orphan-dependents").map(|_| {
+                        value_t!(m, "orphan-dependents", bool).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_propagation_policy: matches.and_then(|m| {
+                    m.value_of("propagation-policy").map(|_| {
+                        value_t!(m, "propagation-policy", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_name: matches.and_then(|m| {
+                    m.value_of("name").map(|_| {
+                        value_t!(m, "name", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_namespace: matches.and_then(|m| {
+                    m.value_of("namespace").map(|_| {
+                        value_t!(m, "namespace", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_pretty: matches.and_then(|m| {
+                    m.value_of("pretty").map(|_| {
+                        value_t!(m, "pretty", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            },
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder created by [`DeleteOptions::delete_core_v1_namespaced_service`](./struct.DeleteOptions.html#method.delete_core_v1_namespaced_service) method for a `DELETE` operation associated with `DeleteOptions`.
+#[repr(transparent)]
+#[derive(Debug, Clone)]
+pub struct DeleteOptionsDeleteBuilder11<Name, Namespace> {
+    inner: DeleteOptionsDeleteBuilder11Container,
+    _param_name: core::marker::PhantomData<Name>,
+    _param_namespace: core::marker::PhantomData<Namespace>,
+}
+
+#[derive(Debug, Default, Clone)]
+struct DeleteOptionsDeleteBuilder11Container {
+    body: self::DeleteOptions,
+    param_dry_run: Option<String>,
+    param_grace_period_seconds: Option<i64>,
+    param_orphan_dependents: Option<bool>,
+    param_propagation_policy: Option<String>,
+    param_name: Option<String>,
+    param_namespace: Option<String>,
+    param_pretty: Option<String>,
+}
+
+impl<Name, Namespace> DeleteOptionsDeleteBuilder11<Name, Namespace> {
+    /// When present, indicates that modifications should not be persisted. An invalid or unrecognized dryRun directive will result in an error response and no further processing of the request. Valid values are: - All: all dry run stages will be processed
+    #[inline]
+    pub fn dry_run(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_dry_run = Some(value.into());
+        self
+    }
+
+    /// Sets [`dry_run`](#method.dry_run) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_dry_run(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_dry_run = value.map(|value| value.into());
+        self
+    }
+
+    /// The duration in seconds before the object should be deleted. Value must be non-negative integer. The value zero indicates delete immediately. If this value is nil, the default grace period for the specified type will be used. Defaults to a per object value if not specified. zero means delete immediately.
+    #[inline]
+    pub fn grace_period_seconds(mut self, value: impl Into<i64>) -> Self {
+        self.inner.param_grace_period_seconds = Some({
+            let val = value.into();
+            self.inner.body.grace_period_seconds = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// Same as [`grace_period_seconds`](#method.grace_period_seconds), but takes a reference.
+    #[inline]
+    pub fn grace_period_seconds_ref(self, value: &i64) -> Self {
+        self.grace_period_seconds(*value)
+    }
+
+    /// Deprecated: please use the PropagationPolicy, this field will be deprecated in 1.7. Should the dependent objects be orphaned. If true/false, the "orphan" finalizer will be added to/removed from the object's finalizers list. Either this field or PropagationPolicy may be set, but not both.
+    #[inline]
+    pub fn orphan_dependents(mut self, value: impl Into<bool>) -> Self {
+        self.inner.param_orphan_dependents = Some({
+            let val = value.into();
+            self.inner.body.orphan_dependents = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// Same as [`orphan_dependents`](#method.orphan_dependents), but takes a reference.
+    #[inline]
+    pub fn orphan_dependents_ref(self, value: &bool) -> Self {
+        self.orphan_dependents(*value)
+    }
+
+    /// Whether and how garbage collection will be performed. Either this field or OrphanDependents may be set, but not both. The default policy is decided by the existing finalizer set in the metadata.finalizers and the resource-specific default policy. Acceptable values are: 'Orphan' - orphan the dependents; 'Background' - allow the garbage collector to delete the dependents in the background; 'Foreground' - a cascading policy that deletes all dependents in the foreground.
+    #[inline]
+    pub fn propagation_policy(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_propagation_policy = Some({
+            let val = value.into();
+            self.inner.body.propagation_policy = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// name of the Service
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> DeleteOptionsDeleteBuilder11<crate::generics::NameExists, Namespace> {
+        self.inner.param_name = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// object name and auth scope, such as for teams and projects
+    #[inline]
+    pub fn namespace(mut self, value: impl Into<String>) -> DeleteOptionsDeleteBuilder11<Name, crate::generics::NamespaceExists> {
+        self.inner.param_namespace = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// If 'true', then the output is pretty printed.
+    #[inline]
+    pub fn pretty(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_pretty = Some(value.into());
+        self
+    }
+
+    /// Sets [`pretty`](#method.pretty) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_pretty(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_pretty = value.map(|value| value.into());
+        self
+    }
+
+    /// APIVersion defines the versioned schema of this representation of an object. Servers should convert recognized schemas to the latest internal value, and may reject unrecognized values. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#resources
+    #[inline]
+    pub fn api_version(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.api_version = Some(value.into());
+        self
+    }
+
+    /// Sets [`api_version`](#method.api_version) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_api_version(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.api_version = value.map(|value| value.into());
+        self
+    }
+
+    /// Kind is a string value representing the REST resource this object represents. Servers may infer this from the endpoint the client submits requests to. Cannot be updated. In CamelCase. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#types-kinds
+    #[inline]
+    pub fn kind(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.kind = Some(value.into());
+        self
+    }
+
+    /// Sets [`kind`](#method.kind) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_kind(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.kind = value.map(|value| value.into());
+        self
+    }
+
+    /// Must be fulfilled before a deletion is carried out. If not possible, a 409 Conflict status will be returned.
+    #[inline]
+    pub fn preconditions(mut self, value: crate::io::k8s::apimachinery::pkg::apis::meta::v1::preconditions::Preconditions) -> Self {
+        self.inner.body.preconditions = Some(value.into());
+        self
+    }
+
+    /// Sets [`preconditions`](#method.preconditions) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_preconditions(mut self, value: Option<crate::io::k8s::apimachinery::pkg::apis::meta::v1::preconditions::Preconditions>) -> Self {
+        self.inner.body.preconditions = value.map(|value| value.into());
+        self
+    }
+}
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for DeleteOptionsDeleteBuilder11<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    type Output = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::Status;
+
+    const METHOD: http::Method = http::Method::DELETE;
+
+    const OPERATION_ID: Option<&'static str> = Some("deleteCoreV1NamespacedService");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok(format!("/api/v1/namespaces/{namespace}/services/{name}", name=crate::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?, namespace=crate::client::path_param("namespace", self.inner.param_namespace.as_ref().expect("missing parameter namespace?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::CONTENT_TYPE.as_str(), "application/json")
+        .json(&self.inner.body)
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("dryRun", self.inner.param_dry_run.as_ref().map(std::string::ToString::to_string)),
+            ("gracePeriodSeconds", self.inner.param_grace_period_seconds.as_ref().map(std::string::ToString::to_string)),
+            ("orphanDependents", self.inner.param_orphan_dependents.as_ref().map(std::string::ToString::to_string)),
+            ("propagationPolicy", self.inner.param_propagation_policy.as_ref().map(std::string::ToString::to_string)),
+            ("pretty", self.inner.param_pretty.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Content-Type")
+            || name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<DeleteOptionsDeleteBuilder11<crate::generics::NameExists, crate::generics::NamespaceExists>> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: DeleteOptionsDeleteBuilder11<crate::generics::NameExists, crate::generics::NamespaceExists>) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl DeleteOptionsDeleteBuilder11<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = DeleteOptionsDeleteBuilder11 {
+            inner: DeleteOptionsDeleteBuilder11Container {
+            body: crate::cli::read_from_input(matches)?,
+            param_dry_run: matches.and_then(|m| {
+                    m.value_of("dry-run").map(|_| {
+                        value_t!(m, "dry-run", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_grace_period_seconds: matches.and_then(|m| {
+                    m.value_of("grace-period-seconds").map(|_| {
+                        value_t!(m, "grace-period-seconds", i64).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_orphan_dependents: matches.and_then(|m| {
+                    m.value_of("orphan-dependents").map(|_| {
+                        value_t!(m, "orphan-dependents", bool).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_propagation_policy: matches.and_then(|m| {
+                    m.value_of("propagation-policy").map(|_| {
+                        value_t!(m, "propagation-policy", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_name: matches.and_then(|m| {
+                    m.value_of("name").map(|_| {
+                        value_t!(m, "name", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_namespace: matches.and_then(|m| {
+                    m.value_of("namespace").map(|_| {
+                        value_t!(m, "namespace", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_pretty: matches.and_then(|m| {
+                    m.value_of("pretty").map(|_| {
+                        value_t!(m, "pretty", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            },
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder created by [`DeleteOptions::delete_core_v1_namespace`](./struct.DeleteOptions.html#method.delete_core_v1_namespace) method for a `DELETE` operation associated with `DeleteOptions`.
+#[repr(transparent)]
+#[derive(Debug, Clone)]
+pub struct DeleteOptionsDeleteBuilder12<Name> {
+    inner: DeleteOptionsDeleteBuilder12Container,
+    _param_name: core::marker::PhantomData<Name>,
+}
+
+#[derive(Debug, Default, Clone)]
+struct DeleteOptionsDeleteBuilder12Container {
+    body: self::DeleteOptions,
+    param_dry_run: Option<String>,
+    param_grace_period_seconds: Option<i64>,
+    param_orphan_dependents: Option<bool>,
+    param_propagation_policy: Option<String>,
+    param_name: Option<String>,
+    param_pretty: Option<String>,
+}
+
+impl<Name> DeleteOptionsDeleteBuilder12<Name> {
+    /// When present, indicates that modifications should not be persisted. An invalid or unrecognized dryRun directive will result in an error response and no further processing of the request. Valid values are: - All: all dry run stages will be processed
+    #[inline]
+    pub fn dry_run(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_dry_run = Some(value.into());
+        self
+    }
+
+    /// Sets [`dry_run`](#method.dry_run) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_dry_run(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_dry_run = value.map(|value| value.into());
+        self
+    }
+
+    /// The duration in seconds before the object should be deleted. Value must be non-negative integer. The value zero indicates delete immediately. If this value is nil, the default grace period for the specified type will be used. Defaults to a per object value if not specified. zero means delete immediately.
+    #[inline]
+    pub fn grace_period_seconds(mut self, value: impl Into<i64>) -> Self {
+        self.inner.param_grace_period_seconds = Some({
+            let val = value.into();
+            self.inner.body.grace_period_seconds = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// Same as [`grace_period_seconds`](#method.grace_period_seconds), but takes a reference.
+    #[inline]
+    pub fn grace_period_seconds_ref(self, value: &i64) -> Self {
+        self.grace_period_seconds(*value)
+    }
+
+    /// Deprecated: please use the PropagationPolicy, this field will be deprecated in 1.7. Should the dependent objects be orphaned. If true/false, the "orphan" finalizer will be added to/removed from the object's finalizers list. Either this field or PropagationPolicy may be set, but not both.
+    #[inline]
+    pub fn orphan_dependents(mut self, value: impl Into<bool>) -> Self {
+        self.inner.param_orphan_dependents = Some({
+            let val = value.into();
+            self.inner.body.orphan_dependents = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// Same as [`orphan_dependents`](#method.orphan_dependents), but takes a reference.
+    #[inline]
+    pub fn orphan_dependents_ref(self, value: &bool) -> Self {
+        self.orphan_dependents(*value)
+    }
+
+    /// Whether and how garbage collection will be performed. Either this field or OrphanDependents may be set, but not both. The default policy is decided by the existing finalizer set in the metadata.finalizers and the resource-specific default policy. Acceptable values are: 'Orphan' - orphan the dependents; 'Background' - allow the garbage collector to delete the dependents in the background; 'Foreground' - a cascading policy that deletes all dependents in the foreground.
+    #[inline]
+    pub fn propagation_policy(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_propagation_policy = Some({
+            let val = value.into();
+            self.inner.body.propagation_policy = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// name of the Namespace
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> DeleteOptionsDeleteBuilder12<crate::generics::NameExists> {
+        self.inner.param_name = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// If 'true', then the output is pretty printed.
+    #[inline]
+    pub fn pretty(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_pretty = Some(value.into());
+        self
+    }
+
+    /// Sets [`pretty`](#method.pretty) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_pretty(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_pretty = value.map(|value| value.into());
+        self
+    }
+
+    /// APIVersion defines the versioned schema of this representation of an object. Servers should convert recognized schemas to the latest internal value, and may reject unrecognized values. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#resources
+    #[inline]
+    pub fn api_version(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.api_version = Some(value.into());
+        self
+    }
+
+    /// Sets [`api_version`](#method.api_version) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_api_version(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.api_version = value.map(|value| value.into());
+        self
+    }
+
+    /// Kind is a string value representing the REST resource this object represents. Servers may infer this from the endpoint the client submits requests to. Cannot be updated. In CamelCase. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#types-kinds
+    #[inline]
+    pub fn kind(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.kind = Some(value.into());
+        self
+    }
+
+    /// Sets [`kind`](#method.kind) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_kind(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.kind = value.map(|value| value.into());
+        self
+    }
+
+    /// Must be fulfilled before a deletion is carried out. If not possible, a 409 Conflict status will be returned.
+    #[inline]
+    pub fn preconditions(mut self, value: crate::io::k8s::apimachinery::pkg::apis::meta::v1::preconditions::Preconditions) -> Self {
+        self.inner.body.preconditions = Some(value.into());
+        self
+    }
+
+    /// Sets [`preconditions`](#method.preconditions) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_preconditions(mut self, value: Option<crate::io::k8s::apimachinery::pkg::apis::meta::v1::preconditions::Preconditions>) -> Self {
+        self.inner.body.preconditions = value.map(|value| value.into());
+        self
+    }
+}
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for DeleteOptionsDeleteBuilder12<crate::generics::NameExists> {
+    type Output = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::Status;
+
+    const METHOD: http::Method = http::Method::DELETE;
+
+    const OPERATION_ID: Option<&'static str> = Some("deleteCoreV1Namespace");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok(format!("/api/v1/namespaces/{name}", name=crate::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::CONTENT_TYPE.as_str(), "application/json")
+        .json(&self.inner.body)
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("dryRun", self.inner.param_dry_run.as_ref().map(std::string::ToString::to_string)),
+            ("gracePeriodSeconds", self.inner.param_grace_period_seconds.as_ref().map(std::string::ToString::to_string)),
+            ("orphanDependents", self.inner.param_orphan_dependents.as_ref().map(std::string::ToString::to_string)),
+            ("propagationPolicy", self.inner.param_propagation_policy.as_ref().map(std::string::ToString::to_string)),
+            ("pretty", self.inner.param_pretty.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Content-Type")
+            || name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<DeleteOptionsDeleteBuilder12<crate::generics::NameExists>> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: DeleteOptionsDeleteBuilder12<crate::generics::NameExists>) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl DeleteOptionsDeleteBuilder12<crate::generics::NameExists> {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = DeleteOptionsDeleteBuilder12 {
+            inner: DeleteOptionsDeleteBuilder12Container {
+            body: crate::cli::read_from_input(matches)?,
+            param_dry_run: matches.and_then(|m| {
+                    m.value_of("dry-run").map(|_| {
+                        value_t!(m, "dry-run", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_grace_period_seconds: matches.and_then(|m| {
+                    m.value_of("grace-period-seconds").map(|_| {
+                        value_t!(m, "grace-period-seconds", i64).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_orphan_dependents: matches.and_then(|m| {
+                    m.value_of("orphan-dependents").map(|_| {
+                        value_t!(m, "orphan-dependents", bool).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_propagation_policy: matches.and_then(|m| {
+                    m.value_of("propagation-policy").map(|_| {
+                        value_t!(m, "propagation-policy", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_name: matches.and_then(|m| {
+                    m.value_of("name").map(|_| {
+                        value_t!(m, "name", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_pretty: matches.and_then(|m| {
+                    m.value_of("pretty").map(|_| {
+                        value_t!(m, "pretty", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            },
+            _param_name: core::marker::PhantomData,
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder created by [`DeleteOptions::delete_core_v1_node`](./struct.DeleteOptions.html#method.delete_core_v1_node) method for a `DELETE` operation associated with `DeleteOptions`.
+#[repr(transparent)]
+#[derive(Debug, Clone)]
+pub struct DeleteOptionsDeleteBuilder13<Name> {
+    inner: DeleteOptionsDeleteBuilder13Container,
+    _param_name: core::marker::PhantomData<Name>,
+}
+
+#[derive(Debug, Default, Clone)]
+struct DeleteOptionsDeleteBuilder13Container {
+    body: self::DeleteOptions,
+    param_dry_run: Option<String>,
+    param_grace_period_seconds: Option<i64>,
+    param_orphan_dependents: Option<bool>,
+    param_propagation_policy: Option<String>,
+    param_name: Option<String>,
+    param_pretty: Option<String>,
+}
+
+impl<Name> DeleteOptionsDeleteBuilder13<Name> {
+    /// When present, indicates that modifications should not be persisted. An invalid or unrecognized dryRun directive will result in an error response and no further processing of the request. Valid values are: - All: all dry run stages will be processed
+    #[inline]
+    pub fn dry_run(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_dry_run = Some(value.into());
+        self
+    }
+
+    /// Sets [`dry_run`](#method.dry_run) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_dry_run(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_dry_run = value.map(|value| value.into());
+        self
+    }
+
+    /// The duration in seconds before the object should be deleted. Value must be non-negative integer. The value zero indicates delete immediately. If this value is nil, the default grace period for the specified type will be used. Defaults to a per object value if not specified. zero means delete immediately.
+    #[inline]
+    pub fn grace_period_seconds(mut self, value: impl Into<i64>) -> Self {
+        self.inner.param_grace_period_seconds = Some({
+            let val = value.into();
+            self.inner.body.grace_period_seconds = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// Same as [`grace_period_seconds`](#method.grace_period_seconds), but takes a reference.
+    #[inline]
+    pub fn grace_period_seconds_ref(self, value: &i64) -> Self {
+        self.grace_period_seconds(*value)
+    }
+
+    /// Deprecated: please use the PropagationPolicy, this field will be deprecated in 1.7. Should the dependent objects be orphaned. If true/false, the "orphan" finalizer will be added to/removed from the object's finalizers list. Either this field or PropagationPolicy may be set, but not both.
+    #[inline]
+    pub fn orphan_dependents(mut self, value: impl Into<bool>) -> Self {
+        self.inner.param_orphan_dependents = Some({
+            let val = value.into();
+            self.inner.body.orphan_dependents = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// Same as [`orphan_dependents`](#method.orphan_dependents), but takes a reference.
+    #[inline]
+    pub fn orphan_dependents_ref(self, value: &bool) -> Self {
+        self.orphan_dependents(*value)
+    }
+
+    /// Whether and how garbage collection will be performed. Either this field or OrphanDependents may be set, but not both. The default policy is decided by the existing finalizer set in the metadata.finalizers and the resource-specific default policy. Acceptable values are: 'Orphan' - orphan the dependents; 'Background' - allow the garbage collector to delete the dependents in the background; 'Foreground' - a cascading policy that deletes all dependents in the foreground.
+    #[inline]
+    pub fn propagation_policy(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_propagation_policy = Some({
+            let val = value.into();
+            self.inner.body.propagation_policy = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// name of the Node
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> DeleteOptionsDeleteBuilder13<crate::generics::NameExists> {
+        self.inner.param_name = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// If 'true', then the output is pretty printed.
+    #[inline]
+    pub fn pretty(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_pretty = Some(value.into());
+        self
+    }
+
+    /// Sets [`pretty`](#method.pretty) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_pretty(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_pretty = value.map(|value| value.into());
+        self
+    }
+
+    /// APIVersion defines the versioned schema of this representation of an object. Servers should convert recognized schemas to the latest internal value, and may reject unrecognized values. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#resources
+    #[inline]
+    pub fn api_version(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.api_version = Some(value.into());
+        self
+    }
+
+    /// Sets [`api_version`](#method.api_version) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_api_version(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.api_version = value.map(|value| value.into());
+        self
+    }
+
+    /// Kind is a string value representing the REST resource this object represents. Servers may infer this from the endpoint the client submits requests to. Cannot be updated. In CamelCase. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#types-kinds
+    #[inline]
+    pub fn kind(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.kind = Some(value.into());
+        self
+    }
+
+    /// Sets [`kind`](#method.kind) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_kind(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.kind = value.map(|value| value.into());
+        self
+    }
+
+    /// Must be fulfilled before a deletion is carried out. If not possible, a 409 Conflict status will be returned.
+    #[inline]
+    pub fn preconditions(mut self, value: crate::io::k8s::apimachinery::pkg::apis::meta::v1::preconditions::Preconditions) -> Self {
+        self.inner.body.preconditions = Some(value.into());
+        self
+    }
+
+    /// Sets [`preconditions`](#method.preconditions) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_preconditions(mut self, value: Option<crate::io::k8s::apimachinery::pkg::apis::meta::v1::preconditions::Preconditions>) -> Self {
+        self.inner.body.preconditions = value.map(|value| value.into());
+        self
+    }
+}
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for DeleteOptionsDeleteBuilder13<crate::generics::NameExists> {
+    type Output = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::Status;
+
+    const METHOD: http::Method = http::Method::DELETE;
+
+    const OPERATION_ID: Option<&'static str> = Some("deleteCoreV1Node");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok(format!("/api/v1/nodes/{name}", name=crate::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::CONTENT_TYPE.as_str(), "application/json")
+        .json(&self.inner.body)
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("dryRun", self.inner.param_dry_run.as_ref().map(std::string::ToString::to_string)),
+            ("gracePeriodSeconds", self.inner.param_grace_period_seconds.as_ref().map(std::string::ToString::to_string)),
+            ("orphanDependents", self.inner.param_orphan_dependents.as_ref().map(std::string::ToString::to_string)),
+            ("propagationPolicy", self.inner.param_propagation_policy.as_ref().map(std::string::ToString::to_string)),
+            ("pretty", self.inner.param_pretty.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Content-Type")
+            || name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<DeleteOptionsDeleteBuilder13<crate::generics::NameExists>> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: DeleteOptionsDeleteBuilder13<crate::generics::NameExists>) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl DeleteOptionsDeleteBuilder13<crate::generics::NameExists> {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = DeleteOptionsDeleteBuilder13 {
+            inner: DeleteOptionsDeleteBuilder13Container {
+            body: crate::cli::read_from_input(matches)?,
+            param_dry_run: matches.and_then(|m| {
+                    m.value_of("dry-run").map(|_| {
+                        value_t!(m, "dry-run", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_grace_period_seconds: matches.and_then(|m| {
+                    m.value_of("grace-period-seconds").map(|_| {
+                        value_t!(m, "grace-period-seconds", i64).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_orphan_dependents: matches.and_then(|m| {
+                    m.value_of("orphan-dependents").map(|_| {
+                        value_t!(m, "orphan-dependents", bool).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_propagation_policy: matches.and_then(|m| {
+                    m.value_of("propagation-policy").map(|_| {
+                        value_t!(m, "propagation-policy", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_name: matches.and_then(|m| {
+                    m.value_of("name").map(|_| {
+                        value_t!(m, "name", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_pretty: matches.and_then(|m| {
+                    m.value_of("pretty").map(|_| {
+                        value_t!(m, "pretty", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            },
+            _param_name: core::marker::PhantomData,
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder created by [`DeleteOptions::delete_core_v1_persistent_volume`](./struct.DeleteOptions.html#method.delete_core_v1_persistent_volume) method for a `DELETE` operation associated with `DeleteOptions`.
+#[repr(transparent)]
+#[derive(Debug, Clone)]
+pub struct DeleteOptionsDeleteBuilder14<Name> {
+    inner: DeleteOptionsDeleteBuilder14Container,
+    _param_name: core::marker::PhantomData<Name>,
+}
+
+#[derive(Debug, Default, Clone)]
+struct DeleteOptionsDeleteBuilder14Container {
+    body: self::DeleteOptions,
+    param_dry_run: Option<String>,
+    param_grace_period_seconds: Option<i64>,
+    param_orphan_dependents: Option<bool>,
+    param_propagation_policy: Option<String>,
+    param_name: Option<String>,
+    param_pretty: Option<String>,
+}
+
+impl<Name> DeleteOptionsDeleteBuilder14<Name> {
+    /// When present, indicates that modifications should not be persisted. An invalid or unrecognized dryRun directive will result in an error response and no further processing of the request. Valid values are: - All: all dry run stages will be processed
+    #[inline]
+    pub fn dry_run(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_dry_run = Some(value.into());
+        self
+    }
+
+    /// Sets [`dry_run`](#method.dry_run) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_dry_run(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_dry_run = value.map(|value| value.into());
+        self
+    }
+
+    /// The duration in seconds before the object should be deleted. Value must be non-negative integer. The value zero indicates delete immediately. If this value is nil, the default grace period for the specified type will be used. Defaults to a per object value if not specified. zero means delete immediately.
+    #[inline]
+    pub fn grace_period_seconds(mut self, value: impl Into<i64>) -> Self {
+        self.inner.param_grace_period_seconds = Some({
+            let val = value.into();
+            self.inner.body.grace_period_seconds = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// Same as [`grace_period_seconds`](#method.grace_period_seconds), but takes a reference.
+    #[inline]
+    pub fn grace_period_seconds_ref(self, value: &i64) -> Self {
+        self.grace_period_seconds(*value)
+    }
+
+    /// Deprecated: please use the PropagationPolicy, this field will be deprecated in 1.7. Should the dependent objects be orphaned. If true/false, the "orphan" finalizer will be added to/removed from the object's finalizers list. Either this field or PropagationPolicy may be set, but not both.
+    #[inline]
+    pub fn orphan_dependents(mut self, value: impl Into<bool>) -> Self {
+        self.inner.param_orphan_dependents = Some({
+            let val = value.into();
+            self.inner.body.orphan_dependents = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// Same as [`orphan_dependents`](#method.orphan_dependents), but takes a reference.
+    #[inline]
+    pub fn orphan_dependents_ref(self, value: &bool) -> Self {
+        self.orphan_dependents(*value)
+    }
+
+    /// Whether and how garbage collection will be performed. Either this field or OrphanDependents may be set, but not both. The default policy is decided by the existing finalizer set in the metadata.finalizers and the resource-specific default policy. Acceptable values are: 'Orphan' - orphan the dependents; 'Background' - allow the garbage collector to delete the dependents in the background; 'Foreground' - a cascading policy that deletes all dependents in the foreground.
+    #[inline]
+    pub fn propagation_policy(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_propagation_policy = Some({
+            let val = value.into();
+            self.inner.body.propagation_policy = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// name of the PersistentVolume
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> DeleteOptionsDeleteBuilder14<crate::generics::NameExists> {
+        self.inner.param_name = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// If 'true', then the output is pretty printed.
+    #[inline]
+    pub fn pretty(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_pretty = Some(value.into());
+        self
+    }
+
+    /// Sets [`pretty`](#method.pretty) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_pretty(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_pretty = value.map(|value| value.into());
+        self
+    }
+
+    /// APIVersion defines the versioned schema of this representation of an object. Servers should convert recognized schemas to the latest internal value, and may reject unrecognized values. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#resources
+    #[inline]
+    pub fn api_version(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.api_version = Some(value.into());
+        self
+    }
+
+    /// Sets [`api_version`](#method.api_version) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_api_version(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.api_version = value.map(|value| value.into());
+        self
+    }
+
+    /// Kind is a string value representing the REST resource this object represents. Servers may infer this from the endpoint the client submits requests to. Cannot be updated. In CamelCase. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#types-kinds
+    #[inline]
+    pub fn kind(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.kind = Some(value.into());
+        self
+    }
+
+    /// Sets [`kind`](#method.kind) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_kind(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.kind = value.map(|value| value.into());
+        self
+    }
+
+    /// Must be fulfilled before a deletion is carried out. If not possible, a 409 Conflict status will be returned.
+    #[inline]
+    pub fn preconditions(mut self, value: crate::io::k8s::apimachinery::pkg::apis::meta::v1::preconditions::Preconditions) -> Self {
+        self.inner.body.preconditions = Some(value.into());
+        self
+    }
+
+    /// Sets [`preconditions`](#method.preconditions) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_preconditions(mut self, value: Option<crate::io::k8s::apimachinery::pkg::apis::meta::v1::preconditions::Preconditions>) -> Self {
+        self.inner.body.preconditions = value.map(|value| value.into());
+        self
+    }
+}
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for DeleteOptionsDeleteBuilder14<crate::generics::NameExists> {
+    type Output = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::Status;
+
+    const METHOD: http::Method = http::Method::DELETE;
+
+    const OPERATION_ID: Option<&'static str> = Some("deleteCoreV1PersistentVolume");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok(format!("/api/v1/persistentvolumes/{name}", name=crate::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::CONTENT_TYPE.as_str(), "application/json")
+        .json(&self.inner.body)
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("dryRun", self.inner.param_dry_run.as_ref().map(std::string::ToString::to_string)),
+            ("gracePeriodSeconds", self.inner.param_grace_period_seconds.as_ref().map(std::string::ToString::to_string)),
+            ("orphanDependents", self.inner.param_orphan_dependents.as_ref().map(std::string::ToString::to_string)),
+            ("propagationPolicy", self.inner.param_propagation_policy.as_ref().map(std::string::ToString::to_string)),
+            ("pretty", self.inner.param_pretty.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Content-Type")
+            || name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<DeleteOptionsDeleteBuilder14<crate::generics::NameExists>> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: DeleteOptionsDeleteBuilder14<crate::generics::NameExists>) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl DeleteOptionsDeleteBuilder14<crate::generics::NameExists> {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = DeleteOptionsDeleteBuilder14 {
+            inner: DeleteOptionsDeleteBuilder14Container {
+            body: crate::cli::read_from_input(matches)?,
+            param_dry_run: matches.and_then(|m| {
+                    m.value_of("dry-run").map(|_| {
+                        value_t!(m, "dry-run", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_grace_period_seconds: matches.and_then(|m| {
+                    m.value_of("grace-period-seconds").map(|_| {
+                        value_t!(m, "grace-period-seconds", i64).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_orphan_dependents: matches.and_then(|m| {
+                    m.value_of("orphan-dependents").map(|_| {
+                        value_t!(m, "orphan-dependents", bool).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_propagation_policy: matches.and_then(|m| {
+                    m.value_of("propagation-policy").map(|_| {
+                        value_t!(m, "propagation-policy", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_name: matches.and_then(|m| {
+                    m.value_of("name").map(|_| {
+                        value_t!(m, "name", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_pretty: matches.and_then(|m| {
+                    m.value_of("pretty").map(|_| {
+                        value_t!(m, "pretty", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            },
+            _param_name: core::marker::PhantomData,
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder created by [`DeleteOptions::delete_admissionregistration_v1beta1_mutating_webhook_configuration`](./struct.DeleteOptions.html#method.delete_admissionregistration_v1beta1_mutating_webhook_configuration) method for a `DELETE` operation associated with `DeleteOptions`.
+#[repr(transparent)]
+#[derive(Debug, Clone)]
+pub struct DeleteOptionsDeleteBuilder15<Name> {
+    inner: DeleteOptionsDeleteBuilder15Container,
+    _param_name: core::marker::PhantomData<Name>,
+}
+
+#[derive(Debug, Default, Clone)]
+struct DeleteOptionsDeleteBuilder15Container {
+    body: self::DeleteOptions,
+    param_dry_run: Option<String>,
+    param_grace_period_seconds: Option<i64>,
+    param_orphan_dependents: Option<bool>,
+    param_propagation_policy: Option<String>,
+    param_name: Option<String>,
+    param_pretty: Option<String>,
+}
+
+impl<Name> DeleteOptionsDeleteBuilder15<Name> {
+    /// When present, indicates that modifications should not be persisted. An invalid or unrecognized dryRun directive will result in an error response and no further processing of the request. Valid values are: - All: all dry run stages will be processed
+    #[inline]
+    pub fn dry_run(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_dry_run = Some(value.into());
+        self
+    }
+
+    /// Sets [`dry_run`](#method.dry_run) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_dry_run(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_dry_run = value.map(|value| value.into());
+        self
+    }
+
+    /// The duration in seconds before the object should be deleted. Value must be non-negative integer. The value zero indicates delete immediately. If this value is nil, the default grace period for the specified type will be used. Defaults to a per object value if not specified. zero means delete immediately.
+    #[inline]
+    pub fn grace_period_seconds(mut self, value: impl Into<i64>) -> Self {
+        self.inner.param_grace_period_seconds = Some({
+            let val = value.into();
+            self.inner.body.grace_period_seconds = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// Same as [`grace_period_seconds`](#method.grace_period_seconds), but takes a reference.
+    #[inline]
+    pub fn grace_period_seconds_ref(self, value: &i64) -> Self {
+        self.grace_period_seconds(*value)
+    }
+
+    /// Deprecated: please use the PropagationPolicy, this field will be deprecated in 1.7. Should the dependent objects be orphaned. If true/false, the "orphan" finalizer will be added to/removed from the object's finalizers list. Either this field or PropagationPolicy may be set, but not both.
+    #[inline]
+    pub fn orphan_dependents(mut self, value: impl Into<bool>) -> Self {
+        self.inner.param_orphan_dependents = Some({
+            let val = value.into();
+            self.inner.body.orphan_dependents = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// Same as [`orphan_dependents`](#method.orphan_dependents), but takes a reference.
+    #[inline]
+    pub fn orphan_dependents_ref(self, value: &bool) -> Self {
+        self.orphan_dependents(*value)
+    }
+
+    /// Whether and how garbage collection will be performed. Either this field or OrphanDependents may be set, but not both. The default policy is decided by the existing finalizer set in the metadata.finalizers and the resource-specific default policy. Acceptable values are: 'Orphan' - orphan the dependents; 'Background' - allow the garbage collector to delete the dependents in the background; 'Foreground' - a cascading policy that deletes all dependents in the foreground.
+    #[inline]
+    pub fn propagation_policy(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_propagation_policy = Some({
+            let val = value.into();
+            self.inner.body.propagation_policy = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// name of the MutatingWebhookConfiguration
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> DeleteOptionsDeleteBuilder15<crate::generics::NameExists> {
+        self.inner.param_name = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// If 'true', then the output is pretty printed.
+    #[inline]
+    pub fn pretty(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_pretty = Some(value.into());
+        self
+    }
+
+    /// Sets [`pretty`](#method.pretty) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_pretty(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_pretty = value.map(|value| value.into());
+        self
+    }
+
+    /// APIVersion defines the versioned schema of this representation of an object. Servers should convert recognized schemas to the latest internal value, and may reject unrecognized values. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#resources
+    #[inline]
+    pub fn api_version(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.api_version = Some(value.into());
+        self
+    }
+
+    /// Sets [`api_version`](#method.api_version) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_api_version(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.api_version = value.map(|value| value.into());
+        self
+    }
+
+    /// Kind is a string value representing the REST resource this object represents. Servers may infer this from the endpoint the client submits requests to. Cannot be updated. In CamelCase. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#types-kinds
+    #[inline]
+    pub fn kind(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.kind = Some(value.into());
+        self
+    }
+
+    /// Sets [`kind`](#method.kind) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_kind(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.kind = value.map(|value| value.into());
+        self
+    }
+
+    /// Must be fulfilled before a deletion is carried out. If not possible, a 409 Conflict status will be returned.
+    #[inline]
+    pub fn preconditions(mut self, value: crate::io::k8s::apimachinery::pkg::apis::meta::v1::preconditions::Preconditions) -> Self {
+        self.inner.body.preconditions = Some(value.into());
+        self
+    }
+
+    /// Sets [`preconditions`](#method.preconditions) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_preconditions(mut self, value: Option<crate::io::k8s::apimachinery::pkg::apis::meta::v1::preconditions::Preconditions>) -> Self {
+        self.inner.body.preconditions = value.map(|value| value.into());
+        self
+    }
+}
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for DeleteOptionsDeleteBuilder15<crate::generics::NameExists> {
+    type Output = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::Status;
+
+    const METHOD: http::Method = http::Method::DELETE;
+
+    const OPERATION_ID: Option<&'static str> = Some("deleteAdmissionregistrationV1beta1MutatingWebhookConfiguration");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok(format!("/apis/admissionregistration.k8s.io/v1beta1/mutatingwebhookconfigurations/{name}", name=crate::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::CONTENT_TYPE.as_str(), "application/json")
+        .json(&self.inner.body)
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("dryRun", self.inner.param_dry_run.as_ref().map(std::string::ToString::to_string)),
+            ("gracePeriodSeconds", self.inner.param_grace_period_seconds.as_ref().map(std::string::ToString::to_string)),
+            ("orphanDependents", self.inner.param_orphan_dependents.as_ref().map(std::string::ToString::to_string)),
+            ("propagationPolicy", self.inner.param_propagation_policy.as_ref().map(std::string::ToString::to_string)),
+            ("pretty", self.inner.param_pretty.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Content-Type")
+            || name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<DeleteOptionsDeleteBuilder15<crate::generics::NameExists>> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: DeleteOptionsDeleteBuilder15<crate::generics::NameExists>) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl DeleteOptionsDeleteBuilder15<crate::generics::NameExists> {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = DeleteOptionsDeleteBuilder15 {
+            inner: DeleteOptionsDeleteBuilder15Container {
+            body: crate::cli::read_from_input(matches)?,
+            param_dry_run: matches.and_then(|m| {
+                    m.value_of("dry-run").map(|_| {
+                        value_t!(m, "dry-run", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_grace_period_seconds: matches.and_then(|m| {
+                    m.value_of("grace-period-seconds").map(|_| {
+                        value_t!(m, "grace-period-seconds", i64).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_orphan_dependents: matches.and_then(|m| {
+                    m.value_of("orphan-dependents").map(|_| {
+                        value_t!(m, "orphan-dependents", bool).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_propagation_policy: matches.and_then(|m| {
+                    m.value_of("propagation-policy").map(|_| {
+                        value_t!(m, "propagation-policy", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_name: matches.and_then(|m| {
+                    m.value_of("name").map(|_| {
+                        value_t!(m, "name", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_pretty: matches.and_then(|m| {
+                    m.value_of("pretty").map(|_| {
+                        value_t!(m, "pretty", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            },
+            _param_name: core::marker::PhantomData,
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder created by [`DeleteOptions::delete_admissionregistration_v1beta1_validating_webhook_configuration`](./struct.DeleteOptions.html#method.delete_admissionregistration_v1beta1_validating_webhook_configuration) method for a `DELETE` operation associated with `DeleteOptions`.
+#[repr(transparent)]
+#[derive(Debug, Clone)]
+pub struct DeleteOptionsDeleteBuilder16<Name> {
+    inner: DeleteOptionsDeleteBuilder16Container,
+    _param_name: core::marker::PhantomData<Name>,
+}
+
+#[derive(Debug, Default, Clone)]
+struct DeleteOptionsDeleteBuilder16Container {
+    body: self::DeleteOptions,
+    param_dry_run: Option<String>,
+    param_grace_period_seconds: Option<i64>,
+    param_orphan_dependents: Option<bool>,
+    param_propagation_policy: Option<String>,
+    param_name: Option<String>,
+    param_pretty: Option<String>,
+}
+
+impl<Name> DeleteOptionsDeleteBuilder16<Name> {
+    /// When present, indicates that modifications should not be persisted. An invalid or unrecognized dryRun directive will result in an error response and no further processing of the request. Valid values are: - All: all dry run stages will be processed
+    #[inline]
+    pub fn dry_run(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_dry_run = Some(value.into());
+        self
+    }
+
+    /// Sets [`dry_run`](#method.dry_run) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_dry_run(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_dry_run = value.map(|value| value.into());
+        self
+    }
+
+    /// The duration in seconds before the object should be deleted. Value must be non-negative integer. The value zero indicates delete immediately. If this value is nil, the default grace period for the specified type will be used. Defaults to a per object value if not specified. zero means delete immediately.
+    #[inline]
+    pub fn grace_period_seconds(mut self, value: impl Into<i64>) -> Self {
+        self.inner.param_grace_period_seconds = Some({
+            let val = value.into();
+            self.inner.body.grace_period_seconds = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// Same as [`grace_period_seconds`](#method.grace_period_seconds), but takes a reference.
+    #[inline]
+    pub fn grace_period_seconds_ref(self, value: &i64) -> Self {
+        self.grace_period_seconds(*value)
+    }
+
+    /// Deprecated: please use the PropagationPolicy, this field will be deprecated in 1.7. Should the dependent objects be orphaned. If true/false, the "orphan" finalizer will be added to/removed from the object's finalizers list. Either this field or PropagationPolicy may be set, but not both.
+    #[inline]
+    pub fn orphan_dependents(mut self, value: impl Into<bool>) -> Self {
+        self.inner.param_orphan_dependents = Some({
+            let val = value.into();
+            self.inner.body.orphan_dependents = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// Same as [`orphan_dependents`](#method.orphan_dependents), but takes a reference.
+    #[inline]
+    pub fn orphan_dependents_ref(self, value: &bool) -> Self {
+        self.orphan_dependents(*value)
+    }
+
+    /// Whether and how garbage collection will be performed. Either this field or OrphanDependents may be set, but not both. The default policy is decided by the existing finalizer set in the metadata.finalizers and the resource-specific default policy. Acceptable values are: 'Orphan' - orphan the dependents; 'Background' - allow the garbage collector to delete the dependents in the background; 'Foreground' - a cascading policy that deletes all dependents in the foreground.
+    #[inline]
+    pub fn propagation_policy(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_propagation_policy = Some({
+            let val = value.into();
+            self.inner.body.propagation_policy = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// name of the ValidatingWebhookConfiguration
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> DeleteOptionsDeleteBuilder16<crate::generics::NameExists> {
+        self.inner.param_name = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// If 'true', then the output is pretty printed.
+    #[inline]
+    pub fn pretty(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_pretty = Some(value.into());
+        self
+    }
+
+    /// Sets [`pretty`](#method.pretty) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_pretty(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_pretty = value.map(|value| value.into());
+        self
+    }
+
+    /// APIVersion defines the versioned schema of this representation of an object. Servers should convert recognized schemas to the latest internal value, and may reject unrecognized values. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#resources
+    #[inline]
+    pub fn api_version(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.api_version = Some(value.into());
+        self
+    }
+
+    /// Sets [`api_version`](#method.api_version) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_api_version(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.api_version = value.map(|value| value.into());
+        self
+    }
+
+    /// Kind is a string value representing the REST resource this object represents. Servers may infer this from the endpoint the client submits requests to. Cannot be updated. In CamelCase. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#types-kinds
+    #[inline]
+    pub fn kind(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.kind = Some(value.into());
+        self
+    }
+
+    /// Sets [`kind`](#method.kind) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_kind(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.kind = value.map(|value| value.into());
+        self
+    }
+
+    /// Must be fulfilled before a deletion is carried out. If not possible, a 409 Conflict status will be returned.
+    #[inline]
+    pub fn preconditions(mut self, value: crate::io::k8s::apimachinery::pkg::apis::meta::v1::preconditions::Preconditions) -> Self {
+        self.inner.body.preconditions = Some(value.into());
+        self
+    }
+
+    /// Sets [`preconditions`](#method.preconditions) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_preconditions(mut self, value: Option<crate::io::k8s::apimachinery::pkg::apis::meta::v1::preconditions::Preconditions>) -> Self {
+        self.inner.body.preconditions = value.map(|value| value.into());
+        self
+    }
+}
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for DeleteOptionsDeleteBuilder16<crate::generics::NameExists> {
+    type Output = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::Status;
+
+    const METHOD: http::Method = http::Method::DELETE;
+
+    const OPERATION_ID: Option<&'static str> = Some("deleteAdmissionregistrationV1beta1ValidatingWebhookConfiguration");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok(format!("/apis/admissionregistration.k8s.io/v1beta1/validatingwebhookconfigurations/{name}", name=crate::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::CONTENT_TYPE.as_str(), "application/json")
+        .json(&self.inner.body)
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("dryRun", self.inner.param_dry_run.as_ref().map(std::string::ToString::to_string)),
+            ("gracePeriodSeconds", self.inner.param_grace_period_seconds.as_ref().map(std::string::ToString::to_string)),
+            ("orphanDependents", self.inner.param_orphan_dependents.as_ref().map(std::string::ToString::to_string)),
+            ("propagationPolicy", self.inner.param_propagation_policy.as_ref().map(std::string::ToString::to_string)),
+            ("pretty", self.inner.param_pretty.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Content-Type")
+            || name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<DeleteOptionsDeleteBuilder16<crate::generics::NameExists>> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: DeleteOptionsDeleteBuilder16<crate::generics::NameExists>) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl DeleteOptionsDeleteBuilder16<crate::generics::NameExists> {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = DeleteOptionsDeleteBuilder16 {
+            inner: DeleteOptionsDeleteBuilder16Container {
+            body: crate::cli::read_from_input(matches)?,
+            param_dry_run: matches.and_then(|m| {
+                    m.value_of("dry-run").map(|_| {
+                        value_t!(m, "dry-run", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_grace_period_seconds: matches.and_then(|m| {
+                    m.value_of("grace-period-seconds").map(|_| {
+                        value_t!(m, "grace-period-seconds", i64).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_orphan_dependents: matches.and_then(|m| {
+                    m.value_of("orphan-dependents").map(|_| {
+                        value_t!(m, "orphan-dependents", bool).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_propagation_policy: matches.and_then(|m| {
+                    m.value_of("propagation-policy").map(|_| {
+                        value_t!(m, "propagation-policy", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_name: matches.and_then(|m| {
+                    m.value_of("name").map(|_| {
+                        value_t!(m, "name", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_pretty: matches.and_then(|m| {
+                    m.value_of("pretty").map(|_| {
+                        value_t!(m, "pretty", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            },
+            _param_name: core::marker::PhantomData,
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder created by [`DeleteOptions::delete_apiextensions_v1beta1_custom_resource_definition`](./struct.DeleteOptions.html#method.delete_apiextensions_v1beta1_custom_resource_definition) method for a `DELETE` operation associated with `DeleteOptions`.
+#[repr(transparent)]
+#[derive(Debug, Clone)]
+pub struct DeleteOptionsDeleteBuilder17<Name> {
+    inner: DeleteOptionsDeleteBuilder17Container,
+    _param_name: core::marker::PhantomData<Name>,
+}
+
+#[derive(Debug, Default, Clone)]
+struct DeleteOptionsDeleteBuilder17Container {
+    body: self::DeleteOptions,
+    param_dry_run: Option<String>,
+    param_grace_period_seconds: Option<i64>,
+    param_orphan_dependents: Option<bool>,
+    param_propagation_policy: Option<String>,
+    param_name: Option<String>,
+    param_pretty: Option<String>,
+}
+
+impl<Name> DeleteOptionsDeleteBuilder17<Name> {
+    /// When present, indicates that modifications should not be persisted. An invalid or unrecognized dryRun directive will result in an error response and no further processing of the request. Valid values are: - All: all dry run stages will be processed
+    #[inline]
+    pub fn dry_run(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_dry_run = Some(value.into());
+        self
+    }
+
+    /// Sets [`dry_run`](#method.dry_run) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_dry_run(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_dry_run = value.map(|value| value.into());
+        self
+    }
+
+    /// The duration in seconds before the object should be deleted. Value must be non-negative integer. The value zero indicates delete immediately. If this value is nil, the default grace period for the specified type will be used. Defaults to a per object value if not specified. zero means delete immediately.
+    #[inline]
+    pub fn grace_period_seconds(mut self, value: impl Into<i64>) -> Self {
+        self.inner.param_grace_period_seconds = Some({
+            let val = value.into();
+            self.inner.body.grace_period_seconds = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// Same as [`grace_period_seconds`](#method.grace_period_seconds), but takes a reference.
+    #[inline]
+    pub fn grace_period_seconds_ref(self, value: &i64) -> Self {
+        self.grace_period_seconds(*value)
+    }
+
+    /// Deprecated: please use the PropagationPolicy, this field will be deprecated in 1.7. Should the dependent objects be orphaned. If true/false, the "orphan" finalizer will be added to/removed from the object's finalizers list. Either this field or PropagationPolicy may be set, but not both.
+    #[inline]
+    pub fn orphan_dependents(mut self, value: impl Into<bool>) -> Self {
+        self.inner.param_orphan_dependents = Some({
+            let val = value.into();
+            self.inner.body.orphan_dependents = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// Same as [`orphan_dependents`](#method.orphan_dependents), but takes a reference.
+    #[inline]
+    pub fn orphan_dependents_ref(self, value: &bool) -> Self {
+        self.orphan_dependents(*value)
+    }
+
+    /// Whether and how garbage collection will be performed. Either this field or OrphanDependents may be set, but not both. The default policy is decided by the existing finalizer set in the metadata.finalizers and the resource-specific default policy. Acceptable values are: 'Orphan' - orphan the dependents; 'Background' - allow the garbage collector to delete the dependents in the background; 'Foreground' - a cascading policy that deletes all dependents in the foreground.
+    #[inline]
+    pub fn propagation_policy(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_propagation_policy = Some({
+            let val = value.into();
+            self.inner.body.propagation_policy = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// name of the CustomResourceDefinition
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> DeleteOptionsDeleteBuilder17<crate::generics::NameExists> {
+        self.inner.param_name = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// If 'true', then the output is pretty printed.
+    #[inline]
+    pub fn pretty(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_pretty = Some(value.into());
+        self
+    }
+
+    /// Sets [`pretty`](#method.pretty) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_pretty(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_pretty = value.map(|value| value.into());
+        self
+    }
+
+    /// APIVersion defines the versioned schema of this representation of an object. Servers should convert recognized schemas to the latest internal value, and may reject unrecognized values. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#resources
+    #[inline]
+    pub fn api_version(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.api_version = Some(value.into());
+        self
+    }
+
+    /// Sets [`api_version`](#method.api_version) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_api_version(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.api_version = value.map(|value| value.into());
+        self
+    }
+
+    /// Kind is a string value representing the REST resource this object represents. Servers may infer this from the endpoint the client submits requests to. Cannot be updated. In CamelCase. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#types-kinds
+    #[inline]
+    pub fn kind(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.kind = Some(value.into());
+        self
+    }
+
+    /// Sets [`kind`](#method.kind) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_kind(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.kind = value.map(|value| value.into());
+        self
+    }
+
+    /// Must be fulfilled before a deletion is carried out. If not possible, a 409 Conflict status will be returned.
+    #[inline]
+    pub fn preconditions(mut self, value: crate::io::k8s::apimachinery::pkg::apis::meta::v1::preconditions::Preconditions) -> Self {
+        self.inner.body.preconditions = Some(value.into());
+        self
+    }
+
+    /// Sets [`preconditions`](#method.preconditions) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_preconditions(mut self, value: Option<crate::io::k8s::apimachinery::pkg::apis::meta::v1::preconditions::Preconditions>) -> Self {
+        self.inner.body.preconditions = value.map(|value| value.into());
+        self
+    }
+}
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for DeleteOptionsDeleteBuilder17<crate::generics::NameExists> {
+    type Output = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::Status;
+
+    const METHOD: http::Method = http::Method::DELETE;
+
+    const OPERATION_ID: Option<&'static str> = Some("deleteApiextensionsV1beta1CustomResourceDefinition");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok(format!("/apis/apiextensions.k8s.io/v1beta1/customresourcedefinitions/{name}", name=crate::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::CONTENT_TYPE.as_str(), "application/json")
+        .json(&self.inner.body)
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("dryRun", self.inner.param_dry_run.as_ref().map(std::string::ToString::to_string)),
+            ("gracePeriodSeconds", self.inner.param_grace_period_seconds.as_ref().map(std::string::ToString::to_string)),
+            ("orphanDependents", self.inner.param_orphan_dependents.as_ref().map(std::string::ToString::to_string)),
+            ("propagationPolicy", self.inner.param_propagation_policy.as_ref().map(std::string::ToString::to_string)),
+            ("pretty", self.inner.param_pretty.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Content-Type")
+            || name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<DeleteOptionsDeleteBuilder17<crate::generics::NameExists>> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: DeleteOptionsDeleteBuilder17<crate::generics::NameExists>) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl DeleteOptionsDeleteBuilder17<crate::generics::NameExists> {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = DeleteOptionsDeleteBuilder17 {
+            inner: DeleteOptionsDeleteBuilder17Container {
+            body: crate::cli::read_from_input(matches)?,
+            param_dry_run: matches.and_then(|m| {
+                    m.value_of("dry-run").map(|_| {
+                        value_t!(m, "dry-run", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_grace_period_seconds: matches.and_then(|m| {
+                    m.value_of("grace-period-seconds").map(|_| {
+                        value_t!(m, "grace-period-seconds", i64).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_orphan_dependents: matches.and_then(|m| {
+                    m.value_of("orphan-dependents").map(|_| {
+                        value_t!(m, "orphan-dependents", bool).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_propagation_policy: matches.and_then(|m| {
+                    m.value_of("propagation-policy").map(|_| {
+                        value_t!(m, "propagation-policy", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_name: matches.and_then(|m| {
+                    m.value_of("name").map(|_| {
+                        value_t!(m, "name", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_pretty: matches.and_then(|m| {
+                    m.value_of("pretty").map(|_| {
+                        value_t!(m, "pretty", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            },
+            _param_name: core::marker::PhantomData,
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder shared by operations (with the same fields and parameters) associated with `DeleteOptions`.
+#[repr(transparent)]
+#[derive(Debug, Clone)]
+pub struct DeleteOptionsOpBuilder1<Op, Name> {
+    inner: DeleteOptionsOpBuilder1Container,
+    _op: core::marker::PhantomData<Op>,
+    _param_name: core::marker::PhantomData<Name>,
+}
+
+#[derive(Debug, Default, Clone)]
+struct DeleteOptionsOpBuilder1Container {
+    body: self::DeleteOptions,
+    param_dry_run: Option<String>,
+    param_grace_period_seconds: Option<i64>,
+    param_orphan_dependents: Option<bool>,
+    param_propagation_policy: Option<String>,
+    param_name: Option<String>,
+    param_pretty: Option<String>,
+}
+
+/// Builder created by [`DeleteOptions::delete_apiregistration_v1api_service`](./struct.DeleteOptions.html#method.delete_apiregistration_v1api_service) method for a `DELETE` operation associated with `DeleteOptions`.
+pub type DeleteOptionsDeleteBuilder18<Name> = DeleteOptionsOpBuilder1<DeleteOptionsDeleteOp18, Name>;
+
+/// Marks the operation of [`DeleteOptionsDeleteBuilder18`] in the shared builder.
+#[derive(Debug, Clone)]
+pub enum DeleteOptionsDeleteOp18 {}
+
+impl<Op, Name> DeleteOptionsOpBuilder1<Op, Name> {
+    /// When present, indicates that modifications should not be persisted. An invalid or unrecognized dryRun directive will result in an error response and no further processing of the request. Valid values are: - All: all dry run stages will be processed
+    #[inline]
+    pub fn dry_run(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_dry_run = Some(value.into());
+        self
+    }
+
+    /// Sets [`dry_run`](#method.dry_run) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_dry_run(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_dry_run = value.map(|value| value.into());
+        self
+    }
+
+    /// The duration in seconds before the object should be deleted. Value must be non-negative integer. The value zero indicates delete immediately. If this value is nil, the default grace period for the specified type will be used. Defaults to a per object value if not specified. zero means delete immediately.
+    #[inline]
+    pub fn grace_period_seconds(mut self, value: impl Into<i64>) -> Self {
+        self.inner.param_grace_period_seconds = Some({
+            let val = value.into();
+            self.inner.body.grace_period_seconds = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// Same as [`grace_period_seconds`](#method.grace_period_seconds), but takes a reference.
+    #[inline]
+    pub fn grace_period_seconds_ref(self, value: &i64) -> Self {
+        self.grace_period_seconds(*value)
+    }
+
+    /// Deprecated: please use the PropagationPolicy, this field will be deprecated in 1.7. Should the dependent objects be orphaned. If true/false, the "orphan" finalizer will be added to/removed from the object's finalizers list. Either this field or PropagationPolicy may be set, but not both.
+    #[inline]
+    pub fn orphan_dependents(mut self, value: impl Into<bool>) -> Self {
+        self.inner.param_orphan_dependents = Some({
+            let val = value.into();
+            self.inner.body.orphan_dependents = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// Same as [`orphan_dependents`](#method.orphan_dependents), but takes a reference.
+    #[inline]
+    pub fn orphan_dependents_ref(self, value: &bool) -> Self {
+        self.orphan_dependents(*value)
+    }
+
+    /// Whether and how garbage collection will be performed. Either this field or OrphanDependents may be set, but not both. The default policy is decided by the existing finalizer set in the metadata.finalizers and the resource-specific default policy. Acceptable values are: 'Orphan' - orphan the dependents; 'Background' - allow the garbage collector to delete the dependents in the background; 'Foreground' - a cascading policy that deletes all dependents in the foreground.
+    #[inline]
+    pub fn propagation_policy(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_propagation_policy = Some({
+            let val = value.into();
+            self.inner.body.propagation_policy = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// name of the APIService
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> DeleteOptionsOpBuilder1<Op, crate::generics::NameExists> {
+        self.inner.param_name = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// If 'true', then the output is pretty printed.
+    #[inline]
+    pub fn pretty(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_pretty = Some(value.into());
+        self
+    }
+
+    /// Sets [`pretty`](#method.pretty) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_pretty(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_pretty = value.map(|value| value.into());
+        self
+    }
+
+    /// APIVersion defines the versioned schema of this representation of an object. Servers should convert recognized schemas to the latest internal value, and may reject unrecognized values. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#resources
+    #[inline]
+    pub fn api_version(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.api_version = Some(value.into());
+        self
+    }
+
+    /// Sets [`api_version`](#method.api_version) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_api_version(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.api_version = value.map(|value| value.into());
+        self
+    }
+
+    /// Kind is a string value representing the REST resource this object represents. Servers may infer this from the endpoint the client submits requests to. Cannot be updated. In CamelCase. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#types-kinds
+    #[inline]
+    pub fn kind(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.kind = Some(value.into());
+        self
+    }
+
+    /// Sets [`kind`](#method.kind) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_kind(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.kind = value.map(|value| value.into());
+        self
+    }
+
+    /// Must be fulfilled before a deletion is carried out. If not possible, a 409 Conflict status will be returned.
+    #[inline]
+    pub fn preconditions(mut self, value: crate::io::k8s::apimachinery::pkg::apis::meta::v1::preconditions::Preconditions) -> Self {
+        self.inner.body.preconditions = Some(value.into());
+        self
+    }
+
+    /// Sets [`preconditions`](#method.preconditions) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_preconditions(mut self, value: Option<crate::io::k8s::apimachinery::pkg::apis::meta::v1::preconditions::Preconditions>) -> Self {
+        self.inner.body.preconditions = value.map(|value| value.into());
+        self
+    }
+}
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for DeleteOptionsDeleteBuilder18<crate::generics::NameExists> {
+    type Output = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::Status;
+
+    const METHOD: http::Method = http::Method::DELETE;
+
+    const OPERATION_ID: Option<&'static str> = Some("deleteApiregistrationV1APIService");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok(format!("/apis/apiregistration.k8s.io/v1/apiservices/{name}", name=crate::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::CONTENT_TYPE.as_str(), "application/json")
+        .json(&self.inner.body)
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("dryRun", self.inner.param_dry_run.as_ref().map(std::string::ToString::to_string)),
+            ("gracePeriodSeconds", self.inner.param_grace_period_seconds.as_ref().map(std::string::ToString::to_string)),
+            ("orphanDependents", self.inner.param_orphan_dependents.as_ref().map(std::string::ToString::to_string)),
+            ("propagationPolicy", self.inner.param_propagation_policy.as_ref().map(std::string::ToString::to_string)),
+            ("pretty", self.inner.param_pretty.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Content-Type")
+            || name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<DeleteOptionsDeleteBuilder18<crate::generics::NameExists>> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: DeleteOptionsDeleteBuilder18<crate::generics::NameExists>) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl DeleteOptionsDeleteBuilder18<crate::generics::NameExists> {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = DeleteOptionsDeleteBuilder18 {
+            inner: DeleteOptionsOpBuilder1Container {
+            body: crate::cli::read_from_input(matches)?,
+            param_dry_run: matches.and_then(|m| {
+                    m.value_of("dry-run").map(|_| {
+                        value_t!(m, "dry-run", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_grace_period_seconds: matches.and_then(|m| {
+                    m.value_of("grace-period-seconds").map(|_| {
+                        value_t!(m, "grace-period-seconds", i64).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_orphan_dependents: matches.and_then(|m| {
+                    m.value_of("orphan-dependents").map(|_| {
+                        value_t!(m, "orphan-dependents", bool).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_propagation_policy: matches.and_then(|m| {
+                    m.value_of("propagation-policy").map(|_| {
+                        value_t!(m, "propagation-policy", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_name: matches.and_then(|m| {
+                    m.value_of("name").map(|_| {
+                        value_t!(m, "name", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_pretty: matches.and_then(|m| {
+                    m.value_of("pretty").map(|_| {
+                        value_t!(m, "pretty", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            },
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder created by [`DeleteOptions::delete_apiregistration_v1beta1_api_service`](./struct.DeleteOptions.html#method.delete_apiregistration_v1beta1_api_service) method for a `DELETE` operation associated with `DeleteOptions`.
+pub type DeleteOptionsDeleteBuilder19<Name> = DeleteOptionsOpBuilder1<DeleteOptionsDeleteOp19, Name>;
+
+/// Marks the operation of [`DeleteOptionsDeleteBuilder19`] in the shared builder.
+#[derive(Debug, Clone)]
+pub enum DeleteOptionsDeleteOp19 {}
+
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for DeleteOptionsDeleteBuilder19<crate::generics::NameExists> {
+    type Output = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::Status;
+
+    const METHOD: http::Method = http::Method::DELETE;
+
+    const OPERATION_ID: Option<&'static str> = Some("deleteApiregistrationV1beta1APIService");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok(format!("/apis/apiregistration.k8s.io/v1beta1/apiservices/{name}", name=crate::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::CONTENT_TYPE.as_str(), "application/json")
+        .json(&self.inner.body)
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("dryRun", self.inner.param_dry_run.as_ref().map(std::string::ToString::to_string)),
+            ("gracePeriodSeconds", self.inner.param_grace_period_seconds.as_ref().map(std::string::ToString::to_string)),
+            ("orphanDependents", self.inner.param_orphan_dependents.as_ref().map(std::string::ToString::to_string)),
+            ("propagationPolicy", self.inner.param_propagation_policy.as_ref().map(std::string::ToString::to_string)),
+            ("pretty", self.inner.param_pretty.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Content-Type")
+            || name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<DeleteOptionsDeleteBuilder19<crate::generics::NameExists>> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: DeleteOptionsDeleteBuilder19<crate::generics::NameExists>) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl DeleteOptionsDeleteBuilder19<crate::generics::NameExists> {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = DeleteOptionsDeleteBuilder19 {
+            inner: DeleteOptionsOpBuilder1Container {
+            body: crate::cli::read_from_input(matches)?,
+            param_dry_run: matches.and_then(|m| {
+                    m.value_of("dry-run").map(|_| {
+                        value_t!(m, "dry-run", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_grace_period_seconds: matches.and_then(|m| {
+                    m.value_of("grace-period-seconds").map(|_| {
+                        value_t!(m, "grace-period-seconds", i64).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_orphan_dependents: matches.and_then(|m| {
+                    m.value_of("orphan-dependents").map(|_| {
+                        value_t!(m, "orphan-dependents", bool).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_propagation_policy: matches.and_then(|m| {
+                    m.value_of("propagation-policy").map(|_| {
+                        value_t!(m, "propagation-policy", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_name: matches.and_then(|m| {
+                    m.value_of("name").map(|_| {
+                        value_t!(m, "name", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_pretty: matches.and_then(|m| {
+                    m.value_of("pretty").map(|_| {
+                        value_t!(m, "pretty", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            },
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder shared by operations (with the same fields and parameters) associated with `DeleteOptions`.
+#[repr(transparent)]
+#[derive(Debug, Clone)]
+pub struct DeleteOptionsOpBuilder2<Op, Name, Namespace> {
+    inner: DeleteOptionsOpBuilder2Container,
+    _op: core::marker::PhantomData<Op>,
+    _param_name: core::marker::PhantomData<Name>,
+    _param_namespace: core::marker::PhantomData<Namespace>,
+}
+
+#[derive(Debug, Default, Clone)]
+struct DeleteOptionsOpBuilder2Container {
+    body: self::DeleteOptions,
+    param_dry_run: Option<String>,
+    param_grace_period_seconds: Option<i64>,
+    param_orphan_dependents: Option<bool>,
+    param_propagation_policy: Option<String>,
+    param_name: Option<String>,
+    param_namespace: Option<String>,
+    param_pretty: Option<String>,
+}
+
+/// Builder created by [`DeleteOptions::delete_apps_v1_namespaced_controller_revision`](./struct.DeleteOptions.html#method.delete_apps_v1_namespaced_controller_revision) method for a `DELETE` operation associated with `DeleteOptions`.
+pub type DeleteOptionsDeleteBuilder20<Name, Namespace> = DeleteOptionsOpBuilder2<DeleteOptionsDeleteOp20, Name, Namespace>;
+
+/// Marks the operation of [`DeleteOptionsDeleteBuilder20`] in the shared builder.
+#[derive(Debug, Clone)]
+pub enum DeleteOptionsDeleteOp20 {}
+
+impl<Op, Name, Namespace> DeleteOptionsOpBuilder2<Op, Name, Namespace> {
+    /// When present, indicates that modifications should not be persisted. An invalid or unrecognized dryRun directive will result in an error response and no further processing of the request. Valid values are: - All: all dry run stages will be processed
+    #[inline]
+    pub fn dry_run(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_dry_run = Some(value.into());
+        self
+    }
+
+    /// Sets [`dry_run`](#method.dry_run) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_dry_run(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_dry_run = value.map(|value| value.into());
+        self
+    }
+
+    /// The duration in seconds before the object should be deleted. Value must be non-negative integer. The value zero indicates delete immediately. If this value is nil, the default grace period for the specified type will be used. Defaults to a per object value if not specified. zero means delete immediately.
+    #[inline]
+    pub fn grace_period_seconds(mut self, value: impl Into<i64>) -> Self {
+        self.inner.param_grace_period_seconds = Some({
+            let val = value.into();
+            self.inner.body.grace_period_seconds = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// Same as [`grace_period_seconds`](#method.grace_period_seconds), but takes a reference.
+    #[inline]
+    pub fn grace_period_seconds_ref(self, value: &i64) -> Self {
+        self.grace_period_seconds(*value)
+    }
+
+    /// Deprecated: please use the PropagationPolicy, this field will be deprecated in 1.7. Should the dependent objects be orphaned. If true/false, the "orphan" finalizer will be added to/removed from the object's finalizers list. Either this field or PropagationPolicy may be set, but not both.
+    #[inline]
+    pub fn orphan_dependents(mut self, value: impl Into<bool>) -> Self {
+        self.inner.param_orphan_dependents = Some({
+            let val = value.into();
+            self.inner.body.orphan_dependents = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// Same as [`orphan_dependents`](#method.orphan_dependents), but takes a reference.
+    #[inline]
+    pub fn orphan_dependents_ref(self, value: &bool) -> Self {
+        self.orphan_dependents(*value)
+    }
+
+    /// Whether and how garbage collection will be performed. Either this field or OrphanDependents may be set, but not both. The default policy is decided by the existing finalizer set in the metadata.finalizers and the resource-specific default policy. Acceptable values are: 'Orphan' - orphan the dependents; 'Background' - allow the garbage collector to delete the dependents in the background; 'Foreground' - a cascading policy that deletes all dependents in the foreground.
+    #[inline]
+    pub fn propagation_policy(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_propagation_policy = Some({
+            let val = value.into();
+            self.inner.body.propagation_policy = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// name of the ControllerRevision
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> DeleteOptionsOpBuilder2<Op, crate::generics::NameExists, Namespace> {
+        self.inner.param_name = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// object name and auth scope, such as for teams and projects
+    #[inline]
+    pub fn namespace(mut self, value: impl Into<String>) -> DeleteOptionsOpBuilder2<Op, Name, crate::generics::NamespaceExists> {
+        self.inner.param_namespace = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// If 'true', then the output is pretty printed.
+    #[inline]
+    pub fn pretty(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_pretty = Some(value.into());
+        self
+    }
+
+    /// Sets [`pretty`](#method.pretty) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_pretty(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_pretty = value.map(|value| value.into());
+        self
+    }
+
+    /// APIVersion defines the versioned schema of this representation of an object. Servers should convert recognized schemas to the latest internal value, and may reject unrecognized values. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#resources
+    #[inline]
+    pub fn api_version(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.api_version = Some(value.into());
+        self
+    }
+
+    /// Sets [`api_version`](#method.api_version) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_api_version(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.api_version = value.map(|value| value.into());
+        self
+    }
+
+    /// Kind is a string value representing the REST resource this object represents. Servers may infer this from the endpoint the client submits requests to. Cannot be updated. In CamelCase. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#types-kinds
+    #[inline]
+    pub fn kind(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.kind = Some(value.into());
+        self
+    }
+
+    /// Sets [`kind`](#method.kind) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_kind(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.kind = value.map(|value| value.into());
+        self
+    }
+
+    /// Must be fulfilled before a deletion is carried out. If not possible, a 409 Conflict status will be returned.
+    #[inline]
+    pub fn preconditions(mut self, value: crate::io::k8s::apimachinery::pkg::apis::meta::v1::preconditions::Preconditions) -> Self {
+        self.inner.body.preconditions = Some(value.into());
+        self
+    }
+
+    /// Sets [`preconditions`](#method.preconditions) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_preconditions(mut self, value: Option<crate::io::k8s::apimachinery::pkg::apis::meta::v1::preconditions::Preconditions>) -> Self {
+        self.inner.body.preconditions = value.map(|value| value.into());
+        self
+    }
+}
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for DeleteOptionsDeleteBuilder20<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    type Output = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::Status;
+
+    const METHOD: http::Method = http::Method::DELETE;
+
+    const OPERATION_ID: Option<&'static str> = Some("deleteAppsV1NamespacedControllerRevision");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok(format!("/apis/apps/v1/namespaces/{namespace}/controllerrevisions/{name}", name=crate::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?, namespace=crate::client::path_param("namespace", self.inner.param_namespace.as_ref().expect("missing parameter namespace?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::CONTENT_TYPE.as_str(), "application/json")
+        .json(&self.inner.body)
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("dryRun", self.inner.param_dry_run.as_ref().map(std::string::ToString::to_string)),
+            ("gracePeriodSeconds", self.inner.param_grace_period_seconds.as_ref().map(std::string::ToString::to_string)),
+            ("orphanDependents", self.inner.param_orphan_dependents.as_ref().map(std::string::ToString::to_string)),
+            ("propagationPolicy", self.inner.param_propagation_policy.as_ref().map(std::string::ToString::to_string)),
+            ("pretty", self.inner.param_pretty.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Content-Type")
+            || name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<DeleteOptionsDeleteBuilder20<crate::generics::NameExists, crate::generics::NamespaceExists>> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: DeleteOptionsDeleteBuilder20<crate::generics::NameExists, crate::generics::NamespaceExists>) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl DeleteOptionsDeleteBuilder20<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = DeleteOptionsDeleteBuilder20 {
+            inner: DeleteOptionsOpBuilder2Container {
+            body: crate::cli::read_from_input(matches)?,
+            param_dry_run: matches.and_then(|m| {
+                    m.value_of("dry-run").map(|_| {
+                        value_t!(m, "dry-run", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_grace_period_seconds: matches.and_then(|m| {
+                    m.value_of("grace-period-seconds").map(|_| {
+                        value_t!(m, "grace-period-seconds", i64).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_orphan_dependents: matches.and_then(|m| {
+                    m.value_of("orphan-dependents").map(|_| {
+                        value_t!(m, "orphan-dependents", bool).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_propagation_policy: matches.and_then(|m| {
+                    m.value_of("propagation-policy").map(|_| {
+                        value_t!(m, "propagation-policy", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_name: matches.and_then(|m| {
+                    m.value_of("name").map(|_| {
+                        value_t!(m, "name", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_namespace: matches.and_then(|m| {
+                    m.value_of("namespace").map(|_| {
+                        value_t!(m, "namespace", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_pretty: matches.and_then(|m| {
+                    m.value_of("pretty").map(|_| {
+                        value_t!(m, "pretty", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            },
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder shared by operations (with the same fields and parameters) associated with `DeleteOptions`.
+#[repr(transparent)]
+#[derive(Debug, Clone)]
+pub struct DeleteOptionsOpBuilder3<Op, Name, Namespace> {
+    inner: DeleteOptionsOpBuilder3Container,
+    _op: core::marker::PhantomData<Op>,
+    _param_name: core::marker::PhantomData<Name>,
+    _param_namespace: core::marker::PhantomData<Namespace>,
+}
+
+#[derive(Debug, Default, Clone)]
+struct DeleteOptionsOpBuilder3Container {
+    body: self::DeleteOptions,
+    param_dry_run: Option<String>,
+    param_grace_period_seconds: Option<i64>,
+    param_orphan_dependents: Option<bool>,
+    param_propagation_policy: Option<String>,
+    param_name: Option<String>,
+    param_namespace: Option<String>,
+    param_pretty: Option<String>,
+}
+
+/// Builder created by [`DeleteOptions::delete_apps_v1_namespaced_daemon_set`](./struct.DeleteOptions.html#method.delete_apps_v1_namespaced_daemon_set) method for a `DELETE` operation associated with `DeleteOptions`.
+pub type DeleteOptionsDeleteBuilder21<Name, Namespace> = DeleteOptionsOpBuilder3<DeleteOptionsDeleteOp21, Name, Namespace>;
+
+/// Marks the operation of [`DeleteOptionsDeleteBuilder21`] in the shared builder.
+#[derive(Debug, Clone)]
+pub enum DeleteOptionsDeleteOp21 {}
+
+impl<Op, Name, Namespace> DeleteOptionsOpBuilder3<Op, Name, Namespace> {
+    /// When present, indicates that modifications should not be persisted. An invalid or unrecognized dryRun directive will result in an error response and no further processing of the request. Valid values are: - All: all dry run stages will be processed
+    #[inline]
+    pub fn dry_run(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_dry_run = Some(value.into());
+        self
+    }
+
+    /// Sets [`dry_run`](#method.dry_run) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_dry_run(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_dry_run = value.map(|value| value.into());
+        self
+    }
+
+    /// The duration in seconds before the object should be deleted. Value must be non-negative integer. The value zero indicates delete immediately. If this value is nil, the default grace period for the specified type will be used. Defaults to a per object value if not specified. zero means delete immediately.
+    #[inline]
+    pub fn grace_period_seconds(mut self, value: impl Into<i64>) -> Self {
+        self.inner.param_grace_period_seconds = Some({
+            let val = value.into();
+            self.inner.body.grace_period_seconds = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// Same as [`grace_period_seconds`](#method.grace_period_seconds), but takes a reference.
+    #[inline]
+    pub fn grace_period_seconds_ref(self, value: &i64) -> Self {
+        self.grace_period_seconds(*value)
+    }
+
+    /// Deprecated: please use the PropagationPolicy, this field will be deprecated in 1.7. Should the dependent objects be orphaned. If true/false, the "orphan" finalizer will be added to/removed from the object's finalizers list. Either this field or PropagationPolicy may be set, but not both.
+    #[inline]
+    pub fn orphan_dependents(mut self, value: impl Into<bool>) -> Self {
+        self.inner.param_orphan_dependents = Some({
+            let val = value.into();
+            self.inner.body.orphan_dependents = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// Same as [`orphan_dependents`](#method.orphan_dependents), but takes a reference.
+    #[inline]
+    pub fn orphan_dependents_ref(self, value: &bool) -> Self {
+        self.orphan_dependents(*value)
+    }
+
+    /// Whether and how garbage collection will be performed. Either this field or OrphanDependents may be set, but not both. The default policy is decided by the existing finalizer set in the metadata.finalizers and the resource-specific default policy. Acceptable values are: 'Orphan' - orphan the dependents; 'Background' - allow the garbage collector to delete the dependents in the background; 'Foreground' - a cascading policy that deletes all dependents in the foreground.
+    #[inline]
+    pub fn propagation_policy(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_propagation_policy = Some({
+            let val = value.into();
+            self.inner.body.propagation_policy = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// name of the DaemonSet
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> DeleteOptionsOpBuilder3<Op, crate::generics::NameExists, Namespace> {
+        self.inner.param_name = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// object name and auth scope, such as for teams and projects
+    #[inline]
+    pub fn namespace(mut self, value: impl Into<String>) -> DeleteOptionsOpBuilder3<Op, Name, crate::generics::NamespaceExists> {
+        self.inner.param_namespace = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// If 'true', then the output is pretty printed.
+    #[inline]
+    pub fn pretty(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_pretty = Some(value.into());
+        self
+    }
+
+    /// Sets [`pretty`](#method.pretty) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_pretty(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_pretty = value.map(|value| value.into());
+        self
+    }
+
+    /// APIVersion defines the versioned schema of this representation of an object. Servers should convert recognized schemas to the latest internal value, and may reject unrecognized values. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#resources
+    #[inline]
+    pub fn api_version(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.api_version = Some(value.into());
+        self
+    }
+
+    /// Sets [`api_version`](#method.api_version) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_api_version(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.api_version = value.map(|value| value.into());
+        self
+    }
+
+    /// Kind is a string value representing the REST resource this object represents. Servers may infer this from the endpoint the client submits requests to. Cannot be updated. In CamelCase. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#types-kinds
+    #[inline]
+    pub fn kind(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.kind = Some(value.into());
+        self
+    }
+
+    /// Sets [`kind`](#method.kind) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_kind(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.kind = value.map(|value| value.into());
+        self
+    }
+
+    /// Must be fulfilled before a deletion is carried out. If not possible, a 409 Conflict status will be returned.
+    #[inline]
+    pub fn preconditions(mut self, value: crate::io::k8s::apimachinery::pkg::apis::meta::v1::preconditions::Preconditions) -> Self {
+        self.inner.body.preconditions = Some(value.into());
+        self
+    }
+
+    /// Sets [`preconditions`](#method.preconditions) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_preconditions(mut self, value: Option<crate::io::k8s::apimachinery::pkg::apis::meta::v1::preconditions::Preconditions>) -> Self {
+        self.inner.body.preconditions = value.map(|value| value.into());
+        self
+    }
+}
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for DeleteOptionsDeleteBuilder21<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    type Output = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::Status;
+
+    const METHOD: http::Method = http::Method::DELETE;
+
+    const OPERATION_ID: Option<&'static str> = Some("deleteAppsV1NamespacedDaemonSet");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok(format!("/apis/apps/v1/namespaces/{namespace}/daemonsets/{name}", name=crate::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?, namespace=crate::client::path_param("namespace", self.inner.param_namespace.as_ref().expect("missing parameter namespace?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::CONTENT_TYPE.as_str(), "application/json")
+        .json(&self.inner.body)
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("dryRun", self.inner.param_dry_run.as_ref().map(std::string::ToString::to_string)),
+            ("gracePeriodSeconds", self.inner.param_grace_period_seconds.as_ref().map(std::string::ToString::to_string)),
+            ("orphanDependents", self.inner.param_orphan_dependents.as_ref().map(std::string::ToString::to_string)),
+            ("propagationPolicy", self.inner.param_propagation_policy.as_ref().map(std::string::ToString::to_string)),
+            ("pretty", self.inner.param_pretty.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Content-Type")
+            || name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<DeleteOptionsDeleteBuilder21<crate::generics::NameExists, crate::generics::NamespaceExists>> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: DeleteOptionsDeleteBuilder21<crate::generics::NameExists, crate::generics::NamespaceExists>) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl DeleteOptionsDeleteBuilder21<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = DeleteOptionsDeleteBuilder21 {
+            inner: DeleteOptionsOpBuilder3Container {
+            body: crate::cli::read_from_input(matches)?,
+            param_dry_run: matches.and_then(|m| {
+                    m.value_of("dry-run").map(|_| {
+                        value_t!(m, "dry-run", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_grace_period_seconds: matches.and_then(|m| {
+                    m.value_of("grace-period-seconds").map(|_| {
+                        value_t!(m, "grace-period-seconds", i64).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_orphan_dependents: matches.and_then(|m| {
+                    m.value_of("orphan-dependents").map(|_| {
+                        value_t!(m, "orphan-dependents", bool).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_propagation_policy: matches.and_then(|m| {
+                    m.value_of("propagation-policy").map(|_| {
+                        value_t!(m, "propagation-policy", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_name: matches.and_then(|m| {
+                    m.value_of("name").map(|_| {
+                        value_t!(m, "name", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_namespace: matches.and_then(|m| {
+                    m.value_of("namespace").map(|_| {
+                        value_t!(m, "namespace", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_pretty: matches.and_then(|m| {
+                    m.value_of("pretty").map(|_| {
+                        value_t!(m, "pretty", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            },
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder shared by operations (with the same fields and parameters) associated with `DeleteOptions`.
+#[repr(transparent)]
+#[derive(Debug, Clone)]
+pub struct DeleteOptionsOpBuilder4<Op, Name, Namespace> {
+    inner: DeleteOptionsOpBuilder4Container,
+    _op: core::marker::PhantomData<Op>,
+    _param_name: core::marker::PhantomData<Name>,
+    _param_namespace: core::marker::PhantomData<Namespace>,
+}
+
+#[derive(Debug, Default, Clone)]
+struct DeleteOptionsOpBuilder4Container {
+    body: self::DeleteOptions,
+    param_dry_run: Option<String>,
+    param_grace_period_seconds: Option<i64>,
+    param_orphan_dependents: Option<bool>,
+    param_propagation_policy: Option<String>,
+    param_name: Option<String>,
+    param_namespace: Option<String>,
+    param_pretty: Option<String>,
+}
+
+/// Builder created by [`DeleteOptions::delete_apps_v1_namespaced_deployment`](./struct.DeleteOptions.html#method.delete_apps_v1_namespaced_deployment) method for a `DELETE` operation associated with `DeleteOptions`.
+pub type DeleteOptionsDeleteBuilder22<Name, Namespace> = DeleteOptionsOpBuilder4<DeleteOptionsDeleteOp22, Name, Namespace>;
+
+/// Marks the operation of [`DeleteOptionsDeleteBuilder22`] in the shared builder.
+#[derive(Debug, Clone)]
+pub enum DeleteOptionsDeleteOp22 {}
+
+impl<Op, Name, Namespace> DeleteOptionsOpBuilder4<Op, Name, Namespace> {
+    /// When present, indicates that modifications should not be persisted. An invalid or unrecognized dryRun directive will result in an error response and no further processing of the request. Valid values are: - All: all dry run stages will be processed
+    #[inline]
+    pub fn dry_run(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_dry_run = Some(value.into());
+        self
+    }
+
+    /// Sets [`dry_run`](#method.dry_run) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_dry_run(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_dry_run = value.map(|value| value.into());
+        self
+    }
+
+    /// The duration in seconds before the object should be deleted. Value must be non-negative integer. The value zero indicates delete immediately. If this value is nil, the default grace period for the specified type will be used. Defaults to a per object value if not specified. zero means delete immediately.
+    #[inline]
+    pub fn grace_period_seconds(mut self, value: impl Into<i64>) -> Self {
+        self.inner.param_grace_period_seconds = Some({
+            let val = value.into();
+            self.inner.body.grace_period_seconds = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// Same as [`grace_period_seconds`](#method.grace_period_seconds), but takes a reference.
+    #[inline]
+    pub fn grace_period_seconds_ref(self, value: &i64) -> Self {
+        self.grace_period_seconds(*value)
+    }
+
+    /// Deprecated: please use the PropagationPolicy, this field will be deprecated in 1.7. Should the dependent objects be orphaned. If true/false, the "orphan" finalizer will be added to/removed from the object's finalizers list. Either this field or PropagationPolicy may be set, but not both.
+    #[inline]
+    pub fn orphan_dependents(mut self, value: impl Into<bool>) -> Self {
+        self.inner.param_orphan_dependents = Some({
+            let val = value.into();
+            self.inner.body.orphan_dependents = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// Same as [`orphan_dependents`](#method.orphan_dependents), but takes a reference.
+    #[inline]
+    pub fn orphan_dependents_ref(self, value: &bool) -> Self {
+        self.orphan_dependents(*value)
+    }
+
+    /// Whether and how garbage collection will be performed. Either this field or OrphanDependents may be set, but not both. The default policy is decided by the existing finalizer set in the metadata.finalizers and the resource-specific default policy. Acceptable values are: 'Orphan' - orphan the dependents; 'Background' - allow the garbage collector to delete the dependents in the background; 'Foreground' - a cascading policy that deletes all dependents in the foreground.
+    #[inline]
+    pub fn propagation_policy(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_propagation_policy = Some({
+            let val = value.into();
+            self.inner.body.propagation_policy = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// name of the Deployment
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> DeleteOptionsOpBuilder4<Op, crate::generics::NameExists, Namespace> {
+        self.inner.param_name = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// object name and auth scope, such as for teams and projects
+    #[inline]
+    pub fn namespace(mut self, value: impl Into<String>) -> DeleteOptionsOpBuilder4<Op, Name, crate::generics::NamespaceExists> {
+        self.inner.param_namespace = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// If 'true', then the output is pretty printed.
+    #[inline]
+    pub fn pretty(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_pretty = Some(value.into());
+        self
+    }
+
+    /// Sets [`pretty`](#method.pretty) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_pretty(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_pretty = value.map(|value| value.into());
+        self
+    }
+
+    /// APIVersion defines the versioned schema of this representation of an object. Servers should convert recognized schemas to the latest internal value, and may reject unrecognized values. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#resources
+    #[inline]
+    pub fn api_version(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.api_version = Some(value.into());
+        self
+    }
+
+    /// Sets [`api_version`](#method.api_version) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_api_version(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.api_version = value.map(|value| value.into());
+        self
+    }
+
+    /// Kind is a string value representing the REST resource this object represents. Servers may infer this from the endpoint the client submits requests to. Cannot be updated. In CamelCase. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#types-kinds
+    #[inline]
+    pub fn kind(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.kind = Some(value.into());
+        self
+    }
+
+    /// Sets [`kind`](#method.kind) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_kind(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.kind = value.map(|value| value.into());
+        self
+    }
+
+    /// Must be fulfilled before a deletion is carried out. If not possible, a 409 Conflict status will be returned.
+    #[inline]
+    pub fn preconditions(mut self, value: crate::io::k8s::apimachinery::pkg::apis::meta::v1::preconditions::Preconditions) -> Self {
+        self.inner.body.preconditions = Some(value.into());
+        self
+    }
+
+    /// Sets [`preconditions`](#method.preconditions) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_preconditions(mut self, value: Option<crate::io::k8s::apimachinery::pkg::apis::meta::v1::preconditions::Preconditions>) -> Self {
+        self.inner.body.preconditions = value.map(|value| value.into());
+        self
+    }
+}
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for DeleteOptionsDeleteBuilder22<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    type Output = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::Status;
+
+    const METHOD: http::Method = http::Method::DELETE;
+
+    const OPERATION_ID: Option<&'static str> = Some("deleteAppsV1NamespacedDeployment");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok(format!("/apis/apps/v1/namespaces/{namespace}/deployments/{name}", name=crate::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?, namespace=crate::client::path_param("namespace", self.inner.param_namespace.as_ref().expect("missing parameter namespace?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::CONTENT_TYPE.as_str(), "application/json")
+        .json(&self.inner.body)
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("dryRun", self.inner.param_dry_run.as_ref().map(std::string::ToString::to_string)),
+            ("gracePeriodSeconds", self.inner.param_grace_period_seconds.as_ref().map(std::string::ToString::to_string)),
+            ("orphanDependents", self.inner.param_orphan_dependents.as_ref().map(std::string::ToString::to_string)),
+            ("propagationPolicy", self.inner.param_propagation_policy.as_ref().map(std::string::ToString::to_string)),
+            ("pretty", self.inner.param_pretty.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Content-Type")
+            || name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<DeleteOptionsDeleteBuilder22<crate::generics::NameExists, crate::generics::NamespaceExists>> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: DeleteOptionsDeleteBuilder22<crate::generics::NameExists, crate::generics::NamespaceExists>) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl DeleteOptionsDeleteBuilder22<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = DeleteOptionsDeleteBuilder22 {
+            inner: DeleteOptionsOpBuilder4Container {
+            body: crate::cli::read_from_input(matches)?,
+            param_dry_run: matches.and_then(|m| {
+                    m.value_of("dry-run").map(|_| {
+                        value_t!(m, "dry-run", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_grace_period_seconds: matches.and_then(|m| {
+                    m.value_of("grace-period-seconds").map(|_| {
+                        value_t!(m, "grace-period-seconds", i64).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_orphan_dependents: matches.and_then(|m| {
+                    m.value_of("orphan-dependents").map(|_| {
+                        value_t!(m, "orphan-dependents", bool).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_propagation_policy: matches.and_then(|m| {
+                    m.value_of("propagation-policy").map(|_| {
+                        value_t!(m, "propagation-policy", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_name: matches.and_then(|m| {
+                    m.value_of("name").map(|_| {
+                        value_t!(m, "name", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_namespace: matches.and_then(|m| {
+                    m.value_of("namespace").map(|_| {
+                        value_t!(m, "namespace", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_pretty: matches.and_then(|m| {
+                    m.value_of("pretty").map(|_| {
+                        value_t!(m, "pretty", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            },
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder shared by operations (with the same fields and parameters) associated with `DeleteOptions`.
+#[repr(transparent)]
+#[derive(Debug, Clone)]
+pub struct DeleteOptionsOpBuilder5<Op, Name, Namespace> {
+    inner: DeleteOptionsOpBuilder5Container,
+    _op: core::marker::PhantomData<Op>,
+    _param_name: core::marker::PhantomData<Name>,
+    _param_namespace: core::marker::PhantomData<Namespace>,
+}
+
+#[derive(Debug, Default, Clone)]
+struct DeleteOptionsOpBuilder5Container {
+    body: self::DeleteOptions,
+    param_dry_run: Option<String>,
+    param_grace_period_seconds: Option<i64>,
+    param_orphan_dependents: Option<bool>,
+    param_propagation_policy: Option<String>,
+    param_name: Option<String>,
+    param_namespace: Option<String>,
+    param_pretty: Option<String>,
+}
+
+/// Builder created by [`DeleteOptions::delete_apps_v1_namespaced_replica_set`](./struct.DeleteOptions.html#method.delete_apps_v1_namespaced_replica_set) method for a `DELETE` operation associated with `DeleteOptions`.
+pub type DeleteOptionsDeleteBuilder23<Name, Namespace> = DeleteOptionsOpBuilder5<DeleteOptionsDeleteOp23, Name, Namespace>;
+
+/// Marks the operation of [`DeleteOptionsDeleteBuilder23`] in the shared builder.
+#[derive(Debug, Clone)]
+pub enum DeleteOptionsDeleteOp23 {}
+
+impl<Op, Name, Namespace> DeleteOptionsOpBuilder5<Op, Name, Namespace> {
+    /// When present, indicates that modifications should not be persisted. An invalid or unrecognized dryRun directive will result in an error response and no further processing of the request. Valid values are: - All: all dry run stages will be processed
+    #[inline]
+    pub fn dry_run(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_dry_run = Some(value.into());
+        self
+    }
+
+    /// Sets [`dry_run`](#method.dry_run) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_dry_run(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_dry_run = value.map(|value| value.into());
+        self
+    }
+
+    /// The duration in seconds before the object should be deleted. Value must be non-negative integer. The value zero indicates delete immediately. If this value is nil, the default grace period for the specified type will be used. Defaults to a per object value if not specified. zero means delete immediately.
+    #[inline]
+    pub fn grace_period_seconds(mut self, value: impl Into<i64>) -> Self {
+        self.inner.param_grace_period_seconds = Some({
+            let val = value.into();
+            self.inner.body.grace_period_seconds = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// Same as [`grace_period_seconds`](#method.grace_period_seconds), but takes a reference.
+    #[inline]
+    pub fn grace_period_seconds_ref(self, value: &i64) -> Self {
+        self.grace_period_seconds(*value)
+    }
+
+    /// Deprecated: please use the PropagationPolicy, this field will be deprecated in 1.7. Should the dependent objects be orphaned. If true/false, the "orphan" finalizer will be added to/removed from the object's finalizers list. Either this field or PropagationPolicy may be set, but not both.
+    #[inline]
+    pub fn orphan_dependents(mut self, value: impl Into<bool>) -> Self {
+        self.inner.param_orphan_dependents = Some({
+            let val = value.into();
+            self.inner.body.orphan_dependents = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// Same as [`orphan_dependents`](#method.orphan_dependents), but takes a reference.
+    #[inline]
+    pub fn orphan_dependents_ref(self, value: &bool) -> Self {
+        self.orphan_dependents(*value)
+    }
+
+    /// Whether and how garbage collection will be performed. Either this field or OrphanDependents may be set, but not both. The default policy is decided by the existing finalizer set in the metadata.finalizers and the resource-specific default policy. Acceptable values are: 'Orphan' - orphan the dependents; 'Background' - allow the garbage collector to delete the dependents in the background; 'Foreground' - a cascading policy that deletes all dependents in the foreground.
+    #[inline]
+    pub fn propagation_policy(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_propagation_policy = Some({
+            let val = value.into();
+            self.inner.body.propagation_policy = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// name of the ReplicaSet
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> DeleteOptionsOpBuilder5<Op, crate::generics::NameExists, Namespace> {
+        self.inner.param_name = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// object name and auth scope, such as for teams and projects
+    #[inline]
+    pub fn namespace(mut self, value: impl Into<String>) -> DeleteOptionsOpBuilder5<Op, Name, crate::generics::NamespaceExists> {
+        self.inner.param_namespace = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// If 'true', then the output is pretty printed.
+    #[inline]
+    pub fn pretty(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_pretty = Some(value.into());
+        self
+    }
+
+    /// Sets [`pretty`](#method.pretty) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_pretty(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_pretty = value.map(|value| value.into());
+        self
+    }
+
+    /// APIVersion defines the versioned schema of this representation of an object. Servers should convert recognized schemas to the latest internal value, and may reject unrecognized values. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#resources
+    #[inline]
+    pub fn api_version(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.api_version = Some(value.into());
+        self
+    }
+
+    /// Sets [`api_version`](#method.api_version) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_api_version(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.api_version = value.map(|value| value.into());
+        self
+    }
+
+    /// Kind is a string value representing the REST resource this object represents. Servers may infer this from the endpoint the client submits requests to. Cannot be updated. In CamelCase. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#types-kinds
+    #[inline]
+    pub fn kind(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.kind = Some(value.into());
+        self
+    }
+
+    /// Sets [`kind`](#method.kind) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_kind(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.kind = value.map(|value| value.into());
+        self
+    }
+
+    /// Must be fulfilled before a deletion is carried out. If not possible, a 409 Conflict status will be returned.
+    #[inline]
+    pub fn preconditions(mut self, value: crate::io::k8s::apimachinery::pkg::apis::meta::v1::preconditions::Preconditions) -> Self {
+        self.inner.body.preconditions = Some(value.into());
+        self
+    }
+
+    /// Sets [`preconditions`](#method.preconditions) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_preconditions(mut self, value: Option<crate::io::k8s::apimachinery::pkg::apis::meta::v1::preconditions::Preconditions>) -> Self {
+        self.inner.body.preconditions = value.map(|value| value.into());
+        self
+    }
+}
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for DeleteOptionsDeleteBuilder23<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    type Output = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::Status;
+
+    const METHOD: http::Method = http::Method::DELETE;
+
+    const OPERATION_ID: Option<&'static str> = Some("deleteAppsV1NamespacedReplicaSet");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok(format!("/apis/apps/v1/namespaces/{namespace}/replicasets/{name}", name=crate::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?, namespace=crate::client::path_param("namespace", self.inner.param_namespace.as_ref().expect("missing parameter namespace?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::CONTENT_TYPE.as_str(), "application/json")
+        .json(&self.inner.body)
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("dryRun", self.inner.param_dry_run.as_ref().map(std::string::ToString::to_string)),
+            ("gracePeriodSeconds", self.inner.param_grace_period_seconds.as_ref().map(std::string::ToString::to_string)),
+            ("orphanDependents", self.inner.param_orphan_dependents.as_ref().map(std::string::ToString::to_string)),
+            ("propagationPolicy", self.inner.param_propagation_policy.as_ref().map(std::string::ToString::to_string)),
+            ("pretty", self.inner.param_pretty.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Content-Type")
+            || name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<DeleteOptionsDeleteBuilder23<crate::generics::NameExists, crate::generics::NamespaceExists>> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: DeleteOptionsDeleteBuilder23<crate::generics::NameExists, crate::generics::NamespaceExists>) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl DeleteOptionsDeleteBuilder23<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = DeleteOptionsDeleteBuilder23 {
+            inner: DeleteOptionsOpBuilder5Container {
+            body: crate::cli::read_from_input(matches)?,
+            param_dry_run: matches.and_then(|m| {
+                    m.value_of("dry-run").map(|_| {
+                        value_t!(m, "dry-run", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_grace_period_seconds: matches.and_then(|m| {
+                    m.value_of("grace-period-seconds").map(|_| {
+                        value_t!(m, "grace-period-seconds", i64).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_orphan_dependents: matches.and_then(|m| {
+                    m.value_of("orphan-dependents").map(|_| {
+                        value_t!(m, "orphan-dependents", bool).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_propagation_policy: matches.and_then(|m| {
+                    m.value_of("propagation-policy").map(|_| {
+                        value_t!(m, "propagation-policy", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_name: matches.and_then(|m| {
+                    m.value_of("name").map(|_| {
+                        value_t!(m, "name", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_namespace: matches.and_then(|m| {
+                    m.value_of("namespace").map(|_| {
+                        value_t!(m, "namespace", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_pretty: matches.and_then(|m| {
+                    m.value_of("pretty").map(|_| {
+                        value_t!(m, "pretty", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            },
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder shared by operations (with the same fields and parameters) associated with `DeleteOptions`.
+#[repr(transparent)]
+#[derive(Debug, Clone)]
+pub struct DeleteOptionsOpBuilder6<Op, Name, Namespace> {
+    inner: DeleteOptionsOpBuilder6Container,
+    _op: core::marker::PhantomData<Op>,
+    _param_name: core::marker::PhantomData<Name>,
+    _param_namespace: core::marker::PhantomData<Namespace>,
+}
+
+#[derive(Debug, Default, Clone)]
+struct DeleteOptionsOpBuilder6Container {
+    body: self::DeleteOptions,
+    param_dry_run: Option<String>,
+    param_grace_period_seconds: Option<i64>,
+    param_orphan_dependents: Option<bool>,
+    param_propagation_policy: Option<String>,
+    param_name: Option<String>,
+    param_namespace: Option<String>,
+    param_pretty: Option<String>,
+}
+
+/// Builder created by [`DeleteOptions::delete_apps_v1_namespaced_stateful_set`](./struct.DeleteOptions.html#method.delete_apps_v1_namespaced_stateful_set) method for a `DELETE` operation associated with `DeleteOptions`.
+pub type DeleteOptionsDeleteBuilder24<Name, Namespace> = DeleteOptionsOpBuilder6<DeleteOptionsDeleteOp24, Name, Namespace>;
+
+/// Marks the operation of [`DeleteOptionsDeleteBuilder24`] in the shared builder.
+#[derive(Debug, Clone)]
+pub enum DeleteOptionsDeleteOp24 {}
+
+impl<Op, Name, Namespace> DeleteOptionsOpBuilder6<Op, Name, Namespace> {
+    /// When present, indicates that modifications should not be persisted. An invalid or unrecognized dryRun directive will result in an error response and no further processing of the request. Valid values are: - All: all dry run stages will be processed
+    #[inline]
+    pub fn dry_run(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_dry_run = Some(value.into());
+        self
+    }
+
+    /// Sets [`dry_run`](#method.dry_run) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_dry_run(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_dry_run = value.map(|value| value.into());
+        self
+    }
+
+    /// The duration in seconds before the object should be deleted. Value must be non-negative integer. The value zero indicates delete immediately. If this value is nil, the default grace period for the specified type will be used. Defaults to a per object value if not specified. zero means delete immediately.
+    #[inline]
+    pub fn grace_period_seconds(mut self, value: impl Into<i64>) -> Self {
+        self.inner.param_grace_period_seconds = Some({
+            let val = value.into();
+            self.inner.body.grace_period_seconds = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// Same as [`grace_period_seconds`](#method.grace_period_seconds), but takes a reference.
+    #[inline]
+    pub fn grace_period_seconds_ref(self, value: &i64) -> Self {
+        self.grace_period_seconds(*value)
+    }
+
+    /// Deprecated: please use the PropagationPolicy, this field will be deprecated in 1.7. Should the dependent objects be orphaned. If true/false, the "orphan" finalizer will be added to/removed from the object's finalizers list. Either this field or PropagationPolicy may be set, but not both.
+    #[inline]
+    pub fn orphan_dependents(mut self, value: impl Into<bool>) -> Self {
+        self.inner.param_orphan_dependents = Some({
+            let val = value.into();
+            self.inner.body.orphan_dependents = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// Same as [`orphan_dependents`](#method.orphan_dependents), but takes a reference.
+    #[inline]
+    pub fn orphan_dependents_ref(self, value: &bool) -> Self {
+        self.orphan_dependents(*value)
+    }
+
+    /// Whether and how garbage collection will be performed. Either this field or OrphanDependents may be set, but not both. The default policy is decided by the existing finalizer set in the metadata.finalizers and the resource-specific default policy. Acceptable values are: 'Orphan' - orphan the dependents; 'Background' - allow the garbage collector to delete the dependents in the background; 'Foreground' - a cascading policy that deletes all dependents in the foreground.
+    #[inline]
+    pub fn propagation_policy(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_propagation_policy = Some({
+            let val = value.into();
+            self.inner.body.propagation_policy = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// name of the StatefulSet
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> DeleteOptionsOpBuilder6<Op, crate::generics::NameExists, Namespace> {
+        self.inner.param_name = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// object name and auth scope, such as for teams and projects
+    #[inline]
+    pub fn namespace(mut self, value: impl Into<String>) -> DeleteOptionsOpBuilder6<Op, Name, crate::generics::NamespaceExists> {
+        self.inner.param_namespace = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// If 'true', then the output is pretty printed.
+    #[inline]
+    pub fn pretty(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_pretty = Some(value.into());
+        self
+    }
+
+    /// Sets [`pretty`](#method.pretty) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_pretty(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_pretty = value.map(|value| value.into());
+        self
+    }
+
+    /// APIVersion defines the versioned schema of this representation of an object. Servers should convert recognized schemas to the latest internal value, and may reject unrecognized values. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#resources
+    #[inline]
+    pub fn api_version(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.api_version = Some(value.into());
+        self
+    }
+
+    /// Sets [`api_version`](#method.api_version) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_api_version(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.api_version = value.map(|value| value.into());
+        self
+    }
+
+    /// Kind is a string value representing the REST resource this object represents. Servers may infer this from the endpoint the client submits requests to. Cannot be updated. In CamelCase. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#types-kinds
+    #[inline]
+    pub fn kind(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.kind = Some(value.into());
+        self
+    }
+
+    /// Sets [`kind`](#method.kind) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_kind(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.kind = value.map(|value| value.into());
+        self
+    }
+
+    /// Must be fulfilled before a deletion is carried out. If not possible, a 409 Conflict status will be returned.
+    #[inline]
+    pub fn preconditions(mut self, value: crate::io::k8s::apimachinery::pkg::apis::meta::v1::preconditions::Preconditions) -> Self {
+        self.inner.body.preconditions = Some(value.into());
+        self
+    }
+
+    /// Sets [`preconditions`](#method.preconditions) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_preconditions(mut self, value: Option<crate::io::k8s::apimachinery::pkg::apis::meta::v1::preconditions::Preconditions>) -> Self {
+        self.inner.body.preconditions = value.map(|value| value.into());
+        self
+    }
+}
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for DeleteOptionsDeleteBuilder24<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    type Output = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::Status;
+
+    const METHOD: http::Method = http::Method::DELETE;
+
+    const OPERATION_ID: Option<&'static str> = Some("deleteAppsV1NamespacedStatefulSet");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok(format!("/apis/apps/v1/namespaces/{namespace}/statefulsets/{name}", name=crate::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?, namespace=crate::client::path_param("namespace", self.inner.param_namespace.as_ref().expect("missing parameter namespace?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::CONTENT_TYPE.as_str(), "application/json")
+        .json(&self.inner.body)
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("dryRun", self.inner.param_dry_run.as_ref().map(std::string::ToString::to_string)),
+            ("gracePeriodSeconds", self.inner.param_grace_period_seconds.as_ref().map(std::string::ToString::to_string)),
+            ("orphanDependents", self.inner.param_orphan_dependents.as_ref().map(std::string::ToString::to_string)),
+            ("propagationPolicy", self.inner.param_propagation_policy.as_ref().map(std::string::ToString::to_string)),
+            ("pretty", self.inner.param_pretty.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Content-Type")
+            || name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<DeleteOptionsDeleteBuilder24<crate::generics::NameExists, crate::generics::NamespaceExists>> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: DeleteOptionsDeleteBuilder24<crate::generics::NameExists, crate::generics::NamespaceExists>) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl DeleteOptionsDeleteBuilder24<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = DeleteOptionsDeleteBuilder24 {
+            inner: DeleteOptionsOpBuilder6Container {
+            body: crate::cli::read_from_input(matches)?,
+            param_dry_run: matches.and_then(|m| {
+                    m.value_of("dry-run").map(|_| {
+                        value_t!(m, "dry-run", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_grace_period_seconds: matches.and_then(|m| {
+                    m.value_of("grace-period-seconds").map(|_| {
+                        value_t!(m, "grace-period-seconds", i64).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_orphan_dependents: matches.and_then(|m| {
+                    m.value_of("orphan-dependents").map(|_| {
+                        value_t!(m, "orphan-dependents", bool).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_propagation_policy: matches.and_then(|m| {
+                    m.value_of("propagation-policy").map(|_| {
+                        value_t!(m, "propagation-policy", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_name: matches.and_then(|m| {
+                    m.value_of("name").map(|_| {
+                        value_t!(m, "name", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_namespace: matches.and_then(|m| {
+                    m.value_of("namespace").map(|_| {
+                        value_t!(m, "namespace", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_pretty: matches.and_then(|m| {
+                    m.value_of("pretty").map(|_| {
+                        value_t!(m, "pretty", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            },
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder created by [`DeleteOptions::delete_apps_v1beta1_namespaced_controller_revision`](./struct.DeleteOptions.html#method.delete_apps_v1beta1_namespaced_controller_revision) method for a `DELETE` operation associated with `DeleteOptions`.
+pub type DeleteOptionsDeleteBuilder25<Name, Namespace> = DeleteOptionsOpBuilder2<DeleteOptionsDeleteOp25, Name, Namespace>;
+
+/// Marks the operation of [`DeleteOptionsDeleteBuilder25`] in the shared builder.
+#[derive(Debug, Clone)]
+pub enum DeleteOptionsDeleteOp25 {}
+
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for DeleteOptionsDeleteBuilder25<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    type Output = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::Status;
+
+    const METHOD: http::Method = http::Method::DELETE;
+
+    const OPERATION_ID: Option<&'static str> = Some("deleteAppsV1beta1NamespacedControllerRevision");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok(format!("/apis/apps/v1beta1/namespaces/{namespace}/controllerrevisions/{name}", name=crate::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?, namespace=crate::client::path_param("namespace", self.inner.param_namespace.as_ref().expect("missing parameter namespace?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::CONTENT_TYPE.as_str(), "application/json")
+        .json(&self.inner.body)
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("dryRun", self.inner.param_dry_run.as_ref().map(std::string::ToString::to_string)),
+            ("gracePeriodSeconds", self.inner.param_grace_period_seconds.as_ref().map(std::string::ToString::to_string)),
+            ("orphanDependents", self.inner.param_orphan_dependents.as_ref().map(std::string::ToString::to_string)),
+            ("propagationPolicy", self.inner.param_propagation_policy.as_ref().map(std::string::ToString::to_string)),
+            ("pretty", self.inner.param_pretty.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Content-Type")
+            || name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<DeleteOptionsDeleteBuilder25<crate::generics::NameExists, crate::generics::NamespaceExists>> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: DeleteOptionsDeleteBuilder25<crate::generics::NameExists, crate::generics::NamespaceExists>) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl DeleteOptionsDeleteBuilder25<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = DeleteOptionsDeleteBuilder25 {
+            inner: DeleteOptionsOpBuilder2Container {
+            body: crate::cli::read_from_input(matches)?,
+            param_dry_run: matches.and_then(|m| {
+                    m.value_of("dry-run").map(|_| {
+                        value_t!(m, "dry-run", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_grace_period_seconds: matches.and_then(|m| {
+                    m.value_of("grace-period-seconds").map(|_| {
+                        value_t!(m, "grace-period-seconds", i64).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_orphan_dependents: matches.and_then(|m| {
+                    m.value_of("orphan-dependents").map(|_| {
+                        value_t!(m, "orphan-dependents", bool).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_propagation_policy: matches.and_then(|m| {
+                    m.value_of("propagation-policy").map(|_| {
+                        value_t!(m, "propagation-policy", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_name: matches.and_then(|m| {
+                    m.value_of("name").map(|_| {
+                        value_t!(m, "name", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_namespace: matches.and_then(|m| {
+                    m.value_of("namespace").map(|_| {
+                        value_t!(m, "namespace", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_pretty: matches.and_then(|m| {
+                    m.value_of("pretty").map(|_| {
+                        value_t!(m, "pretty", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            },
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder created by [`DeleteOptions::delete_apps_v1beta1_namespaced_deployment`](./struct.DeleteOptions.html#method.delete_apps_v1beta1_namespaced_deployment) method for a `DELETE` operation associated with `DeleteOptions`.
+pub type DeleteOptionsDeleteBuilder26<Name, Namespace> = DeleteOptionsOpBuilder4<DeleteOptionsDeleteOp26, Name, Namespace>;
+
+/// Marks the operation of [`DeleteOptionsDeleteBuilder26`] in the shared builder.
+#[derive(Debug, Clone)]
+pub enum DeleteOptionsDeleteOp26 {}
+
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for DeleteOptionsDeleteBuilder26<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    type Output = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::Status;
+
+    const METHOD: http::Method = http::Method::DELETE;
+
+    const OPERATION_ID: Option<&'static str> = Some("deleteAppsV1beta1NamespacedDeployment");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok(format!("/apis/apps/v1beta1/namespaces/{namespace}/deployments/{name}", name=crate::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?, namespace=crate::client::path_param("namespace", self.inner.param_namespace.as_ref().expect("missing parameter namespace?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::CONTENT_TYPE.as_str(), "application/json")
+        .json(&self.inner.body)
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("dryRun", self.inner.param_dry_run.as_ref().map(std::string::ToString::to_string)),
+            ("gracePeriodSeconds", self.inner.param_grace_period_seconds.as_ref().map(std::string::ToString::to_string)),
+            ("orphanDependents", self.inner.param_orphan_dependents.as_ref().map(std::string::ToString::to_string)),
+            ("propagationPolicy", self.inner.param_propagation_policy.as_ref().map(std::string::ToString::to_string)),
+            ("pretty", self.inner.param_pretty.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Content-Type")
+            || name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<DeleteOptionsDeleteBuilder26<crate::generics::NameExists, crate::generics::NamespaceExists>> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: DeleteOptionsDeleteBuilder26<crate::generics::NameExists, crate::generics::NamespaceExists>) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl DeleteOptionsDeleteBuilder26<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = DeleteOptionsDeleteBuilder26 {
+            inner: DeleteOptionsOpBuilder4Container {
+            body: crate::cli::read_from_input(matches)?,
+            param_dry_run: matches.and_then(|m| {
+                    m.value_of("dry-run").map(|_| {
+                        value_t!(m, "dry-run", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_grace_period_seconds: matches.and_then(|m| {
+                    m.value_of("grace-period-seconds").map(|_| {
+                        value_t!(m, "grace-period-seconds", i64).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_orphan_dependents: matches.and_then(|m| {
+                    m.value_of("orphan-dependents").map(|_| {
+                        value_t!(m, "orphan-dependents", bool).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_propagation_policy: matches.and_then(|m| {
+                    m.value_of("propagation-policy").map(|_| {
+                        value_t!(m, "propagation-policy", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_name: matches.and_then(|m| {
+                    m.value_of("name").map(|_| {
+                        value_t!(m, "name", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_namespace: matches.and_then(|m| {
+                    m.value_of("namespace").map(|_| {
+                        value_t!(m, "namespace", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_pretty: matches.and_then(|m| {
+                    m.value_of("pretty").map(|_| {
+                        value_t!(m, "pretty", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            },
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder created by [`DeleteOptions::delete_apps_v1beta1_namespaced_stateful_set`](./struct.DeleteOptions.html#method.delete_apps_v1beta1_namespaced_stateful_set) method for a `DELETE` operation associated with `DeleteOptions`.
+pub type DeleteOptionsDeleteBuilder27<Name, Namespace> = DeleteOptionsOpBuilder6<DeleteOptionsDeleteOp27, Name, Namespace>;
+
+/// Marks the operation of [`DeleteOptionsDeleteBuilder27`] in the shared builder.
+#[derive(Debug, Clone)]
+pub enum DeleteOptionsDeleteOp27 {}
+
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for DeleteOptionsDeleteBuilder27<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    type Output = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::Status;
+
+    const METHOD: http::Method = http::Method::DELETE;
+
+    const OPERATION_ID: Option<&'static str> = Some("deleteAppsV1beta1NamespacedStatefulSet");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok(format!("/apis/apps/v1beta1/namespaces/{namespace}/statefulsets/{name}", name=crate::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?, namespace=crate::client::path_param("namespace", self.inner.param_namespace.as_ref().expect("missing parameter namespace?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::CONTENT_TYPE.as_str(), "application/json")
+        .json(&self.inner.body)
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("dryRun", self.inner.param_dry_run.as_ref().map(std::string::ToString::to_string)),
+            ("gracePeriodSeconds", self.inner.param_grace_period_seconds.as_ref().map(std::string::ToString::to_string)),
+            ("orphanDependents", self.inner.param_orphan_dependents.as_ref().map(std::string::ToString::to_string)),
+            ("propagationPolicy", self.inner.param_propagation_policy.as_ref().map(std::string::ToString::to_string)),
+            ("pretty", self.inner.param_pretty.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Content-Type")
+            || name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<DeleteOptionsDeleteBuilder27<crate::generics::NameExists, crate::generics::NamespaceExists>> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: DeleteOptionsDeleteBuilder27<crate::generics::NameExists, crate::generics::NamespaceExists>) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl DeleteOptionsDeleteBuilder27<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = DeleteOptionsDeleteBuilder27 {
+            inner: DeleteOptionsOpBuilder6Container {
+            body: crate::cli::read_from_input(matches)?,
+            param_dry_run: matches.and_then(|m| {
+                    m.value_of("dry-run").map(|_| {
+                        value_t!(m, "dry-run", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_grace_period_seconds: matches.and_then(|m| {
+                    m.value_of("grace-period-seconds").map(|_| {
+                        value_t!(m, "grace-period-seconds", i64).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_orphan_dependents: matches.and_then(|m| {
+                    m.value_of("orphan-dependents").map(|_| {
+                        value_t!(m, "orphan-dependents", bool).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_propagation_policy: matches.and_then(|m| {
+                    m.value_of("propagation-policy").map(|_| {
+                        value_t!(m, "propagation-policy", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_name: matches.and_then(|m| {
+                    m.value_of("name").map(|_| {
+                        value_t!(m, "name", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_namespace: matches.and_then(|m| {
+                    m.value_of("namespace").map(|_| {
+                        value_t!(m, "namespace", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_pretty: matches.and_then(|m| {
+                    m.value_of("pretty").map(|_| {
+                        value_t!(m, "pretty", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            },
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder created by [`DeleteOptions::delete_apps_v1beta2_namespaced_controller_revision`](./struct.DeleteOptions.html#method.delete_apps_v1beta2_namespaced_controller_revision) method for a `DELETE` operation associated with `DeleteOptions`.
+pub type DeleteOptionsDeleteBuilder28<Name, Namespace> = DeleteOptionsOpBuilder2<DeleteOptionsDeleteOp28, Name, Namespace>;
+
+/// Marks the operation of [`DeleteOptionsDeleteBuilder28`] in the shared builder.
+#[derive(Debug, Clone)]
+pub enum DeleteOptionsDeleteOp28 {}
+
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for DeleteOptionsDeleteBuilder28<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    type Output = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::Status;
+
+    const METHOD: http::Method = http::Method::DELETE;
+
+    const OPERATION_ID: Option<&'static str> = Some("deleteAppsV1beta2NamespacedControllerRevision");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok(format!("/apis/apps/v1beta2/namespaces/{namespace}/controllerrevisions/{name}", name=crate::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?, namespace=crate::client::path_param("namespace", self.inner.param_namespace.as_ref().expect("missing parameter namespace?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::CONTENT_TYPE.as_str(), "application/json")
+        .json(&self.inner.body)
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("dryRun", self.inner.param_dry_run.as_ref().map(std::string::ToString::to_string)),
+            ("gracePeriodSeconds", self.inner.param_grace_period_seconds.as_ref().map(std::string::ToString::to_string)),
+            ("orphanDependents", self.inner.param_orphan_dependents.as_ref().map(std::string::ToString::to_string)),
+            ("propagationPolicy", self.inner.param_propagation_policy.as_ref().map(std::string::ToString::to_string)),
+            ("pretty", self.inner.param_pretty.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Content-Type")
+            || name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<DeleteOptionsDeleteBuilder28<crate::generics::NameExists, crate::generics::NamespaceExists>> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: DeleteOptionsDeleteBuilder28<crate::generics::NameExists, crate::generics::NamespaceExists>) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl DeleteOptionsDeleteBuilder28<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = DeleteOptionsDeleteBuilder28 {
+            inner: DeleteOptionsOpBuilder2Container {
+            body: crate::cli::read_from_input(matches)?,
+            param_dry_run: matches.and_then(|m| {
+                    m.value_of("dry-run").map(|_| {
+                        value_t!(m, "dry-run", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_grace_period_seconds: matches.and_then(|m| {
+                    m.value_of("grace-period-seconds").map(|_| {
+                        value_t!(m, "grace-period-seconds", i64).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_orphan_dependents: matches.and_then(|m| {
+                    m.value_of("orphan-dependents").map(|_| {
+                        value_t!(m, "orphan-dependents", bool).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_propagation_policy: matches.and_then(|m| {
+                    m.value_of("propagation-policy").map(|_| {
+                        value_t!(m, "propagation-policy", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_name: matches.and_then(|m| {
+                    m.value_of("name").map(|_| {
+                        value_t!(m, "name", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_namespace: matches.and_then(|m| {
+                    m.value_of("namespace").map(|_| {
+                        value_t!(m, "namespace", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_pretty: matches.and_then(|m| {
+                    m.value_of("pretty").map(|_| {
+                        value_t!(m, "pretty", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            },
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder created by [`DeleteOptions::delete_apps_v1beta2_namespaced_daemon_set`](./struct.DeleteOptions.html#method.delete_apps_v1beta2_namespaced_daemon_set) method for a `DELETE` operation associated with `DeleteOptions`.
+pub type DeleteOptionsDeleteBuilder29<Name, Namespace> = DeleteOptionsOpBuilder3<DeleteOptionsDeleteOp29, Name, Namespace>;
+
+/// Marks the operation of [`DeleteOptionsDeleteBuilder29`] in the shared builder.
+#[derive(Debug, Clone)]
+pub enum DeleteOptionsDeleteOp29 {}
+
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for DeleteOptionsDeleteBuilder29<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    type Output = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::Status;
+
+    const METHOD: http::Method = http::Method::DELETE;
+
+    const OPERATION_ID: Option<&'static str> = Some("deleteAppsV1beta2NamespacedDaemonSet");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok(format!("/apis/apps/v1beta2/namespaces/{namespace}/daemonsets/{name}", name=crate::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?, namespace=crate::client::path_param("namespace", self.inner.param_namespace.as_ref().expect("missing parameter namespace?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::CONTENT_TYPE.as_str(), "application/json")
+        .json(&self.inner.body)
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("dryRun", self.inner.param_dry_run.as_ref().map(std::string::ToString::to_string)),
+            ("gracePeriodSeconds", self.inner.param_grace_period_seconds.as_ref().map(std::string::ToString::to_string)),
+            ("orphanDependents", self.inner.param_orphan_dependents.as_ref().map(std::string::ToString::to_string)),
+            ("propagationPolicy", self.inner.param_propagation_policy.as_ref().map(std::string::ToString::to_string)),
+            ("pretty", self.inner.param_pretty.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Content-Type")
+            || name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<DeleteOptionsDeleteBuilder29<crate::generics::NameExists, crate::generics::NamespaceExists>> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: DeleteOptionsDeleteBuilder29<crate::generics::NameExists, crate::generics::NamespaceExists>) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl DeleteOptionsDeleteBuilder29<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = DeleteOptionsDeleteBuilder29 {
+            inner: DeleteOptionsOpBuilder3Container {
+            body: crate::cli::read_from_input(matches)?,
+            param_dry_run: matches.and_then(|m| {
+                    m.value_of("dry-run").map(|_| {
+                        value_t!(m, "dry-run", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_grace_period_seconds: matches.and_then(|m| {
+                    m.value_of("grace-period-seconds").map(|_| {
+                        value_t!(m, "grace-period-seconds", i64).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_orphan_dependents: matches.and_then(|m| {
+                    m.value_of("orphan-dependents").map(|_| {
+                        value_t!(m, "orphan-dependents", bool).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_propagation_policy: matches.and_then(|m| {
+                    m.value_of("propagation-policy").map(|_| {
+                        value_t!(m, "propagation-policy", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_name: matches.and_then(|m| {
+                    m.value_of("name").map(|_| {
+                        value_t!(m, "name", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_namespace: matches.and_then(|m| {
+                    m.value_of("namespace").map(|_| {
+                        value_t!(m, "namespace", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_pretty: matches.and_then(|m| {
+                    m.value_of("pretty").map(|_| {
+                        value_t!(m, "pretty", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            },
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder created by [`DeleteOptions::delete_apps_v1beta2_namespaced_deployment`](./struct.DeleteOptions.html#method.delete_apps_v1beta2_namespaced_deployment) method for a `DELETE` operation associated with `DeleteOptions`.
+pub type DeleteOptionsDeleteBuilder30<Name, Namespace> = DeleteOptionsOpBuilder4<DeleteOptionsDeleteOp30, Name, Namespace>;
+
+/// Marks the operation of [`DeleteOptionsDeleteBuilder30`] in the shared builder.
+#[derive(Debug, Clone)]
+pub enum DeleteOptionsDeleteOp30 {}
+
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for DeleteOptionsDeleteBuilder30<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    type Output = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::Status;
+
+    const METHOD: http::Method = http::Method::DELETE;
+
+    const OPERATION_ID: Option<&'static str> = Some("deleteAppsV1beta2NamespacedDeployment");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok(format!("/apis/apps/v1beta2/namespaces/{namespace}/deployments/{name}", name=crate::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?, namespace=crate::client::path_param("namespace", self.inner.param_namespace.as_ref().expect("missing parameter namespace?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::CONTENT_TYPE.as_str(), "application/json")
+        .json(&self.inner.body)
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("dryRun", self.inner.param_dry_run.as_ref().map(std::string::ToString::to_string)),
+            ("gracePeriodSeconds", self.inner.param_grace_period_seconds.as_ref().map(std::string::ToString::to_string)),
+            ("orphanDependents", self.inner.param_orphan_dependents.as_ref().map(std::string::ToString::to_string)),
+            ("propagationPolicy", self.inner.param_propagation_policy.as_ref().map(std::string::ToString::to_string)),
+            ("pretty", self.inner.param_pretty.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Content-Type")
+            || name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<DeleteOptionsDeleteBuilder30<crate::generics::NameExists, crate::generics::NamespaceExists>> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: DeleteOptionsDeleteBuilder30<crate::generics::NameExists, crate::generics::NamespaceExists>) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl DeleteOptionsDeleteBuilder30<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = DeleteOptionsDeleteBuilder30 {
+            inner: DeleteOptionsOpBuilder4Container {
+            body: crate::cli::read_from_input(matches)?,
+            param_dry_run: matches.and_then(|m| {
+                    m.value_of("dry-run").map(|_| {
+                        value_t!(m, "dry-run", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_grace_period_seconds: matches.and_then(|m| {
+                    m.value_of("grace-period-seconds").map(|_| {
+                        value_t!(m, "grace-period-seconds", i64).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_orphan_dependents: matches.and_then(|m| {
+                    m.value_of("orphan-dependents").map(|_| {
+                        value_t!(m, "orphan-dependents", bool).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_propagation_policy: matches.and_then(|m| {
+                    m.value_of("propagation-policy").map(|_| {
+                        value_t!(m, "propagation-policy", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_name: matches.and_then(|m| {
+                    m.value_of("name").map(|_| {
+                        value_t!(m, "name", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_namespace: matches.and_then(|m| {
+                    m.value_of("namespace").map(|_| {
+                        value_t!(m, "namespace", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_pretty: matches.and_then(|m| {
+                    m.value_of("pretty").map(|_| {
+                        value_t!(m, "pretty", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            },
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder created by [`DeleteOptions::delete_apps_v1beta2_namespaced_replica_set`](./struct.DeleteOptions.html#method.delete_apps_v1beta2_namespaced_replica_set) method for a `DELETE` operation associated with `DeleteOptions`.
+pub type DeleteOptionsDeleteBuilder31<Name, Namespace> = DeleteOptionsOpBuilder5<DeleteOptionsDeleteOp31, Name, Namespace>;
+
+/// Marks the operation of [`DeleteOptionsDeleteBuilder31`] in the shared builder.
+#[derive(Debug, Clone)]
+pub enum DeleteOptionsDeleteOp31 {}
+
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for DeleteOptionsDeleteBuilder31<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    type Output = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::Status;
+
+    const METHOD: http::Method = http::Method::DELETE;
+
+    const OPERATION_ID: Option<&'static str> = Some("deleteAppsV1beta2NamespacedReplicaSet");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok(format!("/apis/apps/v1beta2/namespaces/{namespace}/replicasets/{name}", name=crate::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?, namespace=crate::client::path_param("namespace", self.inner.param_namespace.as_ref().expect("missing parameter namespace?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::CONTENT_TYPE.as_str(), "application/json")
+        .json(&self.inner.body)
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("dryRun", self.inner.param_dry_run.as_ref().map(std::string::ToString::to_string)),
+            ("gracePeriodSeconds", self.inner.param_grace_period_seconds.as_ref().map(std::string::ToString::to_string)),
+            ("orphanDependents", self.inner.param_orphan_dependents.as_ref().map(std::string::ToString::to_string)),
+            ("propagationPolicy", self.inner.param_propagation_policy.as_ref().map(std::string::ToString::to_string)),
+            ("pretty", self.inner.param_pretty.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Content-Type")
+            || name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<DeleteOptionsDeleteBuilder31<crate::generics::NameExists, crate::generics::NamespaceExists>> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: DeleteOptionsDeleteBuilder31<crate::generics::NameExists, crate::generics::NamespaceExists>) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl DeleteOptionsDeleteBuilder31<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = DeleteOptionsDeleteBuilder31 {
+            inner: DeleteOptionsOpBuilder5Container {
+            body: crate::cli::read_from_input(matches)?,
+            param_dry_run: matches.and_then(|m| {
+                    m.value_of("dry-run").map(|_| {
+                        value_t!(m, "dry-run", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_grace_period_seconds: matches.and_then(|m| {
+                    m.value_of("grace-period-seconds").map(|_| {
+                        value_t!(m, "grace-period-seconds", i64).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_orphan_dependents: matches.and_then(|m| {
+                    m.value_of("orphan-dependents").map(|_| {
+                        value_t!(m, "orphan-dependents", bool).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_propagation_policy: matches.and_then(|m| {
+                    m.value_of("propagation-policy").map(|_| {
+                        value_t!(m, "propagation-policy", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_name: matches.and_then(|m| {
+                    m.value_of("name").map(|_| {
+                        value_t!(m, "name", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_namespace: matches.and_then(|m| {
+                    m.value_of("namespace").map(|_| {
+                        value_t!(m, "namespace", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_pretty: matches.and_then(|m| {
+                    m.value_of("pretty").map(|_| {
+                        value_t!(m, "pretty", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            },
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder created by [`DeleteOptions::delete_apps_v1beta2_namespaced_stateful_set`](./struct.DeleteOptions.html#method.delete_apps_v1beta2_namespaced_stateful_set) method for a `DELETE` operation associated with `DeleteOptions`.
+pub type DeleteOptionsDeleteBuilder32<Name, Namespace> = DeleteOptionsOpBuilder6<DeleteOptionsDeleteOp32, Name, Namespace>;
+
+/// Marks the operation of [`DeleteOptionsDeleteBuilder32`] in the shared builder.
+#[derive(Debug, Clone)]
+pub enum DeleteOptionsDeleteOp32 {}
+
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for DeleteOptionsDeleteBuilder32<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    type Output = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::Status;
+
+    const METHOD: http::Method = http::Method::DELETE;
+
+    const OPERATION_ID: Option<&'static str> = Some("deleteAppsV1beta2NamespacedStatefulSet");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok(format!("/apis/apps/v1beta2/namespaces/{namespace}/statefulsets/{name}", name=crate::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?, namespace=crate::client::path_param("namespace", self.inner.param_namespace.as_ref().expect("missing parameter namespace?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::CONTENT_TYPE.as_str(), "application/json")
+        .json(&self.inner.body)
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("dryRun", self.inner.param_dry_run.as_ref().map(std::string::ToString::to_string)),
+            ("gracePeriodSeconds", self.inner.param_grace_period_seconds.as_ref().map(std::string::ToString::to_string)),
+            ("orphanDependents", self.inner.param_orphan_dependents.as_ref().map(std::string::ToString::to_string)),
+            ("propagationPolicy", self.inner.param_propagation_policy.as_ref().map(std::string::ToString::to_string)),
+            ("pretty", self.inner.param_pretty.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Content-Type")
+            || name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<DeleteOptionsDeleteBuilder32<crate::generics::NameExists, crate::generics::NamespaceExists>> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: DeleteOptionsDeleteBuilder32<crate::generics::NameExists, crate::generics::NamespaceExists>) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl DeleteOptionsDeleteBuilder32<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = DeleteOptionsDeleteBuilder32 {
+            inner: DeleteOptionsOpBuilder6Container {
+            body: crate::cli::read_from_input(matches)?,
+            param_dry_run: matches.and_then(|m| {
+                    m.value_of("dry-run").map(|_| {
+                        value_t!(m, "dry-run", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_grace_period_seconds: matches.and_then(|m| {
+                    m.value_of("grace-period-seconds").map(|_| {
+                        value_t!(m, "grace-period-seconds", i64).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_orphan_dependents: matches.and_then(|m| {
+                    m.value_of("orphan-dependents").map(|_| {
+                        value_t!(m, "orphan-dependents", bool).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_propagation_policy: matches.and_then(|m| {
+                    m.value_of("propagation-policy").map(|_| {
+                        value_t!(m, "propagation-policy", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_name: matches.and_then(|m| {
+                    m.value_of("name").map(|_| {
+                        value_t!(m, "name", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_namespace: matches.and_then(|m| {
+                    m.value_of("namespace").map(|_| {
+                        value_t!(m, "namespace", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_pretty: matches.and_then(|m| {
+                    m.value_of("pretty").map(|_| {
+                        value_t!(m, "pretty", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            },
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder created by [`DeleteOptions::delete_auditregistration_v1alpha1_audit_sink`](./struct.DeleteOptions.html#method.delete_auditregistration_v1alpha1_audit_sink) method for a `DELETE` operation associated with `DeleteOptions`.
+#[repr(transparent)]
+#[derive(Debug, Clone)]
+pub struct DeleteOptionsDeleteBuilder33<Name> {
+    inner: DeleteOptionsDeleteBuilder33Container,
+    _param_name: core::marker::PhantomData<Name>,
+}
+
+#[derive(Debug, Default, Clone)]
+struct DeleteOptionsDeleteBuilder33Container {
+    body: self::DeleteOptions,
+    param_dry_run: Option<String>,
+    param_grace_period_seconds: Option<i64>,
+    param_orphan_dependents: Option<bool>,
+    param_propagation_policy: Option<String>,
+    param_name: Option<String>,
+    param_pretty: Option<String>,
+}
+
+impl<Name> DeleteOptionsDeleteBuilder33<Name> {
+    /// When present, indicates that modifications should not be persisted. An invalid or unrecognized dryRun directive will result in an error response and no further processing of the request. Valid values are: - All: all dry run stages will be processed
+    #[inline]
+    pub fn dry_run(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_dry_run = Some(value.into());
+        self
+    }
+
+    /// Sets [`dry_run`](#method.dry_run) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_dry_run(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_dry_run = value.map(|value| value.into());
+        self
+    }
+
+    /// The duration in seconds before the object should be deleted. Value must be non-negative integer. The value zero indicates delete immediately. If this value is nil, the default grace period for the specified type will be used. Defaults to a per object value if not specified. zero means delete immediately.
+    #[inline]
+    pub fn grace_period_seconds(mut self, value: impl Into<i64>) -> Self {
+        self.inner.param_grace_period_seconds = Some({
+            let val = value.into();
+            self.inner.body.grace_period_seconds = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// Same as [`grace_period_seconds`](#method.grace_period_seconds), but takes a reference.
+    #[inline]
+    pub fn grace_period_seconds_ref(self, value: &i64) -> Self {
+        self.grace_period_seconds(*value)
+    }
+
+    /// Deprecated: please use the PropagationPolicy, this field will be deprecated in 1.7. Should the dependent objects be orphaned. If true/false, the "orphan" finalizer will be added to/removed from the object's finalizers list. Either this field or PropagationPolicy may be set, but not both.
+    #[inline]
+    pub fn orphan_dependents(mut self, value: impl Into<bool>) -> Self {
+        self.inner.param_orphan_dependents = Some({
+            let val = value.into();
+            self.inner.body.orphan_dependents = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// Same as [`orphan_dependents`](#method.orphan_dependents), but takes a reference.
+    #[inline]
+    pub fn orphan_dependents_ref(self, value: &bool) -> Self {
+        self.orphan_dependents(*value)
+    }
+
+    /// Whether and how garbage collection will be performed. Either this field or OrphanDependents may be set, but not both. The default policy is decided by the existing finalizer set in the metadata.finalizers and the resource-specific default policy. Acceptable values are: 'Orphan' - orphan the dependents; 'Background' - allow the garbage collector to delete the dependents in the background; 'Foreground' - a cascading policy that deletes all dependents in the foreground.
+    #[inline]
+    pub fn propagation_policy(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_propagation_policy = Some({
+            let val = value.into();
+            self.inner.body.propagation_policy = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// name of the AuditSink
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> DeleteOptionsDeleteBuilder33<crate::generics::NameExists> {
+        self.inner.param_name = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// If 'true', then the output is pretty printed.
+    #[inline]
+    pub fn pretty(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_pretty = Some(value.into());
+        self
+    }
+
+    /// Sets [`pretty`](#method.pretty) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_pretty(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_pretty = value.map(|value| value.into());
+        self
+    }
+
+    /// APIVersion defines the versioned schema of this representation of an object. Servers should convert recognized schemas to the latest internal value, and may reject unrecognized values. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#resources
+    #[inline]
+    pub fn api_version(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.api_version = Some(value.into());
+        self
+    }
+
+    /// Sets [`api_version`](#method.api_version) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_api_version(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.api_version = value.map(|value| value.into());
+        self
+    }
+
+    /// Kind is a string value representing the REST resource this object represents. Servers may infer this from the endpoint the client submits requests to. Cannot be updated. In CamelCase. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#types-kinds
+    #[inline]
+    pub fn kind(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.kind = Some(value.into());
+        self
+    }
+
+    /// Sets [`kind`](#method.kind) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_kind(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.kind = value.map(|value| value.into());
+        self
+    }
+
+    /// Must be fulfilled before a deletion is carried out. If not possible, a 409 Conflict status will be returned.
+    #[inline]
+    pub fn preconditions(mut self, value: crate::io::k8s::apimachinery::pkg::apis::meta::v1::preconditions::Preconditions) -> Self {
+        self.inner.body.preconditions = Some(value.into());
+        self
+    }
+
+    /// Sets [`preconditions`](#method.preconditions) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_preconditions(mut self, value: Option<crate::io::k8s::apimachinery::pkg::apis::meta::v1::preconditions::Preconditions>) -> Self {
+        self.inner.body.preconditions = value.map(|value| value.into());
+        self
+    }
+}
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for DeleteOptionsDeleteBuilder33<crate::generics::NameExists> {
+    type Output = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::Status;
+
+    const METHOD: http::Method = http::Method::DELETE;
+
+    const OPERATION_ID: Option<&'static str> = Some("deleteAuditregistrationV1alpha1AuditSink");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok(format!("/apis/auditregistration.k8s.io/v1alpha1/auditsinks/{name}", name=crate::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::CONTENT_TYPE.as_str(), "application/json")
+        .json(&self.inner.body)
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("dryRun", self.inner.param_dry_run.as_ref().map(std::string::ToString::to_string)),
+            ("gracePeriodSeconds", self.inner.param_grace_period_seconds.as_ref().map(std::string::ToString::to_string)),
+            ("orphanDependents", self.inner.param_orphan_dependents.as_ref().map(std::string::ToString::to_string)),
+            ("propagationPolicy", self.inner.param_propagation_policy.as_ref().map(std::string::ToString::to_string)),
+            ("pretty", self.inner.param_pretty.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Content-Type")
+            || name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<DeleteOptionsDeleteBuilder33<crate::generics::NameExists>> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: DeleteOptionsDeleteBuilder33<crate::generics::NameExists>) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl DeleteOptionsDeleteBuilder33<crate::generics::NameExists> {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = DeleteOptionsDeleteBuilder33 {
+            inner: DeleteOptionsDeleteBuilder33Container {
+            body: crate::cli::read_from_input(matches)?,
+            param_dry_run: matches.and_then(|m| {
+                    m.value_of("dry-run").map(|_| {
+                        value_t!(m, "dry-run", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_grace_period_seconds: matches.and_then(|m| {
+                    m.value_of("grace-period-seconds").map(|_| {
+                        value_t!(m, "grace-period-seconds", i64).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_orphan_dependents: matches.and_then(|m| {
+                    m.value_of("orphan-dependents").map(|_| {
+                        value_t!(m, "orphan-dependents", bool).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_propagation_policy: matches.and_then(|m| {
+                    m.value_of("propagation-policy").map(|_| {
+                        value_t!(m, "propagation-policy", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_name: matches.and_then(|m| {
+                    m.value_of("name").map(|_| {
+                        value_t!(m, "name", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_pretty: matches.and_then(|m| {
+                    m.value_of("pretty").map(|_| {
+                        value_t!(m, "pretty", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            },
+            _param_name: core::marker::PhantomData,
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder shared by operations (with the same fields and parameters) associated with `DeleteOptions`.
+#[repr(transparent)]
+#[derive(Debug, Clone)]
+pub struct DeleteOptionsOpBuilder7<Op, Name, Namespace> {
+    inner: DeleteOptionsOpBuilder7Container,
+    _op: core::marker::PhantomData<Op>,
+    _param_name: core::marker::PhantomData<Name>,
+    _param_namespace: core::marker::PhantomData<Namespace>,
+}
+
+#[derive(Debug, Default, Clone)]
+struct DeleteOptionsOpBuilder7Container {
+    body: self::DeleteOptions,
+    param_dry_run: Option<String>,
+    param_grace_period_seconds: Option<i64>,
+    param_orphan_dependents: Option<bool>,
+    param_propagation_policy: Option<String>,
+    param_name: Option<String>,
+    param_namespace: Option<String>,
+    param_pretty: Option<String>,
+}
+
+/// Builder created by [`DeleteOptions::delete_autoscaling_v1_namespaced_horizontal_pod_autoscaler`](./struct.DeleteOptions.html#method.delete_autoscaling_v1_namespaced_horizontal_pod_autoscaler) method for a `DELETE` operation associated with `DeleteOptions`.
+pub type DeleteOptionsDeleteBuilder34<Name, Namespace> = DeleteOptionsOpBuilder7<DeleteOptionsDeleteOp34, Name, Namespace>;
+
+/// Marks the operation of [`DeleteOptionsDeleteBuilder34`] in the shared builder.
+#[derive(Debug, Clone)]
+pub enum DeleteOptionsDeleteOp34 {}
+
+impl<Op, Name, Namespace> DeleteOptionsOpBuilder7<Op, Name, Namespace> {
+    /// When present, indicates that modifications should not be persisted. An invalid or unrecognized dryRun directive will result in an error response and no further processing of the request. Valid values are: - All: all dry run stages will be processed
+    #[inline]
+    pub fn dry_run(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_dry_run = Some(value.into());
+        self
+    }
+
+    /// Sets [`dry_run`](#method.dry_run) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_dry_run(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_dry_run = value.map(|value| value.into());
+        self
+    }
+
+    /// The duration in seconds before the object should be deleted. Value must be non-negative integer. The value zero indicates delete immediately. If this value is nil, the default grace period for the specified type will be used. Defaults to a per object value if not specified. zero means delete immediately.
+    #[inline]
+    pub fn grace_period_seconds(mut self, value: impl Into<i64>) -> Self {
+        self.inner.param_grace_period_seconds = Some({
+            let val = value.into();
+            self.inner.body.grace_period_seconds = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// Same as [`grace_period_seconds`](#method.grace_period_seconds), but takes a reference.
+    #[inline]
+    pub fn grace_period_seconds_ref(self, value: &i64) -> Self {
+        self.grace_period_seconds(*value)
+    }
+
+    /// Deprecated: please use the PropagationPolicy, this field will be deprecated in 1.7. Should the dependent objects be orphaned. If true/false, the "orphan" finalizer will be added to/removed from the object's finalizers list. Either this field or PropagationPolicy may be set, but not both.
+    #[inline]
+    pub fn orphan_dependents(mut self, value: impl Into<bool>) -> Self {
+        self.inner.param_orphan_dependents = Some({
+            let val = value.into();
+            self.inner.body.orphan_dependents = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// Same as [`orphan_dependents`](#method.orphan_dependents), but takes a reference.
+    #[inline]
+    pub fn orphan_dependents_ref(self, value: &bool) -> Self {
+        self.orphan_dependents(*value)
+    }
+
+    /// Whether and how garbage collection will be performed. Either this field or OrphanDependents may be set, but not both. The default policy is decided by the existing finalizer set in the metadata.finalizers and the resource-specific default policy. Acceptable values are: 'Orphan' - orphan the dependents; 'Background' - allow the garbage collector to delete the dependents in the background; 'Foreground' - a cascading policy that deletes all dependents in the foreground.
+    #[inline]
+    pub fn propagation_policy(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_propagation_policy = Some({
+            let val = value.into();
+            self.inner.body.propagation_policy = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// name of the HorizontalPodAutoscaler
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> DeleteOptionsOpBuilder7<Op, crate::generics::NameExists, Namespace> {
+        self.inner.param_name = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// object name and auth scope, such as for teams and projects
+    #[inline]
+    pub fn namespace(mut self, value: impl Into<String>) -> DeleteOptionsOpBuilder7<Op, Name, crate::generics::NamespaceExists> {
+        self.inner.param_namespace = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// If 'true', then the output is pretty printed.
+    #[inline]
+    pub fn pretty(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_pretty = Some(value.into());
+        self
+    }
+
+    /// Sets [`pretty`](#method.pretty) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_pretty(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_pretty = value.map(|value| value.into());
+        self
+    }
+
+    /// APIVersion defines the versioned schema of this representation of an object. Servers should convert recognized schemas to the latest internal value, and may reject unrecognized values. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#resources
+    #[inline]
+    pub fn api_version(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.api_version = Some(value.into());
+        self
+    }
+
+    /// Sets [`api_version`](#method.api_version) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_api_version(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.api_version = value.map(|value| value.into());
+        self
+    }
+
+    /// Kind is a string value representing the REST resource this object represents. Servers may infer this from the endpoint the client submits requests to. Cannot be updated. In CamelCase. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#types-kinds
+    #[inline]
+    pub fn kind(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.kind = Some(value.into());
+        self
+    }
+
+    /// Sets [`kind`](#method.kind) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_kind(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.kind = value.map(|value| value.into());
+        self
+    }
+
+    /// Must be fulfilled before a deletion is carried out. If not possible, a 409 Conflict status will be returned.
+    #[inline]
+    pub fn preconditions(mut self, value: crate::io::k8s::apimachinery::pkg::apis::meta::v1::preconditions::Preconditions) -> Self {
+        self.inner.body.preconditions = Some(value.into());
+        self
+    }
+
+    /// Sets [`preconditions`](#method.preconditions) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_preconditions(mut self, value: Option<crate::io::k8s::apimachinery::pkg::apis::meta::v1::preconditions::Preconditions>) -> Self {
+        self.inner.body.preconditions = value.map(|value| value.into());
+        self
+    }
+}
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for DeleteOptionsDeleteBuilder34<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    type Output = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::Status;
+
+    const METHOD: http::Method = http::Method::DELETE;
+
+    const OPERATION_ID: Option<&'static str> = Some("deleteAutoscalingV1NamespacedHorizontalPodAutoscaler");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok(format!("/apis/autoscaling/v1/namespaces/{namespace}/horizontalpodautoscalers/{name}", name=crate::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?, namespace=crate::client::path_param("namespace", self.inner.param_namespace.as_ref().expect("missing parameter namespace?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::CONTENT_TYPE.as_str(), "application/json")
+        .json(&self.inner.body)
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("dryRun", self.inner.param_dry_run.as_ref().map(std::string::ToString::to_string)),
+            ("gracePeriodSeconds", self.inner.param_grace_period_seconds.as_ref().map(std::string::ToString::to_string)),
+            ("orphanDependents", self.inner.param_orphan_dependents.as_ref().map(std::string::ToString::to_string)),
+            ("propagationPolicy", self.inner.param_propagation_policy.as_ref().map(std::string::ToString::to_string)),
+            ("pretty", self.inner.param_pretty.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Content-Type")
+            || name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<DeleteOptionsDeleteBuilder34<crate::generics::NameExists, crate::generics::NamespaceExists>> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: DeleteOptionsDeleteBuilder34<crate::generics::NameExists, crate::generics::NamespaceExists>) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl DeleteOptionsDeleteBuilder34<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = DeleteOptionsDeleteBuilder34 {
+            inner: DeleteOptionsOpBuilder7Container {
+            body: crate::cli::read_from_input(matches)?,
+            param_dry_run: matches.and_then(|m| {
+                    m.value_of("dry-run").map(|_| {
+                        value_t!(m, "dry-run", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_grace_period_seconds: matches.and_then(|m| {
+                    m.value_of("grace-period-seconds").map(|_| {
+                        value_t!(m, "grace-period-seconds", i64).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_orphan_dependents: matches.and_then(|m| {
+                    m.value_of("orphan-dependents").map(|_| {
+                        value_t!(m, "orphan-dependents", bool).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_propagation_policy: matches.and_then(|m| {
+                    m.value_of("propagation-policy").map(|_| {
+                        value_t!(m, "propagation-policy", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_name: matches.and_then(|m| {
+                    m.value_of("name").map(|_| {
+                        value_t!(m, "name", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_namespace: matches.and_then(|m| {
+                    m.value_of("namespace").map(|_| {
+                        value_t!(m, "namespace", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_pretty: matches.and_then(|m| {
+                    m.value_of("pretty").map(|_| {
+                        value_t!(m, "pretty", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            },
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder created by [`DeleteOptions::delete_autoscaling_v2beta1_namespaced_horizontal_pod_autoscaler`](./struct.DeleteOptions.html#method.delete_autoscaling_v2beta1_namespaced_horizontal_pod_autoscaler) method for a `DELETE` operation associated with `DeleteOptions`.
+pub type DeleteOptionsDeleteBuilder35<Name, Namespace> = DeleteOptionsOpBuilder7<DeleteOptionsDeleteOp35, Name, Namespace>;
+
+/// Marks the operation of [`DeleteOptionsDeleteBuilder35`] in the shared builder.
+#[derive(Debug, Clone)]
+pub enum DeleteOptionsDeleteOp35 {}
+
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for DeleteOptionsDeleteBuilder35<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    type Output = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::Status;
+
+    const METHOD: http::Method = http::Method::DELETE;
+
+    const OPERATION_ID: Option<&'static str> = Some("deleteAutoscalingV2beta1NamespacedHorizontalPodAutoscaler");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok(format!("/apis/autoscaling/v2beta1/namespaces/{namespace}/horizontalpodautoscalers/{name}", name=crate::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?, namespace=crate::client::path_param("namespace", self.inner.param_namespace.as_ref().expect("missing parameter namespace?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::CONTENT_TYPE.as_str(), "application/json")
+        .json(&self.inner.body)
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("dryRun", self.inner.param_dry_run.as_ref().map(std::string::ToString::to_string)),
+            ("gracePeriodSeconds", self.inner.param_grace_period_seconds.as_ref().map(std::string::ToString::to_string)),
+            ("orphanDependents", self.inner.param_orphan_dependents.as_ref().map(std::string::ToString::to_string)),
+            ("propagationPolicy", self.inner.param_propagation_policy.as_ref().map(std::string::ToString::to_string)),
+            ("pretty", self.inner.param_pretty.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Content-Type")
+            || name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<DeleteOptionsDeleteBuilder35<crate::generics::NameExists, crate::generics::NamespaceExists>> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: DeleteOptionsDeleteBuilder35<crate::generics::NameExists, crate::generics::NamespaceExists>) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl DeleteOptionsDeleteBuilder35<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = DeleteOptionsDeleteBuilder35 {
+            inner: DeleteOptionsOpBuilder7Container {
+            body: crate::cli::read_from_input(matches)?,
+            param_dry_run: matches.and_then(|m| {
+                    m.value_of("dry-run").map(|_| {
+                        value_t!(m, "dry-run", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_grace_period_seconds: matches.and_then(|m| {
+                    m.value_of("grace-period-seconds").map(|_| {
+                        value_t!(m, "grace-period-seconds", i64).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_orphan_dependents: matches.and_then(|m| {
+                    m.value_of("orphan-dependents").map(|_| {
+                        value_t!(m, "orphan-dependents", bool).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_propagation_policy: matches.and_then(|m| {
+                    m.value_of("propagation-policy").map(|_| {
+                        value_t!(m, "propagation-policy", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_name: matches.and_then(|m| {
+                    m.value_of("name").map(|_| {
+                        value_t!(m, "name", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_namespace: matches.and_then(|m| {
+                    m.value_of("namespace").map(|_| {
+                        value_t!(m, "namespace", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_pretty: matches.and_then(|m| {
+                    m.value_of("pretty").map(|_| {
+                        value_t!(m, "pretty", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            },
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder created by [`DeleteOptions::delete_autoscaling_v2beta2_namespaced_horizontal_pod_autoscaler`](./struct.DeleteOptions.html#method.delete_autoscaling_v2beta2_namespaced_horizontal_pod_autoscaler) method for a `DELETE` operation associated with `DeleteOptions`.
+pub type DeleteOptionsDeleteBuilder36<Name, Namespace> = DeleteOptionsOpBuilder7<DeleteOptionsDeleteOp36, Name, Namespace>;
+
+/// Marks the operation of [`DeleteOptionsDeleteBuilder36`] in the shared builder.
+#[derive(Debug, Clone)]
+pub enum DeleteOptionsDeleteOp36 {}
+
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for DeleteOptionsDeleteBuilder36<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    type Output = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::Status;
+
+    const METHOD: http::Method = http::Method::DELETE;
+
+    const OPERATION_ID: Option<&'static str> = Some("deleteAutoscalingV2beta2NamespacedHorizontalPodAutoscaler");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok(format!("/apis/autoscaling/v2beta2/namespaces/{namespace}/horizontalpodautoscalers/{name}", name=crate::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?, namespace=crate::client::path_param("namespace", self.inner.param_namespace.as_ref().expect("missing parameter namespace?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::CONTENT_TYPE.as_str(), "application/json")
+        .json(&self.inner.body)
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("dryRun", self.inner.param_dry_run.as_ref().map(std::string::ToString::to_string)),
+            ("gracePeriodSeconds", self.inner.param_grace_period_seconds.as_ref().map(std::string::ToString::to_string)),
+            ("orphanDependents", self.inner.param_orphan_dependents.as_ref().map(std::string::ToString::to_string)),
+            ("propagationPolicy", self.inner.param_propagation_policy.as_ref().map(std::string::ToString::to_string)),
+            ("pretty", self.inner.param_pretty.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Content-Type")
+            || name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<DeleteOptionsDeleteBuilder36<crate::generics::NameExists, crate::generics::NamespaceExists>> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: DeleteOptionsDeleteBuilder36<crate::generics::NameExists, crate::generics::NamespaceExists>) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl DeleteOptionsDeleteBuilder36<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = DeleteOptionsDeleteBuilder36 {
+            inner: DeleteOptionsOpBuilder7Container {
+            body: crate::cli::read_from_input(matches)?,
+            param_dry_run: matches.and_then(|m| {
+                    m.value_of("dry-run").map(|_| {
+                        value_t!(m, "dry-run", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_grace_period_seconds: matches.and_then(|m| {
+                    m.value_of("grace-period-seconds").map(|_| {
+                        value_t!(m, "grace-period-seconds", i64).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_orphan_dependents: matches.and_then(|m| {
+                    m.value_of("orphan-dependents").map(|_| {
+                        value_t!(m, "orphan-dependents", bool).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_propagation_policy: matches.and_then(|m| {
+                    m.value_of("propagation-policy").map(|_| {
+                        value_t!(m, "propagation-policy", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_name: matches.and_then(|m| {
+                    m.value_of("name").map(|_| {
+                        value_t!(m, "name", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_namespace: matches.and_then(|m| {
+                    m.value_of("namespace").map(|_| {
+                        value_t!(m, "namespace", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_pretty: matches.and_then(|m| {
+                    m.value_of("pretty").map(|_| {
+                        value_t!(m, "pretty", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            },
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder created by [`DeleteOptions::delete_batch_v1_namespaced_job`](./struct.DeleteOptions.html#method.delete_batch_v1_namespaced_job) method for a `DELETE` operation associated with `DeleteOptions`.
+#[repr(transparent)]
+#[derive(Debug, Clone)]
+pub struct DeleteOptionsDeleteBuilder37<Name, Namespace> {
+    inner: DeleteOptionsDeleteBuilder37Container,
+    _param_name: core::marker::PhantomData<Name>,
+    _param_namespace: core::marker::PhantomData<Namespace>,
+}
+
+#[derive(Debug, Default, Clone)]
+struct DeleteOptionsDeleteBuilder37Container {
+    body: self::DeleteOptions,
+    param_dry_run: Option<String>,
+    param_grace_period_seconds: Option<i64>,
+    param_orphan_dependents: Option<bool>,
+    param_propagation_policy: Option<String>,
+    param_name: Option<String>,
+    param_namespace: Option<String>,
+    param_pretty: Option<String>,
+}
+
+impl<Name, Namespace> DeleteOptionsDeleteBuilder37<Name, Namespace> {
+    /// When present, indicates that modifications should not be persisted. An invalid or unrecognized dryRun directive will result in an error response and no further processing of the request. Valid values are: - All: all dry run stages will be processed
+    #[inline]
+    pub fn dry_run(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_dry_run = Some(value.into());
+        self
+    }
+
+    /// Sets [`dry_run`](#method.dry_run) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_dry_run(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_dry_run = value.map(|value| value.into());
+        self
+    }
+
+    /// The duration in seconds before the object should be deleted. Value must be non-negative integer. The value zero indicates delete immediately. If this value is nil, the default grace period for the specified type will be used. Defaults to a per object value if not specified. zero means delete immediately.
+    #[inline]
+    pub fn grace_period_seconds(mut self, value: impl Into<i64>) -> Self {
+        self.inner.param_grace_period_seconds = Some({
+            let val = value.into();
+            self.inner.body.grace_period_seconds = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// Same as [`grace_period_seconds`](#method.grace_period_seconds), but takes a reference.
+    #[inline]
+    pub fn grace_period_seconds_ref(self, value: &i64) -> Self {
+        self.grace_period_seconds(*value)
+    }
+
+    /// Deprecated: please use the PropagationPolicy, this field will be deprecated in 1.7. Should the dependent objects be orphaned. If true/false, the "orphan" finalizer will be added to/removed from the object's finalizers list. Either this field or PropagationPolicy may be set, but not both.
+    #[inline]
+    pub fn orphan_dependents(mut self, value: impl Into<bool>) -> Self {
+        self.inner.param_orphan_dependents = Some({
+            let val = value.into();
+            self.inner.body.orphan_dependents = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// Same as [`orphan_dependents`](#method.orphan_dependents), but takes a reference.
+    #[inline]
+    pub fn orphan_dependents_ref(self, value: &bool) -> Self {
+        self.orphan_dependents(*value)
+    }
+
+    /// Whether and how garbage collection will be performed. Either this field or OrphanDependents may be set, but not both. The default policy is decided by the existing finalizer set in the metadata.finalizers and the resource-specific default policy. Acceptable values are: 'Orphan' - orphan the dependents; 'Background' - allow the garbage collector to delete the dependents in the background; 'Foreground' - a cascading policy that deletes all dependents in the foreground.
+    #[inline]
+    pub fn propagation_policy(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_propagation_policy = Some({
+            let val = value.into();
+            self.inner.body.propagation_policy = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// name of the Job
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> DeleteOptionsDeleteBuilder37<crate::generics::NameExists, Namespace> {
+        self.inner.param_name = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// object name and auth scope, such as for teams and projects
+    #[inline]
+    pub fn namespace(mut self, value: impl Into<String>) -> DeleteOptionsDeleteBuilder37<Name, crate::generics::NamespaceExists> {
+        self.inner.param_namespace = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// If 'true', then the output is pretty printed.
+    #[inline]
+    pub fn pretty(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_pretty = Some(value.into());
+        self
+    }
+
+    /// Sets [`pretty`](#method.pretty) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_pretty(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_pretty = value.map(|value| value.into());
+        self
+    }
+
+    /// APIVersion defines the versioned schema of this representation of an object. Servers should convert recognized schemas to the latest internal value, and may reject unrecognized values. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#resources
+    #[inline]
+    pub fn api_version(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.api_version = Some(value.into());
+        self
+    }
+
+    /// Sets [`api_version`](#method.api_version) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_api_version(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.api_version = value.map(|value| value.into());
+        self
+    }
+
+    /// Kind is a string value representing the REST resource this object represents. Servers may infer this from the endpoint the client submits requests to. Cannot be updated. In CamelCase. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#types-kinds
+    #[inline]
+    pub fn kind(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.kind = Some(value.into());
+        self
+    }
+
+    /// Sets [`kind`](#method.kind) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_kind(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.kind = value.map(|value| value.into());
+        self
+    }
+
+    /// Must be fulfilled before a deletion is carried out. If not possible, a 409 Conflict status will be returned.
+    #[inline]
+    pub fn preconditions(mut self, value: crate::io::k8s::apimachinery::pkg::apis::meta::v1::preconditions::Preconditions) -> Self {
+        self.inner.body.preconditions = Some(value.into());
+        self
+    }
+
+    /// Sets [`preconditions`](#method.preconditions) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_preconditions(mut self, value: Option<crate::io::k8s::apimachinery::pkg::apis::meta::v1::preconditions::Preconditions>) -> Self {
+        self.inner.body.preconditions = value.map(|value| value.into());
+        self
+    }
+}
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for DeleteOptionsDeleteBuilder37<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    type Output = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::Status;
+
+    const METHOD: http::Method = http::Method::DELETE;
+
+    const OPERATION_ID: Option<&'static str> = Some("deleteBatchV1NamespacedJob");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok(format!("/apis/batch/v1/namespaces/{namespace}/jobs/{name}", name=crate::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?, namespace=crate::client::path_param("namespace", self.inner.param_namespace.as_ref().expect("missing parameter namespace?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::CONTENT_TYPE.as_str(), "application/json")
+        .json(&self.inner.body)
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("dryRun", self.inner.param_dry_run.as_ref().map(std::string::ToString::to_string)),
+            ("gracePeriodSeconds", self.inner.param_grace_period_seconds.as_ref().map(std::string::ToString::to_string)),
+            ("orphanDependents", self.inner.param_orphan_dependents.as_ref().map(std::string::ToString::to_string)),
+            ("propagationPolicy", self.inner.param_propagation_policy.as_ref().map(std::string::ToString::to_string)),
+            ("pretty", self.inner.param_pretty.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Content-Type")
+            || name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<DeleteOptionsDeleteBuilder37<crate::generics::NameExists, crate::generics::NamespaceExists>> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: DeleteOptionsDeleteBuilder37<crate::generics::NameExists, crate::generics::NamespaceExists>) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl DeleteOptionsDeleteBuilder37<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = DeleteOptionsDeleteBuilder37 {
+            inner: DeleteOptionsDeleteBuilder37Container {
+            body: crate::cli::read_from_input(matches)?,
+            param_dry_run: matches.and_then(|m| {
+                    m.value_of("dry-run").map(|_| {
+                        value_t!(m, "dry-run", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_grace_period_seconds: matches.and_then(|m| {
+                    m.value_of("grace-period-seconds").map(|_| {
+                        value_t!(m, "grace-period-seconds", i64).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_orphan_dependents: matches.and_then(|m| {
+                    m.value_of("orphan-dependents").map(|_| {
+                        value_t!(m, "orphan-dependents", bool).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_propagation_policy: matches.and_then(|m| {
+                    m.value_of("propagation-policy").map(|_| {
+                        value_t!(m, "propagation-policy", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_name: matches.and_then(|m| {
+                    m.value_of("name").map(|_| {
+                        value_t!(m, "name", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_namespace: matches.and_then(|m| {
+                    m.value_of("namespace").map(|_| {
+                        value_t!(m, "namespace", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_pretty: matches.and_then(|m| {
+                    m.value_of("pretty").map(|_| {
+                        value_t!(m, "pretty", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            },
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder shared by operations (with the same fields and parameters) associated with `DeleteOptions`.
+#[repr(transparent)]
+#[derive(Debug, Clone)]
+pub struct DeleteOptionsOpBuilder8<Op, Name, Namespace> {
+    inner: DeleteOptionsOpBuilder8Container,
+    _op: core::marker::PhantomData<Op>,
+    _param_name: core::marker::PhantomData<Name>,
+    _param_namespace: core::marker::PhantomData<Namespace>,
+}
+
+#[derive(Debug, Default, Clone)]
+struct DeleteOptionsOpBuilder8Container {
+    body: self::DeleteOptions,
+    param_dry_run: Option<String>,
+    param_grace_period_seconds: Option<i64>,
+    param_orphan_dependents: Option<bool>,
+    param_propagation_policy: Option<String>,
+    param_name: Option<String>,
+    param_namespace: Option<String>,
+    param_pretty: Option<String>,
+}
+
+/// Builder created by [`DeleteOptions::delete_batch_v1beta1_namespaced_cron_job`](./struct.DeleteOptions.html#method.delete_batch_v1beta1_namespaced_cron_job) method for a `DELETE` operation associated with `DeleteOptions`.
+pub type DeleteOptionsDeleteBuilder38<Name, Namespace> = DeleteOptionsOpBuilder8<DeleteOptionsDeleteOp38, Name, Namespace>;
+
+/// Marks the operation of [`DeleteOptionsDeleteBuilder38`] in the shared builder.
+#[derive(Debug, Clone)]
+pub enum DeleteOptionsDeleteOp38 {}
+
+impl<Op, Name, Namespace> DeleteOptionsOpBuilder8<Op, Name, Namespace> {
+    /// When present, indicates that modifications should not be persisted. An invalid or unrecognized dryRun directive will result in an error response and no further processing of the request. Valid values are: - All: all dry run stages will be processed
+    #[inline]
+    pub fn dry_run(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_dry_run = Some(value.into());
+        self
+    }
+
+    /// Sets [`dry_run`](#method.dry_run) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_dry_run(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_dry_run = value.map(|value| value.into());
+        self
+    }
+
+    /// The duration in seconds before the object should be deleted. Value must be non-negative integer. The value zero indicates delete immediately. If this value is nil, the default grace period for the specified type will be used. Defaults to a per object value if not specified. zero means delete immediately.
+    #[inline]
+    pub fn grace_period_seconds(mut self, value: impl Into<i64>) -> Self {
+        self.inner.param_grace_period_seconds = Some({
+            let val = value.into();
+            self.inner.body.grace_period_seconds = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// Same as [`grace_period_seconds`](#method.grace_period_seconds), but takes a reference.
+    #[inline]
+    pub fn grace_period_seconds_ref(self, value: &i64) -> Self {
+        self.grace_period_seconds(*value)
+    }
+
+    /// Deprecated: please use the PropagationPolicy, this field will be deprecated in 1.7. Should the dependent objects be orphaned. If true/false, the "orphan" finalizer will be added to/removed from the object's finalizers list. Either this field or PropagationPolicy may be set, but not both.
+    #[inline]
+    pub fn orphan_dependents(mut self, value: impl Into<bool>) -> Self {
+        self.inner.param_orphan_dependents = Some({
+            let val = value.into();
+            self.inner.body.orphan_dependents = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// Same as [`orphan_dependents`](#method.orphan_dependents), but takes a reference.
+    #[inline]
+    pub fn orphan_dependents_ref(self, value: &bool) -> Self {
+        self.orphan_dependents(*value)
+    }
+
+    /// Whether and how garbage collection will be performed. Either this field or OrphanDependents may be set, but not both. The default policy is decided by the existing finalizer set in the metadata.finalizers and the resource-specific default policy. Acceptable values are: 'Orphan' - orphan the dependents; 'Background' - allow the garbage collector to delete the dependents in the background; 'Foreground' - a cascading policy that deletes all dependents in the foreground.
+    #[inline]
+    pub fn propagation_policy(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_propagation_policy = Some({
+            let val = value.into();
+            self.inner.body.propagation_policy = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// name of the CronJob
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> DeleteOptionsOpBuilder8<Op, crate::generics::NameExists, Namespace> {
+        self.inner.param_name = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// object name and auth scope, such as for teams and projects
+    #[inline]
+    pub fn namespace(mut self, value: impl Into<String>) -> DeleteOptionsOpBuilder8<Op, Name, crate::generics::NamespaceExists> {
+        self.inner.param_namespace = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// If 'true', then the output is pretty printed.
+    #[inline]
+    pub fn pretty(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_pretty = Some(value.into());
+        self
+    }
+
+    /// Sets [`pretty`](#method.pretty) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_pretty(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_pretty = value.map(|value| value.into());
+        self
+    }
+
+    /// APIVersion defines the versioned schema of this representation of an object. Servers should convert recognized schemas to the latest internal value, and may reject unrecognized values. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#resources
+    #[inline]
+    pub fn api_version(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.api_version = Some(value.into());
+        self
+    }
+
+    /// Sets [`api_version`](#method.api_version) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_api_version(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.api_version = value.map(|value| value.into());
+        self
+    }
+
+    /// Kind is a string value representing the REST resource this object represents. Servers may infer this from the endpoint the client submits requests to. Cannot be updated. In CamelCase. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#types-kinds
+    #[inline]
+    pub fn kind(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.kind = Some(value.into());
+        self
+    }
+
+    /// Sets [`kind`](#method.kind) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_kind(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.kind = value.map(|value| value.into());
+        self
+    }
+
+    /// Must be fulfilled before a deletion is carried out. If not possible, a 409 Conflict status will be returned.
+    #[inline]
+    pub fn preconditions(mut self, value: crate::io::k8s::apimachinery::pkg::apis::meta::v1::preconditions::Preconditions) -> Self {
+        self.inner.body.preconditions = Some(value.into());
+        self
+    }
+
+    /// Sets [`preconditions`](#method.preconditions) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_preconditions(mut self, value: Option<crate::io::k8s::apimachinery::pkg::apis::meta::v1::preconditions::Preconditions>) -> Self {
+        self.inner.body.preconditions = value.map(|value| value.into());
+        self
+    }
+}
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for DeleteOptionsDeleteBuilder38<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    type Output = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::Status;
+
+    const METHOD: http::Method = http::Method::DELETE;
+
+    const OPERATION_ID: Option<&'static str> = Some("deleteBatchV1beta1NamespacedCronJob");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok(format!("/apis/batch/v1beta1/namespaces/{namespace}/cronjobs/{name}", name=crate::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?, namespace=crate::client::path_param("namespace", self.inner.param_namespace.as_ref().expect("missing parameter namespace?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::CONTENT_TYPE.as_str(), "application/json")
+        .json(&self.inner.body)
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("dryRun", self.inner.param_dry_run.as_ref().map(std::string::ToString::to_string)),
+            ("gracePeriodSeconds", self.inner.param_grace_period_seconds.as_ref().map(std::string::ToString::to_string)),
+            ("orphanDependents", self.inner.param_orphan_dependents.as_ref().map(std::string::ToString::to_string)),
+            ("propagationPolicy", self.inner.param_propagation_policy.as_ref().map(std::string::ToString::to_string)),
+            ("pretty", self.inner.param_pretty.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Content-Type")
+            || name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<DeleteOptionsDeleteBuilder38<crate::generics::NameExists, crate::generics::NamespaceExists>> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: DeleteOptionsDeleteBuilder38<crate::generics::NameExists, crate::generics::NamespaceExists>) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl DeleteOptionsDeleteBuilder38<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = DeleteOptionsDeleteBuilder38 {
+            inner: DeleteOptionsOpBuilder8Container {
+            body: crate::cli::read_from_input(matches)?,
+            param_dry_run: matches.and_then(|m| {
+                    m.value_of("dry-run").map(|_| {
+                        value_t!(m, "dry-run", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_grace_period_seconds: matches.and_then(|m| {
+                    m.value_of("grace-period-seconds").map(|_| {
+                        value_t!(m, "grace-period-seconds", i64).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_orphan_dependents: matches.and_then(|m| {
+                    m.value_of("orphan-dependents").map(|_| {
+                        value_t!(m, "orphan-dependents", bool).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_propagation_policy: matches.and_then(|m| {
+                    m.value_of("propagation-policy").map(|_| {
+                        value_t!(m, "propagation-policy", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_name: matches.and_then(|m| {
+                    m.value_of("name").map(|_| {
+                        value_t!(m, "name", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_namespace: matches.and_then(|m| {
+                    m.value_of("namespace").map(|_| {
+                        value_t!(m, "namespace", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_pretty: matches.and_then(|m| {
+                    m.value_of("pretty").map(|_| {
+                        value_t!(m, "pretty", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            },
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder created by [`DeleteOptions::delete_batch_v2alpha1_namespaced_cron_job`](./struct.DeleteOptions.html#method.delete_batch_v2alpha1_namespaced_cron_job) method for a `DELETE` operation associated with `DeleteOptions`.
+pub type DeleteOptionsDeleteBuilder39<Name, Namespace> = DeleteOptionsOpBuilder8<DeleteOptionsDeleteOp39, Name, Namespace>;
+
+/// Marks the operation of [`DeleteOptionsDeleteBuilder39`] in the shared builder.
+#[derive(Debug, Clone)]
+pub enum DeleteOptionsDeleteOp39 {}
+
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for DeleteOptionsDeleteBuilder39<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    type Output = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::Status;
+
+    const METHOD: http::Method = http::Method::DELETE;
+
+    const OPERATION_ID: Option<&'static str> = Some("deleteBatchV2alpha1NamespacedCronJob");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok(format!("/apis/batch/v2alpha1/namespaces/{namespace}/cronjobs/{name}", name=crate::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?, namespace=crate::client::path_param("namespace", self.inner.param_namespace.as_ref().expect("missing parameter namespace?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::CONTENT_TYPE.as_str(), "application/json")
+        .json(&self.inner.body)
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("dryRun", self.inner.param_dry_run.as_ref().map(std::string::ToString::to_string)),
+            ("gracePeriodSeconds", self.inner.param_grace_period_seconds.as_ref().map(std::string::ToString::to_string)),
+            ("orphanDependents", self.inner.param_orphan_dependents.as_ref().map(std::string::ToString::to_string)),
+            ("propagationPolicy", self.inner.param_propagation_policy.as_ref().map(std::string::ToString::to_string)),
+            ("pretty", self.inner.param_pretty.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Content-Type")
+            || name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<DeleteOptionsDeleteBuilder39<crate::generics::NameExists, crate::generics::NamespaceExists>> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: DeleteOptionsDeleteBuilder39<crate::generics::NameExists, crate::generics::NamespaceExists>) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl DeleteOptionsDeleteBuilder39<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = DeleteOptionsDeleteBuilder39 {
+            inner: DeleteOptionsOpBuilder8Container {
+            body: crate::cli::read_from_input(matches)?,
+            param_dry_run: matches.and_then(|m| {
+                    m.value_of("dry-run").map(|_| {
+                        value_t!(m, "dry-run", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_grace_period_seconds: matches.and_then(|m| {
+                    m.value_of("grace-period-seconds").map(|_| {
+                        value_t!(m, "grace-period-seconds", i64).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_orphan_dependents: matches.and_then(|m| {
+                    m.value_of("orphan-dependents").map(|_| {
+                        value_t!(m, "orphan-dependents", bool).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_propagation_policy: matches.and_then(|m| {
+                    m.value_of("propagation-policy").map(|_| {
+                        value_t!(m, "propagation-policy", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_name: matches.and_then(|m| {
+                    m.value_of("name").map(|_| {
+                        value_t!(m, "name", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_namespace: matches.and_then(|m| {
+                    m.value_of("namespace").map(|_| {
+                        value_t!(m, "namespace", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_pretty: matches.and_then(|m| {
+                    m.value_of("pretty").map(|_| {
+                        value_t!(m, "pretty", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            },
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder created by [`DeleteOptions::delete_certificates_v1beta1_certificate_signing_request`](./struct.DeleteOptions.html#method.delete_certificates_v1beta1_certificate_signing_request) method for a `DELETE` operation associated with `DeleteOptions`.
+#[repr(transparent)]
+#[derive(Debug, Clone)]
+pub struct DeleteOptionsDeleteBuilder40<Name> {
+    inner: DeleteOptionsDeleteBuilder40Container,
+    _param_name: core::marker::PhantomData<Name>,
+}
+
+#[derive(Debug, Default, Clone)]
+struct DeleteOptionsDeleteBuilder40Container {
+    body: self::DeleteOptions,
+    param_dry_run: Option<String>,
+    param_grace_period_seconds: Option<i64>,
+    param_orphan_dependents: Option<bool>,
+    param_propagation_policy: Option<String>,
+    param_name: Option<String>,
+    param_pretty: Option<String>,
+}
+
+impl<Name> DeleteOptionsDeleteBuilder40<Name> {
+    /// When present, indicates that modifications should not be persisted. An invalid or unrecognized dryRun directive will result in an error response and no further processing of the request. Valid values are: - All: all dry run stages will be processed
+    #[inline]
+    pub fn dry_run(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_dry_run = Some(value.into());
+        self
+    }
+
+    /// Sets [`dry_run`](#method.dry_run) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_dry_run(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_dry_run = value.map(|value| value.into());
+        self
+    }
+
+    /// The duration in seconds before the object should be deleted. Value must be non-negative integer. The value zero indicates delete immediately. If this value is nil, the default grace period for the specified type will be used. Defaults to a per object value if not specified. zero means delete immediately.
+    #[inline]
+    pub fn grace_period_seconds(mut self, value: impl Into<i64>) -> Self {
+        self.inner.param_grace_period_seconds = Some({
+            let val = value.into();
+            self.inner.body.grace_period_seconds = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// Same as [`grace_period_seconds`](#method.grace_period_seconds), but takes a reference.
+    #[inline]
+    pub fn grace_period_seconds_ref(self, value: &i64) -> Self {
+        self.grace_period_seconds(*value)
+    }
+
+    /// Deprecated: please use the PropagationPolicy, this field will be deprecated in 1.7. Should the dependent objects be orphaned. If true/false, the "orphan" finalizer will be added to/removed from the object's finalizers list. Either this field or PropagationPolicy may be set, but not both.
+    #[inline]
+    pub fn orphan_dependents(mut self, value: impl Into<bool>) -> Self {
+        self.inner.param_orphan_dependents = Some({
+            let val = value.into();
+            self.inner.body.orphan_dependents = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// Same as [`orphan_dependents`](#method.orphan_dependents), but takes a reference.
+    #[inline]
+    pub fn orphan_dependents_ref(self, value: &bool) -> Self {
+        self.orphan_dependents(*value)
+    }
+
+    /// Whether and how garbage collection will be performed. Either this field or OrphanDependents may be set, but not both. The default policy is decided by the existing finalizer set in the metadata.finalizers and the resource-specific default policy. Acceptable values are: 'Orphan' - orphan the dependents; 'Background' - allow the garbage collector to delete the dependents in the background; 'Foreground' - a cascading policy that deletes all dependents in the foreground.
+    #[inline]
+    pub fn propagation_policy(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_propagation_policy = Some({
+            let val = value.into();
+            self.inner.body.propagation_policy = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// name of the CertificateSigningRequest
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> DeleteOptionsDeleteBuilder40<crate::generics::NameExists> {
+        self.inner.param_name = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// If 'true', then the output is pretty printed.
+    #[inline]
+    pub fn pretty(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_pretty = Some(value.into());
+        self
+    }
+
+    /// Sets [`pretty`](#method.pretty) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_pretty(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_pretty = value.map(|value| value.into());
+        self
+    }
+
+    /// APIVersion defines the versioned schema of this representation of an object. Servers should convert recognized schemas to the latest internal value, and may reject unrecognized values. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#resources
+    #[inline]
+    pub fn api_version(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.api_version = Some(value.into());
+        self
+    }
+
+    /// Sets [`api_version`](#method.api_version) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_api_version(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.api_version = value.map(|value| value.into());
+        self
+    }
+
+    /// Kind is a string value representing the REST resource this object represents. Servers may infer this from the endpoint the client submits requests to. Cannot be updated. In CamelCase. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#types-kinds
+    #[inline]
+    pub fn kind(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.kind = Some(value.into());
+        self
+    }
+
+    /// Sets [`kind`](#method.kind) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_kind(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.kind = value.map(|value| value.into());
+        self
+    }
+
+    /// Must be fulfilled before a deletion is carried out. If not possible, a 409 Conflict status will be returned.
+    #[inline]
+    pub fn preconditions(mut self, value: crate::io::k8s::apimachinery::pkg::apis::meta::v1::preconditions::Preconditions) -> Self {
+        self.inner.body.preconditions = Some(value.into());
+        self
+    }
+
+    /// Sets [`preconditions`](#method.preconditions) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_preconditions(mut self, value: Option<crate::io::k8s::apimachinery::pkg::apis::meta::v1::preconditions::Preconditions>) -> Self {
+        self.inner.body.preconditions = value.map(|value| value.into());
+        self
+    }
+}
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for DeleteOptionsDeleteBuilder40<crate::generics::NameExists> {
+    type Output = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::Status;
+
+    const METHOD: http::Method = http::Method::DELETE;
+
+    const OPERATION_ID: Option<&'static str> = Some("deleteCertificatesV1beta1CertificateSigningRequest");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok(format!("/apis/certificates.k8s.io/v1beta1/certificatesigningrequests/{name}", name=crate::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::CONTENT_TYPE.as_str(), "application/json")
+        .json(&self.inner.body)
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("dryRun", self.inner.param_dry_run.as_ref().map(std::string::ToString::to_string)),
+            ("gracePeriodSeconds", self.inner.param_grace_period_seconds.as_ref().map(std::string::ToString::to_string)),
+            ("orphanDependents", self.inner.param_orphan_dependents.as_ref().map(std::string::ToString::to_string)),
+            ("propagationPolicy", self.inner.param_propagation_policy.as_ref().map(std::string::ToString::to_string)),
+            ("pretty", self.inner.param_pretty.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Content-Type")
+            || name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<DeleteOptionsDeleteBuilder40<crate::generics::NameExists>> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: DeleteOptionsDeleteBuilder40<crate::generics::NameExists>) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl DeleteOptionsDeleteBuilder40<crate::generics::NameExists> {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = DeleteOptionsDeleteBuilder40 {
+            inner: DeleteOptionsDeleteBuilder40Container {
+            body: crate::cli::read_from_input(matches)?,
+            param_dry_run: matches.and_then(|m| {
+                    m.value_of("dry-run").map(|_| {
+                        value_t!(m, "dry-run", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_grace_period_seconds: matches.and_then(|m| {
+                    m.value_of("grace-period-seconds").map(|_| {
+                        value_t!(m, "grace-period-seconds", i64).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_orphan_dependents: matches.and_then(|m| {
+                    m.value_of("orphan-dependents").map(|_| {
+                        value_t!(m, "orphan-dependents", bool).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_propagation_policy: matches.and_then(|m| {
+                    m.value_of("propagation-policy").map(|_| {
+                        value_t!(m, "propagation-policy", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_name: matches.and_then(|m| {
+                    m.value_of("name").map(|_| {
+                        value_t!(m, "name", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_pretty: matches.and_then(|m| {
+                    m.value_of("pretty").map(|_| {
+                        value_t!(m, "pretty", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            },
+            _param_name: core::marker::PhantomData,
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder shared by operations (with the same fields and parameters) associated with `DeleteOptions`.
+#[repr(transparent)]
+#[derive(Debug, Clone)]
+pub struct DeleteOptionsOpBuilder9<Op, Name, Namespace> {
+    inner: DeleteOptionsOpBuilder9Container,
+    _op: core::marker::PhantomData<Op>,
+    _param_name: core::marker::PhantomData<Name>,
+    _param_namespace: core::marker::PhantomData<Namespace>,
+}
+
+#[derive(Debug, Default, Clone)]
+struct DeleteOptionsOpBuilder9Container {
+    body: self::DeleteOptions,
+    param_dry_run: Option<String>,
+    param_grace_period_seconds: Option<i64>,
+    param_orphan_dependents: Option<bool>,
+    param_propagation_policy: Option<String>,
+    param_name: Option<String>,
+    param_namespace: Option<String>,
+    param_pretty: Option<String>,
+}
+
+/// Builder created by [`DeleteOptions::delete_coordination_v1_namespaced_lease`](./struct.DeleteOptions.html#method.delete_coordination_v1_namespaced_lease) method for a `DELETE` operation associated with `DeleteOptions`.
+pub type DeleteOptionsDeleteBuilder41<Name, Namespace> = DeleteOptionsOpBuilder9<DeleteOptionsDeleteOp41, Name, Namespace>;
+
+/// Marks the operation of [`DeleteOptionsDeleteBuilder41`] in the shared builder.
+#[derive(Debug, Clone)]
+pub enum DeleteOptionsDeleteOp41 {}
+
+impl<Op, Name, Namespace> DeleteOptionsOpBuilder9<Op, Name, Namespace> {
+    /// When present, indicates that modifications should not be persisted. An invalid or unrecognized dryRun directive will result in an error response and no further processing of the request. Valid values are: - All: all dry run stages will be processed
+    #[inline]
+    pub fn dry_run(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_dry_run = Some(value.into());
+        self
+    }
+
+    /// Sets [`dry_run`](#method.dry_run) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_dry_run(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_dry_run = value.map(|value| value.into());
+        self
+    }
+
+    /// The duration in seconds before the object should be deleted. Value must be non-negative integer. The value zero indicates delete immediately. If this value is nil, the default grace period for the specified type will be used. Defaults to a per object value if not specified. zero means delete immediately.
+    #[inline]
+    pub fn grace_period_seconds(mut self, value: impl Into<i64>) -> Self {
+        self.inner.param_grace_period_seconds = Some({
+            let val = value.into();
+            self.inner.body.grace_period_seconds = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// Same as [`grace_period_seconds`](#method.grace_period_seconds), but takes a reference.
+    #[inline]
+    pub fn grace_period_seconds_ref(self, value: &i64) -> Self {
+        self.grace_period_seconds(*value)
+    }
+
+    /// Deprecated: please use the PropagationPolicy, this field will be deprecated in 1.7. Should the dependent objects be orphaned. If true/false, the "orphan" finalizer will be added to/removed from the object's finalizers list. Either this field or PropagationPolicy may be set, but not both.
+    #[inline]
+    pub fn orphan_dependents(mut self, value: impl Into<bool>) -> Self {
+        self.inner.param_orphan_dependents = Some({
+            let val = value.into();
+            self.inner.body.orphan_dependents = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// Same as [`orphan_dependents`](#method.orphan_dependents), but takes a reference.
+    #[inline]
+    pub fn orphan_dependents_ref(self, value: &bool) -> Self {
+        self.orphan_dependents(*value)
+    }
+
+    /// Whether and how garbage collection will be performed. Either this field or OrphanDependents may be set, but not both. The default policy is decided by the existing finalizer set in the metadata.finalizers and the resource-specific default policy. Acceptable values are: 'Orphan' - orphan the dependents; 'Background' - allow the garbage collector to delete the dependents in the background; 'Foreground' - a cascading policy that deletes all dependents in the foreground.
+    #[inline]
+    pub fn propagation_policy(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_propagation_policy = Some({
+            let val = value.into();
+            self.inner.body.propagation_policy = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// name of the Lease
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> DeleteOptionsOpBuilder9<Op, crate::generics::NameExists, Namespace> {
+        self.inner.param_name = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// object name and auth scope, such as for teams and projects
+    #[inline]
+    pub fn namespace(mut self, value: impl Into<String>) -> DeleteOptionsOpBuilder9<Op, Name, crate::generics::NamespaceExists> {
+        self.inner.param_namespace = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// If 'true', then the output is pretty printed.
+    #[inline]
+    pub fn pretty(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_pretty = Some(value.into());
+        self
+    }
+
+    /// Sets [`pretty`](#method.pretty) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_pretty(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_pretty = value.map(|value| value.into());
+        self
+    }
+
+    /// APIVersion defines the versioned schema of this representation of an object. Servers should convert recognized schemas to the latest internal value, and may reject unrecognized values. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#resources
+    #[inline]
+    pub fn api_version(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.api_version = Some(value.into());
+        self
+    }
+
+    /// Sets [`api_version`](#method.api_version) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_api_version(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.api_version = value.map(|value| value.into());
+        self
+    }
+
+    /// Kind is a string value representing the REST resource this object represents. Servers may infer this from the endpoint the client submits requests to. Cannot be updated. In CamelCase. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#types-kinds
+    #[inline]
+    pub fn kind(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.kind = Some(value.into());
+        self
+    }
+
+    /// Sets [`kind`](#method.kind) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_kind(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.kind = value.map(|value| value.into());
+        self
+    }
+
+    /// Must be fulfilled before a deletion is carried out. If not possible, a 409 Conflict status will be returned.
+    #[inline]
+    pub fn preconditions(mut self, value: crate::io::k8s::apimachinery::pkg::apis::meta::v1::preconditions::Preconditions) -> Self {
+        self.inner.body.preconditions = Some(value.into());
+        self
+    }
+
+    /// Sets [`preconditions`](#method.preconditions) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_preconditions(mut self, value: Option<crate::io::k8s::apimachinery::pkg::apis::meta::v1::preconditions::Preconditions>) -> Self {
+        self.inner.body.preconditions = value.map(|value| value.into());
+        self
+    }
+}
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for DeleteOptionsDeleteBuilder41<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    type Output = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::Status;
+
+    const METHOD: http::Method = http::Method::DELETE;
+
+    const OPERATION_ID: Option<&'static str> = Some("deleteCoordinationV1NamespacedLease");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok(format!("/apis/coordination.k8s.io/v1/namespaces/{namespace}/leases/{name}", name=crate::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?, namespace=crate::client::path_param("namespace", self.inner.param_namespace.as_ref().expect("missing parameter namespace?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::CONTENT_TYPE.as_str(), "application/json")
+        .json(&self.inner.body)
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("dryRun", self.inner.param_dry_run.as_ref().map(std::string::ToString::to_string)),
+            ("gracePeriodSeconds", self.inner.param_grace_period_seconds.as_ref().map(std::string::ToString::to_string)),
+            ("orphanDependents", self.inner.param_orphan_dependents.as_ref().map(std::string::ToString::to_string)),
+            ("propagationPolicy", self.inner.param_propagation_policy.as_ref().map(std::string::ToString::to_string)),
+            ("pretty", self.inner.param_pretty.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Content-Type")
+            || name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<DeleteOptionsDeleteBuilder41<crate::generics::NameExists, crate::generics::NamespaceExists>> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: DeleteOptionsDeleteBuilder41<crate::generics::NameExists, crate::generics::NamespaceExists>) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl DeleteOptionsDeleteBuilder41<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = DeleteOptionsDeleteBuilder41 {
+            inner: DeleteOptionsOpBuilder9Container {
+            body: crate::cli::read_from_input(matches)?,
+            param_dry_run: matches.and_then(|m| {
+                    m.value_of("dry-run").map(|_| {
+                        value_t!(m, "dry-run", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_grace_period_seconds: matches.and_then(|m| {
+                    m.value_of("grace-period-seconds").map(|_| {
+                        value_t!(m, "grace-period-seconds", i64).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_orphan_dependents: matches.and_then(|m| {
+                    m.value_of("orphan-dependents").map(|_| {
+                        value_t!(m, "orphan-dependents", bool).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_propagation_policy: matches.and_then(|m| {
+                    m.value_of("propagation-policy").map(|_| {
+                        value_t!(m, "propagation-policy", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_name: matches.and_then(|m| {
+                    m.value_of("name").map(|_| {
+                        value_t!(m, "name", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_namespace: matches.and_then(|m| {
+                    m.value_of("namespace").map(|_| {
+                        value_t!(m, "namespace", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_pretty: matches.and_then(|m| {
+                    m.value_of("pretty").map(|_| {
+                        value_t!(m, "pretty", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            },
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder created by [`DeleteOptions::delete_coordination_v1beta1_namespaced_lease`](./struct.DeleteOptions.html#method.delete_coordination_v1beta1_namespaced_lease) method for a `DELETE` operation associated with `DeleteOptions`.
+pub type DeleteOptionsDeleteBuilder42<Name, Namespace> = DeleteOptionsOpBuilder9<DeleteOptionsDeleteOp42, Name, Namespace>;
+
+/// Marks the operation of [`DeleteOptionsDeleteBuilder42`] in the shared builder.
+#[derive(Debug, Clone)]
+pub enum DeleteOptionsDeleteOp42 {}
+
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for DeleteOptionsDeleteBuilder42<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    type Output = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::Status;
+
+    const METHOD: http::Method = http::Method::DELETE;
+
+    const OPERATION_ID: Option<&'static str> = Some("deleteCoordinationV1beta1NamespacedLease");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok(format!("/apis/coordination.k8s.io/v1beta1/namespaces/{namespace}/leases/{name}", name=crate::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?, namespace=crate::client::path_param("namespace", self.inner.param_namespace.as_ref().expect("missing parameter namespace?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::CONTENT_TYPE.as_str(), "application/json")
+        .json(&self.inner.body)
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("dryRun", self.inner.param_dry_run.as_ref().map(std::string::ToString::to_string)),
+            ("gracePeriodSeconds", self.inner.param_grace_period_seconds.as_ref().map(std::string::ToString::to_string)),
+            ("orphanDependents", self.inner.param_orphan_dependents.as_ref().map(std::string::ToString::to_string)),
+            ("propagationPolicy", self.inner.param_propagation_policy.as_ref().map(std::string::ToString::to_string)),
+            ("pretty", self.inner.param_pretty.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Content-Type")
+            || name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<DeleteOptionsDeleteBuilder42<crate::generics::NameExists, crate::generics::NamespaceExists>> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: DeleteOptionsDeleteBuilder42<crate::generics::NameExists, crate::generics::NamespaceExists>) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl DeleteOptionsDeleteBuilder42<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = DeleteOptionsDeleteBuilder42 {
+            inner: DeleteOptionsOpBuilder9Container {
+            body: crate::cli::read_from_input(matches)?,
+            param_dry_run: matches.and_then(|m| {
+                    m.value_of("dry-run").map(|_| {
+                        value_t!(m, "dry-run", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_grace_period_seconds: matches.and_then(|m| {
+                    m.value_of("grace-period-seconds").map(|_| {
+                        value_t!(m, "grace-period-seconds", i64).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_orphan_dependents: matches.and_then(|m| {
+                    m.value_of("orphan-dependents").map(|_| {
+                        value_t!(m, "orphan-dependents", bool).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_propagation_policy: matches.and_then(|m| {
+                    m.value_of("propagation-policy").map(|_| {
+                        value_t!(m, "propagation-policy", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_name: matches.and_then(|m| {
+                    m.value_of("name").map(|_| {
+                        value_t!(m, "name", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_namespace: matches.and_then(|m| {
+                    m.value_of("namespace").map(|_| {
+                        value_t!(m, "namespace", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_pretty: matches.and_then(|m| {
+                    m.value_of("pretty").map(|_| {
+                        value_t!(m, "pretty", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            },
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder created by [`DeleteOptions::delete_events_v1beta1_namespaced_event`](./struct.DeleteOptions.html#method.delete_events_v1beta1_namespaced_event) method for a `DELETE` operation associated with `DeleteOptions`.
+pub type DeleteOptionsDeleteBuilder43<Name, Namespace> = DeleteOptionsOpBuilder<DeleteOptionsDeleteOp43, Name, Namespace>;
+
+/// Marks the operation of [`DeleteOptionsDeleteBuilder43`] in the shared builder.
+#[derive(Debug, Clone)]
+pub enum DeleteOptionsDeleteOp43 {}
+
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for DeleteOptionsDeleteBuilder43<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    type Output = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::Status;
+
+    const METHOD: http::Method = http::Method::DELETE;
+
+    const OPERATION_ID: Option<&'static str> = Some("deleteEventsV1beta1NamespacedEvent");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok(format!("/apis/events.k8s.io/v1beta1/namespaces/{namespace}/events/{name}", name=crate::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?, namespace=crate::client::path_param("namespace", self.inner.param_namespace.as_ref().expect("missing parameter namespace?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::CONTENT_TYPE.as_str(), "application/json")
+        .json(&self.inner.body)
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("dryRun", self.inner.param_dry_run.as_ref().map(std::string::ToString::to_string)),
+            ("gracePeriodSeconds", self.inner.param_grace_period_seconds.as_ref().map(std::string::ToString::to_string)),
+            ("orphanDependents", self.inner.param_orphan_dependents.as_ref().map(std::string::ToString::to_string)),
+            ("propagationPolicy", self.inner.param_propagation_policy.as_ref().map(std::string::ToString::to_string)),
+            ("pretty", self.inner.param_pretty.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Content-Type")
+            || name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<DeleteOptionsDeleteBuilder43<crate::generics::NameExists, crate::generics::NamespaceExists>> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: DeleteOptionsDeleteBuilder43<crate::generics::NameExists, crate::generics::NamespaceExists>) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl DeleteOptionsDeleteBuilder43<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = DeleteOptionsDeleteBuilder43 {
+            inner: DeleteOptionsOpBuilderContainer {
+            body: crate::cli::read_from_input(matches)?,
+            param_dry_run: matches.and_then(|m| {
+                    m.value_of("dry-run").map(|_| {
+                        value_t!(m, "dry-run", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_grace_period_seconds: matches.and_then(|m| {
+                    m.value_of("grace-period-seconds").map(|_| {
+                        value_t!(m, "grace-period-seconds", i64).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_orphan_dependents: matches.and_then(|m| {
+                    m.value_of("orphan-dependents").map(|_| {
+                        value_t!(m, "orphan-dependents", bool).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_propagation_policy: matches.and_then(|m| {
+                    m.value_of("propagation-policy").map(|_| {
+                        value_t!(m, "propagation-policy", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_name: matches.and_then(|m| {
+                    m.value_of("name").map(|_| {
+                        value_t!(m, "name", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_namespace: matches.and_then(|m| {
+                    m.value_of("namespace").map(|_| {
+                        value_t!(m, "namespace", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_pretty: matches.and_then(|m| {
+                    m.value_of("pretty").map(|_| {
+                        value_t!(m, "pretty", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            },
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder created by [`DeleteOptions::delete_extensions_v1beta1_namespaced_daemon_set`](./struct.DeleteOptions.html#method.delete_extensions_v1beta1_namespaced_daemon_set) method for a `DELETE` operation associated with `DeleteOptions`.
+pub type DeleteOptionsDeleteBuilder44<Name, Namespace> = DeleteOptionsOpBuilder3<DeleteOptionsDeleteOp44, Name, Namespace>;
+
+/// Marks the operation of [`DeleteOptionsDeleteBuilder44`] in the shared builder.
+#[derive(Debug, Clone)]
+pub enum DeleteOptionsDeleteOp44 {}
+
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for DeleteOptionsDeleteBuilder44<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    type Output = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::Status;
+
+    const METHOD: http::Method = http::Method::DELETE;
+
+    const OPERATION_ID: Option<&'static str> = Some("deleteExtensionsV1beta1NamespacedDaemonSet");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok(format!("/apis/extensions/v1beta1/namespaces/{namespace}/daemonsets/{name}", name=crate::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?, namespace=crate::client::path_param("namespace", self.inner.param_namespace.as_ref().expect("missing parameter namespace?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::CONTENT_TYPE.as_str(), "application/json")
+        .json(&self.inner.body)
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("dryRun", self.inner.param_dry_run.as_ref().map(std::string::ToString::to_string)),
+            ("gracePeriodSeconds", self.inner.param_grace_period_seconds.as_ref().map(std::string::ToString::to_string)),
+            ("orphanDependents", self.inner.param_orphan_dependents.as_ref().map(std::string::ToString::to_string)),
+            ("propagationPolicy", self.inner.param_propagation_policy.as_ref().map(std::string::ToString::to_string)),
+            ("pretty", self.inner.param_pretty.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Content-Type")
+            || name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<DeleteOptionsDeleteBuilder44<crate::generics::NameExists, crate::generics::NamespaceExists>> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: DeleteOptionsDeleteBuilder44<crate::generics::NameExists, crate::generics::NamespaceExists>) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl DeleteOptionsDeleteBuilder44<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = DeleteOptionsDeleteBuilder44 {
+            inner: DeleteOptionsOpBuilder3Container {
+            body: crate::cli::read_from_input(matches)?,
+            param_dry_run: matches.and_then(|m| {
+                    m.value_of("dry-run").map(|_| {
+                        value_t!(m, "dry-run", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_grace_period_seconds: matches.and_then(|m| {
+                    m.value_of("grace-period-seconds").map(|_| {
+                        value_t!(m, "grace-period-seconds", i64).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_orphan_dependents: matches.and_then(|m| {
+                    m.value_of("orphan-dependents").map(|_| {
+                        value_t!(m, "orphan-dependents", bool).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_propagation_policy: matches.and_then(|m| {
+                    m.value_of("propagation-policy").map(|_| {
+                        value_t!(m, "propagation-policy", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_name: matches.and_then(|m| {
+                    m.value_of("name").map(|_| {
+                        value_t!(m, "name", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_namespace: matches.and_then(|m| {
+                    m.value_of("namespace").map(|_| {
+                        value_t!(m, "namespace", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_pretty: matches.and_then(|m| {
+                    m.value_of("pretty").map(|_| {
+                        value_t!(m, "pretty", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            },
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder created by [`DeleteOptions::delete_extensions_v1beta1_namespaced_deployment`](./struct.DeleteOptions.html#method.delete_extensions_v1beta1_namespaced_deployment) method for a `DELETE` operation associated with `DeleteOptions`.
+pub type DeleteOptionsDeleteBuilder45<Name, Namespace> = DeleteOptionsOpBuilder4<DeleteOptionsDeleteOp45, Name, Namespace>;
+
+/// Marks the operation of [`DeleteOptionsDeleteBuilder45`] in the shared builder.
+#[derive(Debug, Clone)]
+pub enum DeleteOptionsDeleteOp45 {}
+
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for DeleteOptionsDeleteBuilder45<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    type Output = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::Status;
+
+    const METHOD: http::Method = http::Method::DELETE;
+
+    const OPERATION_ID: Option<&'static str> = Some("deleteExtensionsV1beta1NamespacedDeployment");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok(format!("/apis/extensions/v1beta1/namespaces/{namespace}/deployments/{name}", name=crate::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?, namespace=crate::client::path_param("namespace", self.inner.param_namespace.as_ref().expect("missing parameter namespace?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::CONTENT_TYPE.as_str(), "application/json")
+        .json(&self.inner.body)
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("dryRun", self.inner.param_dry_run.as_ref().map(std::string::ToString::to_string)),
+            ("gracePeriodSeconds", self.inner.param_grace_period_seconds.as_ref().map(std::string::ToString::to_string)),
+            ("orphanDependents", self.inner.param_orphan_dependents.as_ref().map(std::string::ToString::to_string)),
+            ("propagationPolicy", self.inner.param_propagation_policy.as_ref().map(std::string::ToString::to_string)),
+            ("pretty", self.inner.param_pretty.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Content-Type")
+            || name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<DeleteOptionsDeleteBuilder45<crate::generics::NameExists, crate::generics::NamespaceExists>> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: DeleteOptionsDeleteBuilder45<crate::generics::NameExists, crate::generics::NamespaceExists>) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl DeleteOptionsDeleteBuilder45<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = DeleteOptionsDeleteBuilder45 {
+            inner: DeleteOptionsOpBuilder4Container {
+            body: crate::cli::read_from_input(matches)?,
+            param_dry_run: matches.and_then(|m| {
+                    m.value_of("dry-run").map(|_| {
+                        value_t!(m, "dry-run", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_grace_period_seconds: matches.and_then(|m| {
+                    m.value_of("grace-period-seconds").map(|_| {
+                        value_t!(m, "grace-period-seconds", i64).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_orphan_dependents: matches.and_then(|m| {
+                    m.value_of("orphan-dependents").map(|_| {
+                        value_t!(m, "orphan-dependents", bool).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_propagation_policy: matches.and_then(|m| {
+                    m.value_of("propagation-policy").map(|_| {
+                        value_t!(m, "propagation-policy", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_name: matches.and_then(|m| {
+                    m.value_of("name").map(|_| {
+                        value_t!(m, "name", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_namespace: matches.and_then(|m| {
+                    m.value_of("namespace").map(|_| {
+                        value_t!(m, "namespace", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_pretty: matches.and_then(|m| {
+                    m.value_of("pretty").map(|_| {
+                        value_t!(m, "pretty", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            },
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder shared by operations (with the same fields and parameters) associated with `DeleteOptions`.
+#[repr(transparent)]
+#[derive(Debug, Clone)]
+pub struct DeleteOptionsOpBuilder10<Op, Name, Namespace> {
+    inner: DeleteOptionsOpBuilder10Container,
+    _op: core::marker::PhantomData<Op>,
+    _param_name: core::marker::PhantomData<Name>,
+    _param_namespace: core::marker::PhantomData<Namespace>,
+}
+
+#[derive(Debug, Default, Clone)]
+struct DeleteOptionsOpBuilder10Container {
+    body: self::DeleteOptions,
+    param_dry_run: Option<String>,
+    param_grace_period_seconds: Option<i64>,
+    param_orphan_dependents: Option<bool>,
+    param_propagation_policy: Option<String>,
+    param_name: Option<String>,
+    param_namespace: Option<String>,
+    param_pretty: Option<String>,
+}
+
+/// Builder created by [`DeleteOptions::delete_extensions_v1beta1_namespaced_ingress`](./struct.DeleteOptions.html#method.delete_extensions_v1beta1_namespaced_ingress) method for a `DELETE` operation associated with `DeleteOptions`.
+pub type DeleteOptionsDeleteBuilder46<Name, Namespace> = DeleteOptionsOpBuilder10<DeleteOptionsDeleteOp46, Name, Namespace>;
+
+/// Marks the operation of [`DeleteOptionsDeleteBuilder46`] in the shared builder.
+#[derive(Debug, Clone)]
+pub enum DeleteOptionsDeleteOp46 {}
+
+impl<Op, Name, Namespace> DeleteOptionsOpBuilder10<Op, Name, Namespace> {
+    /// When present, indicates that modifications should not be persisted. An invalid or unrecognized dryRun directive will result in an error response and no further processing of the request. Valid values are: - All: all dry run stages will be processed
+    #[inline]
+    pub fn dry_run(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_dry_run = Some(value.into());
+        self
+    }
+
+    /// Sets [`dry_run`](#method.dry_run) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_dry_run(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_dry_run = value.map(|value| value.into());
+        self
+    }
+
+    /// The duration in seconds before the object should be deleted. Value must be non-negative integer. The value zero indicates delete immediately. If this value is nil, the default grace period for the specified type will be used. Defaults to a per object value if not specified. zero means delete immediately.
+    #[inline]
+    pub fn grace_period_seconds(mut self, value: impl Into<i64>) -> Self {
+        self.inner.param_grace_period_seconds = Some({
+            let val = value.into();
+            self.inner.body.grace_period_seconds = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// Same as [`grace_period_seconds`](#method.grace_period_seconds), but takes a reference.
+    #[inline]
+    pub fn grace_period_seconds_ref(self, value: &i64) -> Self {
+        self.grace_period_seconds(*value)
+    }
+
+    /// Deprecated: please use the PropagationPolicy, this field will be deprecated in 1.7. Should the dependent objects be orphaned. If true/false, the "orphan" finalizer will be added to/removed from the object's finalizers list. Either this field or PropagationPolicy may be set, but not both.
+    #[inline]
+    pub fn orphan_dependents(mut self, value: impl Into<bool>) -> Self {
+        self.inner.param_orphan_dependents = Some({
+            let val = value.into();
+            self.inner.body.orphan_dependents = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// Same as [`orphan_dependents`](#method.orphan_dependents), but takes a reference.
+    #[inline]
+    pub fn orphan_dependents_ref(self, value: &bool) -> Self {
+        self.orphan_dependents(*value)
+    }
+
+    /// Whether and how garbage collection will be performed. Either this field or OrphanDependents may be set, but not both. The default policy is decided by the existing finalizer set in the metadata.finalizers and the resource-specific default policy. Acceptable values are: 'Orphan' - orphan the dependents; 'Background' - allow the garbage collector to delete the dependents in the background; 'Foreground' - a cascading policy that deletes all dependents in the foreground.
+    #[inline]
+    pub fn propagation_policy(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_propagation_policy = Some({
+            let val = value.into();
+            self.inner.body.propagation_policy = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// name of the Ingress
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> DeleteOptionsOpBuilder10<Op, crate::generics::NameExists, Namespace> {
+        self.inner.param_name = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// object name and auth scope, such as for teams and projects
+    #[inline]
+    pub fn namespace(mut self, value: impl Into<String>) -> DeleteOptionsOpBuilder10<Op, Name, crate::generics::NamespaceExists> {
+        self.inner.param_namespace = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// If 'true', then the output is pretty printed.
+    #[inline]
+    pub fn pretty(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_pretty = Some(value.into());
+        self
+    }
+
+    /// Sets [`pretty`](#method.pretty) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_pretty(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_pretty = value.map(|value| value.into());
+        self
+    }
+
+    /// APIVersion defines the versioned schema of this representation of an object. Servers should convert recognized schemas to the latest internal value, and may reject unrecognized values. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#resources
+    #[inline]
+    pub fn api_version(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.api_version = Some(value.into());
+        self
+    }
+
+    /// Sets [`api_version`](#method.api_version) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_api_version(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.api_version = value.map(|value| value.into());
+        self
+    }
+
+    /// Kind is a string value representing the REST resource this object represents. Servers may infer this from the endpoint the client submits requests to. Cannot be updated. In CamelCase. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#types-kinds
+    #[inline]
+    pub fn kind(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.kind = Some(value.into());
+        self
+    }
+
+    /// Sets [`kind`](#method.kind) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_kind(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.kind = value.map(|value| value.into());
+        self
+    }
+
+    /// Must be fulfilled before a deletion is carried out. If not possible, a 409 Conflict status will be returned.
+    #[inline]
+    pub fn preconditions(mut self, value: crate::io::k8s::apimachinery::pkg::apis::meta::v1::preconditions::Preconditions) -> Self {
+        self.inner.body.preconditions = Some(value.into());
+        self
+    }
+
+    /// Sets [`preconditions`](#method.preconditions) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_preconditions(mut self, value: Option<crate::io::k8s::apimachinery::pkg::apis::meta::v1::preconditions::Preconditions>) -> Self {
+        self.inner.body.preconditions = value.map(|value| value.into());
+        self
+    }
+}
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for DeleteOptionsDeleteBuilder46<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    type Output = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::Status;
+
+    const METHOD: http::Method = http::Method::DELETE;
+
+    const OPERATION_ID: Option<&'static str> = Some("deleteExtensionsV1beta1NamespacedIngress");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok(format!("/apis/extensions/v1beta1/namespaces/{namespace}/ingresses/{name}", name=crate::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?, namespace=crate::client::path_param("namespace", self.inner.param_namespace.as_ref().expect("missing parameter namespace?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::CONTENT_TYPE.as_str(), "application/json")
+        .json(&self.inner.body)
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("dryRun", self.inner.param_dry_run.as_ref().map(std::string::ToString::to_string)),
+            ("gracePeriodSeconds", self.inner.param_grace_period_seconds.as_ref().map(std::string::ToString::to_string)),
+            ("orphanDependents", self.inner.param_orphan_dependents.as_ref().map(std::string::ToString::to_string)),
+            ("propagationPolicy", self.inner.param_propagation_policy.as_ref().map(std::string::ToString::to_string)),
+            ("pretty", self.inner.param_pretty.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Content-Type")
+            || name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<DeleteOptionsDeleteBuilder46<crate::generics::NameExists, crate::generics::NamespaceExists>> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: DeleteOptionsDeleteBuilder46<crate::generics::NameExists, crate::generics::NamespaceExists>) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl DeleteOptionsDeleteBuilder46<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = DeleteOptionsDeleteBuilder46 {
+            inner: DeleteOptionsOpBuilder10Container {
+            body: crate::cli::read_from_input(matches)?,
+            param_dry_run: matches.and_then(|m| {
+                    m.value_of("dry-run").map(|_| {
+                        value_t!(m, "dry-run", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_grace_period_seconds: matches.and_then(|m| {
+                    m.value_of("grace-period-seconds").map(|_| {
+                        value_t!(m, "grace-period-seconds", i64).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_orphan_dependents: matches.and_then(|m| {
+                    m.value_of("orphan-dependents").map(|_| {
+                        value_t!(m, "orphan-dependents", bool).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_propagation_policy: matches.and_then(|m| {
+                    m.value_of("propagation-policy").map(|_| {
+                        value_t!(m, "propagation-policy", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_name: matches.and_then(|m| {
+                    m.value_of("name").map(|_| {
+                        value_t!(m, "name", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_namespace: matches.and_then(|m| {
+                    m.value_of("namespace").map(|_| {
+                        value_t!(m, "namespace", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_pretty: matches.and_then(|m| {
+                    m.value_of("pretty").map(|_| {
+                        value_t!(m, "pretty", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            },
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder shared by operations (with the same fields and parameters) associated with `DeleteOptions`.
+#[repr(transparent)]
+#[derive(Debug, Clone)]
+pub struct DeleteOptionsOpBuilder11<Op, Name, Namespace> {
+    inner: DeleteOptionsOpBuilder11Container,
+    _op: core::marker::PhantomData<Op>,
+    _param_name: core::marker::PhantomData<Name>,
+    _param_namespace: core::marker::PhantomData<Namespace>,
+}
+
+#[derive(Debug, Default, Clone)]
+struct DeleteOptionsOpBuilder11Container {
+    body: self::DeleteOptions,
+    param_dry_run: Option<String>,
+    param_grace_period_seconds: Option<i64>,
+    param_orphan_dependents: Option<bool>,
+    param_propagation_policy: Option<String>,
+    param_name: Option<String>,
+    param_namespace: Option<String>,
+    param_pretty: Option<String>,
+}
+
+/// Builder created by [`DeleteOptions::delete_extensions_v1beta1_namespaced_network_policy`](./struct.DeleteOptions.html#method.delete_extensions_v1beta1_namespaced_network_policy) method for a `DELETE` operation associated with `DeleteOptions`.
+pub type DeleteOptionsDeleteBuilder47<Name, Namespace> = DeleteOptionsOpBuilder11<DeleteOptionsDeleteOp47, Name, Namespace>;
+
+/// Marks the operation of [`DeleteOptionsDeleteBuilder47`] in the shared builder.
+#[derive(Debug, Clone)]
+pub enum DeleteOptionsDeleteOp47 {}
+
+impl<Op, Name, Namespace> DeleteOptionsOpBuilder11<Op, Name, Namespace> {
+    /// When present, indicates that modifications should not be persisted. An invalid or unrecognized dryRun directive will result in an error response and no further processing of the request. Valid values are: - All: all dry run stages will be processed
+    #[inline]
+    pub fn dry_run(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_dry_run = Some(value.into());
+        self
+    }
+
+    /// Sets [`dry_run`](#method.dry_run) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_dry_run(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_dry_run = value.map(|value| value.into());
+        self
+    }
+
+    /// The duration in seconds before the object should be deleted. Value must be non-negative integer. The value zero indicates delete immediately. If this value is nil, the default grace period for the specified type will be used. Defaults to a per object value if not specified. zero means delete immediately.
+    #[inline]
+    pub fn grace_period_seconds(mut self, value: impl Into<i64>) -> Self {
+        self.inner.param_grace_period_seconds = Some({
+            let val = value.into();
+            self.inner.body.grace_period_seconds = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// Same as [`grace_period_seconds`](#method.grace_period_seconds), but takes a reference.
+    #[inline]
+    pub fn grace_period_seconds_ref(self, value: &i64) -> Self {
+        self.grace_period_seconds(*value)
+    }
+
+    /// Deprecated: please use the PropagationPolicy, this field will be deprecated in 1.7. Should the dependent objects be orphaned. If true/false, the "orphan" finalizer will be added to/removed from the object's finalizers list. Either this field or PropagationPolicy may be set, but not both.
+    #[inline]
+    pub fn orphan_dependents(mut self, value: impl Into<bool>) -> Self {
+        self.inner.param_orphan_dependents = Some({
+            let val = value.into();
+            self.inner.body.orphan_dependents = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// Same as [`orphan_dependents`](#method.orphan_dependents), but takes a reference.
+    #[inline]
+    pub fn orphan_dependents_ref(self, value: &bool) -> Self {
+        self.orphan_dependents(*value)
+    }
+
+    /// Whether and how garbage collection will be performed. Either this field or OrphanDependents may be set, but not both. The default policy is decided by the existing finalizer set in the metadata.finalizers and the resource-specific default policy. Acceptable values are: 'Orphan' - orphan the dependents; 'Background' - allow the garbage collector to delete the dependents in the background; 'Foreground' - a cascading policy that deletes all dependents in the foreground.
+    #[inline]
+    pub fn propagation_policy(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_propagation_policy = Some({
+            let val = value.into();
+            self.inner.body.propagation_policy = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// name of the NetworkPolicy
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> DeleteOptionsOpBuilder11<Op, crate::generics::NameExists, Namespace> {
+        self.inner.param_name = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// object name and auth scope, such as for teams and projects
+    #[inline]
+    pub fn namespace(mut self, value: impl Into<String>) -> DeleteOptionsOpBuilder11<Op, Name, crate::generics::NamespaceExists> {
+        self.inner.param_namespace = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// If 'true', then the output is pretty printed.
+    #[inline]
+    pub fn pretty(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_pretty = Some(value.into());
+        self
+    }
+
+    /// Sets [`pretty`](#method.pretty) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_pretty(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_pretty = value.map(|value| value.into());
+        self
+    }
+
+    /// APIVersion defines the versioned schema of this representation of an object. Servers should convert recognized schemas to the latest internal value, and may reject unrecognized values. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#resources
+    #[inline]
+    pub fn api_version(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.api_version = Some(value.into());
+        self
+    }
+
+    /// Sets [`api_version`](#method.api_version) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_api_version(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.api_version = value.map(|value| value.into());
+        self
+    }
+
+    /// Kind is a string value representing the REST resource this object represents. Servers may infer this from the endpoint the client submits requests to. Cannot be updated. In CamelCase. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#types-kinds
+    #[inline]
+    pub fn kind(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.kind = Some(value.into());
+        self
+    }
+
+    /// Sets [`kind`](#method.kind) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_kind(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.kind = value.map(|value| value.into());
+        self
+    }
+
+    /// Must be fulfilled before a deletion is carried out. If not possible, a 409 Conflict status will be returned.
+    #[inline]
+    pub fn preconditions(mut self, value: crate::io::k8s::apimachinery::pkg::apis::meta::v1::preconditions::Preconditions) -> Self {
+        self.inner.body.preconditions = Some(value.into());
+        self
+    }
+
+    /// Sets [`preconditions`](#method.preconditions) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_preconditions(mut self, value: Option<crate::io::k8s::apimachinery::pkg::apis::meta::v1::preconditions::Preconditions>) -> Self {
+        self.inner.body.preconditions = value.map(|value| value.into());
+        self
+    }
+}
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for DeleteOptionsDeleteBuilder47<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    type Output = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::Status;
+
+    const METHOD: http::Method = http::Method::DELETE;
+
+    const OPERATION_ID: Option<&'static str> = Some("deleteExtensionsV1beta1NamespacedNetworkPolicy");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok(format!("/apis/extensions/v1beta1/namespaces/{namespace}/networkpolicies/{name}", name=crate::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?, namespace=crate::client::path_param("namespace", self.inner.param_namespace.as_ref().expect("missing parameter namespace?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::CONTENT_TYPE.as_str(), "application/json")
+        .json(&self.inner.body)
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("dryRun", self.inner.param_dry_run.as_ref().map(std::string::ToString::to_string)),
+            ("gracePeriodSeconds", self.inner.param_grace_period_seconds.as_ref().map(std::string::ToString::to_string)),
+            ("orphanDependents", self.inner.param_orphan_dependents.as_ref().map(std::string::ToString::to_string)),
+            ("propagationPolicy", self.inner.param_propagation_policy.as_ref().map(std::string::ToString::to_string)),
+            ("pretty", self.inner.param_pretty.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Content-Type")
+            || name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<DeleteOptionsDeleteBuilder47<crate::generics::NameExists, crate::generics::NamespaceExists>> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: DeleteOptionsDeleteBuilder47<crate::generics::NameExists, crate::generics::NamespaceExists>) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl DeleteOptionsDeleteBuilder47<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = DeleteOptionsDeleteBuilder47 {
+            inner: DeleteOptionsOpBuilder11Container {
+            body: crate::cli::read_from_input(matches)?,
+            param_dry_run: matches.and_then(|m| {
+                    m.value_of("dry-run").map(|_| {
+                        value_t!(m, "dry-run", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_grace_period_seconds: matches.and_then(|m| {
+                    m.value_of("grace-period-seconds").map(|_| {
+                        value_t!(m, "grace-period-seconds", i64).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_orphan_dependents: matches.and_then(|m| {
+                    m.value_of("orphan-dependents").map(|_| {
+                        value_t!(m, "orphan-dependents", bool).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_propagation_policy: matches.and_then(|m| {
+                    m.value_of("propagation-policy").map(|_| {
+                        value_t!(m, "propagation-policy", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_name: matches.and_then(|m| {
+                    m.value_of("name").map(|_| {
+                        value_t!(m, "name", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_namespace: matches.and_then(|m| {
+                    m.value_of("namespace").map(|_| {
+                        value_t!(m, "namespace", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_pretty: matches.and_then(|m| {
+                    m.value_of("pretty").map(|_| {
+                        value_t!(m, "pretty", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            },
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder created by [`DeleteOptions::delete_extensions_v1beta1_namespaced_replica_set`](./struct.DeleteOptions.html#method.delete_extensions_v1beta1_namespaced_replica_set) method for a `DELETE` operation associated with `DeleteOptions`.
+pub type DeleteOptionsDeleteBuilder48<Name, Namespace> = DeleteOptionsOpBuilder5<DeleteOptionsDeleteOp48, Name, Namespace>;
+
+/// Marks the operation of [`DeleteOptionsDeleteBuilder48`] in the shared builder.
+#[derive(Debug, Clone)]
+pub enum DeleteOptionsDeleteOp48 {}
+
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for DeleteOptionsDeleteBuilder48<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    type Output = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::Status;
+
+    const METHOD: http::Method = http::Method::DELETE;
+
+    const OPERATION_ID: Option<&'static str> = Some("deleteExtensionsV1beta1NamespacedReplicaSet");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok(format!("/apis/extensions/v1beta1/namespaces/{namespace}/replicasets/{name}", name=crate::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?, namespace=crate::client::path_param("namespace", self.inner.param_namespace.as_ref().expect("missing parameter namespace?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::CONTENT_TYPE.as_str(), "application/json")
+        .json(&self.inner.body)
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("dryRun", self.inner.param_dry_run.as_ref().map(std::string::ToString::to_string)),
+            ("gracePeriodSeconds", self.inner.param_grace_period_seconds.as_ref().map(std::string::ToString::to_string)),
+            ("orphanDependents", self.inner.param_orphan_dependents.as_ref().map(std::string::ToString::to_string)),
+            ("propagationPolicy", self.inner.param_propagation_policy.as_ref().map(std::string::ToString::to_string)),
+            ("pretty", self.inner.param_pretty.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Content-Type")
+            || name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<DeleteOptionsDeleteBuilder48<crate::generics::NameExists, crate::generics::NamespaceExists>> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: DeleteOptionsDeleteBuilder48<crate::generics::NameExists, crate::generics::NamespaceExists>) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl DeleteOptionsDeleteBuilder48<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = DeleteOptionsDeleteBuilder48 {
+            inner: DeleteOptionsOpBuilder5Container {
+            body: crate::cli::read_from_input(matches)?,
+            param_dry_run: matches.and_then(|m| {
+                    m.value_of("dry-run").map(|_| {
+                        value_t!(m, "dry-run", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_grace_period_seconds: matches.and_then(|m| {
+                    m.value_of("grace-period-seconds").map(|_| {
+                        value_t!(m, "grace-period-seconds", i64).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_orphan_dependents: matches.and_then(|m| {
+                    m.value_of("orphan-dependents").map(|_| {
+                        value_t!(m, "orphan-dependents", bool).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_propagation_policy: matches.and_then(|m| {
+                    m.value_of("propagation-policy").map(|_| {
+                        value_t!(m, "propagation-policy", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_name: matches.and_then(|m| {
+                    m.value_of("name").map(|_| {
+                        value_t!(m, "name", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_namespace: matches.and_then(|m| {
+                    m.value_of("namespace").map(|_| {
+                        value_t!(m, "namespace", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_pretty: matches.and_then(|m| {
+                    m.value_of("pretty").map(|_| {
+                        value_t!(m, "pretty", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            },
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder shared by operations (with the same fields and parameters) associated with `DeleteOptions`.
+#[repr(transparent)]
+#[derive(Debug, Clone)]
+pub struct DeleteOptionsOpBuilder12<Op, Name> {
+    inner: DeleteOptionsOpBuilder12Container,
+    _op: core::marker::PhantomData<Op>,
+    _param_name: core::marker::PhantomData<Name>,
+}
+
+#[derive(Debug, Default, Clone)]
+struct DeleteOptionsOpBuilder12Container {
+    body: self::DeleteOptions,
+    param_dry_run: Option<String>,
+    param_grace_period_seconds: Option<i64>,
+    param_orphan_dependents: Option<bool>,
+    param_propagation_policy: Option<String>,
+    param_name: Option<String>,
+    param_pretty: Option<String>,
+}
+
+/// Builder created by [`DeleteOptions::delete_extensions_v1beta1_pod_security_policy`](./struct.DeleteOptions.html#method.delete_extensions_v1beta1_pod_security_policy) method for a `DELETE` operation associated with `DeleteOptions`.
+pub type DeleteOptionsDeleteBuilder49<Name> = DeleteOptionsOpBuilder12<DeleteOptionsDeleteOp49, Name>;
+
+/// Marks the operation of [`DeleteOptionsDeleteBuilder49`] in the shared builder.
+#[derive(Debug, Clone)]
+pub enum DeleteOptionsDeleteOp49 {}
+
+impl<Op, Name> DeleteOptionsOpBuilder12<Op, Name> {
+    /// When present, indicates that modifications should not be persisted. An invalid or unrecognized dryRun directive will result in an error response and no further processing of the request. Valid values are: - All: all dry run stages will be processed
+    #[inline]
+    pub fn dry_run(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_dry_run = Some(value.into());
+        self
+    }
+
+    /// Sets [`dry_run`](#method.dry_run) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_dry_run(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_dry_run = value.map(|value| value.into());
+        self
+    }
+
+    /// The duration in seconds before the object should be deleted. Value must be non-negative integer. The value zero indicates delete immediately. If this value is nil, the default grace period for the specified type will be used. Defaults to a per object value if not specified. zero means delete immediately.
+    #[inline]
+    pub fn grace_period_seconds(mut self, value: impl Into<i64>) -> Self {
+        self.inner.param_grace_period_seconds = Some({
+            let val = value.into();
+            self.inner.body.grace_period_seconds = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// Same as [`grace_period_seconds`](#method.grace_period_seconds), but takes a reference.
+    #[inline]
+    pub fn grace_period_seconds_ref(self, value: &i64) -> Self {
+        self.grace_period_seconds(*value)
+    }
+
+    /// Deprecated: please use the PropagationPolicy, this field will be deprecated in 1.7. Should the dependent objects be orphaned. If true/false, the "orphan" finalizer will be added to/removed from the object's finalizers list. Either this field or PropagationPolicy may be set, but not both.
+    #[inline]
+    pub fn orphan_dependents(mut self, value: impl Into<bool>) -> Self {
+        self.inner.param_orphan_dependents = Some({
+            let val = value.into();
+            self.inner.body.orphan_dependents = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// Same as [`orphan_dependents`](#method.orphan_dependents), but takes a reference.
+    #[inline]
+    pub fn orphan_dependents_ref(self, value: &bool) -> Self {
+        self.orphan_dependents(*value)
+    }
+
+    /// Whether and how garbage collection will be performed. Either this field or OrphanDependents may be set, but not both. The default policy is decided by the existing finalizer set in the metadata.finalizers and the resource-specific default policy. Acceptable values are: 'Orphan' - orphan the dependents; 'Background' - allow the garbage collector to delete the dependents in the background; 'Foreground' - a cascading policy that deletes all dependents in the foreground.
+    #[inline]
+    pub fn propagation_policy(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_propagation_policy = Some({
+            let val = value.into();
+            self.inner.body.propagation_policy = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// name of the PodSecurityPolicy
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> DeleteOptionsOpBuilder12<Op, crate::generics::NameExists> {
+        self.inner.param_name = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// If 'true', then the output is pretty printed.
+    #[inline]
+    pub fn pretty(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_pretty = Some(value.into());
+        self
+    }
+
+    /// Sets [`pretty`](#method.pretty) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_pretty(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_pretty = value.map(|value| value.into());
+        self
+    }
+
+    /// APIVersion defines the versioned schema of this representation of an object. Servers should convert recognized schemas to the latest internal value, and may reject unrecognized values. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#resources
+    #[inline]
+    pub fn api_version(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.api_version = Some(value.into());
+        self
+    }
+
+    /// Sets [`api_version`](#method.api_version) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_api_version(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.api_version = value.map(|value| value.into());
+        self
+    }
+
+    /// Kind is a string value representing the REST resource this object represents. Servers may infer this from the endpoint the client submits requests to. Cannot be updated. In CamelCase. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#types-kinds
+    #[inline]
+    pub fn kind(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.kind = Some(value.into());
+        self
+    }
+
+    /// Sets [`kind`](#method.kind) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_kind(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.kind = value.map(|value| value.into());
+        self
+    }
+
+    /// Must be fulfilled before a deletion is carried out. If not possible, a 409 Conflict status will be returned.
+    #[inline]
+    pub fn preconditions(mut self, value: crate::io::k8s::apimachinery::pkg::apis::meta::v1::preconditions::Preconditions) -> Self {
+        self.inner.body.preconditions = Some(value.into());
+        self
+    }
+
+    /// Sets [`preconditions`](#method.preconditions) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_preconditions(mut self, value: Option<crate::io::k8s::apimachinery::pkg::apis::meta::v1::preconditions::Preconditions>) -> Self {
+        self.inner.body.preconditions = value.map(|value| value.into());
+        self
+    }
+}
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for DeleteOptionsDeleteBuilder49<crate::generics::NameExists> {
+    type Output = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::Status;
+
+    const METHOD: http::Method = http::Method::DELETE;
+
+    const OPERATION_ID: Option<&'static str> = Some("deleteExtensionsV1beta1PodSecurityPolicy");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok(format!("/apis/extensions/v1beta1/podsecuritypolicies/{name}", name=crate::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::CONTENT_TYPE.as_str(), "application/json")
+        .json(&self.inner.body)
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("dryRun", self.inner.param_dry_run.as_ref().map(std::string::ToString::to_string)),
+            ("gracePeriodSeconds", self.inner.param_grace_period_seconds.as_ref().map(std::string::ToString::to_string)),
+            ("orphanDependents", self.inner.param_orphan_dependents.as_ref().map(std::string::ToString::to_string)),
+            ("propagationPolicy", self.inner.param_propagation_policy.as_ref().map(std::string::ToString::to_string)),
+            ("pretty", self.inner.param_pretty.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Content-Type")
+            || name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<DeleteOptionsDeleteBuilder49<crate::generics::NameExists>> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: DeleteOptionsDeleteBuilder49<crate::generics::NameExists>) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl DeleteOptionsDeleteBuilder49<crate::generics::NameExists> {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = DeleteOptionsDeleteBuilder49 {
+            inner: DeleteOptionsOpBuilder12Container {
+            body: crate::cli::read_from_input(matches)?,
+            param_dry_run: matches.and_then(|m| {
+                    m.value_of("dry-run").map(|_| {
+                        value_t!(m, "dry-run", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_grace_period_seconds: matches.and_then(|m| {
+                    m.value_of("grace-period-seconds").map(|_| {
+                        value_t!(m, "grace-period-seconds", i64).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_orphan_dependents: matches.and_then(|m| {
+                    m.value_of("orphan-dependents").map(|_| {
+                        value_t!(m, "orphan-dependents", bool).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_propagation_policy: matches.and_then(|m| {
+                    m.value_of("propagation-policy").map(|_| {
+                        value_t!(m, "propagation-policy", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_name: matches.and_then(|m| {
+                    m.value_of("name").map(|_| {
+                        value_t!(m, "name", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_pretty: matches.and_then(|m| {
+                    m.value_of("pretty").map(|_| {
+                        value_t!(m, "pretty", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            },
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder created by [`DeleteOptions::delete_networking_v1_namespaced_network_policy`](./struct.DeleteOptions.html#method.delete_networking_v1_namespaced_network_policy) method for a `DELETE` operation associated with `DeleteOptions`.
+pub type DeleteOptionsDeleteBuilder50<Name, Namespace> = DeleteOptionsOpBuilder11<DeleteOptionsDeleteOp50, Name, Namespace>;
+
+/// Marks the operation of [`DeleteOptionsDeleteBuilder50`] in the shared builder.
+#[derive(Debug, Clone)]
+pub enum DeleteOptionsDeleteOp50 {}
+
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for DeleteOptionsDeleteBuilder50<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    type Output = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::Status;
+
+    const METHOD: http::Method = http::Method::DELETE;
+
+    const OPERATION_ID: Option<&'static str> = Some("deleteNetworkingV1NamespacedNetworkPolicy");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok(format!("/apis/networking.k8s.io/v1/namespaces/{namespace}/networkpolicies/{name}", name=crate::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?, namespace=crate::client::path_param("namespace", self.inner.param_namespace.as_ref().expect("missing parameter namespace?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::CONTENT_TYPE.as_str(), "application/json")
+        .json(&self.inner.body)
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("dryRun", self.inner.param_dry_run.as_ref().map(std::string::ToString::to_string)),
+            ("gracePeriodSeconds", self.inner.param_grace_period_seconds.as_ref().map(std::string::ToString::to_string)),
+            ("orphanDependents", self.inner.param_orphan_dependents.as_ref().map(std::string::ToString::to_string)),
+            ("propagationPolicy", self.inner.param_propagation_policy.as_ref().map(std::string::ToString::to_string)),
+            ("pretty", self.inner.param_pretty.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Content-Type")
+            || name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<DeleteOptionsDeleteBuilder50<crate::generics::NameExists, crate::generics::NamespaceExists>> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: DeleteOptionsDeleteBuilder50<crate::generics::NameExists, crate::generics::NamespaceExists>) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl DeleteOptionsDeleteBuilder50<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = DeleteOptionsDeleteBuilder50 {
+            inner: DeleteOptionsOpBuilder11Container {
+            body: crate::cli::read_from_input(matches)?,
+            param_dry_run: matches.and_then(|m| {
+                    m.value_of("dry-run").map(|_| {
+                        value_t!(m, "dry-run", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_grace_period_seconds: matches.and_then(|m| {
+                    m.value_of("grace-period-seconds").map(|_| {
+                        value_t!(m, "grace-period-seconds", i64).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_orphan_dependents: matches.and_then(|m| {
+                    m.value_of("orphan-dependents").map(|_| {
+                        value_t!(m, "orphan-dependents", bool).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_propagation_policy: matches.and_then(|m| {
+                    m.value_of("propagation-policy").map(|_| {
+                        value_t!(m, "propagation-policy", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_name: matches.and_then(|m| {
+                    m.value_of("name").map(|_| {
+                        value_t!(m, "name", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_namespace: matches.and_then(|m| {
+                    m.value_of("namespace").map(|_| {
+                        value_t!(m, "namespace", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_pretty: matches.and_then(|m| {
+                    m.value_of("pretty").map(|_| {
+                        value_t!(m, "pretty", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            },
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder created by [`DeleteOptions::delete_networking_v1beta1_namespaced_ingress`](./struct.DeleteOptions.html#method.delete_networking_v1beta1_namespaced_ingress) method for a `DELETE` operation associated with `DeleteOptions`.
+pub type DeleteOptionsDeleteBuilder51<Name, Namespace> = DeleteOptionsOpBuilder10<DeleteOptionsDeleteOp51, Name, Namespace>;
+
+/// Marks the operation of [`DeleteOptionsDeleteBuilder51`] in the shared builder.
+#[derive(Debug, Clone)]
+pub enum DeleteOptionsDeleteOp51 {}
+
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for DeleteOptionsDeleteBuilder51<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    type Output = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::Status;
+
+    const METHOD: http::Method = http::Method::DELETE;
+
+    const OPERATION_ID: Option<&'static str> = Some("deleteNetworkingV1beta1NamespacedIngress");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok(format!("/apis/networking.k8s.io/v1beta1/namespaces/{namespace}/ingresses/{name}", name=crate::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?, namespace=crate::client::path_param("namespace", self.inner.param_namespace.as_ref().expect("missing parameter namespace?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::CONTENT_TYPE.as_str(), "application/json")
+        .json(&self.inner.body)
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("dryRun", self.inner.param_dry_run.as_ref().map(std::string::ToString::to_string)),
+            ("gracePeriodSeconds", self.inner.param_grace_period_seconds.as_ref().map(std::string::ToString::to_string)),
+            ("orphanDependents", self.inner.param_orphan_dependents.as_ref().map(std::string::ToString::to_string)),
+            ("propagationPolicy", self.inner.param_propagation_policy.as_ref().map(std::string::ToString::to_string)),
+            ("pretty", self.inner.param_pretty.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Content-Type")
+            || name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<DeleteOptionsDeleteBuilder51<crate::generics::NameExists, crate::generics::NamespaceExists>> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: DeleteOptionsDeleteBuilder51<crate::generics::NameExists, crate::generics::NamespaceExists>) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl DeleteOptionsDeleteBuilder51<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = DeleteOptionsDeleteBuilder51 {
+            inner: DeleteOptionsOpBuilder10Container {
+            body: crate::cli::read_from_input(matches)?,
+            param_dry_run: matches.and_then(|m| {
+                    m.value_of("dry-run").map(|_| {
+                        value_t!(m, "dry-run", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_grace_period_seconds: matches.and_then(|m| {
+                    m.value_of("grace-period-seconds").map(|_| {
+                        value_t!(m, "grace-period-seconds", i64).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_orphan_dependents: matches.and_then(|m| {
+                    m.value_of("orphan-dependents").map(|_| {
+                        value_t!(m, "orphan-dependents", bool).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_propagation_policy: matches.and_then(|m| {
+                    m.value_of("propagation-policy").map(|_| {
+                        value_t!(m, "propagation-policy", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_name: matches.and_then(|m| {
+                    m.value_of("name").map(|_| {
+                        value_t!(m, "name", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_namespace: matches.and_then(|m| {
+                    m.value_of("namespace").map(|_| {
+                        value_t!(m, "namespace", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_pretty: matches.and_then(|m| {
+                    m.value_of("pretty").map(|_| {
+                        value_t!(m, "pretty", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            },
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder shared by operations (with the same fields and parameters) associated with `DeleteOptions`.
+#[repr(transparent)]
+#[derive(Debug, Clone)]
+pub struct DeleteOptionsOpBuilder13<Op, Name> {
+    inner: DeleteOptionsOpBuilder13Container,
+    _op: core::marker::PhantomData<Op>,
+    _param_name: core::marker::PhantomData<Name>,
+}
+
+#[derive(Debug, Default, Clone)]
+struct DeleteOptionsOpBuilder13Container {
+    body: self::DeleteOptions,
+    param_dry_run: Option<String>,
+    param_grace_period_seconds: Option<i64>,
+    param_orphan_dependents: Option<bool>,
+    param_propagation_policy: Option<String>,
+    param_name: Option<String>,
+    param_pretty: Option<String>,
+}
+
+/// Builder created by [`DeleteOptions::delete_node_v1alpha1_runtime_class`](./struct.DeleteOptions.html#method.delete_node_v1alpha1_runtime_class) method for a `DELETE` operation associated with `DeleteOptions`.
+pub type DeleteOptionsDeleteBuilder52<Name> = DeleteOptionsOpBuilder13<DeleteOptionsDeleteOp52, Name>;
+
+/// Marks the operation of [`DeleteOptionsDeleteBuilder52`] in the shared builder.
+#[derive(Debug, Clone)]
+pub enum DeleteOptionsDeleteOp52 {}
+
+impl<Op, Name> DeleteOptionsOpBuilder13<Op, Name> {
+    /// When present, indicates that modifications should not be persisted. An invalid or unrecognized dryRun directive will result in an error response and no further processing of the request. Valid values are: - All: all dry run stages will be processed
+    #[inline]
+    pub fn dry_run(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_dry_run = Some(value.into());
+        self
+    }
+
+    /// Sets [`dry_run`](#method.dry_run) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_dry_run(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_dry_run = value.map(|value| value.into());
+        self
+    }
+
+    /// The duration in seconds before the object should be deleted. Value must be non-negative integer. The value zero indicates delete immediately. If this value is nil, the default grace period for the specified type will be used. Defaults to a per object value if not specified. zero means delete immediately.
+    #[inline]
+    pub fn grace_period_seconds(mut self, value: impl Into<i64>) -> Self {
+        self.inner.param_grace_period_seconds = Some({
+            let val = value.into();
+            self.inner.body.grace_period_seconds = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// Same as [`grace_period_seconds`](#method.grace_period_seconds), but takes a reference.
+    #[inline]
+    pub fn grace_period_seconds_ref(self, value: &i64) -> Self {
+        self.grace_period_seconds(*value)
+    }
+
+    /// Deprecated: please use the PropagationPolicy, this field will be deprecated in 1.7. Should the dependent objects be orphaned. If true/false, the "orphan" finalizer will be added to/removed from the object's finalizers list. Either this field or PropagationPolicy may be set, but not both.
+    #[inline]
+    pub fn orphan_dependents(mut self, value: impl Into<bool>) -> Self {
+        self.inner.param_orphan_dependents = Some({
+            let val = value.into();
+            self.inner.body.orphan_dependents = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// Same as [`orphan_dependents`](#method.orphan_dependents), but takes a reference.
+    #[inline]
+    pub fn orphan_dependents_ref(self, value: &bool) -> Self {
+        self.orphan_dependents(*value)
+    }
+
+    /// Whether and how garbage collection will be performed. Either this field or OrphanDependents may be set, but not both. The default policy is decided by the existing finalizer set in the metadata.finalizers and the resource-specific default policy. Acceptable values are: 'Orphan' - orphan the dependents; 'Background' - allow the garbage collector to delete the dependents in the background; 'Foreground' - a cascading policy that deletes all dependents in the foreground.
+    #[inline]
+    pub fn propagation_policy(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_propagation_policy = Some({
+            let val = value.into();
+            self.inner.body.propagation_policy = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// name of the RuntimeClass
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> DeleteOptionsOpBuilder13<Op, crate::generics::NameExists> {
+        self.inner.param_name = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// If 'true', then the output is pretty printed.
+    #[inline]
+    pub fn pretty(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_pretty = Some(value.into());
+        self
+    }
+
+    /// Sets [`pretty`](#method.pretty) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_pretty(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_pretty = value.map(|value| value.into());
+        self
+    }
+
+    /// APIVersion defines the versioned schema of this representation of an object. Servers should convert recognized schemas to the latest internal value, and may reject unrecognized values. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#resources
+    #[inline]
+    pub fn api_version(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.api_version = Some(value.into());
+        self
+    }
+
+    /// Sets [`api_version`](#method.api_version) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_api_version(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.api_version = value.map(|value| value.into());
+        self
+    }
+
+    /// Kind is a string value representing the REST resource this object represents. Servers may infer this from the endpoint the client submits requests to. Cannot be updated. In CamelCase. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#types-kinds
+    #[inline]
+    pub fn kind(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.kind = Some(value.into());
+        self
+    }
+
+    /// Sets [`kind`](#method.kind) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_kind(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.kind = value.map(|value| value.into());
+        self
+    }
+
+    /// Must be fulfilled before a deletion is carried out. If not possible, a 409 Conflict status will be returned.
+    #[inline]
+    pub fn preconditions(mut self, value: crate::io::k8s::apimachinery::pkg::apis::meta::v1::preconditions::Preconditions) -> Self {
+        self.inner.body.preconditions = Some(value.into());
+        self
+    }
+
+    /// Sets [`preconditions`](#method.preconditions) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_preconditions(mut self, value: Option<crate::io::k8s::apimachinery::pkg::apis::meta::v1::preconditions::Preconditions>) -> Self {
+        self.inner.body.preconditions = value.map(|value| value.into());
+        self
+    }
+}
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for DeleteOptionsDeleteBuilder52<crate::generics::NameExists> {
+    type Output = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::Status;
+
+    const METHOD: http::Method = http::Method::DELETE;
+
+    const OPERATION_ID: Option<&'static str> = Some("deleteNodeV1alpha1RuntimeClass");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok(format!("/apis/node.k8s.io/v1alpha1/runtimeclasses/{name}", name=crate::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::CONTENT_TYPE.as_str(), "application/json")
+        .json(&self.inner.body)
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("dryRun", self.inner.param_dry_run.as_ref().map(std::string::ToString::to_string)),
+            ("gracePeriodSeconds", self.inner.param_grace_period_seconds.as_ref().map(std::string::ToString::to_string)),
+            ("orphanDependents", self.inner.param_orphan_dependents.as_ref().map(std::string::ToString::to_string)),
+            ("propagationPolicy", self.inner.param_propagation_policy.as_ref().map(std::string::ToString::to_string)),
+            ("pretty", self.inner.param_pretty.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Content-Type")
+            || name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<DeleteOptionsDeleteBuilder52<crate::generics::NameExists>> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: DeleteOptionsDeleteBuilder52<crate::generics::NameExists>) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl DeleteOptionsDeleteBuilder52<crate::generics::NameExists> {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = DeleteOptionsDeleteBuilder52 {
+            inner: DeleteOptionsOpBuilder13Container {
+            body: crate::cli::read_from_input(matches)?,
+            param_dry_run: matches.and_then(|m| {
+                    m.value_of("dry-run").map(|_| {
+                        value_t!(m, "dry-run", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_grace_period_seconds: matches.and_then(|m| {
+                    m.value_of("grace-period-seconds").map(|_| {
+                        value_t!(m, "grace-period-seconds", i64).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_orphan_dependents: matches.and_then(|m| {
+                    m.value_of("orphan-dependents").map(|_| {
+                        value_t!(m, "orphan-dependents", bool).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_propagation_policy: matches.and_then(|m| {
+                    m.value_of("propagation-policy").map(|_| {
+                        value_t!(m, "propagation-policy", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_name: matches.and_then(|m| {
+                    m.value_of("name").map(|_| {
+                        value_t!(m, "name", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_pretty: matches.and_then(|m| {
+                    m.value_of("pretty").map(|_| {
+                        value_t!(m, "pretty", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            },
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder created by [`DeleteOptions::delete_node_v1beta1_runtime_class`](./struct.DeleteOptions.html#method.delete_node_v1beta1_runtime_class) method for a `DELETE` operation associated with `DeleteOptions`.
+pub type DeleteOptionsDeleteBuilder53<Name> = DeleteOptionsOpBuilder13<DeleteOptionsDeleteOp53, Name>;
+
+/// Marks the operation of [`DeleteOptionsDeleteBuilder53`] in the shared builder.
+#[derive(Debug, Clone)]
+pub enum DeleteOptionsDeleteOp53 {}
+
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for DeleteOptionsDeleteBuilder53<crate::generics::NameExists> {
+    type Output = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::Status;
+
+    const METHOD: http::Method = http::Method::DELETE;
+
+    const OPERATION_ID: Option<&'static str> = Some("deleteNodeV1beta1RuntimeClass");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok(format!("/apis/node.k8s.io/v1beta1/runtimeclasses/{name}", name=crate::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::CONTENT_TYPE.as_str(), "application/json")
+        .json(&self.inner.body)
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("dryRun", self.inner.param_dry_run.as_ref().map(std::string::ToString::to_string)),
+            ("gracePeriodSeconds", self.inner.param_grace_period_seconds.as_ref().map(std::string::ToString::to_string)),
+            ("orphanDependents", self.inner.param_orphan_dependents.as_ref().map(std::string::ToString::to_string)),
+            ("propagationPolicy", self.inner.param_propagation_policy.as_ref().map(std::string::ToString::to_string)),
+            ("pretty", self.inner.param_pretty.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Content-Type")
+            || name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<DeleteOptionsDeleteBuilder53<crate::generics::NameExists>> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: DeleteOptionsDeleteBuilder53<crate::generics::NameExists>) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl DeleteOptionsDeleteBuilder53<crate::generics::NameExists> {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = DeleteOptionsDeleteBuilder53 {
+            inner: DeleteOptionsOpBuilder13Container {
+            body: crate::cli::read_from_input(matches)?,
+            param_dry_run: matches.and_then(|m| {
+                    m.value_of("dry-run").map(|_| {
+                        value_t!(m, "dry-run", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_grace_period_seconds: matches.and_then(|m| {
+                    m.value_of("grace-period-seconds").map(|_| {
+                        value_t!(m, "grace-period-seconds", i64).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_orphan_dependents: matches.and_then(|m| {
+                    m.value_of("orphan-dependents").map(|_| {
+                        value_t!(m, "orphan-dependents", bool).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_propagation_policy: matches.and_then(|m| {
+                    m.value_of("propagation-policy").map(|_| {
+                        value_t!(m, "propagation-policy", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_name: matches.and_then(|m| {
+                    m.value_of("name").map(|_| {
+                        value_t!(m, "name", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_pretty: matches.and_then(|m| {
+                    m.value_of("pretty").map(|_| {
+                        value_t!(m, "pretty", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            },
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder created by [`DeleteOptions::delete_policy_v1beta1_namespaced_pod_disruption_budget`](./struct.DeleteOptions.html#method.delete_policy_v1beta1_namespaced_pod_disruption_budget) method for a `DELETE` operation associated with `DeleteOptions`.
+#[repr(transparent)]
+#[derive(Debug, Clone)]
+pub struct DeleteOptionsDeleteBuilder54<Name, Namespace> {
+    inner: DeleteOptionsDeleteBuilder54Container,
+    _param_name: core::marker::PhantomData<Name>,
+    _param_namespace: core::marker::PhantomData<Namespace>,
+}
+
+#[derive(Debug, Default, Clone)]
+struct DeleteOptionsDeleteBuilder54Container {
+    body: self::DeleteOptions,
+    param_dry_run: Option<String>,
+    param_grace_period_seconds: Option<i64>,
+    param_orphan_dependents: Option<bool>,
+    param_propagation_policy: Option<String>,
+    param_name: Option<String>,
+    param_namespace: Option<String>,
+    param_pretty: Option<String>,
+}
+
+impl<Name, Namespace> DeleteOptionsDeleteBuilder54<Name, Namespace> {
+    /// When present, indicates that modifications should not be persisted. An invalid or unrecognized dryRun directive will result in an error response and no further processing of the request. Valid values are: - All: all dry run stages will be processed
+    #[inline]
+    pub fn dry_run(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_dry_run = Some(value.into());
+        self
+    }
+
+    /// Sets [`dry_run`](#method.dry_run) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_dry_run(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_dry_run = value.map(|value| value.into());
+        self
+    }
+
+    /// The duration in seconds before the object should be deleted. Value must be non-negative integer. The value zero indicates delete immediately. If this value is nil, the default grace period for the specified type will be used. Defaults to a per object value if not specified. zero means delete immediately.
+    #[inline]
+    pub fn grace_period_seconds(mut self, value: impl Into<i64>) -> Self {
+        self.inner.param_grace_period_seconds = Some({
+            let val = value.into();
+            self.inner.body.grace_period_seconds = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// Same as [`grace_period_seconds`](#method.grace_period_seconds), but takes a reference.
+    #[inline]
+    pub fn grace_period_seconds_ref(self, value: &i64) -> Self {
+        self.grace_period_seconds(*value)
+    }
+
+    /// Deprecated: please use the PropagationPolicy, this field will be deprecated in 1.7. Should the dependent objects be orphaned. If true/false, the "orphan" finalizer will be added to/removed from the object's finalizers list. Either this field or PropagationPolicy may be set, but not both.
+    #[inline]
+    pub fn orphan_dependents(mut self, value: impl Into<bool>) -> Self {
+        self.inner.param_orphan_dependents = Some({
+            let val = value.into();
+            self.inner.body.orphan_dependents = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// Same as [`orphan_dependents`](#method.orphan_dependents), but takes a reference.
+    #[inline]
+    pub fn orphan_dependents_ref(self, value: &bool) -> Self {
+        self.orphan_dependents(*value)
+    }
+
+    /// Whether and how garbage collection will be performed. Either this field or OrphanDependents may be set, but not both. The default policy is decided by the existing finalizer set in the metadata.finalizers and the resource-specific default policy. Acceptable values are: 'Orphan' - orphan the dependents; 'Background' - allow the garbage collector to delete the dependents in the background; 'Foreground' - a cascading policy that deletes all dependents in the foreground.
+    #[inline]
+    pub fn propagation_policy(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_propagation_policy = Some({
+            let val = value.into();
+            self.inner.body.propagation_policy = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// name of the PodDisruptionBudget
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> DeleteOptionsDeleteBuilder54<crate::generics::NameExists, Namespace> {
+        self.inner.param_name = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// object name and auth scope, such as for teams and projects
+    #[inline]
+    pub fn namespace(mut self, value: impl Into<String>) -> DeleteOptionsDeleteBuilder54<Name, crate::generics::NamespaceExists> {
+        self.inner.param_namespace = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// If 'true', then the output is pretty printed.
+    #[inline]
+    pub fn pretty(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_pretty = Some(value.into());
+        self
+    }
+
+    /// Sets [`pretty`](#method.pretty) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_pretty(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_pretty = value.map(|value| value.into());
+        self
+    }
+
+    /// APIVersion defines the versioned schema of this representation of an object. Servers should convert recognized schemas to the latest internal value, and may reject unrecognized values. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#resources
+    #[inline]
+    pub fn api_version(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.api_version = Some(value.into());
+        self
+    }
+
+    /// Sets [`api_version`](#method.api_version) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_api_version(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.api_version = value.map(|value| value.into());
+        self
+    }
+
+    /// Kind is a string value representing the REST resource this object represents. Servers may infer this from the endpoint the client submits requests to. Cannot be updated. In CamelCase. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#types-kinds
+    #[inline]
+    pub fn kind(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.kind = Some(value.into());
+        self
+    }
+
+    /// Sets [`kind`](#method.kind) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_kind(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.kind = value.map(|value| value.into());
+        self
+    }
+
+    /// Must be fulfilled before a deletion is carried out. If not possible, a 409 Conflict status will be returned.
+    #[inline]
+    pub fn preconditions(mut self, value: crate::io::k8s::apimachinery::pkg::apis::meta::v1::preconditions::Preconditions) -> Self {
+        self.inner.body.preconditions = Some(value.into());
+        self
+    }
+
+    /// Sets [`preconditions`](#method.preconditions) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_preconditions(mut self, value: Option<crate::io::k8s::apimachinery::pkg::apis::meta::v1::preconditions::Preconditions>) -> Self {
+        self.inner.body.preconditions = value.map(|value| value.into());
+        self
+    }
+}
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for DeleteOptionsDeleteBuilder54<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    type Output = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::Status;
+
+    const METHOD: http::Method = http::Method::DELETE;
+
+    const OPERATION_ID: Option<&'static str> = Some("deletePolicyV1beta1NamespacedPodDisruptionBudget");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok(format!("/apis/policy/v1beta1/namespaces/{namespace}/poddisruptionbudgets/{name}", name=crate::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?, namespace=crate::client::path_param("namespace", self.inner.param_namespace.as_ref().expect("missing parameter namespace?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::CONTENT_TYPE.as_str(), "application/json")
+        .json(&self.inner.body)
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("dryRun", self.inner.param_dry_run.as_ref().map(std::string::ToString::to_string)),
+            ("gracePeriodSeconds", self.inner.param_grace_period_seconds.as_ref().map(std::string::ToString::to_string)),
+            ("orphanDependents", self.inner.param_orphan_dependents.as_ref().map(std::string::ToString::to_string)),
+            ("propagationPolicy", self.inner.param_propagation_policy.as_ref().map(std::string::ToString::to_string)),
+            ("pretty", self.inner.param_pretty.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Content-Type")
+            || name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<DeleteOptionsDeleteBuilder54<crate::generics::NameExists, crate::generics::NamespaceExists>> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: DeleteOptionsDeleteBuilder54<crate::generics::NameExists, crate::generics::NamespaceExists>) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl DeleteOptionsDeleteBuilder54<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = DeleteOptionsDeleteBuilder54 {
+            inner: DeleteOptionsDeleteBuilder54Container {
+            body: crate::cli::read_from_input(matches)?,
+            param_dry_run: matches.and_then(|m| {
+                    m.value_of("dry-run").map(|_| {
+                        value_t!(m, "dry-run", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_grace_period_seconds: matches.and_then(|m| {
+                    m.value_of("grace-period-seconds").map(|_| {
+                        value_t!(m, "grace-period-seconds", i64).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_orphan_dependents: matches.and_then(|m| {
+                    m.value_of("orphan-dependents").map(|_| {
+                        value_t!(m, "orphan-dependents", bool).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_propagation_policy: matches.and_then(|m| {
+                    m.value_of("propagation-policy").map(|_| {
+                        value_t!(m, "propagation-policy", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_name: matches.and_then(|m| {
+                    m.value_of("name").map(|_| {
+                        value_t!(m, "name", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_namespace: matches.and_then(|m| {
+                    m.value_of("namespace").map(|_| {
+                        value_t!(m, "namespace", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_pretty: matches.and_then(|m| {
+                    m.value_of("pretty").map(|_| {
+                        value_t!(m, "pretty", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            },
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder created by [`DeleteOptions::delete_policy_v1beta1_pod_security_policy`](./struct.DeleteOptions.html#method.delete_policy_v1beta1_pod_security_policy) method for a `DELETE` operation associated with `DeleteOptions`.
+pub type DeleteOptionsDeleteBuilder55<Name> = DeleteOptionsOpBuilder12<DeleteOptionsDeleteOp55, Name>;
+
+/// Marks the operation of [`DeleteOptionsDeleteBuilder55`] in the shared builder.
+#[derive(Debug, Clone)]
+pub enum DeleteOptionsDeleteOp55 {}
+
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for DeleteOptionsDeleteBuilder55<crate::generics::NameExists> {
+    type Output = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::Status;
+
+    const METHOD: http::Method = http::Method::DELETE;
+
+    const OPERATION_ID: Option<&'static str> = Some("deletePolicyV1beta1PodSecurityPolicy");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok(format!("/apis/policy/v1beta1/podsecuritypolicies/{name}", name=crate::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::CONTENT_TYPE.as_str(), "application/json")
+        .json(&self.inner.body)
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("dryRun", self.inner.param_dry_run.as_ref().map(std::string::ToString::to_string)),
+            ("gracePeriodSeconds", self.inner.param_grace_period_seconds.as_ref().map(std::string::ToString::to_string)),
+            ("orphanDependents", self.inner.param_orphan_dependents.as_ref().map(std::string::ToString::to_string)),
+            ("propagationPolicy", self.inner.param_propagation_policy.as_ref().map(std::string::ToString::to_string)),
+            ("pretty", self.inner.param_pretty.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Content-Type")
+            || name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<DeleteOptionsDeleteBuilder55<crate::generics::NameExists>> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: DeleteOptionsDeleteBuilder55<crate::generics::NameExists>) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl DeleteOptionsDeleteBuilder55<crate::generics::NameExists> {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = DeleteOptionsDeleteBuilder55 {
+            inner: DeleteOptionsOpBuilder12Container {
+            body: crate::cli::read_from_input(matches)?,
+            param_dry_run: matches.and_then(|m| {
+                    m.value_of("dry-run").map(|_| {
+                        value_t!(m, "dry-run", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_grace_period_seconds: matches.and_then(|m| {
+                    m.value_of("grace-period-seconds").map(|_| {
+                        value_t!(m, "grace-period-seconds", i64).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_orphan_dependents: matches.and_then(|m| {
+                    m.value_of("orphan-dependents").map(|_| {
+                        value_t!(m, "orphan-dependents", bool).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_propagation_policy: matches.and_then(|m| {
+                    m.value_of("propagation-policy").map(|_| {
+                        value_t!(m, "propagation-policy", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_name: matches.and_then(|m| {
+                    m.value_of("name").map(|_| {
+                        value_t!(m, "name", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_pretty: matches.and_then(|m| {
+                    m.value_of("pretty").map(|_| {
+                        value_t!(m, "pretty", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            },
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder shared by operations (with the same fields and parameters) associated with `DeleteOptions`.
+#[repr(transparent)]
+#[derive(Debug, Clone)]
+pub struct DeleteOptionsOpBuilder14<Op, Name> {
+    inner: DeleteOptionsOpBuilder14Container,
+    _op: core::marker::PhantomData<Op>,
+    _param_name: core::marker::PhantomData<Name>,
+}
+
+#[derive(Debug, Default, Clone)]
+struct DeleteOptionsOpBuilder14Container {
+    body: self::DeleteOptions,
+    param_dry_run: Option<String>,
+    param_grace_period_seconds: Option<i64>,
+    param_orphan_dependents: Option<bool>,
+    param_propagation_policy: Option<String>,
+    param_name: Option<String>,
+    param_pretty: Option<String>,
+}
+
+/// Builder created by [`DeleteOptions::delete_rbac_authorization_v1_cluster_role_binding`](./struct.DeleteOptions.html#method.delete_rbac_authorization_v1_cluster_role_binding) method for a `DELETE` operation associated with `DeleteOptions`.
+pub type DeleteOptionsDeleteBuilder56<Name> = DeleteOptionsOpBuilder14<DeleteOptionsDeleteOp56, Name>;
+
+/// Marks the operation of [`DeleteOptionsDeleteBuilder56`] in the shared builder.
+#[derive(Debug, Clone)]
+pub enum DeleteOptionsDeleteOp56 {}
+
+impl<Op, Name> DeleteOptionsOpBuilder14<Op, Name> {
+    /// When present, indicates that modifications should not be persisted. An invalid or unrecognized dryRun directive will result in an error response and no further processing of the request. Valid values are: - All: all dry run stages will be processed
+    #[inline]
+    pub fn dry_run(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_dry_run = Some(value.into());
+        self
+    }
+
+    /// Sets [`dry_run`](#method.dry_run) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_dry_run(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_dry_run = value.map(|value| value.into());
+        self
+    }
+
+    /// The duration in seconds before the object should be deleted. Value must be non-negative integer. The value zero indicates delete immediately. If this value is nil, the default grace period for the specified type will be used. Defaults to a per object value if not specified. zero means delete immediately.
+    #[inline]
+    pub fn grace_period_seconds(mut self, value: impl Into<i64>) -> Self {
+        self.inner.param_grace_period_seconds = Some({
+            let val = value.into();
+            self.inner.body.grace_period_seconds = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// Same as [`grace_period_seconds`](#method.grace_period_seconds), but takes a reference.
+    #[inline]
+    pub fn grace_period_seconds_ref(self, value: &i64) -> Self {
+        self.grace_period_seconds(*value)
+    }
+
+    /// Deprecated: please use the PropagationPolicy, this field will be deprecated in 1.7. Should the dependent objects be orphaned. If true/false, the "orphan" finalizer will be added to/removed from the object's finalizers list. Either this field or PropagationPolicy may be set, but not both.
+    #[inline]
+    pub fn orphan_dependents(mut self, value: impl Into<bool>) -> Self {
+        self.inner.param_orphan_dependents = Some({
+            let val = value.into();
+            self.inner.body.orphan_dependents = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// Same as [`orphan_dependents`](#method.orphan_dependents), but takes a reference.
+    #[inline]
+    pub fn orphan_dependents_ref(self, value: &bool) -> Self {
+        self.orphan_dependents(*value)
+    }
+
+    /// Whether and how garbage collection will be performed. Either this field or OrphanDependents may be set, but not both. The default policy is decided by the existing finalizer set in the metadata.finalizers and the resource-specific default policy. Acceptable values are: 'Orphan' - orphan the dependents; 'Background' - allow the garbage collector to delete the dependents in the background; 'Foreground' - a cascading policy that deletes all dependents in the foreground.
+    #[inline]
+    pub fn propagation_policy(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_propagation_policy = Some({
+            let val = value.into();
+            self.inner.body.propagation_policy = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// name of the ClusterRoleBinding
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> DeleteOptionsOpBuilder14<Op, crate::generics::NameExists> {
+        self.inner.param_name = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// If 'true', then the output is pretty printed.
+    #[inline]
+    pub fn pretty(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_pretty = Some(value.into());
+        self
+    }
+
+    /// Sets [`pretty`](#method.pretty) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_pretty(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_pretty = value.map(|value| value.into());
+        self
+    }
+
+    /// APIVersion defines the versioned schema of this representation of an object. Servers should convert recognized schemas to the latest internal value, and may reject unrecognized values. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#resources
+    #[inline]
+    pub fn api_version(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.api_version = Some(value.into());
+        self
+    }
+
+    /// Sets [`api_version`](#method.api_version) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_api_version(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.api_version = value.map(|value| value.into());
+        self
+    }
+
+    /// Kind is a string value representing the REST resource this object represents. Servers may infer this from the endpoint the client submits requests to. Cannot be updated. In CamelCase. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#types-kinds
+    #[inline]
+    pub fn kind(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.kind = Some(value.into());
+        self
+    }
+
+    /// Sets [`kind`](#method.kind) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_kind(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.kind = value.map(|value| value.into());
+        self
+    }
+
+    /// Must be fulfilled before a deletion is carried out. If not possible, a 409 Conflict status will be returned.
+    #[inline]
+    pub fn preconditions(mut self, value: crate::io::k8s::apimachinery::pkg::apis::meta::v1::preconditions::Preconditions) -> Self {
+        self.inner.body.preconditions = Some(value.into());
+        self
+    }
+
+    /// Sets [`preconditions`](#method.preconditions) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_preconditions(mut self, value: Option<crate::io::k8s::apimachinery::pkg::apis::meta::v1::preconditions::Preconditions>) -> Self {
+        self.inner.body.preconditions = value.map(|value| value.into());
+        self
+    }
+}
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for DeleteOptionsDeleteBuilder56<crate::generics::NameExists> {
+    type Output = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::Status;
+
+    const METHOD: http::Method = http::Method::DELETE;
+
+    const OPERATION_ID: Option<&'static str> = Some("deleteRbacAuthorizationV1ClusterRoleBinding");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok(format!("/apis/rbac.authorization.k8s.io/v1/clusterrolebindings/{name}", name=crate::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::CONTENT_TYPE.as_str(), "application/json")
+        .json(&self.inner.body)
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("dryRun", self.inner.param_dry_run.as_ref().map(std::string::ToString::to_string)),
+            ("gracePeriodSeconds", self.inner.param_grace_period_seconds.as_ref().map(std::string::ToString::to_string)),
+            ("orphanDependents", self.inner.param_orphan_dependents.as_ref().map(std::string::ToString::to_string)),
+            ("propagationPolicy", self.inner.param_propagation_policy.as_ref().map(std::string::ToString::to_string)),
+            ("pretty", self.inner.param_pretty.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Content-Type")
+            || name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<DeleteOptionsDeleteBuilder56<crate::generics::NameExists>> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: DeleteOptionsDeleteBuilder56<crate::generics::NameExists>) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl DeleteOptionsDeleteBuilder56<crate::generics::NameExists> {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = DeleteOptionsDeleteBuilder56 {
+            inner: DeleteOptionsOpBuilder14Container {
+            body: crate::cli::read_from_input(matches)?,
+            param_dry_run: matches.and_then(|m| {
+                    m.value_of("dry-run").map(|_| {
+                        value_t!(m, "dry-run", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_grace_period_seconds: matches.and_then(|m| {
+                    m.value_of("grace-period-seconds").map(|_| {
+                        value_t!(m, "grace-period-seconds", i64).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_orphan_dependents: matches.and_then(|m| {
+                    m.value_of("orphan-dependents").map(|_| {
+                        value_t!(m, "orphan-dependents", bool).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_propagation_policy: matches.and_then(|m| {
+                    m.value_of("propagation-policy").map(|_| {
+                        value_t!(m, "propagation-policy", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_name: matches.and_then(|m| {
+                    m.value_of("name").map(|_| {
+                        value_t!(m, "name", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_pretty: matches.and_then(|m| {
+                    m.value_of("pretty").map(|_| {
+                        value_t!(m, "pretty", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            },
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder shared by operations (with the same fields and parameters) associated with `DeleteOptions`.
+#[repr(transparent)]
+#[derive(Debug, Clone)]
+pub struct DeleteOptionsOpBuilder15<Op, Name> {
+    inner: DeleteOptionsOpBuilder15Container,
+    _op: core::marker::PhantomData<Op>,
+    _param_name: core::marker::PhantomData<Name>,
+}
+
+#[derive(Debug, Default, Clone)]
+struct DeleteOptionsOpBuilder15Container {
+    body: self::DeleteOptions,
+    param_dry_run: Option<String>,
+    param_grace_period_seconds: Option<i64>,
+    param_orphan_dependents: Option<bool>,
+    param_propagation_policy: Option<String>,
+    param_name: Option<String>,
+    param_pretty: Option<String>,
+}
+
+/// Builder created by [`DeleteOptions::delete_rbac_authorization_v1_cluster_role`](./struct.DeleteOptions.html#method.delete_rbac_authorization_v1_cluster_role) method for a `DELETE` operation associated with `DeleteOptions`.
+pub type DeleteOptionsDeleteBuilder57<Name> = DeleteOptionsOpBuilder15<DeleteOptionsDeleteOp57, Name>;
+
+/// Marks the operation of [`DeleteOptionsDeleteBuilder57`] in the shared builder.
+#[derive(Debug, Clone)]
+pub enum DeleteOptionsDeleteOp57 {}
+
+impl<Op, Name> DeleteOptionsOpBuilder15<Op, Name> {
+    /// When present, indicates that modifications should not be persisted. An invalid or unrecognized dryRun directive will result in an error response and no further processing of the request. Valid values are: - All: all dry run stages will be processed
+    #[inline]
+    pub fn dry_run(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_dry_run = Some(value.into());
+        self
+    }
+
+    /// Sets [`dry_run`](#method.dry_run) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_dry_run(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_dry_run = value.map(|value| value.into());
+        self
+    }
+
+    /// The duration in seconds before the object should be deleted. Value must be non-negative integer. The value zero indicates delete immediately. If this value is nil, the default grace period for the specified type will be used. Defaults to a per object value if not specified. zero means delete immediately.
+    #[inline]
+    pub fn grace_period_seconds(mut self, value: impl Into<i64>) -> Self {
+        self.inner.param_grace_period_seconds = Some({
+            let val = value.into();
+            self.inner.body.grace_period_seconds = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// Same as [`grace_period_seconds`](#method.grace_period_seconds), but takes a reference.
+    #[inline]
+    pub fn grace_period_seconds_ref(self, value: &i64) -> Self {
+        self.grace_period_seconds(*value)
+    }
+
+    /// Deprecated: please use the PropagationPolicy, this field will be deprecated in 1.7. Should the dependent objects be orphaned. If true/false, the "orphan" finalizer will be added to/removed from the object's finalizers list. Either this field or PropagationPolicy may be set, but not both.
+    #[inline]
+    pub fn orphan_dependents(mut self, value: impl Into<bool>) -> Self {
+        self.inner.param_orphan_dependents = Some({
+            let val = value.into();
+            self.inner.body.orphan_dependents = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// Same as [`orphan_dependents`](#method.orphan_dependents), but takes a reference.
+    #[inline]
+    pub fn orphan_dependents_ref(self, value: &bool) -> Self {
+        self.orphan_dependents(*value)
+    }
+
+    /// Whether and how garbage collection will be performed. Either this field or OrphanDependents may be set, but not both. The default policy is decided by the existing finalizer set in the metadata.finalizers and the resource-specific default policy. Acceptable values are: 'Orphan' - orphan the dependents; 'Background' - allow the garbage collector to delete the dependents in the background; 'Foreground' - a cascading policy that deletes all dependents in the foreground.
+    #[inline]
+    pub fn propagation_policy(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_propagation_policy = Some({
+            let val = value.into();
+            self.inner.body.propagation_policy = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// name of the ClusterRole
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> DeleteOptionsOpBuilder15<Op, crate::generics::NameExists> {
+        self.inner.param_name = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// If 'true', then the output is pretty printed.
+    #[inline]
+    pub fn pretty(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_pretty = Some(value.into());
+        self
+    }
+
+    /// Sets [`pretty`](#method.pretty) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_pretty(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_pretty = value.map(|value| value.into());
+        self
+    }
+
+    /// APIVersion defines the versioned schema of this representation of an object. Servers should convert recognized schemas to the latest internal value, and may reject unrecognized values. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#resources
+    #[inline]
+    pub fn api_version(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.api_version = Some(value.into());
+        self
+    }
+
+    /// Sets [`api_version`](#method.api_version) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_api_version(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.api_version = value.map(|value| value.into());
+        self
+    }
+
+    /// Kind is a string value representing the REST resource this object represents. Servers may infer this from the endpoint the client submits requests to. Cannot be updated. In CamelCase. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#types-kinds
+    #[inline]
+    pub fn kind(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.kind = Some(value.into());
+        self
+    }
+
+    /// Sets [`kind`](#method.kind) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_kind(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.kind = value.map(|value| value.into());
+        self
+    }
+
+    /// Must be fulfilled before a deletion is carried out. If not possible, a 409 Conflict status will be returned.
+    #[inline]
+    pub fn preconditions(mut self, value: crate::io::k8s::apimachinery::pkg::apis::meta::v1::preconditions::Preconditions) -> Self {
+        self.inner.body.preconditions = Some(value.into());
+        self
+    }
+
+    /// Sets [`preconditions`](#method.preconditions) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_preconditions(mut self, value: Option<crate::io::k8s::apimachinery::pkg::apis::meta::v1::preconditions::Preconditions>) -> Self {
+        self.inner.body.preconditions = value.map(|value| value.into());
+        self
+    }
+}
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for DeleteOptionsDeleteBuilder57<crate::generics::NameExists> {
+    type Output = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::Status;
+
+    const METHOD: http::Method = http::Method::DELETE;
+
+    const OPERATION_ID: Option<&'static str> = Some("deleteRbacAuthorizationV1ClusterRole");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok(format!("/apis/rbac.authorization.k8s.io/v1/clusterroles/{name}", name=crate::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::CONTENT_TYPE.as_str(), "application/json")
+        .json(&self.inner.body)
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("dryRun", self.inner.param_dry_run.as_ref().map(std::string::ToString::to_string)),
+            ("gracePeriodSeconds", self.inner.param_grace_period_seconds.as_ref().map(std::string::ToString::to_string)),
+            ("orphanDependents", self.inner.param_orphan_dependents.as_ref().map(std::string::ToString::to_string)),
+            ("propagationPolicy", self.inner.param_propagation_policy.as_ref().map(std::string::ToString::to_string)),
+            ("pretty", self.inner.param_pretty.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Content-Type")
+            || name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<DeleteOptionsDeleteBuilder57<crate::generics::NameExists>> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: DeleteOptionsDeleteBuilder57<crate::generics::NameExists>) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl DeleteOptionsDeleteBuilder57<crate::generics::NameExists> {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = DeleteOptionsDeleteBuilder57 {
+            inner: DeleteOptionsOpBuilder15Container {
+            body: crate::cli::read_from_input(matches)?,
+            param_dry_run: matches.and_then(|m| {
+                    m.value_of("dry-run").map(|_| {
+                        value_t!(m, "dry-run", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_grace_period_seconds: matches.and_then(|m| {
+                    m.value_of("grace-period-seconds").map(|_| {
+                        value_t!(m, "grace-period-seconds", i64).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_orphan_dependents: matches.and_then(|m| {
+                    m.value_of("orphan-dependents").map(|_| {
+                        value_t!(m, "orphan-dependents", bool).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_propagation_policy: matches.and_then(|m| {
+                    m.value_of("propagation-policy").map(|_| {
+                        value_t!(m, "propagation-policy", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_name: matches.and_then(|m| {
+                    m.value_of("name").map(|_| {
+                        value_t!(m, "name", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_pretty: matches.and_then(|m| {
+                    m.value_of("pretty").map(|_| {
+                        value_t!(m, "pretty", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            },
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder shared by operations (with the same fields and parameters) associated with `DeleteOptions`.
+#[repr(transparent)]
+#[derive(Debug, Clone)]
+pub struct DeleteOptionsOpBuilder16<Op, Name, Namespace> {
+    inner: DeleteOptionsOpBuilder16Container,
+    _op: core::marker::PhantomData<Op>,
+    _param_name: core::marker::PhantomData<Name>,
+    _param_namespace: core::marker::PhantomData<Namespace>,
+}
+
+#[derive(Debug, Default, Clone)]
+struct DeleteOptionsOpBuilder16Container {
+    body: self::DeleteOptions,
+    param_dry_run: Option<String>,
+    param_grace_period_seconds: Option<i64>,
+    param_orphan_dependents: Option<bool>,
+    param_propagation_policy: Option<String>,
+    param_name: Option<String>,
+    param_namespace: Option<String>,
+    param_pretty: Option<String>,
+}
+
+/// Builder created by [`DeleteOptions::delete_rbac_authorization_v1_namespaced_role_binding`](./struct.DeleteOptions.html#method.delete_rbac_authorization_v1_namespaced_role_binding) method for a `DELETE` operation associated with `DeleteOptions`.
+pub type DeleteOptionsDeleteBuilder58<Name, Namespace> = DeleteOptionsOpBuilder16<DeleteOptionsDeleteOp58, Name, Namespace>;
+
+/// Marks the operation of [`DeleteOptionsDeleteBuilder58`] in the shared builder.
+#[derive(Debug, Clone)]
+pub enum DeleteOptionsDeleteOp58 {}
+
+impl<Op, Name, Namespace> DeleteOptionsOpBuilder16<Op, Name, Namespace> {
+    /// When present, indicates that modifications should not be persisted. An invalid or unrecognized dryRun directive will result in an error response and no further processing of the request. Valid values are: - All: all dry run stages will be processed
+    #[inline]
+    pub fn dry_run(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_dry_run = Some(value.into());
+        self
+    }
+
+    /// Sets [`dry_run`](#method.dry_run) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_dry_run(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_dry_run = value.map(|value| value.into());
+        self
+    }
+
+    /// The duration in seconds before the object should be deleted. Value must be non-negative integer. The value zero indicates delete immediately. If this value is nil, the default grace period for the specified type will be used. Defaults to a per object value if not specified. zero means delete immediately.
+    #[inline]
+    pub fn grace_period_seconds(mut self, value: impl Into<i64>) -> Self {
+        self.inner.param_grace_period_seconds = Some({
+            let val = value.into();
+            self.inner.body.grace_period_seconds = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// Same as [`grace_period_seconds`](#method.grace_period_seconds), but takes a reference.
+    #[inline]
+    pub fn grace_period_seconds_ref(self, value: &i64) -> Self {
+        self.grace_period_seconds(*value)
+    }
+
+    /// Deprecated: please use the PropagationPolicy, this field will be deprecated in 1.7. Should the dependent objects be orphaned. If true/false, the "orphan" finalizer will be added to/removed from the object's finalizers list. Either this field or PropagationPolicy may be set, but not both.
+    #[inline]
+    pub fn orphan_dependents(mut self, value: impl Into<bool>) -> Self {
+        self.inner.param_orphan_dependents = Some({
+            let val = value.into();
+            self.inner.body.orphan_dependents = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// Same as [`orphan_dependents`](#method.orphan_dependents), but takes a reference.
+    #[inline]
+    pub fn orphan_dependents_ref(self, value: &bool) -> Self {
+        self.orphan_dependents(*value)
+    }
+
+    /// Whether and how garbage collection will be performed. Either this field or OrphanDependents may be set, but not both. The default policy is decided by the existing finalizer set in the metadata.finalizers and the resource-specific default policy. Acceptable values are: 'Orphan' - orphan the dependents; 'Background' - allow the garbage collector to delete the dependents in the background; 'Foreground' - a cascading policy that deletes all dependents in the foreground.
+    #[inline]
+    pub fn propagation_policy(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_propagation_policy = Some({
+            let val = value.into();
+            self.inner.body.propagation_policy = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// name of the RoleBinding
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> DeleteOptionsOpBuilder16<Op, crate::generics::NameExists, Namespace> {
+        self.inner.param_name = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// object name and auth scope, such as for teams and projects
+    #[inline]
+    pub fn namespace(mut self, value: impl Into<String>) -> DeleteOptionsOpBuilder16<Op, Name, crate::generics::NamespaceExists> {
+        self.inner.param_namespace = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// If 'true', then the output is pretty printed.
+    #[inline]
+    pub fn pretty(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_pretty = Some(value.into());
+        self
+    }
+
+    /// Sets [`pretty`](#method.pretty) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_pretty(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_pretty = value.map(|value| value.into());
+        self
+    }
+
+    /// APIVersion defines the versioned schema of this representation of an object. Servers should convert recognized schemas to the latest internal value, and may reject unrecognized values. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#resources
+    #[inline]
+    pub fn api_version(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.api_version = Some(value.into());
+        self
+    }
+
+    /// Sets [`api_version`](#method.api_version) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_api_version(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.api_version = value.map(|value| value.into());
+        self
+    }
+
+    /// Kind is a string value representing the REST resource this object represents. Servers may infer this from the endpoint the client submits requests to. Cannot be updated. In CamelCase. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#types-kinds
+    #[inline]
+    pub fn kind(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.kind = Some(value.into());
+        self
+    }
+
+    /// Sets [`kind`](#method.kind) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_kind(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.kind = value.map(|value| value.into());
+        self
+    }
+
+    /// Must be fulfilled before a deletion is carried out. If not possible, a 409 Conflict status will be returned.
+    #[inline]
+    pub fn preconditions(mut self, value: crate::io::k8s::apimachinery::pkg::apis::meta::v1::preconditions::Preconditions) -> Self {
+        self.inner.body.preconditions = Some(value.into());
+        self
+    }
+
+    /// Sets [`preconditions`](#method.preconditions) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_preconditions(mut self, value: Option<crate::io::k8s::apimachinery::pkg::apis::meta::v1::preconditions::Preconditions>) -> Self {
+        self.inner.body.preconditions = value.map(|value| value.into());
+        self
+    }
+}
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for DeleteOptionsDeleteBuilder58<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    type Output = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::Status;
+
+    const METHOD: http::Method = http::Method::DELETE;
+
+    const OPERATION_ID: Option<&'static str> = Some("deleteRbacAuthorizationV1NamespacedRoleBinding");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok(format!("/apis/rbac.authorization.k8s.io/v1/namespaces/{namespace}/rolebindings/{name}", name=crate::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?, namespace=crate::client::path_param("namespace", self.inner.param_namespace.as_ref().expect("missing parameter namespace?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::CONTENT_TYPE.as_str(), "application/json")
+        .json(&self.inner.body)
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("dryRun", self.inner.param_dry_run.as_ref().map(std::string::ToString::to_string)),
+            ("gracePeriodSeconds", self.inner.param_grace_period_seconds.as_ref().map(std::string::ToString::to_string)),
+            ("orphanDependents", self.inner.param_orphan_dependents.as_ref().map(std::string::ToString::to_string)),
+            ("propagationPolicy", self.inner.param_propagation_policy.as_ref().map(std::string::ToString::to_string)),
+            ("pretty", self.inner.param_pretty.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Content-Type")
+            || name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<DeleteOptionsDeleteBuilder58<crate::generics::NameExists, crate::generics::NamespaceExists>> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: DeleteOptionsDeleteBuilder58<crate::generics::NameExists, crate::generics::NamespaceExists>) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl DeleteOptionsDeleteBuilder58<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = DeleteOptionsDeleteBuilder58 {
+            inner: DeleteOptionsOpBuilder16Container {
+            body: crate::cli::read_from_input(matches)?,
+            param_dry_run: matches.and_then(|m| {
+                    m.value_of("dry-run").map(|_| {
+                        value_t!(m, "dry-run", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_grace_period_seconds: matches.and_then(|m| {
+                    m.value_of("grace-period-seconds").map(|_| {
+                        value_t!(m, "grace-period-seconds", i64).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_orphan_dependents: matches.and_then(|m| {
+                    m.value_of("orphan-dependents").map(|_| {
+                        value_t!(m, "orphan-dependents", bool).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_propagation_policy: matches.and_then(|m| {
+                    m.value_of("propagation-policy").map(|_| {
+                        value_t!(m, "propagation-policy", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_name: matches.and_then(|m| {
+                    m.value_of("name").map(|_| {
+                        value_t!(m, "name", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_namespace: matches.and_then(|m| {
+                    m.value_of("namespace").map(|_| {
+                        value_t!(m, "namespace", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_pretty: matches.and_then(|m| {
+                    m.value_of("pretty").map(|_| {
+                        value_t!(m, "pretty", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            },
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder shared by operations (with the same fields and parameters) associated with `DeleteOptions`.
+#[repr(transparent)]
+#[derive(Debug, Clone)]
+pub struct DeleteOptionsOpBuilder17<Op, Name, Namespace> {
+    inner: DeleteOptionsOpBuilder17Container,
+    _op: core::marker::PhantomData<Op>,
+    _param_name: core::marker::PhantomData<Name>,
+    _param_namespace: core::marker::PhantomData<Namespace>,
+}
+
+#[derive(Debug, Default, Clone)]
+struct DeleteOptionsOpBuilder17Container {
+    body: self::DeleteOptions,
+    param_dry_run: Option<String>,
+    param_grace_period_seconds: Option<i64>,
+    param_orphan_dependents: Option<bool>,
+    param_propagation_policy: Option<String>,
+    param_name: Option<String>,
+    param_namespace: Option<String>,
+    param_pretty: Option<String>,
+}
+
+/// Builder created by [`DeleteOptions::delete_rbac_authorization_v1_namespaced_role`](./struct.DeleteOptions.html#method.delete_rbac_authorization_v1_namespaced_role) method for a `DELETE` operation associated with `DeleteOptions`.
+pub type DeleteOptionsDeleteBuilder59<Name, Namespace> = DeleteOptionsOpBuilder17<DeleteOptionsDeleteOp59, Name, Namespace>;
+
+/// Marks the operation of [`DeleteOptionsDeleteBuilder59`] in the shared builder.
+#[derive(Debug, Clone)]
+pub enum DeleteOptionsDeleteOp59 {}
+
+impl<Op, Name, Namespace> DeleteOptionsOpBuilder17<Op, Name, Namespace> {
+    /// When present, indicates that modifications should not be persisted. An invalid or unrecognized dryRun directive will result in an error response and no further processing of the request. Valid values are: - All: all dry run stages will be processed
+    #[inline]
+    pub fn dry_run(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_dry_run = Some(value.into());
+        self
+    }
+
+    /// Sets [`dry_run`](#method.dry_run) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_dry_run(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_dry_run = value.map(|value| value.into());
+        self
+    }
+
+    /// The duration in seconds before the object should be deleted. Value must be non-negative integer. The value zero indicates delete immediately. If this value is nil, the default grace period for the specified type will be used. Defaults to a per object value if not specified. zero means delete immediately.
+    #[inline]
+    pub fn grace_period_seconds(mut self, value: impl Into<i64>) -> Self {
+        self.inner.param_grace_period_seconds = Some({
+            let val = value.into();
+            self.inner.body.grace_period_seconds = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// Same as [`grace_period_seconds`](#method.grace_period_seconds), but takes a reference.
+    #[inline]
+    pub fn grace_period_seconds_ref(self, value: &i64) -> Self {
+        self.grace_period_seconds(*value)
+    }
+
+    /// Deprecated: please use the PropagationPolicy, this field will be deprecated in 1.7. Should the dependent objects be orphaned. If true/false, the "orphan" finalizer will be added to/removed from the object's finalizers list. Either this field or PropagationPolicy may be set, but not both.
+    #[inline]
+    pub fn orphan_dependents(mut self, value: impl Into<bool>) -> Self {
+        self.inner.param_orphan_dependents = Some({
+            let val = value.into();
+            self.inner.body.orphan_dependents = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// Same as [`orphan_dependents`](#method.orphan_dependents), but takes a reference.
+    #[inline]
+    pub fn orphan_dependents_ref(self, value: &bool) -> Self {
+        self.orphan_dependents(*value)
+    }
+
+    /// Whether and how garbage collection will be performed. Either this field or OrphanDependents may be set, but not both. The default policy is decided by the existing finalizer set in the metadata.finalizers and the resource-specific default policy. Acceptable values are: 'Orphan' - orphan the dependents; 'Background' - allow the garbage collector to delete the dependents in the background; 'Foreground' - a cascading policy that deletes all dependents in the foreground.
+    #[inline]
+    pub fn propagation_policy(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_propagation_policy = Some({
+            let val = value.into();
+            self.inner.body.propagation_policy = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// name of the Role
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> DeleteOptionsOpBuilder17<Op, crate::generics::NameExists, Namespace> {
+        self.inner.param_name = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// object name and auth scope, such as for teams and projects
+    #[inline]
+    pub fn namespace(mut self, value: impl Into<String>) -> DeleteOptionsOpBuilder17<Op, Name, crate::generics::NamespaceExists> {
+        self.inner.param_namespace = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// If 'true', then the output is pretty printed.
+    #[inline]
+    pub fn pretty(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_pretty = Some(value.into());
+        self
+    }
+
+    /// Sets [`pretty`](#method.pretty) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_pretty(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_pretty = value.map(|value| value.into());
+        self
+    }
+
+    /// APIVersion defines the versioned schema of this representation of an object. Servers should convert recognized schemas to the latest internal value, and may reject unrecognized values. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#resources
+    #[inline]
+    pub fn api_version(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.api_version = Some(value.into());
+        self
+    }
+
+    /// Sets [`api_version`](#method.api_version) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_api_version(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.api_version = value.map(|value| value.into());
+        self
+    }
+
+    /// Kind is a string value representing the REST resource this object represents. Servers may infer this from the endpoint the client submits requests to. Cannot be updated. In CamelCase. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#types-kinds
+    #[inline]
+    pub fn kind(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.kind = Some(value.into());
+        self
+    }
+
+    /// Sets [`kind`](#method.kind) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_kind(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.kind = value.map(|value| value.into());
+        self
+    }
+
+    /// Must be fulfilled before a deletion is carried out. If not possible, a 409 Conflict status will be returned.
+    #[inline]
+    pub fn preconditions(mut self, value: crate::io::k8s::apimachinery::pkg::apis::meta::v1::preconditions::Preconditions) -> Self {
+        self.inner.body.preconditions = Some(value.into());
+        self
+    }
+
+    /// Sets [`preconditions`](#method.preconditions) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_preconditions(mut self, value: Option<crate::io::k8s::apimachinery::pkg::apis::meta::v1::preconditions::Preconditions>) -> Self {
+        self.inner.body.preconditions = value.map(|value| value.into());
+        self
+    }
+}
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for DeleteOptionsDeleteBuilder59<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    type Output = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::Status;
+
+    const METHOD: http::Method = http::Method::DELETE;
+
+    const OPERATION_ID: Option<&'static str> = Some("deleteRbacAuthorizationV1NamespacedRole");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok(format!("/apis/rbac.authorization.k8s.io/v1/namespaces/{namespace}/roles/{name}", name=crate::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?, namespace=crate::client::path_param("namespace", self.inner.param_namespace.as_ref().expect("missing parameter namespace?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::CONTENT_TYPE.as_str(), "application/json")
+        .json(&self.inner.body)
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("dryRun", self.inner.param_dry_run.as_ref().map(std::string::ToString::to_string)),
+            ("gracePeriodSeconds", self.inner.param_grace_period_seconds.as_ref().map(std::string::ToString::to_string)),
+            ("orphanDependents", self.inner.param_orphan_dependents.as_ref().map(std::string::ToString::to_string)),
+            ("propagationPolicy", self.inner.param_propagation_policy.as_ref().map(std::string::ToString::to_string)),
+            ("pretty", self.inner.param_pretty.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Content-Type")
+            || name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<DeleteOptionsDeleteBuilder59<crate::generics::NameExists, crate::generics::NamespaceExists>> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: DeleteOptionsDeleteBuilder59<crate::generics::NameExists, crate::generics::NamespaceExists>) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl DeleteOptionsDeleteBuilder59<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = DeleteOptionsDeleteBuilder59 {
+            inner: DeleteOptionsOpBuilder17Container {
+            body: crate::cli::read_from_input(matches)?,
+            param_dry_run: matches.and_then(|m| {
+                    m.value_of("dry-run").map(|_| {
+                        value_t!(m, "dry-run", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_grace_period_seconds: matches.and_then(|m| {
+                    m.value_of("grace-period-seconds").map(|_| {
+                        value_t!(m, "grace-period-seconds", i64).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_orphan_dependents: matches.and_then(|m| {
+                    m.value_of("orphan-dependents").map(|_| {
+                        value_t!(m, "orphan-dependents", bool).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_propagation_policy: matches.and_then(|m| {
+                    m.value_of("propagation-policy").map(|_| {
+                        value_t!(m, "propagation-policy", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_name: matches.and_then(|m| {
+                    m.value_of("name").map(|_| {
+                        value_t!(m, "name", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_namespace: matches.and_then(|m| {
+                    m.value_of("namespace").map(|_| {
+                        value_t!(m, "namespace", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_pretty: matches.and_then(|m| {
+                    m.value_of("pretty").map(|_| {
+                        value_t!(m, "pretty", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            },
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder created by [`DeleteOptions::delete_rbac_authorization_v1alpha1_cluster_role_binding`](./struct.DeleteOptions.html#method.delete_rbac_authorization_v1alpha1_cluster_role_binding) method for a `DELETE` operation associated with `DeleteOptions`.
+pub type DeleteOptionsDeleteBuilder60<Name> = DeleteOptionsOpBuilder14<DeleteOptionsDeleteOp60, Name>;
+
+/// Marks the operation of [`DeleteOptionsDeleteBuilder60`] in the shared builder.
+#[derive(Debug, Clone)]
+pub enum DeleteOptionsDeleteOp60 {}
+
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for DeleteOptionsDeleteBuilder60<crate::generics::NameExists> {
+    type Output = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::Status;
+
+    const METHOD: http::Method = http::Method::DELETE;
+
+    const OPERATION_ID: Option<&'static str> = Some("deleteRbacAuthorizationV1alpha1ClusterRoleBinding");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok(format!("/apis/rbac.authorization.k8s.io/v1alpha1/clusterrolebindings/{name}", name=crate::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::CONTENT_TYPE.as_str(), "application/json")
+        .json(&self.inner.body)
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("dryRun", self.inner.param_dry_run.as_ref().map(std::string::ToString::to_string)),
+            ("gracePeriodSeconds", self.inner.param_grace_period_seconds.as_ref().map(std::string::ToString::to_string)),
+            ("orphanDependents", self.inner.param_orphan_dependents.as_ref().map(std::string::ToString::to_string)),
+            ("propagationPolicy", self.inner.param_propagation_policy.as_ref().map(std::string::ToString::to_string)),
+            ("pretty", self.inner.param_pretty.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Content-Type")
+            || name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<DeleteOptionsDeleteBuilder60<crate::generics::NameExists>> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: DeleteOptionsDeleteBuilder60<crate::generics::NameExists>) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl DeleteOptionsDeleteBuilder60<crate::generics::NameExists> {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = DeleteOptionsDeleteBuilder60 {
+            inner: DeleteOptionsOpBuilder14Container {
+            body: crate::cli::read_from_input(matches)?,
+            param_dry_run: matches.and_then(|m| {
+                    m.value_of("dry-run").map(|_| {
+                        value_t!(m, "dry-run", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_grace_period_seconds: matches.and_then(|m| {
+                    m.value_of("grace-period-seconds").map(|_| {
+                        value_t!(m, "grace-period-seconds", i64).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_orphan_dependents: matches.and_then(|m| {
+                    m.value_of("orphan-dependents").map(|_| {
+                        value_t!(m, "orphan-dependents", bool).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_propagation_policy: matches.and_then(|m| {
+                    m.value_of("propagation-policy").map(|_| {
+                        value_t!(m, "propagation-policy", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_name: matches.and_then(|m| {
+                    m.value_of("name").map(|_| {
+                        value_t!(m, "name", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_pretty: matches.and_then(|m| {
+                    m.value_of("pretty").map(|_| {
+                        value_t!(m, "pretty", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            },
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder created by [`DeleteOptions::delete_rbac_authorization_v1alpha1_cluster_role`](./struct.DeleteOptions.html#method.delete_rbac_authorization_v1alpha1_cluster_role) method for a `DELETE` operation associated with `DeleteOptions`.
+pub type DeleteOptionsDeleteBuilder61<Name> = DeleteOptionsOpBuilder15<DeleteOptionsDeleteOp61, Name>;
+
+/// Marks the operation of [`DeleteOptionsDeleteBuilder61`] in the shared builder.
+#[derive(Debug, Clone)]
+pub enum DeleteOptionsDeleteOp61 {}
+
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for DeleteOptionsDeleteBuilder61<crate::generics::NameExists> {
+    type Output = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::Status;
+
+    const METHOD: http::Method = http::Method::DELETE;
+
+    const OPERATION_ID: Option<&'static str> = Some("deleteRbacAuthorizationV1alpha1ClusterRole");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok(format!("/apis/rbac.authorization.k8s.io/v1alpha1/clusterroles/{name}", name=crate::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::CONTENT_TYPE.as_str(), "application/json")
+        .json(&self.inner.body)
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("dryRun", self.inner.param_dry_run.as_ref().map(std::string::ToString::to_string)),
+            ("gracePeriodSeconds", self.inner.param_grace_period_seconds.as_ref().map(std::string::ToString::to_string)),
+            ("orphanDependents", self.inner.param_orphan_dependents.as_ref().map(std::string::ToString::to_string)),
+            ("propagationPolicy", self.inner.param_propagation_policy.as_ref().map(std::string::ToString::to_string)),
+            ("pretty", self.inner.param_pretty.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Content-Type")
+            || name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<DeleteOptionsDeleteBuilder61<crate::generics::NameExists>> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: DeleteOptionsDeleteBuilder61<crate::generics::NameExists>) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl DeleteOptionsDeleteBuilder61<crate::generics::NameExists> {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = DeleteOptionsDeleteBuilder61 {
+            inner: DeleteOptionsOpBuilder15Container {
+            body: crate::cli::read_from_input(matches)?,
+            param_dry_run: matches.and_then(|m| {
+                    m.value_of("dry-run").map(|_| {
+                        value_t!(m, "dry-run", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_grace_period_seconds: matches.and_then(|m| {
+                    m.value_of("grace-period-seconds").map(|_| {
+                        value_t!(m, "grace-period-seconds", i64).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_orphan_dependents: matches.and_then(|m| {
+                    m.value_of("orphan-dependents").map(|_| {
+                        value_t!(m, "orphan-dependents", bool).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_propagation_policy: matches.and_then(|m| {
+                    m.value_of("propagation-policy").map(|_| {
+                        value_t!(m, "propagation-policy", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_name: matches.and_then(|m| {
+                    m.value_of("name").map(|_| {
+                        value_t!(m, "name", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_pretty: matches.and_then(|m| {
+                    m.value_of("pretty").map(|_| {
+                        value_t!(m, "pretty", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            },
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder created by [`DeleteOptions::delete_rbac_authorization_v1alpha1_namespaced_role_binding`](./struct.DeleteOptions.html#method.delete_rbac_authorization_v1alpha1_namespaced_role_binding) method for a `DELETE` operation associated with `DeleteOptions`.
+pub type DeleteOptionsDeleteBuilder62<Name, Namespace> = DeleteOptionsOpBuilder16<DeleteOptionsDeleteOp62, Name, Namespace>;
+
+/// Marks the operation of [`DeleteOptionsDeleteBuilder62`] in the shared builder.
+#[derive(Debug, Clone)]
+pub enum DeleteOptionsDeleteOp62 {}
+
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for DeleteOptionsDeleteBuilder62<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    type Output = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::Status;
+
+    const METHOD: http::Method = http::Method::DELETE;
+
+    const OPERATION_ID: Option<&'static str> = Some("deleteRbacAuthorizationV1alpha1NamespacedRoleBinding");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok(format!("/apis/rbac.authorization.k8s.io/v1alpha1/namespaces/{namespace}/rolebindings/{name}", name=crate::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?, namespace=crate::client::path_param("namespace", self.inner.param_namespace.as_ref().expect("missing parameter namespace?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::CONTENT_TYPE.as_str(), "application/json")
+        .json(&self.inner.body)
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("dryRun", self.inner.param_dry_run.as_ref().map(std::string::ToString::to_string)),
+            ("gracePeriodSeconds", self.inner.param_grace_period_seconds.as_ref().map(std::string::ToString::to_string)),
+            ("orphanDependents", self.inner.param_orphan_dependents.as_ref().map(std::string::ToString::to_string)),
+            ("propagationPolicy", self.inner.param_propagation_policy.as_ref().map(std::string::ToString::to_string)),
+            ("pretty", self.inner.param_pretty.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Content-Type")
+            || name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<DeleteOptionsDeleteBuilder62<crate::generics::NameExists, crate::generics::NamespaceExists>> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: DeleteOptionsDeleteBuilder62<crate::generics::NameExists, crate::generics::NamespaceExists>) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl DeleteOptionsDeleteBuilder62<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = DeleteOptionsDeleteBuilder62 {
+            inner: DeleteOptionsOpBuilder16Container {
+            body: crate::cli::read_from_input(matches)?,
+            param_dry_run: matches.and_then(|m| {
+                    m.value_of("dry-run").map(|_| {
+                        value_t!(m, "dry-run", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_grace_period_seconds: matches.and_then(|m| {
+                    m.value_of("grace-period-seconds").map(|_| {
+                        value_t!(m, "grace-period-seconds", i64).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_orphan_dependents: matches.and_then(|m| {
+                    m.value_of("orphan-dependents").map(|_| {
+                        value_t!(m, "orphan-dependents", bool).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_propagation_policy: matches.and_then(|m| {
+                    m.value_of("propagation-policy").map(|_| {
+                        value_t!(m, "propagation-policy", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_name: matches.and_then(|m| {
+                    m.value_of("name").map(|_| {
+                        value_t!(m, "name", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_namespace: matches.and_then(|m| {
+                    m.value_of("namespace").map(|_| {
+                        value_t!(m, "namespace", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_pretty: matches.and_then(|m| {
+                    m.value_of("pretty").map(|_| {
+                        value_t!(m, "pretty", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            },
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder created by [`DeleteOptions::delete_rbac_authorization_v1alpha1_namespaced_role`](./struct.DeleteOptions.html#method.delete_rbac_authorization_v1alpha1_namespaced_role) method for a `DELETE` operation associated with `DeleteOptions`.
+pub type DeleteOptionsDeleteBuilder63<Name, Namespace> = DeleteOptionsOpBuilder17<DeleteOptionsDeleteOp63, Name, Namespace>;
+
+/// Marks the operation of [`DeleteOptionsDeleteBuilder63`] in the shared builder.
+#[derive(Debug, Clone)]
+pub enum DeleteOptionsDeleteOp63 {}
+
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for DeleteOptionsDeleteBuilder63<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    type Output = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::Status;
+
+    const METHOD: http::Method = http::Method::DELETE;
+
+    const OPERATION_ID: Option<&'static str> = Some("deleteRbacAuthorizationV1alpha1NamespacedRole");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok(format!("/apis/rbac.authorization.k8s.io/v1alpha1/namespaces/{namespace}/roles/{name}", name=crate::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?, namespace=crate::client::path_param("namespace", self.inner.param_namespace.as_ref().expect("missing parameter namespace?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::CONTENT_TYPE.as_str(), "application/json")
+        .json(&self.inner.body)
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("dryRun", self.inner.param_dry_run.as_ref().map(std::string::ToString::to_string)),
+            ("gracePeriodSeconds", self.inner.param_grace_period_seconds.as_ref().map(std::string::ToString::to_string)),
+            ("orphanDependents", self.inner.param_orphan_dependents.as_ref().map(std::string::ToString::to_string)),
+            ("propagationPolicy", self.inner.param_propagation_policy.as_ref().map(std::string::ToString::to_string)),
+            ("pretty", self.inner.param_pretty.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Content-Type")
+            || name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<DeleteOptionsDeleteBuilder63<crate::generics::NameExists, crate::generics::NamespaceExists>> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: DeleteOptionsDeleteBuilder63<crate::generics::NameExists, crate::generics::NamespaceExists>) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl DeleteOptionsDeleteBuilder63<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = DeleteOptionsDeleteBuilder63 {
+            inner: DeleteOptionsOpBuilder17Container {
+            body: crate::cli::read_from_input(matches)?,
+            param_dry_run: matches.and_then(|m| {
+                    m.value_of("dry-run").map(|_| {
+                        value_t!(m, "dry-run", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_grace_period_seconds: matches.and_then(|m| {
+                    m.value_of("grace-period-seconds").map(|_| {
+                        value_t!(m, "grace-period-seconds", i64).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_orphan_dependents: matches.and_then(|m| {
+                    m.value_of("orphan-dependents").map(|_| {
+                        value_t!(m, "orphan-dependents", bool).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_propagation_policy: matches.and_then(|m| {
+                    m.value_of("propagation-policy").map(|_| {
+                        value_t!(m, "propagation-policy", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_name: matches.and_then(|m| {
+                    m.value_of("name").map(|_| {
+                        value_t!(m, "name", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_namespace: matches.and_then(|m| {
+                    m.value_of("namespace").map(|_| {
+                        value_t!(m, "namespace", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_pretty: matches.and_then(|m| {
+                    m.value_of("pretty").map(|_| {
+                        value_t!(m, "pretty", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            },
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder created by [`DeleteOptions::delete_rbac_authorization_v1beta1_cluster_role_binding`](./struct.DeleteOptions.html#method.delete_rbac_authorization_v1beta1_cluster_role_binding) method for a `DELETE` operation associated with `DeleteOptions`.
+pub type DeleteOptionsDeleteBuilder64<Name> = DeleteOptionsOpBuilder14<DeleteOptionsDeleteOp64, Name>;
+
+/// Marks the operation of [`DeleteOptionsDeleteBuilder64`] in the shared builder.
+#[derive(Debug, Clone)]
+pub enum DeleteOptionsDeleteOp64 {}
+
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for DeleteOptionsDeleteBuilder64<crate::generics::NameExists> {
+    type Output = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::Status;
+
+    const METHOD: http::Method = http::Method::DELETE;
+
+    const OPERATION_ID: Option<&'static str> = Some("deleteRbacAuthorizationV1beta1ClusterRoleBinding");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok(format!("/apis/rbac.authorization.k8s.io/v1beta1/clusterrolebindings/{name}", name=crate::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::CONTENT_TYPE.as_str(), "application/json")
+        .json(&self.inner.body)
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("dryRun", self.inner.param_dry_run.as_ref().map(std::string::ToString::to_string)),
+            ("gracePeriodSeconds", self.inner.param_grace_period_seconds.as_ref().map(std::string::ToString::to_string)),
+            ("orphanDependents", self.inner.param_orphan_dependents.as_ref().map(std::string::ToString::to_string)),
+            ("propagationPolicy", self.inner.param_propagation_policy.as_ref().map(std::string::ToString::to_string)),
+            ("pretty", self.inner.param_pretty.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Content-Type")
+            || name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<DeleteOptionsDeleteBuilder64<crate::generics::NameExists>> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: DeleteOptionsDeleteBuilder64<crate::generics::NameExists>) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl DeleteOptionsDeleteBuilder64<crate::generics::NameExists> {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = DeleteOptionsDeleteBuilder64 {
+            inner: DeleteOptionsOpBuilder14Container {
+            body: crate::cli::read_from_input(matches)?,
+            param_dry_run: matches.and_then(|m| {
+                    m.value_of("dry-run").map(|_| {
+                        value_t!(m, "dry-run", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_grace_period_seconds: matches.and_then(|m| {
+                    m.value_of("grace-period-seconds").map(|_| {
+                        value_t!(m, "grace-period-seconds", i64).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_orphan_dependents: matches.and_then(|m| {
+                    m.value_of("orphan-dependents").map(|_| {
+                        value_t!(m, "orphan-dependents", bool).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_propagation_policy: matches.and_then(|m| {
+                    m.value_of("propagation-policy").map(|_| {
+                        value_t!(m, "propagation-policy", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_name: matches.and_then(|m| {
+                    m.value_of("name").map(|_| {
+                        value_t!(m, "name", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_pretty: matches.and_then(|m| {
+                    m.value_of("pretty").map(|_| {
+                        value_t!(m, "pretty", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            },
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder created by [`DeleteOptions::delete_rbac_authorization_v1beta1_cluster_role`](./struct.DeleteOptions.html#method.delete_rbac_authorization_v1beta1_cluster_role) method for a `DELETE` operation associated with `DeleteOptions`.
+pub type DeleteOptionsDeleteBuilder65<Name> = DeleteOptionsOpBuilder15<DeleteOptionsDeleteOp65, Name>;
+
+/// Marks the operation of [`DeleteOptionsDeleteBuilder65`] in the shared builder.
+#[derive(Debug, Clone)]
+pub enum DeleteOptionsDeleteOp65 {}
+
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for DeleteOptionsDeleteBuilder65<crate::generics::NameExists> {
+    type Output = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::Status;
+
+    const METHOD: http::Method = http::Method::DELETE;
+
+    const OPERATION_ID: Option<&'static str> = Some("deleteRbacAuthorizationV1beta1ClusterRole");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok(format!("/apis/rbac.authorization.k8s.io/v1beta1/clusterroles/{name}", name=crate::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::CONTENT_TYPE.as_str(), "application/json")
+        .json(&self.inner.body)
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("dryRun", self.inner.param_dry_run.as_ref().map(std::string::ToString::to_string)),
+            ("gracePeriodSeconds", self.inner.param_grace_period_seconds.as_ref().map(std::string::ToString::to_string)),
+            ("orphanDependents", self.inner.param_orphan_dependents.as_ref().map(std::string::ToString::to_string)),
+            ("propagationPolicy", self.inner.param_propagation_policy.as_ref().map(std::string::ToString::to_string)),
+            ("pretty", self.inner.param_pretty.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Content-Type")
+            || name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<DeleteOptionsDeleteBuilder65<crate::generics::NameExists>> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: DeleteOptionsDeleteBuilder65<crate::generics::NameExists>) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl DeleteOptionsDeleteBuilder65<crate::generics::NameExists> {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = DeleteOptionsDeleteBuilder65 {
+            inner: DeleteOptionsOpBuilder15Container {
+            body: crate::cli::read_from_input(matches)?,
+            param_dry_run: matches.and_then(|m| {
+                    m.value_of("dry-run").map(|_| {
+                        value_t!(m, "dry-run", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_grace_period_seconds: matches.and_then(|m| {
+                    m.value_of("grace-period-seconds").map(|_| {
+                        value_t!(m, "grace-period-seconds", i64).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_orphan_dependents: matches.and_then(|m| {
+                    m.value_of("orphan-dependents").map(|_| {
+                        value_t!(m, "orphan-dependents", bool).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_propagation_policy: matches.and_then(|m| {
+                    m.value_of("propagation-policy").map(|_| {
+                        value_t!(m, "propagation-policy", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_name: matches.and_then(|m| {
+                    m.value_of("name").map(|_| {
+                        value_t!(m, "name", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_pretty: matches.and_then(|m| {
+                    m.value_of("pretty").map(|_| {
+                        value_t!(m, "pretty", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            },
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder created by [`DeleteOptions::delete_rbac_authorization_v1beta1_namespaced_role_binding`](./struct.DeleteOptions.html#method.delete_rbac_authorization_v1beta1_namespaced_role_binding) method for a `DELETE` operation associated with `DeleteOptions`.
+pub type DeleteOptionsDeleteBuilder66<Name, Namespace> = DeleteOptionsOpBuilder16<DeleteOptionsDeleteOp66, Name, Namespace>;
+
+/// Marks the operation of [`DeleteOptionsDeleteBuilder66`] in the shared builder.
+#[derive(Debug, Clone)]
+pub enum DeleteOptionsDeleteOp66 {}
+
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for DeleteOptionsDeleteBuilder66<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    type Output = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::Status;
+
+    const METHOD: http::Method = http::Method::DELETE;
+
+    const OPERATION_ID: Option<&'static str> = Some("deleteRbacAuthorizationV1beta1NamespacedRoleBinding");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok(format!("/apis/rbac.authorization.k8s.io/v1beta1/namespaces/{namespace}/rolebindings/{name}", name=crate::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?, namespace=crate::client::path_param("namespace", self.inner.param_namespace.as_ref().expect("missing parameter namespace?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::CONTENT_TYPE.as_str(), "application/json")
+        .json(&self.inner.body)
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("dryRun", self.inner.param_dry_run.as_ref().map(std::string::ToString::to_string)),
+            ("gracePeriodSeconds", self.inner.param_grace_period_seconds.as_ref().map(std::string::ToString::to_string)),
+            ("orphanDependents", self.inner.param_orphan_dependents.as_ref().map(std::string::ToString::to_string)),
+            ("propagationPolicy", self.inner.param_propagation_policy.as_ref().map(std::string::ToString::to_string)),
+            ("pretty", self.inner.param_pretty.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Content-Type")
+            || name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<DeleteOptionsDeleteBuilder66<crate::generics::NameExists, crate::generics::NamespaceExists>> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: DeleteOptionsDeleteBuilder66<crate::generics::NameExists, crate::generics::NamespaceExists>) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl DeleteOptionsDeleteBuilder66<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = DeleteOptionsDeleteBuilder66 {
+            inner: DeleteOptionsOpBuilder16Container {
+            body: crate::cli::read_from_input(matches)?,
+            param_dry_run: matches.and_then(|m| {
+                    m.value_of("dry-run").map(|_| {
+                        value_t!(m, "dry-run", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_grace_period_seconds: matches.and_then(|m| {
+                    m.value_of("grace-period-seconds").map(|_| {
+                        value_t!(m, "grace-period-seconds", i64).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_orphan_dependents: matches.and_then(|m| {
+                    m.value_of("orphan-dependents").map(|_| {
+                        value_t!(m, "orphan-dependents", bool).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_propagation_policy: matches.and_then(|m| {
+                    m.value_of("propagation-policy").map(|_| {
+                        value_t!(m, "propagation-policy", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_name: matches.and_then(|m| {
+                    m.value_of("name").map(|_| {
+                        value_t!(m, "name", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_namespace: matches.and_then(|m| {
+                    m.value_of("namespace").map(|_| {
+                        value_t!(m, "namespace", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_pretty: matches.and_then(|m| {
+                    m.value_of("pretty").map(|_| {
+                        value_t!(m, "pretty", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            },
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder created by [`DeleteOptions::delete_rbac_authorization_v1beta1_namespaced_role`](./struct.DeleteOptions.html#method.delete_rbac_authorization_v1beta1_namespaced_role) method for a `DELETE` operation associated with `DeleteOptions`.
+pub type DeleteOptionsDeleteBuilder67<Name, Namespace> = DeleteOptionsOpBuilder17<DeleteOptionsDeleteOp67, Name, Namespace>;
+
+/// Marks the operation of [`DeleteOptionsDeleteBuilder67`] in the shared builder.
+#[derive(Debug, Clone)]
+pub enum DeleteOptionsDeleteOp67 {}
+
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for DeleteOptionsDeleteBuilder67<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    type Output = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::Status;
+
+    const METHOD: http::Method = http::Method::DELETE;
+
+    const OPERATION_ID: Option<&'static str> = Some("deleteRbacAuthorizationV1beta1NamespacedRole");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok(format!("/apis/rbac.authorization.k8s.io/v1beta1/namespaces/{namespace}/roles/{name}", name=crate::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?, namespace=crate::client::path_param("namespace", self.inner.param_namespace.as_ref().expect("missing parameter namespace?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::CONTENT_TYPE.as_str(), "application/json")
+        .json(&self.inner.body)
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("dryRun", self.inner.param_dry_run.as_ref().map(std::string::ToString::to_string)),
+            ("gracePeriodSeconds", self.inner.param_grace_period_seconds.as_ref().map(std::string::ToString::to_string)),
+            ("orphanDependents", self.inner.param_orphan_dependents.as_ref().map(std::string::ToString::to_string)),
+            ("propagationPolicy", self.inner.param_propagation_policy.as_ref().map(std::string::ToString::to_string)),
+            ("pretty", self.inner.param_pretty.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Content-Type")
+            || name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<DeleteOptionsDeleteBuilder67<crate::generics::NameExists, crate::generics::NamespaceExists>> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: DeleteOptionsDeleteBuilder67<crate::generics::NameExists, crate::generics::NamespaceExists>) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl DeleteOptionsDeleteBuilder67<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = DeleteOptionsDeleteBuilder67 {
+            inner: DeleteOptionsOpBuilder17Container {
+            body: crate::cli::read_from_input(matches)?,
+            param_dry_run: matches.and_then(|m| {
+                    m.value_of("dry-run").map(|_| {
+                        value_t!(m, "dry-run", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_grace_period_seconds: matches.and_then(|m| {
+                    m.value_of("grace-period-seconds").map(|_| {
+                        value_t!(m, "grace-period-seconds", i64).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_orphan_dependents: matches.and_then(|m| {
+                    m.value_of("orphan-dependents").map(|_| {
+                        value_t!(m, "orphan-dependents", bool).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_propagation_policy: matches.and_then(|m| {
+                    m.value_of("propagation-policy").map(|_| {
+                        value_t!(m, "propagation-policy", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_name: matches.and_then(|m| {
+                    m.value_of("name").map(|_| {
+                        value_t!(m, "name", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_namespace: matches.and_then(|m| {
+                    m.value_of("namespace").map(|_| {
+                        value_t!(m, "namespace", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_pretty: matches.and_then(|m| {
+                    m.value_of("pretty").map(|_| {
+                        value_t!(m, "pretty", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            },
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder shared by operations (with the same fields and parameters) associated with `DeleteOptions`.
+#[repr(transparent)]
+#[derive(Debug, Clone)]
+pub struct DeleteOptionsOpBuilder18<Op, Name> {
+    inner: DeleteOptionsOpBuilder18Container,
+    _op: core::marker::PhantomData<Op>,
+    _param_name: core::marker::PhantomData<Name>,
+}
+
+#[derive(Debug, Default, Clone)]
+struct DeleteOptionsOpBuilder18Container {
+    body: self::DeleteOptions,
+    param_dry_run: Option<String>,
+    param_grace_period_seconds: Option<i64>,
+    param_orphan_dependents: Option<bool>,
+    param_propagation_policy: Option<String>,
+    param_name: Option<String>,
+    param_pretty: Option<String>,
+}
+
+/// Builder created by [`DeleteOptions::delete_scheduling_v1_priority_class`](./struct.DeleteOptions.html#method.delete_scheduling_v1_priority_class) method for a `DELETE` operation associated with `DeleteOptions`.
+pub type DeleteOptionsDeleteBuilder68<Name> = DeleteOptionsOpBuilder18<DeleteOptionsDeleteOp68, Name>;
+
+/// Marks the operation of [`DeleteOptionsDeleteBuilder68`] in the shared builder.
+#[derive(Debug, Clone)]
+pub enum DeleteOptionsDeleteOp68 {}
+
+impl<Op, Name> DeleteOptionsOpBuilder18<Op, Name> {
+    /// When present, indicates that modifications should not be persisted. An invalid or unrecognized dryRun directive will result in an error response and no further processing of the request. Valid values are: - All: all dry run stages will be processed
+    #[inline]
+    pub fn dry_run(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_dry_run = Some(value.into());
+        self
+    }
+
+    /// Sets [`dry_run`](#method.dry_run) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_dry_run(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_dry_run = value.map(|value| value.into());
+        self
+    }
+
+    /// The duration in seconds before the object should be deleted. Value must be non-negative integer. The value zero indicates delete immediately. If this value is nil, the default grace period for the specified type will be used. Defaults to a per object value if not specified. zero means delete immediately.
+    #[inline]
+    pub fn grace_period_seconds(mut self, value: impl Into<i64>) -> Self {
+        self.inner.param_grace_period_seconds = Some({
+            let val = value.into();
+            self.inner.body.grace_period_seconds = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// Same as [`grace_period_seconds`](#method.grace_period_seconds), but takes a reference.
+    #[inline]
+    pub fn grace_period_seconds_ref(self, value: &i64) -> Self {
+        self.grace_period_seconds(*value)
+    }
+
+    /// Deprecated: please use the PropagationPolicy, this field will be deprecated in 1.7. Should the dependent objects be orphaned. If true/false, the "orphan" finalizer will be added to/removed from the object's finalizers list. Either this field or PropagationPolicy may be set, but not both.
+    #[inline]
+    pub fn orphan_dependents(mut self, value: impl Into<bool>) -> Self {
+        self.inner.param_orphan_dependents = Some({
+            let val = value.into();
+            self.inner.body.orphan_dependents = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// Same as [`orphan_dependents`](#method.orphan_dependents), but takes a reference.
+    #[inline]
+    pub fn orphan_dependents_ref(self, value: &bool) -> Self {
+        self.orphan_dependents(*value)
+    }
+
+    /// Whether and how garbage collection will be performed. Either this field or OrphanDependents may be set, but not both. The default policy is decided by the existing finalizer set in the metadata.finalizers and the resource-specific default policy. Acceptable values are: 'Orphan' - orphan the dependents; 'Background' - allow the garbage collector to delete the dependents in the background; 'Foreground' - a cascading policy that deletes all dependents in the foreground.
+    #[inline]
+    pub fn propagation_policy(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_propagation_policy = Some({
+            let val = value.into();
+            self.inner.body.propagation_policy = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// name of the PriorityClass
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> DeleteOptionsOpBuilder18<Op, crate::generics::NameExists> {
+        self.inner.param_name = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// If 'true', then the output is pretty printed.
+    #[inline]
+    pub fn pretty(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_pretty = Some(value.into());
+        self
+    }
+
+    /// Sets [`pretty`](#method.pretty) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_pretty(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_pretty = value.map(|value| value.into());
+        self
+    }
+
+    /// APIVersion defines the versioned schema of this representation of an object. Servers should convert recognized schemas to the latest internal value, and may reject unrecognized values. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#resources
+    #[inline]
+    pub fn api_version(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.api_version = Some(value.into());
+        self
+    }
+
+    /// Sets [`api_version`](#method.api_version) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_api_version(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.api_version = value.map(|value| value.into());
+        self
+    }
+
+    /// Kind is a string value representing the REST resource this object represents. Servers may infer this from the endpoint the client submits requests to. Cannot be updated. In CamelCase. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#types-kinds
+    #[inline]
+    pub fn kind(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.kind = Some(value.into());
+        self
+    }
+
+    /// Sets [`kind`](#method.kind) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_kind(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.kind = value.map(|value| value.into());
+        self
+    }
+
+    /// Must be fulfilled before a deletion is carried out. If not possible, a 409 Conflict status will be returned.
+    #[inline]
+    pub fn preconditions(mut self, value: crate::io::k8s::apimachinery::pkg::apis::meta::v1::preconditions::Preconditions) -> Self {
+        self.inner.body.preconditions = Some(value.into());
+        self
+    }
+
+    /// Sets [`preconditions`](#method.preconditions) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_preconditions(mut self, value: Option<crate::io::k8s::apimachinery::pkg::apis::meta::v1::preconditions::Preconditions>) -> Self {
+        self.inner.body.preconditions = value.map(|value| value.into());
+        self
+    }
+}
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for DeleteOptionsDeleteBuilder68<crate::generics::NameExists> {
+    type Output = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::Status;
+
+    const METHOD: http::Method = http::Method::DELETE;
+
+    const OPERATION_ID: Option<&'static str> = Some("deleteSchedulingV1PriorityClass");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok(format!("/apis/scheduling.k8s.io/v1/priorityclasses/{name}", name=crate::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::CONTENT_TYPE.as_str(), "application/json")
+        .json(&self.inner.body)
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("dryRun", self.inner.param_dry_run.as_ref().map(std::string::ToString::to_string)),
+            ("gracePeriodSeconds", self.inner.param_grace_period_seconds.as_ref().map(std::string::ToString::to_string)),
+            ("orphanDependents", self.inner.param_orphan_dependents.as_ref().map(std::string::ToString::to_string)),
+            ("propagationPolicy", self.inner.param_propagation_policy.as_ref().map(std::string::ToString::to_string)),
+            ("pretty", self.inner.param_pretty.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Content-Type")
+            || name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<DeleteOptionsDeleteBuilder68<crate::generics::NameExists>> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: DeleteOptionsDeleteBuilder68<crate::generics::NameExists>) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl DeleteOptionsDeleteBuilder68<crate::generics::NameExists> {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = DeleteOptionsDeleteBuilder68 {
+            inner: DeleteOptionsOpBuilder18Container {
+            body: crate::cli::read_from_input(matches)?,
+            param_dry_run: matches.and_then(|m| {
+                    m.value_of("dry-run").map(|_| {
+                        value_t!(m, "dry-run", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_grace_period_seconds: matches.and_then(|m| {
+                    m.value_of("grace-period-seconds").map(|_| {
+                        value_t!(m, "grace-period-seconds", i64).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_orphan_dependents: matches.and_then(|m| {
+                    m.value_of("orphan-dependents").map(|_| {
+                        value_t!(m, "orphan-dependents", bool).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_propagation_policy: matches.and_then(|m| {
+                    m.value_of("propagation-policy").map(|_| {
+                        value_t!(m, "propagation-policy", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_name: matches.and_then(|m| {
+                    m.value_of("name").map(|_| {
+                        value_t!(m, "name", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_pretty: matches.and_then(|m| {
+                    m.value_of("pretty").map(|_| {
+                        value_t!(m, "pretty", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            },
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder created by [`DeleteOptions::delete_scheduling_v1alpha1_priority_class`](./struct.DeleteOptions.html#method.delete_scheduling_v1alpha1_priority_class) method for a `DELETE` operation associated with `DeleteOptions`.
+pub type DeleteOptionsDeleteBuilder69<Name> = DeleteOptionsOpBuilder18<DeleteOptionsDeleteOp69, Name>;
+
+/// Marks the operation of [`DeleteOptionsDeleteBuilder69`] in the shared builder.
+#[derive(Debug, Clone)]
+pub enum DeleteOptionsDeleteOp69 {}
+
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for DeleteOptionsDeleteBuilder69<crate::generics::NameExists> {
+    type Output = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::Status;
+
+    const METHOD: http::Method = http::Method::DELETE;
+
+    const OPERATION_ID: Option<&'static str> = Some("deleteSchedulingV1alpha1PriorityClass");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok(format!("/apis/scheduling.k8s.io/v1alpha1/priorityclasses/{name}", name=crate::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::CONTENT_TYPE.as_str(), "application/json")
+        .json(&self.inner.body)
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("dryRun", self.inner.param_dry_run.as_ref().map(std::string::ToString::to_string)),
+            ("gracePeriodSeconds", self.inner.param_grace_period_seconds.as_ref().map(std::string::ToString::to_string)),
+            ("orphanDependents", self.inner.param_orphan_dependents.as_ref().map(std::string::ToString::to_string)),
+            ("propagationPolicy", self.inner.param_propagation_policy.as_ref().map(std::string::ToString::to_string)),
+            ("pretty", self.inner.param_pretty.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Content-Type")
+            || name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<DeleteOptionsDeleteBuilder69<crate::generics::NameExists>> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: DeleteOptionsDeleteBuilder69<crate::generics::NameExists>) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl DeleteOptionsDeleteBuilder69<crate::generics::NameExists> {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = DeleteOptionsDeleteBuilder69 {
+            inner: DeleteOptionsOpBuilder18Container {
+            body: crate::cli::read_from_input(matches)?,
+            param_dry_run: matches.and_then(|m| {
+                    m.value_of("dry-run").map(|_| {
+                        value_t!(m, "dry-run", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_grace_period_seconds: matches.and_then(|m| {
+                    m.value_of("grace-period-seconds").map(|_| {
+                        value_t!(m, "grace-period-seconds", i64).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_orphan_dependents: matches.and_then(|m| {
+                    m.value_of("orphan-dependents").map(|_| {
+                        value_t!(m, "orphan-dependents", bool).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_propagation_policy: matches.and_then(|m| {
+                    m.value_of("propagation-policy").map(|_| {
+                        value_t!(m, "propagation-policy", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_name: matches.and_then(|m| {
+                    m.value_of("name").map(|_| {
+                        value_t!(m, "name", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_pretty: matches.and_then(|m| {
+                    m.value_of("pretty").map(|_| {
+                        value_t!(m, "pretty", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            },
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder created by [`DeleteOptions::delete_scheduling_v1beta1_priority_class`](./struct.DeleteOptions.html#method.delete_scheduling_v1beta1_priority_class) method for a `DELETE` operation associated with `DeleteOptions`.
+pub type DeleteOptionsDeleteBuilder70<Name> = DeleteOptionsOpBuilder18<DeleteOptionsDeleteOp70, Name>;
+
+/// Marks the operation of [`DeleteOptionsDeleteBuilder70`] in the shared builder.
+#[derive(Debug, Clone)]
+pub enum DeleteOptionsDeleteOp70 {}
+
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for DeleteOptionsDeleteBuilder70<crate::generics::NameExists> {
+    type Output = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::Status;
+
+    const METHOD: http::Method = http::Method::DELETE;
+
+    const OPERATION_ID: Option<&'static str> = Some("deleteSchedulingV1beta1PriorityClass");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok(format!("/apis/scheduling.k8s.io/v1beta1/priorityclasses/{name}", name=crate::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::CONTENT_TYPE.as_str(), "application/json")
+        .json(&self.inner.body)
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("dryRun", self.inner.param_dry_run.as_ref().map(std::string::ToString::to_string)),
+            ("gracePeriodSeconds", self.inner.param_grace_period_seconds.as_ref().map(std::string::ToString::to_string)),
+            ("orphanDependents", self.inner.param_orphan_dependents.as_ref().map(std::string::ToString::to_string)),
+            ("propagationPolicy", self.inner.param_propagation_policy.as_ref().map(std::string::ToString::to_string)),
+            ("pretty", self.inner.param_pretty.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Content-Type")
+            || name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<DeleteOptionsDeleteBuilder70<crate::generics::NameExists>> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: DeleteOptionsDeleteBuilder70<crate::generics::NameExists>) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl DeleteOptionsDeleteBuilder70<crate::generics::NameExists> {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = DeleteOptionsDeleteBuilder70 {
+            inner: DeleteOptionsOpBuilder18Container {
+            body: crate::cli::read_from_input(matches)?,
+            param_dry_run: matches.and_then(|m| {
+                    m.value_of("dry-run").map(|_| {
+                        value_t!(m, "dry-run", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_grace_period_seconds: matches.and_then(|m| {
+                    m.value_of("grace-period-seconds").map(|_| {
+                        value_t!(m, "grace-period-seconds", i64).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_orphan_dependents: matches.and_then(|m| {
+                    m.value_of("orphan-dependents").map(|_| {
+                        value_t!(m, "orphan-dependents", bool).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_propagation_policy: matches.and_then(|m| {
+                    m.value_of("propagation-policy").map(|_| {
+                        value_t!(m, "propagation-policy", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_name: matches.and_then(|m| {
+                    m.value_of("name").map(|_| {
+                        value_t!(m, "name", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_pretty: matches.and_then(|m| {
+                    m.value_of("pretty").map(|_| {
+                        value_t!(m, "pretty", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            },
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder created by [`DeleteOptions::delete_settings_v1alpha1_namespaced_pod_preset`](./struct.DeleteOptions.html#method.delete_settings_v1alpha1_namespaced_pod_preset) method for a `DELETE` operation associated with `DeleteOptions`.
+#[repr(transparent)]
+#[derive(Debug, Clone)]
+pub struct DeleteOptionsDeleteBuilder71<Name, Namespace> {
+    inner: DeleteOptionsDeleteBuilder71Container,
+    _param_name: core::marker::PhantomData<Name>,
+    _param_namespace: core::marker::PhantomData<Namespace>,
+}
+
+#[derive(Debug, Default, Clone)]
+struct DeleteOptionsDeleteBuilder71Container {
+    body: self::DeleteOptions,
+    param_dry_run: Option<String>,
+    param_grace_period_seconds: Option<i64>,
+    param_orphan_dependents: Option<bool>,
+    param_propagation_policy: Option<String>,
+    param_name: Option<String>,
+    param_namespace: Option<String>,
+    param_pretty: Option<String>,
+}
+
+impl<Name, Namespace> DeleteOptionsDeleteBuilder71<Name, Namespace> {
+    /// When present, indicates that modifications should not be persisted. An invalid or unrecognized dryRun directive will result in an error response and no further processing of the request. Valid values are: - All: all dry run stages will be processed
+    #[inline]
+    pub fn dry_run(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_dry_run = Some(value.into());
+        self
+    }
+
+    /// Sets [`dry_run`](#method.dry_run) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_dry_run(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_dry_run = value.map(|value| value.into());
+        self
+    }
+
+    /// The duration in seconds before the object should be deleted. Value must be non-negative integer. The value zero indicates delete immediately. If this value is nil, the default grace period for the specified type will be used. Defaults to a per object value if not specified. zero means delete immediately.
+    #[inline]
+    pub fn grace_period_seconds(mut self, value: impl Into<i64>) -> Self {
+        self.inner.param_grace_period_seconds = Some({
+            let val = value.into();
+            self.inner.body.grace_period_seconds = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// Same as [`grace_period_seconds`](#method.grace_period_seconds), but takes a reference.
+    #[inline]
+    pub fn grace_period_seconds_ref(self, value: &i64) -> Self {
+        self.grace_period_seconds(*value)
+    }
+
+    /// Deprecated: please use the PropagationPolicy, this field will be deprecated in 1.7. Should the dependent objects be orphaned. If true/false, the "orphan" finalizer will be added to/removed from the object's finalizers list. Either this field or PropagationPolicy may be set, but not both.
+    #[inline]
+    pub fn orphan_dependents(mut self, value: impl Into<bool>) -> Self {
+        self.inner.param_orphan_dependents = Some({
+            let val = value.into();
+            self.inner.body.orphan_dependents = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// Same as [`orphan_dependents`](#method.orphan_dependents), but takes a reference.
+    #[inline]
+    pub fn orphan_dependents_ref(self, value: &bool) -> Self {
+        self.orphan_dependents(*value)
+    }
+
+    /// Whether and how garbage collection will be performed. Either this field or OrphanDependents may be set, but not both. The default policy is decided by the existing finalizer set in the metadata.finalizers and the resource-specific default policy. Acceptable values are: 'Orphan' - orphan the dependents; 'Background' - allow the garbage collector to delete the dependents in the background; 'Foreground' - a cascading policy that deletes all dependents in the foreground.
+    #[inline]
+    pub fn propagation_policy(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_propagation_policy = Some({
+            let val = value.into();
+            self.inner.body.propagation_policy = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// name of the PodPreset
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> DeleteOptionsDeleteBuilder71<crate::generics::NameExists, Namespace> {
+        self.inner.param_name = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// object name and auth scope, such as for teams and projects
+    #[inline]
+    pub fn namespace(mut self, value: impl Into<String>) -> DeleteOptionsDeleteBuilder71<Name, crate::generics::NamespaceExists> {
+        self.inner.param_namespace = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// If 'true', then the output is pretty printed.
+    #[inline]
+    pub fn pretty(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_pretty = Some(value.into());
+        self
+    }
+
+    /// Sets [`pretty`](#method.pretty) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_pretty(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_pretty = value.map(|value| value.into());
+        self
+    }
+
+    /// APIVersion defines the versioned schema of this representation of an object. Servers should convert recognized schemas to the latest internal value, and may reject unrecognized values. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#resources
+    #[inline]
+    pub fn api_version(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.api_version = Some(value.into());
+        self
+    }
+
+    /// Sets [`api_version`](#method.api_version) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_api_version(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.api_version = value.map(|value| value.into());
+        self
+    }
+
+    /// Kind is a string value representing the REST resource this object represents. Servers may infer this from the endpoint the client submits requests to. Cannot be updated. In CamelCase. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#types-kinds
+    #[inline]
+    pub fn kind(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.kind = Some(value.into());
+        self
+    }
+
+    /// Sets [`kind`](#method.kind) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_kind(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.kind = value.map(|value| value.into());
+        self
+    }
+
+    /// Must be fulfilled before a deletion is carried out. If not possible, a 409 Conflict status will be returned.
+    #[inline]
+    pub fn preconditions(mut self, value: crate::io::k8s::apimachinery::pkg::apis::meta::v1::preconditions::Preconditions) -> Self {
+        self.inner.body.preconditions = Some(value.into());
+        self
+    }
+
+    /// Sets [`preconditions`](#method.preconditions) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_preconditions(mut self, value: Option<crate::io::k8s::apimachinery::pkg::apis::meta::v1::preconditions::Preconditions>) -> Self {
+        self.inner.body.preconditions = value.map(|value| value.into());
+        self
+    }
+}
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for DeleteOptionsDeleteBuilder71<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    type Output = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::Status;
+
+    const METHOD: http::Method = http::Method::DELETE;
+
+    const OPERATION_ID: Option<&'static str> = Some("deleteSettingsV1alpha1NamespacedPodPreset");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok(format!("/apis/settings.k8s.io/v1alpha1/namespaces/{namespace}/podpresets/{name}", name=crate::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?, namespace=crate::client::path_param("namespace", self.inner.param_namespace.as_ref().expect("missing parameter namespace?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::CONTENT_TYPE.as_str(), "application/json")
+        .json(&self.inner.body)
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("dryRun", self.inner.param_dry_run.as_ref().map(std::string::ToString::to_string)),
+            ("gracePeriodSeconds", self.inner.param_grace_period_seconds.as_ref().map(std::string::ToString::to_string)),
+            ("orphanDependents", self.inner.param_orphan_dependents.as_ref().map(std::string::ToString::to_string)),
+            ("propagationPolicy", self.inner.param_propagation_policy.as_ref().map(std::string::ToString::to_string)),
+            ("pretty", self.inner.param_pretty.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Content-Type")
+            || name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<DeleteOptionsDeleteBuilder71<crate::generics::NameExists, crate::generics::NamespaceExists>> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: DeleteOptionsDeleteBuilder71<crate::generics::NameExists, crate::generics::NamespaceExists>) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl DeleteOptionsDeleteBuilder71<crate::generics::NameExists, crate::generics::NamespaceExists> {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = DeleteOptionsDeleteBuilder71 {
+            inner: DeleteOptionsDeleteBuilder71Container {
+            body: crate::cli::read_from_input(matches)?,
+            param_dry_run: matches.and_then(|m| {
+                    m.value_of("dry-run").map(|_| {
+                        value_t!(m, "dry-run", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_grace_period_seconds: matches.and_then(|m| {
+                    m.value_of("grace-period-seconds").map(|_| {
+                        value_t!(m, "grace-period-seconds", i64).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_orphan_dependents: matches.and_then(|m| {
+                    m.value_of("orphan-dependents").map(|_| {
+                        value_t!(m, "orphan-dependents", bool).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_propagation_policy: matches.and_then(|m| {
+                    m.value_of("propagation-policy").map(|_| {
+                        value_t!(m, "propagation-policy", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_name: matches.and_then(|m| {
+                    m.value_of("name").map(|_| {
+                        value_t!(m, "name", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_namespace: matches.and_then(|m| {
+                    m.value_of("namespace").map(|_| {
+                        value_t!(m, "namespace", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_pretty: matches.and_then(|m| {
+                    m.value_of("pretty").map(|_| {
+                        value_t!(m, "pretty", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            },
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder shared by operations (with the same fields and parameters) associated with `DeleteOptions`.
+#[repr(transparent)]
+#[derive(Debug, Clone)]
+pub struct DeleteOptionsOpBuilder19<Op, Name> {
+    inner: DeleteOptionsOpBuilder19Container,
+    _op: core::marker::PhantomData<Op>,
+    _param_name: core::marker::PhantomData<Name>,
+}
+
+#[derive(Debug, Default, Clone)]
+struct DeleteOptionsOpBuilder19Container {
+    body: self::DeleteOptions,
+    param_dry_run: Option<String>,
+    param_grace_period_seconds: Option<i64>,
+    param_orphan_dependents: Option<bool>,
+    param_propagation_policy: Option<String>,
+    param_name: Option<String>,
+    param_pretty: Option<String>,
+}
+
+/// Builder created by [`DeleteOptions::delete_storage_v1_storage_class`](./struct.DeleteOptions.html#method.delete_storage_v1_storage_class) method for a `DELETE` operation associated with `DeleteOptions`.
+pub type DeleteOptionsDeleteBuilder72<Name> = DeleteOptionsOpBuilder19<DeleteOptionsDeleteOp72, Name>;
+
+/// Marks the operation of [`DeleteOptionsDeleteBuilder72`] in the shared builder.
+#[derive(Debug, Clone)]
+pub enum DeleteOptionsDeleteOp72 {}
+
+impl<Op, Name> DeleteOptionsOpBuilder19<Op, Name> {
+    /// When present, indicates that modifications should not be persisted. An invalid or unrecognized dryRun directive will result in an error response and no further processing of the request. Valid values are: - All: all dry run stages will be processed
+    #[inline]
+    pub fn dry_run(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_dry_run = Some(value.into());
+        self
+    }
+
+    /// Sets [`dry_run`](#method.dry_run) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_dry_run(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_dry_run = value.map(|value| value.into());
+        self
+    }
+
+    /// The duration in seconds before the object should be deleted. Value must be non-negative integer. The value zero indicates delete immediately. If this value is nil, the default grace period for the specified type will be used. Defaults to a per object value if not specified. zero means delete immediately.
+    #[inline]
+    pub fn grace_period_seconds(mut self, value: impl Into<i64>) -> Self {
+        self.inner.param_grace_period_seconds = Some({
+            let val = value.into();
+            self.inner.body.grace_period_seconds = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// Same as [`grace_period_seconds`](#method.grace_period_seconds), but takes a reference.
+    #[inline]
+    pub fn grace_period_seconds_ref(self, value: &i64) -> Self {
+        self.grace_period_seconds(*value)
+    }
+
+    /// Deprecated: please use the PropagationPolicy, this field will be deprecated in 1.7. Should the dependent objects be orphaned. If true/false, the "orphan" finalizer will be added to/removed from the object's finalizers list. Either this field or PropagationPolicy may be set, but not both.
+    #[inline]
+    pub fn orphan_dependents(mut self, value: impl Into<bool>) -> Self {
+        self.inner.param_orphan_dependents = Some({
+            let val = value.into();
+            self.inner.body.orphan_dependents = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// Same as [`orphan_dependents`](#method.orphan_dependents), but takes a reference.
+    #[inline]
+    pub fn orphan_dependents_ref(self, value: &bool) -> Self {
+        self.orphan_dependents(*value)
+    }
+
+    /// Whether and how garbage collection will be performed. Either this field or OrphanDependents may be set, but not both. The default policy is decided by the existing finalizer set in the metadata.finalizers and the resource-specific default policy. Acceptable values are: 'Orphan' - orphan the dependents; 'Background' - allow the garbage collector to delete the dependents in the background; 'Foreground' - a cascading policy that deletes all dependents in the foreground.
+    #[inline]
+    pub fn propagation_policy(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_propagation_policy = Some({
+            let val = value.into();
+            self.inner.body.propagation_policy = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// name of the StorageClass
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> DeleteOptionsOpBuilder19<Op, crate::generics::NameExists> {
+        self.inner.param_name = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// If 'true', then the output is pretty printed.
+    #[inline]
+    pub fn pretty(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_pretty = Some(value.into());
+        self
+    }
+
+    /// Sets [`pretty`](#method.pretty) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_pretty(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_pretty = value.map(|value| value.into());
+        self
+    }
+
+    /// APIVersion defines the versioned schema of this representation of an object. Servers should convert recognized schemas to the latest internal value, and may reject unrecognized values. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#resources
+    #[inline]
+    pub fn api_version(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.api_version = Some(value.into());
+        self
+    }
+
+    /// Sets [`api_version`](#method.api_version) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_api_version(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.api_version = value.map(|value| value.into());
+        self
+    }
+
+    /// Kind is a string value representing the REST resource this object represents. Servers may infer this from the endpoint the client submits requests to. Cannot be updated. In CamelCase. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#types-kinds
+    #[inline]
+    pub fn kind(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.kind = Some(value.into());
+        self
+    }
+
+    /// Sets [`kind`](#method.kind) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_kind(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.kind = value.map(|value| value.into());
+        self
+    }
+
+    /// Must be fulfilled before a deletion is carried out. If not possible, a 409 Conflict status will be returned.
+    #[inline]
+    pub fn preconditions(mut self, value: crate::io::k8s::apimachinery::pkg::apis::meta::v1::preconditions::Preconditions) -> Self {
+        self.inner.body.preconditions = Some(value.into());
+        self
+    }
+
+    /// Sets [`preconditions`](#method.preconditions) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_preconditions(mut self, value: Option<crate::io::k8s::apimachinery::pkg::apis::meta::v1::preconditions::Preconditions>) -> Self {
+        self.inner.body.preconditions = value.map(|value| value.into());
+        self
+    }
+}
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for DeleteOptionsDeleteBuilder72<crate::generics::NameExists> {
+    type Output = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::Status;
+
+    const METHOD: http::Method = http::Method::DELETE;
+
+    const OPERATION_ID: Option<&'static str> = Some("deleteStorageV1StorageClass");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok(format!("/apis/storage.k8s.io/v1/storageclasses/{name}", name=crate::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::CONTENT_TYPE.as_str(), "application/json")
+        .json(&self.inner.body)
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("dryRun", self.inner.param_dry_run.as_ref().map(std::string::ToString::to_string)),
+            ("gracePeriodSeconds", self.inner.param_grace_period_seconds.as_ref().map(std::string::ToString::to_string)),
+            ("orphanDependents", self.inner.param_orphan_dependents.as_ref().map(std::string::ToString::to_string)),
+            ("propagationPolicy", self.inner.param_propagation_policy.as_ref().map(std::string::ToString::to_string)),
+            ("pretty", self.inner.param_pretty.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Content-Type")
+            || name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<DeleteOptionsDeleteBuilder72<crate::generics::NameExists>> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: DeleteOptionsDeleteBuilder72<crate::generics::NameExists>) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl DeleteOptionsDeleteBuilder72<crate::generics::NameExists> {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = DeleteOptionsDeleteBuilder72 {
+            inner: DeleteOptionsOpBuilder19Container {
+            body: crate::cli::read_from_input(matches)?,
+            param_dry_run: matches.and_then(|m| {
+                    m.value_of("dry-run").map(|_| {
+                        value_t!(m, "dry-run", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_grace_period_seconds: matches.and_then(|m| {
+                    m.value_of("grace-period-seconds").map(|_| {
+                        value_t!(m, "grace-period-seconds", i64).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_orphan_dependents: matches.and_then(|m| {
+                    m.value_of("orphan-dependents").map(|_| {
+                        value_t!(m, "orphan-dependents", bool).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_propagation_policy: matches.and_then(|m| {
+                    m.value_of("propagation-policy").map(|_| {
+                        value_t!(m, "propagation-policy", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_name: matches.and_then(|m| {
+                    m.value_of("name").map(|_| {
+                        value_t!(m, "name", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_pretty: matches.and_then(|m| {
+                    m.value_of("pretty").map(|_| {
+                        value_t!(m, "pretty", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            },
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder shared by operations (with the same fields and parameters) associated with `DeleteOptions`.
+#[repr(transparent)]
+#[derive(Debug, Clone)]
+pub struct DeleteOptionsOpBuilder20<Op, Name> {
+    inner: DeleteOptionsOpBuilder20Container,
+    _op: core::marker::PhantomData<Op>,
+    _param_name: core::marker::PhantomData<Name>,
+}
+
+#[derive(Debug, Default, Clone)]
+struct DeleteOptionsOpBuilder20Container {
+    body: self::DeleteOptions,
+    param_dry_run: Option<String>,
+    param_grace_period_seconds: Option<i64>,
+    param_orphan_dependents: Option<bool>,
+    param_propagation_policy: Option<String>,
+    param_name: Option<String>,
+    param_pretty: Option<String>,
+}
+
+/// Builder created by [`DeleteOptions::delete_storage_v1_volume_attachment`](./struct.DeleteOptions.html#method.delete_storage_v1_volume_attachment) method for a `DELETE` operation associated with `DeleteOptions`.
+pub type DeleteOptionsDeleteBuilder73<Name> = DeleteOptionsOpBuilder20<DeleteOptionsDeleteOp73, Name>;
+
+/// Marks the operation of [`DeleteOptionsDeleteBuilder73`] in the shared builder.
+#[derive(Debug, Clone)]
+pub enum DeleteOptionsDeleteOp73 {}
+
+impl<Op, Name> DeleteOptionsOpBuilder20<Op, Name> {
+    /// When present, indicates that modifications should not be persisted. An invalid or unrecognized dryRun directive will result in an error response and no further processing of the request. Valid values are: - All: all dry run stages will be processed
+    #[inline]
+    pub fn dry_run(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_dry_run = Some(value.into());
+        self
+    }
+
+    /// Sets [`dry_run`](#method.dry_run) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_dry_run(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_dry_run = value.map(|value| value.into());
+        self
+    }
+
+    /// The duration in seconds before the object should be deleted. Value must be non-negative integer. The value zero indicates delete immediately. If this value is nil, the default grace period for the specified type will be used. Defaults to a per object value if not specified. zero means delete immediately.
+    #[inline]
+    pub fn grace_period_seconds(mut self, value: impl Into<i64>) -> Self {
+        self.inner.param_grace_period_seconds = Some({
+            let val = value.into();
+            self.inner.body.grace_period_seconds = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// Same as [`grace_period_seconds`](#method.grace_period_seconds), but takes a reference.
+    #[inline]
+    pub fn grace_period_seconds_ref(self, value: &i64) -> Self {
+        self.grace_period_seconds(*value)
+    }
+
+    /// Deprecated: please use the PropagationPolicy, this field will be deprecated in 1.7. Should the dependent objects be orphaned. If true/false, the "orphan" finalizer will be added to/removed from the object's finalizers list. Either this field or PropagationPolicy may be set, but not both.
+    #[inline]
+    pub fn orphan_dependents(mut self, value: impl Into<bool>) -> Self {
+        self.inner.param_orphan_dependents = Some({
+            let val = value.into();
+            self.inner.body.orphan_dependents = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// Same as [`orphan_dependents`](#method.orphan_dependents), but takes a reference.
+    #[inline]
+    pub fn orphan_dependents_ref(self, value: &bool) -> Self {
+        self.orphan_dependents(*value)
+    }
+
+    /// Whether and how garbage collection will be performed. Either this field or OrphanDependents may be set, but not both. The default policy is decided by the existing finalizer set in the metadata.finalizers and the resource-specific default policy. Acceptable values are: 'Orphan' - orphan the dependents; 'Background' - allow the garbage collector to delete the dependents in the background; 'Foreground' - a cascading policy that deletes all dependents in the foreground.
+    #[inline]
+    pub fn propagation_policy(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_propagation_policy = Some({
+            let val = value.into();
+            self.inner.body.propagation_policy = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// name of the VolumeAttachment
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> DeleteOptionsOpBuilder20<Op, crate::generics::NameExists> {
+        self.inner.param_name = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// If 'true', then the output is pretty printed.
+    #[inline]
+    pub fn pretty(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_pretty = Some(value.into());
+        self
+    }
+
+    /// Sets [`pretty`](#method.pretty) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_pretty(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_pretty = value.map(|value| value.into());
+        self
+    }
+
+    /// APIVersion defines the versioned schema of this representation of an object. Servers should convert recognized schemas to the latest internal value, and may reject unrecognized values. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#resources
+    #[inline]
+    pub fn api_version(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.api_version = Some(value.into());
+        self
+    }
+
+    /// Sets [`api_version`](#method.api_version) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_api_version(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.api_version = value.map(|value| value.into());
+        self
+    }
+
+    /// Kind is a string value representing the REST resource this object represents. Servers may infer this from the endpoint the client submits requests to. Cannot be updated. In CamelCase. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#types-kinds
+    #[inline]
+    pub fn kind(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.kind = Some(value.into());
+        self
+    }
+
+    /// Sets [`kind`](#method.kind) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_kind(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.kind = value.map(|value| value.into());
+        self
+    }
+
+    /// Must be fulfilled before a deletion is carried out. If not possible, a 409 Conflict status will be returned.
+    #[inline]
+    pub fn preconditions(mut self, value: crate::io::k8s::apimachinery::pkg::apis::meta::v1::preconditions::Preconditions) -> Self {
+        self.inner.body.preconditions = Some(value.into());
+        self
+    }
+
+    /// Sets [`preconditions`](#method.preconditions) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_preconditions(mut self, value: Option<crate::io::k8s::apimachinery::pkg::apis::meta::v1::preconditions::Preconditions>) -> Self {
+        self.inner.body.preconditions = value.map(|value| value.into());
+        self
+    }
+}
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for DeleteOptionsDeleteBuilder73<crate::generics::NameExists> {
+    type Output = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::Status;
+
+    const METHOD: http::Method = http::Method::DELETE;
+
+    const OPERATION_ID: Option<&'static str> = Some("deleteStorageV1VolumeAttachment");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok(format!("/apis/storage.k8s.io/v1/volumeattachments/{name}", name=crate::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::CONTENT_TYPE.as_str(), "application/json")
+        .json(&self.inner.body)
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("dryRun", self.inner.param_dry_run.as_ref().map(std::string::ToString::to_string)),
+            ("gracePeriodSeconds", self.inner.param_grace_period_seconds.as_ref().map(std::string::ToString::to_string)),
+            ("orphanDependents", self.inner.param_orphan_dependents.as_ref().map(std::string::ToString::to_string)),
+            ("propagationPolicy", self.inner.param_propagation_policy.as_ref().map(std::string::ToString::to_string)),
+            ("pretty", self.inner.param_pretty.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Content-Type")
+            || name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<DeleteOptionsDeleteBuilder73<crate::generics::NameExists>> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: DeleteOptionsDeleteBuilder73<crate::generics::NameExists>) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl DeleteOptionsDeleteBuilder73<crate::generics::NameExists> {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = DeleteOptionsDeleteBuilder73 {
+            inner: DeleteOptionsOpBuilder20Container {
+            body: crate::cli::read_from_input(matches)?,
+            param_dry_run: matches.and_then(|m| {
+                    m.value_of("dry-run").map(|_| {
+                        value_t!(m, "dry-run", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_grace_period_seconds: matches.and_then(|m| {
+                    m.value_of("grace-period-seconds").map(|_| {
+                        value_t!(m, "grace-period-seconds", i64).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_orphan_dependents: matches.and_then(|m| {
+                    m.value_of("orphan-dependents").map(|_| {
+                        value_t!(m, "orphan-dependents", bool).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_propagation_policy: matches.and_then(|m| {
+                    m.value_of("propagation-policy").map(|_| {
+                        value_t!(m, "propagation-policy", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_name: matches.and_then(|m| {
+                    m.value_of("name").map(|_| {
+                        value_t!(m, "name", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_pretty: matches.and_then(|m| {
+                    m.value_of("pretty").map(|_| {
+                        value_t!(m, "pretty", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            },
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder created by [`DeleteOptions::delete_storage_v1alpha1_volume_attachment`](./struct.DeleteOptions.html#method.delete_storage_v1alpha1_volume_attachment) method for a `DELETE` operation associated with `DeleteOptions`.
+pub type DeleteOptionsDeleteBuilder74<Name> = DeleteOptionsOpBuilder20<DeleteOptionsDeleteOp74, Name>;
+
+/// Marks the operation of [`DeleteOptionsDeleteBuilder74`] in the shared builder.
+#[derive(Debug, Clone)]
+pub enum DeleteOptionsDeleteOp74 {}
+
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for DeleteOptionsDeleteBuilder74<crate::generics::NameExists> {
+    type Output = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::Status;
+
+    const METHOD: http::Method = http::Method::DELETE;
+
+    const OPERATION_ID: Option<&'static str> = Some("deleteStorageV1alpha1VolumeAttachment");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok(format!("/apis/storage.k8s.io/v1alpha1/volumeattachments/{name}", name=crate::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::CONTENT_TYPE.as_str(), "application/json")
+        .json(&self.inner.body)
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("dryRun", self.inner.param_dry_run.as_ref().map(std::string::ToString::to_string)),
+            ("gracePeriodSeconds", self.inner.param_grace_period_seconds.as_ref().map(std::string::ToString::to_string)),
+            ("orphanDependents", self.inner.param_orphan_dependents.as_ref().map(std::string::ToString::to_string)),
+            ("propagationPolicy", self.inner.param_propagation_policy.as_ref().map(std::string::ToString::to_string)),
+            ("pretty", self.inner.param_pretty.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Content-Type")
+            || name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<DeleteOptionsDeleteBuilder74<crate::generics::NameExists>> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: DeleteOptionsDeleteBuilder74<crate::generics::NameExists>) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl DeleteOptionsDeleteBuilder74<crate::generics::NameExists> {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = DeleteOptionsDeleteBuilder74 {
+            inner: DeleteOptionsOpBuilder20Container {
+            body: crate::cli::read_from_input(matches)?,
+            param_dry_run: matches.and_then(|m| {
+                    m.value_of("dry-run").map(|_| {
+                        value_t!(m, "dry-run", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_grace_period_seconds: matches.and_then(|m| {
+                    m.value_of("grace-period-seconds").map(|_| {
+                        value_t!(m, "grace-period-seconds", i64).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_orphan_dependents: matches.and_then(|m| {
+                    m.value_of("orphan-dependents").map(|_| {
+                        value_t!(m, "orphan-dependents", bool).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_propagation_policy: matches.and_then(|m| {
+                    m.value_of("propagation-policy").map(|_| {
+                        value_t!(m, "propagation-policy", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_name: matches.and_then(|m| {
+                    m.value_of("name").map(|_| {
+                        value_t!(m, "name", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_pretty: matches.and_then(|m| {
+                    m.value_of("pretty").map(|_| {
+                        value_t!(m, "pretty", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            },
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder created by [`DeleteOptions::delete_storage_v1beta1_csi_driver`](./struct.DeleteOptions.html#method.delete_storage_v1beta1_csi_driver) method for a `DELETE` operation associated with `DeleteOptions`.
+#[repr(transparent)]
+#[derive(Debug, Clone)]
+pub struct DeleteOptionsDeleteBuilder75<Name> {
+    inner: DeleteOptionsDeleteBuilder75Container,
+    _param_name: core::marker::PhantomData<Name>,
+}
+
+#[derive(Debug, Default, Clone)]
+struct DeleteOptionsDeleteBuilder75Container {
+    body: self::DeleteOptions,
+    param_dry_run: Option<String>,
+    param_grace_period_seconds: Option<i64>,
+    param_orphan_dependents: Option<bool>,
+    param_propagation_policy: Option<String>,
+    param_name: Option<String>,
+    param_pretty: Option<String>,
+}
+
+impl<Name> DeleteOptionsDeleteBuilder75<Name> {
+    /// When present, indicates that modifications should not be persisted. An invalid or unrecognized dryRun directive will result in an error response and no further processing of the request. Valid values are: - All: all dry run stages will be processed
+    #[inline]
+    pub fn dry_run(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_dry_run = Some(value.into());
+        self
+    }
+
+    /// Sets [`dry_run`](#method.dry_run) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_dry_run(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_dry_run = value.map(|value| value.into());
+        self
+    }
+
+    /// The duration in seconds before the object should be deleted. Value must be non-negative integer. The value zero indicates delete immediately. If this value is nil, the default grace period for the specified type will be used. Defaults to a per object value if not specified. zero means delete immediately.
+    #[inline]
+    pub fn grace_period_seconds(mut self, value: impl Into<i64>) -> Self {
+        self.inner.param_grace_period_seconds = Some({
+            let val = value.into();
+            self.inner.body.grace_period_seconds = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// Same as [`grace_period_seconds`](#method.grace_period_seconds), but takes a reference.
+    #[inline]
+    pub fn grace_period_seconds_ref(self, value: &i64) -> Self {
+        self.grace_period_seconds(*value)
+    }
+
+    /// Deprecated: please use the PropagationPolicy, this field will be deprecated in 1.7. Should the dependent objects be orphaned. If true/false, the "orphan" finalizer will be added to/removed from the object's finalizers list. Either this field or PropagationPolicy may be set, but not both.
+    #[inline]
+    pub fn orphan_dependents(mut self, value: impl Into<bool>) -> Self {
+        self.inner.param_orphan_dependents = Some({
+            let val = value.into();
+            self.inner.body.orphan_dependents = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// Same as [`orphan_dependents`](#method.orphan_dependents), but takes a reference.
+    #[inline]
+    pub fn orphan_dependents_ref(self, value: &bool) -> Self {
+        self.orphan_dependents(*value)
+    }
+
+    /// Whether and how garbage collection will be performed. Either this field or OrphanDependents may be set, but not both. The default policy is decided by the existing finalizer set in the metadata.finalizers and the resource-specific default policy. Acceptable values are: 'Orphan' - orphan the dependents; 'Background' - allow the garbage collector to delete the dependents in the background; 'Foreground' - a cascading policy that deletes all dependents in the foreground.
+    #[inline]
+    pub fn propagation_policy(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_propagation_policy = Some({
+            let val = value.into();
+            self.inner.body.propagation_policy = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// name of the CSIDriver
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> DeleteOptionsDeleteBuilder75<crate::generics::NameExists> {
+        self.inner.param_name = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// If 'true', then the output is pretty printed.
+    #[inline]
+    pub fn pretty(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_pretty = Some(value.into());
+        self
+    }
+
+    /// Sets [`pretty`](#method.pretty) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_pretty(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_pretty = value.map(|value| value.into());
+        self
+    }
+
+    /// APIVersion defines the versioned schema of this representation of an object. Servers should convert recognized schemas to the latest internal value, and may reject unrecognized values. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#resources
+    #[inline]
+    pub fn api_version(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.api_version = Some(value.into());
+        self
+    }
+
+    /// Sets [`api_version`](#method.api_version) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_api_version(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.api_version = value.map(|value| value.into());
+        self
+    }
+
+    /// Kind is a string value representing the REST resource this object represents. Servers may infer this from the endpoint the client submits requests to. Cannot be updated. In CamelCase. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#types-kinds
+    #[inline]
+    pub fn kind(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.kind = Some(value.into());
+        self
+    }
+
+    /// Sets [`kind`](#method.kind) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_kind(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.kind = value.map(|value| value.into());
+        self
+    }
+
+    /// Must be fulfilled before a deletion is carried out. If not possible, a 409 Conflict status will be returned.
+    #[inline]
+    pub fn preconditions(mut self, value: crate::io::k8s::apimachinery::pkg::apis::meta::v1::preconditions::Preconditions) -> Self {
+        self.inner.body.preconditions = Some(value.into());
+        self
+    }
+
+    /// Sets [`preconditions`](#method.preconditions) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_preconditions(mut self, value: Option<crate::io::k8s::apimachinery::pkg::apis::meta::v1::preconditions::Preconditions>) -> Self {
+        self.inner.body.preconditions = value.map(|value| value.into());
+        self
+    }
+}
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for DeleteOptionsDeleteBuilder75<crate::generics::NameExists> {
+    type Output = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::Status;
+
+    const METHOD: http::Method = http::Method::DELETE;
+
+    const OPERATION_ID: Option<&'static str> = Some("deleteStorageV1beta1CSIDriver");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok(format!("/apis/storage.k8s.io/v1beta1/csidrivers/{name}", name=crate::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::CONTENT_TYPE.as_str(), "application/json")
+        .json(&self.inner.body)
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("dryRun", self.inner.param_dry_run.as_ref().map(std::string::ToString::to_string)),
+            ("gracePeriodSeconds", self.inner.param_grace_period_seconds.as_ref().map(std::string::ToString::to_string)),
+            ("orphanDependents", self.inner.param_orphan_dependents.as_ref().map(std::string::ToString::to_string)),
+            ("propagationPolicy", self.inner.param_propagation_policy.as_ref().map(std::string::ToString::to_string)),
+            ("pretty", self.inner.param_pretty.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Content-Type")
+            || name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<DeleteOptionsDeleteBuilder75<crate::generics::NameExists>> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: DeleteOptionsDeleteBuilder75<crate::generics::NameExists>) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl DeleteOptionsDeleteBuilder75<crate::generics::NameExists> {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = DeleteOptionsDeleteBuilder75 {
+            inner: DeleteOptionsDeleteBuilder75Container {
+            body: crate::cli::read_from_input(matches)?,
+            param_dry_run: matches.and_then(|m| {
+                    m.value_of("dry-run").map(|_| {
+                        value_t!(m, "dry-run", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_grace_period_seconds: matches.and_then(|m| {
+                    m.value_of("grace-period-seconds").map(|_| {
+                        value_t!(m, "grace-period-seconds", i64).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_orphan_dependents: matches.and_then(|m| {
+                    m.value_of("orphan-dependents").map(|_| {
+                        value_t!(m, "orphan-dependents", bool).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_propagation_policy: matches.and_then(|m| {
+                    m.value_of("propagation-policy").map(|_| {
+                        value_t!(m, "propagation-policy", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_name: matches.and_then(|m| {
+                    m.value_of("name").map(|_| {
+                        value_t!(m, "name", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_pretty: matches.and_then(|m| {
+                    m.value_of("pretty").map(|_| {
+                        value_t!(m, "pretty", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            },
+            _param_name: core::marker::PhantomData,
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder created by [`DeleteOptions::delete_storage_v1beta1_csi_node`](./struct.DeleteOptions.html#method.delete_storage_v1beta1_csi_node) method for a `DELETE` operation associated with `DeleteOptions`.
+#[repr(transparent)]
+#[derive(Debug, Clone)]
+pub struct DeleteOptionsDeleteBuilder76<Name> {
+    inner: DeleteOptionsDeleteBuilder76Container,
+    _param_name: core::marker::PhantomData<Name>,
+}
+
+#[derive(Debug, Default, Clone)]
+struct DeleteOptionsDeleteBuilder76Container {
+    body: self::DeleteOptions,
+    param_dry_run: Option<String>,
+    param_grace_period_seconds: Option<i64>,
+    param_orphan_dependents: Option<bool>,
+    param_propagation_policy: Option<String>,
+    param_name: Option<String>,
+    param_pretty: Option<String>,
+}
+
+impl<Name> DeleteOptionsDeleteBuilder76<Name> {
+    /// When present, indicates that modifications should not be persisted. An invalid or unrecognized dryRun directive will result in an error response and no further processing of the request. Valid values are: - All: all dry run stages will be processed
+    #[inline]
+    pub fn dry_run(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_dry_run = Some(value.into());
+        self
+    }
+
+    /// Sets [`dry_run`](#method.dry_run) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_dry_run(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_dry_run = value.map(|value| value.into());
+        self
+    }
+
+    /// The duration in seconds before the object should be deleted. Value must be non-negative integer. The value zero indicates delete immediately. If this value is nil, the default grace period for the specified type will be used. Defaults to a per object value if not specified. zero means delete immediately.
+    #[inline]
+    pub fn grace_period_seconds(mut self, value: impl Into<i64>) -> Self {
+        self.inner.param_grace_period_seconds = Some({
+            let val = value.into();
+            self.inner.body.grace_period_seconds = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// Same as [`grace_period_seconds`](#method.grace_period_seconds), but takes a reference.
+    #[inline]
+    pub fn grace_period_seconds_ref(self, value: &i64) -> Self {
+        self.grace_period_seconds(*value)
+    }
+
+    /// Deprecated: please use the PropagationPolicy, this field will be deprecated in 1.7. Should the dependent objects be orphaned. If true/false, the "orphan" finalizer will be added to/removed from the object's finalizers list. Either this field or PropagationPolicy may be set, but not both.
+    #[inline]
+    pub fn orphan_dependents(mut self, value: impl Into<bool>) -> Self {
+        self.inner.param_orphan_dependents = Some({
+            let val = value.into();
+            self.inner.body.orphan_dependents = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// Same as [`orphan_dependents`](#method.orphan_dependents), but takes a reference.
+    #[inline]
+    pub fn orphan_dependents_ref(self, value: &bool) -> Self {
+        self.orphan_dependents(*value)
+    }
+
+    /// Whether and how garbage collection will be performed. Either this field or OrphanDependents may be set, but not both. The default policy is decided by the existing finalizer set in the metadata.finalizers and the resource-specific default policy. Acceptable values are: 'Orphan' - orphan the dependents; 'Background' - allow the garbage collector to delete the dependents in the background; 'Foreground' - a cascading policy that deletes all dependents in the foreground.
+    #[inline]
+    pub fn propagation_policy(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_propagation_policy = Some({
+            let val = value.into();
+            self.inner.body.propagation_policy = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// name of the CSINode
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> DeleteOptionsDeleteBuilder76<crate::generics::NameExists> {
+        self.inner.param_name = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// If 'true', then the output is pretty printed.
+    #[inline]
+    pub fn pretty(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_pretty = Some(value.into());
+        self
+    }
+
+    /// Sets [`pretty`](#method.pretty) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_pretty(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_pretty = value.map(|value| value.into());
+        self
+    }
+
+    /// APIVersion defines the versioned schema of this representation of an object. Servers should convert recognized schemas to the latest internal value, and may reject unrecognized values. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#resources
+    #[inline]
+    pub fn api_version(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.api_version = Some(value.into());
+        self
+    }
+
+    /// Sets [`api_version`](#method.api_version) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_api_version(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.api_version = value.map(|value| value.into());
+        self
+    }
+
+    /// Kind is a string value representing the REST resource this object represents. Servers may infer this from the endpoint the client submits requests to. Cannot be updated. In CamelCase. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#types-kinds
+    #[inline]
+    pub fn kind(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.kind = Some(value.into());
+        self
+    }
+
+    /// Sets [`kind`](#method.kind) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_kind(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.kind = value.map(|value| value.into());
+        self
+    }
+
+    /// Must be fulfilled before a deletion is carried out. If not possible, a 409 Conflict status will be returned.
+    #[inline]
+    pub fn preconditions(mut self, value: crate::io::k8s::apimachinery::pkg::apis::meta::v1::preconditions::Preconditions) -> Self {
+        self.inner.body.preconditions = Some(value.into());
+        self
+    }
+
+    /// Sets [`preconditions`](#method.preconditions) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_preconditions(mut self, value: Option<crate::io::k8s::apimachinery::pkg::apis::meta::v1::preconditions::Preconditions>) -> Self {
+        self.inner.body.preconditions = value.map(|value| value.into());
+        self
+    }
+}
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for DeleteOptionsDeleteBuilder76<crate::generics::NameExists> {
+    type Output = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::Status;
+
+    const METHOD: http::Method = http::Method::DELETE;
+
+    const OPERATION_ID: Option<&'static str> = Some("deleteStorageV1beta1CSINode");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok(format!("/apis/storage.k8s.io/v1beta1/csinodes/{name}", name=crate::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::CONTENT_TYPE.as_str(), "application/json")
+        .json(&self.inner.body)
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("dryRun", self.inner.param_dry_run.as_ref().map(std::string::ToString::to_string)),
+            ("gracePeriodSeconds", self.inner.param_grace_period_seconds.as_ref().map(std::string::ToString::to_string)),
+            ("orphanDependents", self.inner.param_orphan_dependents.as_ref().map(std::string::ToString::to_string)),
+            ("propagationPolicy", self.inner.param_propagation_policy.as_ref().map(std::string::ToString::to_string)),
+            ("pretty", self.inner.param_pretty.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Content-Type")
+            || name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<DeleteOptionsDeleteBuilder76<crate::generics::NameExists>> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: DeleteOptionsDeleteBuilder76<crate::generics::NameExists>) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl DeleteOptionsDeleteBuilder76<crate::generics::NameExists> {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = DeleteOptionsDeleteBuilder76 {
+            inner: DeleteOptionsDeleteBuilder76Container {
+            body: crate::cli::read_from_input(matches)?,
+            param_dry_run: matches.and_then(|m| {
+                    m.value_of("dry-run").map(|_| {
+                        value_t!(m, "dry-run", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_grace_period_seconds: matches.and_then(|m| {
+                    m.value_of("grace-period-seconds").map(|_| {
+                        value_t!(m, "grace-period-seconds", i64).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_orphan_dependents: matches.and_then(|m| {
+                    m.value_of("orphan-dependents").map(|_| {
+                        value_t!(m, "orphan-dependents", bool).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_propagation_policy: matches.and_then(|m| {
+                    m.value_of("propagation-policy").map(|_| {
+                        value_t!(m, "propagation-policy", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_name: matches.and_then(|m| {
+                    m.value_of("name").map(|_| {
+                        value_t!(m, "name", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_pretty: matches.and_then(|m| {
+                    m.value_of("pretty").map(|_| {
+                        value_t!(m, "pretty", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            },
+            _param_name: core::marker::PhantomData,
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder created by [`DeleteOptions::delete_storage_v1beta1_storage_class`](./struct.DeleteOptions.html#method.delete_storage_v1beta1_storage_class) method for a `DELETE` operation associated with `DeleteOptions`.
+pub type DeleteOptionsDeleteBuilder77<Name> = DeleteOptionsOpBuilder19<DeleteOptionsDeleteOp77, Name>;
+
+/// Marks the operation of [`DeleteOptionsDeleteBuilder77`] in the shared builder.
+#[derive(Debug, Clone)]
+pub enum DeleteOptionsDeleteOp77 {}
+
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for DeleteOptionsDeleteBuilder77<crate::generics::NameExists> {
+    type Output = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::Status;
+
+    const METHOD: http::Method = http::Method::DELETE;
+
+    const OPERATION_ID: Option<&'static str> = Some("deleteStorageV1beta1StorageClass");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok(format!("/apis/storage.k8s.io/v1beta1/storageclasses/{name}", name=crate::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::CONTENT_TYPE.as_str(), "application/json")
+        .json(&self.inner.body)
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("dryRun", self.inner.param_dry_run.as_ref().map(std::string::ToString::to_string)),
+            ("gracePeriodSeconds", self.inner.param_grace_period_seconds.as_ref().map(std::string::ToString::to_string)),
+            ("orphanDependents", self.inner.param_orphan_dependents.as_ref().map(std::string::ToString::to_string)),
+            ("propagationPolicy", self.inner.param_propagation_policy.as_ref().map(std::string::ToString::to_string)),
+            ("pretty", self.inner.param_pretty.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Content-Type")
+            || name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<DeleteOptionsDeleteBuilder77<crate::generics::NameExists>> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: DeleteOptionsDeleteBuilder77<crate::generics::NameExists>) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl DeleteOptionsDeleteBuilder77<crate::generics::NameExists> {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = DeleteOptionsDeleteBuilder77 {
+            inner: DeleteOptionsOpBuilder19Container {
+            body: crate::cli::read_from_input(matches)?,
+            param_dry_run: matches.and_then(|m| {
+                    m.value_of("dry-run").map(|_| {
+                        value_t!(m, "dry-run", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_grace_period_seconds: matches.and_then(|m| {
+                    m.value_of("grace-period-seconds").map(|_| {
+                        value_t!(m, "grace-period-seconds", i64).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_orphan_dependents: matches.and_then(|m| {
+                    m.value_of("orphan-dependents").map(|_| {
+                        value_t!(m, "orphan-dependents", bool).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_propagation_policy: matches.and_then(|m| {
+                    m.value_of("propagation-policy").map(|_| {
+                        value_t!(m, "propagation-policy", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_name: matches.and_then(|m| {
+                    m.value_of("name").map(|_| {
+                        value_t!(m, "name", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_pretty: matches.and_then(|m| {
+                    m.value_of("pretty").map(|_| {
+                        value_t!(m, "pretty", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            },
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder created by [`DeleteOptions::delete_storage_v1beta1_volume_attachment`](./struct.DeleteOptions.html#method.delete_storage_v1beta1_volume_attachment) method for a `DELETE` operation associated with `DeleteOptions`.
+pub type DeleteOptionsDeleteBuilder78<Name> = DeleteOptionsOpBuilder20<DeleteOptionsDeleteOp78, Name>;
+
+/// Marks the operation of [`DeleteOptionsDeleteBuilder78`] in the shared builder.
+#[derive(Debug, Clone)]
+pub enum DeleteOptionsDeleteOp78 {}
+
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for DeleteOptionsDeleteBuilder78<crate::generics::NameExists> {
+    type Output = crate::io::k8s::apimachinery::pkg::apis::meta::v1::status::Status;
+
+    const METHOD: http::Method = http::Method::DELETE;
+
+    const OPERATION_ID: Option<&'static str> = Some("deleteStorageV1beta1VolumeAttachment");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok(format!("/apis/storage.k8s.io/v1beta1/volumeattachments/{name}", name=crate::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::CONTENT_TYPE.as_str(), "application/json")
+        .json(&self.inner.body)
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("dryRun", self.inner.param_dry_run.as_ref().map(std::string::ToString::to_string)),
+            ("gracePeriodSeconds", self.inner.param_grace_period_seconds.as_ref().map(std::string::ToString::to_string)),
+            ("orphanDependents", self.inner.param_orphan_dependents.as_ref().map(std::string::ToString::to_string)),
+            ("propagationPolicy", self.inner.param_propagation_policy.as_ref().map(std::string::ToString::to_string)),
+            ("pretty", self.inner.param_pretty.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Content-Type")
+            || name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<DeleteOptionsDeleteBuilder78<crate::generics::NameExists>> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: DeleteOptionsDeleteBuilder78<crate::generics::NameExists>) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl DeleteOptionsDeleteBuilder78<crate::generics::NameExists> {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = DeleteOptionsDeleteBuilder78 {
+            inner: DeleteOptionsOpBuilder20Container {
+            body: crate::cli::read_from_input(matches)?,
+            param_dry_run: matches.and_then(|m| {
+                    m.value_of("dry-run").map(|_| {
+                        value_t!(m, "dry-run", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_grace_period_seconds: matches.and_then(|m| {
+                    m.value_of("grace-period-seconds").map(|_| {
+                        value_t!(m, "grace-period-seconds", i64).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_orphan_dependents: matches.and_then(|m| {
+                    m.value_of("orphan-dependents").map(|_| {
+                        value_t!(m, "orphan-dependents", bool).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_propagation_policy: matches.and_then(|m| {
+                    m.value_of("propagation-policy").map(|_| {
+                        value_t!(m, "propagation-policy", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_name: matches.and_then(|m| {
+                    m.value_of("name").map(|_| {
+                        value_t!(m, "name", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_pretty: matches.and_then(|m| {
+                    m.value_of("pretty").map(|_| {
+                        value_t!(m, "pretty", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            },
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+        };
+
+        Ok(thing)
+    }
+}
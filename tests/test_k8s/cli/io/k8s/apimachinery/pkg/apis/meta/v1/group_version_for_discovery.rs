@@ -0,0 +1,65 @@
+
+/// GroupVersion contains the "group/version" and "version" string of a version. It is made a struct to keep extensibility.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct GroupVersionForDiscovery {
+    /// groupVersion specifies the API group and version in the form "group/version"
+    #[serde(rename = "groupVersion")]
+    pub group_version: String,
+    /// version specifies the version in the form of "version". This is to save the clients the trouble of splitting the GroupVersion.
+    pub version: String,
+}
+
+impl GroupVersionForDiscovery {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> GroupVersionForDiscoveryBuilder<crate::generics::MissingGroupVersion, crate::generics::MissingVersion> {
+        GroupVersionForDiscoveryBuilder {
+            body: Default::default(),
+            _group_version: core::marker::PhantomData,
+            _version: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> GroupVersionForDiscoveryBuilder<crate::generics::GroupVersionExists, crate::generics::VersionExists>
+    where
+        Self: Clone,
+    {
+        GroupVersionForDiscoveryBuilder {
+            body: self.clone(),
+            _group_version: core::marker::PhantomData,
+            _version: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<GroupVersionForDiscovery> for GroupVersionForDiscoveryBuilder<crate::generics::GroupVersionExists, crate::generics::VersionExists> {
+    fn into(self) -> GroupVersionForDiscovery {
+        self.body
+    }
+}
+
+/// Builder for [`GroupVersionForDiscovery`](./struct.GroupVersionForDiscovery.html) object.
+#[derive(Debug, Clone)]
+pub struct GroupVersionForDiscoveryBuilder<GroupVersion, Version> {
+    body: self::GroupVersionForDiscovery,
+    _group_version: core::marker::PhantomData<GroupVersion>,
+    _version: core::marker::PhantomData<Version>,
+}
+
+impl<GroupVersion, Version> GroupVersionForDiscoveryBuilder<GroupVersion, Version> {
+    /// groupVersion specifies the API group and version in the form "group/version"
+    #[inline]
+    pub fn group_version(mut self, value: impl Into<String>) -> GroupVersionForDiscoveryBuilder<crate::generics::GroupVersionExists, Version> {
+        self.body.group_version = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// version specifies the version in the form of "version". This is to save the clients the trouble of splitting the GroupVersion.
+    #[inline]
+    pub fn version(mut self, value: impl Into<String>) -> GroupVersionForDiscoveryBuilder<GroupVersion, crate::generics::VersionExists> {
+        self.body.version = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+}
@@ -0,0 +1,52 @@
+
+/// Initializer is information about an initializer that has not yet completed.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct Initializer {
+    /// name of the process that is responsible for initializing this object.
+    pub name: String,
+}
+
+impl Initializer {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> InitializerBuilder<crate::generics::MissingName> {
+        InitializerBuilder {
+            body: Default::default(),
+            _name: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> InitializerBuilder<crate::generics::NameExists>
+    where
+        Self: Clone,
+    {
+        InitializerBuilder {
+            body: self.clone(),
+            _name: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<Initializer> for InitializerBuilder<crate::generics::NameExists> {
+    fn into(self) -> Initializer {
+        self.body
+    }
+}
+
+/// Builder for [`Initializer`](./struct.Initializer.html) object.
+#[derive(Debug, Clone)]
+pub struct InitializerBuilder<Name> {
+    body: self::Initializer,
+    _name: core::marker::PhantomData<Name>,
+}
+
+impl<Name> InitializerBuilder<Name> {
+    /// name of the process that is responsible for initializing this object.
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> InitializerBuilder<crate::generics::NameExists> {
+        self.body.name = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+}
@@ -0,0 +1,402 @@
+
+/// ObjectMeta is metadata that all persisted resources must have, which includes all objects users must create.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct ObjectMeta {
+    /// Annotations is an unstructured key value map stored with a resource that may be set by external tools to store and retrieve arbitrary metadata. They are not queryable and should be preserved when modifying objects. More info: http://kubernetes.io/docs/user-guide/annotations
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub annotations: Option<std::collections::BTreeMap<String, String>>,
+    /// The name of the cluster which the object belongs to. This is used to distinguish resources with same name and namespace in different clusters. This field is not set anywhere right now and apiserver is going to ignore it if set in create or update request.
+    #[serde(rename = "clusterName", skip_serializing_if = "Option::is_none")]
+    pub cluster_name: Option<String>,
+    /// CreationTimestamp is a timestamp representing the server time when this object was created. It is not guaranteed to be set in happens-before order across separate operations. Clients may not set this value. It is represented in RFC3339 form and is in UTC.
+    ///
+    /// Populated by the system. Read-only. Null for lists. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#metadata
+    #[serde(rename = "creationTimestamp", skip_serializing_if = "Option::is_none")]
+    pub creation_timestamp: Option<String>,
+    /// Number of seconds allowed for this object to gracefully terminate before it will be removed from the system. Only set when deletionTimestamp is also set. May only be shortened. Read-only.
+    #[serde(rename = "deletionGracePeriodSeconds", skip_serializing_if = "Option::is_none")]
+    pub deletion_grace_period_seconds: Option<i64>,
+    /// DeletionTimestamp is RFC 3339 date and time at which this resource will be deleted. This field is set by the server when a graceful deletion is requested by the user, and is not directly settable by a client. The resource is expected to be deleted (no longer visible from resource lists, and not reachable by name) after the time in this field, once the finalizers list is empty. As long as the finalizers list contains items, deletion is blocked. Once the deletionTimestamp is set, this value may not be unset or be set further into the future, although it may be shortened or the resource may be deleted prior to this time. For example, a user may request that a pod is deleted in 30 seconds. The Kubelet will react by sending a graceful termination signal to the containers in the pod. After that 30 seconds, the Kubelet will send a hard termination signal (SIGKILL) to the container and after cleanup, remove the pod from the API. In the presence of network partitions, this object may still exist after this timestamp, until an administrator or automated process can determine the resource is fully terminated. If not set, graceful deletion of the object has not been requested.
+    ///
+    /// Populated by the system when a graceful deletion is requested. Read-only. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#metadata
+    #[serde(rename = "deletionTimestamp", skip_serializing_if = "Option::is_none")]
+    pub deletion_timestamp: Option<String>,
+    /// Must be empty before the object is deleted from the registry. Each entry is an identifier for the responsible component that will remove the entry from the list. If the deletionTimestamp of the object is non-nil, entries in this list can only be removed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub finalizers: Option<Vec<String>>,
+    /// GenerateName is an optional prefix, used by the server, to generate a unique name ONLY IF the Name field has not been provided. If this field is used, the name returned to the client will be different than the name passed. This value will also be combined with a unique suffix. The provided value has the same validation rules as the Name field, and may be truncated by the length of the suffix required to make the value unique on the server.
+    ///
+    /// If this field is specified and the generated name exists, the server will NOT return a 409 - instead, it will either return 201 Created or 500 with Reason ServerTimeout indicating a unique name could not be found in the time allotted, and the client should retry (optionally after the time indicated in the Retry-After header).
+    ///
+    /// Applied only if Name is not specified. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#idempotency
+    #[serde(rename = "generateName", skip_serializing_if = "Option::is_none")]
+    pub generate_name: Option<String>,
+    /// A sequence number representing a specific generation of the desired state. Populated by the system. Read-only.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub generation: Option<i64>,
+    /// An initializer is a controller which enforces some system invariant at object creation time. This field is a list of initializers that have not yet acted on this object. If nil or empty, this object has been completely initialized. Otherwise, the object is considered uninitialized and is hidden (in list/watch and get calls) from clients that haven't explicitly asked to observe uninitialized objects.
+    ///
+    /// When an object is created, the system will populate this list with the current set of initializers. Only privileged users may set or modify this list. Once it is empty, it may not be modified further by any user.
+    ///
+    /// DEPRECATED - initializers are an alpha field and will be removed in v1.15.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initializers: Option<crate::io::k8s::apimachinery::pkg::apis::meta::v1::initializers::Initializers>,
+    /// Map of string keys and values that can be used to organize and categorize (scope and select) objects. May match selectors of replication controllers and services. More info: http://kubernetes.io/docs/user-guide/labels
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub labels: Option<std::collections::BTreeMap<String, String>>,
+    /// ManagedFields maps workflow-id and version to the set of fields that are managed by that workflow. This is mostly for internal housekeeping, and users typically shouldn't need to set or understand this field. A workflow can be the user's name, a controller's name, or the name of a specific apply path like "ci-cd". The set of fields is always in the version that the workflow used when modifying the object.
+    ///
+    /// This field is alpha and can be changed or removed without notice.
+    #[serde(rename = "managedFields", skip_serializing_if = "Option::is_none")]
+    pub managed_fields: Option<Vec<crate::io::k8s::apimachinery::pkg::apis::meta::v1::managed_fields_entry::ManagedFieldsEntry>>,
+    /// Name must be unique within a namespace. Is required when creating resources, although some resources may allow a client to request the generation of an appropriate name automatically. Name is primarily intended for creation idempotence and configuration definition. Cannot be updated. More info: http://kubernetes.io/docs/user-guide/identifiers#names
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Namespace defines the space within each name must be unique. An empty namespace is equivalent to the "default" namespace, but "default" is the canonical representation. Not all objects are required to be scoped to a namespace - the value of this field for those objects will be empty.
+    ///
+    /// Must be a DNS_LABEL. Cannot be updated. More info: http://kubernetes.io/docs/user-guide/namespaces
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub namespace: Option<String>,
+    /// List of objects depended by this object. If ALL objects in the list have been deleted, this object will be garbage collected. If this object is managed by a controller, then an entry in this list will point to this controller, with the controller field set to true. There cannot be more than one managing controller.
+    #[serde(rename = "ownerReferences", skip_serializing_if = "Option::is_none")]
+    pub owner_references: Option<Vec<crate::io::k8s::apimachinery::pkg::apis::meta::v1::owner_reference::OwnerReference>>,
+    /// An opaque value that represents the internal version of this object that can be used by clients to determine when objects have changed. May be used for optimistic concurrency, change detection, and the watch operation on a resource or set of resources. Clients must treat these values as opaque and passed unmodified back to the server. They may only be valid for a particular resource or set of resources.
+    ///
+    /// Populated by the system. Read-only. Value must be treated as opaque by clients and . More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#concurrency-control-and-consistency
+    #[serde(rename = "resourceVersion", skip_serializing_if = "Option::is_none")]
+    pub resource_version: Option<String>,
+    /// SelfLink is a URL representing this object. Populated by the system. Read-only.
+    #[serde(rename = "selfLink", skip_serializing_if = "Option::is_none")]
+    pub self_link: Option<String>,
+    /// UID is the unique in time and space value for this object. It is typically generated by the server on successful creation of a resource and is not allowed to change on PUT operations.
+    ///
+    /// Populated by the system. Read-only. More info: http://kubernetes.io/docs/user-guide/identifiers#uids
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub uid: Option<String>,
+}
+
+impl ObjectMeta {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> ObjectMetaBuilder {
+        ObjectMetaBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> ObjectMetaBuilder
+    where
+        Self: Clone,
+    {
+        ObjectMetaBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<ObjectMeta> for ObjectMetaBuilder {
+    fn into(self) -> ObjectMeta {
+        self.body
+    }
+}
+
+/// Builder for [`ObjectMeta`](./struct.ObjectMeta.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct ObjectMetaBuilder {
+    body: self::ObjectMeta,
+}
+
+impl ObjectMetaBuilder {
+    /// Annotations is an unstructured key value map stored with a resource that may be set by external tools to store and retrieve arbitrary metadata. They are not queryable and should be preserved when modifying objects. More info: http://kubernetes.io/docs/user-guide/annotations
+    #[inline]
+    pub fn annotations(mut self, value: impl Iterator<Item = (String, impl Into<String>)>) -> Self {
+        self.body.annotations = Some(value.map(|(key, value)| (key, value.into())).collect::<std::collections::BTreeMap<_, _>>().into());
+        self
+    }
+
+    /// Sets [`annotations`](#method.annotations) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_annotations(mut self, value: Option<impl Iterator<Item = (String, impl Into<String>)>>) -> Self {
+        self.body.annotations = value.map(|value| value.map(|(key, value)| (key, value.into())).collect::<std::collections::BTreeMap<_, _>>().into());
+        self
+    }
+
+    /// The name of the cluster which the object belongs to. This is used to distinguish resources with same name and namespace in different clusters. This field is not set anywhere right now and apiserver is going to ignore it if set in create or update request.
+    #[inline]
+    pub fn cluster_name(mut self, value: impl Into<String>) -> Self {
+        self.body.cluster_name = Some(value.into());
+        self
+    }
+
+    /// Sets [`cluster_name`](#method.cluster_name) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_cluster_name(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.cluster_name = value.map(|value| value.into());
+        self
+    }
+
+    /// CreationTimestamp is a timestamp representing the server time when this object was created. It is not guaranteed to be set in happens-before order across separate operations. Clients may not set this value. It is represented in RFC3339 form and is in UTC.
+    ///
+    /// Populated by the system. Read-only. Null for lists. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#metadata
+    #[inline]
+    pub fn creation_timestamp(mut self, value: impl Into<String>) -> Self {
+        self.body.creation_timestamp = Some(value.into());
+        self
+    }
+
+    /// Sets [`creation_timestamp`](#method.creation_timestamp) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_creation_timestamp(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.creation_timestamp = value.map(|value| value.into());
+        self
+    }
+
+    /// Number of seconds allowed for this object to gracefully terminate before it will be removed from the system. Only set when deletionTimestamp is also set. May only be shortened. Read-only.
+    #[inline]
+    pub fn deletion_grace_period_seconds(mut self, value: impl Into<i64>) -> Self {
+        self.body.deletion_grace_period_seconds = Some(value.into());
+        self
+    }
+
+    /// Sets [`deletion_grace_period_seconds`](#method.deletion_grace_period_seconds) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_deletion_grace_period_seconds(mut self, value: Option<impl Into<i64>>) -> Self {
+        self.body.deletion_grace_period_seconds = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`deletion_grace_period_seconds`](#method.deletion_grace_period_seconds), but takes a reference.
+    #[inline]
+    pub fn deletion_grace_period_seconds_ref(self, value: &i64) -> Self {
+        self.deletion_grace_period_seconds(*value)
+    }
+
+    /// DeletionTimestamp is RFC 3339 date and time at which this resource will be deleted. This field is set by the server when a graceful deletion is requested by the user, and is not directly settable by a client. The resource is expected to be deleted (no longer visible from resource lists, and not reachable by name) after the time in this field, once the finalizers list is empty. As long as the finalizers list contains items, deletion is blocked. Once the deletionTimestamp is set, this value may not be unset or be set further into the future, although it may be shortened or the resource may be deleted prior to this time. For example, a user may request that a pod is deleted in 30 seconds. The Kubelet will react by sending a graceful termination signal to the containers in the pod. After that 30 seconds, the Kubelet will send a hard termination signal (SIGKILL) to the container and after cleanup, remove the pod from the API. In the presence of network partitions, this object may still exist after this timestamp, until an administrator or automated process can determine the resource is fully terminated. If not set, graceful deletion of the object has not been requested.
+    ///
+    /// Populated by the system when a graceful deletion is requested. Read-only. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#metadata
+    #[inline]
+    pub fn deletion_timestamp(mut self, value: impl Into<String>) -> Self {
+        self.body.deletion_timestamp = Some(value.into());
+        self
+    }
+
+    /// Sets [`deletion_timestamp`](#method.deletion_timestamp) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_deletion_timestamp(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.deletion_timestamp = value.map(|value| value.into());
+        self
+    }
+
+    /// Must be empty before the object is deleted from the registry. Each entry is an identifier for the responsible component that will remove the entry from the list. If the deletionTimestamp of the object is non-nil, entries in this list can only be removed.
+    #[inline]
+    pub fn finalizers(mut self, value: impl Iterator<Item = impl Into<String>>) -> Self {
+        self.body.finalizers = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`finalizers`](#method.finalizers) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_finalizers(mut self, value: Option<impl Iterator<Item = impl Into<String>>>) -> Self {
+        self.body.finalizers = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`finalizers`](#method.finalizers), but takes the values as a `Vec`.
+    #[inline]
+    pub fn finalizers_vec(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.body.finalizers = Some(value.into());
+        self
+    }
+
+    /// GenerateName is an optional prefix, used by the server, to generate a unique name ONLY IF the Name field has not been provided. If this field is used, the name returned to the client will be different than the name passed. This value will also be combined with a unique suffix. The provided value has the same validation rules as the Name field, and may be truncated by the length of the suffix required to make the value unique on the server.
+    ///
+    /// If this field is specified and the generated name exists, the server will NOT return a 409 - instead, it will either return 201 Created or 500 with Reason ServerTimeout indicating a unique name could not be found in the time allotted, and the client should retry (optionally after the time indicated in the Retry-After header).
+    ///
+    /// Applied only if Name is not specified. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#idempotency
+    #[inline]
+    pub fn generate_name(mut self, value: impl Into<String>) -> Self {
+        self.body.generate_name = Some(value.into());
+        self
+    }
+
+    /// Sets [`generate_name`](#method.generate_name) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_generate_name(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.generate_name = value.map(|value| value.into());
+        self
+    }
+
+    /// A sequence number representing a specific generation of the desired state. Populated by the system. Read-only.
+    #[inline]
+    pub fn generation(mut self, value: impl Into<i64>) -> Self {
+        self.body.generation = Some(value.into());
+        self
+    }
+
+    /// Sets [`generation`](#method.generation) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_generation(mut self, value: Option<impl Into<i64>>) -> Self {
+        self.body.generation = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`generation`](#method.generation), but takes a reference.
+    #[inline]
+    pub fn generation_ref(self, value: &i64) -> Self {
+        self.generation(*value)
+    }
+
+    /// An initializer is a controller which enforces some system invariant at object creation time. This field is a list of initializers that have not yet acted on this object. If nil or empty, this object has been completely initialized. Otherwise, the object is considered uninitialized and is hidden (in list/watch and get calls) from clients that haven't explicitly asked to observe uninitialized objects.
+    ///
+    /// When an object is created, the system will populate this list with the current set of initializers. Only privileged users may set or modify this list. Once it is empty, it may not be modified further by any user.
+    ///
+    /// DEPRECATED - initializers are an alpha field and will be removed in v1.15.
+    #[inline]
+    pub fn initializers(mut self, value: crate::io::k8s::apimachinery::pkg::apis::meta::v1::initializers::InitializersBuilder<crate::generics::PendingExists>) -> Self {
+        self.body.initializers = Some(value.into());
+        self
+    }
+
+    /// Sets [`initializers`](#method.initializers) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_initializers(mut self, value: Option<crate::io::k8s::apimachinery::pkg::apis::meta::v1::initializers::InitializersBuilder<crate::generics::PendingExists>>) -> Self {
+        self.body.initializers = value.map(|value| value.into());
+        self
+    }
+
+    /// Map of string keys and values that can be used to organize and categorize (scope and select) objects. May match selectors of replication controllers and services. More info: http://kubernetes.io/docs/user-guide/labels
+    #[inline]
+    pub fn labels(mut self, value: impl Iterator<Item = (String, impl Into<String>)>) -> Self {
+        self.body.labels = Some(value.map(|(key, value)| (key, value.into())).collect::<std::collections::BTreeMap<_, _>>().into());
+        self
+    }
+
+    /// Sets [`labels`](#method.labels) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_labels(mut self, value: Option<impl Iterator<Item = (String, impl Into<String>)>>) -> Self {
+        self.body.labels = value.map(|value| value.map(|(key, value)| (key, value.into())).collect::<std::collections::BTreeMap<_, _>>().into());
+        self
+    }
+
+    /// ManagedFields maps workflow-id and version to the set of fields that are managed by that workflow. This is mostly for internal housekeeping, and users typically shouldn't need to set or understand this field. A workflow can be the user's name, a controller's name, or the name of a specific apply path like "ci-cd". The set of fields is always in the version that the workflow used when modifying the object.
+    ///
+    /// This field is alpha and can be changed or removed without notice.
+    #[inline]
+    pub fn managed_fields(mut self, value: impl Iterator<Item = crate::io::k8s::apimachinery::pkg::apis::meta::v1::managed_fields_entry::ManagedFieldsEntry>) -> Self {
+        self.body.managed_fields = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`managed_fields`](#method.managed_fields) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_managed_fields(mut self, value: Option<impl Iterator<Item = crate::io::k8s::apimachinery::pkg::apis::meta::v1::managed_fields_entry::ManagedFieldsEntry>>) -> Self {
+        self.body.managed_fields = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`managed_fields`](#method.managed_fields), but takes the values as a `Vec`.
+    #[inline]
+    pub fn managed_fields_vec(mut self, value: impl Into<Vec<crate::io::k8s::apimachinery::pkg::apis::meta::v1::managed_fields_entry::ManagedFieldsEntry>>) -> Self {
+        self.body.managed_fields = Some(value.into());
+        self
+    }
+
+    /// Name must be unique within a namespace. Is required when creating resources, although some resources may allow a client to request the generation of an appropriate name automatically. Name is primarily intended for creation idempotence and configuration definition. Cannot be updated. More info: http://kubernetes.io/docs/user-guide/identifiers#names
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> Self {
+        self.body.name = Some(value.into());
+        self
+    }
+
+    /// Sets [`name`](#method.name) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_name(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.name = value.map(|value| value.into());
+        self
+    }
+
+    /// Namespace defines the space within each name must be unique. An empty namespace is equivalent to the "default" namespace, but "default" is the canonical representation. Not all objects are required to be scoped to a namespace - the value of this field for those objects will be empty.
+    ///
+    /// Must be a DNS_LABEL. Cannot be updated. More info: http://kubernetes.io/docs/user-guide/namespaces
+    #[inline]
+    pub fn namespace(mut self, value: impl Into<String>) -> Self {
+        self.body.namespace = Some(value.into());
+        self
+    }
+
+    /// Sets [`namespace`](#method.namespace) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_namespace(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.namespace = value.map(|value| value.into());
+        self
+    }
+
+    /// List of objects depended by this object. If ALL objects in the list have been deleted, this object will be garbage collected. If this object is managed by a controller, then an entry in this list will point to this controller, with the controller field set to true. There cannot be more than one managing controller.
+    #[inline]
+    pub fn owner_references(mut self, value: impl Iterator<Item = crate::io::k8s::apimachinery::pkg::apis::meta::v1::owner_reference::OwnerReferenceBuilder<crate::generics::ApiVersionExists, crate::generics::KindExists, crate::generics::NameExists, crate::generics::UidExists>>) -> Self {
+        self.body.owner_references = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`owner_references`](#method.owner_references) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_owner_references(mut self, value: Option<impl Iterator<Item = crate::io::k8s::apimachinery::pkg::apis::meta::v1::owner_reference::OwnerReferenceBuilder<crate::generics::ApiVersionExists, crate::generics::KindExists, crate::generics::NameExists, crate::generics::UidExists>>>) -> Self {
+        self.body.owner_references = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`owner_references`](#method.owner_references), but takes the values as a `Vec`.
+    #[inline]
+    pub fn owner_references_vec(mut self, value: impl Into<Vec<crate::io::k8s::apimachinery::pkg::apis::meta::v1::owner_reference::OwnerReference>>) -> Self {
+        self.body.owner_references = Some(value.into());
+        self
+    }
+
+    /// An opaque value that represents the internal version of this object that can be used by clients to determine when objects have changed. May be used for optimistic concurrency, change detection, and the watch operation on a resource or set of resources. Clients must treat these values as opaque and passed unmodified back to the server. They may only be valid for a particular resource or set of resources.
+    ///
+    /// Populated by the system. Read-only. Value must be treated as opaque by clients and . More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#concurrency-control-and-consistency
+    #[inline]
+    pub fn resource_version(mut self, value: impl Into<String>) -> Self {
+        self.body.resource_version = Some(value.into());
+        self
+    }
+
+    /// Sets [`resource_version`](#method.resource_version) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_resource_version(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.resource_version = value.map(|value| value.into());
+        self
+    }
+
+    /// SelfLink is a URL representing this object. Populated by the system. Read-only.
+    #[inline]
+    pub fn self_link(mut self, value: impl Into<String>) -> Self {
+        self.body.self_link = Some(value.into());
+        self
+    }
+
+    /// Sets [`self_link`](#method.self_link) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_self_link(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.self_link = value.map(|value| value.into());
+        self
+    }
+
+    /// UID is the unique in time and space value for this object. It is typically generated by the server on successful creation of a resource and is not allowed to change on PUT operations.
+    ///
+    /// Populated by the system. Read-only. More info: http://kubernetes.io/docs/user-guide/identifiers#uids
+    #[inline]
+    pub fn uid(mut self, value: impl Into<String>) -> Self {
+        self.body.uid = Some(value.into());
+        self
+    }
+
+    /// Sets [`uid`](#method.uid) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_uid(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.uid = value.map(|value| value.into());
+        self
+    }
+}
@@ -0,0 +1,135 @@
+
+/// OwnerReference contains enough information to let you identify an owning object. An owning object must be in the same namespace as the dependent, or be cluster-scoped, so there is no namespace field.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct OwnerReference {
+    /// API version of the referent.
+    #[serde(rename = "apiVersion")]
+    pub api_version: String,
+    /// If true, AND if the owner has the "foregroundDeletion" finalizer, then the owner cannot be deleted from the key-value store until this reference is removed. Defaults to false. To set this field, a user needs "delete" permission of the owner, otherwise 422 (Unprocessable Entity) will be returned.
+    #[serde(rename = "blockOwnerDeletion", skip_serializing_if = "Option::is_none")]
+    pub block_owner_deletion: Option<bool>,
+    /// If true, this reference points to the managing controller.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub controller: Option<bool>,
+    /// Kind of the referent. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#types-kinds
+    pub kind: String,
+    /// Name of the referent. More info: http://kubernetes.io/docs/user-guide/identifiers#names
+    pub name: String,
+    /// UID of the referent. More info: http://kubernetes.io/docs/user-guide/identifiers#uids
+    pub uid: String,
+}
+
+impl OwnerReference {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> OwnerReferenceBuilder<crate::generics::MissingApiVersion, crate::generics::MissingKind, crate::generics::MissingName, crate::generics::MissingUid> {
+        OwnerReferenceBuilder {
+            body: Default::default(),
+            _api_version: core::marker::PhantomData,
+            _kind: core::marker::PhantomData,
+            _name: core::marker::PhantomData,
+            _uid: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> OwnerReferenceBuilder<crate::generics::ApiVersionExists, crate::generics::KindExists, crate::generics::NameExists, crate::generics::UidExists>
+    where
+        Self: Clone,
+    {
+        OwnerReferenceBuilder {
+            body: self.clone(),
+            _api_version: core::marker::PhantomData,
+            _kind: core::marker::PhantomData,
+            _name: core::marker::PhantomData,
+            _uid: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<OwnerReference> for OwnerReferenceBuilder<crate::generics::ApiVersionExists, crate::generics::KindExists, crate::generics::NameExists, crate::generics::UidExists> {
+    fn into(self) -> OwnerReference {
+        self.body
+    }
+}
+
+/// Builder for [`OwnerReference`](./struct.OwnerReference.html) object.
+#[derive(Debug, Clone)]
+pub struct OwnerReferenceBuilder<ApiVersion, Kind, Name, Uid> {
+    body: self::OwnerReference,
+    _api_version: core::marker::PhantomData<ApiVersion>,
+    _kind: core::marker::PhantomData<Kind>,
+    _name: core::marker::PhantomData<Name>,
+    _uid: core::marker::PhantomData<Uid>,
+}
+
+impl<ApiVersion, Kind, Name, Uid> OwnerReferenceBuilder<ApiVersion, Kind, Name, Uid> {
+    /// API version of the referent.
+    #[inline]
+    pub fn api_version(mut self, value: impl Into<String>) -> OwnerReferenceBuilder<crate::generics::ApiVersionExists, Kind, Name, Uid> {
+        self.body.api_version = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// If true, AND if the owner has the "foregroundDeletion" finalizer, then the owner cannot be deleted from the key-value store until this reference is removed. Defaults to false. To set this field, a user needs "delete" permission of the owner, otherwise 422 (Unprocessable Entity) will be returned.
+    #[inline]
+    pub fn block_owner_deletion(mut self, value: impl Into<bool>) -> Self {
+        self.body.block_owner_deletion = Some(value.into());
+        self
+    }
+
+    /// Sets [`block_owner_deletion`](#method.block_owner_deletion) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_block_owner_deletion(mut self, value: Option<impl Into<bool>>) -> Self {
+        self.body.block_owner_deletion = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`block_owner_deletion`](#method.block_owner_deletion), but takes a reference.
+    #[inline]
+    pub fn block_owner_deletion_ref(self, value: &bool) -> Self {
+        self.block_owner_deletion(*value)
+    }
+
+    /// If true, this reference points to the managing controller.
+    #[inline]
+    pub fn controller(mut self, value: impl Into<bool>) -> Self {
+        self.body.controller = Some(value.into());
+        self
+    }
+
+    /// Sets [`controller`](#method.controller) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_controller(mut self, value: Option<impl Into<bool>>) -> Self {
+        self.body.controller = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`controller`](#method.controller), but takes a reference.
+    #[inline]
+    pub fn controller_ref(self, value: &bool) -> Self {
+        self.controller(*value)
+    }
+
+    /// Kind of the referent. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#types-kinds
+    #[inline]
+    pub fn kind(mut self, value: impl Into<String>) -> OwnerReferenceBuilder<ApiVersion, crate::generics::KindExists, Name, Uid> {
+        self.body.kind = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Name of the referent. More info: http://kubernetes.io/docs/user-guide/identifiers#names
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> OwnerReferenceBuilder<ApiVersion, Kind, crate::generics::NameExists, Uid> {
+        self.body.name = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// UID of the referent. More info: http://kubernetes.io/docs/user-guide/identifiers#uids
+    #[inline]
+    pub fn uid(mut self, value: impl Into<String>) -> OwnerReferenceBuilder<ApiVersion, Kind, Name, crate::generics::UidExists> {
+        self.body.uid = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+}
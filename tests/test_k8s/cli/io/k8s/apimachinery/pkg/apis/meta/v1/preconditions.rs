@@ -0,0 +1,74 @@
+
+/// Preconditions must be fulfilled before an operation (update, delete, etc.) is carried out.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct Preconditions {
+    /// Specifies the target ResourceVersion
+    #[serde(rename = "resourceVersion", skip_serializing_if = "Option::is_none")]
+    pub resource_version: Option<String>,
+    /// Specifies the target UID.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub uid: Option<String>,
+}
+
+impl Preconditions {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> PreconditionsBuilder {
+        PreconditionsBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> PreconditionsBuilder
+    where
+        Self: Clone,
+    {
+        PreconditionsBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<Preconditions> for PreconditionsBuilder {
+    fn into(self) -> Preconditions {
+        self.body
+    }
+}
+
+/// Builder for [`Preconditions`](./struct.Preconditions.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct PreconditionsBuilder {
+    body: self::Preconditions,
+}
+
+impl PreconditionsBuilder {
+    /// Specifies the target ResourceVersion
+    #[inline]
+    pub fn resource_version(mut self, value: impl Into<String>) -> Self {
+        self.body.resource_version = Some(value.into());
+        self
+    }
+
+    /// Sets [`resource_version`](#method.resource_version) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_resource_version(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.resource_version = value.map(|value| value.into());
+        self
+    }
+
+    /// Specifies the target UID.
+    #[inline]
+    pub fn uid(mut self, value: impl Into<String>) -> Self {
+        self.body.uid = Some(value.into());
+        self
+    }
+
+    /// Sets [`uid`](#method.uid) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_uid(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.uid = value.map(|value| value.into());
+        self
+    }
+}
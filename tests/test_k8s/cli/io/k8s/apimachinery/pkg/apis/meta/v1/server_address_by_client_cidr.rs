@@ -0,0 +1,66 @@
+
+/// ServerAddressByClientCIDR helps the client to determine the server address that they should use, depending on the clientCIDR that they match.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct ServerAddressByClientCidr {
+    /// The CIDR with which clients can match their IP to figure out the server address that they should use.
+    #[serde(rename = "clientCIDR")]
+    pub client_cidr: String,
+    /// Address of this server, suitable for a client that matches the above CIDR. This can be a hostname, hostname:port, IP or IP:port.
+    #[serde(rename = "serverAddress")]
+    pub server_address: String,
+}
+
+impl ServerAddressByClientCidr {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> ServerAddressByClientCidrBuilder<crate::generics::MissingClientCidr, crate::generics::MissingServerAddress> {
+        ServerAddressByClientCidrBuilder {
+            body: Default::default(),
+            _client_cidr: core::marker::PhantomData,
+            _server_address: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> ServerAddressByClientCidrBuilder<crate::generics::ClientCidrExists, crate::generics::ServerAddressExists>
+    where
+        Self: Clone,
+    {
+        ServerAddressByClientCidrBuilder {
+            body: self.clone(),
+            _client_cidr: core::marker::PhantomData,
+            _server_address: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<ServerAddressByClientCidr> for ServerAddressByClientCidrBuilder<crate::generics::ClientCidrExists, crate::generics::ServerAddressExists> {
+    fn into(self) -> ServerAddressByClientCidr {
+        self.body
+    }
+}
+
+/// Builder for [`ServerAddressByClientCidr`](./struct.ServerAddressByClientCidr.html) object.
+#[derive(Debug, Clone)]
+pub struct ServerAddressByClientCidrBuilder<ClientCidr, ServerAddress> {
+    body: self::ServerAddressByClientCidr,
+    _client_cidr: core::marker::PhantomData<ClientCidr>,
+    _server_address: core::marker::PhantomData<ServerAddress>,
+}
+
+impl<ClientCidr, ServerAddress> ServerAddressByClientCidrBuilder<ClientCidr, ServerAddress> {
+    /// The CIDR with which clients can match their IP to figure out the server address that they should use.
+    #[inline]
+    pub fn client_cidr(mut self, value: impl Into<String>) -> ServerAddressByClientCidrBuilder<crate::generics::ClientCidrExists, ServerAddress> {
+        self.body.client_cidr = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Address of this server, suitable for a client that matches the above CIDR. This can be a hostname, hostname:port, IP or IP:port.
+    #[inline]
+    pub fn server_address(mut self, value: impl Into<String>) -> ServerAddressByClientCidrBuilder<ClientCidr, crate::generics::ServerAddressExists> {
+        self.body.server_address = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+}
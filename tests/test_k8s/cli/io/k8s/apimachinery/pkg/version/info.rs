@@ -0,0 +1,189 @@
+
+/// Info contains versioning information. how we'll want to distribute that information.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct Info {
+    #[serde(rename = "buildDate")]
+    pub build_date: String,
+    pub compiler: String,
+    #[serde(rename = "gitCommit")]
+    pub git_commit: String,
+    #[serde(rename = "gitTreeState")]
+    pub git_tree_state: String,
+    #[serde(rename = "gitVersion")]
+    pub git_version: String,
+    #[serde(rename = "goVersion")]
+    pub go_version: String,
+    pub major: String,
+    pub minor: String,
+    pub platform: String,
+}
+
+impl Info {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> InfoBuilder<crate::generics::MissingBuildDate, crate::generics::MissingCompiler, crate::generics::MissingGitCommit, crate::generics::MissingGitTreeState, crate::generics::MissingGitVersion, crate::generics::MissingGoVersion, crate::generics::MissingMajor, crate::generics::MissingMinor, crate::generics::MissingPlatform> {
+        InfoBuilder {
+            body: Default::default(),
+            _build_date: core::marker::PhantomData,
+            _compiler: core::marker::PhantomData,
+            _git_commit: core::marker::PhantomData,
+            _git_tree_state: core::marker::PhantomData,
+            _git_version: core::marker::PhantomData,
+            _go_version: core::marker::PhantomData,
+            _major: core::marker::PhantomData,
+            _minor: core::marker::PhantomData,
+            _platform: core::marker::PhantomData,
+        }
+    }
+
+    /// get the code version
+    #[inline]
+    pub fn get_code_version() -> InfoGetBuilder {
+        InfoGetBuilder
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> InfoBuilder<crate::generics::BuildDateExists, crate::generics::CompilerExists, crate::generics::GitCommitExists, crate::generics::GitTreeStateExists, crate::generics::GitVersionExists, crate::generics::GoVersionExists, crate::generics::MajorExists, crate::generics::MinorExists, crate::generics::PlatformExists>
+    where
+        Self: Clone,
+    {
+        InfoBuilder {
+            body: self.clone(),
+            _build_date: core::marker::PhantomData,
+            _compiler: core::marker::PhantomData,
+            _git_commit: core::marker::PhantomData,
+            _git_tree_state: core::marker::PhantomData,
+            _git_version: core::marker::PhantomData,
+            _go_version: core::marker::PhantomData,
+            _major: core::marker::PhantomData,
+            _minor: core::marker::PhantomData,
+            _platform: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<Info> for InfoBuilder<crate::generics::BuildDateExists, crate::generics::CompilerExists, crate::generics::GitCommitExists, crate::generics::GitTreeStateExists, crate::generics::GitVersionExists, crate::generics::GoVersionExists, crate::generics::MajorExists, crate::generics::MinorExists, crate::generics::PlatformExists> {
+    fn into(self) -> Info {
+        self.body
+    }
+}
+
+/// Builder for [`Info`](./struct.Info.html) object.
+#[derive(Debug, Clone)]
+pub struct InfoBuilder<BuildDate, Compiler, GitCommit, GitTreeState, GitVersion, GoVersion, Major, Minor, Platform> {
+    body: self::Info,
+    _build_date: core::marker::PhantomData<BuildDate>,
+    _compiler: core::marker::PhantomData<Compiler>,
+    _git_commit: core::marker::PhantomData<GitCommit>,
+    _git_tree_state: core::marker::PhantomData<GitTreeState>,
+    _git_version: core::marker::PhantomData<GitVersion>,
+    _go_version: core::marker::PhantomData<GoVersion>,
+    _major: core::marker::PhantomData<Major>,
+    _minor: core::marker::PhantomData<Minor>,
+    _platform: core::marker::PhantomData<Platform>,
+}
+
+impl<BuildDate, Compiler, GitCommit, GitTreeState, GitVersion, GoVersion, Major, Minor, Platform> InfoBuilder<BuildDate, Compiler, GitCommit, GitTreeState, GitVersion, GoVersion, Major, Minor, Platform> {
+    #[inline]
+    pub fn build_date(mut self, value: impl Into<String>) -> InfoBuilder<crate::generics::BuildDateExists, Compiler, GitCommit, GitTreeState, GitVersion, GoVersion, Major, Minor, Platform> {
+        self.body.build_date = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    #[inline]
+    pub fn compiler(mut self, value: impl Into<String>) -> InfoBuilder<BuildDate, crate::generics::CompilerExists, GitCommit, GitTreeState, GitVersion, GoVersion, Major, Minor, Platform> {
+        self.body.compiler = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    #[inline]
+    pub fn git_commit(mut self, value: impl Into<String>) -> InfoBuilder<BuildDate, Compiler, crate::generics::GitCommitExists, GitTreeState, GitVersion, GoVersion, Major, Minor, Platform> {
+        self.body.git_commit = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    #[inline]
+    pub fn git_tree_state(mut self, value: impl Into<String>) -> InfoBuilder<BuildDate, Compiler, GitCommit, crate::generics::GitTreeStateExists, GitVersion, GoVersion, Major, Minor, Platform> {
+        self.body.git_tree_state = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    #[inline]
+    pub fn git_version(mut self, value: impl Into<String>) -> InfoBuilder<BuildDate, Compiler, GitCommit, GitTreeState, crate::generics::GitVersionExists, GoVersion, Major, Minor, Platform> {
+        self.body.git_version = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    #[inline]
+    pub fn go_version(mut self, value: impl Into<String>) -> InfoBuilder<BuildDate, Compiler, GitCommit, GitTreeState, GitVersion, crate::generics::GoVersionExists, Major, Minor, Platform> {
+        self.body.go_version = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    #[inline]
+    pub fn major(mut self, value: impl Into<String>) -> InfoBuilder<BuildDate, Compiler, GitCommit, GitTreeState, GitVersion, GoVersion, crate::generics::MajorExists, Minor, Platform> {
+        self.body.major = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    #[inline]
+    pub fn minor(mut self, value: impl Into<String>) -> InfoBuilder<BuildDate, Compiler, GitCommit, GitTreeState, GitVersion, GoVersion, Major, crate::generics::MinorExists, Platform> {
+        self.body.minor = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    #[inline]
+    pub fn platform(mut self, value: impl Into<String>) -> InfoBuilder<BuildDate, Compiler, GitCommit, GitTreeState, GitVersion, GoVersion, Major, Minor, crate::generics::PlatformExists> {
+        self.body.platform = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+}
+
+/// Builder created by [`Info::get_code_version`](./struct.Info.html#method.get_code_version) method for a `GET` operation associated with `Info`.
+#[derive(Debug, Default, Clone)]
+pub struct InfoGetBuilder;
+
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for InfoGetBuilder {
+    type Output = Info;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    const OPERATION_ID: Option<&'static str> = Some("getCodeVersion");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok("/version/".into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::ACCEPT.as_str(), "application/json"))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<InfoGetBuilder> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: InfoGetBuilder) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl InfoGetBuilder {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = InfoGetBuilder {
+        };
+
+        Ok(thing)
+    }
+}
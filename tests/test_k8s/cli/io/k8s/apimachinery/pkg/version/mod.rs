@@ -0,0 +1,4 @@
+
+pub mod info {
+    include!("./info.rs");
+}
@@ -0,0 +1,64 @@
+
+/// APIServiceStatus contains derived information about an API server
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct ApiServiceStatus {
+    /// Current service state of apiService.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub conditions: Option<Vec<crate::io::k8s::kube_aggregator::pkg::apis::apiregistration::v1::api_service_condition::ApiServiceCondition>>,
+}
+
+impl ApiServiceStatus {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> ApiServiceStatusBuilder {
+        ApiServiceStatusBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> ApiServiceStatusBuilder
+    where
+        Self: Clone,
+    {
+        ApiServiceStatusBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<ApiServiceStatus> for ApiServiceStatusBuilder {
+    fn into(self) -> ApiServiceStatus {
+        self.body
+    }
+}
+
+/// Builder for [`ApiServiceStatus`](./struct.ApiServiceStatus.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct ApiServiceStatusBuilder {
+    body: self::ApiServiceStatus,
+}
+
+impl ApiServiceStatusBuilder {
+    /// Current service state of apiService.
+    #[inline]
+    pub fn conditions(mut self, value: impl Iterator<Item = crate::io::k8s::kube_aggregator::pkg::apis::apiregistration::v1::api_service_condition::ApiServiceConditionBuilder<crate::generics::StatusExists, crate::generics::TypeExists>>) -> Self {
+        self.body.conditions = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`conditions`](#method.conditions) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_conditions(mut self, value: Option<impl Iterator<Item = crate::io::k8s::kube_aggregator::pkg::apis::apiregistration::v1::api_service_condition::ApiServiceConditionBuilder<crate::generics::StatusExists, crate::generics::TypeExists>>>) -> Self {
+        self.body.conditions = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`conditions`](#method.conditions), but takes the values as a `Vec`.
+    #[inline]
+    pub fn conditions_vec(mut self, value: impl Into<Vec<crate::io::k8s::kube_aggregator::pkg::apis::apiregistration::v1::api_service_condition::ApiServiceCondition>>) -> Self {
+        self.body.conditions = Some(value.into());
+        self
+    }
+}
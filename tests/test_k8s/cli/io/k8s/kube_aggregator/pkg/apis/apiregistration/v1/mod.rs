@@ -0,0 +1,24 @@
+
+pub mod api_service {
+    include!("./api_service.rs");
+}
+
+pub mod api_service_condition {
+    include!("./api_service_condition.rs");
+}
+
+pub mod api_service_list {
+    include!("./api_service_list.rs");
+}
+
+pub mod api_service_spec {
+    include!("./api_service_spec.rs");
+}
+
+pub mod api_service_status {
+    include!("./api_service_status.rs");
+}
+
+pub mod service_reference {
+    include!("./service_reference.rs");
+}
@@ -0,0 +1,164 @@
+
+/// APIServiceSpec contains information for locating and communicating with a server. Only https is supported, though you are able to disable certificate verification.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct ApiServiceSpec {
+    /// CABundle is a PEM encoded CA bundle which will be used to validate an API server's serving certificate. If unspecified, system trust roots on the apiserver are used.
+    #[serde(rename = "caBundle", skip_serializing_if = "Option::is_none")]
+    pub ca_bundle: Option<String>,
+    /// Group is the API group name this server hosts
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub group: Option<String>,
+    /// GroupPriorityMininum is the priority this group should have at least. Higher priority means that the group is preferred by clients over lower priority ones. Note that other versions of this group might specify even higher GroupPriorityMininum values such that the whole group gets a higher priority. The primary sort is based on GroupPriorityMinimum, ordered highest number to lowest (20 before 10). The secondary sort is based on the alphabetical comparison of the name of the object.  (v1.bar before v1.foo) We'd recommend something like: \*.k8s.io (except extensions) at 18000 and PaaSes (OpenShift, Deis) are recommended to be in the 2000s
+    #[serde(rename = "groupPriorityMinimum")]
+    pub group_priority_minimum: i32,
+    /// InsecureSkipTLSVerify disables TLS certificate verification when communicating with this server. This is strongly discouraged.  You should use the CABundle instead.
+    #[serde(rename = "insecureSkipTLSVerify", skip_serializing_if = "Option::is_none")]
+    pub insecure_skip_tls_verify: Option<bool>,
+    /// Service is a reference to the service for this API server.  It must communicate on port 443 If the Service is nil, that means the handling for the API groupversion is handled locally on this server. The call will simply delegate to the normal handler chain to be fulfilled.
+    pub service: crate::io::k8s::kube_aggregator::pkg::apis::apiregistration::v1beta1::service_reference::ServiceReference,
+    /// Version is the API version this server hosts.  For example, "v1"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+    /// VersionPriority controls the ordering of this API version inside of its group.  Must be greater than zero. The primary sort is based on VersionPriority, ordered highest to lowest (20 before 10). Since it's inside of a group, the number can be small, probably in the 10s. In case of equal version priorities, the version string will be used to compute the order inside a group. If the version string is "kube-like", it will sort above non "kube-like" version strings, which are ordered lexicographically. "Kube-like" versions start with a "v", then are followed by a number (the major version), then optionally the string "alpha" or "beta" and another number (the minor version). These are sorted first by GA > beta > alpha (where GA is a version with no suffix such as beta or alpha), and then by comparing major version, then minor version. An example sorted list of versions: v10, v2, v1, v11beta2, v10beta3, v3beta1, v12alpha1, v11alpha2, foo1, foo10.
+    #[serde(rename = "versionPriority")]
+    pub version_priority: i32,
+}
+
+impl ApiServiceSpec {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> ApiServiceSpecBuilder<crate::generics::MissingGroupPriorityMinimum, crate::generics::MissingService, crate::generics::MissingVersionPriority> {
+        ApiServiceSpecBuilder {
+            body: Default::default(),
+            _group_priority_minimum: core::marker::PhantomData,
+            _service: core::marker::PhantomData,
+            _version_priority: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> ApiServiceSpecBuilder<crate::generics::GroupPriorityMinimumExists, crate::generics::ServiceExists, crate::generics::VersionPriorityExists>
+    where
+        Self: Clone,
+    {
+        ApiServiceSpecBuilder {
+            body: self.clone(),
+            _group_priority_minimum: core::marker::PhantomData,
+            _service: core::marker::PhantomData,
+            _version_priority: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<ApiServiceSpec> for ApiServiceSpecBuilder<crate::generics::GroupPriorityMinimumExists, crate::generics::ServiceExists, crate::generics::VersionPriorityExists> {
+    fn into(self) -> ApiServiceSpec {
+        self.body
+    }
+}
+
+/// Builder for [`ApiServiceSpec`](./struct.ApiServiceSpec.html) object.
+#[derive(Debug, Clone)]
+pub struct ApiServiceSpecBuilder<GroupPriorityMinimum, Service, VersionPriority> {
+    body: self::ApiServiceSpec,
+    _group_priority_minimum: core::marker::PhantomData<GroupPriorityMinimum>,
+    _service: core::marker::PhantomData<Service>,
+    _version_priority: core::marker::PhantomData<VersionPriority>,
+}
+
+impl<GroupPriorityMinimum, Service, VersionPriority> ApiServiceSpecBuilder<GroupPriorityMinimum, Service, VersionPriority> {
+    /// CABundle is a PEM encoded CA bundle which will be used to validate an API server's serving certificate. If unspecified, system trust roots on the apiserver are used.
+    #[inline]
+    pub fn ca_bundle(mut self, value: impl Into<String>) -> Self {
+        self.body.ca_bundle = Some(value.into());
+        self
+    }
+
+    /// Sets [`ca_bundle`](#method.ca_bundle) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_ca_bundle(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.ca_bundle = value.map(|value| value.into());
+        self
+    }
+
+    /// Group is the API group name this server hosts
+    #[inline]
+    pub fn group(mut self, value: impl Into<String>) -> Self {
+        self.body.group = Some(value.into());
+        self
+    }
+
+    /// Sets [`group`](#method.group) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_group(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.group = value.map(|value| value.into());
+        self
+    }
+
+    /// GroupPriorityMininum is the priority this group should have at least. Higher priority means that the group is preferred by clients over lower priority ones. Note that other versions of this group might specify even higher GroupPriorityMininum values such that the whole group gets a higher priority. The primary sort is based on GroupPriorityMinimum, ordered highest number to lowest (20 before 10). The secondary sort is based on the alphabetical comparison of the name of the object.  (v1.bar before v1.foo) We'd recommend something like: \*.k8s.io (except extensions) at 18000 and PaaSes (OpenShift, Deis) are recommended to be in the 2000s
+    #[inline]
+    pub fn group_priority_minimum(mut self, value: impl Into<i32>) -> ApiServiceSpecBuilder<crate::generics::GroupPriorityMinimumExists, Service, VersionPriority> {
+        self.body.group_priority_minimum = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Same as [`group_priority_minimum`](#method.group_priority_minimum), but takes a reference.
+    #[inline]
+    pub fn group_priority_minimum_ref(self, value: &i32) -> ApiServiceSpecBuilder<crate::generics::GroupPriorityMinimumExists, Service, VersionPriority> {
+        self.group_priority_minimum(*value)
+    }
+
+    /// InsecureSkipTLSVerify disables TLS certificate verification when communicating with this server. This is strongly discouraged.  You should use the CABundle instead.
+    #[inline]
+    pub fn insecure_skip_tls_verify(mut self, value: impl Into<bool>) -> Self {
+        self.body.insecure_skip_tls_verify = Some(value.into());
+        self
+    }
+
+    /// Sets [`insecure_skip_tls_verify`](#method.insecure_skip_tls_verify) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_insecure_skip_tls_verify(mut self, value: Option<impl Into<bool>>) -> Self {
+        self.body.insecure_skip_tls_verify = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`insecure_skip_tls_verify`](#method.insecure_skip_tls_verify), but takes a reference.
+    #[inline]
+    pub fn insecure_skip_tls_verify_ref(self, value: &bool) -> Self {
+        self.insecure_skip_tls_verify(*value)
+    }
+
+    /// Service is a reference to the service for this API server.  It must communicate on port 443 If the Service is nil, that means the handling for the API groupversion is handled locally on this server. The call will simply delegate to the normal handler chain to be fulfilled.
+    #[inline]
+    pub fn service(mut self, value: crate::io::k8s::kube_aggregator::pkg::apis::apiregistration::v1beta1::service_reference::ServiceReference) -> ApiServiceSpecBuilder<GroupPriorityMinimum, crate::generics::ServiceExists, VersionPriority> {
+        self.body.service = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Version is the API version this server hosts.  For example, "v1"
+    #[inline]
+    pub fn version(mut self, value: impl Into<String>) -> Self {
+        self.body.version = Some(value.into());
+        self
+    }
+
+    /// Sets [`version`](#method.version) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_version(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.version = value.map(|value| value.into());
+        self
+    }
+
+    /// VersionPriority controls the ordering of this API version inside of its group.  Must be greater than zero. The primary sort is based on VersionPriority, ordered highest to lowest (20 before 10). Since it's inside of a group, the number can be small, probably in the 10s. In case of equal version priorities, the version string will be used to compute the order inside a group. If the version string is "kube-like", it will sort above non "kube-like" version strings, which are ordered lexicographically. "Kube-like" versions start with a "v", then are followed by a number (the major version), then optionally the string "alpha" or "beta" and another number (the minor version). These are sorted first by GA > beta > alpha (where GA is a version with no suffix such as beta or alpha), and then by comparing major version, then minor version. An example sorted list of versions: v10, v2, v1, v11beta2, v10beta3, v3beta1, v12alpha1, v11alpha2, foo1, foo10.
+    #[inline]
+    pub fn version_priority(mut self, value: impl Into<i32>) -> ApiServiceSpecBuilder<GroupPriorityMinimum, Service, crate::generics::VersionPriorityExists> {
+        self.body.version_priority = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Same as [`version_priority`](#method.version_priority), but takes a reference.
+    #[inline]
+    pub fn version_priority_ref(self, value: &i32) -> ApiServiceSpecBuilder<GroupPriorityMinimum, Service, crate::generics::VersionPriorityExists> {
+        self.version_priority(*value)
+    }
+}
@@ -0,0 +1,4 @@
+
+pub mod apiregistration {
+    include!("./apiregistration/mod.rs");
+}
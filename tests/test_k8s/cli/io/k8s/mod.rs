@@ -0,0 +1,16 @@
+
+pub mod api {
+    include!("./api/mod.rs");
+}
+
+pub mod apiextensions_apiserver {
+    include!("./apiextensions_apiserver/mod.rs");
+}
+
+pub mod apimachinery {
+    include!("./apimachinery/mod.rs");
+}
+
+pub mod kube_aggregator {
+    include!("./kube_aggregator/mod.rs");
+}
@@ -0,0 +1,4 @@
+
+pub mod k8s {
+    include!("./k8s/mod.rs");
+}
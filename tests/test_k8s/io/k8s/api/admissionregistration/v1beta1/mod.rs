@@ -0,0 +1,32 @@
+
+pub mod mutating_webhook_configuration {
+    include!("./mutating_webhook_configuration.rs");
+}
+
+pub mod mutating_webhook_configuration_list {
+    include!("./mutating_webhook_configuration_list.rs");
+}
+
+pub mod rule_with_operations {
+    include!("./rule_with_operations.rs");
+}
+
+pub mod service_reference {
+    include!("./service_reference.rs");
+}
+
+pub mod validating_webhook_configuration {
+    include!("./validating_webhook_configuration.rs");
+}
+
+pub mod validating_webhook_configuration_list {
+    include!("./validating_webhook_configuration_list.rs");
+}
+
+pub mod webhook {
+    include!("./webhook.rs");
+}
+
+pub mod webhook_client_config {
+    include!("./webhook_client_config.rs");
+}
@@ -0,0 +1,165 @@
+
+/// RuleWithOperations is a tuple of Operations and Resources. It is recommended to make sure that all the tuple expansions are valid.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct RuleWithOperations {
+    /// APIGroups is the API groups the resources belong to. '\*' is all groups. If '\*' is present, the length of the slice must be one. Required.
+    #[serde(rename = "apiGroups", skip_serializing_if = "Option::is_none")]
+    pub api_groups: Option<Vec<String>>,
+    /// APIVersions is the API versions the resources belong to. '\*' is all versions. If '\*' is present, the length of the slice must be one. Required.
+    #[serde(rename = "apiVersions", skip_serializing_if = "Option::is_none")]
+    pub api_versions: Option<Vec<String>>,
+    /// Operations is the operations the admission hook cares about - CREATE, UPDATE, or * for all operations. If '\*' is present, the length of the slice must be one. Required.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub operations: Option<Vec<String>>,
+    /// Resources is a list of resources this rule applies to.
+    ///
+    /// For example: 'pods' means pods. 'pods/log' means the log subresource of pods. '\*' means all resources, but not subresources. 'pods/\*' means all subresources of pods. '\*/scale' means all scale subresources. '\*/\*' means all resources and their subresources.
+    ///
+    /// If wildcard is present, the validation rule will ensure resources do not overlap with each other.
+    ///
+    /// Depending on the enclosing object, subresources might not be allowed. Required.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resources: Option<Vec<String>>,
+    /// scope specifies the scope of this rule. Valid values are "Cluster", "Namespaced", and "\*" "Cluster" means that only cluster-scoped resources will match this rule. Namespace API objects are cluster-scoped. "Namespaced" means that only namespaced resources will match this rule. "\*" means that there are no scope restrictions. Subresources match the scope of their parent resource. Default is "\*".
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scope: Option<String>,
+}
+
+impl RuleWithOperations {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> RuleWithOperationsBuilder {
+        RuleWithOperationsBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> RuleWithOperationsBuilder
+    where
+        Self: Clone,
+    {
+        RuleWithOperationsBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<RuleWithOperations> for RuleWithOperationsBuilder {
+    fn into(self) -> RuleWithOperations {
+        self.body
+    }
+}
+
+/// Builder for [`RuleWithOperations`](./struct.RuleWithOperations.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct RuleWithOperationsBuilder {
+    body: self::RuleWithOperations,
+}
+
+impl RuleWithOperationsBuilder {
+    /// APIGroups is the API groups the resources belong to. '\*' is all groups. If '\*' is present, the length of the slice must be one. Required.
+    #[inline]
+    pub fn api_groups(mut self, value: impl Iterator<Item = impl Into<String>>) -> Self {
+        self.body.api_groups = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`api_groups`](#method.api_groups) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_api_groups(mut self, value: Option<impl Iterator<Item = impl Into<String>>>) -> Self {
+        self.body.api_groups = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`api_groups`](#method.api_groups), but takes the values as a `Vec`.
+    #[inline]
+    pub fn api_groups_vec(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.body.api_groups = Some(value.into());
+        self
+    }
+
+    /// APIVersions is the API versions the resources belong to. '\*' is all versions. If '\*' is present, the length of the slice must be one. Required.
+    #[inline]
+    pub fn api_versions(mut self, value: impl Iterator<Item = impl Into<String>>) -> Self {
+        self.body.api_versions = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`api_versions`](#method.api_versions) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_api_versions(mut self, value: Option<impl Iterator<Item = impl Into<String>>>) -> Self {
+        self.body.api_versions = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`api_versions`](#method.api_versions), but takes the values as a `Vec`.
+    #[inline]
+    pub fn api_versions_vec(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.body.api_versions = Some(value.into());
+        self
+    }
+
+    /// Operations is the operations the admission hook cares about - CREATE, UPDATE, or * for all operations. If '\*' is present, the length of the slice must be one. Required.
+    #[inline]
+    pub fn operations(mut self, value: impl Iterator<Item = impl Into<String>>) -> Self {
+        self.body.operations = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`operations`](#method.operations) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_operations(mut self, value: Option<impl Iterator<Item = impl Into<String>>>) -> Self {
+        self.body.operations = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`operations`](#method.operations), but takes the values as a `Vec`.
+    #[inline]
+    pub fn operations_vec(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.body.operations = Some(value.into());
+        self
+    }
+
+    /// Resources is a list of resources this rule applies to.
+    ///
+    /// For example: 'pods' means pods. 'pods/log' means the log subresource of pods. '\*' means all resources, but not subresources. 'pods/\*' means all subresources of pods. '\*/scale' means all scale subresources. '\*/\*' means all resources and their subresources.
+    ///
+    /// If wildcard is present, the validation rule will ensure resources do not overlap with each other.
+    ///
+    /// Depending on the enclosing object, subresources might not be allowed. Required.
+    #[inline]
+    pub fn resources(mut self, value: impl Iterator<Item = impl Into<String>>) -> Self {
+        self.body.resources = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`resources`](#method.resources) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_resources(mut self, value: Option<impl Iterator<Item = impl Into<String>>>) -> Self {
+        self.body.resources = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`resources`](#method.resources), but takes the values as a `Vec`.
+    #[inline]
+    pub fn resources_vec(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.body.resources = Some(value.into());
+        self
+    }
+
+    /// scope specifies the scope of this rule. Valid values are "Cluster", "Namespaced", and "\*" "Cluster" means that only cluster-scoped resources will match this rule. Namespace API objects are cluster-scoped. "Namespaced" means that only namespaced resources will match this rule. "\*" means that there are no scope restrictions. Subresources match the scope of their parent resource. Default is "\*".
+    #[inline]
+    pub fn scope(mut self, value: impl Into<String>) -> Self {
+        self.body.scope = Some(value.into());
+        self
+    }
+
+    /// Sets [`scope`](#method.scope) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_scope(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.scope = value.map(|value| value.into());
+        self
+    }
+}
@@ -0,0 +1,115 @@
+
+/// WebhookClientConfig contains the information to make a TLS connection with the webhook
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct WebhookClientConfig {
+    /// `caBundle` is a PEM encoded CA bundle which will be used to validate the webhook's server certificate. If unspecified, system trust roots on the apiserver are used.
+    #[serde(rename = "caBundle", skip_serializing_if = "Option::is_none")]
+    pub ca_bundle: Option<String>,
+    /// `service` is a reference to the service for this webhook. Either `service` or `url` must be specified.
+    ///
+    /// If the webhook is running within the cluster, then you should use `service`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub service: Option<crate::codegen::io::k8s::api::admissionregistration::v1beta1::service_reference::ServiceReference>,
+    /// `url` gives the location of the webhook, in standard URL form (`scheme://host:port/path`). Exactly one of `url` or `service` must be specified.
+    ///
+    /// The `host` should not refer to a service running in the cluster; use the `service` field instead. The host might be resolved via external DNS in some apiservers (e.g., `kube-apiserver` cannot resolve in-cluster DNS as that would be a layering violation). `host` may also be an IP address.
+    ///
+    /// Please note that using `localhost` or `127.0.0.1` as a `host` is risky unless you take great care to run this webhook on all hosts which run an apiserver which might need to make calls to this webhook. Such installs are likely to be non-portable, i.e., not easy to turn up in a new cluster.
+    ///
+    /// The scheme must be "https"; the URL must begin with "https://".
+    ///
+    /// A path is optional, and if present may be any string permissible in a URL. You may use the path to pass an arbitrary string to the webhook, for example, a cluster identifier.
+    ///
+    /// Attempting to use a user or basic auth e.g. "user:password@" is not allowed. Fragments ("#...") and query parameters ("?...") are not allowed, either.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+impl WebhookClientConfig {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> WebhookClientConfigBuilder {
+        WebhookClientConfigBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> WebhookClientConfigBuilder
+    where
+        Self: Clone,
+    {
+        WebhookClientConfigBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<WebhookClientConfig> for WebhookClientConfigBuilder {
+    fn into(self) -> WebhookClientConfig {
+        self.body
+    }
+}
+
+/// Builder for [`WebhookClientConfig`](./struct.WebhookClientConfig.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct WebhookClientConfigBuilder {
+    body: self::WebhookClientConfig,
+}
+
+impl WebhookClientConfigBuilder {
+    /// `caBundle` is a PEM encoded CA bundle which will be used to validate the webhook's server certificate. If unspecified, system trust roots on the apiserver are used.
+    #[inline]
+    pub fn ca_bundle(mut self, value: impl Into<String>) -> Self {
+        self.body.ca_bundle = Some(value.into());
+        self
+    }
+
+    /// Sets [`ca_bundle`](#method.ca_bundle) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_ca_bundle(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.ca_bundle = value.map(|value| value.into());
+        self
+    }
+
+    /// `service` is a reference to the service for this webhook. Either `service` or `url` must be specified.
+    ///
+    /// If the webhook is running within the cluster, then you should use `service`.
+    #[inline]
+    pub fn service(mut self, value: crate::codegen::io::k8s::api::admissionregistration::v1beta1::service_reference::ServiceReferenceBuilder<crate::codegen::generics::NameExists, crate::codegen::generics::NamespaceExists>) -> Self {
+        self.body.service = Some(value.into());
+        self
+    }
+
+    /// Sets [`service`](#method.service) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_service(mut self, value: Option<crate::codegen::io::k8s::api::admissionregistration::v1beta1::service_reference::ServiceReferenceBuilder<crate::codegen::generics::NameExists, crate::codegen::generics::NamespaceExists>>) -> Self {
+        self.body.service = value.map(|value| value.into());
+        self
+    }
+
+    /// `url` gives the location of the webhook, in standard URL form (`scheme://host:port/path`). Exactly one of `url` or `service` must be specified.
+    ///
+    /// The `host` should not refer to a service running in the cluster; use the `service` field instead. The host might be resolved via external DNS in some apiservers (e.g., `kube-apiserver` cannot resolve in-cluster DNS as that would be a layering violation). `host` may also be an IP address.
+    ///
+    /// Please note that using `localhost` or `127.0.0.1` as a `host` is risky unless you take great care to run this webhook on all hosts which run an apiserver which might need to make calls to this webhook. Such installs are likely to be non-portable, i.e., not easy to turn up in a new cluster.
+    ///
+    /// The scheme must be "https"; the URL must begin with "https://".
+    ///
+    /// A path is optional, and if present may be any string permissible in a URL. You may use the path to pass an arbitrary string to the webhook, for example, a cluster identifier.
+    ///
+    /// Attempting to use a user or basic auth e.g. "user:password@" is not allowed. Fragments ("#...") and query parameters ("?...") are not allowed, either.
+    #[inline]
+    pub fn url(mut self, value: impl Into<String>) -> Self {
+        self.body.url = Some(value.into());
+        self
+    }
+
+    /// Sets [`url`](#method.url) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_url(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.url = value.map(|value| value.into());
+        self
+    }
+}
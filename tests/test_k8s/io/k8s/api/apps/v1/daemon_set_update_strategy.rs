@@ -0,0 +1,74 @@
+
+/// DaemonSetUpdateStrategy is a struct used to control the update strategy for a DaemonSet.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct DaemonSetUpdateStrategy {
+    /// Rolling update config params. Present only if type = "RollingUpdate".
+    #[serde(rename = "rollingUpdate", skip_serializing_if = "Option::is_none")]
+    pub rolling_update: Option<crate::codegen::io::k8s::api::apps::v1::rolling_update_daemon_set::RollingUpdateDaemonSet>,
+    /// Type of daemon set update. Can be "RollingUpdate" or "OnDelete". Default is RollingUpdate.
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub type_: Option<String>,
+}
+
+impl DaemonSetUpdateStrategy {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> DaemonSetUpdateStrategyBuilder {
+        DaemonSetUpdateStrategyBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> DaemonSetUpdateStrategyBuilder
+    where
+        Self: Clone,
+    {
+        DaemonSetUpdateStrategyBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<DaemonSetUpdateStrategy> for DaemonSetUpdateStrategyBuilder {
+    fn into(self) -> DaemonSetUpdateStrategy {
+        self.body
+    }
+}
+
+/// Builder for [`DaemonSetUpdateStrategy`](./struct.DaemonSetUpdateStrategy.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct DaemonSetUpdateStrategyBuilder {
+    body: self::DaemonSetUpdateStrategy,
+}
+
+impl DaemonSetUpdateStrategyBuilder {
+    /// Rolling update config params. Present only if type = "RollingUpdate".
+    #[inline]
+    pub fn rolling_update(mut self, value: crate::codegen::io::k8s::api::apps::v1::rolling_update_daemon_set::RollingUpdateDaemonSet) -> Self {
+        self.body.rolling_update = Some(value.into());
+        self
+    }
+
+    /// Sets [`rolling_update`](#method.rolling_update) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_rolling_update(mut self, value: Option<crate::codegen::io::k8s::api::apps::v1::rolling_update_daemon_set::RollingUpdateDaemonSet>) -> Self {
+        self.body.rolling_update = value.map(|value| value.into());
+        self
+    }
+
+    /// Type of daemon set update. Can be "RollingUpdate" or "OnDelete". Default is RollingUpdate.
+    #[inline]
+    pub fn type_(mut self, value: impl Into<String>) -> Self {
+        self.body.type_ = Some(value.into());
+        self
+    }
+
+    /// Sets [`type_`](#method.type_) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_type(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.type_ = value.map(|value| value.into());
+        self
+    }
+}
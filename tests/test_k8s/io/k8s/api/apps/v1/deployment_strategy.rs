@@ -0,0 +1,74 @@
+
+/// DeploymentStrategy describes how to replace existing pods with new ones.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct DeploymentStrategy {
+    /// Rolling update config params. Present only if DeploymentStrategyType = RollingUpdate.
+    #[serde(rename = "rollingUpdate", skip_serializing_if = "Option::is_none")]
+    pub rolling_update: Option<crate::codegen::io::k8s::api::apps::v1::rolling_update_deployment::RollingUpdateDeployment>,
+    /// Type of deployment. Can be "Recreate" or "RollingUpdate". Default is RollingUpdate.
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub type_: Option<String>,
+}
+
+impl DeploymentStrategy {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> DeploymentStrategyBuilder {
+        DeploymentStrategyBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> DeploymentStrategyBuilder
+    where
+        Self: Clone,
+    {
+        DeploymentStrategyBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<DeploymentStrategy> for DeploymentStrategyBuilder {
+    fn into(self) -> DeploymentStrategy {
+        self.body
+    }
+}
+
+/// Builder for [`DeploymentStrategy`](./struct.DeploymentStrategy.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct DeploymentStrategyBuilder {
+    body: self::DeploymentStrategy,
+}
+
+impl DeploymentStrategyBuilder {
+    /// Rolling update config params. Present only if DeploymentStrategyType = RollingUpdate.
+    #[inline]
+    pub fn rolling_update(mut self, value: crate::codegen::io::k8s::api::apps::v1::rolling_update_deployment::RollingUpdateDeployment) -> Self {
+        self.body.rolling_update = Some(value.into());
+        self
+    }
+
+    /// Sets [`rolling_update`](#method.rolling_update) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_rolling_update(mut self, value: Option<crate::codegen::io::k8s::api::apps::v1::rolling_update_deployment::RollingUpdateDeployment>) -> Self {
+        self.body.rolling_update = value.map(|value| value.into());
+        self
+    }
+
+    /// Type of deployment. Can be "Recreate" or "RollingUpdate". Default is RollingUpdate.
+    #[inline]
+    pub fn type_(mut self, value: impl Into<String>) -> Self {
+        self.body.type_ = Some(value.into());
+        self
+    }
+
+    /// Sets [`type_`](#method.type_) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_type(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.type_ = value.map(|value| value.into());
+        self
+    }
+}
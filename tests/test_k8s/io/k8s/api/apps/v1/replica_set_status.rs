@@ -0,0 +1,174 @@
+
+/// ReplicaSetStatus represents the current status of a ReplicaSet.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct ReplicaSetStatus {
+    /// The number of available replicas (ready for at least minReadySeconds) for this replica set.
+    #[serde(rename = "availableReplicas", skip_serializing_if = "Option::is_none")]
+    pub available_replicas: Option<i32>,
+    /// Represents the latest available observations of a replica set's current state.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub conditions: Option<Vec<crate::codegen::io::k8s::api::apps::v1::replica_set_condition::ReplicaSetCondition>>,
+    /// The number of pods that have labels matching the labels of the pod template of the replicaset.
+    #[serde(rename = "fullyLabeledReplicas", skip_serializing_if = "Option::is_none")]
+    pub fully_labeled_replicas: Option<i32>,
+    /// ObservedGeneration reflects the generation of the most recently observed ReplicaSet.
+    #[serde(rename = "observedGeneration", skip_serializing_if = "Option::is_none")]
+    pub observed_generation: Option<i64>,
+    /// The number of ready replicas for this replica set.
+    #[serde(rename = "readyReplicas", skip_serializing_if = "Option::is_none")]
+    pub ready_replicas: Option<i32>,
+    /// Replicas is the most recently oberved number of replicas. More info: https://kubernetes.io/docs/concepts/workloads/controllers/replicationcontroller/#what-is-a-replicationcontroller
+    pub replicas: i32,
+}
+
+impl ReplicaSetStatus {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> ReplicaSetStatusBuilder<crate::codegen::generics::MissingReplicas> {
+        ReplicaSetStatusBuilder {
+            body: Default::default(),
+            _replicas: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> ReplicaSetStatusBuilder<crate::codegen::generics::ReplicasExists>
+    where
+        Self: Clone,
+    {
+        ReplicaSetStatusBuilder {
+            body: self.clone(),
+            _replicas: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<ReplicaSetStatus> for ReplicaSetStatusBuilder<crate::codegen::generics::ReplicasExists> {
+    fn into(self) -> ReplicaSetStatus {
+        self.body
+    }
+}
+
+/// Builder for [`ReplicaSetStatus`](./struct.ReplicaSetStatus.html) object.
+#[derive(Debug, Clone)]
+pub struct ReplicaSetStatusBuilder<Replicas> {
+    body: self::ReplicaSetStatus,
+    _replicas: core::marker::PhantomData<Replicas>,
+}
+
+impl<Replicas> ReplicaSetStatusBuilder<Replicas> {
+    /// The number of available replicas (ready for at least minReadySeconds) for this replica set.
+    #[inline]
+    pub fn available_replicas(mut self, value: impl Into<i32>) -> Self {
+        self.body.available_replicas = Some(value.into());
+        self
+    }
+
+    /// Sets [`available_replicas`](#method.available_replicas) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_available_replicas(mut self, value: Option<impl Into<i32>>) -> Self {
+        self.body.available_replicas = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`available_replicas`](#method.available_replicas), but takes a reference.
+    #[inline]
+    pub fn available_replicas_ref(self, value: &i32) -> Self {
+        self.available_replicas(*value)
+    }
+
+    /// Represents the latest available observations of a replica set's current state.
+    #[inline]
+    pub fn conditions(mut self, value: impl Iterator<Item = crate::codegen::io::k8s::api::apps::v1::replica_set_condition::ReplicaSetConditionBuilder<crate::codegen::generics::StatusExists, crate::codegen::generics::TypeExists>>) -> Self {
+        self.body.conditions = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`conditions`](#method.conditions) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_conditions(mut self, value: Option<impl Iterator<Item = crate::codegen::io::k8s::api::apps::v1::replica_set_condition::ReplicaSetConditionBuilder<crate::codegen::generics::StatusExists, crate::codegen::generics::TypeExists>>>) -> Self {
+        self.body.conditions = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`conditions`](#method.conditions), but takes the values as a `Vec`.
+    #[inline]
+    pub fn conditions_vec(mut self, value: impl Into<Vec<crate::codegen::io::k8s::api::apps::v1::replica_set_condition::ReplicaSetCondition>>) -> Self {
+        self.body.conditions = Some(value.into());
+        self
+    }
+
+    /// The number of pods that have labels matching the labels of the pod template of the replicaset.
+    #[inline]
+    pub fn fully_labeled_replicas(mut self, value: impl Into<i32>) -> Self {
+        self.body.fully_labeled_replicas = Some(value.into());
+        self
+    }
+
+    /// Sets [`fully_labeled_replicas`](#method.fully_labeled_replicas) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_fully_labeled_replicas(mut self, value: Option<impl Into<i32>>) -> Self {
+        self.body.fully_labeled_replicas = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`fully_labeled_replicas`](#method.fully_labeled_replicas), but takes a reference.
+    #[inline]
+    pub fn fully_labeled_replicas_ref(self, value: &i32) -> Self {
+        self.fully_labeled_replicas(*value)
+    }
+
+    /// ObservedGeneration reflects the generation of the most recently observed ReplicaSet.
+    #[inline]
+    pub fn observed_generation(mut self, value: impl Into<i64>) -> Self {
+        self.body.observed_generation = Some(value.into());
+        self
+    }
+
+    /// Sets [`observed_generation`](#method.observed_generation) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_observed_generation(mut self, value: Option<impl Into<i64>>) -> Self {
+        self.body.observed_generation = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`observed_generation`](#method.observed_generation), but takes a reference.
+    #[inline]
+    pub fn observed_generation_ref(self, value: &i64) -> Self {
+        self.observed_generation(*value)
+    }
+
+    /// The number of ready replicas for this replica set.
+    #[inline]
+    pub fn ready_replicas(mut self, value: impl Into<i32>) -> Self {
+        self.body.ready_replicas = Some(value.into());
+        self
+    }
+
+    /// Sets [`ready_replicas`](#method.ready_replicas) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_ready_replicas(mut self, value: Option<impl Into<i32>>) -> Self {
+        self.body.ready_replicas = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`ready_replicas`](#method.ready_replicas), but takes a reference.
+    #[inline]
+    pub fn ready_replicas_ref(self, value: &i32) -> Self {
+        self.ready_replicas(*value)
+    }
+
+    /// Replicas is the most recently oberved number of replicas. More info: https://kubernetes.io/docs/concepts/workloads/controllers/replicationcontroller/#what-is-a-replicationcontroller
+    #[inline]
+    pub fn replicas(mut self, value: impl Into<i32>) -> ReplicaSetStatusBuilder<crate::codegen::generics::ReplicasExists> {
+        self.body.replicas = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Same as [`replicas`](#method.replicas), but takes a reference.
+    #[inline]
+    pub fn replicas_ref(self, value: &i32) -> ReplicaSetStatusBuilder<crate::codegen::generics::ReplicasExists> {
+        self.replicas(*value)
+    }
+}
@@ -0,0 +1,57 @@
+
+/// Spec to control the desired behavior of daemon set rolling update.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct RollingUpdateDaemonSet {
+    /// The maximum number of DaemonSet pods that can be unavailable during the update. Value can be an absolute number (ex: 5) or a percentage of total number of DaemonSet pods at the start of the update (ex: 10%). Absolute number is calculated from percentage by rounding up. This cannot be 0. Default value is 1. Example: when this is set to 30%, at most 30% of the total number of nodes that should be running the daemon pod (i.e. status.desiredNumberScheduled) can have their pods stopped for an update at any given time. The update starts by stopping at most 30% of those DaemonSet pods and then brings up new DaemonSet pods in their place. Once the new pods are available, it then proceeds onto other DaemonSet pods, thus ensuring that at least 70% of original number of DaemonSet pods are available at all times during the update.
+    #[serde(rename = "maxUnavailable", skip_serializing_if = "Option::is_none")]
+    pub max_unavailable: Option<String>,
+}
+
+impl RollingUpdateDaemonSet {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> RollingUpdateDaemonSetBuilder {
+        RollingUpdateDaemonSetBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> RollingUpdateDaemonSetBuilder
+    where
+        Self: Clone,
+    {
+        RollingUpdateDaemonSetBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<RollingUpdateDaemonSet> for RollingUpdateDaemonSetBuilder {
+    fn into(self) -> RollingUpdateDaemonSet {
+        self.body
+    }
+}
+
+/// Builder for [`RollingUpdateDaemonSet`](./struct.RollingUpdateDaemonSet.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct RollingUpdateDaemonSetBuilder {
+    body: self::RollingUpdateDaemonSet,
+}
+
+impl RollingUpdateDaemonSetBuilder {
+    /// The maximum number of DaemonSet pods that can be unavailable during the update. Value can be an absolute number (ex: 5) or a percentage of total number of DaemonSet pods at the start of the update (ex: 10%). Absolute number is calculated from percentage by rounding up. This cannot be 0. Default value is 1. Example: when this is set to 30%, at most 30% of the total number of nodes that should be running the daemon pod (i.e. status.desiredNumberScheduled) can have their pods stopped for an update at any given time. The update starts by stopping at most 30% of those DaemonSet pods and then brings up new DaemonSet pods in their place. Once the new pods are available, it then proceeds onto other DaemonSet pods, thus ensuring that at least 70% of original number of DaemonSet pods are available at all times during the update.
+    #[inline]
+    pub fn max_unavailable(mut self, value: impl Into<String>) -> Self {
+        self.body.max_unavailable = Some(value.into());
+        self
+    }
+
+    /// Sets [`max_unavailable`](#method.max_unavailable) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_max_unavailable(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.max_unavailable = value.map(|value| value.into());
+        self
+    }
+}
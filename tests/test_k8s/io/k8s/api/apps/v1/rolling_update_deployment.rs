@@ -0,0 +1,74 @@
+
+/// Spec to control the desired behavior of rolling update.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct RollingUpdateDeployment {
+    /// The maximum number of pods that can be scheduled above the desired number of pods. Value can be an absolute number (ex: 5) or a percentage of desired pods (ex: 10%). This can not be 0 if MaxUnavailable is 0. Absolute number is calculated from percentage by rounding up. Defaults to 25%. Example: when this is set to 30%, the new ReplicaSet can be scaled up immediately when the rolling update starts, such that the total number of old and new pods do not exceed 130% of desired pods. Once old pods have been killed, new ReplicaSet can be scaled up further, ensuring that total number of pods running at any time during the update is at most 130% of desired pods.
+    #[serde(rename = "maxSurge", skip_serializing_if = "Option::is_none")]
+    pub max_surge: Option<String>,
+    /// The maximum number of pods that can be unavailable during the update. Value can be an absolute number (ex: 5) or a percentage of desired pods (ex: 10%). Absolute number is calculated from percentage by rounding down. This can not be 0 if MaxSurge is 0. Defaults to 25%. Example: when this is set to 30%, the old ReplicaSet can be scaled down to 70% of desired pods immediately when the rolling update starts. Once new pods are ready, old ReplicaSet can be scaled down further, followed by scaling up the new ReplicaSet, ensuring that the total number of pods available at all times during the update is at least 70% of desired pods.
+    #[serde(rename = "maxUnavailable", skip_serializing_if = "Option::is_none")]
+    pub max_unavailable: Option<String>,
+}
+
+impl RollingUpdateDeployment {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> RollingUpdateDeploymentBuilder {
+        RollingUpdateDeploymentBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> RollingUpdateDeploymentBuilder
+    where
+        Self: Clone,
+    {
+        RollingUpdateDeploymentBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<RollingUpdateDeployment> for RollingUpdateDeploymentBuilder {
+    fn into(self) -> RollingUpdateDeployment {
+        self.body
+    }
+}
+
+/// Builder for [`RollingUpdateDeployment`](./struct.RollingUpdateDeployment.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct RollingUpdateDeploymentBuilder {
+    body: self::RollingUpdateDeployment,
+}
+
+impl RollingUpdateDeploymentBuilder {
+    /// The maximum number of pods that can be scheduled above the desired number of pods. Value can be an absolute number (ex: 5) or a percentage of desired pods (ex: 10%). This can not be 0 if MaxUnavailable is 0. Absolute number is calculated from percentage by rounding up. Defaults to 25%. Example: when this is set to 30%, the new ReplicaSet can be scaled up immediately when the rolling update starts, such that the total number of old and new pods do not exceed 130% of desired pods. Once old pods have been killed, new ReplicaSet can be scaled up further, ensuring that total number of pods running at any time during the update is at most 130% of desired pods.
+    #[inline]
+    pub fn max_surge(mut self, value: impl Into<String>) -> Self {
+        self.body.max_surge = Some(value.into());
+        self
+    }
+
+    /// Sets [`max_surge`](#method.max_surge) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_max_surge(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.max_surge = value.map(|value| value.into());
+        self
+    }
+
+    /// The maximum number of pods that can be unavailable during the update. Value can be an absolute number (ex: 5) or a percentage of desired pods (ex: 10%). Absolute number is calculated from percentage by rounding down. This can not be 0 if MaxSurge is 0. Defaults to 25%. Example: when this is set to 30%, the old ReplicaSet can be scaled down to 70% of desired pods immediately when the rolling update starts. Once new pods are ready, old ReplicaSet can be scaled down further, followed by scaling up the new ReplicaSet, ensuring that the total number of pods available at all times during the update is at least 70% of desired pods.
+    #[inline]
+    pub fn max_unavailable(mut self, value: impl Into<String>) -> Self {
+        self.body.max_unavailable = Some(value.into());
+        self
+    }
+
+    /// Sets [`max_unavailable`](#method.max_unavailable) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_max_unavailable(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.max_unavailable = value.map(|value| value.into());
+        self
+    }
+}
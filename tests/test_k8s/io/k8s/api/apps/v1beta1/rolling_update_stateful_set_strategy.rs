@@ -0,0 +1,63 @@
+
+/// RollingUpdateStatefulSetStrategy is used to communicate parameter for RollingUpdateStatefulSetStrategyType.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct RollingUpdateStatefulSetStrategy {
+    /// Partition indicates the ordinal at which the StatefulSet should be partitioned.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub partition: Option<i32>,
+}
+
+impl RollingUpdateStatefulSetStrategy {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> RollingUpdateStatefulSetStrategyBuilder {
+        RollingUpdateStatefulSetStrategyBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> RollingUpdateStatefulSetStrategyBuilder
+    where
+        Self: Clone,
+    {
+        RollingUpdateStatefulSetStrategyBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<RollingUpdateStatefulSetStrategy> for RollingUpdateStatefulSetStrategyBuilder {
+    fn into(self) -> RollingUpdateStatefulSetStrategy {
+        self.body
+    }
+}
+
+/// Builder for [`RollingUpdateStatefulSetStrategy`](./struct.RollingUpdateStatefulSetStrategy.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct RollingUpdateStatefulSetStrategyBuilder {
+    body: self::RollingUpdateStatefulSetStrategy,
+}
+
+impl RollingUpdateStatefulSetStrategyBuilder {
+    /// Partition indicates the ordinal at which the StatefulSet should be partitioned.
+    #[inline]
+    pub fn partition(mut self, value: impl Into<i32>) -> Self {
+        self.body.partition = Some(value.into());
+        self
+    }
+
+    /// Sets [`partition`](#method.partition) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_partition(mut self, value: Option<impl Into<i32>>) -> Self {
+        self.body.partition = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`partition`](#method.partition), but takes a reference.
+    #[inline]
+    pub fn partition_ref(self, value: &i32) -> Self {
+        self.partition(*value)
+    }
+}
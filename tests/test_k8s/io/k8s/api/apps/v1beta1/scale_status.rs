@@ -0,0 +1,92 @@
+
+/// ScaleStatus represents the current status of a scale subresource.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct ScaleStatus {
+    /// actual number of observed instances of the scaled object.
+    pub replicas: i32,
+    /// label query over pods that should match the replicas count. More info: http://kubernetes.io/docs/user-guide/labels#label-selectors
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub selector: Option<std::collections::BTreeMap<String, String>>,
+    /// label selector for pods that should match the replicas count. This is a serializated version of both map-based and more expressive set-based selectors. This is done to avoid introspection in the clients. The string will be in the same format as the query-param syntax. If the target type only supports map-based selectors, both this field and map-based selector field are populated. More info: https://kubernetes.io/docs/concepts/overview/working-with-objects/labels/#label-selectors
+    #[serde(rename = "targetSelector", skip_serializing_if = "Option::is_none")]
+    pub target_selector: Option<String>,
+}
+
+impl ScaleStatus {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> ScaleStatusBuilder<crate::codegen::generics::MissingReplicas> {
+        ScaleStatusBuilder {
+            body: Default::default(),
+            _replicas: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> ScaleStatusBuilder<crate::codegen::generics::ReplicasExists>
+    where
+        Self: Clone,
+    {
+        ScaleStatusBuilder {
+            body: self.clone(),
+            _replicas: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<ScaleStatus> for ScaleStatusBuilder<crate::codegen::generics::ReplicasExists> {
+    fn into(self) -> ScaleStatus {
+        self.body
+    }
+}
+
+/// Builder for [`ScaleStatus`](./struct.ScaleStatus.html) object.
+#[derive(Debug, Clone)]
+pub struct ScaleStatusBuilder<Replicas> {
+    body: self::ScaleStatus,
+    _replicas: core::marker::PhantomData<Replicas>,
+}
+
+impl<Replicas> ScaleStatusBuilder<Replicas> {
+    /// actual number of observed instances of the scaled object.
+    #[inline]
+    pub fn replicas(mut self, value: impl Into<i32>) -> ScaleStatusBuilder<crate::codegen::generics::ReplicasExists> {
+        self.body.replicas = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Same as [`replicas`](#method.replicas), but takes a reference.
+    #[inline]
+    pub fn replicas_ref(self, value: &i32) -> ScaleStatusBuilder<crate::codegen::generics::ReplicasExists> {
+        self.replicas(*value)
+    }
+
+    /// label query over pods that should match the replicas count. More info: http://kubernetes.io/docs/user-guide/labels#label-selectors
+    #[inline]
+    pub fn selector(mut self, value: impl Iterator<Item = (String, impl Into<String>)>) -> Self {
+        self.body.selector = Some(value.map(|(key, value)| (key, value.into())).collect::<std::collections::BTreeMap<_, _>>().into());
+        self
+    }
+
+    /// Sets [`selector`](#method.selector) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_selector(mut self, value: Option<impl Iterator<Item = (String, impl Into<String>)>>) -> Self {
+        self.body.selector = value.map(|value| value.map(|(key, value)| (key, value.into())).collect::<std::collections::BTreeMap<_, _>>().into());
+        self
+    }
+
+    /// label selector for pods that should match the replicas count. This is a serializated version of both map-based and more expressive set-based selectors. This is done to avoid introspection in the clients. The string will be in the same format as the query-param syntax. If the target type only supports map-based selectors, both this field and map-based selector field are populated. More info: https://kubernetes.io/docs/concepts/overview/working-with-objects/labels/#label-selectors
+    #[inline]
+    pub fn target_selector(mut self, value: impl Into<String>) -> Self {
+        self.body.target_selector = Some(value.into());
+        self
+    }
+
+    /// Sets [`target_selector`](#method.target_selector) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_target_selector(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.target_selector = value.map(|value| value.into());
+        self
+    }
+}
@@ -0,0 +1,115 @@
+
+/// ReplicaSetSpec is the specification of a ReplicaSet.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct ReplicaSetSpec {
+    /// Minimum number of seconds for which a newly created pod should be ready without any of its container crashing, for it to be considered available. Defaults to 0 (pod will be considered available as soon as it is ready)
+    #[serde(rename = "minReadySeconds", skip_serializing_if = "Option::is_none")]
+    pub min_ready_seconds: Option<i32>,
+    /// Replicas is the number of desired replicas. This is a pointer to distinguish between explicit zero and unspecified. Defaults to 1. More info: https://kubernetes.io/docs/concepts/workloads/controllers/replicationcontroller/#what-is-a-replicationcontroller
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub replicas: Option<i32>,
+    /// Selector is a label query over pods that should match the replica count. Label keys and values that must match in order to be controlled by this replica set. It must match the pod template's labels. More info: https://kubernetes.io/docs/concepts/overview/working-with-objects/labels/#label-selectors
+    pub selector: crate::codegen::io::k8s::apimachinery::pkg::apis::meta::v1::label_selector::LabelSelector,
+    /// Template is the object that describes the pod that will be created if insufficient replicas are detected. More info: https://kubernetes.io/docs/concepts/workloads/controllers/replicationcontroller#pod-template
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub template: Option<crate::codegen::io::k8s::api::core::v1::pod_template_spec::PodTemplateSpec>,
+}
+
+impl ReplicaSetSpec {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> ReplicaSetSpecBuilder<crate::codegen::generics::MissingSelector> {
+        ReplicaSetSpecBuilder {
+            body: Default::default(),
+            _selector: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> ReplicaSetSpecBuilder<crate::codegen::generics::SelectorExists>
+    where
+        Self: Clone,
+    {
+        ReplicaSetSpecBuilder {
+            body: self.clone(),
+            _selector: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<ReplicaSetSpec> for ReplicaSetSpecBuilder<crate::codegen::generics::SelectorExists> {
+    fn into(self) -> ReplicaSetSpec {
+        self.body
+    }
+}
+
+/// Builder for [`ReplicaSetSpec`](./struct.ReplicaSetSpec.html) object.
+#[derive(Debug, Clone)]
+pub struct ReplicaSetSpecBuilder<Selector> {
+    body: self::ReplicaSetSpec,
+    _selector: core::marker::PhantomData<Selector>,
+}
+
+impl<Selector> ReplicaSetSpecBuilder<Selector> {
+    /// Minimum number of seconds for which a newly created pod should be ready without any of its container crashing, for it to be considered available. Defaults to 0 (pod will be considered available as soon as it is ready)
+    #[inline]
+    pub fn min_ready_seconds(mut self, value: impl Into<i32>) -> Self {
+        self.body.min_ready_seconds = Some(value.into());
+        self
+    }
+
+    /// Sets [`min_ready_seconds`](#method.min_ready_seconds) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_min_ready_seconds(mut self, value: Option<impl Into<i32>>) -> Self {
+        self.body.min_ready_seconds = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`min_ready_seconds`](#method.min_ready_seconds), but takes a reference.
+    #[inline]
+    pub fn min_ready_seconds_ref(self, value: &i32) -> Self {
+        self.min_ready_seconds(*value)
+    }
+
+    /// Replicas is the number of desired replicas. This is a pointer to distinguish between explicit zero and unspecified. Defaults to 1. More info: https://kubernetes.io/docs/concepts/workloads/controllers/replicationcontroller/#what-is-a-replicationcontroller
+    #[inline]
+    pub fn replicas(mut self, value: impl Into<i32>) -> Self {
+        self.body.replicas = Some(value.into());
+        self
+    }
+
+    /// Sets [`replicas`](#method.replicas) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_replicas(mut self, value: Option<impl Into<i32>>) -> Self {
+        self.body.replicas = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`replicas`](#method.replicas), but takes a reference.
+    #[inline]
+    pub fn replicas_ref(self, value: &i32) -> Self {
+        self.replicas(*value)
+    }
+
+    /// Selector is a label query over pods that should match the replica count. Label keys and values that must match in order to be controlled by this replica set. It must match the pod template's labels. More info: https://kubernetes.io/docs/concepts/overview/working-with-objects/labels/#label-selectors
+    #[inline]
+    pub fn selector(mut self, value: crate::codegen::io::k8s::apimachinery::pkg::apis::meta::v1::label_selector::LabelSelector) -> ReplicaSetSpecBuilder<crate::codegen::generics::SelectorExists> {
+        self.body.selector = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Template is the object that describes the pod that will be created if insufficient replicas are detected. More info: https://kubernetes.io/docs/concepts/workloads/controllers/replicationcontroller#pod-template
+    #[inline]
+    pub fn template(mut self, value: crate::codegen::io::k8s::api::core::v1::pod_template_spec::PodTemplateSpec) -> Self {
+        self.body.template = Some(value.into());
+        self
+    }
+
+    /// Sets [`template`](#method.template) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_template(mut self, value: Option<crate::codegen::io::k8s::api::core::v1::pod_template_spec::PodTemplateSpec>) -> Self {
+        self.body.template = value.map(|value| value.into());
+        self
+    }
+}
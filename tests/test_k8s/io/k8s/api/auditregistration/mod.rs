@@ -0,0 +1,4 @@
+
+pub mod v1alpha1 {
+    include!("./v1alpha1/mod.rs");
+}
@@ -0,0 +1,64 @@
+
+/// AuditSinkSpec holds the spec for the audit sink
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct AuditSinkSpec {
+    /// Policy defines the policy for selecting which events should be sent to the webhook required
+    pub policy: crate::codegen::io::k8s::api::auditregistration::v1alpha1::policy::Policy,
+    /// Webhook to send events required
+    pub webhook: crate::codegen::io::k8s::api::auditregistration::v1alpha1::webhook::Webhook,
+}
+
+impl AuditSinkSpec {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> AuditSinkSpecBuilder<crate::codegen::generics::MissingPolicy, crate::codegen::generics::MissingWebhook> {
+        AuditSinkSpecBuilder {
+            body: Default::default(),
+            _policy: core::marker::PhantomData,
+            _webhook: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> AuditSinkSpecBuilder<crate::codegen::generics::PolicyExists, crate::codegen::generics::WebhookExists>
+    where
+        Self: Clone,
+    {
+        AuditSinkSpecBuilder {
+            body: self.clone(),
+            _policy: core::marker::PhantomData,
+            _webhook: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<AuditSinkSpec> for AuditSinkSpecBuilder<crate::codegen::generics::PolicyExists, crate::codegen::generics::WebhookExists> {
+    fn into(self) -> AuditSinkSpec {
+        self.body
+    }
+}
+
+/// Builder for [`AuditSinkSpec`](./struct.AuditSinkSpec.html) object.
+#[derive(Debug, Clone)]
+pub struct AuditSinkSpecBuilder<Policy, Webhook> {
+    body: self::AuditSinkSpec,
+    _policy: core::marker::PhantomData<Policy>,
+    _webhook: core::marker::PhantomData<Webhook>,
+}
+
+impl<Policy, Webhook> AuditSinkSpecBuilder<Policy, Webhook> {
+    /// Policy defines the policy for selecting which events should be sent to the webhook required
+    #[inline]
+    pub fn policy(mut self, value: crate::codegen::io::k8s::api::auditregistration::v1alpha1::policy::PolicyBuilder<crate::codegen::generics::LevelExists>) -> AuditSinkSpecBuilder<crate::codegen::generics::PolicyExists, Webhook> {
+        self.body.policy = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Webhook to send events required
+    #[inline]
+    pub fn webhook(mut self, value: crate::codegen::io::k8s::api::auditregistration::v1alpha1::webhook::WebhookBuilder<crate::codegen::generics::ClientConfigExists>) -> AuditSinkSpecBuilder<Policy, crate::codegen::generics::WebhookExists> {
+        self.body.webhook = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+}
@@ -0,0 +1,32 @@
+
+pub mod audit_sink {
+    include!("./audit_sink.rs");
+}
+
+pub mod audit_sink_list {
+    include!("./audit_sink_list.rs");
+}
+
+pub mod audit_sink_spec {
+    include!("./audit_sink_spec.rs");
+}
+
+pub mod policy {
+    include!("./policy.rs");
+}
+
+pub mod service_reference {
+    include!("./service_reference.rs");
+}
+
+pub mod webhook {
+    include!("./webhook.rs");
+}
+
+pub mod webhook_client_config {
+    include!("./webhook_client_config.rs");
+}
+
+pub mod webhook_throttle_config {
+    include!("./webhook_throttle_config.rs");
+}
@@ -0,0 +1,76 @@
+
+/// Policy defines the configuration of how audit events are logged
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct Policy {
+    /// The Level that all requests are recorded at. available options: None, Metadata, Request, RequestResponse required
+    pub level: String,
+    /// Stages is a list of stages for which events are created.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stages: Option<Vec<String>>,
+}
+
+impl Policy {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> PolicyBuilder<crate::codegen::generics::MissingLevel> {
+        PolicyBuilder {
+            body: Default::default(),
+            _level: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> PolicyBuilder<crate::codegen::generics::LevelExists>
+    where
+        Self: Clone,
+    {
+        PolicyBuilder {
+            body: self.clone(),
+            _level: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<Policy> for PolicyBuilder<crate::codegen::generics::LevelExists> {
+    fn into(self) -> Policy {
+        self.body
+    }
+}
+
+/// Builder for [`Policy`](./struct.Policy.html) object.
+#[derive(Debug, Clone)]
+pub struct PolicyBuilder<Level> {
+    body: self::Policy,
+    _level: core::marker::PhantomData<Level>,
+}
+
+impl<Level> PolicyBuilder<Level> {
+    /// The Level that all requests are recorded at. available options: None, Metadata, Request, RequestResponse required
+    #[inline]
+    pub fn level(mut self, value: impl Into<String>) -> PolicyBuilder<crate::codegen::generics::LevelExists> {
+        self.body.level = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Stages is a list of stages for which events are created.
+    #[inline]
+    pub fn stages(mut self, value: impl Iterator<Item = impl Into<String>>) -> Self {
+        self.body.stages = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`stages`](#method.stages) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_stages(mut self, value: Option<impl Iterator<Item = impl Into<String>>>) -> Self {
+        self.body.stages = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`stages`](#method.stages), but takes the values as a `Vec`.
+    #[inline]
+    pub fn stages_vec(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.body.stages = Some(value.into());
+        self
+    }
+}
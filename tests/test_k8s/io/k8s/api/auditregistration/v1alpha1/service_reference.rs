@@ -0,0 +1,104 @@
+
+/// ServiceReference holds a reference to Service.legacy.k8s.io
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct ServiceReference {
+    /// `name` is the name of the service. Required
+    pub name: String,
+    /// `namespace` is the namespace of the service. Required
+    pub namespace: String,
+    /// `path` is an optional URL path which will be sent in any request to this service.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+    /// If specified, the port on the service that hosting webhook. Default to 443 for backward compatibility. `port` should be a valid port number (1-65535, inclusive).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub port: Option<i32>,
+}
+
+impl ServiceReference {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> ServiceReferenceBuilder<crate::codegen::generics::MissingName, crate::codegen::generics::MissingNamespace> {
+        ServiceReferenceBuilder {
+            body: Default::default(),
+            _name: core::marker::PhantomData,
+            _namespace: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> ServiceReferenceBuilder<crate::codegen::generics::NameExists, crate::codegen::generics::NamespaceExists>
+    where
+        Self: Clone,
+    {
+        ServiceReferenceBuilder {
+            body: self.clone(),
+            _name: core::marker::PhantomData,
+            _namespace: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<ServiceReference> for ServiceReferenceBuilder<crate::codegen::generics::NameExists, crate::codegen::generics::NamespaceExists> {
+    fn into(self) -> ServiceReference {
+        self.body
+    }
+}
+
+/// Builder for [`ServiceReference`](./struct.ServiceReference.html) object.
+#[derive(Debug, Clone)]
+pub struct ServiceReferenceBuilder<Name, Namespace> {
+    body: self::ServiceReference,
+    _name: core::marker::PhantomData<Name>,
+    _namespace: core::marker::PhantomData<Namespace>,
+}
+
+impl<Name, Namespace> ServiceReferenceBuilder<Name, Namespace> {
+    /// `name` is the name of the service. Required
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> ServiceReferenceBuilder<crate::codegen::generics::NameExists, Namespace> {
+        self.body.name = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// `namespace` is the namespace of the service. Required
+    #[inline]
+    pub fn namespace(mut self, value: impl Into<String>) -> ServiceReferenceBuilder<Name, crate::codegen::generics::NamespaceExists> {
+        self.body.namespace = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// `path` is an optional URL path which will be sent in any request to this service.
+    #[inline]
+    pub fn path(mut self, value: impl Into<String>) -> Self {
+        self.body.path = Some(value.into());
+        self
+    }
+
+    /// Sets [`path`](#method.path) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_path(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.path = value.map(|value| value.into());
+        self
+    }
+
+    /// If specified, the port on the service that hosting webhook. Default to 443 for backward compatibility. `port` should be a valid port number (1-65535, inclusive).
+    #[inline]
+    pub fn port(mut self, value: impl Into<i32>) -> Self {
+        self.body.port = Some(value.into());
+        self
+    }
+
+    /// Sets [`port`](#method.port) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_port(mut self, value: Option<impl Into<i32>>) -> Self {
+        self.body.port = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`port`](#method.port), but takes a reference.
+    #[inline]
+    pub fn port_ref(self, value: &i32) -> Self {
+        self.port(*value)
+    }
+}
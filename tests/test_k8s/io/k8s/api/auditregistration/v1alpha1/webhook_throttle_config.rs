@@ -0,0 +1,86 @@
+
+/// WebhookThrottleConfig holds the configuration for throttling events
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct WebhookThrottleConfig {
+    /// ThrottleBurst is the maximum number of events sent at the same moment default 15 QPS
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub burst: Option<i64>,
+    /// ThrottleQPS maximum number of batches per second default 10 QPS
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub qps: Option<i64>,
+}
+
+impl WebhookThrottleConfig {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> WebhookThrottleConfigBuilder {
+        WebhookThrottleConfigBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> WebhookThrottleConfigBuilder
+    where
+        Self: Clone,
+    {
+        WebhookThrottleConfigBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<WebhookThrottleConfig> for WebhookThrottleConfigBuilder {
+    fn into(self) -> WebhookThrottleConfig {
+        self.body
+    }
+}
+
+/// Builder for [`WebhookThrottleConfig`](./struct.WebhookThrottleConfig.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct WebhookThrottleConfigBuilder {
+    body: self::WebhookThrottleConfig,
+}
+
+impl WebhookThrottleConfigBuilder {
+    /// ThrottleBurst is the maximum number of events sent at the same moment default 15 QPS
+    #[inline]
+    pub fn burst(mut self, value: impl Into<i64>) -> Self {
+        self.body.burst = Some(value.into());
+        self
+    }
+
+    /// Sets [`burst`](#method.burst) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_burst(mut self, value: Option<impl Into<i64>>) -> Self {
+        self.body.burst = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`burst`](#method.burst), but takes a reference.
+    #[inline]
+    pub fn burst_ref(self, value: &i64) -> Self {
+        self.burst(*value)
+    }
+
+    /// ThrottleQPS maximum number of batches per second default 10 QPS
+    #[inline]
+    pub fn qps(mut self, value: impl Into<i64>) -> Self {
+        self.body.qps = Some(value.into());
+        self
+    }
+
+    /// Sets [`qps`](#method.qps) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_qps(mut self, value: Option<impl Into<i64>>) -> Self {
+        self.body.qps = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`qps`](#method.qps), but takes a reference.
+    #[inline]
+    pub fn qps_ref(self, value: &i64) -> Self {
+        self.qps(*value)
+    }
+}
@@ -0,0 +1,16 @@
+
+pub mod token_review {
+    include!("./token_review.rs");
+}
+
+pub mod token_review_spec {
+    include!("./token_review_spec.rs");
+}
+
+pub mod token_review_status {
+    include!("./token_review_status.rs");
+}
+
+pub mod user_info {
+    include!("./user_info.rs");
+}
@@ -0,0 +1,121 @@
+
+/// TokenReviewStatus is the result of the token authentication request.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct TokenReviewStatus {
+    /// Audiences are audience identifiers chosen by the authenticator that are compatible with both the TokenReview and token. An identifier is any identifier in the intersection of the TokenReviewSpec audiences and the token's audiences. A client of the TokenReview API that sets the spec.audiences field should validate that a compatible audience identifier is returned in the status.audiences field to ensure that the TokenReview server is audience aware. If a TokenReview returns an empty status.audience field where status.authenticated is "true", the token is valid against the audience of the Kubernetes API server.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub audiences: Option<Vec<String>>,
+    /// Authenticated indicates that the token was associated with a known user.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub authenticated: Option<bool>,
+    /// Error indicates that the token couldn't be checked
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// User is the UserInfo associated with the provided token.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<crate::codegen::io::k8s::api::authentication::v1beta1::user_info::UserInfo>,
+}
+
+impl TokenReviewStatus {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> TokenReviewStatusBuilder {
+        TokenReviewStatusBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> TokenReviewStatusBuilder
+    where
+        Self: Clone,
+    {
+        TokenReviewStatusBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<TokenReviewStatus> for TokenReviewStatusBuilder {
+    fn into(self) -> TokenReviewStatus {
+        self.body
+    }
+}
+
+/// Builder for [`TokenReviewStatus`](./struct.TokenReviewStatus.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct TokenReviewStatusBuilder {
+    body: self::TokenReviewStatus,
+}
+
+impl TokenReviewStatusBuilder {
+    /// Audiences are audience identifiers chosen by the authenticator that are compatible with both the TokenReview and token. An identifier is any identifier in the intersection of the TokenReviewSpec audiences and the token's audiences. A client of the TokenReview API that sets the spec.audiences field should validate that a compatible audience identifier is returned in the status.audiences field to ensure that the TokenReview server is audience aware. If a TokenReview returns an empty status.audience field where status.authenticated is "true", the token is valid against the audience of the Kubernetes API server.
+    #[inline]
+    pub fn audiences(mut self, value: impl Iterator<Item = impl Into<String>>) -> Self {
+        self.body.audiences = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`audiences`](#method.audiences) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_audiences(mut self, value: Option<impl Iterator<Item = impl Into<String>>>) -> Self {
+        self.body.audiences = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`audiences`](#method.audiences), but takes the values as a `Vec`.
+    #[inline]
+    pub fn audiences_vec(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.body.audiences = Some(value.into());
+        self
+    }
+
+    /// Authenticated indicates that the token was associated with a known user.
+    #[inline]
+    pub fn authenticated(mut self, value: impl Into<bool>) -> Self {
+        self.body.authenticated = Some(value.into());
+        self
+    }
+
+    /// Sets [`authenticated`](#method.authenticated) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_authenticated(mut self, value: Option<impl Into<bool>>) -> Self {
+        self.body.authenticated = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`authenticated`](#method.authenticated), but takes a reference.
+    #[inline]
+    pub fn authenticated_ref(self, value: &bool) -> Self {
+        self.authenticated(*value)
+    }
+
+    /// Error indicates that the token couldn't be checked
+    #[inline]
+    pub fn error(mut self, value: impl Into<String>) -> Self {
+        self.body.error = Some(value.into());
+        self
+    }
+
+    /// Sets [`error`](#method.error) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_error(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.error = value.map(|value| value.into());
+        self
+    }
+
+    /// User is the UserInfo associated with the provided token.
+    #[inline]
+    pub fn user(mut self, value: crate::codegen::io::k8s::api::authentication::v1beta1::user_info::UserInfo) -> Self {
+        self.body.user = Some(value.into());
+        self
+    }
+
+    /// Sets [`user`](#method.user) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_user(mut self, value: Option<crate::codegen::io::k8s::api::authentication::v1beta1::user_info::UserInfo>) -> Self {
+        self.body.user = value.map(|value| value.into());
+        self
+    }
+}
@@ -0,0 +1,115 @@
+
+/// UserInfo holds the information about the user needed to implement the user.Info interface.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct UserInfo {
+    /// Any additional information provided by the authenticator.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub extra: Option<std::collections::BTreeMap<String, Vec<String>>>,
+    /// The names of groups this user is a part of.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub groups: Option<Vec<String>>,
+    /// A unique value that identifies this user across time. If this user is deleted and another user by the same name is added, they will have different UIDs.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub uid: Option<String>,
+    /// The name that uniquely identifies this user among all active users.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub username: Option<String>,
+}
+
+impl UserInfo {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> UserInfoBuilder {
+        UserInfoBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> UserInfoBuilder
+    where
+        Self: Clone,
+    {
+        UserInfoBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<UserInfo> for UserInfoBuilder {
+    fn into(self) -> UserInfo {
+        self.body
+    }
+}
+
+/// Builder for [`UserInfo`](./struct.UserInfo.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct UserInfoBuilder {
+    body: self::UserInfo,
+}
+
+impl UserInfoBuilder {
+    /// Any additional information provided by the authenticator.
+    #[inline]
+    pub fn extra(mut self, value: impl Iterator<Item = (String, impl Iterator<Item = impl Into<String>>)>) -> Self {
+        self.body.extra = Some(value.map(|(key, value)| (key, value.map(|value| value.into()).collect::<Vec<_>>().into())).collect::<std::collections::BTreeMap<_, _>>().into());
+        self
+    }
+
+    /// Sets [`extra`](#method.extra) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_extra(mut self, value: Option<impl Iterator<Item = (String, impl Iterator<Item = impl Into<String>>)>>) -> Self {
+        self.body.extra = value.map(|value| value.map(|(key, value)| (key, value.map(|value| value.into()).collect::<Vec<_>>().into())).collect::<std::collections::BTreeMap<_, _>>().into());
+        self
+    }
+
+    /// The names of groups this user is a part of.
+    #[inline]
+    pub fn groups(mut self, value: impl Iterator<Item = impl Into<String>>) -> Self {
+        self.body.groups = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`groups`](#method.groups) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_groups(mut self, value: Option<impl Iterator<Item = impl Into<String>>>) -> Self {
+        self.body.groups = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`groups`](#method.groups), but takes the values as a `Vec`.
+    #[inline]
+    pub fn groups_vec(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.body.groups = Some(value.into());
+        self
+    }
+
+    /// A unique value that identifies this user across time. If this user is deleted and another user by the same name is added, they will have different UIDs.
+    #[inline]
+    pub fn uid(mut self, value: impl Into<String>) -> Self {
+        self.body.uid = Some(value.into());
+        self
+    }
+
+    /// Sets [`uid`](#method.uid) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_uid(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.uid = value.map(|value| value.into());
+        self
+    }
+
+    /// The name that uniquely identifies this user among all active users.
+    #[inline]
+    pub fn username(mut self, value: impl Into<String>) -> Self {
+        self.body.username = Some(value.into());
+        self
+    }
+
+    /// Sets [`username`](#method.username) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_username(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.username = value.map(|value| value.into());
+        self
+    }
+}
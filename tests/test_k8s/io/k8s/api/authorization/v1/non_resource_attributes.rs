@@ -0,0 +1,74 @@
+
+/// NonResourceAttributes includes the authorization attributes available for non-resource requests to the Authorizer interface
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct NonResourceAttributes {
+    /// Path is the URL path of the request
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+    /// Verb is the standard HTTP verb
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub verb: Option<String>,
+}
+
+impl NonResourceAttributes {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> NonResourceAttributesBuilder {
+        NonResourceAttributesBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> NonResourceAttributesBuilder
+    where
+        Self: Clone,
+    {
+        NonResourceAttributesBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<NonResourceAttributes> for NonResourceAttributesBuilder {
+    fn into(self) -> NonResourceAttributes {
+        self.body
+    }
+}
+
+/// Builder for [`NonResourceAttributes`](./struct.NonResourceAttributes.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct NonResourceAttributesBuilder {
+    body: self::NonResourceAttributes,
+}
+
+impl NonResourceAttributesBuilder {
+    /// Path is the URL path of the request
+    #[inline]
+    pub fn path(mut self, value: impl Into<String>) -> Self {
+        self.body.path = Some(value.into());
+        self
+    }
+
+    /// Sets [`path`](#method.path) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_path(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.path = value.map(|value| value.into());
+        self
+    }
+
+    /// Verb is the standard HTTP verb
+    #[inline]
+    pub fn verb(mut self, value: impl Into<String>) -> Self {
+        self.body.verb = Some(value.into());
+        self
+    }
+
+    /// Sets [`verb`](#method.verb) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_verb(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.verb = value.map(|value| value.into());
+        self
+    }
+}
@@ -0,0 +1,83 @@
+
+/// NonResourceRule holds information that describes a rule for the non-resource
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct NonResourceRule {
+    /// NonResourceURLs is a set of partial urls that a user should have access to.  \*s are allowed, but only as the full, final step in the path.  "\*" means all.
+    #[serde(rename = "nonResourceURLs", skip_serializing_if = "Option::is_none")]
+    pub non_resource_ur_ls: Option<Vec<String>>,
+    /// Verb is a list of kubernetes non-resource API verbs, like: get, post, put, delete, patch, head, options.  "\*" means all.
+    pub verbs: Vec<String>,
+}
+
+impl NonResourceRule {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> NonResourceRuleBuilder<crate::codegen::generics::MissingVerbs> {
+        NonResourceRuleBuilder {
+            body: Default::default(),
+            _verbs: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> NonResourceRuleBuilder<crate::codegen::generics::VerbsExists>
+    where
+        Self: Clone,
+    {
+        NonResourceRuleBuilder {
+            body: self.clone(),
+            _verbs: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<NonResourceRule> for NonResourceRuleBuilder<crate::codegen::generics::VerbsExists> {
+    fn into(self) -> NonResourceRule {
+        self.body
+    }
+}
+
+/// Builder for [`NonResourceRule`](./struct.NonResourceRule.html) object.
+#[derive(Debug, Clone)]
+pub struct NonResourceRuleBuilder<Verbs> {
+    body: self::NonResourceRule,
+    _verbs: core::marker::PhantomData<Verbs>,
+}
+
+impl<Verbs> NonResourceRuleBuilder<Verbs> {
+    /// NonResourceURLs is a set of partial urls that a user should have access to.  \*s are allowed, but only as the full, final step in the path.  "\*" means all.
+    #[inline]
+    pub fn non_resource_ur_ls(mut self, value: impl Iterator<Item = impl Into<String>>) -> Self {
+        self.body.non_resource_ur_ls = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`non_resource_ur_ls`](#method.non_resource_ur_ls) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_non_resource_ur_ls(mut self, value: Option<impl Iterator<Item = impl Into<String>>>) -> Self {
+        self.body.non_resource_ur_ls = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`non_resource_ur_ls`](#method.non_resource_ur_ls), but takes the values as a `Vec`.
+    #[inline]
+    pub fn non_resource_ur_ls_vec(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.body.non_resource_ur_ls = Some(value.into());
+        self
+    }
+
+    /// Verb is a list of kubernetes non-resource API verbs, like: get, post, put, delete, patch, head, options.  "\*" means all.
+    #[inline]
+    pub fn verbs(mut self, value: impl Iterator<Item = impl Into<String>>) -> NonResourceRuleBuilder<crate::codegen::generics::VerbsExists> {
+        self.body.verbs = value.map(|value| value.into()).collect::<Vec<_>>().into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Same as [`verbs`](#method.verbs), but takes the values as a `Vec`.
+    #[inline]
+    pub fn verbs_vec(mut self, value: impl Into<Vec<String>>) -> NonResourceRuleBuilder<crate::codegen::generics::VerbsExists> {
+        self.body.verbs = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+}
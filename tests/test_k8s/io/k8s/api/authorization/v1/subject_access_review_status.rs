@@ -0,0 +1,115 @@
+
+/// SubjectAccessReviewStatus
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct SubjectAccessReviewStatus {
+    /// Allowed is required. True if the action would be allowed, false otherwise.
+    pub allowed: bool,
+    /// Denied is optional. True if the action would be denied, otherwise false. If both allowed is false and denied is false, then the authorizer has no opinion on whether to authorize the action. Denied may not be true if Allowed is true.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub denied: Option<bool>,
+    /// EvaluationError is an indication that some error occurred during the authorization check. It is entirely possible to get an error and be able to continue determine authorization status in spite of it. For instance, RBAC can be missing a role, but enough roles are still present and bound to reason about the request.
+    #[serde(rename = "evaluationError", skip_serializing_if = "Option::is_none")]
+    pub evaluation_error: Option<String>,
+    /// Reason is optional.  It indicates why a request was allowed or denied.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+}
+
+impl SubjectAccessReviewStatus {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> SubjectAccessReviewStatusBuilder<crate::codegen::generics::MissingAllowed> {
+        SubjectAccessReviewStatusBuilder {
+            body: Default::default(),
+            _allowed: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> SubjectAccessReviewStatusBuilder<crate::codegen::generics::AllowedExists>
+    where
+        Self: Clone,
+    {
+        SubjectAccessReviewStatusBuilder {
+            body: self.clone(),
+            _allowed: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<SubjectAccessReviewStatus> for SubjectAccessReviewStatusBuilder<crate::codegen::generics::AllowedExists> {
+    fn into(self) -> SubjectAccessReviewStatus {
+        self.body
+    }
+}
+
+/// Builder for [`SubjectAccessReviewStatus`](./struct.SubjectAccessReviewStatus.html) object.
+#[derive(Debug, Clone)]
+pub struct SubjectAccessReviewStatusBuilder<Allowed> {
+    body: self::SubjectAccessReviewStatus,
+    _allowed: core::marker::PhantomData<Allowed>,
+}
+
+impl<Allowed> SubjectAccessReviewStatusBuilder<Allowed> {
+    /// Allowed is required. True if the action would be allowed, false otherwise.
+    #[inline]
+    pub fn allowed(mut self, value: impl Into<bool>) -> SubjectAccessReviewStatusBuilder<crate::codegen::generics::AllowedExists> {
+        self.body.allowed = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Same as [`allowed`](#method.allowed), but takes a reference.
+    #[inline]
+    pub fn allowed_ref(self, value: &bool) -> SubjectAccessReviewStatusBuilder<crate::codegen::generics::AllowedExists> {
+        self.allowed(*value)
+    }
+
+    /// Denied is optional. True if the action would be denied, otherwise false. If both allowed is false and denied is false, then the authorizer has no opinion on whether to authorize the action. Denied may not be true if Allowed is true.
+    #[inline]
+    pub fn denied(mut self, value: impl Into<bool>) -> Self {
+        self.body.denied = Some(value.into());
+        self
+    }
+
+    /// Sets [`denied`](#method.denied) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_denied(mut self, value: Option<impl Into<bool>>) -> Self {
+        self.body.denied = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`denied`](#method.denied), but takes a reference.
+    #[inline]
+    pub fn denied_ref(self, value: &bool) -> Self {
+        self.denied(*value)
+    }
+
+    /// EvaluationError is an indication that some error occurred during the authorization check. It is entirely possible to get an error and be able to continue determine authorization status in spite of it. For instance, RBAC can be missing a role, but enough roles are still present and bound to reason about the request.
+    #[inline]
+    pub fn evaluation_error(mut self, value: impl Into<String>) -> Self {
+        self.body.evaluation_error = Some(value.into());
+        self
+    }
+
+    /// Sets [`evaluation_error`](#method.evaluation_error) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_evaluation_error(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.evaluation_error = value.map(|value| value.into());
+        self
+    }
+
+    /// Reason is optional.  It indicates why a request was allowed or denied.
+    #[inline]
+    pub fn reason(mut self, value: impl Into<String>) -> Self {
+        self.body.reason = Some(value.into());
+        self
+    }
+
+    /// Sets [`reason`](#method.reason) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_reason(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.reason = value.map(|value| value.into());
+        self
+    }
+}
@@ -0,0 +1,74 @@
+
+/// SelfSubjectAccessReviewSpec is a description of the access request.  Exactly one of ResourceAuthorizationAttributes and NonResourceAuthorizationAttributes must be set
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct SelfSubjectAccessReviewSpec {
+    /// NonResourceAttributes describes information for a non-resource access request
+    #[serde(rename = "nonResourceAttributes", skip_serializing_if = "Option::is_none")]
+    pub non_resource_attributes: Option<crate::codegen::io::k8s::api::authorization::v1beta1::non_resource_attributes::NonResourceAttributes>,
+    /// ResourceAuthorizationAttributes describes information for a resource access request
+    #[serde(rename = "resourceAttributes", skip_serializing_if = "Option::is_none")]
+    pub resource_attributes: Option<crate::codegen::io::k8s::api::authorization::v1beta1::resource_attributes::ResourceAttributes>,
+}
+
+impl SelfSubjectAccessReviewSpec {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> SelfSubjectAccessReviewSpecBuilder {
+        SelfSubjectAccessReviewSpecBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> SelfSubjectAccessReviewSpecBuilder
+    where
+        Self: Clone,
+    {
+        SelfSubjectAccessReviewSpecBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<SelfSubjectAccessReviewSpec> for SelfSubjectAccessReviewSpecBuilder {
+    fn into(self) -> SelfSubjectAccessReviewSpec {
+        self.body
+    }
+}
+
+/// Builder for [`SelfSubjectAccessReviewSpec`](./struct.SelfSubjectAccessReviewSpec.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct SelfSubjectAccessReviewSpecBuilder {
+    body: self::SelfSubjectAccessReviewSpec,
+}
+
+impl SelfSubjectAccessReviewSpecBuilder {
+    /// NonResourceAttributes describes information for a non-resource access request
+    #[inline]
+    pub fn non_resource_attributes(mut self, value: crate::codegen::io::k8s::api::authorization::v1beta1::non_resource_attributes::NonResourceAttributes) -> Self {
+        self.body.non_resource_attributes = Some(value.into());
+        self
+    }
+
+    /// Sets [`non_resource_attributes`](#method.non_resource_attributes) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_non_resource_attributes(mut self, value: Option<crate::codegen::io::k8s::api::authorization::v1beta1::non_resource_attributes::NonResourceAttributes>) -> Self {
+        self.body.non_resource_attributes = value.map(|value| value.into());
+        self
+    }
+
+    /// ResourceAuthorizationAttributes describes information for a resource access request
+    #[inline]
+    pub fn resource_attributes(mut self, value: crate::codegen::io::k8s::api::authorization::v1beta1::resource_attributes::ResourceAttributes) -> Self {
+        self.body.resource_attributes = Some(value.into());
+        self
+    }
+
+    /// Sets [`resource_attributes`](#method.resource_attributes) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_resource_attributes(mut self, value: Option<crate::codegen::io::k8s::api::authorization::v1beta1::resource_attributes::ResourceAttributes>) -> Self {
+        self.body.resource_attributes = value.map(|value| value.into());
+        self
+    }
+}
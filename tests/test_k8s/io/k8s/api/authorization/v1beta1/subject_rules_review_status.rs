@@ -0,0 +1,115 @@
+
+/// SubjectRulesReviewStatus contains the result of a rules check. This check can be incomplete depending on the set of authorizers the server is configured with and any errors experienced during evaluation. Because authorization rules are additive, if a rule appears in a list it's safe to assume the subject has that permission, even if that list is incomplete.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct SubjectRulesReviewStatus {
+    /// EvaluationError can appear in combination with Rules. It indicates an error occurred during rule evaluation, such as an authorizer that doesn't support rule evaluation, and that ResourceRules and/or NonResourceRules may be incomplete.
+    #[serde(rename = "evaluationError", skip_serializing_if = "Option::is_none")]
+    pub evaluation_error: Option<String>,
+    /// Incomplete is true when the rules returned by this call are incomplete. This is most commonly encountered when an authorizer, such as an external authorizer, doesn't support rules evaluation.
+    pub incomplete: bool,
+    /// NonResourceRules is the list of actions the subject is allowed to perform on non-resources. The list ordering isn't significant, may contain duplicates, and possibly be incomplete.
+    #[serde(rename = "nonResourceRules")]
+    pub non_resource_rules: Vec<crate::codegen::io::k8s::api::authorization::v1beta1::non_resource_rule::NonResourceRule>,
+    /// ResourceRules is the list of actions the subject is allowed to perform on resources. The list ordering isn't significant, may contain duplicates, and possibly be incomplete.
+    #[serde(rename = "resourceRules")]
+    pub resource_rules: Vec<crate::codegen::io::k8s::api::authorization::v1beta1::resource_rule::ResourceRule>,
+}
+
+impl SubjectRulesReviewStatus {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> SubjectRulesReviewStatusBuilder<crate::codegen::generics::MissingIncomplete, crate::codegen::generics::MissingNonResourceRules, crate::codegen::generics::MissingResourceRules> {
+        SubjectRulesReviewStatusBuilder {
+            body: Default::default(),
+            _incomplete: core::marker::PhantomData,
+            _non_resource_rules: core::marker::PhantomData,
+            _resource_rules: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> SubjectRulesReviewStatusBuilder<crate::codegen::generics::IncompleteExists, crate::codegen::generics::NonResourceRulesExists, crate::codegen::generics::ResourceRulesExists>
+    where
+        Self: Clone,
+    {
+        SubjectRulesReviewStatusBuilder {
+            body: self.clone(),
+            _incomplete: core::marker::PhantomData,
+            _non_resource_rules: core::marker::PhantomData,
+            _resource_rules: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<SubjectRulesReviewStatus> for SubjectRulesReviewStatusBuilder<crate::codegen::generics::IncompleteExists, crate::codegen::generics::NonResourceRulesExists, crate::codegen::generics::ResourceRulesExists> {
+    fn into(self) -> SubjectRulesReviewStatus {
+        self.body
+    }
+}
+
+/// Builder for [`SubjectRulesReviewStatus`](./struct.SubjectRulesReviewStatus.html) object.
+#[derive(Debug, Clone)]
+pub struct SubjectRulesReviewStatusBuilder<Incomplete, NonResourceRules, ResourceRules> {
+    body: self::SubjectRulesReviewStatus,
+    _incomplete: core::marker::PhantomData<Incomplete>,
+    _non_resource_rules: core::marker::PhantomData<NonResourceRules>,
+    _resource_rules: core::marker::PhantomData<ResourceRules>,
+}
+
+impl<Incomplete, NonResourceRules, ResourceRules> SubjectRulesReviewStatusBuilder<Incomplete, NonResourceRules, ResourceRules> {
+    /// EvaluationError can appear in combination with Rules. It indicates an error occurred during rule evaluation, such as an authorizer that doesn't support rule evaluation, and that ResourceRules and/or NonResourceRules may be incomplete.
+    #[inline]
+    pub fn evaluation_error(mut self, value: impl Into<String>) -> Self {
+        self.body.evaluation_error = Some(value.into());
+        self
+    }
+
+    /// Sets [`evaluation_error`](#method.evaluation_error) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_evaluation_error(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.evaluation_error = value.map(|value| value.into());
+        self
+    }
+
+    /// Incomplete is true when the rules returned by this call are incomplete. This is most commonly encountered when an authorizer, such as an external authorizer, doesn't support rules evaluation.
+    #[inline]
+    pub fn incomplete(mut self, value: impl Into<bool>) -> SubjectRulesReviewStatusBuilder<crate::codegen::generics::IncompleteExists, NonResourceRules, ResourceRules> {
+        self.body.incomplete = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Same as [`incomplete`](#method.incomplete), but takes a reference.
+    #[inline]
+    pub fn incomplete_ref(self, value: &bool) -> SubjectRulesReviewStatusBuilder<crate::codegen::generics::IncompleteExists, NonResourceRules, ResourceRules> {
+        self.incomplete(*value)
+    }
+
+    /// NonResourceRules is the list of actions the subject is allowed to perform on non-resources. The list ordering isn't significant, may contain duplicates, and possibly be incomplete.
+    #[inline]
+    pub fn non_resource_rules(mut self, value: impl Iterator<Item = crate::codegen::io::k8s::api::authorization::v1beta1::non_resource_rule::NonResourceRuleBuilder<crate::codegen::generics::VerbsExists>>) -> SubjectRulesReviewStatusBuilder<Incomplete, crate::codegen::generics::NonResourceRulesExists, ResourceRules> {
+        self.body.non_resource_rules = value.map(|value| value.into()).collect::<Vec<_>>().into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Same as [`non_resource_rules`](#method.non_resource_rules), but takes the values as a `Vec`.
+    #[inline]
+    pub fn non_resource_rules_vec(mut self, value: impl Into<Vec<crate::codegen::io::k8s::api::authorization::v1beta1::non_resource_rule::NonResourceRule>>) -> SubjectRulesReviewStatusBuilder<Incomplete, crate::codegen::generics::NonResourceRulesExists, ResourceRules> {
+        self.body.non_resource_rules = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// ResourceRules is the list of actions the subject is allowed to perform on resources. The list ordering isn't significant, may contain duplicates, and possibly be incomplete.
+    #[inline]
+    pub fn resource_rules(mut self, value: impl Iterator<Item = crate::codegen::io::k8s::api::authorization::v1beta1::resource_rule::ResourceRuleBuilder<crate::codegen::generics::VerbsExists>>) -> SubjectRulesReviewStatusBuilder<Incomplete, NonResourceRules, crate::codegen::generics::ResourceRulesExists> {
+        self.body.resource_rules = value.map(|value| value.into()).collect::<Vec<_>>().into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Same as [`resource_rules`](#method.resource_rules), but takes the values as a `Vec`.
+    #[inline]
+    pub fn resource_rules_vec(mut self, value: impl Into<Vec<crate::codegen::io::k8s::api::authorization::v1beta1::resource_rule::ResourceRule>>) -> SubjectRulesReviewStatusBuilder<Incomplete, NonResourceRules, crate::codegen::generics::ResourceRulesExists> {
+        self.body.resource_rules = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+}
@@ -0,0 +1,12 @@
+
+pub mod v1 {
+    include!("./v1/mod.rs");
+}
+
+pub mod v2beta1 {
+    include!("./v2beta1/mod.rs");
+}
+
+pub mod v2beta2 {
+    include!("./v2beta2/mod.rs");
+}
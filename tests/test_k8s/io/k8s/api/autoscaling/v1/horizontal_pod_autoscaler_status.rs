@@ -0,0 +1,141 @@
+
+/// current status of a horizontal pod autoscaler
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct HorizontalPodAutoscalerStatus {
+    /// current average CPU utilization over all pods, represented as a percentage of requested CPU, e.g. 70 means that an average pod is using now 70% of its requested CPU.
+    #[serde(rename = "currentCPUUtilizationPercentage", skip_serializing_if = "Option::is_none")]
+    pub current_cpu_utilization_percentage: Option<i32>,
+    /// current number of replicas of pods managed by this autoscaler.
+    #[serde(rename = "currentReplicas")]
+    pub current_replicas: i32,
+    /// desired number of replicas of pods managed by this autoscaler.
+    #[serde(rename = "desiredReplicas")]
+    pub desired_replicas: i32,
+    /// last time the HorizontalPodAutoscaler scaled the number of pods; used by the autoscaler to control how often the number of pods is changed.
+    #[serde(rename = "lastScaleTime", skip_serializing_if = "Option::is_none")]
+    pub last_scale_time: Option<String>,
+    /// most recent generation observed by this autoscaler.
+    #[serde(rename = "observedGeneration", skip_serializing_if = "Option::is_none")]
+    pub observed_generation: Option<i64>,
+}
+
+impl HorizontalPodAutoscalerStatus {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> HorizontalPodAutoscalerStatusBuilder<crate::codegen::generics::MissingCurrentReplicas, crate::codegen::generics::MissingDesiredReplicas> {
+        HorizontalPodAutoscalerStatusBuilder {
+            body: Default::default(),
+            _current_replicas: core::marker::PhantomData,
+            _desired_replicas: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> HorizontalPodAutoscalerStatusBuilder<crate::codegen::generics::CurrentReplicasExists, crate::codegen::generics::DesiredReplicasExists>
+    where
+        Self: Clone,
+    {
+        HorizontalPodAutoscalerStatusBuilder {
+            body: self.clone(),
+            _current_replicas: core::marker::PhantomData,
+            _desired_replicas: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<HorizontalPodAutoscalerStatus> for HorizontalPodAutoscalerStatusBuilder<crate::codegen::generics::CurrentReplicasExists, crate::codegen::generics::DesiredReplicasExists> {
+    fn into(self) -> HorizontalPodAutoscalerStatus {
+        self.body
+    }
+}
+
+/// Builder for [`HorizontalPodAutoscalerStatus`](./struct.HorizontalPodAutoscalerStatus.html) object.
+#[derive(Debug, Clone)]
+pub struct HorizontalPodAutoscalerStatusBuilder<CurrentReplicas, DesiredReplicas> {
+    body: self::HorizontalPodAutoscalerStatus,
+    _current_replicas: core::marker::PhantomData<CurrentReplicas>,
+    _desired_replicas: core::marker::PhantomData<DesiredReplicas>,
+}
+
+impl<CurrentReplicas, DesiredReplicas> HorizontalPodAutoscalerStatusBuilder<CurrentReplicas, DesiredReplicas> {
+    /// current average CPU utilization over all pods, represented as a percentage of requested CPU, e.g. 70 means that an average pod is using now 70% of its requested CPU.
+    #[inline]
+    pub fn current_cpu_utilization_percentage(mut self, value: impl Into<i32>) -> Self {
+        self.body.current_cpu_utilization_percentage = Some(value.into());
+        self
+    }
+
+    /// Sets [`current_cpu_utilization_percentage`](#method.current_cpu_utilization_percentage) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_current_cpu_utilization_percentage(mut self, value: Option<impl Into<i32>>) -> Self {
+        self.body.current_cpu_utilization_percentage = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`current_cpu_utilization_percentage`](#method.current_cpu_utilization_percentage), but takes a reference.
+    #[inline]
+    pub fn current_cpu_utilization_percentage_ref(self, value: &i32) -> Self {
+        self.current_cpu_utilization_percentage(*value)
+    }
+
+    /// current number of replicas of pods managed by this autoscaler.
+    #[inline]
+    pub fn current_replicas(mut self, value: impl Into<i32>) -> HorizontalPodAutoscalerStatusBuilder<crate::codegen::generics::CurrentReplicasExists, DesiredReplicas> {
+        self.body.current_replicas = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Same as [`current_replicas`](#method.current_replicas), but takes a reference.
+    #[inline]
+    pub fn current_replicas_ref(self, value: &i32) -> HorizontalPodAutoscalerStatusBuilder<crate::codegen::generics::CurrentReplicasExists, DesiredReplicas> {
+        self.current_replicas(*value)
+    }
+
+    /// desired number of replicas of pods managed by this autoscaler.
+    #[inline]
+    pub fn desired_replicas(mut self, value: impl Into<i32>) -> HorizontalPodAutoscalerStatusBuilder<CurrentReplicas, crate::codegen::generics::DesiredReplicasExists> {
+        self.body.desired_replicas = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Same as [`desired_replicas`](#method.desired_replicas), but takes a reference.
+    #[inline]
+    pub fn desired_replicas_ref(self, value: &i32) -> HorizontalPodAutoscalerStatusBuilder<CurrentReplicas, crate::codegen::generics::DesiredReplicasExists> {
+        self.desired_replicas(*value)
+    }
+
+    /// last time the HorizontalPodAutoscaler scaled the number of pods; used by the autoscaler to control how often the number of pods is changed.
+    #[inline]
+    pub fn last_scale_time(mut self, value: impl Into<String>) -> Self {
+        self.body.last_scale_time = Some(value.into());
+        self
+    }
+
+    /// Sets [`last_scale_time`](#method.last_scale_time) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_last_scale_time(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.last_scale_time = value.map(|value| value.into());
+        self
+    }
+
+    /// most recent generation observed by this autoscaler.
+    #[inline]
+    pub fn observed_generation(mut self, value: impl Into<i64>) -> Self {
+        self.body.observed_generation = Some(value.into());
+        self
+    }
+
+    /// Sets [`observed_generation`](#method.observed_generation) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_observed_generation(mut self, value: Option<impl Into<i64>>) -> Self {
+        self.body.observed_generation = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`observed_generation`](#method.observed_generation), but takes a reference.
+    #[inline]
+    pub fn observed_generation_ref(self, value: &i64) -> Self {
+        self.observed_generation(*value)
+    }
+}
@@ -0,0 +1,81 @@
+
+/// CrossVersionObjectReference contains enough information to let you identify the referred resource.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct CrossVersionObjectReference {
+    /// API version of the referent
+    #[serde(rename = "apiVersion", skip_serializing_if = "Option::is_none")]
+    pub api_version: Option<String>,
+    /// Kind of the referent; More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#types-kinds"
+    pub kind: String,
+    /// Name of the referent; More info: http://kubernetes.io/docs/user-guide/identifiers#names
+    pub name: String,
+}
+
+impl CrossVersionObjectReference {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> CrossVersionObjectReferenceBuilder<crate::codegen::generics::MissingKind, crate::codegen::generics::MissingName> {
+        CrossVersionObjectReferenceBuilder {
+            body: Default::default(),
+            _kind: core::marker::PhantomData,
+            _name: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> CrossVersionObjectReferenceBuilder<crate::codegen::generics::KindExists, crate::codegen::generics::NameExists>
+    where
+        Self: Clone,
+    {
+        CrossVersionObjectReferenceBuilder {
+            body: self.clone(),
+            _kind: core::marker::PhantomData,
+            _name: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<CrossVersionObjectReference> for CrossVersionObjectReferenceBuilder<crate::codegen::generics::KindExists, crate::codegen::generics::NameExists> {
+    fn into(self) -> CrossVersionObjectReference {
+        self.body
+    }
+}
+
+/// Builder for [`CrossVersionObjectReference`](./struct.CrossVersionObjectReference.html) object.
+#[derive(Debug, Clone)]
+pub struct CrossVersionObjectReferenceBuilder<Kind, Name> {
+    body: self::CrossVersionObjectReference,
+    _kind: core::marker::PhantomData<Kind>,
+    _name: core::marker::PhantomData<Name>,
+}
+
+impl<Kind, Name> CrossVersionObjectReferenceBuilder<Kind, Name> {
+    /// API version of the referent
+    #[inline]
+    pub fn api_version(mut self, value: impl Into<String>) -> Self {
+        self.body.api_version = Some(value.into());
+        self
+    }
+
+    /// Sets [`api_version`](#method.api_version) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_api_version(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.api_version = value.map(|value| value.into());
+        self
+    }
+
+    /// Kind of the referent; More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#types-kinds"
+    #[inline]
+    pub fn kind(mut self, value: impl Into<String>) -> CrossVersionObjectReferenceBuilder<crate::codegen::generics::KindExists, Name> {
+        self.body.kind = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Name of the referent; More info: http://kubernetes.io/docs/user-guide/identifiers#names
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> CrossVersionObjectReferenceBuilder<Kind, crate::codegen::generics::NameExists> {
+        self.body.name = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+}
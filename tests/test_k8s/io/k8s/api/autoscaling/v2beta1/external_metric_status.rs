@@ -0,0 +1,100 @@
+
+/// ExternalMetricStatus indicates the current value of a global metric not associated with any Kubernetes object.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct ExternalMetricStatus {
+    /// currentAverageValue is the current value of metric averaged over autoscaled pods.
+    #[serde(rename = "currentAverageValue", skip_serializing_if = "Option::is_none")]
+    pub current_average_value: Option<String>,
+    /// currentValue is the current value of the metric (as a quantity)
+    #[serde(rename = "currentValue")]
+    pub current_value: String,
+    /// metricName is the name of a metric used for autoscaling in metric system.
+    #[serde(rename = "metricName")]
+    pub metric_name: String,
+    /// metricSelector is used to identify a specific time series within a given metric.
+    #[serde(rename = "metricSelector", skip_serializing_if = "Option::is_none")]
+    pub metric_selector: Option<crate::codegen::io::k8s::apimachinery::pkg::apis::meta::v1::label_selector::LabelSelector>,
+}
+
+impl ExternalMetricStatus {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> ExternalMetricStatusBuilder<crate::codegen::generics::MissingCurrentValue, crate::codegen::generics::MissingMetricName> {
+        ExternalMetricStatusBuilder {
+            body: Default::default(),
+            _current_value: core::marker::PhantomData,
+            _metric_name: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> ExternalMetricStatusBuilder<crate::codegen::generics::CurrentValueExists, crate::codegen::generics::MetricNameExists>
+    where
+        Self: Clone,
+    {
+        ExternalMetricStatusBuilder {
+            body: self.clone(),
+            _current_value: core::marker::PhantomData,
+            _metric_name: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<ExternalMetricStatus> for ExternalMetricStatusBuilder<crate::codegen::generics::CurrentValueExists, crate::codegen::generics::MetricNameExists> {
+    fn into(self) -> ExternalMetricStatus {
+        self.body
+    }
+}
+
+/// Builder for [`ExternalMetricStatus`](./struct.ExternalMetricStatus.html) object.
+#[derive(Debug, Clone)]
+pub struct ExternalMetricStatusBuilder<CurrentValue, MetricName> {
+    body: self::ExternalMetricStatus,
+    _current_value: core::marker::PhantomData<CurrentValue>,
+    _metric_name: core::marker::PhantomData<MetricName>,
+}
+
+impl<CurrentValue, MetricName> ExternalMetricStatusBuilder<CurrentValue, MetricName> {
+    /// currentAverageValue is the current value of metric averaged over autoscaled pods.
+    #[inline]
+    pub fn current_average_value(mut self, value: impl Into<String>) -> Self {
+        self.body.current_average_value = Some(value.into());
+        self
+    }
+
+    /// Sets [`current_average_value`](#method.current_average_value) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_current_average_value(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.current_average_value = value.map(|value| value.into());
+        self
+    }
+
+    /// currentValue is the current value of the metric (as a quantity)
+    #[inline]
+    pub fn current_value(mut self, value: impl Into<String>) -> ExternalMetricStatusBuilder<crate::codegen::generics::CurrentValueExists, MetricName> {
+        self.body.current_value = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// metricName is the name of a metric used for autoscaling in metric system.
+    #[inline]
+    pub fn metric_name(mut self, value: impl Into<String>) -> ExternalMetricStatusBuilder<CurrentValue, crate::codegen::generics::MetricNameExists> {
+        self.body.metric_name = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// metricSelector is used to identify a specific time series within a given metric.
+    #[inline]
+    pub fn metric_selector(mut self, value: crate::codegen::io::k8s::apimachinery::pkg::apis::meta::v1::label_selector::LabelSelector) -> Self {
+        self.body.metric_selector = Some(value.into());
+        self
+    }
+
+    /// Sets [`metric_selector`](#method.metric_selector) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_metric_selector(mut self, value: Option<crate::codegen::io::k8s::apimachinery::pkg::apis::meta::v1::label_selector::LabelSelector>) -> Self {
+        self.body.metric_selector = value.map(|value| value.into());
+        self
+    }
+}
@@ -0,0 +1,121 @@
+
+/// MetricStatus describes the last-read state of a single metric.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct MetricStatus {
+    /// external refers to a global metric that is not associated with any Kubernetes object. It allows autoscaling based on information coming from components running outside of cluster (for example length of queue in cloud messaging service, or QPS from loadbalancer running outside of cluster).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub external: Option<crate::codegen::io::k8s::api::autoscaling::v2beta1::external_metric_status::ExternalMetricStatus>,
+    /// object refers to a metric describing a single kubernetes object (for example, hits-per-second on an Ingress object).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub object: Option<crate::codegen::io::k8s::api::autoscaling::v2beta1::object_metric_status::ObjectMetricStatus>,
+    /// pods refers to a metric describing each pod in the current scale target (for example, transactions-processed-per-second).  The values will be averaged together before being compared to the target value.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pods: Option<crate::codegen::io::k8s::api::autoscaling::v2beta1::pods_metric_status::PodsMetricStatus>,
+    /// resource refers to a resource metric (such as those specified in requests and limits) known to Kubernetes describing each pod in the current scale target (e.g. CPU or memory). Such metrics are built in to Kubernetes, and have special scaling options on top of those available to normal per-pod metrics using the "pods" source.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resource: Option<crate::codegen::io::k8s::api::autoscaling::v2beta1::resource_metric_status::ResourceMetricStatus>,
+    /// type is the type of metric source.  It will be one of "Object", "Pods" or "Resource", each corresponds to a matching field in the object.
+    #[serde(rename = "type")]
+    pub type_: String,
+}
+
+impl MetricStatus {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> MetricStatusBuilder<crate::codegen::generics::MissingType> {
+        MetricStatusBuilder {
+            body: Default::default(),
+            _type: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> MetricStatusBuilder<crate::codegen::generics::TypeExists>
+    where
+        Self: Clone,
+    {
+        MetricStatusBuilder {
+            body: self.clone(),
+            _type: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<MetricStatus> for MetricStatusBuilder<crate::codegen::generics::TypeExists> {
+    fn into(self) -> MetricStatus {
+        self.body
+    }
+}
+
+/// Builder for [`MetricStatus`](./struct.MetricStatus.html) object.
+#[derive(Debug, Clone)]
+pub struct MetricStatusBuilder<Type> {
+    body: self::MetricStatus,
+    _type: core::marker::PhantomData<Type>,
+}
+
+impl<Type> MetricStatusBuilder<Type> {
+    /// external refers to a global metric that is not associated with any Kubernetes object. It allows autoscaling based on information coming from components running outside of cluster (for example length of queue in cloud messaging service, or QPS from loadbalancer running outside of cluster).
+    #[inline]
+    pub fn external(mut self, value: crate::codegen::io::k8s::api::autoscaling::v2beta1::external_metric_status::ExternalMetricStatusBuilder<crate::codegen::generics::CurrentValueExists, crate::codegen::generics::MetricNameExists>) -> Self {
+        self.body.external = Some(value.into());
+        self
+    }
+
+    /// Sets [`external`](#method.external) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_external(mut self, value: Option<crate::codegen::io::k8s::api::autoscaling::v2beta1::external_metric_status::ExternalMetricStatusBuilder<crate::codegen::generics::CurrentValueExists, crate::codegen::generics::MetricNameExists>>) -> Self {
+        self.body.external = value.map(|value| value.into());
+        self
+    }
+
+    /// object refers to a metric describing a single kubernetes object (for example, hits-per-second on an Ingress object).
+    #[inline]
+    pub fn object(mut self, value: crate::codegen::io::k8s::api::autoscaling::v2beta1::object_metric_status::ObjectMetricStatusBuilder<crate::codegen::generics::CurrentValueExists, crate::codegen::generics::MetricNameExists, crate::codegen::generics::TargetExists>) -> Self {
+        self.body.object = Some(value.into());
+        self
+    }
+
+    /// Sets [`object`](#method.object) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_object(mut self, value: Option<crate::codegen::io::k8s::api::autoscaling::v2beta1::object_metric_status::ObjectMetricStatusBuilder<crate::codegen::generics::CurrentValueExists, crate::codegen::generics::MetricNameExists, crate::codegen::generics::TargetExists>>) -> Self {
+        self.body.object = value.map(|value| value.into());
+        self
+    }
+
+    /// pods refers to a metric describing each pod in the current scale target (for example, transactions-processed-per-second).  The values will be averaged together before being compared to the target value.
+    #[inline]
+    pub fn pods(mut self, value: crate::codegen::io::k8s::api::autoscaling::v2beta1::pods_metric_status::PodsMetricStatusBuilder<crate::codegen::generics::CurrentAverageValueExists, crate::codegen::generics::MetricNameExists>) -> Self {
+        self.body.pods = Some(value.into());
+        self
+    }
+
+    /// Sets [`pods`](#method.pods) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_pods(mut self, value: Option<crate::codegen::io::k8s::api::autoscaling::v2beta1::pods_metric_status::PodsMetricStatusBuilder<crate::codegen::generics::CurrentAverageValueExists, crate::codegen::generics::MetricNameExists>>) -> Self {
+        self.body.pods = value.map(|value| value.into());
+        self
+    }
+
+    /// resource refers to a resource metric (such as those specified in requests and limits) known to Kubernetes describing each pod in the current scale target (e.g. CPU or memory). Such metrics are built in to Kubernetes, and have special scaling options on top of those available to normal per-pod metrics using the "pods" source.
+    #[inline]
+    pub fn resource(mut self, value: crate::codegen::io::k8s::api::autoscaling::v2beta1::resource_metric_status::ResourceMetricStatusBuilder<crate::codegen::generics::CurrentAverageValueExists, crate::codegen::generics::NameExists>) -> Self {
+        self.body.resource = Some(value.into());
+        self
+    }
+
+    /// Sets [`resource`](#method.resource) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_resource(mut self, value: Option<crate::codegen::io::k8s::api::autoscaling::v2beta1::resource_metric_status::ResourceMetricStatusBuilder<crate::codegen::generics::CurrentAverageValueExists, crate::codegen::generics::NameExists>>) -> Self {
+        self.body.resource = value.map(|value| value.into());
+        self
+    }
+
+    /// type is the type of metric source.  It will be one of "Object", "Pods" or "Resource", each corresponds to a matching field in the object.
+    #[inline]
+    pub fn type_(mut self, value: impl Into<String>) -> MetricStatusBuilder<crate::codegen::generics::TypeExists> {
+        self.body.type_ = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+}
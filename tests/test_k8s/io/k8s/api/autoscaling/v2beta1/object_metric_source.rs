@@ -0,0 +1,112 @@
+
+/// ObjectMetricSource indicates how to scale on a metric describing a kubernetes object (for example, hits-per-second on an Ingress object).
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct ObjectMetricSource {
+    /// averageValue is the target value of the average of the metric across all relevant pods (as a quantity)
+    #[serde(rename = "averageValue", skip_serializing_if = "Option::is_none")]
+    pub average_value: Option<String>,
+    /// metricName is the name of the metric in question.
+    #[serde(rename = "metricName")]
+    pub metric_name: String,
+    /// selector is the string-encoded form of a standard kubernetes label selector for the given metric When set, it is passed as an additional parameter to the metrics server for more specific metrics scoping When unset, just the metricName will be used to gather metrics.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub selector: Option<crate::codegen::io::k8s::apimachinery::pkg::apis::meta::v1::label_selector::LabelSelector>,
+    /// target is the described Kubernetes object.
+    pub target: crate::codegen::io::k8s::api::autoscaling::v2beta1::cross_version_object_reference::CrossVersionObjectReference,
+    /// targetValue is the target value of the metric (as a quantity).
+    #[serde(rename = "targetValue")]
+    pub target_value: String,
+}
+
+impl ObjectMetricSource {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> ObjectMetricSourceBuilder<crate::codegen::generics::MissingMetricName, crate::codegen::generics::MissingTarget, crate::codegen::generics::MissingTargetValue> {
+        ObjectMetricSourceBuilder {
+            body: Default::default(),
+            _metric_name: core::marker::PhantomData,
+            _target: core::marker::PhantomData,
+            _target_value: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> ObjectMetricSourceBuilder<crate::codegen::generics::MetricNameExists, crate::codegen::generics::TargetExists, crate::codegen::generics::TargetValueExists>
+    where
+        Self: Clone,
+    {
+        ObjectMetricSourceBuilder {
+            body: self.clone(),
+            _metric_name: core::marker::PhantomData,
+            _target: core::marker::PhantomData,
+            _target_value: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<ObjectMetricSource> for ObjectMetricSourceBuilder<crate::codegen::generics::MetricNameExists, crate::codegen::generics::TargetExists, crate::codegen::generics::TargetValueExists> {
+    fn into(self) -> ObjectMetricSource {
+        self.body
+    }
+}
+
+/// Builder for [`ObjectMetricSource`](./struct.ObjectMetricSource.html) object.
+#[derive(Debug, Clone)]
+pub struct ObjectMetricSourceBuilder<MetricName, Target, TargetValue> {
+    body: self::ObjectMetricSource,
+    _metric_name: core::marker::PhantomData<MetricName>,
+    _target: core::marker::PhantomData<Target>,
+    _target_value: core::marker::PhantomData<TargetValue>,
+}
+
+impl<MetricName, Target, TargetValue> ObjectMetricSourceBuilder<MetricName, Target, TargetValue> {
+    /// averageValue is the target value of the average of the metric across all relevant pods (as a quantity)
+    #[inline]
+    pub fn average_value(mut self, value: impl Into<String>) -> Self {
+        self.body.average_value = Some(value.into());
+        self
+    }
+
+    /// Sets [`average_value`](#method.average_value) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_average_value(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.average_value = value.map(|value| value.into());
+        self
+    }
+
+    /// metricName is the name of the metric in question.
+    #[inline]
+    pub fn metric_name(mut self, value: impl Into<String>) -> ObjectMetricSourceBuilder<crate::codegen::generics::MetricNameExists, Target, TargetValue> {
+        self.body.metric_name = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// selector is the string-encoded form of a standard kubernetes label selector for the given metric When set, it is passed as an additional parameter to the metrics server for more specific metrics scoping When unset, just the metricName will be used to gather metrics.
+    #[inline]
+    pub fn selector(mut self, value: crate::codegen::io::k8s::apimachinery::pkg::apis::meta::v1::label_selector::LabelSelector) -> Self {
+        self.body.selector = Some(value.into());
+        self
+    }
+
+    /// Sets [`selector`](#method.selector) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_selector(mut self, value: Option<crate::codegen::io::k8s::apimachinery::pkg::apis::meta::v1::label_selector::LabelSelector>) -> Self {
+        self.body.selector = value.map(|value| value.into());
+        self
+    }
+
+    /// target is the described Kubernetes object.
+    #[inline]
+    pub fn target(mut self, value: crate::codegen::io::k8s::api::autoscaling::v2beta1::cross_version_object_reference::CrossVersionObjectReferenceBuilder<crate::codegen::generics::KindExists, crate::codegen::generics::NameExists>) -> ObjectMetricSourceBuilder<MetricName, crate::codegen::generics::TargetExists, TargetValue> {
+        self.body.target = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// targetValue is the target value of the metric (as a quantity).
+    #[inline]
+    pub fn target_value(mut self, value: impl Into<String>) -> ObjectMetricSourceBuilder<MetricName, Target, crate::codegen::generics::TargetValueExists> {
+        self.body.target_value = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+}
@@ -0,0 +1,83 @@
+
+/// PodsMetricStatus indicates the current value of a metric describing each pod in the current scale target (for example, transactions-processed-per-second).
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct PodsMetricStatus {
+    /// currentAverageValue is the current value of the average of the metric across all relevant pods (as a quantity)
+    #[serde(rename = "currentAverageValue")]
+    pub current_average_value: String,
+    /// metricName is the name of the metric in question
+    #[serde(rename = "metricName")]
+    pub metric_name: String,
+    /// selector is the string-encoded form of a standard kubernetes label selector for the given metric When set in the PodsMetricSource, it is passed as an additional parameter to the metrics server for more specific metrics scoping. When unset, just the metricName will be used to gather metrics.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub selector: Option<crate::codegen::io::k8s::apimachinery::pkg::apis::meta::v1::label_selector::LabelSelector>,
+}
+
+impl PodsMetricStatus {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> PodsMetricStatusBuilder<crate::codegen::generics::MissingCurrentAverageValue, crate::codegen::generics::MissingMetricName> {
+        PodsMetricStatusBuilder {
+            body: Default::default(),
+            _current_average_value: core::marker::PhantomData,
+            _metric_name: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> PodsMetricStatusBuilder<crate::codegen::generics::CurrentAverageValueExists, crate::codegen::generics::MetricNameExists>
+    where
+        Self: Clone,
+    {
+        PodsMetricStatusBuilder {
+            body: self.clone(),
+            _current_average_value: core::marker::PhantomData,
+            _metric_name: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<PodsMetricStatus> for PodsMetricStatusBuilder<crate::codegen::generics::CurrentAverageValueExists, crate::codegen::generics::MetricNameExists> {
+    fn into(self) -> PodsMetricStatus {
+        self.body
+    }
+}
+
+/// Builder for [`PodsMetricStatus`](./struct.PodsMetricStatus.html) object.
+#[derive(Debug, Clone)]
+pub struct PodsMetricStatusBuilder<CurrentAverageValue, MetricName> {
+    body: self::PodsMetricStatus,
+    _current_average_value: core::marker::PhantomData<CurrentAverageValue>,
+    _metric_name: core::marker::PhantomData<MetricName>,
+}
+
+impl<CurrentAverageValue, MetricName> PodsMetricStatusBuilder<CurrentAverageValue, MetricName> {
+    /// currentAverageValue is the current value of the average of the metric across all relevant pods (as a quantity)
+    #[inline]
+    pub fn current_average_value(mut self, value: impl Into<String>) -> PodsMetricStatusBuilder<crate::codegen::generics::CurrentAverageValueExists, MetricName> {
+        self.body.current_average_value = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// metricName is the name of the metric in question
+    #[inline]
+    pub fn metric_name(mut self, value: impl Into<String>) -> PodsMetricStatusBuilder<CurrentAverageValue, crate::codegen::generics::MetricNameExists> {
+        self.body.metric_name = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// selector is the string-encoded form of a standard kubernetes label selector for the given metric When set in the PodsMetricSource, it is passed as an additional parameter to the metrics server for more specific metrics scoping. When unset, just the metricName will be used to gather metrics.
+    #[inline]
+    pub fn selector(mut self, value: crate::codegen::io::k8s::apimachinery::pkg::apis::meta::v1::label_selector::LabelSelector) -> Self {
+        self.body.selector = Some(value.into());
+        self
+    }
+
+    /// Sets [`selector`](#method.selector) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_selector(mut self, value: Option<crate::codegen::io::k8s::apimachinery::pkg::apis::meta::v1::label_selector::LabelSelector>) -> Self {
+        self.body.selector = value.map(|value| value.into());
+        self
+    }
+}
@@ -0,0 +1,88 @@
+
+/// ResourceMetricStatus indicates the current value of a resource metric known to Kubernetes, as specified in requests and limits, describing each pod in the current scale target (e.g. CPU or memory).  Such metrics are built in to Kubernetes, and have special scaling options on top of those available to normal per-pod metrics using the "pods" source.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct ResourceMetricStatus {
+    /// currentAverageUtilization is the current value of the average of the resource metric across all relevant pods, represented as a percentage of the requested value of the resource for the pods.  It will only be present if `targetAverageValue` was set in the corresponding metric specification.
+    #[serde(rename = "currentAverageUtilization", skip_serializing_if = "Option::is_none")]
+    pub current_average_utilization: Option<i32>,
+    /// currentAverageValue is the current value of the average of the resource metric across all relevant pods, as a raw value (instead of as a percentage of the request), similar to the "pods" metric source type. It will always be set, regardless of the corresponding metric specification.
+    #[serde(rename = "currentAverageValue")]
+    pub current_average_value: String,
+    /// name is the name of the resource in question.
+    pub name: String,
+}
+
+impl ResourceMetricStatus {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> ResourceMetricStatusBuilder<crate::codegen::generics::MissingCurrentAverageValue, crate::codegen::generics::MissingName> {
+        ResourceMetricStatusBuilder {
+            body: Default::default(),
+            _current_average_value: core::marker::PhantomData,
+            _name: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> ResourceMetricStatusBuilder<crate::codegen::generics::CurrentAverageValueExists, crate::codegen::generics::NameExists>
+    where
+        Self: Clone,
+    {
+        ResourceMetricStatusBuilder {
+            body: self.clone(),
+            _current_average_value: core::marker::PhantomData,
+            _name: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<ResourceMetricStatus> for ResourceMetricStatusBuilder<crate::codegen::generics::CurrentAverageValueExists, crate::codegen::generics::NameExists> {
+    fn into(self) -> ResourceMetricStatus {
+        self.body
+    }
+}
+
+/// Builder for [`ResourceMetricStatus`](./struct.ResourceMetricStatus.html) object.
+#[derive(Debug, Clone)]
+pub struct ResourceMetricStatusBuilder<CurrentAverageValue, Name> {
+    body: self::ResourceMetricStatus,
+    _current_average_value: core::marker::PhantomData<CurrentAverageValue>,
+    _name: core::marker::PhantomData<Name>,
+}
+
+impl<CurrentAverageValue, Name> ResourceMetricStatusBuilder<CurrentAverageValue, Name> {
+    /// currentAverageUtilization is the current value of the average of the resource metric across all relevant pods, represented as a percentage of the requested value of the resource for the pods.  It will only be present if `targetAverageValue` was set in the corresponding metric specification.
+    #[inline]
+    pub fn current_average_utilization(mut self, value: impl Into<i32>) -> Self {
+        self.body.current_average_utilization = Some(value.into());
+        self
+    }
+
+    /// Sets [`current_average_utilization`](#method.current_average_utilization) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_current_average_utilization(mut self, value: Option<impl Into<i32>>) -> Self {
+        self.body.current_average_utilization = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`current_average_utilization`](#method.current_average_utilization), but takes a reference.
+    #[inline]
+    pub fn current_average_utilization_ref(self, value: &i32) -> Self {
+        self.current_average_utilization(*value)
+    }
+
+    /// currentAverageValue is the current value of the average of the resource metric across all relevant pods, as a raw value (instead of as a percentage of the request), similar to the "pods" metric source type. It will always be set, regardless of the corresponding metric specification.
+    #[inline]
+    pub fn current_average_value(mut self, value: impl Into<String>) -> ResourceMetricStatusBuilder<crate::codegen::generics::CurrentAverageValueExists, Name> {
+        self.body.current_average_value = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// name is the name of the resource in question.
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> ResourceMetricStatusBuilder<CurrentAverageValue, crate::codegen::generics::NameExists> {
+        self.body.name = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+}
@@ -0,0 +1,64 @@
+
+/// ExternalMetricStatus indicates the current value of a global metric not associated with any Kubernetes object.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct ExternalMetricStatus {
+    /// current contains the current value for the given metric
+    pub current: crate::codegen::io::k8s::api::autoscaling::v2beta2::metric_value_status::MetricValueStatus,
+    /// metric identifies the target metric by name and selector
+    pub metric: crate::codegen::io::k8s::api::autoscaling::v2beta2::metric_identifier::MetricIdentifier,
+}
+
+impl ExternalMetricStatus {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> ExternalMetricStatusBuilder<crate::codegen::generics::MissingCurrent, crate::codegen::generics::MissingMetric> {
+        ExternalMetricStatusBuilder {
+            body: Default::default(),
+            _current: core::marker::PhantomData,
+            _metric: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> ExternalMetricStatusBuilder<crate::codegen::generics::CurrentExists, crate::codegen::generics::MetricExists>
+    where
+        Self: Clone,
+    {
+        ExternalMetricStatusBuilder {
+            body: self.clone(),
+            _current: core::marker::PhantomData,
+            _metric: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<ExternalMetricStatus> for ExternalMetricStatusBuilder<crate::codegen::generics::CurrentExists, crate::codegen::generics::MetricExists> {
+    fn into(self) -> ExternalMetricStatus {
+        self.body
+    }
+}
+
+/// Builder for [`ExternalMetricStatus`](./struct.ExternalMetricStatus.html) object.
+#[derive(Debug, Clone)]
+pub struct ExternalMetricStatusBuilder<Current, Metric> {
+    body: self::ExternalMetricStatus,
+    _current: core::marker::PhantomData<Current>,
+    _metric: core::marker::PhantomData<Metric>,
+}
+
+impl<Current, Metric> ExternalMetricStatusBuilder<Current, Metric> {
+    /// current contains the current value for the given metric
+    #[inline]
+    pub fn current(mut self, value: crate::codegen::io::k8s::api::autoscaling::v2beta2::metric_value_status::MetricValueStatus) -> ExternalMetricStatusBuilder<crate::codegen::generics::CurrentExists, Metric> {
+        self.body.current = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// metric identifies the target metric by name and selector
+    #[inline]
+    pub fn metric(mut self, value: crate::codegen::io::k8s::api::autoscaling::v2beta2::metric_identifier::MetricIdentifierBuilder<crate::codegen::generics::NameExists>) -> ExternalMetricStatusBuilder<Current, crate::codegen::generics::MetricExists> {
+        self.body.metric = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+}
@@ -0,0 +1,161 @@
+
+/// HorizontalPodAutoscalerStatus describes the current status of a horizontal pod autoscaler.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct HorizontalPodAutoscalerStatus {
+    /// conditions is the set of conditions required for this autoscaler to scale its target, and indicates whether or not those conditions are met.
+    pub conditions: Vec<crate::codegen::io::k8s::api::autoscaling::v2beta2::horizontal_pod_autoscaler_condition::HorizontalPodAutoscalerCondition>,
+    /// currentMetrics is the last read state of the metrics used by this autoscaler.
+    #[serde(rename = "currentMetrics", skip_serializing_if = "Option::is_none")]
+    pub current_metrics: Option<Vec<crate::codegen::io::k8s::api::autoscaling::v2beta2::metric_status::MetricStatus>>,
+    /// currentReplicas is current number of replicas of pods managed by this autoscaler, as last seen by the autoscaler.
+    #[serde(rename = "currentReplicas")]
+    pub current_replicas: i32,
+    /// desiredReplicas is the desired number of replicas of pods managed by this autoscaler, as last calculated by the autoscaler.
+    #[serde(rename = "desiredReplicas")]
+    pub desired_replicas: i32,
+    /// lastScaleTime is the last time the HorizontalPodAutoscaler scaled the number of pods, used by the autoscaler to control how often the number of pods is changed.
+    #[serde(rename = "lastScaleTime", skip_serializing_if = "Option::is_none")]
+    pub last_scale_time: Option<String>,
+    /// observedGeneration is the most recent generation observed by this autoscaler.
+    #[serde(rename = "observedGeneration", skip_serializing_if = "Option::is_none")]
+    pub observed_generation: Option<i64>,
+}
+
+impl HorizontalPodAutoscalerStatus {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> HorizontalPodAutoscalerStatusBuilder<crate::codegen::generics::MissingConditions, crate::codegen::generics::MissingCurrentReplicas, crate::codegen::generics::MissingDesiredReplicas> {
+        HorizontalPodAutoscalerStatusBuilder {
+            body: Default::default(),
+            _conditions: core::marker::PhantomData,
+            _current_replicas: core::marker::PhantomData,
+            _desired_replicas: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> HorizontalPodAutoscalerStatusBuilder<crate::codegen::generics::ConditionsExists, crate::codegen::generics::CurrentReplicasExists, crate::codegen::generics::DesiredReplicasExists>
+    where
+        Self: Clone,
+    {
+        HorizontalPodAutoscalerStatusBuilder {
+            body: self.clone(),
+            _conditions: core::marker::PhantomData,
+            _current_replicas: core::marker::PhantomData,
+            _desired_replicas: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<HorizontalPodAutoscalerStatus> for HorizontalPodAutoscalerStatusBuilder<crate::codegen::generics::ConditionsExists, crate::codegen::generics::CurrentReplicasExists, crate::codegen::generics::DesiredReplicasExists> {
+    fn into(self) -> HorizontalPodAutoscalerStatus {
+        self.body
+    }
+}
+
+/// Builder for [`HorizontalPodAutoscalerStatus`](./struct.HorizontalPodAutoscalerStatus.html) object.
+#[derive(Debug, Clone)]
+pub struct HorizontalPodAutoscalerStatusBuilder<Conditions, CurrentReplicas, DesiredReplicas> {
+    body: self::HorizontalPodAutoscalerStatus,
+    _conditions: core::marker::PhantomData<Conditions>,
+    _current_replicas: core::marker::PhantomData<CurrentReplicas>,
+    _desired_replicas: core::marker::PhantomData<DesiredReplicas>,
+}
+
+impl<Conditions, CurrentReplicas, DesiredReplicas> HorizontalPodAutoscalerStatusBuilder<Conditions, CurrentReplicas, DesiredReplicas> {
+    /// conditions is the set of conditions required for this autoscaler to scale its target, and indicates whether or not those conditions are met.
+    #[inline]
+    pub fn conditions(mut self, value: impl Iterator<Item = crate::codegen::io::k8s::api::autoscaling::v2beta2::horizontal_pod_autoscaler_condition::HorizontalPodAutoscalerConditionBuilder<crate::codegen::generics::StatusExists, crate::codegen::generics::TypeExists>>) -> HorizontalPodAutoscalerStatusBuilder<crate::codegen::generics::ConditionsExists, CurrentReplicas, DesiredReplicas> {
+        self.body.conditions = value.map(|value| value.into()).collect::<Vec<_>>().into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Same as [`conditions`](#method.conditions), but takes the values as a `Vec`.
+    #[inline]
+    pub fn conditions_vec(mut self, value: impl Into<Vec<crate::codegen::io::k8s::api::autoscaling::v2beta2::horizontal_pod_autoscaler_condition::HorizontalPodAutoscalerCondition>>) -> HorizontalPodAutoscalerStatusBuilder<crate::codegen::generics::ConditionsExists, CurrentReplicas, DesiredReplicas> {
+        self.body.conditions = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// currentMetrics is the last read state of the metrics used by this autoscaler.
+    #[inline]
+    pub fn current_metrics(mut self, value: impl Iterator<Item = crate::codegen::io::k8s::api::autoscaling::v2beta2::metric_status::MetricStatusBuilder<crate::codegen::generics::TypeExists>>) -> Self {
+        self.body.current_metrics = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`current_metrics`](#method.current_metrics) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_current_metrics(mut self, value: Option<impl Iterator<Item = crate::codegen::io::k8s::api::autoscaling::v2beta2::metric_status::MetricStatusBuilder<crate::codegen::generics::TypeExists>>>) -> Self {
+        self.body.current_metrics = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`current_metrics`](#method.current_metrics), but takes the values as a `Vec`.
+    #[inline]
+    pub fn current_metrics_vec(mut self, value: impl Into<Vec<crate::codegen::io::k8s::api::autoscaling::v2beta2::metric_status::MetricStatus>>) -> Self {
+        self.body.current_metrics = Some(value.into());
+        self
+    }
+
+    /// currentReplicas is current number of replicas of pods managed by this autoscaler, as last seen by the autoscaler.
+    #[inline]
+    pub fn current_replicas(mut self, value: impl Into<i32>) -> HorizontalPodAutoscalerStatusBuilder<Conditions, crate::codegen::generics::CurrentReplicasExists, DesiredReplicas> {
+        self.body.current_replicas = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Same as [`current_replicas`](#method.current_replicas), but takes a reference.
+    #[inline]
+    pub fn current_replicas_ref(self, value: &i32) -> HorizontalPodAutoscalerStatusBuilder<Conditions, crate::codegen::generics::CurrentReplicasExists, DesiredReplicas> {
+        self.current_replicas(*value)
+    }
+
+    /// desiredReplicas is the desired number of replicas of pods managed by this autoscaler, as last calculated by the autoscaler.
+    #[inline]
+    pub fn desired_replicas(mut self, value: impl Into<i32>) -> HorizontalPodAutoscalerStatusBuilder<Conditions, CurrentReplicas, crate::codegen::generics::DesiredReplicasExists> {
+        self.body.desired_replicas = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Same as [`desired_replicas`](#method.desired_replicas), but takes a reference.
+    #[inline]
+    pub fn desired_replicas_ref(self, value: &i32) -> HorizontalPodAutoscalerStatusBuilder<Conditions, CurrentReplicas, crate::codegen::generics::DesiredReplicasExists> {
+        self.desired_replicas(*value)
+    }
+
+    /// lastScaleTime is the last time the HorizontalPodAutoscaler scaled the number of pods, used by the autoscaler to control how often the number of pods is changed.
+    #[inline]
+    pub fn last_scale_time(mut self, value: impl Into<String>) -> Self {
+        self.body.last_scale_time = Some(value.into());
+        self
+    }
+
+    /// Sets [`last_scale_time`](#method.last_scale_time) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_last_scale_time(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.last_scale_time = value.map(|value| value.into());
+        self
+    }
+
+    /// observedGeneration is the most recent generation observed by this autoscaler.
+    #[inline]
+    pub fn observed_generation(mut self, value: impl Into<i64>) -> Self {
+        self.body.observed_generation = Some(value.into());
+        self
+    }
+
+    /// Sets [`observed_generation`](#method.observed_generation) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_observed_generation(mut self, value: Option<impl Into<i64>>) -> Self {
+        self.body.observed_generation = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`observed_generation`](#method.observed_generation), but takes a reference.
+    #[inline]
+    pub fn observed_generation_ref(self, value: &i64) -> Self {
+        self.observed_generation(*value)
+    }
+}
@@ -0,0 +1,69 @@
+
+/// MetricIdentifier defines the name and optionally selector for a metric
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct MetricIdentifier {
+    /// name is the name of the given metric
+    pub name: String,
+    /// selector is the string-encoded form of a standard kubernetes label selector for the given metric When set, it is passed as an additional parameter to the metrics server for more specific metrics scoping. When unset, just the metricName will be used to gather metrics.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub selector: Option<crate::codegen::io::k8s::apimachinery::pkg::apis::meta::v1::label_selector::LabelSelector>,
+}
+
+impl MetricIdentifier {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> MetricIdentifierBuilder<crate::codegen::generics::MissingName> {
+        MetricIdentifierBuilder {
+            body: Default::default(),
+            _name: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> MetricIdentifierBuilder<crate::codegen::generics::NameExists>
+    where
+        Self: Clone,
+    {
+        MetricIdentifierBuilder {
+            body: self.clone(),
+            _name: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<MetricIdentifier> for MetricIdentifierBuilder<crate::codegen::generics::NameExists> {
+    fn into(self) -> MetricIdentifier {
+        self.body
+    }
+}
+
+/// Builder for [`MetricIdentifier`](./struct.MetricIdentifier.html) object.
+#[derive(Debug, Clone)]
+pub struct MetricIdentifierBuilder<Name> {
+    body: self::MetricIdentifier,
+    _name: core::marker::PhantomData<Name>,
+}
+
+impl<Name> MetricIdentifierBuilder<Name> {
+    /// name is the name of the given metric
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> MetricIdentifierBuilder<crate::codegen::generics::NameExists> {
+        self.body.name = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// selector is the string-encoded form of a standard kubernetes label selector for the given metric When set, it is passed as an additional parameter to the metrics server for more specific metrics scoping. When unset, just the metricName will be used to gather metrics.
+    #[inline]
+    pub fn selector(mut self, value: crate::codegen::io::k8s::apimachinery::pkg::apis::meta::v1::label_selector::LabelSelector) -> Self {
+        self.body.selector = Some(value.into());
+        self
+    }
+
+    /// Sets [`selector`](#method.selector) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_selector(mut self, value: Option<crate::codegen::io::k8s::apimachinery::pkg::apis::meta::v1::label_selector::LabelSelector>) -> Self {
+        self.body.selector = value.map(|value| value.into());
+        self
+    }
+}
@@ -0,0 +1,97 @@
+
+/// MetricValueStatus holds the current value for a metric
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct MetricValueStatus {
+    /// currentAverageUtilization is the current value of the average of the resource metric across all relevant pods, represented as a percentage of the requested value of the resource for the pods.
+    #[serde(rename = "averageUtilization", skip_serializing_if = "Option::is_none")]
+    pub average_utilization: Option<i32>,
+    /// averageValue is the current value of the average of the metric across all relevant pods (as a quantity)
+    #[serde(rename = "averageValue", skip_serializing_if = "Option::is_none")]
+    pub average_value: Option<String>,
+    /// value is the current value of the metric (as a quantity).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+}
+
+impl MetricValueStatus {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> MetricValueStatusBuilder {
+        MetricValueStatusBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> MetricValueStatusBuilder
+    where
+        Self: Clone,
+    {
+        MetricValueStatusBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<MetricValueStatus> for MetricValueStatusBuilder {
+    fn into(self) -> MetricValueStatus {
+        self.body
+    }
+}
+
+/// Builder for [`MetricValueStatus`](./struct.MetricValueStatus.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct MetricValueStatusBuilder {
+    body: self::MetricValueStatus,
+}
+
+impl MetricValueStatusBuilder {
+    /// currentAverageUtilization is the current value of the average of the resource metric across all relevant pods, represented as a percentage of the requested value of the resource for the pods.
+    #[inline]
+    pub fn average_utilization(mut self, value: impl Into<i32>) -> Self {
+        self.body.average_utilization = Some(value.into());
+        self
+    }
+
+    /// Sets [`average_utilization`](#method.average_utilization) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_average_utilization(mut self, value: Option<impl Into<i32>>) -> Self {
+        self.body.average_utilization = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`average_utilization`](#method.average_utilization), but takes a reference.
+    #[inline]
+    pub fn average_utilization_ref(self, value: &i32) -> Self {
+        self.average_utilization(*value)
+    }
+
+    /// averageValue is the current value of the average of the metric across all relevant pods (as a quantity)
+    #[inline]
+    pub fn average_value(mut self, value: impl Into<String>) -> Self {
+        self.body.average_value = Some(value.into());
+        self
+    }
+
+    /// Sets [`average_value`](#method.average_value) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_average_value(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.average_value = value.map(|value| value.into());
+        self
+    }
+
+    /// value is the current value of the metric (as a quantity).
+    #[inline]
+    pub fn value(mut self, value: impl Into<String>) -> Self {
+        self.body.value = Some(value.into());
+        self
+    }
+
+    /// Sets [`value`](#method.value) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_value(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.value = value.map(|value| value.into());
+        self
+    }
+}
@@ -0,0 +1,76 @@
+
+pub mod cross_version_object_reference {
+    include!("./cross_version_object_reference.rs");
+}
+
+pub mod external_metric_source {
+    include!("./external_metric_source.rs");
+}
+
+pub mod external_metric_status {
+    include!("./external_metric_status.rs");
+}
+
+pub mod horizontal_pod_autoscaler {
+    include!("./horizontal_pod_autoscaler.rs");
+}
+
+pub mod horizontal_pod_autoscaler_condition {
+    include!("./horizontal_pod_autoscaler_condition.rs");
+}
+
+pub mod horizontal_pod_autoscaler_list {
+    include!("./horizontal_pod_autoscaler_list.rs");
+}
+
+pub mod horizontal_pod_autoscaler_spec {
+    include!("./horizontal_pod_autoscaler_spec.rs");
+}
+
+pub mod horizontal_pod_autoscaler_status {
+    include!("./horizontal_pod_autoscaler_status.rs");
+}
+
+pub mod metric_identifier {
+    include!("./metric_identifier.rs");
+}
+
+pub mod metric_spec {
+    include!("./metric_spec.rs");
+}
+
+pub mod metric_status {
+    include!("./metric_status.rs");
+}
+
+pub mod metric_target {
+    include!("./metric_target.rs");
+}
+
+pub mod metric_value_status {
+    include!("./metric_value_status.rs");
+}
+
+pub mod object_metric_source {
+    include!("./object_metric_source.rs");
+}
+
+pub mod object_metric_status {
+    include!("./object_metric_status.rs");
+}
+
+pub mod pods_metric_source {
+    include!("./pods_metric_source.rs");
+}
+
+pub mod pods_metric_status {
+    include!("./pods_metric_status.rs");
+}
+
+pub mod resource_metric_source {
+    include!("./resource_metric_source.rs");
+}
+
+pub mod resource_metric_status {
+    include!("./resource_metric_status.rs");
+}
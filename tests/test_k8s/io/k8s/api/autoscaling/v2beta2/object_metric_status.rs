@@ -0,0 +1,75 @@
+
+/// ObjectMetricStatus indicates the current value of a metric describing a kubernetes object (for example, hits-per-second on an Ingress object).
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct ObjectMetricStatus {
+    /// current contains the current value for the given metric
+    pub current: crate::codegen::io::k8s::api::autoscaling::v2beta2::metric_value_status::MetricValueStatus,
+    #[serde(rename = "describedObject")]
+    pub described_object: crate::codegen::io::k8s::api::autoscaling::v2beta2::cross_version_object_reference::CrossVersionObjectReference,
+    /// metric identifies the target metric by name and selector
+    pub metric: crate::codegen::io::k8s::api::autoscaling::v2beta2::metric_identifier::MetricIdentifier,
+}
+
+impl ObjectMetricStatus {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> ObjectMetricStatusBuilder<crate::codegen::generics::MissingCurrent, crate::codegen::generics::MissingDescribedObject, crate::codegen::generics::MissingMetric> {
+        ObjectMetricStatusBuilder {
+            body: Default::default(),
+            _current: core::marker::PhantomData,
+            _described_object: core::marker::PhantomData,
+            _metric: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> ObjectMetricStatusBuilder<crate::codegen::generics::CurrentExists, crate::codegen::generics::DescribedObjectExists, crate::codegen::generics::MetricExists>
+    where
+        Self: Clone,
+    {
+        ObjectMetricStatusBuilder {
+            body: self.clone(),
+            _current: core::marker::PhantomData,
+            _described_object: core::marker::PhantomData,
+            _metric: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<ObjectMetricStatus> for ObjectMetricStatusBuilder<crate::codegen::generics::CurrentExists, crate::codegen::generics::DescribedObjectExists, crate::codegen::generics::MetricExists> {
+    fn into(self) -> ObjectMetricStatus {
+        self.body
+    }
+}
+
+/// Builder for [`ObjectMetricStatus`](./struct.ObjectMetricStatus.html) object.
+#[derive(Debug, Clone)]
+pub struct ObjectMetricStatusBuilder<Current, DescribedObject, Metric> {
+    body: self::ObjectMetricStatus,
+    _current: core::marker::PhantomData<Current>,
+    _described_object: core::marker::PhantomData<DescribedObject>,
+    _metric: core::marker::PhantomData<Metric>,
+}
+
+impl<Current, DescribedObject, Metric> ObjectMetricStatusBuilder<Current, DescribedObject, Metric> {
+    /// current contains the current value for the given metric
+    #[inline]
+    pub fn current(mut self, value: crate::codegen::io::k8s::api::autoscaling::v2beta2::metric_value_status::MetricValueStatus) -> ObjectMetricStatusBuilder<crate::codegen::generics::CurrentExists, DescribedObject, Metric> {
+        self.body.current = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    #[inline]
+    pub fn described_object(mut self, value: crate::codegen::io::k8s::api::autoscaling::v2beta2::cross_version_object_reference::CrossVersionObjectReferenceBuilder<crate::codegen::generics::KindExists, crate::codegen::generics::NameExists>) -> ObjectMetricStatusBuilder<Current, crate::codegen::generics::DescribedObjectExists, Metric> {
+        self.body.described_object = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// metric identifies the target metric by name and selector
+    #[inline]
+    pub fn metric(mut self, value: crate::codegen::io::k8s::api::autoscaling::v2beta2::metric_identifier::MetricIdentifierBuilder<crate::codegen::generics::NameExists>) -> ObjectMetricStatusBuilder<Current, DescribedObject, crate::codegen::generics::MetricExists> {
+        self.body.metric = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+}
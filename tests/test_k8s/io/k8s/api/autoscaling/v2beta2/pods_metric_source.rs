@@ -0,0 +1,64 @@
+
+/// PodsMetricSource indicates how to scale on a metric describing each pod in the current scale target (for example, transactions-processed-per-second). The values will be averaged together before being compared to the target value.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct PodsMetricSource {
+    /// metric identifies the target metric by name and selector
+    pub metric: crate::codegen::io::k8s::api::autoscaling::v2beta2::metric_identifier::MetricIdentifier,
+    /// target specifies the target value for the given metric
+    pub target: crate::codegen::io::k8s::api::autoscaling::v2beta2::metric_target::MetricTarget,
+}
+
+impl PodsMetricSource {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> PodsMetricSourceBuilder<crate::codegen::generics::MissingMetric, crate::codegen::generics::MissingTarget> {
+        PodsMetricSourceBuilder {
+            body: Default::default(),
+            _metric: core::marker::PhantomData,
+            _target: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> PodsMetricSourceBuilder<crate::codegen::generics::MetricExists, crate::codegen::generics::TargetExists>
+    where
+        Self: Clone,
+    {
+        PodsMetricSourceBuilder {
+            body: self.clone(),
+            _metric: core::marker::PhantomData,
+            _target: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<PodsMetricSource> for PodsMetricSourceBuilder<crate::codegen::generics::MetricExists, crate::codegen::generics::TargetExists> {
+    fn into(self) -> PodsMetricSource {
+        self.body
+    }
+}
+
+/// Builder for [`PodsMetricSource`](./struct.PodsMetricSource.html) object.
+#[derive(Debug, Clone)]
+pub struct PodsMetricSourceBuilder<Metric, Target> {
+    body: self::PodsMetricSource,
+    _metric: core::marker::PhantomData<Metric>,
+    _target: core::marker::PhantomData<Target>,
+}
+
+impl<Metric, Target> PodsMetricSourceBuilder<Metric, Target> {
+    /// metric identifies the target metric by name and selector
+    #[inline]
+    pub fn metric(mut self, value: crate::codegen::io::k8s::api::autoscaling::v2beta2::metric_identifier::MetricIdentifierBuilder<crate::codegen::generics::NameExists>) -> PodsMetricSourceBuilder<crate::codegen::generics::MetricExists, Target> {
+        self.body.metric = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// target specifies the target value for the given metric
+    #[inline]
+    pub fn target(mut self, value: crate::codegen::io::k8s::api::autoscaling::v2beta2::metric_target::MetricTargetBuilder<crate::codegen::generics::TypeExists>) -> PodsMetricSourceBuilder<Metric, crate::codegen::generics::TargetExists> {
+        self.body.target = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+}
@@ -0,0 +1,64 @@
+
+/// ResourceMetricSource indicates how to scale on a resource metric known to Kubernetes, as specified in requests and limits, describing each pod in the current scale target (e.g. CPU or memory).  The values will be averaged together before being compared to the target.  Such metrics are built in to Kubernetes, and have special scaling options on top of those available to normal per-pod metrics using the "pods" source.  Only one "target" type should be set.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct ResourceMetricSource {
+    /// name is the name of the resource in question.
+    pub name: String,
+    /// target specifies the target value for the given metric
+    pub target: crate::codegen::io::k8s::api::autoscaling::v2beta2::metric_target::MetricTarget,
+}
+
+impl ResourceMetricSource {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> ResourceMetricSourceBuilder<crate::codegen::generics::MissingName, crate::codegen::generics::MissingTarget> {
+        ResourceMetricSourceBuilder {
+            body: Default::default(),
+            _name: core::marker::PhantomData,
+            _target: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> ResourceMetricSourceBuilder<crate::codegen::generics::NameExists, crate::codegen::generics::TargetExists>
+    where
+        Self: Clone,
+    {
+        ResourceMetricSourceBuilder {
+            body: self.clone(),
+            _name: core::marker::PhantomData,
+            _target: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<ResourceMetricSource> for ResourceMetricSourceBuilder<crate::codegen::generics::NameExists, crate::codegen::generics::TargetExists> {
+    fn into(self) -> ResourceMetricSource {
+        self.body
+    }
+}
+
+/// Builder for [`ResourceMetricSource`](./struct.ResourceMetricSource.html) object.
+#[derive(Debug, Clone)]
+pub struct ResourceMetricSourceBuilder<Name, Target> {
+    body: self::ResourceMetricSource,
+    _name: core::marker::PhantomData<Name>,
+    _target: core::marker::PhantomData<Target>,
+}
+
+impl<Name, Target> ResourceMetricSourceBuilder<Name, Target> {
+    /// name is the name of the resource in question.
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> ResourceMetricSourceBuilder<crate::codegen::generics::NameExists, Target> {
+        self.body.name = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// target specifies the target value for the given metric
+    #[inline]
+    pub fn target(mut self, value: crate::codegen::io::k8s::api::autoscaling::v2beta2::metric_target::MetricTargetBuilder<crate::codegen::generics::TypeExists>) -> ResourceMetricSourceBuilder<Name, crate::codegen::generics::TargetExists> {
+        self.body.target = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+}
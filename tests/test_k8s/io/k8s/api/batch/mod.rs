@@ -0,0 +1,12 @@
+
+pub mod v1 {
+    include!("./v1/mod.rs");
+}
+
+pub mod v1beta1 {
+    include!("./v1beta1/mod.rs");
+}
+
+pub mod v2alpha1 {
+    include!("./v2alpha1/mod.rs");
+}
@@ -0,0 +1,174 @@
+
+/// CronJobSpec describes how the job execution will look like and when it will actually run.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct CronJobSpec {
+    /// Specifies how to treat concurrent executions of a Job. Valid values are: - "Allow" (default): allows CronJobs to run concurrently; - "Forbid": forbids concurrent runs, skipping next run if previous run hasn't finished yet; - "Replace": cancels currently running job and replaces it with a new one
+    #[serde(rename = "concurrencyPolicy", skip_serializing_if = "Option::is_none")]
+    pub concurrency_policy: Option<String>,
+    /// The number of failed finished jobs to retain. This is a pointer to distinguish between explicit zero and not specified. Defaults to 1.
+    #[serde(rename = "failedJobsHistoryLimit", skip_serializing_if = "Option::is_none")]
+    pub failed_jobs_history_limit: Option<i32>,
+    /// Specifies the job that will be created when executing a CronJob.
+    #[serde(rename = "jobTemplate")]
+    pub job_template: crate::codegen::io::k8s::api::batch::v1beta1::job_template_spec::JobTemplateSpec,
+    /// The schedule in Cron format, see https://en.wikipedia.org/wiki/Cron.
+    pub schedule: String,
+    /// Optional deadline in seconds for starting the job if it misses scheduled time for any reason.  Missed jobs executions will be counted as failed ones.
+    #[serde(rename = "startingDeadlineSeconds", skip_serializing_if = "Option::is_none")]
+    pub starting_deadline_seconds: Option<i64>,
+    /// The number of successful finished jobs to retain. This is a pointer to distinguish between explicit zero and not specified. Defaults to 3.
+    #[serde(rename = "successfulJobsHistoryLimit", skip_serializing_if = "Option::is_none")]
+    pub successful_jobs_history_limit: Option<i32>,
+    /// This flag tells the controller to suspend subsequent executions, it does not apply to already started executions.  Defaults to false.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub suspend: Option<bool>,
+}
+
+impl CronJobSpec {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> CronJobSpecBuilder<crate::codegen::generics::MissingJobTemplate, crate::codegen::generics::MissingSchedule> {
+        CronJobSpecBuilder {
+            body: Default::default(),
+            _job_template: core::marker::PhantomData,
+            _schedule: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> CronJobSpecBuilder<crate::codegen::generics::JobTemplateExists, crate::codegen::generics::ScheduleExists>
+    where
+        Self: Clone,
+    {
+        CronJobSpecBuilder {
+            body: self.clone(),
+            _job_template: core::marker::PhantomData,
+            _schedule: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<CronJobSpec> for CronJobSpecBuilder<crate::codegen::generics::JobTemplateExists, crate::codegen::generics::ScheduleExists> {
+    fn into(self) -> CronJobSpec {
+        self.body
+    }
+}
+
+/// Builder for [`CronJobSpec`](./struct.CronJobSpec.html) object.
+#[derive(Debug, Clone)]
+pub struct CronJobSpecBuilder<JobTemplate, Schedule> {
+    body: self::CronJobSpec,
+    _job_template: core::marker::PhantomData<JobTemplate>,
+    _schedule: core::marker::PhantomData<Schedule>,
+}
+
+impl<JobTemplate, Schedule> CronJobSpecBuilder<JobTemplate, Schedule> {
+    /// Specifies how to treat concurrent executions of a Job. Valid values are: - "Allow" (default): allows CronJobs to run concurrently; - "Forbid": forbids concurrent runs, skipping next run if previous run hasn't finished yet; - "Replace": cancels currently running job and replaces it with a new one
+    #[inline]
+    pub fn concurrency_policy(mut self, value: impl Into<String>) -> Self {
+        self.body.concurrency_policy = Some(value.into());
+        self
+    }
+
+    /// Sets [`concurrency_policy`](#method.concurrency_policy) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_concurrency_policy(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.concurrency_policy = value.map(|value| value.into());
+        self
+    }
+
+    /// The number of failed finished jobs to retain. This is a pointer to distinguish between explicit zero and not specified. Defaults to 1.
+    #[inline]
+    pub fn failed_jobs_history_limit(mut self, value: impl Into<i32>) -> Self {
+        self.body.failed_jobs_history_limit = Some(value.into());
+        self
+    }
+
+    /// Sets [`failed_jobs_history_limit`](#method.failed_jobs_history_limit) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_failed_jobs_history_limit(mut self, value: Option<impl Into<i32>>) -> Self {
+        self.body.failed_jobs_history_limit = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`failed_jobs_history_limit`](#method.failed_jobs_history_limit), but takes a reference.
+    #[inline]
+    pub fn failed_jobs_history_limit_ref(self, value: &i32) -> Self {
+        self.failed_jobs_history_limit(*value)
+    }
+
+    /// Specifies the job that will be created when executing a CronJob.
+    #[inline]
+    pub fn job_template(mut self, value: crate::codegen::io::k8s::api::batch::v1beta1::job_template_spec::JobTemplateSpec) -> CronJobSpecBuilder<crate::codegen::generics::JobTemplateExists, Schedule> {
+        self.body.job_template = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// The schedule in Cron format, see https://en.wikipedia.org/wiki/Cron.
+    #[inline]
+    pub fn schedule(mut self, value: impl Into<String>) -> CronJobSpecBuilder<JobTemplate, crate::codegen::generics::ScheduleExists> {
+        self.body.schedule = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Optional deadline in seconds for starting the job if it misses scheduled time for any reason.  Missed jobs executions will be counted as failed ones.
+    #[inline]
+    pub fn starting_deadline_seconds(mut self, value: impl Into<i64>) -> Self {
+        self.body.starting_deadline_seconds = Some(value.into());
+        self
+    }
+
+    /// Sets [`starting_deadline_seconds`](#method.starting_deadline_seconds) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_starting_deadline_seconds(mut self, value: Option<impl Into<i64>>) -> Self {
+        self.body.starting_deadline_seconds = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`starting_deadline_seconds`](#method.starting_deadline_seconds), but takes a reference.
+    #[inline]
+    pub fn starting_deadline_seconds_ref(self, value: &i64) -> Self {
+        self.starting_deadline_seconds(*value)
+    }
+
+    /// The number of successful finished jobs to retain. This is a pointer to distinguish between explicit zero and not specified. Defaults to 3.
+    #[inline]
+    pub fn successful_jobs_history_limit(mut self, value: impl Into<i32>) -> Self {
+        self.body.successful_jobs_history_limit = Some(value.into());
+        self
+    }
+
+    /// Sets [`successful_jobs_history_limit`](#method.successful_jobs_history_limit) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_successful_jobs_history_limit(mut self, value: Option<impl Into<i32>>) -> Self {
+        self.body.successful_jobs_history_limit = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`successful_jobs_history_limit`](#method.successful_jobs_history_limit), but takes a reference.
+    #[inline]
+    pub fn successful_jobs_history_limit_ref(self, value: &i32) -> Self {
+        self.successful_jobs_history_limit(*value)
+    }
+
+    /// This flag tells the controller to suspend subsequent executions, it does not apply to already started executions.  Defaults to false.
+    #[inline]
+    pub fn suspend(mut self, value: impl Into<bool>) -> Self {
+        self.body.suspend = Some(value.into());
+        self
+    }
+
+    /// Sets [`suspend`](#method.suspend) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_suspend(mut self, value: Option<impl Into<bool>>) -> Self {
+        self.body.suspend = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`suspend`](#method.suspend), but takes a reference.
+    #[inline]
+    pub fn suspend_ref(self, value: &bool) -> Self {
+        self.suspend(*value)
+    }
+}
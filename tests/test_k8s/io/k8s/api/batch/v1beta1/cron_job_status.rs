@@ -0,0 +1,81 @@
+
+/// CronJobStatus represents the current state of a cron job.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct CronJobStatus {
+    /// A list of pointers to currently running jobs.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub active: Option<Vec<crate::codegen::io::k8s::api::core::v1::object_reference::ObjectReference>>,
+    /// Information when was the last time the job was successfully scheduled.
+    #[serde(rename = "lastScheduleTime", skip_serializing_if = "Option::is_none")]
+    pub last_schedule_time: Option<String>,
+}
+
+impl CronJobStatus {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> CronJobStatusBuilder {
+        CronJobStatusBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> CronJobStatusBuilder
+    where
+        Self: Clone,
+    {
+        CronJobStatusBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<CronJobStatus> for CronJobStatusBuilder {
+    fn into(self) -> CronJobStatus {
+        self.body
+    }
+}
+
+/// Builder for [`CronJobStatus`](./struct.CronJobStatus.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct CronJobStatusBuilder {
+    body: self::CronJobStatus,
+}
+
+impl CronJobStatusBuilder {
+    /// A list of pointers to currently running jobs.
+    #[inline]
+    pub fn active(mut self, value: impl Iterator<Item = crate::codegen::io::k8s::api::core::v1::object_reference::ObjectReference>) -> Self {
+        self.body.active = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`active`](#method.active) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_active(mut self, value: Option<impl Iterator<Item = crate::codegen::io::k8s::api::core::v1::object_reference::ObjectReference>>) -> Self {
+        self.body.active = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`active`](#method.active), but takes the values as a `Vec`.
+    #[inline]
+    pub fn active_vec(mut self, value: impl Into<Vec<crate::codegen::io::k8s::api::core::v1::object_reference::ObjectReference>>) -> Self {
+        self.body.active = Some(value.into());
+        self
+    }
+
+    /// Information when was the last time the job was successfully scheduled.
+    #[inline]
+    pub fn last_schedule_time(mut self, value: impl Into<String>) -> Self {
+        self.body.last_schedule_time = Some(value.into());
+        self
+    }
+
+    /// Sets [`last_schedule_time`](#method.last_schedule_time) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_last_schedule_time(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.last_schedule_time = value.map(|value| value.into());
+        self
+    }
+}
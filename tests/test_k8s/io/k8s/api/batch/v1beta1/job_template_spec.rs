@@ -0,0 +1,74 @@
+
+/// JobTemplateSpec describes the data a Job should have when created from a template
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct JobTemplateSpec {
+    /// Standard object's metadata of the jobs created from this template. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#metadata
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<crate::codegen::io::k8s::apimachinery::pkg::apis::meta::v1::object_meta::ObjectMeta>,
+    /// Specification of the desired behavior of the job. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#spec-and-status
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub spec: Option<crate::codegen::io::k8s::api::batch::v1::job_spec::JobSpec>,
+}
+
+impl JobTemplateSpec {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> JobTemplateSpecBuilder {
+        JobTemplateSpecBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> JobTemplateSpecBuilder
+    where
+        Self: Clone,
+    {
+        JobTemplateSpecBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<JobTemplateSpec> for JobTemplateSpecBuilder {
+    fn into(self) -> JobTemplateSpec {
+        self.body
+    }
+}
+
+/// Builder for [`JobTemplateSpec`](./struct.JobTemplateSpec.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct JobTemplateSpecBuilder {
+    body: self::JobTemplateSpec,
+}
+
+impl JobTemplateSpecBuilder {
+    /// Standard object's metadata of the jobs created from this template. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#metadata
+    #[inline]
+    pub fn metadata(mut self, value: crate::codegen::io::k8s::apimachinery::pkg::apis::meta::v1::object_meta::ObjectMeta) -> Self {
+        self.body.metadata = Some(value.into());
+        self
+    }
+
+    /// Sets [`metadata`](#method.metadata) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_metadata(mut self, value: Option<crate::codegen::io::k8s::apimachinery::pkg::apis::meta::v1::object_meta::ObjectMeta>) -> Self {
+        self.body.metadata = value.map(|value| value.into());
+        self
+    }
+
+    /// Specification of the desired behavior of the job. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#spec-and-status
+    #[inline]
+    pub fn spec(mut self, value: crate::codegen::io::k8s::api::batch::v1::job_spec::JobSpecBuilder<crate::codegen::generics::TemplateExists>) -> Self {
+        self.body.spec = Some(value.into());
+        self
+    }
+
+    /// Sets [`spec`](#method.spec) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_spec(mut self, value: Option<crate::codegen::io::k8s::api::batch::v1::job_spec::JobSpecBuilder<crate::codegen::generics::TemplateExists>>) -> Self {
+        self.body.spec = value.map(|value| value.into());
+        self
+    }
+}
@@ -0,0 +1,102 @@
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct CertificateSigningRequestCondition {
+    /// timestamp for the last update to this condition
+    #[serde(rename = "lastUpdateTime", skip_serializing_if = "Option::is_none")]
+    pub last_update_time: Option<String>,
+    /// human readable message with details about the request state
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    /// brief reason for the request state
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+    /// request approval state, currently Approved or Denied.
+    #[serde(rename = "type")]
+    pub type_: String,
+}
+
+impl CertificateSigningRequestCondition {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> CertificateSigningRequestConditionBuilder<crate::codegen::generics::MissingType> {
+        CertificateSigningRequestConditionBuilder {
+            body: Default::default(),
+            _type: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> CertificateSigningRequestConditionBuilder<crate::codegen::generics::TypeExists>
+    where
+        Self: Clone,
+    {
+        CertificateSigningRequestConditionBuilder {
+            body: self.clone(),
+            _type: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<CertificateSigningRequestCondition> for CertificateSigningRequestConditionBuilder<crate::codegen::generics::TypeExists> {
+    fn into(self) -> CertificateSigningRequestCondition {
+        self.body
+    }
+}
+
+/// Builder for [`CertificateSigningRequestCondition`](./struct.CertificateSigningRequestCondition.html) object.
+#[derive(Debug, Clone)]
+pub struct CertificateSigningRequestConditionBuilder<Type> {
+    body: self::CertificateSigningRequestCondition,
+    _type: core::marker::PhantomData<Type>,
+}
+
+impl<Type> CertificateSigningRequestConditionBuilder<Type> {
+    /// timestamp for the last update to this condition
+    #[inline]
+    pub fn last_update_time(mut self, value: impl Into<String>) -> Self {
+        self.body.last_update_time = Some(value.into());
+        self
+    }
+
+    /// Sets [`last_update_time`](#method.last_update_time) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_last_update_time(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.last_update_time = value.map(|value| value.into());
+        self
+    }
+
+    /// human readable message with details about the request state
+    #[inline]
+    pub fn message(mut self, value: impl Into<String>) -> Self {
+        self.body.message = Some(value.into());
+        self
+    }
+
+    /// Sets [`message`](#method.message) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_message(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.message = value.map(|value| value.into());
+        self
+    }
+
+    /// brief reason for the request state
+    #[inline]
+    pub fn reason(mut self, value: impl Into<String>) -> Self {
+        self.body.reason = Some(value.into());
+        self
+    }
+
+    /// Sets [`reason`](#method.reason) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_reason(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.reason = value.map(|value| value.into());
+        self
+    }
+
+    /// request approval state, currently Approved or Denied.
+    #[inline]
+    pub fn type_(mut self, value: impl Into<String>) -> CertificateSigningRequestConditionBuilder<crate::codegen::generics::TypeExists> {
+        self.body.type_ = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+}
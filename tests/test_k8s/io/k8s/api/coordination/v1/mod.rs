@@ -0,0 +1,12 @@
+
+pub mod lease {
+    include!("./lease.rs");
+}
+
+pub mod lease_list {
+    include!("./lease_list.rs");
+}
+
+pub mod lease_spec {
+    include!("./lease_spec.rs");
+}
@@ -0,0 +1,89 @@
+
+/// AzureFile represents an Azure File Service mount on the host and bind mount to the pod.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct AzureFileVolumeSource {
+    /// Defaults to false (read/write). ReadOnly here will force the ReadOnly setting in VolumeMounts.
+    #[serde(rename = "readOnly", skip_serializing_if = "Option::is_none")]
+    pub read_only: Option<bool>,
+    /// the name of secret that contains Azure Storage Account Name and Key
+    #[serde(rename = "secretName")]
+    pub secret_name: String,
+    /// Share Name
+    #[serde(rename = "shareName")]
+    pub share_name: String,
+}
+
+impl AzureFileVolumeSource {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> AzureFileVolumeSourceBuilder<crate::codegen::generics::MissingSecretName, crate::codegen::generics::MissingShareName> {
+        AzureFileVolumeSourceBuilder {
+            body: Default::default(),
+            _secret_name: core::marker::PhantomData,
+            _share_name: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> AzureFileVolumeSourceBuilder<crate::codegen::generics::SecretNameExists, crate::codegen::generics::ShareNameExists>
+    where
+        Self: Clone,
+    {
+        AzureFileVolumeSourceBuilder {
+            body: self.clone(),
+            _secret_name: core::marker::PhantomData,
+            _share_name: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<AzureFileVolumeSource> for AzureFileVolumeSourceBuilder<crate::codegen::generics::SecretNameExists, crate::codegen::generics::ShareNameExists> {
+    fn into(self) -> AzureFileVolumeSource {
+        self.body
+    }
+}
+
+/// Builder for [`AzureFileVolumeSource`](./struct.AzureFileVolumeSource.html) object.
+#[derive(Debug, Clone)]
+pub struct AzureFileVolumeSourceBuilder<SecretName, ShareName> {
+    body: self::AzureFileVolumeSource,
+    _secret_name: core::marker::PhantomData<SecretName>,
+    _share_name: core::marker::PhantomData<ShareName>,
+}
+
+impl<SecretName, ShareName> AzureFileVolumeSourceBuilder<SecretName, ShareName> {
+    /// Defaults to false (read/write). ReadOnly here will force the ReadOnly setting in VolumeMounts.
+    #[inline]
+    pub fn read_only(mut self, value: impl Into<bool>) -> Self {
+        self.body.read_only = Some(value.into());
+        self
+    }
+
+    /// Sets [`read_only`](#method.read_only) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_read_only(mut self, value: Option<impl Into<bool>>) -> Self {
+        self.body.read_only = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`read_only`](#method.read_only), but takes a reference.
+    #[inline]
+    pub fn read_only_ref(self, value: &bool) -> Self {
+        self.read_only(*value)
+    }
+
+    /// the name of secret that contains Azure Storage Account Name and Key
+    #[inline]
+    pub fn secret_name(mut self, value: impl Into<String>) -> AzureFileVolumeSourceBuilder<crate::codegen::generics::SecretNameExists, ShareName> {
+        self.body.secret_name = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Share Name
+    #[inline]
+    pub fn share_name(mut self, value: impl Into<String>) -> AzureFileVolumeSourceBuilder<SecretName, crate::codegen::generics::ShareNameExists> {
+        self.body.share_name = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+}
@@ -0,0 +1,63 @@
+
+/// ClientIPConfig represents the configurations of Client IP based session affinity.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct ClientIpConfig {
+    /// timeoutSeconds specifies the seconds of ClientIP type session sticky time. The value must be >0 && <=86400(for 1 day) if ServiceAffinity == "ClientIP". Default value is 10800(for 3 hours).
+    #[serde(rename = "timeoutSeconds", skip_serializing_if = "Option::is_none")]
+    pub timeout_seconds: Option<i32>,
+}
+
+impl ClientIpConfig {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> ClientIpConfigBuilder {
+        ClientIpConfigBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> ClientIpConfigBuilder
+    where
+        Self: Clone,
+    {
+        ClientIpConfigBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<ClientIpConfig> for ClientIpConfigBuilder {
+    fn into(self) -> ClientIpConfig {
+        self.body
+    }
+}
+
+/// Builder for [`ClientIpConfig`](./struct.ClientIpConfig.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct ClientIpConfigBuilder {
+    body: self::ClientIpConfig,
+}
+
+impl ClientIpConfigBuilder {
+    /// timeoutSeconds specifies the seconds of ClientIP type session sticky time. The value must be >0 && <=86400(for 1 day) if ServiceAffinity == "ClientIP". Default value is 10800(for 3 hours).
+    #[inline]
+    pub fn timeout_seconds(mut self, value: impl Into<i32>) -> Self {
+        self.body.timeout_seconds = Some(value.into());
+        self
+    }
+
+    /// Sets [`timeout_seconds`](#method.timeout_seconds) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_timeout_seconds(mut self, value: Option<impl Into<i32>>) -> Self {
+        self.body.timeout_seconds = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`timeout_seconds`](#method.timeout_seconds), but takes a reference.
+    #[inline]
+    pub fn timeout_seconds_ref(self, value: &i32) -> Self {
+        self.timeout_seconds(*value)
+    }
+}
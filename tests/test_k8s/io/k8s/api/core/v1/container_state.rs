@@ -0,0 +1,91 @@
+
+/// ContainerState holds a possible state of container. Only one of its members may be specified. If none of them is specified, the default one is ContainerStateWaiting.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct ContainerState {
+    /// Details about a running container
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub running: Option<crate::codegen::io::k8s::api::core::v1::container_state_running::ContainerStateRunning>,
+    /// Details about a terminated container
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub terminated: Option<crate::codegen::io::k8s::api::core::v1::container_state_terminated::ContainerStateTerminated>,
+    /// Details about a waiting container
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub waiting: Option<crate::codegen::io::k8s::api::core::v1::container_state_waiting::ContainerStateWaiting>,
+}
+
+impl ContainerState {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> ContainerStateBuilder {
+        ContainerStateBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> ContainerStateBuilder
+    where
+        Self: Clone,
+    {
+        ContainerStateBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<ContainerState> for ContainerStateBuilder {
+    fn into(self) -> ContainerState {
+        self.body
+    }
+}
+
+/// Builder for [`ContainerState`](./struct.ContainerState.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct ContainerStateBuilder {
+    body: self::ContainerState,
+}
+
+impl ContainerStateBuilder {
+    /// Details about a running container
+    #[inline]
+    pub fn running(mut self, value: crate::codegen::io::k8s::api::core::v1::container_state_running::ContainerStateRunning) -> Self {
+        self.body.running = Some(value.into());
+        self
+    }
+
+    /// Sets [`running`](#method.running) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_running(mut self, value: Option<crate::codegen::io::k8s::api::core::v1::container_state_running::ContainerStateRunning>) -> Self {
+        self.body.running = value.map(|value| value.into());
+        self
+    }
+
+    /// Details about a terminated container
+    #[inline]
+    pub fn terminated(mut self, value: crate::codegen::io::k8s::api::core::v1::container_state_terminated::ContainerStateTerminatedBuilder<crate::codegen::generics::ExitCodeExists>) -> Self {
+        self.body.terminated = Some(value.into());
+        self
+    }
+
+    /// Sets [`terminated`](#method.terminated) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_terminated(mut self, value: Option<crate::codegen::io::k8s::api::core::v1::container_state_terminated::ContainerStateTerminatedBuilder<crate::codegen::generics::ExitCodeExists>>) -> Self {
+        self.body.terminated = value.map(|value| value.into());
+        self
+    }
+
+    /// Details about a waiting container
+    #[inline]
+    pub fn waiting(mut self, value: crate::codegen::io::k8s::api::core::v1::container_state_waiting::ContainerStateWaiting) -> Self {
+        self.body.waiting = Some(value.into());
+        self
+    }
+
+    /// Sets [`waiting`](#method.waiting) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_waiting(mut self, value: Option<crate::codegen::io::k8s::api::core::v1::container_state_waiting::ContainerStateWaiting>) -> Self {
+        self.body.waiting = value.map(|value| value.into());
+        self
+    }
+}
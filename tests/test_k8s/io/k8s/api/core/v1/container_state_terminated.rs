@@ -0,0 +1,167 @@
+
+/// ContainerStateTerminated is a terminated state of a container.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct ContainerStateTerminated {
+    /// Container's ID in the format 'docker://\<container_id>'
+    #[serde(rename = "containerID", skip_serializing_if = "Option::is_none")]
+    pub container_id: Option<String>,
+    /// Exit status from the last termination of the container
+    #[serde(rename = "exitCode")]
+    pub exit_code: i32,
+    /// Time at which the container last terminated
+    #[serde(rename = "finishedAt", skip_serializing_if = "Option::is_none")]
+    pub finished_at: Option<String>,
+    /// Message regarding the last termination of the container
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    /// (brief) reason from the last termination of the container
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+    /// Signal from the last termination of the container
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub signal: Option<i32>,
+    /// Time at which previous execution of the container started
+    #[serde(rename = "startedAt", skip_serializing_if = "Option::is_none")]
+    pub started_at: Option<String>,
+}
+
+impl ContainerStateTerminated {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> ContainerStateTerminatedBuilder<crate::codegen::generics::MissingExitCode> {
+        ContainerStateTerminatedBuilder {
+            body: Default::default(),
+            _exit_code: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> ContainerStateTerminatedBuilder<crate::codegen::generics::ExitCodeExists>
+    where
+        Self: Clone,
+    {
+        ContainerStateTerminatedBuilder {
+            body: self.clone(),
+            _exit_code: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<ContainerStateTerminated> for ContainerStateTerminatedBuilder<crate::codegen::generics::ExitCodeExists> {
+    fn into(self) -> ContainerStateTerminated {
+        self.body
+    }
+}
+
+/// Builder for [`ContainerStateTerminated`](./struct.ContainerStateTerminated.html) object.
+#[derive(Debug, Clone)]
+pub struct ContainerStateTerminatedBuilder<ExitCode> {
+    body: self::ContainerStateTerminated,
+    _exit_code: core::marker::PhantomData<ExitCode>,
+}
+
+impl<ExitCode> ContainerStateTerminatedBuilder<ExitCode> {
+    /// Container's ID in the format 'docker://\<container_id>'
+    #[inline]
+    pub fn container_id(mut self, value: impl Into<String>) -> Self {
+        self.body.container_id = Some(value.into());
+        self
+    }
+
+    /// Sets [`container_id`](#method.container_id) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_container_id(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.container_id = value.map(|value| value.into());
+        self
+    }
+
+    /// Exit status from the last termination of the container
+    #[inline]
+    pub fn exit_code(mut self, value: impl Into<i32>) -> ContainerStateTerminatedBuilder<crate::codegen::generics::ExitCodeExists> {
+        self.body.exit_code = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Same as [`exit_code`](#method.exit_code), but takes a reference.
+    #[inline]
+    pub fn exit_code_ref(self, value: &i32) -> ContainerStateTerminatedBuilder<crate::codegen::generics::ExitCodeExists> {
+        self.exit_code(*value)
+    }
+
+    /// Time at which the container last terminated
+    #[inline]
+    pub fn finished_at(mut self, value: impl Into<String>) -> Self {
+        self.body.finished_at = Some(value.into());
+        self
+    }
+
+    /// Sets [`finished_at`](#method.finished_at) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_finished_at(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.finished_at = value.map(|value| value.into());
+        self
+    }
+
+    /// Message regarding the last termination of the container
+    #[inline]
+    pub fn message(mut self, value: impl Into<String>) -> Self {
+        self.body.message = Some(value.into());
+        self
+    }
+
+    /// Sets [`message`](#method.message) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_message(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.message = value.map(|value| value.into());
+        self
+    }
+
+    /// (brief) reason from the last termination of the container
+    #[inline]
+    pub fn reason(mut self, value: impl Into<String>) -> Self {
+        self.body.reason = Some(value.into());
+        self
+    }
+
+    /// Sets [`reason`](#method.reason) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_reason(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.reason = value.map(|value| value.into());
+        self
+    }
+
+    /// Signal from the last termination of the container
+    #[inline]
+    pub fn signal(mut self, value: impl Into<i32>) -> Self {
+        self.body.signal = Some(value.into());
+        self
+    }
+
+    /// Sets [`signal`](#method.signal) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_signal(mut self, value: Option<impl Into<i32>>) -> Self {
+        self.body.signal = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`signal`](#method.signal), but takes a reference.
+    #[inline]
+    pub fn signal_ref(self, value: &i32) -> Self {
+        self.signal(*value)
+    }
+
+    /// Time at which previous execution of the container started
+    #[inline]
+    pub fn started_at(mut self, value: impl Into<String>) -> Self {
+        self.body.started_at = Some(value.into());
+        self
+    }
+
+    /// Sets [`started_at`](#method.started_at) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_started_at(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.started_at = value.map(|value| value.into());
+        self
+    }
+}
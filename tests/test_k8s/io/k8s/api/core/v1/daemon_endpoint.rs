@@ -0,0 +1,59 @@
+
+/// DaemonEndpoint contains information about a single Daemon endpoint.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct DaemonEndpoint {
+    /// Port number of the given endpoint.
+    #[serde(rename = "Port")]
+    pub port: i32,
+}
+
+impl DaemonEndpoint {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> DaemonEndpointBuilder<crate::codegen::generics::MissingPort> {
+        DaemonEndpointBuilder {
+            body: Default::default(),
+            _port: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> DaemonEndpointBuilder<crate::codegen::generics::PortExists>
+    where
+        Self: Clone,
+    {
+        DaemonEndpointBuilder {
+            body: self.clone(),
+            _port: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<DaemonEndpoint> for DaemonEndpointBuilder<crate::codegen::generics::PortExists> {
+    fn into(self) -> DaemonEndpoint {
+        self.body
+    }
+}
+
+/// Builder for [`DaemonEndpoint`](./struct.DaemonEndpoint.html) object.
+#[derive(Debug, Clone)]
+pub struct DaemonEndpointBuilder<Port> {
+    body: self::DaemonEndpoint,
+    _port: core::marker::PhantomData<Port>,
+}
+
+impl<Port> DaemonEndpointBuilder<Port> {
+    /// Port number of the given endpoint.
+    #[inline]
+    pub fn port(mut self, value: impl Into<i32>) -> DaemonEndpointBuilder<crate::codegen::generics::PortExists> {
+        self.body.port = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Same as [`port`](#method.port), but takes a reference.
+    #[inline]
+    pub fn port_ref(self, value: &i32) -> DaemonEndpointBuilder<crate::codegen::generics::PortExists> {
+        self.port(*value)
+    }
+}
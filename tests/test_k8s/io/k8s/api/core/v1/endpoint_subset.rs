@@ -0,0 +1,119 @@
+
+/// EndpointSubset is a group of addresses with a common set of ports. The expanded set of endpoints is the Cartesian product of Addresses x Ports. For example, given:
+///   {
+///     Addresses: \[{"ip": "10.10.1.1"}, {"ip": "10.10.2.2"}\],
+///     Ports:     \[{"name": "a", "port": 8675}, {"name": "b", "port": 309}\]
+///   }
+/// The resulting set of endpoints can be viewed as:
+///     a: \[ 10.10.1.1:8675, 10.10.2.2:8675 \],
+///     b: \[ 10.10.1.1:309, 10.10.2.2:309 \]
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct EndpointSubset {
+    /// IP addresses which offer the related ports that are marked as ready. These endpoints should be considered safe for load balancers and clients to utilize.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub addresses: Option<Vec<crate::codegen::io::k8s::api::core::v1::endpoint_address::EndpointAddress>>,
+    /// IP addresses which offer the related ports but are not currently marked as ready because they have not yet finished starting, have recently failed a readiness check, or have recently failed a liveness check.
+    #[serde(rename = "notReadyAddresses", skip_serializing_if = "Option::is_none")]
+    pub not_ready_addresses: Option<Vec<crate::codegen::io::k8s::api::core::v1::endpoint_address::EndpointAddress>>,
+    /// Port numbers available on the related IP addresses.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ports: Option<Vec<crate::codegen::io::k8s::api::core::v1::endpoint_port::EndpointPort>>,
+}
+
+impl EndpointSubset {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> EndpointSubsetBuilder {
+        EndpointSubsetBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> EndpointSubsetBuilder
+    where
+        Self: Clone,
+    {
+        EndpointSubsetBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<EndpointSubset> for EndpointSubsetBuilder {
+    fn into(self) -> EndpointSubset {
+        self.body
+    }
+}
+
+/// Builder for [`EndpointSubset`](./struct.EndpointSubset.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct EndpointSubsetBuilder {
+    body: self::EndpointSubset,
+}
+
+impl EndpointSubsetBuilder {
+    /// IP addresses which offer the related ports that are marked as ready. These endpoints should be considered safe for load balancers and clients to utilize.
+    #[inline]
+    pub fn addresses(mut self, value: impl Iterator<Item = crate::codegen::io::k8s::api::core::v1::endpoint_address::EndpointAddressBuilder<crate::codegen::generics::IpExists>>) -> Self {
+        self.body.addresses = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`addresses`](#method.addresses) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_addresses(mut self, value: Option<impl Iterator<Item = crate::codegen::io::k8s::api::core::v1::endpoint_address::EndpointAddressBuilder<crate::codegen::generics::IpExists>>>) -> Self {
+        self.body.addresses = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`addresses`](#method.addresses), but takes the values as a `Vec`.
+    #[inline]
+    pub fn addresses_vec(mut self, value: impl Into<Vec<crate::codegen::io::k8s::api::core::v1::endpoint_address::EndpointAddress>>) -> Self {
+        self.body.addresses = Some(value.into());
+        self
+    }
+
+    /// IP addresses which offer the related ports but are not currently marked as ready because they have not yet finished starting, have recently failed a readiness check, or have recently failed a liveness check.
+    #[inline]
+    pub fn not_ready_addresses(mut self, value: impl Iterator<Item = crate::codegen::io::k8s::api::core::v1::endpoint_address::EndpointAddressBuilder<crate::codegen::generics::IpExists>>) -> Self {
+        self.body.not_ready_addresses = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`not_ready_addresses`](#method.not_ready_addresses) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_not_ready_addresses(mut self, value: Option<impl Iterator<Item = crate::codegen::io::k8s::api::core::v1::endpoint_address::EndpointAddressBuilder<crate::codegen::generics::IpExists>>>) -> Self {
+        self.body.not_ready_addresses = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`not_ready_addresses`](#method.not_ready_addresses), but takes the values as a `Vec`.
+    #[inline]
+    pub fn not_ready_addresses_vec(mut self, value: impl Into<Vec<crate::codegen::io::k8s::api::core::v1::endpoint_address::EndpointAddress>>) -> Self {
+        self.body.not_ready_addresses = Some(value.into());
+        self
+    }
+
+    /// Port numbers available on the related IP addresses.
+    #[inline]
+    pub fn ports(mut self, value: impl Iterator<Item = crate::codegen::io::k8s::api::core::v1::endpoint_port::EndpointPortBuilder<crate::codegen::generics::PortExists>>) -> Self {
+        self.body.ports = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`ports`](#method.ports) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_ports(mut self, value: Option<impl Iterator<Item = crate::codegen::io::k8s::api::core::v1::endpoint_port::EndpointPortBuilder<crate::codegen::generics::PortExists>>>) -> Self {
+        self.body.ports = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`ports`](#method.ports), but takes the values as a `Vec`.
+    #[inline]
+    pub fn ports_vec(mut self, value: impl Into<Vec<crate::codegen::io::k8s::api::core::v1::endpoint_port::EndpointPort>>) -> Self {
+        self.body.ports = Some(value.into());
+        self
+    }
+}
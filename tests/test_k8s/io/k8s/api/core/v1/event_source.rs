@@ -0,0 +1,74 @@
+
+/// EventSource contains information for an event.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct EventSource {
+    /// Component from which the event is generated.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub component: Option<String>,
+    /// Node name on which the event is generated.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub host: Option<String>,
+}
+
+impl EventSource {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> EventSourceBuilder {
+        EventSourceBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> EventSourceBuilder
+    where
+        Self: Clone,
+    {
+        EventSourceBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<EventSource> for EventSourceBuilder {
+    fn into(self) -> EventSource {
+        self.body
+    }
+}
+
+/// Builder for [`EventSource`](./struct.EventSource.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct EventSourceBuilder {
+    body: self::EventSource,
+}
+
+impl EventSourceBuilder {
+    /// Component from which the event is generated.
+    #[inline]
+    pub fn component(mut self, value: impl Into<String>) -> Self {
+        self.body.component = Some(value.into());
+        self
+    }
+
+    /// Sets [`component`](#method.component) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_component(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.component = value.map(|value| value.into());
+        self
+    }
+
+    /// Node name on which the event is generated.
+    #[inline]
+    pub fn host(mut self, value: impl Into<String>) -> Self {
+        self.body.host = Some(value.into());
+        self
+    }
+
+    /// Sets [`host`](#method.host) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_host(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.host = value.map(|value| value.into());
+        self
+    }
+}
@@ -0,0 +1,64 @@
+
+/// ExecAction describes a "run in container" action.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct ExecAction {
+    /// Command is the command line to execute inside the container, the working directory for the command  is root ('/') in the container's filesystem. The command is simply exec'd, it is not run inside a shell, so traditional shell instructions ('|', etc) won't work. To use a shell, you need to explicitly call out to that shell. Exit status of 0 is treated as live/healthy and non-zero is unhealthy.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub command: Option<Vec<String>>,
+}
+
+impl ExecAction {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> ExecActionBuilder {
+        ExecActionBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> ExecActionBuilder
+    where
+        Self: Clone,
+    {
+        ExecActionBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<ExecAction> for ExecActionBuilder {
+    fn into(self) -> ExecAction {
+        self.body
+    }
+}
+
+/// Builder for [`ExecAction`](./struct.ExecAction.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct ExecActionBuilder {
+    body: self::ExecAction,
+}
+
+impl ExecActionBuilder {
+    /// Command is the command line to execute inside the container, the working directory for the command  is root ('/') in the container's filesystem. The command is simply exec'd, it is not run inside a shell, so traditional shell instructions ('|', etc) won't work. To use a shell, you need to explicitly call out to that shell. Exit status of 0 is treated as live/healthy and non-zero is unhealthy.
+    #[inline]
+    pub fn command(mut self, value: impl Iterator<Item = impl Into<String>>) -> Self {
+        self.body.command = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`command`](#method.command) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_command(mut self, value: Option<impl Iterator<Item = impl Into<String>>>) -> Self {
+        self.body.command = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`command`](#method.command), but takes the values as a `Vec`.
+    #[inline]
+    pub fn command_vec(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.body.command = Some(value.into());
+        self
+    }
+}
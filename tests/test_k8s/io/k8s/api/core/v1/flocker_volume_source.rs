@@ -0,0 +1,74 @@
+
+/// Represents a Flocker volume mounted by the Flocker agent. One and only one of datasetName and datasetUUID should be set. Flocker volumes do not support ownership management or SELinux relabeling.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct FlockerVolumeSource {
+    /// Name of the dataset stored as metadata -> name on the dataset for Flocker should be considered as deprecated
+    #[serde(rename = "datasetName", skip_serializing_if = "Option::is_none")]
+    pub dataset_name: Option<String>,
+    /// UUID of the dataset. This is unique identifier of a Flocker dataset
+    #[serde(rename = "datasetUUID", skip_serializing_if = "Option::is_none")]
+    pub dataset_uuid: Option<String>,
+}
+
+impl FlockerVolumeSource {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> FlockerVolumeSourceBuilder {
+        FlockerVolumeSourceBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> FlockerVolumeSourceBuilder
+    where
+        Self: Clone,
+    {
+        FlockerVolumeSourceBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<FlockerVolumeSource> for FlockerVolumeSourceBuilder {
+    fn into(self) -> FlockerVolumeSource {
+        self.body
+    }
+}
+
+/// Builder for [`FlockerVolumeSource`](./struct.FlockerVolumeSource.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct FlockerVolumeSourceBuilder {
+    body: self::FlockerVolumeSource,
+}
+
+impl FlockerVolumeSourceBuilder {
+    /// Name of the dataset stored as metadata -> name on the dataset for Flocker should be considered as deprecated
+    #[inline]
+    pub fn dataset_name(mut self, value: impl Into<String>) -> Self {
+        self.body.dataset_name = Some(value.into());
+        self
+    }
+
+    /// Sets [`dataset_name`](#method.dataset_name) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_dataset_name(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.dataset_name = value.map(|value| value.into());
+        self
+    }
+
+    /// UUID of the dataset. This is unique identifier of a Flocker dataset
+    #[inline]
+    pub fn dataset_uuid(mut self, value: impl Into<String>) -> Self {
+        self.body.dataset_uuid = Some(value.into());
+        self
+    }
+
+    /// Sets [`dataset_uuid`](#method.dataset_uuid) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_dataset_uuid(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.dataset_uuid = value.map(|value| value.into());
+        self
+    }
+}
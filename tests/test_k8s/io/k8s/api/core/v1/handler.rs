@@ -0,0 +1,91 @@
+
+/// Handler defines a specific action that should be taken
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct Handler {
+    /// One and only one of the following should be specified. Exec specifies the action to take.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exec: Option<crate::codegen::io::k8s::api::core::v1::exec_action::ExecAction>,
+    /// HTTPGet specifies the http request to perform.
+    #[serde(rename = "httpGet", skip_serializing_if = "Option::is_none")]
+    pub http_get: Option<crate::codegen::io::k8s::api::core::v1::http_get_action::HttpGetAction>,
+    /// TCPSocket specifies an action involving a TCP port. TCP hooks not yet supported
+    #[serde(rename = "tcpSocket", skip_serializing_if = "Option::is_none")]
+    pub tcp_socket: Option<crate::codegen::io::k8s::api::core::v1::tcp_socket_action::TcpSocketAction>,
+}
+
+impl Handler {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> HandlerBuilder {
+        HandlerBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> HandlerBuilder
+    where
+        Self: Clone,
+    {
+        HandlerBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<Handler> for HandlerBuilder {
+    fn into(self) -> Handler {
+        self.body
+    }
+}
+
+/// Builder for [`Handler`](./struct.Handler.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct HandlerBuilder {
+    body: self::Handler,
+}
+
+impl HandlerBuilder {
+    /// One and only one of the following should be specified. Exec specifies the action to take.
+    #[inline]
+    pub fn exec(mut self, value: crate::codegen::io::k8s::api::core::v1::exec_action::ExecAction) -> Self {
+        self.body.exec = Some(value.into());
+        self
+    }
+
+    /// Sets [`exec`](#method.exec) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_exec(mut self, value: Option<crate::codegen::io::k8s::api::core::v1::exec_action::ExecAction>) -> Self {
+        self.body.exec = value.map(|value| value.into());
+        self
+    }
+
+    /// HTTPGet specifies the http request to perform.
+    #[inline]
+    pub fn http_get(mut self, value: crate::codegen::io::k8s::api::core::v1::http_get_action::HttpGetActionBuilder<crate::codegen::generics::PortExists>) -> Self {
+        self.body.http_get = Some(value.into());
+        self
+    }
+
+    /// Sets [`http_get`](#method.http_get) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_http_get(mut self, value: Option<crate::codegen::io::k8s::api::core::v1::http_get_action::HttpGetActionBuilder<crate::codegen::generics::PortExists>>) -> Self {
+        self.body.http_get = value.map(|value| value.into());
+        self
+    }
+
+    /// TCPSocket specifies an action involving a TCP port. TCP hooks not yet supported
+    #[inline]
+    pub fn tcp_socket(mut self, value: crate::codegen::io::k8s::api::core::v1::tcp_socket_action::TcpSocketActionBuilder<crate::codegen::generics::PortExists>) -> Self {
+        self.body.tcp_socket = Some(value.into());
+        self
+    }
+
+    /// Sets [`tcp_socket`](#method.tcp_socket) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_tcp_socket(mut self, value: Option<crate::codegen::io::k8s::api::core::v1::tcp_socket_action::TcpSocketActionBuilder<crate::codegen::generics::PortExists>>) -> Self {
+        self.body.tcp_socket = value.map(|value| value.into());
+        self
+    }
+}
@@ -0,0 +1,81 @@
+
+/// HostAlias holds the mapping between IP and hostnames that will be injected as an entry in the pod's hosts file.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct HostAlias {
+    /// Hostnames for the above IP address.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hostnames: Option<Vec<String>>,
+    /// IP address of the host file entry.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ip: Option<String>,
+}
+
+impl HostAlias {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> HostAliasBuilder {
+        HostAliasBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> HostAliasBuilder
+    where
+        Self: Clone,
+    {
+        HostAliasBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<HostAlias> for HostAliasBuilder {
+    fn into(self) -> HostAlias {
+        self.body
+    }
+}
+
+/// Builder for [`HostAlias`](./struct.HostAlias.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct HostAliasBuilder {
+    body: self::HostAlias,
+}
+
+impl HostAliasBuilder {
+    /// Hostnames for the above IP address.
+    #[inline]
+    pub fn hostnames(mut self, value: impl Iterator<Item = impl Into<String>>) -> Self {
+        self.body.hostnames = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`hostnames`](#method.hostnames) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_hostnames(mut self, value: Option<impl Iterator<Item = impl Into<String>>>) -> Self {
+        self.body.hostnames = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`hostnames`](#method.hostnames), but takes the values as a `Vec`.
+    #[inline]
+    pub fn hostnames_vec(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.body.hostnames = Some(value.into());
+        self
+    }
+
+    /// IP address of the host file entry.
+    #[inline]
+    pub fn ip(mut self, value: impl Into<String>) -> Self {
+        self.body.ip = Some(value.into());
+        self
+    }
+
+    /// Sets [`ip`](#method.ip) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_ip(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.ip = value.map(|value| value.into());
+        self
+    }
+}
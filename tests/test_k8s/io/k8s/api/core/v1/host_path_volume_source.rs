@@ -0,0 +1,69 @@
+
+/// Represents a host path mapped into a pod. Host path volumes do not support ownership management or SELinux relabeling.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct HostPathVolumeSource {
+    /// Path of the directory on the host. If the path is a symlink, it will follow the link to the real path. More info: https://kubernetes.io/docs/concepts/storage/volumes#hostpath
+    pub path: String,
+    /// Type for HostPath Volume Defaults to "" More info: https://kubernetes.io/docs/concepts/storage/volumes#hostpath
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub type_: Option<String>,
+}
+
+impl HostPathVolumeSource {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> HostPathVolumeSourceBuilder<crate::codegen::generics::MissingPath> {
+        HostPathVolumeSourceBuilder {
+            body: Default::default(),
+            _path: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> HostPathVolumeSourceBuilder<crate::codegen::generics::PathExists>
+    where
+        Self: Clone,
+    {
+        HostPathVolumeSourceBuilder {
+            body: self.clone(),
+            _path: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<HostPathVolumeSource> for HostPathVolumeSourceBuilder<crate::codegen::generics::PathExists> {
+    fn into(self) -> HostPathVolumeSource {
+        self.body
+    }
+}
+
+/// Builder for [`HostPathVolumeSource`](./struct.HostPathVolumeSource.html) object.
+#[derive(Debug, Clone)]
+pub struct HostPathVolumeSourceBuilder<Path> {
+    body: self::HostPathVolumeSource,
+    _path: core::marker::PhantomData<Path>,
+}
+
+impl<Path> HostPathVolumeSourceBuilder<Path> {
+    /// Path of the directory on the host. If the path is a symlink, it will follow the link to the real path. More info: https://kubernetes.io/docs/concepts/storage/volumes#hostpath
+    #[inline]
+    pub fn path(mut self, value: impl Into<String>) -> HostPathVolumeSourceBuilder<crate::codegen::generics::PathExists> {
+        self.body.path = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Type for HostPath Volume Defaults to "" More info: https://kubernetes.io/docs/concepts/storage/volumes#hostpath
+    #[inline]
+    pub fn type_(mut self, value: impl Into<String>) -> Self {
+        self.body.type_ = Some(value.into());
+        self
+    }
+
+    /// Sets [`type_`](#method.type_) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_type(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.type_ = value.map(|value| value.into());
+        self
+    }
+}
@@ -0,0 +1,127 @@
+
+/// HTTPGetAction describes an action based on HTTP Get requests.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct HttpGetAction {
+    /// Host name to connect to, defaults to the pod IP. You probably want to set "Host" in httpHeaders instead.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub host: Option<String>,
+    /// Custom headers to set in the request. HTTP allows repeated headers.
+    #[serde(rename = "httpHeaders", skip_serializing_if = "Option::is_none")]
+    pub http_headers: Option<Vec<crate::codegen::io::k8s::api::core::v1::http_header::HttpHeader>>,
+    /// Path to access on the HTTP server.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+    /// Name or number of the port to access on the container. Number must be in the range 1 to 65535. Name must be an IANA_SVC_NAME.
+    pub port: String,
+    /// Scheme to use for connecting to the host. Defaults to HTTP.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scheme: Option<String>,
+}
+
+impl HttpGetAction {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> HttpGetActionBuilder<crate::codegen::generics::MissingPort> {
+        HttpGetActionBuilder {
+            body: Default::default(),
+            _port: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> HttpGetActionBuilder<crate::codegen::generics::PortExists>
+    where
+        Self: Clone,
+    {
+        HttpGetActionBuilder {
+            body: self.clone(),
+            _port: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<HttpGetAction> for HttpGetActionBuilder<crate::codegen::generics::PortExists> {
+    fn into(self) -> HttpGetAction {
+        self.body
+    }
+}
+
+/// Builder for [`HttpGetAction`](./struct.HttpGetAction.html) object.
+#[derive(Debug, Clone)]
+pub struct HttpGetActionBuilder<Port> {
+    body: self::HttpGetAction,
+    _port: core::marker::PhantomData<Port>,
+}
+
+impl<Port> HttpGetActionBuilder<Port> {
+    /// Host name to connect to, defaults to the pod IP. You probably want to set "Host" in httpHeaders instead.
+    #[inline]
+    pub fn host(mut self, value: impl Into<String>) -> Self {
+        self.body.host = Some(value.into());
+        self
+    }
+
+    /// Sets [`host`](#method.host) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_host(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.host = value.map(|value| value.into());
+        self
+    }
+
+    /// Custom headers to set in the request. HTTP allows repeated headers.
+    #[inline]
+    pub fn http_headers(mut self, value: impl Iterator<Item = crate::codegen::io::k8s::api::core::v1::http_header::HttpHeaderBuilder<crate::codegen::generics::NameExists, crate::codegen::generics::ValueExists>>) -> Self {
+        self.body.http_headers = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`http_headers`](#method.http_headers) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_http_headers(mut self, value: Option<impl Iterator<Item = crate::codegen::io::k8s::api::core::v1::http_header::HttpHeaderBuilder<crate::codegen::generics::NameExists, crate::codegen::generics::ValueExists>>>) -> Self {
+        self.body.http_headers = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`http_headers`](#method.http_headers), but takes the values as a `Vec`.
+    #[inline]
+    pub fn http_headers_vec(mut self, value: impl Into<Vec<crate::codegen::io::k8s::api::core::v1::http_header::HttpHeader>>) -> Self {
+        self.body.http_headers = Some(value.into());
+        self
+    }
+
+    /// Path to access on the HTTP server.
+    #[inline]
+    pub fn path(mut self, value: impl Into<String>) -> Self {
+        self.body.path = Some(value.into());
+        self
+    }
+
+    /// Sets [`path`](#method.path) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_path(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.path = value.map(|value| value.into());
+        self
+    }
+
+    /// Name or number of the port to access on the container. Number must be in the range 1 to 65535. Name must be an IANA_SVC_NAME.
+    #[inline]
+    pub fn port(mut self, value: impl Into<String>) -> HttpGetActionBuilder<crate::codegen::generics::PortExists> {
+        self.body.port = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Scheme to use for connecting to the host. Defaults to HTTP.
+    #[inline]
+    pub fn scheme(mut self, value: impl Into<String>) -> Self {
+        self.body.scheme = Some(value.into());
+        self
+    }
+
+    /// Sets [`scheme`](#method.scheme) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_scheme(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.scheme = value.map(|value| value.into());
+        self
+    }
+}
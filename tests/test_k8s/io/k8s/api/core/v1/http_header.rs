@@ -0,0 +1,64 @@
+
+/// HTTPHeader describes a custom header to be used in HTTP probes
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct HttpHeader {
+    /// The header field name
+    pub name: String,
+    /// The header field value
+    pub value: String,
+}
+
+impl HttpHeader {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> HttpHeaderBuilder<crate::codegen::generics::MissingName, crate::codegen::generics::MissingValue> {
+        HttpHeaderBuilder {
+            body: Default::default(),
+            _name: core::marker::PhantomData,
+            _value: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> HttpHeaderBuilder<crate::codegen::generics::NameExists, crate::codegen::generics::ValueExists>
+    where
+        Self: Clone,
+    {
+        HttpHeaderBuilder {
+            body: self.clone(),
+            _name: core::marker::PhantomData,
+            _value: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<HttpHeader> for HttpHeaderBuilder<crate::codegen::generics::NameExists, crate::codegen::generics::ValueExists> {
+    fn into(self) -> HttpHeader {
+        self.body
+    }
+}
+
+/// Builder for [`HttpHeader`](./struct.HttpHeader.html) object.
+#[derive(Debug, Clone)]
+pub struct HttpHeaderBuilder<Name, Value> {
+    body: self::HttpHeader,
+    _name: core::marker::PhantomData<Name>,
+    _value: core::marker::PhantomData<Value>,
+}
+
+impl<Name, Value> HttpHeaderBuilder<Name, Value> {
+    /// The header field name
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> HttpHeaderBuilder<crate::codegen::generics::NameExists, Value> {
+        self.body.name = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// The header field value
+    #[inline]
+    pub fn value(mut self, value: impl Into<String>) -> HttpHeaderBuilder<Name, crate::codegen::generics::ValueExists> {
+        self.body.value = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+}
@@ -0,0 +1,87 @@
+
+/// Maps a string key to a path within a volume.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct KeyToPath {
+    /// The key to project.
+    pub key: String,
+    /// Optional: mode bits to use on this file, must be a value between 0 and 0777. If not specified, the volume defaultMode will be used. This might be in conflict with other options that affect the file mode, like fsGroup, and the result can be other mode bits set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mode: Option<i32>,
+    /// The relative path of the file to map the key to. May not be an absolute path. May not contain the path element '..'. May not start with the string '..'.
+    pub path: String,
+}
+
+impl KeyToPath {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> KeyToPathBuilder<crate::codegen::generics::MissingKey, crate::codegen::generics::MissingPath> {
+        KeyToPathBuilder {
+            body: Default::default(),
+            _key: core::marker::PhantomData,
+            _path: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> KeyToPathBuilder<crate::codegen::generics::KeyExists, crate::codegen::generics::PathExists>
+    where
+        Self: Clone,
+    {
+        KeyToPathBuilder {
+            body: self.clone(),
+            _key: core::marker::PhantomData,
+            _path: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<KeyToPath> for KeyToPathBuilder<crate::codegen::generics::KeyExists, crate::codegen::generics::PathExists> {
+    fn into(self) -> KeyToPath {
+        self.body
+    }
+}
+
+/// Builder for [`KeyToPath`](./struct.KeyToPath.html) object.
+#[derive(Debug, Clone)]
+pub struct KeyToPathBuilder<Key, Path> {
+    body: self::KeyToPath,
+    _key: core::marker::PhantomData<Key>,
+    _path: core::marker::PhantomData<Path>,
+}
+
+impl<Key, Path> KeyToPathBuilder<Key, Path> {
+    /// The key to project.
+    #[inline]
+    pub fn key(mut self, value: impl Into<String>) -> KeyToPathBuilder<crate::codegen::generics::KeyExists, Path> {
+        self.body.key = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Optional: mode bits to use on this file, must be a value between 0 and 0777. If not specified, the volume defaultMode will be used. This might be in conflict with other options that affect the file mode, like fsGroup, and the result can be other mode bits set.
+    #[inline]
+    pub fn mode(mut self, value: impl Into<i32>) -> Self {
+        self.body.mode = Some(value.into());
+        self
+    }
+
+    /// Sets [`mode`](#method.mode) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_mode(mut self, value: Option<impl Into<i32>>) -> Self {
+        self.body.mode = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`mode`](#method.mode), but takes a reference.
+    #[inline]
+    pub fn mode_ref(self, value: &i32) -> Self {
+        self.mode(*value)
+    }
+
+    /// The relative path of the file to map the key to. May not be an absolute path. May not contain the path element '..'. May not start with the string '..'.
+    #[inline]
+    pub fn path(mut self, value: impl Into<String>) -> KeyToPathBuilder<Key, crate::codegen::generics::PathExists> {
+        self.body.path = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+}
@@ -0,0 +1,59 @@
+
+/// LimitRangeSpec defines a min/max usage limit for resources that match on kind.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct LimitRangeSpec {
+    /// Limits is the list of LimitRangeItem objects that are enforced.
+    pub limits: Vec<crate::codegen::io::k8s::api::core::v1::limit_range_item::LimitRangeItem>,
+}
+
+impl LimitRangeSpec {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> LimitRangeSpecBuilder<crate::codegen::generics::MissingLimits> {
+        LimitRangeSpecBuilder {
+            body: Default::default(),
+            _limits: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> LimitRangeSpecBuilder<crate::codegen::generics::LimitsExists>
+    where
+        Self: Clone,
+    {
+        LimitRangeSpecBuilder {
+            body: self.clone(),
+            _limits: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<LimitRangeSpec> for LimitRangeSpecBuilder<crate::codegen::generics::LimitsExists> {
+    fn into(self) -> LimitRangeSpec {
+        self.body
+    }
+}
+
+/// Builder for [`LimitRangeSpec`](./struct.LimitRangeSpec.html) object.
+#[derive(Debug, Clone)]
+pub struct LimitRangeSpecBuilder<Limits> {
+    body: self::LimitRangeSpec,
+    _limits: core::marker::PhantomData<Limits>,
+}
+
+impl<Limits> LimitRangeSpecBuilder<Limits> {
+    /// Limits is the list of LimitRangeItem objects that are enforced.
+    #[inline]
+    pub fn limits(mut self, value: impl Iterator<Item = crate::codegen::io::k8s::api::core::v1::limit_range_item::LimitRangeItem>) -> LimitRangeSpecBuilder<crate::codegen::generics::LimitsExists> {
+        self.body.limits = value.map(|value| value.into()).collect::<Vec<_>>().into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Same as [`limits`](#method.limits), but takes the values as a `Vec`.
+    #[inline]
+    pub fn limits_vec(mut self, value: impl Into<Vec<crate::codegen::io::k8s::api::core::v1::limit_range_item::LimitRangeItem>>) -> LimitRangeSpecBuilder<crate::codegen::generics::LimitsExists> {
+        self.body.limits = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+}
@@ -0,0 +1,74 @@
+
+/// LoadBalancerIngress represents the status of a load-balancer ingress point: traffic intended for the service should be sent to an ingress point.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct LoadBalancerIngress {
+    /// Hostname is set for load-balancer ingress points that are DNS based (typically AWS load-balancers)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hostname: Option<String>,
+    /// IP is set for load-balancer ingress points that are IP based (typically GCE or OpenStack load-balancers)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ip: Option<String>,
+}
+
+impl LoadBalancerIngress {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> LoadBalancerIngressBuilder {
+        LoadBalancerIngressBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> LoadBalancerIngressBuilder
+    where
+        Self: Clone,
+    {
+        LoadBalancerIngressBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<LoadBalancerIngress> for LoadBalancerIngressBuilder {
+    fn into(self) -> LoadBalancerIngress {
+        self.body
+    }
+}
+
+/// Builder for [`LoadBalancerIngress`](./struct.LoadBalancerIngress.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct LoadBalancerIngressBuilder {
+    body: self::LoadBalancerIngress,
+}
+
+impl LoadBalancerIngressBuilder {
+    /// Hostname is set for load-balancer ingress points that are DNS based (typically AWS load-balancers)
+    #[inline]
+    pub fn hostname(mut self, value: impl Into<String>) -> Self {
+        self.body.hostname = Some(value.into());
+        self
+    }
+
+    /// Sets [`hostname`](#method.hostname) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_hostname(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.hostname = value.map(|value| value.into());
+        self
+    }
+
+    /// IP is set for load-balancer ingress points that are IP based (typically GCE or OpenStack load-balancers)
+    #[inline]
+    pub fn ip(mut self, value: impl Into<String>) -> Self {
+        self.body.ip = Some(value.into());
+        self
+    }
+
+    /// Sets [`ip`](#method.ip) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_ip(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.ip = value.map(|value| value.into());
+        self
+    }
+}
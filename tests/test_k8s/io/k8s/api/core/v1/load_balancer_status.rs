@@ -0,0 +1,64 @@
+
+/// LoadBalancerStatus represents the status of a load-balancer.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct LoadBalancerStatus {
+    /// Ingress is a list containing ingress points for the load-balancer. Traffic intended for the service should be sent to these ingress points.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ingress: Option<Vec<crate::codegen::io::k8s::api::core::v1::load_balancer_ingress::LoadBalancerIngress>>,
+}
+
+impl LoadBalancerStatus {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> LoadBalancerStatusBuilder {
+        LoadBalancerStatusBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> LoadBalancerStatusBuilder
+    where
+        Self: Clone,
+    {
+        LoadBalancerStatusBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<LoadBalancerStatus> for LoadBalancerStatusBuilder {
+    fn into(self) -> LoadBalancerStatus {
+        self.body
+    }
+}
+
+/// Builder for [`LoadBalancerStatus`](./struct.LoadBalancerStatus.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct LoadBalancerStatusBuilder {
+    body: self::LoadBalancerStatus,
+}
+
+impl LoadBalancerStatusBuilder {
+    /// Ingress is a list containing ingress points for the load-balancer. Traffic intended for the service should be sent to these ingress points.
+    #[inline]
+    pub fn ingress(mut self, value: impl Iterator<Item = crate::codegen::io::k8s::api::core::v1::load_balancer_ingress::LoadBalancerIngress>) -> Self {
+        self.body.ingress = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`ingress`](#method.ingress) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_ingress(mut self, value: Option<impl Iterator<Item = crate::codegen::io::k8s::api::core::v1::load_balancer_ingress::LoadBalancerIngress>>) -> Self {
+        self.body.ingress = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`ingress`](#method.ingress), but takes the values as a `Vec`.
+    #[inline]
+    pub fn ingress_vec(mut self, value: impl Into<Vec<crate::codegen::io::k8s::api::core::v1::load_balancer_ingress::LoadBalancerIngress>>) -> Self {
+        self.body.ingress = Some(value.into());
+        self
+    }
+}
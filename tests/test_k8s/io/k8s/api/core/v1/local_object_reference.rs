@@ -0,0 +1,57 @@
+
+/// LocalObjectReference contains enough information to let you locate the referenced object inside the same namespace.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct LocalObjectReference {
+    /// Name of the referent. More info: https://kubernetes.io/docs/concepts/overview/working-with-objects/names/#names
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+impl LocalObjectReference {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> LocalObjectReferenceBuilder {
+        LocalObjectReferenceBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> LocalObjectReferenceBuilder
+    where
+        Self: Clone,
+    {
+        LocalObjectReferenceBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<LocalObjectReference> for LocalObjectReferenceBuilder {
+    fn into(self) -> LocalObjectReference {
+        self.body
+    }
+}
+
+/// Builder for [`LocalObjectReference`](./struct.LocalObjectReference.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct LocalObjectReferenceBuilder {
+    body: self::LocalObjectReference,
+}
+
+impl LocalObjectReferenceBuilder {
+    /// Name of the referent. More info: https://kubernetes.io/docs/concepts/overview/working-with-objects/names/#names
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> Self {
+        self.body.name = Some(value.into());
+        self
+    }
+
+    /// Sets [`name`](#method.name) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_name(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.name = value.map(|value| value.into());
+        self
+    }
+}
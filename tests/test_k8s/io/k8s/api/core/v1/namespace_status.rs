@@ -0,0 +1,57 @@
+
+/// NamespaceStatus is information about the current status of a Namespace.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct NamespaceStatus {
+    /// Phase is the current lifecycle phase of the namespace. More info: https://kubernetes.io/docs/tasks/administer-cluster/namespaces/
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phase: Option<String>,
+}
+
+impl NamespaceStatus {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> NamespaceStatusBuilder {
+        NamespaceStatusBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> NamespaceStatusBuilder
+    where
+        Self: Clone,
+    {
+        NamespaceStatusBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<NamespaceStatus> for NamespaceStatusBuilder {
+    fn into(self) -> NamespaceStatus {
+        self.body
+    }
+}
+
+/// Builder for [`NamespaceStatus`](./struct.NamespaceStatus.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct NamespaceStatusBuilder {
+    body: self::NamespaceStatus,
+}
+
+impl NamespaceStatusBuilder {
+    /// Phase is the current lifecycle phase of the namespace. More info: https://kubernetes.io/docs/tasks/administer-cluster/namespaces/
+    #[inline]
+    pub fn phase(mut self, value: impl Into<String>) -> Self {
+        self.body.phase = Some(value.into());
+        self
+    }
+
+    /// Sets [`phase`](#method.phase) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_phase(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.phase = value.map(|value| value.into());
+        self
+    }
+}
@@ -0,0 +1,108 @@
+
+/// NodeConfigStatus describes the status of the config assigned by Node.Spec.ConfigSource.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct NodeConfigStatus {
+    /// Active reports the checkpointed config the node is actively using. Active will represent either the current version of the Assigned config, or the current LastKnownGood config, depending on whether attempting to use the Assigned config results in an error.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub active: Option<crate::codegen::io::k8s::api::core::v1::node_config_source::NodeConfigSource>,
+    /// Assigned reports the checkpointed config the node will try to use. When Node.Spec.ConfigSource is updated, the node checkpoints the associated config payload to local disk, along with a record indicating intended config. The node refers to this record to choose its config checkpoint, and reports this record in Assigned. Assigned only updates in the status after the record has been checkpointed to disk. When the Kubelet is restarted, it tries to make the Assigned config the Active config by loading and validating the checkpointed payload identified by Assigned.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub assigned: Option<crate::codegen::io::k8s::api::core::v1::node_config_source::NodeConfigSource>,
+    /// Error describes any problems reconciling the Spec.ConfigSource to the Active config. Errors may occur, for example, attempting to checkpoint Spec.ConfigSource to the local Assigned record, attempting to checkpoint the payload associated with Spec.ConfigSource, attempting to load or validate the Assigned config, etc. Errors may occur at different points while syncing config. Earlier errors (e.g. download or checkpointing errors) will not result in a rollback to LastKnownGood, and may resolve across Kubelet retries. Later errors (e.g. loading or validating a checkpointed config) will result in a rollback to LastKnownGood. In the latter case, it is usually possible to resolve the error by fixing the config assigned in Spec.ConfigSource. You can find additional information for debugging by searching the error message in the Kubelet log. Error is a human-readable description of the error state; machines can check whether or not Error is empty, but should not rely on the stability of the Error text across Kubelet versions.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// LastKnownGood reports the checkpointed config the node will fall back to when it encounters an error attempting to use the Assigned config. The Assigned config becomes the LastKnownGood config when the node determines that the Assigned config is stable and correct. This is currently implemented as a 10-minute soak period starting when the local record of Assigned config is updated. If the Assigned config is Active at the end of this period, it becomes the LastKnownGood. Note that if Spec.ConfigSource is reset to nil (use local defaults), the LastKnownGood is also immediately reset to nil, because the local default config is always assumed good. You should not make assumptions about the node's method of determining config stability and correctness, as this may change or become configurable in the future.
+    #[serde(rename = "lastKnownGood", skip_serializing_if = "Option::is_none")]
+    pub last_known_good: Option<crate::codegen::io::k8s::api::core::v1::node_config_source::NodeConfigSource>,
+}
+
+impl NodeConfigStatus {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> NodeConfigStatusBuilder {
+        NodeConfigStatusBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> NodeConfigStatusBuilder
+    where
+        Self: Clone,
+    {
+        NodeConfigStatusBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<NodeConfigStatus> for NodeConfigStatusBuilder {
+    fn into(self) -> NodeConfigStatus {
+        self.body
+    }
+}
+
+/// Builder for [`NodeConfigStatus`](./struct.NodeConfigStatus.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct NodeConfigStatusBuilder {
+    body: self::NodeConfigStatus,
+}
+
+impl NodeConfigStatusBuilder {
+    /// Active reports the checkpointed config the node is actively using. Active will represent either the current version of the Assigned config, or the current LastKnownGood config, depending on whether attempting to use the Assigned config results in an error.
+    #[inline]
+    pub fn active(mut self, value: crate::codegen::io::k8s::api::core::v1::node_config_source::NodeConfigSource) -> Self {
+        self.body.active = Some(value.into());
+        self
+    }
+
+    /// Sets [`active`](#method.active) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_active(mut self, value: Option<crate::codegen::io::k8s::api::core::v1::node_config_source::NodeConfigSource>) -> Self {
+        self.body.active = value.map(|value| value.into());
+        self
+    }
+
+    /// Assigned reports the checkpointed config the node will try to use. When Node.Spec.ConfigSource is updated, the node checkpoints the associated config payload to local disk, along with a record indicating intended config. The node refers to this record to choose its config checkpoint, and reports this record in Assigned. Assigned only updates in the status after the record has been checkpointed to disk. When the Kubelet is restarted, it tries to make the Assigned config the Active config by loading and validating the checkpointed payload identified by Assigned.
+    #[inline]
+    pub fn assigned(mut self, value: crate::codegen::io::k8s::api::core::v1::node_config_source::NodeConfigSource) -> Self {
+        self.body.assigned = Some(value.into());
+        self
+    }
+
+    /// Sets [`assigned`](#method.assigned) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_assigned(mut self, value: Option<crate::codegen::io::k8s::api::core::v1::node_config_source::NodeConfigSource>) -> Self {
+        self.body.assigned = value.map(|value| value.into());
+        self
+    }
+
+    /// Error describes any problems reconciling the Spec.ConfigSource to the Active config. Errors may occur, for example, attempting to checkpoint Spec.ConfigSource to the local Assigned record, attempting to checkpoint the payload associated with Spec.ConfigSource, attempting to load or validate the Assigned config, etc. Errors may occur at different points while syncing config. Earlier errors (e.g. download or checkpointing errors) will not result in a rollback to LastKnownGood, and may resolve across Kubelet retries. Later errors (e.g. loading or validating a checkpointed config) will result in a rollback to LastKnownGood. In the latter case, it is usually possible to resolve the error by fixing the config assigned in Spec.ConfigSource. You can find additional information for debugging by searching the error message in the Kubelet log. Error is a human-readable description of the error state; machines can check whether or not Error is empty, but should not rely on the stability of the Error text across Kubelet versions.
+    #[inline]
+    pub fn error(mut self, value: impl Into<String>) -> Self {
+        self.body.error = Some(value.into());
+        self
+    }
+
+    /// Sets [`error`](#method.error) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_error(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.error = value.map(|value| value.into());
+        self
+    }
+
+    /// LastKnownGood reports the checkpointed config the node will fall back to when it encounters an error attempting to use the Assigned config. The Assigned config becomes the LastKnownGood config when the node determines that the Assigned config is stable and correct. This is currently implemented as a 10-minute soak period starting when the local record of Assigned config is updated. If the Assigned config is Active at the end of this period, it becomes the LastKnownGood. Note that if Spec.ConfigSource is reset to nil (use local defaults), the LastKnownGood is also immediately reset to nil, because the local default config is always assumed good. You should not make assumptions about the node's method of determining config stability and correctness, as this may change or become configurable in the future.
+    #[inline]
+    pub fn last_known_good(mut self, value: crate::codegen::io::k8s::api::core::v1::node_config_source::NodeConfigSource) -> Self {
+        self.body.last_known_good = Some(value.into());
+        self
+    }
+
+    /// Sets [`last_known_good`](#method.last_known_good) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_last_known_good(mut self, value: Option<crate::codegen::io::k8s::api::core::v1::node_config_source::NodeConfigSource>) -> Self {
+        self.body.last_known_good = value.map(|value| value.into());
+        self
+    }
+}
@@ -0,0 +1,57 @@
+
+/// NodeDaemonEndpoints lists ports opened by daemons running on the Node.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct NodeDaemonEndpoints {
+    /// Endpoint on which Kubelet is listening.
+    #[serde(rename = "kubeletEndpoint", skip_serializing_if = "Option::is_none")]
+    pub kubelet_endpoint: Option<crate::codegen::io::k8s::api::core::v1::daemon_endpoint::DaemonEndpoint>,
+}
+
+impl NodeDaemonEndpoints {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> NodeDaemonEndpointsBuilder {
+        NodeDaemonEndpointsBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> NodeDaemonEndpointsBuilder
+    where
+        Self: Clone,
+    {
+        NodeDaemonEndpointsBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<NodeDaemonEndpoints> for NodeDaemonEndpointsBuilder {
+    fn into(self) -> NodeDaemonEndpoints {
+        self.body
+    }
+}
+
+/// Builder for [`NodeDaemonEndpoints`](./struct.NodeDaemonEndpoints.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct NodeDaemonEndpointsBuilder {
+    body: self::NodeDaemonEndpoints,
+}
+
+impl NodeDaemonEndpointsBuilder {
+    /// Endpoint on which Kubelet is listening.
+    #[inline]
+    pub fn kubelet_endpoint(mut self, value: crate::codegen::io::k8s::api::core::v1::daemon_endpoint::DaemonEndpointBuilder<crate::codegen::generics::PortExists>) -> Self {
+        self.body.kubelet_endpoint = Some(value.into());
+        self
+    }
+
+    /// Sets [`kubelet_endpoint`](#method.kubelet_endpoint) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_kubelet_endpoint(mut self, value: Option<crate::codegen::io::k8s::api::core::v1::daemon_endpoint::DaemonEndpointBuilder<crate::codegen::generics::PortExists>>) -> Self {
+        self.body.kubelet_endpoint = value.map(|value| value.into());
+        self
+    }
+}
@@ -0,0 +1,88 @@
+
+/// A null or empty node selector term matches no objects. The requirements of them are ANDed. The TopologySelectorTerm type implements a subset of the NodeSelectorTerm.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct NodeSelectorTerm {
+    /// A list of node selector requirements by node's labels.
+    #[serde(rename = "matchExpressions", skip_serializing_if = "Option::is_none")]
+    pub match_expressions: Option<Vec<crate::codegen::io::k8s::api::core::v1::node_selector_requirement::NodeSelectorRequirement>>,
+    /// A list of node selector requirements by node's fields.
+    #[serde(rename = "matchFields", skip_serializing_if = "Option::is_none")]
+    pub match_fields: Option<Vec<crate::codegen::io::k8s::api::core::v1::node_selector_requirement::NodeSelectorRequirement>>,
+}
+
+impl NodeSelectorTerm {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> NodeSelectorTermBuilder {
+        NodeSelectorTermBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> NodeSelectorTermBuilder
+    where
+        Self: Clone,
+    {
+        NodeSelectorTermBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<NodeSelectorTerm> for NodeSelectorTermBuilder {
+    fn into(self) -> NodeSelectorTerm {
+        self.body
+    }
+}
+
+/// Builder for [`NodeSelectorTerm`](./struct.NodeSelectorTerm.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct NodeSelectorTermBuilder {
+    body: self::NodeSelectorTerm,
+}
+
+impl NodeSelectorTermBuilder {
+    /// A list of node selector requirements by node's labels.
+    #[inline]
+    pub fn match_expressions(mut self, value: impl Iterator<Item = crate::codegen::io::k8s::api::core::v1::node_selector_requirement::NodeSelectorRequirementBuilder<crate::codegen::generics::KeyExists, crate::codegen::generics::OperatorExists>>) -> Self {
+        self.body.match_expressions = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`match_expressions`](#method.match_expressions) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_match_expressions(mut self, value: Option<impl Iterator<Item = crate::codegen::io::k8s::api::core::v1::node_selector_requirement::NodeSelectorRequirementBuilder<crate::codegen::generics::KeyExists, crate::codegen::generics::OperatorExists>>>) -> Self {
+        self.body.match_expressions = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`match_expressions`](#method.match_expressions), but takes the values as a `Vec`.
+    #[inline]
+    pub fn match_expressions_vec(mut self, value: impl Into<Vec<crate::codegen::io::k8s::api::core::v1::node_selector_requirement::NodeSelectorRequirement>>) -> Self {
+        self.body.match_expressions = Some(value.into());
+        self
+    }
+
+    /// A list of node selector requirements by node's fields.
+    #[inline]
+    pub fn match_fields(mut self, value: impl Iterator<Item = crate::codegen::io::k8s::api::core::v1::node_selector_requirement::NodeSelectorRequirementBuilder<crate::codegen::generics::KeyExists, crate::codegen::generics::OperatorExists>>) -> Self {
+        self.body.match_fields = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`match_fields`](#method.match_fields) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_match_fields(mut self, value: Option<impl Iterator<Item = crate::codegen::io::k8s::api::core::v1::node_selector_requirement::NodeSelectorRequirementBuilder<crate::codegen::generics::KeyExists, crate::codegen::generics::OperatorExists>>>) -> Self {
+        self.body.match_fields = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`match_fields`](#method.match_fields), but takes the values as a `Vec`.
+    #[inline]
+    pub fn match_fields_vec(mut self, value: impl Into<Vec<crate::codegen::io::k8s::api::core::v1::node_selector_requirement::NodeSelectorRequirement>>) -> Self {
+        self.body.match_fields = Some(value.into());
+        self
+    }
+}
@@ -0,0 +1,169 @@
+
+/// NodeSystemInfo is a set of ids/uuids to uniquely identify the node.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct NodeSystemInfo {
+    /// The Architecture reported by the node
+    pub architecture: String,
+    /// Boot ID reported by the node.
+    #[serde(rename = "bootID")]
+    pub boot_id: String,
+    /// ContainerRuntime Version reported by the node through runtime remote API (e.g. docker://1.5.0).
+    #[serde(rename = "containerRuntimeVersion")]
+    pub container_runtime_version: String,
+    /// Kernel Version reported by the node from 'uname -r' (e.g. 3.16.0-0.bpo.4-amd64).
+    #[serde(rename = "kernelVersion")]
+    pub kernel_version: String,
+    /// KubeProxy Version reported by the node.
+    #[serde(rename = "kubeProxyVersion")]
+    pub kube_proxy_version: String,
+    /// Kubelet Version reported by the node.
+    #[serde(rename = "kubeletVersion")]
+    pub kubelet_version: String,
+    /// MachineID reported by the node. For unique machine identification in the cluster this field is preferred. Learn more from man(5) machine-id: http://man7.org/linux/man-pages/man5/machine-id.5.html
+    #[serde(rename = "machineID")]
+    pub machine_id: String,
+    /// The Operating System reported by the node
+    #[serde(rename = "operatingSystem")]
+    pub operating_system: String,
+    /// OS Image reported by the node from /etc/os-release (e.g. Debian GNU/Linux 7 (wheezy)).
+    #[serde(rename = "osImage")]
+    pub os_image: String,
+    /// SystemUUID reported by the node. For unique machine identification MachineID is preferred. This field is specific to Red Hat hosts https://access.redhat.com/documentation/en-US/Red_Hat_Subscription_Management/1/html/RHSM/getting-system-uuid.html
+    #[serde(rename = "systemUUID")]
+    pub system_uuid: String,
+}
+
+impl NodeSystemInfo {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> NodeSystemInfoBuilder<crate::codegen::generics::MissingArchitecture, crate::codegen::generics::MissingBootId, crate::codegen::generics::MissingContainerRuntimeVersion, crate::codegen::generics::MissingKernelVersion, crate::codegen::generics::MissingKubeProxyVersion, crate::codegen::generics::MissingKubeletVersion, crate::codegen::generics::MissingMachineId, crate::codegen::generics::MissingOperatingSystem, crate::codegen::generics::MissingOsImage, crate::codegen::generics::MissingSystemUuid> {
+        NodeSystemInfoBuilder {
+            body: Default::default(),
+            _architecture: core::marker::PhantomData,
+            _boot_id: core::marker::PhantomData,
+            _container_runtime_version: core::marker::PhantomData,
+            _kernel_version: core::marker::PhantomData,
+            _kube_proxy_version: core::marker::PhantomData,
+            _kubelet_version: core::marker::PhantomData,
+            _machine_id: core::marker::PhantomData,
+            _operating_system: core::marker::PhantomData,
+            _os_image: core::marker::PhantomData,
+            _system_uuid: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> NodeSystemInfoBuilder<crate::codegen::generics::ArchitectureExists, crate::codegen::generics::BootIdExists, crate::codegen::generics::ContainerRuntimeVersionExists, crate::codegen::generics::KernelVersionExists, crate::codegen::generics::KubeProxyVersionExists, crate::codegen::generics::KubeletVersionExists, crate::codegen::generics::MachineIdExists, crate::codegen::generics::OperatingSystemExists, crate::codegen::generics::OsImageExists, crate::codegen::generics::SystemUuidExists>
+    where
+        Self: Clone,
+    {
+        NodeSystemInfoBuilder {
+            body: self.clone(),
+            _architecture: core::marker::PhantomData,
+            _boot_id: core::marker::PhantomData,
+            _container_runtime_version: core::marker::PhantomData,
+            _kernel_version: core::marker::PhantomData,
+            _kube_proxy_version: core::marker::PhantomData,
+            _kubelet_version: core::marker::PhantomData,
+            _machine_id: core::marker::PhantomData,
+            _operating_system: core::marker::PhantomData,
+            _os_image: core::marker::PhantomData,
+            _system_uuid: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<NodeSystemInfo> for NodeSystemInfoBuilder<crate::codegen::generics::ArchitectureExists, crate::codegen::generics::BootIdExists, crate::codegen::generics::ContainerRuntimeVersionExists, crate::codegen::generics::KernelVersionExists, crate::codegen::generics::KubeProxyVersionExists, crate::codegen::generics::KubeletVersionExists, crate::codegen::generics::MachineIdExists, crate::codegen::generics::OperatingSystemExists, crate::codegen::generics::OsImageExists, crate::codegen::generics::SystemUuidExists> {
+    fn into(self) -> NodeSystemInfo {
+        self.body
+    }
+}
+
+/// Builder for [`NodeSystemInfo`](./struct.NodeSystemInfo.html) object.
+#[derive(Debug, Clone)]
+pub struct NodeSystemInfoBuilder<Architecture, BootId, ContainerRuntimeVersion, KernelVersion, KubeProxyVersion, KubeletVersion, MachineId, OperatingSystem, OsImage, SystemUuid> {
+    body: self::NodeSystemInfo,
+    _architecture: core::marker::PhantomData<Architecture>,
+    _boot_id: core::marker::PhantomData<BootId>,
+    _container_runtime_version: core::marker::PhantomData<ContainerRuntimeVersion>,
+    _kernel_version: core::marker::PhantomData<KernelVersion>,
+    _kube_proxy_version: core::marker::PhantomData<KubeProxyVersion>,
+    _kubelet_version: core::marker::PhantomData<KubeletVersion>,
+    _machine_id: core::marker::PhantomData<MachineId>,
+    _operating_system: core::marker::PhantomData<OperatingSystem>,
+    _os_image: core::marker::PhantomData<OsImage>,
+    _system_uuid: core::marker::PhantomData<SystemUuid>,
+}
+
+impl<Architecture, BootId, ContainerRuntimeVersion, KernelVersion, KubeProxyVersion, KubeletVersion, MachineId, OperatingSystem, OsImage, SystemUuid> NodeSystemInfoBuilder<Architecture, BootId, ContainerRuntimeVersion, KernelVersion, KubeProxyVersion, KubeletVersion, MachineId, OperatingSystem, OsImage, SystemUuid> {
+    /// The Architecture reported by the node
+    #[inline]
+    pub fn architecture(mut self, value: impl Into<String>) -> NodeSystemInfoBuilder<crate::codegen::generics::ArchitectureExists, BootId, ContainerRuntimeVersion, KernelVersion, KubeProxyVersion, KubeletVersion, MachineId, OperatingSystem, OsImage, SystemUuid> {
+        self.body.architecture = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Boot ID reported by the node.
+    #[inline]
+    pub fn boot_id(mut self, value: impl Into<String>) -> NodeSystemInfoBuilder<Architecture, crate::codegen::generics::BootIdExists, ContainerRuntimeVersion, KernelVersion, KubeProxyVersion, KubeletVersion, MachineId, OperatingSystem, OsImage, SystemUuid> {
+        self.body.boot_id = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// ContainerRuntime Version reported by the node through runtime remote API (e.g. docker://1.5.0).
+    #[inline]
+    pub fn container_runtime_version(mut self, value: impl Into<String>) -> NodeSystemInfoBuilder<Architecture, BootId, crate::codegen::generics::ContainerRuntimeVersionExists, KernelVersion, KubeProxyVersion, KubeletVersion, MachineId, OperatingSystem, OsImage, SystemUuid> {
+        self.body.container_runtime_version = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Kernel Version reported by the node from 'uname -r' (e.g. 3.16.0-0.bpo.4-amd64).
+    #[inline]
+    pub fn kernel_version(mut self, value: impl Into<String>) -> NodeSystemInfoBuilder<Architecture, BootId, ContainerRuntimeVersion, crate::codegen::generics::KernelVersionExists, KubeProxyVersion, KubeletVersion, MachineId, OperatingSystem, OsImage, SystemUuid> {
+        self.body.kernel_version = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// KubeProxy Version reported by the node.
+    #[inline]
+    pub fn kube_proxy_version(mut self, value: impl Into<String>) -> NodeSystemInfoBuilder<Architecture, BootId, ContainerRuntimeVersion, KernelVersion, crate::codegen::generics::KubeProxyVersionExists, KubeletVersion, MachineId, OperatingSystem, OsImage, SystemUuid> {
+        self.body.kube_proxy_version = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Kubelet Version reported by the node.
+    #[inline]
+    pub fn kubelet_version(mut self, value: impl Into<String>) -> NodeSystemInfoBuilder<Architecture, BootId, ContainerRuntimeVersion, KernelVersion, KubeProxyVersion, crate::codegen::generics::KubeletVersionExists, MachineId, OperatingSystem, OsImage, SystemUuid> {
+        self.body.kubelet_version = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// MachineID reported by the node. For unique machine identification in the cluster this field is preferred. Learn more from man(5) machine-id: http://man7.org/linux/man-pages/man5/machine-id.5.html
+    #[inline]
+    pub fn machine_id(mut self, value: impl Into<String>) -> NodeSystemInfoBuilder<Architecture, BootId, ContainerRuntimeVersion, KernelVersion, KubeProxyVersion, KubeletVersion, crate::codegen::generics::MachineIdExists, OperatingSystem, OsImage, SystemUuid> {
+        self.body.machine_id = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// The Operating System reported by the node
+    #[inline]
+    pub fn operating_system(mut self, value: impl Into<String>) -> NodeSystemInfoBuilder<Architecture, BootId, ContainerRuntimeVersion, KernelVersion, KubeProxyVersion, KubeletVersion, MachineId, crate::codegen::generics::OperatingSystemExists, OsImage, SystemUuid> {
+        self.body.operating_system = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// OS Image reported by the node from /etc/os-release (e.g. Debian GNU/Linux 7 (wheezy)).
+    #[inline]
+    pub fn os_image(mut self, value: impl Into<String>) -> NodeSystemInfoBuilder<Architecture, BootId, ContainerRuntimeVersion, KernelVersion, KubeProxyVersion, KubeletVersion, MachineId, OperatingSystem, crate::codegen::generics::OsImageExists, SystemUuid> {
+        self.body.os_image = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// SystemUUID reported by the node. For unique machine identification MachineID is preferred. This field is specific to Red Hat hosts https://access.redhat.com/documentation/en-US/Red_Hat_Subscription_Management/1/html/RHSM/getting-system-uuid.html
+    #[inline]
+    pub fn system_uuid(mut self, value: impl Into<String>) -> NodeSystemInfoBuilder<Architecture, BootId, ContainerRuntimeVersion, KernelVersion, KubeProxyVersion, KubeletVersion, MachineId, OperatingSystem, OsImage, crate::codegen::generics::SystemUuidExists> {
+        self.body.system_uuid = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+}
@@ -0,0 +1,76 @@
+
+/// PersistentVolumeClaimVolumeSource references the user's PVC in the same namespace. This volume finds the bound PV and mounts that volume for the pod. A PersistentVolumeClaimVolumeSource is, essentially, a wrapper around another type of volume that is owned by someone else (the system).
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct PersistentVolumeClaimVolumeSource {
+    /// ClaimName is the name of a PersistentVolumeClaim in the same namespace as the pod using this volume. More info: https://kubernetes.io/docs/concepts/storage/persistent-volumes#persistentvolumeclaims
+    #[serde(rename = "claimName")]
+    pub claim_name: String,
+    /// Will force the ReadOnly setting in VolumeMounts. Default false.
+    #[serde(rename = "readOnly", skip_serializing_if = "Option::is_none")]
+    pub read_only: Option<bool>,
+}
+
+impl PersistentVolumeClaimVolumeSource {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> PersistentVolumeClaimVolumeSourceBuilder<crate::codegen::generics::MissingClaimName> {
+        PersistentVolumeClaimVolumeSourceBuilder {
+            body: Default::default(),
+            _claim_name: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> PersistentVolumeClaimVolumeSourceBuilder<crate::codegen::generics::ClaimNameExists>
+    where
+        Self: Clone,
+    {
+        PersistentVolumeClaimVolumeSourceBuilder {
+            body: self.clone(),
+            _claim_name: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<PersistentVolumeClaimVolumeSource> for PersistentVolumeClaimVolumeSourceBuilder<crate::codegen::generics::ClaimNameExists> {
+    fn into(self) -> PersistentVolumeClaimVolumeSource {
+        self.body
+    }
+}
+
+/// Builder for [`PersistentVolumeClaimVolumeSource`](./struct.PersistentVolumeClaimVolumeSource.html) object.
+#[derive(Debug, Clone)]
+pub struct PersistentVolumeClaimVolumeSourceBuilder<ClaimName> {
+    body: self::PersistentVolumeClaimVolumeSource,
+    _claim_name: core::marker::PhantomData<ClaimName>,
+}
+
+impl<ClaimName> PersistentVolumeClaimVolumeSourceBuilder<ClaimName> {
+    /// ClaimName is the name of a PersistentVolumeClaim in the same namespace as the pod using this volume. More info: https://kubernetes.io/docs/concepts/storage/persistent-volumes#persistentvolumeclaims
+    #[inline]
+    pub fn claim_name(mut self, value: impl Into<String>) -> PersistentVolumeClaimVolumeSourceBuilder<crate::codegen::generics::ClaimNameExists> {
+        self.body.claim_name = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Will force the ReadOnly setting in VolumeMounts. Default false.
+    #[inline]
+    pub fn read_only(mut self, value: impl Into<bool>) -> Self {
+        self.body.read_only = Some(value.into());
+        self
+    }
+
+    /// Sets [`read_only`](#method.read_only) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_read_only(mut self, value: Option<impl Into<bool>>) -> Self {
+        self.body.read_only = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`read_only`](#method.read_only), but takes a reference.
+    #[inline]
+    pub fn read_only_ref(self, value: &bool) -> Self {
+        self.read_only(*value)
+    }
+}
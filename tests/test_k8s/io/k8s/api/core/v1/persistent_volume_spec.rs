@@ -0,0 +1,564 @@
+
+/// PersistentVolumeSpec is the specification of a persistent volume.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct PersistentVolumeSpec {
+    /// AccessModes contains all ways the volume can be mounted. More info: https://kubernetes.io/docs/concepts/storage/persistent-volumes#access-modes
+    #[serde(rename = "accessModes", skip_serializing_if = "Option::is_none")]
+    pub access_modes: Option<Vec<String>>,
+    /// AWSElasticBlockStore represents an AWS Disk resource that is attached to a kubelet's host machine and then exposed to the pod. More info: https://kubernetes.io/docs/concepts/storage/volumes#awselasticblockstore
+    #[serde(rename = "awsElasticBlockStore", skip_serializing_if = "Option::is_none")]
+    pub aws_elastic_block_store: Option<crate::codegen::io::k8s::api::core::v1::aws_elastic_block_store_volume_source::AwsElasticBlockStoreVolumeSource>,
+    /// AzureDisk represents an Azure Data Disk mount on the host and bind mount to the pod.
+    #[serde(rename = "azureDisk", skip_serializing_if = "Option::is_none")]
+    pub azure_disk: Option<crate::codegen::io::k8s::api::core::v1::azure_disk_volume_source::AzureDiskVolumeSource>,
+    /// AzureFile represents an Azure File Service mount on the host and bind mount to the pod.
+    #[serde(rename = "azureFile", skip_serializing_if = "Option::is_none")]
+    pub azure_file: Option<crate::codegen::io::k8s::api::core::v1::azure_file_persistent_volume_source::AzureFilePersistentVolumeSource>,
+    /// A description of the persistent volume's resources and capacity. More info: https://kubernetes.io/docs/concepts/storage/persistent-volumes#capacity
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub capacity: Option<std::collections::BTreeMap<String, String>>,
+    /// CephFS represents a Ceph FS mount on the host that shares a pod's lifetime
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cephfs: Option<crate::codegen::io::k8s::api::core::v1::ceph_fs_persistent_volume_source::CephFsPersistentVolumeSource>,
+    /// Cinder represents a cinder volume attached and mounted on kubelets host machine More info: https://releases.k8s.io/HEAD/examples/mysql-cinder-pd/README.md
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cinder: Option<crate::codegen::io::k8s::api::core::v1::cinder_persistent_volume_source::CinderPersistentVolumeSource>,
+    /// ClaimRef is part of a bi-directional binding between PersistentVolume and PersistentVolumeClaim. Expected to be non-nil when bound. claim.VolumeName is the authoritative bind between PV and PVC. More info: https://kubernetes.io/docs/concepts/storage/persistent-volumes#binding
+    #[serde(rename = "claimRef", skip_serializing_if = "Option::is_none")]
+    pub claim_ref: Option<crate::codegen::io::k8s::api::core::v1::object_reference::ObjectReference>,
+    /// CSI represents storage that is handled by an external CSI driver (Beta feature).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub csi: Option<crate::codegen::io::k8s::api::core::v1::csi_persistent_volume_source::CsiPersistentVolumeSource>,
+    /// FC represents a Fibre Channel resource that is attached to a kubelet's host machine and then exposed to the pod.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fc: Option<crate::codegen::io::k8s::api::core::v1::fc_volume_source::FcVolumeSource>,
+    /// FlexVolume represents a generic volume resource that is provisioned/attached using an exec based plugin.
+    #[serde(rename = "flexVolume", skip_serializing_if = "Option::is_none")]
+    pub flex_volume: Option<crate::codegen::io::k8s::api::core::v1::flex_persistent_volume_source::FlexPersistentVolumeSource>,
+    /// Flocker represents a Flocker volume attached to a kubelet's host machine and exposed to the pod for its usage. This depends on the Flocker control service being running
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub flocker: Option<crate::codegen::io::k8s::api::core::v1::flocker_volume_source::FlockerVolumeSource>,
+    /// GCEPersistentDisk represents a GCE Disk resource that is attached to a kubelet's host machine and then exposed to the pod. Provisioned by an admin. More info: https://kubernetes.io/docs/concepts/storage/volumes#gcepersistentdisk
+    #[serde(rename = "gcePersistentDisk", skip_serializing_if = "Option::is_none")]
+    pub gce_persistent_disk: Option<crate::codegen::io::k8s::api::core::v1::gce_persistent_disk_volume_source::GcePersistentDiskVolumeSource>,
+    /// Glusterfs represents a Glusterfs volume that is attached to a host and exposed to the pod. Provisioned by an admin. More info: https://releases.k8s.io/HEAD/examples/volumes/glusterfs/README.md
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub glusterfs: Option<crate::codegen::io::k8s::api::core::v1::glusterfs_persistent_volume_source::GlusterfsPersistentVolumeSource>,
+    /// HostPath represents a directory on the host. Provisioned by a developer or tester. This is useful for single-node development and testing only! On-host storage is not supported in any way and WILL NOT WORK in a multi-node cluster. More info: https://kubernetes.io/docs/concepts/storage/volumes#hostpath
+    #[serde(rename = "hostPath", skip_serializing_if = "Option::is_none")]
+    pub host_path: Option<crate::codegen::io::k8s::api::core::v1::host_path_volume_source::HostPathVolumeSource>,
+    /// ISCSI represents an ISCSI Disk resource that is attached to a kubelet's host machine and then exposed to the pod. Provisioned by an admin.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub iscsi: Option<crate::codegen::io::k8s::api::core::v1::iscsi_persistent_volume_source::IscsiPersistentVolumeSource>,
+    /// Local represents directly-attached storage with node affinity
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub local: Option<crate::codegen::io::k8s::api::core::v1::local_volume_source::LocalVolumeSource>,
+    /// A list of mount options, e.g. \["ro", "soft"\]. Not validated - mount will simply fail if one is invalid. More info: https://kubernetes.io/docs/concepts/storage/persistent-volumes/#mount-options
+    #[serde(rename = "mountOptions", skip_serializing_if = "Option::is_none")]
+    pub mount_options: Option<Vec<String>>,
+    /// NFS represents an NFS mount on the host. Provisioned by an admin. More info: https://kubernetes.io/docs/concepts/storage/volumes#nfs
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nfs: Option<crate::codegen::io::k8s::api::core::v1::nfs_volume_source::NfsVolumeSource>,
+    /// NodeAffinity defines constraints that limit what nodes this volume can be accessed from. This field influences the scheduling of pods that use this volume.
+    #[serde(rename = "nodeAffinity", skip_serializing_if = "Option::is_none")]
+    pub node_affinity: Option<crate::codegen::io::k8s::api::core::v1::volume_node_affinity::VolumeNodeAffinity>,
+    /// What happens to a persistent volume when released from its claim. Valid options are Retain (default for manually created PersistentVolumes), Delete (default for dynamically provisioned PersistentVolumes), and Recycle (deprecated). Recycle must be supported by the volume plugin underlying this PersistentVolume. More info: https://kubernetes.io/docs/concepts/storage/persistent-volumes#reclaiming
+    #[serde(rename = "persistentVolumeReclaimPolicy", skip_serializing_if = "Option::is_none")]
+    pub persistent_volume_reclaim_policy: Option<String>,
+    /// PhotonPersistentDisk represents a PhotonController persistent disk attached and mounted on kubelets host machine
+    #[serde(rename = "photonPersistentDisk", skip_serializing_if = "Option::is_none")]
+    pub photon_persistent_disk: Option<crate::codegen::io::k8s::api::core::v1::photon_persistent_disk_volume_source::PhotonPersistentDiskVolumeSource>,
+    /// PortworxVolume represents a portworx volume attached and mounted on kubelets host machine
+    #[serde(rename = "portworxVolume", skip_serializing_if = "Option::is_none")]
+    pub portworx_volume: Option<crate::codegen::io::k8s::api::core::v1::portworx_volume_source::PortworxVolumeSource>,
+    /// Quobyte represents a Quobyte mount on the host that shares a pod's lifetime
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quobyte: Option<crate::codegen::io::k8s::api::core::v1::quobyte_volume_source::QuobyteVolumeSource>,
+    /// RBD represents a Rados Block Device mount on the host that shares a pod's lifetime. More info: https://releases.k8s.io/HEAD/examples/volumes/rbd/README.md
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rbd: Option<crate::codegen::io::k8s::api::core::v1::rbd_persistent_volume_source::RbdPersistentVolumeSource>,
+    /// ScaleIO represents a ScaleIO persistent volume attached and mounted on Kubernetes nodes.
+    #[serde(rename = "scaleIO", skip_serializing_if = "Option::is_none")]
+    pub scale_io: Option<crate::codegen::io::k8s::api::core::v1::scale_io_persistent_volume_source::ScaleIoPersistentVolumeSource>,
+    /// Name of StorageClass to which this persistent volume belongs. Empty value means that this volume does not belong to any StorageClass.
+    #[serde(rename = "storageClassName", skip_serializing_if = "Option::is_none")]
+    pub storage_class_name: Option<String>,
+    /// StorageOS represents a StorageOS volume that is attached to the kubelet's host machine and mounted into the pod More info: https://releases.k8s.io/HEAD/examples/volumes/storageos/README.md
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub storageos: Option<crate::codegen::io::k8s::api::core::v1::storage_os_persistent_volume_source::StorageOsPersistentVolumeSource>,
+    /// volumeMode defines if a volume is intended to be used with a formatted filesystem or to remain in raw block state. Value of Filesystem is implied when not included in spec. This is a beta feature.
+    #[serde(rename = "volumeMode", skip_serializing_if = "Option::is_none")]
+    pub volume_mode: Option<String>,
+    /// VsphereVolume represents a vSphere volume attached and mounted on kubelets host machine
+    #[serde(rename = "vsphereVolume", skip_serializing_if = "Option::is_none")]
+    pub vsphere_volume: Option<crate::codegen::io::k8s::api::core::v1::vsphere_virtual_disk_volume_source::VsphereVirtualDiskVolumeSource>,
+}
+
+impl PersistentVolumeSpec {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> PersistentVolumeSpecBuilder {
+        PersistentVolumeSpecBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> PersistentVolumeSpecBuilder
+    where
+        Self: Clone,
+    {
+        PersistentVolumeSpecBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<PersistentVolumeSpec> for PersistentVolumeSpecBuilder {
+    fn into(self) -> PersistentVolumeSpec {
+        self.body
+    }
+}
+
+/// Builder for [`PersistentVolumeSpec`](./struct.PersistentVolumeSpec.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct PersistentVolumeSpecBuilder {
+    body: self::PersistentVolumeSpec,
+}
+
+impl PersistentVolumeSpecBuilder {
+    /// AccessModes contains all ways the volume can be mounted. More info: https://kubernetes.io/docs/concepts/storage/persistent-volumes#access-modes
+    #[inline]
+    pub fn access_modes(mut self, value: impl Iterator<Item = impl Into<String>>) -> Self {
+        self.body.access_modes = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`access_modes`](#method.access_modes) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_access_modes(mut self, value: Option<impl Iterator<Item = impl Into<String>>>) -> Self {
+        self.body.access_modes = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`access_modes`](#method.access_modes), but takes the values as a `Vec`.
+    #[inline]
+    pub fn access_modes_vec(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.body.access_modes = Some(value.into());
+        self
+    }
+
+    /// AWSElasticBlockStore represents an AWS Disk resource that is attached to a kubelet's host machine and then exposed to the pod. More info: https://kubernetes.io/docs/concepts/storage/volumes#awselasticblockstore
+    #[inline]
+    pub fn aws_elastic_block_store(mut self, value: crate::codegen::io::k8s::api::core::v1::aws_elastic_block_store_volume_source::AwsElasticBlockStoreVolumeSourceBuilder<crate::codegen::generics::VolumeIdExists>) -> Self {
+        self.body.aws_elastic_block_store = Some(value.into());
+        self
+    }
+
+    /// Sets [`aws_elastic_block_store`](#method.aws_elastic_block_store) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_aws_elastic_block_store(mut self, value: Option<crate::codegen::io::k8s::api::core::v1::aws_elastic_block_store_volume_source::AwsElasticBlockStoreVolumeSourceBuilder<crate::codegen::generics::VolumeIdExists>>) -> Self {
+        self.body.aws_elastic_block_store = value.map(|value| value.into());
+        self
+    }
+
+    /// AzureDisk represents an Azure Data Disk mount on the host and bind mount to the pod.
+    #[inline]
+    pub fn azure_disk(mut self, value: crate::codegen::io::k8s::api::core::v1::azure_disk_volume_source::AzureDiskVolumeSourceBuilder<crate::codegen::generics::DiskNameExists, crate::codegen::generics::DiskUriExists>) -> Self {
+        self.body.azure_disk = Some(value.into());
+        self
+    }
+
+    /// Sets [`azure_disk`](#method.azure_disk) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_azure_disk(mut self, value: Option<crate::codegen::io::k8s::api::core::v1::azure_disk_volume_source::AzureDiskVolumeSourceBuilder<crate::codegen::generics::DiskNameExists, crate::codegen::generics::DiskUriExists>>) -> Self {
+        self.body.azure_disk = value.map(|value| value.into());
+        self
+    }
+
+    /// AzureFile represents an Azure File Service mount on the host and bind mount to the pod.
+    #[inline]
+    pub fn azure_file(mut self, value: crate::codegen::io::k8s::api::core::v1::azure_file_persistent_volume_source::AzureFilePersistentVolumeSourceBuilder<crate::codegen::generics::SecretNameExists, crate::codegen::generics::ShareNameExists>) -> Self {
+        self.body.azure_file = Some(value.into());
+        self
+    }
+
+    /// Sets [`azure_file`](#method.azure_file) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_azure_file(mut self, value: Option<crate::codegen::io::k8s::api::core::v1::azure_file_persistent_volume_source::AzureFilePersistentVolumeSourceBuilder<crate::codegen::generics::SecretNameExists, crate::codegen::generics::ShareNameExists>>) -> Self {
+        self.body.azure_file = value.map(|value| value.into());
+        self
+    }
+
+    /// A description of the persistent volume's resources and capacity. More info: https://kubernetes.io/docs/concepts/storage/persistent-volumes#capacity
+    #[inline]
+    pub fn capacity(mut self, value: impl Iterator<Item = (String, impl Into<String>)>) -> Self {
+        self.body.capacity = Some(value.map(|(key, value)| (key, value.into())).collect::<std::collections::BTreeMap<_, _>>().into());
+        self
+    }
+
+    /// Sets [`capacity`](#method.capacity) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_capacity(mut self, value: Option<impl Iterator<Item = (String, impl Into<String>)>>) -> Self {
+        self.body.capacity = value.map(|value| value.map(|(key, value)| (key, value.into())).collect::<std::collections::BTreeMap<_, _>>().into());
+        self
+    }
+
+    /// CephFS represents a Ceph FS mount on the host that shares a pod's lifetime
+    #[inline]
+    pub fn cephfs(mut self, value: crate::codegen::io::k8s::api::core::v1::ceph_fs_persistent_volume_source::CephFsPersistentVolumeSourceBuilder<crate::codegen::generics::MonitorsExists>) -> Self {
+        self.body.cephfs = Some(value.into());
+        self
+    }
+
+    /// Sets [`cephfs`](#method.cephfs) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_cephfs(mut self, value: Option<crate::codegen::io::k8s::api::core::v1::ceph_fs_persistent_volume_source::CephFsPersistentVolumeSourceBuilder<crate::codegen::generics::MonitorsExists>>) -> Self {
+        self.body.cephfs = value.map(|value| value.into());
+        self
+    }
+
+    /// Cinder represents a cinder volume attached and mounted on kubelets host machine More info: https://releases.k8s.io/HEAD/examples/mysql-cinder-pd/README.md
+    #[inline]
+    pub fn cinder(mut self, value: crate::codegen::io::k8s::api::core::v1::cinder_persistent_volume_source::CinderPersistentVolumeSourceBuilder<crate::codegen::generics::VolumeIdExists>) -> Self {
+        self.body.cinder = Some(value.into());
+        self
+    }
+
+    /// Sets [`cinder`](#method.cinder) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_cinder(mut self, value: Option<crate::codegen::io::k8s::api::core::v1::cinder_persistent_volume_source::CinderPersistentVolumeSourceBuilder<crate::codegen::generics::VolumeIdExists>>) -> Self {
+        self.body.cinder = value.map(|value| value.into());
+        self
+    }
+
+    /// ClaimRef is part of a bi-directional binding between PersistentVolume and PersistentVolumeClaim. Expected to be non-nil when bound. claim.VolumeName is the authoritative bind between PV and PVC. More info: https://kubernetes.io/docs/concepts/storage/persistent-volumes#binding
+    #[inline]
+    pub fn claim_ref(mut self, value: crate::codegen::io::k8s::api::core::v1::object_reference::ObjectReference) -> Self {
+        self.body.claim_ref = Some(value.into());
+        self
+    }
+
+    /// Sets [`claim_ref`](#method.claim_ref) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_claim_ref(mut self, value: Option<crate::codegen::io::k8s::api::core::v1::object_reference::ObjectReference>) -> Self {
+        self.body.claim_ref = value.map(|value| value.into());
+        self
+    }
+
+    /// CSI represents storage that is handled by an external CSI driver (Beta feature).
+    #[inline]
+    pub fn csi(mut self, value: crate::codegen::io::k8s::api::core::v1::csi_persistent_volume_source::CsiPersistentVolumeSourceBuilder<crate::codegen::generics::DriverExists, crate::codegen::generics::VolumeHandleExists>) -> Self {
+        self.body.csi = Some(value.into());
+        self
+    }
+
+    /// Sets [`csi`](#method.csi) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_csi(mut self, value: Option<crate::codegen::io::k8s::api::core::v1::csi_persistent_volume_source::CsiPersistentVolumeSourceBuilder<crate::codegen::generics::DriverExists, crate::codegen::generics::VolumeHandleExists>>) -> Self {
+        self.body.csi = value.map(|value| value.into());
+        self
+    }
+
+    /// FC represents a Fibre Channel resource that is attached to a kubelet's host machine and then exposed to the pod.
+    #[inline]
+    pub fn fc(mut self, value: crate::codegen::io::k8s::api::core::v1::fc_volume_source::FcVolumeSource) -> Self {
+        self.body.fc = Some(value.into());
+        self
+    }
+
+    /// Sets [`fc`](#method.fc) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_fc(mut self, value: Option<crate::codegen::io::k8s::api::core::v1::fc_volume_source::FcVolumeSource>) -> Self {
+        self.body.fc = value.map(|value| value.into());
+        self
+    }
+
+    /// FlexVolume represents a generic volume resource that is provisioned/attached using an exec based plugin.
+    #[inline]
+    pub fn flex_volume(mut self, value: crate::codegen::io::k8s::api::core::v1::flex_persistent_volume_source::FlexPersistentVolumeSourceBuilder<crate::codegen::generics::DriverExists>) -> Self {
+        self.body.flex_volume = Some(value.into());
+        self
+    }
+
+    /// Sets [`flex_volume`](#method.flex_volume) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_flex_volume(mut self, value: Option<crate::codegen::io::k8s::api::core::v1::flex_persistent_volume_source::FlexPersistentVolumeSourceBuilder<crate::codegen::generics::DriverExists>>) -> Self {
+        self.body.flex_volume = value.map(|value| value.into());
+        self
+    }
+
+    /// Flocker represents a Flocker volume attached to a kubelet's host machine and exposed to the pod for its usage. This depends on the Flocker control service being running
+    #[inline]
+    pub fn flocker(mut self, value: crate::codegen::io::k8s::api::core::v1::flocker_volume_source::FlockerVolumeSource) -> Self {
+        self.body.flocker = Some(value.into());
+        self
+    }
+
+    /// Sets [`flocker`](#method.flocker) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_flocker(mut self, value: Option<crate::codegen::io::k8s::api::core::v1::flocker_volume_source::FlockerVolumeSource>) -> Self {
+        self.body.flocker = value.map(|value| value.into());
+        self
+    }
+
+    /// GCEPersistentDisk represents a GCE Disk resource that is attached to a kubelet's host machine and then exposed to the pod. Provisioned by an admin. More info: https://kubernetes.io/docs/concepts/storage/volumes#gcepersistentdisk
+    #[inline]
+    pub fn gce_persistent_disk(mut self, value: crate::codegen::io::k8s::api::core::v1::gce_persistent_disk_volume_source::GcePersistentDiskVolumeSourceBuilder<crate::codegen::generics::PdNameExists>) -> Self {
+        self.body.gce_persistent_disk = Some(value.into());
+        self
+    }
+
+    /// Sets [`gce_persistent_disk`](#method.gce_persistent_disk) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_gce_persistent_disk(mut self, value: Option<crate::codegen::io::k8s::api::core::v1::gce_persistent_disk_volume_source::GcePersistentDiskVolumeSourceBuilder<crate::codegen::generics::PdNameExists>>) -> Self {
+        self.body.gce_persistent_disk = value.map(|value| value.into());
+        self
+    }
+
+    /// Glusterfs represents a Glusterfs volume that is attached to a host and exposed to the pod. Provisioned by an admin. More info: https://releases.k8s.io/HEAD/examples/volumes/glusterfs/README.md
+    #[inline]
+    pub fn glusterfs(mut self, value: crate::codegen::io::k8s::api::core::v1::glusterfs_persistent_volume_source::GlusterfsPersistentVolumeSourceBuilder<crate::codegen::generics::EndpointsExists, crate::codegen::generics::PathExists>) -> Self {
+        self.body.glusterfs = Some(value.into());
+        self
+    }
+
+    /// Sets [`glusterfs`](#method.glusterfs) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_glusterfs(mut self, value: Option<crate::codegen::io::k8s::api::core::v1::glusterfs_persistent_volume_source::GlusterfsPersistentVolumeSourceBuilder<crate::codegen::generics::EndpointsExists, crate::codegen::generics::PathExists>>) -> Self {
+        self.body.glusterfs = value.map(|value| value.into());
+        self
+    }
+
+    /// HostPath represents a directory on the host. Provisioned by a developer or tester. This is useful for single-node development and testing only! On-host storage is not supported in any way and WILL NOT WORK in a multi-node cluster. More info: https://kubernetes.io/docs/concepts/storage/volumes#hostpath
+    #[inline]
+    pub fn host_path(mut self, value: crate::codegen::io::k8s::api::core::v1::host_path_volume_source::HostPathVolumeSourceBuilder<crate::codegen::generics::PathExists>) -> Self {
+        self.body.host_path = Some(value.into());
+        self
+    }
+
+    /// Sets [`host_path`](#method.host_path) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_host_path(mut self, value: Option<crate::codegen::io::k8s::api::core::v1::host_path_volume_source::HostPathVolumeSourceBuilder<crate::codegen::generics::PathExists>>) -> Self {
+        self.body.host_path = value.map(|value| value.into());
+        self
+    }
+
+    /// ISCSI represents an ISCSI Disk resource that is attached to a kubelet's host machine and then exposed to the pod. Provisioned by an admin.
+    #[inline]
+    pub fn iscsi(mut self, value: crate::codegen::io::k8s::api::core::v1::iscsi_persistent_volume_source::IscsiPersistentVolumeSourceBuilder<crate::codegen::generics::IqnExists, crate::codegen::generics::LunExists, crate::codegen::generics::TargetPortalExists>) -> Self {
+        self.body.iscsi = Some(value.into());
+        self
+    }
+
+    /// Sets [`iscsi`](#method.iscsi) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_iscsi(mut self, value: Option<crate::codegen::io::k8s::api::core::v1::iscsi_persistent_volume_source::IscsiPersistentVolumeSourceBuilder<crate::codegen::generics::IqnExists, crate::codegen::generics::LunExists, crate::codegen::generics::TargetPortalExists>>) -> Self {
+        self.body.iscsi = value.map(|value| value.into());
+        self
+    }
+
+    /// Local represents directly-attached storage with node affinity
+    #[inline]
+    pub fn local(mut self, value: crate::codegen::io::k8s::api::core::v1::local_volume_source::LocalVolumeSourceBuilder<crate::codegen::generics::PathExists>) -> Self {
+        self.body.local = Some(value.into());
+        self
+    }
+
+    /// Sets [`local`](#method.local) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_local(mut self, value: Option<crate::codegen::io::k8s::api::core::v1::local_volume_source::LocalVolumeSourceBuilder<crate::codegen::generics::PathExists>>) -> Self {
+        self.body.local = value.map(|value| value.into());
+        self
+    }
+
+    /// A list of mount options, e.g. \["ro", "soft"\]. Not validated - mount will simply fail if one is invalid. More info: https://kubernetes.io/docs/concepts/storage/persistent-volumes/#mount-options
+    #[inline]
+    pub fn mount_options(mut self, value: impl Iterator<Item = impl Into<String>>) -> Self {
+        self.body.mount_options = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`mount_options`](#method.mount_options) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_mount_options(mut self, value: Option<impl Iterator<Item = impl Into<String>>>) -> Self {
+        self.body.mount_options = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`mount_options`](#method.mount_options), but takes the values as a `Vec`.
+    #[inline]
+    pub fn mount_options_vec(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.body.mount_options = Some(value.into());
+        self
+    }
+
+    /// NFS represents an NFS mount on the host. Provisioned by an admin. More info: https://kubernetes.io/docs/concepts/storage/volumes#nfs
+    #[inline]
+    pub fn nfs(mut self, value: crate::codegen::io::k8s::api::core::v1::nfs_volume_source::NfsVolumeSourceBuilder<crate::codegen::generics::PathExists, crate::codegen::generics::ServerExists>) -> Self {
+        self.body.nfs = Some(value.into());
+        self
+    }
+
+    /// Sets [`nfs`](#method.nfs) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_nfs(mut self, value: Option<crate::codegen::io::k8s::api::core::v1::nfs_volume_source::NfsVolumeSourceBuilder<crate::codegen::generics::PathExists, crate::codegen::generics::ServerExists>>) -> Self {
+        self.body.nfs = value.map(|value| value.into());
+        self
+    }
+
+    /// NodeAffinity defines constraints that limit what nodes this volume can be accessed from. This field influences the scheduling of pods that use this volume.
+    #[inline]
+    pub fn node_affinity(mut self, value: crate::codegen::io::k8s::api::core::v1::volume_node_affinity::VolumeNodeAffinity) -> Self {
+        self.body.node_affinity = Some(value.into());
+        self
+    }
+
+    /// Sets [`node_affinity`](#method.node_affinity) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_node_affinity(mut self, value: Option<crate::codegen::io::k8s::api::core::v1::volume_node_affinity::VolumeNodeAffinity>) -> Self {
+        self.body.node_affinity = value.map(|value| value.into());
+        self
+    }
+
+    /// What happens to a persistent volume when released from its claim. Valid options are Retain (default for manually created PersistentVolumes), Delete (default for dynamically provisioned PersistentVolumes), and Recycle (deprecated). Recycle must be supported by the volume plugin underlying this PersistentVolume. More info: https://kubernetes.io/docs/concepts/storage/persistent-volumes#reclaiming
+    #[inline]
+    pub fn persistent_volume_reclaim_policy(mut self, value: impl Into<String>) -> Self {
+        self.body.persistent_volume_reclaim_policy = Some(value.into());
+        self
+    }
+
+    /// Sets [`persistent_volume_reclaim_policy`](#method.persistent_volume_reclaim_policy) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_persistent_volume_reclaim_policy(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.persistent_volume_reclaim_policy = value.map(|value| value.into());
+        self
+    }
+
+    /// PhotonPersistentDisk represents a PhotonController persistent disk attached and mounted on kubelets host machine
+    #[inline]
+    pub fn photon_persistent_disk(mut self, value: crate::codegen::io::k8s::api::core::v1::photon_persistent_disk_volume_source::PhotonPersistentDiskVolumeSourceBuilder<crate::codegen::generics::PdIdExists>) -> Self {
+        self.body.photon_persistent_disk = Some(value.into());
+        self
+    }
+
+    /// Sets [`photon_persistent_disk`](#method.photon_persistent_disk) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_photon_persistent_disk(mut self, value: Option<crate::codegen::io::k8s::api::core::v1::photon_persistent_disk_volume_source::PhotonPersistentDiskVolumeSourceBuilder<crate::codegen::generics::PdIdExists>>) -> Self {
+        self.body.photon_persistent_disk = value.map(|value| value.into());
+        self
+    }
+
+    /// PortworxVolume represents a portworx volume attached and mounted on kubelets host machine
+    #[inline]
+    pub fn portworx_volume(mut self, value: crate::codegen::io::k8s::api::core::v1::portworx_volume_source::PortworxVolumeSourceBuilder<crate::codegen::generics::VolumeIdExists>) -> Self {
+        self.body.portworx_volume = Some(value.into());
+        self
+    }
+
+    /// Sets [`portworx_volume`](#method.portworx_volume) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_portworx_volume(mut self, value: Option<crate::codegen::io::k8s::api::core::v1::portworx_volume_source::PortworxVolumeSourceBuilder<crate::codegen::generics::VolumeIdExists>>) -> Self {
+        self.body.portworx_volume = value.map(|value| value.into());
+        self
+    }
+
+    /// Quobyte represents a Quobyte mount on the host that shares a pod's lifetime
+    #[inline]
+    pub fn quobyte(mut self, value: crate::codegen::io::k8s::api::core::v1::quobyte_volume_source::QuobyteVolumeSourceBuilder<crate::codegen::generics::RegistryExists, crate::codegen::generics::VolumeExists>) -> Self {
+        self.body.quobyte = Some(value.into());
+        self
+    }
+
+    /// Sets [`quobyte`](#method.quobyte) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_quobyte(mut self, value: Option<crate::codegen::io::k8s::api::core::v1::quobyte_volume_source::QuobyteVolumeSourceBuilder<crate::codegen::generics::RegistryExists, crate::codegen::generics::VolumeExists>>) -> Self {
+        self.body.quobyte = value.map(|value| value.into());
+        self
+    }
+
+    /// RBD represents a Rados Block Device mount on the host that shares a pod's lifetime. More info: https://releases.k8s.io/HEAD/examples/volumes/rbd/README.md
+    #[inline]
+    pub fn rbd(mut self, value: crate::codegen::io::k8s::api::core::v1::rbd_persistent_volume_source::RbdPersistentVolumeSourceBuilder<crate::codegen::generics::ImageExists, crate::codegen::generics::MonitorsExists>) -> Self {
+        self.body.rbd = Some(value.into());
+        self
+    }
+
+    /// Sets [`rbd`](#method.rbd) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_rbd(mut self, value: Option<crate::codegen::io::k8s::api::core::v1::rbd_persistent_volume_source::RbdPersistentVolumeSourceBuilder<crate::codegen::generics::ImageExists, crate::codegen::generics::MonitorsExists>>) -> Self {
+        self.body.rbd = value.map(|value| value.into());
+        self
+    }
+
+    /// ScaleIO represents a ScaleIO persistent volume attached and mounted on Kubernetes nodes.
+    #[inline]
+    pub fn scale_io(mut self, value: crate::codegen::io::k8s::api::core::v1::scale_io_persistent_volume_source::ScaleIoPersistentVolumeSourceBuilder<crate::codegen::generics::GatewayExists, crate::codegen::generics::SecretRefExists, crate::codegen::generics::SystemExists>) -> Self {
+        self.body.scale_io = Some(value.into());
+        self
+    }
+
+    /// Sets [`scale_io`](#method.scale_io) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_scale_io(mut self, value: Option<crate::codegen::io::k8s::api::core::v1::scale_io_persistent_volume_source::ScaleIoPersistentVolumeSourceBuilder<crate::codegen::generics::GatewayExists, crate::codegen::generics::SecretRefExists, crate::codegen::generics::SystemExists>>) -> Self {
+        self.body.scale_io = value.map(|value| value.into());
+        self
+    }
+
+    /// Name of StorageClass to which this persistent volume belongs. Empty value means that this volume does not belong to any StorageClass.
+    #[inline]
+    pub fn storage_class_name(mut self, value: impl Into<String>) -> Self {
+        self.body.storage_class_name = Some(value.into());
+        self
+    }
+
+    /// Sets [`storage_class_name`](#method.storage_class_name) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_storage_class_name(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.storage_class_name = value.map(|value| value.into());
+        self
+    }
+
+    /// StorageOS represents a StorageOS volume that is attached to the kubelet's host machine and mounted into the pod More info: https://releases.k8s.io/HEAD/examples/volumes/storageos/README.md
+    #[inline]
+    pub fn storageos(mut self, value: crate::codegen::io::k8s::api::core::v1::storage_os_persistent_volume_source::StorageOsPersistentVolumeSource) -> Self {
+        self.body.storageos = Some(value.into());
+        self
+    }
+
+    /// Sets [`storageos`](#method.storageos) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_storageos(mut self, value: Option<crate::codegen::io::k8s::api::core::v1::storage_os_persistent_volume_source::StorageOsPersistentVolumeSource>) -> Self {
+        self.body.storageos = value.map(|value| value.into());
+        self
+    }
+
+    /// volumeMode defines if a volume is intended to be used with a formatted filesystem or to remain in raw block state. Value of Filesystem is implied when not included in spec. This is a beta feature.
+    #[inline]
+    pub fn volume_mode(mut self, value: impl Into<String>) -> Self {
+        self.body.volume_mode = Some(value.into());
+        self
+    }
+
+    /// Sets [`volume_mode`](#method.volume_mode) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_volume_mode(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.volume_mode = value.map(|value| value.into());
+        self
+    }
+
+    /// VsphereVolume represents a vSphere volume attached and mounted on kubelets host machine
+    #[inline]
+    pub fn vsphere_volume(mut self, value: crate::codegen::io::k8s::api::core::v1::vsphere_virtual_disk_volume_source::VsphereVirtualDiskVolumeSourceBuilder<crate::codegen::generics::VolumePathExists>) -> Self {
+        self.body.vsphere_volume = Some(value.into());
+        self
+    }
+
+    /// Sets [`vsphere_volume`](#method.vsphere_volume) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_vsphere_volume(mut self, value: Option<crate::codegen::io::k8s::api::core::v1::vsphere_virtual_disk_volume_source::VsphereVirtualDiskVolumeSourceBuilder<crate::codegen::generics::VolumePathExists>>) -> Self {
+        self.body.vsphere_volume = value.map(|value| value.into());
+        self
+    }
+}
@@ -0,0 +1,91 @@
+
+/// PersistentVolumeStatus is the current status of a persistent volume.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct PersistentVolumeStatus {
+    /// A human-readable message indicating details about why the volume is in this state.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    /// Phase indicates if a volume is available, bound to a claim, or released by a claim. More info: https://kubernetes.io/docs/concepts/storage/persistent-volumes#phase
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phase: Option<String>,
+    /// Reason is a brief CamelCase string that describes any failure and is meant for machine parsing and tidy display in the CLI.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+}
+
+impl PersistentVolumeStatus {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> PersistentVolumeStatusBuilder {
+        PersistentVolumeStatusBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> PersistentVolumeStatusBuilder
+    where
+        Self: Clone,
+    {
+        PersistentVolumeStatusBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<PersistentVolumeStatus> for PersistentVolumeStatusBuilder {
+    fn into(self) -> PersistentVolumeStatus {
+        self.body
+    }
+}
+
+/// Builder for [`PersistentVolumeStatus`](./struct.PersistentVolumeStatus.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct PersistentVolumeStatusBuilder {
+    body: self::PersistentVolumeStatus,
+}
+
+impl PersistentVolumeStatusBuilder {
+    /// A human-readable message indicating details about why the volume is in this state.
+    #[inline]
+    pub fn message(mut self, value: impl Into<String>) -> Self {
+        self.body.message = Some(value.into());
+        self
+    }
+
+    /// Sets [`message`](#method.message) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_message(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.message = value.map(|value| value.into());
+        self
+    }
+
+    /// Phase indicates if a volume is available, bound to a claim, or released by a claim. More info: https://kubernetes.io/docs/concepts/storage/persistent-volumes#phase
+    #[inline]
+    pub fn phase(mut self, value: impl Into<String>) -> Self {
+        self.body.phase = Some(value.into());
+        self
+    }
+
+    /// Sets [`phase`](#method.phase) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_phase(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.phase = value.map(|value| value.into());
+        self
+    }
+
+    /// Reason is a brief CamelCase string that describes any failure and is meant for machine parsing and tidy display in the CLI.
+    #[inline]
+    pub fn reason(mut self, value: impl Into<String>) -> Self {
+        self.body.reason = Some(value.into());
+        self
+    }
+
+    /// Sets [`reason`](#method.reason) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_reason(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.reason = value.map(|value| value.into());
+        self
+    }
+}
@@ -0,0 +1,94 @@
+
+/// Defines a set of pods (namely those matching the labelSelector relative to the given namespace(s)) that this pod should be co-located (affinity) or not co-located (anti-affinity) with, where co-located is defined as running on a node whose value of the label with key \<topologyKey> matches that of any node on which a pod of the set of pods is running
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct PodAffinityTerm {
+    /// A label query over a set of resources, in this case pods.
+    #[serde(rename = "labelSelector", skip_serializing_if = "Option::is_none")]
+    pub label_selector: Option<crate::codegen::io::k8s::apimachinery::pkg::apis::meta::v1::label_selector::LabelSelector>,
+    /// namespaces specifies which namespaces the labelSelector applies to (matches against); null or empty list means "this pod's namespace"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub namespaces: Option<Vec<String>>,
+    /// This pod should be co-located (affinity) or not co-located (anti-affinity) with the pods matching the labelSelector in the specified namespaces, where co-located is defined as running on a node whose value of the label with key topologyKey matches that of any node on which any of the selected pods is running. Empty topologyKey is not allowed.
+    #[serde(rename = "topologyKey")]
+    pub topology_key: String,
+}
+
+impl PodAffinityTerm {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> PodAffinityTermBuilder<crate::codegen::generics::MissingTopologyKey> {
+        PodAffinityTermBuilder {
+            body: Default::default(),
+            _topology_key: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> PodAffinityTermBuilder<crate::codegen::generics::TopologyKeyExists>
+    where
+        Self: Clone,
+    {
+        PodAffinityTermBuilder {
+            body: self.clone(),
+            _topology_key: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<PodAffinityTerm> for PodAffinityTermBuilder<crate::codegen::generics::TopologyKeyExists> {
+    fn into(self) -> PodAffinityTerm {
+        self.body
+    }
+}
+
+/// Builder for [`PodAffinityTerm`](./struct.PodAffinityTerm.html) object.
+#[derive(Debug, Clone)]
+pub struct PodAffinityTermBuilder<TopologyKey> {
+    body: self::PodAffinityTerm,
+    _topology_key: core::marker::PhantomData<TopologyKey>,
+}
+
+impl<TopologyKey> PodAffinityTermBuilder<TopologyKey> {
+    /// A label query over a set of resources, in this case pods.
+    #[inline]
+    pub fn label_selector(mut self, value: crate::codegen::io::k8s::apimachinery::pkg::apis::meta::v1::label_selector::LabelSelector) -> Self {
+        self.body.label_selector = Some(value.into());
+        self
+    }
+
+    /// Sets [`label_selector`](#method.label_selector) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_label_selector(mut self, value: Option<crate::codegen::io::k8s::apimachinery::pkg::apis::meta::v1::label_selector::LabelSelector>) -> Self {
+        self.body.label_selector = value.map(|value| value.into());
+        self
+    }
+
+    /// namespaces specifies which namespaces the labelSelector applies to (matches against); null or empty list means "this pod's namespace"
+    #[inline]
+    pub fn namespaces(mut self, value: impl Iterator<Item = impl Into<String>>) -> Self {
+        self.body.namespaces = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`namespaces`](#method.namespaces) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_namespaces(mut self, value: Option<impl Iterator<Item = impl Into<String>>>) -> Self {
+        self.body.namespaces = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`namespaces`](#method.namespaces), but takes the values as a `Vec`.
+    #[inline]
+    pub fn namespaces_vec(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.body.namespaces = Some(value.into());
+        self
+    }
+
+    /// This pod should be co-located (affinity) or not co-located (anti-affinity) with the pods matching the labelSelector in the specified namespaces, where co-located is defined as running on a node whose value of the label with key topologyKey matches that of any node on which any of the selected pods is running. Empty topologyKey is not allowed.
+    #[inline]
+    pub fn topology_key(mut self, value: impl Into<String>) -> PodAffinityTermBuilder<crate::codegen::generics::TopologyKeyExists> {
+        self.body.topology_key = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+}
@@ -0,0 +1,88 @@
+
+/// Pod anti affinity is a group of inter pod anti affinity scheduling rules.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct PodAntiAffinity {
+    /// The scheduler will prefer to schedule pods to nodes that satisfy the anti-affinity expressions specified by this field, but it may choose a node that violates one or more of the expressions. The node that is most preferred is the one with the greatest sum of weights, i.e. for each node that meets all of the scheduling requirements (resource request, requiredDuringScheduling anti-affinity expressions, etc.), compute a sum by iterating through the elements of this field and adding "weight" to the sum if the node has pods which matches the corresponding podAffinityTerm; the node(s) with the highest sum are the most preferred.
+    #[serde(rename = "preferredDuringSchedulingIgnoredDuringExecution", skip_serializing_if = "Option::is_none")]
+    pub preferred_during_scheduling_ignored_during_execution: Option<Vec<crate::codegen::io::k8s::api::core::v1::weighted_pod_affinity_term::WeightedPodAffinityTerm>>,
+    /// If the anti-affinity requirements specified by this field are not met at scheduling time, the pod will not be scheduled onto the node. If the anti-affinity requirements specified by this field cease to be met at some point during pod execution (e.g. due to a pod label update), the system may or may not try to eventually evict the pod from its node. When there are multiple elements, the lists of nodes corresponding to each podAffinityTerm are intersected, i.e. all terms must be satisfied.
+    #[serde(rename = "requiredDuringSchedulingIgnoredDuringExecution", skip_serializing_if = "Option::is_none")]
+    pub required_during_scheduling_ignored_during_execution: Option<Vec<crate::codegen::io::k8s::api::core::v1::pod_affinity_term::PodAffinityTerm>>,
+}
+
+impl PodAntiAffinity {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> PodAntiAffinityBuilder {
+        PodAntiAffinityBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> PodAntiAffinityBuilder
+    where
+        Self: Clone,
+    {
+        PodAntiAffinityBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<PodAntiAffinity> for PodAntiAffinityBuilder {
+    fn into(self) -> PodAntiAffinity {
+        self.body
+    }
+}
+
+/// Builder for [`PodAntiAffinity`](./struct.PodAntiAffinity.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct PodAntiAffinityBuilder {
+    body: self::PodAntiAffinity,
+}
+
+impl PodAntiAffinityBuilder {
+    /// The scheduler will prefer to schedule pods to nodes that satisfy the anti-affinity expressions specified by this field, but it may choose a node that violates one or more of the expressions. The node that is most preferred is the one with the greatest sum of weights, i.e. for each node that meets all of the scheduling requirements (resource request, requiredDuringScheduling anti-affinity expressions, etc.), compute a sum by iterating through the elements of this field and adding "weight" to the sum if the node has pods which matches the corresponding podAffinityTerm; the node(s) with the highest sum are the most preferred.
+    #[inline]
+    pub fn preferred_during_scheduling_ignored_during_execution(mut self, value: impl Iterator<Item = crate::codegen::io::k8s::api::core::v1::weighted_pod_affinity_term::WeightedPodAffinityTermBuilder<crate::codegen::generics::PodAffinityTermExists, crate::codegen::generics::WeightExists>>) -> Self {
+        self.body.preferred_during_scheduling_ignored_during_execution = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`preferred_during_scheduling_ignored_during_execution`](#method.preferred_during_scheduling_ignored_during_execution) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_preferred_during_scheduling_ignored_during_execution(mut self, value: Option<impl Iterator<Item = crate::codegen::io::k8s::api::core::v1::weighted_pod_affinity_term::WeightedPodAffinityTermBuilder<crate::codegen::generics::PodAffinityTermExists, crate::codegen::generics::WeightExists>>>) -> Self {
+        self.body.preferred_during_scheduling_ignored_during_execution = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`preferred_during_scheduling_ignored_during_execution`](#method.preferred_during_scheduling_ignored_during_execution), but takes the values as a `Vec`.
+    #[inline]
+    pub fn preferred_during_scheduling_ignored_during_execution_vec(mut self, value: impl Into<Vec<crate::codegen::io::k8s::api::core::v1::weighted_pod_affinity_term::WeightedPodAffinityTerm>>) -> Self {
+        self.body.preferred_during_scheduling_ignored_during_execution = Some(value.into());
+        self
+    }
+
+    /// If the anti-affinity requirements specified by this field are not met at scheduling time, the pod will not be scheduled onto the node. If the anti-affinity requirements specified by this field cease to be met at some point during pod execution (e.g. due to a pod label update), the system may or may not try to eventually evict the pod from its node. When there are multiple elements, the lists of nodes corresponding to each podAffinityTerm are intersected, i.e. all terms must be satisfied.
+    #[inline]
+    pub fn required_during_scheduling_ignored_during_execution(mut self, value: impl Iterator<Item = crate::codegen::io::k8s::api::core::v1::pod_affinity_term::PodAffinityTermBuilder<crate::codegen::generics::TopologyKeyExists>>) -> Self {
+        self.body.required_during_scheduling_ignored_during_execution = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`required_during_scheduling_ignored_during_execution`](#method.required_during_scheduling_ignored_during_execution) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_required_during_scheduling_ignored_during_execution(mut self, value: Option<impl Iterator<Item = crate::codegen::io::k8s::api::core::v1::pod_affinity_term::PodAffinityTermBuilder<crate::codegen::generics::TopologyKeyExists>>>) -> Self {
+        self.body.required_during_scheduling_ignored_during_execution = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`required_during_scheduling_ignored_during_execution`](#method.required_during_scheduling_ignored_during_execution), but takes the values as a `Vec`.
+    #[inline]
+    pub fn required_during_scheduling_ignored_during_execution_vec(mut self, value: impl Into<Vec<crate::codegen::io::k8s::api::core::v1::pod_affinity_term::PodAffinityTerm>>) -> Self {
+        self.body.required_during_scheduling_ignored_during_execution = Some(value.into());
+        self
+    }
+}
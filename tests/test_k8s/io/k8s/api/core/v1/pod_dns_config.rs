@@ -0,0 +1,112 @@
+
+/// PodDNSConfig defines the DNS parameters of a pod in addition to those generated from DNSPolicy.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct PodDnsConfig {
+    /// A list of DNS name server IP addresses. This will be appended to the base nameservers generated from DNSPolicy. Duplicated nameservers will be removed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nameservers: Option<Vec<String>>,
+    /// A list of DNS resolver options. This will be merged with the base options generated from DNSPolicy. Duplicated entries will be removed. Resolution options given in Options will override those that appear in the base DNSPolicy.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub options: Option<Vec<crate::codegen::io::k8s::api::core::v1::pod_dns_config_option::PodDnsConfigOption>>,
+    /// A list of DNS search domains for host-name lookup. This will be appended to the base search paths generated from DNSPolicy. Duplicated search paths will be removed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub searches: Option<Vec<String>>,
+}
+
+impl PodDnsConfig {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> PodDnsConfigBuilder {
+        PodDnsConfigBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> PodDnsConfigBuilder
+    where
+        Self: Clone,
+    {
+        PodDnsConfigBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<PodDnsConfig> for PodDnsConfigBuilder {
+    fn into(self) -> PodDnsConfig {
+        self.body
+    }
+}
+
+/// Builder for [`PodDnsConfig`](./struct.PodDnsConfig.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct PodDnsConfigBuilder {
+    body: self::PodDnsConfig,
+}
+
+impl PodDnsConfigBuilder {
+    /// A list of DNS name server IP addresses. This will be appended to the base nameservers generated from DNSPolicy. Duplicated nameservers will be removed.
+    #[inline]
+    pub fn nameservers(mut self, value: impl Iterator<Item = impl Into<String>>) -> Self {
+        self.body.nameservers = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`nameservers`](#method.nameservers) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_nameservers(mut self, value: Option<impl Iterator<Item = impl Into<String>>>) -> Self {
+        self.body.nameservers = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`nameservers`](#method.nameservers), but takes the values as a `Vec`.
+    #[inline]
+    pub fn nameservers_vec(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.body.nameservers = Some(value.into());
+        self
+    }
+
+    /// A list of DNS resolver options. This will be merged with the base options generated from DNSPolicy. Duplicated entries will be removed. Resolution options given in Options will override those that appear in the base DNSPolicy.
+    #[inline]
+    pub fn options(mut self, value: impl Iterator<Item = crate::codegen::io::k8s::api::core::v1::pod_dns_config_option::PodDnsConfigOption>) -> Self {
+        self.body.options = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`options`](#method.options) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_options(mut self, value: Option<impl Iterator<Item = crate::codegen::io::k8s::api::core::v1::pod_dns_config_option::PodDnsConfigOption>>) -> Self {
+        self.body.options = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`options`](#method.options), but takes the values as a `Vec`.
+    #[inline]
+    pub fn options_vec(mut self, value: impl Into<Vec<crate::codegen::io::k8s::api::core::v1::pod_dns_config_option::PodDnsConfigOption>>) -> Self {
+        self.body.options = Some(value.into());
+        self
+    }
+
+    /// A list of DNS search domains for host-name lookup. This will be appended to the base search paths generated from DNSPolicy. Duplicated search paths will be removed.
+    #[inline]
+    pub fn searches(mut self, value: impl Iterator<Item = impl Into<String>>) -> Self {
+        self.body.searches = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`searches`](#method.searches) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_searches(mut self, value: Option<impl Iterator<Item = impl Into<String>>>) -> Self {
+        self.body.searches = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`searches`](#method.searches), but takes the values as a `Vec`.
+    #[inline]
+    pub fn searches_vec(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.body.searches = Some(value.into());
+        self
+    }
+}
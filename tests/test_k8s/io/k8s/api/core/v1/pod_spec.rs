@@ -0,0 +1,648 @@
+
+/// PodSpec is a description of a pod.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct PodSpec {
+    /// Optional duration in seconds the pod may be active on the node relative to StartTime before the system will actively try to mark it failed and kill associated containers. Value must be a positive integer.
+    #[serde(rename = "activeDeadlineSeconds", skip_serializing_if = "Option::is_none")]
+    pub active_deadline_seconds: Option<i64>,
+    /// If specified, the pod's scheduling constraints
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub affinity: Option<crate::codegen::io::k8s::api::core::v1::affinity::Affinity>,
+    /// AutomountServiceAccountToken indicates whether a service account token should be automatically mounted.
+    #[serde(rename = "automountServiceAccountToken", skip_serializing_if = "Option::is_none")]
+    pub automount_service_account_token: Option<bool>,
+    /// List of containers belonging to the pod. Containers cannot currently be added or removed. There must be at least one container in a Pod. Cannot be updated.
+    pub containers: Vec<crate::codegen::io::k8s::api::core::v1::container::Container>,
+    /// Specifies the DNS parameters of a pod. Parameters specified here will be merged to the generated DNS configuration based on DNSPolicy.
+    #[serde(rename = "dnsConfig", skip_serializing_if = "Option::is_none")]
+    pub dns_config: Option<crate::codegen::io::k8s::api::core::v1::pod_dns_config::PodDnsConfig>,
+    /// Set DNS policy for the pod. Defaults to "ClusterFirst". Valid values are 'ClusterFirstWithHostNet', 'ClusterFirst', 'Default' or 'None'. DNS parameters given in DNSConfig will be merged with the policy selected with DNSPolicy. To have DNS options set along with hostNetwork, you have to specify DNS policy explicitly to 'ClusterFirstWithHostNet'.
+    #[serde(rename = "dnsPolicy", skip_serializing_if = "Option::is_none")]
+    pub dns_policy: Option<String>,
+    /// EnableServiceLinks indicates whether information about services should be injected into pod's environment variables, matching the syntax of Docker links. Optional: Defaults to true.
+    #[serde(rename = "enableServiceLinks", skip_serializing_if = "Option::is_none")]
+    pub enable_service_links: Option<bool>,
+    /// HostAliases is an optional list of hosts and IPs that will be injected into the pod's hosts file if specified. This is only valid for non-hostNetwork pods.
+    #[serde(rename = "hostAliases", skip_serializing_if = "Option::is_none")]
+    pub host_aliases: Option<Vec<crate::codegen::io::k8s::api::core::v1::host_alias::HostAlias>>,
+    /// Use the host's ipc namespace. Optional: Default to false.
+    #[serde(rename = "hostIPC", skip_serializing_if = "Option::is_none")]
+    pub host_ipc: Option<bool>,
+    /// Host networking requested for this pod. Use the host's network namespace. If this option is set, the ports that will be used must be specified. Default to false.
+    #[serde(rename = "hostNetwork", skip_serializing_if = "Option::is_none")]
+    pub host_network: Option<bool>,
+    /// Use the host's pid namespace. Optional: Default to false.
+    #[serde(rename = "hostPID", skip_serializing_if = "Option::is_none")]
+    pub host_pid: Option<bool>,
+    /// Specifies the hostname of the Pod If not specified, the pod's hostname will be set to a system-defined value.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hostname: Option<String>,
+    /// ImagePullSecrets is an optional list of references to secrets in the same namespace to use for pulling any of the images used by this PodSpec. If specified, these secrets will be passed to individual puller implementations for them to use. For example, in the case of docker, only DockerConfig type secrets are honored. More info: https://kubernetes.io/docs/concepts/containers/images#specifying-imagepullsecrets-on-a-pod
+    #[serde(rename = "imagePullSecrets", skip_serializing_if = "Option::is_none")]
+    pub image_pull_secrets: Option<Vec<crate::codegen::io::k8s::api::core::v1::local_object_reference::LocalObjectReference>>,
+    /// List of initialization containers belonging to the pod. Init containers are executed in order prior to containers being started. If any init container fails, the pod is considered to have failed and is handled according to its restartPolicy. The name for an init container or normal container must be unique among all containers. Init containers may not have Lifecycle actions, Readiness probes, or Liveness probes. The resourceRequirements of an init container are taken into account during scheduling by finding the highest request/limit for each resource type, and then using the max of of that value or the sum of the normal containers. Limits are applied to init containers in a similar fashion. Init containers cannot currently be added or removed. Cannot be updated. More info: https://kubernetes.io/docs/concepts/workloads/pods/init-containers/
+    #[serde(rename = "initContainers", skip_serializing_if = "Option::is_none")]
+    pub init_containers: Option<Vec<crate::codegen::io::k8s::api::core::v1::container::Container>>,
+    /// NodeName is a request to schedule this pod onto a specific node. If it is non-empty, the scheduler simply schedules this pod onto that node, assuming that it fits resource requirements.
+    #[serde(rename = "nodeName", skip_serializing_if = "Option::is_none")]
+    pub node_name: Option<String>,
+    /// NodeSelector is a selector which must be true for the pod to fit on a node. Selector which must match a node's labels for the pod to be scheduled on that node. More info: https://kubernetes.io/docs/concepts/configuration/assign-pod-node/
+    #[serde(rename = "nodeSelector", skip_serializing_if = "Option::is_none")]
+    pub node_selector: Option<std::collections::BTreeMap<String, String>>,
+    /// The priority value. Various system components use this field to find the priority of the pod. When Priority Admission Controller is enabled, it prevents users from setting this field. The admission controller populates this field from PriorityClassName. The higher the value, the higher the priority.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub priority: Option<i32>,
+    /// If specified, indicates the pod's priority. "system-node-critical" and "system-cluster-critical" are two special keywords which indicate the highest priorities with the former being the highest priority. Any other name must be defined by creating a PriorityClass object with that name. If not specified, the pod priority will be default or zero if there is no default.
+    #[serde(rename = "priorityClassName", skip_serializing_if = "Option::is_none")]
+    pub priority_class_name: Option<String>,
+    /// If specified, all readiness gates will be evaluated for pod readiness. A pod is ready when all its containers are ready AND all conditions specified in the readiness gates have status equal to "True" More info: https://git.k8s.io/enhancements/keps/sig-network/0007-pod-ready%2B%2B.md
+    #[serde(rename = "readinessGates", skip_serializing_if = "Option::is_none")]
+    pub readiness_gates: Option<Vec<crate::codegen::io::k8s::api::core::v1::pod_readiness_gate::PodReadinessGate>>,
+    /// Restart policy for all containers within the pod. One of Always, OnFailure, Never. Default to Always. More info: https://kubernetes.io/docs/concepts/workloads/pods/pod-lifecycle/#restart-policy
+    #[serde(rename = "restartPolicy", skip_serializing_if = "Option::is_none")]
+    pub restart_policy: Option<String>,
+    /// RuntimeClassName refers to a RuntimeClass object in the node.k8s.io group, which should be used to run this pod.  If no RuntimeClass resource matches the named class, the pod will not be run. If unset or empty, the "legacy" RuntimeClass will be used, which is an implicit class with an empty definition that uses the default runtime handler. More info: https://git.k8s.io/enhancements/keps/sig-node/runtime-class.md This is a beta feature as of Kubernetes v1.14.
+    #[serde(rename = "runtimeClassName", skip_serializing_if = "Option::is_none")]
+    pub runtime_class_name: Option<String>,
+    /// If specified, the pod will be dispatched by specified scheduler. If not specified, the pod will be dispatched by default scheduler.
+    #[serde(rename = "schedulerName", skip_serializing_if = "Option::is_none")]
+    pub scheduler_name: Option<String>,
+    /// SecurityContext holds pod-level security attributes and common container settings. Optional: Defaults to empty.  See type description for default values of each field.
+    #[serde(rename = "securityContext", skip_serializing_if = "Option::is_none")]
+    pub security_context: Option<crate::codegen::io::k8s::api::core::v1::pod_security_context::PodSecurityContext>,
+    /// DeprecatedServiceAccount is a depreciated alias for ServiceAccountName. Deprecated: Use serviceAccountName instead.
+    #[serde(rename = "serviceAccount", skip_serializing_if = "Option::is_none")]
+    pub service_account: Option<String>,
+    /// ServiceAccountName is the name of the ServiceAccount to use to run this pod. More info: https://kubernetes.io/docs/tasks/configure-pod-container/configure-service-account/
+    #[serde(rename = "serviceAccountName", skip_serializing_if = "Option::is_none")]
+    pub service_account_name: Option<String>,
+    /// Share a single process namespace between all of the containers in a pod. When this is set containers will be able to view and signal processes from other containers in the same pod, and the first process in each container will not be assigned PID 1. HostPID and ShareProcessNamespace cannot both be set. Optional: Default to false. This field is beta-level and may be disabled with the PodShareProcessNamespace feature.
+    #[serde(rename = "shareProcessNamespace", skip_serializing_if = "Option::is_none")]
+    pub share_process_namespace: Option<bool>,
+    /// If specified, the fully qualified Pod hostname will be "\<hostname>.\<subdomain>.\<pod namespace>.svc.\<cluster domain>". If not specified, the pod will not have a domainname at all.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subdomain: Option<String>,
+    /// Optional duration in seconds the pod needs to terminate gracefully. May be decreased in delete request. Value must be non-negative integer. The value zero indicates delete immediately. If this value is nil, the default grace period will be used instead. The grace period is the duration in seconds after the processes running in the pod are sent a termination signal and the time when the processes are forcibly halted with a kill signal. Set this value longer than the expected cleanup time for your process. Defaults to 30 seconds.
+    #[serde(rename = "terminationGracePeriodSeconds", skip_serializing_if = "Option::is_none")]
+    pub termination_grace_period_seconds: Option<i64>,
+    /// If specified, the pod's tolerations.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tolerations: Option<Vec<crate::codegen::io::k8s::api::core::v1::toleration::Toleration>>,
+    /// List of volumes that can be mounted by containers belonging to the pod. More info: https://kubernetes.io/docs/concepts/storage/volumes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub volumes: Option<Vec<crate::codegen::io::k8s::api::core::v1::volume::Volume>>,
+}
+
+impl PodSpec {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> PodSpecBuilder<crate::codegen::generics::MissingContainers> {
+        PodSpecBuilder {
+            body: Default::default(),
+            _containers: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> PodSpecBuilder<crate::codegen::generics::ContainersExists>
+    where
+        Self: Clone,
+    {
+        PodSpecBuilder {
+            body: self.clone(),
+            _containers: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<PodSpec> for PodSpecBuilder<crate::codegen::generics::ContainersExists> {
+    fn into(self) -> PodSpec {
+        self.body
+    }
+}
+
+/// Builder for [`PodSpec`](./struct.PodSpec.html) object.
+#[derive(Debug, Clone)]
+pub struct PodSpecBuilder<Containers> {
+    body: self::PodSpec,
+    _containers: core::marker::PhantomData<Containers>,
+}
+
+impl<Containers> PodSpecBuilder<Containers> {
+    /// Optional duration in seconds the pod may be active on the node relative to StartTime before the system will actively try to mark it failed and kill associated containers. Value must be a positive integer.
+    #[inline]
+    pub fn active_deadline_seconds(mut self, value: impl Into<i64>) -> Self {
+        self.body.active_deadline_seconds = Some(value.into());
+        self
+    }
+
+    /// Sets [`active_deadline_seconds`](#method.active_deadline_seconds) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_active_deadline_seconds(mut self, value: Option<impl Into<i64>>) -> Self {
+        self.body.active_deadline_seconds = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`active_deadline_seconds`](#method.active_deadline_seconds), but takes a reference.
+    #[inline]
+    pub fn active_deadline_seconds_ref(self, value: &i64) -> Self {
+        self.active_deadline_seconds(*value)
+    }
+
+    /// If specified, the pod's scheduling constraints
+    #[inline]
+    pub fn affinity(mut self, value: crate::codegen::io::k8s::api::core::v1::affinity::Affinity) -> Self {
+        self.body.affinity = Some(value.into());
+        self
+    }
+
+    /// Sets [`affinity`](#method.affinity) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_affinity(mut self, value: Option<crate::codegen::io::k8s::api::core::v1::affinity::Affinity>) -> Self {
+        self.body.affinity = value.map(|value| value.into());
+        self
+    }
+
+    /// AutomountServiceAccountToken indicates whether a service account token should be automatically mounted.
+    #[inline]
+    pub fn automount_service_account_token(mut self, value: impl Into<bool>) -> Self {
+        self.body.automount_service_account_token = Some(value.into());
+        self
+    }
+
+    /// Sets [`automount_service_account_token`](#method.automount_service_account_token) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_automount_service_account_token(mut self, value: Option<impl Into<bool>>) -> Self {
+        self.body.automount_service_account_token = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`automount_service_account_token`](#method.automount_service_account_token), but takes a reference.
+    #[inline]
+    pub fn automount_service_account_token_ref(self, value: &bool) -> Self {
+        self.automount_service_account_token(*value)
+    }
+
+    /// List of containers belonging to the pod. Containers cannot currently be added or removed. There must be at least one container in a Pod. Cannot be updated.
+    #[inline]
+    pub fn containers(mut self, value: impl Iterator<Item = crate::codegen::io::k8s::api::core::v1::container::ContainerBuilder<crate::codegen::generics::NameExists>>) -> PodSpecBuilder<crate::codegen::generics::ContainersExists> {
+        self.body.containers = value.map(|value| value.into()).collect::<Vec<_>>().into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Same as [`containers`](#method.containers), but takes the values as a `Vec`.
+    #[inline]
+    pub fn containers_vec(mut self, value: impl Into<Vec<crate::codegen::io::k8s::api::core::v1::container::Container>>) -> PodSpecBuilder<crate::codegen::generics::ContainersExists> {
+        self.body.containers = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Specifies the DNS parameters of a pod. Parameters specified here will be merged to the generated DNS configuration based on DNSPolicy.
+    #[inline]
+    pub fn dns_config(mut self, value: crate::codegen::io::k8s::api::core::v1::pod_dns_config::PodDnsConfig) -> Self {
+        self.body.dns_config = Some(value.into());
+        self
+    }
+
+    /// Sets [`dns_config`](#method.dns_config) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_dns_config(mut self, value: Option<crate::codegen::io::k8s::api::core::v1::pod_dns_config::PodDnsConfig>) -> Self {
+        self.body.dns_config = value.map(|value| value.into());
+        self
+    }
+
+    /// Set DNS policy for the pod. Defaults to "ClusterFirst". Valid values are 'ClusterFirstWithHostNet', 'ClusterFirst', 'Default' or 'None'. DNS parameters given in DNSConfig will be merged with the policy selected with DNSPolicy. To have DNS options set along with hostNetwork, you have to specify DNS policy explicitly to 'ClusterFirstWithHostNet'.
+    #[inline]
+    pub fn dns_policy(mut self, value: impl Into<String>) -> Self {
+        self.body.dns_policy = Some(value.into());
+        self
+    }
+
+    /// Sets [`dns_policy`](#method.dns_policy) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_dns_policy(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.dns_policy = value.map(|value| value.into());
+        self
+    }
+
+    /// EnableServiceLinks indicates whether information about services should be injected into pod's environment variables, matching the syntax of Docker links. Optional: Defaults to true.
+    #[inline]
+    pub fn enable_service_links(mut self, value: impl Into<bool>) -> Self {
+        self.body.enable_service_links = Some(value.into());
+        self
+    }
+
+    /// Sets [`enable_service_links`](#method.enable_service_links) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_enable_service_links(mut self, value: Option<impl Into<bool>>) -> Self {
+        self.body.enable_service_links = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`enable_service_links`](#method.enable_service_links), but takes a reference.
+    #[inline]
+    pub fn enable_service_links_ref(self, value: &bool) -> Self {
+        self.enable_service_links(*value)
+    }
+
+    /// HostAliases is an optional list of hosts and IPs that will be injected into the pod's hosts file if specified. This is only valid for non-hostNetwork pods.
+    #[inline]
+    pub fn host_aliases(mut self, value: impl Iterator<Item = crate::codegen::io::k8s::api::core::v1::host_alias::HostAlias>) -> Self {
+        self.body.host_aliases = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`host_aliases`](#method.host_aliases) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_host_aliases(mut self, value: Option<impl Iterator<Item = crate::codegen::io::k8s::api::core::v1::host_alias::HostAlias>>) -> Self {
+        self.body.host_aliases = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`host_aliases`](#method.host_aliases), but takes the values as a `Vec`.
+    #[inline]
+    pub fn host_aliases_vec(mut self, value: impl Into<Vec<crate::codegen::io::k8s::api::core::v1::host_alias::HostAlias>>) -> Self {
+        self.body.host_aliases = Some(value.into());
+        self
+    }
+
+    /// Use the host's ipc namespace. Optional: Default to false.
+    #[inline]
+    pub fn host_ipc(mut self, value: impl Into<bool>) -> Self {
+        self.body.host_ipc = Some(value.into());
+        self
+    }
+
+    /// Sets [`host_ipc`](#method.host_ipc) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_host_ipc(mut self, value: Option<impl Into<bool>>) -> Self {
+        self.body.host_ipc = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`host_ipc`](#method.host_ipc), but takes a reference.
+    #[inline]
+    pub fn host_ipc_ref(self, value: &bool) -> Self {
+        self.host_ipc(*value)
+    }
+
+    /// Host networking requested for this pod. Use the host's network namespace. If this option is set, the ports that will be used must be specified. Default to false.
+    #[inline]
+    pub fn host_network(mut self, value: impl Into<bool>) -> Self {
+        self.body.host_network = Some(value.into());
+        self
+    }
+
+    /// Sets [`host_network`](#method.host_network) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_host_network(mut self, value: Option<impl Into<bool>>) -> Self {
+        self.body.host_network = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`host_network`](#method.host_network), but takes a reference.
+    #[inline]
+    pub fn host_network_ref(self, value: &bool) -> Self {
+        self.host_network(*value)
+    }
+
+    /// Use the host's pid namespace. Optional: Default to false.
+    #[inline]
+    pub fn host_pid(mut self, value: impl Into<bool>) -> Self {
+        self.body.host_pid = Some(value.into());
+        self
+    }
+
+    /// Sets [`host_pid`](#method.host_pid) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_host_pid(mut self, value: Option<impl Into<bool>>) -> Self {
+        self.body.host_pid = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`host_pid`](#method.host_pid), but takes a reference.
+    #[inline]
+    pub fn host_pid_ref(self, value: &bool) -> Self {
+        self.host_pid(*value)
+    }
+
+    /// Specifies the hostname of the Pod If not specified, the pod's hostname will be set to a system-defined value.
+    #[inline]
+    pub fn hostname(mut self, value: impl Into<String>) -> Self {
+        self.body.hostname = Some(value.into());
+        self
+    }
+
+    /// Sets [`hostname`](#method.hostname) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_hostname(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.hostname = value.map(|value| value.into());
+        self
+    }
+
+    /// ImagePullSecrets is an optional list of references to secrets in the same namespace to use for pulling any of the images used by this PodSpec. If specified, these secrets will be passed to individual puller implementations for them to use. For example, in the case of docker, only DockerConfig type secrets are honored. More info: https://kubernetes.io/docs/concepts/containers/images#specifying-imagepullsecrets-on-a-pod
+    #[inline]
+    pub fn image_pull_secrets(mut self, value: impl Iterator<Item = crate::codegen::io::k8s::api::core::v1::local_object_reference::LocalObjectReference>) -> Self {
+        self.body.image_pull_secrets = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`image_pull_secrets`](#method.image_pull_secrets) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_image_pull_secrets(mut self, value: Option<impl Iterator<Item = crate::codegen::io::k8s::api::core::v1::local_object_reference::LocalObjectReference>>) -> Self {
+        self.body.image_pull_secrets = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`image_pull_secrets`](#method.image_pull_secrets), but takes the values as a `Vec`.
+    #[inline]
+    pub fn image_pull_secrets_vec(mut self, value: impl Into<Vec<crate::codegen::io::k8s::api::core::v1::local_object_reference::LocalObjectReference>>) -> Self {
+        self.body.image_pull_secrets = Some(value.into());
+        self
+    }
+
+    /// List of initialization containers belonging to the pod. Init containers are executed in order prior to containers being started. If any init container fails, the pod is considered to have failed and is handled according to its restartPolicy. The name for an init container or normal container must be unique among all containers. Init containers may not have Lifecycle actions, Readiness probes, or Liveness probes. The resourceRequirements of an init container are taken into account during scheduling by finding the highest request/limit for each resource type, and then using the max of of that value or the sum of the normal containers. Limits are applied to init containers in a similar fashion. Init containers cannot currently be added or removed. Cannot be updated. More info: https://kubernetes.io/docs/concepts/workloads/pods/init-containers/
+    #[inline]
+    pub fn init_containers(mut self, value: impl Iterator<Item = crate::codegen::io::k8s::api::core::v1::container::ContainerBuilder<crate::codegen::generics::NameExists>>) -> Self {
+        self.body.init_containers = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`init_containers`](#method.init_containers) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_init_containers(mut self, value: Option<impl Iterator<Item = crate::codegen::io::k8s::api::core::v1::container::ContainerBuilder<crate::codegen::generics::NameExists>>>) -> Self {
+        self.body.init_containers = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`init_containers`](#method.init_containers), but takes the values as a `Vec`.
+    #[inline]
+    pub fn init_containers_vec(mut self, value: impl Into<Vec<crate::codegen::io::k8s::api::core::v1::container::Container>>) -> Self {
+        self.body.init_containers = Some(value.into());
+        self
+    }
+
+    /// NodeName is a request to schedule this pod onto a specific node. If it is non-empty, the scheduler simply schedules this pod onto that node, assuming that it fits resource requirements.
+    #[inline]
+    pub fn node_name(mut self, value: impl Into<String>) -> Self {
+        self.body.node_name = Some(value.into());
+        self
+    }
+
+    /// Sets [`node_name`](#method.node_name) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_node_name(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.node_name = value.map(|value| value.into());
+        self
+    }
+
+    /// NodeSelector is a selector which must be true for the pod to fit on a node. Selector which must match a node's labels for the pod to be scheduled on that node. More info: https://kubernetes.io/docs/concepts/configuration/assign-pod-node/
+    #[inline]
+    pub fn node_selector(mut self, value: impl Iterator<Item = (String, impl Into<String>)>) -> Self {
+        self.body.node_selector = Some(value.map(|(key, value)| (key, value.into())).collect::<std::collections::BTreeMap<_, _>>().into());
+        self
+    }
+
+    /// Sets [`node_selector`](#method.node_selector) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_node_selector(mut self, value: Option<impl Iterator<Item = (String, impl Into<String>)>>) -> Self {
+        self.body.node_selector = value.map(|value| value.map(|(key, value)| (key, value.into())).collect::<std::collections::BTreeMap<_, _>>().into());
+        self
+    }
+
+    /// The priority value. Various system components use this field to find the priority of the pod. When Priority Admission Controller is enabled, it prevents users from setting this field. The admission controller populates this field from PriorityClassName. The higher the value, the higher the priority.
+    #[inline]
+    pub fn priority(mut self, value: impl Into<i32>) -> Self {
+        self.body.priority = Some(value.into());
+        self
+    }
+
+    /// Sets [`priority`](#method.priority) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_priority(mut self, value: Option<impl Into<i32>>) -> Self {
+        self.body.priority = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`priority`](#method.priority), but takes a reference.
+    #[inline]
+    pub fn priority_ref(self, value: &i32) -> Self {
+        self.priority(*value)
+    }
+
+    /// If specified, indicates the pod's priority. "system-node-critical" and "system-cluster-critical" are two special keywords which indicate the highest priorities with the former being the highest priority. Any other name must be defined by creating a PriorityClass object with that name. If not specified, the pod priority will be default or zero if there is no default.
+    #[inline]
+    pub fn priority_class_name(mut self, value: impl Into<String>) -> Self {
+        self.body.priority_class_name = Some(value.into());
+        self
+    }
+
+    /// Sets [`priority_class_name`](#method.priority_class_name) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_priority_class_name(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.priority_class_name = value.map(|value| value.into());
+        self
+    }
+
+    /// If specified, all readiness gates will be evaluated for pod readiness. A pod is ready when all its containers are ready AND all conditions specified in the readiness gates have status equal to "True" More info: https://git.k8s.io/enhancements/keps/sig-network/0007-pod-ready%2B%2B.md
+    #[inline]
+    pub fn readiness_gates(mut self, value: impl Iterator<Item = crate::codegen::io::k8s::api::core::v1::pod_readiness_gate::PodReadinessGateBuilder<crate::codegen::generics::ConditionTypeExists>>) -> Self {
+        self.body.readiness_gates = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`readiness_gates`](#method.readiness_gates) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_readiness_gates(mut self, value: Option<impl Iterator<Item = crate::codegen::io::k8s::api::core::v1::pod_readiness_gate::PodReadinessGateBuilder<crate::codegen::generics::ConditionTypeExists>>>) -> Self {
+        self.body.readiness_gates = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`readiness_gates`](#method.readiness_gates), but takes the values as a `Vec`.
+    #[inline]
+    pub fn readiness_gates_vec(mut self, value: impl Into<Vec<crate::codegen::io::k8s::api::core::v1::pod_readiness_gate::PodReadinessGate>>) -> Self {
+        self.body.readiness_gates = Some(value.into());
+        self
+    }
+
+    /// Restart policy for all containers within the pod. One of Always, OnFailure, Never. Default to Always. More info: https://kubernetes.io/docs/concepts/workloads/pods/pod-lifecycle/#restart-policy
+    #[inline]
+    pub fn restart_policy(mut self, value: impl Into<String>) -> Self {
+        self.body.restart_policy = Some(value.into());
+        self
+    }
+
+    /// Sets [`restart_policy`](#method.restart_policy) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_restart_policy(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.restart_policy = value.map(|value| value.into());
+        self
+    }
+
+    /// RuntimeClassName refers to a RuntimeClass object in the node.k8s.io group, which should be used to run this pod.  If no RuntimeClass resource matches the named class, the pod will not be run. If unset or empty, the "legacy" RuntimeClass will be used, which is an implicit class with an empty definition that uses the default runtime handler. More info: https://git.k8s.io/enhancements/keps/sig-node/runtime-class.md This is a beta feature as of Kubernetes v1.14.
+    #[inline]
+    pub fn runtime_class_name(mut self, value: impl Into<String>) -> Self {
+        self.body.runtime_class_name = Some(value.into());
+        self
+    }
+
+    /// Sets [`runtime_class_name`](#method.runtime_class_name) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_runtime_class_name(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.runtime_class_name = value.map(|value| value.into());
+        self
+    }
+
+    /// If specified, the pod will be dispatched by specified scheduler. If not specified, the pod will be dispatched by default scheduler.
+    #[inline]
+    pub fn scheduler_name(mut self, value: impl Into<String>) -> Self {
+        self.body.scheduler_name = Some(value.into());
+        self
+    }
+
+    /// Sets [`scheduler_name`](#method.scheduler_name) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_scheduler_name(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.scheduler_name = value.map(|value| value.into());
+        self
+    }
+
+    /// SecurityContext holds pod-level security attributes and common container settings. Optional: Defaults to empty.  See type description for default values of each field.
+    #[inline]
+    pub fn security_context(mut self, value: crate::codegen::io::k8s::api::core::v1::pod_security_context::PodSecurityContext) -> Self {
+        self.body.security_context = Some(value.into());
+        self
+    }
+
+    /// Sets [`security_context`](#method.security_context) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_security_context(mut self, value: Option<crate::codegen::io::k8s::api::core::v1::pod_security_context::PodSecurityContext>) -> Self {
+        self.body.security_context = value.map(|value| value.into());
+        self
+    }
+
+    /// DeprecatedServiceAccount is a depreciated alias for ServiceAccountName. Deprecated: Use serviceAccountName instead.
+    #[inline]
+    pub fn service_account(mut self, value: impl Into<String>) -> Self {
+        self.body.service_account = Some(value.into());
+        self
+    }
+
+    /// Sets [`service_account`](#method.service_account) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_service_account(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.service_account = value.map(|value| value.into());
+        self
+    }
+
+    /// ServiceAccountName is the name of the ServiceAccount to use to run this pod. More info: https://kubernetes.io/docs/tasks/configure-pod-container/configure-service-account/
+    #[inline]
+    pub fn service_account_name(mut self, value: impl Into<String>) -> Self {
+        self.body.service_account_name = Some(value.into());
+        self
+    }
+
+    /// Sets [`service_account_name`](#method.service_account_name) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_service_account_name(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.service_account_name = value.map(|value| value.into());
+        self
+    }
+
+    /// Share a single process namespace between all of the containers in a pod. When this is set containers will be able to view and signal processes from other containers in the same pod, and the first process in each container will not be assigned PID 1. HostPID and ShareProcessNamespace cannot both be set. Optional: Default to false. This field is beta-level and may be disabled with the PodShareProcessNamespace feature.
+    #[inline]
+    pub fn share_process_namespace(mut self, value: impl Into<bool>) -> Self {
+        self.body.share_process_namespace = Some(value.into());
+        self
+    }
+
+    /// Sets [`share_process_namespace`](#method.share_process_namespace) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_share_process_namespace(mut self, value: Option<impl Into<bool>>) -> Self {
+        self.body.share_process_namespace = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`share_process_namespace`](#method.share_process_namespace), but takes a reference.
+    #[inline]
+    pub fn share_process_namespace_ref(self, value: &bool) -> Self {
+        self.share_process_namespace(*value)
+    }
+
+    /// If specified, the fully qualified Pod hostname will be "\<hostname>.\<subdomain>.\<pod namespace>.svc.\<cluster domain>". If not specified, the pod will not have a domainname at all.
+    #[inline]
+    pub fn subdomain(mut self, value: impl Into<String>) -> Self {
+        self.body.subdomain = Some(value.into());
+        self
+    }
+
+    /// Sets [`subdomain`](#method.subdomain) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_subdomain(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.subdomain = value.map(|value| value.into());
+        self
+    }
+
+    /// Optional duration in seconds the pod needs to terminate gracefully. May be decreased in delete request. Value must be non-negative integer. The value zero indicates delete immediately. If this value is nil, the default grace period will be used instead. The grace period is the duration in seconds after the processes running in the pod are sent a termination signal and the time when the processes are forcibly halted with a kill signal. Set this value longer than the expected cleanup time for your process. Defaults to 30 seconds.
+    #[inline]
+    pub fn termination_grace_period_seconds(mut self, value: impl Into<i64>) -> Self {
+        self.body.termination_grace_period_seconds = Some(value.into());
+        self
+    }
+
+    /// Sets [`termination_grace_period_seconds`](#method.termination_grace_period_seconds) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_termination_grace_period_seconds(mut self, value: Option<impl Into<i64>>) -> Self {
+        self.body.termination_grace_period_seconds = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`termination_grace_period_seconds`](#method.termination_grace_period_seconds), but takes a reference.
+    #[inline]
+    pub fn termination_grace_period_seconds_ref(self, value: &i64) -> Self {
+        self.termination_grace_period_seconds(*value)
+    }
+
+    /// If specified, the pod's tolerations.
+    #[inline]
+    pub fn tolerations(mut self, value: impl Iterator<Item = crate::codegen::io::k8s::api::core::v1::toleration::Toleration>) -> Self {
+        self.body.tolerations = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`tolerations`](#method.tolerations) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_tolerations(mut self, value: Option<impl Iterator<Item = crate::codegen::io::k8s::api::core::v1::toleration::Toleration>>) -> Self {
+        self.body.tolerations = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`tolerations`](#method.tolerations), but takes the values as a `Vec`.
+    #[inline]
+    pub fn tolerations_vec(mut self, value: impl Into<Vec<crate::codegen::io::k8s::api::core::v1::toleration::Toleration>>) -> Self {
+        self.body.tolerations = Some(value.into());
+        self
+    }
+
+    /// List of volumes that can be mounted by containers belonging to the pod. More info: https://kubernetes.io/docs/concepts/storage/volumes
+    #[inline]
+    pub fn volumes(mut self, value: impl Iterator<Item = crate::codegen::io::k8s::api::core::v1::volume::VolumeBuilder<crate::codegen::generics::NameExists>>) -> Self {
+        self.body.volumes = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`volumes`](#method.volumes) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_volumes(mut self, value: Option<impl Iterator<Item = crate::codegen::io::k8s::api::core::v1::volume::VolumeBuilder<crate::codegen::generics::NameExists>>>) -> Self {
+        self.body.volumes = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`volumes`](#method.volumes), but takes the values as a `Vec`.
+    #[inline]
+    pub fn volumes_vec(mut self, value: impl Into<Vec<crate::codegen::io::k8s::api::core::v1::volume::Volume>>) -> Self {
+        self.body.volumes = Some(value.into());
+        self
+    }
+}
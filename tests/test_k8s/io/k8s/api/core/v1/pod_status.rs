@@ -0,0 +1,256 @@
+
+/// PodStatus represents information about the status of a pod. Status may trail the actual state of a system, especially if the node that hosts the pod cannot contact the control plane.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct PodStatus {
+    /// Current service state of pod. More info: https://kubernetes.io/docs/concepts/workloads/pods/pod-lifecycle#pod-conditions
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub conditions: Option<Vec<crate::codegen::io::k8s::api::core::v1::pod_condition::PodCondition>>,
+    /// The list has one entry per container in the manifest. Each entry is currently the output of `docker inspect`. More info: https://kubernetes.io/docs/concepts/workloads/pods/pod-lifecycle#pod-and-container-status
+    #[serde(rename = "containerStatuses", skip_serializing_if = "Option::is_none")]
+    pub container_statuses: Option<Vec<crate::codegen::io::k8s::api::core::v1::container_status::ContainerStatus>>,
+    /// IP address of the host to which the pod is assigned. Empty if not yet scheduled.
+    #[serde(rename = "hostIP", skip_serializing_if = "Option::is_none")]
+    pub host_ip: Option<String>,
+    /// The list has one entry per init container in the manifest. The most recent successful init container will have ready = true, the most recently started container will have startTime set. More info: https://kubernetes.io/docs/concepts/workloads/pods/pod-lifecycle#pod-and-container-status
+    #[serde(rename = "initContainerStatuses", skip_serializing_if = "Option::is_none")]
+    pub init_container_statuses: Option<Vec<crate::codegen::io::k8s::api::core::v1::container_status::ContainerStatus>>,
+    /// A human readable message indicating details about why the pod is in this condition.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    /// nominatedNodeName is set only when this pod preempts other pods on the node, but it cannot be scheduled right away as preemption victims receive their graceful termination periods. This field does not guarantee that the pod will be scheduled on this node. Scheduler may decide to place the pod elsewhere if other nodes become available sooner. Scheduler may also decide to give the resources on this node to a higher priority pod that is created after preemption. As a result, this field may be different than PodSpec.nodeName when the pod is scheduled.
+    #[serde(rename = "nominatedNodeName", skip_serializing_if = "Option::is_none")]
+    pub nominated_node_name: Option<String>,
+    /// The phase of a Pod is a simple, high-level summary of where the Pod is in its lifecycle. The conditions array, the reason and message fields, and the individual container status arrays contain more detail about the pod's status. There are five possible phase values:
+    ///
+    /// Pending: The pod has been accepted by the Kubernetes system, but one or more of the container images has not been created. This includes time before being scheduled as well as time spent downloading images over the network, which could take a while. Running: The pod has been bound to a node, and all of the containers have been created. At least one container is still running, or is in the process of starting or restarting. Succeeded: All containers in the pod have terminated in success, and will not be restarted. Failed: All containers in the pod have terminated, and at least one container has terminated in failure. The container either exited with non-zero status or was terminated by the system. Unknown: For some reason the state of the pod could not be obtained, typically due to an error in communicating with the host of the pod.
+    ///
+    /// More info: https://kubernetes.io/docs/concepts/workloads/pods/pod-lifecycle#pod-phase
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phase: Option<String>,
+    /// IP address allocated to the pod. Routable at least within the cluster. Empty if not yet allocated.
+    #[serde(rename = "podIP", skip_serializing_if = "Option::is_none")]
+    pub pod_ip: Option<String>,
+    /// The Quality of Service (QOS) classification assigned to the pod based on resource requirements See PodQOSClass type for available QOS classes More info: https://git.k8s.io/community/contributors/design-proposals/node/resource-qos.md
+    #[serde(rename = "qosClass", skip_serializing_if = "Option::is_none")]
+    pub qos_class: Option<String>,
+    /// A brief CamelCase message indicating details about why the pod is in this state. e.g. 'Evicted'
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+    /// RFC 3339 date and time at which the object was acknowledged by the Kubelet. This is before the Kubelet pulled the container image(s) for the pod.
+    #[serde(rename = "startTime", skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<String>,
+}
+
+impl PodStatus {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> PodStatusBuilder {
+        PodStatusBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> PodStatusBuilder
+    where
+        Self: Clone,
+    {
+        PodStatusBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<PodStatus> for PodStatusBuilder {
+    fn into(self) -> PodStatus {
+        self.body
+    }
+}
+
+/// Builder for [`PodStatus`](./struct.PodStatus.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct PodStatusBuilder {
+    body: self::PodStatus,
+}
+
+impl PodStatusBuilder {
+    /// Current service state of pod. More info: https://kubernetes.io/docs/concepts/workloads/pods/pod-lifecycle#pod-conditions
+    #[inline]
+    pub fn conditions(mut self, value: impl Iterator<Item = crate::codegen::io::k8s::api::core::v1::pod_condition::PodConditionBuilder<crate::codegen::generics::StatusExists, crate::codegen::generics::TypeExists>>) -> Self {
+        self.body.conditions = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`conditions`](#method.conditions) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_conditions(mut self, value: Option<impl Iterator<Item = crate::codegen::io::k8s::api::core::v1::pod_condition::PodConditionBuilder<crate::codegen::generics::StatusExists, crate::codegen::generics::TypeExists>>>) -> Self {
+        self.body.conditions = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`conditions`](#method.conditions), but takes the values as a `Vec`.
+    #[inline]
+    pub fn conditions_vec(mut self, value: impl Into<Vec<crate::codegen::io::k8s::api::core::v1::pod_condition::PodCondition>>) -> Self {
+        self.body.conditions = Some(value.into());
+        self
+    }
+
+    /// The list has one entry per container in the manifest. Each entry is currently the output of `docker inspect`. More info: https://kubernetes.io/docs/concepts/workloads/pods/pod-lifecycle#pod-and-container-status
+    #[inline]
+    pub fn container_statuses(mut self, value: impl Iterator<Item = crate::codegen::io::k8s::api::core::v1::container_status::ContainerStatusBuilder<crate::codegen::generics::ImageExists, crate::codegen::generics::ImageIdExists, crate::codegen::generics::NameExists, crate::codegen::generics::ReadyExists, crate::codegen::generics::RestartCountExists>>) -> Self {
+        self.body.container_statuses = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`container_statuses`](#method.container_statuses) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_container_statuses(mut self, value: Option<impl Iterator<Item = crate::codegen::io::k8s::api::core::v1::container_status::ContainerStatusBuilder<crate::codegen::generics::ImageExists, crate::codegen::generics::ImageIdExists, crate::codegen::generics::NameExists, crate::codegen::generics::ReadyExists, crate::codegen::generics::RestartCountExists>>>) -> Self {
+        self.body.container_statuses = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`container_statuses`](#method.container_statuses), but takes the values as a `Vec`.
+    #[inline]
+    pub fn container_statuses_vec(mut self, value: impl Into<Vec<crate::codegen::io::k8s::api::core::v1::container_status::ContainerStatus>>) -> Self {
+        self.body.container_statuses = Some(value.into());
+        self
+    }
+
+    /// IP address of the host to which the pod is assigned. Empty if not yet scheduled.
+    #[inline]
+    pub fn host_ip(mut self, value: impl Into<String>) -> Self {
+        self.body.host_ip = Some(value.into());
+        self
+    }
+
+    /// Sets [`host_ip`](#method.host_ip) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_host_ip(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.host_ip = value.map(|value| value.into());
+        self
+    }
+
+    /// The list has one entry per init container in the manifest. The most recent successful init container will have ready = true, the most recently started container will have startTime set. More info: https://kubernetes.io/docs/concepts/workloads/pods/pod-lifecycle#pod-and-container-status
+    #[inline]
+    pub fn init_container_statuses(mut self, value: impl Iterator<Item = crate::codegen::io::k8s::api::core::v1::container_status::ContainerStatusBuilder<crate::codegen::generics::ImageExists, crate::codegen::generics::ImageIdExists, crate::codegen::generics::NameExists, crate::codegen::generics::ReadyExists, crate::codegen::generics::RestartCountExists>>) -> Self {
+        self.body.init_container_statuses = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`init_container_statuses`](#method.init_container_statuses) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_init_container_statuses(mut self, value: Option<impl Iterator<Item = crate::codegen::io::k8s::api::core::v1::container_status::ContainerStatusBuilder<crate::codegen::generics::ImageExists, crate::codegen::generics::ImageIdExists, crate::codegen::generics::NameExists, crate::codegen::generics::ReadyExists, crate::codegen::generics::RestartCountExists>>>) -> Self {
+        self.body.init_container_statuses = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`init_container_statuses`](#method.init_container_statuses), but takes the values as a `Vec`.
+    #[inline]
+    pub fn init_container_statuses_vec(mut self, value: impl Into<Vec<crate::codegen::io::k8s::api::core::v1::container_status::ContainerStatus>>) -> Self {
+        self.body.init_container_statuses = Some(value.into());
+        self
+    }
+
+    /// A human readable message indicating details about why the pod is in this condition.
+    #[inline]
+    pub fn message(mut self, value: impl Into<String>) -> Self {
+        self.body.message = Some(value.into());
+        self
+    }
+
+    /// Sets [`message`](#method.message) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_message(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.message = value.map(|value| value.into());
+        self
+    }
+
+    /// nominatedNodeName is set only when this pod preempts other pods on the node, but it cannot be scheduled right away as preemption victims receive their graceful termination periods. This field does not guarantee that the pod will be scheduled on this node. Scheduler may decide to place the pod elsewhere if other nodes become available sooner. Scheduler may also decide to give the resources on this node to a higher priority pod that is created after preemption. As a result, this field may be different than PodSpec.nodeName when the pod is scheduled.
+    #[inline]
+    pub fn nominated_node_name(mut self, value: impl Into<String>) -> Self {
+        self.body.nominated_node_name = Some(value.into());
+        self
+    }
+
+    /// Sets [`nominated_node_name`](#method.nominated_node_name) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_nominated_node_name(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.nominated_node_name = value.map(|value| value.into());
+        self
+    }
+
+    /// The phase of a Pod is a simple, high-level summary of where the Pod is in its lifecycle. The conditions array, the reason and message fields, and the individual container status arrays contain more detail about the pod's status. There are five possible phase values:
+    ///
+    /// Pending: The pod has been accepted by the Kubernetes system, but one or more of the container images has not been created. This includes time before being scheduled as well as time spent downloading images over the network, which could take a while. Running: The pod has been bound to a node, and all of the containers have been created. At least one container is still running, or is in the process of starting or restarting. Succeeded: All containers in the pod have terminated in success, and will not be restarted. Failed: All containers in the pod have terminated, and at least one container has terminated in failure. The container either exited with non-zero status or was terminated by the system. Unknown: For some reason the state of the pod could not be obtained, typically due to an error in communicating with the host of the pod.
+    ///
+    /// More info: https://kubernetes.io/docs/concepts/workloads/pods/pod-lifecycle#pod-phase
+    #[inline]
+    pub fn phase(mut self, value: impl Into<String>) -> Self {
+        self.body.phase = Some(value.into());
+        self
+    }
+
+    /// Sets [`phase`](#method.phase) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_phase(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.phase = value.map(|value| value.into());
+        self
+    }
+
+    /// IP address allocated to the pod. Routable at least within the cluster. Empty if not yet allocated.
+    #[inline]
+    pub fn pod_ip(mut self, value: impl Into<String>) -> Self {
+        self.body.pod_ip = Some(value.into());
+        self
+    }
+
+    /// Sets [`pod_ip`](#method.pod_ip) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_pod_ip(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.pod_ip = value.map(|value| value.into());
+        self
+    }
+
+    /// The Quality of Service (QOS) classification assigned to the pod based on resource requirements See PodQOSClass type for available QOS classes More info: https://git.k8s.io/community/contributors/design-proposals/node/resource-qos.md
+    #[inline]
+    pub fn qos_class(mut self, value: impl Into<String>) -> Self {
+        self.body.qos_class = Some(value.into());
+        self
+    }
+
+    /// Sets [`qos_class`](#method.qos_class) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_qos_class(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.qos_class = value.map(|value| value.into());
+        self
+    }
+
+    /// A brief CamelCase message indicating details about why the pod is in this state. e.g. 'Evicted'
+    #[inline]
+    pub fn reason(mut self, value: impl Into<String>) -> Self {
+        self.body.reason = Some(value.into());
+        self
+    }
+
+    /// Sets [`reason`](#method.reason) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_reason(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.reason = value.map(|value| value.into());
+        self
+    }
+
+    /// RFC 3339 date and time at which the object was acknowledged by the Kubelet. This is before the Kubelet pulled the container image(s) for the pod.
+    #[inline]
+    pub fn start_time(mut self, value: impl Into<String>) -> Self {
+        self.body.start_time = Some(value.into());
+        self
+    }
+
+    /// Sets [`start_time`](#method.start_time) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_start_time(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.start_time = value.map(|value| value.into());
+        self
+    }
+}
@@ -0,0 +1,93 @@
+
+/// PortworxVolumeSource represents a Portworx volume resource.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct PortworxVolumeSource {
+    /// FSType represents the filesystem type to mount Must be a filesystem type supported by the host operating system. Ex. "ext4", "xfs". Implicitly inferred to be "ext4" if unspecified.
+    #[serde(rename = "fsType", skip_serializing_if = "Option::is_none")]
+    pub fs_type: Option<String>,
+    /// Defaults to false (read/write). ReadOnly here will force the ReadOnly setting in VolumeMounts.
+    #[serde(rename = "readOnly", skip_serializing_if = "Option::is_none")]
+    pub read_only: Option<bool>,
+    /// VolumeID uniquely identifies a Portworx volume
+    #[serde(rename = "volumeID")]
+    pub volume_id: String,
+}
+
+impl PortworxVolumeSource {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> PortworxVolumeSourceBuilder<crate::codegen::generics::MissingVolumeId> {
+        PortworxVolumeSourceBuilder {
+            body: Default::default(),
+            _volume_id: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> PortworxVolumeSourceBuilder<crate::codegen::generics::VolumeIdExists>
+    where
+        Self: Clone,
+    {
+        PortworxVolumeSourceBuilder {
+            body: self.clone(),
+            _volume_id: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<PortworxVolumeSource> for PortworxVolumeSourceBuilder<crate::codegen::generics::VolumeIdExists> {
+    fn into(self) -> PortworxVolumeSource {
+        self.body
+    }
+}
+
+/// Builder for [`PortworxVolumeSource`](./struct.PortworxVolumeSource.html) object.
+#[derive(Debug, Clone)]
+pub struct PortworxVolumeSourceBuilder<VolumeId> {
+    body: self::PortworxVolumeSource,
+    _volume_id: core::marker::PhantomData<VolumeId>,
+}
+
+impl<VolumeId> PortworxVolumeSourceBuilder<VolumeId> {
+    /// FSType represents the filesystem type to mount Must be a filesystem type supported by the host operating system. Ex. "ext4", "xfs". Implicitly inferred to be "ext4" if unspecified.
+    #[inline]
+    pub fn fs_type(mut self, value: impl Into<String>) -> Self {
+        self.body.fs_type = Some(value.into());
+        self
+    }
+
+    /// Sets [`fs_type`](#method.fs_type) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_fs_type(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.fs_type = value.map(|value| value.into());
+        self
+    }
+
+    /// Defaults to false (read/write). ReadOnly here will force the ReadOnly setting in VolumeMounts.
+    #[inline]
+    pub fn read_only(mut self, value: impl Into<bool>) -> Self {
+        self.body.read_only = Some(value.into());
+        self
+    }
+
+    /// Sets [`read_only`](#method.read_only) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_read_only(mut self, value: Option<impl Into<bool>>) -> Self {
+        self.body.read_only = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`read_only`](#method.read_only), but takes a reference.
+    #[inline]
+    pub fn read_only_ref(self, value: &bool) -> Self {
+        self.read_only(*value)
+    }
+
+    /// VolumeID uniquely identifies a Portworx volume
+    #[inline]
+    pub fn volume_id(mut self, value: impl Into<String>) -> PortworxVolumeSourceBuilder<crate::codegen::generics::VolumeIdExists> {
+        self.body.volume_id = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+}
@@ -0,0 +1,82 @@
+
+/// Represents a projected volume source
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct ProjectedVolumeSource {
+    /// Mode bits to use on created files by default. Must be a value between 0 and 0777. Directories within the path are not affected by this setting. This might be in conflict with other options that affect the file mode, like fsGroup, and the result can be other mode bits set.
+    #[serde(rename = "defaultMode", skip_serializing_if = "Option::is_none")]
+    pub default_mode: Option<i32>,
+    /// list of volume projections
+    pub sources: Vec<crate::codegen::io::k8s::api::core::v1::volume_projection::VolumeProjection>,
+}
+
+impl ProjectedVolumeSource {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> ProjectedVolumeSourceBuilder<crate::codegen::generics::MissingSources> {
+        ProjectedVolumeSourceBuilder {
+            body: Default::default(),
+            _sources: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> ProjectedVolumeSourceBuilder<crate::codegen::generics::SourcesExists>
+    where
+        Self: Clone,
+    {
+        ProjectedVolumeSourceBuilder {
+            body: self.clone(),
+            _sources: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<ProjectedVolumeSource> for ProjectedVolumeSourceBuilder<crate::codegen::generics::SourcesExists> {
+    fn into(self) -> ProjectedVolumeSource {
+        self.body
+    }
+}
+
+/// Builder for [`ProjectedVolumeSource`](./struct.ProjectedVolumeSource.html) object.
+#[derive(Debug, Clone)]
+pub struct ProjectedVolumeSourceBuilder<Sources> {
+    body: self::ProjectedVolumeSource,
+    _sources: core::marker::PhantomData<Sources>,
+}
+
+impl<Sources> ProjectedVolumeSourceBuilder<Sources> {
+    /// Mode bits to use on created files by default. Must be a value between 0 and 0777. Directories within the path are not affected by this setting. This might be in conflict with other options that affect the file mode, like fsGroup, and the result can be other mode bits set.
+    #[inline]
+    pub fn default_mode(mut self, value: impl Into<i32>) -> Self {
+        self.body.default_mode = Some(value.into());
+        self
+    }
+
+    /// Sets [`default_mode`](#method.default_mode) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_default_mode(mut self, value: Option<impl Into<i32>>) -> Self {
+        self.body.default_mode = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`default_mode`](#method.default_mode), but takes a reference.
+    #[inline]
+    pub fn default_mode_ref(self, value: &i32) -> Self {
+        self.default_mode(*value)
+    }
+
+    /// list of volume projections
+    #[inline]
+    pub fn sources(mut self, value: impl Iterator<Item = crate::codegen::io::k8s::api::core::v1::volume_projection::VolumeProjection>) -> ProjectedVolumeSourceBuilder<crate::codegen::generics::SourcesExists> {
+        self.body.sources = value.map(|value| value.into()).collect::<Vec<_>>().into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Same as [`sources`](#method.sources), but takes the values as a `Vec`.
+    #[inline]
+    pub fn sources_vec(mut self, value: impl Into<Vec<crate::codegen::io::k8s::api::core::v1::volume_projection::VolumeProjection>>) -> ProjectedVolumeSourceBuilder<crate::codegen::generics::SourcesExists> {
+        self.body.sources = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+}
@@ -0,0 +1,138 @@
+
+/// Represents a Quobyte mount that lasts the lifetime of a pod. Quobyte volumes do not support ownership management or SELinux relabeling.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct QuobyteVolumeSource {
+    /// Group to map volume access to Default is no group
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub group: Option<String>,
+    /// ReadOnly here will force the Quobyte volume to be mounted with read-only permissions. Defaults to false.
+    #[serde(rename = "readOnly", skip_serializing_if = "Option::is_none")]
+    pub read_only: Option<bool>,
+    /// Registry represents a single or multiple Quobyte Registry services specified as a string as host:port pair (multiple entries are separated with commas) which acts as the central registry for volumes
+    pub registry: String,
+    /// Tenant owning the given Quobyte volume in the Backend Used with dynamically provisioned Quobyte volumes, value is set by the plugin
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tenant: Option<String>,
+    /// User to map volume access to Defaults to serivceaccount user
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<String>,
+    /// Volume is a string that references an already created Quobyte volume by name.
+    pub volume: String,
+}
+
+impl QuobyteVolumeSource {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> QuobyteVolumeSourceBuilder<crate::codegen::generics::MissingRegistry, crate::codegen::generics::MissingVolume> {
+        QuobyteVolumeSourceBuilder {
+            body: Default::default(),
+            _registry: core::marker::PhantomData,
+            _volume: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> QuobyteVolumeSourceBuilder<crate::codegen::generics::RegistryExists, crate::codegen::generics::VolumeExists>
+    where
+        Self: Clone,
+    {
+        QuobyteVolumeSourceBuilder {
+            body: self.clone(),
+            _registry: core::marker::PhantomData,
+            _volume: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<QuobyteVolumeSource> for QuobyteVolumeSourceBuilder<crate::codegen::generics::RegistryExists, crate::codegen::generics::VolumeExists> {
+    fn into(self) -> QuobyteVolumeSource {
+        self.body
+    }
+}
+
+/// Builder for [`QuobyteVolumeSource`](./struct.QuobyteVolumeSource.html) object.
+#[derive(Debug, Clone)]
+pub struct QuobyteVolumeSourceBuilder<Registry, Volume> {
+    body: self::QuobyteVolumeSource,
+    _registry: core::marker::PhantomData<Registry>,
+    _volume: core::marker::PhantomData<Volume>,
+}
+
+impl<Registry, Volume> QuobyteVolumeSourceBuilder<Registry, Volume> {
+    /// Group to map volume access to Default is no group
+    #[inline]
+    pub fn group(mut self, value: impl Into<String>) -> Self {
+        self.body.group = Some(value.into());
+        self
+    }
+
+    /// Sets [`group`](#method.group) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_group(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.group = value.map(|value| value.into());
+        self
+    }
+
+    /// ReadOnly here will force the Quobyte volume to be mounted with read-only permissions. Defaults to false.
+    #[inline]
+    pub fn read_only(mut self, value: impl Into<bool>) -> Self {
+        self.body.read_only = Some(value.into());
+        self
+    }
+
+    /// Sets [`read_only`](#method.read_only) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_read_only(mut self, value: Option<impl Into<bool>>) -> Self {
+        self.body.read_only = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`read_only`](#method.read_only), but takes a reference.
+    #[inline]
+    pub fn read_only_ref(self, value: &bool) -> Self {
+        self.read_only(*value)
+    }
+
+    /// Registry represents a single or multiple Quobyte Registry services specified as a string as host:port pair (multiple entries are separated with commas) which acts as the central registry for volumes
+    #[inline]
+    pub fn registry(mut self, value: impl Into<String>) -> QuobyteVolumeSourceBuilder<crate::codegen::generics::RegistryExists, Volume> {
+        self.body.registry = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Tenant owning the given Quobyte volume in the Backend Used with dynamically provisioned Quobyte volumes, value is set by the plugin
+    #[inline]
+    pub fn tenant(mut self, value: impl Into<String>) -> Self {
+        self.body.tenant = Some(value.into());
+        self
+    }
+
+    /// Sets [`tenant`](#method.tenant) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_tenant(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.tenant = value.map(|value| value.into());
+        self
+    }
+
+    /// User to map volume access to Defaults to serivceaccount user
+    #[inline]
+    pub fn user(mut self, value: impl Into<String>) -> Self {
+        self.body.user = Some(value.into());
+        self
+    }
+
+    /// Sets [`user`](#method.user) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_user(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.user = value.map(|value| value.into());
+        self
+    }
+
+    /// Volume is a string that references an already created Quobyte volume by name.
+    #[inline]
+    pub fn volume(mut self, value: impl Into<String>) -> QuobyteVolumeSourceBuilder<Registry, crate::codegen::generics::VolumeExists> {
+        self.body.volume = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+}
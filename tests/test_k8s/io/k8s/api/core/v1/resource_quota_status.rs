@@ -0,0 +1,74 @@
+
+/// ResourceQuotaStatus defines the enforced hard limits and observed use.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct ResourceQuotaStatus {
+    /// Hard is the set of enforced hard limits for each named resource. More info: https://kubernetes.io/docs/concepts/policy/resource-quotas/
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hard: Option<std::collections::BTreeMap<String, String>>,
+    /// Used is the current observed total usage of the resource in the namespace.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub used: Option<std::collections::BTreeMap<String, String>>,
+}
+
+impl ResourceQuotaStatus {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> ResourceQuotaStatusBuilder {
+        ResourceQuotaStatusBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> ResourceQuotaStatusBuilder
+    where
+        Self: Clone,
+    {
+        ResourceQuotaStatusBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<ResourceQuotaStatus> for ResourceQuotaStatusBuilder {
+    fn into(self) -> ResourceQuotaStatus {
+        self.body
+    }
+}
+
+/// Builder for [`ResourceQuotaStatus`](./struct.ResourceQuotaStatus.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct ResourceQuotaStatusBuilder {
+    body: self::ResourceQuotaStatus,
+}
+
+impl ResourceQuotaStatusBuilder {
+    /// Hard is the set of enforced hard limits for each named resource. More info: https://kubernetes.io/docs/concepts/policy/resource-quotas/
+    #[inline]
+    pub fn hard(mut self, value: impl Iterator<Item = (String, impl Into<String>)>) -> Self {
+        self.body.hard = Some(value.map(|(key, value)| (key, value.into())).collect::<std::collections::BTreeMap<_, _>>().into());
+        self
+    }
+
+    /// Sets [`hard`](#method.hard) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_hard(mut self, value: Option<impl Iterator<Item = (String, impl Into<String>)>>) -> Self {
+        self.body.hard = value.map(|value| value.map(|(key, value)| (key, value.into())).collect::<std::collections::BTreeMap<_, _>>().into());
+        self
+    }
+
+    /// Used is the current observed total usage of the resource in the namespace.
+    #[inline]
+    pub fn used(mut self, value: impl Iterator<Item = (String, impl Into<String>)>) -> Self {
+        self.body.used = Some(value.map(|(key, value)| (key, value.into())).collect::<std::collections::BTreeMap<_, _>>().into());
+        self
+    }
+
+    /// Sets [`used`](#method.used) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_used(mut self, value: Option<impl Iterator<Item = (String, impl Into<String>)>>) -> Self {
+        self.body.used = value.map(|value| value.map(|(key, value)| (key, value.into())).collect::<std::collections::BTreeMap<_, _>>().into());
+        self
+    }
+}
@@ -0,0 +1,74 @@
+
+/// ResourceRequirements describes the compute resource requirements.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct ResourceRequirements {
+    /// Limits describes the maximum amount of compute resources allowed. More info: https://kubernetes.io/docs/concepts/configuration/manage-compute-resources-container/
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limits: Option<std::collections::BTreeMap<String, String>>,
+    /// Requests describes the minimum amount of compute resources required. If Requests is omitted for a container, it defaults to Limits if that is explicitly specified, otherwise to an implementation-defined value. More info: https://kubernetes.io/docs/concepts/configuration/manage-compute-resources-container/
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub requests: Option<std::collections::BTreeMap<String, String>>,
+}
+
+impl ResourceRequirements {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> ResourceRequirementsBuilder {
+        ResourceRequirementsBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> ResourceRequirementsBuilder
+    where
+        Self: Clone,
+    {
+        ResourceRequirementsBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<ResourceRequirements> for ResourceRequirementsBuilder {
+    fn into(self) -> ResourceRequirements {
+        self.body
+    }
+}
+
+/// Builder for [`ResourceRequirements`](./struct.ResourceRequirements.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct ResourceRequirementsBuilder {
+    body: self::ResourceRequirements,
+}
+
+impl ResourceRequirementsBuilder {
+    /// Limits describes the maximum amount of compute resources allowed. More info: https://kubernetes.io/docs/concepts/configuration/manage-compute-resources-container/
+    #[inline]
+    pub fn limits(mut self, value: impl Iterator<Item = (String, impl Into<String>)>) -> Self {
+        self.body.limits = Some(value.map(|(key, value)| (key, value.into())).collect::<std::collections::BTreeMap<_, _>>().into());
+        self
+    }
+
+    /// Sets [`limits`](#method.limits) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_limits(mut self, value: Option<impl Iterator<Item = (String, impl Into<String>)>>) -> Self {
+        self.body.limits = value.map(|value| value.map(|(key, value)| (key, value.into())).collect::<std::collections::BTreeMap<_, _>>().into());
+        self
+    }
+
+    /// Requests describes the minimum amount of compute resources required. If Requests is omitted for a container, it defaults to Limits if that is explicitly specified, otherwise to an implementation-defined value. More info: https://kubernetes.io/docs/concepts/configuration/manage-compute-resources-container/
+    #[inline]
+    pub fn requests(mut self, value: impl Iterator<Item = (String, impl Into<String>)>) -> Self {
+        self.body.requests = Some(value.map(|(key, value)| (key, value.into())).collect::<std::collections::BTreeMap<_, _>>().into());
+        self
+    }
+
+    /// Sets [`requests`](#method.requests) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_requests(mut self, value: Option<impl Iterator<Item = (String, impl Into<String>)>>) -> Self {
+        self.body.requests = value.map(|value| value.map(|(key, value)| (key, value.into())).collect::<std::collections::BTreeMap<_, _>>().into());
+        self
+    }
+}
@@ -0,0 +1,89 @@
+
+/// A scoped-resource selector requirement is a selector that contains values, a scope name, and an operator that relates the scope name and values.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct ScopedResourceSelectorRequirement {
+    /// Represents a scope's relationship to a set of values. Valid operators are In, NotIn, Exists, DoesNotExist.
+    pub operator: String,
+    /// The name of the scope that the selector applies to.
+    #[serde(rename = "scopeName")]
+    pub scope_name: String,
+    /// An array of string values. If the operator is In or NotIn, the values array must be non-empty. If the operator is Exists or DoesNotExist, the values array must be empty. This array is replaced during a strategic merge patch.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub values: Option<Vec<String>>,
+}
+
+impl ScopedResourceSelectorRequirement {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> ScopedResourceSelectorRequirementBuilder<crate::codegen::generics::MissingOperator, crate::codegen::generics::MissingScopeName> {
+        ScopedResourceSelectorRequirementBuilder {
+            body: Default::default(),
+            _operator: core::marker::PhantomData,
+            _scope_name: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> ScopedResourceSelectorRequirementBuilder<crate::codegen::generics::OperatorExists, crate::codegen::generics::ScopeNameExists>
+    where
+        Self: Clone,
+    {
+        ScopedResourceSelectorRequirementBuilder {
+            body: self.clone(),
+            _operator: core::marker::PhantomData,
+            _scope_name: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<ScopedResourceSelectorRequirement> for ScopedResourceSelectorRequirementBuilder<crate::codegen::generics::OperatorExists, crate::codegen::generics::ScopeNameExists> {
+    fn into(self) -> ScopedResourceSelectorRequirement {
+        self.body
+    }
+}
+
+/// Builder for [`ScopedResourceSelectorRequirement`](./struct.ScopedResourceSelectorRequirement.html) object.
+#[derive(Debug, Clone)]
+pub struct ScopedResourceSelectorRequirementBuilder<Operator, ScopeName> {
+    body: self::ScopedResourceSelectorRequirement,
+    _operator: core::marker::PhantomData<Operator>,
+    _scope_name: core::marker::PhantomData<ScopeName>,
+}
+
+impl<Operator, ScopeName> ScopedResourceSelectorRequirementBuilder<Operator, ScopeName> {
+    /// Represents a scope's relationship to a set of values. Valid operators are In, NotIn, Exists, DoesNotExist.
+    #[inline]
+    pub fn operator(mut self, value: impl Into<String>) -> ScopedResourceSelectorRequirementBuilder<crate::codegen::generics::OperatorExists, ScopeName> {
+        self.body.operator = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// The name of the scope that the selector applies to.
+    #[inline]
+    pub fn scope_name(mut self, value: impl Into<String>) -> ScopedResourceSelectorRequirementBuilder<Operator, crate::codegen::generics::ScopeNameExists> {
+        self.body.scope_name = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// An array of string values. If the operator is In or NotIn, the values array must be non-empty. If the operator is Exists or DoesNotExist, the values array must be empty. This array is replaced during a strategic merge patch.
+    #[inline]
+    pub fn values(mut self, value: impl Iterator<Item = impl Into<String>>) -> Self {
+        self.body.values = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`values`](#method.values) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_values(mut self, value: Option<impl Iterator<Item = impl Into<String>>>) -> Self {
+        self.body.values = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`values`](#method.values), but takes the values as a `Vec`.
+    #[inline]
+    pub fn values_vec(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.body.values = Some(value.into());
+        self
+    }
+}
@@ -0,0 +1,108 @@
+
+/// SELinuxOptions are the labels to be applied to the container
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct SeLinuxOptions {
+    /// Level is SELinux level label that applies to the container.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub level: Option<String>,
+    /// Role is a SELinux role label that applies to the container.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub role: Option<String>,
+    /// Type is a SELinux type label that applies to the container.
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub type_: Option<String>,
+    /// User is a SELinux user label that applies to the container.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<String>,
+}
+
+impl SeLinuxOptions {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> SeLinuxOptionsBuilder {
+        SeLinuxOptionsBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> SeLinuxOptionsBuilder
+    where
+        Self: Clone,
+    {
+        SeLinuxOptionsBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<SeLinuxOptions> for SeLinuxOptionsBuilder {
+    fn into(self) -> SeLinuxOptions {
+        self.body
+    }
+}
+
+/// Builder for [`SeLinuxOptions`](./struct.SeLinuxOptions.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct SeLinuxOptionsBuilder {
+    body: self::SeLinuxOptions,
+}
+
+impl SeLinuxOptionsBuilder {
+    /// Level is SELinux level label that applies to the container.
+    #[inline]
+    pub fn level(mut self, value: impl Into<String>) -> Self {
+        self.body.level = Some(value.into());
+        self
+    }
+
+    /// Sets [`level`](#method.level) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_level(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.level = value.map(|value| value.into());
+        self
+    }
+
+    /// Role is a SELinux role label that applies to the container.
+    #[inline]
+    pub fn role(mut self, value: impl Into<String>) -> Self {
+        self.body.role = Some(value.into());
+        self
+    }
+
+    /// Sets [`role`](#method.role) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_role(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.role = value.map(|value| value.into());
+        self
+    }
+
+    /// Type is a SELinux type label that applies to the container.
+    #[inline]
+    pub fn type_(mut self, value: impl Into<String>) -> Self {
+        self.body.type_ = Some(value.into());
+        self
+    }
+
+    /// Sets [`type_`](#method.type_) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_type(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.type_ = value.map(|value| value.into());
+        self
+    }
+
+    /// User is a SELinux user label that applies to the container.
+    #[inline]
+    pub fn user(mut self, value: impl Into<String>) -> Self {
+        self.body.user = Some(value.into());
+        self
+    }
+
+    /// Sets [`user`](#method.user) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_user(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.user = value.map(|value| value.into());
+        self
+    }
+}
@@ -0,0 +1,132 @@
+
+/// ServicePort contains information on service's port.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct ServicePort {
+    /// The name of this port within the service. This must be a DNS_LABEL. All ports within a ServiceSpec must have unique names. This maps to the 'Name' field in EndpointPort objects. Optional if only one ServicePort is defined on this service.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The port on each node on which this service is exposed when type=NodePort or LoadBalancer. Usually assigned by the system. If specified, it will be allocated to the service if unused or else creation of the service will fail. Default is to auto-allocate a port if the ServiceType of this Service requires one. More info: https://kubernetes.io/docs/concepts/services-networking/service/#type-nodeport
+    #[serde(rename = "nodePort", skip_serializing_if = "Option::is_none")]
+    pub node_port: Option<i32>,
+    /// The port that will be exposed by this service.
+    pub port: i32,
+    /// The IP protocol for this port. Supports "TCP", "UDP", and "SCTP". Default is TCP.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub protocol: Option<String>,
+    /// Number or name of the port to access on the pods targeted by the service. Number must be in the range 1 to 65535. Name must be an IANA_SVC_NAME. If this is a string, it will be looked up as a named port in the target Pod's container ports. If this is not specified, the value of the 'port' field is used (an identity map). This field is ignored for services with clusterIP=None, and should be omitted or set equal to the 'port' field. More info: https://kubernetes.io/docs/concepts/services-networking/service/#defining-a-service
+    #[serde(rename = "targetPort", skip_serializing_if = "Option::is_none")]
+    pub target_port: Option<String>,
+}
+
+impl ServicePort {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> ServicePortBuilder<crate::codegen::generics::MissingPort> {
+        ServicePortBuilder {
+            body: Default::default(),
+            _port: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> ServicePortBuilder<crate::codegen::generics::PortExists>
+    where
+        Self: Clone,
+    {
+        ServicePortBuilder {
+            body: self.clone(),
+            _port: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<ServicePort> for ServicePortBuilder<crate::codegen::generics::PortExists> {
+    fn into(self) -> ServicePort {
+        self.body
+    }
+}
+
+/// Builder for [`ServicePort`](./struct.ServicePort.html) object.
+#[derive(Debug, Clone)]
+pub struct ServicePortBuilder<Port> {
+    body: self::ServicePort,
+    _port: core::marker::PhantomData<Port>,
+}
+
+impl<Port> ServicePortBuilder<Port> {
+    /// The name of this port within the service. This must be a DNS_LABEL. All ports within a ServiceSpec must have unique names. This maps to the 'Name' field in EndpointPort objects. Optional if only one ServicePort is defined on this service.
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> Self {
+        self.body.name = Some(value.into());
+        self
+    }
+
+    /// Sets [`name`](#method.name) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_name(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.name = value.map(|value| value.into());
+        self
+    }
+
+    /// The port on each node on which this service is exposed when type=NodePort or LoadBalancer. Usually assigned by the system. If specified, it will be allocated to the service if unused or else creation of the service will fail. Default is to auto-allocate a port if the ServiceType of this Service requires one. More info: https://kubernetes.io/docs/concepts/services-networking/service/#type-nodeport
+    #[inline]
+    pub fn node_port(mut self, value: impl Into<i32>) -> Self {
+        self.body.node_port = Some(value.into());
+        self
+    }
+
+    /// Sets [`node_port`](#method.node_port) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_node_port(mut self, value: Option<impl Into<i32>>) -> Self {
+        self.body.node_port = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`node_port`](#method.node_port), but takes a reference.
+    #[inline]
+    pub fn node_port_ref(self, value: &i32) -> Self {
+        self.node_port(*value)
+    }
+
+    /// The port that will be exposed by this service.
+    #[inline]
+    pub fn port(mut self, value: impl Into<i32>) -> ServicePortBuilder<crate::codegen::generics::PortExists> {
+        self.body.port = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Same as [`port`](#method.port), but takes a reference.
+    #[inline]
+    pub fn port_ref(self, value: &i32) -> ServicePortBuilder<crate::codegen::generics::PortExists> {
+        self.port(*value)
+    }
+
+    /// The IP protocol for this port. Supports "TCP", "UDP", and "SCTP". Default is TCP.
+    #[inline]
+    pub fn protocol(mut self, value: impl Into<String>) -> Self {
+        self.body.protocol = Some(value.into());
+        self
+    }
+
+    /// Sets [`protocol`](#method.protocol) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_protocol(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.protocol = value.map(|value| value.into());
+        self
+    }
+
+    /// Number or name of the port to access on the pods targeted by the service. Number must be in the range 1 to 65535. Name must be an IANA_SVC_NAME. If this is a string, it will be looked up as a named port in the target Pod's container ports. If this is not specified, the value of the 'port' field is used (an identity map). This field is ignored for services with clusterIP=None, and should be omitted or set equal to the 'port' field. More info: https://kubernetes.io/docs/concepts/services-networking/service/#defining-a-service
+    #[inline]
+    pub fn target_port(mut self, value: impl Into<String>) -> Self {
+        self.body.target_port = Some(value.into());
+        self
+    }
+
+    /// Sets [`target_port`](#method.target_port) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_target_port(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.target_port = value.map(|value| value.into());
+        self
+    }
+}
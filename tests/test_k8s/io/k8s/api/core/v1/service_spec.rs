@@ -0,0 +1,294 @@
+
+/// ServiceSpec describes the attributes that a user creates on a service.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct ServiceSpec {
+    /// clusterIP is the IP address of the service and is usually assigned randomly by the master. If an address is specified manually and is not in use by others, it will be allocated to the service; otherwise, creation of the service will fail. This field can not be changed through updates. Valid values are "None", empty string (""), or a valid IP address. "None" can be specified for headless services when proxying is not required. Only applies to types ClusterIP, NodePort, and LoadBalancer. Ignored if type is ExternalName. More info: https://kubernetes.io/docs/concepts/services-networking/service/#virtual-ips-and-service-proxies
+    #[serde(rename = "clusterIP", skip_serializing_if = "Option::is_none")]
+    pub cluster_ip: Option<String>,
+    /// externalIPs is a list of IP addresses for which nodes in the cluster will also accept traffic for this service.  These IPs are not managed by Kubernetes.  The user is responsible for ensuring that traffic arrives at a node with this IP.  A common example is external load-balancers that are not part of the Kubernetes system.
+    #[serde(rename = "externalIPs", skip_serializing_if = "Option::is_none")]
+    pub external_i_ps: Option<Vec<String>>,
+    /// externalName is the external reference that kubedns or equivalent will return as a CNAME record for this service. No proxying will be involved. Must be a valid RFC-1123 hostname (https://tools.ietf.org/html/rfc1123) and requires Type to be ExternalName.
+    #[serde(rename = "externalName", skip_serializing_if = "Option::is_none")]
+    pub external_name: Option<String>,
+    /// externalTrafficPolicy denotes if this Service desires to route external traffic to node-local or cluster-wide endpoints. "Local" preserves the client source IP and avoids a second hop for LoadBalancer and Nodeport type services, but risks potentially imbalanced traffic spreading. "Cluster" obscures the client source IP and may cause a second hop to another node, but should have good overall load-spreading.
+    #[serde(rename = "externalTrafficPolicy", skip_serializing_if = "Option::is_none")]
+    pub external_traffic_policy: Option<String>,
+    /// healthCheckNodePort specifies the healthcheck nodePort for the service. If not specified, HealthCheckNodePort is created by the service api backend with the allocated nodePort. Will use user-specified nodePort value if specified by the client. Only effects when Type is set to LoadBalancer and ExternalTrafficPolicy is set to Local.
+    #[serde(rename = "healthCheckNodePort", skip_serializing_if = "Option::is_none")]
+    pub health_check_node_port: Option<i32>,
+    /// Only applies to Service Type: LoadBalancer LoadBalancer will get created with the IP specified in this field. This feature depends on whether the underlying cloud-provider supports specifying the loadBalancerIP when a load balancer is created. This field will be ignored if the cloud-provider does not support the feature.
+    #[serde(rename = "loadBalancerIP", skip_serializing_if = "Option::is_none")]
+    pub load_balancer_ip: Option<String>,
+    /// If specified and supported by the platform, this will restrict traffic through the cloud-provider load-balancer will be restricted to the specified client IPs. This field will be ignored if the cloud-provider does not support the feature." More info: https://kubernetes.io/docs/tasks/access-application-cluster/configure-cloud-provider-firewall/
+    #[serde(rename = "loadBalancerSourceRanges", skip_serializing_if = "Option::is_none")]
+    pub load_balancer_source_ranges: Option<Vec<String>>,
+    /// The list of ports that are exposed by this service. More info: https://kubernetes.io/docs/concepts/services-networking/service/#virtual-ips-and-service-proxies
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ports: Option<Vec<crate::codegen::io::k8s::api::core::v1::service_port::ServicePort>>,
+    /// publishNotReadyAddresses, when set to true, indicates that DNS implementations must publish the notReadyAddresses of subsets for the Endpoints associated with the Service. The default value is false. The primary use case for setting this field is to use a StatefulSet's Headless Service to propagate SRV records for its Pods without respect to their readiness for purpose of peer discovery.
+    #[serde(rename = "publishNotReadyAddresses", skip_serializing_if = "Option::is_none")]
+    pub publish_not_ready_addresses: Option<bool>,
+    /// Route service traffic to pods with label keys and values matching this selector. If empty or not present, the service is assumed to have an external process managing its endpoints, which Kubernetes will not modify. Only applies to types ClusterIP, NodePort, and LoadBalancer. Ignored if type is ExternalName. More info: https://kubernetes.io/docs/concepts/services-networking/service/
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub selector: Option<std::collections::BTreeMap<String, String>>,
+    /// Supports "ClientIP" and "None". Used to maintain session affinity. Enable client IP based session affinity. Must be ClientIP or None. Defaults to None. More info: https://kubernetes.io/docs/concepts/services-networking/service/#virtual-ips-and-service-proxies
+    #[serde(rename = "sessionAffinity", skip_serializing_if = "Option::is_none")]
+    pub session_affinity: Option<String>,
+    /// sessionAffinityConfig contains the configurations of session affinity.
+    #[serde(rename = "sessionAffinityConfig", skip_serializing_if = "Option::is_none")]
+    pub session_affinity_config: Option<crate::codegen::io::k8s::api::core::v1::session_affinity_config::SessionAffinityConfig>,
+    /// type determines how the Service is exposed. Defaults to ClusterIP. Valid options are ExternalName, ClusterIP, NodePort, and LoadBalancer. "ExternalName" maps to the specified externalName. "ClusterIP" allocates a cluster-internal IP address for load-balancing to endpoints. Endpoints are determined by the selector or if that is not specified, by manual construction of an Endpoints object. If clusterIP is "None", no virtual IP is allocated and the endpoints are published as a set of endpoints rather than a stable IP. "NodePort" builds on ClusterIP and allocates a port on every node which routes to the clusterIP. "LoadBalancer" builds on NodePort and creates an external load-balancer (if supported in the current cloud) which routes to the clusterIP. More info: https://kubernetes.io/docs/concepts/services-networking/service/#publishing-services-service-types
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub type_: Option<String>,
+}
+
+impl ServiceSpec {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> ServiceSpecBuilder {
+        ServiceSpecBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> ServiceSpecBuilder
+    where
+        Self: Clone,
+    {
+        ServiceSpecBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<ServiceSpec> for ServiceSpecBuilder {
+    fn into(self) -> ServiceSpec {
+        self.body
+    }
+}
+
+/// Builder for [`ServiceSpec`](./struct.ServiceSpec.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct ServiceSpecBuilder {
+    body: self::ServiceSpec,
+}
+
+impl ServiceSpecBuilder {
+    /// clusterIP is the IP address of the service and is usually assigned randomly by the master. If an address is specified manually and is not in use by others, it will be allocated to the service; otherwise, creation of the service will fail. This field can not be changed through updates. Valid values are "None", empty string (""), or a valid IP address. "None" can be specified for headless services when proxying is not required. Only applies to types ClusterIP, NodePort, and LoadBalancer. Ignored if type is ExternalName. More info: https://kubernetes.io/docs/concepts/services-networking/service/#virtual-ips-and-service-proxies
+    #[inline]
+    pub fn cluster_ip(mut self, value: impl Into<String>) -> Self {
+        self.body.cluster_ip = Some(value.into());
+        self
+    }
+
+    /// Sets [`cluster_ip`](#method.cluster_ip) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_cluster_ip(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.cluster_ip = value.map(|value| value.into());
+        self
+    }
+
+    /// externalIPs is a list of IP addresses for which nodes in the cluster will also accept traffic for this service.  These IPs are not managed by Kubernetes.  The user is responsible for ensuring that traffic arrives at a node with this IP.  A common example is external load-balancers that are not part of the Kubernetes system.
+    #[inline]
+    pub fn external_i_ps(mut self, value: impl Iterator<Item = impl Into<String>>) -> Self {
+        self.body.external_i_ps = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`external_i_ps`](#method.external_i_ps) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_external_i_ps(mut self, value: Option<impl Iterator<Item = impl Into<String>>>) -> Self {
+        self.body.external_i_ps = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`external_i_ps`](#method.external_i_ps), but takes the values as a `Vec`.
+    #[inline]
+    pub fn external_i_ps_vec(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.body.external_i_ps = Some(value.into());
+        self
+    }
+
+    /// externalName is the external reference that kubedns or equivalent will return as a CNAME record for this service. No proxying will be involved. Must be a valid RFC-1123 hostname (https://tools.ietf.org/html/rfc1123) and requires Type to be ExternalName.
+    #[inline]
+    pub fn external_name(mut self, value: impl Into<String>) -> Self {
+        self.body.external_name = Some(value.into());
+        self
+    }
+
+    /// Sets [`external_name`](#method.external_name) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_external_name(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.external_name = value.map(|value| value.into());
+        self
+    }
+
+    /// externalTrafficPolicy denotes if this Service desires to route external traffic to node-local or cluster-wide endpoints. "Local" preserves the client source IP and avoids a second hop for LoadBalancer and Nodeport type services, but risks potentially imbalanced traffic spreading. "Cluster" obscures the client source IP and may cause a second hop to another node, but should have good overall load-spreading.
+    #[inline]
+    pub fn external_traffic_policy(mut self, value: impl Into<String>) -> Self {
+        self.body.external_traffic_policy = Some(value.into());
+        self
+    }
+
+    /// Sets [`external_traffic_policy`](#method.external_traffic_policy) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_external_traffic_policy(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.external_traffic_policy = value.map(|value| value.into());
+        self
+    }
+
+    /// healthCheckNodePort specifies the healthcheck nodePort for the service. If not specified, HealthCheckNodePort is created by the service api backend with the allocated nodePort. Will use user-specified nodePort value if specified by the client. Only effects when Type is set to LoadBalancer and ExternalTrafficPolicy is set to Local.
+    #[inline]
+    pub fn health_check_node_port(mut self, value: impl Into<i32>) -> Self {
+        self.body.health_check_node_port = Some(value.into());
+        self
+    }
+
+    /// Sets [`health_check_node_port`](#method.health_check_node_port) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_health_check_node_port(mut self, value: Option<impl Into<i32>>) -> Self {
+        self.body.health_check_node_port = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`health_check_node_port`](#method.health_check_node_port), but takes a reference.
+    #[inline]
+    pub fn health_check_node_port_ref(self, value: &i32) -> Self {
+        self.health_check_node_port(*value)
+    }
+
+    /// Only applies to Service Type: LoadBalancer LoadBalancer will get created with the IP specified in this field. This feature depends on whether the underlying cloud-provider supports specifying the loadBalancerIP when a load balancer is created. This field will be ignored if the cloud-provider does not support the feature.
+    #[inline]
+    pub fn load_balancer_ip(mut self, value: impl Into<String>) -> Self {
+        self.body.load_balancer_ip = Some(value.into());
+        self
+    }
+
+    /// Sets [`load_balancer_ip`](#method.load_balancer_ip) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_load_balancer_ip(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.load_balancer_ip = value.map(|value| value.into());
+        self
+    }
+
+    /// If specified and supported by the platform, this will restrict traffic through the cloud-provider load-balancer will be restricted to the specified client IPs. This field will be ignored if the cloud-provider does not support the feature." More info: https://kubernetes.io/docs/tasks/access-application-cluster/configure-cloud-provider-firewall/
+    #[inline]
+    pub fn load_balancer_source_ranges(mut self, value: impl Iterator<Item = impl Into<String>>) -> Self {
+        self.body.load_balancer_source_ranges = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`load_balancer_source_ranges`](#method.load_balancer_source_ranges) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_load_balancer_source_ranges(mut self, value: Option<impl Iterator<Item = impl Into<String>>>) -> Self {
+        self.body.load_balancer_source_ranges = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`load_balancer_source_ranges`](#method.load_balancer_source_ranges), but takes the values as a `Vec`.
+    #[inline]
+    pub fn load_balancer_source_ranges_vec(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.body.load_balancer_source_ranges = Some(value.into());
+        self
+    }
+
+    /// The list of ports that are exposed by this service. More info: https://kubernetes.io/docs/concepts/services-networking/service/#virtual-ips-and-service-proxies
+    #[inline]
+    pub fn ports(mut self, value: impl Iterator<Item = crate::codegen::io::k8s::api::core::v1::service_port::ServicePortBuilder<crate::codegen::generics::PortExists>>) -> Self {
+        self.body.ports = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`ports`](#method.ports) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_ports(mut self, value: Option<impl Iterator<Item = crate::codegen::io::k8s::api::core::v1::service_port::ServicePortBuilder<crate::codegen::generics::PortExists>>>) -> Self {
+        self.body.ports = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`ports`](#method.ports), but takes the values as a `Vec`.
+    #[inline]
+    pub fn ports_vec(mut self, value: impl Into<Vec<crate::codegen::io::k8s::api::core::v1::service_port::ServicePort>>) -> Self {
+        self.body.ports = Some(value.into());
+        self
+    }
+
+    /// publishNotReadyAddresses, when set to true, indicates that DNS implementations must publish the notReadyAddresses of subsets for the Endpoints associated with the Service. The default value is false. The primary use case for setting this field is to use a StatefulSet's Headless Service to propagate SRV records for its Pods without respect to their readiness for purpose of peer discovery.
+    #[inline]
+    pub fn publish_not_ready_addresses(mut self, value: impl Into<bool>) -> Self {
+        self.body.publish_not_ready_addresses = Some(value.into());
+        self
+    }
+
+    /// Sets [`publish_not_ready_addresses`](#method.publish_not_ready_addresses) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_publish_not_ready_addresses(mut self, value: Option<impl Into<bool>>) -> Self {
+        self.body.publish_not_ready_addresses = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`publish_not_ready_addresses`](#method.publish_not_ready_addresses), but takes a reference.
+    #[inline]
+    pub fn publish_not_ready_addresses_ref(self, value: &bool) -> Self {
+        self.publish_not_ready_addresses(*value)
+    }
+
+    /// Route service traffic to pods with label keys and values matching this selector. If empty or not present, the service is assumed to have an external process managing its endpoints, which Kubernetes will not modify. Only applies to types ClusterIP, NodePort, and LoadBalancer. Ignored if type is ExternalName. More info: https://kubernetes.io/docs/concepts/services-networking/service/
+    #[inline]
+    pub fn selector(mut self, value: impl Iterator<Item = (String, impl Into<String>)>) -> Self {
+        self.body.selector = Some(value.map(|(key, value)| (key, value.into())).collect::<std::collections::BTreeMap<_, _>>().into());
+        self
+    }
+
+    /// Sets [`selector`](#method.selector) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_selector(mut self, value: Option<impl Iterator<Item = (String, impl Into<String>)>>) -> Self {
+        self.body.selector = value.map(|value| value.map(|(key, value)| (key, value.into())).collect::<std::collections::BTreeMap<_, _>>().into());
+        self
+    }
+
+    /// Supports "ClientIP" and "None". Used to maintain session affinity. Enable client IP based session affinity. Must be ClientIP or None. Defaults to None. More info: https://kubernetes.io/docs/concepts/services-networking/service/#virtual-ips-and-service-proxies
+    #[inline]
+    pub fn session_affinity(mut self, value: impl Into<String>) -> Self {
+        self.body.session_affinity = Some(value.into());
+        self
+    }
+
+    /// Sets [`session_affinity`](#method.session_affinity) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_session_affinity(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.session_affinity = value.map(|value| value.into());
+        self
+    }
+
+    /// sessionAffinityConfig contains the configurations of session affinity.
+    #[inline]
+    pub fn session_affinity_config(mut self, value: crate::codegen::io::k8s::api::core::v1::session_affinity_config::SessionAffinityConfig) -> Self {
+        self.body.session_affinity_config = Some(value.into());
+        self
+    }
+
+    /// Sets [`session_affinity_config`](#method.session_affinity_config) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_session_affinity_config(mut self, value: Option<crate::codegen::io::k8s::api::core::v1::session_affinity_config::SessionAffinityConfig>) -> Self {
+        self.body.session_affinity_config = value.map(|value| value.into());
+        self
+    }
+
+    /// type determines how the Service is exposed. Defaults to ClusterIP. Valid options are ExternalName, ClusterIP, NodePort, and LoadBalancer. "ExternalName" maps to the specified externalName. "ClusterIP" allocates a cluster-internal IP address for load-balancing to endpoints. Endpoints are determined by the selector or if that is not specified, by manual construction of an Endpoints object. If clusterIP is "None", no virtual IP is allocated and the endpoints are published as a set of endpoints rather than a stable IP. "NodePort" builds on ClusterIP and allocates a port on every node which routes to the clusterIP. "LoadBalancer" builds on NodePort and creates an external load-balancer (if supported in the current cloud) which routes to the clusterIP. More info: https://kubernetes.io/docs/concepts/services-networking/service/#publishing-services-service-types
+    #[inline]
+    pub fn type_(mut self, value: impl Into<String>) -> Self {
+        self.body.type_ = Some(value.into());
+        self
+    }
+
+    /// Sets [`type_`](#method.type_) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_type(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.type_ = value.map(|value| value.into());
+        self
+    }
+}
@@ -0,0 +1,57 @@
+
+/// SessionAffinityConfig represents the configurations of session affinity.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct SessionAffinityConfig {
+    /// clientIP contains the configurations of Client IP based session affinity.
+    #[serde(rename = "clientIP", skip_serializing_if = "Option::is_none")]
+    pub client_ip: Option<crate::codegen::io::k8s::api::core::v1::client_ip_config::ClientIpConfig>,
+}
+
+impl SessionAffinityConfig {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> SessionAffinityConfigBuilder {
+        SessionAffinityConfigBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> SessionAffinityConfigBuilder
+    where
+        Self: Clone,
+    {
+        SessionAffinityConfigBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<SessionAffinityConfig> for SessionAffinityConfigBuilder {
+    fn into(self) -> SessionAffinityConfig {
+        self.body
+    }
+}
+
+/// Builder for [`SessionAffinityConfig`](./struct.SessionAffinityConfig.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct SessionAffinityConfigBuilder {
+    body: self::SessionAffinityConfig,
+}
+
+impl SessionAffinityConfigBuilder {
+    /// clientIP contains the configurations of Client IP based session affinity.
+    #[inline]
+    pub fn client_ip(mut self, value: crate::codegen::io::k8s::api::core::v1::client_ip_config::ClientIpConfig) -> Self {
+        self.body.client_ip = Some(value.into());
+        self
+    }
+
+    /// Sets [`client_ip`](#method.client_ip) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_client_ip(mut self, value: Option<crate::codegen::io::k8s::api::core::v1::client_ip_config::ClientIpConfig>) -> Self {
+        self.body.client_ip = value.map(|value| value.into());
+        self
+    }
+}
@@ -0,0 +1,64 @@
+
+/// Sysctl defines a kernel parameter to be set
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct Sysctl {
+    /// Name of a property to set
+    pub name: String,
+    /// Value of a property to set
+    pub value: String,
+}
+
+impl Sysctl {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> SysctlBuilder<crate::codegen::generics::MissingName, crate::codegen::generics::MissingValue> {
+        SysctlBuilder {
+            body: Default::default(),
+            _name: core::marker::PhantomData,
+            _value: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> SysctlBuilder<crate::codegen::generics::NameExists, crate::codegen::generics::ValueExists>
+    where
+        Self: Clone,
+    {
+        SysctlBuilder {
+            body: self.clone(),
+            _name: core::marker::PhantomData,
+            _value: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<Sysctl> for SysctlBuilder<crate::codegen::generics::NameExists, crate::codegen::generics::ValueExists> {
+    fn into(self) -> Sysctl {
+        self.body
+    }
+}
+
+/// Builder for [`Sysctl`](./struct.Sysctl.html) object.
+#[derive(Debug, Clone)]
+pub struct SysctlBuilder<Name, Value> {
+    body: self::Sysctl,
+    _name: core::marker::PhantomData<Name>,
+    _value: core::marker::PhantomData<Value>,
+}
+
+impl<Name, Value> SysctlBuilder<Name, Value> {
+    /// Name of a property to set
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> SysctlBuilder<crate::codegen::generics::NameExists, Value> {
+        self.body.name = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Value of a property to set
+    #[inline]
+    pub fn value(mut self, value: impl Into<String>) -> SysctlBuilder<Name, crate::codegen::generics::ValueExists> {
+        self.body.value = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+}
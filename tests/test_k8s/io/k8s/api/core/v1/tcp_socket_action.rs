@@ -0,0 +1,69 @@
+
+/// TCPSocketAction describes an action based on opening a socket
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct TcpSocketAction {
+    /// Optional: Host name to connect to, defaults to the pod IP.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub host: Option<String>,
+    /// Number or name of the port to access on the container. Number must be in the range 1 to 65535. Name must be an IANA_SVC_NAME.
+    pub port: String,
+}
+
+impl TcpSocketAction {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> TcpSocketActionBuilder<crate::codegen::generics::MissingPort> {
+        TcpSocketActionBuilder {
+            body: Default::default(),
+            _port: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> TcpSocketActionBuilder<crate::codegen::generics::PortExists>
+    where
+        Self: Clone,
+    {
+        TcpSocketActionBuilder {
+            body: self.clone(),
+            _port: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<TcpSocketAction> for TcpSocketActionBuilder<crate::codegen::generics::PortExists> {
+    fn into(self) -> TcpSocketAction {
+        self.body
+    }
+}
+
+/// Builder for [`TcpSocketAction`](./struct.TcpSocketAction.html) object.
+#[derive(Debug, Clone)]
+pub struct TcpSocketActionBuilder<Port> {
+    body: self::TcpSocketAction,
+    _port: core::marker::PhantomData<Port>,
+}
+
+impl<Port> TcpSocketActionBuilder<Port> {
+    /// Optional: Host name to connect to, defaults to the pod IP.
+    #[inline]
+    pub fn host(mut self, value: impl Into<String>) -> Self {
+        self.body.host = Some(value.into());
+        self
+    }
+
+    /// Sets [`host`](#method.host) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_host(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.host = value.map(|value| value.into());
+        self
+    }
+
+    /// Number or name of the port to access on the container. Number must be in the range 1 to 65535. Name must be an IANA_SVC_NAME.
+    #[inline]
+    pub fn port(mut self, value: impl Into<String>) -> TcpSocketActionBuilder<crate::codegen::generics::PortExists> {
+        self.body.port = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+}
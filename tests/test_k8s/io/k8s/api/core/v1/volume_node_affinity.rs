@@ -0,0 +1,57 @@
+
+/// VolumeNodeAffinity defines constraints that limit what nodes this volume can be accessed from.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct VolumeNodeAffinity {
+    /// Required specifies hard node constraints that must be met.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub required: Option<crate::codegen::io::k8s::api::core::v1::node_selector::NodeSelector>,
+}
+
+impl VolumeNodeAffinity {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> VolumeNodeAffinityBuilder {
+        VolumeNodeAffinityBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> VolumeNodeAffinityBuilder
+    where
+        Self: Clone,
+    {
+        VolumeNodeAffinityBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<VolumeNodeAffinity> for VolumeNodeAffinityBuilder {
+    fn into(self) -> VolumeNodeAffinity {
+        self.body
+    }
+}
+
+/// Builder for [`VolumeNodeAffinity`](./struct.VolumeNodeAffinity.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct VolumeNodeAffinityBuilder {
+    body: self::VolumeNodeAffinity,
+}
+
+impl VolumeNodeAffinityBuilder {
+    /// Required specifies hard node constraints that must be met.
+    #[inline]
+    pub fn required(mut self, value: crate::codegen::io::k8s::api::core::v1::node_selector::NodeSelectorBuilder<crate::codegen::generics::NodeSelectorTermsExists>) -> Self {
+        self.body.required = Some(value.into());
+        self
+    }
+
+    /// Sets [`required`](#method.required) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_required(mut self, value: Option<crate::codegen::io::k8s::api::core::v1::node_selector::NodeSelectorBuilder<crate::codegen::generics::NodeSelectorTermsExists>>) -> Self {
+        self.body.required = value.map(|value| value.into());
+        self
+    }
+}
@@ -0,0 +1,104 @@
+
+/// Represents a vSphere volume resource.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct VsphereVirtualDiskVolumeSource {
+    /// Filesystem type to mount. Must be a filesystem type supported by the host operating system. Ex. "ext4", "xfs", "ntfs". Implicitly inferred to be "ext4" if unspecified.
+    #[serde(rename = "fsType", skip_serializing_if = "Option::is_none")]
+    pub fs_type: Option<String>,
+    /// Storage Policy Based Management (SPBM) profile ID associated with the StoragePolicyName.
+    #[serde(rename = "storagePolicyID", skip_serializing_if = "Option::is_none")]
+    pub storage_policy_id: Option<String>,
+    /// Storage Policy Based Management (SPBM) profile name.
+    #[serde(rename = "storagePolicyName", skip_serializing_if = "Option::is_none")]
+    pub storage_policy_name: Option<String>,
+    /// Path that identifies vSphere volume vmdk
+    #[serde(rename = "volumePath")]
+    pub volume_path: String,
+}
+
+impl VsphereVirtualDiskVolumeSource {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> VsphereVirtualDiskVolumeSourceBuilder<crate::codegen::generics::MissingVolumePath> {
+        VsphereVirtualDiskVolumeSourceBuilder {
+            body: Default::default(),
+            _volume_path: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> VsphereVirtualDiskVolumeSourceBuilder<crate::codegen::generics::VolumePathExists>
+    where
+        Self: Clone,
+    {
+        VsphereVirtualDiskVolumeSourceBuilder {
+            body: self.clone(),
+            _volume_path: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<VsphereVirtualDiskVolumeSource> for VsphereVirtualDiskVolumeSourceBuilder<crate::codegen::generics::VolumePathExists> {
+    fn into(self) -> VsphereVirtualDiskVolumeSource {
+        self.body
+    }
+}
+
+/// Builder for [`VsphereVirtualDiskVolumeSource`](./struct.VsphereVirtualDiskVolumeSource.html) object.
+#[derive(Debug, Clone)]
+pub struct VsphereVirtualDiskVolumeSourceBuilder<VolumePath> {
+    body: self::VsphereVirtualDiskVolumeSource,
+    _volume_path: core::marker::PhantomData<VolumePath>,
+}
+
+impl<VolumePath> VsphereVirtualDiskVolumeSourceBuilder<VolumePath> {
+    /// Filesystem type to mount. Must be a filesystem type supported by the host operating system. Ex. "ext4", "xfs", "ntfs". Implicitly inferred to be "ext4" if unspecified.
+    #[inline]
+    pub fn fs_type(mut self, value: impl Into<String>) -> Self {
+        self.body.fs_type = Some(value.into());
+        self
+    }
+
+    /// Sets [`fs_type`](#method.fs_type) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_fs_type(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.fs_type = value.map(|value| value.into());
+        self
+    }
+
+    /// Storage Policy Based Management (SPBM) profile ID associated with the StoragePolicyName.
+    #[inline]
+    pub fn storage_policy_id(mut self, value: impl Into<String>) -> Self {
+        self.body.storage_policy_id = Some(value.into());
+        self
+    }
+
+    /// Sets [`storage_policy_id`](#method.storage_policy_id) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_storage_policy_id(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.storage_policy_id = value.map(|value| value.into());
+        self
+    }
+
+    /// Storage Policy Based Management (SPBM) profile name.
+    #[inline]
+    pub fn storage_policy_name(mut self, value: impl Into<String>) -> Self {
+        self.body.storage_policy_name = Some(value.into());
+        self
+    }
+
+    /// Sets [`storage_policy_name`](#method.storage_policy_name) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_storage_policy_name(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.storage_policy_name = value.map(|value| value.into());
+        self
+    }
+
+    /// Path that identifies vSphere volume vmdk
+    #[inline]
+    pub fn volume_path(mut self, value: impl Into<String>) -> VsphereVirtualDiskVolumeSourceBuilder<crate::codegen::generics::VolumePathExists> {
+        self.body.volume_path = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+}
@@ -0,0 +1,1199 @@
+
+/// Event is a report of an event somewhere in the cluster. It generally denotes some state change in the system.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct Event {
+    /// What action was taken/failed regarding to the regarding object.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action: Option<String>,
+    /// APIVersion defines the versioned schema of this representation of an object. Servers should convert recognized schemas to the latest internal value, and may reject unrecognized values. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#resources
+    #[serde(rename = "apiVersion", skip_serializing_if = "Option::is_none")]
+    pub api_version: Option<String>,
+    /// Deprecated field assuring backward compatibility with core.v1 Event type
+    #[serde(rename = "deprecatedCount", skip_serializing_if = "Option::is_none")]
+    pub deprecated_count: Option<i32>,
+    /// Deprecated field assuring backward compatibility with core.v1 Event type
+    #[serde(rename = "deprecatedFirstTimestamp", skip_serializing_if = "Option::is_none")]
+    pub deprecated_first_timestamp: Option<String>,
+    /// Deprecated field assuring backward compatibility with core.v1 Event type
+    #[serde(rename = "deprecatedLastTimestamp", skip_serializing_if = "Option::is_none")]
+    pub deprecated_last_timestamp: Option<String>,
+    /// Deprecated field assuring backward compatibility with core.v1 Event type
+    #[serde(rename = "deprecatedSource", skip_serializing_if = "Option::is_none")]
+    pub deprecated_source: Option<crate::codegen::io::k8s::api::core::v1::event_source::EventSource>,
+    /// Required. Time when this Event was first observed.
+    #[serde(rename = "eventTime")]
+    pub event_time: String,
+    /// Kind is a string value representing the REST resource this object represents. Servers may infer this from the endpoint the client submits requests to. Cannot be updated. In CamelCase. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#types-kinds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub kind: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<crate::codegen::io::k8s::apimachinery::pkg::apis::meta::v1::object_meta::ObjectMeta>,
+    /// Optional. A human-readable description of the status of this operation. Maximal length of the note is 1kB, but libraries should be prepared to handle values up to 64kB.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+    /// Why the action was taken.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+    /// The object this Event is about. In most cases it's an Object reporting controller implements. E.g. ReplicaSetController implements ReplicaSets and this event is emitted because it acts on some changes in a ReplicaSet object.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub regarding: Option<crate::codegen::io::k8s::api::core::v1::object_reference::ObjectReference>,
+    /// Optional secondary object for more complex actions. E.g. when regarding object triggers a creation or deletion of related object.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub related: Option<crate::codegen::io::k8s::api::core::v1::object_reference::ObjectReference>,
+    /// Name of the controller that emitted this Event, e.g. `kubernetes.io/kubelet`.
+    #[serde(rename = "reportingController", skip_serializing_if = "Option::is_none")]
+    pub reporting_controller: Option<String>,
+    /// ID of the controller instance, e.g. `kubelet-xyzf`.
+    #[serde(rename = "reportingInstance", skip_serializing_if = "Option::is_none")]
+    pub reporting_instance: Option<String>,
+    /// Data about the Event series this event represents or nil if it's a singleton Event.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub series: Option<crate::codegen::io::k8s::api::events::v1beta1::event_series::EventSeries>,
+    /// Type of this event (Normal, Warning), new types could be added in the future.
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub type_: Option<String>,
+}
+
+impl Event {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> EventBuilder<crate::codegen::generics::MissingEventTime> {
+        EventBuilder {
+            body: Default::default(),
+            _event_time: core::marker::PhantomData,
+        }
+    }
+
+    /// create an Event
+    #[inline]
+    pub fn create_events_v1beta1_namespaced_event() -> EventPostBuilder<crate::codegen::generics::MissingNamespace, crate::codegen::generics::MissingEventTime> {
+        EventPostBuilder {
+            inner: Default::default(),
+            _param_namespace: core::marker::PhantomData,
+            _event_time: core::marker::PhantomData,
+        }
+    }
+
+    /// read the specified Event
+    #[inline]
+    pub fn read_events_v1beta1_namespaced_event() -> EventGetBuilder1<crate::codegen::generics::MissingName, crate::codegen::generics::MissingNamespace> {
+        EventGetBuilder1 {
+            inner: Default::default(),
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        }
+    }
+
+    /// replace the specified Event
+    #[inline]
+    pub fn replace_events_v1beta1_namespaced_event() -> EventPutBuilder1<crate::codegen::generics::MissingName, crate::codegen::generics::MissingNamespace, crate::codegen::generics::MissingEventTime> {
+        EventPutBuilder1 {
+            inner: Default::default(),
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+            _event_time: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> EventBuilder<crate::codegen::generics::EventTimeExists>
+    where
+        Self: Clone,
+    {
+        EventBuilder {
+            body: self.clone(),
+            _event_time: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<Event> for EventBuilder<crate::codegen::generics::EventTimeExists> {
+    fn into(self) -> Event {
+        self.body
+    }
+}
+
+impl Into<Event> for EventPostBuilder<crate::codegen::generics::NamespaceExists, crate::codegen::generics::EventTimeExists> {
+    fn into(self) -> Event {
+        self.inner.body
+    }
+}
+
+impl Into<Event> for EventPutBuilder1<crate::codegen::generics::NameExists, crate::codegen::generics::NamespaceExists, crate::codegen::generics::EventTimeExists> {
+    fn into(self) -> Event {
+        self.inner.body
+    }
+}
+
+/// Builder for [`Event`](./struct.Event.html) object.
+#[derive(Debug, Clone)]
+pub struct EventBuilder<EventTime> {
+    body: self::Event,
+    _event_time: core::marker::PhantomData<EventTime>,
+}
+
+impl<EventTime> EventBuilder<EventTime> {
+    /// What action was taken/failed regarding to the regarding object.
+    #[inline]
+    pub fn action(mut self, value: impl Into<String>) -> Self {
+        self.body.action = Some(value.into());
+        self
+    }
+
+    /// Sets [`action`](#method.action) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_action(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.action = value.map(|value| value.into());
+        self
+    }
+
+    /// APIVersion defines the versioned schema of this representation of an object. Servers should convert recognized schemas to the latest internal value, and may reject unrecognized values. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#resources
+    #[inline]
+    pub fn api_version(mut self, value: impl Into<String>) -> Self {
+        self.body.api_version = Some(value.into());
+        self
+    }
+
+    /// Sets [`api_version`](#method.api_version) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_api_version(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.api_version = value.map(|value| value.into());
+        self
+    }
+
+    /// Deprecated field assuring backward compatibility with core.v1 Event type
+    #[inline]
+    pub fn deprecated_count(mut self, value: impl Into<i32>) -> Self {
+        self.body.deprecated_count = Some(value.into());
+        self
+    }
+
+    /// Sets [`deprecated_count`](#method.deprecated_count) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_deprecated_count(mut self, value: Option<impl Into<i32>>) -> Self {
+        self.body.deprecated_count = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`deprecated_count`](#method.deprecated_count), but takes a reference.
+    #[inline]
+    pub fn deprecated_count_ref(self, value: &i32) -> Self {
+        self.deprecated_count(*value)
+    }
+
+    /// Deprecated field assuring backward compatibility with core.v1 Event type
+    #[inline]
+    pub fn deprecated_first_timestamp(mut self, value: impl Into<String>) -> Self {
+        self.body.deprecated_first_timestamp = Some(value.into());
+        self
+    }
+
+    /// Sets [`deprecated_first_timestamp`](#method.deprecated_first_timestamp) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_deprecated_first_timestamp(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.deprecated_first_timestamp = value.map(|value| value.into());
+        self
+    }
+
+    /// Deprecated field assuring backward compatibility with core.v1 Event type
+    #[inline]
+    pub fn deprecated_last_timestamp(mut self, value: impl Into<String>) -> Self {
+        self.body.deprecated_last_timestamp = Some(value.into());
+        self
+    }
+
+    /// Sets [`deprecated_last_timestamp`](#method.deprecated_last_timestamp) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_deprecated_last_timestamp(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.deprecated_last_timestamp = value.map(|value| value.into());
+        self
+    }
+
+    /// Deprecated field assuring backward compatibility with core.v1 Event type
+    #[inline]
+    pub fn deprecated_source(mut self, value: crate::codegen::io::k8s::api::core::v1::event_source::EventSource) -> Self {
+        self.body.deprecated_source = Some(value.into());
+        self
+    }
+
+    /// Sets [`deprecated_source`](#method.deprecated_source) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_deprecated_source(mut self, value: Option<crate::codegen::io::k8s::api::core::v1::event_source::EventSource>) -> Self {
+        self.body.deprecated_source = value.map(|value| value.into());
+        self
+    }
+
+    /// Required. Time when this Event was first observed.
+    #[inline]
+    pub fn event_time(mut self, value: impl Into<String>) -> EventBuilder<crate::codegen::generics::EventTimeExists> {
+        self.body.event_time = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Kind is a string value representing the REST resource this object represents. Servers may infer this from the endpoint the client submits requests to. Cannot be updated. In CamelCase. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#types-kinds
+    #[inline]
+    pub fn kind(mut self, value: impl Into<String>) -> Self {
+        self.body.kind = Some(value.into());
+        self
+    }
+
+    /// Sets [`kind`](#method.kind) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_kind(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.kind = value.map(|value| value.into());
+        self
+    }
+
+    #[inline]
+    pub fn metadata(mut self, value: crate::codegen::io::k8s::apimachinery::pkg::apis::meta::v1::object_meta::ObjectMeta) -> Self {
+        self.body.metadata = Some(value.into());
+        self
+    }
+
+    /// Sets [`metadata`](#method.metadata) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_metadata(mut self, value: Option<crate::codegen::io::k8s::apimachinery::pkg::apis::meta::v1::object_meta::ObjectMeta>) -> Self {
+        self.body.metadata = value.map(|value| value.into());
+        self
+    }
+
+    /// Optional. A human-readable description of the status of this operation. Maximal length of the note is 1kB, but libraries should be prepared to handle values up to 64kB.
+    #[inline]
+    pub fn note(mut self, value: impl Into<String>) -> Self {
+        self.body.note = Some(value.into());
+        self
+    }
+
+    /// Sets [`note`](#method.note) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_note(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.note = value.map(|value| value.into());
+        self
+    }
+
+    /// Why the action was taken.
+    #[inline]
+    pub fn reason(mut self, value: impl Into<String>) -> Self {
+        self.body.reason = Some(value.into());
+        self
+    }
+
+    /// Sets [`reason`](#method.reason) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_reason(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.reason = value.map(|value| value.into());
+        self
+    }
+
+    /// The object this Event is about. In most cases it's an Object reporting controller implements. E.g. ReplicaSetController implements ReplicaSets and this event is emitted because it acts on some changes in a ReplicaSet object.
+    #[inline]
+    pub fn regarding(mut self, value: crate::codegen::io::k8s::api::core::v1::object_reference::ObjectReference) -> Self {
+        self.body.regarding = Some(value.into());
+        self
+    }
+
+    /// Sets [`regarding`](#method.regarding) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_regarding(mut self, value: Option<crate::codegen::io::k8s::api::core::v1::object_reference::ObjectReference>) -> Self {
+        self.body.regarding = value.map(|value| value.into());
+        self
+    }
+
+    /// Optional secondary object for more complex actions. E.g. when regarding object triggers a creation or deletion of related object.
+    #[inline]
+    pub fn related(mut self, value: crate::codegen::io::k8s::api::core::v1::object_reference::ObjectReference) -> Self {
+        self.body.related = Some(value.into());
+        self
+    }
+
+    /// Sets [`related`](#method.related) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_related(mut self, value: Option<crate::codegen::io::k8s::api::core::v1::object_reference::ObjectReference>) -> Self {
+        self.body.related = value.map(|value| value.into());
+        self
+    }
+
+    /// Name of the controller that emitted this Event, e.g. `kubernetes.io/kubelet`.
+    #[inline]
+    pub fn reporting_controller(mut self, value: impl Into<String>) -> Self {
+        self.body.reporting_controller = Some(value.into());
+        self
+    }
+
+    /// Sets [`reporting_controller`](#method.reporting_controller) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_reporting_controller(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.reporting_controller = value.map(|value| value.into());
+        self
+    }
+
+    /// ID of the controller instance, e.g. `kubelet-xyzf`.
+    #[inline]
+    pub fn reporting_instance(mut self, value: impl Into<String>) -> Self {
+        self.body.reporting_instance = Some(value.into());
+        self
+    }
+
+    /// Sets [`reporting_instance`](#method.reporting_instance) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_reporting_instance(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.reporting_instance = value.map(|value| value.into());
+        self
+    }
+
+    /// Data about the Event series this event represents or nil if it's a singleton Event.
+    #[inline]
+    pub fn series(mut self, value: crate::codegen::io::k8s::api::events::v1beta1::event_series::EventSeriesBuilder<crate::codegen::generics::CountExists, crate::codegen::generics::LastObservedTimeExists, crate::codegen::generics::StateExists>) -> Self {
+        self.body.series = Some(value.into());
+        self
+    }
+
+    /// Sets [`series`](#method.series) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_series(mut self, value: Option<crate::codegen::io::k8s::api::events::v1beta1::event_series::EventSeriesBuilder<crate::codegen::generics::CountExists, crate::codegen::generics::LastObservedTimeExists, crate::codegen::generics::StateExists>>) -> Self {
+        self.body.series = value.map(|value| value.into());
+        self
+    }
+
+    /// Type of this event (Normal, Warning), new types could be added in the future.
+    #[inline]
+    pub fn type_(mut self, value: impl Into<String>) -> Self {
+        self.body.type_ = Some(value.into());
+        self
+    }
+
+    /// Sets [`type_`](#method.type_) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_type(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.type_ = value.map(|value| value.into());
+        self
+    }
+}
+
+/// Builder created by [`Event::create_events_v1beta1_namespaced_event`](./struct.Event.html#method.create_events_v1beta1_namespaced_event) method for a `POST` operation associated with `Event`.
+#[repr(transparent)]
+#[derive(Debug, Clone)]
+pub struct EventPostBuilder<Namespace, EventTime> {
+    inner: EventPostBuilderContainer,
+    _param_namespace: core::marker::PhantomData<Namespace>,
+    _event_time: core::marker::PhantomData<EventTime>,
+}
+
+#[derive(Debug, Default, Clone)]
+struct EventPostBuilderContainer {
+    body: self::Event,
+    param_dry_run: Option<String>,
+    param_field_manager: Option<String>,
+    param_namespace: Option<String>,
+    param_pretty: Option<String>,
+}
+
+impl<Namespace, EventTime> EventPostBuilder<Namespace, EventTime> {
+    /// When present, indicates that modifications should not be persisted. An invalid or unrecognized dryRun directive will result in an error response and no further processing of the request. Valid values are: - All: all dry run stages will be processed
+    #[inline]
+    pub fn dry_run(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_dry_run = Some(value.into());
+        self
+    }
+
+    /// Sets [`dry_run`](#method.dry_run) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_dry_run(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_dry_run = value.map(|value| value.into());
+        self
+    }
+
+    /// fieldManager is a name associated with the actor or entity that is making these changes. The value must be less than or 128 characters long, and only contain printable characters, as defined by https://golang.org/pkg/unicode/#IsPrint.
+    #[inline]
+    pub fn field_manager(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_field_manager = Some(value.into());
+        self
+    }
+
+    /// Sets [`field_manager`](#method.field_manager) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_field_manager(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_field_manager = value.map(|value| value.into());
+        self
+    }
+
+    /// object name and auth scope, such as for teams and projects
+    #[inline]
+    pub fn namespace(mut self, value: impl Into<String>) -> EventPostBuilder<crate::codegen::generics::NamespaceExists, EventTime> {
+        self.inner.param_namespace = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// If 'true', then the output is pretty printed.
+    #[inline]
+    pub fn pretty(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_pretty = Some(value.into());
+        self
+    }
+
+    /// Sets [`pretty`](#method.pretty) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_pretty(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_pretty = value.map(|value| value.into());
+        self
+    }
+
+    /// What action was taken/failed regarding to the regarding object.
+    #[inline]
+    pub fn action(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.action = Some(value.into());
+        self
+    }
+
+    /// Sets [`action`](#method.action) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_action(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.action = value.map(|value| value.into());
+        self
+    }
+
+    /// APIVersion defines the versioned schema of this representation of an object. Servers should convert recognized schemas to the latest internal value, and may reject unrecognized values. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#resources
+    #[inline]
+    pub fn api_version(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.api_version = Some(value.into());
+        self
+    }
+
+    /// Sets [`api_version`](#method.api_version) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_api_version(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.api_version = value.map(|value| value.into());
+        self
+    }
+
+    /// Deprecated field assuring backward compatibility with core.v1 Event type
+    #[inline]
+    pub fn deprecated_count(mut self, value: impl Into<i32>) -> Self {
+        self.inner.body.deprecated_count = Some(value.into());
+        self
+    }
+
+    /// Sets [`deprecated_count`](#method.deprecated_count) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_deprecated_count(mut self, value: Option<impl Into<i32>>) -> Self {
+        self.inner.body.deprecated_count = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`deprecated_count`](#method.deprecated_count), but takes a reference.
+    #[inline]
+    pub fn deprecated_count_ref(self, value: &i32) -> Self {
+        self.deprecated_count(*value)
+    }
+
+    /// Deprecated field assuring backward compatibility with core.v1 Event type
+    #[inline]
+    pub fn deprecated_first_timestamp(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.deprecated_first_timestamp = Some(value.into());
+        self
+    }
+
+    /// Sets [`deprecated_first_timestamp`](#method.deprecated_first_timestamp) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_deprecated_first_timestamp(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.deprecated_first_timestamp = value.map(|value| value.into());
+        self
+    }
+
+    /// Deprecated field assuring backward compatibility with core.v1 Event type
+    #[inline]
+    pub fn deprecated_last_timestamp(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.deprecated_last_timestamp = Some(value.into());
+        self
+    }
+
+    /// Sets [`deprecated_last_timestamp`](#method.deprecated_last_timestamp) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_deprecated_last_timestamp(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.deprecated_last_timestamp = value.map(|value| value.into());
+        self
+    }
+
+    /// Deprecated field assuring backward compatibility with core.v1 Event type
+    #[inline]
+    pub fn deprecated_source(mut self, value: crate::codegen::io::k8s::api::core::v1::event_source::EventSource) -> Self {
+        self.inner.body.deprecated_source = Some(value.into());
+        self
+    }
+
+    /// Sets [`deprecated_source`](#method.deprecated_source) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_deprecated_source(mut self, value: Option<crate::codegen::io::k8s::api::core::v1::event_source::EventSource>) -> Self {
+        self.inner.body.deprecated_source = value.map(|value| value.into());
+        self
+    }
+
+    /// Required. Time when this Event was first observed.
+    #[inline]
+    pub fn event_time(mut self, value: impl Into<String>) -> EventPostBuilder<Namespace, crate::codegen::generics::EventTimeExists> {
+        self.inner.body.event_time = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Kind is a string value representing the REST resource this object represents. Servers may infer this from the endpoint the client submits requests to. Cannot be updated. In CamelCase. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#types-kinds
+    #[inline]
+    pub fn kind(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.kind = Some(value.into());
+        self
+    }
+
+    /// Sets [`kind`](#method.kind) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_kind(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.kind = value.map(|value| value.into());
+        self
+    }
+
+    #[inline]
+    pub fn metadata(mut self, value: crate::codegen::io::k8s::apimachinery::pkg::apis::meta::v1::object_meta::ObjectMeta) -> Self {
+        self.inner.body.metadata = Some(value.into());
+        self
+    }
+
+    /// Sets [`metadata`](#method.metadata) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_metadata(mut self, value: Option<crate::codegen::io::k8s::apimachinery::pkg::apis::meta::v1::object_meta::ObjectMeta>) -> Self {
+        self.inner.body.metadata = value.map(|value| value.into());
+        self
+    }
+
+    /// Optional. A human-readable description of the status of this operation. Maximal length of the note is 1kB, but libraries should be prepared to handle values up to 64kB.
+    #[inline]
+    pub fn note(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.note = Some(value.into());
+        self
+    }
+
+    /// Sets [`note`](#method.note) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_note(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.note = value.map(|value| value.into());
+        self
+    }
+
+    /// Why the action was taken.
+    #[inline]
+    pub fn reason(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.reason = Some(value.into());
+        self
+    }
+
+    /// Sets [`reason`](#method.reason) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_reason(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.reason = value.map(|value| value.into());
+        self
+    }
+
+    /// The object this Event is about. In most cases it's an Object reporting controller implements. E.g. ReplicaSetController implements ReplicaSets and this event is emitted because it acts on some changes in a ReplicaSet object.
+    #[inline]
+    pub fn regarding(mut self, value: crate::codegen::io::k8s::api::core::v1::object_reference::ObjectReference) -> Self {
+        self.inner.body.regarding = Some(value.into());
+        self
+    }
+
+    /// Sets [`regarding`](#method.regarding) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_regarding(mut self, value: Option<crate::codegen::io::k8s::api::core::v1::object_reference::ObjectReference>) -> Self {
+        self.inner.body.regarding = value.map(|value| value.into());
+        self
+    }
+
+    /// Optional secondary object for more complex actions. E.g. when regarding object triggers a creation or deletion of related object.
+    #[inline]
+    pub fn related(mut self, value: crate::codegen::io::k8s::api::core::v1::object_reference::ObjectReference) -> Self {
+        self.inner.body.related = Some(value.into());
+        self
+    }
+
+    /// Sets [`related`](#method.related) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_related(mut self, value: Option<crate::codegen::io::k8s::api::core::v1::object_reference::ObjectReference>) -> Self {
+        self.inner.body.related = value.map(|value| value.into());
+        self
+    }
+
+    /// Name of the controller that emitted this Event, e.g. `kubernetes.io/kubelet`.
+    #[inline]
+    pub fn reporting_controller(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.reporting_controller = Some(value.into());
+        self
+    }
+
+    /// Sets [`reporting_controller`](#method.reporting_controller) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_reporting_controller(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.reporting_controller = value.map(|value| value.into());
+        self
+    }
+
+    /// ID of the controller instance, e.g. `kubelet-xyzf`.
+    #[inline]
+    pub fn reporting_instance(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.reporting_instance = Some(value.into());
+        self
+    }
+
+    /// Sets [`reporting_instance`](#method.reporting_instance) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_reporting_instance(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.reporting_instance = value.map(|value| value.into());
+        self
+    }
+
+    /// Data about the Event series this event represents or nil if it's a singleton Event.
+    #[inline]
+    pub fn series(mut self, value: crate::codegen::io::k8s::api::events::v1beta1::event_series::EventSeriesBuilder<crate::codegen::generics::CountExists, crate::codegen::generics::LastObservedTimeExists, crate::codegen::generics::StateExists>) -> Self {
+        self.inner.body.series = Some(value.into());
+        self
+    }
+
+    /// Sets [`series`](#method.series) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_series(mut self, value: Option<crate::codegen::io::k8s::api::events::v1beta1::event_series::EventSeriesBuilder<crate::codegen::generics::CountExists, crate::codegen::generics::LastObservedTimeExists, crate::codegen::generics::StateExists>>) -> Self {
+        self.inner.body.series = value.map(|value| value.into());
+        self
+    }
+
+    /// Type of this event (Normal, Warning), new types could be added in the future.
+    #[inline]
+    pub fn type_(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.type_ = Some(value.into());
+        self
+    }
+
+    /// Sets [`type_`](#method.type_) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_type(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.type_ = value.map(|value| value.into());
+        self
+    }
+}
+
+impl<Client: crate::codegen::client::ApiClient + Sync + 'static> crate::codegen::client::Sendable<Client> for EventPostBuilder<crate::codegen::generics::NamespaceExists, crate::codegen::generics::EventTimeExists> {
+    type Output = crate::codegen::io::k8s::api::events::v1beta1::event::Event;
+
+    const METHOD: http::Method = http::Method::POST;
+
+    const OPERATION_ID: Option<&'static str> = Some("createEventsV1beta1NamespacedEvent");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::codegen::client::ApiError<Client::Response>> {
+        Ok(format!("/apis/events.k8s.io/v1beta1/namespaces/{namespace}/events", namespace=crate::codegen::client::path_param("namespace", self.inner.param_namespace.as_ref().expect("missing parameter namespace?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::codegen::client::ApiError<Client::Response>> {
+        use crate::codegen::client::Request;
+        Ok(req
+        .header(http::header::CONTENT_TYPE.as_str(), "application/json")
+        .json(&self.inner.body)
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("dryRun", self.inner.param_dry_run.as_ref().map(std::string::ToString::to_string)),
+            ("fieldManager", self.inner.param_field_manager.as_ref().map(std::string::ToString::to_string)),
+            ("pretty", self.inner.param_pretty.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Content-Type")
+            || name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<EventPostBuilder<crate::codegen::generics::NamespaceExists, crate::codegen::generics::EventTimeExists>> for reqwest::Request {
+    type Error = crate::codegen::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: EventPostBuilder<crate::codegen::generics::NamespaceExists, crate::codegen::generics::EventTimeExists>) -> Result<Self, Self::Error> {
+        let req = crate::codegen::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::codegen::client::ApiError::Reqwest)
+    }
+}
+
+/// Builder created by [`Event::read_events_v1beta1_namespaced_event`](./struct.Event.html#method.read_events_v1beta1_namespaced_event) method for a `GET` operation associated with `Event`.
+#[repr(transparent)]
+#[derive(Debug, Clone)]
+pub struct EventGetBuilder1<Name, Namespace> {
+    inner: EventGetBuilder1Container,
+    _param_name: core::marker::PhantomData<Name>,
+    _param_namespace: core::marker::PhantomData<Namespace>,
+}
+
+#[derive(Debug, Default, Clone)]
+struct EventGetBuilder1Container {
+    param_exact: Option<bool>,
+    param_export: Option<bool>,
+    param_name: Option<String>,
+    param_namespace: Option<String>,
+    param_pretty: Option<String>,
+}
+
+impl<Name, Namespace> EventGetBuilder1<Name, Namespace> {
+    /// Should the export be exact.  Exact export maintains cluster-specific fields like 'Namespace'. Deprecated. Planned for removal in 1.18.
+    #[inline]
+    pub fn exact(mut self, value: impl Into<bool>) -> Self {
+        self.inner.param_exact = Some(value.into());
+        self
+    }
+
+    /// Sets [`exact`](#method.exact) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_exact(mut self, value: Option<impl Into<bool>>) -> Self {
+        self.inner.param_exact = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`exact`](#method.exact), but takes a reference.
+    #[inline]
+    pub fn exact_ref(self, value: &bool) -> Self {
+        self.exact(*value)
+    }
+
+    /// Should this value be exported.  Export strips fields that a user can not specify. Deprecated. Planned for removal in 1.18.
+    #[inline]
+    pub fn export(mut self, value: impl Into<bool>) -> Self {
+        self.inner.param_export = Some(value.into());
+        self
+    }
+
+    /// Sets [`export`](#method.export) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_export(mut self, value: Option<impl Into<bool>>) -> Self {
+        self.inner.param_export = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`export`](#method.export), but takes a reference.
+    #[inline]
+    pub fn export_ref(self, value: &bool) -> Self {
+        self.export(*value)
+    }
+
+    /// name of the Event
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> EventGetBuilder1<crate::codegen::generics::NameExists, Namespace> {
+        self.inner.param_name = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// object name and auth scope, such as for teams and projects
+    #[inline]
+    pub fn namespace(mut self, value: impl Into<String>) -> EventGetBuilder1<Name, crate::codegen::generics::NamespaceExists> {
+        self.inner.param_namespace = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// If 'true', then the output is pretty printed.
+    #[inline]
+    pub fn pretty(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_pretty = Some(value.into());
+        self
+    }
+
+    /// Sets [`pretty`](#method.pretty) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_pretty(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_pretty = value.map(|value| value.into());
+        self
+    }
+}
+
+impl<Client: crate::codegen::client::ApiClient + Sync + 'static> crate::codegen::client::Sendable<Client> for EventGetBuilder1<crate::codegen::generics::NameExists, crate::codegen::generics::NamespaceExists> {
+    type Output = Event;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    const OPERATION_ID: Option<&'static str> = Some("readEventsV1beta1NamespacedEvent");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::codegen::client::ApiError<Client::Response>> {
+        Ok(format!("/apis/events.k8s.io/v1beta1/namespaces/{namespace}/events/{name}", name=crate::codegen::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?, namespace=crate::codegen::client::path_param("namespace", self.inner.param_namespace.as_ref().expect("missing parameter namespace?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::codegen::client::ApiError<Client::Response>> {
+        use crate::codegen::client::Request;
+        Ok(req
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("exact", self.inner.param_exact.as_ref().map(std::string::ToString::to_string)),
+            ("export", self.inner.param_export.as_ref().map(std::string::ToString::to_string)),
+            ("pretty", self.inner.param_pretty.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<EventGetBuilder1<crate::codegen::generics::NameExists, crate::codegen::generics::NamespaceExists>> for reqwest::Request {
+    type Error = crate::codegen::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: EventGetBuilder1<crate::codegen::generics::NameExists, crate::codegen::generics::NamespaceExists>) -> Result<Self, Self::Error> {
+        let req = crate::codegen::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::codegen::client::ApiError::Reqwest)
+    }
+}
+
+/// Builder created by [`Event::replace_events_v1beta1_namespaced_event`](./struct.Event.html#method.replace_events_v1beta1_namespaced_event) method for a `PUT` operation associated with `Event`.
+#[repr(transparent)]
+#[derive(Debug, Clone)]
+pub struct EventPutBuilder1<Name, Namespace, EventTime> {
+    inner: EventPutBuilder1Container,
+    _param_name: core::marker::PhantomData<Name>,
+    _param_namespace: core::marker::PhantomData<Namespace>,
+    _event_time: core::marker::PhantomData<EventTime>,
+}
+
+#[derive(Debug, Default, Clone)]
+struct EventPutBuilder1Container {
+    body: self::Event,
+    param_dry_run: Option<String>,
+    param_field_manager: Option<String>,
+    param_name: Option<String>,
+    param_namespace: Option<String>,
+    param_pretty: Option<String>,
+}
+
+impl<Name, Namespace, EventTime> EventPutBuilder1<Name, Namespace, EventTime> {
+    /// When present, indicates that modifications should not be persisted. An invalid or unrecognized dryRun directive will result in an error response and no further processing of the request. Valid values are: - All: all dry run stages will be processed
+    #[inline]
+    pub fn dry_run(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_dry_run = Some(value.into());
+        self
+    }
+
+    /// Sets [`dry_run`](#method.dry_run) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_dry_run(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_dry_run = value.map(|value| value.into());
+        self
+    }
+
+    /// fieldManager is a name associated with the actor or entity that is making these changes. The value must be less than or 128 characters long, and only contain printable characters, as defined by https://golang.org/pkg/unicode/#IsPrint.
+    #[inline]
+    pub fn field_manager(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_field_manager = Some(value.into());
+        self
+    }
+
+    /// Sets [`field_manager`](#method.field_manager) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_field_manager(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_field_manager = value.map(|value| value.into());
+        self
+    }
+
+    /// name of the Event
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> EventPutBuilder1<crate::codegen::generics::NameExists, Namespace, EventTime> {
+        self.inner.param_name = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// object name and auth scope, such as for teams and projects
+    #[inline]
+    pub fn namespace(mut self, value: impl Into<String>) -> EventPutBuilder1<Name, crate::codegen::generics::NamespaceExists, EventTime> {
+        self.inner.param_namespace = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// If 'true', then the output is pretty printed.
+    #[inline]
+    pub fn pretty(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_pretty = Some(value.into());
+        self
+    }
+
+    /// Sets [`pretty`](#method.pretty) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_pretty(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_pretty = value.map(|value| value.into());
+        self
+    }
+
+    /// What action was taken/failed regarding to the regarding object.
+    #[inline]
+    pub fn action(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.action = Some(value.into());
+        self
+    }
+
+    /// Sets [`action`](#method.action) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_action(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.action = value.map(|value| value.into());
+        self
+    }
+
+    /// APIVersion defines the versioned schema of this representation of an object. Servers should convert recognized schemas to the latest internal value, and may reject unrecognized values. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#resources
+    #[inline]
+    pub fn api_version(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.api_version = Some(value.into());
+        self
+    }
+
+    /// Sets [`api_version`](#method.api_version) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_api_version(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.api_version = value.map(|value| value.into());
+        self
+    }
+
+    /// Deprecated field assuring backward compatibility with core.v1 Event type
+    #[inline]
+    pub fn deprecated_count(mut self, value: impl Into<i32>) -> Self {
+        self.inner.body.deprecated_count = Some(value.into());
+        self
+    }
+
+    /// Sets [`deprecated_count`](#method.deprecated_count) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_deprecated_count(mut self, value: Option<impl Into<i32>>) -> Self {
+        self.inner.body.deprecated_count = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`deprecated_count`](#method.deprecated_count), but takes a reference.
+    #[inline]
+    pub fn deprecated_count_ref(self, value: &i32) -> Self {
+        self.deprecated_count(*value)
+    }
+
+    /// Deprecated field assuring backward compatibility with core.v1 Event type
+    #[inline]
+    pub fn deprecated_first_timestamp(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.deprecated_first_timestamp = Some(value.into());
+        self
+    }
+
+    /// Sets [`deprecated_first_timestamp`](#method.deprecated_first_timestamp) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_deprecated_first_timestamp(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.deprecated_first_timestamp = value.map(|value| value.into());
+        self
+    }
+
+    /// Deprecated field assuring backward compatibility with core.v1 Event type
+    #[inline]
+    pub fn deprecated_last_timestamp(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.deprecated_last_timestamp = Some(value.into());
+        self
+    }
+
+    /// Sets [`deprecated_last_timestamp`](#method.deprecated_last_timestamp) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_deprecated_last_timestamp(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.deprecated_last_timestamp = value.map(|value| value.into());
+        self
+    }
+
+    /// Deprecated field assuring backward compatibility with core.v1 Event type
+    #[inline]
+    pub fn deprecated_source(mut self, value: crate::codegen::io::k8s::api::core::v1::event_source::EventSource) -> Self {
+        self.inner.body.deprecated_source = Some(value.into());
+        self
+    }
+
+    /// Sets [`deprecated_source`](#method.deprecated_source) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_deprecated_source(mut self, value: Option<crate::codegen::io::k8s::api::core::v1::event_source::EventSource>) -> Self {
+        self.inner.body.deprecated_source = value.map(|value| value.into());
+        self
+    }
+
+    /// Required. Time when this Event was first observed.
+    #[inline]
+    pub fn event_time(mut self, value: impl Into<String>) -> EventPutBuilder1<Name, Namespace, crate::codegen::generics::EventTimeExists> {
+        self.inner.body.event_time = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Kind is a string value representing the REST resource this object represents. Servers may infer this from the endpoint the client submits requests to. Cannot be updated. In CamelCase. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#types-kinds
+    #[inline]
+    pub fn kind(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.kind = Some(value.into());
+        self
+    }
+
+    /// Sets [`kind`](#method.kind) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_kind(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.kind = value.map(|value| value.into());
+        self
+    }
+
+    #[inline]
+    pub fn metadata(mut self, value: crate::codegen::io::k8s::apimachinery::pkg::apis::meta::v1::object_meta::ObjectMeta) -> Self {
+        self.inner.body.metadata = Some(value.into());
+        self
+    }
+
+    /// Sets [`metadata`](#method.metadata) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_metadata(mut self, value: Option<crate::codegen::io::k8s::apimachinery::pkg::apis::meta::v1::object_meta::ObjectMeta>) -> Self {
+        self.inner.body.metadata = value.map(|value| value.into());
+        self
+    }
+
+    /// Optional. A human-readable description of the status of this operation. Maximal length of the note is 1kB, but libraries should be prepared to handle values up to 64kB.
+    #[inline]
+    pub fn note(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.note = Some(value.into());
+        self
+    }
+
+    /// Sets [`note`](#method.note) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_note(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.note = value.map(|value| value.into());
+        self
+    }
+
+    /// Why the action was taken.
+    #[inline]
+    pub fn reason(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.reason = Some(value.into());
+        self
+    }
+
+    /// Sets [`reason`](#method.reason) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_reason(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.reason = value.map(|value| value.into());
+        self
+    }
+
+    /// The object this Event is about. In most cases it's an Object reporting controller implements. E.g. ReplicaSetController implements ReplicaSets and this event is emitted because it acts on some changes in a ReplicaSet object.
+    #[inline]
+    pub fn regarding(mut self, value: crate::codegen::io::k8s::api::core::v1::object_reference::ObjectReference) -> Self {
+        self.inner.body.regarding = Some(value.into());
+        self
+    }
+
+    /// Sets [`regarding`](#method.regarding) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_regarding(mut self, value: Option<crate::codegen::io::k8s::api::core::v1::object_reference::ObjectReference>) -> Self {
+        self.inner.body.regarding = value.map(|value| value.into());
+        self
+    }
+
+    /// Optional secondary object for more complex actions. E.g. when regarding object triggers a creation or deletion of related object.
+    #[inline]
+    pub fn related(mut self, value: crate::codegen::io::k8s::api::core::v1::object_reference::ObjectReference) -> Self {
+        self.inner.body.related = Some(value.into());
+        self
+    }
+
+    /// Sets [`related`](#method.related) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_related(mut self, value: Option<crate::codegen::io::k8s::api::core::v1::object_reference::ObjectReference>) -> Self {
+        self.inner.body.related = value.map(|value| value.into());
+        self
+    }
+
+    /// Name of the controller that emitted this Event, e.g. `kubernetes.io/kubelet`.
+    #[inline]
+    pub fn reporting_controller(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.reporting_controller = Some(value.into());
+        self
+    }
+
+    /// Sets [`reporting_controller`](#method.reporting_controller) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_reporting_controller(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.reporting_controller = value.map(|value| value.into());
+        self
+    }
+
+    /// ID of the controller instance, e.g. `kubelet-xyzf`.
+    #[inline]
+    pub fn reporting_instance(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.reporting_instance = Some(value.into());
+        self
+    }
+
+    /// Sets [`reporting_instance`](#method.reporting_instance) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_reporting_instance(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.reporting_instance = value.map(|value| value.into());
+        self
+    }
+
+    /// Data about the Event series this event represents or nil if it's a singleton Event.
+    #[inline]
+    pub fn series(mut self, value: crate::codegen::io::k8s::api::events::v1beta1::event_series::EventSeriesBuilder<crate::codegen::generics::CountExists, crate::codegen::generics::LastObservedTimeExists, crate::codegen::generics::StateExists>) -> Self {
+        self.inner.body.series = Some(value.into());
+        self
+    }
+
+    /// Sets [`series`](#method.series) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_series(mut self, value: Option<crate::codegen::io::k8s::api::events::v1beta1::event_series::EventSeriesBuilder<crate::codegen::generics::CountExists, crate::codegen::generics::LastObservedTimeExists, crate::codegen::generics::StateExists>>) -> Self {
+        self.inner.body.series = value.map(|value| value.into());
+        self
+    }
+
+    /// Type of this event (Normal, Warning), new types could be added in the future.
+    #[inline]
+    pub fn type_(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.type_ = Some(value.into());
+        self
+    }
+
+    /// Sets [`type_`](#method.type_) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_type(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.type_ = value.map(|value| value.into());
+        self
+    }
+}
+
+impl<Client: crate::codegen::client::ApiClient + Sync + 'static> crate::codegen::client::Sendable<Client> for EventPutBuilder1<crate::codegen::generics::NameExists, crate::codegen::generics::NamespaceExists, crate::codegen::generics::EventTimeExists> {
+    type Output = crate::codegen::io::k8s::api::events::v1beta1::event::Event;
+
+    const METHOD: http::Method = http::Method::PUT;
+
+    const OPERATION_ID: Option<&'static str> = Some("replaceEventsV1beta1NamespacedEvent");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::codegen::client::ApiError<Client::Response>> {
+        Ok(format!("/apis/events.k8s.io/v1beta1/namespaces/{namespace}/events/{name}", name=crate::codegen::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?, namespace=crate::codegen::client::path_param("namespace", self.inner.param_namespace.as_ref().expect("missing parameter namespace?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::codegen::client::ApiError<Client::Response>> {
+        use crate::codegen::client::Request;
+        Ok(req
+        .header(http::header::CONTENT_TYPE.as_str(), "application/json")
+        .json(&self.inner.body)
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("dryRun", self.inner.param_dry_run.as_ref().map(std::string::ToString::to_string)),
+            ("fieldManager", self.inner.param_field_manager.as_ref().map(std::string::ToString::to_string)),
+            ("pretty", self.inner.param_pretty.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Content-Type")
+            || name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<EventPutBuilder1<crate::codegen::generics::NameExists, crate::codegen::generics::NamespaceExists, crate::codegen::generics::EventTimeExists>> for reqwest::Request {
+    type Error = crate::codegen::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: EventPutBuilder1<crate::codegen::generics::NameExists, crate::codegen::generics::NamespaceExists, crate::codegen::generics::EventTimeExists>) -> Result<Self, Self::Error> {
+        let req = crate::codegen::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::codegen::client::ApiError::Reqwest)
+    }
+}
@@ -0,0 +1,83 @@
+
+/// EventSeries contain information on series of events, i.e. thing that was/is happening continuously for some time.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct EventSeries {
+    /// Number of occurrences in this series up to the last heartbeat time
+    pub count: i32,
+    /// Time when last Event from the series was seen before last heartbeat.
+    #[serde(rename = "lastObservedTime")]
+    pub last_observed_time: String,
+    /// Information whether this series is ongoing or finished. Deprecated. Planned removal for 1.18
+    pub state: String,
+}
+
+impl EventSeries {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> EventSeriesBuilder<crate::codegen::generics::MissingCount, crate::codegen::generics::MissingLastObservedTime, crate::codegen::generics::MissingState> {
+        EventSeriesBuilder {
+            body: Default::default(),
+            _count: core::marker::PhantomData,
+            _last_observed_time: core::marker::PhantomData,
+            _state: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> EventSeriesBuilder<crate::codegen::generics::CountExists, crate::codegen::generics::LastObservedTimeExists, crate::codegen::generics::StateExists>
+    where
+        Self: Clone,
+    {
+        EventSeriesBuilder {
+            body: self.clone(),
+            _count: core::marker::PhantomData,
+            _last_observed_time: core::marker::PhantomData,
+            _state: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<EventSeries> for EventSeriesBuilder<crate::codegen::generics::CountExists, crate::codegen::generics::LastObservedTimeExists, crate::codegen::generics::StateExists> {
+    fn into(self) -> EventSeries {
+        self.body
+    }
+}
+
+/// Builder for [`EventSeries`](./struct.EventSeries.html) object.
+#[derive(Debug, Clone)]
+pub struct EventSeriesBuilder<Count, LastObservedTime, State> {
+    body: self::EventSeries,
+    _count: core::marker::PhantomData<Count>,
+    _last_observed_time: core::marker::PhantomData<LastObservedTime>,
+    _state: core::marker::PhantomData<State>,
+}
+
+impl<Count, LastObservedTime, State> EventSeriesBuilder<Count, LastObservedTime, State> {
+    /// Number of occurrences in this series up to the last heartbeat time
+    #[inline]
+    pub fn count(mut self, value: impl Into<i32>) -> EventSeriesBuilder<crate::codegen::generics::CountExists, LastObservedTime, State> {
+        self.body.count = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Same as [`count`](#method.count), but takes a reference.
+    #[inline]
+    pub fn count_ref(self, value: &i32) -> EventSeriesBuilder<crate::codegen::generics::CountExists, LastObservedTime, State> {
+        self.count(*value)
+    }
+
+    /// Time when last Event from the series was seen before last heartbeat.
+    #[inline]
+    pub fn last_observed_time(mut self, value: impl Into<String>) -> EventSeriesBuilder<Count, crate::codegen::generics::LastObservedTimeExists, State> {
+        self.body.last_observed_time = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Information whether this series is ongoing or finished. Deprecated. Planned removal for 1.18
+    #[inline]
+    pub fn state(mut self, value: impl Into<String>) -> EventSeriesBuilder<Count, LastObservedTime, crate::codegen::generics::StateExists> {
+        self.body.state = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+}
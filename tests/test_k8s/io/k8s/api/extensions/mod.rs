@@ -0,0 +1,4 @@
+
+pub mod v1beta1 {
+    include!("./v1beta1/mod.rs");
+}
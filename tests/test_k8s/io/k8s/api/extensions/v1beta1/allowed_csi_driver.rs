@@ -0,0 +1,52 @@
+
+/// AllowedCSIDriver represents a single inline CSI Driver that is allowed to be used.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct AllowedCsiDriver {
+    /// Name is the registered name of the CSI driver
+    pub name: String,
+}
+
+impl AllowedCsiDriver {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> AllowedCsiDriverBuilder<crate::codegen::generics::MissingName> {
+        AllowedCsiDriverBuilder {
+            body: Default::default(),
+            _name: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> AllowedCsiDriverBuilder<crate::codegen::generics::NameExists>
+    where
+        Self: Clone,
+    {
+        AllowedCsiDriverBuilder {
+            body: self.clone(),
+            _name: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<AllowedCsiDriver> for AllowedCsiDriverBuilder<crate::codegen::generics::NameExists> {
+    fn into(self) -> AllowedCsiDriver {
+        self.body
+    }
+}
+
+/// Builder for [`AllowedCsiDriver`](./struct.AllowedCsiDriver.html) object.
+#[derive(Debug, Clone)]
+pub struct AllowedCsiDriverBuilder<Name> {
+    body: self::AllowedCsiDriver,
+    _name: core::marker::PhantomData<Name>,
+}
+
+impl<Name> AllowedCsiDriverBuilder<Name> {
+    /// Name is the registered name of the CSI driver
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> AllowedCsiDriverBuilder<crate::codegen::generics::NameExists> {
+        self.body.name = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+}
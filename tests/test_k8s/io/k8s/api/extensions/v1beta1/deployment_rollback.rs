@@ -0,0 +1,305 @@
+
+/// DEPRECATED. DeploymentRollback stores the information required to rollback a deployment.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct DeploymentRollback {
+    /// APIVersion defines the versioned schema of this representation of an object. Servers should convert recognized schemas to the latest internal value, and may reject unrecognized values. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#resources
+    #[serde(rename = "apiVersion", skip_serializing_if = "Option::is_none")]
+    pub api_version: Option<String>,
+    /// Kind is a string value representing the REST resource this object represents. Servers may infer this from the endpoint the client submits requests to. Cannot be updated. In CamelCase. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#types-kinds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub kind: Option<String>,
+    /// Required: This must match the Name of a deployment.
+    pub name: String,
+    /// The config of this deployment rollback.
+    #[serde(rename = "rollbackTo")]
+    pub rollback_to: crate::codegen::io::k8s::api::extensions::v1beta1::rollback_config::RollbackConfig,
+    /// The annotations to be updated to a deployment
+    #[serde(rename = "updatedAnnotations", skip_serializing_if = "Option::is_none")]
+    pub updated_annotations: Option<std::collections::BTreeMap<String, String>>,
+}
+
+impl DeploymentRollback {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> DeploymentRollbackBuilder<crate::codegen::generics::MissingName, crate::codegen::generics::MissingRollbackTo> {
+        DeploymentRollbackBuilder {
+            body: Default::default(),
+            _name: core::marker::PhantomData,
+            _rollback_to: core::marker::PhantomData,
+        }
+    }
+
+    /// create rollback of a Deployment
+    #[inline]
+    pub fn create_extensions_v1beta1_namespaced_deployment_rollback() -> DeploymentRollbackPostBuilder<crate::codegen::generics::MissingName, crate::codegen::generics::MissingNamespace, crate::codegen::generics::MissingRollbackTo> {
+        DeploymentRollbackPostBuilder {
+            inner: Default::default(),
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+            _rollback_to: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> DeploymentRollbackBuilder<crate::codegen::generics::NameExists, crate::codegen::generics::RollbackToExists>
+    where
+        Self: Clone,
+    {
+        DeploymentRollbackBuilder {
+            body: self.clone(),
+            _name: core::marker::PhantomData,
+            _rollback_to: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<DeploymentRollback> for DeploymentRollbackBuilder<crate::codegen::generics::NameExists, crate::codegen::generics::RollbackToExists> {
+    fn into(self) -> DeploymentRollback {
+        self.body
+    }
+}
+
+impl Into<DeploymentRollback> for DeploymentRollbackPostBuilder<crate::codegen::generics::NameExists, crate::codegen::generics::NamespaceExists, crate::codegen::generics::RollbackToExists> {
+    fn into(self) -> DeploymentRollback {
+        self.inner.body
+    }
+}
+
+/// Builder for [`DeploymentRollback`](./struct.DeploymentRollback.html) object.
+#[derive(Debug, Clone)]
+pub struct DeploymentRollbackBuilder<Name, RollbackTo> {
+    body: self::DeploymentRollback,
+    _name: core::marker::PhantomData<Name>,
+    _rollback_to: core::marker::PhantomData<RollbackTo>,
+}
+
+impl<Name, RollbackTo> DeploymentRollbackBuilder<Name, RollbackTo> {
+    /// APIVersion defines the versioned schema of this representation of an object. Servers should convert recognized schemas to the latest internal value, and may reject unrecognized values. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#resources
+    #[inline]
+    pub fn api_version(mut self, value: impl Into<String>) -> Self {
+        self.body.api_version = Some(value.into());
+        self
+    }
+
+    /// Sets [`api_version`](#method.api_version) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_api_version(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.api_version = value.map(|value| value.into());
+        self
+    }
+
+    /// Kind is a string value representing the REST resource this object represents. Servers may infer this from the endpoint the client submits requests to. Cannot be updated. In CamelCase. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#types-kinds
+    #[inline]
+    pub fn kind(mut self, value: impl Into<String>) -> Self {
+        self.body.kind = Some(value.into());
+        self
+    }
+
+    /// Sets [`kind`](#method.kind) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_kind(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.kind = value.map(|value| value.into());
+        self
+    }
+
+    /// Required: This must match the Name of a deployment.
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> DeploymentRollbackBuilder<crate::codegen::generics::NameExists, RollbackTo> {
+        self.body.name = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// The config of this deployment rollback.
+    #[inline]
+    pub fn rollback_to(mut self, value: crate::codegen::io::k8s::api::extensions::v1beta1::rollback_config::RollbackConfig) -> DeploymentRollbackBuilder<Name, crate::codegen::generics::RollbackToExists> {
+        self.body.rollback_to = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// The annotations to be updated to a deployment
+    #[inline]
+    pub fn updated_annotations(mut self, value: impl Iterator<Item = (String, impl Into<String>)>) -> Self {
+        self.body.updated_annotations = Some(value.map(|(key, value)| (key, value.into())).collect::<std::collections::BTreeMap<_, _>>().into());
+        self
+    }
+
+    /// Sets [`updated_annotations`](#method.updated_annotations) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_updated_annotations(mut self, value: Option<impl Iterator<Item = (String, impl Into<String>)>>) -> Self {
+        self.body.updated_annotations = value.map(|value| value.map(|(key, value)| (key, value.into())).collect::<std::collections::BTreeMap<_, _>>().into());
+        self
+    }
+}
+
+/// Builder created by [`DeploymentRollback::create_extensions_v1beta1_namespaced_deployment_rollback`](./struct.DeploymentRollback.html#method.create_extensions_v1beta1_namespaced_deployment_rollback) method for a `POST` operation associated with `DeploymentRollback`.
+#[repr(transparent)]
+#[derive(Debug, Clone)]
+pub struct DeploymentRollbackPostBuilder<Name, Namespace, RollbackTo> {
+    inner: DeploymentRollbackPostBuilderContainer,
+    _param_name: core::marker::PhantomData<Name>,
+    _param_namespace: core::marker::PhantomData<Namespace>,
+    _rollback_to: core::marker::PhantomData<RollbackTo>,
+}
+
+#[derive(Debug, Default, Clone)]
+struct DeploymentRollbackPostBuilderContainer {
+    body: self::DeploymentRollback,
+    param_dry_run: Option<String>,
+    param_field_manager: Option<String>,
+    param_name: Option<String>,
+    param_namespace: Option<String>,
+    param_pretty: Option<String>,
+}
+
+impl<Name, Namespace, RollbackTo> DeploymentRollbackPostBuilder<Name, Namespace, RollbackTo> {
+    /// When present, indicates that modifications should not be persisted. An invalid or unrecognized dryRun directive will result in an error response and no further processing of the request. Valid values are: - All: all dry run stages will be processed
+    #[inline]
+    pub fn dry_run(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_dry_run = Some(value.into());
+        self
+    }
+
+    /// Sets [`dry_run`](#method.dry_run) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_dry_run(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_dry_run = value.map(|value| value.into());
+        self
+    }
+
+    /// fieldManager is a name associated with the actor or entity that is making these changes. The value must be less than or 128 characters long, and only contain printable characters, as defined by https://golang.org/pkg/unicode/#IsPrint.
+    #[inline]
+    pub fn field_manager(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_field_manager = Some(value.into());
+        self
+    }
+
+    /// Sets [`field_manager`](#method.field_manager) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_field_manager(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_field_manager = value.map(|value| value.into());
+        self
+    }
+
+    /// name of the DeploymentRollback
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> DeploymentRollbackPostBuilder<crate::codegen::generics::NameExists, Namespace, RollbackTo> {
+        self.inner.param_name = Some({
+            let val = value.into();
+            self.inner.body.name = val.clone().into();
+            val
+        });
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// object name and auth scope, such as for teams and projects
+    #[inline]
+    pub fn namespace(mut self, value: impl Into<String>) -> DeploymentRollbackPostBuilder<Name, crate::codegen::generics::NamespaceExists, RollbackTo> {
+        self.inner.param_namespace = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// If 'true', then the output is pretty printed.
+    #[inline]
+    pub fn pretty(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_pretty = Some(value.into());
+        self
+    }
+
+    /// Sets [`pretty`](#method.pretty) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_pretty(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_pretty = value.map(|value| value.into());
+        self
+    }
+
+    /// APIVersion defines the versioned schema of this representation of an object. Servers should convert recognized schemas to the latest internal value, and may reject unrecognized values. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#resources
+    #[inline]
+    pub fn api_version(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.api_version = Some(value.into());
+        self
+    }
+
+    /// Sets [`api_version`](#method.api_version) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_api_version(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.api_version = value.map(|value| value.into());
+        self
+    }
+
+    /// Kind is a string value representing the REST resource this object represents. Servers may infer this from the endpoint the client submits requests to. Cannot be updated. In CamelCase. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#types-kinds
+    #[inline]
+    pub fn kind(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.kind = Some(value.into());
+        self
+    }
+
+    /// Sets [`kind`](#method.kind) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_kind(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.kind = value.map(|value| value.into());
+        self
+    }
+
+    /// The config of this deployment rollback.
+    #[inline]
+    pub fn rollback_to(mut self, value: crate::codegen::io::k8s::api::extensions::v1beta1::rollback_config::RollbackConfig) -> DeploymentRollbackPostBuilder<Name, Namespace, crate::codegen::generics::RollbackToExists> {
+        self.inner.body.rollback_to = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// The annotations to be updated to a deployment
+    #[inline]
+    pub fn updated_annotations(mut self, value: impl Iterator<Item = (String, impl Into<String>)>) -> Self {
+        self.inner.body.updated_annotations = Some(value.map(|(key, value)| (key, value.into())).collect::<std::collections::BTreeMap<_, _>>().into());
+        self
+    }
+
+    /// Sets [`updated_annotations`](#method.updated_annotations) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_updated_annotations(mut self, value: Option<impl Iterator<Item = (String, impl Into<String>)>>) -> Self {
+        self.inner.body.updated_annotations = value.map(|value| value.map(|(key, value)| (key, value.into())).collect::<std::collections::BTreeMap<_, _>>().into());
+        self
+    }
+}
+
+impl<Client: crate::codegen::client::ApiClient + Sync + 'static> crate::codegen::client::Sendable<Client> for DeploymentRollbackPostBuilder<crate::codegen::generics::NameExists, crate::codegen::generics::NamespaceExists, crate::codegen::generics::RollbackToExists> {
+    type Output = crate::codegen::io::k8s::apimachinery::pkg::apis::meta::v1::status::Status;
+
+    const METHOD: http::Method = http::Method::POST;
+
+    const OPERATION_ID: Option<&'static str> = Some("createExtensionsV1beta1NamespacedDeploymentRollback");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::codegen::client::ApiError<Client::Response>> {
+        Ok(format!("/apis/extensions/v1beta1/namespaces/{namespace}/deployments/{name}/rollback", name=crate::codegen::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?, namespace=crate::codegen::client::path_param("namespace", self.inner.param_namespace.as_ref().expect("missing parameter namespace?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::codegen::client::ApiError<Client::Response>> {
+        use crate::codegen::client::Request;
+        Ok(req
+        .header(http::header::CONTENT_TYPE.as_str(), "application/json")
+        .json(&self.inner.body)
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("dryRun", self.inner.param_dry_run.as_ref().map(std::string::ToString::to_string)),
+            ("fieldManager", self.inner.param_field_manager.as_ref().map(std::string::ToString::to_string)),
+            ("pretty", self.inner.param_pretty.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Content-Type")
+            || name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<DeploymentRollbackPostBuilder<crate::codegen::generics::NameExists, crate::codegen::generics::NamespaceExists, crate::codegen::generics::RollbackToExists>> for reqwest::Request {
+    type Error = crate::codegen::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: DeploymentRollbackPostBuilder<crate::codegen::generics::NameExists, crate::codegen::generics::NamespaceExists, crate::codegen::generics::RollbackToExists>) -> Result<Self, Self::Error> {
+        let req = crate::codegen::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::codegen::client::ApiError::Reqwest)
+    }
+}
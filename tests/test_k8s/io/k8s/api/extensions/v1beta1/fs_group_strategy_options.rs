@@ -0,0 +1,81 @@
+
+/// FSGroupStrategyOptions defines the strategy type and options used to create the strategy. Deprecated: use FSGroupStrategyOptions from policy API Group instead.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct FsGroupStrategyOptions {
+    /// ranges are the allowed ranges of fs groups.  If you would like to force a single fs group then supply a single range with the same start and end. Required for MustRunAs.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ranges: Option<Vec<crate::codegen::io::k8s::api::extensions::v1beta1::id_range::IdRange>>,
+    /// rule is the strategy that will dictate what FSGroup is used in the SecurityContext.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rule: Option<String>,
+}
+
+impl FsGroupStrategyOptions {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> FsGroupStrategyOptionsBuilder {
+        FsGroupStrategyOptionsBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> FsGroupStrategyOptionsBuilder
+    where
+        Self: Clone,
+    {
+        FsGroupStrategyOptionsBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<FsGroupStrategyOptions> for FsGroupStrategyOptionsBuilder {
+    fn into(self) -> FsGroupStrategyOptions {
+        self.body
+    }
+}
+
+/// Builder for [`FsGroupStrategyOptions`](./struct.FsGroupStrategyOptions.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct FsGroupStrategyOptionsBuilder {
+    body: self::FsGroupStrategyOptions,
+}
+
+impl FsGroupStrategyOptionsBuilder {
+    /// ranges are the allowed ranges of fs groups.  If you would like to force a single fs group then supply a single range with the same start and end. Required for MustRunAs.
+    #[inline]
+    pub fn ranges(mut self, value: impl Iterator<Item = crate::codegen::io::k8s::api::extensions::v1beta1::id_range::IdRangeBuilder<crate::codegen::generics::MaxExists, crate::codegen::generics::MinExists>>) -> Self {
+        self.body.ranges = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`ranges`](#method.ranges) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_ranges(mut self, value: Option<impl Iterator<Item = crate::codegen::io::k8s::api::extensions::v1beta1::id_range::IdRangeBuilder<crate::codegen::generics::MaxExists, crate::codegen::generics::MinExists>>>) -> Self {
+        self.body.ranges = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`ranges`](#method.ranges), but takes the values as a `Vec`.
+    #[inline]
+    pub fn ranges_vec(mut self, value: impl Into<Vec<crate::codegen::io::k8s::api::extensions::v1beta1::id_range::IdRange>>) -> Self {
+        self.body.ranges = Some(value.into());
+        self
+    }
+
+    /// rule is the strategy that will dictate what FSGroup is used in the SecurityContext.
+    #[inline]
+    pub fn rule(mut self, value: impl Into<String>) -> Self {
+        self.body.rule = Some(value.into());
+        self
+    }
+
+    /// Sets [`rule`](#method.rule) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_rule(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.rule = value.map(|value| value.into());
+        self
+    }
+}
@@ -0,0 +1,82 @@
+
+/// IngressRule represents the rules mapping the paths under a specified host to the related backend services. Incoming requests are first evaluated for a host match, then routed to the backend associated with the matching IngressRuleValue.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct IngressRule {
+    /// Host is the fully qualified domain name of a network host, as defined by RFC 3986. Note the following deviations from the "host" part of the URI as defined in the RFC: 1. IPs are not allowed. Currently an IngressRuleValue can only apply to the
+    /// 	  IP in the Spec of the parent Ingress.
+    /// 2. The `:` delimiter is not respected because ports are not allowed.
+    /// 	  Currently the port of an Ingress is implicitly :80 for http and
+    /// 	  :443 for https.
+    /// Both these may change in the future. Incoming requests are matched against the host before the IngressRuleValue. If the host is unspecified, the Ingress routes all traffic based on the specified IngressRuleValue.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub host: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub http: Option<crate::codegen::io::k8s::api::extensions::v1beta1::http_ingress_rule_value::HttpIngressRuleValue>,
+}
+
+impl IngressRule {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> IngressRuleBuilder {
+        IngressRuleBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> IngressRuleBuilder
+    where
+        Self: Clone,
+    {
+        IngressRuleBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<IngressRule> for IngressRuleBuilder {
+    fn into(self) -> IngressRule {
+        self.body
+    }
+}
+
+/// Builder for [`IngressRule`](./struct.IngressRule.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct IngressRuleBuilder {
+    body: self::IngressRule,
+}
+
+impl IngressRuleBuilder {
+    /// Host is the fully qualified domain name of a network host, as defined by RFC 3986. Note the following deviations from the "host" part of the URI as defined in the RFC: 1. IPs are not allowed. Currently an IngressRuleValue can only apply to the
+    /// 	  IP in the Spec of the parent Ingress.
+    /// 2. The `:` delimiter is not respected because ports are not allowed.
+    /// 	  Currently the port of an Ingress is implicitly :80 for http and
+    /// 	  :443 for https.
+    /// Both these may change in the future. Incoming requests are matched against the host before the IngressRuleValue. If the host is unspecified, the Ingress routes all traffic based on the specified IngressRuleValue.
+    #[inline]
+    pub fn host(mut self, value: impl Into<String>) -> Self {
+        self.body.host = Some(value.into());
+        self
+    }
+
+    /// Sets [`host`](#method.host) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_host(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.host = value.map(|value| value.into());
+        self
+    }
+
+    #[inline]
+    pub fn http(mut self, value: crate::codegen::io::k8s::api::extensions::v1beta1::http_ingress_rule_value::HttpIngressRuleValueBuilder<crate::codegen::generics::PathsExists>) -> Self {
+        self.body.http = Some(value.into());
+        self
+    }
+
+    /// Sets [`http`](#method.http) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_http(mut self, value: Option<crate::codegen::io::k8s::api::extensions::v1beta1::http_ingress_rule_value::HttpIngressRuleValueBuilder<crate::codegen::generics::PathsExists>>) -> Self {
+        self.body.http = value.map(|value| value.into());
+        self
+    }
+}
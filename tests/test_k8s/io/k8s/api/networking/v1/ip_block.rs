@@ -0,0 +1,76 @@
+
+/// IPBlock describes a particular CIDR (Ex. "192.168.1.1/24") that is allowed to the pods matched by a NetworkPolicySpec's podSelector. The except entry describes CIDRs that should not be included within this rule.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct IpBlock {
+    /// CIDR is a string representing the IP Block Valid examples are "192.168.1.1/24"
+    pub cidr: String,
+    /// Except is a slice of CIDRs that should not be included within an IP Block Valid examples are "192.168.1.1/24" Except values will be rejected if they are outside the CIDR range
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub except: Option<Vec<String>>,
+}
+
+impl IpBlock {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> IpBlockBuilder<crate::codegen::generics::MissingCidr> {
+        IpBlockBuilder {
+            body: Default::default(),
+            _cidr: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> IpBlockBuilder<crate::codegen::generics::CidrExists>
+    where
+        Self: Clone,
+    {
+        IpBlockBuilder {
+            body: self.clone(),
+            _cidr: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<IpBlock> for IpBlockBuilder<crate::codegen::generics::CidrExists> {
+    fn into(self) -> IpBlock {
+        self.body
+    }
+}
+
+/// Builder for [`IpBlock`](./struct.IpBlock.html) object.
+#[derive(Debug, Clone)]
+pub struct IpBlockBuilder<Cidr> {
+    body: self::IpBlock,
+    _cidr: core::marker::PhantomData<Cidr>,
+}
+
+impl<Cidr> IpBlockBuilder<Cidr> {
+    /// CIDR is a string representing the IP Block Valid examples are "192.168.1.1/24"
+    #[inline]
+    pub fn cidr(mut self, value: impl Into<String>) -> IpBlockBuilder<crate::codegen::generics::CidrExists> {
+        self.body.cidr = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Except is a slice of CIDRs that should not be included within an IP Block Valid examples are "192.168.1.1/24" Except values will be rejected if they are outside the CIDR range
+    #[inline]
+    pub fn except(mut self, value: impl Iterator<Item = impl Into<String>>) -> Self {
+        self.body.except = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`except`](#method.except) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_except(mut self, value: Option<impl Iterator<Item = impl Into<String>>>) -> Self {
+        self.body.except = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`except`](#method.except), but takes the values as a `Vec`.
+    #[inline]
+    pub fn except_vec(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.body.except = Some(value.into());
+        self
+    }
+}
@@ -0,0 +1,88 @@
+
+/// NetworkPolicyEgressRule describes a particular set of traffic that is allowed out of pods matched by a NetworkPolicySpec's podSelector. The traffic must match both ports and to. This type is beta-level in 1.8
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct NetworkPolicyEgressRule {
+    /// List of destination ports for outgoing traffic. Each item in this list is combined using a logical OR. If this field is empty or missing, this rule matches all ports (traffic not restricted by port). If this field is present and contains at least one item, then this rule allows traffic only if the traffic matches at least one port in the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ports: Option<Vec<crate::codegen::io::k8s::api::networking::v1::network_policy_port::NetworkPolicyPort>>,
+    /// List of destinations for outgoing traffic of pods selected for this rule. Items in this list are combined using a logical OR operation. If this field is empty or missing, this rule matches all destinations (traffic not restricted by destination). If this field is present and contains at least one item, this rule allows traffic only if the traffic matches at least one item in the to list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub to: Option<Vec<crate::codegen::io::k8s::api::networking::v1::network_policy_peer::NetworkPolicyPeer>>,
+}
+
+impl NetworkPolicyEgressRule {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> NetworkPolicyEgressRuleBuilder {
+        NetworkPolicyEgressRuleBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> NetworkPolicyEgressRuleBuilder
+    where
+        Self: Clone,
+    {
+        NetworkPolicyEgressRuleBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<NetworkPolicyEgressRule> for NetworkPolicyEgressRuleBuilder {
+    fn into(self) -> NetworkPolicyEgressRule {
+        self.body
+    }
+}
+
+/// Builder for [`NetworkPolicyEgressRule`](./struct.NetworkPolicyEgressRule.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct NetworkPolicyEgressRuleBuilder {
+    body: self::NetworkPolicyEgressRule,
+}
+
+impl NetworkPolicyEgressRuleBuilder {
+    /// List of destination ports for outgoing traffic. Each item in this list is combined using a logical OR. If this field is empty or missing, this rule matches all ports (traffic not restricted by port). If this field is present and contains at least one item, then this rule allows traffic only if the traffic matches at least one port in the list.
+    #[inline]
+    pub fn ports(mut self, value: impl Iterator<Item = crate::codegen::io::k8s::api::networking::v1::network_policy_port::NetworkPolicyPort>) -> Self {
+        self.body.ports = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`ports`](#method.ports) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_ports(mut self, value: Option<impl Iterator<Item = crate::codegen::io::k8s::api::networking::v1::network_policy_port::NetworkPolicyPort>>) -> Self {
+        self.body.ports = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`ports`](#method.ports), but takes the values as a `Vec`.
+    #[inline]
+    pub fn ports_vec(mut self, value: impl Into<Vec<crate::codegen::io::k8s::api::networking::v1::network_policy_port::NetworkPolicyPort>>) -> Self {
+        self.body.ports = Some(value.into());
+        self
+    }
+
+    /// List of destinations for outgoing traffic of pods selected for this rule. Items in this list are combined using a logical OR operation. If this field is empty or missing, this rule matches all destinations (traffic not restricted by destination). If this field is present and contains at least one item, this rule allows traffic only if the traffic matches at least one item in the to list.
+    #[inline]
+    pub fn to(mut self, value: impl Iterator<Item = crate::codegen::io::k8s::api::networking::v1::network_policy_peer::NetworkPolicyPeer>) -> Self {
+        self.body.to = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`to`](#method.to) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_to(mut self, value: Option<impl Iterator<Item = crate::codegen::io::k8s::api::networking::v1::network_policy_peer::NetworkPolicyPeer>>) -> Self {
+        self.body.to = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`to`](#method.to), but takes the values as a `Vec`.
+    #[inline]
+    pub fn to_vec(mut self, value: impl Into<Vec<crate::codegen::io::k8s::api::networking::v1::network_policy_peer::NetworkPolicyPeer>>) -> Self {
+        self.body.to = Some(value.into());
+        self
+    }
+}
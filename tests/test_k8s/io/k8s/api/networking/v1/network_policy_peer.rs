@@ -0,0 +1,99 @@
+
+/// NetworkPolicyPeer describes a peer to allow traffic from. Only certain combinations of fields are allowed
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct NetworkPolicyPeer {
+    /// IPBlock defines policy on a particular IPBlock. If this field is set then neither of the other fields can be.
+    #[serde(rename = "ipBlock", skip_serializing_if = "Option::is_none")]
+    pub ip_block: Option<crate::codegen::io::k8s::api::networking::v1::ip_block::IpBlock>,
+    /// Selects Namespaces using cluster-scoped labels. This field follows standard label selector semantics; if present but empty, it selects all namespaces.
+    ///
+    /// If PodSelector is also set, then the NetworkPolicyPeer as a whole selects the Pods matching PodSelector in the Namespaces selected by NamespaceSelector. Otherwise it selects all Pods in the Namespaces selected by NamespaceSelector.
+    #[serde(rename = "namespaceSelector", skip_serializing_if = "Option::is_none")]
+    pub namespace_selector: Option<crate::codegen::io::k8s::apimachinery::pkg::apis::meta::v1::label_selector::LabelSelector>,
+    /// This is a label selector which selects Pods. This field follows standard label selector semantics; if present but empty, it selects all pods.
+    ///
+    /// If NamespaceSelector is also set, then the NetworkPolicyPeer as a whole selects the Pods matching PodSelector in the Namespaces selected by NamespaceSelector. Otherwise it selects the Pods matching PodSelector in the policy's own Namespace.
+    #[serde(rename = "podSelector", skip_serializing_if = "Option::is_none")]
+    pub pod_selector: Option<crate::codegen::io::k8s::apimachinery::pkg::apis::meta::v1::label_selector::LabelSelector>,
+}
+
+impl NetworkPolicyPeer {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> NetworkPolicyPeerBuilder {
+        NetworkPolicyPeerBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> NetworkPolicyPeerBuilder
+    where
+        Self: Clone,
+    {
+        NetworkPolicyPeerBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<NetworkPolicyPeer> for NetworkPolicyPeerBuilder {
+    fn into(self) -> NetworkPolicyPeer {
+        self.body
+    }
+}
+
+/// Builder for [`NetworkPolicyPeer`](./struct.NetworkPolicyPeer.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct NetworkPolicyPeerBuilder {
+    body: self::NetworkPolicyPeer,
+}
+
+impl NetworkPolicyPeerBuilder {
+    /// IPBlock defines policy on a particular IPBlock. If this field is set then neither of the other fields can be.
+    #[inline]
+    pub fn ip_block(mut self, value: crate::codegen::io::k8s::api::networking::v1::ip_block::IpBlockBuilder<crate::codegen::generics::CidrExists>) -> Self {
+        self.body.ip_block = Some(value.into());
+        self
+    }
+
+    /// Sets [`ip_block`](#method.ip_block) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_ip_block(mut self, value: Option<crate::codegen::io::k8s::api::networking::v1::ip_block::IpBlockBuilder<crate::codegen::generics::CidrExists>>) -> Self {
+        self.body.ip_block = value.map(|value| value.into());
+        self
+    }
+
+    /// Selects Namespaces using cluster-scoped labels. This field follows standard label selector semantics; if present but empty, it selects all namespaces.
+    ///
+    /// If PodSelector is also set, then the NetworkPolicyPeer as a whole selects the Pods matching PodSelector in the Namespaces selected by NamespaceSelector. Otherwise it selects all Pods in the Namespaces selected by NamespaceSelector.
+    #[inline]
+    pub fn namespace_selector(mut self, value: crate::codegen::io::k8s::apimachinery::pkg::apis::meta::v1::label_selector::LabelSelector) -> Self {
+        self.body.namespace_selector = Some(value.into());
+        self
+    }
+
+    /// Sets [`namespace_selector`](#method.namespace_selector) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_namespace_selector(mut self, value: Option<crate::codegen::io::k8s::apimachinery::pkg::apis::meta::v1::label_selector::LabelSelector>) -> Self {
+        self.body.namespace_selector = value.map(|value| value.into());
+        self
+    }
+
+    /// This is a label selector which selects Pods. This field follows standard label selector semantics; if present but empty, it selects all pods.
+    ///
+    /// If NamespaceSelector is also set, then the NetworkPolicyPeer as a whole selects the Pods matching PodSelector in the Namespaces selected by NamespaceSelector. Otherwise it selects the Pods matching PodSelector in the policy's own Namespace.
+    #[inline]
+    pub fn pod_selector(mut self, value: crate::codegen::io::k8s::apimachinery::pkg::apis::meta::v1::label_selector::LabelSelector) -> Self {
+        self.body.pod_selector = Some(value.into());
+        self
+    }
+
+    /// Sets [`pod_selector`](#method.pod_selector) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_pod_selector(mut self, value: Option<crate::codegen::io::k8s::apimachinery::pkg::apis::meta::v1::label_selector::LabelSelector>) -> Self {
+        self.body.pod_selector = value.map(|value| value.into());
+        self
+    }
+}
@@ -0,0 +1,57 @@
+
+/// IngressStatus describe the current state of the Ingress.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct IngressStatus {
+    /// LoadBalancer contains the current status of the load-balancer.
+    #[serde(rename = "loadBalancer", skip_serializing_if = "Option::is_none")]
+    pub load_balancer: Option<crate::codegen::io::k8s::api::core::v1::load_balancer_status::LoadBalancerStatus>,
+}
+
+impl IngressStatus {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> IngressStatusBuilder {
+        IngressStatusBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> IngressStatusBuilder
+    where
+        Self: Clone,
+    {
+        IngressStatusBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<IngressStatus> for IngressStatusBuilder {
+    fn into(self) -> IngressStatus {
+        self.body
+    }
+}
+
+/// Builder for [`IngressStatus`](./struct.IngressStatus.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct IngressStatusBuilder {
+    body: self::IngressStatus,
+}
+
+impl IngressStatusBuilder {
+    /// LoadBalancer contains the current status of the load-balancer.
+    #[inline]
+    pub fn load_balancer(mut self, value: crate::codegen::io::k8s::api::core::v1::load_balancer_status::LoadBalancerStatus) -> Self {
+        self.body.load_balancer = Some(value.into());
+        self
+    }
+
+    /// Sets [`load_balancer`](#method.load_balancer) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_load_balancer(mut self, value: Option<crate::codegen::io::k8s::api::core::v1::load_balancer_status::LoadBalancerStatus>) -> Self {
+        self.body.load_balancer = value.map(|value| value.into());
+        self
+    }
+}
@@ -0,0 +1,12 @@
+
+pub mod runtime_class {
+    include!("./runtime_class.rs");
+}
+
+pub mod runtime_class_list {
+    include!("./runtime_class_list.rs");
+}
+
+pub mod runtime_class_spec {
+    include!("./runtime_class_spec.rs");
+}
@@ -0,0 +1,8 @@
+
+pub mod runtime_class {
+    include!("./runtime_class.rs");
+}
+
+pub mod runtime_class_list {
+    include!("./runtime_class_list.rs");
+}
@@ -0,0 +1,84 @@
+
+/// AllowedHostPath defines the host volume conditions that will be enabled by a policy for pods to use. It requires the path prefix to be defined.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct AllowedHostPath {
+    /// pathPrefix is the path prefix that the host volume must match. It does not support `*`. Trailing slashes are trimmed when validating the path prefix with a host path.
+    ///
+    /// Examples: `/foo` would allow `/foo`, `/foo/` and `/foo/bar` `/foo` would not allow `/food` or `/etc/foo`
+    #[serde(rename = "pathPrefix", skip_serializing_if = "Option::is_none")]
+    pub path_prefix: Option<String>,
+    /// when set to true, will allow host volumes matching the pathPrefix only if all volume mounts are readOnly.
+    #[serde(rename = "readOnly", skip_serializing_if = "Option::is_none")]
+    pub read_only: Option<bool>,
+}
+
+impl AllowedHostPath {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> AllowedHostPathBuilder {
+        AllowedHostPathBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> AllowedHostPathBuilder
+    where
+        Self: Clone,
+    {
+        AllowedHostPathBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<AllowedHostPath> for AllowedHostPathBuilder {
+    fn into(self) -> AllowedHostPath {
+        self.body
+    }
+}
+
+/// Builder for [`AllowedHostPath`](./struct.AllowedHostPath.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct AllowedHostPathBuilder {
+    body: self::AllowedHostPath,
+}
+
+impl AllowedHostPathBuilder {
+    /// pathPrefix is the path prefix that the host volume must match. It does not support `*`. Trailing slashes are trimmed when validating the path prefix with a host path.
+    ///
+    /// Examples: `/foo` would allow `/foo`, `/foo/` and `/foo/bar` `/foo` would not allow `/food` or `/etc/foo`
+    #[inline]
+    pub fn path_prefix(mut self, value: impl Into<String>) -> Self {
+        self.body.path_prefix = Some(value.into());
+        self
+    }
+
+    /// Sets [`path_prefix`](#method.path_prefix) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_path_prefix(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.path_prefix = value.map(|value| value.into());
+        self
+    }
+
+    /// when set to true, will allow host volumes matching the pathPrefix only if all volume mounts are readOnly.
+    #[inline]
+    pub fn read_only(mut self, value: impl Into<bool>) -> Self {
+        self.body.read_only = Some(value.into());
+        self
+    }
+
+    /// Sets [`read_only`](#method.read_only) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_read_only(mut self, value: Option<impl Into<bool>>) -> Self {
+        self.body.read_only = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`read_only`](#method.read_only), but takes a reference.
+    #[inline]
+    pub fn read_only_ref(self, value: &bool) -> Self {
+        self.read_only(*value)
+    }
+}
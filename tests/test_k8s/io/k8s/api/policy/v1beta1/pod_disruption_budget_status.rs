@@ -0,0 +1,156 @@
+
+/// PodDisruptionBudgetStatus represents information about the status of a PodDisruptionBudget. Status may trail the actual state of a system.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct PodDisruptionBudgetStatus {
+    /// current number of healthy pods
+    #[serde(rename = "currentHealthy")]
+    pub current_healthy: i32,
+    /// minimum desired number of healthy pods
+    #[serde(rename = "desiredHealthy")]
+    pub desired_healthy: i32,
+    /// DisruptedPods contains information about pods whose eviction was processed by the API server eviction subresource handler but has not yet been observed by the PodDisruptionBudget controller. A pod will be in this map from the time when the API server processed the eviction request to the time when the pod is seen by PDB controller as having been marked for deletion (or after a timeout). The key in the map is the name of the pod and the value is the time when the API server processed the eviction request. If the deletion didn't occur and a pod is still there it will be removed from the list automatically by PodDisruptionBudget controller after some time. If everything goes smooth this map should be empty for the most of the time. Large number of entries in the map may indicate problems with pod deletions.
+    #[serde(rename = "disruptedPods", skip_serializing_if = "Option::is_none")]
+    pub disrupted_pods: Option<std::collections::BTreeMap<String, String>>,
+    /// Number of pod disruptions that are currently allowed.
+    #[serde(rename = "disruptionsAllowed")]
+    pub disruptions_allowed: i32,
+    /// total number of pods counted by this disruption budget
+    #[serde(rename = "expectedPods")]
+    pub expected_pods: i32,
+    /// Most recent generation observed when updating this PDB status. PodDisruptionsAllowed and other status informatio is valid only if observedGeneration equals to PDB's object generation.
+    #[serde(rename = "observedGeneration", skip_serializing_if = "Option::is_none")]
+    pub observed_generation: Option<i64>,
+}
+
+impl PodDisruptionBudgetStatus {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> PodDisruptionBudgetStatusBuilder<crate::codegen::generics::MissingCurrentHealthy, crate::codegen::generics::MissingDesiredHealthy, crate::codegen::generics::MissingDisruptionsAllowed, crate::codegen::generics::MissingExpectedPods> {
+        PodDisruptionBudgetStatusBuilder {
+            body: Default::default(),
+            _current_healthy: core::marker::PhantomData,
+            _desired_healthy: core::marker::PhantomData,
+            _disruptions_allowed: core::marker::PhantomData,
+            _expected_pods: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> PodDisruptionBudgetStatusBuilder<crate::codegen::generics::CurrentHealthyExists, crate::codegen::generics::DesiredHealthyExists, crate::codegen::generics::DisruptionsAllowedExists, crate::codegen::generics::ExpectedPodsExists>
+    where
+        Self: Clone,
+    {
+        PodDisruptionBudgetStatusBuilder {
+            body: self.clone(),
+            _current_healthy: core::marker::PhantomData,
+            _desired_healthy: core::marker::PhantomData,
+            _disruptions_allowed: core::marker::PhantomData,
+            _expected_pods: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<PodDisruptionBudgetStatus> for PodDisruptionBudgetStatusBuilder<crate::codegen::generics::CurrentHealthyExists, crate::codegen::generics::DesiredHealthyExists, crate::codegen::generics::DisruptionsAllowedExists, crate::codegen::generics::ExpectedPodsExists> {
+    fn into(self) -> PodDisruptionBudgetStatus {
+        self.body
+    }
+}
+
+/// Builder for [`PodDisruptionBudgetStatus`](./struct.PodDisruptionBudgetStatus.html) object.
+#[derive(Debug, Clone)]
+pub struct PodDisruptionBudgetStatusBuilder<CurrentHealthy, DesiredHealthy, DisruptionsAllowed, ExpectedPods> {
+    body: self::PodDisruptionBudgetStatus,
+    _current_healthy: core::marker::PhantomData<CurrentHealthy>,
+    _desired_healthy: core::marker::PhantomData<DesiredHealthy>,
+    _disruptions_allowed: core::marker::PhantomData<DisruptionsAllowed>,
+    _expected_pods: core::marker::PhantomData<ExpectedPods>,
+}
+
+impl<CurrentHealthy, DesiredHealthy, DisruptionsAllowed, ExpectedPods> PodDisruptionBudgetStatusBuilder<CurrentHealthy, DesiredHealthy, DisruptionsAllowed, ExpectedPods> {
+    /// current number of healthy pods
+    #[inline]
+    pub fn current_healthy(mut self, value: impl Into<i32>) -> PodDisruptionBudgetStatusBuilder<crate::codegen::generics::CurrentHealthyExists, DesiredHealthy, DisruptionsAllowed, ExpectedPods> {
+        self.body.current_healthy = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Same as [`current_healthy`](#method.current_healthy), but takes a reference.
+    #[inline]
+    pub fn current_healthy_ref(self, value: &i32) -> PodDisruptionBudgetStatusBuilder<crate::codegen::generics::CurrentHealthyExists, DesiredHealthy, DisruptionsAllowed, ExpectedPods> {
+        self.current_healthy(*value)
+    }
+
+    /// minimum desired number of healthy pods
+    #[inline]
+    pub fn desired_healthy(mut self, value: impl Into<i32>) -> PodDisruptionBudgetStatusBuilder<CurrentHealthy, crate::codegen::generics::DesiredHealthyExists, DisruptionsAllowed, ExpectedPods> {
+        self.body.desired_healthy = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Same as [`desired_healthy`](#method.desired_healthy), but takes a reference.
+    #[inline]
+    pub fn desired_healthy_ref(self, value: &i32) -> PodDisruptionBudgetStatusBuilder<CurrentHealthy, crate::codegen::generics::DesiredHealthyExists, DisruptionsAllowed, ExpectedPods> {
+        self.desired_healthy(*value)
+    }
+
+    /// DisruptedPods contains information about pods whose eviction was processed by the API server eviction subresource handler but has not yet been observed by the PodDisruptionBudget controller. A pod will be in this map from the time when the API server processed the eviction request to the time when the pod is seen by PDB controller as having been marked for deletion (or after a timeout). The key in the map is the name of the pod and the value is the time when the API server processed the eviction request. If the deletion didn't occur and a pod is still there it will be removed from the list automatically by PodDisruptionBudget controller after some time. If everything goes smooth this map should be empty for the most of the time. Large number of entries in the map may indicate problems with pod deletions.
+    #[inline]
+    pub fn disrupted_pods(mut self, value: impl Iterator<Item = (String, impl Into<String>)>) -> Self {
+        self.body.disrupted_pods = Some(value.map(|(key, value)| (key, value.into())).collect::<std::collections::BTreeMap<_, _>>().into());
+        self
+    }
+
+    /// Sets [`disrupted_pods`](#method.disrupted_pods) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_disrupted_pods(mut self, value: Option<impl Iterator<Item = (String, impl Into<String>)>>) -> Self {
+        self.body.disrupted_pods = value.map(|value| value.map(|(key, value)| (key, value.into())).collect::<std::collections::BTreeMap<_, _>>().into());
+        self
+    }
+
+    /// Number of pod disruptions that are currently allowed.
+    #[inline]
+    pub fn disruptions_allowed(mut self, value: impl Into<i32>) -> PodDisruptionBudgetStatusBuilder<CurrentHealthy, DesiredHealthy, crate::codegen::generics::DisruptionsAllowedExists, ExpectedPods> {
+        self.body.disruptions_allowed = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Same as [`disruptions_allowed`](#method.disruptions_allowed), but takes a reference.
+    #[inline]
+    pub fn disruptions_allowed_ref(self, value: &i32) -> PodDisruptionBudgetStatusBuilder<CurrentHealthy, DesiredHealthy, crate::codegen::generics::DisruptionsAllowedExists, ExpectedPods> {
+        self.disruptions_allowed(*value)
+    }
+
+    /// total number of pods counted by this disruption budget
+    #[inline]
+    pub fn expected_pods(mut self, value: impl Into<i32>) -> PodDisruptionBudgetStatusBuilder<CurrentHealthy, DesiredHealthy, DisruptionsAllowed, crate::codegen::generics::ExpectedPodsExists> {
+        self.body.expected_pods = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Same as [`expected_pods`](#method.expected_pods), but takes a reference.
+    #[inline]
+    pub fn expected_pods_ref(self, value: &i32) -> PodDisruptionBudgetStatusBuilder<CurrentHealthy, DesiredHealthy, DisruptionsAllowed, crate::codegen::generics::ExpectedPodsExists> {
+        self.expected_pods(*value)
+    }
+
+    /// Most recent generation observed when updating this PDB status. PodDisruptionsAllowed and other status informatio is valid only if observedGeneration equals to PDB's object generation.
+    #[inline]
+    pub fn observed_generation(mut self, value: impl Into<i64>) -> Self {
+        self.body.observed_generation = Some(value.into());
+        self
+    }
+
+    /// Sets [`observed_generation`](#method.observed_generation) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_observed_generation(mut self, value: Option<impl Into<i64>>) -> Self {
+        self.body.observed_generation = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`observed_generation`](#method.observed_generation), but takes a reference.
+    #[inline]
+    pub fn observed_generation_ref(self, value: &i64) -> Self {
+        self.observed_generation(*value)
+    }
+}
@@ -0,0 +1,559 @@
+
+/// PodSecurityPolicySpec defines the policy enforced.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct PodSecurityPolicySpec {
+    /// allowPrivilegeEscalation determines if a pod can request to allow privilege escalation. If unspecified, defaults to true.
+    #[serde(rename = "allowPrivilegeEscalation", skip_serializing_if = "Option::is_none")]
+    pub allow_privilege_escalation: Option<bool>,
+    /// AllowedCSIDrivers is a whitelist of inline CSI drivers that must be explicitly set to be embedded within a pod spec. An empty value means no CSI drivers can run inline within a pod spec.
+    #[serde(rename = "allowedCSIDrivers", skip_serializing_if = "Option::is_none")]
+    pub allowed_csi_drivers: Option<Vec<crate::codegen::io::k8s::api::policy::v1beta1::allowed_csi_driver::AllowedCsiDriver>>,
+    /// allowedCapabilities is a list of capabilities that can be requested to add to the container. Capabilities in this field may be added at the pod author's discretion. You must not list a capability in both allowedCapabilities and requiredDropCapabilities.
+    #[serde(rename = "allowedCapabilities", skip_serializing_if = "Option::is_none")]
+    pub allowed_capabilities: Option<Vec<String>>,
+    /// allowedFlexVolumes is a whitelist of allowed Flexvolumes.  Empty or nil indicates that all Flexvolumes may be used.  This parameter is effective only when the usage of the Flexvolumes is allowed in the "volumes" field.
+    #[serde(rename = "allowedFlexVolumes", skip_serializing_if = "Option::is_none")]
+    pub allowed_flex_volumes: Option<Vec<crate::codegen::io::k8s::api::policy::v1beta1::allowed_flex_volume::AllowedFlexVolume>>,
+    /// allowedHostPaths is a white list of allowed host paths. Empty indicates that all host paths may be used.
+    #[serde(rename = "allowedHostPaths", skip_serializing_if = "Option::is_none")]
+    pub allowed_host_paths: Option<Vec<crate::codegen::io::k8s::api::policy::v1beta1::allowed_host_path::AllowedHostPath>>,
+    /// AllowedProcMountTypes is a whitelist of allowed ProcMountTypes. Empty or nil indicates that only the DefaultProcMountType may be used. This requires the ProcMountType feature flag to be enabled.
+    #[serde(rename = "allowedProcMountTypes", skip_serializing_if = "Option::is_none")]
+    pub allowed_proc_mount_types: Option<Vec<String>>,
+    /// allowedUnsafeSysctls is a list of explicitly allowed unsafe sysctls, defaults to none. Each entry is either a plain sysctl name or ends in "\*" in which case it is considered as a prefix of allowed sysctls. Single * means all unsafe sysctls are allowed. Kubelet has to whitelist all allowed unsafe sysctls explicitly to avoid rejection.
+    ///
+    /// Examples: e.g. "foo/\*" allows "foo/bar", "foo/baz", etc. e.g. "foo.\*" allows "foo.bar", "foo.baz", etc.
+    #[serde(rename = "allowedUnsafeSysctls", skip_serializing_if = "Option::is_none")]
+    pub allowed_unsafe_sysctls: Option<Vec<String>>,
+    /// defaultAddCapabilities is the default set of capabilities that will be added to the container unless the pod spec specifically drops the capability.  You may not list a capability in both defaultAddCapabilities and requiredDropCapabilities. Capabilities added here are implicitly allowed, and need not be included in the allowedCapabilities list.
+    #[serde(rename = "defaultAddCapabilities", skip_serializing_if = "Option::is_none")]
+    pub default_add_capabilities: Option<Vec<String>>,
+    /// defaultAllowPrivilegeEscalation controls the default setting for whether a process can gain more privileges than its parent process.
+    #[serde(rename = "defaultAllowPrivilegeEscalation", skip_serializing_if = "Option::is_none")]
+    pub default_allow_privilege_escalation: Option<bool>,
+    /// forbiddenSysctls is a list of explicitly forbidden sysctls, defaults to none. Each entry is either a plain sysctl name or ends in "\*" in which case it is considered as a prefix of forbidden sysctls. Single * means all sysctls are forbidden.
+    ///
+    /// Examples: e.g. "foo/\*" forbids "foo/bar", "foo/baz", etc. e.g. "foo.\*" forbids "foo.bar", "foo.baz", etc.
+    #[serde(rename = "forbiddenSysctls", skip_serializing_if = "Option::is_none")]
+    pub forbidden_sysctls: Option<Vec<String>>,
+    /// fsGroup is the strategy that will dictate what fs group is used by the SecurityContext.
+    #[serde(rename = "fsGroup")]
+    pub fs_group: crate::codegen::io::k8s::api::policy::v1beta1::fs_group_strategy_options::FsGroupStrategyOptions,
+    /// hostIPC determines if the policy allows the use of HostIPC in the pod spec.
+    #[serde(rename = "hostIPC", skip_serializing_if = "Option::is_none")]
+    pub host_ipc: Option<bool>,
+    /// hostNetwork determines if the policy allows the use of HostNetwork in the pod spec.
+    #[serde(rename = "hostNetwork", skip_serializing_if = "Option::is_none")]
+    pub host_network: Option<bool>,
+    /// hostPID determines if the policy allows the use of HostPID in the pod spec.
+    #[serde(rename = "hostPID", skip_serializing_if = "Option::is_none")]
+    pub host_pid: Option<bool>,
+    /// hostPorts determines which host port ranges are allowed to be exposed.
+    #[serde(rename = "hostPorts", skip_serializing_if = "Option::is_none")]
+    pub host_ports: Option<Vec<crate::codegen::io::k8s::api::policy::v1beta1::host_port_range::HostPortRange>>,
+    /// privileged determines if a pod can request to be run as privileged.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub privileged: Option<bool>,
+    /// readOnlyRootFilesystem when set to true will force containers to run with a read only root file system.  If the container specifically requests to run with a non-read only root file system the PSP should deny the pod. If set to false the container may run with a read only root file system if it wishes but it will not be forced to.
+    #[serde(rename = "readOnlyRootFilesystem", skip_serializing_if = "Option::is_none")]
+    pub read_only_root_filesystem: Option<bool>,
+    /// requiredDropCapabilities are the capabilities that will be dropped from the container.  These are required to be dropped and cannot be added.
+    #[serde(rename = "requiredDropCapabilities", skip_serializing_if = "Option::is_none")]
+    pub required_drop_capabilities: Option<Vec<String>>,
+    /// RunAsGroup is the strategy that will dictate the allowable RunAsGroup values that may be set. If this field is omitted, the pod's RunAsGroup can take any value. This field requires the RunAsGroup feature gate to be enabled.
+    #[serde(rename = "runAsGroup", skip_serializing_if = "Option::is_none")]
+    pub run_as_group: Option<crate::codegen::io::k8s::api::policy::v1beta1::run_as_group_strategy_options::RunAsGroupStrategyOptions>,
+    /// runAsUser is the strategy that will dictate the allowable RunAsUser values that may be set.
+    #[serde(rename = "runAsUser")]
+    pub run_as_user: crate::codegen::io::k8s::api::policy::v1beta1::run_as_user_strategy_options::RunAsUserStrategyOptions,
+    /// runtimeClass is the strategy that will dictate the allowable RuntimeClasses for a pod. If this field is omitted, the pod's runtimeClassName field is unrestricted. Enforcement of this field depends on the RuntimeClass feature gate being enabled.
+    #[serde(rename = "runtimeClass", skip_serializing_if = "Option::is_none")]
+    pub runtime_class: Option<crate::codegen::io::k8s::api::policy::v1beta1::runtime_class_strategy_options::RuntimeClassStrategyOptions>,
+    /// seLinux is the strategy that will dictate the allowable labels that may be set.
+    #[serde(rename = "seLinux")]
+    pub se_linux: crate::codegen::io::k8s::api::policy::v1beta1::se_linux_strategy_options::SeLinuxStrategyOptions,
+    /// supplementalGroups is the strategy that will dictate what supplemental groups are used by the SecurityContext.
+    #[serde(rename = "supplementalGroups")]
+    pub supplemental_groups: crate::codegen::io::k8s::api::policy::v1beta1::supplemental_groups_strategy_options::SupplementalGroupsStrategyOptions,
+    /// volumes is a white list of allowed volume plugins. Empty indicates that no volumes may be used. To allow all volumes you may use '\*'.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub volumes: Option<Vec<String>>,
+}
+
+impl PodSecurityPolicySpec {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> PodSecurityPolicySpecBuilder<crate::codegen::generics::MissingFsGroup, crate::codegen::generics::MissingRunAsUser, crate::codegen::generics::MissingSeLinux, crate::codegen::generics::MissingSupplementalGroups> {
+        PodSecurityPolicySpecBuilder {
+            body: Default::default(),
+            _fs_group: core::marker::PhantomData,
+            _run_as_user: core::marker::PhantomData,
+            _se_linux: core::marker::PhantomData,
+            _supplemental_groups: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> PodSecurityPolicySpecBuilder<crate::codegen::generics::FsGroupExists, crate::codegen::generics::RunAsUserExists, crate::codegen::generics::SeLinuxExists, crate::codegen::generics::SupplementalGroupsExists>
+    where
+        Self: Clone,
+    {
+        PodSecurityPolicySpecBuilder {
+            body: self.clone(),
+            _fs_group: core::marker::PhantomData,
+            _run_as_user: core::marker::PhantomData,
+            _se_linux: core::marker::PhantomData,
+            _supplemental_groups: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<PodSecurityPolicySpec> for PodSecurityPolicySpecBuilder<crate::codegen::generics::FsGroupExists, crate::codegen::generics::RunAsUserExists, crate::codegen::generics::SeLinuxExists, crate::codegen::generics::SupplementalGroupsExists> {
+    fn into(self) -> PodSecurityPolicySpec {
+        self.body
+    }
+}
+
+/// Builder for [`PodSecurityPolicySpec`](./struct.PodSecurityPolicySpec.html) object.
+#[derive(Debug, Clone)]
+pub struct PodSecurityPolicySpecBuilder<FsGroup, RunAsUser, SeLinux, SupplementalGroups> {
+    body: self::PodSecurityPolicySpec,
+    _fs_group: core::marker::PhantomData<FsGroup>,
+    _run_as_user: core::marker::PhantomData<RunAsUser>,
+    _se_linux: core::marker::PhantomData<SeLinux>,
+    _supplemental_groups: core::marker::PhantomData<SupplementalGroups>,
+}
+
+impl<FsGroup, RunAsUser, SeLinux, SupplementalGroups> PodSecurityPolicySpecBuilder<FsGroup, RunAsUser, SeLinux, SupplementalGroups> {
+    /// allowPrivilegeEscalation determines if a pod can request to allow privilege escalation. If unspecified, defaults to true.
+    #[inline]
+    pub fn allow_privilege_escalation(mut self, value: impl Into<bool>) -> Self {
+        self.body.allow_privilege_escalation = Some(value.into());
+        self
+    }
+
+    /// Sets [`allow_privilege_escalation`](#method.allow_privilege_escalation) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_allow_privilege_escalation(mut self, value: Option<impl Into<bool>>) -> Self {
+        self.body.allow_privilege_escalation = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`allow_privilege_escalation`](#method.allow_privilege_escalation), but takes a reference.
+    #[inline]
+    pub fn allow_privilege_escalation_ref(self, value: &bool) -> Self {
+        self.allow_privilege_escalation(*value)
+    }
+
+    /// AllowedCSIDrivers is a whitelist of inline CSI drivers that must be explicitly set to be embedded within a pod spec. An empty value means no CSI drivers can run inline within a pod spec.
+    #[inline]
+    pub fn allowed_csi_drivers(mut self, value: impl Iterator<Item = crate::codegen::io::k8s::api::policy::v1beta1::allowed_csi_driver::AllowedCsiDriverBuilder<crate::codegen::generics::NameExists>>) -> Self {
+        self.body.allowed_csi_drivers = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`allowed_csi_drivers`](#method.allowed_csi_drivers) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_allowed_csi_drivers(mut self, value: Option<impl Iterator<Item = crate::codegen::io::k8s::api::policy::v1beta1::allowed_csi_driver::AllowedCsiDriverBuilder<crate::codegen::generics::NameExists>>>) -> Self {
+        self.body.allowed_csi_drivers = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`allowed_csi_drivers`](#method.allowed_csi_drivers), but takes the values as a `Vec`.
+    #[inline]
+    pub fn allowed_csi_drivers_vec(mut self, value: impl Into<Vec<crate::codegen::io::k8s::api::policy::v1beta1::allowed_csi_driver::AllowedCsiDriver>>) -> Self {
+        self.body.allowed_csi_drivers = Some(value.into());
+        self
+    }
+
+    /// allowedCapabilities is a list of capabilities that can be requested to add to the container. Capabilities in this field may be added at the pod author's discretion. You must not list a capability in both allowedCapabilities and requiredDropCapabilities.
+    #[inline]
+    pub fn allowed_capabilities(mut self, value: impl Iterator<Item = impl Into<String>>) -> Self {
+        self.body.allowed_capabilities = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`allowed_capabilities`](#method.allowed_capabilities) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_allowed_capabilities(mut self, value: Option<impl Iterator<Item = impl Into<String>>>) -> Self {
+        self.body.allowed_capabilities = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`allowed_capabilities`](#method.allowed_capabilities), but takes the values as a `Vec`.
+    #[inline]
+    pub fn allowed_capabilities_vec(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.body.allowed_capabilities = Some(value.into());
+        self
+    }
+
+    /// allowedFlexVolumes is a whitelist of allowed Flexvolumes.  Empty or nil indicates that all Flexvolumes may be used.  This parameter is effective only when the usage of the Flexvolumes is allowed in the "volumes" field.
+    #[inline]
+    pub fn allowed_flex_volumes(mut self, value: impl Iterator<Item = crate::codegen::io::k8s::api::policy::v1beta1::allowed_flex_volume::AllowedFlexVolumeBuilder<crate::codegen::generics::DriverExists>>) -> Self {
+        self.body.allowed_flex_volumes = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`allowed_flex_volumes`](#method.allowed_flex_volumes) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_allowed_flex_volumes(mut self, value: Option<impl Iterator<Item = crate::codegen::io::k8s::api::policy::v1beta1::allowed_flex_volume::AllowedFlexVolumeBuilder<crate::codegen::generics::DriverExists>>>) -> Self {
+        self.body.allowed_flex_volumes = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`allowed_flex_volumes`](#method.allowed_flex_volumes), but takes the values as a `Vec`.
+    #[inline]
+    pub fn allowed_flex_volumes_vec(mut self, value: impl Into<Vec<crate::codegen::io::k8s::api::policy::v1beta1::allowed_flex_volume::AllowedFlexVolume>>) -> Self {
+        self.body.allowed_flex_volumes = Some(value.into());
+        self
+    }
+
+    /// allowedHostPaths is a white list of allowed host paths. Empty indicates that all host paths may be used.
+    #[inline]
+    pub fn allowed_host_paths(mut self, value: impl Iterator<Item = crate::codegen::io::k8s::api::policy::v1beta1::allowed_host_path::AllowedHostPath>) -> Self {
+        self.body.allowed_host_paths = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`allowed_host_paths`](#method.allowed_host_paths) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_allowed_host_paths(mut self, value: Option<impl Iterator<Item = crate::codegen::io::k8s::api::policy::v1beta1::allowed_host_path::AllowedHostPath>>) -> Self {
+        self.body.allowed_host_paths = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`allowed_host_paths`](#method.allowed_host_paths), but takes the values as a `Vec`.
+    #[inline]
+    pub fn allowed_host_paths_vec(mut self, value: impl Into<Vec<crate::codegen::io::k8s::api::policy::v1beta1::allowed_host_path::AllowedHostPath>>) -> Self {
+        self.body.allowed_host_paths = Some(value.into());
+        self
+    }
+
+    /// AllowedProcMountTypes is a whitelist of allowed ProcMountTypes. Empty or nil indicates that only the DefaultProcMountType may be used. This requires the ProcMountType feature flag to be enabled.
+    #[inline]
+    pub fn allowed_proc_mount_types(mut self, value: impl Iterator<Item = impl Into<String>>) -> Self {
+        self.body.allowed_proc_mount_types = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`allowed_proc_mount_types`](#method.allowed_proc_mount_types) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_allowed_proc_mount_types(mut self, value: Option<impl Iterator<Item = impl Into<String>>>) -> Self {
+        self.body.allowed_proc_mount_types = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`allowed_proc_mount_types`](#method.allowed_proc_mount_types), but takes the values as a `Vec`.
+    #[inline]
+    pub fn allowed_proc_mount_types_vec(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.body.allowed_proc_mount_types = Some(value.into());
+        self
+    }
+
+    /// allowedUnsafeSysctls is a list of explicitly allowed unsafe sysctls, defaults to none. Each entry is either a plain sysctl name or ends in "\*" in which case it is considered as a prefix of allowed sysctls. Single * means all unsafe sysctls are allowed. Kubelet has to whitelist all allowed unsafe sysctls explicitly to avoid rejection.
+    ///
+    /// Examples: e.g. "foo/\*" allows "foo/bar", "foo/baz", etc. e.g. "foo.\*" allows "foo.bar", "foo.baz", etc.
+    #[inline]
+    pub fn allowed_unsafe_sysctls(mut self, value: impl Iterator<Item = impl Into<String>>) -> Self {
+        self.body.allowed_unsafe_sysctls = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`allowed_unsafe_sysctls`](#method.allowed_unsafe_sysctls) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_allowed_unsafe_sysctls(mut self, value: Option<impl Iterator<Item = impl Into<String>>>) -> Self {
+        self.body.allowed_unsafe_sysctls = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`allowed_unsafe_sysctls`](#method.allowed_unsafe_sysctls), but takes the values as a `Vec`.
+    #[inline]
+    pub fn allowed_unsafe_sysctls_vec(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.body.allowed_unsafe_sysctls = Some(value.into());
+        self
+    }
+
+    /// defaultAddCapabilities is the default set of capabilities that will be added to the container unless the pod spec specifically drops the capability.  You may not list a capability in both defaultAddCapabilities and requiredDropCapabilities. Capabilities added here are implicitly allowed, and need not be included in the allowedCapabilities list.
+    #[inline]
+    pub fn default_add_capabilities(mut self, value: impl Iterator<Item = impl Into<String>>) -> Self {
+        self.body.default_add_capabilities = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`default_add_capabilities`](#method.default_add_capabilities) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_default_add_capabilities(mut self, value: Option<impl Iterator<Item = impl Into<String>>>) -> Self {
+        self.body.default_add_capabilities = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`default_add_capabilities`](#method.default_add_capabilities), but takes the values as a `Vec`.
+    #[inline]
+    pub fn default_add_capabilities_vec(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.body.default_add_capabilities = Some(value.into());
+        self
+    }
+
+    /// defaultAllowPrivilegeEscalation controls the default setting for whether a process can gain more privileges than its parent process.
+    #[inline]
+    pub fn default_allow_privilege_escalation(mut self, value: impl Into<bool>) -> Self {
+        self.body.default_allow_privilege_escalation = Some(value.into());
+        self
+    }
+
+    /// Sets [`default_allow_privilege_escalation`](#method.default_allow_privilege_escalation) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_default_allow_privilege_escalation(mut self, value: Option<impl Into<bool>>) -> Self {
+        self.body.default_allow_privilege_escalation = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`default_allow_privilege_escalation`](#method.default_allow_privilege_escalation), but takes a reference.
+    #[inline]
+    pub fn default_allow_privilege_escalation_ref(self, value: &bool) -> Self {
+        self.default_allow_privilege_escalation(*value)
+    }
+
+    /// forbiddenSysctls is a list of explicitly forbidden sysctls, defaults to none. Each entry is either a plain sysctl name or ends in "\*" in which case it is considered as a prefix of forbidden sysctls. Single * means all sysctls are forbidden.
+    ///
+    /// Examples: e.g. "foo/\*" forbids "foo/bar", "foo/baz", etc. e.g. "foo.\*" forbids "foo.bar", "foo.baz", etc.
+    #[inline]
+    pub fn forbidden_sysctls(mut self, value: impl Iterator<Item = impl Into<String>>) -> Self {
+        self.body.forbidden_sysctls = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`forbidden_sysctls`](#method.forbidden_sysctls) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_forbidden_sysctls(mut self, value: Option<impl Iterator<Item = impl Into<String>>>) -> Self {
+        self.body.forbidden_sysctls = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`forbidden_sysctls`](#method.forbidden_sysctls), but takes the values as a `Vec`.
+    #[inline]
+    pub fn forbidden_sysctls_vec(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.body.forbidden_sysctls = Some(value.into());
+        self
+    }
+
+    /// fsGroup is the strategy that will dictate what fs group is used by the SecurityContext.
+    #[inline]
+    pub fn fs_group(mut self, value: crate::codegen::io::k8s::api::policy::v1beta1::fs_group_strategy_options::FsGroupStrategyOptions) -> PodSecurityPolicySpecBuilder<crate::codegen::generics::FsGroupExists, RunAsUser, SeLinux, SupplementalGroups> {
+        self.body.fs_group = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// hostIPC determines if the policy allows the use of HostIPC in the pod spec.
+    #[inline]
+    pub fn host_ipc(mut self, value: impl Into<bool>) -> Self {
+        self.body.host_ipc = Some(value.into());
+        self
+    }
+
+    /// Sets [`host_ipc`](#method.host_ipc) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_host_ipc(mut self, value: Option<impl Into<bool>>) -> Self {
+        self.body.host_ipc = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`host_ipc`](#method.host_ipc), but takes a reference.
+    #[inline]
+    pub fn host_ipc_ref(self, value: &bool) -> Self {
+        self.host_ipc(*value)
+    }
+
+    /// hostNetwork determines if the policy allows the use of HostNetwork in the pod spec.
+    #[inline]
+    pub fn host_network(mut self, value: impl Into<bool>) -> Self {
+        self.body.host_network = Some(value.into());
+        self
+    }
+
+    /// Sets [`host_network`](#method.host_network) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_host_network(mut self, value: Option<impl Into<bool>>) -> Self {
+        self.body.host_network = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`host_network`](#method.host_network), but takes a reference.
+    #[inline]
+    pub fn host_network_ref(self, value: &bool) -> Self {
+        self.host_network(*value)
+    }
+
+    /// hostPID determines if the policy allows the use of HostPID in the pod spec.
+    #[inline]
+    pub fn host_pid(mut self, value: impl Into<bool>) -> Self {
+        self.body.host_pid = Some(value.into());
+        self
+    }
+
+    /// Sets [`host_pid`](#method.host_pid) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_host_pid(mut self, value: Option<impl Into<bool>>) -> Self {
+        self.body.host_pid = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`host_pid`](#method.host_pid), but takes a reference.
+    #[inline]
+    pub fn host_pid_ref(self, value: &bool) -> Self {
+        self.host_pid(*value)
+    }
+
+    /// hostPorts determines which host port ranges are allowed to be exposed.
+    #[inline]
+    pub fn host_ports(mut self, value: impl Iterator<Item = crate::codegen::io::k8s::api::policy::v1beta1::host_port_range::HostPortRangeBuilder<crate::codegen::generics::MaxExists, crate::codegen::generics::MinExists>>) -> Self {
+        self.body.host_ports = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`host_ports`](#method.host_ports) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_host_ports(mut self, value: Option<impl Iterator<Item = crate::codegen::io::k8s::api::policy::v1beta1::host_port_range::HostPortRangeBuilder<crate::codegen::generics::MaxExists, crate::codegen::generics::MinExists>>>) -> Self {
+        self.body.host_ports = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`host_ports`](#method.host_ports), but takes the values as a `Vec`.
+    #[inline]
+    pub fn host_ports_vec(mut self, value: impl Into<Vec<crate::codegen::io::k8s::api::policy::v1beta1::host_port_range::HostPortRange>>) -> Self {
+        self.body.host_ports = Some(value.into());
+        self
+    }
+
+    /// privileged determines if a pod can request to be run as privileged.
+    #[inline]
+    pub fn privileged(mut self, value: impl Into<bool>) -> Self {
+        self.body.privileged = Some(value.into());
+        self
+    }
+
+    /// Sets [`privileged`](#method.privileged) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_privileged(mut self, value: Option<impl Into<bool>>) -> Self {
+        self.body.privileged = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`privileged`](#method.privileged), but takes a reference.
+    #[inline]
+    pub fn privileged_ref(self, value: &bool) -> Self {
+        self.privileged(*value)
+    }
+
+    /// readOnlyRootFilesystem when set to true will force containers to run with a read only root file system.  If the container specifically requests to run with a non-read only root file system the PSP should deny the pod. If set to false the container may run with a read only root file system if it wishes but it will not be forced to.
+    #[inline]
+    pub fn read_only_root_filesystem(mut self, value: impl Into<bool>) -> Self {
+        self.body.read_only_root_filesystem = Some(value.into());
+        self
+    }
+
+    /// Sets [`read_only_root_filesystem`](#method.read_only_root_filesystem) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_read_only_root_filesystem(mut self, value: Option<impl Into<bool>>) -> Self {
+        self.body.read_only_root_filesystem = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`read_only_root_filesystem`](#method.read_only_root_filesystem), but takes a reference.
+    #[inline]
+    pub fn read_only_root_filesystem_ref(self, value: &bool) -> Self {
+        self.read_only_root_filesystem(*value)
+    }
+
+    /// requiredDropCapabilities are the capabilities that will be dropped from the container.  These are required to be dropped and cannot be added.
+    #[inline]
+    pub fn required_drop_capabilities(mut self, value: impl Iterator<Item = impl Into<String>>) -> Self {
+        self.body.required_drop_capabilities = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`required_drop_capabilities`](#method.required_drop_capabilities) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_required_drop_capabilities(mut self, value: Option<impl Iterator<Item = impl Into<String>>>) -> Self {
+        self.body.required_drop_capabilities = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`required_drop_capabilities`](#method.required_drop_capabilities), but takes the values as a `Vec`.
+    #[inline]
+    pub fn required_drop_capabilities_vec(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.body.required_drop_capabilities = Some(value.into());
+        self
+    }
+
+    /// RunAsGroup is the strategy that will dictate the allowable RunAsGroup values that may be set. If this field is omitted, the pod's RunAsGroup can take any value. This field requires the RunAsGroup feature gate to be enabled.
+    #[inline]
+    pub fn run_as_group(mut self, value: crate::codegen::io::k8s::api::policy::v1beta1::run_as_group_strategy_options::RunAsGroupStrategyOptionsBuilder<crate::codegen::generics::RuleExists>) -> Self {
+        self.body.run_as_group = Some(value.into());
+        self
+    }
+
+    /// Sets [`run_as_group`](#method.run_as_group) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_run_as_group(mut self, value: Option<crate::codegen::io::k8s::api::policy::v1beta1::run_as_group_strategy_options::RunAsGroupStrategyOptionsBuilder<crate::codegen::generics::RuleExists>>) -> Self {
+        self.body.run_as_group = value.map(|value| value.into());
+        self
+    }
+
+    /// runAsUser is the strategy that will dictate the allowable RunAsUser values that may be set.
+    #[inline]
+    pub fn run_as_user(mut self, value: crate::codegen::io::k8s::api::policy::v1beta1::run_as_user_strategy_options::RunAsUserStrategyOptionsBuilder<crate::codegen::generics::RuleExists>) -> PodSecurityPolicySpecBuilder<FsGroup, crate::codegen::generics::RunAsUserExists, SeLinux, SupplementalGroups> {
+        self.body.run_as_user = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// runtimeClass is the strategy that will dictate the allowable RuntimeClasses for a pod. If this field is omitted, the pod's runtimeClassName field is unrestricted. Enforcement of this field depends on the RuntimeClass feature gate being enabled.
+    #[inline]
+    pub fn runtime_class(mut self, value: crate::codegen::io::k8s::api::policy::v1beta1::runtime_class_strategy_options::RuntimeClassStrategyOptionsBuilder<crate::codegen::generics::AllowedRuntimeClassNamesExists>) -> Self {
+        self.body.runtime_class = Some(value.into());
+        self
+    }
+
+    /// Sets [`runtime_class`](#method.runtime_class) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_runtime_class(mut self, value: Option<crate::codegen::io::k8s::api::policy::v1beta1::runtime_class_strategy_options::RuntimeClassStrategyOptionsBuilder<crate::codegen::generics::AllowedRuntimeClassNamesExists>>) -> Self {
+        self.body.runtime_class = value.map(|value| value.into());
+        self
+    }
+
+    /// seLinux is the strategy that will dictate the allowable labels that may be set.
+    #[inline]
+    pub fn se_linux(mut self, value: crate::codegen::io::k8s::api::policy::v1beta1::se_linux_strategy_options::SeLinuxStrategyOptionsBuilder<crate::codegen::generics::RuleExists>) -> PodSecurityPolicySpecBuilder<FsGroup, RunAsUser, crate::codegen::generics::SeLinuxExists, SupplementalGroups> {
+        self.body.se_linux = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// supplementalGroups is the strategy that will dictate what supplemental groups are used by the SecurityContext.
+    #[inline]
+    pub fn supplemental_groups(mut self, value: crate::codegen::io::k8s::api::policy::v1beta1::supplemental_groups_strategy_options::SupplementalGroupsStrategyOptions) -> PodSecurityPolicySpecBuilder<FsGroup, RunAsUser, SeLinux, crate::codegen::generics::SupplementalGroupsExists> {
+        self.body.supplemental_groups = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// volumes is a white list of allowed volume plugins. Empty indicates that no volumes may be used. To allow all volumes you may use '\*'.
+    #[inline]
+    pub fn volumes(mut self, value: impl Iterator<Item = impl Into<String>>) -> Self {
+        self.body.volumes = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`volumes`](#method.volumes) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_volumes(mut self, value: Option<impl Iterator<Item = impl Into<String>>>) -> Self {
+        self.body.volumes = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`volumes`](#method.volumes), but takes the values as a `Vec`.
+    #[inline]
+    pub fn volumes_vec(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.body.volumes = Some(value.into());
+        self
+    }
+}
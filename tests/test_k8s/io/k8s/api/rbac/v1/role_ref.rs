@@ -0,0 +1,77 @@
+
+/// RoleRef contains information that points to the role being used
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct RoleRef {
+    /// APIGroup is the group for the resource being referenced
+    #[serde(rename = "apiGroup")]
+    pub api_group: String,
+    /// Kind is the type of resource being referenced
+    pub kind: String,
+    /// Name is the name of resource being referenced
+    pub name: String,
+}
+
+impl RoleRef {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> RoleRefBuilder<crate::codegen::generics::MissingApiGroup, crate::codegen::generics::MissingKind, crate::codegen::generics::MissingName> {
+        RoleRefBuilder {
+            body: Default::default(),
+            _api_group: core::marker::PhantomData,
+            _kind: core::marker::PhantomData,
+            _name: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> RoleRefBuilder<crate::codegen::generics::ApiGroupExists, crate::codegen::generics::KindExists, crate::codegen::generics::NameExists>
+    where
+        Self: Clone,
+    {
+        RoleRefBuilder {
+            body: self.clone(),
+            _api_group: core::marker::PhantomData,
+            _kind: core::marker::PhantomData,
+            _name: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<RoleRef> for RoleRefBuilder<crate::codegen::generics::ApiGroupExists, crate::codegen::generics::KindExists, crate::codegen::generics::NameExists> {
+    fn into(self) -> RoleRef {
+        self.body
+    }
+}
+
+/// Builder for [`RoleRef`](./struct.RoleRef.html) object.
+#[derive(Debug, Clone)]
+pub struct RoleRefBuilder<ApiGroup, Kind, Name> {
+    body: self::RoleRef,
+    _api_group: core::marker::PhantomData<ApiGroup>,
+    _kind: core::marker::PhantomData<Kind>,
+    _name: core::marker::PhantomData<Name>,
+}
+
+impl<ApiGroup, Kind, Name> RoleRefBuilder<ApiGroup, Kind, Name> {
+    /// APIGroup is the group for the resource being referenced
+    #[inline]
+    pub fn api_group(mut self, value: impl Into<String>) -> RoleRefBuilder<crate::codegen::generics::ApiGroupExists, Kind, Name> {
+        self.body.api_group = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Kind is the type of resource being referenced
+    #[inline]
+    pub fn kind(mut self, value: impl Into<String>) -> RoleRefBuilder<ApiGroup, crate::codegen::generics::KindExists, Name> {
+        self.body.kind = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Name is the name of resource being referenced
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> RoleRefBuilder<ApiGroup, Kind, crate::codegen::generics::NameExists> {
+        self.body.name = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+}
@@ -0,0 +1,64 @@
+
+/// AggregationRule describes how to locate ClusterRoles to aggregate into the ClusterRole
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct AggregationRule {
+    /// ClusterRoleSelectors holds a list of selectors which will be used to find ClusterRoles and create the rules. If any of the selectors match, then the ClusterRole's permissions will be added
+    #[serde(rename = "clusterRoleSelectors", skip_serializing_if = "Option::is_none")]
+    pub cluster_role_selectors: Option<Vec<crate::codegen::io::k8s::apimachinery::pkg::apis::meta::v1::label_selector::LabelSelector>>,
+}
+
+impl AggregationRule {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> AggregationRuleBuilder {
+        AggregationRuleBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> AggregationRuleBuilder
+    where
+        Self: Clone,
+    {
+        AggregationRuleBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<AggregationRule> for AggregationRuleBuilder {
+    fn into(self) -> AggregationRule {
+        self.body
+    }
+}
+
+/// Builder for [`AggregationRule`](./struct.AggregationRule.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct AggregationRuleBuilder {
+    body: self::AggregationRule,
+}
+
+impl AggregationRuleBuilder {
+    /// ClusterRoleSelectors holds a list of selectors which will be used to find ClusterRoles and create the rules. If any of the selectors match, then the ClusterRole's permissions will be added
+    #[inline]
+    pub fn cluster_role_selectors(mut self, value: impl Iterator<Item = crate::codegen::io::k8s::apimachinery::pkg::apis::meta::v1::label_selector::LabelSelector>) -> Self {
+        self.body.cluster_role_selectors = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`cluster_role_selectors`](#method.cluster_role_selectors) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_cluster_role_selectors(mut self, value: Option<impl Iterator<Item = crate::codegen::io::k8s::apimachinery::pkg::apis::meta::v1::label_selector::LabelSelector>>) -> Self {
+        self.body.cluster_role_selectors = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`cluster_role_selectors`](#method.cluster_role_selectors), but takes the values as a `Vec`.
+    #[inline]
+    pub fn cluster_role_selectors_vec(mut self, value: impl Into<Vec<crate::codegen::io::k8s::apimachinery::pkg::apis::meta::v1::label_selector::LabelSelector>>) -> Self {
+        self.body.cluster_role_selectors = Some(value.into());
+        self
+    }
+}
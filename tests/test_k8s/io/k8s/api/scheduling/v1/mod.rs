@@ -0,0 +1,8 @@
+
+pub mod priority_class {
+    include!("./priority_class.rs");
+}
+
+pub mod priority_class_list {
+    include!("./priority_class_list.rs");
+}
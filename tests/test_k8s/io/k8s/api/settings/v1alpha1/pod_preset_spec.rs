@@ -0,0 +1,153 @@
+
+/// PodPresetSpec is a description of a pod preset.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct PodPresetSpec {
+    /// Env defines the collection of EnvVar to inject into containers.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub env: Option<Vec<crate::codegen::io::k8s::api::core::v1::env_var::EnvVar>>,
+    /// EnvFrom defines the collection of EnvFromSource to inject into containers.
+    #[serde(rename = "envFrom", skip_serializing_if = "Option::is_none")]
+    pub env_from: Option<Vec<crate::codegen::io::k8s::api::core::v1::env_from_source::EnvFromSource>>,
+    /// Selector is a label query over a set of resources, in this case pods. Required.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub selector: Option<crate::codegen::io::k8s::apimachinery::pkg::apis::meta::v1::label_selector::LabelSelector>,
+    /// VolumeMounts defines the collection of VolumeMount to inject into containers.
+    #[serde(rename = "volumeMounts", skip_serializing_if = "Option::is_none")]
+    pub volume_mounts: Option<Vec<crate::codegen::io::k8s::api::core::v1::volume_mount::VolumeMount>>,
+    /// Volumes defines the collection of Volume to inject into the pod.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub volumes: Option<Vec<crate::codegen::io::k8s::api::core::v1::volume::Volume>>,
+}
+
+impl PodPresetSpec {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> PodPresetSpecBuilder {
+        PodPresetSpecBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> PodPresetSpecBuilder
+    where
+        Self: Clone,
+    {
+        PodPresetSpecBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<PodPresetSpec> for PodPresetSpecBuilder {
+    fn into(self) -> PodPresetSpec {
+        self.body
+    }
+}
+
+/// Builder for [`PodPresetSpec`](./struct.PodPresetSpec.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct PodPresetSpecBuilder {
+    body: self::PodPresetSpec,
+}
+
+impl PodPresetSpecBuilder {
+    /// Env defines the collection of EnvVar to inject into containers.
+    #[inline]
+    pub fn env(mut self, value: impl Iterator<Item = crate::codegen::io::k8s::api::core::v1::env_var::EnvVarBuilder<crate::codegen::generics::NameExists>>) -> Self {
+        self.body.env = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`env`](#method.env) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_env(mut self, value: Option<impl Iterator<Item = crate::codegen::io::k8s::api::core::v1::env_var::EnvVarBuilder<crate::codegen::generics::NameExists>>>) -> Self {
+        self.body.env = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`env`](#method.env), but takes the values as a `Vec`.
+    #[inline]
+    pub fn env_vec(mut self, value: impl Into<Vec<crate::codegen::io::k8s::api::core::v1::env_var::EnvVar>>) -> Self {
+        self.body.env = Some(value.into());
+        self
+    }
+
+    /// EnvFrom defines the collection of EnvFromSource to inject into containers.
+    #[inline]
+    pub fn env_from(mut self, value: impl Iterator<Item = crate::codegen::io::k8s::api::core::v1::env_from_source::EnvFromSource>) -> Self {
+        self.body.env_from = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`env_from`](#method.env_from) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_env_from(mut self, value: Option<impl Iterator<Item = crate::codegen::io::k8s::api::core::v1::env_from_source::EnvFromSource>>) -> Self {
+        self.body.env_from = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`env_from`](#method.env_from), but takes the values as a `Vec`.
+    #[inline]
+    pub fn env_from_vec(mut self, value: impl Into<Vec<crate::codegen::io::k8s::api::core::v1::env_from_source::EnvFromSource>>) -> Self {
+        self.body.env_from = Some(value.into());
+        self
+    }
+
+    /// Selector is a label query over a set of resources, in this case pods. Required.
+    #[inline]
+    pub fn selector(mut self, value: crate::codegen::io::k8s::apimachinery::pkg::apis::meta::v1::label_selector::LabelSelector) -> Self {
+        self.body.selector = Some(value.into());
+        self
+    }
+
+    /// Sets [`selector`](#method.selector) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_selector(mut self, value: Option<crate::codegen::io::k8s::apimachinery::pkg::apis::meta::v1::label_selector::LabelSelector>) -> Self {
+        self.body.selector = value.map(|value| value.into());
+        self
+    }
+
+    /// VolumeMounts defines the collection of VolumeMount to inject into containers.
+    #[inline]
+    pub fn volume_mounts(mut self, value: impl Iterator<Item = crate::codegen::io::k8s::api::core::v1::volume_mount::VolumeMountBuilder<crate::codegen::generics::MountPathExists, crate::codegen::generics::NameExists>>) -> Self {
+        self.body.volume_mounts = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`volume_mounts`](#method.volume_mounts) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_volume_mounts(mut self, value: Option<impl Iterator<Item = crate::codegen::io::k8s::api::core::v1::volume_mount::VolumeMountBuilder<crate::codegen::generics::MountPathExists, crate::codegen::generics::NameExists>>>) -> Self {
+        self.body.volume_mounts = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`volume_mounts`](#method.volume_mounts), but takes the values as a `Vec`.
+    #[inline]
+    pub fn volume_mounts_vec(mut self, value: impl Into<Vec<crate::codegen::io::k8s::api::core::v1::volume_mount::VolumeMount>>) -> Self {
+        self.body.volume_mounts = Some(value.into());
+        self
+    }
+
+    /// Volumes defines the collection of Volume to inject into the pod.
+    #[inline]
+    pub fn volumes(mut self, value: impl Iterator<Item = crate::codegen::io::k8s::api::core::v1::volume::VolumeBuilder<crate::codegen::generics::NameExists>>) -> Self {
+        self.body.volumes = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`volumes`](#method.volumes) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_volumes(mut self, value: Option<impl Iterator<Item = crate::codegen::io::k8s::api::core::v1::volume::VolumeBuilder<crate::codegen::generics::NameExists>>>) -> Self {
+        self.body.volumes = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`volumes`](#method.volumes), but takes the values as a `Vec`.
+    #[inline]
+    pub fn volumes_vec(mut self, value: impl Into<Vec<crate::codegen::io::k8s::api::core::v1::volume::Volume>>) -> Self {
+        self.body.volumes = Some(value.into());
+        self
+    }
+}
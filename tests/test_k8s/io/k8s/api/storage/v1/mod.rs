@@ -0,0 +1,32 @@
+
+pub mod storage_class {
+    include!("./storage_class.rs");
+}
+
+pub mod storage_class_list {
+    include!("./storage_class_list.rs");
+}
+
+pub mod volume_attachment {
+    include!("./volume_attachment.rs");
+}
+
+pub mod volume_attachment_list {
+    include!("./volume_attachment_list.rs");
+}
+
+pub mod volume_attachment_source {
+    include!("./volume_attachment_source.rs");
+}
+
+pub mod volume_attachment_spec {
+    include!("./volume_attachment_spec.rs");
+}
+
+pub mod volume_attachment_status {
+    include!("./volume_attachment_status.rs");
+}
+
+pub mod volume_error {
+    include!("./volume_error.rs");
+}
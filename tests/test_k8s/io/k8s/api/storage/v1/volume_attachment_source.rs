@@ -0,0 +1,57 @@
+
+/// VolumeAttachmentSource represents a volume that should be attached. Right now only PersistenVolumes can be attached via external attacher, in future we may allow also inline volumes in pods. Exactly one member can be set.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct VolumeAttachmentSource {
+    /// Name of the persistent volume to attach.
+    #[serde(rename = "persistentVolumeName", skip_serializing_if = "Option::is_none")]
+    pub persistent_volume_name: Option<String>,
+}
+
+impl VolumeAttachmentSource {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> VolumeAttachmentSourceBuilder {
+        VolumeAttachmentSourceBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> VolumeAttachmentSourceBuilder
+    where
+        Self: Clone,
+    {
+        VolumeAttachmentSourceBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<VolumeAttachmentSource> for VolumeAttachmentSourceBuilder {
+    fn into(self) -> VolumeAttachmentSource {
+        self.body
+    }
+}
+
+/// Builder for [`VolumeAttachmentSource`](./struct.VolumeAttachmentSource.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct VolumeAttachmentSourceBuilder {
+    body: self::VolumeAttachmentSource,
+}
+
+impl VolumeAttachmentSourceBuilder {
+    /// Name of the persistent volume to attach.
+    #[inline]
+    pub fn persistent_volume_name(mut self, value: impl Into<String>) -> Self {
+        self.body.persistent_volume_name = Some(value.into());
+        self
+    }
+
+    /// Sets [`persistent_volume_name`](#method.persistent_volume_name) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_persistent_volume_name(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.persistent_volume_name = value.map(|value| value.into());
+        self
+    }
+}
@@ -0,0 +1,109 @@
+
+/// VolumeAttachmentStatus is the status of a VolumeAttachment request.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct VolumeAttachmentStatus {
+    /// The last error encountered during attach operation, if any. This field must only be set by the entity completing the attach operation, i.e. the external-attacher.
+    #[serde(rename = "attachError", skip_serializing_if = "Option::is_none")]
+    pub attach_error: Option<crate::codegen::io::k8s::api::storage::v1::volume_error::VolumeError>,
+    /// Indicates the volume is successfully attached. This field must only be set by the entity completing the attach operation, i.e. the external-attacher.
+    pub attached: bool,
+    /// Upon successful attach, this field is populated with any information returned by the attach operation that must be passed into subsequent WaitForAttach or Mount calls. This field must only be set by the entity completing the attach operation, i.e. the external-attacher.
+    #[serde(rename = "attachmentMetadata", skip_serializing_if = "Option::is_none")]
+    pub attachment_metadata: Option<std::collections::BTreeMap<String, String>>,
+    /// The last error encountered during detach operation, if any. This field must only be set by the entity completing the detach operation, i.e. the external-attacher.
+    #[serde(rename = "detachError", skip_serializing_if = "Option::is_none")]
+    pub detach_error: Option<crate::codegen::io::k8s::api::storage::v1::volume_error::VolumeError>,
+}
+
+impl VolumeAttachmentStatus {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> VolumeAttachmentStatusBuilder<crate::codegen::generics::MissingAttached> {
+        VolumeAttachmentStatusBuilder {
+            body: Default::default(),
+            _attached: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> VolumeAttachmentStatusBuilder<crate::codegen::generics::AttachedExists>
+    where
+        Self: Clone,
+    {
+        VolumeAttachmentStatusBuilder {
+            body: self.clone(),
+            _attached: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<VolumeAttachmentStatus> for VolumeAttachmentStatusBuilder<crate::codegen::generics::AttachedExists> {
+    fn into(self) -> VolumeAttachmentStatus {
+        self.body
+    }
+}
+
+/// Builder for [`VolumeAttachmentStatus`](./struct.VolumeAttachmentStatus.html) object.
+#[derive(Debug, Clone)]
+pub struct VolumeAttachmentStatusBuilder<Attached> {
+    body: self::VolumeAttachmentStatus,
+    _attached: core::marker::PhantomData<Attached>,
+}
+
+impl<Attached> VolumeAttachmentStatusBuilder<Attached> {
+    /// The last error encountered during attach operation, if any. This field must only be set by the entity completing the attach operation, i.e. the external-attacher.
+    #[inline]
+    pub fn attach_error(mut self, value: crate::codegen::io::k8s::api::storage::v1::volume_error::VolumeError) -> Self {
+        self.body.attach_error = Some(value.into());
+        self
+    }
+
+    /// Sets [`attach_error`](#method.attach_error) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_attach_error(mut self, value: Option<crate::codegen::io::k8s::api::storage::v1::volume_error::VolumeError>) -> Self {
+        self.body.attach_error = value.map(|value| value.into());
+        self
+    }
+
+    /// Indicates the volume is successfully attached. This field must only be set by the entity completing the attach operation, i.e. the external-attacher.
+    #[inline]
+    pub fn attached(mut self, value: impl Into<bool>) -> VolumeAttachmentStatusBuilder<crate::codegen::generics::AttachedExists> {
+        self.body.attached = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Same as [`attached`](#method.attached), but takes a reference.
+    #[inline]
+    pub fn attached_ref(self, value: &bool) -> VolumeAttachmentStatusBuilder<crate::codegen::generics::AttachedExists> {
+        self.attached(*value)
+    }
+
+    /// Upon successful attach, this field is populated with any information returned by the attach operation that must be passed into subsequent WaitForAttach or Mount calls. This field must only be set by the entity completing the attach operation, i.e. the external-attacher.
+    #[inline]
+    pub fn attachment_metadata(mut self, value: impl Iterator<Item = (String, impl Into<String>)>) -> Self {
+        self.body.attachment_metadata = Some(value.map(|(key, value)| (key, value.into())).collect::<std::collections::BTreeMap<_, _>>().into());
+        self
+    }
+
+    /// Sets [`attachment_metadata`](#method.attachment_metadata) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_attachment_metadata(mut self, value: Option<impl Iterator<Item = (String, impl Into<String>)>>) -> Self {
+        self.body.attachment_metadata = value.map(|value| value.map(|(key, value)| (key, value.into())).collect::<std::collections::BTreeMap<_, _>>().into());
+        self
+    }
+
+    /// The last error encountered during detach operation, if any. This field must only be set by the entity completing the detach operation, i.e. the external-attacher.
+    #[inline]
+    pub fn detach_error(mut self, value: crate::codegen::io::k8s::api::storage::v1::volume_error::VolumeError) -> Self {
+        self.body.detach_error = Some(value.into());
+        self
+    }
+
+    /// Sets [`detach_error`](#method.detach_error) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_detach_error(mut self, value: Option<crate::codegen::io::k8s::api::storage::v1::volume_error::VolumeError>) -> Self {
+        self.body.detach_error = value.map(|value| value.into());
+        self
+    }
+}
@@ -0,0 +1,77 @@
+
+/// VolumeAttachmentSpec is the specification of a VolumeAttachment request.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct VolumeAttachmentSpec {
+    /// Attacher indicates the name of the volume driver that MUST handle this request. This is the name returned by GetPluginName().
+    pub attacher: String,
+    /// The node that the volume should be attached to.
+    #[serde(rename = "nodeName")]
+    pub node_name: String,
+    /// Source represents the volume that should be attached.
+    pub source: crate::codegen::io::k8s::api::storage::v1alpha1::volume_attachment_source::VolumeAttachmentSource,
+}
+
+impl VolumeAttachmentSpec {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> VolumeAttachmentSpecBuilder<crate::codegen::generics::MissingAttacher, crate::codegen::generics::MissingNodeName, crate::codegen::generics::MissingSource> {
+        VolumeAttachmentSpecBuilder {
+            body: Default::default(),
+            _attacher: core::marker::PhantomData,
+            _node_name: core::marker::PhantomData,
+            _source: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> VolumeAttachmentSpecBuilder<crate::codegen::generics::AttacherExists, crate::codegen::generics::NodeNameExists, crate::codegen::generics::SourceExists>
+    where
+        Self: Clone,
+    {
+        VolumeAttachmentSpecBuilder {
+            body: self.clone(),
+            _attacher: core::marker::PhantomData,
+            _node_name: core::marker::PhantomData,
+            _source: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<VolumeAttachmentSpec> for VolumeAttachmentSpecBuilder<crate::codegen::generics::AttacherExists, crate::codegen::generics::NodeNameExists, crate::codegen::generics::SourceExists> {
+    fn into(self) -> VolumeAttachmentSpec {
+        self.body
+    }
+}
+
+/// Builder for [`VolumeAttachmentSpec`](./struct.VolumeAttachmentSpec.html) object.
+#[derive(Debug, Clone)]
+pub struct VolumeAttachmentSpecBuilder<Attacher, NodeName, Source> {
+    body: self::VolumeAttachmentSpec,
+    _attacher: core::marker::PhantomData<Attacher>,
+    _node_name: core::marker::PhantomData<NodeName>,
+    _source: core::marker::PhantomData<Source>,
+}
+
+impl<Attacher, NodeName, Source> VolumeAttachmentSpecBuilder<Attacher, NodeName, Source> {
+    /// Attacher indicates the name of the volume driver that MUST handle this request. This is the name returned by GetPluginName().
+    #[inline]
+    pub fn attacher(mut self, value: impl Into<String>) -> VolumeAttachmentSpecBuilder<crate::codegen::generics::AttacherExists, NodeName, Source> {
+        self.body.attacher = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// The node that the volume should be attached to.
+    #[inline]
+    pub fn node_name(mut self, value: impl Into<String>) -> VolumeAttachmentSpecBuilder<Attacher, crate::codegen::generics::NodeNameExists, Source> {
+        self.body.node_name = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Source represents the volume that should be attached.
+    #[inline]
+    pub fn source(mut self, value: crate::codegen::io::k8s::api::storage::v1alpha1::volume_attachment_source::VolumeAttachmentSource) -> VolumeAttachmentSpecBuilder<Attacher, NodeName, crate::codegen::generics::SourceExists> {
+        self.body.source = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+}
@@ -0,0 +1,59 @@
+
+/// CSINodeSpec holds information about the specification of all CSI drivers installed on a node
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct CsiNodeSpec {
+    /// drivers is a list of information of all CSI Drivers existing on a node. If all drivers in the list are uninstalled, this can become empty.
+    pub drivers: Vec<crate::codegen::io::k8s::api::storage::v1beta1::csi_node_driver::CsiNodeDriver>,
+}
+
+impl CsiNodeSpec {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> CsiNodeSpecBuilder<crate::codegen::generics::MissingDrivers> {
+        CsiNodeSpecBuilder {
+            body: Default::default(),
+            _drivers: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> CsiNodeSpecBuilder<crate::codegen::generics::DriversExists>
+    where
+        Self: Clone,
+    {
+        CsiNodeSpecBuilder {
+            body: self.clone(),
+            _drivers: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<CsiNodeSpec> for CsiNodeSpecBuilder<crate::codegen::generics::DriversExists> {
+    fn into(self) -> CsiNodeSpec {
+        self.body
+    }
+}
+
+/// Builder for [`CsiNodeSpec`](./struct.CsiNodeSpec.html) object.
+#[derive(Debug, Clone)]
+pub struct CsiNodeSpecBuilder<Drivers> {
+    body: self::CsiNodeSpec,
+    _drivers: core::marker::PhantomData<Drivers>,
+}
+
+impl<Drivers> CsiNodeSpecBuilder<Drivers> {
+    /// drivers is a list of information of all CSI Drivers existing on a node. If all drivers in the list are uninstalled, this can become empty.
+    #[inline]
+    pub fn drivers(mut self, value: impl Iterator<Item = crate::codegen::io::k8s::api::storage::v1beta1::csi_node_driver::CsiNodeDriverBuilder<crate::codegen::generics::NameExists, crate::codegen::generics::NodeIdExists>>) -> CsiNodeSpecBuilder<crate::codegen::generics::DriversExists> {
+        self.body.drivers = value.map(|value| value.into()).collect::<Vec<_>>().into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Same as [`drivers`](#method.drivers), but takes the values as a `Vec`.
+    #[inline]
+    pub fn drivers_vec(mut self, value: impl Into<Vec<crate::codegen::io::k8s::api::storage::v1beta1::csi_node_driver::CsiNodeDriver>>) -> CsiNodeSpecBuilder<crate::codegen::generics::DriversExists> {
+        self.body.drivers = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+}
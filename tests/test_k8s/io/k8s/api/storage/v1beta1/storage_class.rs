@@ -0,0 +1,901 @@
+
+/// StorageClass describes the parameters for a class of storage for which PersistentVolumes can be dynamically provisioned.
+///
+/// StorageClasses are non-namespaced; the name of the storage class according to etcd is in ObjectMeta.Name.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct StorageClass {
+    /// AllowVolumeExpansion shows whether the storage class allow volume expand
+    #[serde(rename = "allowVolumeExpansion", skip_serializing_if = "Option::is_none")]
+    pub allow_volume_expansion: Option<bool>,
+    /// Restrict the node topologies where volumes can be dynamically provisioned. Each volume plugin defines its own supported topology specifications. An empty TopologySelectorTerm list means there is no topology restriction. This field is only honored by servers that enable the VolumeScheduling feature.
+    #[serde(rename = "allowedTopologies", skip_serializing_if = "Option::is_none")]
+    pub allowed_topologies: Option<Vec<crate::codegen::io::k8s::api::core::v1::topology_selector_term::TopologySelectorTerm>>,
+    /// APIVersion defines the versioned schema of this representation of an object. Servers should convert recognized schemas to the latest internal value, and may reject unrecognized values. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#resources
+    #[serde(rename = "apiVersion", skip_serializing_if = "Option::is_none")]
+    pub api_version: Option<String>,
+    /// Kind is a string value representing the REST resource this object represents. Servers may infer this from the endpoint the client submits requests to. Cannot be updated. In CamelCase. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#types-kinds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub kind: Option<String>,
+    /// Standard object's metadata. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#metadata
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<crate::codegen::io::k8s::apimachinery::pkg::apis::meta::v1::object_meta::ObjectMeta>,
+    /// Dynamically provisioned PersistentVolumes of this storage class are created with these mountOptions, e.g. \["ro", "soft"\]. Not validated - mount of the PVs will simply fail if one is invalid.
+    #[serde(rename = "mountOptions", skip_serializing_if = "Option::is_none")]
+    pub mount_options: Option<Vec<String>>,
+    /// Parameters holds the parameters for the provisioner that should create volumes of this storage class.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parameters: Option<std::collections::BTreeMap<String, String>>,
+    /// Provisioner indicates the type of the provisioner.
+    pub provisioner: String,
+    /// Dynamically provisioned PersistentVolumes of this storage class are created with this reclaimPolicy. Defaults to Delete.
+    #[serde(rename = "reclaimPolicy", skip_serializing_if = "Option::is_none")]
+    pub reclaim_policy: Option<String>,
+    /// VolumeBindingMode indicates how PersistentVolumeClaims should be provisioned and bound.  When unset, VolumeBindingImmediate is used. This field is only honored by servers that enable the VolumeScheduling feature.
+    #[serde(rename = "volumeBindingMode", skip_serializing_if = "Option::is_none")]
+    pub volume_binding_mode: Option<String>,
+}
+
+impl StorageClass {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> StorageClassBuilder<crate::codegen::generics::MissingProvisioner> {
+        StorageClassBuilder {
+            body: Default::default(),
+            _provisioner: core::marker::PhantomData,
+        }
+    }
+
+    /// create a StorageClass
+    #[inline]
+    pub fn create_storage_v1beta1_storage_class() -> StorageClassPostBuilder<crate::codegen::generics::MissingProvisioner> {
+        StorageClassPostBuilder {
+            inner: Default::default(),
+            _provisioner: core::marker::PhantomData,
+        }
+    }
+
+    /// read the specified StorageClass
+    #[inline]
+    pub fn read_storage_v1beta1_storage_class() -> StorageClassGetBuilder1<crate::codegen::generics::MissingName> {
+        StorageClassGetBuilder1 {
+            inner: Default::default(),
+            _param_name: core::marker::PhantomData,
+        }
+    }
+
+    /// replace the specified StorageClass
+    #[inline]
+    pub fn replace_storage_v1beta1_storage_class() -> StorageClassPutBuilder1<crate::codegen::generics::MissingName, crate::codegen::generics::MissingProvisioner> {
+        StorageClassPutBuilder1 {
+            inner: Default::default(),
+            _param_name: core::marker::PhantomData,
+            _provisioner: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> StorageClassBuilder<crate::codegen::generics::ProvisionerExists>
+    where
+        Self: Clone,
+    {
+        StorageClassBuilder {
+            body: self.clone(),
+            _provisioner: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<StorageClass> for StorageClassBuilder<crate::codegen::generics::ProvisionerExists> {
+    fn into(self) -> StorageClass {
+        self.body
+    }
+}
+
+impl Into<StorageClass> for StorageClassPostBuilder<crate::codegen::generics::ProvisionerExists> {
+    fn into(self) -> StorageClass {
+        self.inner.body
+    }
+}
+
+impl Into<StorageClass> for StorageClassPutBuilder1<crate::codegen::generics::NameExists, crate::codegen::generics::ProvisionerExists> {
+    fn into(self) -> StorageClass {
+        self.inner.body
+    }
+}
+
+/// Builder for [`StorageClass`](./struct.StorageClass.html) object.
+#[derive(Debug, Clone)]
+pub struct StorageClassBuilder<Provisioner> {
+    body: self::StorageClass,
+    _provisioner: core::marker::PhantomData<Provisioner>,
+}
+
+impl<Provisioner> StorageClassBuilder<Provisioner> {
+    /// AllowVolumeExpansion shows whether the storage class allow volume expand
+    #[inline]
+    pub fn allow_volume_expansion(mut self, value: impl Into<bool>) -> Self {
+        self.body.allow_volume_expansion = Some(value.into());
+        self
+    }
+
+    /// Sets [`allow_volume_expansion`](#method.allow_volume_expansion) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_allow_volume_expansion(mut self, value: Option<impl Into<bool>>) -> Self {
+        self.body.allow_volume_expansion = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`allow_volume_expansion`](#method.allow_volume_expansion), but takes a reference.
+    #[inline]
+    pub fn allow_volume_expansion_ref(self, value: &bool) -> Self {
+        self.allow_volume_expansion(*value)
+    }
+
+    /// Restrict the node topologies where volumes can be dynamically provisioned. Each volume plugin defines its own supported topology specifications. An empty TopologySelectorTerm list means there is no topology restriction. This field is only honored by servers that enable the VolumeScheduling feature.
+    #[inline]
+    pub fn allowed_topologies(mut self, value: impl Iterator<Item = crate::codegen::io::k8s::api::core::v1::topology_selector_term::TopologySelectorTerm>) -> Self {
+        self.body.allowed_topologies = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`allowed_topologies`](#method.allowed_topologies) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_allowed_topologies(mut self, value: Option<impl Iterator<Item = crate::codegen::io::k8s::api::core::v1::topology_selector_term::TopologySelectorTerm>>) -> Self {
+        self.body.allowed_topologies = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`allowed_topologies`](#method.allowed_topologies), but takes the values as a `Vec`.
+    #[inline]
+    pub fn allowed_topologies_vec(mut self, value: impl Into<Vec<crate::codegen::io::k8s::api::core::v1::topology_selector_term::TopologySelectorTerm>>) -> Self {
+        self.body.allowed_topologies = Some(value.into());
+        self
+    }
+
+    /// APIVersion defines the versioned schema of this representation of an object. Servers should convert recognized schemas to the latest internal value, and may reject unrecognized values. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#resources
+    #[inline]
+    pub fn api_version(mut self, value: impl Into<String>) -> Self {
+        self.body.api_version = Some(value.into());
+        self
+    }
+
+    /// Sets [`api_version`](#method.api_version) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_api_version(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.api_version = value.map(|value| value.into());
+        self
+    }
+
+    /// Kind is a string value representing the REST resource this object represents. Servers may infer this from the endpoint the client submits requests to. Cannot be updated. In CamelCase. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#types-kinds
+    #[inline]
+    pub fn kind(mut self, value: impl Into<String>) -> Self {
+        self.body.kind = Some(value.into());
+        self
+    }
+
+    /// Sets [`kind`](#method.kind) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_kind(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.kind = value.map(|value| value.into());
+        self
+    }
+
+    /// Standard object's metadata. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#metadata
+    #[inline]
+    pub fn metadata(mut self, value: crate::codegen::io::k8s::apimachinery::pkg::apis::meta::v1::object_meta::ObjectMeta) -> Self {
+        self.body.metadata = Some(value.into());
+        self
+    }
+
+    /// Sets [`metadata`](#method.metadata) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_metadata(mut self, value: Option<crate::codegen::io::k8s::apimachinery::pkg::apis::meta::v1::object_meta::ObjectMeta>) -> Self {
+        self.body.metadata = value.map(|value| value.into());
+        self
+    }
+
+    /// Dynamically provisioned PersistentVolumes of this storage class are created with these mountOptions, e.g. \["ro", "soft"\]. Not validated - mount of the PVs will simply fail if one is invalid.
+    #[inline]
+    pub fn mount_options(mut self, value: impl Iterator<Item = impl Into<String>>) -> Self {
+        self.body.mount_options = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`mount_options`](#method.mount_options) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_mount_options(mut self, value: Option<impl Iterator<Item = impl Into<String>>>) -> Self {
+        self.body.mount_options = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`mount_options`](#method.mount_options), but takes the values as a `Vec`.
+    #[inline]
+    pub fn mount_options_vec(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.body.mount_options = Some(value.into());
+        self
+    }
+
+    /// Parameters holds the parameters for the provisioner that should create volumes of this storage class.
+    #[inline]
+    pub fn parameters(mut self, value: impl Iterator<Item = (String, impl Into<String>)>) -> Self {
+        self.body.parameters = Some(value.map(|(key, value)| (key, value.into())).collect::<std::collections::BTreeMap<_, _>>().into());
+        self
+    }
+
+    /// Sets [`parameters`](#method.parameters) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_parameters(mut self, value: Option<impl Iterator<Item = (String, impl Into<String>)>>) -> Self {
+        self.body.parameters = value.map(|value| value.map(|(key, value)| (key, value.into())).collect::<std::collections::BTreeMap<_, _>>().into());
+        self
+    }
+
+    /// Provisioner indicates the type of the provisioner.
+    #[inline]
+    pub fn provisioner(mut self, value: impl Into<String>) -> StorageClassBuilder<crate::codegen::generics::ProvisionerExists> {
+        self.body.provisioner = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Dynamically provisioned PersistentVolumes of this storage class are created with this reclaimPolicy. Defaults to Delete.
+    #[inline]
+    pub fn reclaim_policy(mut self, value: impl Into<String>) -> Self {
+        self.body.reclaim_policy = Some(value.into());
+        self
+    }
+
+    /// Sets [`reclaim_policy`](#method.reclaim_policy) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_reclaim_policy(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.reclaim_policy = value.map(|value| value.into());
+        self
+    }
+
+    /// VolumeBindingMode indicates how PersistentVolumeClaims should be provisioned and bound.  When unset, VolumeBindingImmediate is used. This field is only honored by servers that enable the VolumeScheduling feature.
+    #[inline]
+    pub fn volume_binding_mode(mut self, value: impl Into<String>) -> Self {
+        self.body.volume_binding_mode = Some(value.into());
+        self
+    }
+
+    /// Sets [`volume_binding_mode`](#method.volume_binding_mode) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_volume_binding_mode(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.volume_binding_mode = value.map(|value| value.into());
+        self
+    }
+}
+
+/// Builder created by [`StorageClass::create_storage_v1beta1_storage_class`](./struct.StorageClass.html#method.create_storage_v1beta1_storage_class) method for a `POST` operation associated with `StorageClass`.
+#[repr(transparent)]
+#[derive(Debug, Clone)]
+pub struct StorageClassPostBuilder<Provisioner> {
+    inner: StorageClassPostBuilderContainer,
+    _provisioner: core::marker::PhantomData<Provisioner>,
+}
+
+#[derive(Debug, Default, Clone)]
+struct StorageClassPostBuilderContainer {
+    body: self::StorageClass,
+    param_dry_run: Option<String>,
+    param_field_manager: Option<String>,
+    param_pretty: Option<String>,
+}
+
+impl<Provisioner> StorageClassPostBuilder<Provisioner> {
+    /// When present, indicates that modifications should not be persisted. An invalid or unrecognized dryRun directive will result in an error response and no further processing of the request. Valid values are: - All: all dry run stages will be processed
+    #[inline]
+    pub fn dry_run(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_dry_run = Some(value.into());
+        self
+    }
+
+    /// Sets [`dry_run`](#method.dry_run) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_dry_run(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_dry_run = value.map(|value| value.into());
+        self
+    }
+
+    /// fieldManager is a name associated with the actor or entity that is making these changes. The value must be less than or 128 characters long, and only contain printable characters, as defined by https://golang.org/pkg/unicode/#IsPrint.
+    #[inline]
+    pub fn field_manager(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_field_manager = Some(value.into());
+        self
+    }
+
+    /// Sets [`field_manager`](#method.field_manager) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_field_manager(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_field_manager = value.map(|value| value.into());
+        self
+    }
+
+    /// If 'true', then the output is pretty printed.
+    #[inline]
+    pub fn pretty(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_pretty = Some(value.into());
+        self
+    }
+
+    /// Sets [`pretty`](#method.pretty) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_pretty(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_pretty = value.map(|value| value.into());
+        self
+    }
+
+    /// AllowVolumeExpansion shows whether the storage class allow volume expand
+    #[inline]
+    pub fn allow_volume_expansion(mut self, value: impl Into<bool>) -> Self {
+        self.inner.body.allow_volume_expansion = Some(value.into());
+        self
+    }
+
+    /// Sets [`allow_volume_expansion`](#method.allow_volume_expansion) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_allow_volume_expansion(mut self, value: Option<impl Into<bool>>) -> Self {
+        self.inner.body.allow_volume_expansion = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`allow_volume_expansion`](#method.allow_volume_expansion), but takes a reference.
+    #[inline]
+    pub fn allow_volume_expansion_ref(self, value: &bool) -> Self {
+        self.allow_volume_expansion(*value)
+    }
+
+    /// Restrict the node topologies where volumes can be dynamically provisioned. Each volume plugin defines its own supported topology specifications. An empty TopologySelectorTerm list means there is no topology restriction. This field is only honored by servers that enable the VolumeScheduling feature.
+    #[inline]
+    pub fn allowed_topologies(mut self, value: impl Iterator<Item = crate::codegen::io::k8s::api::core::v1::topology_selector_term::TopologySelectorTerm>) -> Self {
+        self.inner.body.allowed_topologies = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`allowed_topologies`](#method.allowed_topologies) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_allowed_topologies(mut self, value: Option<impl Iterator<Item = crate::codegen::io::k8s::api::core::v1::topology_selector_term::TopologySelectorTerm>>) -> Self {
+        self.inner.body.allowed_topologies = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`allowed_topologies`](#method.allowed_topologies), but takes the values as a `Vec`.
+    #[inline]
+    pub fn allowed_topologies_vec(mut self, value: impl Into<Vec<crate::codegen::io::k8s::api::core::v1::topology_selector_term::TopologySelectorTerm>>) -> Self {
+        self.inner.body.allowed_topologies = Some(value.into());
+        self
+    }
+
+    /// APIVersion defines the versioned schema of this representation of an object. Servers should convert recognized schemas to the latest internal value, and may reject unrecognized values. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#resources
+    #[inline]
+    pub fn api_version(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.api_version = Some(value.into());
+        self
+    }
+
+    /// Sets [`api_version`](#method.api_version) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_api_version(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.api_version = value.map(|value| value.into());
+        self
+    }
+
+    /// Kind is a string value representing the REST resource this object represents. Servers may infer this from the endpoint the client submits requests to. Cannot be updated. In CamelCase. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#types-kinds
+    #[inline]
+    pub fn kind(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.kind = Some(value.into());
+        self
+    }
+
+    /// Sets [`kind`](#method.kind) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_kind(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.kind = value.map(|value| value.into());
+        self
+    }
+
+    /// Standard object's metadata. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#metadata
+    #[inline]
+    pub fn metadata(mut self, value: crate::codegen::io::k8s::apimachinery::pkg::apis::meta::v1::object_meta::ObjectMeta) -> Self {
+        self.inner.body.metadata = Some(value.into());
+        self
+    }
+
+    /// Sets [`metadata`](#method.metadata) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_metadata(mut self, value: Option<crate::codegen::io::k8s::apimachinery::pkg::apis::meta::v1::object_meta::ObjectMeta>) -> Self {
+        self.inner.body.metadata = value.map(|value| value.into());
+        self
+    }
+
+    /// Dynamically provisioned PersistentVolumes of this storage class are created with these mountOptions, e.g. \["ro", "soft"\]. Not validated - mount of the PVs will simply fail if one is invalid.
+    #[inline]
+    pub fn mount_options(mut self, value: impl Iterator<Item = impl Into<String>>) -> Self {
+        self.inner.body.mount_options = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`mount_options`](#method.mount_options) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_mount_options(mut self, value: Option<impl Iterator<Item = impl Into<String>>>) -> Self {
+        self.inner.body.mount_options = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`mount_options`](#method.mount_options), but takes the values as a `Vec`.
+    #[inline]
+    pub fn mount_options_vec(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.inner.body.mount_options = Some(value.into());
+        self
+    }
+
+    /// Parameters holds the parameters for the provisioner that should create volumes of this storage class.
+    #[inline]
+    pub fn parameters(mut self, value: impl Iterator<Item = (String, impl Into<String>)>) -> Self {
+        self.inner.body.parameters = Some(value.map(|(key, value)| (key, value.into())).collect::<std::collections::BTreeMap<_, _>>().into());
+        self
+    }
+
+    /// Sets [`parameters`](#method.parameters) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_parameters(mut self, value: Option<impl Iterator<Item = (String, impl Into<String>)>>) -> Self {
+        self.inner.body.parameters = value.map(|value| value.map(|(key, value)| (key, value.into())).collect::<std::collections::BTreeMap<_, _>>().into());
+        self
+    }
+
+    /// Provisioner indicates the type of the provisioner.
+    #[inline]
+    pub fn provisioner(mut self, value: impl Into<String>) -> StorageClassPostBuilder<crate::codegen::generics::ProvisionerExists> {
+        self.inner.body.provisioner = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Dynamically provisioned PersistentVolumes of this storage class are created with this reclaimPolicy. Defaults to Delete.
+    #[inline]
+    pub fn reclaim_policy(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.reclaim_policy = Some(value.into());
+        self
+    }
+
+    /// Sets [`reclaim_policy`](#method.reclaim_policy) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_reclaim_policy(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.reclaim_policy = value.map(|value| value.into());
+        self
+    }
+
+    /// VolumeBindingMode indicates how PersistentVolumeClaims should be provisioned and bound.  When unset, VolumeBindingImmediate is used. This field is only honored by servers that enable the VolumeScheduling feature.
+    #[inline]
+    pub fn volume_binding_mode(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.volume_binding_mode = Some(value.into());
+        self
+    }
+
+    /// Sets [`volume_binding_mode`](#method.volume_binding_mode) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_volume_binding_mode(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.volume_binding_mode = value.map(|value| value.into());
+        self
+    }
+}
+
+impl<Client: crate::codegen::client::ApiClient + Sync + 'static> crate::codegen::client::Sendable<Client> for StorageClassPostBuilder<crate::codegen::generics::ProvisionerExists> {
+    type Output = crate::codegen::io::k8s::api::storage::v1beta1::storage_class::StorageClass;
+
+    const METHOD: http::Method = http::Method::POST;
+
+    const OPERATION_ID: Option<&'static str> = Some("createStorageV1beta1StorageClass");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::codegen::client::ApiError<Client::Response>> {
+        Ok("/apis/storage.k8s.io/v1beta1/storageclasses".into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::codegen::client::ApiError<Client::Response>> {
+        use crate::codegen::client::Request;
+        Ok(req
+        .header(http::header::CONTENT_TYPE.as_str(), "application/json")
+        .json(&self.inner.body)
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("dryRun", self.inner.param_dry_run.as_ref().map(std::string::ToString::to_string)),
+            ("fieldManager", self.inner.param_field_manager.as_ref().map(std::string::ToString::to_string)),
+            ("pretty", self.inner.param_pretty.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Content-Type")
+            || name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<StorageClassPostBuilder<crate::codegen::generics::ProvisionerExists>> for reqwest::Request {
+    type Error = crate::codegen::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: StorageClassPostBuilder<crate::codegen::generics::ProvisionerExists>) -> Result<Self, Self::Error> {
+        let req = crate::codegen::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::codegen::client::ApiError::Reqwest)
+    }
+}
+
+/// Builder created by [`StorageClass::read_storage_v1beta1_storage_class`](./struct.StorageClass.html#method.read_storage_v1beta1_storage_class) method for a `GET` operation associated with `StorageClass`.
+#[repr(transparent)]
+#[derive(Debug, Clone)]
+pub struct StorageClassGetBuilder1<Name> {
+    inner: StorageClassGetBuilder1Container,
+    _param_name: core::marker::PhantomData<Name>,
+}
+
+#[derive(Debug, Default, Clone)]
+struct StorageClassGetBuilder1Container {
+    param_exact: Option<bool>,
+    param_export: Option<bool>,
+    param_name: Option<String>,
+    param_pretty: Option<String>,
+}
+
+impl<Name> StorageClassGetBuilder1<Name> {
+    /// Should the export be exact.  Exact export maintains cluster-specific fields like 'Namespace'. Deprecated. Planned for removal in 1.18.
+    #[inline]
+    pub fn exact(mut self, value: impl Into<bool>) -> Self {
+        self.inner.param_exact = Some(value.into());
+        self
+    }
+
+    /// Sets [`exact`](#method.exact) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_exact(mut self, value: Option<impl Into<bool>>) -> Self {
+        self.inner.param_exact = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`exact`](#method.exact), but takes a reference.
+    #[inline]
+    pub fn exact_ref(self, value: &bool) -> Self {
+        self.exact(*value)
+    }
+
+    /// Should this value be exported.  Export strips fields that a user can not specify. Deprecated. Planned for removal in 1.18.
+    #[inline]
+    pub fn export(mut self, value: impl Into<bool>) -> Self {
+        self.inner.param_export = Some(value.into());
+        self
+    }
+
+    /// Sets [`export`](#method.export) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_export(mut self, value: Option<impl Into<bool>>) -> Self {
+        self.inner.param_export = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`export`](#method.export), but takes a reference.
+    #[inline]
+    pub fn export_ref(self, value: &bool) -> Self {
+        self.export(*value)
+    }
+
+    /// name of the StorageClass
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> StorageClassGetBuilder1<crate::codegen::generics::NameExists> {
+        self.inner.param_name = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// If 'true', then the output is pretty printed.
+    #[inline]
+    pub fn pretty(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_pretty = Some(value.into());
+        self
+    }
+
+    /// Sets [`pretty`](#method.pretty) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_pretty(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_pretty = value.map(|value| value.into());
+        self
+    }
+}
+
+impl<Client: crate::codegen::client::ApiClient + Sync + 'static> crate::codegen::client::Sendable<Client> for StorageClassGetBuilder1<crate::codegen::generics::NameExists> {
+    type Output = StorageClass;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    const OPERATION_ID: Option<&'static str> = Some("readStorageV1beta1StorageClass");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::codegen::client::ApiError<Client::Response>> {
+        Ok(format!("/apis/storage.k8s.io/v1beta1/storageclasses/{name}", name=crate::codegen::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::codegen::client::ApiError<Client::Response>> {
+        use crate::codegen::client::Request;
+        Ok(req
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("exact", self.inner.param_exact.as_ref().map(std::string::ToString::to_string)),
+            ("export", self.inner.param_export.as_ref().map(std::string::ToString::to_string)),
+            ("pretty", self.inner.param_pretty.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<StorageClassGetBuilder1<crate::codegen::generics::NameExists>> for reqwest::Request {
+    type Error = crate::codegen::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: StorageClassGetBuilder1<crate::codegen::generics::NameExists>) -> Result<Self, Self::Error> {
+        let req = crate::codegen::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::codegen::client::ApiError::Reqwest)
+    }
+}
+
+/// Builder created by [`StorageClass::replace_storage_v1beta1_storage_class`](./struct.StorageClass.html#method.replace_storage_v1beta1_storage_class) method for a `PUT` operation associated with `StorageClass`.
+#[repr(transparent)]
+#[derive(Debug, Clone)]
+pub struct StorageClassPutBuilder1<Name, Provisioner> {
+    inner: StorageClassPutBuilder1Container,
+    _param_name: core::marker::PhantomData<Name>,
+    _provisioner: core::marker::PhantomData<Provisioner>,
+}
+
+#[derive(Debug, Default, Clone)]
+struct StorageClassPutBuilder1Container {
+    body: self::StorageClass,
+    param_dry_run: Option<String>,
+    param_field_manager: Option<String>,
+    param_name: Option<String>,
+    param_pretty: Option<String>,
+}
+
+impl<Name, Provisioner> StorageClassPutBuilder1<Name, Provisioner> {
+    /// When present, indicates that modifications should not be persisted. An invalid or unrecognized dryRun directive will result in an error response and no further processing of the request. Valid values are: - All: all dry run stages will be processed
+    #[inline]
+    pub fn dry_run(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_dry_run = Some(value.into());
+        self
+    }
+
+    /// Sets [`dry_run`](#method.dry_run) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_dry_run(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_dry_run = value.map(|value| value.into());
+        self
+    }
+
+    /// fieldManager is a name associated with the actor or entity that is making these changes. The value must be less than or 128 characters long, and only contain printable characters, as defined by https://golang.org/pkg/unicode/#IsPrint.
+    #[inline]
+    pub fn field_manager(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_field_manager = Some(value.into());
+        self
+    }
+
+    /// Sets [`field_manager`](#method.field_manager) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_field_manager(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_field_manager = value.map(|value| value.into());
+        self
+    }
+
+    /// name of the StorageClass
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> StorageClassPutBuilder1<crate::codegen::generics::NameExists, Provisioner> {
+        self.inner.param_name = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// If 'true', then the output is pretty printed.
+    #[inline]
+    pub fn pretty(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_pretty = Some(value.into());
+        self
+    }
+
+    /// Sets [`pretty`](#method.pretty) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_pretty(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_pretty = value.map(|value| value.into());
+        self
+    }
+
+    /// AllowVolumeExpansion shows whether the storage class allow volume expand
+    #[inline]
+    pub fn allow_volume_expansion(mut self, value: impl Into<bool>) -> Self {
+        self.inner.body.allow_volume_expansion = Some(value.into());
+        self
+    }
+
+    /// Sets [`allow_volume_expansion`](#method.allow_volume_expansion) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_allow_volume_expansion(mut self, value: Option<impl Into<bool>>) -> Self {
+        self.inner.body.allow_volume_expansion = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`allow_volume_expansion`](#method.allow_volume_expansion), but takes a reference.
+    #[inline]
+    pub fn allow_volume_expansion_ref(self, value: &bool) -> Self {
+        self.allow_volume_expansion(*value)
+    }
+
+    /// Restrict the node topologies where volumes can be dynamically provisioned. Each volume plugin defines its own supported topology specifications. An empty TopologySelectorTerm list means there is no topology restriction. This field is only honored by servers that enable the VolumeScheduling feature.
+    #[inline]
+    pub fn allowed_topologies(mut self, value: impl Iterator<Item = crate::codegen::io::k8s::api::core::v1::topology_selector_term::TopologySelectorTerm>) -> Self {
+        self.inner.body.allowed_topologies = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`allowed_topologies`](#method.allowed_topologies) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_allowed_topologies(mut self, value: Option<impl Iterator<Item = crate::codegen::io::k8s::api::core::v1::topology_selector_term::TopologySelectorTerm>>) -> Self {
+        self.inner.body.allowed_topologies = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`allowed_topologies`](#method.allowed_topologies), but takes the values as a `Vec`.
+    #[inline]
+    pub fn allowed_topologies_vec(mut self, value: impl Into<Vec<crate::codegen::io::k8s::api::core::v1::topology_selector_term::TopologySelectorTerm>>) -> Self {
+        self.inner.body.allowed_topologies = Some(value.into());
+        self
+    }
+
+    /// APIVersion defines the versioned schema of this representation of an object. Servers should convert recognized schemas to the latest internal value, and may reject unrecognized values. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#resources
+    #[inline]
+    pub fn api_version(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.api_version = Some(value.into());
+        self
+    }
+
+    /// Sets [`api_version`](#method.api_version) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_api_version(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.api_version = value.map(|value| value.into());
+        self
+    }
+
+    /// Kind is a string value representing the REST resource this object represents. Servers may infer this from the endpoint the client submits requests to. Cannot be updated. In CamelCase. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#types-kinds
+    #[inline]
+    pub fn kind(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.kind = Some(value.into());
+        self
+    }
+
+    /// Sets [`kind`](#method.kind) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_kind(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.kind = value.map(|value| value.into());
+        self
+    }
+
+    /// Standard object's metadata. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#metadata
+    #[inline]
+    pub fn metadata(mut self, value: crate::codegen::io::k8s::apimachinery::pkg::apis::meta::v1::object_meta::ObjectMeta) -> Self {
+        self.inner.body.metadata = Some(value.into());
+        self
+    }
+
+    /// Sets [`metadata`](#method.metadata) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_metadata(mut self, value: Option<crate::codegen::io::k8s::apimachinery::pkg::apis::meta::v1::object_meta::ObjectMeta>) -> Self {
+        self.inner.body.metadata = value.map(|value| value.into());
+        self
+    }
+
+    /// Dynamically provisioned PersistentVolumes of this storage class are created with these mountOptions, e.g. \["ro", "soft"\]. Not validated - mount of the PVs will simply fail if one is invalid.
+    #[inline]
+    pub fn mount_options(mut self, value: impl Iterator<Item = impl Into<String>>) -> Self {
+        self.inner.body.mount_options = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`mount_options`](#method.mount_options) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_mount_options(mut self, value: Option<impl Iterator<Item = impl Into<String>>>) -> Self {
+        self.inner.body.mount_options = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`mount_options`](#method.mount_options), but takes the values as a `Vec`.
+    #[inline]
+    pub fn mount_options_vec(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.inner.body.mount_options = Some(value.into());
+        self
+    }
+
+    /// Parameters holds the parameters for the provisioner that should create volumes of this storage class.
+    #[inline]
+    pub fn parameters(mut self, value: impl Iterator<Item = (String, impl Into<String>)>) -> Self {
+        self.inner.body.parameters = Some(value.map(|(key, value)| (key, value.into())).collect::<std::collections::BTreeMap<_, _>>().into());
+        self
+    }
+
+    /// Sets [`parameters`](#method.parameters) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_parameters(mut self, value: Option<impl Iterator<Item = (String, impl Into<String>)>>) -> Self {
+        self.inner.body.parameters = value.map(|value| value.map(|(key, value)| (key, value.into())).collect::<std::collections::BTreeMap<_, _>>().into());
+        self
+    }
+
+    /// Provisioner indicates the type of the provisioner.
+    #[inline]
+    pub fn provisioner(mut self, value: impl Into<String>) -> StorageClassPutBuilder1<Name, crate::codegen::generics::ProvisionerExists> {
+        self.inner.body.provisioner = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Dynamically provisioned PersistentVolumes of this storage class are created with this reclaimPolicy. Defaults to Delete.
+    #[inline]
+    pub fn reclaim_policy(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.reclaim_policy = Some(value.into());
+        self
+    }
+
+    /// Sets [`reclaim_policy`](#method.reclaim_policy) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_reclaim_policy(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.reclaim_policy = value.map(|value| value.into());
+        self
+    }
+
+    /// VolumeBindingMode indicates how PersistentVolumeClaims should be provisioned and bound.  When unset, VolumeBindingImmediate is used. This field is only honored by servers that enable the VolumeScheduling feature.
+    #[inline]
+    pub fn volume_binding_mode(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.volume_binding_mode = Some(value.into());
+        self
+    }
+
+    /// Sets [`volume_binding_mode`](#method.volume_binding_mode) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_volume_binding_mode(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.body.volume_binding_mode = value.map(|value| value.into());
+        self
+    }
+}
+
+impl<Client: crate::codegen::client::ApiClient + Sync + 'static> crate::codegen::client::Sendable<Client> for StorageClassPutBuilder1<crate::codegen::generics::NameExists, crate::codegen::generics::ProvisionerExists> {
+    type Output = crate::codegen::io::k8s::api::storage::v1beta1::storage_class::StorageClass;
+
+    const METHOD: http::Method = http::Method::PUT;
+
+    const OPERATION_ID: Option<&'static str> = Some("replaceStorageV1beta1StorageClass");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::codegen::client::ApiError<Client::Response>> {
+        Ok(format!("/apis/storage.k8s.io/v1beta1/storageclasses/{name}", name=crate::codegen::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::codegen::client::ApiError<Client::Response>> {
+        use crate::codegen::client::Request;
+        Ok(req
+        .header(http::header::CONTENT_TYPE.as_str(), "application/json")
+        .json(&self.inner.body)
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("dryRun", self.inner.param_dry_run.as_ref().map(std::string::ToString::to_string)),
+            ("fieldManager", self.inner.param_field_manager.as_ref().map(std::string::ToString::to_string)),
+            ("pretty", self.inner.param_pretty.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Content-Type")
+            || name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<StorageClassPutBuilder1<crate::codegen::generics::NameExists, crate::codegen::generics::ProvisionerExists>> for reqwest::Request {
+    type Error = crate::codegen::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: StorageClassPutBuilder1<crate::codegen::generics::NameExists, crate::codegen::generics::ProvisionerExists>) -> Result<Self, Self::Error> {
+        let req = crate::codegen::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::codegen::client::ApiError::Reqwest)
+    }
+}
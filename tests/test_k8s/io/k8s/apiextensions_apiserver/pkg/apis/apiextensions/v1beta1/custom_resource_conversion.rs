@@ -0,0 +1,93 @@
+
+/// CustomResourceConversion describes how to convert different versions of a CR.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct CustomResourceConversion {
+    /// ConversionReviewVersions is an ordered list of preferred `ConversionReview` versions the Webhook expects. API server will try to use first version in the list which it supports. If none of the versions specified in this list supported by API server, conversion will fail for this object. If a persisted Webhook configuration specifies allowed versions and does not include any versions known to the API Server, calls to the webhook will fail. Default to `['v1beta1']`.
+    #[serde(rename = "conversionReviewVersions", skip_serializing_if = "Option::is_none")]
+    pub conversion_review_versions: Option<Vec<String>>,
+    /// `strategy` specifies the conversion strategy. Allowed values are: - `None`: The converter only change the apiVersion and would not touch any other field in the CR. - `Webhook`: API Server will call to an external webhook to do the conversion. Additional information is needed for this option.
+    pub strategy: String,
+    /// `webhookClientConfig` is the instructions for how to call the webhook if strategy is `Webhook`. This field is alpha-level and is only honored by servers that enable the CustomResourceWebhookConversion feature.
+    #[serde(rename = "webhookClientConfig", skip_serializing_if = "Option::is_none")]
+    pub webhook_client_config: Option<crate::codegen::io::k8s::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::webhook_client_config::WebhookClientConfig>,
+}
+
+impl CustomResourceConversion {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> CustomResourceConversionBuilder<crate::codegen::generics::MissingStrategy> {
+        CustomResourceConversionBuilder {
+            body: Default::default(),
+            _strategy: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> CustomResourceConversionBuilder<crate::codegen::generics::StrategyExists>
+    where
+        Self: Clone,
+    {
+        CustomResourceConversionBuilder {
+            body: self.clone(),
+            _strategy: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<CustomResourceConversion> for CustomResourceConversionBuilder<crate::codegen::generics::StrategyExists> {
+    fn into(self) -> CustomResourceConversion {
+        self.body
+    }
+}
+
+/// Builder for [`CustomResourceConversion`](./struct.CustomResourceConversion.html) object.
+#[derive(Debug, Clone)]
+pub struct CustomResourceConversionBuilder<Strategy> {
+    body: self::CustomResourceConversion,
+    _strategy: core::marker::PhantomData<Strategy>,
+}
+
+impl<Strategy> CustomResourceConversionBuilder<Strategy> {
+    /// ConversionReviewVersions is an ordered list of preferred `ConversionReview` versions the Webhook expects. API server will try to use first version in the list which it supports. If none of the versions specified in this list supported by API server, conversion will fail for this object. If a persisted Webhook configuration specifies allowed versions and does not include any versions known to the API Server, calls to the webhook will fail. Default to `['v1beta1']`.
+    #[inline]
+    pub fn conversion_review_versions(mut self, value: impl Iterator<Item = impl Into<String>>) -> Self {
+        self.body.conversion_review_versions = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`conversion_review_versions`](#method.conversion_review_versions) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_conversion_review_versions(mut self, value: Option<impl Iterator<Item = impl Into<String>>>) -> Self {
+        self.body.conversion_review_versions = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`conversion_review_versions`](#method.conversion_review_versions), but takes the values as a `Vec`.
+    #[inline]
+    pub fn conversion_review_versions_vec(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.body.conversion_review_versions = Some(value.into());
+        self
+    }
+
+    /// `strategy` specifies the conversion strategy. Allowed values are: - `None`: The converter only change the apiVersion and would not touch any other field in the CR. - `Webhook`: API Server will call to an external webhook to do the conversion. Additional information is needed for this option.
+    #[inline]
+    pub fn strategy(mut self, value: impl Into<String>) -> CustomResourceConversionBuilder<crate::codegen::generics::StrategyExists> {
+        self.body.strategy = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// `webhookClientConfig` is the instructions for how to call the webhook if strategy is `Webhook`. This field is alpha-level and is only honored by servers that enable the CustomResourceWebhookConversion feature.
+    #[inline]
+    pub fn webhook_client_config(mut self, value: crate::codegen::io::k8s::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::webhook_client_config::WebhookClientConfig) -> Self {
+        self.body.webhook_client_config = Some(value.into());
+        self
+    }
+
+    /// Sets [`webhook_client_config`](#method.webhook_client_config) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_webhook_client_config(mut self, value: Option<crate::codegen::io::k8s::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::webhook_client_config::WebhookClientConfig>) -> Self {
+        self.body.webhook_client_config = value.map(|value| value.into());
+        self
+    }
+}
@@ -0,0 +1,146 @@
+
+/// CustomResourceDefinitionNames indicates the names to serve this CustomResourceDefinition
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct CustomResourceDefinitionNames {
+    /// Categories is a list of grouped resources custom resources belong to (e.g. 'all')
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub categories: Option<Vec<String>>,
+    /// Kind is the serialized kind of the resource.  It is normally CamelCase and singular.
+    pub kind: String,
+    /// ListKind is the serialized kind of the list for this resource.  Defaults to \<kind>List.
+    #[serde(rename = "listKind", skip_serializing_if = "Option::is_none")]
+    pub list_kind: Option<String>,
+    /// Plural is the plural name of the resource to serve.  It must match the name of the CustomResourceDefinition-registration too: plural.group and it must be all lowercase.
+    pub plural: String,
+    /// ShortNames are short names for the resource.  It must be all lowercase.
+    #[serde(rename = "shortNames", skip_serializing_if = "Option::is_none")]
+    pub short_names: Option<Vec<String>>,
+    /// Singular is the singular name of the resource.  It must be all lowercase  Defaults to lowercased \<kind>
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub singular: Option<String>,
+}
+
+impl CustomResourceDefinitionNames {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> CustomResourceDefinitionNamesBuilder<crate::codegen::generics::MissingKind, crate::codegen::generics::MissingPlural> {
+        CustomResourceDefinitionNamesBuilder {
+            body: Default::default(),
+            _kind: core::marker::PhantomData,
+            _plural: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> CustomResourceDefinitionNamesBuilder<crate::codegen::generics::KindExists, crate::codegen::generics::PluralExists>
+    where
+        Self: Clone,
+    {
+        CustomResourceDefinitionNamesBuilder {
+            body: self.clone(),
+            _kind: core::marker::PhantomData,
+            _plural: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<CustomResourceDefinitionNames> for CustomResourceDefinitionNamesBuilder<crate::codegen::generics::KindExists, crate::codegen::generics::PluralExists> {
+    fn into(self) -> CustomResourceDefinitionNames {
+        self.body
+    }
+}
+
+/// Builder for [`CustomResourceDefinitionNames`](./struct.CustomResourceDefinitionNames.html) object.
+#[derive(Debug, Clone)]
+pub struct CustomResourceDefinitionNamesBuilder<Kind, Plural> {
+    body: self::CustomResourceDefinitionNames,
+    _kind: core::marker::PhantomData<Kind>,
+    _plural: core::marker::PhantomData<Plural>,
+}
+
+impl<Kind, Plural> CustomResourceDefinitionNamesBuilder<Kind, Plural> {
+    /// Categories is a list of grouped resources custom resources belong to (e.g. 'all')
+    #[inline]
+    pub fn categories(mut self, value: impl Iterator<Item = impl Into<String>>) -> Self {
+        self.body.categories = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`categories`](#method.categories) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_categories(mut self, value: Option<impl Iterator<Item = impl Into<String>>>) -> Self {
+        self.body.categories = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`categories`](#method.categories), but takes the values as a `Vec`.
+    #[inline]
+    pub fn categories_vec(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.body.categories = Some(value.into());
+        self
+    }
+
+    /// Kind is the serialized kind of the resource.  It is normally CamelCase and singular.
+    #[inline]
+    pub fn kind(mut self, value: impl Into<String>) -> CustomResourceDefinitionNamesBuilder<crate::codegen::generics::KindExists, Plural> {
+        self.body.kind = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// ListKind is the serialized kind of the list for this resource.  Defaults to \<kind>List.
+    #[inline]
+    pub fn list_kind(mut self, value: impl Into<String>) -> Self {
+        self.body.list_kind = Some(value.into());
+        self
+    }
+
+    /// Sets [`list_kind`](#method.list_kind) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_list_kind(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.list_kind = value.map(|value| value.into());
+        self
+    }
+
+    /// Plural is the plural name of the resource to serve.  It must match the name of the CustomResourceDefinition-registration too: plural.group and it must be all lowercase.
+    #[inline]
+    pub fn plural(mut self, value: impl Into<String>) -> CustomResourceDefinitionNamesBuilder<Kind, crate::codegen::generics::PluralExists> {
+        self.body.plural = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// ShortNames are short names for the resource.  It must be all lowercase.
+    #[inline]
+    pub fn short_names(mut self, value: impl Iterator<Item = impl Into<String>>) -> Self {
+        self.body.short_names = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`short_names`](#method.short_names) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_short_names(mut self, value: Option<impl Iterator<Item = impl Into<String>>>) -> Self {
+        self.body.short_names = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`short_names`](#method.short_names), but takes the values as a `Vec`.
+    #[inline]
+    pub fn short_names_vec(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.body.short_names = Some(value.into());
+        self
+    }
+
+    /// Singular is the singular name of the resource.  It must be all lowercase  Defaults to lowercased \<kind>
+    #[inline]
+    pub fn singular(mut self, value: impl Into<String>) -> Self {
+        self.body.singular = Some(value.into());
+        self
+    }
+
+    /// Sets [`singular`](#method.singular) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_singular(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.singular = value.map(|value| value.into());
+        self
+    }
+}
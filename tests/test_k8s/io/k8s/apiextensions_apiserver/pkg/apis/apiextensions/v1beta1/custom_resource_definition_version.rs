@@ -0,0 +1,146 @@
+
+/// CustomResourceDefinitionVersion describes a version for CRD.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct CustomResourceDefinitionVersion<Any> {
+    /// AdditionalPrinterColumns are additional columns shown e.g. in kubectl next to the name. Defaults to a created-at column. Top-level and per-version columns are mutually exclusive. Per-version columns must not all be set to identical values (top-level columns should be used instead) This field is alpha-level and is only honored by servers that enable the CustomResourceWebhookConversion feature. NOTE: CRDs created prior to 1.13 populated the top-level additionalPrinterColumns field by default. To apply an update that changes to per-version additionalPrinterColumns, the top-level additionalPrinterColumns field must be explicitly set to null
+    #[serde(rename = "additionalPrinterColumns", skip_serializing_if = "Option::is_none")]
+    pub additional_printer_columns: Option<Vec<crate::codegen::io::k8s::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::custom_resource_column_definition::CustomResourceColumnDefinition>>,
+    /// Name is the version name, e.g. “v1”, “v2beta1”, etc.
+    pub name: String,
+    /// Schema describes the schema for CustomResource used in validation, pruning, and defaulting. Top-level and per-version schemas are mutually exclusive. Per-version schemas must not all be set to identical values (top-level validation schema should be used instead) This field is alpha-level and is only honored by servers that enable the CustomResourceWebhookConversion feature.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schema: Option<crate::codegen::io::k8s::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::custom_resource_validation::CustomResourceValidation<Any>>,
+    /// Served is a flag enabling/disabling this version from being served via REST APIs
+    pub served: bool,
+    /// Storage flags the version as storage version. There must be exactly one flagged as storage version.
+    pub storage: bool,
+    /// Subresources describes the subresources for CustomResource Top-level and per-version subresources are mutually exclusive. Per-version subresources must not all be set to identical values (top-level subresources should be used instead) This field is alpha-level and is only honored by servers that enable the CustomResourceWebhookConversion feature.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subresources: Option<crate::codegen::io::k8s::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::custom_resource_subresources::CustomResourceSubresources>,
+}
+
+impl<Any: Default> CustomResourceDefinitionVersion<Any> {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> CustomResourceDefinitionVersionBuilder<crate::codegen::generics::MissingName, crate::codegen::generics::MissingServed, crate::codegen::generics::MissingStorage, Any> {
+        CustomResourceDefinitionVersionBuilder {
+            body: Default::default(),
+            _name: core::marker::PhantomData,
+            _served: core::marker::PhantomData,
+            _storage: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> CustomResourceDefinitionVersionBuilder<crate::codegen::generics::NameExists, crate::codegen::generics::ServedExists, crate::codegen::generics::StorageExists, Any>
+    where
+        Self: Clone,
+    {
+        CustomResourceDefinitionVersionBuilder {
+            body: self.clone(),
+            _name: core::marker::PhantomData,
+            _served: core::marker::PhantomData,
+            _storage: core::marker::PhantomData,
+        }
+    }
+}
+
+impl<Any> Into<CustomResourceDefinitionVersion<Any>> for CustomResourceDefinitionVersionBuilder<crate::codegen::generics::NameExists, crate::codegen::generics::ServedExists, crate::codegen::generics::StorageExists, Any> {
+    fn into(self) -> CustomResourceDefinitionVersion<Any> {
+        self.body
+    }
+}
+
+/// Builder for [`CustomResourceDefinitionVersion`](./struct.CustomResourceDefinitionVersion.html) object.
+#[derive(Debug, Clone)]
+pub struct CustomResourceDefinitionVersionBuilder<Name, Served, Storage, Any> {
+    body: self::CustomResourceDefinitionVersion<Any>,
+    _name: core::marker::PhantomData<Name>,
+    _served: core::marker::PhantomData<Served>,
+    _storage: core::marker::PhantomData<Storage>,
+}
+
+impl<Name, Served, Storage, Any> CustomResourceDefinitionVersionBuilder<Name, Served, Storage, Any> {
+    /// AdditionalPrinterColumns are additional columns shown e.g. in kubectl next to the name. Defaults to a created-at column. Top-level and per-version columns are mutually exclusive. Per-version columns must not all be set to identical values (top-level columns should be used instead) This field is alpha-level and is only honored by servers that enable the CustomResourceWebhookConversion feature. NOTE: CRDs created prior to 1.13 populated the top-level additionalPrinterColumns field by default. To apply an update that changes to per-version additionalPrinterColumns, the top-level additionalPrinterColumns field must be explicitly set to null
+    #[inline]
+    pub fn additional_printer_columns(mut self, value: impl Iterator<Item = crate::codegen::io::k8s::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::custom_resource_column_definition::CustomResourceColumnDefinitionBuilder<crate::codegen::generics::JsonPathExists, crate::codegen::generics::NameExists, crate::codegen::generics::TypeExists>>) -> Self {
+        self.body.additional_printer_columns = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`additional_printer_columns`](#method.additional_printer_columns) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_additional_printer_columns(mut self, value: Option<impl Iterator<Item = crate::codegen::io::k8s::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::custom_resource_column_definition::CustomResourceColumnDefinitionBuilder<crate::codegen::generics::JsonPathExists, crate::codegen::generics::NameExists, crate::codegen::generics::TypeExists>>>) -> Self {
+        self.body.additional_printer_columns = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`additional_printer_columns`](#method.additional_printer_columns), but takes the values as a `Vec`.
+    #[inline]
+    pub fn additional_printer_columns_vec(mut self, value: impl Into<Vec<crate::codegen::io::k8s::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::custom_resource_column_definition::CustomResourceColumnDefinition>>) -> Self {
+        self.body.additional_printer_columns = Some(value.into());
+        self
+    }
+
+    /// Name is the version name, e.g. “v1”, “v2beta1”, etc.
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> CustomResourceDefinitionVersionBuilder<crate::codegen::generics::NameExists, Served, Storage, Any> {
+        self.body.name = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Schema describes the schema for CustomResource used in validation, pruning, and defaulting. Top-level and per-version schemas are mutually exclusive. Per-version schemas must not all be set to identical values (top-level validation schema should be used instead) This field is alpha-level and is only honored by servers that enable the CustomResourceWebhookConversion feature.
+    #[inline]
+    pub fn schema(mut self, value: crate::codegen::io::k8s::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::custom_resource_validation::CustomResourceValidation<Any>) -> Self {
+        self.body.schema = Some(value.into());
+        self
+    }
+
+    /// Sets [`schema`](#method.schema) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_schema(mut self, value: Option<crate::codegen::io::k8s::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::custom_resource_validation::CustomResourceValidation<Any>>) -> Self {
+        self.body.schema = value.map(|value| value.into());
+        self
+    }
+
+    /// Served is a flag enabling/disabling this version from being served via REST APIs
+    #[inline]
+    pub fn served(mut self, value: impl Into<bool>) -> CustomResourceDefinitionVersionBuilder<Name, crate::codegen::generics::ServedExists, Storage, Any> {
+        self.body.served = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Same as [`served`](#method.served), but takes a reference.
+    #[inline]
+    pub fn served_ref(self, value: &bool) -> CustomResourceDefinitionVersionBuilder<Name, crate::codegen::generics::ServedExists, Storage, Any> {
+        self.served(*value)
+    }
+
+    /// Storage flags the version as storage version. There must be exactly one flagged as storage version.
+    #[inline]
+    pub fn storage(mut self, value: impl Into<bool>) -> CustomResourceDefinitionVersionBuilder<Name, Served, crate::codegen::generics::StorageExists, Any> {
+        self.body.storage = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Same as [`storage`](#method.storage), but takes a reference.
+    #[inline]
+    pub fn storage_ref(self, value: &bool) -> CustomResourceDefinitionVersionBuilder<Name, Served, crate::codegen::generics::StorageExists, Any> {
+        self.storage(*value)
+    }
+
+    /// Subresources describes the subresources for CustomResource Top-level and per-version subresources are mutually exclusive. Per-version subresources must not all be set to identical values (top-level subresources should be used instead) This field is alpha-level and is only honored by servers that enable the CustomResourceWebhookConversion feature.
+    #[inline]
+    pub fn subresources(mut self, value: crate::codegen::io::k8s::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::custom_resource_subresources::CustomResourceSubresources) -> Self {
+        self.body.subresources = Some(value.into());
+        self
+    }
+
+    /// Sets [`subresources`](#method.subresources) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_subresources(mut self, value: Option<crate::codegen::io::k8s::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::custom_resource_subresources::CustomResourceSubresources>) -> Self {
+        self.body.subresources = value.map(|value| value.into());
+        self
+    }
+}
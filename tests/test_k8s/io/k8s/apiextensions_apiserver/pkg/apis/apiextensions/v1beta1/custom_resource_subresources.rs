@@ -0,0 +1,74 @@
+
+/// CustomResourceSubresources defines the status and scale subresources for CustomResources.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct CustomResourceSubresources {
+    /// Scale denotes the scale subresource for CustomResources
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scale: Option<crate::codegen::io::k8s::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::custom_resource_subresource_scale::CustomResourceSubresourceScale>,
+    /// Status denotes the status subresource for CustomResources
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<crate::codegen::io::k8s::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::custom_resource_subresource_status::CustomResourceSubresourceStatus>,
+}
+
+impl CustomResourceSubresources {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> CustomResourceSubresourcesBuilder {
+        CustomResourceSubresourcesBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> CustomResourceSubresourcesBuilder
+    where
+        Self: Clone,
+    {
+        CustomResourceSubresourcesBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<CustomResourceSubresources> for CustomResourceSubresourcesBuilder {
+    fn into(self) -> CustomResourceSubresources {
+        self.body
+    }
+}
+
+/// Builder for [`CustomResourceSubresources`](./struct.CustomResourceSubresources.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct CustomResourceSubresourcesBuilder {
+    body: self::CustomResourceSubresources,
+}
+
+impl CustomResourceSubresourcesBuilder {
+    /// Scale denotes the scale subresource for CustomResources
+    #[inline]
+    pub fn scale(mut self, value: crate::codegen::io::k8s::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::custom_resource_subresource_scale::CustomResourceSubresourceScaleBuilder<crate::codegen::generics::SpecReplicasPathExists, crate::codegen::generics::StatusReplicasPathExists>) -> Self {
+        self.body.scale = Some(value.into());
+        self
+    }
+
+    /// Sets [`scale`](#method.scale) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_scale(mut self, value: Option<crate::codegen::io::k8s::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::custom_resource_subresource_scale::CustomResourceSubresourceScaleBuilder<crate::codegen::generics::SpecReplicasPathExists, crate::codegen::generics::StatusReplicasPathExists>>) -> Self {
+        self.body.scale = value.map(|value| value.into());
+        self
+    }
+
+    /// Status denotes the status subresource for CustomResources
+    #[inline]
+    pub fn status(mut self, value: crate::codegen::io::k8s::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::custom_resource_subresource_status::CustomResourceSubresourceStatus) -> Self {
+        self.body.status = Some(value.into());
+        self
+    }
+
+    /// Sets [`status`](#method.status) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_status(mut self, value: Option<crate::codegen::io::k8s::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::custom_resource_subresource_status::CustomResourceSubresourceStatus>) -> Self {
+        self.body.status = value.map(|value| value.into());
+        self
+    }
+}
@@ -0,0 +1,57 @@
+
+/// CustomResourceValidation is a list of validation methods for CustomResources.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct CustomResourceValidation<Any> {
+    /// OpenAPIV3Schema is the OpenAPI v3 schema to be validated against.
+    #[serde(rename = "openAPIV3Schema", skip_serializing_if = "Option::is_none")]
+    pub open_apiv3_schema: Option<Box<crate::codegen::io::k8s::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::json_schema_props::JsonSchemaProps<Any>>>,
+}
+
+impl<Any: Default> CustomResourceValidation<Any> {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> CustomResourceValidationBuilder<Any> {
+        CustomResourceValidationBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> CustomResourceValidationBuilder<Any>
+    where
+        Self: Clone,
+    {
+        CustomResourceValidationBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl<Any> Into<CustomResourceValidation<Any>> for CustomResourceValidationBuilder<Any> {
+    fn into(self) -> CustomResourceValidation<Any> {
+        self.body
+    }
+}
+
+/// Builder for [`CustomResourceValidation`](./struct.CustomResourceValidation.html) object.
+#[derive(Debug, Clone)]
+pub struct CustomResourceValidationBuilder<Any> {
+    body: self::CustomResourceValidation<Any>,
+}
+
+impl<Any> CustomResourceValidationBuilder<Any> {
+    /// OpenAPIV3Schema is the OpenAPI v3 schema to be validated against.
+    #[inline]
+    pub fn open_apiv3_schema(mut self, value: crate::codegen::io::k8s::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::json_schema_props::JsonSchemaProps<Any>) -> Self {
+        self.body.open_apiv3_schema = Some(value.into());
+        self
+    }
+
+    /// Sets [`open_apiv3_schema`](#method.open_apiv3_schema) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_open_apiv3_schema(mut self, value: Option<crate::codegen::io::k8s::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::json_schema_props::JsonSchemaProps<Any>>) -> Self {
+        self.body.open_apiv3_schema = value.map(|value| value.into());
+        self
+    }
+}
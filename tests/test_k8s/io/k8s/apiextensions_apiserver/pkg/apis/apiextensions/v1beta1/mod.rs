@@ -0,0 +1,68 @@
+
+pub mod custom_resource_column_definition {
+    include!("./custom_resource_column_definition.rs");
+}
+
+pub mod custom_resource_conversion {
+    include!("./custom_resource_conversion.rs");
+}
+
+pub mod custom_resource_definition {
+    include!("./custom_resource_definition.rs");
+}
+
+pub mod custom_resource_definition_condition {
+    include!("./custom_resource_definition_condition.rs");
+}
+
+pub mod custom_resource_definition_list {
+    include!("./custom_resource_definition_list.rs");
+}
+
+pub mod custom_resource_definition_names {
+    include!("./custom_resource_definition_names.rs");
+}
+
+pub mod custom_resource_definition_spec {
+    include!("./custom_resource_definition_spec.rs");
+}
+
+pub mod custom_resource_definition_status {
+    include!("./custom_resource_definition_status.rs");
+}
+
+pub mod custom_resource_definition_version {
+    include!("./custom_resource_definition_version.rs");
+}
+
+pub mod custom_resource_subresource_scale {
+    include!("./custom_resource_subresource_scale.rs");
+}
+
+pub mod custom_resource_subresource_status {
+    include!("./custom_resource_subresource_status.rs");
+}
+
+pub mod custom_resource_subresources {
+    include!("./custom_resource_subresources.rs");
+}
+
+pub mod custom_resource_validation {
+    include!("./custom_resource_validation.rs");
+}
+
+pub mod external_documentation {
+    include!("./external_documentation.rs");
+}
+
+pub mod json_schema_props {
+    include!("./json_schema_props.rs");
+}
+
+pub mod service_reference {
+    include!("./service_reference.rs");
+}
+
+pub mod webhook_client_config {
+    include!("./webhook_client_config.rs");
+}
@@ -0,0 +1,4 @@
+
+pub mod apiextensions {
+    include!("./apiextensions/mod.rs");
+}
@@ -0,0 +1,4 @@
+
+pub mod apis {
+    include!("./apis/mod.rs");
+}
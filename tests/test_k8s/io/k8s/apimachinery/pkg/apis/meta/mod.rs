@@ -0,0 +1,4 @@
+
+pub mod v1 {
+    include!("./v1/mod.rs");
+}
@@ -0,0 +1,213 @@
+
+/// APIResource specifies the name of a resource and whether it is namespaced.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct ApiResource {
+    /// categories is a list of the grouped resources this resource belongs to (e.g. 'all')
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub categories: Option<Vec<String>>,
+    /// group is the preferred group of the resource.  Empty implies the group of the containing resource list. For subresources, this may have a different value, for example: Scale".
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub group: Option<String>,
+    /// kind is the kind for the resource (e.g. 'Foo' is the kind for a resource 'foo')
+    pub kind: String,
+    /// name is the plural name of the resource.
+    pub name: String,
+    /// namespaced indicates if a resource is namespaced or not.
+    pub namespaced: bool,
+    /// shortNames is a list of suggested short names of the resource.
+    #[serde(rename = "shortNames", skip_serializing_if = "Option::is_none")]
+    pub short_names: Option<Vec<String>>,
+    /// singularName is the singular name of the resource.  This allows clients to handle plural and singular opaquely. The singularName is more correct for reporting status on a single item and both singular and plural are allowed from the kubectl CLI interface.
+    #[serde(rename = "singularName")]
+    pub singular_name: String,
+    /// The hash value of the storage version, the version this resource is converted to when written to the data store. Value must be treated as opaque by clients. Only equality comparison on the value is valid. This is an alpha feature and may change or be removed in the future. The field is populated by the apiserver only if the StorageVersionHash feature gate is enabled. This field will remain optional even if it graduates.
+    #[serde(rename = "storageVersionHash", skip_serializing_if = "Option::is_none")]
+    pub storage_version_hash: Option<String>,
+    /// verbs is a list of supported kube verbs (this includes get, list, watch, create, update, patch, delete, deletecollection, and proxy)
+    pub verbs: Vec<String>,
+    /// version is the preferred version of the resource.  Empty implies the version of the containing resource list For subresources, this may have a different value, for example: v1 (while inside a v1beta1 version of the core resource's group)".
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+}
+
+impl ApiResource {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> ApiResourceBuilder<crate::codegen::generics::MissingKind, crate::codegen::generics::MissingName, crate::codegen::generics::MissingNamespaced, crate::codegen::generics::MissingSingularName, crate::codegen::generics::MissingVerbs> {
+        ApiResourceBuilder {
+            body: Default::default(),
+            _kind: core::marker::PhantomData,
+            _name: core::marker::PhantomData,
+            _namespaced: core::marker::PhantomData,
+            _singular_name: core::marker::PhantomData,
+            _verbs: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> ApiResourceBuilder<crate::codegen::generics::KindExists, crate::codegen::generics::NameExists, crate::codegen::generics::NamespacedExists, crate::codegen::generics::SingularNameExists, crate::codegen::generics::VerbsExists>
+    where
+        Self: Clone,
+    {
+        ApiResourceBuilder {
+            body: self.clone(),
+            _kind: core::marker::PhantomData,
+            _name: core::marker::PhantomData,
+            _namespaced: core::marker::PhantomData,
+            _singular_name: core::marker::PhantomData,
+            _verbs: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<ApiResource> for ApiResourceBuilder<crate::codegen::generics::KindExists, crate::codegen::generics::NameExists, crate::codegen::generics::NamespacedExists, crate::codegen::generics::SingularNameExists, crate::codegen::generics::VerbsExists> {
+    fn into(self) -> ApiResource {
+        self.body
+    }
+}
+
+/// Builder for [`ApiResource`](./struct.ApiResource.html) object.
+#[derive(Debug, Clone)]
+pub struct ApiResourceBuilder<Kind, Name, Namespaced, SingularName, Verbs> {
+    body: self::ApiResource,
+    _kind: core::marker::PhantomData<Kind>,
+    _name: core::marker::PhantomData<Name>,
+    _namespaced: core::marker::PhantomData<Namespaced>,
+    _singular_name: core::marker::PhantomData<SingularName>,
+    _verbs: core::marker::PhantomData<Verbs>,
+}
+
+impl<Kind, Name, Namespaced, SingularName, Verbs> ApiResourceBuilder<Kind, Name, Namespaced, SingularName, Verbs> {
+    /// categories is a list of the grouped resources this resource belongs to (e.g. 'all')
+    #[inline]
+    pub fn categories(mut self, value: impl Iterator<Item = impl Into<String>>) -> Self {
+        self.body.categories = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`categories`](#method.categories) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_categories(mut self, value: Option<impl Iterator<Item = impl Into<String>>>) -> Self {
+        self.body.categories = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`categories`](#method.categories), but takes the values as a `Vec`.
+    #[inline]
+    pub fn categories_vec(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.body.categories = Some(value.into());
+        self
+    }
+
+    /// group is the preferred group of the resource.  Empty implies the group of the containing resource list. For subresources, this may have a different value, for example: Scale".
+    #[inline]
+    pub fn group(mut self, value: impl Into<String>) -> Self {
+        self.body.group = Some(value.into());
+        self
+    }
+
+    /// Sets [`group`](#method.group) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_group(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.group = value.map(|value| value.into());
+        self
+    }
+
+    /// kind is the kind for the resource (e.g. 'Foo' is the kind for a resource 'foo')
+    #[inline]
+    pub fn kind(mut self, value: impl Into<String>) -> ApiResourceBuilder<crate::codegen::generics::KindExists, Name, Namespaced, SingularName, Verbs> {
+        self.body.kind = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// name is the plural name of the resource.
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> ApiResourceBuilder<Kind, crate::codegen::generics::NameExists, Namespaced, SingularName, Verbs> {
+        self.body.name = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// namespaced indicates if a resource is namespaced or not.
+    #[inline]
+    pub fn namespaced(mut self, value: impl Into<bool>) -> ApiResourceBuilder<Kind, Name, crate::codegen::generics::NamespacedExists, SingularName, Verbs> {
+        self.body.namespaced = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Same as [`namespaced`](#method.namespaced), but takes a reference.
+    #[inline]
+    pub fn namespaced_ref(self, value: &bool) -> ApiResourceBuilder<Kind, Name, crate::codegen::generics::NamespacedExists, SingularName, Verbs> {
+        self.namespaced(*value)
+    }
+
+    /// shortNames is a list of suggested short names of the resource.
+    #[inline]
+    pub fn short_names(mut self, value: impl Iterator<Item = impl Into<String>>) -> Self {
+        self.body.short_names = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`short_names`](#method.short_names) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_short_names(mut self, value: Option<impl Iterator<Item = impl Into<String>>>) -> Self {
+        self.body.short_names = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`short_names`](#method.short_names), but takes the values as a `Vec`.
+    #[inline]
+    pub fn short_names_vec(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.body.short_names = Some(value.into());
+        self
+    }
+
+    /// singularName is the singular name of the resource.  This allows clients to handle plural and singular opaquely. The singularName is more correct for reporting status on a single item and both singular and plural are allowed from the kubectl CLI interface.
+    #[inline]
+    pub fn singular_name(mut self, value: impl Into<String>) -> ApiResourceBuilder<Kind, Name, Namespaced, crate::codegen::generics::SingularNameExists, Verbs> {
+        self.body.singular_name = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// The hash value of the storage version, the version this resource is converted to when written to the data store. Value must be treated as opaque by clients. Only equality comparison on the value is valid. This is an alpha feature and may change or be removed in the future. The field is populated by the apiserver only if the StorageVersionHash feature gate is enabled. This field will remain optional even if it graduates.
+    #[inline]
+    pub fn storage_version_hash(mut self, value: impl Into<String>) -> Self {
+        self.body.storage_version_hash = Some(value.into());
+        self
+    }
+
+    /// Sets [`storage_version_hash`](#method.storage_version_hash) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_storage_version_hash(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.storage_version_hash = value.map(|value| value.into());
+        self
+    }
+
+    /// verbs is a list of supported kube verbs (this includes get, list, watch, create, update, patch, delete, deletecollection, and proxy)
+    #[inline]
+    pub fn verbs(mut self, value: impl Iterator<Item = impl Into<String>>) -> ApiResourceBuilder<Kind, Name, Namespaced, SingularName, crate::codegen::generics::VerbsExists> {
+        self.body.verbs = value.map(|value| value.into()).collect::<Vec<_>>().into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Same as [`verbs`](#method.verbs), but takes the values as a `Vec`.
+    #[inline]
+    pub fn verbs_vec(mut self, value: impl Into<Vec<String>>) -> ApiResourceBuilder<Kind, Name, Namespaced, SingularName, crate::codegen::generics::VerbsExists> {
+        self.body.verbs = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// version is the preferred version of the resource.  Empty implies the version of the containing resource list For subresources, this may have a different value, for example: v1 (while inside a v1beta1 version of the core resource's group)".
+    #[inline]
+    pub fn version(mut self, value: impl Into<String>) -> Self {
+        self.body.version = Some(value.into());
+        self
+    }
+
+    /// Sets [`version`](#method.version) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_version(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.version = value.map(|value| value.into());
+        self
+    }
+}
@@ -0,0 +1,5 @@
+
+/// Fields stores a set of fields in a data structure like a Trie. To understand how this is used, see: https://github.com/kubernetes-sigs/structured-merge-diff
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct Fields {}
+
@@ -0,0 +1,76 @@
+
+/// Initializers tracks the progress of initialization.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct Initializers {
+    /// Pending is a list of initializers that must execute in order before this object is visible. When the last pending initializer is removed, and no failing result is set, the initializers struct will be set to nil and the object is considered as initialized and visible to all clients.
+    pub pending: Vec<crate::codegen::io::k8s::apimachinery::pkg::apis::meta::v1::initializer::Initializer>,
+    /// If result is set with the Failure field, the object will be persisted to storage and then deleted, ensuring that other clients can observe the deletion.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result: Option<crate::codegen::io::k8s::apimachinery::pkg::apis::meta::v1::status::Status>,
+}
+
+impl Initializers {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> InitializersBuilder<crate::codegen::generics::MissingPending> {
+        InitializersBuilder {
+            body: Default::default(),
+            _pending: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> InitializersBuilder<crate::codegen::generics::PendingExists>
+    where
+        Self: Clone,
+    {
+        InitializersBuilder {
+            body: self.clone(),
+            _pending: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<Initializers> for InitializersBuilder<crate::codegen::generics::PendingExists> {
+    fn into(self) -> Initializers {
+        self.body
+    }
+}
+
+/// Builder for [`Initializers`](./struct.Initializers.html) object.
+#[derive(Debug, Clone)]
+pub struct InitializersBuilder<Pending> {
+    body: self::Initializers,
+    _pending: core::marker::PhantomData<Pending>,
+}
+
+impl<Pending> InitializersBuilder<Pending> {
+    /// Pending is a list of initializers that must execute in order before this object is visible. When the last pending initializer is removed, and no failing result is set, the initializers struct will be set to nil and the object is considered as initialized and visible to all clients.
+    #[inline]
+    pub fn pending(mut self, value: impl Iterator<Item = crate::codegen::io::k8s::apimachinery::pkg::apis::meta::v1::initializer::InitializerBuilder<crate::codegen::generics::NameExists>>) -> InitializersBuilder<crate::codegen::generics::PendingExists> {
+        self.body.pending = value.map(|value| value.into()).collect::<Vec<_>>().into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Same as [`pending`](#method.pending), but takes the values as a `Vec`.
+    #[inline]
+    pub fn pending_vec(mut self, value: impl Into<Vec<crate::codegen::io::k8s::apimachinery::pkg::apis::meta::v1::initializer::Initializer>>) -> InitializersBuilder<crate::codegen::generics::PendingExists> {
+        self.body.pending = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// If result is set with the Failure field, the object will be persisted to storage and then deleted, ensuring that other clients can observe the deletion.
+    #[inline]
+    pub fn result(mut self, value: crate::codegen::io::k8s::apimachinery::pkg::apis::meta::v1::status::Status) -> Self {
+        self.body.result = Some(value.into());
+        self
+    }
+
+    /// Sets [`result`](#method.result) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_result(mut self, value: Option<crate::codegen::io::k8s::apimachinery::pkg::apis::meta::v1::status::Status>) -> Self {
+        self.body.result = value.map(|value| value.into());
+        self
+    }
+}
@@ -0,0 +1,81 @@
+
+/// A label selector is a label query over a set of resources. The result of matchLabels and matchExpressions are ANDed. An empty label selector matches all objects. A null label selector matches no objects.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct LabelSelector {
+    /// matchExpressions is a list of label selector requirements. The requirements are ANDed.
+    #[serde(rename = "matchExpressions", skip_serializing_if = "Option::is_none")]
+    pub match_expressions: Option<Vec<crate::codegen::io::k8s::apimachinery::pkg::apis::meta::v1::label_selector_requirement::LabelSelectorRequirement>>,
+    /// matchLabels is a map of {key,value} pairs. A single {key,value} in the matchLabels map is equivalent to an element of matchExpressions, whose key field is "key", the operator is "In", and the values array contains only "value". The requirements are ANDed.
+    #[serde(rename = "matchLabels", skip_serializing_if = "Option::is_none")]
+    pub match_labels: Option<std::collections::BTreeMap<String, String>>,
+}
+
+impl LabelSelector {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> LabelSelectorBuilder {
+        LabelSelectorBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> LabelSelectorBuilder
+    where
+        Self: Clone,
+    {
+        LabelSelectorBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<LabelSelector> for LabelSelectorBuilder {
+    fn into(self) -> LabelSelector {
+        self.body
+    }
+}
+
+/// Builder for [`LabelSelector`](./struct.LabelSelector.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct LabelSelectorBuilder {
+    body: self::LabelSelector,
+}
+
+impl LabelSelectorBuilder {
+    /// matchExpressions is a list of label selector requirements. The requirements are ANDed.
+    #[inline]
+    pub fn match_expressions(mut self, value: impl Iterator<Item = crate::codegen::io::k8s::apimachinery::pkg::apis::meta::v1::label_selector_requirement::LabelSelectorRequirementBuilder<crate::codegen::generics::KeyExists, crate::codegen::generics::OperatorExists>>) -> Self {
+        self.body.match_expressions = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`match_expressions`](#method.match_expressions) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_match_expressions(mut self, value: Option<impl Iterator<Item = crate::codegen::io::k8s::apimachinery::pkg::apis::meta::v1::label_selector_requirement::LabelSelectorRequirementBuilder<crate::codegen::generics::KeyExists, crate::codegen::generics::OperatorExists>>>) -> Self {
+        self.body.match_expressions = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`match_expressions`](#method.match_expressions), but takes the values as a `Vec`.
+    #[inline]
+    pub fn match_expressions_vec(mut self, value: impl Into<Vec<crate::codegen::io::k8s::apimachinery::pkg::apis::meta::v1::label_selector_requirement::LabelSelectorRequirement>>) -> Self {
+        self.body.match_expressions = Some(value.into());
+        self
+    }
+
+    /// matchLabels is a map of {key,value} pairs. A single {key,value} in the matchLabels map is equivalent to an element of matchExpressions, whose key field is "key", the operator is "In", and the values array contains only "value". The requirements are ANDed.
+    #[inline]
+    pub fn match_labels(mut self, value: impl Iterator<Item = (String, impl Into<String>)>) -> Self {
+        self.body.match_labels = Some(value.map(|(key, value)| (key, value.into())).collect::<std::collections::BTreeMap<_, _>>().into());
+        self
+    }
+
+    /// Sets [`match_labels`](#method.match_labels) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_match_labels(mut self, value: Option<impl Iterator<Item = (String, impl Into<String>)>>) -> Self {
+        self.body.match_labels = value.map(|value| value.map(|(key, value)| (key, value.into())).collect::<std::collections::BTreeMap<_, _>>().into());
+        self
+    }
+}
@@ -0,0 +1,88 @@
+
+/// A label selector requirement is a selector that contains values, a key, and an operator that relates the key and values.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct LabelSelectorRequirement {
+    /// key is the label key that the selector applies to.
+    pub key: String,
+    /// operator represents a key's relationship to a set of values. Valid operators are In, NotIn, Exists and DoesNotExist.
+    pub operator: String,
+    /// values is an array of string values. If the operator is In or NotIn, the values array must be non-empty. If the operator is Exists or DoesNotExist, the values array must be empty. This array is replaced during a strategic merge patch.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub values: Option<Vec<String>>,
+}
+
+impl LabelSelectorRequirement {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> LabelSelectorRequirementBuilder<crate::codegen::generics::MissingKey, crate::codegen::generics::MissingOperator> {
+        LabelSelectorRequirementBuilder {
+            body: Default::default(),
+            _key: core::marker::PhantomData,
+            _operator: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> LabelSelectorRequirementBuilder<crate::codegen::generics::KeyExists, crate::codegen::generics::OperatorExists>
+    where
+        Self: Clone,
+    {
+        LabelSelectorRequirementBuilder {
+            body: self.clone(),
+            _key: core::marker::PhantomData,
+            _operator: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<LabelSelectorRequirement> for LabelSelectorRequirementBuilder<crate::codegen::generics::KeyExists, crate::codegen::generics::OperatorExists> {
+    fn into(self) -> LabelSelectorRequirement {
+        self.body
+    }
+}
+
+/// Builder for [`LabelSelectorRequirement`](./struct.LabelSelectorRequirement.html) object.
+#[derive(Debug, Clone)]
+pub struct LabelSelectorRequirementBuilder<Key, Operator> {
+    body: self::LabelSelectorRequirement,
+    _key: core::marker::PhantomData<Key>,
+    _operator: core::marker::PhantomData<Operator>,
+}
+
+impl<Key, Operator> LabelSelectorRequirementBuilder<Key, Operator> {
+    /// key is the label key that the selector applies to.
+    #[inline]
+    pub fn key(mut self, value: impl Into<String>) -> LabelSelectorRequirementBuilder<crate::codegen::generics::KeyExists, Operator> {
+        self.body.key = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// operator represents a key's relationship to a set of values. Valid operators are In, NotIn, Exists and DoesNotExist.
+    #[inline]
+    pub fn operator(mut self, value: impl Into<String>) -> LabelSelectorRequirementBuilder<Key, crate::codegen::generics::OperatorExists> {
+        self.body.operator = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// values is an array of string values. If the operator is In or NotIn, the values array must be non-empty. If the operator is Exists or DoesNotExist, the values array must be empty. This array is replaced during a strategic merge patch.
+    #[inline]
+    pub fn values(mut self, value: impl Iterator<Item = impl Into<String>>) -> Self {
+        self.body.values = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`values`](#method.values) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_values(mut self, value: Option<impl Iterator<Item = impl Into<String>>>) -> Self {
+        self.body.values = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`values`](#method.values), but takes the values as a `Vec`.
+    #[inline]
+    pub fn values_vec(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.body.values = Some(value.into());
+        self
+    }
+}
@@ -0,0 +1,114 @@
+
+/// ListMeta describes metadata that synthetic resources must have, including lists and various status objects. A resource may have only one of {ObjectMeta, ListMeta}.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct ListMeta {
+    /// continue may be set if the user set a limit on the number of items returned, and indicates that the server has more data available. The value is opaque and may be used to issue another request to the endpoint that served this list to retrieve the next set of available objects. Continuing a consistent list may not be possible if the server configuration has changed or more than a few minutes have passed. The resourceVersion field returned when using this continue value will be identical to the value in the first response, unless you have received this token from an error message.
+    #[serde(rename = "continue", skip_serializing_if = "Option::is_none")]
+    pub continue_: Option<String>,
+    /// RemainingItemCount is the number of subsequent items in the list which are not included in this list response. If the list request contained label or field selectors, then the number of remaining items is unknown and this field will be unset. If the list is complete (either because it is unpaginated or because this is the last page), then there are no more remaining items and this field will also be unset.  Servers older than v1.15 do not set this field.
+    #[serde(rename = "remainingItemCount", skip_serializing_if = "Option::is_none")]
+    pub remaining_item_count: Option<i64>,
+    /// String that identifies the server's internal version of this object that can be used by clients to determine when objects have changed. Value must be treated as opaque by clients and passed unmodified back to the server. Populated by the system. Read-only. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#concurrency-control-and-consistency
+    #[serde(rename = "resourceVersion", skip_serializing_if = "Option::is_none")]
+    pub resource_version: Option<String>,
+    /// selfLink is a URL representing this object. Populated by the system. Read-only.
+    #[serde(rename = "selfLink", skip_serializing_if = "Option::is_none")]
+    pub self_link: Option<String>,
+}
+
+impl ListMeta {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> ListMetaBuilder {
+        ListMetaBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> ListMetaBuilder
+    where
+        Self: Clone,
+    {
+        ListMetaBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<ListMeta> for ListMetaBuilder {
+    fn into(self) -> ListMeta {
+        self.body
+    }
+}
+
+/// Builder for [`ListMeta`](./struct.ListMeta.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct ListMetaBuilder {
+    body: self::ListMeta,
+}
+
+impl ListMetaBuilder {
+    /// continue may be set if the user set a limit on the number of items returned, and indicates that the server has more data available. The value is opaque and may be used to issue another request to the endpoint that served this list to retrieve the next set of available objects. Continuing a consistent list may not be possible if the server configuration has changed or more than a few minutes have passed. The resourceVersion field returned when using this continue value will be identical to the value in the first response, unless you have received this token from an error message.
+    #[inline]
+    pub fn continue_(mut self, value: impl Into<String>) -> Self {
+        self.body.continue_ = Some(value.into());
+        self
+    }
+
+    /// Sets [`continue_`](#method.continue_) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_continue(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.continue_ = value.map(|value| value.into());
+        self
+    }
+
+    /// RemainingItemCount is the number of subsequent items in the list which are not included in this list response. If the list request contained label or field selectors, then the number of remaining items is unknown and this field will be unset. If the list is complete (either because it is unpaginated or because this is the last page), then there are no more remaining items and this field will also be unset.  Servers older than v1.15 do not set this field.
+    #[inline]
+    pub fn remaining_item_count(mut self, value: impl Into<i64>) -> Self {
+        self.body.remaining_item_count = Some(value.into());
+        self
+    }
+
+    /// Sets [`remaining_item_count`](#method.remaining_item_count) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_remaining_item_count(mut self, value: Option<impl Into<i64>>) -> Self {
+        self.body.remaining_item_count = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`remaining_item_count`](#method.remaining_item_count), but takes a reference.
+    #[inline]
+    pub fn remaining_item_count_ref(self, value: &i64) -> Self {
+        self.remaining_item_count(*value)
+    }
+
+    /// String that identifies the server's internal version of this object that can be used by clients to determine when objects have changed. Value must be treated as opaque by clients and passed unmodified back to the server. Populated by the system. Read-only. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#concurrency-control-and-consistency
+    #[inline]
+    pub fn resource_version(mut self, value: impl Into<String>) -> Self {
+        self.body.resource_version = Some(value.into());
+        self
+    }
+
+    /// Sets [`resource_version`](#method.resource_version) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_resource_version(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.resource_version = value.map(|value| value.into());
+        self
+    }
+
+    /// selfLink is a URL representing this object. Populated by the system. Read-only.
+    #[inline]
+    pub fn self_link(mut self, value: impl Into<String>) -> Self {
+        self.body.self_link = Some(value.into());
+        self
+    }
+
+    /// Sets [`self_link`](#method.self_link) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_self_link(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.self_link = value.map(|value| value.into());
+        self
+    }
+}
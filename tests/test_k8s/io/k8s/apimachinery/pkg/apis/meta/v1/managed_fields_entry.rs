@@ -0,0 +1,125 @@
+
+/// ManagedFieldsEntry is a workflow-id, a FieldSet and the group version of the resource that the fieldset applies to.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct ManagedFieldsEntry {
+    /// APIVersion defines the version of this resource that this field set applies to. The format is "group/version" just like the top-level APIVersion field. It is necessary to track the version of a field set because it cannot be automatically converted.
+    #[serde(rename = "apiVersion", skip_serializing_if = "Option::is_none")]
+    pub api_version: Option<String>,
+    /// Fields identifies a set of fields.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fields: Option<crate::codegen::io::k8s::apimachinery::pkg::apis::meta::v1::fields::Fields>,
+    /// Manager is an identifier of the workflow managing these fields.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub manager: Option<String>,
+    /// Operation is the type of operation which lead to this ManagedFieldsEntry being created. The only valid values for this field are 'Apply' and 'Update'.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub operation: Option<String>,
+    /// Time is timestamp of when these fields were set. It should always be empty if Operation is 'Apply'
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time: Option<String>,
+}
+
+impl ManagedFieldsEntry {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> ManagedFieldsEntryBuilder {
+        ManagedFieldsEntryBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> ManagedFieldsEntryBuilder
+    where
+        Self: Clone,
+    {
+        ManagedFieldsEntryBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<ManagedFieldsEntry> for ManagedFieldsEntryBuilder {
+    fn into(self) -> ManagedFieldsEntry {
+        self.body
+    }
+}
+
+/// Builder for [`ManagedFieldsEntry`](./struct.ManagedFieldsEntry.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct ManagedFieldsEntryBuilder {
+    body: self::ManagedFieldsEntry,
+}
+
+impl ManagedFieldsEntryBuilder {
+    /// APIVersion defines the version of this resource that this field set applies to. The format is "group/version" just like the top-level APIVersion field. It is necessary to track the version of a field set because it cannot be automatically converted.
+    #[inline]
+    pub fn api_version(mut self, value: impl Into<String>) -> Self {
+        self.body.api_version = Some(value.into());
+        self
+    }
+
+    /// Sets [`api_version`](#method.api_version) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_api_version(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.api_version = value.map(|value| value.into());
+        self
+    }
+
+    /// Fields identifies a set of fields.
+    #[inline]
+    pub fn fields(mut self, value: crate::codegen::io::k8s::apimachinery::pkg::apis::meta::v1::fields::Fields) -> Self {
+        self.body.fields = Some(value.into());
+        self
+    }
+
+    /// Sets [`fields`](#method.fields) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_fields(mut self, value: Option<crate::codegen::io::k8s::apimachinery::pkg::apis::meta::v1::fields::Fields>) -> Self {
+        self.body.fields = value.map(|value| value.into());
+        self
+    }
+
+    /// Manager is an identifier of the workflow managing these fields.
+    #[inline]
+    pub fn manager(mut self, value: impl Into<String>) -> Self {
+        self.body.manager = Some(value.into());
+        self
+    }
+
+    /// Sets [`manager`](#method.manager) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_manager(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.manager = value.map(|value| value.into());
+        self
+    }
+
+    /// Operation is the type of operation which lead to this ManagedFieldsEntry being created. The only valid values for this field are 'Apply' and 'Update'.
+    #[inline]
+    pub fn operation(mut self, value: impl Into<String>) -> Self {
+        self.body.operation = Some(value.into());
+        self
+    }
+
+    /// Sets [`operation`](#method.operation) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_operation(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.operation = value.map(|value| value.into());
+        self
+    }
+
+    /// Time is timestamp of when these fields were set. It should always be empty if Operation is 'Apply'
+    #[inline]
+    pub fn time(mut self, value: impl Into<String>) -> Self {
+        self.body.time = Some(value.into());
+        self
+    }
+
+    /// Sets [`time`](#method.time) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_time(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.time = value.map(|value| value.into());
+        self
+    }
+}
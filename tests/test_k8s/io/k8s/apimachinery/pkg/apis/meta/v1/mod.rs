@@ -0,0 +1,92 @@
+
+pub mod api_group {
+    include!("./api_group.rs");
+}
+
+pub mod api_group_list {
+    include!("./api_group_list.rs");
+}
+
+pub mod api_resource {
+    include!("./api_resource.rs");
+}
+
+pub mod api_resource_list {
+    include!("./api_resource_list.rs");
+}
+
+pub mod api_versions {
+    include!("./api_versions.rs");
+}
+
+pub mod delete_options {
+    include!("./delete_options.rs");
+}
+
+pub mod fields {
+    include!("./fields.rs");
+}
+
+pub mod group_version_for_discovery {
+    include!("./group_version_for_discovery.rs");
+}
+
+pub mod initializer {
+    include!("./initializer.rs");
+}
+
+pub mod initializers {
+    include!("./initializers.rs");
+}
+
+pub mod label_selector {
+    include!("./label_selector.rs");
+}
+
+pub mod label_selector_requirement {
+    include!("./label_selector_requirement.rs");
+}
+
+pub mod list_meta {
+    include!("./list_meta.rs");
+}
+
+pub mod managed_fields_entry {
+    include!("./managed_fields_entry.rs");
+}
+
+pub mod object_meta {
+    include!("./object_meta.rs");
+}
+
+pub mod owner_reference {
+    include!("./owner_reference.rs");
+}
+
+pub mod patch {
+    include!("./patch.rs");
+}
+
+pub mod preconditions {
+    include!("./preconditions.rs");
+}
+
+pub mod server_address_by_client_cidr {
+    include!("./server_address_by_client_cidr.rs");
+}
+
+pub mod status {
+    include!("./status.rs");
+}
+
+pub mod status_cause {
+    include!("./status_cause.rs");
+}
+
+pub mod status_details {
+    include!("./status_details.rs");
+}
+
+pub mod watch_event {
+    include!("./watch_event.rs");
+}
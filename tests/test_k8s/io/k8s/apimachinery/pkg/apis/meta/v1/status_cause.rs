@@ -0,0 +1,99 @@
+
+/// StatusCause provides more information about an api.Status failure, including cases when multiple errors are encountered.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct StatusCause {
+    /// The field of the resource that has caused this error, as named by its JSON serialization. May include dot and postfix notation for nested attributes. Arrays are zero-indexed.  Fields may appear more than once in an array of causes due to fields having multiple errors. Optional.
+    ///
+    /// Examples:
+    ///   "name" - the field "name" on the current resource
+    ///   "items\[0\].name" - the field "name" on the first array entry in "items"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub field: Option<String>,
+    /// A human-readable description of the cause of the error.  This field may be presented as-is to a reader.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    /// A machine-readable description of the cause of the error. If this value is empty there is no information available.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+}
+
+impl StatusCause {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> StatusCauseBuilder {
+        StatusCauseBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> StatusCauseBuilder
+    where
+        Self: Clone,
+    {
+        StatusCauseBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<StatusCause> for StatusCauseBuilder {
+    fn into(self) -> StatusCause {
+        self.body
+    }
+}
+
+/// Builder for [`StatusCause`](./struct.StatusCause.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct StatusCauseBuilder {
+    body: self::StatusCause,
+}
+
+impl StatusCauseBuilder {
+    /// The field of the resource that has caused this error, as named by its JSON serialization. May include dot and postfix notation for nested attributes. Arrays are zero-indexed.  Fields may appear more than once in an array of causes due to fields having multiple errors. Optional.
+    ///
+    /// Examples:
+    ///   "name" - the field "name" on the current resource
+    ///   "items\[0\].name" - the field "name" on the first array entry in "items"
+    #[inline]
+    pub fn field(mut self, value: impl Into<String>) -> Self {
+        self.body.field = Some(value.into());
+        self
+    }
+
+    /// Sets [`field`](#method.field) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_field(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.field = value.map(|value| value.into());
+        self
+    }
+
+    /// A human-readable description of the cause of the error.  This field may be presented as-is to a reader.
+    #[inline]
+    pub fn message(mut self, value: impl Into<String>) -> Self {
+        self.body.message = Some(value.into());
+        self
+    }
+
+    /// Sets [`message`](#method.message) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_message(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.message = value.map(|value| value.into());
+        self
+    }
+
+    /// A machine-readable description of the cause of the error. If this value is empty there is no information available.
+    #[inline]
+    pub fn reason(mut self, value: impl Into<String>) -> Self {
+        self.body.reason = Some(value.into());
+        self
+    }
+
+    /// Sets [`reason`](#method.reason) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_reason(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.reason = value.map(|value| value.into());
+        self
+    }
+}
@@ -0,0 +1,155 @@
+
+/// StatusDetails is a set of additional properties that MAY be set by the server to provide additional information about a response. The Reason field of a Status object defines what attributes will be set. Clients must ignore fields that do not match the defined type of each attribute, and should assume that any attribute may be empty, invalid, or under defined.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct StatusDetails {
+    /// The Causes array includes more details associated with the StatusReason failure. Not all StatusReasons may provide detailed causes.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub causes: Option<Vec<crate::codegen::io::k8s::apimachinery::pkg::apis::meta::v1::status_cause::StatusCause>>,
+    /// The group attribute of the resource associated with the status StatusReason.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub group: Option<String>,
+    /// The kind attribute of the resource associated with the status StatusReason. On some operations may differ from the requested resource Kind. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#types-kinds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub kind: Option<String>,
+    /// The name attribute of the resource associated with the status StatusReason (when there is a single name which can be described).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// If specified, the time in seconds before the operation should be retried. Some errors may indicate the client must take an alternate action - for those errors this field may indicate how long to wait before taking the alternate action.
+    #[serde(rename = "retryAfterSeconds", skip_serializing_if = "Option::is_none")]
+    pub retry_after_seconds: Option<i32>,
+    /// UID of the resource. (when there is a single resource which can be described). More info: http://kubernetes.io/docs/user-guide/identifiers#uids
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub uid: Option<String>,
+}
+
+impl StatusDetails {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> StatusDetailsBuilder {
+        StatusDetailsBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> StatusDetailsBuilder
+    where
+        Self: Clone,
+    {
+        StatusDetailsBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<StatusDetails> for StatusDetailsBuilder {
+    fn into(self) -> StatusDetails {
+        self.body
+    }
+}
+
+/// Builder for [`StatusDetails`](./struct.StatusDetails.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct StatusDetailsBuilder {
+    body: self::StatusDetails,
+}
+
+impl StatusDetailsBuilder {
+    /// The Causes array includes more details associated with the StatusReason failure. Not all StatusReasons may provide detailed causes.
+    #[inline]
+    pub fn causes(mut self, value: impl Iterator<Item = crate::codegen::io::k8s::apimachinery::pkg::apis::meta::v1::status_cause::StatusCause>) -> Self {
+        self.body.causes = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`causes`](#method.causes) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_causes(mut self, value: Option<impl Iterator<Item = crate::codegen::io::k8s::apimachinery::pkg::apis::meta::v1::status_cause::StatusCause>>) -> Self {
+        self.body.causes = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`causes`](#method.causes), but takes the values as a `Vec`.
+    #[inline]
+    pub fn causes_vec(mut self, value: impl Into<Vec<crate::codegen::io::k8s::apimachinery::pkg::apis::meta::v1::status_cause::StatusCause>>) -> Self {
+        self.body.causes = Some(value.into());
+        self
+    }
+
+    /// The group attribute of the resource associated with the status StatusReason.
+    #[inline]
+    pub fn group(mut self, value: impl Into<String>) -> Self {
+        self.body.group = Some(value.into());
+        self
+    }
+
+    /// Sets [`group`](#method.group) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_group(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.group = value.map(|value| value.into());
+        self
+    }
+
+    /// The kind attribute of the resource associated with the status StatusReason. On some operations may differ from the requested resource Kind. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#types-kinds
+    #[inline]
+    pub fn kind(mut self, value: impl Into<String>) -> Self {
+        self.body.kind = Some(value.into());
+        self
+    }
+
+    /// Sets [`kind`](#method.kind) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_kind(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.kind = value.map(|value| value.into());
+        self
+    }
+
+    /// The name attribute of the resource associated with the status StatusReason (when there is a single name which can be described).
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> Self {
+        self.body.name = Some(value.into());
+        self
+    }
+
+    /// Sets [`name`](#method.name) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_name(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.name = value.map(|value| value.into());
+        self
+    }
+
+    /// If specified, the time in seconds before the operation should be retried. Some errors may indicate the client must take an alternate action - for those errors this field may indicate how long to wait before taking the alternate action.
+    #[inline]
+    pub fn retry_after_seconds(mut self, value: impl Into<i32>) -> Self {
+        self.body.retry_after_seconds = Some(value.into());
+        self
+    }
+
+    /// Sets [`retry_after_seconds`](#method.retry_after_seconds) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_retry_after_seconds(mut self, value: Option<impl Into<i32>>) -> Self {
+        self.body.retry_after_seconds = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`retry_after_seconds`](#method.retry_after_seconds), but takes a reference.
+    #[inline]
+    pub fn retry_after_seconds_ref(self, value: &i32) -> Self {
+        self.retry_after_seconds(*value)
+    }
+
+    /// UID of the resource. (when there is a single resource which can be described). More info: http://kubernetes.io/docs/user-guide/identifiers#uids
+    #[inline]
+    pub fn uid(mut self, value: impl Into<String>) -> Self {
+        self.body.uid = Some(value.into());
+        self
+    }
+
+    /// Sets [`uid`](#method.uid) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_uid(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.uid = value.map(|value| value.into());
+        self
+    }
+}
@@ -0,0 +1,4 @@
+
+pub mod meta {
+    include!("./meta/mod.rs");
+}
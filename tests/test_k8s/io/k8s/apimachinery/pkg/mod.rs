@@ -0,0 +1,12 @@
+
+pub mod apis {
+    include!("./apis/mod.rs");
+}
+
+pub mod runtime {
+    include!("./runtime/mod.rs");
+}
+
+pub mod version {
+    include!("./version/mod.rs");
+}
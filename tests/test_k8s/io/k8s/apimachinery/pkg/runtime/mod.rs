@@ -0,0 +1,4 @@
+
+pub mod raw_extension {
+    include!("./raw_extension.rs");
+}
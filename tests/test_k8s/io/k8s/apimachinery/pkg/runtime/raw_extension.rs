@@ -0,0 +1,80 @@
+
+/// RawExtension is used to hold extensions in external versions.
+///
+/// To use this, make a field which has RawExtension as its type in your external, versioned struct, and Object in your internal struct. You also need to register your various plugin types.
+///
+/// // Internal package: type MyAPIObject struct {
+/// 	runtime.TypeMeta `json:",inline"`
+/// 	MyPlugin runtime.Object `json:"myPlugin"`
+/// } type PluginA struct {
+/// 	AOption string `json:"aOption"`
+/// }
+///
+/// // External package: type MyAPIObject struct {
+/// 	runtime.TypeMeta `json:",inline"`
+/// 	MyPlugin runtime.RawExtension `json:"myPlugin"`
+/// } type PluginA struct {
+/// 	AOption string `json:"aOption"`
+/// }
+///
+/// // On the wire, the JSON will look something like this: {
+/// 	"kind":"MyAPIObject",
+/// 	"apiVersion":"v1",
+/// 	"myPlugin": {
+/// 		"kind":"PluginA",
+/// 		"aOption":"foo",
+/// 	},
+/// }
+///
+/// So what happens? Decode first uses json or yaml to unmarshal the serialized data into your external MyAPIObject. That causes the raw JSON to be stored, but not unpacked. The next step is to copy (using pkg/conversion) into the internal struct. The runtime package's DefaultScheme has conversion functions installed which will unpack the JSON stored in RawExtension, turning it into the correct object type, and storing it in the Object. (TODO: In the case where the object is of an unknown type, a runtime.Unknown object will be created and stored.)
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct RawExtension {
+    /// Raw is the underlying serialization of this object.
+    #[serde(rename = "Raw")]
+    pub raw: String,
+}
+
+impl RawExtension {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> RawExtensionBuilder<crate::codegen::generics::MissingRaw> {
+        RawExtensionBuilder {
+            body: Default::default(),
+            _raw: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> RawExtensionBuilder<crate::codegen::generics::RawExists>
+    where
+        Self: Clone,
+    {
+        RawExtensionBuilder {
+            body: self.clone(),
+            _raw: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<RawExtension> for RawExtensionBuilder<crate::codegen::generics::RawExists> {
+    fn into(self) -> RawExtension {
+        self.body
+    }
+}
+
+/// Builder for [`RawExtension`](./struct.RawExtension.html) object.
+#[derive(Debug, Clone)]
+pub struct RawExtensionBuilder<Raw> {
+    body: self::RawExtension,
+    _raw: core::marker::PhantomData<Raw>,
+}
+
+impl<Raw> RawExtensionBuilder<Raw> {
+    /// Raw is the underlying serialization of this object.
+    #[inline]
+    pub fn raw(mut self, value: impl Into<String>) -> RawExtensionBuilder<crate::codegen::generics::RawExists> {
+        self.body.raw = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+}
@@ -0,0 +1,97 @@
+
+/// ServiceReference holds a reference to Service.legacy.k8s.io
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct ServiceReference {
+    /// Name is the name of the service
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Namespace is the namespace of the service
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub namespace: Option<String>,
+    /// If specified, the port on the service that hosting webhook. Default to 443 for backward compatibility. `port` should be a valid port number (1-65535, inclusive).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub port: Option<i32>,
+}
+
+impl ServiceReference {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> ServiceReferenceBuilder {
+        ServiceReferenceBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> ServiceReferenceBuilder
+    where
+        Self: Clone,
+    {
+        ServiceReferenceBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<ServiceReference> for ServiceReferenceBuilder {
+    fn into(self) -> ServiceReference {
+        self.body
+    }
+}
+
+/// Builder for [`ServiceReference`](./struct.ServiceReference.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct ServiceReferenceBuilder {
+    body: self::ServiceReference,
+}
+
+impl ServiceReferenceBuilder {
+    /// Name is the name of the service
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> Self {
+        self.body.name = Some(value.into());
+        self
+    }
+
+    /// Sets [`name`](#method.name) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_name(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.name = value.map(|value| value.into());
+        self
+    }
+
+    /// Namespace is the namespace of the service
+    #[inline]
+    pub fn namespace(mut self, value: impl Into<String>) -> Self {
+        self.body.namespace = Some(value.into());
+        self
+    }
+
+    /// Sets [`namespace`](#method.namespace) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_namespace(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.namespace = value.map(|value| value.into());
+        self
+    }
+
+    /// If specified, the port on the service that hosting webhook. Default to 443 for backward compatibility. `port` should be a valid port number (1-65535, inclusive).
+    #[inline]
+    pub fn port(mut self, value: impl Into<i32>) -> Self {
+        self.body.port = Some(value.into());
+        self
+    }
+
+    /// Sets [`port`](#method.port) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_port(mut self, value: Option<impl Into<i32>>) -> Self {
+        self.body.port = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`port`](#method.port), but takes a reference.
+    #[inline]
+    pub fn port_ref(self, value: &i32) -> Self {
+        self.port(*value)
+    }
+}
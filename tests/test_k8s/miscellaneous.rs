@@ -0,0 +1,3415 @@
+
+/// Namespace for operations that cannot be added to any other modules.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct Miscellaneous {}
+
+impl Miscellaneous {
+    /// connect GET requests to attach of Pod
+    #[inline]
+    pub fn connect_core_v1_get_namespaced_pod_attach() -> MiscellaneousGetBuilder<crate::codegen::generics::MissingName, crate::codegen::generics::MissingNamespace> {
+        MiscellaneousGetBuilder {
+            inner: Default::default(),
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        }
+    }
+
+    /// connect POST requests to attach of Pod
+    #[inline]
+    pub fn connect_core_v1_post_namespaced_pod_attach() -> MiscellaneousPostBuilder<crate::codegen::generics::MissingName, crate::codegen::generics::MissingNamespace> {
+        MiscellaneousPostBuilder {
+            inner: Default::default(),
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        }
+    }
+
+    /// connect GET requests to exec of Pod
+    #[inline]
+    pub fn connect_core_v1_get_namespaced_pod_exec() -> MiscellaneousGetBuilder1<crate::codegen::generics::MissingName, crate::codegen::generics::MissingNamespace> {
+        MiscellaneousGetBuilder1 {
+            inner: Default::default(),
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        }
+    }
+
+    /// connect POST requests to exec of Pod
+    #[inline]
+    pub fn connect_core_v1_post_namespaced_pod_exec() -> MiscellaneousPostBuilder1<crate::codegen::generics::MissingName, crate::codegen::generics::MissingNamespace> {
+        MiscellaneousPostBuilder1 {
+            inner: Default::default(),
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        }
+    }
+
+    /// read log of the specified Pod
+    #[inline]
+    pub fn read_core_v1_namespaced_pod_log() -> MiscellaneousGetBuilder2<crate::codegen::generics::MissingName, crate::codegen::generics::MissingNamespace> {
+        MiscellaneousGetBuilder2 {
+            inner: Default::default(),
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        }
+    }
+
+    /// connect GET requests to portforward of Pod
+    #[inline]
+    pub fn connect_core_v1_get_namespaced_pod_portforward() -> MiscellaneousGetBuilder3<crate::codegen::generics::MissingName, crate::codegen::generics::MissingNamespace> {
+        MiscellaneousGetBuilder3 {
+            inner: Default::default(),
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        }
+    }
+
+    /// connect POST requests to portforward of Pod
+    #[inline]
+    pub fn connect_core_v1_post_namespaced_pod_portforward() -> MiscellaneousPostBuilder3<crate::codegen::generics::MissingName, crate::codegen::generics::MissingNamespace> {
+        MiscellaneousPostBuilder3 {
+            inner: Default::default(),
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        }
+    }
+
+    /// connect GET requests to proxy of Pod
+    #[inline]
+    pub fn connect_core_v1_get_namespaced_pod_proxy() -> MiscellaneousGetBuilder4<crate::codegen::generics::MissingName, crate::codegen::generics::MissingNamespace> {
+        MiscellaneousGetBuilder4 {
+            inner: Default::default(),
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        }
+    }
+
+    /// connect PUT requests to proxy of Pod
+    #[inline]
+    pub fn connect_core_v1_put_namespaced_pod_proxy() -> MiscellaneousPutBuilder4<crate::codegen::generics::MissingName, crate::codegen::generics::MissingNamespace> {
+        MiscellaneousPutBuilder4 {
+            inner: Default::default(),
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        }
+    }
+
+    /// connect POST requests to proxy of Pod
+    #[inline]
+    pub fn connect_core_v1_post_namespaced_pod_proxy() -> MiscellaneousPostBuilder4<crate::codegen::generics::MissingName, crate::codegen::generics::MissingNamespace> {
+        MiscellaneousPostBuilder4 {
+            inner: Default::default(),
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        }
+    }
+
+    /// connect DELETE requests to proxy of Pod
+    #[inline]
+    pub fn connect_core_v1_delete_namespaced_pod_proxy() -> MiscellaneousDeleteBuilder4<crate::codegen::generics::MissingName, crate::codegen::generics::MissingNamespace> {
+        MiscellaneousDeleteBuilder4 {
+            inner: Default::default(),
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        }
+    }
+
+    /// connect OPTIONS requests to proxy of Pod
+    #[inline]
+    pub fn connect_core_v1_options_namespaced_pod_proxy() -> MiscellaneousOptionsBuilder4<crate::codegen::generics::MissingName, crate::codegen::generics::MissingNamespace> {
+        MiscellaneousOptionsBuilder4 {
+            inner: Default::default(),
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        }
+    }
+
+    /// connect HEAD requests to proxy of Pod
+    #[inline]
+    pub fn connect_core_v1_head_namespaced_pod_proxy() -> MiscellaneousHeadBuilder4<crate::codegen::generics::MissingName, crate::codegen::generics::MissingNamespace> {
+        MiscellaneousHeadBuilder4 {
+            inner: Default::default(),
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        }
+    }
+
+    /// connect PATCH requests to proxy of Pod
+    #[inline]
+    pub fn connect_core_v1_patch_namespaced_pod_proxy() -> MiscellaneousPatchBuilder4<crate::codegen::generics::MissingName, crate::codegen::generics::MissingNamespace> {
+        MiscellaneousPatchBuilder4 {
+            inner: Default::default(),
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        }
+    }
+
+    /// connect GET requests to proxy of Pod
+    #[inline]
+    pub fn connect_core_v1_get_namespaced_pod_proxy_with_path() -> MiscellaneousGetBuilder5<crate::codegen::generics::MissingName, crate::codegen::generics::MissingNamespace, crate::codegen::generics::MissingPath> {
+        MiscellaneousGetBuilder5 {
+            inner: Default::default(),
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+            _param_path: core::marker::PhantomData,
+        }
+    }
+
+    /// connect PUT requests to proxy of Pod
+    #[inline]
+    pub fn connect_core_v1_put_namespaced_pod_proxy_with_path() -> MiscellaneousPutBuilder5<crate::codegen::generics::MissingName, crate::codegen::generics::MissingNamespace, crate::codegen::generics::MissingPath> {
+        MiscellaneousPutBuilder5 {
+            inner: Default::default(),
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+            _param_path: core::marker::PhantomData,
+        }
+    }
+
+    /// connect POST requests to proxy of Pod
+    #[inline]
+    pub fn connect_core_v1_post_namespaced_pod_proxy_with_path() -> MiscellaneousPostBuilder5<crate::codegen::generics::MissingName, crate::codegen::generics::MissingNamespace, crate::codegen::generics::MissingPath> {
+        MiscellaneousPostBuilder5 {
+            inner: Default::default(),
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+            _param_path: core::marker::PhantomData,
+        }
+    }
+
+    /// connect DELETE requests to proxy of Pod
+    #[inline]
+    pub fn connect_core_v1_delete_namespaced_pod_proxy_with_path() -> MiscellaneousDeleteBuilder5<crate::codegen::generics::MissingName, crate::codegen::generics::MissingNamespace, crate::codegen::generics::MissingPath> {
+        MiscellaneousDeleteBuilder5 {
+            inner: Default::default(),
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+            _param_path: core::marker::PhantomData,
+        }
+    }
+
+    /// connect OPTIONS requests to proxy of Pod
+    #[inline]
+    pub fn connect_core_v1_options_namespaced_pod_proxy_with_path() -> MiscellaneousOptionsBuilder5<crate::codegen::generics::MissingName, crate::codegen::generics::MissingNamespace, crate::codegen::generics::MissingPath> {
+        MiscellaneousOptionsBuilder5 {
+            inner: Default::default(),
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+            _param_path: core::marker::PhantomData,
+        }
+    }
+
+    /// connect HEAD requests to proxy of Pod
+    #[inline]
+    pub fn connect_core_v1_head_namespaced_pod_proxy_with_path() -> MiscellaneousHeadBuilder5<crate::codegen::generics::MissingName, crate::codegen::generics::MissingNamespace, crate::codegen::generics::MissingPath> {
+        MiscellaneousHeadBuilder5 {
+            inner: Default::default(),
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+            _param_path: core::marker::PhantomData,
+        }
+    }
+
+    /// connect PATCH requests to proxy of Pod
+    #[inline]
+    pub fn connect_core_v1_patch_namespaced_pod_proxy_with_path() -> MiscellaneousPatchBuilder5<crate::codegen::generics::MissingName, crate::codegen::generics::MissingNamespace, crate::codegen::generics::MissingPath> {
+        MiscellaneousPatchBuilder5 {
+            inner: Default::default(),
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+            _param_path: core::marker::PhantomData,
+        }
+    }
+
+    /// connect GET requests to proxy of Service
+    #[inline]
+    pub fn connect_core_v1_get_namespaced_service_proxy() -> MiscellaneousGetBuilder6<crate::codegen::generics::MissingName, crate::codegen::generics::MissingNamespace> {
+        MiscellaneousGetBuilder6 {
+            inner: Default::default(),
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        }
+    }
+
+    /// connect PUT requests to proxy of Service
+    #[inline]
+    pub fn connect_core_v1_put_namespaced_service_proxy() -> MiscellaneousPutBuilder6<crate::codegen::generics::MissingName, crate::codegen::generics::MissingNamespace> {
+        MiscellaneousPutBuilder6 {
+            inner: Default::default(),
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        }
+    }
+
+    /// connect POST requests to proxy of Service
+    #[inline]
+    pub fn connect_core_v1_post_namespaced_service_proxy() -> MiscellaneousPostBuilder6<crate::codegen::generics::MissingName, crate::codegen::generics::MissingNamespace> {
+        MiscellaneousPostBuilder6 {
+            inner: Default::default(),
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        }
+    }
+
+    /// connect DELETE requests to proxy of Service
+    #[inline]
+    pub fn connect_core_v1_delete_namespaced_service_proxy() -> MiscellaneousDeleteBuilder6<crate::codegen::generics::MissingName, crate::codegen::generics::MissingNamespace> {
+        MiscellaneousDeleteBuilder6 {
+            inner: Default::default(),
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        }
+    }
+
+    /// connect OPTIONS requests to proxy of Service
+    #[inline]
+    pub fn connect_core_v1_options_namespaced_service_proxy() -> MiscellaneousOptionsBuilder6<crate::codegen::generics::MissingName, crate::codegen::generics::MissingNamespace> {
+        MiscellaneousOptionsBuilder6 {
+            inner: Default::default(),
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        }
+    }
+
+    /// connect HEAD requests to proxy of Service
+    #[inline]
+    pub fn connect_core_v1_head_namespaced_service_proxy() -> MiscellaneousHeadBuilder6<crate::codegen::generics::MissingName, crate::codegen::generics::MissingNamespace> {
+        MiscellaneousHeadBuilder6 {
+            inner: Default::default(),
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        }
+    }
+
+    /// connect PATCH requests to proxy of Service
+    #[inline]
+    pub fn connect_core_v1_patch_namespaced_service_proxy() -> MiscellaneousPatchBuilder6<crate::codegen::generics::MissingName, crate::codegen::generics::MissingNamespace> {
+        MiscellaneousPatchBuilder6 {
+            inner: Default::default(),
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        }
+    }
+
+    /// connect GET requests to proxy of Service
+    #[inline]
+    pub fn connect_core_v1_get_namespaced_service_proxy_with_path() -> MiscellaneousGetBuilder7<crate::codegen::generics::MissingName, crate::codegen::generics::MissingNamespace, crate::codegen::generics::MissingPath> {
+        MiscellaneousGetBuilder7 {
+            inner: Default::default(),
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+            _param_path: core::marker::PhantomData,
+        }
+    }
+
+    /// connect PUT requests to proxy of Service
+    #[inline]
+    pub fn connect_core_v1_put_namespaced_service_proxy_with_path() -> MiscellaneousPutBuilder7<crate::codegen::generics::MissingName, crate::codegen::generics::MissingNamespace, crate::codegen::generics::MissingPath> {
+        MiscellaneousPutBuilder7 {
+            inner: Default::default(),
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+            _param_path: core::marker::PhantomData,
+        }
+    }
+
+    /// connect POST requests to proxy of Service
+    #[inline]
+    pub fn connect_core_v1_post_namespaced_service_proxy_with_path() -> MiscellaneousPostBuilder7<crate::codegen::generics::MissingName, crate::codegen::generics::MissingNamespace, crate::codegen::generics::MissingPath> {
+        MiscellaneousPostBuilder7 {
+            inner: Default::default(),
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+            _param_path: core::marker::PhantomData,
+        }
+    }
+
+    /// connect DELETE requests to proxy of Service
+    #[inline]
+    pub fn connect_core_v1_delete_namespaced_service_proxy_with_path() -> MiscellaneousDeleteBuilder7<crate::codegen::generics::MissingName, crate::codegen::generics::MissingNamespace, crate::codegen::generics::MissingPath> {
+        MiscellaneousDeleteBuilder7 {
+            inner: Default::default(),
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+            _param_path: core::marker::PhantomData,
+        }
+    }
+
+    /// connect OPTIONS requests to proxy of Service
+    #[inline]
+    pub fn connect_core_v1_options_namespaced_service_proxy_with_path() -> MiscellaneousOptionsBuilder7<crate::codegen::generics::MissingName, crate::codegen::generics::MissingNamespace, crate::codegen::generics::MissingPath> {
+        MiscellaneousOptionsBuilder7 {
+            inner: Default::default(),
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+            _param_path: core::marker::PhantomData,
+        }
+    }
+
+    /// connect HEAD requests to proxy of Service
+    #[inline]
+    pub fn connect_core_v1_head_namespaced_service_proxy_with_path() -> MiscellaneousHeadBuilder7<crate::codegen::generics::MissingName, crate::codegen::generics::MissingNamespace, crate::codegen::generics::MissingPath> {
+        MiscellaneousHeadBuilder7 {
+            inner: Default::default(),
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+            _param_path: core::marker::PhantomData,
+        }
+    }
+
+    /// connect PATCH requests to proxy of Service
+    #[inline]
+    pub fn connect_core_v1_patch_namespaced_service_proxy_with_path() -> MiscellaneousPatchBuilder7<crate::codegen::generics::MissingName, crate::codegen::generics::MissingNamespace, crate::codegen::generics::MissingPath> {
+        MiscellaneousPatchBuilder7 {
+            inner: Default::default(),
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+            _param_path: core::marker::PhantomData,
+        }
+    }
+
+    /// connect GET requests to proxy of Node
+    #[inline]
+    pub fn connect_core_v1_get_node_proxy() -> MiscellaneousGetBuilder8<crate::codegen::generics::MissingName> {
+        MiscellaneousGetBuilder8 {
+            inner: Default::default(),
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+        }
+    }
+
+    /// connect PUT requests to proxy of Node
+    #[inline]
+    pub fn connect_core_v1_put_node_proxy() -> MiscellaneousPutBuilder8<crate::codegen::generics::MissingName> {
+        MiscellaneousPutBuilder8 {
+            inner: Default::default(),
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+        }
+    }
+
+    /// connect POST requests to proxy of Node
+    #[inline]
+    pub fn connect_core_v1_post_node_proxy() -> MiscellaneousPostBuilder8<crate::codegen::generics::MissingName> {
+        MiscellaneousPostBuilder8 {
+            inner: Default::default(),
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+        }
+    }
+
+    /// connect DELETE requests to proxy of Node
+    #[inline]
+    pub fn connect_core_v1_delete_node_proxy() -> MiscellaneousDeleteBuilder8<crate::codegen::generics::MissingName> {
+        MiscellaneousDeleteBuilder8 {
+            inner: Default::default(),
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+        }
+    }
+
+    /// connect OPTIONS requests to proxy of Node
+    #[inline]
+    pub fn connect_core_v1_options_node_proxy() -> MiscellaneousOptionsBuilder8<crate::codegen::generics::MissingName> {
+        MiscellaneousOptionsBuilder8 {
+            inner: Default::default(),
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+        }
+    }
+
+    /// connect HEAD requests to proxy of Node
+    #[inline]
+    pub fn connect_core_v1_head_node_proxy() -> MiscellaneousHeadBuilder8<crate::codegen::generics::MissingName> {
+        MiscellaneousHeadBuilder8 {
+            inner: Default::default(),
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+        }
+    }
+
+    /// connect PATCH requests to proxy of Node
+    #[inline]
+    pub fn connect_core_v1_patch_node_proxy() -> MiscellaneousPatchBuilder8<crate::codegen::generics::MissingName> {
+        MiscellaneousPatchBuilder8 {
+            inner: Default::default(),
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+        }
+    }
+
+    /// connect GET requests to proxy of Node
+    #[inline]
+    pub fn connect_core_v1_get_node_proxy_with_path() -> MiscellaneousGetBuilder9<crate::codegen::generics::MissingName, crate::codegen::generics::MissingPath> {
+        MiscellaneousGetBuilder9 {
+            inner: Default::default(),
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+            _param_path: core::marker::PhantomData,
+        }
+    }
+
+    /// connect PUT requests to proxy of Node
+    #[inline]
+    pub fn connect_core_v1_put_node_proxy_with_path() -> MiscellaneousPutBuilder9<crate::codegen::generics::MissingName, crate::codegen::generics::MissingPath> {
+        MiscellaneousPutBuilder9 {
+            inner: Default::default(),
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+            _param_path: core::marker::PhantomData,
+        }
+    }
+
+    /// connect POST requests to proxy of Node
+    #[inline]
+    pub fn connect_core_v1_post_node_proxy_with_path() -> MiscellaneousPostBuilder9<crate::codegen::generics::MissingName, crate::codegen::generics::MissingPath> {
+        MiscellaneousPostBuilder9 {
+            inner: Default::default(),
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+            _param_path: core::marker::PhantomData,
+        }
+    }
+
+    /// connect DELETE requests to proxy of Node
+    #[inline]
+    pub fn connect_core_v1_delete_node_proxy_with_path() -> MiscellaneousDeleteBuilder9<crate::codegen::generics::MissingName, crate::codegen::generics::MissingPath> {
+        MiscellaneousDeleteBuilder9 {
+            inner: Default::default(),
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+            _param_path: core::marker::PhantomData,
+        }
+    }
+
+    /// connect OPTIONS requests to proxy of Node
+    #[inline]
+    pub fn connect_core_v1_options_node_proxy_with_path() -> MiscellaneousOptionsBuilder9<crate::codegen::generics::MissingName, crate::codegen::generics::MissingPath> {
+        MiscellaneousOptionsBuilder9 {
+            inner: Default::default(),
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+            _param_path: core::marker::PhantomData,
+        }
+    }
+
+    /// connect HEAD requests to proxy of Node
+    #[inline]
+    pub fn connect_core_v1_head_node_proxy_with_path() -> MiscellaneousHeadBuilder9<crate::codegen::generics::MissingName, crate::codegen::generics::MissingPath> {
+        MiscellaneousHeadBuilder9 {
+            inner: Default::default(),
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+            _param_path: core::marker::PhantomData,
+        }
+    }
+
+    /// connect PATCH requests to proxy of Node
+    #[inline]
+    pub fn connect_core_v1_patch_node_proxy_with_path() -> MiscellaneousPatchBuilder9<crate::codegen::generics::MissingName, crate::codegen::generics::MissingPath> {
+        MiscellaneousPatchBuilder9 {
+            inner: Default::default(),
+            _op: core::marker::PhantomData,
+            _param_name: core::marker::PhantomData,
+            _param_path: core::marker::PhantomData,
+        }
+    }
+}
+
+/// Builder shared by operations (with the same fields and parameters) associated with `Miscellaneous`.
+#[repr(transparent)]
+#[derive(Debug, Clone)]
+pub struct MiscellaneousOpBuilder<Op, Name, Namespace> {
+    inner: MiscellaneousOpBuilderContainer,
+    _op: core::marker::PhantomData<Op>,
+    _param_name: core::marker::PhantomData<Name>,
+    _param_namespace: core::marker::PhantomData<Namespace>,
+}
+
+#[derive(Debug, Default, Clone)]
+struct MiscellaneousOpBuilderContainer {
+    param_container: Option<String>,
+    param_name: Option<String>,
+    param_namespace: Option<String>,
+    param_stderr: Option<bool>,
+    param_stdin: Option<bool>,
+    param_stdout: Option<bool>,
+    param_tty: Option<bool>,
+}
+
+/// Builder created by [`Miscellaneous::connect_core_v1_get_namespaced_pod_attach`](./struct.Miscellaneous.html#method.connect_core_v1_get_namespaced_pod_attach) method for a `GET` operation associated with `Miscellaneous`.
+pub type MiscellaneousGetBuilder<Name, Namespace> = MiscellaneousOpBuilder<MiscellaneousGetOp, Name, Namespace>;
+
+/// Marks the operation of [`MiscellaneousGetBuilder`] in the shared builder.
+#[derive(Debug, Clone)]
+pub enum MiscellaneousGetOp {}
+
+impl<Op, Name, Namespace> MiscellaneousOpBuilder<Op, Name, Namespace> {
+    /// The container in which to execute the command. Defaults to only container if there is only one container in the pod.
+    #[inline]
+    pub fn container(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_container = Some(value.into());
+        self
+    }
+
+    /// Sets [`container`](#method.container) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_container(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_container = value.map(|value| value.into());
+        self
+    }
+
+    /// name of the PodAttachOptions
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> MiscellaneousOpBuilder<Op, crate::codegen::generics::NameExists, Namespace> {
+        self.inner.param_name = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// object name and auth scope, such as for teams and projects
+    #[inline]
+    pub fn namespace(mut self, value: impl Into<String>) -> MiscellaneousOpBuilder<Op, Name, crate::codegen::generics::NamespaceExists> {
+        self.inner.param_namespace = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Stderr if true indicates that stderr is to be redirected for the attach call. Defaults to true.
+    #[inline]
+    pub fn stderr(mut self, value: impl Into<bool>) -> Self {
+        self.inner.param_stderr = Some(value.into());
+        self
+    }
+
+    /// Sets [`stderr`](#method.stderr) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_stderr(mut self, value: Option<impl Into<bool>>) -> Self {
+        self.inner.param_stderr = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`stderr`](#method.stderr), but takes a reference.
+    #[inline]
+    pub fn stderr_ref(self, value: &bool) -> Self {
+        self.stderr(*value)
+    }
+
+    /// Stdin if true, redirects the standard input stream of the pod for this call. Defaults to false.
+    #[inline]
+    pub fn stdin(mut self, value: impl Into<bool>) -> Self {
+        self.inner.param_stdin = Some(value.into());
+        self
+    }
+
+    /// Sets [`stdin`](#method.stdin) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_stdin(mut self, value: Option<impl Into<bool>>) -> Self {
+        self.inner.param_stdin = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`stdin`](#method.stdin), but takes a reference.
+    #[inline]
+    pub fn stdin_ref(self, value: &bool) -> Self {
+        self.stdin(*value)
+    }
+
+    /// Stdout if true indicates that stdout is to be redirected for the attach call. Defaults to true.
+    #[inline]
+    pub fn stdout(mut self, value: impl Into<bool>) -> Self {
+        self.inner.param_stdout = Some(value.into());
+        self
+    }
+
+    /// Sets [`stdout`](#method.stdout) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_stdout(mut self, value: Option<impl Into<bool>>) -> Self {
+        self.inner.param_stdout = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`stdout`](#method.stdout), but takes a reference.
+    #[inline]
+    pub fn stdout_ref(self, value: &bool) -> Self {
+        self.stdout(*value)
+    }
+
+    /// TTY if true indicates that a tty will be allocated for the attach call. This is passed through the container runtime so the tty is allocated on the worker node by the container runtime. Defaults to false.
+    #[inline]
+    pub fn tty(mut self, value: impl Into<bool>) -> Self {
+        self.inner.param_tty = Some(value.into());
+        self
+    }
+
+    /// Sets [`tty`](#method.tty) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_tty(mut self, value: Option<impl Into<bool>>) -> Self {
+        self.inner.param_tty = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`tty`](#method.tty), but takes a reference.
+    #[inline]
+    pub fn tty_ref(self, value: &bool) -> Self {
+        self.tty(*value)
+    }
+}
+
+impl<Client: crate::codegen::client::ApiClient + Sync + 'static> crate::codegen::client::Sendable<Client> for MiscellaneousGetBuilder<crate::codegen::generics::NameExists, crate::codegen::generics::NamespaceExists> {
+    type Output = String;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    const OPERATION_ID: Option<&'static str> = Some("connectCoreV1GetNamespacedPodAttach");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::codegen::client::ApiError<Client::Response>> {
+        Ok(format!("/api/v1/namespaces/{namespace}/pods/{name}/attach", name=crate::codegen::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?, namespace=crate::codegen::client::path_param("namespace", self.inner.param_namespace.as_ref().expect("missing parameter namespace?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::codegen::client::ApiError<Client::Response>> {
+        use crate::codegen::client::Request;
+        Ok(req
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("container", self.inner.param_container.as_ref().map(std::string::ToString::to_string)),
+            ("stderr", self.inner.param_stderr.as_ref().map(std::string::ToString::to_string)),
+            ("stdin", self.inner.param_stdin.as_ref().map(std::string::ToString::to_string)),
+            ("stdout", self.inner.param_stdout.as_ref().map(std::string::ToString::to_string)),
+            ("tty", self.inner.param_tty.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<MiscellaneousGetBuilder<crate::codegen::generics::NameExists, crate::codegen::generics::NamespaceExists>> for reqwest::Request {
+    type Error = crate::codegen::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: MiscellaneousGetBuilder<crate::codegen::generics::NameExists, crate::codegen::generics::NamespaceExists>) -> Result<Self, Self::Error> {
+        let req = crate::codegen::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::codegen::client::ApiError::Reqwest)
+    }
+}
+
+/// Builder created by [`Miscellaneous::connect_core_v1_post_namespaced_pod_attach`](./struct.Miscellaneous.html#method.connect_core_v1_post_namespaced_pod_attach) method for a `POST` operation associated with `Miscellaneous`.
+pub type MiscellaneousPostBuilder<Name, Namespace> = MiscellaneousOpBuilder<MiscellaneousPostOp, Name, Namespace>;
+
+/// Marks the operation of [`MiscellaneousPostBuilder`] in the shared builder.
+#[derive(Debug, Clone)]
+pub enum MiscellaneousPostOp {}
+
+
+impl<Client: crate::codegen::client::ApiClient + Sync + 'static> crate::codegen::client::Sendable<Client> for MiscellaneousPostBuilder<crate::codegen::generics::NameExists, crate::codegen::generics::NamespaceExists> {
+    type Output = String;
+
+    const METHOD: http::Method = http::Method::POST;
+
+    const OPERATION_ID: Option<&'static str> = Some("connectCoreV1PostNamespacedPodAttach");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::codegen::client::ApiError<Client::Response>> {
+        Ok(format!("/api/v1/namespaces/{namespace}/pods/{name}/attach", name=crate::codegen::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?, namespace=crate::codegen::client::path_param("namespace", self.inner.param_namespace.as_ref().expect("missing parameter namespace?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::codegen::client::ApiError<Client::Response>> {
+        use crate::codegen::client::Request;
+        Ok(req
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("container", self.inner.param_container.as_ref().map(std::string::ToString::to_string)),
+            ("stderr", self.inner.param_stderr.as_ref().map(std::string::ToString::to_string)),
+            ("stdin", self.inner.param_stdin.as_ref().map(std::string::ToString::to_string)),
+            ("stdout", self.inner.param_stdout.as_ref().map(std::string::ToString::to_string)),
+            ("tty", self.inner.param_tty.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<MiscellaneousPostBuilder<crate::codegen::generics::NameExists, crate::codegen::generics::NamespaceExists>> for reqwest::Request {
+    type Error = crate::codegen::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: MiscellaneousPostBuilder<crate::codegen::generics::NameExists, crate::codegen::generics::NamespaceExists>) -> Result<Self, Self::Error> {
+        let req = crate::codegen::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::codegen::client::ApiError::Reqwest)
+    }
+}
+
+/// Builder shared by operations (with the same fields and parameters) associated with `Miscellaneous`.
+#[repr(transparent)]
+#[derive(Debug, Clone)]
+pub struct MiscellaneousOpBuilder1<Op, Name, Namespace> {
+    inner: MiscellaneousOpBuilder1Container,
+    _op: core::marker::PhantomData<Op>,
+    _param_name: core::marker::PhantomData<Name>,
+    _param_namespace: core::marker::PhantomData<Namespace>,
+}
+
+#[derive(Debug, Default, Clone)]
+struct MiscellaneousOpBuilder1Container {
+    param_command: Option<String>,
+    param_container: Option<String>,
+    param_name: Option<String>,
+    param_namespace: Option<String>,
+    param_stderr: Option<bool>,
+    param_stdin: Option<bool>,
+    param_stdout: Option<bool>,
+    param_tty: Option<bool>,
+}
+
+/// Builder created by [`Miscellaneous::connect_core_v1_get_namespaced_pod_exec`](./struct.Miscellaneous.html#method.connect_core_v1_get_namespaced_pod_exec) method for a `GET` operation associated with `Miscellaneous`.
+pub type MiscellaneousGetBuilder1<Name, Namespace> = MiscellaneousOpBuilder1<MiscellaneousGetOp1, Name, Namespace>;
+
+/// Marks the operation of [`MiscellaneousGetBuilder1`] in the shared builder.
+#[derive(Debug, Clone)]
+pub enum MiscellaneousGetOp1 {}
+
+impl<Op, Name, Namespace> MiscellaneousOpBuilder1<Op, Name, Namespace> {
+    /// Command is the remote command to execute. argv array. Not executed within a shell.
+    #[inline]
+    pub fn command(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_command = Some(value.into());
+        self
+    }
+
+    /// Sets [`command`](#method.command) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_command(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_command = value.map(|value| value.into());
+        self
+    }
+
+    /// Container in which to execute the command. Defaults to only container if there is only one container in the pod.
+    #[inline]
+    pub fn container(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_container = Some(value.into());
+        self
+    }
+
+    /// Sets [`container`](#method.container) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_container(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_container = value.map(|value| value.into());
+        self
+    }
+
+    /// name of the PodExecOptions
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> MiscellaneousOpBuilder1<Op, crate::codegen::generics::NameExists, Namespace> {
+        self.inner.param_name = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// object name and auth scope, such as for teams and projects
+    #[inline]
+    pub fn namespace(mut self, value: impl Into<String>) -> MiscellaneousOpBuilder1<Op, Name, crate::codegen::generics::NamespaceExists> {
+        self.inner.param_namespace = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Redirect the standard error stream of the pod for this call. Defaults to true.
+    #[inline]
+    pub fn stderr(mut self, value: impl Into<bool>) -> Self {
+        self.inner.param_stderr = Some(value.into());
+        self
+    }
+
+    /// Sets [`stderr`](#method.stderr) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_stderr(mut self, value: Option<impl Into<bool>>) -> Self {
+        self.inner.param_stderr = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`stderr`](#method.stderr), but takes a reference.
+    #[inline]
+    pub fn stderr_ref(self, value: &bool) -> Self {
+        self.stderr(*value)
+    }
+
+    /// Redirect the standard input stream of the pod for this call. Defaults to false.
+    #[inline]
+    pub fn stdin(mut self, value: impl Into<bool>) -> Self {
+        self.inner.param_stdin = Some(value.into());
+        self
+    }
+
+    /// Sets [`stdin`](#method.stdin) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_stdin(mut self, value: Option<impl Into<bool>>) -> Self {
+        self.inner.param_stdin = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`stdin`](#method.stdin), but takes a reference.
+    #[inline]
+    pub fn stdin_ref(self, value: &bool) -> Self {
+        self.stdin(*value)
+    }
+
+    /// Redirect the standard output stream of the pod for this call. Defaults to true.
+    #[inline]
+    pub fn stdout(mut self, value: impl Into<bool>) -> Self {
+        self.inner.param_stdout = Some(value.into());
+        self
+    }
+
+    /// Sets [`stdout`](#method.stdout) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_stdout(mut self, value: Option<impl Into<bool>>) -> Self {
+        self.inner.param_stdout = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`stdout`](#method.stdout), but takes a reference.
+    #[inline]
+    pub fn stdout_ref(self, value: &bool) -> Self {
+        self.stdout(*value)
+    }
+
+    /// TTY if true indicates that a tty will be allocated for the exec call. Defaults to false.
+    #[inline]
+    pub fn tty(mut self, value: impl Into<bool>) -> Self {
+        self.inner.param_tty = Some(value.into());
+        self
+    }
+
+    /// Sets [`tty`](#method.tty) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_tty(mut self, value: Option<impl Into<bool>>) -> Self {
+        self.inner.param_tty = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`tty`](#method.tty), but takes a reference.
+    #[inline]
+    pub fn tty_ref(self, value: &bool) -> Self {
+        self.tty(*value)
+    }
+}
+
+impl<Client: crate::codegen::client::ApiClient + Sync + 'static> crate::codegen::client::Sendable<Client> for MiscellaneousGetBuilder1<crate::codegen::generics::NameExists, crate::codegen::generics::NamespaceExists> {
+    type Output = String;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    const OPERATION_ID: Option<&'static str> = Some("connectCoreV1GetNamespacedPodExec");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::codegen::client::ApiError<Client::Response>> {
+        Ok(format!("/api/v1/namespaces/{namespace}/pods/{name}/exec", name=crate::codegen::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?, namespace=crate::codegen::client::path_param("namespace", self.inner.param_namespace.as_ref().expect("missing parameter namespace?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::codegen::client::ApiError<Client::Response>> {
+        use crate::codegen::client::Request;
+        Ok(req
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("command", self.inner.param_command.as_ref().map(std::string::ToString::to_string)),
+            ("container", self.inner.param_container.as_ref().map(std::string::ToString::to_string)),
+            ("stderr", self.inner.param_stderr.as_ref().map(std::string::ToString::to_string)),
+            ("stdin", self.inner.param_stdin.as_ref().map(std::string::ToString::to_string)),
+            ("stdout", self.inner.param_stdout.as_ref().map(std::string::ToString::to_string)),
+            ("tty", self.inner.param_tty.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<MiscellaneousGetBuilder1<crate::codegen::generics::NameExists, crate::codegen::generics::NamespaceExists>> for reqwest::Request {
+    type Error = crate::codegen::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: MiscellaneousGetBuilder1<crate::codegen::generics::NameExists, crate::codegen::generics::NamespaceExists>) -> Result<Self, Self::Error> {
+        let req = crate::codegen::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::codegen::client::ApiError::Reqwest)
+    }
+}
+
+/// Builder created by [`Miscellaneous::connect_core_v1_post_namespaced_pod_exec`](./struct.Miscellaneous.html#method.connect_core_v1_post_namespaced_pod_exec) method for a `POST` operation associated with `Miscellaneous`.
+pub type MiscellaneousPostBuilder1<Name, Namespace> = MiscellaneousOpBuilder1<MiscellaneousPostOp1, Name, Namespace>;
+
+/// Marks the operation of [`MiscellaneousPostBuilder1`] in the shared builder.
+#[derive(Debug, Clone)]
+pub enum MiscellaneousPostOp1 {}
+
+
+impl<Client: crate::codegen::client::ApiClient + Sync + 'static> crate::codegen::client::Sendable<Client> for MiscellaneousPostBuilder1<crate::codegen::generics::NameExists, crate::codegen::generics::NamespaceExists> {
+    type Output = String;
+
+    const METHOD: http::Method = http::Method::POST;
+
+    const OPERATION_ID: Option<&'static str> = Some("connectCoreV1PostNamespacedPodExec");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::codegen::client::ApiError<Client::Response>> {
+        Ok(format!("/api/v1/namespaces/{namespace}/pods/{name}/exec", name=crate::codegen::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?, namespace=crate::codegen::client::path_param("namespace", self.inner.param_namespace.as_ref().expect("missing parameter namespace?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::codegen::client::ApiError<Client::Response>> {
+        use crate::codegen::client::Request;
+        Ok(req
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("command", self.inner.param_command.as_ref().map(std::string::ToString::to_string)),
+            ("container", self.inner.param_container.as_ref().map(std::string::ToString::to_string)),
+            ("stderr", self.inner.param_stderr.as_ref().map(std::string::ToString::to_string)),
+            ("stdin", self.inner.param_stdin.as_ref().map(std::string::ToString::to_string)),
+            ("stdout", self.inner.param_stdout.as_ref().map(std::string::ToString::to_string)),
+            ("tty", self.inner.param_tty.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<MiscellaneousPostBuilder1<crate::codegen::generics::NameExists, crate::codegen::generics::NamespaceExists>> for reqwest::Request {
+    type Error = crate::codegen::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: MiscellaneousPostBuilder1<crate::codegen::generics::NameExists, crate::codegen::generics::NamespaceExists>) -> Result<Self, Self::Error> {
+        let req = crate::codegen::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::codegen::client::ApiError::Reqwest)
+    }
+}
+
+/// Builder created by [`Miscellaneous::read_core_v1_namespaced_pod_log`](./struct.Miscellaneous.html#method.read_core_v1_namespaced_pod_log) method for a `GET` operation associated with `Miscellaneous`.
+#[repr(transparent)]
+#[derive(Debug, Clone)]
+pub struct MiscellaneousGetBuilder2<Name, Namespace> {
+    inner: MiscellaneousGetBuilder2Container,
+    _param_name: core::marker::PhantomData<Name>,
+    _param_namespace: core::marker::PhantomData<Namespace>,
+}
+
+#[derive(Debug, Default, Clone)]
+struct MiscellaneousGetBuilder2Container {
+    param_container: Option<String>,
+    param_follow: Option<bool>,
+    param_limit_bytes: Option<i64>,
+    param_name: Option<String>,
+    param_namespace: Option<String>,
+    param_pretty: Option<String>,
+    param_previous: Option<bool>,
+    param_since_seconds: Option<i64>,
+    param_tail_lines: Option<i64>,
+    param_timestamps: Option<bool>,
+}
+
+impl<Name, Namespace> MiscellaneousGetBuilder2<Name, Namespace> {
+    /// The container for which to stream logs. Defaults to only container if there is one container in the pod.
+    #[inline]
+    pub fn container(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_container = Some(value.into());
+        self
+    }
+
+    /// Sets [`container`](#method.container) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_container(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_container = value.map(|value| value.into());
+        self
+    }
+
+    /// Follow the log stream of the pod. Defaults to false.
+    #[inline]
+    pub fn follow(mut self, value: impl Into<bool>) -> Self {
+        self.inner.param_follow = Some(value.into());
+        self
+    }
+
+    /// Sets [`follow`](#method.follow) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_follow(mut self, value: Option<impl Into<bool>>) -> Self {
+        self.inner.param_follow = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`follow`](#method.follow), but takes a reference.
+    #[inline]
+    pub fn follow_ref(self, value: &bool) -> Self {
+        self.follow(*value)
+    }
+
+    /// If set, the number of bytes to read from the server before terminating the log output. This may not display a complete final line of logging, and may return slightly more or slightly less than the specified limit.
+    #[inline]
+    pub fn limit_bytes(mut self, value: impl Into<i64>) -> Self {
+        self.inner.param_limit_bytes = Some(value.into());
+        self
+    }
+
+    /// Sets [`limit_bytes`](#method.limit_bytes) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_limit_bytes(mut self, value: Option<impl Into<i64>>) -> Self {
+        self.inner.param_limit_bytes = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`limit_bytes`](#method.limit_bytes), but takes a reference.
+    #[inline]
+    pub fn limit_bytes_ref(self, value: &i64) -> Self {
+        self.limit_bytes(*value)
+    }
+
+    /// name of the Pod
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> MiscellaneousGetBuilder2<crate::codegen::generics::NameExists, Namespace> {
+        self.inner.param_name = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// object name and auth scope, such as for teams and projects
+    #[inline]
+    pub fn namespace(mut self, value: impl Into<String>) -> MiscellaneousGetBuilder2<Name, crate::codegen::generics::NamespaceExists> {
+        self.inner.param_namespace = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// If 'true', then the output is pretty printed.
+    #[inline]
+    pub fn pretty(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_pretty = Some(value.into());
+        self
+    }
+
+    /// Sets [`pretty`](#method.pretty) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_pretty(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_pretty = value.map(|value| value.into());
+        self
+    }
+
+    /// Return previous terminated container logs. Defaults to false.
+    #[inline]
+    pub fn previous(mut self, value: impl Into<bool>) -> Self {
+        self.inner.param_previous = Some(value.into());
+        self
+    }
+
+    /// Sets [`previous`](#method.previous) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_previous(mut self, value: Option<impl Into<bool>>) -> Self {
+        self.inner.param_previous = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`previous`](#method.previous), but takes a reference.
+    #[inline]
+    pub fn previous_ref(self, value: &bool) -> Self {
+        self.previous(*value)
+    }
+
+    /// A relative time in seconds before the current time from which to show logs. If this value precedes the time a pod was started, only logs since the pod start will be returned. If this value is in the future, no logs will be returned. Only one of sinceSeconds or sinceTime may be specified.
+    #[inline]
+    pub fn since_seconds(mut self, value: impl Into<i64>) -> Self {
+        self.inner.param_since_seconds = Some(value.into());
+        self
+    }
+
+    /// Sets [`since_seconds`](#method.since_seconds) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_since_seconds(mut self, value: Option<impl Into<i64>>) -> Self {
+        self.inner.param_since_seconds = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`since_seconds`](#method.since_seconds), but takes a reference.
+    #[inline]
+    pub fn since_seconds_ref(self, value: &i64) -> Self {
+        self.since_seconds(*value)
+    }
+
+    /// If set, the number of lines from the end of the logs to show. If not specified, logs are shown from the creation of the container or sinceSeconds or sinceTime
+    #[inline]
+    pub fn tail_lines(mut self, value: impl Into<i64>) -> Self {
+        self.inner.param_tail_lines = Some(value.into());
+        self
+    }
+
+    /// Sets [`tail_lines`](#method.tail_lines) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_tail_lines(mut self, value: Option<impl Into<i64>>) -> Self {
+        self.inner.param_tail_lines = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`tail_lines`](#method.tail_lines), but takes a reference.
+    #[inline]
+    pub fn tail_lines_ref(self, value: &i64) -> Self {
+        self.tail_lines(*value)
+    }
+
+    /// If true, add an RFC3339 or RFC3339Nano timestamp at the beginning of every line of log output. Defaults to false.
+    #[inline]
+    pub fn timestamps(mut self, value: impl Into<bool>) -> Self {
+        self.inner.param_timestamps = Some(value.into());
+        self
+    }
+
+    /// Sets [`timestamps`](#method.timestamps) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_timestamps(mut self, value: Option<impl Into<bool>>) -> Self {
+        self.inner.param_timestamps = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`timestamps`](#method.timestamps), but takes a reference.
+    #[inline]
+    pub fn timestamps_ref(self, value: &bool) -> Self {
+        self.timestamps(*value)
+    }
+}
+
+impl<Client: crate::codegen::client::ApiClient + Sync + 'static> crate::codegen::client::Sendable<Client> for MiscellaneousGetBuilder2<crate::codegen::generics::NameExists, crate::codegen::generics::NamespaceExists> {
+    type Output = String;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    const OPERATION_ID: Option<&'static str> = Some("readCoreV1NamespacedPodLog");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::codegen::client::ApiError<Client::Response>> {
+        Ok(format!("/api/v1/namespaces/{namespace}/pods/{name}/log", name=crate::codegen::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?, namespace=crate::codegen::client::path_param("namespace", self.inner.param_namespace.as_ref().expect("missing parameter namespace?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::codegen::client::ApiError<Client::Response>> {
+        use crate::codegen::client::Request;
+        Ok(req
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("container", self.inner.param_container.as_ref().map(std::string::ToString::to_string)),
+            ("follow", self.inner.param_follow.as_ref().map(std::string::ToString::to_string)),
+            ("limitBytes", self.inner.param_limit_bytes.as_ref().map(std::string::ToString::to_string)),
+            ("pretty", self.inner.param_pretty.as_ref().map(std::string::ToString::to_string)),
+            ("previous", self.inner.param_previous.as_ref().map(std::string::ToString::to_string)),
+            ("sinceSeconds", self.inner.param_since_seconds.as_ref().map(std::string::ToString::to_string)),
+            ("tailLines", self.inner.param_tail_lines.as_ref().map(std::string::ToString::to_string)),
+            ("timestamps", self.inner.param_timestamps.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<MiscellaneousGetBuilder2<crate::codegen::generics::NameExists, crate::codegen::generics::NamespaceExists>> for reqwest::Request {
+    type Error = crate::codegen::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: MiscellaneousGetBuilder2<crate::codegen::generics::NameExists, crate::codegen::generics::NamespaceExists>) -> Result<Self, Self::Error> {
+        let req = crate::codegen::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::codegen::client::ApiError::Reqwest)
+    }
+}
+
+/// Builder shared by operations (with the same fields and parameters) associated with `Miscellaneous`.
+#[repr(transparent)]
+#[derive(Debug, Clone)]
+pub struct MiscellaneousOpBuilder2<Op, Name, Namespace> {
+    inner: MiscellaneousOpBuilder2Container,
+    _op: core::marker::PhantomData<Op>,
+    _param_name: core::marker::PhantomData<Name>,
+    _param_namespace: core::marker::PhantomData<Namespace>,
+}
+
+#[derive(Debug, Default, Clone)]
+struct MiscellaneousOpBuilder2Container {
+    param_name: Option<String>,
+    param_namespace: Option<String>,
+    param_ports: Option<i64>,
+}
+
+/// Builder created by [`Miscellaneous::connect_core_v1_get_namespaced_pod_portforward`](./struct.Miscellaneous.html#method.connect_core_v1_get_namespaced_pod_portforward) method for a `GET` operation associated with `Miscellaneous`.
+pub type MiscellaneousGetBuilder3<Name, Namespace> = MiscellaneousOpBuilder2<MiscellaneousGetOp3, Name, Namespace>;
+
+/// Marks the operation of [`MiscellaneousGetBuilder3`] in the shared builder.
+#[derive(Debug, Clone)]
+pub enum MiscellaneousGetOp3 {}
+
+impl<Op, Name, Namespace> MiscellaneousOpBuilder2<Op, Name, Namespace> {
+    /// name of the PodPortForwardOptions
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> MiscellaneousOpBuilder2<Op, crate::codegen::generics::NameExists, Namespace> {
+        self.inner.param_name = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// object name and auth scope, such as for teams and projects
+    #[inline]
+    pub fn namespace(mut self, value: impl Into<String>) -> MiscellaneousOpBuilder2<Op, Name, crate::codegen::generics::NamespaceExists> {
+        self.inner.param_namespace = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// List of ports to forward Required when using WebSockets
+    #[inline]
+    pub fn ports(mut self, value: impl Into<i64>) -> Self {
+        self.inner.param_ports = Some(value.into());
+        self
+    }
+
+    /// Sets [`ports`](#method.ports) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_ports(mut self, value: Option<impl Into<i64>>) -> Self {
+        self.inner.param_ports = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`ports`](#method.ports), but takes a reference.
+    #[inline]
+    pub fn ports_ref(self, value: &i64) -> Self {
+        self.ports(*value)
+    }
+}
+
+impl<Client: crate::codegen::client::ApiClient + Sync + 'static> crate::codegen::client::Sendable<Client> for MiscellaneousGetBuilder3<crate::codegen::generics::NameExists, crate::codegen::generics::NamespaceExists> {
+    type Output = String;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    const OPERATION_ID: Option<&'static str> = Some("connectCoreV1GetNamespacedPodPortforward");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::codegen::client::ApiError<Client::Response>> {
+        Ok(format!("/api/v1/namespaces/{namespace}/pods/{name}/portforward", name=crate::codegen::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?, namespace=crate::codegen::client::path_param("namespace", self.inner.param_namespace.as_ref().expect("missing parameter namespace?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::codegen::client::ApiError<Client::Response>> {
+        use crate::codegen::client::Request;
+        Ok(req
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("ports", self.inner.param_ports.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<MiscellaneousGetBuilder3<crate::codegen::generics::NameExists, crate::codegen::generics::NamespaceExists>> for reqwest::Request {
+    type Error = crate::codegen::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: MiscellaneousGetBuilder3<crate::codegen::generics::NameExists, crate::codegen::generics::NamespaceExists>) -> Result<Self, Self::Error> {
+        let req = crate::codegen::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::codegen::client::ApiError::Reqwest)
+    }
+}
+
+/// Builder created by [`Miscellaneous::connect_core_v1_post_namespaced_pod_portforward`](./struct.Miscellaneous.html#method.connect_core_v1_post_namespaced_pod_portforward) method for a `POST` operation associated with `Miscellaneous`.
+pub type MiscellaneousPostBuilder3<Name, Namespace> = MiscellaneousOpBuilder2<MiscellaneousPostOp3, Name, Namespace>;
+
+/// Marks the operation of [`MiscellaneousPostBuilder3`] in the shared builder.
+#[derive(Debug, Clone)]
+pub enum MiscellaneousPostOp3 {}
+
+
+impl<Client: crate::codegen::client::ApiClient + Sync + 'static> crate::codegen::client::Sendable<Client> for MiscellaneousPostBuilder3<crate::codegen::generics::NameExists, crate::codegen::generics::NamespaceExists> {
+    type Output = String;
+
+    const METHOD: http::Method = http::Method::POST;
+
+    const OPERATION_ID: Option<&'static str> = Some("connectCoreV1PostNamespacedPodPortforward");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::codegen::client::ApiError<Client::Response>> {
+        Ok(format!("/api/v1/namespaces/{namespace}/pods/{name}/portforward", name=crate::codegen::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?, namespace=crate::codegen::client::path_param("namespace", self.inner.param_namespace.as_ref().expect("missing parameter namespace?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::codegen::client::ApiError<Client::Response>> {
+        use crate::codegen::client::Request;
+        Ok(req
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("ports", self.inner.param_ports.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<MiscellaneousPostBuilder3<crate::codegen::generics::NameExists, crate::codegen::generics::NamespaceExists>> for reqwest::Request {
+    type Error = crate::codegen::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: MiscellaneousPostBuilder3<crate::codegen::generics::NameExists, crate::codegen::generics::NamespaceExists>) -> Result<Self, Self::Error> {
+        let req = crate::codegen::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::codegen::client::ApiError::Reqwest)
+    }
+}
+
+/// Builder shared by operations (with the same fields and parameters) associated with `Miscellaneous`.
+#[repr(transparent)]
+#[derive(Debug, Clone)]
+pub struct MiscellaneousOpBuilder3<Op, Name, Namespace> {
+    inner: MiscellaneousOpBuilder3Container,
+    _op: core::marker::PhantomData<Op>,
+    _param_name: core::marker::PhantomData<Name>,
+    _param_namespace: core::marker::PhantomData<Namespace>,
+}
+
+#[derive(Debug, Default, Clone)]
+struct MiscellaneousOpBuilder3Container {
+    param_name: Option<String>,
+    param_namespace: Option<String>,
+    param_path: Option<String>,
+}
+
+/// Builder created by [`Miscellaneous::connect_core_v1_get_namespaced_pod_proxy`](./struct.Miscellaneous.html#method.connect_core_v1_get_namespaced_pod_proxy) method for a `GET` operation associated with `Miscellaneous`.
+pub type MiscellaneousGetBuilder4<Name, Namespace> = MiscellaneousOpBuilder3<MiscellaneousGetOp4, Name, Namespace>;
+
+/// Marks the operation of [`MiscellaneousGetBuilder4`] in the shared builder.
+#[derive(Debug, Clone)]
+pub enum MiscellaneousGetOp4 {}
+
+impl<Op, Name, Namespace> MiscellaneousOpBuilder3<Op, Name, Namespace> {
+    /// name of the PodProxyOptions
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> MiscellaneousOpBuilder3<Op, crate::codegen::generics::NameExists, Namespace> {
+        self.inner.param_name = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// object name and auth scope, such as for teams and projects
+    #[inline]
+    pub fn namespace(mut self, value: impl Into<String>) -> MiscellaneousOpBuilder3<Op, Name, crate::codegen::generics::NamespaceExists> {
+        self.inner.param_namespace = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Path is the URL path to use for the current proxy request to pod.
+    #[inline]
+    pub fn path(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_path = Some(value.into());
+        self
+    }
+
+    /// Sets [`path`](#method.path) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_path(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_path = value.map(|value| value.into());
+        self
+    }
+}
+
+impl<Client: crate::codegen::client::ApiClient + Sync + 'static> crate::codegen::client::Sendable<Client> for MiscellaneousGetBuilder4<crate::codegen::generics::NameExists, crate::codegen::generics::NamespaceExists> {
+    type Output = String;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    const OPERATION_ID: Option<&'static str> = Some("connectCoreV1GetNamespacedPodProxy");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::codegen::client::ApiError<Client::Response>> {
+        Ok(format!("/api/v1/namespaces/{namespace}/pods/{name}/proxy", name=crate::codegen::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?, namespace=crate::codegen::client::path_param("namespace", self.inner.param_namespace.as_ref().expect("missing parameter namespace?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::codegen::client::ApiError<Client::Response>> {
+        use crate::codegen::client::Request;
+        Ok(req
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("path", self.inner.param_path.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<MiscellaneousGetBuilder4<crate::codegen::generics::NameExists, crate::codegen::generics::NamespaceExists>> for reqwest::Request {
+    type Error = crate::codegen::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: MiscellaneousGetBuilder4<crate::codegen::generics::NameExists, crate::codegen::generics::NamespaceExists>) -> Result<Self, Self::Error> {
+        let req = crate::codegen::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::codegen::client::ApiError::Reqwest)
+    }
+}
+
+/// Builder created by [`Miscellaneous::connect_core_v1_put_namespaced_pod_proxy`](./struct.Miscellaneous.html#method.connect_core_v1_put_namespaced_pod_proxy) method for a `PUT` operation associated with `Miscellaneous`.
+pub type MiscellaneousPutBuilder4<Name, Namespace> = MiscellaneousOpBuilder3<MiscellaneousPutOp4, Name, Namespace>;
+
+/// Marks the operation of [`MiscellaneousPutBuilder4`] in the shared builder.
+#[derive(Debug, Clone)]
+pub enum MiscellaneousPutOp4 {}
+
+
+impl<Client: crate::codegen::client::ApiClient + Sync + 'static> crate::codegen::client::Sendable<Client> for MiscellaneousPutBuilder4<crate::codegen::generics::NameExists, crate::codegen::generics::NamespaceExists> {
+    type Output = String;
+
+    const METHOD: http::Method = http::Method::PUT;
+
+    const OPERATION_ID: Option<&'static str> = Some("connectCoreV1PutNamespacedPodProxy");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::codegen::client::ApiError<Client::Response>> {
+        Ok(format!("/api/v1/namespaces/{namespace}/pods/{name}/proxy", name=crate::codegen::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?, namespace=crate::codegen::client::path_param("namespace", self.inner.param_namespace.as_ref().expect("missing parameter namespace?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::codegen::client::ApiError<Client::Response>> {
+        use crate::codegen::client::Request;
+        Ok(req
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("path", self.inner.param_path.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<MiscellaneousPutBuilder4<crate::codegen::generics::NameExists, crate::codegen::generics::NamespaceExists>> for reqwest::Request {
+    type Error = crate::codegen::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: MiscellaneousPutBuilder4<crate::codegen::generics::NameExists, crate::codegen::generics::NamespaceExists>) -> Result<Self, Self::Error> {
+        let req = crate::codegen::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::codegen::client::ApiError::Reqwest)
+    }
+}
+
+/// Builder created by [`Miscellaneous::connect_core_v1_post_namespaced_pod_proxy`](./struct.Miscellaneous.html#method.connect_core_v1_post_namespaced_pod_proxy) method for a `POST` operation associated with `Miscellaneous`.
+pub type MiscellaneousPostBuilder4<Name, Namespace> = MiscellaneousOpBuilder3<MiscellaneousPostOp4, Name, Namespace>;
+
+/// Marks the operation of [`MiscellaneousPostBuilder4`] in the shared builder.
+#[derive(Debug, Clone)]
+pub enum MiscellaneousPostOp4 {}
+
+
+impl<Client: crate::codegen::client::ApiClient + Sync + 'static> crate::codegen::client::Sendable<Client> for MiscellaneousPostBuilder4<crate::codegen::generics::NameExists, crate::codegen::generics::NamespaceExists> {
+    type Output = String;
+
+    const METHOD: http::Method = http::Method::POST;
+
+    const OPERATION_ID: Option<&'static str> = Some("connectCoreV1PostNamespacedPodProxy");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::codegen::client::ApiError<Client::Response>> {
+        Ok(format!("/api/v1/namespaces/{namespace}/pods/{name}/proxy", name=crate::codegen::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?, namespace=crate::codegen::client::path_param("namespace", self.inner.param_namespace.as_ref().expect("missing parameter namespace?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::codegen::client::ApiError<Client::Response>> {
+        use crate::codegen::client::Request;
+        Ok(req
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("path", self.inner.param_path.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<MiscellaneousPostBuilder4<crate::codegen::generics::NameExists, crate::codegen::generics::NamespaceExists>> for reqwest::Request {
+    type Error = crate::codegen::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: MiscellaneousPostBuilder4<crate::codegen::generics::NameExists, crate::codegen::generics::NamespaceExists>) -> Result<Self, Self::Error> {
+        let req = crate::codegen::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::codegen::client::ApiError::Reqwest)
+    }
+}
+
+/// Builder created by [`Miscellaneous::connect_core_v1_delete_namespaced_pod_proxy`](./struct.Miscellaneous.html#method.connect_core_v1_delete_namespaced_pod_proxy) method for a `DELETE` operation associated with `Miscellaneous`.
+pub type MiscellaneousDeleteBuilder4<Name, Namespace> = MiscellaneousOpBuilder3<MiscellaneousDeleteOp4, Name, Namespace>;
+
+/// Marks the operation of [`MiscellaneousDeleteBuilder4`] in the shared builder.
+#[derive(Debug, Clone)]
+pub enum MiscellaneousDeleteOp4 {}
+
+
+impl<Client: crate::codegen::client::ApiClient + Sync + 'static> crate::codegen::client::Sendable<Client> for MiscellaneousDeleteBuilder4<crate::codegen::generics::NameExists, crate::codegen::generics::NamespaceExists> {
+    type Output = String;
+
+    const METHOD: http::Method = http::Method::DELETE;
+
+    const OPERATION_ID: Option<&'static str> = Some("connectCoreV1DeleteNamespacedPodProxy");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::codegen::client::ApiError<Client::Response>> {
+        Ok(format!("/api/v1/namespaces/{namespace}/pods/{name}/proxy", name=crate::codegen::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?, namespace=crate::codegen::client::path_param("namespace", self.inner.param_namespace.as_ref().expect("missing parameter namespace?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::codegen::client::ApiError<Client::Response>> {
+        use crate::codegen::client::Request;
+        Ok(req
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("path", self.inner.param_path.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<MiscellaneousDeleteBuilder4<crate::codegen::generics::NameExists, crate::codegen::generics::NamespaceExists>> for reqwest::Request {
+    type Error = crate::codegen::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: MiscellaneousDeleteBuilder4<crate::codegen::generics::NameExists, crate::codegen::generics::NamespaceExists>) -> Result<Self, Self::Error> {
+        let req = crate::codegen::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::codegen::client::ApiError::Reqwest)
+    }
+}
+
+/// Builder created by [`Miscellaneous::connect_core_v1_options_namespaced_pod_proxy`](./struct.Miscellaneous.html#method.connect_core_v1_options_namespaced_pod_proxy) method for a `OPTIONS` operation associated with `Miscellaneous`.
+pub type MiscellaneousOptionsBuilder4<Name, Namespace> = MiscellaneousOpBuilder3<MiscellaneousOptionsOp4, Name, Namespace>;
+
+/// Marks the operation of [`MiscellaneousOptionsBuilder4`] in the shared builder.
+#[derive(Debug, Clone)]
+pub enum MiscellaneousOptionsOp4 {}
+
+
+impl<Client: crate::codegen::client::ApiClient + Sync + 'static> crate::codegen::client::Sendable<Client> for MiscellaneousOptionsBuilder4<crate::codegen::generics::NameExists, crate::codegen::generics::NamespaceExists> {
+    type Output = String;
+
+    const METHOD: http::Method = http::Method::OPTIONS;
+
+    const OPERATION_ID: Option<&'static str> = Some("connectCoreV1OptionsNamespacedPodProxy");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::codegen::client::ApiError<Client::Response>> {
+        Ok(format!("/api/v1/namespaces/{namespace}/pods/{name}/proxy", name=crate::codegen::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?, namespace=crate::codegen::client::path_param("namespace", self.inner.param_namespace.as_ref().expect("missing parameter namespace?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::codegen::client::ApiError<Client::Response>> {
+        use crate::codegen::client::Request;
+        Ok(req
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("path", self.inner.param_path.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<MiscellaneousOptionsBuilder4<crate::codegen::generics::NameExists, crate::codegen::generics::NamespaceExists>> for reqwest::Request {
+    type Error = crate::codegen::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: MiscellaneousOptionsBuilder4<crate::codegen::generics::NameExists, crate::codegen::generics::NamespaceExists>) -> Result<Self, Self::Error> {
+        let req = crate::codegen::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::codegen::client::ApiError::Reqwest)
+    }
+}
+
+/// Builder created by [`Miscellaneous::connect_core_v1_head_namespaced_pod_proxy`](./struct.Miscellaneous.html#method.connect_core_v1_head_namespaced_pod_proxy) method for a `HEAD` operation associated with `Miscellaneous`.
+pub type MiscellaneousHeadBuilder4<Name, Namespace> = MiscellaneousOpBuilder3<MiscellaneousHeadOp4, Name, Namespace>;
+
+/// Marks the operation of [`MiscellaneousHeadBuilder4`] in the shared builder.
+#[derive(Debug, Clone)]
+pub enum MiscellaneousHeadOp4 {}
+
+
+impl<Client: crate::codegen::client::ApiClient + Sync + 'static> crate::codegen::client::Sendable<Client> for MiscellaneousHeadBuilder4<crate::codegen::generics::NameExists, crate::codegen::generics::NamespaceExists> {
+    type Output = String;
+
+    const METHOD: http::Method = http::Method::HEAD;
+
+    const OPERATION_ID: Option<&'static str> = Some("connectCoreV1HeadNamespacedPodProxy");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::codegen::client::ApiError<Client::Response>> {
+        Ok(format!("/api/v1/namespaces/{namespace}/pods/{name}/proxy", name=crate::codegen::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?, namespace=crate::codegen::client::path_param("namespace", self.inner.param_namespace.as_ref().expect("missing parameter namespace?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::codegen::client::ApiError<Client::Response>> {
+        use crate::codegen::client::Request;
+        Ok(req
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("path", self.inner.param_path.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<MiscellaneousHeadBuilder4<crate::codegen::generics::NameExists, crate::codegen::generics::NamespaceExists>> for reqwest::Request {
+    type Error = crate::codegen::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: MiscellaneousHeadBuilder4<crate::codegen::generics::NameExists, crate::codegen::generics::NamespaceExists>) -> Result<Self, Self::Error> {
+        let req = crate::codegen::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::codegen::client::ApiError::Reqwest)
+    }
+}
+
+/// Builder created by [`Miscellaneous::connect_core_v1_patch_namespaced_pod_proxy`](./struct.Miscellaneous.html#method.connect_core_v1_patch_namespaced_pod_proxy) method for a `PATCH` operation associated with `Miscellaneous`.
+pub type MiscellaneousPatchBuilder4<Name, Namespace> = MiscellaneousOpBuilder3<MiscellaneousPatchOp4, Name, Namespace>;
+
+/// Marks the operation of [`MiscellaneousPatchBuilder4`] in the shared builder.
+#[derive(Debug, Clone)]
+pub enum MiscellaneousPatchOp4 {}
+
+
+impl<Client: crate::codegen::client::ApiClient + Sync + 'static> crate::codegen::client::Sendable<Client> for MiscellaneousPatchBuilder4<crate::codegen::generics::NameExists, crate::codegen::generics::NamespaceExists> {
+    type Output = String;
+
+    const METHOD: http::Method = http::Method::PATCH;
+
+    const OPERATION_ID: Option<&'static str> = Some("connectCoreV1PatchNamespacedPodProxy");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::codegen::client::ApiError<Client::Response>> {
+        Ok(format!("/api/v1/namespaces/{namespace}/pods/{name}/proxy", name=crate::codegen::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?, namespace=crate::codegen::client::path_param("namespace", self.inner.param_namespace.as_ref().expect("missing parameter namespace?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::codegen::client::ApiError<Client::Response>> {
+        use crate::codegen::client::Request;
+        Ok(req
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("path", self.inner.param_path.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<MiscellaneousPatchBuilder4<crate::codegen::generics::NameExists, crate::codegen::generics::NamespaceExists>> for reqwest::Request {
+    type Error = crate::codegen::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: MiscellaneousPatchBuilder4<crate::codegen::generics::NameExists, crate::codegen::generics::NamespaceExists>) -> Result<Self, Self::Error> {
+        let req = crate::codegen::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::codegen::client::ApiError::Reqwest)
+    }
+}
+
+/// Builder shared by operations (with the same fields and parameters) associated with `Miscellaneous`.
+#[repr(transparent)]
+#[derive(Debug, Clone)]
+pub struct MiscellaneousOpBuilder4<Op, Name, Namespace, Path> {
+    inner: MiscellaneousOpBuilder4Container,
+    _op: core::marker::PhantomData<Op>,
+    _param_name: core::marker::PhantomData<Name>,
+    _param_namespace: core::marker::PhantomData<Namespace>,
+    _param_path: core::marker::PhantomData<Path>,
+}
+
+#[derive(Debug, Default, Clone)]
+struct MiscellaneousOpBuilder4Container {
+    param_name: Option<String>,
+    param_namespace: Option<String>,
+    param_path: Option<String>,
+}
+
+/// Builder created by [`Miscellaneous::connect_core_v1_get_namespaced_pod_proxy_with_path`](./struct.Miscellaneous.html#method.connect_core_v1_get_namespaced_pod_proxy_with_path) method for a `GET` operation associated with `Miscellaneous`.
+pub type MiscellaneousGetBuilder5<Name, Namespace, Path> = MiscellaneousOpBuilder4<MiscellaneousGetOp5, Name, Namespace, Path>;
+
+/// Marks the operation of [`MiscellaneousGetBuilder5`] in the shared builder.
+#[derive(Debug, Clone)]
+pub enum MiscellaneousGetOp5 {}
+
+impl<Op, Name, Namespace, Path> MiscellaneousOpBuilder4<Op, Name, Namespace, Path> {
+    /// name of the PodProxyOptions
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> MiscellaneousOpBuilder4<Op, crate::codegen::generics::NameExists, Namespace, Path> {
+        self.inner.param_name = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// object name and auth scope, such as for teams and projects
+    #[inline]
+    pub fn namespace(mut self, value: impl Into<String>) -> MiscellaneousOpBuilder4<Op, Name, crate::codegen::generics::NamespaceExists, Path> {
+        self.inner.param_namespace = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// path to the resource
+    #[inline]
+    pub fn path(mut self, value: impl Into<String>) -> MiscellaneousOpBuilder4<Op, Name, Namespace, crate::codegen::generics::PathExists> {
+        self.inner.param_path = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+}
+
+impl<Client: crate::codegen::client::ApiClient + Sync + 'static> crate::codegen::client::Sendable<Client> for MiscellaneousGetBuilder5<crate::codegen::generics::NameExists, crate::codegen::generics::NamespaceExists, crate::codegen::generics::PathExists> {
+    type Output = String;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    const OPERATION_ID: Option<&'static str> = Some("connectCoreV1GetNamespacedPodProxyWithPath");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::codegen::client::ApiError<Client::Response>> {
+        Ok(format!("/api/v1/namespaces/{namespace}/pods/{name}/proxy/{path}", name=crate::codegen::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?, namespace=crate::codegen::client::path_param("namespace", self.inner.param_namespace.as_ref().expect("missing parameter namespace?"))?, path=crate::codegen::client::path_param("path", self.inner.param_path.as_ref().expect("missing parameter path?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::codegen::client::ApiError<Client::Response>> {
+        use crate::codegen::client::Request;
+        Ok(req
+        .header(http::header::ACCEPT.as_str(), "application/json"))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<MiscellaneousGetBuilder5<crate::codegen::generics::NameExists, crate::codegen::generics::NamespaceExists, crate::codegen::generics::PathExists>> for reqwest::Request {
+    type Error = crate::codegen::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: MiscellaneousGetBuilder5<crate::codegen::generics::NameExists, crate::codegen::generics::NamespaceExists, crate::codegen::generics::PathExists>) -> Result<Self, Self::Error> {
+        let req = crate::codegen::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::codegen::client::ApiError::Reqwest)
+    }
+}
+
+/// Builder created by [`Miscellaneous::connect_core_v1_put_namespaced_pod_proxy_with_path`](./struct.Miscellaneous.html#method.connect_core_v1_put_namespaced_pod_proxy_with_path) method for a `PUT` operation associated with `Miscellaneous`.
+pub type MiscellaneousPutBuilder5<Name, Namespace, Path> = MiscellaneousOpBuilder4<MiscellaneousPutOp5, Name, Namespace, Path>;
+
+/// Marks the operation of [`MiscellaneousPutBuilder5`] in the shared builder.
+#[derive(Debug, Clone)]
+pub enum MiscellaneousPutOp5 {}
+
+
+impl<Client: crate::codegen::client::ApiClient + Sync + 'static> crate::codegen::client::Sendable<Client> for MiscellaneousPutBuilder5<crate::codegen::generics::NameExists, crate::codegen::generics::NamespaceExists, crate::codegen::generics::PathExists> {
+    type Output = String;
+
+    const METHOD: http::Method = http::Method::PUT;
+
+    const OPERATION_ID: Option<&'static str> = Some("connectCoreV1PutNamespacedPodProxyWithPath");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::codegen::client::ApiError<Client::Response>> {
+        Ok(format!("/api/v1/namespaces/{namespace}/pods/{name}/proxy/{path}", name=crate::codegen::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?, namespace=crate::codegen::client::path_param("namespace", self.inner.param_namespace.as_ref().expect("missing parameter namespace?"))?, path=crate::codegen::client::path_param("path", self.inner.param_path.as_ref().expect("missing parameter path?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::codegen::client::ApiError<Client::Response>> {
+        use crate::codegen::client::Request;
+        Ok(req
+        .header(http::header::ACCEPT.as_str(), "application/json"))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<MiscellaneousPutBuilder5<crate::codegen::generics::NameExists, crate::codegen::generics::NamespaceExists, crate::codegen::generics::PathExists>> for reqwest::Request {
+    type Error = crate::codegen::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: MiscellaneousPutBuilder5<crate::codegen::generics::NameExists, crate::codegen::generics::NamespaceExists, crate::codegen::generics::PathExists>) -> Result<Self, Self::Error> {
+        let req = crate::codegen::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::codegen::client::ApiError::Reqwest)
+    }
+}
+
+/// Builder created by [`Miscellaneous::connect_core_v1_post_namespaced_pod_proxy_with_path`](./struct.Miscellaneous.html#method.connect_core_v1_post_namespaced_pod_proxy_with_path) method for a `POST` operation associated with `Miscellaneous`.
+pub type MiscellaneousPostBuilder5<Name, Namespace, Path> = MiscellaneousOpBuilder4<MiscellaneousPostOp5, Name, Namespace, Path>;
+
+/// Marks the operation of [`MiscellaneousPostBuilder5`] in the shared builder.
+#[derive(Debug, Clone)]
+pub enum MiscellaneousPostOp5 {}
+
+
+impl<Client: crate::codegen::client::ApiClient + Sync + 'static> crate::codegen::client::Sendable<Client> for MiscellaneousPostBuilder5<crate::codegen::generics::NameExists, crate::codegen::generics::NamespaceExists, crate::codegen::generics::PathExists> {
+    type Output = String;
+
+    const METHOD: http::Method = http::Method::POST;
+
+    const OPERATION_ID: Option<&'static str> = Some("connectCoreV1PostNamespacedPodProxyWithPath");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::codegen::client::ApiError<Client::Response>> {
+        Ok(format!("/api/v1/namespaces/{namespace}/pods/{name}/proxy/{path}", name=crate::codegen::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?, namespace=crate::codegen::client::path_param("namespace", self.inner.param_namespace.as_ref().expect("missing parameter namespace?"))?, path=crate::codegen::client::path_param("path", self.inner.param_path.as_ref().expect("missing parameter path?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::codegen::client::ApiError<Client::Response>> {
+        use crate::codegen::client::Request;
+        Ok(req
+        .header(http::header::ACCEPT.as_str(), "application/json"))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<MiscellaneousPostBuilder5<crate::codegen::generics::NameExists, crate::codegen::generics::NamespaceExists, crate::codegen::generics::PathExists>> for reqwest::Request {
+    type Error = crate::codegen::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: MiscellaneousPostBuilder5<crate::codegen::generics::NameExists, crate::codegen::generics::NamespaceExists, crate::codegen::generics::PathExists>) -> Result<Self, Self::Error> {
+        let req = crate::codegen::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::codegen::client::ApiError::Reqwest)
+    }
+}
+
+/// Builder created by [`Miscellaneous::connect_core_v1_delete_namespaced_pod_proxy_with_path`](./struct.Miscellaneous.html#method.connect_core_v1_delete_namespaced_pod_proxy_with_path) method for a `DELETE` operation associated with `Miscellaneous`.
+pub type MiscellaneousDeleteBuilder5<Name, Namespace, Path> = MiscellaneousOpBuilder4<MiscellaneousDeleteOp5, Name, Namespace, Path>;
+
+/// Marks the operation of [`MiscellaneousDeleteBuilder5`] in the shared builder.
+#[derive(Debug, Clone)]
+pub enum MiscellaneousDeleteOp5 {}
+
+
+impl<Client: crate::codegen::client::ApiClient + Sync + 'static> crate::codegen::client::Sendable<Client> for MiscellaneousDeleteBuilder5<crate::codegen::generics::NameExists, crate::codegen::generics::NamespaceExists, crate::codegen::generics::PathExists> {
+    type Output = String;
+
+    const METHOD: http::Method = http::Method::DELETE;
+
+    const OPERATION_ID: Option<&'static str> = Some("connectCoreV1DeleteNamespacedPodProxyWithPath");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::codegen::client::ApiError<Client::Response>> {
+        Ok(format!("/api/v1/namespaces/{namespace}/pods/{name}/proxy/{path}", name=crate::codegen::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?, namespace=crate::codegen::client::path_param("namespace", self.inner.param_namespace.as_ref().expect("missing parameter namespace?"))?, path=crate::codegen::client::path_param("path", self.inner.param_path.as_ref().expect("missing parameter path?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::codegen::client::ApiError<Client::Response>> {
+        use crate::codegen::client::Request;
+        Ok(req
+        .header(http::header::ACCEPT.as_str(), "application/json"))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<MiscellaneousDeleteBuilder5<crate::codegen::generics::NameExists, crate::codegen::generics::NamespaceExists, crate::codegen::generics::PathExists>> for reqwest::Request {
+    type Error = crate::codegen::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: MiscellaneousDeleteBuilder5<crate::codegen::generics::NameExists, crate::codegen::generics::NamespaceExists, crate::codegen::generics::PathExists>) -> Result<Self, Self::Error> {
+        let req = crate::codegen::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::codegen::client::ApiError::Reqwest)
+    }
+}
+
+/// Builder created by [`Miscellaneous::connect_core_v1_options_namespaced_pod_proxy_with_path`](./struct.Miscellaneous.html#method.connect_core_v1_options_namespaced_pod_proxy_with_path) method for a `OPTIONS` operation associated with `Miscellaneous`.
+pub type MiscellaneousOptionsBuilder5<Name, Namespace, Path> = MiscellaneousOpBuilder4<MiscellaneousOptionsOp5, Name, Namespace, Path>;
+
+/// Marks the operation of [`MiscellaneousOptionsBuilder5`] in the shared builder.
+#[derive(Debug, Clone)]
+pub enum MiscellaneousOptionsOp5 {}
+
+
+impl<Client: crate::codegen::client::ApiClient + Sync + 'static> crate::codegen::client::Sendable<Client> for MiscellaneousOptionsBuilder5<crate::codegen::generics::NameExists, crate::codegen::generics::NamespaceExists, crate::codegen::generics::PathExists> {
+    type Output = String;
+
+    const METHOD: http::Method = http::Method::OPTIONS;
+
+    const OPERATION_ID: Option<&'static str> = Some("connectCoreV1OptionsNamespacedPodProxyWithPath");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::codegen::client::ApiError<Client::Response>> {
+        Ok(format!("/api/v1/namespaces/{namespace}/pods/{name}/proxy/{path}", name=crate::codegen::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?, namespace=crate::codegen::client::path_param("namespace", self.inner.param_namespace.as_ref().expect("missing parameter namespace?"))?, path=crate::codegen::client::path_param("path", self.inner.param_path.as_ref().expect("missing parameter path?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::codegen::client::ApiError<Client::Response>> {
+        use crate::codegen::client::Request;
+        Ok(req
+        .header(http::header::ACCEPT.as_str(), "application/json"))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<MiscellaneousOptionsBuilder5<crate::codegen::generics::NameExists, crate::codegen::generics::NamespaceExists, crate::codegen::generics::PathExists>> for reqwest::Request {
+    type Error = crate::codegen::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: MiscellaneousOptionsBuilder5<crate::codegen::generics::NameExists, crate::codegen::generics::NamespaceExists, crate::codegen::generics::PathExists>) -> Result<Self, Self::Error> {
+        let req = crate::codegen::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::codegen::client::ApiError::Reqwest)
+    }
+}
+
+/// Builder created by [`Miscellaneous::connect_core_v1_head_namespaced_pod_proxy_with_path`](./struct.Miscellaneous.html#method.connect_core_v1_head_namespaced_pod_proxy_with_path) method for a `HEAD` operation associated with `Miscellaneous`.
+pub type MiscellaneousHeadBuilder5<Name, Namespace, Path> = MiscellaneousOpBuilder4<MiscellaneousHeadOp5, Name, Namespace, Path>;
+
+/// Marks the operation of [`MiscellaneousHeadBuilder5`] in the shared builder.
+#[derive(Debug, Clone)]
+pub enum MiscellaneousHeadOp5 {}
+
+
+impl<Client: crate::codegen::client::ApiClient + Sync + 'static> crate::codegen::client::Sendable<Client> for MiscellaneousHeadBuilder5<crate::codegen::generics::NameExists, crate::codegen::generics::NamespaceExists, crate::codegen::generics::PathExists> {
+    type Output = String;
+
+    const METHOD: http::Method = http::Method::HEAD;
+
+    const OPERATION_ID: Option<&'static str> = Some("connectCoreV1HeadNamespacedPodProxyWithPath");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::codegen::client::ApiError<Client::Response>> {
+        Ok(format!("/api/v1/namespaces/{namespace}/pods/{name}/proxy/{path}", name=crate::codegen::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?, namespace=crate::codegen::client::path_param("namespace", self.inner.param_namespace.as_ref().expect("missing parameter namespace?"))?, path=crate::codegen::client::path_param("path", self.inner.param_path.as_ref().expect("missing parameter path?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::codegen::client::ApiError<Client::Response>> {
+        use crate::codegen::client::Request;
+        Ok(req
+        .header(http::header::ACCEPT.as_str(), "application/json"))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<MiscellaneousHeadBuilder5<crate::codegen::generics::NameExists, crate::codegen::generics::NamespaceExists, crate::codegen::generics::PathExists>> for reqwest::Request {
+    type Error = crate::codegen::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: MiscellaneousHeadBuilder5<crate::codegen::generics::NameExists, crate::codegen::generics::NamespaceExists, crate::codegen::generics::PathExists>) -> Result<Self, Self::Error> {
+        let req = crate::codegen::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::codegen::client::ApiError::Reqwest)
+    }
+}
+
+/// Builder created by [`Miscellaneous::connect_core_v1_patch_namespaced_pod_proxy_with_path`](./struct.Miscellaneous.html#method.connect_core_v1_patch_namespaced_pod_proxy_with_path) method for a `PATCH` operation associated with `Miscellaneous`.
+pub type MiscellaneousPatchBuilder5<Name, Namespace, Path> = MiscellaneousOpBuilder4<MiscellaneousPatchOp5, Name, Namespace, Path>;
+
+/// Marks the operation of [`MiscellaneousPatchBuilder5`] in the shared builder.
+#[derive(Debug, Clone)]
+pub enum MiscellaneousPatchOp5 {}
+
+
+impl<Client: crate::codegen::client::ApiClient + Sync + 'static> crate::codegen::client::Sendable<Client> for MiscellaneousPatchBuilder5<crate::codegen::generics::NameExists, crate::codegen::generics::NamespaceExists, crate::codegen::generics::PathExists> {
+    type Output = String;
+
+    const METHOD: http::Method = http::Method::PATCH;
+
+    const OPERATION_ID: Option<&'static str> = Some("connectCoreV1PatchNamespacedPodProxyWithPath");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::codegen::client::ApiError<Client::Response>> {
+        Ok(format!("/api/v1/namespaces/{namespace}/pods/{name}/proxy/{path}", name=crate::codegen::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?, namespace=crate::codegen::client::path_param("namespace", self.inner.param_namespace.as_ref().expect("missing parameter namespace?"))?, path=crate::codegen::client::path_param("path", self.inner.param_path.as_ref().expect("missing parameter path?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::codegen::client::ApiError<Client::Response>> {
+        use crate::codegen::client::Request;
+        Ok(req
+        .header(http::header::ACCEPT.as_str(), "application/json"))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<MiscellaneousPatchBuilder5<crate::codegen::generics::NameExists, crate::codegen::generics::NamespaceExists, crate::codegen::generics::PathExists>> for reqwest::Request {
+    type Error = crate::codegen::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: MiscellaneousPatchBuilder5<crate::codegen::generics::NameExists, crate::codegen::generics::NamespaceExists, crate::codegen::generics::PathExists>) -> Result<Self, Self::Error> {
+        let req = crate::codegen::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::codegen::client::ApiError::Reqwest)
+    }
+}
+
+/// Builder shared by operations (with the same fields and parameters) associated with `Miscellaneous`.
+#[repr(transparent)]
+#[derive(Debug, Clone)]
+pub struct MiscellaneousOpBuilder5<Op, Name, Namespace> {
+    inner: MiscellaneousOpBuilder5Container,
+    _op: core::marker::PhantomData<Op>,
+    _param_name: core::marker::PhantomData<Name>,
+    _param_namespace: core::marker::PhantomData<Namespace>,
+}
+
+#[derive(Debug, Default, Clone)]
+struct MiscellaneousOpBuilder5Container {
+    param_name: Option<String>,
+    param_namespace: Option<String>,
+    param_path: Option<String>,
+}
+
+/// Builder created by [`Miscellaneous::connect_core_v1_get_namespaced_service_proxy`](./struct.Miscellaneous.html#method.connect_core_v1_get_namespaced_service_proxy) method for a `GET` operation associated with `Miscellaneous`.
+pub type MiscellaneousGetBuilder6<Name, Namespace> = MiscellaneousOpBuilder5<MiscellaneousGetOp6, Name, Namespace>;
+
+/// Marks the operation of [`MiscellaneousGetBuilder6`] in the shared builder.
+#[derive(Debug, Clone)]
+pub enum MiscellaneousGetOp6 {}
+
+impl<Op, Name, Namespace> MiscellaneousOpBuilder5<Op, Name, Namespace> {
+    /// name of the ServiceProxyOptions
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> MiscellaneousOpBuilder5<Op, crate::codegen::generics::NameExists, Namespace> {
+        self.inner.param_name = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// object name and auth scope, such as for teams and projects
+    #[inline]
+    pub fn namespace(mut self, value: impl Into<String>) -> MiscellaneousOpBuilder5<Op, Name, crate::codegen::generics::NamespaceExists> {
+        self.inner.param_namespace = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Path is the part of URLs that include service endpoints, suffixes, and parameters to use for the current proxy request to service. For example, the whole request URL is http://localhost/api/v1/namespaces/kube-system/services/elasticsearch-logging/\_search?q=user:kimchy. Path is \_search?q=user:kimchy.
+    #[inline]
+    pub fn path(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_path = Some(value.into());
+        self
+    }
+
+    /// Sets [`path`](#method.path) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_path(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_path = value.map(|value| value.into());
+        self
+    }
+}
+
+impl<Client: crate::codegen::client::ApiClient + Sync + 'static> crate::codegen::client::Sendable<Client> for MiscellaneousGetBuilder6<crate::codegen::generics::NameExists, crate::codegen::generics::NamespaceExists> {
+    type Output = String;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    const OPERATION_ID: Option<&'static str> = Some("connectCoreV1GetNamespacedServiceProxy");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::codegen::client::ApiError<Client::Response>> {
+        Ok(format!("/api/v1/namespaces/{namespace}/services/{name}/proxy", name=crate::codegen::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?, namespace=crate::codegen::client::path_param("namespace", self.inner.param_namespace.as_ref().expect("missing parameter namespace?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::codegen::client::ApiError<Client::Response>> {
+        use crate::codegen::client::Request;
+        Ok(req
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("path", self.inner.param_path.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<MiscellaneousGetBuilder6<crate::codegen::generics::NameExists, crate::codegen::generics::NamespaceExists>> for reqwest::Request {
+    type Error = crate::codegen::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: MiscellaneousGetBuilder6<crate::codegen::generics::NameExists, crate::codegen::generics::NamespaceExists>) -> Result<Self, Self::Error> {
+        let req = crate::codegen::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::codegen::client::ApiError::Reqwest)
+    }
+}
+
+/// Builder created by [`Miscellaneous::connect_core_v1_put_namespaced_service_proxy`](./struct.Miscellaneous.html#method.connect_core_v1_put_namespaced_service_proxy) method for a `PUT` operation associated with `Miscellaneous`.
+pub type MiscellaneousPutBuilder6<Name, Namespace> = MiscellaneousOpBuilder5<MiscellaneousPutOp6, Name, Namespace>;
+
+/// Marks the operation of [`MiscellaneousPutBuilder6`] in the shared builder.
+#[derive(Debug, Clone)]
+pub enum MiscellaneousPutOp6 {}
+
+
+impl<Client: crate::codegen::client::ApiClient + Sync + 'static> crate::codegen::client::Sendable<Client> for MiscellaneousPutBuilder6<crate::codegen::generics::NameExists, crate::codegen::generics::NamespaceExists> {
+    type Output = String;
+
+    const METHOD: http::Method = http::Method::PUT;
+
+    const OPERATION_ID: Option<&'static str> = Some("connectCoreV1PutNamespacedServiceProxy");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::codegen::client::ApiError<Client::Response>> {
+        Ok(format!("/api/v1/namespaces/{namespace}/services/{name}/proxy", name=crate::codegen::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?, namespace=crate::codegen::client::path_param("namespace", self.inner.param_namespace.as_ref().expect("missing parameter namespace?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::codegen::client::ApiError<Client::Response>> {
+        use crate::codegen::client::Request;
+        Ok(req
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("path", self.inner.param_path.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<MiscellaneousPutBuilder6<crate::codegen::generics::NameExists, crate::codegen::generics::NamespaceExists>> for reqwest::Request {
+    type Error = crate::codegen::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: MiscellaneousPutBuilder6<crate::codegen::generics::NameExists, crate::codegen::generics::NamespaceExists>) -> Result<Self, Self::Error> {
+        let req = crate::codegen::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::codegen::client::ApiError::Reqwest)
+    }
+}
+
+/// Builder created by [`Miscellaneous::connect_core_v1_post_namespaced_service_proxy`](./struct.Miscellaneous.html#method.connect_core_v1_post_namespaced_service_proxy) method for a `POST` operation associated with `Miscellaneous`.
+pub type MiscellaneousPostBuilder6<Name, Namespace> = MiscellaneousOpBuilder5<MiscellaneousPostOp6, Name, Namespace>;
+
+/// Marks the operation of [`MiscellaneousPostBuilder6`] in the shared builder.
+#[derive(Debug, Clone)]
+pub enum MiscellaneousPostOp6 {}
+
+
+impl<Client: crate::codegen::client::ApiClient + Sync + 'static> crate::codegen::client::Sendable<Client> for MiscellaneousPostBuilder6<crate::codegen::generics::NameExists, crate::codegen::generics::NamespaceExists> {
+    type Output = String;
+
+    const METHOD: http::Method = http::Method::POST;
+
+    const OPERATION_ID: Option<&'static str> = Some("connectCoreV1PostNamespacedServiceProxy");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::codegen::client::ApiError<Client::Response>> {
+        Ok(format!("/api/v1/namespaces/{namespace}/services/{name}/proxy", name=crate::codegen::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?, namespace=crate::codegen::client::path_param("namespace", self.inner.param_namespace.as_ref().expect("missing parameter namespace?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::codegen::client::ApiError<Client::Response>> {
+        use crate::codegen::client::Request;
+        Ok(req
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("path", self.inner.param_path.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<MiscellaneousPostBuilder6<crate::codegen::generics::NameExists, crate::codegen::generics::NamespaceExists>> for reqwest::Request {
+    type Error = crate::codegen::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: MiscellaneousPostBuilder6<crate::codegen::generics::NameExists, crate::codegen::generics::NamespaceExists>) -> Result<Self, Self::Error> {
+        let req = crate::codegen::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::codegen::client::ApiError::Reqwest)
+    }
+}
+
+/// Builder created by [`Miscellaneous::connect_core_v1_delete_namespaced_service_proxy`](./struct.Miscellaneous.html#method.connect_core_v1_delete_namespaced_service_proxy) method for a `DELETE` operation associated with `Miscellaneous`.
+pub type MiscellaneousDeleteBuilder6<Name, Namespace> = MiscellaneousOpBuilder5<MiscellaneousDeleteOp6, Name, Namespace>;
+
+/// Marks the operation of [`MiscellaneousDeleteBuilder6`] in the shared builder.
+#[derive(Debug, Clone)]
+pub enum MiscellaneousDeleteOp6 {}
+
+
+impl<Client: crate::codegen::client::ApiClient + Sync + 'static> crate::codegen::client::Sendable<Client> for MiscellaneousDeleteBuilder6<crate::codegen::generics::NameExists, crate::codegen::generics::NamespaceExists> {
+    type Output = String;
+
+    const METHOD: http::Method = http::Method::DELETE;
+
+    const OPERATION_ID: Option<&'static str> = Some("connectCoreV1DeleteNamespacedServiceProxy");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::codegen::client::ApiError<Client::Response>> {
+        Ok(format!("/api/v1/namespaces/{namespace}/services/{name}/proxy", name=crate::codegen::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?, namespace=crate::codegen::client::path_param("namespace", self.inner.param_namespace.as_ref().expect("missing parameter namespace?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::codegen::client::ApiError<Client::Response>> {
+        use crate::codegen::client::Request;
+        Ok(req
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("path", self.inner.param_path.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<MiscellaneousDeleteBuilder6<crate::codegen::generics::NameExists, crate::codegen::generics::NamespaceExists>> for reqwest::Request {
+    type Error = crate::codegen::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: MiscellaneousDeleteBuilder6<crate::codegen::generics::NameExists, crate::codegen::generics::NamespaceExists>) -> Result<Self, Self::Error> {
+        let req = crate::codegen::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::codegen::client::ApiError::Reqwest)
+    }
+}
+
+/// Builder created by [`Miscellaneous::connect_core_v1_options_namespaced_service_proxy`](./struct.Miscellaneous.html#method.connect_core_v1_options_namespaced_service_proxy) method for a `OPTIONS` operation associated with `Miscellaneous`.
+pub type MiscellaneousOptionsBuilder6<Name, Namespace> = MiscellaneousOpBuilder5<MiscellaneousOptionsOp6, Name, Namespace>;
+
+/// Marks the operation of [`MiscellaneousOptionsBuilder6`] in the shared builder.
+#[derive(Debug, Clone)]
+pub enum MiscellaneousOptionsOp6 {}
+
+
+impl<Client: crate::codegen::client::ApiClient + Sync + 'static> crate::codegen::client::Sendable<Client> for MiscellaneousOptionsBuilder6<crate::codegen::generics::NameExists, crate::codegen::generics::NamespaceExists> {
+    type Output = String;
+
+    const METHOD: http::Method = http::Method::OPTIONS;
+
+    const OPERATION_ID: Option<&'static str> = Some("connectCoreV1OptionsNamespacedServiceProxy");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::codegen::client::ApiError<Client::Response>> {
+        Ok(format!("/api/v1/namespaces/{namespace}/services/{name}/proxy", name=crate::codegen::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?, namespace=crate::codegen::client::path_param("namespace", self.inner.param_namespace.as_ref().expect("missing parameter namespace?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::codegen::client::ApiError<Client::Response>> {
+        use crate::codegen::client::Request;
+        Ok(req
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("path", self.inner.param_path.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<MiscellaneousOptionsBuilder6<crate::codegen::generics::NameExists, crate::codegen::generics::NamespaceExists>> for reqwest::Request {
+    type Error = crate::codegen::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: MiscellaneousOptionsBuilder6<crate::codegen::generics::NameExists, crate::codegen::generics::NamespaceExists>) -> Result<Self, Self::Error> {
+        let req = crate::codegen::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::codegen::client::ApiError::Reqwest)
+    }
+}
+
+/// Builder created by [`Miscellaneous::connect_core_v1_head_namespaced_service_proxy`](./struct.Miscellaneous.html#method.connect_core_v1_head_namespaced_service_proxy) method for a `HEAD` operation associated with `Miscellaneous`.
+pub type MiscellaneousHeadBuilder6<Name, Namespace> = MiscellaneousOpBuilder5<MiscellaneousHeadOp6, Name, Namespace>;
+
+/// Marks the operation of [`MiscellaneousHeadBuilder6`] in the shared builder.
+#[derive(Debug, Clone)]
+pub enum MiscellaneousHeadOp6 {}
+
+
+impl<Client: crate::codegen::client::ApiClient + Sync + 'static> crate::codegen::client::Sendable<Client> for MiscellaneousHeadBuilder6<crate::codegen::generics::NameExists, crate::codegen::generics::NamespaceExists> {
+    type Output = String;
+
+    const METHOD: http::Method = http::Method::HEAD;
+
+    const OPERATION_ID: Option<&'static str> = Some("connectCoreV1HeadNamespacedServiceProxy");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::codegen::client::ApiError<Client::Response>> {
+        Ok(format!("/api/v1/namespaces/{namespace}/services/{name}/proxy", name=crate::codegen::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?, namespace=crate::codegen::client::path_param("namespace", self.inner.param_namespace.as_ref().expect("missing parameter namespace?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::codegen::client::ApiError<Client::Response>> {
+        use crate::codegen::client::Request;
+        Ok(req
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("path", self.inner.param_path.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<MiscellaneousHeadBuilder6<crate::codegen::generics::NameExists, crate::codegen::generics::NamespaceExists>> for reqwest::Request {
+    type Error = crate::codegen::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: MiscellaneousHeadBuilder6<crate::codegen::generics::NameExists, crate::codegen::generics::NamespaceExists>) -> Result<Self, Self::Error> {
+        let req = crate::codegen::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::codegen::client::ApiError::Reqwest)
+    }
+}
+
+/// Builder created by [`Miscellaneous::connect_core_v1_patch_namespaced_service_proxy`](./struct.Miscellaneous.html#method.connect_core_v1_patch_namespaced_service_proxy) method for a `PATCH` operation associated with `Miscellaneous`.
+pub type MiscellaneousPatchBuilder6<Name, Namespace> = MiscellaneousOpBuilder5<MiscellaneousPatchOp6, Name, Namespace>;
+
+/// Marks the operation of [`MiscellaneousPatchBuilder6`] in the shared builder.
+#[derive(Debug, Clone)]
+pub enum MiscellaneousPatchOp6 {}
+
+
+impl<Client: crate::codegen::client::ApiClient + Sync + 'static> crate::codegen::client::Sendable<Client> for MiscellaneousPatchBuilder6<crate::codegen::generics::NameExists, crate::codegen::generics::NamespaceExists> {
+    type Output = String;
+
+    const METHOD: http::Method = http::Method::PATCH;
+
+    const OPERATION_ID: Option<&'static str> = Some("connectCoreV1PatchNamespacedServiceProxy");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::codegen::client::ApiError<Client::Response>> {
+        Ok(format!("/api/v1/namespaces/{namespace}/services/{name}/proxy", name=crate::codegen::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?, namespace=crate::codegen::client::path_param("namespace", self.inner.param_namespace.as_ref().expect("missing parameter namespace?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::codegen::client::ApiError<Client::Response>> {
+        use crate::codegen::client::Request;
+        Ok(req
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("path", self.inner.param_path.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<MiscellaneousPatchBuilder6<crate::codegen::generics::NameExists, crate::codegen::generics::NamespaceExists>> for reqwest::Request {
+    type Error = crate::codegen::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: MiscellaneousPatchBuilder6<crate::codegen::generics::NameExists, crate::codegen::generics::NamespaceExists>) -> Result<Self, Self::Error> {
+        let req = crate::codegen::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::codegen::client::ApiError::Reqwest)
+    }
+}
+
+/// Builder shared by operations (with the same fields and parameters) associated with `Miscellaneous`.
+#[repr(transparent)]
+#[derive(Debug, Clone)]
+pub struct MiscellaneousOpBuilder6<Op, Name, Namespace, Path> {
+    inner: MiscellaneousOpBuilder6Container,
+    _op: core::marker::PhantomData<Op>,
+    _param_name: core::marker::PhantomData<Name>,
+    _param_namespace: core::marker::PhantomData<Namespace>,
+    _param_path: core::marker::PhantomData<Path>,
+}
+
+#[derive(Debug, Default, Clone)]
+struct MiscellaneousOpBuilder6Container {
+    param_name: Option<String>,
+    param_namespace: Option<String>,
+    param_path: Option<String>,
+}
+
+/// Builder created by [`Miscellaneous::connect_core_v1_get_namespaced_service_proxy_with_path`](./struct.Miscellaneous.html#method.connect_core_v1_get_namespaced_service_proxy_with_path) method for a `GET` operation associated with `Miscellaneous`.
+pub type MiscellaneousGetBuilder7<Name, Namespace, Path> = MiscellaneousOpBuilder6<MiscellaneousGetOp7, Name, Namespace, Path>;
+
+/// Marks the operation of [`MiscellaneousGetBuilder7`] in the shared builder.
+#[derive(Debug, Clone)]
+pub enum MiscellaneousGetOp7 {}
+
+impl<Op, Name, Namespace, Path> MiscellaneousOpBuilder6<Op, Name, Namespace, Path> {
+    /// name of the ServiceProxyOptions
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> MiscellaneousOpBuilder6<Op, crate::codegen::generics::NameExists, Namespace, Path> {
+        self.inner.param_name = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// object name and auth scope, such as for teams and projects
+    #[inline]
+    pub fn namespace(mut self, value: impl Into<String>) -> MiscellaneousOpBuilder6<Op, Name, crate::codegen::generics::NamespaceExists, Path> {
+        self.inner.param_namespace = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// path to the resource
+    #[inline]
+    pub fn path(mut self, value: impl Into<String>) -> MiscellaneousOpBuilder6<Op, Name, Namespace, crate::codegen::generics::PathExists> {
+        self.inner.param_path = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+}
+
+impl<Client: crate::codegen::client::ApiClient + Sync + 'static> crate::codegen::client::Sendable<Client> for MiscellaneousGetBuilder7<crate::codegen::generics::NameExists, crate::codegen::generics::NamespaceExists, crate::codegen::generics::PathExists> {
+    type Output = String;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    const OPERATION_ID: Option<&'static str> = Some("connectCoreV1GetNamespacedServiceProxyWithPath");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::codegen::client::ApiError<Client::Response>> {
+        Ok(format!("/api/v1/namespaces/{namespace}/services/{name}/proxy/{path}", name=crate::codegen::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?, namespace=crate::codegen::client::path_param("namespace", self.inner.param_namespace.as_ref().expect("missing parameter namespace?"))?, path=crate::codegen::client::path_param("path", self.inner.param_path.as_ref().expect("missing parameter path?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::codegen::client::ApiError<Client::Response>> {
+        use crate::codegen::client::Request;
+        Ok(req
+        .header(http::header::ACCEPT.as_str(), "application/json"))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<MiscellaneousGetBuilder7<crate::codegen::generics::NameExists, crate::codegen::generics::NamespaceExists, crate::codegen::generics::PathExists>> for reqwest::Request {
+    type Error = crate::codegen::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: MiscellaneousGetBuilder7<crate::codegen::generics::NameExists, crate::codegen::generics::NamespaceExists, crate::codegen::generics::PathExists>) -> Result<Self, Self::Error> {
+        let req = crate::codegen::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::codegen::client::ApiError::Reqwest)
+    }
+}
+
+/// Builder created by [`Miscellaneous::connect_core_v1_put_namespaced_service_proxy_with_path`](./struct.Miscellaneous.html#method.connect_core_v1_put_namespaced_service_proxy_with_path) method for a `PUT` operation associated with `Miscellaneous`.
+pub type MiscellaneousPutBuilder7<Name, Namespace, Path> = MiscellaneousOpBuilder6<MiscellaneousPutOp7, Name, Namespace, Path>;
+
+/// Marks the operation of [`MiscellaneousPutBuilder7`] in the shared builder.
+#[derive(Debug, Clone)]
+pub enum MiscellaneousPutOp7 {}
+
+
+impl<Client: crate::codegen::client::ApiClient + Sync + 'static> crate::codegen::client::Sendable<Client> for MiscellaneousPutBuilder7<crate::codegen::generics::NameExists, crate::codegen::generics::NamespaceExists, crate::codegen::generics::PathExists> {
+    type Output = String;
+
+    const METHOD: http::Method = http::Method::PUT;
+
+    const OPERATION_ID: Option<&'static str> = Some("connectCoreV1PutNamespacedServiceProxyWithPath");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::codegen::client::ApiError<Client::Response>> {
+        Ok(format!("/api/v1/namespaces/{namespace}/services/{name}/proxy/{path}", name=crate::codegen::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?, namespace=crate::codegen::client::path_param("namespace", self.inner.param_namespace.as_ref().expect("missing parameter namespace?"))?, path=crate::codegen::client::path_param("path", self.inner.param_path.as_ref().expect("missing parameter path?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::codegen::client::ApiError<Client::Response>> {
+        use crate::codegen::client::Request;
+        Ok(req
+        .header(http::header::ACCEPT.as_str(), "application/json"))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<MiscellaneousPutBuilder7<crate::codegen::generics::NameExists, crate::codegen::generics::NamespaceExists, crate::codegen::generics::PathExists>> for reqwest::Request {
+    type Error = crate::codegen::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: MiscellaneousPutBuilder7<crate::codegen::generics::NameExists, crate::codegen::generics::NamespaceExists, crate::codegen::generics::PathExists>) -> Result<Self, Self::Error> {
+        let req = crate::codegen::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::codegen::client::ApiError::Reqwest)
+    }
+}
+
+/// Builder created by [`Miscellaneous::connect_core_v1_post_namespaced_service_proxy_with_path`](./struct.Miscellaneous.html#method.connect_core_v1_post_namespaced_service_proxy_with_path) method for a `POST` operation associated with `Miscellaneous`.
+pub type MiscellaneousPostBuilder7<Name, Namespace, Path> = MiscellaneousOpBuilder6<MiscellaneousPostOp7, Name, Namespace, Path>;
+
+/// Marks the operation of [`MiscellaneousPostBuilder7`] in the shared builder.
+#[derive(Debug, Clone)]
+pub enum MiscellaneousPostOp7 {}
+
+
+impl<Client: crate::codegen::client::ApiClient + Sync + 'static> crate::codegen::client::Sendable<Client> for MiscellaneousPostBuilder7<crate::codegen::generics::NameExists, crate::codegen::generics::NamespaceExists, crate::codegen::generics::PathExists> {
+    type Output = String;
+
+    const METHOD: http::Method = http::Method::POST;
+
+    const OPERATION_ID: Option<&'static str> = Some("connectCoreV1PostNamespacedServiceProxyWithPath");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::codegen::client::ApiError<Client::Response>> {
+        Ok(format!("/api/v1/namespaces/{namespace}/services/{name}/proxy/{path}", name=crate::codegen::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?, namespace=crate::codegen::client::path_param("namespace", self.inner.param_namespace.as_ref().expect("missing parameter namespace?"))?, path=crate::codegen::client::path_param("path", self.inner.param_path.as_ref().expect("missing parameter path?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::codegen::client::ApiError<Client::Response>> {
+        use crate::codegen::client::Request;
+        Ok(req
+        .header(http::header::ACCEPT.as_str(), "application/json"))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<MiscellaneousPostBuilder7<crate::codegen::generics::NameExists, crate::codegen::generics::NamespaceExists, crate::codegen::generics::PathExists>> for reqwest::Request {
+    type Error = crate::codegen::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: MiscellaneousPostBuilder7<crate::codegen::generics::NameExists, crate::codegen::generics::NamespaceExists, crate::codegen::generics::PathExists>) -> Result<Self, Self::Error> {
+        let req = crate::codegen::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::codegen::client::ApiError::Reqwest)
+    }
+}
+
+/// Builder created by [`Miscellaneous::connect_core_v1_delete_namespaced_service_proxy_with_path`](./struct.Miscellaneous.html#method.connect_core_v1_delete_namespaced_service_proxy_with_path) method for a `DELETE` operation associated with `Miscellaneous`.
+pub type MiscellaneousDeleteBuilder7<Name, Namespace, Path> = MiscellaneousOpBuilder6<MiscellaneousDeleteOp7, Name, Namespace, Path>;
+
+/// Marks the operation of [`MiscellaneousDeleteBuilder7`] in the shared builder.
+#[derive(Debug, Clone)]
+pub enum MiscellaneousDeleteOp7 {}
+
+
+impl<Client: crate::codegen::client::ApiClient + Sync + 'static> crate::codegen::client::Sendable<Client> for MiscellaneousDeleteBuilder7<crate::codegen::generics::NameExists, crate::codegen::generics::NamespaceExists, crate::codegen::generics::PathExists> {
+    type Output = String;
+
+    const METHOD: http::Method = http::Method::DELETE;
+
+    const OPERATION_ID: Option<&'static str> = Some("connectCoreV1DeleteNamespacedServiceProxyWithPath");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::codegen::client::ApiError<Client::Response>> {
+        Ok(format!("/api/v1/namespaces/{namespace}/services/{name}/proxy/{path}", name=crate::codegen::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?, namespace=crate::codegen::client::path_param("namespace", self.inner.param_namespace.as_ref().expect("missing parameter namespace?"))?, path=crate::codegen::client::path_param("path", self.inner.param_path.as_ref().expect("missing parameter path?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::codegen::client::ApiError<Client::Response>> {
+        use crate::codegen::client::Request;
+        Ok(req
+        .header(http::header::ACCEPT.as_str(), "application/json"))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<MiscellaneousDeleteBuilder7<crate::codegen::generics::NameExists, crate::codegen::generics::NamespaceExists, crate::codegen::generics::PathExists>> for reqwest::Request {
+    type Error = crate::codegen::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: MiscellaneousDeleteBuilder7<crate::codegen::generics::NameExists, crate::codegen::generics::NamespaceExists, crate::codegen::generics::PathExists>) -> Result<Self, Self::Error> {
+        let req = crate::codegen::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::codegen::client::ApiError::Reqwest)
+    }
+}
+
+/// Builder created by [`Miscellaneous::connect_core_v1_options_namespaced_service_proxy_with_path`](./struct.Miscellaneous.html#method.connect_core_v1_options_namespaced_service_proxy_with_path) method for a `OPTIONS` operation associated with `Miscellaneous`.
+pub type MiscellaneousOptionsBuilder7<Name, Namespace, Path> = MiscellaneousOpBuilder6<MiscellaneousOptionsOp7, Name, Namespace, Path>;
+
+/// Marks the operation of [`MiscellaneousOptionsBuilder7`] in the shared builder.
+#[derive(Debug, Clone)]
+pub enum MiscellaneousOptionsOp7 {}
+
+
+impl<Client: crate::codegen::client::ApiClient + Sync + 'static> crate::codegen::client::Sendable<Client> for MiscellaneousOptionsBuilder7<crate::codegen::generics::NameExists, crate::codegen::generics::NamespaceExists, crate::codegen::generics::PathExists> {
+    type Output = String;
+
+    const METHOD: http::Method = http::Method::OPTIONS;
+
+    const OPERATION_ID: Option<&'static str> = Some("connectCoreV1OptionsNamespacedServiceProxyWithPath");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::codegen::client::ApiError<Client::Response>> {
+        Ok(format!("/api/v1/namespaces/{namespace}/services/{name}/proxy/{path}", name=crate::codegen::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?, namespace=crate::codegen::client::path_param("namespace", self.inner.param_namespace.as_ref().expect("missing parameter namespace?"))?, path=crate::codegen::client::path_param("path", self.inner.param_path.as_ref().expect("missing parameter path?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::codegen::client::ApiError<Client::Response>> {
+        use crate::codegen::client::Request;
+        Ok(req
+        .header(http::header::ACCEPT.as_str(), "application/json"))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<MiscellaneousOptionsBuilder7<crate::codegen::generics::NameExists, crate::codegen::generics::NamespaceExists, crate::codegen::generics::PathExists>> for reqwest::Request {
+    type Error = crate::codegen::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: MiscellaneousOptionsBuilder7<crate::codegen::generics::NameExists, crate::codegen::generics::NamespaceExists, crate::codegen::generics::PathExists>) -> Result<Self, Self::Error> {
+        let req = crate::codegen::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::codegen::client::ApiError::Reqwest)
+    }
+}
+
+/// Builder created by [`Miscellaneous::connect_core_v1_head_namespaced_service_proxy_with_path`](./struct.Miscellaneous.html#method.connect_core_v1_head_namespaced_service_proxy_with_path) method for a `HEAD` operation associated with `Miscellaneous`.
+pub type MiscellaneousHeadBuilder7<Name, Namespace, Path> = MiscellaneousOpBuilder6<MiscellaneousHeadOp7, Name, Namespace, Path>;
+
+/// Marks the operation of [`MiscellaneousHeadBuilder7`] in the shared builder.
+#[derive(Debug, Clone)]
+pub enum MiscellaneousHeadOp7 {}
+
+
+impl<Client: crate::codegen::client::ApiClient + Sync + 'static> crate::codegen::client::Sendable<Client> for MiscellaneousHeadBuilder7<crate::codegen::generics::NameExists, crate::codegen::generics::NamespaceExists, crate::codegen::generics::PathExists> {
+    type Output = String;
+
+    const METHOD: http::Method = http::Method::HEAD;
+
+    const OPERATION_ID: Option<&'static str> = Some("connectCoreV1HeadNamespacedServiceProxyWithPath");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::codegen::client::ApiError<Client::Response>> {
+        Ok(format!("/api/v1/namespaces/{namespace}/services/{name}/proxy/{path}", name=crate::codegen::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?, namespace=crate::codegen::client::path_param("namespace", self.inner.param_namespace.as_ref().expect("missing parameter namespace?"))?, path=crate::codegen::client::path_param("path", self.inner.param_path.as_ref().expect("missing parameter path?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::codegen::client::ApiError<Client::Response>> {
+        use crate::codegen::client::Request;
+        Ok(req
+        .header(http::header::ACCEPT.as_str(), "application/json"))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<MiscellaneousHeadBuilder7<crate::codegen::generics::NameExists, crate::codegen::generics::NamespaceExists, crate::codegen::generics::PathExists>> for reqwest::Request {
+    type Error = crate::codegen::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: MiscellaneousHeadBuilder7<crate::codegen::generics::NameExists, crate::codegen::generics::NamespaceExists, crate::codegen::generics::PathExists>) -> Result<Self, Self::Error> {
+        let req = crate::codegen::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::codegen::client::ApiError::Reqwest)
+    }
+}
+
+/// Builder created by [`Miscellaneous::connect_core_v1_patch_namespaced_service_proxy_with_path`](./struct.Miscellaneous.html#method.connect_core_v1_patch_namespaced_service_proxy_with_path) method for a `PATCH` operation associated with `Miscellaneous`.
+pub type MiscellaneousPatchBuilder7<Name, Namespace, Path> = MiscellaneousOpBuilder6<MiscellaneousPatchOp7, Name, Namespace, Path>;
+
+/// Marks the operation of [`MiscellaneousPatchBuilder7`] in the shared builder.
+#[derive(Debug, Clone)]
+pub enum MiscellaneousPatchOp7 {}
+
+
+impl<Client: crate::codegen::client::ApiClient + Sync + 'static> crate::codegen::client::Sendable<Client> for MiscellaneousPatchBuilder7<crate::codegen::generics::NameExists, crate::codegen::generics::NamespaceExists, crate::codegen::generics::PathExists> {
+    type Output = String;
+
+    const METHOD: http::Method = http::Method::PATCH;
+
+    const OPERATION_ID: Option<&'static str> = Some("connectCoreV1PatchNamespacedServiceProxyWithPath");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::codegen::client::ApiError<Client::Response>> {
+        Ok(format!("/api/v1/namespaces/{namespace}/services/{name}/proxy/{path}", name=crate::codegen::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?, namespace=crate::codegen::client::path_param("namespace", self.inner.param_namespace.as_ref().expect("missing parameter namespace?"))?, path=crate::codegen::client::path_param("path", self.inner.param_path.as_ref().expect("missing parameter path?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::codegen::client::ApiError<Client::Response>> {
+        use crate::codegen::client::Request;
+        Ok(req
+        .header(http::header::ACCEPT.as_str(), "application/json"))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<MiscellaneousPatchBuilder7<crate::codegen::generics::NameExists, crate::codegen::generics::NamespaceExists, crate::codegen::generics::PathExists>> for reqwest::Request {
+    type Error = crate::codegen::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: MiscellaneousPatchBuilder7<crate::codegen::generics::NameExists, crate::codegen::generics::NamespaceExists, crate::codegen::generics::PathExists>) -> Result<Self, Self::Error> {
+        let req = crate::codegen::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::codegen::client::ApiError::Reqwest)
+    }
+}
+
+/// Builder shared by operations (with the same fields and parameters) associated with `Miscellaneous`.
+#[repr(transparent)]
+#[derive(Debug, Clone)]
+pub struct MiscellaneousOpBuilder7<Op, Name> {
+    inner: MiscellaneousOpBuilder7Container,
+    _op: core::marker::PhantomData<Op>,
+    _param_name: core::marker::PhantomData<Name>,
+}
+
+#[derive(Debug, Default, Clone)]
+struct MiscellaneousOpBuilder7Container {
+    param_name: Option<String>,
+    param_path: Option<String>,
+}
+
+/// Builder created by [`Miscellaneous::connect_core_v1_get_node_proxy`](./struct.Miscellaneous.html#method.connect_core_v1_get_node_proxy) method for a `GET` operation associated with `Miscellaneous`.
+pub type MiscellaneousGetBuilder8<Name> = MiscellaneousOpBuilder7<MiscellaneousGetOp8, Name>;
+
+/// Marks the operation of [`MiscellaneousGetBuilder8`] in the shared builder.
+#[derive(Debug, Clone)]
+pub enum MiscellaneousGetOp8 {}
+
+impl<Op, Name> MiscellaneousOpBuilder7<Op, Name> {
+    /// name of the NodeProxyOptions
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> MiscellaneousOpBuilder7<Op, crate::codegen::generics::NameExists> {
+        self.inner.param_name = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Path is the URL path to use for the current proxy request to node.
+    #[inline]
+    pub fn path(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_path = Some(value.into());
+        self
+    }
+
+    /// Sets [`path`](#method.path) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_path(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_path = value.map(|value| value.into());
+        self
+    }
+}
+
+impl<Client: crate::codegen::client::ApiClient + Sync + 'static> crate::codegen::client::Sendable<Client> for MiscellaneousGetBuilder8<crate::codegen::generics::NameExists> {
+    type Output = String;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    const OPERATION_ID: Option<&'static str> = Some("connectCoreV1GetNodeProxy");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::codegen::client::ApiError<Client::Response>> {
+        Ok(format!("/api/v1/nodes/{name}/proxy", name=crate::codegen::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::codegen::client::ApiError<Client::Response>> {
+        use crate::codegen::client::Request;
+        Ok(req
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("path", self.inner.param_path.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<MiscellaneousGetBuilder8<crate::codegen::generics::NameExists>> for reqwest::Request {
+    type Error = crate::codegen::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: MiscellaneousGetBuilder8<crate::codegen::generics::NameExists>) -> Result<Self, Self::Error> {
+        let req = crate::codegen::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::codegen::client::ApiError::Reqwest)
+    }
+}
+
+/// Builder created by [`Miscellaneous::connect_core_v1_put_node_proxy`](./struct.Miscellaneous.html#method.connect_core_v1_put_node_proxy) method for a `PUT` operation associated with `Miscellaneous`.
+pub type MiscellaneousPutBuilder8<Name> = MiscellaneousOpBuilder7<MiscellaneousPutOp8, Name>;
+
+/// Marks the operation of [`MiscellaneousPutBuilder8`] in the shared builder.
+#[derive(Debug, Clone)]
+pub enum MiscellaneousPutOp8 {}
+
+
+impl<Client: crate::codegen::client::ApiClient + Sync + 'static> crate::codegen::client::Sendable<Client> for MiscellaneousPutBuilder8<crate::codegen::generics::NameExists> {
+    type Output = String;
+
+    const METHOD: http::Method = http::Method::PUT;
+
+    const OPERATION_ID: Option<&'static str> = Some("connectCoreV1PutNodeProxy");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::codegen::client::ApiError<Client::Response>> {
+        Ok(format!("/api/v1/nodes/{name}/proxy", name=crate::codegen::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::codegen::client::ApiError<Client::Response>> {
+        use crate::codegen::client::Request;
+        Ok(req
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("path", self.inner.param_path.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<MiscellaneousPutBuilder8<crate::codegen::generics::NameExists>> for reqwest::Request {
+    type Error = crate::codegen::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: MiscellaneousPutBuilder8<crate::codegen::generics::NameExists>) -> Result<Self, Self::Error> {
+        let req = crate::codegen::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::codegen::client::ApiError::Reqwest)
+    }
+}
+
+/// Builder created by [`Miscellaneous::connect_core_v1_post_node_proxy`](./struct.Miscellaneous.html#method.connect_core_v1_post_node_proxy) method for a `POST` operation associated with `Miscellaneous`.
+pub type MiscellaneousPostBuilder8<Name> = MiscellaneousOpBuilder7<MiscellaneousPostOp8, Name>;
+
+/// Marks the operation of [`MiscellaneousPostBuilder8`] in the shared builder.
+#[derive(Debug, Clone)]
+pub enum MiscellaneousPostOp8 {}
+
+
+impl<Client: crate::codegen::client::ApiClient + Sync + 'static> crate::codegen::client::Sendable<Client> for MiscellaneousPostBuilder8<crate::codegen::generics::NameExists> {
+    type Output = String;
+
+    const METHOD: http::Method = http::Method::POST;
+
+    const OPERATION_ID: Option<&'static str> = Some("connectCoreV1PostNodeProxy");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::codegen::client::ApiError<Client::Response>> {
+        Ok(format!("/api/v1/nodes/{name}/proxy", name=crate::codegen::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::codegen::client::ApiError<Client::Response>> {
+        use crate::codegen::client::Request;
+        Ok(req
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("path", self.inner.param_path.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<MiscellaneousPostBuilder8<crate::codegen::generics::NameExists>> for reqwest::Request {
+    type Error = crate::codegen::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: MiscellaneousPostBuilder8<crate::codegen::generics::NameExists>) -> Result<Self, Self::Error> {
+        let req = crate::codegen::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::codegen::client::ApiError::Reqwest)
+    }
+}
+
+/// Builder created by [`Miscellaneous::connect_core_v1_delete_node_proxy`](./struct.Miscellaneous.html#method.connect_core_v1_delete_node_proxy) method for a `DELETE` operation associated with `Miscellaneous`.
+pub type MiscellaneousDeleteBuilder8<Name> = MiscellaneousOpBuilder7<MiscellaneousDeleteOp8, Name>;
+
+/// Marks the operation of [`MiscellaneousDeleteBuilder8`] in the shared builder.
+#[derive(Debug, Clone)]
+pub enum MiscellaneousDeleteOp8 {}
+
+
+impl<Client: crate::codegen::client::ApiClient + Sync + 'static> crate::codegen::client::Sendable<Client> for MiscellaneousDeleteBuilder8<crate::codegen::generics::NameExists> {
+    type Output = String;
+
+    const METHOD: http::Method = http::Method::DELETE;
+
+    const OPERATION_ID: Option<&'static str> = Some("connectCoreV1DeleteNodeProxy");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::codegen::client::ApiError<Client::Response>> {
+        Ok(format!("/api/v1/nodes/{name}/proxy", name=crate::codegen::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::codegen::client::ApiError<Client::Response>> {
+        use crate::codegen::client::Request;
+        Ok(req
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("path", self.inner.param_path.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<MiscellaneousDeleteBuilder8<crate::codegen::generics::NameExists>> for reqwest::Request {
+    type Error = crate::codegen::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: MiscellaneousDeleteBuilder8<crate::codegen::generics::NameExists>) -> Result<Self, Self::Error> {
+        let req = crate::codegen::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::codegen::client::ApiError::Reqwest)
+    }
+}
+
+/// Builder created by [`Miscellaneous::connect_core_v1_options_node_proxy`](./struct.Miscellaneous.html#method.connect_core_v1_options_node_proxy) method for a `OPTIONS` operation associated with `Miscellaneous`.
+pub type MiscellaneousOptionsBuilder8<Name> = MiscellaneousOpBuilder7<MiscellaneousOptionsOp8, Name>;
+
+/// Marks the operation of [`MiscellaneousOptionsBuilder8`] in the shared builder.
+#[derive(Debug, Clone)]
+pub enum MiscellaneousOptionsOp8 {}
+
+
+impl<Client: crate::codegen::client::ApiClient + Sync + 'static> crate::codegen::client::Sendable<Client> for MiscellaneousOptionsBuilder8<crate::codegen::generics::NameExists> {
+    type Output = String;
+
+    const METHOD: http::Method = http::Method::OPTIONS;
+
+    const OPERATION_ID: Option<&'static str> = Some("connectCoreV1OptionsNodeProxy");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::codegen::client::ApiError<Client::Response>> {
+        Ok(format!("/api/v1/nodes/{name}/proxy", name=crate::codegen::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::codegen::client::ApiError<Client::Response>> {
+        use crate::codegen::client::Request;
+        Ok(req
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("path", self.inner.param_path.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<MiscellaneousOptionsBuilder8<crate::codegen::generics::NameExists>> for reqwest::Request {
+    type Error = crate::codegen::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: MiscellaneousOptionsBuilder8<crate::codegen::generics::NameExists>) -> Result<Self, Self::Error> {
+        let req = crate::codegen::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::codegen::client::ApiError::Reqwest)
+    }
+}
+
+/// Builder created by [`Miscellaneous::connect_core_v1_head_node_proxy`](./struct.Miscellaneous.html#method.connect_core_v1_head_node_proxy) method for a `HEAD` operation associated with `Miscellaneous`.
+pub type MiscellaneousHeadBuilder8<Name> = MiscellaneousOpBuilder7<MiscellaneousHeadOp8, Name>;
+
+/// Marks the operation of [`MiscellaneousHeadBuilder8`] in the shared builder.
+#[derive(Debug, Clone)]
+pub enum MiscellaneousHeadOp8 {}
+
+
+impl<Client: crate::codegen::client::ApiClient + Sync + 'static> crate::codegen::client::Sendable<Client> for MiscellaneousHeadBuilder8<crate::codegen::generics::NameExists> {
+    type Output = String;
+
+    const METHOD: http::Method = http::Method::HEAD;
+
+    const OPERATION_ID: Option<&'static str> = Some("connectCoreV1HeadNodeProxy");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::codegen::client::ApiError<Client::Response>> {
+        Ok(format!("/api/v1/nodes/{name}/proxy", name=crate::codegen::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::codegen::client::ApiError<Client::Response>> {
+        use crate::codegen::client::Request;
+        Ok(req
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("path", self.inner.param_path.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<MiscellaneousHeadBuilder8<crate::codegen::generics::NameExists>> for reqwest::Request {
+    type Error = crate::codegen::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: MiscellaneousHeadBuilder8<crate::codegen::generics::NameExists>) -> Result<Self, Self::Error> {
+        let req = crate::codegen::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::codegen::client::ApiError::Reqwest)
+    }
+}
+
+/// Builder created by [`Miscellaneous::connect_core_v1_patch_node_proxy`](./struct.Miscellaneous.html#method.connect_core_v1_patch_node_proxy) method for a `PATCH` operation associated with `Miscellaneous`.
+pub type MiscellaneousPatchBuilder8<Name> = MiscellaneousOpBuilder7<MiscellaneousPatchOp8, Name>;
+
+/// Marks the operation of [`MiscellaneousPatchBuilder8`] in the shared builder.
+#[derive(Debug, Clone)]
+pub enum MiscellaneousPatchOp8 {}
+
+
+impl<Client: crate::codegen::client::ApiClient + Sync + 'static> crate::codegen::client::Sendable<Client> for MiscellaneousPatchBuilder8<crate::codegen::generics::NameExists> {
+    type Output = String;
+
+    const METHOD: http::Method = http::Method::PATCH;
+
+    const OPERATION_ID: Option<&'static str> = Some("connectCoreV1PatchNodeProxy");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::codegen::client::ApiError<Client::Response>> {
+        Ok(format!("/api/v1/nodes/{name}/proxy", name=crate::codegen::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::codegen::client::ApiError<Client::Response>> {
+        use crate::codegen::client::Request;
+        Ok(req
+        .header(http::header::ACCEPT.as_str(), "application/json")
+        .query(&[
+            ("path", self.inner.param_path.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<MiscellaneousPatchBuilder8<crate::codegen::generics::NameExists>> for reqwest::Request {
+    type Error = crate::codegen::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: MiscellaneousPatchBuilder8<crate::codegen::generics::NameExists>) -> Result<Self, Self::Error> {
+        let req = crate::codegen::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::codegen::client::ApiError::Reqwest)
+    }
+}
+
+/// Builder shared by operations (with the same fields and parameters) associated with `Miscellaneous`.
+#[repr(transparent)]
+#[derive(Debug, Clone)]
+pub struct MiscellaneousOpBuilder8<Op, Name, Path> {
+    inner: MiscellaneousOpBuilder8Container,
+    _op: core::marker::PhantomData<Op>,
+    _param_name: core::marker::PhantomData<Name>,
+    _param_path: core::marker::PhantomData<Path>,
+}
+
+#[derive(Debug, Default, Clone)]
+struct MiscellaneousOpBuilder8Container {
+    param_name: Option<String>,
+    param_path: Option<String>,
+}
+
+/// Builder created by [`Miscellaneous::connect_core_v1_get_node_proxy_with_path`](./struct.Miscellaneous.html#method.connect_core_v1_get_node_proxy_with_path) method for a `GET` operation associated with `Miscellaneous`.
+pub type MiscellaneousGetBuilder9<Name, Path> = MiscellaneousOpBuilder8<MiscellaneousGetOp9, Name, Path>;
+
+/// Marks the operation of [`MiscellaneousGetBuilder9`] in the shared builder.
+#[derive(Debug, Clone)]
+pub enum MiscellaneousGetOp9 {}
+
+impl<Op, Name, Path> MiscellaneousOpBuilder8<Op, Name, Path> {
+    /// name of the NodeProxyOptions
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> MiscellaneousOpBuilder8<Op, crate::codegen::generics::NameExists, Path> {
+        self.inner.param_name = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// path to the resource
+    #[inline]
+    pub fn path(mut self, value: impl Into<String>) -> MiscellaneousOpBuilder8<Op, Name, crate::codegen::generics::PathExists> {
+        self.inner.param_path = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+}
+
+impl<Client: crate::codegen::client::ApiClient + Sync + 'static> crate::codegen::client::Sendable<Client> for MiscellaneousGetBuilder9<crate::codegen::generics::NameExists, crate::codegen::generics::PathExists> {
+    type Output = String;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    const OPERATION_ID: Option<&'static str> = Some("connectCoreV1GetNodeProxyWithPath");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::codegen::client::ApiError<Client::Response>> {
+        Ok(format!("/api/v1/nodes/{name}/proxy/{path}", name=crate::codegen::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?, path=crate::codegen::client::path_param("path", self.inner.param_path.as_ref().expect("missing parameter path?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::codegen::client::ApiError<Client::Response>> {
+        use crate::codegen::client::Request;
+        Ok(req
+        .header(http::header::ACCEPT.as_str(), "application/json"))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<MiscellaneousGetBuilder9<crate::codegen::generics::NameExists, crate::codegen::generics::PathExists>> for reqwest::Request {
+    type Error = crate::codegen::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: MiscellaneousGetBuilder9<crate::codegen::generics::NameExists, crate::codegen::generics::PathExists>) -> Result<Self, Self::Error> {
+        let req = crate::codegen::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::codegen::client::ApiError::Reqwest)
+    }
+}
+
+/// Builder created by [`Miscellaneous::connect_core_v1_put_node_proxy_with_path`](./struct.Miscellaneous.html#method.connect_core_v1_put_node_proxy_with_path) method for a `PUT` operation associated with `Miscellaneous`.
+pub type MiscellaneousPutBuilder9<Name, Path> = MiscellaneousOpBuilder8<MiscellaneousPutOp9, Name, Path>;
+
+/// Marks the operation of [`MiscellaneousPutBuilder9`] in the shared builder.
+#[derive(Debug, Clone)]
+pub enum MiscellaneousPutOp9 {}
+
+
+impl<Client: crate::codegen::client::ApiClient + Sync + 'static> crate::codegen::client::Sendable<Client> for MiscellaneousPutBuilder9<crate::codegen::generics::NameExists, crate::codegen::generics::PathExists> {
+    type Output = String;
+
+    const METHOD: http::Method = http::Method::PUT;
+
+    const OPERATION_ID: Option<&'static str> = Some("connectCoreV1PutNodeProxyWithPath");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::codegen::client::ApiError<Client::Response>> {
+        Ok(format!("/api/v1/nodes/{name}/proxy/{path}", name=crate::codegen::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?, path=crate::codegen::client::path_param("path", self.inner.param_path.as_ref().expect("missing parameter path?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::codegen::client::ApiError<Client::Response>> {
+        use crate::codegen::client::Request;
+        Ok(req
+        .header(http::header::ACCEPT.as_str(), "application/json"))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<MiscellaneousPutBuilder9<crate::codegen::generics::NameExists, crate::codegen::generics::PathExists>> for reqwest::Request {
+    type Error = crate::codegen::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: MiscellaneousPutBuilder9<crate::codegen::generics::NameExists, crate::codegen::generics::PathExists>) -> Result<Self, Self::Error> {
+        let req = crate::codegen::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::codegen::client::ApiError::Reqwest)
+    }
+}
+
+/// Builder created by [`Miscellaneous::connect_core_v1_post_node_proxy_with_path`](./struct.Miscellaneous.html#method.connect_core_v1_post_node_proxy_with_path) method for a `POST` operation associated with `Miscellaneous`.
+pub type MiscellaneousPostBuilder9<Name, Path> = MiscellaneousOpBuilder8<MiscellaneousPostOp9, Name, Path>;
+
+/// Marks the operation of [`MiscellaneousPostBuilder9`] in the shared builder.
+#[derive(Debug, Clone)]
+pub enum MiscellaneousPostOp9 {}
+
+
+impl<Client: crate::codegen::client::ApiClient + Sync + 'static> crate::codegen::client::Sendable<Client> for MiscellaneousPostBuilder9<crate::codegen::generics::NameExists, crate::codegen::generics::PathExists> {
+    type Output = String;
+
+    const METHOD: http::Method = http::Method::POST;
+
+    const OPERATION_ID: Option<&'static str> = Some("connectCoreV1PostNodeProxyWithPath");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::codegen::client::ApiError<Client::Response>> {
+        Ok(format!("/api/v1/nodes/{name}/proxy/{path}", name=crate::codegen::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?, path=crate::codegen::client::path_param("path", self.inner.param_path.as_ref().expect("missing parameter path?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::codegen::client::ApiError<Client::Response>> {
+        use crate::codegen::client::Request;
+        Ok(req
+        .header(http::header::ACCEPT.as_str(), "application/json"))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<MiscellaneousPostBuilder9<crate::codegen::generics::NameExists, crate::codegen::generics::PathExists>> for reqwest::Request {
+    type Error = crate::codegen::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: MiscellaneousPostBuilder9<crate::codegen::generics::NameExists, crate::codegen::generics::PathExists>) -> Result<Self, Self::Error> {
+        let req = crate::codegen::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::codegen::client::ApiError::Reqwest)
+    }
+}
+
+/// Builder created by [`Miscellaneous::connect_core_v1_delete_node_proxy_with_path`](./struct.Miscellaneous.html#method.connect_core_v1_delete_node_proxy_with_path) method for a `DELETE` operation associated with `Miscellaneous`.
+pub type MiscellaneousDeleteBuilder9<Name, Path> = MiscellaneousOpBuilder8<MiscellaneousDeleteOp9, Name, Path>;
+
+/// Marks the operation of [`MiscellaneousDeleteBuilder9`] in the shared builder.
+#[derive(Debug, Clone)]
+pub enum MiscellaneousDeleteOp9 {}
+
+
+impl<Client: crate::codegen::client::ApiClient + Sync + 'static> crate::codegen::client::Sendable<Client> for MiscellaneousDeleteBuilder9<crate::codegen::generics::NameExists, crate::codegen::generics::PathExists> {
+    type Output = String;
+
+    const METHOD: http::Method = http::Method::DELETE;
+
+    const OPERATION_ID: Option<&'static str> = Some("connectCoreV1DeleteNodeProxyWithPath");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::codegen::client::ApiError<Client::Response>> {
+        Ok(format!("/api/v1/nodes/{name}/proxy/{path}", name=crate::codegen::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?, path=crate::codegen::client::path_param("path", self.inner.param_path.as_ref().expect("missing parameter path?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::codegen::client::ApiError<Client::Response>> {
+        use crate::codegen::client::Request;
+        Ok(req
+        .header(http::header::ACCEPT.as_str(), "application/json"))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<MiscellaneousDeleteBuilder9<crate::codegen::generics::NameExists, crate::codegen::generics::PathExists>> for reqwest::Request {
+    type Error = crate::codegen::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: MiscellaneousDeleteBuilder9<crate::codegen::generics::NameExists, crate::codegen::generics::PathExists>) -> Result<Self, Self::Error> {
+        let req = crate::codegen::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::codegen::client::ApiError::Reqwest)
+    }
+}
+
+/// Builder created by [`Miscellaneous::connect_core_v1_options_node_proxy_with_path`](./struct.Miscellaneous.html#method.connect_core_v1_options_node_proxy_with_path) method for a `OPTIONS` operation associated with `Miscellaneous`.
+pub type MiscellaneousOptionsBuilder9<Name, Path> = MiscellaneousOpBuilder8<MiscellaneousOptionsOp9, Name, Path>;
+
+/// Marks the operation of [`MiscellaneousOptionsBuilder9`] in the shared builder.
+#[derive(Debug, Clone)]
+pub enum MiscellaneousOptionsOp9 {}
+
+
+impl<Client: crate::codegen::client::ApiClient + Sync + 'static> crate::codegen::client::Sendable<Client> for MiscellaneousOptionsBuilder9<crate::codegen::generics::NameExists, crate::codegen::generics::PathExists> {
+    type Output = String;
+
+    const METHOD: http::Method = http::Method::OPTIONS;
+
+    const OPERATION_ID: Option<&'static str> = Some("connectCoreV1OptionsNodeProxyWithPath");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::codegen::client::ApiError<Client::Response>> {
+        Ok(format!("/api/v1/nodes/{name}/proxy/{path}", name=crate::codegen::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?, path=crate::codegen::client::path_param("path", self.inner.param_path.as_ref().expect("missing parameter path?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::codegen::client::ApiError<Client::Response>> {
+        use crate::codegen::client::Request;
+        Ok(req
+        .header(http::header::ACCEPT.as_str(), "application/json"))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<MiscellaneousOptionsBuilder9<crate::codegen::generics::NameExists, crate::codegen::generics::PathExists>> for reqwest::Request {
+    type Error = crate::codegen::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: MiscellaneousOptionsBuilder9<crate::codegen::generics::NameExists, crate::codegen::generics::PathExists>) -> Result<Self, Self::Error> {
+        let req = crate::codegen::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::codegen::client::ApiError::Reqwest)
+    }
+}
+
+/// Builder created by [`Miscellaneous::connect_core_v1_head_node_proxy_with_path`](./struct.Miscellaneous.html#method.connect_core_v1_head_node_proxy_with_path) method for a `HEAD` operation associated with `Miscellaneous`.
+pub type MiscellaneousHeadBuilder9<Name, Path> = MiscellaneousOpBuilder8<MiscellaneousHeadOp9, Name, Path>;
+
+/// Marks the operation of [`MiscellaneousHeadBuilder9`] in the shared builder.
+#[derive(Debug, Clone)]
+pub enum MiscellaneousHeadOp9 {}
+
+
+impl<Client: crate::codegen::client::ApiClient + Sync + 'static> crate::codegen::client::Sendable<Client> for MiscellaneousHeadBuilder9<crate::codegen::generics::NameExists, crate::codegen::generics::PathExists> {
+    type Output = String;
+
+    const METHOD: http::Method = http::Method::HEAD;
+
+    const OPERATION_ID: Option<&'static str> = Some("connectCoreV1HeadNodeProxyWithPath");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::codegen::client::ApiError<Client::Response>> {
+        Ok(format!("/api/v1/nodes/{name}/proxy/{path}", name=crate::codegen::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?, path=crate::codegen::client::path_param("path", self.inner.param_path.as_ref().expect("missing parameter path?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::codegen::client::ApiError<Client::Response>> {
+        use crate::codegen::client::Request;
+        Ok(req
+        .header(http::header::ACCEPT.as_str(), "application/json"))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<MiscellaneousHeadBuilder9<crate::codegen::generics::NameExists, crate::codegen::generics::PathExists>> for reqwest::Request {
+    type Error = crate::codegen::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: MiscellaneousHeadBuilder9<crate::codegen::generics::NameExists, crate::codegen::generics::PathExists>) -> Result<Self, Self::Error> {
+        let req = crate::codegen::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::codegen::client::ApiError::Reqwest)
+    }
+}
+
+/// Builder created by [`Miscellaneous::connect_core_v1_patch_node_proxy_with_path`](./struct.Miscellaneous.html#method.connect_core_v1_patch_node_proxy_with_path) method for a `PATCH` operation associated with `Miscellaneous`.
+pub type MiscellaneousPatchBuilder9<Name, Path> = MiscellaneousOpBuilder8<MiscellaneousPatchOp9, Name, Path>;
+
+/// Marks the operation of [`MiscellaneousPatchBuilder9`] in the shared builder.
+#[derive(Debug, Clone)]
+pub enum MiscellaneousPatchOp9 {}
+
+
+impl<Client: crate::codegen::client::ApiClient + Sync + 'static> crate::codegen::client::Sendable<Client> for MiscellaneousPatchBuilder9<crate::codegen::generics::NameExists, crate::codegen::generics::PathExists> {
+    type Output = String;
+
+    const METHOD: http::Method = http::Method::PATCH;
+
+    const OPERATION_ID: Option<&'static str> = Some("connectCoreV1PatchNodeProxyWithPath");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["BearerToken"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::codegen::client::ApiError<Client::Response>> {
+        Ok(format!("/api/v1/nodes/{name}/proxy/{path}", name=crate::codegen::client::path_param("name", self.inner.param_name.as_ref().expect("missing parameter name?"))?, path=crate::codegen::client::path_param("path", self.inner.param_path.as_ref().expect("missing parameter path?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::codegen::client::ApiError<Client::Response>> {
+        use crate::codegen::client::Request;
+        Ok(req
+        .header(http::header::ACCEPT.as_str(), "application/json"))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<MiscellaneousPatchBuilder9<crate::codegen::generics::NameExists, crate::codegen::generics::PathExists>> for reqwest::Request {
+    type Error = crate::codegen::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: MiscellaneousPatchBuilder9<crate::codegen::generics::NameExists, crate::codegen::generics::PathExists>) -> Result<Self, Self::Error> {
+        let req = crate::codegen::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::codegen::client::ApiError::Reqwest)
+    }
+}
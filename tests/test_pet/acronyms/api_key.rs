@@ -0,0 +1,144 @@
+
+/// Key for accessing the API over HTTP.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct APIKey {
+    #[serde(rename = "HTTPHeaders", skip_serializing_if = "Option::is_none")]
+    pub http_headers: Option<crate::api_key::APIKeyHTTPHeaders>,
+    #[serde(rename = "keyID")]
+    pub key_id: String,
+    pub secret: String,
+    #[serde(deserialize_with = "Option::deserialize")]
+    pub signature: Option<String>,
+}
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct APIKeyHTTPHeaders {
+    #[serde(rename = "userAgent", skip_serializing_if = "Option::is_none")]
+    pub user_agent: Option<String>,
+}
+
+impl APIKey {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> APIKeyBuilder<crate::generics::MissingKeyID, crate::generics::MissingSecret> {
+        APIKeyBuilder {
+            body: Default::default(),
+            _key_id: core::marker::PhantomData,
+            _secret: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> APIKeyBuilder<crate::generics::KeyIDExists, crate::generics::SecretExists>
+    where
+        Self: Clone,
+    {
+        APIKeyBuilder {
+            body: self.clone(),
+            _key_id: core::marker::PhantomData,
+            _secret: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<APIKey> for APIKeyBuilder<crate::generics::KeyIDExists, crate::generics::SecretExists> {
+    fn into(self) -> APIKey {
+        self.body
+    }
+}
+
+/// Builder for [`APIKey`](./struct.APIKey.html) object.
+#[derive(Debug, Clone)]
+pub struct APIKeyBuilder<KeyID, Secret> {
+    body: self::APIKey,
+    _key_id: core::marker::PhantomData<KeyID>,
+    _secret: core::marker::PhantomData<Secret>,
+}
+
+impl<KeyID, Secret> APIKeyBuilder<KeyID, Secret> {
+    #[inline]
+    pub fn http_headers(mut self, value: crate::api_key::APIKeyHTTPHeaders) -> Self {
+        self.body.http_headers = Some(value.into());
+        self
+    }
+
+    /// Sets [`http_headers`](#method.http_headers) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_http_headers(mut self, value: Option<crate::api_key::APIKeyHTTPHeaders>) -> Self {
+        self.body.http_headers = value.map(|value| value.into());
+        self
+    }
+
+    #[inline]
+    pub fn key_id(mut self, value: impl Into<String>) -> APIKeyBuilder<crate::generics::KeyIDExists, Secret> {
+        self.body.key_id = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    #[inline]
+    pub fn secret(mut self, value: impl Into<String>) -> APIKeyBuilder<KeyID, crate::generics::SecretExists> {
+        self.body.secret = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    #[inline]
+    pub fn signature(mut self, value: impl Into<String>) -> Self {
+        self.body.signature = Some(value.into());
+        self
+    }
+
+    /// Sets [`signature`](#method.signature) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_signature(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.signature = value.map(|value| value.into());
+        self
+    }
+}
+
+impl APIKeyHTTPHeaders {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> APIKeyHTTPHeadersBuilder {
+        APIKeyHTTPHeadersBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> APIKeyHTTPHeadersBuilder
+    where
+        Self: Clone,
+    {
+        APIKeyHTTPHeadersBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<APIKeyHTTPHeaders> for APIKeyHTTPHeadersBuilder {
+    fn into(self) -> APIKeyHTTPHeaders {
+        self.body
+    }
+}
+
+/// Builder for [`APIKeyHTTPHeaders`](./struct.APIKeyHTTPHeaders.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct APIKeyHTTPHeadersBuilder {
+    body: self::APIKeyHTTPHeaders,
+}
+
+impl APIKeyHTTPHeadersBuilder {
+    #[inline]
+    pub fn user_agent(mut self, value: impl Into<String>) -> Self {
+        self.body.user_agent = Some(value.into());
+        self
+    }
+
+    /// Sets [`user_agent`](#method.user_agent) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_user_agent(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.user_agent = value.map(|value| value.into());
+        self
+    }
+}
@@ -0,0 +1,35 @@
+
+pub struct MissingFoobar;
+pub struct FoobarExists;
+pub struct MissingGrantType;
+pub struct GrantTypeExists;
+pub struct MissingId;
+pub struct IdExists;
+pub struct MissingKeyID;
+pub struct KeyIDExists;
+pub struct MissingKind;
+pub struct KindExists;
+pub struct MissingLicense;
+pub struct LicenseExists;
+pub struct MissingName;
+pub struct NameExists;
+pub struct MissingNickname;
+pub struct NicknameExists;
+pub struct MissingPassword;
+pub struct PasswordExists;
+pub struct MissingPetId;
+pub struct PetIdExists;
+pub struct MissingSecret;
+pub struct SecretExists;
+pub struct MissingSession;
+pub struct SessionExists;
+pub struct MissingSomeDataFile;
+pub struct SomeDataFileExists;
+pub struct MissingTakenAt;
+pub struct TakenAtExists;
+pub struct MissingUsername;
+pub struct UsernameExists;
+pub struct MissingValues;
+pub struct ValuesExists;
+pub struct MissingXAuth;
+pub struct XAuthExists;
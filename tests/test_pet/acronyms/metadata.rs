@@ -0,0 +1,130 @@
+
+/// Version of a resource along with its labels.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct Metadata {
+    /// Free-form labels.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub labels: Option<crate::metadata::MetadataLabels>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scores: Option<std::collections::BTreeMap<String, std::collections::BTreeMap<String, Vec<i64>>>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<i64>,
+    #[serde(flatten)]
+    pub pattern_fields: Option<std::collections::BTreeMap<String, String>>,
+}
+
+/// Free-form labels.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct MetadataLabels {}
+
+impl Metadata {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> MetadataBuilder {
+        MetadataBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> MetadataBuilder
+    where
+        Self: Clone,
+    {
+        MetadataBuilder {
+            body: self.clone(),
+        }
+    }
+
+    /// Insert a pattern property, provided that its key matches one of
+    /// the patterns for this object.
+    pub fn insert_pattern_field(&mut self, key: String, value: String) -> Result<(), crate::util::InvalidKey> {
+        lazy_static::lazy_static! {
+            static ref PATTERNS: Vec<regex::Regex> = vec![
+                regex::Regex::new("^x-[a-z-]+$").expect("invalid pattern?"),
+            ];
+        }
+
+        if !PATTERNS.iter().any(|p| p.is_match(&key)) {
+            return Err(crate::util::InvalidKey(key));
+        }
+
+        self.pattern_fields.get_or_insert_with(Default::default).insert(key, value);
+        Ok(())
+    }
+}
+
+impl Into<Metadata> for MetadataBuilder {
+    fn into(self) -> Metadata {
+        self.body
+    }
+}
+
+/// Builder for [`Metadata`](./struct.Metadata.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct MetadataBuilder {
+    body: self::Metadata,
+}
+
+impl MetadataBuilder {
+    /// Free-form labels.
+    #[inline]
+    pub fn labels(mut self, value: crate::metadata::MetadataLabels) -> Self {
+        self.body.labels = Some(value.into());
+        self
+    }
+
+    /// Sets [`labels`](#method.labels) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_labels(mut self, value: Option<crate::metadata::MetadataLabels>) -> Self {
+        self.body.labels = value.map(|value| value.into());
+        self
+    }
+
+    #[inline]
+    pub fn scores(mut self, value: impl Iterator<Item = (String, impl Iterator<Item = (String, impl Iterator<Item = impl Into<i64>>)>)>) -> Self {
+        self.body.scores = Some(value.map(|(key, value)| (key, value.map(|(key, value)| (key, value.map(|value| value.into()).collect::<Vec<_>>().into())).collect::<std::collections::BTreeMap<_, _>>().into())).collect::<std::collections::BTreeMap<_, _>>().into());
+        self
+    }
+
+    /// Sets [`scores`](#method.scores) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_scores(mut self, value: Option<impl Iterator<Item = (String, impl Iterator<Item = (String, impl Iterator<Item = impl Into<i64>>)>)>>) -> Self {
+        self.body.scores = value.map(|value| value.map(|(key, value)| (key, value.map(|(key, value)| (key, value.map(|value| value.into()).collect::<Vec<_>>().into())).collect::<std::collections::BTreeMap<_, _>>().into())).collect::<std::collections::BTreeMap<_, _>>().into());
+        self
+    }
+
+    #[inline]
+    pub fn version(mut self, value: impl Into<i64>) -> Self {
+        self.body.version = Some(value.into());
+        self
+    }
+
+    /// Sets [`version`](#method.version) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_version(mut self, value: Option<impl Into<i64>>) -> Self {
+        self.body.version = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`version`](#method.version), but takes a reference.
+    #[inline]
+    pub fn version_ref(self, value: &i64) -> Self {
+        self.version(*value)
+    }
+
+    #[inline]
+    pub fn pattern_fields(mut self, value: impl Iterator<Item = (String, impl Into<String>)>) -> Self {
+        self.body.pattern_fields = Some(value.map(|(key, value)| (key, value.into())).collect::<std::collections::BTreeMap<_, _>>().into());
+        self
+    }
+
+    /// Sets [`pattern_fields`](#method.pattern_fields) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_pattern_fields(mut self, value: Option<impl Iterator<Item = (String, impl Into<String>)>>) -> Self {
+        self.body.pattern_fields = value.map(|value| value.map(|(key, value)| (key, value.into())).collect::<std::collections::BTreeMap<_, _>>().into());
+        self
+    }
+}
+
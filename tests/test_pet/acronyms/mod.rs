@@ -0,0 +1,1335 @@
+
+#[allow(deprecated)]
+pub mod account {
+    include!("./account.rs");
+}
+
+#[allow(deprecated)]
+pub mod adoption {
+    include!("./adoption.rs");
+}
+
+#[allow(deprecated)]
+pub mod animal {
+    include!("./animal.rs");
+}
+
+#[allow(deprecated)]
+pub mod api_key {
+    include!("./api_key.rs");
+}
+
+#[allow(deprecated)]
+pub mod breeder {
+    include!("./breeder.rs");
+}
+
+#[allow(deprecated)]
+pub mod category {
+    include!("./category.rs");
+}
+
+#[allow(deprecated)]
+pub mod event {
+    include!("./event.rs");
+}
+
+#[allow(deprecated)]
+pub mod get_shipments_id_response {
+    include!("./get_shipments_id_response.rs");
+}
+
+#[allow(deprecated)]
+pub mod inventory {
+    include!("./inventory.rs");
+}
+
+#[allow(deprecated)]
+pub mod metadata {
+    include!("./metadata.rs");
+}
+
+#[allow(deprecated)]
+pub mod miscellaneous {
+    include!("./miscellaneous.rs");
+}
+
+#[allow(deprecated)]
+pub mod order {
+    include!("./order.rs");
+}
+
+#[allow(deprecated)]
+pub mod owner {
+    include!("./owner.rs");
+}
+
+#[allow(deprecated)]
+pub mod payment {
+    include!("./payment.rs");
+}
+
+#[allow(deprecated)]
+pub mod pet {
+    include!("./pet.rs");
+}
+
+#[allow(deprecated)]
+pub mod pet_filter {
+    include!("./pet_filter.rs");
+}
+
+#[allow(deprecated)]
+pub mod post_shipments_body {
+    include!("./post_shipments_body.rs");
+}
+
+#[allow(deprecated)]
+pub mod recursive_container {
+    include!("./recursive_container.rs");
+}
+
+#[allow(deprecated)]
+pub mod recursive_object {
+    include!("./recursive_object.rs");
+}
+
+#[allow(deprecated)]
+pub mod status {
+    include!("./status.rs");
+}
+
+#[allow(deprecated)]
+pub mod tag {
+    include!("./tag.rs");
+}
+
+#[allow(deprecated)]
+pub mod test_enum {
+    include!("./test_enum.rs");
+}
+
+#[allow(deprecated)]
+pub mod test_nested_array_with_object {
+    include!("./test_nested_array_with_object.rs");
+}
+
+#[allow(deprecated)]
+pub mod token {
+    include!("./token.rs");
+}
+
+#[allow(deprecated)]
+pub mod token_request {
+    include!("./token_request.rs");
+}
+
+pub mod client {
+    use futures::Stream;
+
+
+    use std::borrow::Cow;
+    use std::fmt::Debug;
+    use std::path::Path;
+
+    /// Common API errors.
+    #[derive(Debug, thiserror::Error)]
+    pub enum ApiError<R: Debug + Send + 'static> {
+        #[error("API request failed for path: {} (code: {})", _0, _1)]
+        Failure(String, http::status::StatusCode, R),
+        #[error("Unsupported media type in response: {}", _0)]
+        UnsupportedMediaType(String, R),
+        #[error("An error has occurred while performing the API request: {}", _0)]
+        Reqwest(reqwest::Error),
+        #[error("I/O error: {}", _0)]
+        Io(std::io::Error),
+        #[error("Path parameter {:?} is empty", _0)]
+        EmptyPathParameter(&'static str),
+        #[error("Invalid multipart response: {}", _0)]
+        InvalidMultipart(&'static str),
+        #[error("Response body is larger than {} bytes", _0)]
+        ResponseTooLarge(usize),
+        #[error("API request was cancelled")]
+        Cancelled,
+        #[error("Error en/decoding \"application/json\" data: {}", _0)]
+        ApplicationJson(serde_json::Error),
+        #[error("Error en/decoding \"application/yaml\" data: {}", _0)]
+        ApplicationYaml(serde_yaml::Error),
+    }
+
+    impl<R: Debug + Send + 'static> ApiError<R> {
+        /// Maps the response carried by this error (if any) using the given function.
+        pub fn map_response<S, F>(self, f: F) -> ApiError<S>
+            where S: Debug + Send + 'static,
+                  F: FnOnce(R) -> S
+        {
+            match self {
+                ApiError::Failure(path, code, resp) => ApiError::Failure(path, code, f(resp)),
+                ApiError::UnsupportedMediaType(ty, resp) => ApiError::UnsupportedMediaType(ty, f(resp)),
+                ApiError::Reqwest(e) => ApiError::Reqwest(e),
+                ApiError::Io(e) => ApiError::Io(e),
+                ApiError::EmptyPathParameter(name) => ApiError::EmptyPathParameter(name),
+                ApiError::InvalidMultipart(msg) => ApiError::InvalidMultipart(msg),
+                ApiError::ResponseTooLarge(limit) => ApiError::ResponseTooLarge(limit),
+                ApiError::Cancelled => ApiError::Cancelled,
+                ApiError::ApplicationJson(e) => ApiError::ApplicationJson(e),
+                ApiError::ApplicationYaml(e) => ApiError::ApplicationYaml(e),
+            }
+        }
+    }
+
+    /// Form object for building multipart request body.
+    pub trait Form: Sized {
+        /// Creates a new builder.
+        fn new() -> Self;
+
+        /// Adds the given key and value as text.
+        fn text<T, U>(self, key: T, value: U) -> Self
+            where T: Into<Cow<'static, str>>,
+                  U: Into<Cow<'static, str>>;
+
+        /// Adds the file from the given path for streaming.
+        fn file<K>(self, key: K, path: &Path) -> std::io::Result<Self>
+            where K: Into<Cow<'static, str>>;
+
+        /// Adds a file with the given (in-memory) contents. Forms which only
+        /// stream files from paths can leave this out, in which case this fails
+        /// with `ErrorKind::Unsupported`.
+        fn bytes<K>(self, key: K, bytes: Vec<u8>) -> std::io::Result<Self>
+            where K: Into<Cow<'static, str>>
+        {
+            let _ = (key, bytes);
+            Err(std::io::Error::new(std::io::ErrorKind::Unsupported, "in-memory parts aren't supported by this form"))
+        }
+
+        /// Adds the given file part (either from a path or from memory).
+        fn file_part<K>(self, key: K, part: &crate::util::FilePart) -> std::io::Result<Self>
+            where K: Into<Cow<'static, str>>
+        {
+            match part {
+                crate::util::FilePart::Path(p) => self.file(key, p),
+                crate::util::FilePart::Bytes(b) => self.bytes(key, b.clone()),
+            }
+        }
+    }
+
+    /// HTTP Request.
+    pub trait Request {
+        type Form: Form;
+
+        /// Sets the header with the given key and value.
+        fn header(self, name: &'static str, value: &str) -> Self;
+
+        /// Sets body using the given vector of bytes.
+        ///
+        /// **NOTE:** Appropriate `Content-Type` header must be set
+        /// after calling this method.
+        fn body_bytes(self, body: Vec<u8>) -> Self;
+
+        /// Sets JSON body based on the given value (and `Content-Type`, unless
+        /// it's already been set).
+        fn json<T: serde::Serialize>(self, value: &T) -> Self;
+
+        /// Sets `application/x-www-form-urlencoded` body based on the given value.
+        fn form<T: serde::Serialize>(self, value: &T) -> Self;
+
+        /// Sets `multipart/form-data` body using the given form.
+        fn multipart_form_data(self, form: Self::Form) -> Self;
+
+        /// Adds query parameters based on the given value.
+        ///
+        /// **NOTE:** This method can be called more than once (say, once for
+        /// the operation's parameters and then for API keys), in which case
+        /// the parameters must be appended to the existing ones.
+        fn query<T: serde::Serialize>(self, params: &T) -> Self;
+
+        /// Sets the timeout for this request (overriding the client's default).
+        /// Clients without per-request timeouts can ignore it, which is what
+        /// this does by default.
+        fn timeout(self, timeout: std::time::Duration) -> Self
+            where Self: Sized
+        {
+            let _ = timeout;
+            self
+        }
+
+        /// Marks this request as being made for the given operation. This does
+        /// nothing by default (mock clients can use it for matching requests).
+        fn operation_id(self, id: &'static str) -> Self
+            where Self: Sized
+        {
+            let _ = id;
+            self
+        }
+    }
+
+    impl Form for reqwest::multipart::Form {
+        fn new() -> Self {
+            reqwest::multipart::Form::new()
+        }
+
+        fn text<T, U>(self, key: T, value: U) -> Self
+            where T: Into<Cow<'static, str>>,
+                  U: Into<Cow<'static, str>>
+        {
+            reqwest::multipart::Form::text(self, key, value)
+        }
+
+        fn file<K>(self, key: K, path: &Path) -> std::io::Result<Self>
+            where K: Into<Cow<'static, str>>
+        {
+            use reqwest::multipart::{Form, Part};
+            use tokio_util::codec::{BytesCodec, FramedRead};
+
+            let fd = std::fs::File::open(path)?;
+            let reader = tokio::fs::File::from_std(fd);
+            let bytes_stream = FramedRead::new(reader, BytesCodec::new());
+            let part = Part::stream(reqwest::Body::wrap_stream(bytes_stream));
+            Ok(Form::part(self, key, part))
+        }
+
+        fn bytes<K>(self, key: K, bytes: Vec<u8>) -> std::io::Result<Self>
+            where K: Into<Cow<'static, str>>
+        {
+            Ok(reqwest::multipart::Form::part(self, key, reqwest::multipart::Part::bytes(bytes)))
+        }
+    }
+
+    impl Request for reqwest::RequestBuilder {
+        type Form = reqwest::multipart::Form;
+
+        fn header(self, name: &'static str, value: &str) -> Self {
+            reqwest::RequestBuilder::header(self, name, value)
+        }
+
+        fn multipart_form_data(self, form: Self::Form) -> Self {
+            self.multipart(form)
+        }
+
+        fn body_bytes(self, body: Vec<u8>) -> Self {
+            self.body(body)
+        }
+
+        fn json<T: serde::Serialize>(self, value: &T) -> Self {
+            <reqwest::RequestBuilder>::json(self, value)
+        }
+
+        fn form<T: serde::Serialize>(self, value: &T) -> Self {
+            reqwest::RequestBuilder::form(self, value)
+        }
+
+        fn query<T: serde::Serialize>(self, params: &T) -> Self {
+            reqwest::RequestBuilder::query(self, params)
+        }
+
+        fn timeout(self, timeout: std::time::Duration) -> Self {
+            reqwest::RequestBuilder::timeout(self, timeout)
+        }
+    }
+
+    /// HTTP Response.
+    #[async_trait::async_trait]
+    pub trait Response: Debug + Send + Sized {
+        type Bytes: AsRef<[u8]>;
+        type Error;
+
+        /// Gets the value for the given header name, if any.
+        fn header(&self, name: &'static str) -> Option<&str>;
+
+        /// Takes all headers from the response.
+        fn take_headers(&mut self) -> http::header::HeaderMap;
+
+        /// Status code for this response.
+        fn status(&self) -> http::status::StatusCode;
+
+        /// Media type for this response body (if any).
+        fn media_type(&self) -> Option<mime::MediaType>;
+
+        /// Response body as a stream.
+        fn stream(self) -> Box<dyn Stream<Item=Result<Self::Bytes, Self::Error>> + Send + Unpin>;
+
+        /// Vector of bytes from the response body.
+        async fn body_bytes(self) -> Result<Self::Bytes, ApiError<Self>>;
+    }
+
+    #[async_trait::async_trait]
+    impl Response for reqwest::Response {
+        type Bytes = bytes::Bytes;
+        type Error = reqwest::Error;
+
+        fn header(&self, name: &'static str) -> Option<&str> {
+            self.headers().get(name).and_then(|v| v.to_str().ok())
+        }
+
+        fn take_headers(&mut self) -> http::header::HeaderMap {
+            std::mem::replace(self.headers_mut(), http::header::HeaderMap::new())
+        }
+
+        fn status(&self) -> http::status::StatusCode {
+            reqwest::Response::status(self)
+        }
+
+        fn media_type(&self) -> Option<mime::MediaType> {
+            self.header(http::header::CONTENT_TYPE.as_str())
+                .and_then(|v| v.parse().ok())
+        }
+
+        fn stream(self) -> Box<dyn Stream<Item=Result<Self::Bytes, Self::Error>> + Send + Unpin> {
+            Box::new(self.bytes_stream()) as Box<_>
+        }
+
+        async fn body_bytes(self) -> Result<Self::Bytes, ApiError<Self>> {
+            Ok(self.bytes().await?)
+        }
+    }
+
+    /// Base URL of the API (unless it's been overridden with `ClientConfig::base_url`).
+    pub const BASE_URL: &str = "https://pets.com:8888/api";
+
+    /// Represents an API client.
+    #[async_trait::async_trait]
+    pub trait ApiClient {
+        type Request: Request + Send;
+        type Response: Response;
+
+        /// Consumes a method and a relative path and produces a request builder for a single API call.
+        fn request_builder(&self, method: http::Method, rel_path: &str) -> Self::Request;
+
+        /// Same as `request_builder`, but the path is relative to the given base URL
+        /// (instead of `BASE_URL`). Clients which don't deal with URLs can ignore
+        /// the base URL, which is what this does by default.
+        fn request_builder_with_base(&self, base_url: &str, method: http::Method, rel_path: &str) -> Self::Request {
+            let _ = base_url;
+            self.request_builder(method, rel_path)
+        }
+
+        /// Performs the HTTP request using the given `Request` object
+        /// and returns a `Response` future.
+        async fn make_request(&self, req: Self::Request) -> Result<Self::Response, ApiError<Self::Response>>;
+
+        /// Policy for retrying the failed requests made with this client (if any).
+        fn retry_policy(&self) -> Option<&RetryPolicy> {
+            None
+        }
+
+        /// Headers (like `User-Agent` or `Accept-Language`) added to all requests
+        /// made with this client, except for those set by the operation itself.
+        fn default_headers(&self) -> &[(&'static str, String)] {
+            &[]
+        }
+
+        /// Authenticates the request for an operation with the given security
+        /// requirements (alternatives, each listing the names of the schemes
+        /// which must be satisfied together). This does nothing by default.
+        fn authorize(&self, req: Self::Request, security: &[&[&str]]) -> Self::Request {
+            let _ = security;
+            req
+        }
+    }
+
+    /// Policy for retrying requests which have failed with connection errors
+    /// (or timeouts), and optionally with server errors (`5xx`).
+    ///
+    /// Only idempotent methods are retried by default, so that (say) a `POST`
+    /// which has reached the server isn't made twice.
+    #[derive(Debug, Clone)]
+    pub struct RetryPolicy {
+        max_attempts: u32,
+        backoff: std::time::Duration,
+        server_errors: bool,
+        all_methods: bool,
+    }
+
+    impl RetryPolicy {
+        /// Creates a policy which makes up to the given number of attempts (including
+        /// the first one), waiting for the given duration before the first retry
+        /// and twice as long before each subsequent retry.
+        pub fn new(max_attempts: u32, backoff: std::time::Duration) -> Self {
+            RetryPolicy {
+                max_attempts,
+                backoff,
+                server_errors: false,
+                all_methods: false,
+            }
+        }
+
+        /// Whether to retry the requests failing with server errors (`5xx`) as well.
+        pub fn server_errors(mut self, retry: bool) -> Self {
+            self.server_errors = retry;
+            self
+        }
+
+        /// Whether to retry the requests with non-idempotent methods (`POST`
+        /// and `PATCH`) as well.
+        pub fn all_methods(mut self, retry: bool) -> Self {
+            self.all_methods = retry;
+            self
+        }
+
+        /// Returns how long to wait before retrying the given (failed) attempt of
+        /// a request with the given method, or `None` if it shouldn't be retried.
+        pub fn delay(&self, method: &http::Method, attempt: u32) -> Option<std::time::Duration> {
+            let idempotent = method != http::Method::POST && method != http::Method::PATCH;
+            if attempt >= self.max_attempts || !(idempotent || self.all_methods) {
+                return None;
+            }
+
+            Some(self.backoff * 2u32.saturating_pow(attempt - 1))
+        }
+
+        /// Returns how long to wait before retrying the request which has failed
+        /// with the given error, if it should be retried.
+        fn delay_for_error<R: Debug + Send>(&self, method: &http::Method, attempt: u32, err: &ApiError<R>) -> Option<std::time::Duration> {
+            match err {
+                ApiError::Reqwest(e) if e.is_connect() || e.is_timeout() => self.delay(method, attempt),
+                ApiError::Io(_) => self.delay(method, attempt),
+                _ => None,
+            }
+        }
+
+        /// Returns how long to wait before retrying the request which has got
+        /// a response with the given status, if it should be retried.
+        fn delay_for_status(&self, method: &http::Method, attempt: u32, status: http::StatusCode) -> Option<std::time::Duration> {
+            if self.server_errors && status.is_server_error() {
+                self.delay(method, attempt)
+            } else {
+                None
+            }
+        }
+    }
+
+    /// Waits for the given duration (without depending on any async runtime).
+    ///
+    /// **NOTE:** This spawns a thread which sleeps for the duration, because the
+    /// clients (and executors) are up to the user and there's no timer which
+    /// works with all of them. This is only done between the retries of failed
+    /// requests, which are few (`RetryPolicy::new` caps the attempts) and are
+    /// already waiting on a backoff, so the cost of a thread doesn't matter.
+    async fn wait(duration: std::time::Duration) {
+        let (tx, rx) = futures::channel::oneshot::channel();
+        std::thread::spawn(move || {
+            std::thread::sleep(duration);
+            let _ = tx.send(());
+        });
+
+        let _ = rx.await;
+    }
+
+    #[async_trait::async_trait]
+    impl ApiClient for reqwest::Client {
+        type Request = reqwest::RequestBuilder;
+        type Response = reqwest::Response;
+
+        fn request_builder(&self, method: http::Method, rel_path: &str) -> Self::Request {
+            self.request_builder_with_base(BASE_URL, method, rel_path)
+        }
+
+        fn request_builder_with_base(&self, base_url: &str, method: http::Method, rel_path: &str) -> Self::Request {
+            let u = format!("{}/{}", base_url.trim_end_matches('/'), rel_path.trim_start_matches('/'));
+            self.request(method, &u)
+        }
+
+        async fn make_request(&self, req: Self::Request) -> Result<Self::Response, ApiError<Self::Response>> {
+            let req = req.build()?;
+            let resp = self.execute(req).await?;
+            Ok(resp)
+        }
+    }
+
+    /// Credential for one of the security schemes declared by the API.
+    #[derive(Clone)]
+    pub enum Credential {
+        /// Key for an `apiKey` scheme.
+        ApiKey(String),
+        /// Access token for an `oauth2` scheme.
+        Bearer(String),
+        /// Username and (optional) password for a `basic` scheme.
+        Basic {
+            username: String,
+            password: Option<String>,
+        },
+    }
+
+    impl Debug for Credential {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            // Never leak the secrets.
+            match self {
+                Credential::ApiKey(_) => f.write_str("ApiKey(..)"),
+                Credential::Bearer(_) => f.write_str("Bearer(..)"),
+                Credential::Basic { .. } => f.write_str("Basic { .. }"),
+            }
+        }
+    }
+
+    /// Where the key for an `apiKey` security scheme is sent.
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub enum ApiKeyLocation {
+        /// Header with the given name.
+        Header(&'static str),
+        /// Query parameter with the given name.
+        Query(&'static str),
+    }
+
+    /// Returns the location of the key for the given `apiKey` scheme (if it's been declared).
+    fn api_key_location(scheme: &str) -> Option<ApiKeyLocation> {
+        match scheme {
+            "account_key" => Some(ApiKeyLocation::Query("account")),
+            "api_key" => Some(ApiKeyLocation::Header("X-API-Key")),
+            _ => None,
+        }
+    }
+
+    /// Encodes the given bytes using the standard base64 alphabet (with padding).
+    fn base64_encode(bytes: &[u8]) -> String {
+        const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+        let mut encoded = String::with_capacity((bytes.len() + 2) / 3 * 4);
+        for chunk in bytes.chunks(3) {
+            let n = chunk.iter().enumerate()
+                .fold(0u32, |n, (i, &b)| n | u32::from(b) << (16 - 8 * i));
+            for i in 0..4 {
+                if i <= chunk.len() {
+                    encoded.push(ALPHABET[(n >> (18 - 6 * i) & 0x3f) as usize] as char);
+                } else {
+                    encoded.push('=');
+                }
+            }
+        }
+
+        encoded
+    }
+
+    /// Configuration shared by all requests sent through a `Client`.
+    ///
+    /// The configuration is shared (rather than copied) by its clones, so cloning
+    /// is cheap and changes (like refreshed credentials) are visible to all of them.
+    #[derive(Debug, Clone, Default)]
+    pub struct ClientConfig {
+        max_in_flight: Option<std::sync::Arc<tokio::sync::Semaphore>>,
+        max_response_size: Option<usize>,
+        timeout: Option<std::time::Duration>,
+        retry: Option<RetryPolicy>,
+        base_url: Option<String>,
+        headers: Vec<(&'static str, String)>,
+        credentials: std::sync::Arc<std::sync::RwLock<std::collections::BTreeMap<String, Credential>>>,
+    }
+
+    impl ClientConfig {
+        /// Sets the credential for the security scheme with the given name.
+        pub fn credential(self, scheme: &str, credential: Credential) -> Self {
+            self.set_credential(scheme, credential);
+            self
+        }
+
+        /// Replaces the credential for the security scheme with the given name
+        /// (say, with a refreshed token) in this config and all its clones.
+        pub fn set_credential(&self, scheme: &str, credential: Credential) {
+            self.credentials.write()
+                .unwrap_or_else(std::sync::PoisonError::into_inner)
+                .insert(scheme.into(), credential);
+        }
+
+        /// Returns the credential (if any) set for the given security scheme.
+        pub fn credential_for(&self, scheme: &str) -> Option<Credential> {
+            self.credentials.read()
+                .unwrap_or_else(std::sync::PoisonError::into_inner)
+                .get(scheme)
+                .cloned()
+        }
+
+        /// Limits the number of requests in flight at any given time (unlimited by default).
+        ///
+        /// A request occupies its slot until its response (or its body) has been
+        /// consumed or dropped. Clones of this config share the same slots.
+        pub fn max_in_flight(mut self, limit: usize) -> Self {
+            self.max_in_flight = Some(std::sync::Arc::new(tokio::sync::Semaphore::new(limit)));
+            self
+        }
+
+        /// Limits the size (in bytes) of the response bodies which are read into
+        /// memory for decoding (unlimited by default). Larger bodies are rejected
+        /// with `ApiError::ResponseTooLarge` as soon as they cross the limit.
+        ///
+        /// Streamed bodies (i.e., files and downloads) aren't limited.
+        pub fn max_response_size(mut self, limit: usize) -> Self {
+            self.max_response_size = Some(limit);
+            self
+        }
+
+        /// Sets the timeout for all requests, except for the operations
+        /// with their own timeout (`x-timeout`). Those can be overridden for
+        /// a single request by building it with `Sendable::request` and then
+        /// calling `Request::timeout`.
+        pub fn timeout(mut self, timeout: std::time::Duration) -> Self {
+            self.timeout = Some(timeout);
+            self
+        }
+
+        /// Retries the failed requests based on the given policy (no retries by default).
+        pub fn retry(mut self, policy: RetryPolicy) -> Self {
+            self.retry = Some(policy);
+            self
+        }
+
+        /// Sends requests to the given base URL (say, of a staging server or a proxy)
+        /// instead of `BASE_URL`.
+        pub fn base_url<U: Into<String>>(mut self, url: U) -> Self {
+            self.base_url = Some(url.into());
+            self
+        }
+
+        /// Adds a header (say, `User-Agent`) to all requests, unless the operation
+        /// sets it already (from a header parameter, for instance).
+        pub fn default_header<V: Into<String>>(mut self, name: &'static str, value: V) -> Self {
+            self.headers.push((name, value.into()));
+            self
+        }
+    }
+
+    /// API client which applies the given configuration to the wrapped client.
+    #[derive(Debug, Clone)]
+    pub struct Client<C = reqwest::Client> {
+        inner: C,
+        config: ClientConfig,
+    }
+
+    impl<C> Client<C> {
+        /// Creates a client from the given client and configuration.
+        pub fn new(inner: C, config: ClientConfig) -> Self {
+            Client { inner, config }
+        }
+
+        /// Returns the wrapped client.
+        pub fn inner(&self) -> &C {
+            &self.inner
+        }
+
+        /// Returns the configuration of this client.
+        pub fn config(&self) -> &ClientConfig {
+            &self.config
+        }
+    }
+
+    /// Response from a `Client`, holding on to its request slot (if any)
+    /// until the body has been read.
+    #[derive(Debug)]
+    pub struct ClientResponse<R> {
+        inner: R,
+        permit: Option<tokio::sync::OwnedSemaphorePermit>,
+        max_size: Option<usize>,
+    }
+
+    impl<R> ClientResponse<R> {
+        /// Returns the wrapped response.
+        pub fn into_inner(self) -> R {
+            self.inner
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl<R> Response for ClientResponse<R>
+        where R: Response + 'static,
+              R::Bytes: From<Vec<u8>>,
+              R::Error: Into<Box<dyn std::error::Error + Send + Sync>>
+    {
+        type Bytes = R::Bytes;
+        type Error = R::Error;
+
+        fn header(&self, name: &'static str) -> Option<&str> {
+            self.inner.header(name)
+        }
+
+        fn take_headers(&mut self) -> http::header::HeaderMap {
+            self.inner.take_headers()
+        }
+
+        fn status(&self) -> http::status::StatusCode {
+            self.inner.status()
+        }
+
+        fn media_type(&self) -> Option<mime::MediaType> {
+            self.inner.media_type()
+        }
+
+        fn stream(self) -> Box<dyn Stream<Item=Result<Self::Bytes, Self::Error>> + Send + Unpin> {
+            use futures::StreamExt;
+
+            let permit = self.permit;
+            Box::new(self.inner.stream().map(move |chunk| {
+                let _permit = &permit;
+                chunk
+            })) as Box<_>
+        }
+
+        async fn body_bytes(self) -> Result<Self::Bytes, ApiError<Self>> {
+            use futures::StreamExt;
+
+            let ClientResponse { inner, permit, max_size } = self;
+            let limit = match max_size {
+                Some(l) => l,
+                None => return inner.body_bytes().await
+                    .map_err(|e| e.map_response(|inner| ClientResponse { inner, permit, max_size })),
+            };
+
+            // Bail out early if we know the size, but don't trust it while reading.
+            let size = inner.header(http::header::CONTENT_LENGTH.as_str())
+                .and_then(|v| v.parse::<usize>().ok());
+            if matches!(size, Some(s) if s > limit) {
+                return Err(ApiError::ResponseTooLarge(limit));
+            }
+
+            let mut body = Vec::with_capacity(size.unwrap_or_default());
+            let mut stream = inner.stream();
+            while let Some(chunk) = stream.next().await {
+                let chunk = chunk.map_err(|e| std::io::Error::new(std::io::ErrorKind::Other, e))?;
+                if body.len() + chunk.as_ref().len() > limit {
+                    return Err(ApiError::ResponseTooLarge(limit));
+                }
+
+                body.extend_from_slice(chunk.as_ref());
+            }
+
+            Ok(body.into())
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl<C> ApiClient for Client<C>
+        where C: ApiClient + Send + Sync,
+              C::Response: 'static,
+              <C::Response as Response>::Bytes: From<Vec<u8>>,
+              <C::Response as Response>::Error: Into<Box<dyn std::error::Error + Send + Sync>>
+    {
+        type Request = C::Request;
+        type Response = ClientResponse<C::Response>;
+
+        fn request_builder(&self, method: http::Method, rel_path: &str) -> Self::Request {
+            // Operations setting their own timeout override this one later.
+            let req = match &self.config.base_url {
+                Some(u) => self.inner.request_builder_with_base(u, method, rel_path),
+                None => self.inner.request_builder(method, rel_path),
+            };
+            match self.config.timeout {
+                Some(t) => req.timeout(t),
+                None => req,
+            }
+        }
+
+        async fn make_request(&self, req: Self::Request) -> Result<Self::Response, ApiError<Self::Response>> {
+            let permit = match &self.config.max_in_flight {
+                Some(s) => Some(s.clone().acquire_owned().await),
+                None => None,
+            };
+
+            let max_size = self.config.max_response_size;
+            match self.inner.make_request(req).await {
+                Ok(inner) => Ok(ClientResponse { inner, permit, max_size }),
+                Err(e) => Err(e.map_response(|inner| ClientResponse { inner, permit, max_size })),
+            }
+        }
+
+        fn retry_policy(&self) -> Option<&RetryPolicy> {
+            self.config.retry.as_ref()
+        }
+
+        fn default_headers(&self) -> &[(&'static str, String)] {
+            &self.config.headers
+        }
+
+        fn authorize(&self, mut req: Self::Request, security: &[&[&str]]) -> Self::Request {
+            // Use the first alternative for which we have all the credentials.
+            let credentials = security.iter()
+                .filter(|schemes| !schemes.is_empty())
+                .find_map(|schemes| schemes.iter()
+                    .map(|&s| self.config.credential_for(s).map(|c| (s, c)))
+                    .collect::<Option<Vec<_>>>());
+
+            for (scheme, credential) in credentials.into_iter().flatten() {
+                match credential {
+                    Credential::Bearer(token) => {
+                        req = req.header("Authorization", &format!("Bearer {}", token));
+                    },
+                    Credential::Basic { username, password } => {
+                        let pair = format!("{}:{}", username, password.as_deref().unwrap_or_default());
+                        req = req.header("Authorization", &format!("Basic {}", base64_encode(pair.as_bytes())));
+                    },
+                    Credential::ApiKey(key) => match api_key_location(scheme) {
+                        Some(ApiKeyLocation::Header(name)) => req = req.header(name, &key),
+                        Some(ApiKeyLocation::Query(name)) => req = req.query(&[(name, key)]),
+                        None => (),
+                    },
+                }
+            }
+
+            req
+        }
+    }
+
+    /// A trait for indicating that the implementor can send an API call.
+    #[async_trait::async_trait]
+    pub trait Sendable<Client>
+    where
+        Client: ApiClient + Sync + 'static,
+        Self: Sized
+    {
+        /// The output object from this API request.
+        type Output: serde::de::DeserializeOwned;
+
+        /// HTTP method used by this call.
+        const METHOD: http::Method;
+
+        /// ID of this operation (if the spec provides one).
+        const OPERATION_ID: Option<&'static str> = None;
+
+        /// Security requirements of this call (see `ApiClient::authorize`).
+        const SECURITY: &'static [&'static [&'static str]] = &[];
+
+        /// Relative URL for this API call formatted appropriately with parameter values.
+        ///
+        /// **NOTE:** This URL **must** begin with `/`.
+        fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, ApiError<Client::Response>>;
+
+        /// Modifier for this object. Builders override this method if they
+        /// wish to add query parameters, set body, etc.
+        ///
+        /// This can be applied to any request (say, one made with `reqwest`
+        /// directly), as long as its URL has been built from `rel_path`.
+        fn modify(&self, req: Client::Request) -> Result<Client::Request, ApiError<Client::Response>> {
+            Ok(req)
+        }
+
+        /// Whether this call sets the given header (case-insensitive) on its own,
+        /// in which case the client's default for that header is skipped.
+        fn sets_header(&self, name: &str) -> bool {
+            let _ = name;
+            false
+        }
+
+        /// Builds the request for this API call without sending it, so that it
+        /// can be altered (with custom headers, signatures, etc.) and then sent
+        /// using `ApiClient::make_request`.
+        fn request(&self, client: &Client) -> Result<Client::Request, ApiError<Client::Response>> {
+            let rel_path = self.rel_path()?;
+            let mut req = client.request_builder(Self::METHOD, &rel_path);
+            if let Some(id) = Self::OPERATION_ID {
+                req = req.operation_id(id);
+            }
+
+            for (name, value) in client.default_headers() {
+                if !self.sets_header(name) {
+                    req = req.header(name, value);
+                }
+            }
+
+            let req = self.modify(req)?;
+            Ok(client.authorize(req, Self::SECURITY))
+        }
+
+        /// Assembles the request for this API call (exactly as `send` would)
+        /// and returns it without sending. This is a testing convenience for
+        /// asserting on the method, URL, headers and body without a live server.
+        fn dry_run(&self, client: &Client) -> Result<reqwest::Request, ApiError<Client::Response>>
+            where Client: ApiClient<Request = reqwest::RequestBuilder>
+        {
+            Ok(self.request(client)?.build()?)
+        }
+
+        /// Sends the request and returns a future for the response object.
+        async fn send(&self, client: &Client) -> Result<ResponseWrapper<Self::Output, Self>, ApiError<Client::Response>> {
+            let resp = self.send_raw(client).await?;
+            ResponseWrapper::decode(resp).await
+        }
+
+        /// Sends the request, unless the given future (say, a shutdown signal)
+        /// completes first, in which case the request is dropped (which aborts it)
+        /// and `ApiError::Cancelled` is returned.
+        async fn send_cancellable<C>(&self, client: &Client, cancel: C) -> Result<ResponseWrapper<Self::Output, Self>, ApiError<Client::Response>>
+            where C: std::future::Future<Output = ()> + Send
+        {
+            let send = self.send(client);
+            futures::pin_mut!(send, cancel);
+            match futures::future::select(send, cancel).await {
+                futures::future::Either::Left((resp, _)) => resp,
+                futures::future::Either::Right(_) => Err(ApiError::Cancelled),
+            }
+        }
+
+        /// Binds this builder to the given client, so that the resulting value
+        /// can be `.await`ed directly (instead of calling `send`).
+        ///
+        /// **NOTE:** This relies on `IntoFuture`, which needs Rust 1.64 (or newer).
+        fn with_client(self, client: &Client) -> WithClient<'_, Self, Client> {
+            WithClient { builder: self, client }
+        }
+
+        /// Sends the request (like `send`), but decodes failures into the given error
+        /// type, which is generated for operations declaring error responses.
+        async fn send_typed<E>(&self, client: &Client) -> Result<ResponseWrapper<Self::Output, Self>, E>
+            where E: OperationError<Client::Response>
+        {
+            let err = match self.send(client).await {
+                Ok(resp) => return Ok(resp),
+                Err(e) => e,
+            };
+
+            Err(E::from_api_error(err).await)
+        }
+
+        /// Sends the request and returns the response body as a stream of chunks
+        /// along with its total length (if known), so that callers can report
+        /// progress for large downloads.
+        async fn download(&self, client: &Client) -> Result<crate::util::Download<<Client::Response as Response>::Bytes, <Client::Response as Response>::Error>, ApiError<Client::Response>> {
+            let resp = self.send_raw(client).await?;
+            let total = resp
+                .header(http::header::CONTENT_LENGTH.as_str())
+                .and_then(|v| v.parse().ok());
+            Ok(crate::util::Download::new(crate::util::ResponseStream(resp.stream()), total))
+        }
+
+        /// Convenience method for returning a raw response after sending a request
+        /// (and retrying it based on the client's policy, if needed).
+        async fn send_raw(&self, client: &Client) -> Result<Client::Response, ApiError<Client::Response>> {
+            let mut attempt = 1;
+            let resp = loop {
+                let req = self.request(client)?;
+                let policy = client.retry_policy();
+                let delay = match client.make_request(req).await {
+                    Ok(resp) => match policy.and_then(|p| p.delay_for_status(&Self::METHOD, attempt, resp.status())) {
+                        Some(d) => d,
+                        None => break resp,
+                    },
+                    Err(e) => match policy.and_then(|p| p.delay_for_error(&Self::METHOD, attempt, &e)) {
+                        Some(d) => d,
+                        None => return Err(e),
+                    },
+                };
+
+                attempt += 1;
+                wait(delay).await;
+            };
+
+            if resp.status().is_success() {
+                Ok(resp)
+            } else {
+                Err(ApiError::Failure(self.rel_path()?.into_owned(), resp.status(), resp))
+            }
+        }
+    }
+
+    /// A fully built API call bound to a client. Awaiting this sends the request.
+    pub struct WithClient<'a, B, Client> {
+        builder: B,
+        client: &'a Client,
+    }
+
+    impl<'a, B, Client> std::future::IntoFuture for WithClient<'a, B, Client>
+    where
+        B: Sendable<Client> + Send + Sync + 'a,
+        Client: ApiClient + Sync + Send + 'static,
+    {
+        type Output = Result<ResponseWrapper<B::Output, B>, ApiError<Client::Response>>;
+        type IntoFuture = futures::future::BoxFuture<'a, Self::Output>;
+
+        fn into_future(self) -> Self::IntoFuture {
+            Box::pin(async move { self.builder.send(self.client).await })
+        }
+    }
+
+    /// Operation-specific error, decoded from a failed API call (usually based
+    /// on the status code of the response).
+    #[async_trait::async_trait]
+    pub trait OperationError<R: Response + 'static>: Sized {
+        /// Converts the given error (decoding the response body if needed).
+        async fn from_api_error(err: ApiError<R>) -> Self;
+    }
+
+    /// Decodes the body of a response (based on its media type).
+    pub async fn decode_body<T, R>(resp: R) -> Result<T, ApiError<R>>
+        where T: serde::de::DeserializeOwned,
+              R: Response + 'static
+    {
+        ResponseWrapper::<T, ()>::decode(resp).await.map(|w| w.object)
+    }
+
+    /// Wrapper containing response-related information.
+    pub struct ResponseWrapper<T, B> {
+        /// Response object
+        pub object: T,
+        /// Response headers
+        pub headers: http::HeaderMap,
+        /// Response status code
+        pub status: http::status::StatusCode,
+        _builder: core::marker::PhantomData<B>,
+    }
+
+    impl<T, B> ResponseWrapper<T, B> {
+        pub(crate) async fn wrap<F, R>(mut resp: R, f: impl FnOnce(R) -> F) -> Result<Self, ApiError<R>>
+            where F: std::future::Future<Output=Result<T, ApiError<R>>>,
+                  R: Response + 'static
+        {
+            let status = resp.status();
+            let headers = resp.take_headers();
+            Ok(ResponseWrapper {
+                object: f(resp).await?,
+                headers,
+                status,
+                _builder: core::marker::PhantomData,
+            })
+        }
+
+        /// Maps the response object (keeping the headers and status code).
+        pub fn map<U>(self, f: impl FnOnce(T) -> U) -> ResponseWrapper<U, B> {
+            ResponseWrapper {
+                object: f(self.object),
+                headers: self.headers,
+                status: self.status,
+                _builder: core::marker::PhantomData,
+            }
+        }
+
+        /// Whether the server has responded with a part of the body
+        /// (`206 Partial Content`) for the requested range.
+        pub fn is_partial(&self) -> bool {
+            self.status == http::status::StatusCode::PARTIAL_CONTENT
+        }
+    }
+
+    impl<T: serde::de::DeserializeOwned, B> ResponseWrapper<T, B> {
+        /// Decodes the response body (based on its media type) and wraps it.
+        pub(crate) async fn decode<R: Response + 'static>(resp: R) -> Result<Self, ApiError<R>> {
+            let media = resp.media_type();
+            if let Some(ty) = media {
+                if media_types::M_0.matches(&ty) {
+                    return ResponseWrapper::wrap(resp, |r| async {
+                        let bytes = r.body_bytes().await?;
+                        serde_json::from_reader(strip_bom(bytes.as_ref())).map_err(ApiError::from)
+                    }).await
+                }
+                else if media_types::M_1.matches(&ty) {
+                    return ResponseWrapper::wrap(resp, |r| async {
+                        let bytes = r.body_bytes().await?;
+                        serde_yaml::from_reader(strip_bom(bytes.as_ref())).map_err(ApiError::from)
+                    }).await
+                }
+            }
+
+            let ty = resp.header(http::header::CONTENT_TYPE.as_str())
+                .map(|v| String::from_utf8_lossy(v.as_bytes()).into_owned())
+                .unwrap_or_default();
+            Err(ApiError::UnsupportedMediaType(ty, resp))
+        }
+    }
+
+    /// Strips the UTF-8 byte order mark (if any) which some servers prepend to
+    /// response bodies, because decoders reject it (unlike leading whitespace).
+    fn strip_bom(bytes: &[u8]) -> &[u8] {
+        bytes.strip_prefix(b"\xEF\xBB\xBF").unwrap_or(bytes)
+    }
+
+    impl<'de, T, B> serde::de::Deserialize<'de> for ResponseWrapper<T, B> {
+        fn deserialize<D>(_: D) -> Result<Self, D::Error>
+        where
+            D: serde::de::Deserializer<'de>
+        {
+            unimplemented!("ResponseWrapper is not supposed to be deserialized.");
+        }
+    }
+
+    impl<T, B> std::ops::Deref for ResponseWrapper<T, B> {
+        type Target = T;
+
+        fn deref(&self) -> &Self::Target {
+            &self.object
+        }
+    }
+
+    impl<T, B> std::ops::DerefMut for ResponseWrapper<T, B> {
+        fn deref_mut(&mut self) -> &mut <Self as std::ops::Deref>::Target {
+            &mut self.object
+        }
+    }
+
+    /// Formats the value of a path parameter, failing if it's empty
+    /// (because an empty value would collapse the path segments).
+    ///
+    /// The value is percent-encoded as a path segment (so that `/`, `?`, spaces,
+    /// etc. don't change the URL), leaving the existing escapes (`%XX`) as they are.
+    pub fn path_param<R: Response + 'static>(name: &'static str, value: impl std::fmt::Display) -> Result<String, ApiError<R>> {
+        let value = value.to_string();
+        if value.is_empty() {
+            return Err(ApiError::EmptyPathParameter(name));
+        }
+
+        let bytes = value.as_bytes();
+        let mut segment = String::with_capacity(bytes.len());
+        for (i, &b) in bytes.iter().enumerate() {
+            match b {
+                b'%' if bytes.len() > i + 2
+                    && bytes[i + 1].is_ascii_hexdigit()
+                    && bytes[i + 2].is_ascii_hexdigit() => segment.push('%'),
+                b'a'..=b'z' | b'A'..=b'Z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~'
+                | b'!' | b'$' | b'&' | b'\'' | b'(' | b')' | b'*' | b'+' | b',' | b';' | b'='
+                | b':' | b'@' => segment.push(b as char),
+                _ => segment.push_str(&format!("%{:02X}", b)),
+            }
+        }
+
+        Ok(segment)
+    }
+
+    /// Flattens the given object into `deepObject` query parameters, i.e., its fields
+    /// are sent as `name[field]=value` (with more brackets for nested objects).
+    /// Unset (`null`) fields are skipped, and array items are sent as repeated keys.
+    pub fn deep_object_query<T: serde::Serialize>(name: &str, value: &T) -> Vec<(String, String)> {
+        fn flatten(key: String, value: serde_json::Value, pairs: &mut Vec<(String, String)>) {
+            match value {
+                serde_json::Value::Null => (),
+                serde_json::Value::Object(map) => for (k, v) in map {
+                    flatten(format!("{}[{}]", key, k), v, pairs);
+                },
+                serde_json::Value::Array(items) => for v in items {
+                    flatten(key.clone(), v, pairs);
+                },
+                serde_json::Value::String(s) => pairs.push((key, s)),
+                v => pairs.push((key, v.to_string())),
+            }
+        }
+
+        let mut pairs = vec![];
+        if let Ok(value) = serde_json::to_value(value) {
+            flatten(name.into(), value, &mut pairs);
+        }
+
+        pairs
+    }
+
+    /// Formats a cookie parameter as `name=value`, percent-encoding the bytes
+    /// which aren't allowed in cookie values (RFC 6265).
+    pub fn cookie_pair(name: &str, value: impl std::fmt::Display) -> String {
+        let mut pair = format!("{}=", name);
+        for b in value.to_string().bytes() {
+            match b {
+                0x21 | 0x23..=0x24 | 0x26..=0x2B | 0x2D..=0x3A | 0x3C..=0x5B | 0x5D..=0x7E => pair.push(b as char),
+                _ => pair.push_str(&format!("%{:02X}", b)),
+            }
+        }
+
+        pair
+    }
+
+    /// Reads a `multipart/mixed` response (with the given boundary) whose first
+    /// part is JSON (see `MultipartMixed` for the expected structure).
+    pub async fn multipart_mixed<T, R>(boundary: Option<String>, resp: R) -> Result<crate::util::MultipartMixed<T>, ApiError<R>>
+        where T: serde::de::DeserializeOwned,
+              R: Response + 'static
+    {
+        let boundary = boundary.ok_or(ApiError::InvalidMultipart("missing boundary in content type"))?;
+        let bytes = resp.body_bytes().await?;
+        let mut parts = crate::util::parse_multipart(&boundary, bytes.as_ref())
+            .ok_or(ApiError::InvalidMultipart("malformed body"))?
+            .into_iter();
+        let first = parts.next().ok_or(ApiError::InvalidMultipart("missing metadata part"))?;
+        Ok(crate::util::MultipartMixed {
+            metadata: serde_json::from_slice(&first.body)?,
+            parts: parts.collect(),
+        })
+    }
+
+    pub mod media_types {
+        use lazy_static::lazy_static;
+
+        lazy_static! {
+            pub static ref M_0: mime::MediaRange =
+                mime::MediaRange::parse("application/json").expect("cannot parse \"application/json\" as media range");
+            pub static ref M_1: mime::MediaRange =
+                mime::MediaRange::parse("application/yaml").expect("cannot parse \"application/yaml\" as media range");
+        }
+    }
+
+    impl<R: Response + 'static> From<std::io::Error> for ApiError<R> {
+        fn from(e: std::io::Error) -> Self {
+            ApiError::Io(e)
+        }
+    }
+
+    impl<R: Response + 'static> From<reqwest::Error> for ApiError<R> {
+        fn from(e: reqwest::Error) -> Self {
+            ApiError::Reqwest(e)
+        }
+    }
+
+    impl<R: Response + 'static> From<serde_json::Error> for ApiError<R> {
+        fn from(e: serde_json::Error) -> Self {
+            ApiError::ApplicationJson(e)
+        }
+    }
+
+    impl<R: Response + 'static> From<serde_yaml::Error> for ApiError<R> {
+        fn from(e: serde_yaml::Error) -> Self {
+            ApiError::ApplicationYaml(e)
+        }
+    }
+}
+
+pub mod callbacks {
+    include!("./callbacks.rs");
+}
+
+/// Commonly used traits and types, so that a single glob import gets you going.
+///
+/// This re-exports the client traits (`ApiClient`, `Sendable`, etc.), `ApiError`,
+/// `ResponseWrapper`, the client configuration, the mock client (if it's generated),
+/// the helper types from `util` and all the definitions whose names are unique
+/// across modules.
+#[allow(unused_imports, deprecated)]
+pub mod prelude {
+    pub use crate::client::{ApiClient, ApiError, Client, ClientConfig, Credential, Form, Request, Response, ResponseWrapper, Sendable};
+    pub use crate::util::{Delimited, Download, ResponseStream};
+    pub use crate::callbacks::*;
+    pub use crate::api_key::APIKey;
+    pub use crate::api_key::APIKeyHTTPHeaders;
+    pub use crate::account::Account;
+    pub use crate::adoption::Adoption;
+    pub use crate::animal::Animal;
+    pub use crate::breeder::Breeder;
+    pub use crate::category::Category;
+    pub use crate::event::Event;
+    pub use crate::get_shipments_id_response::GetShipmentsIdResponse;
+    pub use crate::get_shipments_id_response::GetShipmentsIdResponseAddress;
+    pub use crate::inventory::Inventory;
+    pub use crate::inventory::InventoryBinsValue;
+    pub use crate::metadata::Metadata;
+    pub use crate::metadata::MetadataLabels;
+    pub use crate::miscellaneous::Miscellaneous;
+    pub use crate::order::Order;
+    pub use crate::order::OrderAddress;
+    pub use crate::order::OrderListItem;
+    pub use crate::order::OrderStatus;
+    pub use crate::order::OrderTestStringEnum;
+    pub use crate::owner::Owner;
+    pub use crate::owner::OwnerGuest;
+    pub use crate::payment::Payment;
+    pub use crate::payment::PaymentBankTransfer;
+    pub use crate::payment::PaymentCard;
+    pub use crate::pet::Pet;
+    pub use crate::pet_filter::PetFilter;
+    pub use crate::post_shipments_body::PostShipmentsBody;
+    pub use crate::post_shipments_body::PostShipmentsBodyAddress;
+    pub use crate::recursive_container::RecursiveContainer;
+    pub use crate::recursive_object::RecursiveObject;
+    pub use crate::status::Status;
+    pub use crate::tag::Tag;
+    pub use crate::test_enum::TestEnum;
+    pub use crate::test_nested_array_with_object::TestNestedArrayWithObjectItemItem;
+    pub use crate::test_nested_array_with_object::TestNestedArrayWithObjectItemItemBar;
+    pub use crate::token::Token;
+    pub use crate::token_request::TokenRequest;
+}
+
+pub mod generics {
+    include!("./generics.rs");
+}
+
+pub mod util {
+    include!("./util.rs");
+}
@@ -0,0 +1,567 @@
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Order {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address: Option<crate::order::OrderAddress>,
+    #[serde(rename = "deliverBy", skip_serializing_if = "Option::is_none")]
+    pub deliver_by: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub list: Option<Vec<crate::order::OrderListItem>>,
+    #[serde(rename = "placedAt", skip_serializing_if = "Option::is_none")]
+    pub placed_at: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub receipt: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<crate::order::OrderStatus>,
+    #[serde(rename = "test-string-enum", skip_serializing_if = "Option::is_none")]
+    pub test_string_enum: Option<crate::order::OrderTestStringEnum>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total: Option<f64>,
+}
+
+impl Default for Order {
+    fn default() -> Self {
+        Order {
+            address: Default::default(),
+            deliver_by: Default::default(),
+            id: Default::default(),
+            list: Default::default(),
+            placed_at: Default::default(),
+            receipt: Default::default(),
+            status: Some(crate::order::OrderStatus::OrderPlaced),
+            test_string_enum: Some(crate::order::OrderTestStringEnum::Number_72_9),
+            total: Default::default(),
+        }
+    }
+}
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct OrderAddress {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line1: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line2: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrderListItem {
+    #[serde(rename = "petId", skip_serializing_if = "Option::is_none")]
+    pub pet_id: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quantity: Option<i64>,
+}
+
+impl Default for OrderListItem {
+    fn default() -> Self {
+        OrderListItem {
+            pet_id: Default::default(),
+            quantity: Some(1_i64),
+        }
+    }
+}
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[allow(non_camel_case_types)]
+pub enum OrderStatus {
+    #[serde(rename = "paymentPending")]
+    PaymentPending,
+    #[serde(rename = "orderPlaced")]
+    OrderPlaced,
+    #[serde(rename = "shipped")]
+    Shipped,
+    #[serde(rename = "fulfilled")]
+    Fulfilled,
+}
+impl Default for OrderStatus {
+    fn default() -> Self {
+        OrderStatus::PaymentPending
+    }
+}
+
+impl OrderStatus {
+    /// All the variants of this enum.
+    pub const ALL: &'static [Self] = &[
+        OrderStatus::PaymentPending,
+        OrderStatus::OrderPlaced,
+        OrderStatus::Shipped,
+        OrderStatus::Fulfilled,
+    ];
+
+    /// Returns the value of this variant as it appears on the wire
+    /// (i.e., its serialized form).
+    pub fn as_wire_str(&self) -> &'static str {
+        match self {
+            OrderStatus::PaymentPending => "paymentPending",
+            OrderStatus::OrderPlaced => "orderPlaced",
+            OrderStatus::Shipped => "shipped",
+            OrderStatus::Fulfilled => "fulfilled",
+        }
+    }
+}
+
+impl std::fmt::Display for OrderStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.as_wire_str())
+    }
+}
+
+impl std::str::FromStr for OrderStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL.iter().find(|v| v.as_wire_str() == s).cloned().ok_or_else(|| {
+            let expected: Vec<_> = Self::ALL.iter().map(Self::as_wire_str).collect();
+            format!("unknown value {:?} for OrderStatus (expected one of {:?})", s, expected)
+        })
+    }
+}
+
+impl std::convert::TryFrom<&str> for OrderStatus {
+    type Error = String;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl std::convert::TryFrom<String> for OrderStatus {
+    type Error = String;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[allow(non_camel_case_types)]
+pub enum OrderTestStringEnum {
+    #[serde(rename = "booya")]
+    Booya,
+    #[serde(rename = "72.9")]
+    Number_72_9,
+    #[serde(rename = "true")]
+    True,
+    #[serde(rename = "-53")]
+    Number__53,
+    #[serde(rename = "BOOYA")]
+    BOOYA,
+    #[serde(rename = "self")]
+    Self_,
+    #[serde(rename = "2nd")]
+    Value_2nd,
+    #[serde(rename = "")]
+    Empty,
+    #[serde(rename = "Booya")]
+    Booya2,
+    #[serde(rename = "booya1")]
+    Booya1,
+    #[serde(rename = "-")]
+    Empty1,
+}
+impl Default for OrderTestStringEnum {
+    fn default() -> Self {
+        OrderTestStringEnum::Booya
+    }
+}
+
+impl OrderTestStringEnum {
+    /// All the variants of this enum.
+    pub const ALL: &'static [Self] = &[
+        OrderTestStringEnum::Booya,
+        OrderTestStringEnum::Number_72_9,
+        OrderTestStringEnum::True,
+        OrderTestStringEnum::Number__53,
+        OrderTestStringEnum::BOOYA,
+        OrderTestStringEnum::Self_,
+        OrderTestStringEnum::Value_2nd,
+        OrderTestStringEnum::Empty,
+        OrderTestStringEnum::Booya2,
+        OrderTestStringEnum::Booya1,
+        OrderTestStringEnum::Empty1,
+    ];
+
+    /// Returns the value of this variant as it appears on the wire
+    /// (i.e., its serialized form).
+    pub fn as_wire_str(&self) -> &'static str {
+        match self {
+            OrderTestStringEnum::Booya => "booya",
+            OrderTestStringEnum::Number_72_9 => "72.9",
+            OrderTestStringEnum::True => "true",
+            OrderTestStringEnum::Number__53 => "-53",
+            OrderTestStringEnum::BOOYA => "BOOYA",
+            OrderTestStringEnum::Self_ => "self",
+            OrderTestStringEnum::Value_2nd => "2nd",
+            OrderTestStringEnum::Empty => "",
+            OrderTestStringEnum::Booya2 => "Booya",
+            OrderTestStringEnum::Booya1 => "booya1",
+            OrderTestStringEnum::Empty1 => "-",
+        }
+    }
+}
+
+impl std::fmt::Display for OrderTestStringEnum {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.as_wire_str())
+    }
+}
+
+impl std::str::FromStr for OrderTestStringEnum {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL.iter().find(|v| v.as_wire_str() == s).cloned().ok_or_else(|| {
+            let expected: Vec<_> = Self::ALL.iter().map(Self::as_wire_str).collect();
+            format!("unknown value {:?} for OrderTestStringEnum (expected one of {:?})", s, expected)
+        })
+    }
+}
+
+impl std::convert::TryFrom<&str> for OrderTestStringEnum {
+    type Error = String;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl std::convert::TryFrom<String> for OrderTestStringEnum {
+    type Error = String;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl Order {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> OrderBuilder {
+        OrderBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> OrderBuilder
+    where
+        Self: Clone,
+    {
+        OrderBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<Order> for OrderBuilder {
+    fn into(self) -> Order {
+        self.body
+    }
+}
+
+/// Builder for [`Order`](./struct.Order.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct OrderBuilder {
+    body: self::Order,
+}
+
+impl OrderBuilder {
+    #[inline]
+    pub fn address(mut self, value: crate::order::OrderAddress) -> Self {
+        self.body.address = Some(value.into());
+        self
+    }
+
+    /// Sets [`address`](#method.address) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_address(mut self, value: Option<crate::order::OrderAddress>) -> Self {
+        self.body.address = value.map(|value| value.into());
+        self
+    }
+
+    #[inline]
+    pub fn deliver_by(mut self, value: impl Into<String>) -> Self {
+        self.body.deliver_by = Some(value.into());
+        self
+    }
+
+    /// Sets [`deliver_by`](#method.deliver_by) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_deliver_by(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.deliver_by = value.map(|value| value.into());
+        self
+    }
+
+    #[inline]
+    pub fn id(mut self, value: impl Into<i64>) -> Self {
+        self.body.id = Some(value.into());
+        self
+    }
+
+    /// Sets [`id`](#method.id) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_id(mut self, value: Option<impl Into<i64>>) -> Self {
+        self.body.id = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`id`](#method.id), but takes a reference.
+    #[inline]
+    pub fn id_ref(self, value: &i64) -> Self {
+        self.id(*value)
+    }
+
+    #[inline]
+    pub fn list(mut self, value: impl Iterator<Item = crate::order::OrderListItem>) -> Self {
+        self.body.list = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`list`](#method.list) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_list(mut self, value: Option<impl Iterator<Item = crate::order::OrderListItem>>) -> Self {
+        self.body.list = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`list`](#method.list), but takes the values as a `Vec`.
+    #[inline]
+    pub fn list_vec(mut self, value: impl Into<Vec<crate::order::OrderListItem>>) -> Self {
+        self.body.list = Some(value.into());
+        self
+    }
+
+    #[inline]
+    pub fn placed_at(mut self, value: impl Into<String>) -> Self {
+        self.body.placed_at = Some(value.into());
+        self
+    }
+
+    /// Sets [`placed_at`](#method.placed_at) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_placed_at(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.placed_at = value.map(|value| value.into());
+        self
+    }
+
+    #[inline]
+    pub fn receipt(mut self, value: impl Into<String>) -> Self {
+        self.body.receipt = Some(value.into());
+        self
+    }
+
+    /// Sets [`receipt`](#method.receipt) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_receipt(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.receipt = value.map(|value| value.into());
+        self
+    }
+
+    #[inline]
+    pub fn status(mut self, value: crate::order::OrderStatus) -> Self {
+        self.body.status = Some(value.into());
+        self
+    }
+
+    /// Sets [`status`](#method.status) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_status(mut self, value: Option<crate::order::OrderStatus>) -> Self {
+        self.body.status = value.map(|value| value.into());
+        self
+    }
+
+    #[inline]
+    pub fn test_string_enum(mut self, value: crate::order::OrderTestStringEnum) -> Self {
+        self.body.test_string_enum = Some(value.into());
+        self
+    }
+
+    /// Sets [`test_string_enum`](#method.test_string_enum) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_test_string_enum(mut self, value: Option<crate::order::OrderTestStringEnum>) -> Self {
+        self.body.test_string_enum = value.map(|value| value.into());
+        self
+    }
+
+    #[inline]
+    pub fn total(mut self, value: impl Into<f64>) -> Self {
+        self.body.total = Some(value.into());
+        self
+    }
+
+    /// Sets [`total`](#method.total) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_total(mut self, value: Option<impl Into<f64>>) -> Self {
+        self.body.total = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`total`](#method.total), but takes a reference.
+    #[inline]
+    pub fn total_ref(self, value: &f64) -> Self {
+        self.total(*value)
+    }
+}
+
+impl OrderAddress {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> OrderAddressBuilder {
+        OrderAddressBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> OrderAddressBuilder
+    where
+        Self: Clone,
+    {
+        OrderAddressBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<OrderAddress> for OrderAddressBuilder {
+    fn into(self) -> OrderAddress {
+        self.body
+    }
+}
+
+/// Builder for [`OrderAddress`](./struct.OrderAddress.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct OrderAddressBuilder {
+    body: self::OrderAddress,
+}
+
+impl OrderAddressBuilder {
+    #[inline]
+    pub fn code(mut self, value: impl Into<String>) -> Self {
+        self.body.code = Some(value.into());
+        self
+    }
+
+    /// Sets [`code`](#method.code) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_code(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.code = value.map(|value| value.into());
+        self
+    }
+
+    #[inline]
+    pub fn line1(mut self, value: impl Into<String>) -> Self {
+        self.body.line1 = Some(value.into());
+        self
+    }
+
+    /// Sets [`line1`](#method.line1) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_line1(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.line1 = value.map(|value| value.into());
+        self
+    }
+
+    #[inline]
+    pub fn line2(mut self, value: impl Into<String>) -> Self {
+        self.body.line2 = Some(value.into());
+        self
+    }
+
+    /// Sets [`line2`](#method.line2) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_line2(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.line2 = value.map(|value| value.into());
+        self
+    }
+
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> Self {
+        self.body.name = Some(value.into());
+        self
+    }
+
+    /// Sets [`name`](#method.name) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_name(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.name = value.map(|value| value.into());
+        self
+    }
+}
+
+impl OrderListItem {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> OrderListItemBuilder {
+        OrderListItemBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> OrderListItemBuilder
+    where
+        Self: Clone,
+    {
+        OrderListItemBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<OrderListItem> for OrderListItemBuilder {
+    fn into(self) -> OrderListItem {
+        self.body
+    }
+}
+
+/// Builder for [`OrderListItem`](./struct.OrderListItem.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct OrderListItemBuilder {
+    body: self::OrderListItem,
+}
+
+impl OrderListItemBuilder {
+    #[inline]
+    pub fn pet_id(mut self, value: impl Into<i64>) -> Self {
+        self.body.pet_id = Some(value.into());
+        self
+    }
+
+    /// Sets [`pet_id`](#method.pet_id) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_pet_id(mut self, value: Option<impl Into<i64>>) -> Self {
+        self.body.pet_id = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`pet_id`](#method.pet_id), but takes a reference.
+    #[inline]
+    pub fn pet_id_ref(self, value: &i64) -> Self {
+        self.pet_id(*value)
+    }
+
+    #[inline]
+    pub fn quantity(mut self, value: impl Into<i64>) -> Self {
+        self.body.quantity = Some(value.into());
+        self
+    }
+
+    /// Sets [`quantity`](#method.quantity) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_quantity(mut self, value: Option<impl Into<i64>>) -> Self {
+        self.body.quantity = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`quantity`](#method.quantity), but takes a reference.
+    #[inline]
+    pub fn quantity_ref(self, value: &i64) -> Self {
+        self.quantity(*value)
+    }
+}
+
+
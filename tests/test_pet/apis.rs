@@ -0,0 +1,165 @@
+
+use crate::client::Sendable;
+
+/// Operations tagged with `default`.
+///
+/// This trait is object-safe (so it can be used as `Box<dyn DefaultApi<reqwest::Client>>`).
+/// To keep it that way, methods are not generic - each method takes the builder for
+/// its operation (with all the required fields and parameters set) and returns
+/// a boxed future (through `async_trait`).
+#[async_trait::async_trait]
+pub trait DefaultApi<Client: crate::client::ApiClient + Send + Sync + 'static>: Send + Sync {
+    /// Delete multiple pets
+    async fn delete_pets_pet_id(&self, op: crate::status::StatusDeleteBuilder<crate::generics::PetIdExists>) -> Result<<crate::status::StatusDeleteBuilder<crate::generics::PetIdExists> as crate::client::Sendable<Client>>::Output, crate::client::ApiError<Client::Response>>;
+
+    async fn get_another_route_referring_recursive_object(&self, op: crate::recursive_container::RecursiveContainerGetBuilder) -> Result<<crate::recursive_container::RecursiveContainerGetBuilder as crate::client::Sendable<Client>>::Output, crate::client::ApiError<Client::Response>>;
+
+    /// Download the pedigree certificate of a pet.
+    async fn get_pet_certificate(&self, op: crate::miscellaneous::MiscellaneousGetBuilder<crate::generics::PetIdExists>) -> Result<<crate::miscellaneous::MiscellaneousGetBuilder<crate::generics::PetIdExists> as crate::client::Sendable<Client>>::Output, crate::client::ApiError<Client::Response>>;
+
+    /// Fetch pet along with its photo
+    async fn get_pet_with_photo(&self, op: crate::pet::PetGetBuilder4<crate::generics::PetIdExists>) -> Result<<crate::pet::PetGetBuilder4<crate::generics::PetIdExists> as crate::client::Sendable<Client>>::Output, crate::client::ApiError<Client::Response>>;
+
+    /// Download the archive of all pets.
+    async fn get_test_archive(&self, op: crate::miscellaneous::MiscellaneousGetBuilder1) -> Result<<crate::miscellaneous::MiscellaneousGetBuilder1 as crate::client::Sendable<Client>>::Output, crate::client::ApiError<Client::Response>>;
+
+    async fn get_test_array(&self, op: crate::miscellaneous::MiscellaneousGetBuilder2) -> Result<<crate::miscellaneous::MiscellaneousGetBuilder2 as crate::client::Sendable<Client>>::Output, crate::client::ApiError<Client::Response>>;
+
+    async fn get_test_file(&self, op: crate::miscellaneous::MiscellaneousGetBuilder3) -> Result<<crate::miscellaneous::MiscellaneousGetBuilder3 as crate::client::Sendable<Client>>::Output, crate::client::ApiError<Client::Response>>;
+
+    /// Fetch the pets of some kind.
+    async fn list_pets_by_kind(&self, op: crate::pet::PetGetBuilder1<crate::generics::KindExists>) -> Result<<crate::pet::PetGetBuilder1<crate::generics::KindExists> as crate::client::Sendable<Client>>::Output, crate::client::ApiError<Client::Response>>;
+
+    /// Revoke an access token
+    async fn post_oauth_revoke(&self, op: crate::token_request::TokenRequestPostBuilder<crate::generics::GrantTypeExists>) -> Result<<crate::token_request::TokenRequestPostBuilder<crate::generics::GrantTypeExists> as crate::client::Sendable<Client>>::Output, crate::client::ApiError<Client::Response>>;
+
+    /// Issue an access token
+    async fn post_oauth_token(&self, op: crate::token_request::TokenRequestPostBuilder1<crate::generics::GrantTypeExists>) -> Result<<crate::token_request::TokenRequestPostBuilder1<crate::generics::GrantTypeExists> as crate::client::Sendable<Client>>::Output, crate::client::ApiError<Client::Response>>;
+
+    async fn post_route_referring_recursive_object(&self, op: crate::recursive_container::RecursiveContainerPostBuilder1) -> Result<<crate::recursive_container::RecursiveContainerPostBuilder1 as crate::client::Sendable<Client>>::Output, crate::client::ApiError<Client::Response>>;
+
+    /// Create shipment for order
+    async fn post_shipments(&self, op: crate::post_shipments_body::PostShipmentsBodyPostBuilder) -> Result<<crate::post_shipments_body::PostShipmentsBodyPostBuilder as crate::client::Sendable<Client>>::Output, crate::client::ApiError<Client::Response>>;
+
+    async fn post_test_parameter_values(&self, op: crate::miscellaneous::MiscellaneousPostBuilder4<crate::generics::ValuesExists>) -> Result<<crate::miscellaneous::MiscellaneousPostBuilder4<crate::generics::ValuesExists> as crate::client::Sendable<Client>>::Output, crate::client::ApiError<Client::Response>>;
+
+    async fn put_test_file(&self, op: crate::status::StatusPutBuilder1<crate::generics::SomeDataFileExists, crate::generics::FoobarExists>) -> Result<<crate::status::StatusPutBuilder1<crate::generics::SomeDataFileExists, crate::generics::FoobarExists> as crate::client::Sendable<Client>>::Output, crate::client::ApiError<Client::Response>>;
+
+    /// Upload the pedigree certificate of a pet.
+    async fn upload_pet_certificate(&self, op: crate::pet::PetPutBuilder3<crate::generics::PetIdExists>) -> Result<<crate::pet::PetPutBuilder3<crate::generics::PetIdExists> as crate::client::Sendable<Client>>::Output, crate::client::ApiError<Client::Response>>;
+}
+
+#[async_trait::async_trait]
+impl<Client: crate::client::ApiClient + Send + Sync + 'static> DefaultApi<Client> for Client {
+    async fn delete_pets_pet_id(&self, op: crate::status::StatusDeleteBuilder<crate::generics::PetIdExists>) -> Result<<crate::status::StatusDeleteBuilder<crate::generics::PetIdExists> as crate::client::Sendable<Client>>::Output, crate::client::ApiError<Client::Response>> {
+        Ok(op.send(self).await?.object)
+    }
+
+    async fn get_another_route_referring_recursive_object(&self, op: crate::recursive_container::RecursiveContainerGetBuilder) -> Result<<crate::recursive_container::RecursiveContainerGetBuilder as crate::client::Sendable<Client>>::Output, crate::client::ApiError<Client::Response>> {
+        Ok(op.send(self).await?.object)
+    }
+
+    async fn get_pet_certificate(&self, op: crate::miscellaneous::MiscellaneousGetBuilder<crate::generics::PetIdExists>) -> Result<<crate::miscellaneous::MiscellaneousGetBuilder<crate::generics::PetIdExists> as crate::client::Sendable<Client>>::Output, crate::client::ApiError<Client::Response>> {
+        Ok(op.send(self).await?.object)
+    }
+
+    async fn get_pet_with_photo(&self, op: crate::pet::PetGetBuilder4<crate::generics::PetIdExists>) -> Result<<crate::pet::PetGetBuilder4<crate::generics::PetIdExists> as crate::client::Sendable<Client>>::Output, crate::client::ApiError<Client::Response>> {
+        Ok(op.send(self).await?.object)
+    }
+
+    async fn get_test_archive(&self, op: crate::miscellaneous::MiscellaneousGetBuilder1) -> Result<<crate::miscellaneous::MiscellaneousGetBuilder1 as crate::client::Sendable<Client>>::Output, crate::client::ApiError<Client::Response>> {
+        Ok(op.send(self).await?.object)
+    }
+
+    async fn get_test_array(&self, op: crate::miscellaneous::MiscellaneousGetBuilder2) -> Result<<crate::miscellaneous::MiscellaneousGetBuilder2 as crate::client::Sendable<Client>>::Output, crate::client::ApiError<Client::Response>> {
+        Ok(op.send(self).await?.object)
+    }
+
+    async fn get_test_file(&self, op: crate::miscellaneous::MiscellaneousGetBuilder3) -> Result<<crate::miscellaneous::MiscellaneousGetBuilder3 as crate::client::Sendable<Client>>::Output, crate::client::ApiError<Client::Response>> {
+        Ok(op.send(self).await?.object)
+    }
+
+    async fn list_pets_by_kind(&self, op: crate::pet::PetGetBuilder1<crate::generics::KindExists>) -> Result<<crate::pet::PetGetBuilder1<crate::generics::KindExists> as crate::client::Sendable<Client>>::Output, crate::client::ApiError<Client::Response>> {
+        Ok(op.send(self).await?.object)
+    }
+
+    async fn post_oauth_revoke(&self, op: crate::token_request::TokenRequestPostBuilder<crate::generics::GrantTypeExists>) -> Result<<crate::token_request::TokenRequestPostBuilder<crate::generics::GrantTypeExists> as crate::client::Sendable<Client>>::Output, crate::client::ApiError<Client::Response>> {
+        Ok(op.send(self).await?.object)
+    }
+
+    async fn post_oauth_token(&self, op: crate::token_request::TokenRequestPostBuilder1<crate::generics::GrantTypeExists>) -> Result<<crate::token_request::TokenRequestPostBuilder1<crate::generics::GrantTypeExists> as crate::client::Sendable<Client>>::Output, crate::client::ApiError<Client::Response>> {
+        Ok(op.send(self).await?.object)
+    }
+
+    async fn post_route_referring_recursive_object(&self, op: crate::recursive_container::RecursiveContainerPostBuilder1) -> Result<<crate::recursive_container::RecursiveContainerPostBuilder1 as crate::client::Sendable<Client>>::Output, crate::client::ApiError<Client::Response>> {
+        Ok(op.send(self).await?.object)
+    }
+
+    async fn post_shipments(&self, op: crate::post_shipments_body::PostShipmentsBodyPostBuilder) -> Result<<crate::post_shipments_body::PostShipmentsBodyPostBuilder as crate::client::Sendable<Client>>::Output, crate::client::ApiError<Client::Response>> {
+        Ok(op.send(self).await?.object)
+    }
+
+    async fn post_test_parameter_values(&self, op: crate::miscellaneous::MiscellaneousPostBuilder4<crate::generics::ValuesExists>) -> Result<<crate::miscellaneous::MiscellaneousPostBuilder4<crate::generics::ValuesExists> as crate::client::Sendable<Client>>::Output, crate::client::ApiError<Client::Response>> {
+        Ok(op.send(self).await?.object)
+    }
+
+    async fn put_test_file(&self, op: crate::status::StatusPutBuilder1<crate::generics::SomeDataFileExists, crate::generics::FoobarExists>) -> Result<<crate::status::StatusPutBuilder1<crate::generics::SomeDataFileExists, crate::generics::FoobarExists> as crate::client::Sendable<Client>>::Output, crate::client::ApiError<Client::Response>> {
+        Ok(op.send(self).await?.object)
+    }
+
+    async fn upload_pet_certificate(&self, op: crate::pet::PetPutBuilder3<crate::generics::PetIdExists>) -> Result<<crate::pet::PetPutBuilder3<crate::generics::PetIdExists> as crate::client::Sendable<Client>>::Output, crate::client::ApiError<Client::Response>> {
+        Ok(op.send(self).await?.object)
+    }
+}
+
+/// Operations tagged with `pet`.
+///
+/// This trait is object-safe (so it can be used as `Box<dyn PetApi<reqwest::Client>>`).
+/// To keep it that way, methods are not generic - each method takes the builder for
+/// its operation (with all the required fields and parameters set) and returns
+/// a boxed future (through `async_trait`).
+#[async_trait::async_trait]
+pub trait PetApi<Client: crate::client::ApiClient + Send + Sync + 'static>: Send + Sync {
+    /// Add a new pet to the store
+    async fn add_pet(&self, op: crate::pet::PetPostBuilder<crate::generics::XAuthExists, crate::generics::IdExists, crate::generics::NameExists, serde_yaml::Value>) -> Result<<crate::pet::PetPostBuilder<crate::generics::XAuthExists, crate::generics::IdExists, crate::generics::NameExists, serde_yaml::Value> as crate::client::Sendable<Client>>::Output, crate::client::ApiError<Client::Response>>;
+
+    /// Find pet by ID
+    async fn get_pet_by_id(&self, op: crate::pet::PetGetBuilder2<crate::generics::PetIdExists>) -> Result<<crate::pet::PetGetBuilder2<crate::generics::PetIdExists> as crate::client::Sendable<Client>>::Output, crate::client::ApiError<Client::Response>>;
+
+    /// Update an existing pet
+    async fn update_pet(&self, op: crate::pet::PetPutBuilder2<crate::generics::PetIdExists, crate::generics::IdExists, crate::generics::NameExists, serde_yaml::Value>) -> Result<<crate::pet::PetPutBuilder2<crate::generics::PetIdExists, crate::generics::IdExists, crate::generics::NameExists, serde_yaml::Value> as crate::client::Sendable<Client>>::Output, crate::client::ApiError<Client::Response>>;
+}
+
+#[async_trait::async_trait]
+impl<Client: crate::client::ApiClient + Send + Sync + 'static> PetApi<Client> for Client {
+    async fn add_pet(&self, op: crate::pet::PetPostBuilder<crate::generics::XAuthExists, crate::generics::IdExists, crate::generics::NameExists, serde_yaml::Value>) -> Result<<crate::pet::PetPostBuilder<crate::generics::XAuthExists, crate::generics::IdExists, crate::generics::NameExists, serde_yaml::Value> as crate::client::Sendable<Client>>::Output, crate::client::ApiError<Client::Response>> {
+        Ok(op.send(self).await?.object)
+    }
+
+    async fn get_pet_by_id(&self, op: crate::pet::PetGetBuilder2<crate::generics::PetIdExists>) -> Result<<crate::pet::PetGetBuilder2<crate::generics::PetIdExists> as crate::client::Sendable<Client>>::Output, crate::client::ApiError<Client::Response>> {
+        Ok(op.send(self).await?.object)
+    }
+
+    async fn update_pet(&self, op: crate::pet::PetPutBuilder2<crate::generics::PetIdExists, crate::generics::IdExists, crate::generics::NameExists, serde_yaml::Value>) -> Result<<crate::pet::PetPutBuilder2<crate::generics::PetIdExists, crate::generics::IdExists, crate::generics::NameExists, serde_yaml::Value> as crate::client::Sendable<Client>>::Output, crate::client::ApiError<Client::Response>> {
+        Ok(op.send(self).await?.object)
+    }
+}
+
+/// Operations tagged with `store`.
+///
+/// This trait is object-safe (so it can be used as `Box<dyn StoreApi<reqwest::Client>>`).
+/// To keep it that way, methods are not generic - each method takes the builder for
+/// its operation (with all the required fields and parameters set) and returns
+/// a boxed future (through `async_trait`).
+#[async_trait::async_trait]
+pub trait StoreApi<Client: crate::client::ApiClient + Send + Sync + 'static>: Send + Sync {
+    /// Fetch shipment by ID
+    async fn get_shipment(&self, op: crate::get_shipments_id_response::GetShipmentsIdResponseGetBuilder<crate::generics::IdExists, crate::generics::SessionExists>) -> Result<<crate::get_shipments_id_response::GetShipmentsIdResponseGetBuilder<crate::generics::IdExists, crate::generics::SessionExists> as crate::client::Sendable<Client>>::Output, crate::client::ApiError<Client::Response>>;
+}
+
+#[async_trait::async_trait]
+impl<Client: crate::client::ApiClient + Send + Sync + 'static> StoreApi<Client> for Client {
+    async fn get_shipment(&self, op: crate::get_shipments_id_response::GetShipmentsIdResponseGetBuilder<crate::generics::IdExists, crate::generics::SessionExists>) -> Result<<crate::get_shipments_id_response::GetShipmentsIdResponseGetBuilder<crate::generics::IdExists, crate::generics::SessionExists> as crate::client::Sendable<Client>>::Output, crate::client::ApiError<Client::Response>> {
+        Ok(op.send(self).await?.object)
+    }
+}
@@ -0,0 +1,12 @@
+use array_strings::tag::Tag;
+
+#[test]
+fn test_max_length_on_deserialization() {
+    let tag: Tag = serde_json::from_str(r#"{"id": 1, "name": "sixteen chars!!!"}"#).unwrap();
+    assert_eq!(tag.name.as_deref(), Some("sixteen chars!!!"));
+
+    // `name` has `maxLength: 16`, so longer values are rejected.
+    let err = serde_json::from_str::<Tag>(r#"{"id": 1, "name": "seventeen chars!!"}"#).unwrap_err();
+    assert!(err.is_data(), "unexpected error: {}", err);
+    assert!(err.to_string().contains("16"), "unexpected error: {}", err);
+}
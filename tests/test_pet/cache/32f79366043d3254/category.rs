@@ -0,0 +1,342 @@
+
+/// [Naming rules \[RFC 1123\]](<https://example.com/docs/category_(naming)>)
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct Category {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub count: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub level: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub position: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rank: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ratio: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub score: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub serial: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ticket: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub weight: Option<f32>,
+}
+
+impl Category {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> CategoryBuilder {
+        CategoryBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> CategoryBuilder
+    where
+        Self: Clone,
+    {
+        CategoryBuilder {
+            body: self.clone(),
+        }
+    }
+
+    /// Check the fields of this object against the constraints (like `minimum`
+    /// or `pattern`) in the spec.
+    pub fn validate(&self) -> Result<(), crate::util::ValidationError> {
+        if let Some(v) = self.level {
+            if v < -2147483647 {
+                return Err(crate::util::ValidationError {
+                    field: "level",
+                    reason: "must be at least -2147483647",
+                });
+            }
+        }
+        if let Some(v) = &self.name {
+            lazy_static::lazy_static! {
+                static ref PATTERN: regex::Regex = regex::Regex::new("^[a-z][a-z0-9-]*$").expect("invalid pattern?");
+            }
+
+            if !PATTERN.is_match(v) {
+                return Err(crate::util::ValidationError {
+                    field: "name",
+                    reason: "must match the pattern \"^[a-z][a-z0-9-]*$\"",
+                });
+            }
+        }
+        if let Some(v) = self.position {
+            if v < 1 {
+                return Err(crate::util::ValidationError {
+                    field: "position",
+                    reason: "must be at least 1",
+                });
+            }
+            if v > 1000 {
+                return Err(crate::util::ValidationError {
+                    field: "position",
+                    reason: "must be at most 1000",
+                });
+            }
+        }
+        if let Some(v) = self.rank {
+            if v <= 0 {
+                return Err(crate::util::ValidationError {
+                    field: "rank",
+                    reason: "must be greater than 0",
+                });
+            }
+            if v > 9 {
+                return Err(crate::util::ValidationError {
+                    field: "rank",
+                    reason: "must be at most 9",
+                });
+            }
+        }
+        if let Some(v) = self.ticket {
+            if v < -9007199254740993 {
+                return Err(crate::util::ValidationError {
+                    field: "ticket",
+                    reason: "must be at least -9007199254740993",
+                });
+            }
+            if v >= 9007199254740993 {
+                return Err(crate::util::ValidationError {
+                    field: "ticket",
+                    reason: "must be less than 9007199254740993",
+                });
+            }
+        }
+        if let Some(v) = self.weight {
+            if v < 0.5_f32 {
+                return Err(crate::util::ValidationError {
+                    field: "weight",
+                    reason: "must be at least 0.5",
+                });
+            }
+            if v >= 100_f32 {
+                return Err(crate::util::ValidationError {
+                    field: "weight",
+                    reason: "must be less than 100",
+                });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Into<Category> for CategoryBuilder {
+    fn into(self) -> Category {
+        self.body
+    }
+}
+
+/// Builder for [`Category`](./struct.Category.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct CategoryBuilder {
+    body: self::Category,
+}
+
+impl CategoryBuilder {
+    #[inline]
+    pub fn count(mut self, value: impl Into<i64>) -> Self {
+        self.body.count = Some(value.into());
+        self
+    }
+
+    /// Sets [`count`](#method.count) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_count(mut self, value: Option<impl Into<i64>>) -> Self {
+        self.body.count = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`count`](#method.count), but takes a reference.
+    #[inline]
+    pub fn count_ref(self, value: &i64) -> Self {
+        self.count(*value)
+    }
+
+    #[inline]
+    pub fn id(mut self, value: impl Into<i64>) -> Self {
+        self.body.id = Some(value.into());
+        self
+    }
+
+    /// Sets [`id`](#method.id) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_id(mut self, value: Option<impl Into<i64>>) -> Self {
+        self.body.id = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`id`](#method.id), but takes a reference.
+    #[inline]
+    pub fn id_ref(self, value: &i64) -> Self {
+        self.id(*value)
+    }
+
+    #[inline]
+    pub fn level(mut self, value: impl Into<i32>) -> Self {
+        self.body.level = Some(value.into());
+        self
+    }
+
+    /// Sets [`level`](#method.level) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_level(mut self, value: Option<impl Into<i32>>) -> Self {
+        self.body.level = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`level`](#method.level), but takes a reference.
+    #[inline]
+    pub fn level_ref(self, value: &i32) -> Self {
+        self.level(*value)
+    }
+
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> Self {
+        self.body.name = Some(value.into());
+        self
+    }
+
+    /// Sets [`name`](#method.name) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_name(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.name = value.map(|value| value.into());
+        self
+    }
+
+    #[inline]
+    pub fn position(mut self, value: impl Into<i64>) -> Self {
+        self.body.position = Some(value.into());
+        self
+    }
+
+    /// Sets [`position`](#method.position) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_position(mut self, value: Option<impl Into<i64>>) -> Self {
+        self.body.position = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`position`](#method.position), but takes a reference.
+    #[inline]
+    pub fn position_ref(self, value: &i64) -> Self {
+        self.position(*value)
+    }
+
+    #[inline]
+    pub fn rank(mut self, value: impl Into<i32>) -> Self {
+        self.body.rank = Some(value.into());
+        self
+    }
+
+    /// Sets [`rank`](#method.rank) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_rank(mut self, value: Option<impl Into<i32>>) -> Self {
+        self.body.rank = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`rank`](#method.rank), but takes a reference.
+    #[inline]
+    pub fn rank_ref(self, value: &i32) -> Self {
+        self.rank(*value)
+    }
+
+    #[inline]
+    pub fn ratio(mut self, value: impl Into<f64>) -> Self {
+        self.body.ratio = Some(value.into());
+        self
+    }
+
+    /// Sets [`ratio`](#method.ratio) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_ratio(mut self, value: Option<impl Into<f64>>) -> Self {
+        self.body.ratio = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`ratio`](#method.ratio), but takes a reference.
+    #[inline]
+    pub fn ratio_ref(self, value: &f64) -> Self {
+        self.ratio(*value)
+    }
+
+    #[inline]
+    pub fn score(mut self, value: impl Into<f64>) -> Self {
+        self.body.score = Some(value.into());
+        self
+    }
+
+    /// Sets [`score`](#method.score) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_score(mut self, value: Option<impl Into<f64>>) -> Self {
+        self.body.score = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`score`](#method.score), but takes a reference.
+    #[inline]
+    pub fn score_ref(self, value: &f64) -> Self {
+        self.score(*value)
+    }
+
+    #[inline]
+    pub fn serial(mut self, value: impl Into<String>) -> Self {
+        self.body.serial = Some(value.into());
+        self
+    }
+
+    /// Sets [`serial`](#method.serial) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_serial(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.serial = value.map(|value| value.into());
+        self
+    }
+
+    #[inline]
+    pub fn ticket(mut self, value: impl Into<i64>) -> Self {
+        self.body.ticket = Some(value.into());
+        self
+    }
+
+    /// Sets [`ticket`](#method.ticket) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_ticket(mut self, value: Option<impl Into<i64>>) -> Self {
+        self.body.ticket = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`ticket`](#method.ticket), but takes a reference.
+    #[inline]
+    pub fn ticket_ref(self, value: &i64) -> Self {
+        self.ticket(*value)
+    }
+
+    #[inline]
+    pub fn weight(mut self, value: impl Into<f32>) -> Self {
+        self.body.weight = Some(value.into());
+        self
+    }
+
+    /// Sets [`weight`](#method.weight) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_weight(mut self, value: Option<impl Into<f32>>) -> Self {
+        self.body.weight = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`weight`](#method.weight), but takes a reference.
+    #[inline]
+    pub fn weight_ref(self, value: &f32) -> Self {
+        self.weight(*value)
+    }
+}
@@ -0,0 +1,433 @@
+
+use futures::stream::{Stream, StreamExt};
+use serde::{Deserialize, Deserializer};
+use tokio::io::{AsyncWrite, AsyncWriteExt};
+
+use std::error::Error;
+use std::fmt::{self, Display, Write};
+use std::io;
+use std::marker::{PhantomData, Unpin};
+use std::ops::{Deref, DerefMut};
+use std::pin::Pin;
+use std::str::FromStr;
+use std::task::{Context, Poll};
+
+/// HTTP body response stream.
+pub struct ResponseStream<T, E>(pub Box<dyn Stream<Item=Result<T, E>> + Unpin>);
+
+/// **NOTE:** This is just a stub. It panics on deserialization.
+impl<'de, T, E> Deserialize<'de> for ResponseStream<T, E> {
+    fn deserialize<D>(_: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>
+    {
+        unimplemented!("Response stream is not supposed to be deserialized");
+    }
+}
+
+impl<T, E> ResponseStream<T, E>
+    where T: AsRef<[u8]>,
+          E: Into<Box<dyn Error + Send + Sync + 'static>>
+{
+    /// Copy this stream to anything that implements `AsyncWrite`.
+    pub async fn to_writer<W>(mut self, writer: &mut W) -> io::Result<()>
+        where W: AsyncWrite + Unpin + ?Sized
+    {
+        while let Some(r) = self.0.next().await {
+            let chunk = r.map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
+            writer.write_all(chunk.as_ref()).await?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Response body as a stream of chunks, along with its total length (if the
+/// server has specified `Content-Length`) for tracking the download progress.
+pub struct Download<T, E> {
+    stream: ResponseStream<T, E>,
+    total: Option<u64>,
+    received: u64,
+}
+
+impl<T, E> Download<T, E> {
+    /// Creates a download from the given stream and the total length of its body.
+    pub fn new(stream: ResponseStream<T, E>, total: Option<u64>) -> Self {
+        Download {
+            stream,
+            total,
+            received: 0,
+        }
+    }
+
+    /// Total length of the body in bytes (if it's known).
+    pub fn total(&self) -> Option<u64> {
+        self.total
+    }
+
+    /// Number of bytes received so far.
+    pub fn received(&self) -> u64 {
+        self.received
+    }
+
+    /// Returns the underlying stream.
+    pub fn into_stream(self) -> ResponseStream<T, E> {
+        self.stream
+    }
+}
+
+impl<T: AsRef<[u8]>, E> Stream for Download<T, E> {
+    type Item = Result<T, E>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        let item = futures::ready!(Pin::new(&mut this.stream.0).poll_next(cx));
+        if let Some(Ok(chunk)) = &item {
+            this.received += chunk.as_ref().len() as u64;
+        }
+
+        Poll::Ready(item)
+    }
+}
+
+/// Response made of multiple parts (`multipart/mixed`).
+///
+/// **NOTE:** The first part is assumed to be JSON, and it's decoded into
+/// `metadata`. The remaining parts are kept as they are (in order).
+#[derive(Debug, Clone)]
+pub struct MultipartMixed<T> {
+    /// Object decoded from the first (JSON) part.
+    pub metadata: T,
+    /// Remaining parts in the response.
+    pub parts: Vec<MultipartPart>,
+}
+
+/// **NOTE:** This is just a stub. Multipart bodies are parsed by the client,
+/// so this always fails.
+impl<'de, T> Deserialize<'de> for MultipartMixed<T> {
+    fn deserialize<D>(_: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>
+    {
+        Err(serde::de::Error::custom("multipart/mixed bodies can't be deserialized"))
+    }
+}
+
+/// Single part in a multipart body.
+#[derive(Debug, Clone, Default)]
+pub struct MultipartPart {
+    /// Headers for this part (with lowercased names).
+    pub headers: Vec<(String, String)>,
+    /// Body of this part.
+    pub body: Vec<u8>,
+}
+
+impl MultipartPart {
+    /// Returns the value of the given header (if any) in this part.
+    pub fn header(&self, name: &str) -> Option<&str> {
+        let name = name.to_ascii_lowercase();
+        self.headers.iter().find(|(k, _)| *k == name).map(|(_, v)| v.as_str())
+    }
+
+    /// Returns the content type of this part (if any).
+    pub fn content_type(&self) -> Option<&str> {
+        self.header("content-type")
+    }
+}
+
+/// Returns the boundary from the given `Content-Type` header value (if any).
+pub fn multipart_boundary(content_type: &str) -> Option<&str> {
+    content_type.split(';').skip(1).find_map(|param| {
+        let mut kv = param.splitn(2, '=');
+        match (kv.next(), kv.next()) {
+            (Some(k), Some(v)) if k.trim().eq_ignore_ascii_case("boundary") => Some(v.trim().trim_matches('"')),
+            _ => None,
+        }
+    })
+}
+
+/// Splits the given multipart body into parts using the given boundary.
+/// Returns `None` if the body is malformed.
+pub fn parse_multipart(boundary: &str, body: &[u8]) -> Option<Vec<MultipartPart>> {
+    fn find(haystack: &[u8], needle: &[u8], start: usize) -> Option<usize> {
+        haystack.get(start..)?
+            .windows(needle.len())
+            .position(|w| w == needle)
+            .map(|i| i + start)
+    }
+
+    let delimiter = format!("--{}", boundary);
+    let delimiter = delimiter.as_bytes();
+    let mut parts = vec![];
+    let mut pos = find(body, delimiter, 0)? + delimiter.len();
+    loop {
+        // Final delimiter is followed by "--"
+        if body[pos..].starts_with(b"--") {
+            return Some(parts);
+        }
+
+        let next = find(body, delimiter, pos)?;
+        let mut chunk = &body[pos..next];
+        chunk = chunk.strip_prefix(b"\r\n").unwrap_or(chunk);
+        chunk = chunk.strip_suffix(b"\r\n").unwrap_or(chunk);
+
+        let (head, content) = match chunk.strip_prefix(b"\r\n") {
+            // No headers for this part.
+            Some(c) => (&chunk[..0], c),
+            None => {
+                let idx = find(chunk, b"\r\n\r\n", 0)?;
+                (&chunk[..idx], &chunk[idx + 4..])
+            }
+        };
+
+        let headers = String::from_utf8_lossy(head)
+            .split("\r\n")
+            .filter_map(|line| {
+                let mut kv = line.splitn(2, ':');
+                match (kv.next(), kv.next()) {
+                    (Some(k), Some(v)) => Some((k.trim().to_ascii_lowercase(), v.trim().to_owned())),
+                    _ => None,
+                }
+            })
+            .collect();
+
+        parts.push(MultipartPart {
+            headers,
+            body: content.to_vec(),
+        });
+
+        pos = next + delimiter.len();
+    }
+}
+
+/// Representation requested from the server through the `Prefer` header
+/// (for operations which honor `Prefer: return=...`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PreferReturn {
+    /// The server should respond with the full object.
+    Representation,
+    /// The server can respond with an empty body (`204 No Content`).
+    Minimal,
+}
+
+impl Display for PreferReturn {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PreferReturn::Representation => f.write_str("return=representation"),
+            PreferReturn::Minimal => f.write_str("return=minimal"),
+        }
+    }
+}
+
+impl FromStr for PreferReturn {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim_start_matches("return=") {
+            "representation" => Ok(PreferReturn::Representation),
+            "minimal" => Ok(PreferReturn::Minimal),
+            _ => Err(format!("unknown return preference {:?}", s)),
+        }
+    }
+}
+
+/// Range of bytes (first and last positions, inclusive) requested through
+/// the `Range` header for partial downloads. Ranges without the last position
+/// extend to the end of the body.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ByteRange(pub u64, pub Option<u64>);
+
+impl Display for ByteRange {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.1 {
+            Some(end) => write!(f, "bytes={}-{}", self.0, end),
+            None => write!(f, "bytes={}-", self.0),
+        }
+    }
+}
+
+impl FromStr for ByteRange {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.trim_start_matches("bytes=").splitn(2, '-');
+        match (parts.next().map(str::parse), parts.next()) {
+            (Some(Ok(start)), Some("")) => Ok(ByteRange(start, None)),
+            (Some(Ok(start)), Some(end)) => match end.parse() {
+                Ok(end) if start <= end => Ok(ByteRange(start, Some(end))),
+                _ => Err(format!("invalid byte range {:?}", s)),
+            },
+            _ => Err(format!("invalid byte range {:?}", s)),
+        }
+    }
+}
+
+/// Contents of a file parameter in `multipart/form-data` requests.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FilePart {
+    /// File at the given path (which is streamed from disk).
+    Path(std::path::PathBuf),
+    /// File contents in memory.
+    Bytes(Vec<u8>),
+}
+
+impl From<std::path::PathBuf> for FilePart {
+    fn from(p: std::path::PathBuf) -> Self {
+        FilePart::Path(p)
+    }
+}
+
+impl From<&std::path::Path> for FilePart {
+    fn from(p: &std::path::Path) -> Self {
+        FilePart::Path(p.into())
+    }
+}
+
+impl From<&str> for FilePart {
+    fn from(p: &str) -> Self {
+        FilePart::Path(p.into())
+    }
+}
+
+impl From<Vec<u8>> for FilePart {
+    fn from(b: Vec<u8>) -> Self {
+        FilePart::Bytes(b)
+    }
+}
+
+impl From<&[u8]> for FilePart {
+    fn from(b: &[u8]) -> Self {
+        FilePart::Bytes(b.into())
+    }
+}
+
+impl FromStr for FilePart {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(FilePart::Path(s.into()))
+    }
+}
+
+/// Key which doesn't match any of the patterns allowed for the keys of a map.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidKey(pub String);
+
+impl Display for InvalidKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "key {:?} doesn't match any of the allowed patterns", self.0)
+    }
+}
+
+impl Error for InvalidKey {}
+
+/// Field whose value doesn't satisfy the constraints in the spec.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidationError {
+    pub field: &'static str,
+    pub reason: &'static str,
+}
+
+impl Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "field {:?} {}", self.field, self.reason)
+    }
+}
+
+impl Error for ValidationError {}
+
+/// Marker trait for delimiting. We represent each type of delimiting
+/// with an unit struct and implement this
+pub trait Delimiting {
+    const DELIMITER: char;
+}
+
+/// Marker trait for whether the delimiting unit struct can be used by
+/// iterators. This is not implemented by `multi` - Multiple instances are
+/// allowed only in form data and query, and we need something for parsing
+/// stuff from CLI. At the same time, we also cannot allow serializing this
+/// container in the same way as others.
+pub trait Allowed {}
+
+macro_rules! impl_delim {
+    ($ty:ident => $delim:expr) => {
+        #[derive(Debug, Clone)]
+        pub struct $ty;
+
+        impl Delimiting for $ty {
+            const DELIMITER: char = $delim;
+        }
+    };
+}
+
+impl_delim!(Csv => ',');
+impl Allowed for Csv {}
+
+impl_delim!(Ssv => ' ');
+impl Allowed for Ssv {}
+
+impl_delim!(Tsv => '\t');
+impl Allowed for Tsv {}
+
+impl_delim!(Pipes => '|');
+impl Allowed for Pipes {}
+
+// NOTE: We use ampersand only for convenience.
+impl_delim!(Multi => '&');
+
+/// Wrapper over a vector which also holds a marker type for delimiting.
+#[derive(Debug, Clone)]
+pub struct Delimited<T, D>(Vec<T>, PhantomData<D>);
+
+impl<T, D> From<Vec<T>> for Delimited<T, D> {
+    fn from(v: Vec<T>) -> Self {
+        Delimited(v, PhantomData)
+    }
+}
+
+impl<T, D> Deref for Delimited<T, D> {
+    type Target = Vec<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T, D> DerefMut for Delimited<T, D> {
+    fn deref_mut(&mut self) -> &mut <Self as Deref>::Target {
+        &mut self.0
+    }
+}
+
+impl<T: FromStr, D: Delimiting> FromStr for Delimited<T, D> {
+    type Err = <T as FromStr>::Err;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let vec: Result<Vec<_>, _> = s.split(D::DELIMITER).map(|s| s.parse::<T>()).collect();
+        Ok(Delimited(vec?, PhantomData))
+    }
+}
+
+impl<T: Display, D: Delimiting + Allowed> Display for Delimited<T, D> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, v) in self.0.iter().enumerate() {
+            if i > 0 {
+                f.write_char(D::DELIMITER)?;
+            }
+
+            v.fmt(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Delimited values are serialized as strings (i.e., the same way they appear in URLs).
+impl<T: Display, D: Delimiting + Allowed> serde::Serialize for Delimited<T, D> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer
+    {
+        serializer.collect_str(self)
+    }
+}
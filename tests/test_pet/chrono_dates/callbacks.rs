@@ -0,0 +1,14 @@
+
+/// Handlers for the requests made by the API back to the client after `addPet`.
+///
+/// The generated client doesn't receive these requests, so this is meant to be
+/// implemented by the server (in whichever web framework) receiving them.
+pub trait AddPetCallbacks {
+    /// Notifies the owner when the pet has been adopted.
+    ///
+    /// `POST {$request.header.X-Callback-Url}/adopted`
+    fn on_pet_adopted(&self, payload: crate::adoption::Adoption) -> crate::status::Status;
+
+    /// `DELETE {$request.header.X-Callback-Url}/pets/{$response.body#/id}`
+    fn on_pet_removed(&self);
+}
@@ -0,0 +1,253 @@
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct PostShipmentsBody {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address: Option<crate::post_shipments_body::PostShipmentsBodyAddress>,
+    #[serde(rename = "orderId", skip_serializing_if = "Option::is_none")]
+    pub order_id: Option<String>,
+}
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct PostShipmentsBodyAddress {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line1: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line2: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+impl PostShipmentsBody {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> PostShipmentsBodyBuilder {
+        PostShipmentsBodyBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create shipment for order
+    #[deprecated(note = "Create shipment for order")]
+    #[inline]
+    pub fn post() -> PostShipmentsBodyPostBuilder {
+        PostShipmentsBodyPostBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> PostShipmentsBodyBuilder
+    where
+        Self: Clone,
+    {
+        PostShipmentsBodyBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<PostShipmentsBody> for PostShipmentsBodyBuilder {
+    fn into(self) -> PostShipmentsBody {
+        self.body
+    }
+}
+
+impl Into<PostShipmentsBody> for PostShipmentsBodyPostBuilder {
+    fn into(self) -> PostShipmentsBody {
+        self.body
+    }
+}
+
+/// Builder for [`PostShipmentsBody`](./struct.PostShipmentsBody.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct PostShipmentsBodyBuilder {
+    body: self::PostShipmentsBody,
+}
+
+impl PostShipmentsBodyBuilder {
+    #[inline]
+    pub fn address(mut self, value: crate::post_shipments_body::PostShipmentsBodyAddress) -> Self {
+        self.body.address = Some(value.into());
+        self
+    }
+
+    /// Sets [`address`](#method.address) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_address(mut self, value: Option<crate::post_shipments_body::PostShipmentsBodyAddress>) -> Self {
+        self.body.address = value.map(|value| value.into());
+        self
+    }
+
+    #[inline]
+    pub fn order_id(mut self, value: impl Into<String>) -> Self {
+        self.body.order_id = Some(value.into());
+        self
+    }
+
+    /// Sets [`order_id`](#method.order_id) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_order_id(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.order_id = value.map(|value| value.into());
+        self
+    }
+}
+
+/// Builder created by [`PostShipmentsBody::post`](./struct.PostShipmentsBody.html#method.post) method for a `POST` operation associated with `PostShipmentsBody`.
+#[derive(Debug, Default, Clone)]
+pub struct PostShipmentsBodyPostBuilder {
+    body: self::PostShipmentsBody,
+}
+
+impl PostShipmentsBodyPostBuilder {
+    #[inline]
+    pub fn address(mut self, value: crate::post_shipments_body::PostShipmentsBodyAddress) -> Self {
+        self.body.address = Some(value.into());
+        self
+    }
+
+    /// Sets [`address`](#method.address) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_address(mut self, value: Option<crate::post_shipments_body::PostShipmentsBodyAddress>) -> Self {
+        self.body.address = value.map(|value| value.into());
+        self
+    }
+
+    #[inline]
+    pub fn order_id(mut self, value: impl Into<String>) -> Self {
+        self.body.order_id = Some(value.into());
+        self
+    }
+
+    /// Sets [`order_id`](#method.order_id) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_order_id(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.order_id = value.map(|value| value.into());
+        self
+    }
+}
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for PostShipmentsBodyPostBuilder {
+    type Output = serde_yaml::Value;
+
+    const METHOD: http::Method = http::Method::POST;
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["api_key"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok("/shipments".into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::CONTENT_TYPE.as_str(), "application/yaml")
+        .body_bytes({
+            let mut vec = vec![];
+            serde_yaml::to_writer(&mut vec, &self.body)?;
+            vec
+        })
+        .header(http::header::ACCEPT.as_str(), "application/yaml"))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Content-Type")
+            || name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<PostShipmentsBodyPostBuilder> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: PostShipmentsBodyPostBuilder) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+impl PostShipmentsBodyAddress {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> PostShipmentsBodyAddressBuilder {
+        PostShipmentsBodyAddressBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> PostShipmentsBodyAddressBuilder
+    where
+        Self: Clone,
+    {
+        PostShipmentsBodyAddressBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<PostShipmentsBodyAddress> for PostShipmentsBodyAddressBuilder {
+    fn into(self) -> PostShipmentsBodyAddress {
+        self.body
+    }
+}
+
+/// Builder for [`PostShipmentsBodyAddress`](./struct.PostShipmentsBodyAddress.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct PostShipmentsBodyAddressBuilder {
+    body: self::PostShipmentsBodyAddress,
+}
+
+impl PostShipmentsBodyAddressBuilder {
+    #[inline]
+    pub fn code(mut self, value: impl Into<String>) -> Self {
+        self.body.code = Some(value.into());
+        self
+    }
+
+    /// Sets [`code`](#method.code) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_code(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.code = value.map(|value| value.into());
+        self
+    }
+
+    #[inline]
+    pub fn line1(mut self, value: impl Into<String>) -> Self {
+        self.body.line1 = Some(value.into());
+        self
+    }
+
+    /// Sets [`line1`](#method.line1) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_line1(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.line1 = value.map(|value| value.into());
+        self
+    }
+
+    #[inline]
+    pub fn line2(mut self, value: impl Into<String>) -> Self {
+        self.body.line2 = Some(value.into());
+        self
+    }
+
+    /// Sets [`line2`](#method.line2) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_line2(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.line2 = value.map(|value| value.into());
+        self
+    }
+
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> Self {
+        self.body.name = Some(value.into());
+        self
+    }
+
+    /// Sets [`name`](#method.name) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_name(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.name = value.map(|value| value.into());
+        self
+    }
+}
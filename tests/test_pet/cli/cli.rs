@@ -0,0 +1,93 @@
+use clap::ArgMatches;
+use crate::client::{ApiClient, ApiError, Sendable};
+use serde::{Serialize, Deserialize};
+
+use std::io::Read;
+
+pub(crate) fn read_from_input<T>(matches: Option<&ArgMatches<'_>>) -> Result<T, crate::ClientError>
+where
+    T: Serialize,
+    for<'de> T: Deserialize<'de>
+{
+    let path = matches
+        .expect("no args for builder with body?")
+        .value_of("payload").expect("payload?");
+
+    let mut bytes = vec![];
+    if path == "-" {
+        std::io::stdin().read_to_end(&mut bytes).map_err(crate::ClientError::Io)?;
+    } else {
+        std::fs::File::open(&path)
+            .and_then(|mut fd| fd.read_to_end(&mut bytes))
+            .map_err(crate::ClientError::Io)?;
+    };
+
+    let err = match serde_json::from_reader(bytes.as_slice()) {
+        Ok(t) => return Ok(t),
+        Err(e) => crate::ClientError::Api(ApiError::ApplicationJson(e)),
+    };
+
+    log::debug!("Error decoding payload as application/json: {:?}", err);
+
+    let err = match serde_yaml::from_reader(bytes.as_slice()) {
+        Ok(t) => return Ok(t),
+        Err(e) => crate::ClientError::Api(ApiError::ApplicationYaml(e)),
+    };
+
+    log::debug!("Error decoding payload as application/yaml: {:?}", err);
+
+    Err(err)
+}
+
+pub(super) async fn fetch_response<'a, C>(client: &'a C,
+                                          _matches: &ArgMatches<'_>,
+                                          sub_cmd: &str,
+                                          sub_matches: Option<&ArgMatches<'_>>)
+                                          -> Result<C::Response, crate::ClientError>
+where
+    C: ApiClient + Send + Sync + 'static,
+    crate::ClientError: From<ApiError<C::Response>>
+{
+    let resp = match sub_cmd {
+
+        "get-shipment" => {
+            let builder = crate::get_shipments_id_response::GetShipmentsIdResponseGetBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "list-pets" => {
+            let builder = crate::pet::PetGetBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "add-pet" => {
+            let builder = crate::pet::PetPostBuilder::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "list-pets-by-kind" => {
+            let builder = crate::pet::PetGetBuilder1::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "get-pet-by-id" => {
+            let builder = crate::pet::PetGetBuilder2::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "update-pet" => {
+            let builder = crate::pet::PetPutBuilder2::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "upload-pet-certificate" => {
+            let builder = crate::pet::PetPutBuilder3::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        "get-pet-with-photo" => {
+            let builder = crate::pet::PetGetBuilder4::from_args(sub_matches)?;
+            builder.send_raw(client).await
+        },
+        _ => unimplemented!(),
+    };
+
+    match resp {
+        Ok(r) => Ok(r),
+        Err(ApiError::Failure(_, _, r)) => Ok(r.into_inner()),
+        Err(e) => return Err(e.into()),
+    }
+}
@@ -0,0 +1,327 @@
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct GetShipmentsIdResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address: Option<crate::get_shipments_id_response::GetShipmentsIdResponseAddress>,
+    #[serde(rename = "createdOn", skip_serializing_if = "Option::is_none")]
+    pub created_on: Option<String>,
+    #[serde(rename = "orderId", skip_serializing_if = "Option::is_none")]
+    pub order_id: Option<String>,
+    #[serde(rename = "shippedOn", skip_serializing_if = "Option::is_none")]
+    pub shipped_on: Option<String>,
+}
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct GetShipmentsIdResponseAddress {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line1: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line2: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+impl GetShipmentsIdResponse {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> GetShipmentsIdResponseBuilder {
+        GetShipmentsIdResponseBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Fetch shipment by ID
+    ///
+    /// [See more](<https://example.com/docs/shipments>)
+    #[inline]
+    pub fn get_shipment() -> GetShipmentsIdResponseGetBuilder<crate::generics::MissingId, crate::generics::MissingSession> {
+        GetShipmentsIdResponseGetBuilder {
+            inner: Default::default(),
+            _param_id: core::marker::PhantomData,
+            _param_session: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> GetShipmentsIdResponseBuilder
+    where
+        Self: Clone,
+    {
+        GetShipmentsIdResponseBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<GetShipmentsIdResponse> for GetShipmentsIdResponseBuilder {
+    fn into(self) -> GetShipmentsIdResponse {
+        self.body
+    }
+}
+
+/// Builder for [`GetShipmentsIdResponse`](./struct.GetShipmentsIdResponse.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct GetShipmentsIdResponseBuilder {
+    body: self::GetShipmentsIdResponse,
+}
+
+impl GetShipmentsIdResponseBuilder {
+    #[inline]
+    pub fn address(mut self, value: crate::get_shipments_id_response::GetShipmentsIdResponseAddress) -> Self {
+        self.body.address = Some(value.into());
+        self
+    }
+
+    /// Sets [`address`](#method.address) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_address(mut self, value: Option<crate::get_shipments_id_response::GetShipmentsIdResponseAddress>) -> Self {
+        self.body.address = value.map(|value| value.into());
+        self
+    }
+
+    #[inline]
+    pub fn created_on(mut self, value: impl Into<String>) -> Self {
+        self.body.created_on = Some(value.into());
+        self
+    }
+
+    /// Sets [`created_on`](#method.created_on) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_created_on(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.created_on = value.map(|value| value.into());
+        self
+    }
+
+    #[inline]
+    pub fn order_id(mut self, value: impl Into<String>) -> Self {
+        self.body.order_id = Some(value.into());
+        self
+    }
+
+    /// Sets [`order_id`](#method.order_id) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_order_id(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.order_id = value.map(|value| value.into());
+        self
+    }
+
+    #[inline]
+    pub fn shipped_on(mut self, value: impl Into<String>) -> Self {
+        self.body.shipped_on = Some(value.into());
+        self
+    }
+
+    /// Sets [`shipped_on`](#method.shipped_on) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_shipped_on(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.shipped_on = value.map(|value| value.into());
+        self
+    }
+}
+
+/// Builder created by [`GetShipmentsIdResponse::get_shipment`](./struct.GetShipmentsIdResponse.html#method.get_shipment) method for a `GET` operation associated with `GetShipmentsIdResponse`.
+#[repr(transparent)]
+#[derive(Debug, Clone)]
+pub struct GetShipmentsIdResponseGetBuilder<Id, Session> {
+    inner: GetShipmentsIdResponseGetBuilderContainer,
+    _param_id: core::marker::PhantomData<Id>,
+    _param_session: core::marker::PhantomData<Session>,
+}
+
+#[derive(Debug, Default, Clone)]
+struct GetShipmentsIdResponseGetBuilderContainer {
+    param_id: Option<String>,
+    param_session: Option<String>,
+    param_theme: Option<String>,
+}
+
+impl<Id, Session> GetShipmentsIdResponseGetBuilder<Id, Session> {
+    #[inline]
+    pub fn id(mut self, value: impl Into<String>) -> GetShipmentsIdResponseGetBuilder<crate::generics::IdExists, Session> {
+        self.inner.param_id = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    #[inline]
+    pub fn session(mut self, value: impl Into<String>) -> GetShipmentsIdResponseGetBuilder<Id, crate::generics::SessionExists> {
+        self.inner.param_session = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    #[inline]
+    pub fn theme(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_theme = Some(value.into());
+        self
+    }
+
+    /// Sets [`theme`](#method.theme) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_theme(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_theme = value.map(|value| value.into());
+        self
+    }
+}
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for GetShipmentsIdResponseGetBuilder<crate::generics::IdExists, crate::generics::SessionExists> {
+    type Output = GetShipmentsIdResponse;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    const OPERATION_ID: Option<&'static str> = Some("getShipment");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["api_key"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok(format!("/shipments/{id}", id=crate::client::path_param("id", self.inner.param_id.as_ref().expect("missing parameter id?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        let mut req = req;
+        let mut cookies = vec![];
+        cookies.push(crate::client::cookie_pair("session", self.inner.param_session.as_ref().expect("missing parameter session?")));
+        if let Some(v) = self.inner.param_theme.as_ref() {
+            cookies.push(crate::client::cookie_pair("theme", v));
+        }
+        if !cookies.is_empty() {
+            req = req.header("Cookie", &cookies.join("; "));
+        }
+
+        Ok(req
+        .header(http::header::ACCEPT.as_str(), "application/yaml"))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Accept")
+            || name.eq_ignore_ascii_case("Cookie")
+    }
+}
+
+impl std::convert::TryFrom<GetShipmentsIdResponseGetBuilder<crate::generics::IdExists, crate::generics::SessionExists>> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: GetShipmentsIdResponseGetBuilder<crate::generics::IdExists, crate::generics::SessionExists>) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl GetShipmentsIdResponseGetBuilder<crate::generics::IdExists, crate::generics::SessionExists> {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = GetShipmentsIdResponseGetBuilder {
+            inner: GetShipmentsIdResponseGetBuilderContainer {
+            param_id: matches.and_then(|m| {
+                    m.value_of("id").map(|_| {
+                        value_t!(m, "id", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_session: matches.and_then(|m| {
+                    m.value_of("session").map(|_| {
+                        value_t!(m, "session", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_theme: matches.and_then(|m| {
+                    m.value_of("theme").map(|_| {
+                        value_t!(m, "theme", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            },
+            _param_id: core::marker::PhantomData,
+            _param_session: core::marker::PhantomData,
+        };
+
+        Ok(thing)
+    }
+}
+
+impl GetShipmentsIdResponseAddress {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> GetShipmentsIdResponseAddressBuilder {
+        GetShipmentsIdResponseAddressBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> GetShipmentsIdResponseAddressBuilder
+    where
+        Self: Clone,
+    {
+        GetShipmentsIdResponseAddressBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<GetShipmentsIdResponseAddress> for GetShipmentsIdResponseAddressBuilder {
+    fn into(self) -> GetShipmentsIdResponseAddress {
+        self.body
+    }
+}
+
+/// Builder for [`GetShipmentsIdResponseAddress`](./struct.GetShipmentsIdResponseAddress.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct GetShipmentsIdResponseAddressBuilder {
+    body: self::GetShipmentsIdResponseAddress,
+}
+
+impl GetShipmentsIdResponseAddressBuilder {
+    #[inline]
+    pub fn code(mut self, value: impl Into<String>) -> Self {
+        self.body.code = Some(value.into());
+        self
+    }
+
+    /// Sets [`code`](#method.code) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_code(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.code = value.map(|value| value.into());
+        self
+    }
+
+    #[inline]
+    pub fn line1(mut self, value: impl Into<String>) -> Self {
+        self.body.line1 = Some(value.into());
+        self
+    }
+
+    /// Sets [`line1`](#method.line1) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_line1(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.line1 = value.map(|value| value.into());
+        self
+    }
+
+    #[inline]
+    pub fn line2(mut self, value: impl Into<String>) -> Self {
+        self.body.line2 = Some(value.into());
+        self
+    }
+
+    /// Sets [`line2`](#method.line2) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_line2(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.line2 = value.map(|value| value.into());
+        self
+    }
+
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> Self {
+        self.body.name = Some(value.into());
+        self
+    }
+
+    /// Sets [`name`](#method.name) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_name(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.name = value.map(|value| value.into());
+        self
+    }
+}
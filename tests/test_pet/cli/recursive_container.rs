@@ -0,0 +1,155 @@
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct RecursiveContainer<Any> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub object: Option<Box<crate::recursive_object::RecursiveObject<Any>>>,
+}
+
+impl<Any: Default> RecursiveContainer<Any> {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> RecursiveContainerBuilder<Any> {
+        RecursiveContainerBuilder {
+            body: Default::default(),
+        }
+    }
+
+    #[inline]
+    pub fn get() -> RecursiveContainerGetBuilder {
+        RecursiveContainerGetBuilder
+    }
+
+    #[inline]
+    pub fn post_1() -> RecursiveContainerPostBuilder1 {
+        RecursiveContainerPostBuilder1
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> RecursiveContainerBuilder<Any>
+    where
+        Self: Clone,
+    {
+        RecursiveContainerBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl<Any> Into<RecursiveContainer<Any>> for RecursiveContainerBuilder<Any> {
+    fn into(self) -> RecursiveContainer<Any> {
+        self.body
+    }
+}
+
+/// Builder for [`RecursiveContainer`](./struct.RecursiveContainer.html) object.
+#[derive(Debug, Clone)]
+pub struct RecursiveContainerBuilder<Any> {
+    body: self::RecursiveContainer<Any>,
+}
+
+impl<Any> RecursiveContainerBuilder<Any> {
+    #[inline]
+    pub fn object(mut self, value: crate::recursive_object::RecursiveObject<Any>) -> Self {
+        self.body.object = Some(value.into());
+        self
+    }
+
+    /// Sets [`object`](#method.object) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_object(mut self, value: Option<crate::recursive_object::RecursiveObject<Any>>) -> Self {
+        self.body.object = value.map(|value| value.into());
+        self
+    }
+}
+
+/// Builder created by [`RecursiveContainer::get`](./struct.RecursiveContainer.html#method.get) method for a `GET` operation associated with `RecursiveContainer`.
+#[derive(Debug, Default, Clone)]
+pub struct RecursiveContainerGetBuilder;
+
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for RecursiveContainerGetBuilder {
+    type Output = RecursiveContainer<serde_yaml::Value>;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["api_key"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok("/another/route/referring/recursive/object".into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::ACCEPT.as_str(), "application/yaml"))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<RecursiveContainerGetBuilder> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: RecursiveContainerGetBuilder) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl RecursiveContainerGetBuilder {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = RecursiveContainerGetBuilder {
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder created by [`RecursiveContainer::post_1`](./struct.RecursiveContainer.html#method.post_1) method for a `POST` operation associated with `RecursiveContainer`.
+#[derive(Debug, Default, Clone)]
+pub struct RecursiveContainerPostBuilder1;
+
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for RecursiveContainerPostBuilder1 {
+    type Output = RecursiveContainer<serde_yaml::Value>;
+
+    const METHOD: http::Method = http::Method::POST;
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["api_key"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok("/route/referring/recursive/object".into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::ACCEPT.as_str(), "application/yaml"))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<RecursiveContainerPostBuilder1> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: RecursiveContainerPostBuilder1) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl RecursiveContainerPostBuilder1 {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = RecursiveContainerPostBuilder1 {
+        };
+
+        Ok(thing)
+    }
+}
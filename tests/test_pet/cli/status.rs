@@ -0,0 +1,322 @@
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct Status {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+}
+
+impl Status {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> StatusBuilder {
+        StatusBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Delete multiple pets
+    #[inline]
+    pub fn delete() -> StatusDeleteBuilder<crate::generics::MissingPetId> {
+        StatusDeleteBuilder {
+            inner: Default::default(),
+            _param_pet_id: core::marker::PhantomData,
+        }
+    }
+
+    #[inline]
+    pub fn put_1() -> StatusPutBuilder1<crate::generics::MissingSomeDataFile, crate::generics::MissingFoobar> {
+        StatusPutBuilder1 {
+            inner: Default::default(),
+            _param_some_data_file: core::marker::PhantomData,
+            _param_foobar: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> StatusBuilder
+    where
+        Self: Clone,
+    {
+        StatusBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<Status> for StatusBuilder {
+    fn into(self) -> Status {
+        self.body
+    }
+}
+
+/// Builder for [`Status`](./struct.Status.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct StatusBuilder {
+    body: self::Status,
+}
+
+impl StatusBuilder {
+    #[inline]
+    pub fn status(mut self, value: impl Into<String>) -> Self {
+        self.body.status = Some(value.into());
+        self
+    }
+
+    /// Sets [`status`](#method.status) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_status(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.status = value.map(|value| value.into());
+        self
+    }
+}
+
+/// Builder created by [`Status::delete`](./struct.Status.html#method.delete) method for a `DELETE` operation associated with `Status`.
+#[repr(transparent)]
+#[derive(Debug, Clone)]
+pub struct StatusDeleteBuilder<PetId> {
+    inner: StatusDeleteBuilderContainer,
+    _param_pet_id: core::marker::PhantomData<PetId>,
+}
+
+#[derive(Debug, Default, Clone)]
+struct StatusDeleteBuilderContainer {
+    param_pet_id: Option<crate::util::Delimited<i64, crate::util::Csv>>,
+    param_x_request_id: Option<String>,
+}
+
+impl<PetId> StatusDeleteBuilder<PetId> {
+    #[inline]
+    pub fn pet_id(mut self, value: impl Iterator<Item = impl Into<i64>>) -> StatusDeleteBuilder<crate::generics::PetIdExists> {
+        self.inner.param_pet_id = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Same as [`pet_id`](#method.pet_id), but takes the values as a `Vec`.
+    #[inline]
+    pub fn pet_id_vec(mut self, value: impl Into<Vec<i64>>) -> StatusDeleteBuilder<crate::generics::PetIdExists> {
+        let value: Vec<_> = value.into();
+        self.inner.param_pet_id = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    #[inline]
+    pub fn x_request_id(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_x_request_id = Some(value.into());
+        self
+    }
+
+    /// Sets [`x_request_id`](#method.x_request_id) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_x_request_id(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_x_request_id = value.map(|value| value.into());
+        self
+    }
+}
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for StatusDeleteBuilder<crate::generics::PetIdExists> {
+    type Output = Status;
+
+    const METHOD: http::Method = http::Method::DELETE;
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["api_key"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok(format!("/pets/{petId}", petId=crate::client::path_param("petId", self.inner.param_pet_id.as_ref().expect("missing parameter pet_id?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        let mut req = req;
+        if let Some(v) = &self.inner.param_x_request_id.as_ref().map(std::string::ToString::to_string) {
+            req = req.header("X-Request-Id", &v);
+        }
+
+        Ok(req
+        .header(http::header::ACCEPT.as_str(), "application/yaml"))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        (name.eq_ignore_ascii_case("X-Request-Id") && self.inner.param_x_request_id.is_some())
+            || name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<StatusDeleteBuilder<crate::generics::PetIdExists>> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: StatusDeleteBuilder<crate::generics::PetIdExists>) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl StatusDeleteBuilder<crate::generics::PetIdExists> {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = StatusDeleteBuilder {
+            inner: StatusDeleteBuilderContainer {
+            param_pet_id: matches.and_then(|m| {
+                    m.value_of("pet-id").map(|_| {
+                        value_t!(m, "pet-id", crate::util::Delimited<i64, crate::util::Csv>).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_x_request_id: matches.and_then(|m| {
+                    m.value_of("x-request-id").map(|_| {
+                        value_t!(m, "x-request-id", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            },
+            _param_pet_id: core::marker::PhantomData,
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Builder created by [`Status::put_1`](./struct.Status.html#method.put_1) method for a `PUT` operation associated with `Status`.
+#[repr(transparent)]
+#[derive(Debug, Clone)]
+pub struct StatusPutBuilder1<SomeDataFile, Foobar> {
+    inner: StatusPutBuilder1Container,
+    _param_some_data_file: core::marker::PhantomData<SomeDataFile>,
+    _param_foobar: core::marker::PhantomData<Foobar>,
+}
+
+#[derive(Debug, Default, Clone)]
+struct StatusPutBuilder1Container {
+    param_some_data_file: Option<crate::util::FilePart>,
+    param_some_other_file: Option<crate::util::FilePart>,
+    param_foobar: Option<String>,
+    param_booya: Option<crate::util::Delimited<crate::util::Delimited<i64, crate::util::Csv>, crate::util::Multi>>,
+}
+
+impl<SomeDataFile, Foobar> StatusPutBuilder1<SomeDataFile, Foobar> {
+    #[inline]
+    pub fn some_data_file(mut self, value: impl Into<crate::util::FilePart>) -> StatusPutBuilder1<crate::generics::SomeDataFileExists, Foobar> {
+        self.inner.param_some_data_file = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    #[inline]
+    pub fn some_other_file(mut self, value: impl Into<crate::util::FilePart>) -> Self {
+        self.inner.param_some_other_file = Some(value.into());
+        self
+    }
+
+    /// Sets [`some_other_file`](#method.some_other_file) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_some_other_file(mut self, value: Option<impl Into<crate::util::FilePart>>) -> Self {
+        self.inner.param_some_other_file = value.map(|value| value.into());
+        self
+    }
+
+    #[inline]
+    pub fn foobar(mut self, value: impl Into<String>) -> StatusPutBuilder1<SomeDataFile, crate::generics::FoobarExists> {
+        self.inner.param_foobar = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    #[inline]
+    pub fn booya(mut self, value: impl Iterator<Item = impl Iterator<Item = impl Into<i64>>>) -> Self {
+        self.inner.param_booya = Some(value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`booya`](#method.booya) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_booya(mut self, value: Option<impl Iterator<Item = impl Iterator<Item = impl Into<i64>>>>) -> Self {
+        self.inner.param_booya = value.map(|value| value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into()).collect::<Vec<_>>().into());
+        self
+    }
+}
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for StatusPutBuilder1<crate::generics::SomeDataFileExists, crate::generics::FoobarExists> {
+    type Output = Status;
+
+    const METHOD: http::Method = http::Method::PUT;
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["api_key"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok("/test/file".into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::ACCEPT.as_str(), "application/yaml")
+        .multipart_form_data({
+            use crate::client::Form;
+            let mut form = <Client::Request as Request>::Form::new();
+            if let Some(v) = self.inner.param_some_data_file.as_ref() {
+                form = form.file_part("someDataFile", v)?;
+            }
+            if let Some(v) = self.inner.param_some_other_file.as_ref() {
+                form = form.file_part("someOtherFile", v)?;
+            }
+            if let Some(v) = self.inner.param_foobar.as_ref() {
+                form = form.text("foobar", v.to_string());
+            }
+            if let Some(stuff) = self.inner.param_booya.as_ref() {
+                for v in stuff.iter() {
+                    form = form.text("booya", v.to_string());
+                }
+            }
+            form
+        }))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Content-Type")
+            || name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<StatusPutBuilder1<crate::generics::SomeDataFileExists, crate::generics::FoobarExists>> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: StatusPutBuilder1<crate::generics::SomeDataFileExists, crate::generics::FoobarExists>) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+#[allow(unused_variables)]
+impl StatusPutBuilder1<crate::generics::SomeDataFileExists, crate::generics::FoobarExists> {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = StatusPutBuilder1 {
+            inner: StatusPutBuilder1Container {
+            param_some_data_file: matches.and_then(|m| {
+                    m.value_of("some-data-file").map(|_| {
+                        value_t!(m, "some-data-file", crate::util::FilePart).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_some_other_file: matches.and_then(|m| {
+                    m.value_of("some-other-file").map(|_| {
+                        value_t!(m, "some-other-file", crate::util::FilePart).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_foobar: matches.and_then(|m| {
+                    m.value_of("foobar").map(|_| {
+                        value_t!(m, "foobar", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_booya: matches.and_then(|m| {
+                    m.value_of("booya").map(|_| {
+                        value_t!(m, "booya", crate::util::Delimited<crate::util::Delimited<i64, crate::util::Csv>, crate::util::Multi>).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            },
+            _param_some_data_file: core::marker::PhantomData,
+            _param_foobar: core::marker::PhantomData,
+        };
+
+        Ok(thing)
+    }
+}
@@ -0,0 +1,17 @@
+
+/// Pet or order (wrapped along with its kind).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "kind", content = "data")]
+pub enum Event<Any> {
+    #[serde(rename = "Pet")]
+    Pet(crate::pet::Pet<Any>),
+    #[serde(rename = "Order")]
+    Order(crate::order::Order),
+}
+
+impl<Any: Default> Default for Event<Any> {
+    fn default() -> Self {
+        Event::Pet(Default::default())
+    }
+}
+
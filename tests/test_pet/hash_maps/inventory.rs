@@ -0,0 +1,134 @@
+
+/// Stock counts for each item, along with when they were taken.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct Inventory {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bins: Option<std::collections::HashMap<String, crate::inventory::InventoryBinsValue>>,
+    #[serde(rename = "takenAt")]
+    pub taken_at: String,
+    #[serde(flatten)]
+    pub other_fields: Option<std::collections::HashMap<String, i64>>,
+}
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct InventoryBinsValue {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shelf: Option<String>,
+}
+
+impl Inventory {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> InventoryBuilder<crate::generics::MissingTakenAt> {
+        InventoryBuilder {
+            body: Default::default(),
+            _taken_at: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> InventoryBuilder<crate::generics::TakenAtExists>
+    where
+        Self: Clone,
+    {
+        InventoryBuilder {
+            body: self.clone(),
+            _taken_at: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<Inventory> for InventoryBuilder<crate::generics::TakenAtExists> {
+    fn into(self) -> Inventory {
+        self.body
+    }
+}
+
+/// Builder for [`Inventory`](./struct.Inventory.html) object.
+#[derive(Debug, Clone)]
+pub struct InventoryBuilder<TakenAt> {
+    body: self::Inventory,
+    _taken_at: core::marker::PhantomData<TakenAt>,
+}
+
+impl<TakenAt> InventoryBuilder<TakenAt> {
+    #[inline]
+    pub fn bins(mut self, value: impl Iterator<Item = (String, crate::inventory::InventoryBinsValue)>) -> Self {
+        self.body.bins = Some(value.map(|(key, value)| (key, value.into())).collect::<std::collections::HashMap<_, _>>().into());
+        self
+    }
+
+    /// Sets [`bins`](#method.bins) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_bins(mut self, value: Option<impl Iterator<Item = (String, crate::inventory::InventoryBinsValue)>>) -> Self {
+        self.body.bins = value.map(|value| value.map(|(key, value)| (key, value.into())).collect::<std::collections::HashMap<_, _>>().into());
+        self
+    }
+
+    #[inline]
+    pub fn taken_at(mut self, value: impl Into<String>) -> InventoryBuilder<crate::generics::TakenAtExists> {
+        self.body.taken_at = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    #[inline]
+    pub fn other_fields(mut self, value: impl Iterator<Item = (String, impl Into<i64>)>) -> Self {
+        self.body.other_fields = Some(value.map(|(key, value)| (key, value.into())).collect::<std::collections::HashMap<_, _>>().into());
+        self
+    }
+
+    /// Sets [`other_fields`](#method.other_fields) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_other_fields(mut self, value: Option<impl Iterator<Item = (String, impl Into<i64>)>>) -> Self {
+        self.body.other_fields = value.map(|value| value.map(|(key, value)| (key, value.into())).collect::<std::collections::HashMap<_, _>>().into());
+        self
+    }
+}
+
+impl InventoryBinsValue {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> InventoryBinsValueBuilder {
+        InventoryBinsValueBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> InventoryBinsValueBuilder
+    where
+        Self: Clone,
+    {
+        InventoryBinsValueBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<InventoryBinsValue> for InventoryBinsValueBuilder {
+    fn into(self) -> InventoryBinsValue {
+        self.body
+    }
+}
+
+/// Builder for [`InventoryBinsValue`](./struct.InventoryBinsValue.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct InventoryBinsValueBuilder {
+    body: self::InventoryBinsValue,
+}
+
+impl InventoryBinsValueBuilder {
+    #[inline]
+    pub fn shelf(mut self, value: impl Into<String>) -> Self {
+        self.body.shelf = Some(value.into());
+        self
+    }
+
+    /// Sets [`shelf`](#method.shelf) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_shelf(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.shelf = value.map(|value| value.into());
+        self
+    }
+}
@@ -0,0 +1,1317 @@
+
+/// A pet is a person's best friend
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct Pet<Any> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category: Option<crate::category::Category>,
+    /// Time when the pet was added.
+    ///
+    /// # Example
+    ///
+    /// ```json
+    /// "2020-01-01T00:00:00Z"
+    /// ```
+    #[serde(skip_serializing)]
+    #[serde(rename = "createdAt")]
+    pub created_at: String,
+    pub id: i64,
+    pub name: String,
+    /// # Example
+    ///
+    /// ```json
+    /// [
+    ///   "https://example.com/pets/1.png"
+    /// ]
+    /// ```
+    #[serde(rename = "photoUrls", skip_serializing_if = "Option::is_none")]
+    pub photo_urls: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tags: Option<Vec<crate::tag::Tag>>,
+    #[serde(flatten)]
+    pub other_fields: Option<indexmap::IndexMap<String, Any>>,
+}
+
+impl<Any: Default> Pet<Any> {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> PetBuilder<crate::generics::MissingId, crate::generics::MissingName, Any> {
+        PetBuilder {
+            body: Default::default(),
+            _id: core::marker::PhantomData,
+            _name: core::marker::PhantomData,
+        }
+    }
+
+    /// Fetch list of pets
+    #[inline]
+    pub(crate) fn list_pets() -> PetGetBuilder {
+        PetGetBuilder {
+            param_limit: None,
+            param_sort_by: None,
+            param_born_after: None,
+            param_updated_since: None,
+            param_tags: None,
+            param_ids: None,
+            param_fields: None,
+            param_filter: None,
+        }
+    }
+
+    /// Add a new pet to the store
+    #[inline]
+    pub fn add_pet() -> PetPostBuilder<crate::generics::MissingXAuth, crate::generics::MissingId, crate::generics::MissingName, Any> {
+        PetPostBuilder {
+            inner: Default::default(),
+            _param_x_auth: core::marker::PhantomData,
+            _id: core::marker::PhantomData,
+            _name: core::marker::PhantomData,
+        }
+    }
+
+    /// Fetch the pets of some kind.
+    #[inline]
+    pub fn list_pets_by_kind() -> PetGetBuilder1<crate::generics::MissingKind> {
+        PetGetBuilder1 {
+            inner: Default::default(),
+            _param_kind: core::marker::PhantomData,
+        }
+    }
+
+    /// Find pet by ID
+    #[inline]
+    pub fn get_pet_by_id() -> PetGetBuilder2<crate::generics::MissingPetId> {
+        PetGetBuilder2 {
+            inner: Default::default(),
+            _param_pet_id: core::marker::PhantomData,
+        }
+    }
+
+    /// Update an existing pet
+    #[inline]
+    pub fn update_pet() -> PetPutBuilder2<crate::generics::MissingPetId, crate::generics::MissingId, crate::generics::MissingName, Any> {
+        PetPutBuilder2 {
+            inner: Default::default(),
+            _param_pet_id: core::marker::PhantomData,
+            _id: core::marker::PhantomData,
+            _name: core::marker::PhantomData,
+        }
+    }
+
+    /// Upload the pedigree certificate of a pet.
+    #[inline]
+    pub fn upload_pet_certificate() -> PetPutBuilder3<crate::generics::MissingPetId> {
+        PetPutBuilder3 {
+            inner: Default::default(),
+            _param_pet_id: core::marker::PhantomData,
+        }
+    }
+
+    /// Fetch pet along with its photo
+    #[inline]
+    pub fn get_pet_with_photo() -> PetGetBuilder4<crate::generics::MissingPetId> {
+        PetGetBuilder4 {
+            inner: Default::default(),
+            _param_pet_id: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> PetBuilder<crate::generics::IdExists, crate::generics::NameExists, Any>
+    where
+        Self: Clone,
+    {
+        PetBuilder {
+            body: self.clone(),
+            _id: core::marker::PhantomData,
+            _name: core::marker::PhantomData,
+        }
+    }
+}
+
+impl<Any> Into<Pet<Any>> for PetBuilder<crate::generics::IdExists, crate::generics::NameExists, Any> {
+    fn into(self) -> Pet<Any> {
+        self.body
+    }
+}
+
+impl<Any> Into<Pet<Any>> for PetPostBuilder<crate::generics::XAuthExists, crate::generics::IdExists, crate::generics::NameExists, Any> {
+    fn into(self) -> Pet<Any> {
+        self.inner.body
+    }
+}
+
+impl<Any> Into<Pet<Any>> for PetPutBuilder2<crate::generics::PetIdExists, crate::generics::IdExists, crate::generics::NameExists, Any> {
+    fn into(self) -> Pet<Any> {
+        self.inner.body
+    }
+}
+
+/// Builder for [`Pet`](./struct.Pet.html) object.
+#[derive(Debug, Clone)]
+pub struct PetBuilder<Id, Name, Any> {
+    body: self::Pet<Any>,
+    _id: core::marker::PhantomData<Id>,
+    _name: core::marker::PhantomData<Name>,
+}
+
+impl<Id, Name, Any> PetBuilder<Id, Name, Any> {
+    #[inline]
+    pub fn category(mut self, value: crate::category::Category) -> Self {
+        self.body.category = Some(value.into());
+        self
+    }
+
+    /// Sets [`category`](#method.category) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_category(mut self, value: Option<crate::category::Category>) -> Self {
+        self.body.category = value.map(|value| value.into());
+        self
+    }
+
+    #[inline]
+    pub fn id(mut self, value: impl Into<i64>) -> PetBuilder<crate::generics::IdExists, Name, Any> {
+        self.body.id = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Same as [`id`](#method.id), but takes a reference.
+    #[inline]
+    pub fn id_ref(self, value: &i64) -> PetBuilder<crate::generics::IdExists, Name, Any> {
+        self.id(*value)
+    }
+
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> PetBuilder<Id, crate::generics::NameExists, Any> {
+        self.body.name = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    #[inline]
+    pub fn photo_urls(mut self, value: impl Iterator<Item = impl Into<String>>) -> Self {
+        self.body.photo_urls = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`photo_urls`](#method.photo_urls) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_photo_urls(mut self, value: Option<impl Iterator<Item = impl Into<String>>>) -> Self {
+        self.body.photo_urls = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`photo_urls`](#method.photo_urls), but takes the values as a `Vec`.
+    #[inline]
+    pub fn photo_urls_vec(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.body.photo_urls = Some(value.into());
+        self
+    }
+
+    #[inline]
+    pub fn tags(mut self, value: impl Iterator<Item = crate::tag::Tag>) -> Self {
+        self.body.tags = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`tags`](#method.tags) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_tags(mut self, value: Option<impl Iterator<Item = crate::tag::Tag>>) -> Self {
+        self.body.tags = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`tags`](#method.tags), but takes the values as a `Vec`.
+    #[inline]
+    pub fn tags_vec(mut self, value: impl Into<Vec<crate::tag::Tag>>) -> Self {
+        self.body.tags = Some(value.into());
+        self
+    }
+
+    #[inline]
+    pub fn other_fields(mut self, value: impl Iterator<Item = (String, impl Into<Any>)>) -> Self {
+        self.body.other_fields = Some(value.map(|(key, value)| (key, value.into())).collect::<indexmap::IndexMap<_, _>>().into());
+        self
+    }
+
+    /// Sets [`other_fields`](#method.other_fields) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_other_fields(mut self, value: Option<impl Iterator<Item = (String, impl Into<Any>)>>) -> Self {
+        self.body.other_fields = value.map(|value| value.map(|(key, value)| (key, value.into())).collect::<indexmap::IndexMap<_, _>>().into());
+        self
+    }
+}
+
+/// Builder created by [`Pet::list_pets`](./struct.Pet.html#method.list_pets) method for a `GET` operation associated with `Pet`.
+#[derive(Debug, Default, Clone)]
+pub struct PetGetBuilder {
+    param_limit: Option<i32>,
+    param_sort_by: Option<String>,
+    param_born_after: Option<String>,
+    param_updated_since: Option<String>,
+    param_tags: Option<crate::util::Delimited<String, crate::util::Pipes>>,
+    param_ids: Option<crate::util::Delimited<i64, crate::util::Multi>>,
+    param_fields: Option<crate::util::Delimited<String, crate::util::Csv>>,
+    param_filter: Option<crate::pet_filter::PetFilter>,
+}
+
+impl PetGetBuilder {
+    #[inline]
+    pub fn limit(mut self, value: impl Into<i32>) -> Self {
+        self.param_limit = Some(value.into());
+        self
+    }
+
+    /// Sets [`limit`](#method.limit) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_limit(mut self, value: Option<impl Into<i32>>) -> Self {
+        self.param_limit = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`limit`](#method.limit), but takes a reference.
+    #[inline]
+    pub fn limit_ref(self, value: &i32) -> Self {
+        self.limit(*value)
+    }
+
+    #[inline]
+    pub fn sort_by(mut self, value: impl Into<String>) -> Self {
+        self.param_sort_by = Some(value.into());
+        self
+    }
+
+    /// Sets [`sort_by`](#method.sort_by) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_sort_by(mut self, value: Option<impl Into<String>>) -> Self {
+        self.param_sort_by = value.map(|value| value.into());
+        self
+    }
+
+    #[inline]
+    pub fn born_after(mut self, value: impl Into<String>) -> Self {
+        self.param_born_after = Some(value.into());
+        self
+    }
+
+    /// Sets [`born_after`](#method.born_after) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_born_after(mut self, value: Option<impl Into<String>>) -> Self {
+        self.param_born_after = value.map(|value| value.into());
+        self
+    }
+
+    #[inline]
+    pub fn updated_since(mut self, value: impl Into<String>) -> Self {
+        self.param_updated_since = Some(value.into());
+        self
+    }
+
+    /// Sets [`updated_since`](#method.updated_since) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_updated_since(mut self, value: Option<impl Into<String>>) -> Self {
+        self.param_updated_since = value.map(|value| value.into());
+        self
+    }
+
+    #[inline]
+    pub fn tags(mut self, value: impl Iterator<Item = impl Into<String>>) -> Self {
+        self.param_tags = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`tags`](#method.tags) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_tags(mut self, value: Option<impl Iterator<Item = impl Into<String>>>) -> Self {
+        self.param_tags = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`tags`](#method.tags), but takes the values as a `Vec`.
+    #[inline]
+    pub fn tags_vec(mut self, value: impl Into<Vec<String>>) -> Self {
+        let value: Vec<_> = value.into();
+        self.param_tags = Some(value.into());
+        self
+    }
+
+    #[inline]
+    pub fn ids(mut self, value: impl Iterator<Item = impl Into<i64>>) -> Self {
+        self.param_ids = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`ids`](#method.ids) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_ids(mut self, value: Option<impl Iterator<Item = impl Into<i64>>>) -> Self {
+        self.param_ids = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`ids`](#method.ids), but takes the values as a `Vec`.
+    #[inline]
+    pub fn ids_vec(mut self, value: impl Into<Vec<i64>>) -> Self {
+        let value: Vec<_> = value.into();
+        self.param_ids = Some(value.into());
+        self
+    }
+
+    #[inline]
+    pub fn fields(mut self, value: impl Iterator<Item = impl Into<String>>) -> Self {
+        self.param_fields = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`fields`](#method.fields) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_fields(mut self, value: Option<impl Iterator<Item = impl Into<String>>>) -> Self {
+        self.param_fields = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`fields`](#method.fields), but takes the values as a `Vec`.
+    #[inline]
+    pub fn fields_vec(mut self, value: impl Into<Vec<String>>) -> Self {
+        let value: Vec<_> = value.into();
+        self.param_fields = Some(value.into());
+        self
+    }
+
+    #[inline]
+    pub fn filter(mut self, value: crate::pet_filter::PetFilter) -> Self {
+        self.param_filter = Some(value.into());
+        self
+    }
+
+    /// Sets [`filter`](#method.filter) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_filter(mut self, value: Option<crate::pet_filter::PetFilter>) -> Self {
+        self.param_filter = value.map(|value| value.into());
+        self
+    }
+}
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for PetGetBuilder {
+    type Output = Vec<Pet<serde_yaml::Value>>;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    const OPERATION_ID: Option<&'static str> = Some("listPets");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["account_key", "api_key"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok("/pets".into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::ACCEPT.as_str(), "application/yaml")
+        .query(&[
+            ("limit", self.param_limit.as_ref().map(std::string::ToString::to_string)),
+            ("sortBy", self.param_sort_by.as_ref().map(std::string::ToString::to_string)),
+            ("bornAfter", self.param_born_after.as_ref().map(std::string::ToString::to_string)),
+            ("updatedSince", self.param_updated_since.as_ref().map(std::string::ToString::to_string)),
+            ("tags", self.param_tags.as_ref().map(std::string::ToString::to_string)),
+            ("fields", self.param_fields.as_ref().map(std::string::ToString::to_string))
+        ])
+        .query({
+            &self.param_ids.as_ref().map(|v| {
+                v.iter().map(|v| ("ids", v.to_string())).collect::<Vec<_>>()
+            }).unwrap_or_default()
+        })
+        .query({
+            &self.param_filter.as_ref().map(|v| crate::client::deep_object_query("filter", v)).unwrap_or_default()
+        }))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<PetGetBuilder> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: PetGetBuilder) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+/// Allowed values for the `X-Api-Version` header.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[allow(non_camel_case_types)]
+pub enum XApiVersion {
+    V1,
+    V2,
+}
+
+impl XApiVersion {
+    /// All the variants of this enum.
+    pub const ALL: &'static [Self] = &[
+        XApiVersion::V1,
+        XApiVersion::V2,
+    ];
+
+    /// Returns the value of this variant as it appears on the wire
+    /// (i.e., its serialized form).
+    pub fn as_wire_str(&self) -> &'static str {
+        match self {
+            XApiVersion::V1 => "v1",
+            XApiVersion::V2 => "v2",
+        }
+    }
+}
+
+impl std::fmt::Display for XApiVersion {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.as_wire_str())
+    }
+}
+
+impl std::str::FromStr for XApiVersion {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL.iter().find(|v| v.as_wire_str() == s).cloned().ok_or_else(|| {
+            let expected: Vec<_> = Self::ALL.iter().map(Self::as_wire_str).collect();
+            format!("unknown value {:?} for XApiVersion (expected one of {:?})", s, expected)
+        })
+    }
+}
+
+impl std::convert::TryFrom<&str> for XApiVersion {
+    type Error = String;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl std::convert::TryFrom<String> for XApiVersion {
+    type Error = String;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+/// Builder created by [`Pet::add_pet`](./struct.Pet.html#method.add_pet) method for a `POST` operation associated with `Pet`.
+#[repr(transparent)]
+#[derive(Debug, Clone)]
+pub struct PetPostBuilder<XAuth, Id, Name, Any> {
+    inner: PetPostBuilderContainer<Any>,
+    _param_x_auth: core::marker::PhantomData<XAuth>,
+    _id: core::marker::PhantomData<Id>,
+    _name: core::marker::PhantomData<Name>,
+}
+
+#[derive(Debug, Default, Clone)]
+struct PetPostBuilderContainer<Any> {
+    body: self::Pet<Any>,
+    param_x_auth: Option<String>,
+    param_x_pet_id: Option<i64>,
+    param_x_api_version: Option<XApiVersion>,
+}
+
+impl<XAuth, Id, Name, Any> PetPostBuilder<XAuth, Id, Name, Any> {
+    #[inline]
+    pub fn x_auth(mut self, value: impl Into<String>) -> PetPostBuilder<crate::generics::XAuthExists, Id, Name, Any> {
+        self.inner.param_x_auth = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    #[inline]
+    pub fn x_pet_id(mut self, value: impl Into<i64>) -> Self {
+        self.inner.param_x_pet_id = Some(value.into());
+        self
+    }
+
+    /// Sets [`x_pet_id`](#method.x_pet_id) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_x_pet_id(mut self, value: Option<impl Into<i64>>) -> Self {
+        self.inner.param_x_pet_id = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`x_pet_id`](#method.x_pet_id), but takes a reference.
+    #[inline]
+    pub fn x_pet_id_ref(self, value: &i64) -> Self {
+        self.x_pet_id(*value)
+    }
+
+    #[inline]
+    pub fn x_api_version(mut self, value: impl Into<self::XApiVersion>) -> Self {
+        self.inner.param_x_api_version = Some(value.into());
+        self
+    }
+
+    /// Sets [`x_api_version`](#method.x_api_version) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_x_api_version(mut self, value: Option<impl Into<self::XApiVersion>>) -> Self {
+        self.inner.param_x_api_version = value.map(|value| value.into());
+        self
+    }
+
+    #[inline]
+    pub fn category(mut self, value: crate::category::Category) -> Self {
+        self.inner.body.category = Some(value.into());
+        self
+    }
+
+    /// Sets [`category`](#method.category) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_category(mut self, value: Option<crate::category::Category>) -> Self {
+        self.inner.body.category = value.map(|value| value.into());
+        self
+    }
+
+    #[inline]
+    pub fn id(mut self, value: impl Into<i64>) -> PetPostBuilder<XAuth, crate::generics::IdExists, Name, Any> {
+        self.inner.body.id = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Same as [`id`](#method.id), but takes a reference.
+    #[inline]
+    pub fn id_ref(self, value: &i64) -> PetPostBuilder<XAuth, crate::generics::IdExists, Name, Any> {
+        self.id(*value)
+    }
+
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> PetPostBuilder<XAuth, Id, crate::generics::NameExists, Any> {
+        self.inner.body.name = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    #[inline]
+    pub fn photo_urls(mut self, value: impl Iterator<Item = impl Into<String>>) -> Self {
+        self.inner.body.photo_urls = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`photo_urls`](#method.photo_urls) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_photo_urls(mut self, value: Option<impl Iterator<Item = impl Into<String>>>) -> Self {
+        self.inner.body.photo_urls = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`photo_urls`](#method.photo_urls), but takes the values as a `Vec`.
+    #[inline]
+    pub fn photo_urls_vec(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.inner.body.photo_urls = Some(value.into());
+        self
+    }
+
+    #[inline]
+    pub fn tags(mut self, value: impl Iterator<Item = crate::tag::Tag>) -> Self {
+        self.inner.body.tags = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`tags`](#method.tags) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_tags(mut self, value: Option<impl Iterator<Item = crate::tag::Tag>>) -> Self {
+        self.inner.body.tags = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`tags`](#method.tags), but takes the values as a `Vec`.
+    #[inline]
+    pub fn tags_vec(mut self, value: impl Into<Vec<crate::tag::Tag>>) -> Self {
+        self.inner.body.tags = Some(value.into());
+        self
+    }
+
+    #[inline]
+    pub fn other_fields(mut self, value: impl Iterator<Item = (String, impl Into<Any>)>) -> Self {
+        self.inner.body.other_fields = Some(value.map(|(key, value)| (key, value.into())).collect::<indexmap::IndexMap<_, _>>().into());
+        self
+    }
+
+    /// Sets [`other_fields`](#method.other_fields) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_other_fields(mut self, value: Option<impl Iterator<Item = (String, impl Into<Any>)>>) -> Self {
+        self.inner.body.other_fields = value.map(|value| value.map(|(key, value)| (key, value.into())).collect::<indexmap::IndexMap<_, _>>().into());
+        self
+    }
+}
+
+impl<Client: crate::client::ApiClient + Sync + 'static, Any: serde::Serialize> crate::client::Sendable<Client> for PetPostBuilder<crate::generics::XAuthExists, crate::generics::IdExists, crate::generics::NameExists, Any> {
+    type Output = crate::pet::Pet<serde_yaml::Value>;
+
+    const METHOD: http::Method = http::Method::POST;
+
+    const OPERATION_ID: Option<&'static str> = Some("addPet");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["api_key"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok("/pets".into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        let mut req = req;
+        req = req.header("X-Auth", &self.inner.param_x_auth.as_ref().map(std::string::ToString::to_string).expect("missing parameter x_auth?"));
+        if let Some(v) = &self.inner.param_x_pet_id.as_ref().map(std::string::ToString::to_string) {
+            req = req.header("X-Pet-ID", &v);
+        }
+        if let Some(v) = &self.inner.param_x_api_version.as_ref().map(std::string::ToString::to_string) {
+            req = req.header("X-Api-Version", &v);
+        }
+
+        Ok(req
+        .header(http::header::CONTENT_TYPE.as_str(), "application/yaml")
+        .body_bytes({
+            let mut vec = vec![];
+            serde_yaml::to_writer(&mut vec, &self.inner.body)?;
+            vec
+        })
+        .header(http::header::ACCEPT.as_str(), "application/yaml"))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("X-Auth")
+            || (name.eq_ignore_ascii_case("X-Pet-ID") && self.inner.param_x_pet_id.is_some())
+            || (name.eq_ignore_ascii_case("X-Api-Version") && self.inner.param_x_api_version.is_some())
+            || name.eq_ignore_ascii_case("Content-Type")
+            || name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl<Any: serde::Serialize> std::convert::TryFrom<PetPostBuilder<crate::generics::XAuthExists, crate::generics::IdExists, crate::generics::NameExists, Any>> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: PetPostBuilder<crate::generics::XAuthExists, crate::generics::IdExists, crate::generics::NameExists, Any>) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+impl<Any> crate::client::ResponseWrapper<crate::pet::Pet<serde_yaml::Value>, PetPostBuilder<crate::generics::XAuthExists, crate::generics::IdExists, crate::generics::NameExists, Any>> {
+    /// Maximum allowed requests in the current period
+    #[inline]
+    pub fn x_rate_limit(&self) -> Option<i64> {
+        self.headers.get("x-rate-limit").and_then(|v| String::from_utf8_lossy(v.as_ref()).parse().ok())
+    }
+    /// Whether the requests have exceeded for this window.
+    #[inline]
+    pub fn x_rate_limit_exceeded(&self) -> Option<bool> {
+        self.headers.get("x-rate-limit-exceeded").and_then(|v| String::from_utf8_lossy(v.as_ref()).parse().ok())
+    }
+    /// Remaining requests in the current period
+    #[inline]
+    pub fn x_rate_limit_remaining(&self) -> Option<i64> {
+        self.headers.get("x-rate-limit-remaining").and_then(|v| String::from_utf8_lossy(v.as_ref()).parse().ok())
+    }
+    /// Time at which rate limit is reset (in UNIX epoch)
+    #[inline]
+    pub fn x_rate_limit_reset(&self) -> Option<i64> {
+        self.headers.get("x-rate-limit-reset").and_then(|v| String::from_utf8_lossy(v.as_ref()).parse().ok())
+    }
+    #[inline]
+    pub fn x_array(&self) -> Option<crate::util::Delimited<crate::util::Delimited<crate::util::Delimited<crate::util::Delimited<f64, crate::util::Ssv>, crate::util::Tsv>, crate::util::Csv>, crate::util::Csv>> {
+        self.headers.get("x-array").and_then(|v| String::from_utf8_lossy(v.as_ref()).parse().ok())
+    }
+    #[inline]
+    pub fn x_string(&self) -> Option<String> {
+        self.headers.get("x-string").and_then(|v| String::from_utf8_lossy(v.as_ref()).parse().ok())
+    }
+}
+
+/// Allowed values for the `kind` path parameter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[allow(non_camel_case_types)]
+pub enum Kind {
+    Dog,
+    Cat,
+    Self_,
+}
+
+impl Kind {
+    /// All the variants of this enum.
+    pub const ALL: &'static [Self] = &[
+        Kind::Dog,
+        Kind::Cat,
+        Kind::Self_,
+    ];
+
+    /// Returns the value of this variant as it appears on the wire
+    /// (i.e., its serialized form).
+    pub fn as_wire_str(&self) -> &'static str {
+        match self {
+            Kind::Dog => "dog",
+            Kind::Cat => "cat",
+            Kind::Self_ => "self",
+        }
+    }
+}
+
+impl std::fmt::Display for Kind {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.as_wire_str())
+    }
+}
+
+impl std::str::FromStr for Kind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL.iter().find(|v| v.as_wire_str() == s).cloned().ok_or_else(|| {
+            let expected: Vec<_> = Self::ALL.iter().map(Self::as_wire_str).collect();
+            format!("unknown value {:?} for Kind (expected one of {:?})", s, expected)
+        })
+    }
+}
+
+impl std::convert::TryFrom<&str> for Kind {
+    type Error = String;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl std::convert::TryFrom<String> for Kind {
+    type Error = String;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+/// Builder created by [`Pet::list_pets_by_kind`](./struct.Pet.html#method.list_pets_by_kind) method for a `GET` operation associated with `Pet`.
+#[repr(transparent)]
+#[derive(Debug, Clone)]
+pub struct PetGetBuilder1<Kind> {
+    inner: PetGetBuilder1Container,
+    _param_kind: core::marker::PhantomData<Kind>,
+}
+
+#[derive(Debug, Default, Clone)]
+struct PetGetBuilder1Container {
+    param_kind: Option<Kind>,
+    param_filter: Option<crate::pet_filter::PetFilter>,
+}
+
+impl<Kind> PetGetBuilder1<Kind> {
+    #[inline]
+    pub fn kind(mut self, value: impl Into<self::Kind>) -> PetGetBuilder1<crate::generics::KindExists> {
+        self.inner.param_kind = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    #[inline]
+    pub fn filter(mut self, value: crate::pet_filter::PetFilter) -> Self {
+        self.inner.param_filter = Some(value.into());
+        self
+    }
+
+    /// Sets [`filter`](#method.filter) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_filter(mut self, value: Option<crate::pet_filter::PetFilter>) -> Self {
+        self.inner.param_filter = value.map(|value| value.into());
+        self
+    }
+}
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for PetGetBuilder1<crate::generics::KindExists> {
+    type Output = Vec<Pet<serde_yaml::Value>>;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    const OPERATION_ID: Option<&'static str> = Some("listPetsByKind");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["api_key"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok(format!("/pets/kind/{kind}", kind=crate::client::path_param("kind", self.inner.param_kind.as_ref().expect("missing parameter kind?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::ACCEPT.as_str(), "application/yaml")
+        .query({
+            &self.inner.param_filter.as_ref().map(|v| crate::client::deep_object_query("filter", v)).unwrap_or_default()
+        }))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<PetGetBuilder1<crate::generics::KindExists>> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: PetGetBuilder1<crate::generics::KindExists>) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+/// Builder created by [`Pet::get_pet_by_id`](./struct.Pet.html#method.get_pet_by_id) method for a `GET` operation associated with `Pet`.
+#[repr(transparent)]
+#[derive(Debug, Clone)]
+pub struct PetGetBuilder2<PetId> {
+    inner: PetGetBuilder2Container,
+    _param_pet_id: core::marker::PhantomData<PetId>,
+}
+
+#[derive(Debug, Default, Clone)]
+struct PetGetBuilder2Container {
+    param_pet_id: Option<i64>,
+    param_x_request_id: Option<String>,
+}
+
+impl<PetId> PetGetBuilder2<PetId> {
+    /// ID of the pet.
+    #[inline]
+    pub fn pet_id(mut self, value: impl Into<i64>) -> PetGetBuilder2<crate::generics::PetIdExists> {
+        self.inner.param_pet_id = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Same as [`pet_id`](#method.pet_id), but takes a reference.
+    #[inline]
+    pub fn pet_id_ref(self, value: &i64) -> PetGetBuilder2<crate::generics::PetIdExists> {
+        self.pet_id(*value)
+    }
+
+    #[inline]
+    pub fn x_request_id(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_x_request_id = Some(value.into());
+        self
+    }
+
+    /// Sets [`x_request_id`](#method.x_request_id) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_x_request_id(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_x_request_id = value.map(|value| value.into());
+        self
+    }
+}
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for PetGetBuilder2<crate::generics::PetIdExists> {
+    type Output = Pet<serde_yaml::Value>;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    const OPERATION_ID: Option<&'static str> = Some("getPetById");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["bearer"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok(format!("/pets/{petId}", petId=crate::client::path_param("petId", self.inner.param_pet_id.as_ref().expect("missing parameter pet_id?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        let mut req = req;
+        if let Some(v) = &self.inner.param_x_request_id.as_ref().map(std::string::ToString::to_string) {
+            req = req.header("X-Request-Id", &v);
+        }
+
+        Ok(req
+        .header(http::header::ACCEPT.as_str(), "application/yaml")
+        .timeout(std::time::Duration::from_millis(2500)))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        (name.eq_ignore_ascii_case("X-Request-Id") && self.inner.param_x_request_id.is_some())
+            || name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<PetGetBuilder2<crate::generics::PetIdExists>> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: PetGetBuilder2<crate::generics::PetIdExists>) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+/// Error responses of [`PetGetBuilder2`] (based on the status code).
+#[derive(Debug)]
+pub enum PetGetError2<R: crate::client::Response + 'static> {
+    /// `404` response.
+    NotFound,
+    /// `422` response.
+    UnprocessableEntity(crate::status::Status),
+    /// Response for any other status code.
+    Default(http::StatusCode, crate::status::Status),
+    /// Any other failure (including undeclared status codes).
+    Other(crate::client::ApiError<R>),
+}
+
+#[async_trait::async_trait]
+impl<R: crate::client::Response + 'static> crate::client::OperationError<R> for PetGetError2<R> {
+    async fn from_api_error(err: crate::client::ApiError<R>) -> Self {
+        let (_, status, resp) = match err {
+            crate::client::ApiError::Failure(p, s, r) => (p, s, r),
+            e => return PetGetError2::Other(e),
+        };
+
+        match status.as_u16() {
+            404 => PetGetError2::NotFound,
+            422 => match crate::client::decode_body(resp).await {
+                Ok(v) => PetGetError2::UnprocessableEntity(v),
+                Err(e) => PetGetError2::Other(e),
+            },
+            _ => match crate::client::decode_body(resp).await {
+                Ok(v) => PetGetError2::Default(status, v),
+                Err(e) => PetGetError2::Other(e),
+            },
+        }
+    }
+}
+
+/// Builder created by [`Pet::update_pet`](./struct.Pet.html#method.update_pet) method for a `PUT` operation associated with `Pet`.
+#[repr(transparent)]
+#[derive(Debug, Clone)]
+pub struct PetPutBuilder2<PetId, Id, Name, Any> {
+    inner: PetPutBuilder2Container<Any>,
+    _param_pet_id: core::marker::PhantomData<PetId>,
+    _id: core::marker::PhantomData<Id>,
+    _name: core::marker::PhantomData<Name>,
+}
+
+#[derive(Debug, Default, Clone)]
+struct PetPutBuilder2Container<Any> {
+    body: self::Pet<Any>,
+    param_prefer: Option<crate::util::PreferReturn>,
+    param_pet_id: Option<i64>,
+    param_x_request_id: Option<String>,
+}
+
+impl<PetId, Id, Name, Any> PetPutBuilder2<PetId, Id, Name, Any> {
+    #[inline]
+    pub fn prefer_return(mut self, value: crate::util::PreferReturn) -> Self {
+        self.inner.param_prefer = Some(value.into());
+        self
+    }
+
+    /// ID of the pet.
+    #[inline]
+    pub fn pet_id(mut self, value: impl Into<i64>) -> PetPutBuilder2<crate::generics::PetIdExists, Id, Name, Any> {
+        self.inner.param_pet_id = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Same as [`pet_id`](#method.pet_id), but takes a reference.
+    #[inline]
+    pub fn pet_id_ref(self, value: &i64) -> PetPutBuilder2<crate::generics::PetIdExists, Id, Name, Any> {
+        self.pet_id(*value)
+    }
+
+    #[inline]
+    pub fn x_request_id(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_x_request_id = Some(value.into());
+        self
+    }
+
+    /// Sets [`x_request_id`](#method.x_request_id) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_x_request_id(mut self, value: Option<impl Into<String>>) -> Self {
+        self.inner.param_x_request_id = value.map(|value| value.into());
+        self
+    }
+
+    #[inline]
+    pub fn category(mut self, value: crate::category::Category) -> Self {
+        self.inner.body.category = Some(value.into());
+        self
+    }
+
+    /// Sets [`category`](#method.category) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_category(mut self, value: Option<crate::category::Category>) -> Self {
+        self.inner.body.category = value.map(|value| value.into());
+        self
+    }
+
+    #[inline]
+    pub fn id(mut self, value: impl Into<i64>) -> PetPutBuilder2<PetId, crate::generics::IdExists, Name, Any> {
+        self.inner.body.id = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Same as [`id`](#method.id), but takes a reference.
+    #[inline]
+    pub fn id_ref(self, value: &i64) -> PetPutBuilder2<PetId, crate::generics::IdExists, Name, Any> {
+        self.id(*value)
+    }
+
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> PetPutBuilder2<PetId, Id, crate::generics::NameExists, Any> {
+        self.inner.body.name = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    #[inline]
+    pub fn photo_urls(mut self, value: impl Iterator<Item = impl Into<String>>) -> Self {
+        self.inner.body.photo_urls = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`photo_urls`](#method.photo_urls) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_photo_urls(mut self, value: Option<impl Iterator<Item = impl Into<String>>>) -> Self {
+        self.inner.body.photo_urls = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`photo_urls`](#method.photo_urls), but takes the values as a `Vec`.
+    #[inline]
+    pub fn photo_urls_vec(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.inner.body.photo_urls = Some(value.into());
+        self
+    }
+
+    #[inline]
+    pub fn tags(mut self, value: impl Iterator<Item = crate::tag::Tag>) -> Self {
+        self.inner.body.tags = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`tags`](#method.tags) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_tags(mut self, value: Option<impl Iterator<Item = crate::tag::Tag>>) -> Self {
+        self.inner.body.tags = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`tags`](#method.tags), but takes the values as a `Vec`.
+    #[inline]
+    pub fn tags_vec(mut self, value: impl Into<Vec<crate::tag::Tag>>) -> Self {
+        self.inner.body.tags = Some(value.into());
+        self
+    }
+
+    #[inline]
+    pub fn other_fields(mut self, value: impl Iterator<Item = (String, impl Into<Any>)>) -> Self {
+        self.inner.body.other_fields = Some(value.map(|(key, value)| (key, value.into())).collect::<indexmap::IndexMap<_, _>>().into());
+        self
+    }
+
+    /// Sets [`other_fields`](#method.other_fields) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_other_fields(mut self, value: Option<impl Iterator<Item = (String, impl Into<Any>)>>) -> Self {
+        self.inner.body.other_fields = value.map(|value| value.map(|(key, value)| (key, value.into())).collect::<indexmap::IndexMap<_, _>>().into());
+        self
+    }
+}
+
+#[async_trait::async_trait]
+impl<Client: crate::client::ApiClient + Sync + 'static, Any: serde::Serialize + Send + Sync> crate::client::Sendable<Client> for PetPutBuilder2<crate::generics::PetIdExists, crate::generics::IdExists, crate::generics::NameExists, Any> {
+    type Output = Option<crate::pet::Pet<serde_yaml::Value>>;
+
+    const METHOD: http::Method = http::Method::PUT;
+
+    const OPERATION_ID: Option<&'static str> = Some("updatePet");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["bearer"], &["api_key", "basic"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok(format!("/pets/{petId}", petId=crate::client::path_param("petId", self.inner.param_pet_id.as_ref().expect("missing parameter pet_id?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        let mut req = req;
+        if let Some(v) = &self.inner.param_prefer.as_ref().map(std::string::ToString::to_string) {
+            req = req.header("Prefer", &v);
+        }
+        if let Some(v) = &self.inner.param_x_request_id.as_ref().map(std::string::ToString::to_string) {
+            req = req.header("X-Request-Id", &v);
+        }
+
+        Ok(req
+        .header(http::header::CONTENT_TYPE.as_str(), "application/yaml")
+        .body_bytes({
+            let mut vec = vec![];
+            serde_yaml::to_writer(&mut vec, &self.inner.body)?;
+            vec
+        })
+        .header(http::header::ACCEPT.as_str(), "application/yaml"))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        (name.eq_ignore_ascii_case("Prefer") && self.inner.param_prefer.is_some())
+            || (name.eq_ignore_ascii_case("X-Request-Id") && self.inner.param_x_request_id.is_some())
+            || name.eq_ignore_ascii_case("Content-Type")
+            || name.eq_ignore_ascii_case("Accept")
+    }
+
+    async fn send(&self, client: &Client) -> Result<crate::client::ResponseWrapper<Self::Output, Self>, crate::client::ApiError<Client::Response>> {
+        use crate::client::Response;
+        let resp = self.send_raw(client).await?;
+        if resp.status() == http::StatusCode::NO_CONTENT {
+            return crate::client::ResponseWrapper::wrap(resp, |_| async { Ok(None) }).await;
+        }
+
+        crate::client::ResponseWrapper::decode(resp).await
+    }
+}
+
+impl<Any: serde::Serialize + Send + Sync> std::convert::TryFrom<PetPutBuilder2<crate::generics::PetIdExists, crate::generics::IdExists, crate::generics::NameExists, Any>> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: PetPutBuilder2<crate::generics::PetIdExists, crate::generics::IdExists, crate::generics::NameExists, Any>) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+/// Builder created by [`Pet::upload_pet_certificate`](./struct.Pet.html#method.upload_pet_certificate) method for a `PUT` operation associated with `Pet`.
+#[repr(transparent)]
+#[derive(Debug, Clone)]
+pub struct PetPutBuilder3<PetId> {
+    inner: PetPutBuilder3Container,
+    _param_pet_id: core::marker::PhantomData<PetId>,
+}
+
+#[derive(Debug, Default, Clone)]
+struct PetPutBuilder3Container {
+    param_pet_id: Option<i64>,
+    param_certificate: Option<Vec<u8>>,
+}
+
+impl<PetId> PetPutBuilder3<PetId> {
+    /// ID of the pet.
+    #[inline]
+    pub fn pet_id(mut self, value: impl Into<i64>) -> PetPutBuilder3<crate::generics::PetIdExists> {
+        self.inner.param_pet_id = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Same as [`pet_id`](#method.pet_id), but takes a reference.
+    #[inline]
+    pub fn pet_id_ref(self, value: &i64) -> PetPutBuilder3<crate::generics::PetIdExists> {
+        self.pet_id(*value)
+    }
+
+    /// Scanned certificate.
+    #[inline]
+    pub fn certificate(mut self, value: impl Into<Vec<u8>>) -> Self {
+        self.inner.param_certificate = Some(value.into());
+        self
+    }
+
+    /// Sets [`certificate`](#method.certificate) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_certificate(mut self, value: Option<impl Into<Vec<u8>>>) -> Self {
+        self.inner.param_certificate = value.map(|value| value.into());
+        self
+    }
+}
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for PetPutBuilder3<crate::generics::PetIdExists> {
+    type Output = Pet<serde_yaml::Value>;
+
+    const METHOD: http::Method = http::Method::PUT;
+
+    const OPERATION_ID: Option<&'static str> = Some("uploadPetCertificate");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["api_key"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok(format!("/pets/{petId}/certificate", petId=crate::client::path_param("petId", self.inner.param_pet_id.as_ref().expect("missing parameter pet_id?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::CONTENT_TYPE.as_str(), "application/pdf")
+        .body_bytes(self.inner.param_certificate.clone().map(Vec::from).unwrap_or_default())
+        .header(http::header::ACCEPT.as_str(), "application/yaml"))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Content-Type")
+            || name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<PetPutBuilder3<crate::generics::PetIdExists>> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: PetPutBuilder3<crate::generics::PetIdExists>) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+/// Builder created by [`Pet::get_pet_with_photo`](./struct.Pet.html#method.get_pet_with_photo) method for a `GET` operation associated with `Pet`.
+#[repr(transparent)]
+#[derive(Debug, Clone)]
+pub struct PetGetBuilder4<PetId> {
+    inner: PetGetBuilder4Container,
+    _param_pet_id: core::marker::PhantomData<PetId>,
+}
+
+#[derive(Debug, Default, Clone)]
+struct PetGetBuilder4Container {
+    param_pet_id: Option<i64>,
+}
+
+impl<PetId> PetGetBuilder4<PetId> {
+    /// ID of the pet.
+    #[inline]
+    pub fn pet_id(mut self, value: impl Into<i64>) -> PetGetBuilder4<crate::generics::PetIdExists> {
+        self.inner.param_pet_id = Some(value.into());
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Same as [`pet_id`](#method.pet_id), but takes a reference.
+    #[inline]
+    pub fn pet_id_ref(self, value: &i64) -> PetGetBuilder4<crate::generics::PetIdExists> {
+        self.pet_id(*value)
+    }
+}
+
+#[async_trait::async_trait]
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for PetGetBuilder4<crate::generics::PetIdExists> {
+    type Output = crate::util::MultipartMixed<Pet<serde_yaml::Value>>;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    const OPERATION_ID: Option<&'static str> = Some("getPetWithPhoto");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["api_key"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok(format!("/pets/{petId}/photo", petId=crate::client::path_param("petId", self.inner.param_pet_id.as_ref().expect("missing parameter pet_id?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::ACCEPT.as_str(), "multipart/mixed"))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Accept")
+    }
+
+    async fn send(&self, client: &Client) -> Result<crate::client::ResponseWrapper<Self::Output, Self>, crate::client::ApiError<Client::Response>> {
+        use crate::client::Response;
+        let resp = self.send_raw(client).await?;
+        let boundary = resp.header("content-type").and_then(crate::util::multipart_boundary).map(String::from);
+        crate::client::ResponseWrapper::wrap(resp, |r| crate::client::multipart_mixed(boundary, r)).await
+    }
+}
+
+impl std::convert::TryFrom<PetGetBuilder4<crate::generics::PetIdExists>> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: PetGetBuilder4<crate::generics::PetIdExists>) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
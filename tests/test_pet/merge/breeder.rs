@@ -0,0 +1,129 @@
+
+/// Account of a breeder (along with its license).
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct Breeder {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bio: Option<String>,
+    pub license: String,
+    pub nickname: String,
+    #[serde(skip_deserializing)]
+    pub password: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token: Option<String>,
+    pub username: String,
+}
+
+impl Breeder {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> BreederBuilder<crate::generics::MissingLicense, crate::generics::MissingNickname, crate::generics::MissingPassword, crate::generics::MissingUsername> {
+        BreederBuilder {
+            body: Default::default(),
+            _license: core::marker::PhantomData,
+            _nickname: core::marker::PhantomData,
+            _password: core::marker::PhantomData,
+            _username: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    ///
+    /// **NOTE:** Write-only fields (`password`) should be set again.
+    #[inline]
+    pub fn to_builder(&self) -> BreederBuilder<crate::generics::LicenseExists, crate::generics::NicknameExists, crate::generics::MissingPassword, crate::generics::UsernameExists>
+    where
+        Self: Clone,
+    {
+        BreederBuilder {
+            body: self.clone(),
+            _license: core::marker::PhantomData,
+            _nickname: core::marker::PhantomData,
+            _password: core::marker::PhantomData,
+            _username: core::marker::PhantomData,
+        }
+    }
+
+    /// Merge the fields of `other` into this object. Optional fields are
+    /// overwritten only if they're set in `other`, whereas required fields
+    /// are always overwritten.
+    pub fn merge(&mut self, other: Self) {
+        if let Some(v) = other.bio {
+            self.bio = Some(v);
+        }
+        self.license = other.license;
+        self.nickname = other.nickname;
+        self.password = other.password;
+        if let Some(v) = other.token {
+            self.token = Some(v);
+        }
+        self.username = other.username;
+    }
+}
+
+impl Into<Breeder> for BreederBuilder<crate::generics::LicenseExists, crate::generics::NicknameExists, crate::generics::PasswordExists, crate::generics::UsernameExists> {
+    fn into(self) -> Breeder {
+        self.body
+    }
+}
+
+/// Builder for [`Breeder`](./struct.Breeder.html) object.
+#[derive(Debug, Clone)]
+pub struct BreederBuilder<License, Nickname, Password, Username> {
+    body: self::Breeder,
+    _license: core::marker::PhantomData<License>,
+    _nickname: core::marker::PhantomData<Nickname>,
+    _password: core::marker::PhantomData<Password>,
+    _username: core::marker::PhantomData<Username>,
+}
+
+impl<License, Nickname, Password, Username> BreederBuilder<License, Nickname, Password, Username> {
+    #[inline]
+    pub fn bio(mut self, value: impl Into<String>) -> Self {
+        self.body.bio = Some(value.into());
+        self
+    }
+
+    /// Sets [`bio`](#method.bio) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_bio(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.bio = value.map(|value| value.into());
+        self
+    }
+
+    #[inline]
+    pub fn license(mut self, value: impl Into<String>) -> BreederBuilder<crate::generics::LicenseExists, Nickname, Password, Username> {
+        self.body.license = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    #[inline]
+    pub fn nickname(mut self, value: impl Into<String>) -> BreederBuilder<License, crate::generics::NicknameExists, Password, Username> {
+        self.body.nickname = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    #[inline]
+    pub fn password(mut self, value: impl Into<String>) -> BreederBuilder<License, Nickname, crate::generics::PasswordExists, Username> {
+        self.body.password = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    #[inline]
+    pub fn token(mut self, value: impl Into<String>) -> Self {
+        self.body.token = Some(value.into());
+        self
+    }
+
+    /// Sets [`token`](#method.token) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_token(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.token = value.map(|value| value.into());
+        self
+    }
+
+    #[inline]
+    pub fn username(mut self, value: impl Into<String>) -> BreederBuilder<License, Nickname, Password, crate::generics::UsernameExists> {
+        self.body.username = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+}
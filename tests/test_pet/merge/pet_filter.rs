@@ -0,0 +1,106 @@
+
+/// Criteria for filtering the pets.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct PetFilter {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category: Option<crate::category::Category>,
+    #[serde(rename = "minAge", skip_serializing_if = "Option::is_none")]
+    pub min_age: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+impl PetFilter {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> PetFilterBuilder {
+        PetFilterBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> PetFilterBuilder
+    where
+        Self: Clone,
+    {
+        PetFilterBuilder {
+            body: self.clone(),
+        }
+    }
+
+    /// Merge the fields of `other` into this object. Optional fields are
+    /// overwritten only if they're set in `other`, whereas required fields
+    /// are always overwritten.
+    pub fn merge(&mut self, other: Self) {
+        if let Some(v) = other.category {
+            self.category = Some(v);
+        }
+        if let Some(v) = other.min_age {
+            self.min_age = Some(v);
+        }
+        if let Some(v) = other.name {
+            self.name = Some(v);
+        }
+    }
+}
+
+impl Into<PetFilter> for PetFilterBuilder {
+    fn into(self) -> PetFilter {
+        self.body
+    }
+}
+
+/// Builder for [`PetFilter`](./struct.PetFilter.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct PetFilterBuilder {
+    body: self::PetFilter,
+}
+
+impl PetFilterBuilder {
+    #[inline]
+    pub fn category(mut self, value: crate::category::Category) -> Self {
+        self.body.category = Some(value.into());
+        self
+    }
+
+    /// Sets [`category`](#method.category) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_category(mut self, value: Option<crate::category::Category>) -> Self {
+        self.body.category = value.map(|value| value.into());
+        self
+    }
+
+    #[inline]
+    pub fn min_age(mut self, value: impl Into<i64>) -> Self {
+        self.body.min_age = Some(value.into());
+        self
+    }
+
+    /// Sets [`min_age`](#method.min_age) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_min_age(mut self, value: Option<impl Into<i64>>) -> Self {
+        self.body.min_age = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`min_age`](#method.min_age), but takes a reference.
+    #[inline]
+    pub fn min_age_ref(self, value: &i64) -> Self {
+        self.min_age(*value)
+    }
+
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> Self {
+        self.body.name = Some(value.into());
+        self
+    }
+
+    /// Sets [`name`](#method.name) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_name(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.name = value.map(|value| value.into());
+        self
+    }
+}
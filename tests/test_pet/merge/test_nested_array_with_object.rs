@@ -0,0 +1,148 @@
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct TestNestedArrayWithObjectItemItem {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bar: Option<crate::test_nested_array_with_object::TestNestedArrayWithObjectItemItemBar>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub foo: Option<String>,
+}
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct TestNestedArrayWithObjectItemItemBar {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub baz: Option<i64>,
+}
+
+impl TestNestedArrayWithObjectItemItem {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> TestNestedArrayWithObjectItemItemBuilder {
+        TestNestedArrayWithObjectItemItemBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> TestNestedArrayWithObjectItemItemBuilder
+    where
+        Self: Clone,
+    {
+        TestNestedArrayWithObjectItemItemBuilder {
+            body: self.clone(),
+        }
+    }
+
+    /// Merge the fields of `other` into this object. Optional fields are
+    /// overwritten only if they're set in `other`, whereas required fields
+    /// are always overwritten.
+    pub fn merge(&mut self, other: Self) {
+        if let Some(v) = other.bar {
+            self.bar = Some(v);
+        }
+        if let Some(v) = other.foo {
+            self.foo = Some(v);
+        }
+    }
+}
+
+impl Into<TestNestedArrayWithObjectItemItem> for TestNestedArrayWithObjectItemItemBuilder {
+    fn into(self) -> TestNestedArrayWithObjectItemItem {
+        self.body
+    }
+}
+
+/// Builder for [`TestNestedArrayWithObjectItemItem`](./struct.TestNestedArrayWithObjectItemItem.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct TestNestedArrayWithObjectItemItemBuilder {
+    body: self::TestNestedArrayWithObjectItemItem,
+}
+
+impl TestNestedArrayWithObjectItemItemBuilder {
+    #[inline]
+    pub fn bar(mut self, value: crate::test_nested_array_with_object::TestNestedArrayWithObjectItemItemBar) -> Self {
+        self.body.bar = Some(value.into());
+        self
+    }
+
+    /// Sets [`bar`](#method.bar) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_bar(mut self, value: Option<crate::test_nested_array_with_object::TestNestedArrayWithObjectItemItemBar>) -> Self {
+        self.body.bar = value.map(|value| value.into());
+        self
+    }
+
+    #[inline]
+    pub fn foo(mut self, value: impl Into<String>) -> Self {
+        self.body.foo = Some(value.into());
+        self
+    }
+
+    /// Sets [`foo`](#method.foo) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_foo(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.foo = value.map(|value| value.into());
+        self
+    }
+}
+
+impl TestNestedArrayWithObjectItemItemBar {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> TestNestedArrayWithObjectItemItemBarBuilder {
+        TestNestedArrayWithObjectItemItemBarBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> TestNestedArrayWithObjectItemItemBarBuilder
+    where
+        Self: Clone,
+    {
+        TestNestedArrayWithObjectItemItemBarBuilder {
+            body: self.clone(),
+        }
+    }
+
+    /// Merge the fields of `other` into this object. Optional fields are
+    /// overwritten only if they're set in `other`, whereas required fields
+    /// are always overwritten.
+    pub fn merge(&mut self, other: Self) {
+        if let Some(v) = other.baz {
+            self.baz = Some(v);
+        }
+    }
+}
+
+impl Into<TestNestedArrayWithObjectItemItemBar> for TestNestedArrayWithObjectItemItemBarBuilder {
+    fn into(self) -> TestNestedArrayWithObjectItemItemBar {
+        self.body
+    }
+}
+
+/// Builder for [`TestNestedArrayWithObjectItemItemBar`](./struct.TestNestedArrayWithObjectItemItemBar.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct TestNestedArrayWithObjectItemItemBarBuilder {
+    body: self::TestNestedArrayWithObjectItemItemBar,
+}
+
+impl TestNestedArrayWithObjectItemItemBarBuilder {
+    #[inline]
+    pub fn baz(mut self, value: impl Into<i64>) -> Self {
+        self.body.baz = Some(value.into());
+        self
+    }
+
+    /// Sets [`baz`](#method.baz) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_baz(mut self, value: Option<impl Into<i64>>) -> Self {
+        self.body.baz = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`baz`](#method.baz), but takes a reference.
+    #[inline]
+    pub fn baz_ref(self, value: &i64) -> Self {
+        self.baz(*value)
+    }
+}
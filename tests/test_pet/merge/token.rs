@@ -0,0 +1,89 @@
+
+/// OAuth access token.
+#[deprecated(note = "OAuth access token.")]
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct Token {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub access_token: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires_in: Option<i64>,
+}
+
+impl Token {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> TokenBuilder {
+        TokenBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> TokenBuilder
+    where
+        Self: Clone,
+    {
+        TokenBuilder {
+            body: self.clone(),
+        }
+    }
+
+    /// Merge the fields of `other` into this object. Optional fields are
+    /// overwritten only if they're set in `other`, whereas required fields
+    /// are always overwritten.
+    pub fn merge(&mut self, other: Self) {
+        if let Some(v) = other.access_token {
+            self.access_token = Some(v);
+        }
+        if let Some(v) = other.expires_in {
+            self.expires_in = Some(v);
+        }
+    }
+}
+
+impl Into<Token> for TokenBuilder {
+    fn into(self) -> Token {
+        self.body
+    }
+}
+
+/// Builder for [`Token`](./struct.Token.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct TokenBuilder {
+    body: self::Token,
+}
+
+impl TokenBuilder {
+    #[inline]
+    pub fn access_token(mut self, value: impl Into<String>) -> Self {
+        self.body.access_token = Some(value.into());
+        self
+    }
+
+    /// Sets [`access_token`](#method.access_token) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_access_token(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.access_token = value.map(|value| value.into());
+        self
+    }
+
+    #[inline]
+    pub fn expires_in(mut self, value: impl Into<i64>) -> Self {
+        self.body.expires_in = Some(value.into());
+        self
+    }
+
+    /// Sets [`expires_in`](#method.expires_in) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_expires_in(mut self, value: Option<impl Into<i64>>) -> Self {
+        self.body.expires_in = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`expires_in`](#method.expires_in), but takes a reference.
+    #[inline]
+    pub fn expires_in_ref(self, value: &i64) -> Self {
+        self.expires_in(*value)
+    }
+}
@@ -0,0 +1,73 @@
+
+/// Sent by the store when someone adopts a pet.
+#[non_exhaustive]
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct Adoption {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub adopter: Option<String>,
+    #[serde(rename = "petId")]
+    pub pet_id: i64,
+}
+
+impl Adoption {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> AdoptionBuilder<crate::generics::MissingPetId> {
+        AdoptionBuilder {
+            body: Default::default(),
+            _pet_id: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> AdoptionBuilder<crate::generics::PetIdExists>
+    where
+        Self: Clone,
+    {
+        AdoptionBuilder {
+            body: self.clone(),
+            _pet_id: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<Adoption> for AdoptionBuilder<crate::generics::PetIdExists> {
+    fn into(self) -> Adoption {
+        self.body
+    }
+}
+
+/// Builder for [`Adoption`](./struct.Adoption.html) object.
+#[derive(Debug, Clone)]
+pub struct AdoptionBuilder<PetId> {
+    body: self::Adoption,
+    _pet_id: core::marker::PhantomData<PetId>,
+}
+
+impl<PetId> AdoptionBuilder<PetId> {
+    #[inline]
+    pub fn adopter(mut self, value: impl Into<String>) -> Self {
+        self.body.adopter = Some(value.into());
+        self
+    }
+
+    /// Sets [`adopter`](#method.adopter) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_adopter(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.adopter = value.map(|value| value.into());
+        self
+    }
+
+    #[inline]
+    pub fn pet_id(mut self, value: impl Into<i64>) -> AdoptionBuilder<crate::generics::PetIdExists> {
+        self.body.pet_id = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Same as [`pet_id`](#method.pet_id), but takes a reference.
+    #[inline]
+    pub fn pet_id_ref(self, value: &i64) -> AdoptionBuilder<crate::generics::PetIdExists> {
+        self.pet_id(*value)
+    }
+}
@@ -0,0 +1,30 @@
+
+/// Tag or category (internally tagged).
+#[non_exhaustive]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "kind")]
+pub enum Animal {
+    #[serde(rename = "tag", alias = "tags")]
+    Tag(crate::tag::Tag),
+    #[serde(rename = "category")]
+    Category(crate::category::Category),
+}
+
+impl Default for Animal {
+    fn default() -> Self {
+        Animal::Tag(Default::default())
+    }
+}
+
+impl From<crate::tag::Tag> for Animal {
+    fn from(value: crate::tag::Tag) -> Self {
+        Animal::Tag(value)
+    }
+}
+
+impl From<crate::category::Category> for Animal {
+    fn from(value: crate::category::Category) -> Self {
+        Animal::Category(value)
+    }
+}
+
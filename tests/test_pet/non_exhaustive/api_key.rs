@@ -0,0 +1,146 @@
+
+/// Key for accessing the API over HTTP.
+#[non_exhaustive]
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct ApiKey {
+    #[serde(rename = "HTTPHeaders", skip_serializing_if = "Option::is_none")]
+    pub http_headers: Option<crate::api_key::ApiKeyHttpHeaders>,
+    #[serde(rename = "keyID")]
+    pub key_id: String,
+    pub secret: String,
+    #[serde(deserialize_with = "Option::deserialize")]
+    pub signature: Option<String>,
+}
+#[non_exhaustive]
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct ApiKeyHttpHeaders {
+    #[serde(rename = "userAgent", skip_serializing_if = "Option::is_none")]
+    pub user_agent: Option<String>,
+}
+
+impl ApiKey {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> ApiKeyBuilder<crate::generics::MissingKeyId, crate::generics::MissingSecret> {
+        ApiKeyBuilder {
+            body: Default::default(),
+            _key_id: core::marker::PhantomData,
+            _secret: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> ApiKeyBuilder<crate::generics::KeyIdExists, crate::generics::SecretExists>
+    where
+        Self: Clone,
+    {
+        ApiKeyBuilder {
+            body: self.clone(),
+            _key_id: core::marker::PhantomData,
+            _secret: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<ApiKey> for ApiKeyBuilder<crate::generics::KeyIdExists, crate::generics::SecretExists> {
+    fn into(self) -> ApiKey {
+        self.body
+    }
+}
+
+/// Builder for [`ApiKey`](./struct.ApiKey.html) object.
+#[derive(Debug, Clone)]
+pub struct ApiKeyBuilder<KeyId, Secret> {
+    body: self::ApiKey,
+    _key_id: core::marker::PhantomData<KeyId>,
+    _secret: core::marker::PhantomData<Secret>,
+}
+
+impl<KeyId, Secret> ApiKeyBuilder<KeyId, Secret> {
+    #[inline]
+    pub fn http_headers(mut self, value: crate::api_key::ApiKeyHttpHeaders) -> Self {
+        self.body.http_headers = Some(value.into());
+        self
+    }
+
+    /// Sets [`http_headers`](#method.http_headers) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_http_headers(mut self, value: Option<crate::api_key::ApiKeyHttpHeaders>) -> Self {
+        self.body.http_headers = value.map(|value| value.into());
+        self
+    }
+
+    #[inline]
+    pub fn key_id(mut self, value: impl Into<String>) -> ApiKeyBuilder<crate::generics::KeyIdExists, Secret> {
+        self.body.key_id = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    #[inline]
+    pub fn secret(mut self, value: impl Into<String>) -> ApiKeyBuilder<KeyId, crate::generics::SecretExists> {
+        self.body.secret = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    #[inline]
+    pub fn signature(mut self, value: impl Into<String>) -> Self {
+        self.body.signature = Some(value.into());
+        self
+    }
+
+    /// Sets [`signature`](#method.signature) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_signature(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.signature = value.map(|value| value.into());
+        self
+    }
+}
+
+impl ApiKeyHttpHeaders {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> ApiKeyHttpHeadersBuilder {
+        ApiKeyHttpHeadersBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> ApiKeyHttpHeadersBuilder
+    where
+        Self: Clone,
+    {
+        ApiKeyHttpHeadersBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<ApiKeyHttpHeaders> for ApiKeyHttpHeadersBuilder {
+    fn into(self) -> ApiKeyHttpHeaders {
+        self.body
+    }
+}
+
+/// Builder for [`ApiKeyHttpHeaders`](./struct.ApiKeyHttpHeaders.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct ApiKeyHttpHeadersBuilder {
+    body: self::ApiKeyHttpHeaders,
+}
+
+impl ApiKeyHttpHeadersBuilder {
+    #[inline]
+    pub fn user_agent(mut self, value: impl Into<String>) -> Self {
+        self.body.user_agent = Some(value.into());
+        self
+    }
+
+    /// Sets [`user_agent`](#method.user_agent) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_user_agent(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.user_agent = value.map(|value| value.into());
+        self
+    }
+}
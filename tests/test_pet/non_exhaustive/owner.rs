@@ -0,0 +1,89 @@
+
+/// Account, account ID or guest owning a pet (untagged).
+#[non_exhaustive]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum Owner {
+    Account(crate::account::Account),
+    Variant2(i64),
+    Guest(crate::owner::OwnerGuest),
+}
+
+impl Default for Owner {
+    fn default() -> Self {
+        Owner::Account(Default::default())
+    }
+}
+
+impl From<crate::account::Account> for Owner {
+    fn from(value: crate::account::Account) -> Self {
+        Owner::Account(value)
+    }
+}
+
+impl From<i64> for Owner {
+    fn from(value: i64) -> Self {
+        Owner::Variant2(value)
+    }
+}
+
+impl From<crate::owner::OwnerGuest> for Owner {
+    fn from(value: crate::owner::OwnerGuest) -> Self {
+        Owner::Guest(value)
+    }
+}
+#[non_exhaustive]
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct OwnerGuest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nickname: Option<String>,
+}
+
+
+impl OwnerGuest {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> OwnerGuestBuilder {
+        OwnerGuestBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> OwnerGuestBuilder
+    where
+        Self: Clone,
+    {
+        OwnerGuestBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<OwnerGuest> for OwnerGuestBuilder {
+    fn into(self) -> OwnerGuest {
+        self.body
+    }
+}
+
+/// Builder for [`OwnerGuest`](./struct.OwnerGuest.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct OwnerGuestBuilder {
+    body: self::OwnerGuest,
+}
+
+impl OwnerGuestBuilder {
+    #[inline]
+    pub fn nickname(mut self, value: impl Into<String>) -> Self {
+        self.body.nickname = Some(value.into());
+        self
+    }
+
+    /// Sets [`nickname`](#method.nickname) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_nickname(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.nickname = value.map(|value| value.into());
+        self
+    }
+}
@@ -0,0 +1,191 @@
+
+/// Payment for an order (by card or bank transfer).
+#[non_exhaustive]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "method")]
+pub enum Payment {
+    #[serde(rename = "Card")]
+    Card(crate::payment::PaymentCard),
+    #[serde(rename = "BankTransfer")]
+    BankTransfer(crate::payment::PaymentBankTransfer),
+}
+
+impl Default for Payment {
+    fn default() -> Self {
+        Payment::Card(Default::default())
+    }
+}
+
+impl From<crate::payment::PaymentCard> for Payment {
+    fn from(value: crate::payment::PaymentCard) -> Self {
+        Payment::Card(value)
+    }
+}
+
+impl From<crate::payment::PaymentBankTransfer> for Payment {
+    fn from(value: crate::payment::PaymentBankTransfer) -> Self {
+        Payment::BankTransfer(value)
+    }
+}
+#[non_exhaustive]
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PaymentCard {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub card_number: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expiry_month: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub holder_name: Option<String>,
+}
+#[non_exhaustive]
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PaymentBankTransfer {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_number: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub routing_code: Option<String>,
+}
+
+
+impl PaymentCard {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> PaymentCardBuilder {
+        PaymentCardBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> PaymentCardBuilder
+    where
+        Self: Clone,
+    {
+        PaymentCardBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<PaymentCard> for PaymentCardBuilder {
+    fn into(self) -> PaymentCard {
+        self.body
+    }
+}
+
+/// Builder for [`PaymentCard`](./struct.PaymentCard.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct PaymentCardBuilder {
+    body: self::PaymentCard,
+}
+
+impl PaymentCardBuilder {
+    #[inline]
+    pub fn card_number(mut self, value: impl Into<String>) -> Self {
+        self.body.card_number = Some(value.into());
+        self
+    }
+
+    /// Sets [`card_number`](#method.card_number) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_card_number(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.card_number = value.map(|value| value.into());
+        self
+    }
+
+    #[inline]
+    pub fn expiry_month(mut self, value: impl Into<i64>) -> Self {
+        self.body.expiry_month = Some(value.into());
+        self
+    }
+
+    /// Sets [`expiry_month`](#method.expiry_month) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_expiry_month(mut self, value: Option<impl Into<i64>>) -> Self {
+        self.body.expiry_month = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`expiry_month`](#method.expiry_month), but takes a reference.
+    #[inline]
+    pub fn expiry_month_ref(self, value: &i64) -> Self {
+        self.expiry_month(*value)
+    }
+
+    #[inline]
+    pub fn holder_name(mut self, value: impl Into<String>) -> Self {
+        self.body.holder_name = Some(value.into());
+        self
+    }
+
+    /// Sets [`holder_name`](#method.holder_name) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_holder_name(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.holder_name = value.map(|value| value.into());
+        self
+    }
+}
+
+impl PaymentBankTransfer {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> PaymentBankTransferBuilder {
+        PaymentBankTransferBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> PaymentBankTransferBuilder
+    where
+        Self: Clone,
+    {
+        PaymentBankTransferBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<PaymentBankTransfer> for PaymentBankTransferBuilder {
+    fn into(self) -> PaymentBankTransfer {
+        self.body
+    }
+}
+
+/// Builder for [`PaymentBankTransfer`](./struct.PaymentBankTransfer.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct PaymentBankTransferBuilder {
+    body: self::PaymentBankTransfer,
+}
+
+impl PaymentBankTransferBuilder {
+    #[inline]
+    pub fn account_number(mut self, value: impl Into<String>) -> Self {
+        self.body.account_number = Some(value.into());
+        self
+    }
+
+    /// Sets [`account_number`](#method.account_number) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_account_number(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.account_number = value.map(|value| value.into());
+        self
+    }
+
+    #[inline]
+    pub fn routing_code(mut self, value: impl Into<String>) -> Self {
+        self.body.routing_code = Some(value.into());
+        self
+    }
+
+    /// Sets [`routing_code`](#method.routing_code) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_routing_code(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.routing_code = value.map(|value| value.into());
+        self
+    }
+}
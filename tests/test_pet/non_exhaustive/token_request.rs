@@ -0,0 +1,325 @@
+
+/// OAuth token request.
+#[non_exhaustive]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TokenRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_id: Option<String>,
+    pub grant_type: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offline: Option<bool>,
+    /// Space-separated scopes (use "offline" for refresh tokens).
+    #[deprecated(note = "Space-separated scopes (use \"offline\" for refresh tokens).")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scope: Option<String>,
+}
+
+impl Default for TokenRequest {
+    fn default() -> Self {
+        TokenRequest {
+            client_id: Default::default(),
+            grant_type: "client_credentials".into(),
+            offline: Some(false),
+            scope: Default::default(),
+        }
+    }
+}
+
+impl TokenRequest {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> TokenRequestBuilder<crate::generics::MissingGrantType> {
+        TokenRequestBuilder {
+            body: Default::default(),
+            _grant_type: core::marker::PhantomData,
+        }
+    }
+
+    /// Revoke an access token
+    #[inline]
+    pub fn post() -> TokenRequestPostBuilder<crate::generics::MissingGrantType> {
+        TokenRequestPostBuilder {
+            body: Default::default(),
+            _op: core::marker::PhantomData,
+            _grant_type: core::marker::PhantomData,
+        }
+    }
+
+    /// Issue an access token
+    #[inline]
+    pub fn post_1() -> TokenRequestPostBuilder1<crate::generics::MissingGrantType> {
+        TokenRequestPostBuilder1 {
+            body: Default::default(),
+            _op: core::marker::PhantomData,
+            _grant_type: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> TokenRequestBuilder<crate::generics::GrantTypeExists>
+    where
+        Self: Clone,
+    {
+        TokenRequestBuilder {
+            body: self.clone(),
+            _grant_type: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<TokenRequest> for TokenRequestBuilder<crate::generics::GrantTypeExists> {
+    fn into(self) -> TokenRequest {
+        self.body
+    }
+}
+
+impl Into<TokenRequest> for TokenRequestPostBuilder<crate::generics::GrantTypeExists> {
+    fn into(self) -> TokenRequest {
+        self.body
+    }
+}
+
+impl Into<TokenRequest> for TokenRequestPostBuilder1<crate::generics::GrantTypeExists> {
+    fn into(self) -> TokenRequest {
+        self.body
+    }
+}
+
+/// Builder for [`TokenRequest`](./struct.TokenRequest.html) object.
+#[derive(Debug, Clone)]
+pub struct TokenRequestBuilder<GrantType> {
+    body: self::TokenRequest,
+    _grant_type: core::marker::PhantomData<GrantType>,
+}
+
+impl<GrantType> TokenRequestBuilder<GrantType> {
+    #[inline]
+    pub fn client_id(mut self, value: impl Into<String>) -> Self {
+        self.body.client_id = Some(value.into());
+        self
+    }
+
+    /// Sets [`client_id`](#method.client_id) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_client_id(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.client_id = value.map(|value| value.into());
+        self
+    }
+
+    #[inline]
+    pub fn grant_type(mut self, value: impl Into<String>) -> TokenRequestBuilder<crate::generics::GrantTypeExists> {
+        self.body.grant_type = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    #[inline]
+    pub fn offline(mut self, value: impl Into<bool>) -> Self {
+        self.body.offline = Some(value.into());
+        self
+    }
+
+    /// Sets [`offline`](#method.offline) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_offline(mut self, value: Option<impl Into<bool>>) -> Self {
+        self.body.offline = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`offline`](#method.offline), but takes a reference.
+    #[inline]
+    pub fn offline_ref(self, value: &bool) -> Self {
+        self.offline(*value)
+    }
+
+    /// Space-separated scopes (use "offline" for refresh tokens).
+    #[deprecated(note = "Space-separated scopes (use \"offline\" for refresh tokens).")]
+    #[inline]
+    pub fn scope(mut self, value: impl Into<String>) -> Self {
+        self.body.scope = Some(value.into());
+        self
+    }
+
+    /// Sets [`scope`](#method.scope) if the given value is `Some` (or unsets it otherwise).
+    #[deprecated(note = "Space-separated scopes (use \"offline\" for refresh tokens).")]
+    #[inline]
+    pub fn maybe_scope(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.scope = value.map(|value| value.into());
+        self
+    }
+}
+
+/// Builder shared by operations (with the same fields and parameters) associated with `TokenRequest`.
+#[derive(Debug, Clone)]
+pub struct TokenRequestOpBuilder<Op, GrantType> {
+    body: self::TokenRequest,
+    _op: core::marker::PhantomData<Op>,
+    _grant_type: core::marker::PhantomData<GrantType>,
+}
+
+/// Builder created by [`TokenRequest::post`](./struct.TokenRequest.html#method.post) method for a `POST` operation associated with `TokenRequest`.
+pub type TokenRequestPostBuilder<GrantType> = TokenRequestOpBuilder<TokenRequestPostOp, GrantType>;
+
+/// Marks the operation of [`TokenRequestPostBuilder`] in the shared builder.
+#[derive(Debug, Clone)]
+pub enum TokenRequestPostOp {}
+
+impl<Op, GrantType> TokenRequestOpBuilder<Op, GrantType> {
+    #[inline]
+    pub fn client_id(mut self, value: impl Into<String>) -> Self {
+        self.body.client_id = Some(value.into());
+        self
+    }
+
+    /// Sets [`client_id`](#method.client_id) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_client_id(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.client_id = value.map(|value| value.into());
+        self
+    }
+
+    #[inline]
+    pub fn grant_type(mut self, value: impl Into<String>) -> TokenRequestOpBuilder<Op, crate::generics::GrantTypeExists> {
+        self.body.grant_type = value.into();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    #[inline]
+    pub fn offline(mut self, value: impl Into<bool>) -> Self {
+        self.body.offline = Some(value.into());
+        self
+    }
+
+    /// Sets [`offline`](#method.offline) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_offline(mut self, value: Option<impl Into<bool>>) -> Self {
+        self.body.offline = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`offline`](#method.offline), but takes a reference.
+    #[inline]
+    pub fn offline_ref(self, value: &bool) -> Self {
+        self.offline(*value)
+    }
+
+    /// Space-separated scopes (use "offline" for refresh tokens).
+    #[deprecated(note = "Space-separated scopes (use \"offline\" for refresh tokens).")]
+    #[inline]
+    pub fn scope(mut self, value: impl Into<String>) -> Self {
+        self.body.scope = Some(value.into());
+        self
+    }
+
+    /// Sets [`scope`](#method.scope) if the given value is `Some` (or unsets it otherwise).
+    #[deprecated(note = "Space-separated scopes (use \"offline\" for refresh tokens).")]
+    #[inline]
+    pub fn maybe_scope(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.scope = value.map(|value| value.into());
+        self
+    }
+}
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for TokenRequestPostBuilder<crate::generics::GrantTypeExists> {
+    type Output = crate::status::Status;
+
+    const METHOD: http::Method = http::Method::POST;
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["api_key"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok("/oauth/revoke".into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::CONTENT_TYPE.as_str(), "application/json")
+        .json(&self.body)
+        .header(http::header::ACCEPT.as_str(), "application/yaml"))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Content-Type")
+            || name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<TokenRequestPostBuilder<crate::generics::GrantTypeExists>> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: TokenRequestPostBuilder<crate::generics::GrantTypeExists>) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+/// Builder created by [`TokenRequest::post_1`](./struct.TokenRequest.html#method.post_1) method for a `POST` operation associated with `TokenRequest`.
+pub type TokenRequestPostBuilder1<GrantType> = TokenRequestOpBuilder<TokenRequestPostOp1, GrantType>;
+
+/// Marks the operation of [`TokenRequestPostBuilder1`] in the shared builder.
+#[derive(Debug, Clone)]
+pub enum TokenRequestPostOp1 {}
+
+
+#[async_trait::async_trait]
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for TokenRequestPostBuilder1<crate::generics::GrantTypeExists> {
+    type Output = TokenRequestPostResponse1;
+
+    const METHOD: http::Method = http::Method::POST;
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["api_key"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok("/oauth/token".into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::CONTENT_TYPE.as_str(), "application/x-www-form-urlencoded")
+        .form(&self.body)
+        .header(http::header::ACCEPT.as_str(), "application/yaml"))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Content-Type")
+            || name.eq_ignore_ascii_case("Accept")
+    }
+
+    async fn send(&self, client: &Client) -> Result<crate::client::ResponseWrapper<Self::Output, Self>, crate::client::ApiError<Client::Response>> {
+        use crate::client::Response;
+        let resp = self.send_raw(client).await?;
+        Ok(match resp.status().as_u16() {
+            202 => crate::client::ResponseWrapper::<crate::status::Status, Self>::decode(resp).await?.map(TokenRequestPostResponse1::Accepted),
+            204 => crate::client::ResponseWrapper::wrap(resp, |_| async { Ok(TokenRequestPostResponse1::NoContent) }).await?,
+            _ => crate::client::ResponseWrapper::<crate::token::Token, Self>::decode(resp).await?.map(TokenRequestPostResponse1::Ok),
+        })
+    }
+}
+
+impl std::convert::TryFrom<TokenRequestPostBuilder1<crate::generics::GrantTypeExists>> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: TokenRequestPostBuilder1<crate::generics::GrantTypeExists>) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+/// Responses of [`TokenRequestPostBuilder1`] (based on the status code).
+#[derive(Debug, Clone)]
+pub enum TokenRequestPostResponse1 {
+    /// `200` response.
+    Ok(crate::token::Token),
+    /// `202` response.
+    Accepted(crate::status::Status),
+    /// `204` response.
+    NoContent,
+}
+
+impl<'de> serde::de::Deserialize<'de> for TokenRequestPostResponse1 {
+    fn deserialize<D: serde::de::Deserializer<'de>>(_: D) -> Result<Self, D::Error> {
+        Err(serde::de::Error::custom("TokenRequestPostResponse1 is decoded based on the response status"))
+    }
+}
@@ -0,0 +1,85 @@
+
+/// # Example
+///
+/// ```json
+/// {
+///   "id": 7,
+///   "name": "\"good\" boy"
+/// }
+/// ```
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct Tag {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<i64>,
+    /// Name shown as \<html> with \*stars\*, \[links\] and `code_spans` (see <https://example.com/tags>), but not \[twice\] or \_ a_b.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+impl Tag {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> TagBuilder {
+        TagBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> TagBuilder
+    where
+        Self: Clone,
+    {
+        TagBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<Tag> for TagBuilder {
+    fn into(self) -> Tag {
+        self.body
+    }
+}
+
+/// Builder for [`Tag`](./struct.Tag.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct TagBuilder {
+    body: self::Tag,
+}
+
+impl TagBuilder {
+    #[inline]
+    pub fn id(mut self, value: impl Into<i64>) -> Self {
+        self.body.id = Some(value.into());
+        self
+    }
+
+    /// Sets [`id`](#method.id) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_id(mut self, value: Option<impl Into<i64>>) -> Self {
+        self.body.id = value.map(|value| value.into());
+        self
+    }
+
+    /// Same as [`id`](#method.id), but takes a reference.
+    #[inline]
+    pub fn id_ref(self, value: &i64) -> Self {
+        self.id(*value)
+    }
+
+    /// Name shown as \<html> with \*stars\*, \[links\] and `code_spans` (see <https://example.com/tags>), but not \[twice\] or \_ a_b.
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> Self {
+        self.body.name = Some(value.into());
+        self
+    }
+
+    /// Sets [`name`](#method.name) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_name(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.name = value.map(|value| value.into());
+        self
+    }
+}
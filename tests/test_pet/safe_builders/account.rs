@@ -0,0 +1,114 @@
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct Account {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bio: Option<String>,
+    #[serde(deserialize_with = "Option::deserialize")]
+    pub nickname: Option<String>,
+    #[serde(skip_deserializing)]
+    pub password: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token: Option<String>,
+    pub username: String,
+}
+
+impl Account {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> AccountBuilder<crate::generics::MissingPassword, crate::generics::MissingUsername> {
+        AccountBuilder {
+            body: Default::default(),
+            _password: core::marker::PhantomData,
+            _username: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a builder from this object.
+    ///
+    /// **NOTE:** Write-only fields (`password`) should be set again.
+    #[inline]
+    pub fn to_builder(&self) -> AccountBuilder<crate::generics::MissingPassword, crate::generics::UsernameExists>
+    where
+        Self: Clone,
+    {
+        AccountBuilder {
+            body: self.clone(),
+            _password: core::marker::PhantomData,
+            _username: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<Account> for AccountBuilder<crate::generics::PasswordExists, crate::generics::UsernameExists> {
+    fn into(self) -> Account {
+        self.body
+    }
+}
+
+/// Builder for [`Account`](./struct.Account.html) object.
+#[derive(Debug, Clone)]
+pub struct AccountBuilder<Password, Username> {
+    body: self::Account,
+    _password: core::marker::PhantomData<Password>,
+    _username: core::marker::PhantomData<Username>,
+}
+
+impl<Password, Username> AccountBuilder<Password, Username> {
+    #[inline]
+    pub fn bio(mut self, value: impl Into<String>) -> Self {
+        self.body.bio = Some(value.into());
+        self
+    }
+
+    /// Sets [`bio`](#method.bio) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_bio(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.bio = value.map(|value| value.into());
+        self
+    }
+
+    #[inline]
+    pub fn nickname(mut self, value: impl Into<String>) -> Self {
+        self.body.nickname = Some(value.into());
+        self
+    }
+
+    /// Sets [`nickname`](#method.nickname) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_nickname(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.nickname = value.map(|value| value.into());
+        self
+    }
+
+    #[inline]
+    pub fn password(mut self, value: impl Into<String>) -> AccountBuilder<crate::generics::PasswordExists, Username> {
+        self.body.password = value.into();
+        AccountBuilder {
+            body: self.body,
+            _password: core::marker::PhantomData,
+            _username: core::marker::PhantomData,
+        }
+    }
+
+    #[inline]
+    pub fn token(mut self, value: impl Into<String>) -> Self {
+        self.body.token = Some(value.into());
+        self
+    }
+
+    /// Sets [`token`](#method.token) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_token(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.token = value.map(|value| value.into());
+        self
+    }
+
+    #[inline]
+    pub fn username(mut self, value: impl Into<String>) -> AccountBuilder<Password, crate::generics::UsernameExists> {
+        self.body.username = value.into();
+        AccountBuilder {
+            body: self.body,
+            _password: core::marker::PhantomData,
+            _username: core::marker::PhantomData,
+        }
+    }
+}
@@ -0,0 +1,429 @@
+
+/// Namespace for operations that cannot be added to any other modules.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct Miscellaneous {}
+
+impl Miscellaneous {
+    /// Download the pedigree certificate of a pet.
+    #[inline]
+    pub fn get_pet_certificate() -> MiscellaneousGetBuilder<crate::generics::MissingPetId> {
+        MiscellaneousGetBuilder {
+            inner: Default::default(),
+            _param_pet_id: core::marker::PhantomData,
+        }
+    }
+
+    /// Download the archive of all pets.
+    #[inline]
+    pub fn get_1() -> MiscellaneousGetBuilder1 {
+        MiscellaneousGetBuilder1 {
+            _op: core::marker::PhantomData,
+            param_range: None,
+        }
+    }
+
+    #[inline]
+    pub fn get_2() -> MiscellaneousGetBuilder2 {
+        MiscellaneousGetBuilder2
+    }
+
+    #[inline]
+    pub fn get_3() -> MiscellaneousGetBuilder3 {
+        MiscellaneousGetBuilder3 {
+            _op: core::marker::PhantomData,
+            param_range: None,
+        }
+    }
+
+    #[inline]
+    pub fn post_4() -> MiscellaneousPostBuilder4<crate::generics::MissingValues> {
+        MiscellaneousPostBuilder4 {
+            inner: Default::default(),
+            _param_values: core::marker::PhantomData,
+        }
+    }
+}
+
+/// Builder created by [`Miscellaneous::get_pet_certificate`](./struct.Miscellaneous.html#method.get_pet_certificate) method for a `GET` operation associated with `Miscellaneous`.
+#[repr(transparent)]
+#[derive(Debug, Clone)]
+pub struct MiscellaneousGetBuilder<PetId> {
+    inner: MiscellaneousGetBuilderContainer,
+    _param_pet_id: core::marker::PhantomData<PetId>,
+}
+
+#[derive(Debug, Default, Clone)]
+struct MiscellaneousGetBuilderContainer {
+    param_pet_id: Option<i64>,
+    param_range: Option<crate::util::ByteRange>,
+}
+
+impl<PetId> MiscellaneousGetBuilder<PetId> {
+    /// ID of the pet.
+    #[inline]
+    pub fn pet_id(mut self, value: impl Into<i64>) -> MiscellaneousGetBuilder<crate::generics::PetIdExists> {
+        self.inner.param_pet_id = Some(value.into());
+        MiscellaneousGetBuilder {
+            inner: self.inner,
+            _param_pet_id: core::marker::PhantomData,
+        }
+    }
+
+    /// Same as [`pet_id`](#method.pet_id), but takes a reference.
+    #[inline]
+    pub fn pet_id_ref(self, value: &i64) -> MiscellaneousGetBuilder<crate::generics::PetIdExists> {
+        self.pet_id(*value)
+    }
+
+    /// Range of bytes (inclusive) to be downloaded.
+    #[inline]
+    pub fn range(mut self, start: u64, end: u64) -> Self {
+        self.inner.param_range = Some(crate::util::ByteRange(start, Some(end)));
+        self
+    }
+
+    /// Same as [`range`](#method.range), but the range extends to the end of the body.
+    #[inline]
+    pub fn range_from(mut self, start: u64) -> Self {
+        self.inner.param_range = Some(crate::util::ByteRange(start, None));
+        self
+    }
+}
+
+#[async_trait::async_trait]
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for MiscellaneousGetBuilder<crate::generics::PetIdExists> {
+    type Output = crate::util::ResponseStream<<<Client as crate::client::ApiClient>::Response as crate::client::Response>::Bytes, <<Client as crate::client::ApiClient>::Response as crate::client::Response>::Error>;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    const OPERATION_ID: Option<&'static str> = Some("getPetCertificate");
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["api_key"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok(format!("/pets/{petId}/certificate", petId=crate::client::path_param("petId", self.inner.param_pet_id.as_ref().expect("missing parameter pet_id?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        let mut req = req;
+        if let Some(v) = &self.inner.param_range.as_ref().map(std::string::ToString::to_string) {
+            req = req.header("Range", &v);
+        }
+
+        Ok(req
+        .header(http::header::ACCEPT.as_str(), "application/octet-stream"))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        (name.eq_ignore_ascii_case("Range") && self.inner.param_range.is_some())
+            || name.eq_ignore_ascii_case("Accept")
+    }
+
+    async fn send(&self, client: &Client) -> Result<crate::client::ResponseWrapper<Self::Output, Self>, crate::client::ApiError<Client::Response>> {
+        use crate::client::Response;
+        let resp = self.send_raw(client).await?;
+        Ok(crate::client::ResponseWrapper::wrap(resp, |r| async {
+            Ok(crate::util::ResponseStream(r.stream()))
+        }).await.unwrap())
+    }
+}
+
+impl std::convert::TryFrom<MiscellaneousGetBuilder<crate::generics::PetIdExists>> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: MiscellaneousGetBuilder<crate::generics::PetIdExists>) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+/// Builder shared by operations (with the same fields and parameters) associated with `Miscellaneous`.
+#[derive(Debug, Clone)]
+pub struct MiscellaneousOpBuilder<Op> {
+    _op: core::marker::PhantomData<Op>,
+    param_range: Option<crate::util::ByteRange>,
+}
+
+impl<Op> Default for MiscellaneousOpBuilder<Op> {
+    fn default() -> Self {
+        MiscellaneousOpBuilder {
+            _op: core::marker::PhantomData,
+            param_range: None,
+        }
+    }
+}
+
+/// Builder created by [`Miscellaneous::get_1`](./struct.Miscellaneous.html#method.get_1) method for a `GET` operation associated with `Miscellaneous`.
+pub type MiscellaneousGetBuilder1 = MiscellaneousOpBuilder<MiscellaneousGetOp1>;
+
+/// Marks the operation of [`MiscellaneousGetBuilder1`] in the shared builder.
+#[derive(Debug, Clone)]
+pub enum MiscellaneousGetOp1 {}
+
+impl<Op> MiscellaneousOpBuilder<Op> {
+    /// Range of bytes (inclusive) to be downloaded.
+    #[inline]
+    pub fn range(mut self, start: u64, end: u64) -> Self {
+        self.param_range = Some(crate::util::ByteRange(start, Some(end)));
+        self
+    }
+
+    /// Same as [`range`](#method.range), but the range extends to the end of the body.
+    #[inline]
+    pub fn range_from(mut self, start: u64) -> Self {
+        self.param_range = Some(crate::util::ByteRange(start, None));
+        self
+    }
+}
+
+#[async_trait::async_trait]
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for MiscellaneousGetBuilder1 {
+    type Output = crate::util::ResponseStream<<<Client as crate::client::ApiClient>::Response as crate::client::Response>::Bytes, <<Client as crate::client::ApiClient>::Response as crate::client::Response>::Error>;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["api_key"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok("/test/archive".into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        let mut req = req;
+        if let Some(v) = &self.param_range.as_ref().map(std::string::ToString::to_string) {
+            req = req.header("Range", &v);
+        }
+
+        Ok(req)
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Range") && self.param_range.is_some()
+    }
+
+    async fn send(&self, client: &Client) -> Result<crate::client::ResponseWrapper<Self::Output, Self>, crate::client::ApiError<Client::Response>> {
+        use crate::client::Response;
+        let resp = self.send_raw(client).await?;
+        Ok(crate::client::ResponseWrapper::wrap(resp, |r| async {
+            Ok(crate::util::ResponseStream(r.stream()))
+        }).await.unwrap())
+    }
+}
+
+impl std::convert::TryFrom<MiscellaneousGetBuilder1> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: MiscellaneousGetBuilder1) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+/// Builder created by [`Miscellaneous::get_2`](./struct.Miscellaneous.html#method.get_2) method for a `GET` operation associated with `Miscellaneous`.
+#[derive(Debug, Default, Clone)]
+pub struct MiscellaneousGetBuilder2;
+
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for MiscellaneousGetBuilder2 {
+    type Output = Vec<Vec<crate::test_nested_array_with_object::TestNestedArrayWithObjectItemItem>>;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["api_key"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok("/test/array".into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::ACCEPT.as_str(), "application/yaml"))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<MiscellaneousGetBuilder2> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: MiscellaneousGetBuilder2) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+/// Builder created by [`Miscellaneous::get_3`](./struct.Miscellaneous.html#method.get_3) method for a `GET` operation associated with `Miscellaneous`.
+pub type MiscellaneousGetBuilder3 = MiscellaneousOpBuilder<MiscellaneousGetOp3>;
+
+/// Marks the operation of [`MiscellaneousGetBuilder3`] in the shared builder.
+#[derive(Debug, Clone)]
+pub enum MiscellaneousGetOp3 {}
+
+
+#[async_trait::async_trait]
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for MiscellaneousGetBuilder3 {
+    type Output = crate::util::ResponseStream<<<Client as crate::client::ApiClient>::Response as crate::client::Response>::Bytes, <<Client as crate::client::ApiClient>::Response as crate::client::Response>::Error>;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["api_key"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok("/test/file".into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        let mut req = req;
+        if let Some(v) = &self.param_range.as_ref().map(std::string::ToString::to_string) {
+            req = req.header("Range", &v);
+        }
+
+        Ok(req)
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case("Range") && self.param_range.is_some()
+    }
+
+    async fn send(&self, client: &Client) -> Result<crate::client::ResponseWrapper<Self::Output, Self>, crate::client::ApiError<Client::Response>> {
+        use crate::client::Response;
+        let resp = self.send_raw(client).await?;
+        Ok(crate::client::ResponseWrapper::wrap(resp, |r| async {
+            Ok(crate::util::ResponseStream(r.stream()))
+        }).await.unwrap())
+    }
+}
+
+impl std::convert::TryFrom<MiscellaneousGetBuilder3> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: MiscellaneousGetBuilder3) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
+
+/// Builder created by [`Miscellaneous::post_4`](./struct.Miscellaneous.html#method.post_4) method for a `POST` operation associated with `Miscellaneous`.
+#[repr(transparent)]
+#[derive(Debug, Clone)]
+pub struct MiscellaneousPostBuilder4<Values> {
+    inner: MiscellaneousPostBuilder4Container,
+    _param_values: core::marker::PhantomData<Values>,
+}
+
+#[derive(Debug, Default, Clone)]
+struct MiscellaneousPostBuilder4Container {
+    param_values: Option<crate::util::Delimited<crate::util::Delimited<crate::util::Delimited<crate::util::Delimited<String, crate::util::Pipes>, crate::util::Csv>, crate::util::Ssv>, crate::util::Tsv>>,
+    param_x_foobar: Option<crate::util::Delimited<crate::util::Delimited<crate::util::Delimited<crate::util::Delimited<f64, crate::util::Ssv>, crate::util::Tsv>, crate::util::Csv>, crate::util::Pipes>>,
+    param_booya: Option<crate::util::Delimited<crate::util::Delimited<i64, crate::util::Csv>, crate::util::Multi>>,
+    param_foo: Option<crate::util::Delimited<crate::util::Delimited<String, crate::util::Csv>, crate::util::Multi>>,
+}
+
+impl<Values> MiscellaneousPostBuilder4<Values> {
+    #[inline]
+    pub fn values(mut self, value: impl Iterator<Item = impl Iterator<Item = impl Iterator<Item = impl Iterator<Item = impl Into<String>>>>>) -> MiscellaneousPostBuilder4<crate::generics::ValuesExists> {
+        self.inner.param_values = Some(value.map(|value| value.map(|value| value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into()).collect::<Vec<_>>().into()).collect::<Vec<_>>().into()).collect::<Vec<_>>().into());
+        MiscellaneousPostBuilder4 {
+            inner: self.inner,
+            _param_values: core::marker::PhantomData,
+        }
+    }
+
+    #[inline]
+    pub fn x_foobar(mut self, value: impl Iterator<Item = impl Iterator<Item = impl Iterator<Item = impl Iterator<Item = impl Into<f64>>>>>) -> Self {
+        self.inner.param_x_foobar = Some(value.map(|value| value.map(|value| value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into()).collect::<Vec<_>>().into()).collect::<Vec<_>>().into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`x_foobar`](#method.x_foobar) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_x_foobar(mut self, value: Option<impl Iterator<Item = impl Iterator<Item = impl Iterator<Item = impl Iterator<Item = impl Into<f64>>>>>>) -> Self {
+        self.inner.param_x_foobar = value.map(|value| value.map(|value| value.map(|value| value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into()).collect::<Vec<_>>().into()).collect::<Vec<_>>().into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    #[inline]
+    pub fn booya(mut self, value: impl Iterator<Item = impl Iterator<Item = impl Into<i64>>>) -> Self {
+        self.inner.param_booya = Some(value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`booya`](#method.booya) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_booya(mut self, value: Option<impl Iterator<Item = impl Iterator<Item = impl Into<i64>>>>) -> Self {
+        self.inner.param_booya = value.map(|value| value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    #[inline]
+    pub fn foo(mut self, value: impl Iterator<Item = impl Iterator<Item = impl Into<String>>>) -> Self {
+        self.inner.param_foo = Some(value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`foo`](#method.foo) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_foo(mut self, value: Option<impl Iterator<Item = impl Iterator<Item = impl Into<String>>>>) -> Self {
+        self.inner.param_foo = value.map(|value| value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into()).collect::<Vec<_>>().into());
+        self
+    }
+}
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for MiscellaneousPostBuilder4<crate::generics::ValuesExists> {
+    type Output = String;
+
+    const METHOD: http::Method = http::Method::POST;
+
+    const SECURITY: &'static [&'static [&'static str]] = &[&["api_key"]];
+
+    fn rel_path(&self) -> Result<std::borrow::Cow<'static, str>, crate::client::ApiError<Client::Response>> {
+        Ok(format!("/test/parameter/{values}", values=crate::client::path_param("values", self.inner.param_values.as_ref().expect("missing parameter values?"))?).into())
+    }
+
+    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        let mut req = req;
+        if let Some(v) = &self.inner.param_x_foobar.as_ref().map(std::string::ToString::to_string) {
+            req = req.header("X-foobar", &v);
+        }
+
+        Ok(req
+        .header(http::header::CONTENT_TYPE.as_str(), "application/x-www-form-urlencoded")
+        .header(http::header::ACCEPT.as_str(), "application/yaml")
+        .body_bytes({
+            let mut ser = url::form_urlencoded::Serializer::new(String::new());
+            if let Some(stuff) = self.inner.param_booya.as_ref() {
+                for v in stuff.iter() {
+                    ser.append_pair("booya", &v.to_string());
+                }
+            }
+            ser.finish().into_bytes()
+        })
+        .query({
+            &self.inner.param_foo.as_ref().map(|v| {
+                v.iter().map(|v| ("foo", v.to_string())).collect::<Vec<_>>()
+            }).unwrap_or_default()
+        }))
+    }
+
+    fn sets_header(&self, name: &str) -> bool {
+        (name.eq_ignore_ascii_case("X-foobar") && self.inner.param_x_foobar.is_some())
+            || name.eq_ignore_ascii_case("Content-Type")
+            || name.eq_ignore_ascii_case("Accept")
+    }
+}
+
+impl std::convert::TryFrom<MiscellaneousPostBuilder4<crate::generics::ValuesExists>> for reqwest::Request {
+    type Error = crate::client::ApiError<reqwest::Response>;
+
+    fn try_from(builder: MiscellaneousPostBuilder4<crate::generics::ValuesExists>) -> Result<Self, Self::Error> {
+        let req = crate::client::Sendable::<reqwest::Client>::request(&builder, &reqwest::Client::new())?;
+        req.build().map_err(crate::client::ApiError::Reqwest)
+    }
+}
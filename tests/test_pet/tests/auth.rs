@@ -0,0 +1,123 @@
+use futures::executor::block_on;
+use test_pet::client::{
+    ApiClient, Client, ClientConfig, Credential, MockClient, MockResponse, Sendable,
+};
+use test_pet::pet::Pet;
+
+type AnyPet = Pet<serde_json::Value>;
+
+fn pet_client(config: ClientConfig) -> Client<MockClient> {
+    let mock = MockClient::new(|req| match req.method {
+        http::Method::PUT => MockResponse::new(http::StatusCode::NO_CONTENT),
+        _ => MockResponse::new(http::StatusCode::OK).json(&serde_json::json!({
+            "id": 1,
+            "name": "Rex",
+            "createdAt": "2020-01-01T00:00:00Z",
+        })),
+    });
+
+    Client::new(mock, config)
+}
+
+fn header(client: &Client<MockClient>, index: usize, name: &str) -> Option<String> {
+    client.inner().requests()[index]
+        .headers
+        .get(name)
+        .map(|v| v.to_str().unwrap().to_owned())
+}
+
+#[test]
+fn test_bearer_auth() {
+    let config = ClientConfig::default().credential("bearer", Credential::Bearer("old".into()));
+    let client = pet_client(config);
+
+    block_on(AnyPet::get_pet_by_id().pet_id(1).send(&client)).unwrap();
+    assert_eq!(header(&client, 0, "Authorization").unwrap(), "Bearer old");
+
+    // Refreshed tokens are picked up by the following requests.
+    client
+        .config()
+        .set_credential("bearer", Credential::Bearer("new".into()));
+    block_on(AnyPet::get_pet_by_id().pet_id(1).send(&client)).unwrap();
+    assert_eq!(header(&client, 1, "Authorization").unwrap(), "Bearer new");
+
+    // Operations which don't need the token don't get it.
+    block_on(
+        AnyPet::add_pet()
+            .x_auth("secret")
+            .id(1)
+            .name("Rex")
+            .send(&client),
+    )
+    .unwrap();
+    assert_eq!(header(&client, 2, "Authorization"), None);
+}
+
+#[test]
+fn test_basic_auth() {
+    // `updatePet` takes either the bearer token or the API key along with basic auth.
+    let config = ClientConfig::default()
+        .credential("api_key", Credential::ApiKey("key".into()))
+        .credential(
+            "basic",
+            Credential::Basic {
+                username: "Aladdin".into(),
+                password: Some("open sesame".into()),
+            },
+        );
+    let client = pet_client(config);
+    let update = || AnyPet::update_pet().pet_id(1).id(1).name("Rex");
+
+    assert!(block_on(update().send(&client)).unwrap().is_none());
+    assert_eq!(
+        header(&client, 0, "Authorization").unwrap(),
+        "Basic QWxhZGRpbjpvcGVuIHNlc2FtZQ=="
+    );
+    assert_eq!(header(&client, 0, "X-API-Key").unwrap(), "key");
+
+    client.config().set_credential(
+        "basic",
+        Credential::Basic {
+            username: "user".into(),
+            password: None,
+        },
+    );
+    block_on(update().send(&client)).unwrap();
+    // base64("user:")
+    assert_eq!(
+        header(&client, 1, "Authorization").unwrap(),
+        "Basic dXNlcjo="
+    );
+}
+
+#[test]
+fn test_api_key_auth() {
+    let config = ClientConfig::default()
+        .credential("api_key", Credential::ApiKey("key".into()))
+        .credential("account_key", Credential::ApiKey("acct".into()));
+    let client = pet_client(config);
+
+    // `addPet` needs the (global) `api_key`, which goes in the `X-API-Key` header.
+    block_on(
+        AnyPet::add_pet()
+            .x_auth("secret")
+            .id(1)
+            .name("Rex")
+            .send(&client),
+    )
+    .unwrap();
+    let req = &client.inner().requests()[0];
+    assert_eq!(req.headers.get("X-API-Key").unwrap(), "key");
+    assert_eq!(req.query, None);
+
+    // `listPets` (which is crate-private) also needs the `account_key`,
+    // which goes in the `account` query parameter.
+    let req = client.authorize(
+        client.request_builder(http::Method::GET, "/pets"),
+        &[&["api_key", "account_key"]],
+    );
+    block_on(client.make_request(req)).unwrap();
+    let req = &client.inner().requests()[1];
+    assert_eq!(req.headers.get("X-API-Key").unwrap(), "key");
+    assert_eq!(req.query, Some(serde_json::json!([["account", "acct"]])));
+}
@@ -0,0 +1,46 @@
+use std::sync::Mutex;
+use test_pet::adoption::Adoption;
+use test_pet::callbacks::AddPetCallbacks;
+use test_pet::status::Status;
+
+/// Server which receives the callbacks of `addPet`.
+#[derive(Default)]
+struct Owner {
+    adoptions: Mutex<Vec<Adoption>>,
+    removed: Mutex<usize>,
+}
+
+impl AddPetCallbacks for Owner {
+    fn on_pet_adopted(&self, payload: Adoption) -> Status {
+        let status = format!("adopted by {}", payload.adopter.as_deref().unwrap_or("?"));
+        self.adoptions.lock().unwrap().push(payload);
+        Status {
+            status: Some(status),
+        }
+    }
+
+    fn on_pet_removed(&self) {
+        *self.removed.lock().unwrap() += 1;
+    }
+}
+
+#[test]
+fn test_callback_handlers() {
+    let owner = Owner::default();
+    let payload: Adoption = serde_json::from_str(r#"{"petId": 7, "adopter": "Alice"}"#).unwrap();
+    let status = owner.on_pet_adopted(payload);
+    assert_eq!(status.status.as_deref(), Some("adopted by Alice"));
+    assert_eq!(
+        serde_json::to_value(&status).unwrap(),
+        serde_json::json!({"status": "adopted by Alice"})
+    );
+
+    let adoptions = owner.adoptions.lock().unwrap();
+    assert_eq!(adoptions.len(), 1);
+    assert_eq!(adoptions[0].pet_id, 7);
+
+    // Handlers can be called through a trait object as well.
+    let handler: &dyn AddPetCallbacks = &owner;
+    handler.on_pet_removed();
+    assert_eq!(*owner.removed.lock().unwrap(), 1);
+}
@@ -0,0 +1,367 @@
+use futures::executor::block_on;
+use futures::FutureExt;
+use std::borrow::Cow;
+use std::path::Path;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::task::Poll;
+use test_pet::client::{
+    ApiClient, ApiError, Client, ClientConfig, Credential, Form, MockClient, MockRequest,
+    MockResponse, Request, Response, Sendable,
+};
+use test_pet::pet::Pet;
+use test_pet::util::FilePart;
+
+type AnyPet = Pet<serde_json::Value>;
+
+fn pet_json(id: i64, name: &str) -> serde_json::Value {
+    serde_json::json!({
+        "id": id,
+        "name": name,
+        "createdAt": "2020-01-01T00:00:00Z",
+    })
+}
+
+fn pet_client() -> MockClient {
+    MockClient::new(|req| {
+        let id = req.path.rsplit('/').next().unwrap().parse().unwrap();
+        MockResponse::new(http::StatusCode::OK).json(&pet_json(id, "Rex"))
+    })
+}
+
+/// Client which keeps track of the requests it's making at the same time.
+struct CountingClient {
+    inner: MockClient,
+    in_flight: AtomicUsize,
+    peak: AtomicUsize,
+}
+
+#[async_trait::async_trait]
+impl ApiClient for CountingClient {
+    type Request = MockRequest;
+    type Response = MockResponse;
+
+    fn request_builder(&self, method: http::Method, rel_path: &str) -> Self::Request {
+        self.inner.request_builder(method, rel_path)
+    }
+
+    async fn make_request(
+        &self,
+        req: Self::Request,
+    ) -> Result<Self::Response, ApiError<Self::Response>> {
+        let n = self.in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+        self.peak.fetch_max(n, Ordering::SeqCst);
+
+        // Let the other requests in before this one finishes.
+        let mut yielded = false;
+        futures::future::poll_fn(|cx| {
+            if yielded {
+                return Poll::Ready(());
+            }
+
+            yielded = true;
+            cx.waker().wake_by_ref();
+            Poll::Pending
+        })
+        .await;
+
+        let resp = self.inner.make_request(req).await;
+        self.in_flight.fetch_sub(1, Ordering::SeqCst);
+        resp
+    }
+}
+
+/// Mock client which can be cloned (sharing the recorded requests).
+#[derive(Clone)]
+struct SharedClient(Arc<MockClient>);
+
+#[async_trait::async_trait]
+impl ApiClient for SharedClient {
+    type Request = MockRequest;
+    type Response = MockResponse;
+
+    fn request_builder(&self, method: http::Method, rel_path: &str) -> Self::Request {
+        self.0.request_builder(method, rel_path)
+    }
+
+    async fn make_request(
+        &self,
+        req: Self::Request,
+    ) -> Result<Self::Response, ApiError<Self::Response>> {
+        self.0.make_request(req).await
+    }
+}
+
+/// Client whose requests never complete.
+#[derive(Default)]
+struct PendingClient {
+    started: AtomicUsize,
+}
+
+#[async_trait::async_trait]
+impl ApiClient for PendingClient {
+    type Request = MockRequest;
+    type Response = MockResponse;
+
+    fn request_builder(&self, method: http::Method, rel_path: &str) -> Self::Request {
+        MockClient::default().request_builder(method, rel_path)
+    }
+
+    async fn make_request(
+        &self,
+        _req: Self::Request,
+    ) -> Result<Self::Response, ApiError<Self::Response>> {
+        self.started.fetch_add(1, Ordering::SeqCst);
+        futures::future::pending().await
+    }
+}
+
+/// Request which only implements the required methods (as a custom client would).
+#[derive(Debug, Default)]
+struct MinimalRequest {
+    headers: Vec<(&'static str, String)>,
+}
+
+#[derive(Debug, Default)]
+struct MinimalForm {
+    files: Vec<String>,
+}
+
+impl Form for MinimalForm {
+    fn new() -> Self {
+        MinimalForm::default()
+    }
+
+    fn text<T, U>(self, _key: T, _value: U) -> Self
+    where
+        T: Into<Cow<'static, str>>,
+        U: Into<Cow<'static, str>>,
+    {
+        self
+    }
+
+    fn file<K>(mut self, key: K, _path: &Path) -> std::io::Result<Self>
+    where
+        K: Into<Cow<'static, str>>,
+    {
+        self.files.push(key.into().into_owned());
+        Ok(self)
+    }
+}
+
+impl Request for MinimalRequest {
+    type Form = MinimalForm;
+
+    fn header(mut self, name: &'static str, value: &str) -> Self {
+        self.headers.push((name, value.into()));
+        self
+    }
+
+    fn body_bytes(self, _body: Vec<u8>) -> Self {
+        self
+    }
+
+    fn json<T: serde::Serialize>(self, _value: &T) -> Self {
+        self
+    }
+
+    fn form<T: serde::Serialize>(self, _value: &T) -> Self {
+        self
+    }
+
+    fn multipart_form_data(self, _form: Self::Form) -> Self {
+        self
+    }
+
+    fn query<T: serde::Serialize>(self, _params: &T) -> Self {
+        self
+    }
+}
+
+#[test]
+fn test_minimal_request_defaults() {
+    let req = MinimalRequest::default()
+        .header("X-Auth", "secret")
+        .timeout(std::time::Duration::from_secs(1));
+    assert_eq!(req.headers, [("X-Auth", "secret".to_owned())]);
+
+    let form = MinimalForm::new()
+        .file_part("photo", &FilePart::Path("rex.png".into()))
+        .unwrap();
+    assert_eq!(form.files, ["photo"]);
+    let err = form
+        .file_part("photo", &FilePart::Bytes(vec![1, 2, 3]))
+        .unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::Unsupported);
+}
+
+#[test]
+fn test_max_in_flight() {
+    let inner = CountingClient {
+        inner: pet_client(),
+        in_flight: AtomicUsize::new(0),
+        peak: AtomicUsize::new(0),
+    };
+    let client = Client::new(inner, ClientConfig::default().max_in_flight(2));
+
+    let builders = (1..=8)
+        .map(|id| AnyPet::get_pet_by_id().pet_id(id))
+        .collect::<Vec<_>>();
+    let pets = block_on(futures::future::join_all(
+        builders.iter().map(|b| b.send(&client)),
+    ));
+    for (id, pet) in (1..=8).zip(pets) {
+        assert_eq!(pet.unwrap().id, id);
+    }
+
+    assert_eq!(client.inner().inner.requests().len(), 8);
+    assert_eq!(client.inner().peak.load(Ordering::SeqCst), 2);
+}
+
+#[test]
+fn test_max_in_flight_until_body_is_read() {
+    let client = Client::new(pet_client(), ClientConfig::default().max_in_flight(1));
+    let resp = block_on(client.make_request(client.request_builder(http::Method::GET, "/pets/1")))
+        .unwrap();
+
+    // The slot is taken until the body of the first response has been read.
+    let mut next = client.make_request(client.request_builder(http::Method::GET, "/pets/2"));
+    assert!((&mut next).now_or_never().is_none());
+    assert_eq!(client.inner().requests().len(), 1);
+
+    let body: serde_json::Value =
+        serde_json::from_slice(&block_on(resp.body_bytes()).unwrap()).unwrap();
+    assert_eq!(body["id"], 1);
+    assert!(block_on(next).is_ok());
+    assert_eq!(client.inner().requests().len(), 2);
+}
+
+#[test]
+fn test_max_response_size() {
+    let limit = serde_json::to_vec(&pet_json(1, "Rex")).unwrap().len();
+
+    let client = Client::new(
+        pet_client(),
+        ClientConfig::default().max_response_size(limit),
+    );
+    let pet = block_on(AnyPet::get_pet_by_id().pet_id(1).send(&client)).unwrap();
+    assert_eq!(pet.name, "Rex");
+
+    let client = Client::new(
+        pet_client(),
+        ClientConfig::default().max_response_size(limit - 1),
+    );
+    match block_on(AnyPet::get_pet_by_id().pet_id(1).send(&client)) {
+        Err(ApiError::ResponseTooLarge(l)) => assert_eq!(l, limit - 1),
+        r => panic!("unexpected result: {:?}", r.map(|p| p.id)),
+    }
+}
+
+#[test]
+fn test_cancellable_send() {
+    let client = PendingClient::default();
+    let builder = AnyPet::get_pet_by_id().pet_id(1);
+    match block_on(builder.send_cancellable(&client, futures::future::ready(()))) {
+        Err(ApiError::Cancelled) => (),
+        r => panic!("unexpected result: {:?}", r.map(|p| p.id)),
+    }
+
+    // The request was in flight when it was cancelled.
+    assert_eq!(client.started.load(Ordering::SeqCst), 1);
+
+    // Requests completing before the cancellation aren't affected.
+    let client = pet_client();
+    let pet = block_on(builder.send_cancellable(&client, futures::future::pending())).unwrap();
+    assert_eq!(pet.id, 1);
+}
+
+#[test]
+fn test_credentials_shared_by_clones() {
+    let config = ClientConfig::default().credential("bearer", Credential::Bearer("old".into()));
+    let client = Client::new(SharedClient(Arc::new(pet_client())), config);
+    let cloned = client.clone();
+
+    block_on(AnyPet::get_pet_by_id().pet_id(1).send(&cloned)).unwrap();
+    client
+        .config()
+        .set_credential("bearer", Credential::Bearer("new".into()));
+    block_on(AnyPet::get_pet_by_id().pet_id(1).send(&cloned)).unwrap();
+
+    let tokens = client
+        .inner()
+        .0
+        .requests()
+        .into_iter()
+        .map(|r| r.headers.get("Authorization").unwrap().clone())
+        .collect::<Vec<_>>();
+    assert_eq!(tokens, ["Bearer old", "Bearer new"]);
+}
+
+#[test]
+fn test_default_headers() {
+    let config = ClientConfig::default()
+        .default_header("Accept-Language", "fr")
+        .default_header("X-Request-Id", "default")
+        .default_header("Accept", "text/plain");
+    let client = Client::new(pet_client(), config);
+
+    block_on(AnyPet::get_pet_by_id().pet_id(1).send(&client)).unwrap();
+    block_on(
+        AnyPet::get_pet_by_id()
+            .pet_id(2)
+            .x_request_id("mine")
+            .send(&client),
+    )
+    .unwrap();
+
+    let requests = client.inner().requests();
+    let values = |name| {
+        requests
+            .iter()
+            .map(|r| {
+                r.headers
+                    .get_all(name)
+                    .iter()
+                    .map(|v| v.to_str().unwrap())
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(values("Accept-Language"), [["fr"], ["fr"]]);
+    // Headers set by the operation (or by the caller) override the defaults.
+    assert_eq!(values("X-Request-Id"), [vec!["default"], vec!["mine"]]);
+    assert_eq!(
+        values("Accept"),
+        [["application/yaml"], ["application/yaml"]]
+    );
+}
+
+#[test]
+fn test_base_url_override() {
+    let config = ClientConfig::default().base_url("http://localhost:8080/v2");
+    let client = Client::new(pet_client(), config.clone());
+    block_on(AnyPet::get_pet_by_id().pet_id(1).send(&client)).unwrap();
+
+    let req = &client.inner().requests()[0];
+    assert_eq!(req.base_url.as_deref(), Some("http://localhost:8080/v2"));
+    assert_eq!(req.path, "/pets/1");
+
+    // Mock clients used directly don't override the base URL.
+    let client = pet_client();
+    block_on(AnyPet::get_pet_by_id().pet_id(1).send(&client)).unwrap();
+    assert_eq!(client.requests()[0].base_url, None);
+
+    let client = Client::new(reqwest::Client::new(), config);
+    let req = AnyPet::get_pet_by_id().pet_id(1).dry_run(&client).unwrap();
+    assert_eq!(req.url().as_str(), "http://localhost:8080/v2/pets/1");
+}
+
+#[test]
+fn test_await_builder() {
+    let client = pet_client();
+    let pet =
+        block_on(async { AnyPet::get_pet_by_id().pet_id(4).with_client(&client).await }).unwrap();
+    assert_eq!(pet.id, 4);
+    assert_eq!(pet.name, "Rex");
+    assert_eq!(client.requests()[0].path, "/pets/4");
+}
@@ -0,0 +1,90 @@
+use futures::executor::block_on;
+use futures::StreamExt;
+use test_pet::client::{MockClient, MockResponse, Sendable};
+use test_pet::miscellaneous::Miscellaneous;
+use test_pet::util::ByteRange;
+
+fn certificate_client() -> MockClient {
+    MockClient::new(|req| match req.headers.get("Range") {
+        Some(_) => MockResponse::new(http::StatusCode::PARTIAL_CONTENT).body(&b"cert"[..]),
+        None => MockResponse::new(http::StatusCode::OK).body(&b"certificate"[..]),
+    })
+}
+
+#[test]
+fn test_range_header() {
+    let client = certificate_client();
+    let resp = block_on(
+        Miscellaneous::get_pet_certificate()
+            .pet_id(1)
+            .range(0, 3)
+            .send(&client),
+    )
+    .unwrap();
+    assert!(resp.is_partial());
+
+    block_on(
+        Miscellaneous::get_pet_certificate()
+            .pet_id(1)
+            .range_from(4)
+            .send(&client),
+    )
+    .unwrap();
+    let resp = block_on(Miscellaneous::get_pet_certificate().pet_id(1).send(&client)).unwrap();
+    assert!(!resp.is_partial());
+
+    let ranges = client
+        .requests()
+        .into_iter()
+        .map(|r| r.headers.get("Range").cloned())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        ranges,
+        [
+            Some("bytes=0-3".parse().unwrap()),
+            Some("bytes=4-".parse().unwrap()),
+            None
+        ]
+    );
+
+    assert_eq!("bytes=0-3".parse(), Ok(ByteRange(0, Some(3))));
+    assert_eq!("bytes=4-".parse(), Ok(ByteRange(4, None)));
+    assert!("bytes=3-0".parse::<ByteRange>().is_err());
+    assert!("bytes=-500".parse::<ByteRange>().is_err());
+}
+
+#[test]
+fn test_download_progress() {
+    let client = MockClient::default().on_operation(
+        "getPetCertificate",
+        MockResponse::new(http::StatusCode::OK)
+            .header("Content-Length", "11")
+            .body(&b"certificate"[..])
+            .chunked(4),
+    );
+
+    let builder = Miscellaneous::get_pet_certificate().pet_id(1);
+    let mut download = block_on(builder.download(&client)).unwrap();
+    assert_eq!(download.total(), Some(11));
+    assert_eq!(download.received(), 0);
+
+    let mut progress = vec![];
+    let mut body = vec![];
+    while let Some(chunk) = block_on(download.next()) {
+        body.extend_from_slice(&chunk.unwrap());
+        progress.push(download.received());
+    }
+    assert_eq!(progress, [4, 8, 11]);
+    assert_eq!(body, b"certificate");
+
+    // Total is unknown without `Content-Length`.
+    let client = MockClient::default().on_operation(
+        "getPetCertificate",
+        MockResponse::new(http::StatusCode::OK).body(&b"certificate"[..]),
+    );
+    let mut download = block_on(builder.download(&client)).unwrap();
+    assert_eq!(download.total(), None);
+    let chunks = block_on(download.by_ref().collect::<Vec<_>>());
+    assert_eq!(chunks.len(), 1);
+    assert_eq!(download.received(), 11);
+}
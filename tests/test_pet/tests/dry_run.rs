@@ -0,0 +1,22 @@
+use test_pet::client::Sendable;
+use test_pet::pet::Pet;
+
+type AnyPet = Pet<serde_json::Value>;
+
+#[test]
+fn test_dry_run_post() {
+    let client = reqwest::Client::new();
+    let builder = AnyPet::add_pet().x_auth("secret").id(7).name("Tom");
+    let req = builder.dry_run(&client).unwrap();
+
+    assert_eq!(req.method(), http::Method::POST);
+    assert_eq!(req.url().as_str(), "https://pets.com:8888/api/pets");
+    assert_eq!(req.headers()["X-Auth"], "secret");
+    // The spec is in YAML, so that's what `addPet` consumes.
+    assert_eq!(req.headers()["Content-Type"], "application/yaml");
+
+    let body = req.body().and_then(|b| b.as_bytes()).unwrap();
+    let body: serde_yaml::Value = serde_yaml::from_slice(body).unwrap();
+    assert_eq!(body["id"].as_i64(), Some(7));
+    assert_eq!(body["name"].as_str(), Some("Tom"));
+}
@@ -0,0 +1,64 @@
+use test_pet::order::OrderStatus;
+use test_pet::pet::Kind;
+use test_pet::test_enum::TestEnum;
+
+/// Checks that each variant in `all` parses back from its wire value.
+fn assert_round_trips<T>(all: &[T], as_wire_str: fn(&T) -> &'static str)
+where
+    T: std::str::FromStr,
+    T::Err: std::fmt::Debug,
+{
+    let mut values = all.iter().map(as_wire_str).collect::<Vec<_>>();
+    for value in &values {
+        let parsed: T = value.parse().unwrap();
+        assert_eq!(as_wire_str(&parsed), *value);
+    }
+
+    values.sort_unstable();
+    values.dedup();
+    assert_eq!(values.len(), all.len());
+}
+
+#[test]
+fn test_all_variants() {
+    // These matches are exhaustive, so they'll break when variants are added.
+    match Kind::Dog {
+        Kind::Dog | Kind::Cat | Kind::Self_ => assert_eq!(Kind::ALL.len(), 3),
+    }
+
+    match OrderStatus::Shipped {
+        OrderStatus::PaymentPending
+        | OrderStatus::OrderPlaced
+        | OrderStatus::Shipped
+        | OrderStatus::Fulfilled => assert_eq!(OrderStatus::ALL.len(), 4),
+    }
+
+    match TestEnum::True {
+        TestEnum::True
+        | TestEnum::Number_1_5
+        | TestEnum::Number_23
+        | TestEnum::Number_964
+        | TestEnum::Number__79_23
+        | TestEnum::Number_14343
+        | TestEnum::Number__964
+        | TestEnum::Hello
+        | TestEnum::Foo
+        | TestEnum::Bar => assert_eq!(TestEnum::ALL.len(), 10),
+    }
+}
+
+#[test]
+fn test_wire_values_round_trip() {
+    assert_round_trips(Kind::ALL, Kind::as_wire_str);
+    assert_round_trips(OrderStatus::ALL, OrderStatus::as_wire_str);
+    assert_round_trips(TestEnum::ALL, TestEnum::as_wire_str);
+
+    // Wire values are the serialized forms of the variants.
+    assert_eq!(Kind::Self_.as_wire_str(), "self");
+    for status in OrderStatus::ALL {
+        let value = serde_json::to_value(status).unwrap();
+        assert_eq!(value.as_str(), Some(status.as_wire_str()));
+    }
+
+    assert!("unknown".parse::<OrderStatus>().is_err());
+}
@@ -0,0 +1,38 @@
+use futures::executor::block_on;
+use test_pet::client::{ApiError, Client, ClientConfig, MockResponse, Sendable};
+use test_pet::pet::Pet;
+
+type AnyPet = Pet<serde_json::Value>;
+
+fn build_request(url: &str) -> Result<reqwest::Request, ApiError<MockResponse>> {
+    Ok(reqwest::Client::new().get(url).build()?)
+}
+
+fn decode_pet(body: &str) -> Result<AnyPet, ApiError<MockResponse>> {
+    Ok(serde_json::from_str(body)?)
+}
+
+#[test]
+fn test_error_conversions() {
+    match build_request("not a url") {
+        Err(ApiError::Reqwest(e)) => assert!(e.is_builder()),
+        r => panic!("unexpected result: {:?}", r),
+    }
+    assert!(build_request("https://pets.com/api/pets").is_ok());
+
+    match decode_pet("{") {
+        Err(ApiError::ApplicationJson(e)) => assert!(e.is_eof()),
+        r => panic!("unexpected result: {:?}", r.map(|p| p.id)),
+    }
+}
+
+#[test]
+fn test_transport_error() {
+    // `reqwest` fails the request (before sending it) when the URL is invalid.
+    let config = ClientConfig::default().base_url("not a url");
+    let client = Client::new(reqwest::Client::new(), config);
+    match block_on(AnyPet::get_pet_by_id().pet_id(1).send(&client)) {
+        Err(ApiError::Reqwest(e)) => assert!(e.is_builder()),
+        r => panic!("unexpected result: {:?}", r.map(|p| p.id)),
+    }
+}
@@ -0,0 +1,35 @@
+use test_pet::client::{Client, Credential};
+
+// Environment is shared by the tests, so everything's checked in one test.
+#[test]
+fn test_client_from_env() {
+    std::env::set_var("PETSTORE_API_KEY", "key");
+    std::env::set_var("PETSTORE_BASIC_USERNAME", "user");
+    std::env::remove_var("PETSTORE_BASIC_PASSWORD");
+    std::env::remove_var("PETSTORE_BEARER_TOKEN");
+
+    let client = Client::from_env().unwrap();
+    let config = client.config();
+    match config.credential_for("api_key") {
+        Some(Credential::ApiKey(k)) => assert_eq!(k, "key"),
+        c => panic!("unexpected credential: {:?}", c),
+    }
+    match config.credential_for("basic") {
+        Some(Credential::Basic { username, password }) => {
+            assert_eq!(username, "user");
+            assert_eq!(password, None);
+        }
+        c => panic!("unexpected credential: {:?}", c),
+    }
+    assert!(config.credential_for("bearer").is_none());
+
+    // Empty variables are treated as unset.
+    std::env::set_var("PETSTORE_API_KEY", "");
+    let err = Client::from_env().unwrap_err();
+    assert_eq!(err.var, "PETSTORE_API_KEY");
+    assert_eq!(err.scheme, "api_key");
+
+    std::env::remove_var("PETSTORE_API_KEY");
+    let err = Client::from_env().unwrap_err();
+    assert_eq!(err.var, "PETSTORE_API_KEY");
+}
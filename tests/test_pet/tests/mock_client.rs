@@ -0,0 +1,284 @@
+use futures::executor::block_on;
+use test_pet::client::{
+    ApiClient, ApiError, MockClient, MockResponse, Request, Response, Sendable,
+};
+use test_pet::get_shipments_id_response::GetShipmentsIdResponse;
+use test_pet::pet::{Kind, Pet};
+use test_pet::util::PreferReturn;
+
+type AnyPet = Pet<serde_json::Value>;
+
+fn pet_json(id: i64, name: &str) -> serde_json::Value {
+    serde_json::json!({
+        "id": id,
+        "name": name,
+        "createdAt": "2020-01-01T00:00:00Z",
+    })
+}
+
+#[test]
+fn test_canned_response_for_operation() {
+    let client = MockClient::default().on_operation(
+        "listPetsByKind",
+        MockResponse::new(http::StatusCode::OK).json(&vec![pet_json(1, "Rex")]),
+    );
+
+    let pets = block_on(AnyPet::list_pets_by_kind().kind(Kind::Dog).send(&client)).unwrap();
+    assert_eq!(pets.len(), 1);
+    assert_eq!(pets[0].name, "Rex");
+
+    assert!(client.called("listPetsByKind"));
+    assert!(!client.called("getPetById"));
+    let requests = client.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].operation_id, Some("listPetsByKind"));
+    assert_eq!(requests[0].method, http::Method::GET);
+    assert_eq!(requests[0].path, "/pets/kind/dog");
+}
+
+#[test]
+fn test_canned_response_for_path() {
+    let client = MockClient::default().on(
+        http::Method::GET,
+        "/pets/1",
+        MockResponse::new(http::StatusCode::OK).json(&pet_json(1, "Rex")),
+    );
+
+    let pet = block_on(AnyPet::get_pet_by_id().pet_id(1).send(&client)).unwrap();
+    assert_eq!(pet.id, 1);
+
+    // Requests which aren't matched get `404 Not Found`.
+    match block_on(AnyPet::get_pet_by_id().pet_id(2).send(&client)) {
+        Err(ApiError::Failure(_, status, _)) => assert_eq!(status, http::StatusCode::NOT_FOUND),
+        r => panic!("unexpected result: {:?}", r.map(|p| p.id)),
+    }
+
+    assert!(client.called("getPetById"));
+    let paths = client
+        .requests()
+        .into_iter()
+        .map(|r| r.path)
+        .collect::<Vec<_>>();
+    assert_eq!(paths, ["/pets/1", "/pets/2"]);
+}
+
+#[test]
+fn test_recorded_request_body_and_headers() {
+    let client = MockClient::new(|req| {
+        let mut resp = pet_json(7, "Tom");
+        resp["x-body-len"] = req.body.as_ref().map(Vec::len).unwrap_or(0).into();
+        MockResponse::new(http::StatusCode::OK).json(&resp)
+    });
+
+    let pet = block_on(
+        AnyPet::add_pet()
+            .x_auth("secret")
+            .id(7)
+            .name("Tom")
+            .send(&client),
+    )
+    .unwrap();
+    assert_eq!(pet.name, "Tom");
+
+    let req = &client.requests()[0];
+    assert_eq!(req.operation_id, Some("addPet"));
+    assert_eq!(req.method, http::Method::POST);
+    assert_eq!(req.headers.get("X-Auth").unwrap(), "secret");
+    // `addPet` consumes YAML.
+    assert_eq!(req.headers.get("Content-Type").unwrap(), "application/yaml");
+    let body: serde_yaml::Value = serde_yaml::from_slice(req.body.as_ref().unwrap()).unwrap();
+    assert_eq!(body["id"].as_i64(), Some(7));
+    assert_eq!(body["name"].as_str(), Some("Tom"));
+}
+
+#[test]
+fn test_serialization_errors_fail_the_request() {
+    // JSON objects can't have non-string keys.
+    let mut body = std::collections::BTreeMap::new();
+    body.insert((1, 2), "pair");
+
+    let client = MockClient::default();
+    let req = client
+        .request_builder(http::Method::POST, "/pets")
+        .json(&body);
+    match block_on(client.make_request(req)) {
+        Err(ApiError::Io(e)) => assert_eq!(e.kind(), std::io::ErrorKind::InvalidInput),
+        r => panic!("unexpected result: {:?}", r),
+    }
+
+    let req = &client.requests()[0];
+    assert!(req.body.is_none());
+    assert!(req.error.is_some());
+}
+
+#[test]
+fn test_invalid_mock_values_fail_the_request() {
+    let client = MockClient::default();
+    let req = client
+        .request_builder(http::Method::GET, "/pets")
+        .header("X-Auth", "line\nbreak");
+    match block_on(client.make_request(req)) {
+        Err(ApiError::Io(e)) => assert!(e.to_string().contains("\"X-Auth\"")),
+        r => panic!("unexpected result: {:?}", r),
+    }
+    assert!(client.requests()[0].headers.is_empty());
+
+    let mut body = std::collections::BTreeMap::new();
+    body.insert((1, 2), "pair");
+    let client = MockClient::default()
+        .on(
+            http::Method::GET,
+            "/pets/1",
+            MockResponse::new(http::StatusCode::OK).json(&body),
+        )
+        .on(
+            http::Method::GET,
+            "/pets/2",
+            MockResponse::new(http::StatusCode::OK).header("X-Rate", "line\nbreak"),
+        );
+    for path in &["/pets/1", "/pets/2"] {
+        let req = client.request_builder(http::Method::GET, path);
+        match block_on(client.make_request(req)) {
+            Err(ApiError::Io(e)) => assert_eq!(e.kind(), std::io::ErrorKind::InvalidInput),
+            r => panic!("unexpected result: {:?}", r),
+        }
+    }
+    assert_eq!(client.requests().len(), 2);
+}
+
+#[test]
+fn test_response_with_bom() {
+    let body = format!("\u{feff}{}", pet_json(3, "Felix"));
+    let client = MockClient::default().on_operation(
+        "getPetById",
+        MockResponse::new(http::StatusCode::OK)
+            .header("Content-Type", "application/json")
+            .body(body),
+    );
+
+    let pet = block_on(AnyPet::get_pet_by_id().pet_id(3).send(&client)).unwrap();
+    assert_eq!(pet.id, 3);
+    assert_eq!(pet.name, "Felix");
+}
+
+#[test]
+fn test_empty_path_parameter() {
+    let client = MockClient::default();
+    let builder = GetShipmentsIdResponse::get_shipment().id("").session("s");
+    match block_on(builder.send(&client)) {
+        Err(ApiError::EmptyPathParameter(name)) => assert_eq!(name, "id"),
+        r => panic!("unexpected result: {:?}", r.map(|_| ())),
+    }
+
+    assert!(client.requests().is_empty());
+}
+
+#[test]
+fn test_path_parameter_encoding() {
+    let client = MockClient::default();
+    for id in &["a/b c", "a%2Fb%20c", "100%"] {
+        let builder = GetShipmentsIdResponse::get_shipment().id(*id).session("s");
+        let _ = block_on(builder.send(&client));
+    }
+
+    let paths = client
+        .requests()
+        .into_iter()
+        .map(|r| r.path)
+        .collect::<Vec<_>>();
+    // Existing escapes are left alone, whereas stray `%`s are encoded.
+    assert_eq!(
+        paths,
+        [
+            "/shipments/a%2Fb%20c",
+            "/shipments/a%2Fb%20c",
+            "/shipments/100%25"
+        ]
+    );
+}
+
+#[test]
+fn test_operation_timeout() {
+    let client = MockClient::default();
+    let op = AnyPet::get_pet_by_id().pet_id(1);
+    let req = op.request(&client).unwrap();
+    assert_eq!(req.timeout, Some(std::time::Duration::from_millis(2500)));
+
+    // The operation's timeout can be overridden for a single request.
+    let req = op
+        .request(&client)
+        .unwrap()
+        .timeout(std::time::Duration::from_secs(10));
+    assert_eq!(req.timeout, Some(std::time::Duration::from_secs(10)));
+    block_on(client.make_request(req)).unwrap();
+    assert_eq!(
+        client.requests()[0].timeout,
+        Some(std::time::Duration::from_secs(10))
+    );
+}
+
+#[test]
+fn test_modify_external_request() {
+    let client = MockClient::default().on_operation(
+        "getPetById",
+        MockResponse::new(http::StatusCode::OK).json(&pet_json(1, "Rex")),
+    );
+    let op = AnyPet::get_pet_by_id().pet_id(1).x_request_id("abc");
+
+    // Request built by the caller (with its own headers) and filled in by `modify`.
+    let rel_path = Sendable::<MockClient>::rel_path(&op).unwrap();
+    let req = client
+        .request_builder(http::Method::GET, &rel_path)
+        .header("X-Signature", "sig")
+        .operation_id("getPetById");
+    let req = Sendable::<MockClient>::modify(&op, req).unwrap();
+    let resp = block_on(client.make_request(req)).unwrap();
+    assert_eq!(resp.status(), http::StatusCode::OK);
+
+    // Request built by `request` and altered before sending.
+    let req = op.request(&client).unwrap().header("X-Signature", "other");
+    block_on(client.make_request(req)).unwrap();
+
+    let requests = client.requests();
+    assert_eq!(requests.len(), 2);
+    for (req, sig) in requests.iter().zip(&["sig", "other"]) {
+        assert_eq!(req.path, "/pets/1");
+        assert_eq!(req.headers.get("X-Request-Id").unwrap(), "abc");
+        assert_eq!(req.headers.get("Accept").unwrap(), "application/yaml");
+        assert_eq!(req.headers.get("X-Signature").unwrap(), *sig);
+        assert_eq!(req.timeout, Some(std::time::Duration::from_millis(2500)));
+    }
+}
+
+#[test]
+fn test_prefer_return() {
+    let client = MockClient::new(|req| match req.headers.get("Prefer") {
+        Some(v) if v == "return=minimal" => MockResponse::new(http::StatusCode::NO_CONTENT),
+        _ => MockResponse::new(http::StatusCode::OK).json(&pet_json(1, "Rex")),
+    });
+    let update = || AnyPet::update_pet().pet_id(1).id(1).name("Rex");
+
+    let pet = block_on(
+        update()
+            .prefer_return(PreferReturn::Representation)
+            .send(&client),
+    )
+    .unwrap();
+    assert_eq!(pet.as_ref().map(|p| p.name.as_str()), Some("Rex"));
+
+    let pet = block_on(update().prefer_return(PreferReturn::Minimal).send(&client)).unwrap();
+    assert!(pet.is_none());
+
+    let preferences = client
+        .requests()
+        .into_iter()
+        .map(|r| r.headers.get("Prefer").cloned())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        preferences,
+        [
+            Some("return=representation".parse().unwrap()),
+            Some("return=minimal".parse().unwrap())
+        ]
+    );
+}
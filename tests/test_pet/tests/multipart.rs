@@ -0,0 +1,91 @@
+use futures::executor::block_on;
+use test_pet::client::{ApiError, MockClient, MockResponse, Sendable};
+use test_pet::pet::Pet;
+use test_pet::util::{multipart_boundary, parse_multipart, MultipartMixed};
+
+type AnyPet = Pet<serde_json::Value>;
+
+const PHOTO: &[u8] = b"\x89PNG\r\n\x1a\n\x00\xff\r\n";
+
+fn mixed_body() -> Vec<u8> {
+    let mut body = b"preamble\r\n--frontier\r\nContent-Type: application/json\r\n\r\n".to_vec();
+    body.extend_from_slice(br#"{"id": 1, "name": "Rex", "createdAt": "2020-01-01T00:00:00Z"}"#);
+    body.extend_from_slice(b"\r\n--frontier\r\nContent-Type: image/png\r\n");
+    body.extend_from_slice(b"Content-Disposition: attachment; filename=\"rex.png\"\r\n\r\n");
+    body.extend_from_slice(PHOTO);
+    body.extend_from_slice(b"\r\n--frontier\r\n\r\nno headers\r\n--frontier--\r\n");
+    body
+}
+
+fn photo_client(body: Vec<u8>) -> MockClient {
+    MockClient::default().on_operation(
+        "getPetWithPhoto",
+        MockResponse::new(http::StatusCode::OK)
+            .header("Content-Type", "multipart/mixed; boundary=\"frontier\"")
+            .body(body),
+    )
+}
+
+#[test]
+fn test_multipart_mixed_response() {
+    let client = photo_client(mixed_body());
+    let resp = block_on(AnyPet::get_pet_with_photo().pet_id(1).send(&client)).unwrap();
+
+    assert_eq!(resp.metadata.id, 1);
+    assert_eq!(resp.metadata.name, "Rex");
+    assert_eq!(resp.parts.len(), 2);
+
+    let photo = &resp.parts[0];
+    assert_eq!(photo.content_type(), Some("image/png"));
+    assert_eq!(
+        photo.header("Content-Disposition"),
+        Some("attachment; filename=\"rex.png\"")
+    );
+    assert_eq!(photo.body, PHOTO);
+
+    assert!(resp.parts[1].headers.is_empty());
+    assert_eq!(resp.parts[1].body, b"no headers");
+}
+
+#[test]
+fn test_malformed_multipart_response() {
+    // Missing the closing delimiter.
+    let body = b"--frontier\r\nContent-Type: text/plain\r\n\r\ntruncated".to_vec();
+    assert!(parse_multipart("frontier", &body).is_none());
+    // Missing the blank line after the headers.
+    assert!(parse_multipart(
+        "frontier",
+        b"--frontier\r\nContent-Type: text/plain\r\n--frontier--"
+    )
+    .is_none());
+    // Missing the boundary altogether.
+    assert!(parse_multipart("frontier", b"just some bytes").is_none());
+
+    let client = photo_client(body);
+    match block_on(AnyPet::get_pet_with_photo().pet_id(1).send(&client)) {
+        Err(ApiError::InvalidMultipart(reason)) => assert_eq!(reason, "malformed body"),
+        r => panic!("unexpected result: {:?}", r.map(|r| r.parts.len())),
+    }
+}
+
+#[test]
+fn test_multipart_boundary() {
+    assert_eq!(
+        multipart_boundary("multipart/mixed; boundary=\"frontier\""),
+        Some("frontier")
+    );
+    assert_eq!(
+        multipart_boundary("multipart/mixed;charset=utf-8; Boundary=abc"),
+        Some("abc")
+    );
+    assert_eq!(multipart_boundary("multipart/mixed"), None);
+}
+
+#[test]
+fn test_multipart_mixed_deserialize() {
+    let err = serde_json::from_str::<MultipartMixed<AnyPet>>("{}").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "multipart/mixed bodies can't be deserialized"
+    );
+}
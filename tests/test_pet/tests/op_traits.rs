@@ -0,0 +1,28 @@
+use futures::executor::block_on;
+use std::sync::{Arc, Mutex};
+use test_pet::apis::DefaultApi;
+use test_pet::client::{MockClient, MockResponse};
+use test_pet::pet::{Kind, Pet};
+
+type AnyPet = Pet<serde_json::Value>;
+
+#[test]
+fn test_trait_object_dispatch() {
+    let operations = Arc::new(Mutex::new(vec![]));
+    let recorded = operations.clone();
+    let client = MockClient::new(move |req| {
+        recorded.lock().unwrap().push(req.operation_id);
+        MockResponse::new(http::StatusCode::OK).json(&serde_json::json!([{
+            "id": 1,
+            "name": "Rex",
+            "createdAt": "2020-01-01T00:00:00Z",
+        }]))
+    });
+
+    let api: Box<dyn DefaultApi<MockClient>> = Box::new(client);
+    let pets =
+        block_on(api.list_pets_by_kind(AnyPet::list_pets_by_kind().kind(Kind::Dog))).unwrap();
+    assert_eq!(pets.len(), 1);
+    assert_eq!(pets[0].name, "Rex");
+    assert_eq!(*operations.lock().unwrap(), [Some("listPetsByKind")]);
+}
@@ -0,0 +1,26 @@
+use test_pet::prelude::*;
+
+#[test]
+fn test_prelude_is_enough() {
+    let mock = MockClient::default().on_operation(
+        "getPetById",
+        MockResponse::new(http::StatusCode::OK).json(&serde_json::json!({
+            "id": 1,
+            "name": "Rex",
+            "createdAt": "2020-01-01T00:00:00Z",
+        })),
+    );
+    let config = ClientConfig::default().credential("bearer", Credential::Bearer("token".into()));
+    let client = Client::new(mock, config);
+
+    let pet = futures::executor::block_on(
+        Pet::<serde_json::Value>::get_pet_by_id()
+            .pet_id(1)
+            .send(&client),
+    )
+    .unwrap();
+    assert_eq!(pet.name, "Rex");
+
+    let req = &client.inner().requests()[0];
+    assert_eq!(req.headers.get("Authorization").unwrap(), "Bearer token");
+}
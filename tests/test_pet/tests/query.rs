@@ -0,0 +1,71 @@
+use futures::executor::block_on;
+use test_pet::client::{deep_object_query, MockClient, Sendable};
+use test_pet::pet::{Kind, Pet};
+use test_pet::pet_filter::PetFilter;
+
+type AnyPet = Pet<serde_json::Value>;
+
+fn filter() -> PetFilter {
+    PetFilter {
+        name: Some("Rex".into()),
+        min_age: Some(2),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_deep_object_query() {
+    let client = MockClient::default();
+    let builder = || AnyPet::list_pets_by_kind().kind(Kind::Dog);
+    let _ = block_on(builder().filter(filter()).send(&client));
+    let _ = block_on(builder().send(&client));
+
+    let queries = client
+        .requests()
+        .into_iter()
+        .map(|r| r.query)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        queries,
+        [
+            Some(serde_json::json!([
+                ["filter[minAge]", "2"],
+                ["filter[name]", "Rex"]
+            ])),
+            Some(serde_json::json!([])),
+        ]
+    );
+
+    let client = reqwest::Client::new();
+    let req = AnyPet::list_pets_by_kind()
+        .kind(Kind::Dog)
+        .filter(filter())
+        .dry_run(&client)
+        .unwrap();
+    assert_eq!(
+        req.url().query(),
+        Some("filter%5BminAge%5D=2&filter%5Bname%5D=Rex")
+    );
+    let pairs = req.url().query_pairs().into_owned().collect::<Vec<_>>();
+    assert_eq!(
+        pairs,
+        [
+            ("filter[minAge]".to_owned(), "2".to_owned()),
+            ("filter[name]".to_owned(), "Rex".to_owned())
+        ]
+    );
+}
+
+#[test]
+fn test_nested_deep_object_query() {
+    let mut filter = filter();
+    filter.category = Some(serde_json::from_value(serde_json::json!({"name": "hound"})).unwrap());
+    assert_eq!(
+        deep_object_query("filter", &filter),
+        [
+            ("filter[category][name]".to_owned(), "hound".to_owned()),
+            ("filter[minAge]".to_owned(), "2".to_owned()),
+            ("filter[name]".to_owned(), "Rex".to_owned()),
+        ]
+    );
+}
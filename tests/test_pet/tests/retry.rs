@@ -0,0 +1,87 @@
+use futures::executor::block_on;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use test_pet::client::{
+    ApiError, Client, ClientConfig, MockClient, MockResponse, RetryPolicy, Sendable,
+};
+use test_pet::pet::Pet;
+
+type AnyPet = Pet<serde_json::Value>;
+
+/// Mock client failing (with `503`) the first given number of requests.
+fn flaky_client(failures: usize) -> (MockClient, Arc<AtomicUsize>) {
+    let attempts = Arc::new(AtomicUsize::new(0));
+    let counter = attempts.clone();
+    let client = MockClient::new(move |_| {
+        if counter.fetch_add(1, Ordering::SeqCst) < failures {
+            return MockResponse::new(http::StatusCode::SERVICE_UNAVAILABLE);
+        }
+
+        MockResponse::new(http::StatusCode::OK).json(&serde_json::json!({
+            "id": 1,
+            "name": "Rex",
+            "createdAt": "2020-01-01T00:00:00Z",
+        }))
+    });
+
+    (client, attempts)
+}
+
+#[test]
+fn test_retry_delays() {
+    let policy = RetryPolicy::new(4, Duration::from_millis(10));
+    let delays = (1..=4)
+        .map(|attempt| policy.delay(&http::Method::GET, attempt))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        delays,
+        [
+            Some(Duration::from_millis(10)),
+            Some(Duration::from_millis(20)),
+            Some(Duration::from_millis(40)),
+            None,
+        ]
+    );
+
+    // Non-idempotent methods aren't retried unless they're allowed.
+    assert_eq!(policy.delay(&http::Method::POST, 1), None);
+    let policy = policy.all_methods(true);
+    assert_eq!(
+        policy.delay(&http::Method::POST, 1),
+        Some(Duration::from_millis(10))
+    );
+}
+
+#[test]
+fn test_retry_server_errors() {
+    let policy = RetryPolicy::new(3, Duration::from_millis(10)).server_errors(true);
+    let (mock, attempts) = flaky_client(2);
+    let client = Client::new(mock, ClientConfig::default().retry(policy.clone()));
+
+    let start = Instant::now();
+    let pet = block_on(AnyPet::get_pet_by_id().pet_id(1).send(&client)).unwrap();
+    assert_eq!(pet.name, "Rex");
+    assert_eq!(attempts.load(Ordering::SeqCst), 3);
+    assert_eq!(client.inner().requests().len(), 3);
+    // Waited 10ms before the second attempt and 20ms before the third.
+    assert!(start.elapsed() >= Duration::from_millis(30));
+
+    // The last failure is returned once the attempts run out.
+    let (mock, attempts) = flaky_client(3);
+    let client = Client::new(mock, ClientConfig::default().retry(policy));
+    match block_on(AnyPet::get_pet_by_id().pet_id(1).send(&client)) {
+        Err(ApiError::Failure(_, status, _)) => {
+            assert_eq!(status, http::StatusCode::SERVICE_UNAVAILABLE)
+        }
+        r => panic!("unexpected result: {:?}", r.map(|p| p.id)),
+    }
+    assert_eq!(attempts.load(Ordering::SeqCst), 3);
+
+    // Server errors aren't retried by default.
+    let (mock, attempts) = flaky_client(1);
+    let policy = RetryPolicy::new(3, Duration::from_millis(10));
+    let client = Client::new(mock, ClientConfig::default().retry(policy));
+    assert!(block_on(AnyPet::get_pet_by_id().pet_id(1).send(&client)).is_err());
+    assert_eq!(attempts.load(Ordering::SeqCst), 1);
+}
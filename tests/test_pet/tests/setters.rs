@@ -0,0 +1,44 @@
+use futures::executor::block_on;
+use test_pet::category::Category;
+use test_pet::client::{MockClient, Sendable};
+use test_pet::pet::Pet;
+
+type AnyPet = Pet<serde_json::Value>;
+
+struct PetId(i64);
+
+impl From<PetId> for i64 {
+    fn from(id: PetId) -> Self {
+        id.0
+    }
+}
+
+#[test]
+fn test_scalar_setters() {
+    let id = 3;
+    let category: Category = Category::builder()
+        .id(1)
+        .rank(2i8)
+        .position_ref(&id)
+        .maybe_count(Some(PetId(4)))
+        .ratio(0.5f32)
+        .into();
+    assert_eq!(category.id, Some(1));
+    assert_eq!(category.rank, Some(2));
+    assert_eq!(category.position, Some(3));
+    assert_eq!(category.count, Some(4));
+    assert_eq!(category.ratio, Some(0.5));
+}
+
+#[test]
+fn test_scalar_parameter_setters() {
+    let client = MockClient::default();
+    let _ = block_on(AnyPet::get_pet_by_id().pet_id(PetId(1)).send(&client));
+    let _ = block_on(AnyPet::get_pet_by_id().pet_id_ref(&2).send(&client));
+    let paths = client
+        .requests()
+        .into_iter()
+        .map(|r| r.path)
+        .collect::<Vec<_>>();
+    assert_eq!(paths, ["/pets/1", "/pets/2"]);
+}
@@ -0,0 +1,17 @@
+// The standalone module is meant to be included at the root of some other crate.
+#[macro_use]
+extern crate serde;
+
+include!("../standalone/mod.rs");
+
+use client::{MockClient, Sendable};
+use futures::executor::block_on;
+
+#[test]
+fn test_standalone_module_builds_requests() {
+    let client = MockClient::default();
+    let op = pet::Pet::<serde_json::Value>::get_pet_by_id().pet_id(1);
+    assert!(block_on(op.send(&client)).is_err());
+    assert_eq!(client.requests()[0].operation_id, Some("getPetById"));
+    assert_eq!(client.requests()[0].path, "/pets/1");
+}
@@ -0,0 +1,47 @@
+use test_pet::payment::{Payment, PaymentBankTransfer, PaymentCard};
+
+#[test]
+fn test_variant_fields_round_trip() {
+    let value = serde_json::json!({
+        "method": "Card",
+        "cardNumber": "4242424242424242",
+        "expiryMonth": 12,
+        "holderName": "Jane Doe",
+    });
+
+    // Variant fields are renamed with `rename_all` (instead of per field).
+    let payment: Payment = serde_json::from_value(value.clone()).unwrap();
+    match &payment {
+        Payment::Card(card) => {
+            assert_eq!(card.card_number.as_deref(), Some("4242424242424242"));
+            assert_eq!(card.expiry_month, Some(12));
+            assert_eq!(card.holder_name.as_deref(), Some("Jane Doe"));
+        }
+        p => panic!("unexpected variant: {:?}", p),
+    }
+
+    assert_eq!(serde_json::to_value(&payment).unwrap(), value);
+
+    let transfer: PaymentBankTransfer = PaymentBankTransfer::builder()
+        .account_number("123")
+        .routing_code("456")
+        .into();
+    let payment = Payment::from(transfer);
+    let value = serde_json::to_value(&payment).unwrap();
+    assert_eq!(
+        value,
+        serde_json::json!({
+            "method": "BankTransfer",
+            "accountNumber": "123",
+            "routingCode": "456",
+        })
+    );
+    match serde_json::from_value(value).unwrap() {
+        Payment::BankTransfer(t) => assert_eq!(t.routing_code.as_deref(), Some("456")),
+        p => panic!("unexpected variant: {:?}", p),
+    }
+
+    // Fields in their original (snake case) form aren't picked up.
+    let card: PaymentCard = serde_json::from_str(r#"{"card_number": "1"}"#).unwrap();
+    assert!(card.card_number.is_none());
+}
@@ -0,0 +1,25 @@
+use test_pet::category::Category;
+
+fn category(level: i32, ticket: i64) -> Category {
+    let mut c = Category::default();
+    c.level = Some(level);
+    c.ticket = Some(ticket);
+    c
+}
+
+#[test]
+fn test_integer_bounds_at_type_limits() {
+    assert!(category(i32::MAX, 0).validate().is_ok());
+    assert!(category(-2147483647, 0).validate().is_ok());
+    let err = category(i32::MIN, 0).validate().unwrap_err();
+    assert_eq!(err.field, "level");
+    assert_eq!(err.reason, "must be at least -2147483647");
+
+    // These are past 2^53, where `f64` can't tell them apart.
+    assert!(category(0, 9007199254740992).validate().is_ok());
+    let err = category(0, 9007199254740993).validate().unwrap_err();
+    assert_eq!(err.field, "ticket");
+    assert_eq!(err.reason, "must be less than 9007199254740993");
+    assert!(category(0, -9007199254740993).validate().is_ok());
+    assert!(category(0, -9007199254740994).validate().is_err());
+}
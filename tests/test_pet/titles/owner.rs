@@ -0,0 +1,87 @@
+
+/// Account, account ID or guest owning a pet (untagged).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum Owner {
+    Account(crate::account::Account),
+    Variant2(i64),
+    Guest(crate::owner::Guest),
+}
+
+impl Default for Owner {
+    fn default() -> Self {
+        Owner::Account(Default::default())
+    }
+}
+
+impl From<crate::account::Account> for Owner {
+    fn from(value: crate::account::Account) -> Self {
+        Owner::Account(value)
+    }
+}
+
+impl From<i64> for Owner {
+    fn from(value: i64) -> Self {
+        Owner::Variant2(value)
+    }
+}
+
+impl From<crate::owner::Guest> for Owner {
+    fn from(value: crate::owner::Guest) -> Self {
+        Owner::Guest(value)
+    }
+}
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct Guest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nickname: Option<String>,
+}
+
+
+impl Guest {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> GuestBuilder {
+        GuestBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> GuestBuilder
+    where
+        Self: Clone,
+    {
+        GuestBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl Into<Guest> for GuestBuilder {
+    fn into(self) -> Guest {
+        self.body
+    }
+}
+
+/// Builder for [`Guest`](./struct.Guest.html) object.
+#[derive(Debug, Default, Clone)]
+pub struct GuestBuilder {
+    body: self::Guest,
+}
+
+impl GuestBuilder {
+    #[inline]
+    pub fn nickname(mut self, value: impl Into<String>) -> Self {
+        self.body.nickname = Some(value.into());
+        self
+    }
+
+    /// Sets [`nickname`](#method.nickname) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_nickname(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.nickname = value.map(|value| value.into());
+        self
+    }
+}
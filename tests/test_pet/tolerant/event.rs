@@ -0,0 +1,20 @@
+
+/// Pet or order (wrapped along with its kind).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "kind", content = "data")]
+pub enum Event<Any> {
+    #[serde(rename = "Pet")]
+    Pet(crate::pet::Pet<Any>),
+    #[serde(rename = "Order")]
+    Order(crate::order::Order),
+    /// Object with an unknown tag (as it appeared on the wire).
+    #[serde(untagged)]
+    Unknown(serde_json::Value),
+}
+
+impl<Any: Default> Default for Event<Any> {
+    fn default() -> Self {
+        Event::Pet(Default::default())
+    }
+}
+
@@ -0,0 +1,21 @@
+
+pub struct MissingFoobar;
+pub struct FoobarExists;
+pub struct MissingGrantType;
+pub struct GrantTypeExists;
+pub struct MissingId;
+pub struct IdExists;
+pub struct MissingKind;
+pub struct KindExists;
+pub struct MissingName;
+pub struct NameExists;
+pub struct MissingPetId;
+pub struct PetIdExists;
+pub struct MissingSession;
+pub struct SessionExists;
+pub struct MissingSomeDataFile;
+pub struct SomeDataFileExists;
+pub struct MissingValues;
+pub struct ValuesExists;
+pub struct MissingXAuth;
+pub struct XAuthExists;
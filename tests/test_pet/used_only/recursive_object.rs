@@ -0,0 +1,105 @@
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct RecursiveObject<Any> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub any: Option<Any>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub children: Option<Vec<crate::recursive_object::RecursiveObject<Any>>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next: Option<Box<crate::recursive_object::RecursiveObject<Any>>>,
+}
+
+impl<Any: Default> RecursiveObject<Any> {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> RecursiveObjectBuilder<Any> {
+        RecursiveObjectBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create a builder from this object.
+    #[inline]
+    pub fn to_builder(&self) -> RecursiveObjectBuilder<Any>
+    where
+        Self: Clone,
+    {
+        RecursiveObjectBuilder {
+            body: self.clone(),
+        }
+    }
+}
+
+impl<Any> Into<RecursiveObject<Any>> for RecursiveObjectBuilder<Any> {
+    fn into(self) -> RecursiveObject<Any> {
+        self.body
+    }
+}
+
+/// Builder for [`RecursiveObject`](./struct.RecursiveObject.html) object.
+#[derive(Debug, Clone)]
+pub struct RecursiveObjectBuilder<Any> {
+    body: self::RecursiveObject<Any>,
+}
+
+impl<Any> RecursiveObjectBuilder<Any> {
+    #[inline]
+    pub fn any(mut self, value: impl Into<Any>) -> Self {
+        self.body.any = Some(value.into());
+        self
+    }
+
+    /// Sets [`any`](#method.any) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_any(mut self, value: Option<impl Into<Any>>) -> Self {
+        self.body.any = value.map(|value| value.into());
+        self
+    }
+
+    #[inline]
+    pub fn children(mut self, value: impl Iterator<Item = crate::recursive_object::RecursiveObject<Any>>) -> Self {
+        self.body.children = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Sets [`children`](#method.children) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_children(mut self, value: Option<impl Iterator<Item = crate::recursive_object::RecursiveObject<Any>>>) -> Self {
+        self.body.children = value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Same as [`children`](#method.children), but takes the values as a `Vec`.
+    #[inline]
+    pub fn children_vec(mut self, value: impl Into<Vec<crate::recursive_object::RecursiveObject<Any>>>) -> Self {
+        self.body.children = Some(value.into());
+        self
+    }
+
+    #[inline]
+    pub fn id(mut self, value: impl Into<String>) -> Self {
+        self.body.id = Some(value.into());
+        self
+    }
+
+    /// Sets [`id`](#method.id) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_id(mut self, value: Option<impl Into<String>>) -> Self {
+        self.body.id = value.map(|value| value.into());
+        self
+    }
+
+    #[inline]
+    pub fn next(mut self, value: crate::recursive_object::RecursiveObject<Any>) -> Self {
+        self.body.next = Some(value.into());
+        self
+    }
+
+    /// Sets [`next`](#method.next) if the given value is `Some` (or unsets it otherwise).
+    #[inline]
+    pub fn maybe_next(mut self, value: Option<crate::recursive_object::RecursiveObject<Any>>) -> Self {
+        self.body.next = value.map(|value| value.into());
+        self
+    }
+}